@@ -29,25 +29,27 @@ impl AccountBrands {
     */
     pub async fn brands_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         exclude_distributor_brand: &str,
         include_logos: &str,
     ) -> Result<crate::types::AccountBrands> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_distributor_brand.is_empty() {
             query_args.push((
-                "exclude_distributor_brand".to_string(),
+                "exclude_distributor_brand",
                 exclude_distributor_brand.to_string(),
             ));
         }
         if !include_logos.is_empty() {
-            query_args.push(("include_logos".to_string(), include_logos.to_string()));
+            query_args.push(("include_logos", include_logos.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/brands?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/brands",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -72,9 +74,10 @@ impl AccountBrands {
     */
     pub async fn brands_post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::Brand,
     ) -> Result<crate::types::AccountBrands> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands",
             crate::progenitor_support::encode_path(account_id),
@@ -100,9 +103,10 @@ impl AccountBrands {
     */
     pub async fn brands_delete(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::BrandsRequest,
     ) -> Result<crate::types::AccountBrands> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands",
             crate::progenitor_support::encode_path(account_id),
@@ -131,27 +135,30 @@ impl AccountBrands {
     */
     pub async fn brand_get(
         &self,
-        account_id: &str,
-        brand_id: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
         include_external_references: &str,
         include_logos: &str,
     ) -> Result<crate::types::Brand> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_external_references.is_empty() {
             query_args.push((
-                "include_external_references".to_string(),
+                "include_external_references",
                 include_external_references.to_string(),
             ));
         }
         if !include_logos.is_empty() {
-            query_args.push(("include_logos".to_string(), include_logos.to_string()));
+            query_args.push(("include_logos", include_logos.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/brands/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(brand_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/brands/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -173,10 +180,12 @@ impl AccountBrands {
     */
     pub async fn brand_put(
         &self,
-        account_id: &str,
-        brand_id: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
         body: &crate::types::Brand,
     ) -> Result<crate::types::Brand> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -202,7 +211,13 @@ impl AccountBrands {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `brand_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn brand_delete(&self, account_id: &str, brand_id: &str) -> Result<()> {
+    pub async fn brand_delete(
+        &self,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -226,7 +241,13 @@ impl AccountBrands {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `brand_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn brand_export_get_file(&self, account_id: &str, brand_id: &str) -> Result<()> {
+    pub async fn brand_export_get_file(
+        &self,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/file",
             crate::progenitor_support::encode_path(account_id),
@@ -253,10 +274,13 @@ impl AccountBrands {
     */
     pub async fn brand_logo_get(
         &self,
-        account_id: &str,
-        brand_id: &str,
-        logo_type: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
+        logo_type: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
+        let logo_type = logo_type.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -288,11 +312,14 @@ impl AccountBrands {
     */
     pub async fn brand_logo_put<T: Into<reqwest::Body>>(
         &self,
-        account_id: &str,
-        brand_id: &str,
-        logo_type: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
+        logo_type: impl AsRef<str>,
         body: bytes::Bytes,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
+        let logo_type = logo_type.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -320,10 +347,13 @@ impl AccountBrands {
     */
     pub async fn brand_logo_delete(
         &self,
-        account_id: &str,
-        brand_id: &str,
-        logo_type: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
+        logo_type: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
+        let logo_type = logo_type.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -350,9 +380,11 @@ impl AccountBrands {
     */
     pub async fn brand_resources_get_list(
         &self,
-        account_id: &str,
-        brand_id: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
     ) -> Result<crate::types::BrandResourcesList> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/resources",
             crate::progenitor_support::encode_path(account_id),
@@ -390,26 +422,30 @@ impl AccountBrands {
     */
     pub async fn brand_resources_get(
         &self,
-        account_id: &str,
-        brand_id: &str,
-        resource_content_type: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
+        resource_content_type: impl AsRef<str>,
         langcode: &str,
         return_master: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
+        let resource_content_type = resource_content_type.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !langcode.is_empty() {
-            query_args.push(("langcode".to_string(), langcode.to_string()));
+            query_args.push(("langcode", langcode.to_string()));
         }
         if !return_master.is_empty() {
-            query_args.push(("return_master".to_string(), return_master.to_string()));
+            query_args.push(("return_master", return_master.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/brands/{}/resources/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(brand_id),
-            crate::progenitor_support::encode_path(resource_content_type),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/brands/{}/resources/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                crate::progenitor_support::encode_path(resource_content_type),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -445,10 +481,13 @@ impl AccountBrands {
     */
     pub async fn brand_resources_put(
         &self,
-        account_id: &str,
-        brand_id: &str,
-        resource_content_type: &str,
+        account_id: impl AsRef<str>,
+        brand_id: impl AsRef<str>,
+        resource_content_type: impl AsRef<str>,
     ) -> Result<crate::types::BrandResources> {
+        let account_id = account_id.as_ref();
+        let brand_id = brand_id.as_ref();
+        let resource_content_type = resource_content_type.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/resources/{}",
             crate::progenitor_support::encode_path(account_id),
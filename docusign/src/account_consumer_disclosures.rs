@@ -76,18 +76,20 @@ impl AccountConsumerDisclosures {
     */
     pub async fn consumer_disclosure_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         lang_code: &str,
     ) -> Result<crate::types::AccountConsumerDisclosures> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang_code.is_empty() {
-            query_args.push(("langCode".to_string(), lang_code.to_string()));
+            query_args.push(("langCode", lang_code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/consumer_disclosure?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/consumer_disclosure",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -157,9 +159,11 @@ impl AccountConsumerDisclosures {
     */
     pub async fn consumer_disclosure_get_lang_code(
         &self,
-        account_id: &str,
-        lang_code: &str,
+        account_id: impl AsRef<str>,
+        lang_code: impl AsRef<str>,
     ) -> Result<crate::types::AccountConsumerDisclosures> {
+        let account_id = account_id.as_ref();
+        let lang_code = lang_code.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/consumer_disclosure/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -279,21 +283,24 @@ impl AccountConsumerDisclosures {
     */
     pub async fn consumer_disclosure_put(
         &self,
-        account_id: &str,
-        lang_code: &str,
+        account_id: impl AsRef<str>,
+        lang_code: impl AsRef<str>,
         include_metadata: &str,
         body: &crate::types::ConsumerDisclosure,
     ) -> Result<crate::types::ConsumerDisclosure> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let lang_code = lang_code.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/consumer_disclosure/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(lang_code),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/consumer_disclosure/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(lang_code),
+            ),
+            &query_args,
         );
 
         self.client
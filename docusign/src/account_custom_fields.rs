@@ -23,7 +23,11 @@ impl AccountCustomFields {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn get(&self, account_id: &str) -> Result<crate::types::AccountCustomFields> {
+    pub async fn get(
+        &self,
+        account_id: impl AsRef<str>,
+    ) -> Result<crate::types::AccountCustomFields> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -46,22 +50,21 @@ impl AccountCustomFields {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         apply_to_templates: &str,
         body: &crate::types::CustomField,
     ) -> Result<crate::types::AccountCustomFields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !apply_to_templates.is_empty() {
-            query_args.push((
-                "apply_to_templates".to_string(),
-                apply_to_templates.to_string(),
-            ));
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/custom_fields?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -84,24 +87,24 @@ impl AccountCustomFields {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        custom_field_id: &str,
+        account_id: impl AsRef<str>,
+        custom_field_id: impl AsRef<str>,
         apply_to_templates: &str,
         body: &crate::types::CustomField,
     ) -> Result<crate::types::AccountCustomFields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let custom_field_id = custom_field_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !apply_to_templates.is_empty() {
-            query_args.push((
-                "apply_to_templates".to_string(),
-                apply_to_templates.to_string(),
-            ));
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/custom_fields/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(custom_field_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_field_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -124,23 +127,23 @@ impl AccountCustomFields {
     */
     pub async fn delete(
         &self,
-        account_id: &str,
-        custom_field_id: &str,
+        account_id: impl AsRef<str>,
+        custom_field_id: impl AsRef<str>,
         apply_to_templates: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let custom_field_id = custom_field_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !apply_to_templates.is_empty() {
-            query_args.push((
-                "apply_to_templates".to_string(),
-                apply_to_templates.to_string(),
-            ));
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/custom_fields/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(custom_field_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_field_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
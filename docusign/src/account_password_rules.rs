@@ -23,7 +23,11 @@ impl AccountPasswordRules {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn get(&self, account_id: &str) -> Result<crate::types::AccountPasswordRulesData> {
+    pub async fn get(
+        &self,
+        account_id: impl AsRef<str>,
+    ) -> Result<crate::types::AccountPasswordRulesData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/password_rules",
             crate::progenitor_support::encode_path(account_id),
@@ -47,9 +51,10 @@ impl AccountPasswordRules {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AccountPasswordRulesData,
     ) -> Result<crate::types::AccountPasswordRulesData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/password_rules",
             crate::progenitor_support::encode_path(account_id),
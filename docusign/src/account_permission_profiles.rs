@@ -34,18 +34,20 @@ impl AccountPermissionProfiles {
     */
     pub async fn permission_profiles_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         include: &str,
     ) -> Result<crate::types::PermissionProfileInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/permission_profiles",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -65,19 +67,21 @@ impl AccountPermissionProfiles {
     */
     pub async fn permission_profiles_post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         include: &str,
         body: &crate::types::PermissionProfile,
     ) -> Result<crate::types::PermissionProfile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/permission_profiles",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -106,20 +110,23 @@ impl AccountPermissionProfiles {
     */
     pub async fn permission_profiles_get_profile(
         &self,
-        account_id: &str,
-        permission_profile_id: &str,
+        account_id: impl AsRef<str>,
+        permission_profile_id: impl AsRef<str>,
         include: &str,
     ) -> Result<crate::types::PermissionProfile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let permission_profile_id = permission_profile_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(permission_profile_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -146,21 +153,24 @@ impl AccountPermissionProfiles {
     */
     pub async fn permission_profiles_put(
         &self,
-        account_id: &str,
-        permission_profile_id: &str,
+        account_id: impl AsRef<str>,
+        permission_profile_id: impl AsRef<str>,
         include: &str,
         body: &crate::types::PermissionProfile,
     ) -> Result<crate::types::PermissionProfile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let permission_profile_id = permission_profile_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(permission_profile_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -191,20 +201,23 @@ impl AccountPermissionProfiles {
     */
     pub async fn permission_profiles_delete(
         &self,
-        account_id: &str,
-        permission_profile_id: &str,
+        account_id: impl AsRef<str>,
+        permission_profile_id: impl AsRef<str>,
         move_users_to: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let permission_profile_id = permission_profile_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !move_users_to.is_empty() {
-            query_args.push(("move_users_to".to_string(), move_users_to.to_string()));
+            query_args.push(("move_users_to", move_users_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(permission_profile_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -25,8 +25,9 @@ impl AccountSealProviders {
     */
     pub async fn account_signature_providers_get_seal(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountSealProviders> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/seals",
             crate::progenitor_support::encode_path(account_id),
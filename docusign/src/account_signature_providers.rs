@@ -25,8 +25,9 @@ impl AccountSignatureProviders {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountSignatureProvidersData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signatureProviders",
             crate::progenitor_support::encode_path(account_id),
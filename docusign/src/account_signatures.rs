@@ -28,26 +28,28 @@ impl AccountSignatures {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         stamp_format: &str,
         stamp_name: &str,
         stamp_type: &str,
     ) -> Result<crate::types::AccountSignaturesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !stamp_format.is_empty() {
-            query_args.push(("stamp_format".to_string(), stamp_format.to_string()));
+            query_args.push(("stamp_format", stamp_format.to_string()));
         }
         if !stamp_name.is_empty() {
-            query_args.push(("stamp_name".to_string(), stamp_name.to_string()));
+            query_args.push(("stamp_name", stamp_name.to_string()));
         }
         if !stamp_type.is_empty() {
-            query_args.push(("stamp_type".to_string(), stamp_type.to_string()));
+            query_args.push(("stamp_type", stamp_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/signatures",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -67,9 +69,10 @@ impl AccountSignatures {
     */
     pub async fn put_signature(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AccountSignaturesInformation,
     ) -> Result<crate::types::AccountSignaturesInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signatures",
             crate::progenitor_support::encode_path(account_id),
@@ -95,19 +98,21 @@ impl AccountSignatures {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         decode_only: &str,
         body: &crate::types::AccountSignaturesInformation,
     ) -> Result<crate::types::AccountSignaturesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !decode_only.is_empty() {
-            query_args.push(("decode_only".to_string(), decode_only.to_string()));
+            query_args.push(("decode_only", decode_only.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/signatures",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -129,9 +134,11 @@ impl AccountSignatures {
     */
     pub async fn get_signature(
         &self,
-        account_id: &str,
-        signature_id: &str,
+        account_id: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountSignature> {
+        let account_id = account_id.as_ref();
+        let signature_id = signature_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signatures/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -156,24 +163,27 @@ impl AccountSignatures {
     */
     pub async fn put_signature_account_signatures(
         &self,
-        account_id: &str,
-        signature_id: &str,
+        account_id: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
         close_existing_signature: &str,
         body: &crate::types::AccountSignatureDefinition,
     ) -> Result<crate::types::AccountSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let signature_id = signature_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !close_existing_signature.is_empty() {
             query_args.push((
-                "close_existing_signature".to_string(),
+                "close_existing_signature",
                 close_existing_signature.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(signature_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -193,7 +203,13 @@ impl AccountSignatures {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn delete_signature(&self, account_id: &str, signature_id: &str) -> Result<()> {
+    pub async fn delete_signature(
+        &self,
+        account_id: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let signature_id = signature_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signatures/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -222,22 +238,26 @@ impl AccountSignatures {
     */
     pub async fn get_signature_image(
         &self,
-        account_id: &str,
-        image_type: &str,
-        signature_id: &str,
+        account_id: impl AsRef<str>,
+        image_type: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let image_type = image_type.as_ref();
+        let signature_id = signature_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(signature_id),
-            crate::progenitor_support::encode_path(image_type),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -262,22 +282,26 @@ impl AccountSignatures {
     */
     pub async fn put_signature_image(
         &self,
-        account_id: &str,
-        image_type: &str,
-        signature_id: &str,
+        account_id: impl AsRef<str>,
+        image_type: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
         transparent_png: &str,
     ) -> Result<crate::types::AccountSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let image_type = image_type.as_ref();
+        let signature_id = signature_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !transparent_png.is_empty() {
-            query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
+            query_args.push(("transparent_png", transparent_png.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(signature_id),
-            crate::progenitor_support::encode_path(image_type),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -301,10 +325,13 @@ impl AccountSignatures {
     */
     pub async fn delete_signature_image(
         &self,
-        account_id: &str,
-        image_type: &str,
-        signature_id: &str,
+        account_id: impl AsRef<str>,
+        image_type: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountSignature> {
+        let account_id = account_id.as_ref();
+        let image_type = image_type.as_ref();
+        let signature_id = signature_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signatures/{}/{}",
             crate::progenitor_support::encode_path(account_id),
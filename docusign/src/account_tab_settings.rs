@@ -25,8 +25,9 @@ impl AccountTabSettings {
     */
     pub async fn tab_settings_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::TabAccountSettings> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -48,9 +49,10 @@ impl AccountTabSettings {
     */
     pub async fn tab_settings_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::TabAccountSettings,
     ) -> Result<crate::types::TabAccountSettings> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/tabs",
             crate::progenitor_support::encode_path(account_id),
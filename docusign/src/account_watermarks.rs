@@ -23,7 +23,11 @@ impl AccountWatermarks {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn watermark_get(&self, account_id: &str) -> Result<crate::types::Watermark> {
+    pub async fn watermark_get(
+        &self,
+        account_id: impl AsRef<str>,
+    ) -> Result<crate::types::Watermark> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/watermark",
             crate::progenitor_support::encode_path(account_id),
@@ -45,9 +49,10 @@ impl AccountWatermarks {
     */
     pub async fn watermark_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::Watermark,
     ) -> Result<crate::types::Watermark> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/watermark",
             crate::progenitor_support::encode_path(account_id),
@@ -71,9 +76,10 @@ impl AccountWatermarks {
     */
     pub async fn watermark_preview_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::Watermark,
     ) -> Result<crate::types::Watermark> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/watermark/preview",
             crate::progenitor_support::encode_path(account_id),
@@ -183,21 +183,23 @@ impl Accounts {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         include_account_settings: &str,
     ) -> Result<crate::types::AccountInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_account_settings.is_empty() {
             query_args.push((
-                "include_account_settings".to_string(),
+                "include_account_settings",
                 include_account_settings.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -214,7 +216,8 @@ impl Accounts {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn delete(&self, account_id: &str) -> Result<()> {
+    pub async fn delete(&self, account_id: impl AsRef<str>) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -244,18 +247,20 @@ impl Accounts {
     */
     pub async fn billing_charges_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         include_charges: &str,
     ) -> Result<crate::types::BillingChargeResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_charges.is_empty() {
-            query_args.push(("include_charges".to_string(), include_charges.to_string()));
+            query_args.push(("include_charges", include_charges.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_charges?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/billing_charges",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -275,10 +280,12 @@ impl Accounts {
     */
     pub async fn captive_recipients_delete_part(
         &self,
-        account_id: &str,
-        recipient_part: &str,
+        account_id: impl AsRef<str>,
+        recipient_part: impl AsRef<str>,
         body: &crate::types::CaptiveRecipientInformation,
     ) -> Result<crate::types::CaptiveRecipientInformation> {
+        let account_id = account_id.as_ref();
+        let recipient_part = recipient_part.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/captive_recipients/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -304,18 +311,20 @@ impl Accounts {
     */
     pub async fn recipient_names_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         email: &str,
     ) -> Result<crate::types::RecipientNamesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/recipient_names?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/recipient_names",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -334,8 +343,9 @@ impl Accounts {
     */
     pub async fn settings_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountSettingsInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings",
             crate::progenitor_support::encode_path(account_id),
@@ -357,9 +367,10 @@ impl Accounts {
     */
     pub async fn settings_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AccountSettingsInformation,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings",
             crate::progenitor_support::encode_path(account_id),
@@ -385,8 +396,9 @@ impl Accounts {
     */
     pub async fn envelope_purge_configuration_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/envelope_purge_configuration",
             crate::progenitor_support::encode_path(account_id),
@@ -412,9 +424,10 @@ impl Accounts {
     */
     pub async fn envelope_purge_configuration_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::EnvelopePurgeConfiguration,
     ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/envelope_purge_configuration",
             crate::progenitor_support::encode_path(account_id),
@@ -438,8 +451,9 @@ impl Accounts {
     */
     pub async fn notification_defaults_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::NotificationDefaultsData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/notification_defaults",
             crate::progenitor_support::encode_path(account_id),
@@ -461,9 +475,10 @@ impl Accounts {
     */
     pub async fn notification_defaults_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::NotificationDefaultsData,
     ) -> Result<crate::types::NotificationDefaultsData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/notification_defaults",
             crate::progenitor_support::encode_path(account_id),
@@ -517,7 +532,7 @@ impl Accounts {
     */
     pub async fn shared_access_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         count: &str,
         envelopes_not_shared_user_status: &str,
         folder_ids: &str,
@@ -527,39 +542,41 @@ impl Accounts {
         start_position: &str,
         user_ids: &str,
     ) -> Result<crate::types::AccountSharedAccess> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !envelopes_not_shared_user_status.is_empty() {
             query_args.push((
-                "envelopes_not_shared_user_status".to_string(),
+                "envelopes_not_shared_user_status",
                 envelopes_not_shared_user_status.to_string(),
             ));
         }
         if !folder_ids.is_empty() {
-            query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
+            query_args.push(("folder_ids", folder_ids.to_string()));
         }
         if !item_type.is_empty() {
-            query_args.push(("item_type".to_string(), item_type.to_string()));
+            query_args.push(("item_type", item_type.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !shared.is_empty() {
-            query_args.push(("shared".to_string(), shared.to_string()));
+            query_args.push(("shared", shared.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !user_ids.is_empty() {
-            query_args.push(("user_ids".to_string(), user_ids.to_string()));
+            query_args.push(("user_ids", user_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/shared_access?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/shared_access",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -597,30 +614,32 @@ impl Accounts {
     */
     pub async fn shared_access_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         item_type: &str,
         preserve_existing_shared_access: &str,
         user_ids: &str,
         body: &crate::types::AccountSharedAccess,
     ) -> Result<crate::types::AccountSharedAccess> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !item_type.is_empty() {
-            query_args.push(("item_type".to_string(), item_type.to_string()));
+            query_args.push(("item_type", item_type.to_string()));
         }
         if !preserve_existing_shared_access.is_empty() {
             query_args.push((
-                "preserve_existing_shared_access".to_string(),
+                "preserve_existing_shared_access",
                 preserve_existing_shared_access.to_string(),
             ));
         }
         if !user_ids.is_empty() {
-            query_args.push(("user_ids".to_string(), user_ids.to_string()));
+            query_args.push(("user_ids", user_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/shared_access?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/shared_access",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -647,8 +666,9 @@ impl Accounts {
     */
     pub async fn supported_languages_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::SupportedLanguages> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/supported_languages",
             crate::progenitor_support::encode_path(account_id),
@@ -670,8 +690,9 @@ impl Accounts {
     */
     pub async fn unsupported_file_types_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::FileTypeList> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/unsupported_file_types",
             crate::progenitor_support::encode_path(account_id),
@@ -694,9 +715,11 @@ impl Accounts {
     */
     pub async fn organization_exports_get_settings_export(
         &self,
-        organization_id: &str,
-        result_id: &str,
+        organization_id: impl AsRef<str>,
+        result_id: impl AsRef<str>,
     ) -> Result<()> {
+        let organization_id = organization_id.as_ref();
+        let result_id = result_id.as_ref();
         let url = format!(
             "/v2.1/organization_exports/{}/account_settings/{}",
             crate::progenitor_support::encode_path(organization_id),
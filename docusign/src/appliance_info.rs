@@ -25,8 +25,9 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_dynamic_system_setting(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/display_appliance_info/dynamicsystemsettings",
             crate::progenitor_support::encode_path(account_id),
@@ -48,8 +49,9 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_template(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/display_appliance_info/templateInfo",
             crate::progenitor_support::encode_path(account_id),
@@ -72,9 +74,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info",
             crate::progenitor_support::encode_path(account_id),
@@ -98,9 +102,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_account(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::DisplayApplianceAccount> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/account_info",
             crate::progenitor_support::encode_path(account_id),
@@ -124,9 +130,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_custom_field(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -150,9 +158,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_delete_custom_fields(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields/delete",
             crate::progenitor_support::encode_path(account_id),
@@ -176,9 +186,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_date_signed(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/date_signed",
             crate::progenitor_support::encode_path(account_id),
@@ -203,10 +215,13 @@ impl ApplianceInfo {
     */
     pub async fn envelope_put_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -232,10 +247,13 @@ impl ApplianceInfo {
     */
     pub async fn envelope_delete_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -260,9 +278,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_document_page(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document_page_list",
             crate::progenitor_support::encode_path(account_id),
@@ -286,9 +306,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_image(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/image",
             crate::progenitor_support::encode_path(account_id),
@@ -314,10 +336,13 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_locale_policy(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/localepolicy/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -340,7 +365,13 @@ impl ApplianceInfo {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+    pub async fn envelope_put_page(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
             crate::progenitor_support::encode_path(account_id),
@@ -362,7 +393,13 @@ impl ApplianceInfo {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn envelope_post_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+    pub async fn envelope_post_page(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
             crate::progenitor_support::encode_path(account_id),
@@ -384,7 +421,13 @@ impl ApplianceInfo {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn envelope_delete_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+    pub async fn envelope_delete_page(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info/delete",
             crate::progenitor_support::encode_path(account_id),
@@ -408,9 +451,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_put_pdf(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf",
             crate::progenitor_support::encode_path(account_id),
@@ -437,10 +482,13 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_pdf(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        pdf_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        pdf_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let pdf_id = pdf_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -465,9 +513,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_pdf_blob(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
             crate::progenitor_support::encode_path(account_id),
@@ -489,7 +539,13 @@ impl ApplianceInfo {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn envelope_put_pdf_blob(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+    pub async fn envelope_put_pdf_blob(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
             crate::progenitor_support::encode_path(account_id),
@@ -513,9 +569,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_post_pdf_blob(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
             crate::progenitor_support::encode_path(account_id),
@@ -539,9 +597,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_put_recipient_denied_document_copy(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
             crate::progenitor_support::encode_path(account_id),
@@ -565,9 +625,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_delete_recipient_denied_document_copy(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
             crate::progenitor_support::encode_path(account_id),
@@ -591,9 +653,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_get_signer_attachment(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ApplianceInfo> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
             crate::progenitor_support::encode_path(account_id),
@@ -617,9 +681,11 @@ impl ApplianceInfo {
     */
     pub async fn envelope_delete_signer_attachment(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
             crate::progenitor_support::encode_path(account_id),
@@ -27,22 +27,24 @@ impl BccEmailArchive {
     */
     pub async fn get_list(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::BccEmailArchiveList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/settings/bcc_email_archives?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -66,9 +68,10 @@ impl BccEmailArchive {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::BccEmailArchiveData,
     ) -> Result<crate::types::BccEmailArchiveData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/bcc_email_archives",
             crate::progenitor_support::encode_path(account_id),
@@ -95,24 +98,27 @@ impl BccEmailArchive {
     */
     pub async fn get_history_list(
         &self,
-        account_id: &str,
-        bcc_email_archive_id: &str,
+        account_id: impl AsRef<str>,
+        bcc_email_archive_id: impl AsRef<str>,
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::BccEmailArchiveHistoryList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let bcc_email_archive_id = bcc_email_archive_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/settings/bcc_email_archives/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(bcc_email_archive_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bcc_email_archive_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -133,7 +139,13 @@ impl BccEmailArchive {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `bcc_email_archive_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        account_id: impl AsRef<str>,
+        bcc_email_archive_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let bcc_email_archive_id = bcc_email_archive_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -37,32 +37,34 @@ impl BillingPlans {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         include_credit_card_information: &str,
         include_metadata: &str,
         include_successor_plans: &str,
     ) -> Result<crate::types::AccountBillingPlanResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_credit_card_information.is_empty() {
             query_args.push((
-                "include_credit_card_information".to_string(),
+                "include_credit_card_information",
                 include_credit_card_information.to_string(),
             ));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !include_successor_plans.is_empty() {
             query_args.push((
-                "include_successor_plans".to_string(),
+                "include_successor_plans",
                 include_successor_plans.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_plan?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/billing_plan",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -82,22 +84,21 @@ impl BillingPlans {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         preview_billing_plan: &str,
         body: &crate::types::BillingPlanInformation,
     ) -> Result<crate::types::BillingPlanUpdateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !preview_billing_plan.is_empty() {
-            query_args.push((
-                "preview_billing_plan".to_string(),
-                preview_billing_plan.to_string(),
-            ));
+            query_args.push(("preview_billing_plan", preview_billing_plan.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_plan?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/billing_plan",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -118,8 +119,9 @@ impl BillingPlans {
     */
     pub async fn get_credit_card_info(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::CreditCardInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/credit_card",
             crate::progenitor_support::encode_path(account_id),
@@ -141,8 +143,9 @@ impl BillingPlans {
     */
     pub async fn get_downgrade_request_info(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::DowngradRequestBillingInfoResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/downgrade",
             crate::progenitor_support::encode_path(account_id),
@@ -164,9 +167,10 @@ impl BillingPlans {
     */
     pub async fn put_downgrade_account(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::DowngradeBillingPlanInformation,
     ) -> Result<crate::types::DowngradePlanUpdateResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/downgrade",
             crate::progenitor_support::encode_path(account_id),
@@ -190,9 +194,10 @@ impl BillingPlans {
     */
     pub async fn purchased_envelopes_put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::PurchasedEnvelopesInformation,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/purchased_envelopes",
             crate::progenitor_support::encode_path(account_id),
@@ -228,8 +233,9 @@ impl BillingPlans {
     */
     pub async fn get_plan(
         &self,
-        billing_plan_id: &str,
+        billing_plan_id: impl AsRef<str>,
     ) -> Result<crate::types::BillingPlanResponse> {
+        let billing_plan_id = billing_plan_id.as_ref();
         let url = format!(
             "/v2.1/billing_plans/{}",
             crate::progenitor_support::encode_path(billing_plan_id),
@@ -38,30 +38,32 @@ impl BulkSend {
     */
     pub async fn batch_get_batche(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         batch_ids: &str,
         count: &str,
         start_position: &str,
         status: &str,
     ) -> Result<crate::types::BulkSendBatchSummaries> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !batch_ids.is_empty() {
-            query_args.push(("batch_ids".to_string(), batch_ids.to_string()));
+            query_args.push(("batch_ids", batch_ids.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/bulk_send_batch?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/bulk_send_batch",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -87,9 +89,11 @@ impl BulkSend {
     */
     pub async fn batch_get_statu(
         &self,
-        account_id: &str,
-        bulk_send_batch_id: &str,
+        account_id: impl AsRef<str>,
+        bulk_send_batch_id: impl AsRef<str>,
     ) -> Result<crate::types::BulkSendBatchStatus> {
+        let account_id = account_id.as_ref();
+        let bulk_send_batch_id = bulk_send_batch_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_batch/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -113,10 +117,12 @@ impl BulkSend {
     */
     pub async fn batch_put_status(
         &self,
-        account_id: &str,
-        bulk_send_batch_id: &str,
+        account_id: impl AsRef<str>,
+        bulk_send_batch_id: impl AsRef<str>,
         body: &crate::types::BulkSendBatchRequest,
     ) -> Result<crate::types::BulkSendBatchStatus> {
+        let account_id = account_id.as_ref();
+        let bulk_send_batch_id = bulk_send_batch_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_batch/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -141,8 +147,9 @@ impl BulkSend {
     */
     pub async fn v_2crud_get_list(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::BulkSendingListSummaries> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists",
             crate::progenitor_support::encode_path(account_id),
@@ -190,9 +197,10 @@ impl BulkSend {
     */
     pub async fn v_2crud_post_list(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::BulkSendingList,
     ) -> Result<crate::types::BulkSendingList> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists",
             crate::progenitor_support::encode_path(account_id),
@@ -217,9 +225,11 @@ impl BulkSend {
     */
     pub async fn v_2crud_get_list_bulk_send(
         &self,
-        account_id: &str,
-        bulk_send_list_id: &str,
+        account_id: impl AsRef<str>,
+        bulk_send_list_id: impl AsRef<str>,
     ) -> Result<crate::types::BulkSendingList> {
+        let account_id = account_id.as_ref();
+        let bulk_send_list_id = bulk_send_list_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -243,10 +253,12 @@ impl BulkSend {
     */
     pub async fn v_2crud_put_list(
         &self,
-        account_id: &str,
-        bulk_send_list_id: &str,
+        account_id: impl AsRef<str>,
+        bulk_send_list_id: impl AsRef<str>,
         body: &crate::types::BulkSendingList,
     ) -> Result<crate::types::BulkSendingList> {
+        let account_id = account_id.as_ref();
+        let bulk_send_list_id = bulk_send_list_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -272,9 +284,11 @@ impl BulkSend {
     */
     pub async fn v_2crud_delete_list(
         &self,
-        account_id: &str,
-        bulk_send_list_id: &str,
+        account_id: impl AsRef<str>,
+        bulk_send_list_id: impl AsRef<str>,
     ) -> Result<crate::types::BulkSendingListSummaries> {
+        let account_id = account_id.as_ref();
+        let bulk_send_list_id = bulk_send_list_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -335,10 +349,12 @@ impl BulkSend {
     */
     pub async fn post_request(
         &self,
-        account_id: &str,
-        bulk_send_list_id: &str,
+        account_id: impl AsRef<str>,
+        bulk_send_list_id: impl AsRef<str>,
         body: &crate::types::BulkSendRequest,
     ) -> Result<crate::types::BulkSendResponse> {
+        let account_id = account_id.as_ref();
+        let bulk_send_list_id = bulk_send_list_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}/send",
             crate::progenitor_support::encode_path(account_id),
@@ -401,10 +417,12 @@ impl BulkSend {
     */
     pub async fn test_post_request(
         &self,
-        account_id: &str,
-        bulk_send_list_id: &str,
+        account_id: impl AsRef<str>,
+        bulk_send_list_id: impl AsRef<str>,
         body: &crate::types::BulkSendRequest,
     ) -> Result<crate::types::BulkSendTestResponse> {
+        let account_id = account_id.as_ref();
+        let bulk_send_list_id = bulk_send_list_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}/test",
             crate::progenitor_support::encode_path(account_id),
@@ -25,9 +25,10 @@ impl ChunkedUploads {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ChunkedUploadRequest,
     ) -> Result<crate::types::ChunkedUploadResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/chunked_uploads",
             crate::progenitor_support::encode_path(account_id),
@@ -55,20 +56,23 @@ impl ChunkedUploads {
     */
     pub async fn get_upload(
         &self,
-        account_id: &str,
-        chunked_upload_id: &str,
+        account_id: impl AsRef<str>,
+        chunked_upload_id: impl AsRef<str>,
         include: &str,
     ) -> Result<crate::types::ChunkedUploadResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let chunked_upload_id = chunked_upload_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/chunked_uploads/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(chunked_upload_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -93,20 +97,23 @@ impl ChunkedUploads {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        chunked_upload_id: &str,
+        account_id: impl AsRef<str>,
+        chunked_upload_id: impl AsRef<str>,
         action: &str,
     ) -> Result<crate::types::ChunkedUploadResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let chunked_upload_id = chunked_upload_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/chunked_uploads/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(chunked_upload_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -134,9 +141,11 @@ impl ChunkedUploads {
     */
     pub async fn delete_upload(
         &self,
-        account_id: &str,
-        chunked_upload_id: &str,
+        account_id: impl AsRef<str>,
+        chunked_upload_id: impl AsRef<str>,
     ) -> Result<crate::types::ChunkedUploadResponse> {
+        let account_id = account_id.as_ref();
+        let chunked_upload_id = chunked_upload_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/chunked_uploads/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -177,11 +186,14 @@ impl ChunkedUploads {
     */
     pub async fn put_upload_part(
         &self,
-        account_id: &str,
-        chunked_upload_id: &str,
-        chunked_upload_part_seq: &str,
+        account_id: impl AsRef<str>,
+        chunked_upload_id: impl AsRef<str>,
+        chunked_upload_part_seq: impl AsRef<str>,
         body: &crate::types::ChunkedUploadRequest,
     ) -> Result<crate::types::ChunkedUploadResponse> {
+        let account_id = account_id.as_ref();
+        let chunked_upload_id = chunked_upload_id.as_ref();
+        let chunked_upload_part_seq = chunked_upload_part_seq.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/chunked_uploads/{}/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -49,9 +49,9 @@ impl CloudStorage {
     */
     pub async fn folder_get_all(
         &self,
-        account_id: &str,
-        service_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        service_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         cloud_storage_folder_path: &str,
         count: &str,
         order: &str,
@@ -59,35 +59,39 @@ impl CloudStorage {
         search_text: &str,
         start_position: &str,
     ) -> Result<crate::types::ExternalFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let service_id = service_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cloud_storage_folder_path.is_empty() {
             query_args.push((
-                "cloud_storage_folder_path".to_string(),
+                "cloud_storage_folder_path",
                 cloud_storage_folder_path.to_string(),
             ));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(service_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -134,10 +138,10 @@ impl CloudStorage {
     */
     pub async fn folder_get(
         &self,
-        account_id: &str,
-        folder_id: &str,
-        service_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+        service_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         cloud_storage_folder_path: &str,
         cloud_storage_folderid_plain: &str,
         count: &str,
@@ -146,42 +150,47 @@ impl CloudStorage {
         search_text: &str,
         start_position: &str,
     ) -> Result<crate::types::ExternalFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let service_id = service_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cloud_storage_folder_path.is_empty() {
             query_args.push((
-                "cloud_storage_folder_path".to_string(),
+                "cloud_storage_folder_path",
                 cloud_storage_folder_path.to_string(),
             ));
         }
         if !cloud_storage_folderid_plain.is_empty() {
             query_args.push((
-                "cloud_storage_folderid_plain".to_string(),
+                "cloud_storage_folderid_plain",
                 cloud_storage_folderid_plain.to_string(),
             ));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(service_id),
-            crate::progenitor_support::encode_path(folder_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id),
+                crate::progenitor_support::encode_path(folder_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
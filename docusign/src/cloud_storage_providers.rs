@@ -32,20 +32,23 @@ impl CloudStorageProviders {
     */
     pub async fn cloud_storage_get_provider(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         redirect_url: &str,
     ) -> Result<crate::types::CloudStorageProvidersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !redirect_url.is_empty() {
-            query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
+            query_args.push(("redirectUrl", redirect_url.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -66,10 +69,12 @@ impl CloudStorageProviders {
     */
     pub async fn cloud_storage_post(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::CloudStorageProvidersData,
     ) -> Result<crate::types::CloudStorageProvidersData> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/cloud_storage",
             crate::progenitor_support::encode_path(account_id),
@@ -96,10 +101,12 @@ impl CloudStorageProviders {
     */
     pub async fn cloud_storage_delete_providers(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::CloudStorageProvidersData,
     ) -> Result<crate::types::CloudStorageProvidersData> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/cloud_storage",
             crate::progenitor_support::encode_path(account_id),
@@ -133,22 +140,26 @@ impl CloudStorageProviders {
     */
     pub async fn cloud_storage_get(
         &self,
-        account_id: &str,
-        service_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        service_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         redirect_url: &str,
     ) -> Result<crate::types::CloudStorageProvidersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let service_id = service_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !redirect_url.is_empty() {
-            query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
+            query_args.push(("redirectUrl", redirect_url.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(service_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -172,10 +183,13 @@ impl CloudStorageProviders {
     */
     pub async fn cloud_storage_delete(
         &self,
-        account_id: &str,
-        service_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        service_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::CloudStorageProvidersData> {
+        let account_id = account_id.as_ref();
+        let service_id = service_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
             crate::progenitor_support::encode_path(account_id),
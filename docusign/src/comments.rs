@@ -29,20 +29,23 @@ impl Comments {
     */
     pub async fn get_transcript(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         encoding: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/comments/transcript?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/comments/transcript",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
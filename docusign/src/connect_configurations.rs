@@ -27,8 +27,9 @@ impl ConnectConfigurations {
     */
     pub async fn connect_get_config(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::ConnectConfigResults> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect",
             crate::progenitor_support::encode_path(account_id),
@@ -52,9 +53,10 @@ impl ConnectConfigurations {
     */
     pub async fn connect_put_configuration(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ConnectCustomConfiguration,
     ) -> Result<crate::types::ConnectCustomConfiguration> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect",
             crate::progenitor_support::encode_path(account_id),
@@ -81,9 +83,10 @@ impl ConnectConfigurations {
     */
     pub async fn connect_post_configuration(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ConnectCustomConfiguration,
     ) -> Result<crate::types::ConnectCustomConfiguration> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect",
             crate::progenitor_support::encode_path(account_id),
@@ -111,9 +114,11 @@ impl ConnectConfigurations {
     */
     pub async fn connect_get_config_connect_configurations(
         &self,
-        account_id: &str,
-        connect_id: &str,
+        account_id: impl AsRef<str>,
+        connect_id: impl AsRef<str>,
     ) -> Result<crate::types::ConnectConfigResults> {
+        let account_id = account_id.as_ref();
+        let connect_id = connect_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -140,7 +145,13 @@ impl ConnectConfigurations {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `connect_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn connect_delete_config(&self, account_id: &str, connect_id: &str) -> Result<()> {
+    pub async fn connect_delete_config(
+        &self,
+        account_id: impl AsRef<str>,
+        connect_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let connect_id = connect_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -181,8 +192,8 @@ impl ConnectConfigurations {
     */
     pub async fn connect_get_user(
         &self,
-        account_id: &str,
-        connect_id: &str,
+        account_id: impl AsRef<str>,
+        connect_id: impl AsRef<str>,
         count: &str,
         email_substring: &str,
         list_included_users: &str,
@@ -190,37 +201,34 @@ impl ConnectConfigurations {
         status: &str,
         user_name_substring: &str,
     ) -> Result<crate::types::IntegratedUserInfoList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let connect_id = connect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email_substring.is_empty() {
-            query_args.push(("email_substring".to_string(), email_substring.to_string()));
+            query_args.push(("email_substring", email_substring.to_string()));
         }
         if !list_included_users.is_empty() {
-            query_args.push((
-                "list_included_users".to_string(),
-                list_included_users.to_string(),
-            ));
+            query_args.push(("list_included_users", list_included_users.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !user_name_substring.is_empty() {
-            query_args.push((
-                "user_name_substring".to_string(),
-                user_name_substring.to_string(),
-            ));
+            query_args.push(("user_name_substring", user_name_substring.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/{}/users?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(connect_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/connect/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(connect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -26,9 +26,10 @@ impl ConnectEvents {
     */
     pub async fn connect_publish_put_retry(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ConnectFailureFilter,
     ) -> Result<crate::types::ConnectFailureResults> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/envelopes/retry_queue",
             crate::progenitor_support::encode_path(account_id),
@@ -53,9 +54,11 @@ impl ConnectEvents {
     */
     pub async fn connect_publish_put_retry_envelope(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::ConnectFailureResults> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/envelopes/{}/retry_queue",
             crate::progenitor_support::encode_path(account_id),
@@ -84,22 +87,24 @@ impl ConnectEvents {
     */
     pub async fn connect_failures_get_log(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::ConnectLogs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/failures?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/connect/failures",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -119,9 +124,11 @@ impl ConnectEvents {
     */
     pub async fn connect_failures_delete_failure_log(
         &self,
-        account_id: &str,
-        failure_id: &str,
+        account_id: impl AsRef<str>,
+        failure_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let failure_id = failure_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/failures/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -152,22 +159,24 @@ impl ConnectEvents {
     */
     pub async fn connect_log_get_log(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::ConnectLogs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/logs?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/connect/logs",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -184,7 +193,8 @@ impl ConnectEvents {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn connect_log_delete_logs(&self, account_id: &str) -> Result<()> {
+    pub async fn connect_log_delete_logs(&self, account_id: impl AsRef<str>) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/logs",
             crate::progenitor_support::encode_path(account_id),
@@ -210,20 +220,23 @@ impl ConnectEvents {
     */
     pub async fn connect_log_get(
         &self,
-        account_id: &str,
-        log_id: &str,
+        account_id: impl AsRef<str>,
+        log_id: impl AsRef<str>,
         additional_info: &str,
     ) -> Result<crate::types::ConnectLog> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let log_id = log_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !additional_info.is_empty() {
-            query_args.push(("additional_info".to_string(), additional_info.to_string()));
+            query_args.push(("additional_info", additional_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/logs/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(log_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/connect/logs/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(log_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -242,7 +255,13 @@ impl ConnectEvents {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `log_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn connect_log_delete(&self, account_id: &str, log_id: &str) -> Result<()> {
+    pub async fn connect_log_delete(
+        &self,
+        account_id: impl AsRef<str>,
+        log_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let log_id = log_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/logs/{}",
             crate::progenitor_support::encode_path(account_id),
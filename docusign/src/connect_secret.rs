@@ -24,7 +24,13 @@ impl ConnectSecret {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `key_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn connect_hmac_delete_secret(&self, account_id: &str, key_id: &str) -> Result<()> {
+    pub async fn connect_hmac_delete_secret(
+        &self,
+        account_id: impl AsRef<str>,
+        key_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let key_id = key_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/connect/secret/{}",
             crate::progenitor_support::encode_path(account_id),
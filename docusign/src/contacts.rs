@@ -25,9 +25,10 @@ impl Contacts {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ContactModRequest,
     ) -> Result<crate::types::ContactUpdateResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/contacts",
             crate::progenitor_support::encode_path(account_id),
@@ -53,9 +54,10 @@ impl Contacts {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ContactModRequest,
     ) -> Result<crate::types::ContactUpdateResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/contacts",
             crate::progenitor_support::encode_path(account_id),
@@ -79,9 +81,10 @@ impl Contacts {
     */
     pub async fn delete(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ContactModRequest,
     ) -> Result<crate::types::ContactUpdateResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/contacts",
             crate::progenitor_support::encode_path(account_id),
@@ -121,20 +124,23 @@ impl Contacts {
     */
     pub async fn get(
         &self,
-        account_id: &str,
-        contact_id: &str,
+        account_id: impl AsRef<str>,
+        contact_id: impl AsRef<str>,
         cloud_provider: &str,
     ) -> Result<crate::types::ContactGetResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let contact_id = contact_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cloud_provider.is_empty() {
-            query_args.push(("cloud_provider".to_string(), cloud_provider.to_string()));
+            query_args.push(("cloud_provider", cloud_provider.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/contacts/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(contact_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/contacts/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(contact_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -154,9 +160,11 @@ impl Contacts {
     */
     pub async fn delete_contacts(
         &self,
-        account_id: &str,
-        contact_id: &str,
+        account_id: impl AsRef<str>,
+        contact_id: impl AsRef<str>,
     ) -> Result<crate::types::ContactUpdateResponse> {
+        let account_id = account_id.as_ref();
+        let contact_id = contact_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/contacts/{}",
             crate::progenitor_support::encode_path(account_id),
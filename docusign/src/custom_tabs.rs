@@ -26,18 +26,20 @@ impl CustomTabs {
     */
     pub async fn tabs_get_tab_definition(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         custom_tab_only: &str,
     ) -> Result<crate::types::TabMetadataList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_tab_only.is_empty() {
-            query_args.push(("custom_tab_only".to_string(), custom_tab_only.to_string()));
+            query_args.push(("custom_tab_only", custom_tab_only.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/tab_definitions?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/tab_definitions",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -58,9 +60,10 @@ impl CustomTabs {
     */
     pub async fn tabs_post_tab_definitions(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::TabMetadata,
     ) -> Result<crate::types::TabMetadata> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions",
             crate::progenitor_support::encode_path(account_id),
@@ -85,9 +88,11 @@ impl CustomTabs {
     */
     pub async fn tab_get_custom(
         &self,
-        account_id: &str,
-        custom_tab_id: &str,
+        account_id: impl AsRef<str>,
+        custom_tab_id: impl AsRef<str>,
     ) -> Result<crate::types::TabMetadata> {
+        let account_id = account_id.as_ref();
+        let custom_tab_id = custom_tab_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -113,10 +118,12 @@ impl CustomTabs {
     */
     pub async fn tab_put_custom(
         &self,
-        account_id: &str,
-        custom_tab_id: &str,
+        account_id: impl AsRef<str>,
+        custom_tab_id: impl AsRef<str>,
         body: &crate::types::TabMetadata,
     ) -> Result<crate::types::TabMetadata> {
+        let account_id = account_id.as_ref();
+        let custom_tab_id = custom_tab_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -140,7 +147,13 @@ impl CustomTabs {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `custom_tab_id: &str` -- The DocuSign-generated custom tab id for the custom tab to be applied. This can only be used when adding new tabs for a recipient. When used, the new tab inherits all the custom tab properties.
     */
-    pub async fn tab_delete_custom(&self, account_id: &str, custom_tab_id: &str) -> Result<()> {
+    pub async fn tab_delete_custom(
+        &self,
+        account_id: impl AsRef<str>,
+        custom_tab_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let custom_tab_id = custom_tab_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions/{}",
             crate::progenitor_support::encode_path(account_id),
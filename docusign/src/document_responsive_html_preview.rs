@@ -33,11 +33,14 @@ impl DocumentResponsiveHtmlPreview {
     */
     pub async fn responsive_html_post_document_preview(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::DocumentHtmlDefinition,
     ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/responsive_html_preview",
             crate::progenitor_support::encode_path(account_id),
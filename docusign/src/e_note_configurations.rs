@@ -23,7 +23,11 @@ impl ENoteConfigurations {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn get(&self, account_id: &str) -> Result<crate::types::ENoteConfiguration> {
+    pub async fn get(
+        &self,
+        account_id: impl AsRef<str>,
+    ) -> Result<crate::types::ENoteConfiguration> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/enote_configuration",
             crate::progenitor_support::encode_path(account_id),
@@ -45,9 +49,10 @@ impl ENoteConfigurations {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ENoteConfiguration,
     ) -> Result<crate::types::ENoteConfiguration> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/enote_configuration",
             crate::progenitor_support::encode_path(account_id),
@@ -69,7 +74,8 @@ impl ENoteConfigurations {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn delete(&self, account_id: &str) -> Result<()> {
+    pub async fn delete(&self, account_id: impl AsRef<str>) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/settings/enote_configuration",
             crate::progenitor_support::encode_path(account_id),
@@ -26,9 +26,11 @@ impl EnvelopeAttachments {
     */
     pub async fn attachments_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments",
             crate::progenitor_support::encode_path(account_id),
@@ -52,10 +54,12 @@ impl EnvelopeAttachments {
     */
     pub async fn attachments_put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeAttachmentsRequest,
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments",
             crate::progenitor_support::encode_path(account_id),
@@ -81,10 +85,12 @@ impl EnvelopeAttachments {
     */
     pub async fn attachments_delete(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeAttachmentsRequest,
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments",
             crate::progenitor_support::encode_path(account_id),
@@ -111,10 +117,13 @@ impl EnvelopeAttachments {
     */
     pub async fn attachments_get_attachment(
         &self,
-        account_id: &str,
-        attachment_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        attachment_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let attachment_id = attachment_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -140,11 +149,14 @@ impl EnvelopeAttachments {
     */
     pub async fn attachments_put_attachment(
         &self,
-        account_id: &str,
-        attachment_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        attachment_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::Attachment,
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let account_id = account_id.as_ref();
+        let attachment_id = attachment_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -0,0 +1,327 @@
+//! Build an envelope from local documents and signers whose tabs are placed with
+//! anchor strings, send it, and wait for it to be signed.
+//!
+//! ```no_run
+//! # async fn run(docusign: docusign::Client, account_id: &str) -> anyhow::Result<()> {
+//! use std::time::Duration;
+//!
+//! use docusign::envelopes::{AnchorTab, EnvelopeBuilder, EnvelopeDocument, EnvelopeSigner};
+//!
+//! let contract = tokio::fs::File::open("contract.pdf").await?;
+//! let envelope = EnvelopeBuilder::new("Please sign the contract")
+//!     .document(EnvelopeDocument::from_reader("1", "Contract", "pdf", contract).await?)
+//!     .signer(
+//!         EnvelopeSigner::new("1", "jane@example.com", "Jane Doe")
+//!             .tab(AnchorTab::sign_here("/sn1/").offset(20, 10))
+//!             .tab(AnchorTab::date_signed("/ds1/")),
+//!     );
+//!
+//! let envelopes = docusign.envelopes();
+//! let summary = envelopes.send(account_id, &envelope).await?;
+//! let envelope = envelopes
+//!     .wait_for_completion(account_id, &summary.envelope_id, Duration::from_secs(30))
+//!     .await?;
+//! println!("the envelope was {}", envelope.status);
+//! # Ok(())
+//! # }
+//! ```
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    envelopes::Envelopes,
+    types::{Envelope, EnvelopeSummary},
+};
+
+/// An envelope to create, made of documents and the signers of the documents.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvelopeBuilder {
+    email_subject: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    email_blurb: String,
+    documents: Vec<EnvelopeDocument>,
+    recipients: Recipients,
+    status: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+struct Recipients {
+    signers: Vec<EnvelopeSigner>,
+}
+
+/// A document of an envelope, sent base64 encoded.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvelopeDocument {
+    /// The id of the document in the envelope, like `1`.
+    pub document_id: String,
+    pub name: String,
+    /// The type of the document, like `pdf` or `docx`.
+    pub file_extension: String,
+    #[serde(rename = "documentBase64")]
+    content: String,
+}
+
+/// Someone who signs the documents of an envelope.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvelopeSigner {
+    recipient_id: String,
+    email: String,
+    name: String,
+    routing_order: String,
+    tabs: SignerTabs,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SignerTabs {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sign_here_tabs: Vec<AnchorTab>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    initial_here_tabs: Vec<AnchorTab>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    date_signed_tabs: Vec<AnchorTab>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    full_name_tabs: Vec<AnchorTab>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    text_tabs: Vec<AnchorTab>,
+}
+
+/// The kinds of tabs a signer can be given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabKind {
+    SignHere,
+    InitialHere,
+    DateSigned,
+    FullName,
+    /// A field the signer fills in.
+    Text,
+}
+
+/**
+ * A tab placed next to every occurrence of a string in the documents, usually
+ * written in white so that the signers do not see it.
+ */
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AnchorTab {
+    #[serde(skip)]
+    kind: TabKind,
+    anchor_string: String,
+    anchor_units: String,
+    anchor_x_offset: String,
+    anchor_y_offset: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    tab_label: String,
+}
+
+impl EnvelopeBuilder {
+    /// An envelope sent with the given email subject.
+    pub fn new(email_subject: &str) -> Self {
+        EnvelopeBuilder {
+            email_subject: email_subject.to_string(),
+            email_blurb: String::new(),
+            documents: Vec::new(),
+            recipients: Default::default(),
+            status: "sent".to_string(),
+        }
+    }
+
+    /// The body of the email sent to the signers.
+    pub fn email_blurb(mut self, email_blurb: &str) -> Self {
+        self.email_blurb = email_blurb.to_string();
+        self
+    }
+
+    pub fn document(mut self, document: EnvelopeDocument) -> Self {
+        self.documents.push(document);
+        self
+    }
+
+    pub fn signer(mut self, signer: EnvelopeSigner) -> Self {
+        self.recipients.signers.push(signer);
+        self
+    }
+
+    /// Create the envelope as a draft instead of sending it.
+    pub fn draft(mut self) -> Self {
+        self.status = "created".to_string();
+        self
+    }
+}
+
+impl EnvelopeDocument {
+    pub fn from_bytes(
+        document_id: &str,
+        name: &str,
+        file_extension: &str,
+        contents: &[u8],
+    ) -> Self {
+        EnvelopeDocument {
+            document_id: document_id.to_string(),
+            name: name.to_string(),
+            file_extension: file_extension.to_string(),
+            content: base64::encode(contents),
+        }
+    }
+
+    /// A document read from `reader`, encoding it as it is read.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn from_reader<R>(
+        document_id: &str,
+        name: &str,
+        file_extension: &str,
+        mut reader: R,
+    ) -> Result<Self>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        let mut content = String::new();
+        // A multiple of 3 bytes, so the pieces encode to the encoding of the whole.
+        let mut buf = vec![0u8; 3 * 16 * 1024];
+        let mut filled = 0;
+        loop {
+            let n = reader.read(&mut buf[filled..]).await?;
+            filled += n;
+            if n == 0 || filled == buf.len() {
+                base64::encode_config_buf(&buf[..filled], base64::STANDARD, &mut content);
+                filled = 0;
+            }
+            if n == 0 {
+                break;
+            }
+        }
+
+        Ok(EnvelopeDocument {
+            document_id: document_id.to_string(),
+            name: name.to_string(),
+            file_extension: file_extension.to_string(),
+            content,
+        })
+    }
+}
+
+impl EnvelopeSigner {
+    /// A signer, `recipient_id` identifies them in the envelope, like `1`.
+    pub fn new(recipient_id: &str, email: &str, name: &str) -> Self {
+        EnvelopeSigner {
+            recipient_id: recipient_id.to_string(),
+            email: email.to_string(),
+            name: name.to_string(),
+            routing_order: "1".to_string(),
+            tabs: Default::default(),
+        }
+    }
+
+    /// The order the signers sign in, the ones with the same order sign at the same
+    /// time. The default is 1.
+    pub fn routing_order(mut self, routing_order: u32) -> Self {
+        self.routing_order = routing_order.to_string();
+        self
+    }
+
+    pub fn tab(mut self, tab: AnchorTab) -> Self {
+        let tabs = match tab.kind {
+            TabKind::SignHere => &mut self.tabs.sign_here_tabs,
+            TabKind::InitialHere => &mut self.tabs.initial_here_tabs,
+            TabKind::DateSigned => &mut self.tabs.date_signed_tabs,
+            TabKind::FullName => &mut self.tabs.full_name_tabs,
+            TabKind::Text => &mut self.tabs.text_tabs,
+        };
+        tabs.push(tab);
+        self
+    }
+}
+
+impl AnchorTab {
+    /// A tab of the given kind, placed at the anchor string.
+    pub fn new(kind: TabKind, anchor_string: &str) -> Self {
+        AnchorTab {
+            kind,
+            anchor_string: anchor_string.to_string(),
+            anchor_units: "pixels".to_string(),
+            anchor_x_offset: "0".to_string(),
+            anchor_y_offset: "0".to_string(),
+            tab_label: String::new(),
+        }
+    }
+
+    pub fn sign_here(anchor_string: &str) -> Self {
+        AnchorTab::new(TabKind::SignHere, anchor_string)
+    }
+
+    pub fn initial_here(anchor_string: &str) -> Self {
+        AnchorTab::new(TabKind::InitialHere, anchor_string)
+    }
+
+    pub fn date_signed(anchor_string: &str) -> Self {
+        AnchorTab::new(TabKind::DateSigned, anchor_string)
+    }
+
+    pub fn full_name(anchor_string: &str) -> Self {
+        AnchorTab::new(TabKind::FullName, anchor_string)
+    }
+
+    /// A text field, whose value is found under the label once the envelope is
+    /// signed.
+    pub fn text(anchor_string: &str, tab_label: &str) -> Self {
+        let mut tab = AnchorTab::new(TabKind::Text, anchor_string);
+        tab.tab_label = tab_label.to_string();
+        tab
+    }
+
+    /// Move the tab from the anchor string by this many pixels, to the right and
+    /// down.
+    pub fn offset(mut self, x: i32, y: i32) -> Self {
+        self.anchor_x_offset = x.to_string();
+        self.anchor_y_offset = y.to_string();
+        self
+    }
+}
+
+impl Envelopes {
+    /// Create the envelope, which is sent to the signers unless it is a draft.
+    pub async fn send(
+        &self,
+        account_id: &str,
+        envelope: &EnvelopeBuilder,
+    ) -> Result<EnvelopeSummary> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes",
+            crate::progenitor_support::encode_path(account_id),
+        );
+
+        self.client
+            .post(
+                &url,
+                Some(reqwest::Body::from(serde_json::to_vec(envelope)?)),
+            )
+            .await
+    }
+
+    /// Get the envelope every `interval` until it was completed, declined or voided.
+    pub async fn wait_for_completion(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        interval: Duration,
+    ) -> Result<Envelope> {
+        loop {
+            let envelope = self.get_envelopes(account_id, envelope_id, "", "").await?;
+            if matches!(
+                envelope.status.as_str(),
+                "completed" | "declined" | "voided"
+            ) {
+                return Ok(envelope);
+            }
+
+            crate::runtime::sleep(interval).await;
+        }
+    }
+}
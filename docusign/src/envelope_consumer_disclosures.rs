@@ -78,22 +78,26 @@ impl EnvelopeConsumerDisclosures {
     */
     pub async fn consumer_disclosure_get_envelope_recipient(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         lang_code: &str,
     ) -> Result<crate::types::ConsumerDisclosure> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang_code.is_empty() {
-            query_args.push(("langCode".to_string(), lang_code.to_string()));
+            query_args.push(("langCode", lang_code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(recipient_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -210,11 +214,15 @@ impl EnvelopeConsumerDisclosures {
     */
     pub async fn consumer_disclosure_get_envelope_recipient_lang_code(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        lang_code: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        lang_code: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::ConsumerDisclosure> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let lang_code = lang_code.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -28,9 +28,11 @@ impl EnvelopeCustomFields {
     */
     pub async fn custom_fields_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::CustomFieldsEnvelope> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -57,10 +59,12 @@ impl EnvelopeCustomFields {
     */
     pub async fn custom_fields_put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeCustomFields,
     ) -> Result<crate::types::EnvelopeCustomFields> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -89,10 +93,12 @@ impl EnvelopeCustomFields {
     */
     pub async fn custom_fields_post(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeCustomFields,
     ) -> Result<crate::types::EnvelopeCustomFields> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -118,10 +124,12 @@ impl EnvelopeCustomFields {
     */
     pub async fn custom_fields_delete(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeCustomFields,
     ) -> Result<crate::types::EnvelopeCustomFields> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -27,10 +27,13 @@ impl EnvelopeDocumentFields {
     */
     pub async fn document_fields_get(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
@@ -56,11 +59,14 @@ impl EnvelopeDocumentFields {
     */
     pub async fn document_fields_put(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeDocumentFields,
     ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
@@ -88,11 +94,14 @@ impl EnvelopeDocumentFields {
     */
     pub async fn document_fields_post(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeDocumentFields,
     ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
@@ -120,11 +129,14 @@ impl EnvelopeDocumentFields {
     */
     pub async fn document_fields_delete(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeDocumentFields,
     ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
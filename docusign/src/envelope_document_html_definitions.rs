@@ -28,10 +28,13 @@ impl EnvelopeDocumentHtmlDefinitions {
     */
     pub async fn responsive_html_get_envelope_document_definition(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::DocumentHtmlDefinitionOriginals> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/html_definitions",
             crate::progenitor_support::encode_path(account_id),
@@ -28,11 +28,15 @@ impl EnvelopeDocumentTabs {
     */
     pub async fn tabs_get_page(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
-        page_number: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let page_number = page_number.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -65,26 +69,30 @@ impl EnvelopeDocumentTabs {
     */
     pub async fn tabs_get_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         include_metadata: &str,
         page_numbers: &str,
     ) -> Result<crate::types::EnvelopeDocumentTabs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !page_numbers.is_empty() {
-            query_args.push(("page_numbers".to_string(), page_numbers.to_string()));
+            query_args.push(("page_numbers", page_numbers.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -105,11 +113,14 @@ impl EnvelopeDocumentTabs {
     */
     pub async fn tabs_put_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::Tabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -137,11 +148,14 @@ impl EnvelopeDocumentTabs {
     */
     pub async fn tabs_post_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::Tabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -169,11 +183,14 @@ impl EnvelopeDocumentTabs {
     */
     pub async fn tabs_delete_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::Tabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
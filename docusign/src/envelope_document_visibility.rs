@@ -27,10 +27,13 @@ impl EnvelopeDocumentVisibility {
     */
     pub async fn recipients_get_recipient_document_visibility(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::DocumentVisibilityList> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
             crate::progenitor_support::encode_path(account_id),
@@ -58,11 +61,14 @@ impl EnvelopeDocumentVisibility {
     */
     pub async fn recipients_put_recipient_document_visibility(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         body: &crate::types::DocumentVisibilityList,
     ) -> Result<crate::types::DocumentVisibilityList> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
             crate::progenitor_support::encode_path(account_id),
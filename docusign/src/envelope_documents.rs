@@ -32,8 +32,8 @@ impl EnvelopeDocuments {
     */
     pub async fn documents_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         documents_by_userid: &str,
         include_document_size: &str,
         include_metadata: &str,
@@ -41,37 +41,34 @@ impl EnvelopeDocuments {
         recipient_id: &str,
         shared_user_id: &str,
     ) -> Result<crate::types::EnvelopeDocumentsResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !documents_by_userid.is_empty() {
-            query_args.push((
-                "documents_by_userid".to_string(),
-                documents_by_userid.to_string(),
-            ));
+            query_args.push(("documents_by_userid", documents_by_userid.to_string()));
         }
         if !include_document_size.is_empty() {
-            query_args.push((
-                "include_document_size".to_string(),
-                include_document_size.to_string(),
-            ));
+            query_args.push(("include_document_size", include_document_size.to_string()));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
         if !recipient_id.is_empty() {
-            query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
+            query_args.push(("recipient_id", recipient_id.to_string()));
         }
         if !shared_user_id.is_empty() {
-            query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
+            query_args.push(("shared_user_id", shared_user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -100,10 +97,12 @@ impl EnvelopeDocuments {
     */
     pub async fn documents_put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents",
             crate::progenitor_support::encode_path(account_id),
@@ -152,10 +151,12 @@ impl EnvelopeDocuments {
     */
     pub async fn documents_delete(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents",
             crate::progenitor_support::encode_path(account_id),
@@ -195,9 +196,9 @@ impl EnvelopeDocuments {
     */
     pub async fn documents_get_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         certificate: &str,
         documents_by_userid: &str,
         encoding: &str,
@@ -208,44 +209,45 @@ impl EnvelopeDocuments {
         show_changes: &str,
         watermark: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !certificate.is_empty() {
-            query_args.push(("certificate".to_string(), certificate.to_string()));
+            query_args.push(("certificate", certificate.to_string()));
         }
         if !documents_by_userid.is_empty() {
-            query_args.push((
-                "documents_by_userid".to_string(),
-                documents_by_userid.to_string(),
-            ));
+            query_args.push(("documents_by_userid", documents_by_userid.to_string()));
         }
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
         if !encrypt.is_empty() {
-            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+            query_args.push(("encrypt", encrypt.to_string()));
         }
         if !language.is_empty() {
-            query_args.push(("language".to_string(), language.to_string()));
+            query_args.push(("language", language.to_string()));
         }
         if !recipient_id.is_empty() {
-            query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
+            query_args.push(("recipient_id", recipient_id.to_string()));
         }
         if !shared_user_id.is_empty() {
-            query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
+            query_args.push(("shared_user_id", shared_user_id.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
         if !watermark.is_empty() {
-            query_args.push(("watermark".to_string(), watermark.to_string()));
+            query_args.push(("watermark", watermark.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -282,10 +284,13 @@ impl EnvelopeDocuments {
     */
     pub async fn documents_put_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeDocument> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -26,9 +26,11 @@ impl EnvelopeEmailSettings {
     */
     pub async fn email_settings_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EmailSettings> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
             crate::progenitor_support::encode_path(account_id),
@@ -54,10 +56,12 @@ impl EnvelopeEmailSettings {
     */
     pub async fn email_settings_put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EmailSettings,
     ) -> Result<crate::types::EmailSettings> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
             crate::progenitor_support::encode_path(account_id),
@@ -85,10 +89,12 @@ impl EnvelopeEmailSettings {
     */
     pub async fn email_settings_post(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EmailSettings,
     ) -> Result<crate::types::EmailSettings> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
             crate::progenitor_support::encode_path(account_id),
@@ -114,9 +120,11 @@ impl EnvelopeEmailSettings {
     */
     pub async fn email_settings_delete(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EmailSettings> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
             crate::progenitor_support::encode_path(account_id),
@@ -28,9 +28,11 @@ impl EnvelopeFormData {
     */
     pub async fn form_data_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeFormDataType> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/form_data",
             crate::progenitor_support::encode_path(account_id),
@@ -38,9 +38,11 @@ impl EnvelopeLocks {
     */
     pub async fn lock_get_envelope(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeLocks> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
             crate::progenitor_support::encode_path(account_id),
@@ -86,10 +88,12 @@ impl EnvelopeLocks {
     */
     pub async fn lock_put_envelope(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::LockRequest,
     ) -> Result<crate::types::EnvelopeLocks> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
             crate::progenitor_support::encode_path(account_id),
@@ -171,10 +175,12 @@ impl EnvelopeLocks {
     */
     pub async fn lock_post_envelope(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::LockRequest,
     ) -> Result<crate::types::EnvelopeLocks> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
             crate::progenitor_support::encode_path(account_id),
@@ -216,9 +222,11 @@ impl EnvelopeLocks {
     */
     pub async fn lock_delete_envelope(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeLocks> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
             crate::progenitor_support::encode_path(account_id),
@@ -35,29 +35,33 @@ impl EnvelopeRecipientTabs {
     */
     pub async fn recipients_get_recipient_tab(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         include_anchor_tab_locations: &str,
         include_metadata: &str,
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
-                "include_anchor_tab_locations".to_string(),
+                "include_anchor_tab_locations",
                 include_anchor_tab_locations.to_string(),
             ));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(recipient_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -81,11 +85,14 @@ impl EnvelopeRecipientTabs {
     */
     pub async fn recipients_put_recipient_tabs(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         body: &crate::types::EnvelopeRecipientTabs,
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -113,11 +120,14 @@ impl EnvelopeRecipientTabs {
     */
     pub async fn recipients_post_recipient_tabs(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         body: &crate::types::EnvelopeRecipientTabs,
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -145,11 +155,14 @@ impl EnvelopeRecipientTabs {
     */
     pub async fn recipients_delete_recipient_tabs(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         body: &crate::types::EnvelopeRecipientTabs,
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -32,35 +32,38 @@ impl EnvelopeRecipients {
     */
     pub async fn recipients_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         include_anchor_tab_locations: &str,
         include_extended: &str,
         include_metadata: &str,
         include_tabs: &str,
     ) -> Result<crate::types::EnvelopeRecipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
-                "include_anchor_tab_locations".to_string(),
+                "include_anchor_tab_locations",
                 include_anchor_tab_locations.to_string(),
             ));
         }
         if !include_extended.is_empty() {
-            query_args.push(("include_extended".to_string(), include_extended.to_string()));
+            query_args.push(("include_extended", include_extended.to_string()));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -112,32 +115,35 @@ impl EnvelopeRecipients {
     */
     pub async fn recipients_put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         combine_same_order_recipients: &str,
         offline_signing: &str,
         resend_envelope: &str,
         body: &crate::types::EnvelopeRecipients,
     ) -> Result<crate::types::RecipientsUpdateSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !combine_same_order_recipients.is_empty() {
             query_args.push((
-                "combine_same_order_recipients".to_string(),
+                "combine_same_order_recipients",
                 combine_same_order_recipients.to_string(),
             ));
         }
         if !offline_signing.is_empty() {
-            query_args.push(("offline_signing".to_string(), offline_signing.to_string()));
+            query_args.push(("offline_signing", offline_signing.to_string()));
         }
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -162,21 +168,24 @@ impl EnvelopeRecipients {
     */
     pub async fn recipients_post(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         resend_envelope: &str,
         body: &crate::types::EnvelopeRecipients,
     ) -> Result<crate::types::EnvelopeRecipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -200,10 +209,12 @@ impl EnvelopeRecipients {
     */
     pub async fn recipients_delete(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeRecipients,
     ) -> Result<crate::types::EnvelopeRecipients> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients",
             crate::progenitor_support::encode_path(account_id),
@@ -231,10 +242,12 @@ impl EnvelopeRecipients {
     */
     pub async fn recipients_put_document_visibility(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::DocumentVisibilityList,
     ) -> Result<crate::types::DocumentVisibilityList> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/document_visibility",
             crate::progenitor_support::encode_path(account_id),
@@ -263,10 +276,13 @@ impl EnvelopeRecipients {
     */
     pub async fn recipients_delete_recipient(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeRecipients> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -292,10 +308,13 @@ impl EnvelopeRecipients {
     */
     pub async fn recipients_post_recipient_proof_file_resource_token(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::ProofServiceResourceToken> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/identity_proof_token",
             crate::progenitor_support::encode_path(account_id),
@@ -334,10 +353,13 @@ impl EnvelopeRecipients {
     */
     pub async fn views_post_recipient_manual_review_view(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::ViewUrl> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/views/identity_manual_review",
             crate::progenitor_support::encode_path(account_id),
@@ -364,10 +386,12 @@ impl EnvelopeRecipients {
     */
     pub async fn views_post_preview(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::RecipientPreviewRequest,
     ) -> Result<crate::types::ViewUrl> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/recipient_preview",
             crate::progenitor_support::encode_path(account_id),
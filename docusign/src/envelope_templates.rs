@@ -33,22 +33,26 @@ impl EnvelopeTemplates {
     */
     pub async fn templates_get_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         include: &str,
     ) -> Result<crate::types::TemplateInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -70,26 +74,30 @@ impl EnvelopeTemplates {
     */
     pub async fn templates_post_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         preserve_template_recipient: &str,
         body: &crate::types::DocumentTemplateList,
     ) -> Result<crate::types::DocumentTemplateList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !preserve_template_recipient.is_empty() {
             query_args.push((
-                "preserve_template_recipient".to_string(),
+                "preserve_template_recipient",
                 preserve_template_recipient.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -113,11 +121,15 @@ impl EnvelopeTemplates {
     */
     pub async fn templates_delete_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -145,20 +157,23 @@ impl EnvelopeTemplates {
     */
     pub async fn templates_get_envelope(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         include: &str,
     ) -> Result<crate::types::TemplateInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/templates?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -179,24 +194,27 @@ impl EnvelopeTemplates {
     */
     pub async fn templates_post_envelope(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         preserve_template_recipient: &str,
         body: &crate::types::DocumentTemplateList,
     ) -> Result<crate::types::DocumentTemplateList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !preserve_template_recipient.is_empty() {
             query_args.push((
-                "preserve_template_recipient".to_string(),
+                "preserve_template_recipient",
                 preserve_template_recipient.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/templates?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client
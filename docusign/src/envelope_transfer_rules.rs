@@ -29,22 +29,24 @@ impl EnvelopeTransferRules {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/transfer_rules?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -65,9 +67,10 @@ impl EnvelopeTransferRules {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::EnvelopeTransferRuleInformation,
     ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules",
             crate::progenitor_support::encode_path(account_id),
@@ -102,9 +105,10 @@ impl EnvelopeTransferRules {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::EnvelopeTransferRuleRequest,
     ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules",
             crate::progenitor_support::encode_path(account_id),
@@ -133,10 +137,12 @@ impl EnvelopeTransferRules {
     */
     pub async fn put_rule(
         &self,
-        account_id: &str,
-        envelope_transfer_rule_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_transfer_rule_id: impl AsRef<str>,
         body: &crate::types::EnvelopeTransferRule,
     ) -> Result<crate::types::EnvelopeTransferRule> {
+        let account_id = account_id.as_ref();
+        let envelope_transfer_rule_id = envelope_transfer_rule_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -162,7 +168,13 @@ impl EnvelopeTransferRules {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_transfer_rule_id: &str` -- The id of the envelope transfer rule. The system generates this id when the rule is first created.
     */
-    pub async fn delete(&self, account_id: &str, envelope_transfer_rule_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_transfer_rule_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_transfer_rule_id = envelope_transfer_rule_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
             crate::progenitor_support::encode_path(account_id),
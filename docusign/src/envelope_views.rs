@@ -41,10 +41,12 @@ impl EnvelopeViews {
     */
     pub async fn views_post_envelope_correct_view(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::CorrectViewRequest,
     ) -> Result<crate::types::EnvelopeViews> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/correct",
             crate::progenitor_support::encode_path(account_id),
@@ -70,10 +72,12 @@ impl EnvelopeViews {
     */
     pub async fn views_delete_envelope_correct_view(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::CorrectViewRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/correct",
             crate::progenitor_support::encode_path(account_id),
@@ -116,10 +120,12 @@ impl EnvelopeViews {
     */
     pub async fn views_post_envelope_edit_view(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::ReturnUrlRequest,
     ) -> Result<crate::types::EnvelopeViews> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/edit",
             crate::progenitor_support::encode_path(account_id),
@@ -171,10 +177,12 @@ impl EnvelopeViews {
     */
     pub async fn views_post_envelope_recipient_view(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::RecipientViewRequest,
     ) -> Result<crate::types::EnvelopeViews> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/recipient",
             crate::progenitor_support::encode_path(account_id),
@@ -228,10 +236,12 @@ impl EnvelopeViews {
     */
     pub async fn views_post_envelope_sender_view(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::ReturnUrlRequest,
     ) -> Result<crate::types::EnvelopeViews> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/sender",
             crate::progenitor_support::encode_path(account_id),
@@ -262,10 +272,12 @@ impl EnvelopeViews {
     */
     pub async fn views_post_envelope_recipient_shared_view(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::RecipientViewRequest,
     ) -> Result<crate::types::ViewUrl> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/shared",
             crate::progenitor_support::encode_path(account_id),
@@ -295,9 +307,10 @@ impl EnvelopeViews {
     */
     pub async fn views_post_account_console_view(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ConsoleViewRequest,
     ) -> Result<crate::types::EnvelopeViews> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/views/console",
             crate::progenitor_support::encode_path(account_id),
@@ -24,7 +24,13 @@ impl EnvelopeWorkflowDefinition {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn get(&self, account_id: &str, envelope_id: &str) -> Result<crate::types::Workflow> {
+    pub async fn get(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<crate::types::Workflow> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/workflow",
             crate::progenitor_support::encode_path(account_id),
@@ -48,10 +54,12 @@ impl EnvelopeWorkflowDefinition {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::Workflow,
     ) -> Result<crate::types::Workflow> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/workflow",
             crate::progenitor_support::encode_path(account_id),
@@ -76,7 +84,13 @@ impl EnvelopeWorkflowDefinition {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn delete(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/workflow",
             crate::progenitor_support::encode_path(account_id),
@@ -100,9 +114,11 @@ impl EnvelopeWorkflowDefinition {
     */
     pub async fn get_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::Workflow> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/workflow",
             crate::progenitor_support::encode_path(account_id),
@@ -126,10 +142,12 @@ impl EnvelopeWorkflowDefinition {
     */
     pub async fn put_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::Workflow,
     ) -> Result<crate::types::Workflow> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/workflow",
             crate::progenitor_support::encode_path(account_id),
@@ -153,7 +171,13 @@ impl EnvelopeWorkflowDefinition {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn delete_template(&self, account_id: &str, template_id: &str) -> Result<()> {
+    pub async fn delete_template(
+        &self,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/workflow",
             crate::progenitor_support::encode_path(account_id),
@@ -1,5 +1,8 @@
 use anyhow::Result;
 
+pub use crate::envelope_builder::{
+    AnchorTab, EnvelopeBuilder, EnvelopeDocument, EnvelopeSigner, TabKind,
+};
 use crate::Client;
 
 pub struct Envelopes {
@@ -271,7 +274,7 @@ impl Envelopes {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         ac_status: &str,
         block: &str,
         cdse_mode: &str,
@@ -303,117 +306,110 @@ impl Envelopes {
         user_id: &str,
         user_name: &str,
     ) -> Result<crate::types::EnvelopesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ac_status.is_empty() {
-            query_args.push(("ac_status".to_string(), ac_status.to_string()));
+            query_args.push(("ac_status", ac_status.to_string()));
         }
         if !block.is_empty() {
-            query_args.push(("block".to_string(), block.to_string()));
+            query_args.push(("block", block.to_string()));
         }
         if !cdse_mode.is_empty() {
-            query_args.push(("cdse_mode".to_string(), cdse_mode.to_string()));
+            query_args.push(("cdse_mode", cdse_mode.to_string()));
         }
         if !continuation_token.is_empty() {
-            query_args.push((
-                "continuation_token".to_string(),
-                continuation_token.to_string(),
-            ));
+            query_args.push(("continuation_token", continuation_token.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !custom_field.is_empty() {
-            query_args.push(("custom_field".to_string(), custom_field.to_string()));
+            query_args.push(("custom_field", custom_field.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !envelope_ids.is_empty() {
-            query_args.push(("envelope_ids".to_string(), envelope_ids.to_string()));
+            query_args.push(("envelope_ids", envelope_ids.to_string()));
         }
         if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.to_string()));
+            query_args.push(("exclude", exclude.to_string()));
         }
         if !folder_ids.is_empty() {
-            query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
+            query_args.push(("folder_ids", folder_ids.to_string()));
         }
         if !folder_types.is_empty() {
-            query_args.push(("folder_types".to_string(), folder_types.to_string()));
+            query_args.push(("folder_types", folder_types.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !from_to_status.is_empty() {
-            query_args.push(("from_to_status".to_string(), from_to_status.to_string()));
+            query_args.push(("from_to_status", from_to_status.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !include_purge_information.is_empty() {
             query_args.push((
-                "include_purge_information".to_string(),
+                "include_purge_information",
                 include_purge_information.to_string(),
             ));
         }
         if !intersecting_folder_ids.is_empty() {
             query_args.push((
-                "intersecting_folder_ids".to_string(),
+                "intersecting_folder_ids",
                 intersecting_folder_ids.to_string(),
             ));
         }
         if !last_queried_date.is_empty() {
-            query_args.push((
-                "last_queried_date".to_string(),
-                last_queried_date.to_string(),
-            ));
+            query_args.push(("last_queried_date", last_queried_date.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !powerformids.is_empty() {
-            query_args.push(("powerformids".to_string(), powerformids.to_string()));
+            query_args.push(("powerformids", powerformids.to_string()));
         }
         if !query_budget.is_empty() {
-            query_args.push(("query_budget".to_string(), query_budget.to_string()));
+            query_args.push(("query_budget", query_budget.to_string()));
         }
         if !requester_date_format.is_empty() {
-            query_args.push((
-                "requester_date_format".to_string(),
-                requester_date_format.to_string(),
-            ));
+            query_args.push(("requester_date_format", requester_date_format.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
         if !transaction_ids.is_empty() {
-            query_args.push(("transaction_ids".to_string(), transaction_ids.to_string()));
+            query_args.push(("transaction_ids", transaction_ids.to_string()));
         }
         if !user_filter.is_empty() {
-            query_args.push(("user_filter".to_string(), user_filter.to_string()));
+            query_args.push(("user_filter", user_filter.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
         if !user_name.is_empty() {
-            query_args.push(("user_name".to_string(), user_name.to_string()));
+            query_args.push(("user_name", user_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -507,40 +503,36 @@ impl Envelopes {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         cdse_mode: &str,
         change_routing_order: &str,
         completed_documents_only: &str,
         merge_roles_on_draft: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cdse_mode.is_empty() {
-            query_args.push(("cdse_mode".to_string(), cdse_mode.to_string()));
+            query_args.push(("cdse_mode", cdse_mode.to_string()));
         }
         if !change_routing_order.is_empty() {
-            query_args.push((
-                "change_routing_order".to_string(),
-                change_routing_order.to_string(),
-            ));
+            query_args.push(("change_routing_order", change_routing_order.to_string()));
         }
         if !completed_documents_only.is_empty() {
             query_args.push((
-                "completed_documents_only".to_string(),
+                "completed_documents_only",
                 completed_documents_only.to_string(),
             ));
         }
         if !merge_roles_on_draft.is_empty() {
-            query_args.push((
-                "merge_roles_on_draft".to_string(),
-                merge_roles_on_draft.to_string(),
-            ));
+            query_args.push(("merge_roles_on_draft", merge_roles_on_draft.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -663,7 +655,7 @@ impl Envelopes {
     */
     pub async fn put_status(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         ac_status: &str,
         block: &str,
         count: &str,
@@ -678,48 +670,50 @@ impl Envelopes {
         user_name: &str,
         body: &crate::types::EnvelopeIdsRequest,
     ) -> Result<crate::types::EnvelopesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ac_status.is_empty() {
-            query_args.push(("ac_status".to_string(), ac_status.to_string()));
+            query_args.push(("ac_status", ac_status.to_string()));
         }
         if !block.is_empty() {
-            query_args.push(("block".to_string(), block.to_string()));
+            query_args.push(("block", block.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !envelope_ids.is_empty() {
-            query_args.push(("envelope_ids".to_string(), envelope_ids.to_string()));
+            query_args.push(("envelope_ids", envelope_ids.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !from_to_status.is_empty() {
-            query_args.push(("from_to_status".to_string(), from_to_status.to_string()));
+            query_args.push(("from_to_status", from_to_status.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
         if !transaction_ids.is_empty() {
-            query_args.push(("transaction_ids".to_string(), transaction_ids.to_string()));
+            query_args.push(("transaction_ids", transaction_ids.to_string()));
         }
         if !user_name.is_empty() {
-            query_args.push(("user_name".to_string(), user_name.to_string()));
+            query_args.push(("user_name", user_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/status?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/status",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -756,24 +750,27 @@ impl Envelopes {
     */
     pub async fn get_envelopes(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         advanced_update: &str,
         include: &str,
     ) -> Result<crate::types::Envelope> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !advanced_update.is_empty() {
-            query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
+            query_args.push(("advanced_update", advanced_update.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -959,25 +956,28 @@ impl Envelopes {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         advanced_update: &str,
         resend_envelope: &str,
         body: &crate::types::Envelope,
     ) -> Result<crate::types::EnvelopeUpdateSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !advanced_update.is_empty() {
-            query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
+            query_args.push(("advanced_update", advanced_update.to_string()));
         }
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -999,9 +999,11 @@ impl Envelopes {
     */
     pub async fn audit_events_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::EnvelopeAuditEventResponse> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/audit_events",
             crate::progenitor_support::encode_path(account_id),
@@ -1033,9 +1035,9 @@ impl Envelopes {
     */
     pub async fn pages_get_page_image(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         count: &str,
         dpi: &str,
         max_height: &str,
@@ -1044,35 +1046,39 @@ impl Envelopes {
         show_changes: &str,
         start_position: &str,
     ) -> Result<crate::types::PageImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !nocache.is_empty() {
-            query_args.push(("nocache".to_string(), nocache.to_string()));
+            query_args.push(("nocache", nocache.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1094,11 +1100,15 @@ impl Envelopes {
     */
     pub async fn pages_delete_page(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
-        page_number: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let page_number = page_number.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -1130,36 +1140,41 @@ impl Envelopes {
     */
     pub async fn pages_get_page_image_envelopes(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
-        page_number: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
         dpi: &str,
         max_height: &str,
         max_width: &str,
         show_changes: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let page_number = page_number.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(document_id),
-            crate::progenitor_support::encode_path(page_number),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1181,12 +1196,16 @@ impl Envelopes {
     */
     pub async fn pages_put_page_image(
         &self,
-        account_id: &str,
-        document_id: &str,
-        envelope_id: &str,
-        page_number: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
         body: &crate::types::PageRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let page_number = page_number.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
             crate::progenitor_support::encode_path(account_id),
@@ -1214,9 +1233,11 @@ impl Envelopes {
     */
     pub async fn notification_get(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
     ) -> Result<crate::types::Notification> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/notification",
             crate::progenitor_support::encode_path(account_id),
@@ -1242,10 +1263,12 @@ impl Envelopes {
     */
     pub async fn notification_put(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::EnvelopeNotificationRequest,
     ) -> Result<crate::types::Notification> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/notification",
             crate::progenitor_support::encode_path(account_id),
@@ -1281,22 +1304,26 @@ impl Envelopes {
     */
     pub async fn recipients_get_recipient_initials_image(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(recipient_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1319,10 +1346,13 @@ impl Envelopes {
     */
     pub async fn recipients_put_recipient_initials_image(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
             crate::progenitor_support::encode_path(account_id),
@@ -1348,10 +1378,13 @@ impl Envelopes {
     */
     pub async fn recipients_get_recipient_signature(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::UserSignature> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature",
             crate::progenitor_support::encode_path(account_id),
@@ -1386,22 +1419,26 @@ impl Envelopes {
     */
     pub async fn recipients_get_recipient_signature_image(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(envelope_id),
-            crate::progenitor_support::encode_path(recipient_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1424,10 +1461,13 @@ impl Envelopes {
     */
     pub async fn recipients_put_recipient_signature_image(
         &self,
-        account_id: &str,
-        envelope_id: &str,
-        recipient_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
             crate::progenitor_support::encode_path(account_id),
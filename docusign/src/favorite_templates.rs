@@ -23,7 +23,11 @@ impl FavoriteTemplates {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn get(&self, account_id: &str) -> Result<crate::types::FavoriteTemplatesInfo> {
+    pub async fn get(
+        &self,
+        account_id: impl AsRef<str>,
+    ) -> Result<crate::types::FavoriteTemplatesInfo> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/favorite_templates",
             crate::progenitor_support::encode_path(account_id),
@@ -46,9 +50,10 @@ impl FavoriteTemplates {
     */
     pub async fn put_template(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::FavoriteTemplatesInfo,
     ) -> Result<crate::types::FavoriteTemplatesInfo> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/favorite_templates",
             crate::progenitor_support::encode_path(account_id),
@@ -72,9 +77,10 @@ impl FavoriteTemplates {
     */
     pub async fn un_template(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::FavoriteTemplatesInfo,
     ) -> Result<crate::types::FavoriteTemplatesInfo> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/favorite_templates",
             crate::progenitor_support::encode_path(account_id),
@@ -41,34 +41,36 @@ impl Folders {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         include: &str,
         include_items: &str,
         start_position: &str,
         template: &str,
         user_filter: &str,
     ) -> Result<crate::types::FoldersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !include_items.is_empty() {
-            query_args.push(("include_items".to_string(), include_items.to_string()));
+            query_args.push(("include_items", include_items.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !template.is_empty() {
-            query_args.push(("template".to_string(), template.to_string()));
+            query_args.push(("template", template.to_string()));
         }
         if !user_filter.is_empty() {
-            query_args.push(("user_filter".to_string(), user_filter.to_string()));
+            query_args.push(("user_filter", user_filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/folders?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/folders",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -96,8 +98,8 @@ impl Folders {
     */
     pub async fn get_items(
         &self,
-        account_id: &str,
-        folder_id: &str,
+        account_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
         from_date: &str,
         include_items: &str,
         owner_email: &str,
@@ -107,37 +109,40 @@ impl Folders {
         status: &str,
         to_date: &str,
     ) -> Result<crate::types::FolderItemsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !include_items.is_empty() {
-            query_args.push(("include_items".to_string(), include_items.to_string()));
+            query_args.push(("include_items", include_items.to_string()));
         }
         if !owner_email.is_empty() {
-            query_args.push(("owner_email".to_string(), owner_email.to_string()));
+            query_args.push(("owner_email", owner_email.to_string()));
         }
         if !owner_name.is_empty() {
-            query_args.push(("owner_name".to_string(), owner_name.to_string()));
+            query_args.push(("owner_name", owner_name.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/folders/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(folder_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(folder_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -162,10 +167,12 @@ impl Folders {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        folder_id: &str,
+        account_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
         body: &crate::types::FoldersRequest,
     ) -> Result<crate::types::FoldersResponse> {
+        let account_id = account_id.as_ref();
+        let folder_id = folder_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/folders/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -205,8 +212,8 @@ impl Folders {
     */
     pub async fn search_get_contents(
         &self,
-        account_id: &str,
-        search_folder_id: &str,
+        account_id: impl AsRef<str>,
+        search_folder_id: impl AsRef<str>,
         all: &str,
         count: &str,
         from_date: &str,
@@ -216,40 +223,40 @@ impl Folders {
         start_position: &str,
         to_date: &str,
     ) -> Result<crate::types::FolderItemResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let search_folder_id = search_folder_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !all.is_empty() {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !include_recipients.is_empty() {
-            query_args.push((
-                "include_recipients".to_string(),
-                include_recipients.to_string(),
-            ));
+            query_args.push(("include_recipients", include_recipients.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/search_folders/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(search_folder_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/search_folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(search_folder_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
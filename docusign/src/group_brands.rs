@@ -26,9 +26,11 @@ impl GroupBrands {
     */
     pub async fn brands_get_group(
         &self,
-        account_id: &str,
-        group_id: &str,
+        account_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
     ) -> Result<crate::types::GroupBrands> {
+        let account_id = account_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/brands",
             crate::progenitor_support::encode_path(account_id),
@@ -52,10 +54,12 @@ impl GroupBrands {
     */
     pub async fn brands_put_group(
         &self,
-        account_id: &str,
-        group_id: &str,
+        account_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
         body: &crate::types::BrandsRequest,
     ) -> Result<crate::types::GroupBrands> {
+        let account_id = account_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/brands",
             crate::progenitor_support::encode_path(account_id),
@@ -81,10 +85,12 @@ impl GroupBrands {
     */
     pub async fn brands_delete_group(
         &self,
-        account_id: &str,
-        group_id: &str,
+        account_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
         body: &crate::types::BrandsRequest,
     ) -> Result<crate::types::GroupBrands> {
+        let account_id = account_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/brands",
             crate::progenitor_support::encode_path(account_id),
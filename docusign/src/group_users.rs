@@ -28,24 +28,27 @@ impl GroupUsers {
     */
     pub async fn groups_get(
         &self,
-        account_id: &str,
-        group_id: &str,
+        account_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::UsersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/groups/{}/users?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/groups/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -65,10 +68,12 @@ impl GroupUsers {
     */
     pub async fn groups_put(
         &self,
-        account_id: &str,
-        group_id: &str,
+        account_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
         body: &crate::types::UserInfoList,
     ) -> Result<crate::types::UsersResponse> {
+        let account_id = account_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/users",
             crate::progenitor_support::encode_path(account_id),
@@ -95,10 +100,12 @@ impl GroupUsers {
     */
     pub async fn groups_delete(
         &self,
-        account_id: &str,
-        group_id: &str,
+        account_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
         body: &crate::types::UserInfoList,
     ) -> Result<crate::types::UsersResponse> {
+        let account_id = account_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/users",
             crate::progenitor_support::encode_path(account_id),
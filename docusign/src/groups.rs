@@ -30,37 +30,36 @@ impl Groups {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         count: &str,
         group_type: &str,
         include_usercount: &str,
         search_text: &str,
         start_position: &str,
     ) -> Result<crate::types::GroupInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !group_type.is_empty() {
-            query_args.push(("group_type".to_string(), group_type.to_string()));
+            query_args.push(("group_type", group_type.to_string()));
         }
         if !include_usercount.is_empty() {
-            query_args.push((
-                "include_usercount".to_string(),
-                include_usercount.to_string(),
-            ));
+            query_args.push(("include_usercount", include_usercount.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/groups?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/groups",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -79,9 +78,10 @@ impl Groups {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::GroupInformation,
     ) -> Result<crate::types::GroupInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups",
             crate::progenitor_support::encode_path(account_id),
@@ -107,9 +107,10 @@ impl Groups {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::GroupInformation,
     ) -> Result<crate::types::GroupInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups",
             crate::progenitor_support::encode_path(account_id),
@@ -147,9 +148,10 @@ impl Groups {
     */
     pub async fn delete(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::GroupInformation,
     ) -> Result<crate::types::GroupInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/groups",
             crate::progenitor_support::encode_path(account_id),
@@ -27,8 +27,9 @@ impl IdentityVerifications {
     */
     pub async fn account_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountIdentityVerificationResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/identity_verification",
             crate::progenitor_support::encode_path(account_id),
@@ -29,22 +29,24 @@ impl Invoices {
     */
     pub async fn billing_get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::BillingInvoicesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_invoices?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/billing_invoices",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -97,9 +99,11 @@ impl Invoices {
     */
     pub async fn billing_get_invoices(
         &self,
-        account_id: &str,
-        invoice_id: &str,
+        account_id: impl AsRef<str>,
+        invoice_id: impl AsRef<str>,
     ) -> Result<crate::types::BillingInvoice> {
+        let account_id = account_id.as_ref();
+        let invoice_id = invoice_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_invoices/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -124,8 +128,9 @@ impl Invoices {
     */
     pub async fn billing_get_past_due(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::BillingInvoicesSummary> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_invoices_past_due",
             crate::progenitor_support::encode_path(account_id),
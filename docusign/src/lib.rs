@@ -204,6 +204,7 @@ pub mod account_watermarks;
 pub mod accounts;
 /// .
 pub mod appliance_info;
+pub mod batch;
 /// You can configure automatic archiving of emails sent from all of your DocuSign accounts.
 ///
 ///For more information, see [Email Archive Configuration](https://support.docusign.com/en/guides/ndse-admin-guide-email-archive-configuration).
@@ -212,6 +213,9 @@ pub mod appliance_info;
 pub mod bcc_email_archive;
 /// The Billing resource provides methods that allow you to manage the billing plans,associated with an account.
 pub mod billing_plans;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
 /// You can use bulk send lists for anything that you need to send to a large number of recipients on a
 ///recurring basis, such as:
 ///
@@ -280,6 +284,7 @@ pub mod billing_plans;
 ///For more information about using bulk send, see [Bulk Sending Envelopes](https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/bulk-send/).
 ///.
 pub mod bulk_send;
+pub mod cache;
 /// A chunked upload is a temporary file that you upload in parts and stage at DocuSign, then refer to as the content for other API calls. For example, you might use it for document content when assembling an envelope or template.
 ///
 ///A chunked upload is linked to the DocuSign account member who initiated the API call. This user is the only user who is able to reference the chunked upload.
@@ -309,6 +314,7 @@ pub mod bulk_send;
 ///- The amount of time that a chunked upload is active after you initialize it. The default value is 20 minutes.
 ///.
 pub mod chunked_uploads;
+pub mod client_config;
 /// The CloudStorage resource provides methods that allow you to list files stored on your cloud storage provider.
 pub mod cloud_storage;
 /// The following providers are supported:
@@ -369,6 +375,7 @@ pub mod e_note_configurations;
 /// The EnvelopeAttachments resource provides methods that allow you to manage attachments.
 ///.
 pub mod envelope_attachments;
+mod envelope_builder;
 /// The `EnvelopeConsumerDisclosures` resource provides methods that enable you to retrieve the Electronic Record and Signature Disclosure (ERSD) for an envelope recipient. This is the disclosure that displays to each new recipient who is going to sign or add other information, or who is required to view the documents you send to them. The recipient must read and agree to the terms of the disclosure before they can access and take action on the documents you send. The ERSD does not apply to copy-only recipients, but does apply to recipients who must sign or view your documents.
 ///
 ///You can retrieve either the default ERSD that DocuSign provides for U.S.-based transactions, or a custom ERSD.
@@ -1396,8 +1403,11 @@ pub mod envelope_workflow_definition;
 pub mod envelopes;
 /// .
 pub mod favorite_templates;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fixtures;
 /// The Folders resource provides methods that allow you to view contents of folders on the account and move envelopes between folders.
 pub mod folders;
+pub mod from_env;
 /// For the custom groups you define for your account, you can assign brands to specify the ones that group members can use. Group members can use the available brands when they send envelopes or create templates. For more information, see [Assign Brands to Groups](https://support.docusign.com/en/guides/ndse-admin-guide-assign-brands-to-groups).
 pub mod group_brands;
 /// The GroupUsers resource provides methods that allow you to manage the users in a group.
@@ -1416,6 +1426,8 @@ pub mod groups;
 pub mod identity_verifications;
 /// The Invoices resource provides methods that allow you to manage the invoices for an account.
 pub mod invoices;
+mod json_stream;
+pub mod metrics;
 /// DocuSign eNotary makes the notarization process fully digital
 ///for senders, signers, and notaries.
 ///It enables a notary public to act as an in-person witness
@@ -1429,6 +1441,8 @@ pub mod notary;
 pub mod notary_journals;
 /// Creating, updating, and deleting notary jurisdiction objects.
 pub mod notary_jurisdiction;
+/// One page of the results of a list endpoint.
+pub mod page;
 /// .
 pub mod payment_gateway_accounts;
 /// The Payments resource provides methods that allow you to manage payments for an account.
@@ -1463,17 +1477,21 @@ pub mod power_form_data;
 ///- `PowerForms_PowerFormId_Mismatch`: A `powerFormId` mismatch has occurred.
 ///.
 pub mod power_forms;
+pub mod redact;
 /// .
 pub mod reports;
 /// The RequestLogs resource provide methods that allow you to retrieve and delete the API request log files.
 ///
 ///The log files contain the API requests associated with your integration. They can aid you in troubleshooting specific issues within an integration, or if DocuSign Support requests an API trace log.
 pub mod request_logs;
+pub mod request_options;
 /// The Resources resource provides a method which retrieves the base resources that are available.
 pub mod resources;
 /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
 ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
 pub mod responsive_html_preview;
+pub mod retry;
+mod runtime;
 /// The Services resource provides a method that allow you to retrieve the available service versions.
 pub mod services;
 /// The SigningGroupUsers resource provides methods that allow you to manage users in Signing Groups.
@@ -1493,6 +1511,7 @@ pub mod signing_group_users;
 ///
 ///For more information, see [Signing Groups](https://support.docusign.com/en/guides/ndse-user-guide-signing-groups).
 pub mod signing_groups;
+pub mod single_flight;
 /// .
 pub mod tabs_blob;
 /// The TemplateBulkRecipients resource provide methods that allow you manage the bulk recipient file for an template.
@@ -2133,6 +2152,9 @@ pub mod template_views;
 pub mod templates;
 #[cfg(test)]
 mod tests;
+pub mod token_store;
+pub mod trace;
+pub mod transport;
 pub mod types;
 /// The UserCustomSettings resource provides methods that allow you to manage the custom settings for a user.
 ///
@@ -2187,6 +2209,8 @@ use anyhow::{anyhow, Error, Result};
 pub const DEFAULT_HOST: &str = "https://na4.docusign.net";
 
 mod progenitor_support {
+    use std::fmt::{self, Write};
+
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
     const PATH_SET: &AsciiSet = &CONTROLS
@@ -2200,9 +2224,80 @@ mod progenitor_support {
         .add(b'{')
         .add(b'}');
 
+    /// A path component, percent-encoded as it is formatted.
+    struct EncodePath<'a, T: ?Sized>(&'a T);
+
+    impl<T: fmt::Display + ?Sized> fmt::Display for EncodePath<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            struct Encoder<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+            impl Write for Encoder<'_, '_> {
+                fn write_str(&mut self, s: &str) -> fmt::Result {
+                    write!(self.0, "{}", utf8_percent_encode(s, PATH_SET))
+                }
+            }
+
+            write!(Encoder(f), "{}", self.0)
+        }
+    }
+
+    /// Percent-encode a path component, a `&str` or any value that implements
+    /// `Display`, without copying it into a `String` first.
+    #[allow(dead_code)]
+    pub(crate) fn encode_path<T: fmt::Display + ?Sized>(pc: &T) -> impl fmt::Display + '_ {
+        EncodePath(pc)
+    }
+
+    /// Write a path and its percent-encoded query parameters into a single `String`.
     #[allow(dead_code)]
-    pub(crate) fn encode_path(pc: &str) -> String {
-        utf8_percent_encode(pc, PATH_SET).to_string()
+    pub(crate) fn build_url(path: fmt::Arguments<'_>, query_args: &[(&str, String)]) -> String {
+        let mut url = String::new();
+        url.write_fmt(path).unwrap();
+        push_query(
+            &mut url,
+            query_args
+                .iter()
+                .map(|(name, value)| (*name, value.as_str())),
+        );
+        url
+    }
+
+    /// Add percent-encoded query parameters to a URL that may already have a query
+    /// string.
+    #[allow(dead_code)]
+    pub(crate) fn append_query(url: &str, query_args: &[(&str, &str)]) -> String {
+        let mut url = url.to_string();
+        push_query(&mut url, query_args.iter().copied());
+        url
+    }
+
+    fn push_query<'a, I>(url: &mut String, query_args: I)
+    where
+        I: Iterator<Item = (&'a str, &'a str)>,
+    {
+        for (name, value) in query_args {
+            if !url.contains('?') {
+                url.push('?');
+            } else if !url.ends_with('?') && !url.ends_with('&') {
+                url.push('&');
+            }
+            push_form_encoded(url, name);
+            url.push('=');
+            push_form_encoded(url, value);
+        }
+    }
+
+    /// Encode as `application/x-www-form-urlencoded`, like `serde_urlencoded` does.
+    fn push_form_encoded(url: &mut String, s: &str) {
+        for b in s.bytes() {
+            match b {
+                b'*' | b'-' | b'.' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' => {
+                    url.push(b as char)
+                }
+                b' ' => url.push('+'),
+                _ => write!(url, "%{:02X}", b).unwrap(),
+            }
+        }
     }
 }
 
@@ -2210,12 +2305,49 @@ use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use tokio::sync::RwLock;
+use web_time::Instant;
 
 const TOKEN_ENDPOINT: &str = "https://account.docusign.com/oauth/token";
 const USER_CONSENT_ENDPOINT: &str = "https://account.docusign.com/oauth/auth";
 
+const SANDBOX_HOST: &str = "https://demo.docusign.net";
+const SANDBOX_TOKEN_ENDPOINT: &str = "https://account-d.docusign.com/oauth/token";
+const SANDBOX_USER_CONSENT_ENDPOINT: &str = "https://account-d.docusign.com/oauth/auth";
+
+/// The environment a client makes its requests to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Production,
+    /// The sandbox, to try the API out on data kept apart from production.
+    Sandbox,
+}
+
+impl Environment {
+    /// The host of the API in the environment.
+    pub fn host(&self) -> &'static str {
+        match self {
+            Environment::Production => DEFAULT_HOST,
+            Environment::Sandbox => SANDBOX_HOST,
+        }
+    }
+
+    fn token_endpoint(&self) -> &'static str {
+        match self {
+            Environment::Production => TOKEN_ENDPOINT,
+            Environment::Sandbox => SANDBOX_TOKEN_ENDPOINT,
+        }
+    }
+
+    fn user_consent_endpoint(&self) -> &'static str {
+        match self {
+            Environment::Production => USER_CONSENT_ENDPOINT,
+            Environment::Sandbox => SANDBOX_USER_CONSENT_ENDPOINT,
+        }
+    }
+}
+
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
@@ -2226,13 +2358,17 @@ pub struct Client {
     redirect_uri: String,
 
     auto_refresh: bool,
+    on_token_refresh: Option<Arc<dyn Fn(&AccessToken) + Send + Sync>>,
+    token_store: Option<Arc<dyn crate::token_store::TokenStore>>,
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
+    environment: Environment,
 }
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+#[derive(JsonSchema, Clone, Default, Serialize, Deserialize)]
 pub struct AccessToken {
     #[serde(
         default,
@@ -2267,12 +2403,26 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+// The tokens are redacted, so that access tokens can be logged.
+impl std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("token_type", &self.token_type)
+            .field("access_token", &crate::redact::Secret(&self.access_token))
+            .field("expires_in", &self.expires_in)
+            .field("refresh_token", &crate::redact::Secret(&self.refresh_token))
+            .field("refresh_token_expires_in", &self.refresh_token_expires_in)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
 const REFRESH_THRESHOLD: Duration = Duration::from_secs(60);
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct InnerToken {
     access_token: String,
     refresh_token: String,
@@ -2298,18 +2448,17 @@ impl Client {
         Q: ToString,
     {
         // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
         let client = reqwest::Client::builder().build();
         match client {
             Ok(c) => {
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware)
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-                        retry_policy,
-                    ))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
 
                 Client {
@@ -2324,7 +2473,11 @@ impl Client {
                     })),
 
                     auto_refresh: false,
+                    on_token_refresh: None,
+                    token_store: None,
                     client,
+                    request_options: Default::default(),
+                    environment: Environment::Production,
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -2337,6 +2490,24 @@ impl Client {
         self
     }
 
+    /// Sets a callback that is invoked with the new `AccessToken` every time the client
+    /// fetches or refreshes an access token. This can be used to persist the tokens.
+    pub fn set_token_refresh_callback<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&AccessToken) + Send + Sync + 'static,
+    {
+        self.on_token_refresh = Some(Arc::new(callback));
+        self
+    }
+
+    /// Keep the tokens in the given store, shared with the other clients using it.
+    /// See the `token_store` module.
+    pub fn with_token_store(&self, store: Arc<dyn crate::token_store::TokenStore>) -> Self {
+        let mut c = self.clone();
+        c.token_store = Some(store);
+        c
+    }
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -2401,6 +2572,55 @@ impl Client {
         c
     }
 
+    /// Make the requests to the given environment, and get the access tokens from it
+    /// as well. This replaces a host set with `with_host`.
+    pub fn with_environment(&self, environment: Environment) -> Self {
+        let mut c = self.clone();
+        c.host = environment.host().to_string();
+        c.environment = environment;
+        c
+    }
+
+    /// Override the HTTP client used to make requests. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics. The default client traces and
+    /// retries requests, add the same middleware to keep that behavior.
+    pub fn with_http_client(&self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        let mut c = self.clone();
+        c.client = client;
+        c
+    }
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client set
+    /// with `with_http_client`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }
+
+    /// Send a request, applying the request options of the client.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        self.request_options.execute(&self.client, req).await
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -2424,29 +2644,94 @@ impl Client {
     /// Return a user consent url with an optional set of scopes.
     /// If no scopes are provided, they will not be passed in the url.
     pub fn user_consent_url(&self, scopes: &[String]) -> String {
-        let state = uuid::Uuid::new_v4();
-
-        let url = format!(
-            "{}?client_id={}&response_type=code&redirect_uri={}&state={}",
-            USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
-        );
+        let state = uuid::Uuid::new_v4().to_string();
+        let scope = scopes.join(" ");
+
+        let mut query_args = vec![
+            ("client_id", self.client_id.as_str()),
+            ("response_type", "code"),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("state", state.as_str()),
+        ];
 
-        if scopes.is_empty() {
-            return url;
+        // Add the scopes.
+        if !scopes.is_empty() {
+            query_args.push(("scope", scope.as_str()));
         }
 
-        // Add the scopes.
-        format!("{}&scope={}", url, scopes.join(" "))
+        crate::progenitor_support::append_query(
+            self.environment.user_consent_endpoint(),
+            &query_args,
+        )
     }
 
     /// Refresh an access token from a refresh token. Client must have a refresh token
     /// for this to work.
     pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+        let store = match &self.token_store {
+            Some(store) => store,
+            None => return self.fetch_refreshed_access_token().await,
+        };
+
+        store.lock_refresh().await?;
+        let t = self.refresh_stored_access_token(store.as_ref()).await;
+        store.unlock_refresh().await?;
+        t
+    }
+
+    async fn refresh_stored_access_token(
+        &self,
+        store: &dyn crate::token_store::TokenStore,
+    ) -> Result<AccessToken> {
+        if let Some(stored) = store.get().await? {
+            // Another client refreshed the token while this one waited for the lock.
+            let expired = stored
+                .expires_in()
+                .map(|e| e <= REFRESH_THRESHOLD.as_secs() as i64);
+            if stored.access_token != self.token.read().await.access_token && expired != Some(true)
+            {
+                self.set_stored_token(&stored).await;
+                return Ok(stored.to_access_token());
+            }
+
+            // Providers that rotate refresh tokens revoke the ones that were used, refresh
+            // from the last one.
+            if !stored.refresh_token.is_empty() {
+                self.token.write().await.refresh_token = stored.refresh_token;
+            }
+        }
+
+        let t = self.fetch_refreshed_access_token().await?;
+        store
+            .put(&crate::token_store::StoredToken::from_access_token(&t))
+            .await?;
+        Ok(t)
+    }
+
+    async fn load_stored_token(&self) -> Result<()> {
+        if let Some(store) = &self.token_store {
+            if let Some(stored) = store.get().await? {
+                self.set_stored_token(&stored).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set_stored_token(&self, stored: &crate::token_store::StoredToken) {
+        *self.token.write().await = InnerToken {
+            access_token: stored.access_token.clone(),
+            refresh_token: stored.refresh_token.clone(),
+            expires_at: stored.expires_in().and_then(Self::compute_expires_at),
+        };
+    }
+
+    async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
             if refresh_token.is_empty() {
-                anyhow!("refresh token cannot be empty");
+                return Err(anyhow!("refresh token cannot be empty"));
             }
 
             let mut headers = reqwest::header::HeaderMap::new();
@@ -2462,9 +2747,8 @@ impl Client {
                 ("client_secret", &self.client_secret),
                 ("redirect_uri", &self.redirect_uri),
             ];
-            let client = reqwest::Client::new();
-            client
-                .post(TOKEN_ENDPOINT)
+            self.client
+                .post(self.environment.token_endpoint())
                 .headers(headers)
                 .form(&params)
                 .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -2473,16 +2757,37 @@ impl Client {
         };
 
         // Unwrap the response.
-        let t: AccessToken = response.json().await?;
+        let status = response.status();
+        let body = response.bytes().await?;
+        if !status.is_success() {
+            return Err(if body.is_empty() {
+                anyhow!("code: {}, empty response", status)
+            } else {
+                anyhow!(
+                    "code: {}, error: {:?}",
+                    status,
+                    String::from_utf8_lossy(&body),
+                )
+            });
+        }
+        let mut t: AccessToken = serde_json::from_slice(&body)?;
 
-        let refresh_token = self.token.read().await.refresh_token.clone();
+        // Some providers rotate the refresh token on every refresh, keep the one we
+        // have if a new one was not returned.
+        if t.refresh_token.is_empty() {
+            t.refresh_token = self.token.read().await.refresh_token.clone();
+        }
 
         *self.token.write().await = InnerToken {
             access_token: t.access_token.clone(),
-            refresh_token,
+            refresh_token: t.refresh_token.clone(),
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        if let Some(callback) = &self.on_token_refresh {
+            callback(&t);
+        }
+
         Ok(t)
     }
 
@@ -2503,9 +2808,9 @@ impl Client {
             ("redirect_uri", &self.redirect_uri),
             ("state", state),
         ];
-        let client = reqwest::Client::new();
-        let resp = client
-            .post(TOKEN_ENDPOINT)
+        let resp = self
+            .client
+            .post(self.environment.token_endpoint())
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -2513,7 +2818,20 @@ impl Client {
             .await?;
 
         // Unwrap the response.
-        let t: AccessToken = resp.json().await?;
+        let status = resp.status();
+        let body = resp.bytes().await?;
+        if !status.is_success() {
+            return Err(if body.is_empty() {
+                anyhow!("code: {}, empty response", status)
+            } else {
+                anyhow!(
+                    "code: {}, error: {:?}",
+                    status,
+                    String::from_utf8_lossy(&body),
+                )
+            });
+        }
+        let t: AccessToken = serde_json::from_slice(&body)?;
 
         *self.token.write().await = InnerToken {
             access_token: t.access_token.clone(),
@@ -2521,6 +2839,16 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        if let Some(store) = &self.token_store {
+            store
+                .put(&crate::token_store::StoredToken::from_access_token(&t))
+                .await?;
+        }
+
+        if let Some(callback) = &self.on_token_refresh {
+            callback(&t);
+        }
+
         Ok(t)
     }
 
@@ -2563,10 +2891,11 @@ impl Client {
         }
 
         if let Some(body) = body {
-            log::debug!(
-                "body: {:?}",
-                String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap()
-            );
+            // The bodies of these requests are in memory, uploads stream theirs separately.
+            let bytes = body
+                .as_bytes()
+                .ok_or_else(|| anyhow!("the body of the request must be in memory"))?;
+            log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
 
             req = req.body(body);
         }
@@ -2580,37 +2909,52 @@ impl Client {
         uri: &str,
         body: Option<reqwest::Body>,
     ) -> Result<reqwest::Response> {
+        // Start from the token of the store when the client was created without one.
+        if self.token_store.is_some() && self.token.read().await.access_token.is_empty() {
+            self.load_stored_token().await?;
+        }
+
+        // We have a known expired token, there is no point in trying to make a request
+        // without refreshing it first.
+        let refreshed = self.auto_refresh && self.is_expired().await == Some(true);
+        if refreshed {
+            self.refresh_access_token().await?;
+        }
+
         let req = self.make_request(&method, uri, body).await?;
+        if !self.auto_refresh || refreshed {
+            return self.execute(req).await;
+        }
 
-        let resp = if self.auto_refresh {
-            let expired = self.is_expired().await;
+        // We either have a (theoretically) known good token or we do not know the expiration
+        // of the token at all. We make an optimistic attempt at the request, keeping a copy of
+        // it around so it can be replayed if the provider rejects the token. Streaming bodies
+        // can not be copied, in which case the failure is deferred to the caller.
+        let retry = req.try_clone();
+        let resp = self.execute(req).await?;
+
+        if resp.status() != http::StatusCode::UNAUTHORIZED
+            || self.token.read().await.refresh_token.is_empty()
+        {
+            return Ok(resp);
+        }
+
+        match retry {
+            Some(mut retry) => {
+                self.refresh_access_token().await?;
 
-            match expired {
-                // We have a known expired token, there is no point in trying to make a request
-                // without refreshing it first
-                Some(true) => {
-                    self.refresh_access_token().await?;
-                    self.client.execute(req).await?
+                let (_, auth) = self.url_and_auth(retry.url().as_str()).await?;
+                if let Some(auth_str) = auth {
+                    retry.headers_mut().insert(
+                        http::header::AUTHORIZATION,
+                        reqwest::header::HeaderValue::from_str(&auth_str)?,
+                    );
                 }
-                // We have a (theoretically) known good token available. We make an optimistic
-                // attempting at the request. If the token is no longer good, then something other
-                // than the expiration is triggering the failure. We defer handling of these errors
-                // to the caller
-                Some(false) => self.client.execute(req).await?,
-
-                // We do not know what state we are in. We could have a valid or expired token.
-                // Generally this means we are in one of two cases:
-                //   1. We have not yet performed a token refresh (and do not know the expiration
-                //      of the user provided token)
-                //   2. The provider is returning unusable expiration times, at which point we
-                //      choose to ignore them
-                None => self.client.execute(req).await?,
-            }
-        } else {
-            self.client.execute(req).await?
-        };
 
-        Ok(resp)
+                self.execute(retry).await
+            }
+            None => Ok(resp),
+        }
     }
 
     async fn request<Out>(
@@ -2707,6 +3051,17 @@ impl Client {
     /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.post_multipart(uri, form).await
+    }
+
+    /// Send a `multipart/form-data` `POST` request, for example to upload a file
+    /// along with other fields. The content type, with the boundary of the parts,
+    /// is set from the form.
+    #[allow(dead_code)]
+    async fn post_multipart<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -2721,15 +3076,10 @@ impl Client {
 
         let mut req = instance.client.request(http::Method::POST, url);
 
-        // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
             reqwest::header::HeaderValue::from_static("application/json"),
         );
-        req = req.header(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
 
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
@@ -2738,7 +3088,7 @@ impl Client {
         log::debug!("form: {:?}", form);
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -2809,7 +3159,7 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -2901,7 +3251,7 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -2966,6 +3316,41 @@ impl Client {
         self.unfold(uri).await
     }
 
+    /// Stream the items of the JSON array in the response to a `GET`, parsing each of
+    /// them as soon as it downloaded rather than once the whole body is in memory. The
+    /// array is either the body itself, or the first field of the body holding an
+    /// array, like the `data` of a page of results.
+    pub async fn get_stream_items<T>(
+        &self,
+        uri: &str,
+    ) -> Result<impl futures::Stream<Item = Result<T>>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self
+            .streaming()
+            .request_raw(http::Method::GET, &(self.host.to_string() + uri), None)
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let response_body = response.bytes().await?;
+            let error = if response_body.is_empty() {
+                anyhow!("code: {}, empty response", status)
+            } else {
+                anyhow!(
+                    "code: {}, error: {:?}",
+                    status,
+                    String::from_utf8_lossy(&response_body),
+                )
+            };
+
+            return Err(error);
+        }
+
+        Ok(crate::json_stream::items(response))
+    }
+
     /// "unfold" paginated results of a vector of items
     #[allow(dead_code)]
     async fn unfold<D>(&self, uri: &str) -> Result<Vec<D>>
@@ -5414,3 +5799,70 @@ impl Client {
         connect_secret::ConnectSecret::new(self.clone())
     }
 }
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("Client");
+        d.field("host", &self.host)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &crate::redact::Secret(&self.client_secret))
+            .field("redirect_uri", &self.redirect_uri);
+        // Rather than waiting for a refresh of the token to complete, leave it out.
+        match self.token.try_read() {
+            Ok(token) => d.field("token", &*token),
+            Err(_) => d.field("token", &format_args!("<refreshing>")),
+        };
+        d.field("auto_refresh", &self.auto_refresh)
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for InnerToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerToken")
+            .field("access_token", &crate::redact::Secret(&self.access_token))
+            .field("refresh_token", &crate::redact::Secret(&self.refresh_token))
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Create a client from the environment variables:
+///
+/// - `DOCUSIGN_CLIENT_ID` and `DOCUSIGN_CLIENT_SECRET`, the app.
+/// - `DOCUSIGN_REDIRECT_URI`, `DOCUSIGN_TOKEN` and `DOCUSIGN_REFRESH_TOKEN`, when they are set.
+/// - `DOCUSIGN_HOST`, the host to make the requests to, when it is set.
+impl crate::from_env::FromEnv for Client {
+    fn env_vars() -> &'static [&'static str] {
+        &[
+            "DOCUSIGN_CLIENT_ID",
+            "DOCUSIGN_CLIENT_SECRET",
+            "DOCUSIGN_REDIRECT_URI",
+            "DOCUSIGN_TOKEN",
+            "DOCUSIGN_REFRESH_TOKEN",
+            "DOCUSIGN_HOST",
+        ]
+    }
+
+    fn from_env() -> Result<Self> {
+        let refresh_token = crate::from_env::optional("DOCUSIGN_REFRESH_TOKEN");
+        let mut client = Client::new(
+            crate::from_env::required("DOCUSIGN_CLIENT_ID")?,
+            crate::from_env::required("DOCUSIGN_CLIENT_SECRET")?,
+            crate::from_env::optional("DOCUSIGN_REDIRECT_URI"),
+            crate::from_env::optional("DOCUSIGN_TOKEN"),
+            &refresh_token,
+        );
+        // With a refresh token, the client gets a new access token when it expires.
+        if !refresh_token.is_empty() {
+            client.set_auto_access_token_refresh(true);
+        }
+
+        let host = crate::from_env::optional("DOCUSIGN_HOST");
+        if !host.is_empty() {
+            client = client.with_host(host);
+        }
+        Ok(client)
+    }
+}
@@ -0,0 +1,340 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: docusign::Client) {
+//! use docusign::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "DocuSign";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/service_information",
+    "/v2.1",
+    "/v2.1/accounts",
+    "/v2.1/accounts/provisioning",
+    "/v2.1/accounts/{accountId}",
+    "/v2.1/accounts/{accountId}/billing_charges",
+    "/v2.1/accounts/{accountId}/billing_invoices",
+    "/v2.1/accounts/{accountId}/billing_invoices/{invoiceId}",
+    "/v2.1/accounts/{accountId}/billing_invoices_past_due",
+    "/v2.1/accounts/{accountId}/billing_payments",
+    "/v2.1/accounts/{accountId}/billing_payments/{paymentId}",
+    "/v2.1/accounts/{accountId}/billing_plan",
+    "/v2.1/accounts/{accountId}/billing_plan/credit_card",
+    "/v2.1/accounts/{accountId}/billing_plan/downgrade",
+    "/v2.1/accounts/{accountId}/billing_plan/purchased_envelopes",
+    "/v2.1/accounts/{accountId}/brands",
+    "/v2.1/accounts/{accountId}/brands/{brandId}",
+    "/v2.1/accounts/{accountId}/brands/{brandId}/file",
+    "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+    "/v2.1/accounts/{accountId}/brands/{brandId}/resources",
+    "/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}",
+    "/v2.1/accounts/{accountId}/bulk_send_batch",
+    "/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}",
+    "/v2.1/accounts/{accountId}/bulk_send_lists",
+    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}",
+    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/send",
+    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/test",
+    "/v2.1/accounts/{accountId}/captive_recipients/{recipientPart}",
+    "/v2.1/accounts/{accountId}/chunked_uploads",
+    "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}",
+    "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}/{chunkedUploadPartSeq}",
+    "/v2.1/accounts/{accountId}/connect",
+    "/v2.1/accounts/{accountId}/connect/envelopes/retry_queue",
+    "/v2.1/accounts/{accountId}/connect/envelopes/{envelopeId}/retry_queue",
+    "/v2.1/accounts/{accountId}/connect/failures",
+    "/v2.1/accounts/{accountId}/connect/failures/{failureId}",
+    "/v2.1/accounts/{accountId}/connect/logs",
+    "/v2.1/accounts/{accountId}/connect/logs/{logId}",
+    "/v2.1/accounts/{accountId}/connect/secret/{keyId}",
+    "/v2.1/accounts/{accountId}/connect/{connectId}",
+    "/v2.1/accounts/{accountId}/connect/{connectId}/users",
+    "/v2.1/accounts/{accountId}/consumer_disclosure",
+    "/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}",
+    "/v2.1/accounts/{accountId}/contacts",
+    "/v2.1/accounts/{accountId}/contacts/{contactId}",
+    "/v2.1/accounts/{accountId}/custom_fields",
+    "/v2.1/accounts/{accountId}/custom_fields/{customFieldId}",
+    "/v2.1/accounts/{accountId}/display_appliance_info/dynamicsystemsettings",
+    "/v2.1/accounts/{accountId}/display_appliance_info/templateInfo",
+    "/v2.1/accounts/{accountId}/envelopes",
+    "/v2.1/accounts/{accountId}/envelopes/status",
+    "/v2.1/accounts/{accountId}/envelopes/transfer_rules",
+    "/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/audit_events",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/account_info",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields/delete",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/date_signed",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document_page_list",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/image",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/localepolicy/{userId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info/delete",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf/{pdfId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/html_definitions",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/tabs",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/responsive_html_preview",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/form_data",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/html_definitions",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/document_visibility",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure/{langCode}",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/identity_proof_token",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/views/identity_manual_review",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/responsive_html_preview",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/edit",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient_preview",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/sender",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/shared",
+    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+    "/v2.1/accounts/{accountId}/favorite_templates",
+    "/v2.1/accounts/{accountId}/folders",
+    "/v2.1/accounts/{accountId}/folders/{folderId}",
+    "/v2.1/accounts/{accountId}/groups",
+    "/v2.1/accounts/{accountId}/groups/{groupId}/brands",
+    "/v2.1/accounts/{accountId}/groups/{groupId}/users",
+    "/v2.1/accounts/{accountId}/identity_verification",
+    "/v2.1/accounts/{accountId}/payment_gateway_accounts",
+    "/v2.1/accounts/{accountId}/permission_profiles",
+    "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+    "/v2.1/accounts/{accountId}/powerforms",
+    "/v2.1/accounts/{accountId}/powerforms/senders",
+    "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+    "/v2.1/accounts/{accountId}/powerforms/{powerFormId}/form_data",
+    "/v2.1/accounts/{accountId}/recipient_names",
+    "/v2.1/accounts/{accountId}/reports",
+    "/v2.1/accounts/{accountId}/reports/report_results",
+    "/v2.1/accounts/{accountId}/reports/report_results_csv",
+    "/v2.1/accounts/{accountId}/reports/{id}",
+    "/v2.1/accounts/{accountId}/seals",
+    "/v2.1/accounts/{accountId}/search_folders/{searchFolderId}",
+    "/v2.1/accounts/{accountId}/settings",
+    "/v2.1/accounts/{accountId}/settings/bcc_email_archives",
+    "/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}",
+    "/v2.1/accounts/{accountId}/settings/enote_configuration",
+    "/v2.1/accounts/{accountId}/settings/envelope_purge_configuration",
+    "/v2.1/accounts/{accountId}/settings/notification_defaults",
+    "/v2.1/accounts/{accountId}/settings/password_rules",
+    "/v2.1/accounts/{accountId}/settings/tabs",
+    "/v2.1/accounts/{accountId}/shared_access",
+    "/v2.1/accounts/{accountId}/signatureProviders",
+    "/v2.1/accounts/{accountId}/signatures",
+    "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+    "/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}",
+    "/v2.1/accounts/{accountId}/signing_groups",
+    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}",
+    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users",
+    "/v2.1/accounts/{accountId}/supported_languages",
+    "/v2.1/accounts/{accountId}/tab_definitions",
+    "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+    "/v2.1/accounts/{accountId}/templates",
+    "/v2.1/accounts/{accountId}/templates/{templateId}",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/html_definitions",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/tabs",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/responsive_html_preview",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/html_definitions",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/lock",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/notification",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/document_visibility",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/responsive_html_preview",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/views/edit",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/views/recipient_preview",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+    "/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}",
+    "/v2.1/accounts/{accountId}/unsupported_file_types",
+    "/v2.1/accounts/{accountId}/users",
+    "/v2.1/accounts/{accountId}/users/{userId}",
+    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage",
+    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}",
+    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders",
+    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders/{folderId}",
+    "/v2.1/accounts/{accountId}/users/{userId}/custom_settings",
+    "/v2.1/accounts/{accountId}/users/{userId}/profile",
+    "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+    "/v2.1/accounts/{accountId}/users/{userId}/settings",
+    "/v2.1/accounts/{accountId}/users/{userId}/signatures",
+    "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+    "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}",
+    "/v2.1/accounts/{accountId}/views/console",
+    "/v2.1/accounts/{accountId}/watermark",
+    "/v2.1/accounts/{accountId}/watermark/preview",
+    "/v2.1/accounts/{accountId}/workspaces",
+    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}",
+    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}",
+    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files",
+    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}",
+    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}/pages",
+    "/v2.1/billing_plans",
+    "/v2.1/billing_plans/{billingPlanId}",
+    "/v2.1/current_user/notary",
+    "/v2.1/current_user/notary/journals",
+    "/v2.1/current_user/notary/jurisdictions",
+    "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+    "/v2.1/current_user/password_rules",
+    "/v2.1/diagnostics/request_logs",
+    "/v2.1/diagnostics/request_logs/{requestLogId}",
+    "/v2.1/diagnostics/settings",
+    "/v2.1/display_appliance_info/error",
+    "/v2.1/display_appliance_info/redeem",
+    "/v2.1/organization_exports/{organizationId}/account_settings/{resultId}",
+];
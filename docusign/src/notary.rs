@@ -25,15 +25,14 @@ impl Notary {
     * * `include_jurisdictions: &str` -- If **true**, the response will include a `jurisdiction` property that contains an array of all supported jurisdictions for the current user.
     */
     pub async fn get(&self, include_jurisdictions: &str) -> Result<crate::types::NotaryResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_jurisdictions.is_empty() {
-            query_args.push((
-                "include_jurisdictions".to_string(),
-                include_jurisdictions.to_string(),
-            ));
+            query_args.push(("include_jurisdictions", include_jurisdictions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2.1/current_user/notary?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v2.1/current_user/notary"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -87,8 +87,9 @@ impl NotaryJurisdiction {
     */
     pub async fn s_get_jurisdiction(
         &self,
-        jurisdiction_id: &str,
+        jurisdiction_id: impl AsRef<str>,
     ) -> Result<crate::types::NotaryJurisdictionData> {
+        let jurisdiction_id = jurisdiction_id.as_ref();
         let url = format!(
             "/v2.1/current_user/notary/jurisdictions/{}",
             crate::progenitor_support::encode_path(jurisdiction_id),
@@ -196,9 +197,10 @@ impl NotaryJurisdiction {
     */
     pub async fn s_put_jurisdiction(
         &self,
-        jurisdiction_id: &str,
+        jurisdiction_id: impl AsRef<str>,
         body: &crate::types::NotaryJurisdictionData,
     ) -> Result<crate::types::NotaryJurisdictionData> {
+        let jurisdiction_id = jurisdiction_id.as_ref();
         let url = format!(
             "/v2.1/current_user/notary/jurisdictions/{}",
             crate::progenitor_support::encode_path(jurisdiction_id),
@@ -248,7 +250,8 @@ impl NotaryJurisdiction {
     *   -  `62 - Florida Commissioner of Deeds`
     *   .
     */
-    pub async fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> Result<()> {
+    pub async fn s_delete_jurisdiction(&self, jurisdiction_id: impl AsRef<str>) -> Result<()> {
+        let jurisdiction_id = jurisdiction_id.as_ref();
         let url = format!(
             "/v2.1/current_user/notary/jurisdictions/{}",
             crate::progenitor_support::encode_path(jurisdiction_id),
@@ -25,8 +25,9 @@ impl PaymentGatewayAccounts {
     */
     pub async fn get_all(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::PaymentGatewayAccountsInfo> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/payment_gateway_accounts",
             crate::progenitor_support::encode_path(account_id),
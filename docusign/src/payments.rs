@@ -29,22 +29,24 @@ impl Payments {
     */
     pub async fn billing_get_list(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::BillingPaymentsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_payments?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/billing_payments",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -70,9 +72,10 @@ impl Payments {
     */
     pub async fn billing_post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::BillingPaymentRequest,
     ) -> Result<crate::types::BillingPaymentResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_payments",
             crate::progenitor_support::encode_path(account_id),
@@ -99,9 +102,11 @@ impl Payments {
     */
     pub async fn billing_get(
         &self,
-        account_id: &str,
-        payment_id: &str,
+        account_id: impl AsRef<str>,
+        payment_id: impl AsRef<str>,
     ) -> Result<crate::types::BillingPaymentItem> {
+        let account_id = account_id.as_ref();
+        let payment_id = payment_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/billing_payments/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -45,28 +45,31 @@ impl PowerFormData {
     */
     pub async fn power_forms_get_form_data(
         &self,
-        account_id: &str,
-        power_form_id: &str,
+        account_id: impl AsRef<str>,
+        power_form_id: impl AsRef<str>,
         data_layout: &str,
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::PowerFormsFormDataResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let power_form_id = power_form_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !data_layout.is_empty() {
-            query_args.push(("data_layout".to_string(), data_layout.to_string()));
+            query_args.push(("data_layout", data_layout.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/powerforms/{}/form_data?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(power_form_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/powerforms/{}/form_data",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(power_form_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
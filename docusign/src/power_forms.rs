@@ -44,30 +44,32 @@ impl PowerForms {
     */
     pub async fn get_list(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         from_date: &str,
         order: &str,
         order_by: &str,
         to_date: &str,
     ) -> Result<crate::types::PowerFormsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/powerforms?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/powerforms",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -125,9 +127,10 @@ impl PowerForms {
     */
     pub async fn post_form(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::PowerForm,
     ) -> Result<crate::types::PowerForm> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/powerforms",
             crate::progenitor_support::encode_path(account_id),
@@ -151,9 +154,10 @@ impl PowerForms {
     */
     pub async fn delete_list(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::PowerFormsRequest,
     ) -> Result<crate::types::PowerFormsResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/powerforms",
             crate::progenitor_support::encode_path(account_id),
@@ -178,18 +182,20 @@ impl PowerForms {
     */
     pub async fn get_senders(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         start_position: &str,
     ) -> Result<crate::types::PowerFormSendersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/powerforms/senders?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/powerforms/senders",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -209,9 +215,11 @@ impl PowerForms {
     */
     pub async fn get_form(
         &self,
-        account_id: &str,
-        power_form_id: &str,
+        account_id: impl AsRef<str>,
+        power_form_id: impl AsRef<str>,
     ) -> Result<crate::types::PowerForm> {
+        let account_id = account_id.as_ref();
+        let power_form_id = power_form_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/powerforms/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -235,10 +243,12 @@ impl PowerForms {
     */
     pub async fn put_form(
         &self,
-        account_id: &str,
-        power_form_id: &str,
+        account_id: impl AsRef<str>,
+        power_form_id: impl AsRef<str>,
         body: &crate::types::PowerForm,
     ) -> Result<crate::types::PowerForm> {
+        let account_id = account_id.as_ref();
+        let power_form_id = power_form_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/powerforms/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -262,7 +272,13 @@ impl PowerForms {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `power_form_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn delete_form(&self, account_id: &str, power_form_id: &str) -> Result<()> {
+    pub async fn delete_form(
+        &self,
+        account_id: impl AsRef<str>,
+        power_form_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let power_form_id = power_form_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/powerforms/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -26,8 +26,9 @@ impl Reports {
     */
     pub async fn product_get_list(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportInProductList> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/reports",
             crate::progenitor_support::encode_path(account_id),
@@ -49,9 +50,10 @@ impl Reports {
     */
     pub async fn product_post_create(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ReportInProductRunRequest,
     ) -> Result<crate::types::ReportInProductSaveResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/reports",
             crate::progenitor_support::encode_path(account_id),
@@ -75,9 +77,10 @@ impl Reports {
     */
     pub async fn product_put_run_results(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ReportInProductRunRequest,
     ) -> Result<crate::types::ReportInProductRunResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/reports/report_results",
             crate::progenitor_support::encode_path(account_id),
@@ -101,9 +104,10 @@ impl Reports {
     */
     pub async fn product_put_results_csv(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::ReportInProductCsvRunRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/reports/report_results_csv",
             crate::progenitor_support::encode_path(account_id),
@@ -128,9 +132,11 @@ impl Reports {
     */
     pub async fn product_get(
         &self,
-        account_id: &str,
-        id: &str,
+        account_id: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::ReportInProductGet> {
+        let account_id = account_id.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/reports/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -154,10 +160,12 @@ impl Reports {
     */
     pub async fn product_put_save(
         &self,
-        account_id: &str,
-        id: &str,
+        account_id: impl AsRef<str>,
+        id: impl AsRef<str>,
         body: &crate::types::ReportInProductRunRequest,
     ) -> Result<crate::types::ReportInProductSaveResponse> {
+        let account_id = account_id.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/reports/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -183,9 +191,11 @@ impl Reports {
     */
     pub async fn product_delete(
         &self,
-        account_id: &str,
-        id: &str,
+        account_id: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::ReportInProductSaveResponse> {
+        let account_id = account_id.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/reports/{}",
             crate::progenitor_support::encode_path(account_id),
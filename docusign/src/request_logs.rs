@@ -28,12 +28,14 @@ impl RequestLogs {
     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
     pub async fn api_get_log(&self, encoding: &str) -> Result<crate::types::ApiRequestLogsResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2.1/diagnostics/request_logs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v2.1/diagnostics/request_logs"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -67,7 +69,8 @@ impl RequestLogs {
     *
     * * `request_log_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn api_get(&self, request_log_id: &str) -> Result<bytes::Bytes> {
+    pub async fn api_get(&self, request_log_id: impl AsRef<str>) -> Result<bytes::Bytes> {
+        let request_log_id = request_log_id.as_ref();
         let url = format!(
             "/v2.1/diagnostics/request_logs/{}",
             crate::progenitor_support::encode_path(request_log_id),
@@ -0,0 +1,201 @@
+//! Options for the requests of a client: a timeout, extra headers and cancellation.
+//!
+//! The options apply to every request made with the client returned by
+//! `Client::with_request_options`. The client is cheap to clone, so this can be
+//! used for a single call as well as for all the calls of a client.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use docusign::request_options::{CancellationToken, RequestOptions};
+//!
+//! let token = CancellationToken::new();
+//! let options = RequestOptions {
+//!     timeout: Some(Duration::from_secs(10)),
+//!     cancel_token: Some(token.clone()),
+//!     ..Default::default()
+//! };
+//!
+//! // Pass `options` to `Client::with_request_options`, calling `token.cancel()` then
+//! // aborts the requests of the returned client.
+//! ```
+//!
+//! Headers to send with every request, like a `User-Agent` for the deployment, can
+//! be added to the options or to a client:
+//!
+//! ```no_run
+//! # fn run(client: docusign::Client) -> anyhow::Result<()> {
+//! let client = client
+//!     .with_user_agent("billing-sync/1.4")?
+//!     .with_default_header("x-request-source", "billing-sync")?;
+//! # Ok(())
+//! # }
+//! ```
+use std::{convert::TryFrom, sync::Arc, time::Duration};
+
+use anyhow::{anyhow, Result};
+use futures::future::{self, Either};
+use reqwest::header::{HeaderName, HeaderValue};
+pub use tokio_util::sync::CancellationToken;
+
+/// Options applied to the requests of a client.
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Abort a request that did not complete within this time. The time includes
+    /// the retries of the request.
+    pub timeout: Option<Duration>,
+    /// Headers added to every request, replacing the ones with the same name set by
+    /// the client.
+    pub headers: reqwest::header::HeaderMap,
+    /// Abort the requests in flight once the token is cancelled.
+    pub cancel_token: Option<CancellationToken>,
+    /// Answer `GET` requests from this cache when it has a fresh response for them.
+    pub cache: Option<crate::cache::ResponseCache>,
+    /// Coalesce identical `GET` requests in flight at the same time into one.
+    pub single_flight: Option<crate::single_flight::SingleFlight>,
+    /// Report the metrics of every request that completes to this sink.
+    pub metrics: Option<Arc<dyn crate::metrics::MetricsSink>>,
+    /// Query parameters with credentials, whose values are redacted from the URLs of
+    /// the errors along with `redact::DEFAULT_URL_PARAMS`.
+    pub redact_url_params: Vec<String>,
+}
+
+impl RequestOptions {
+    /// Send the header with every request, replacing the one with the same name set
+    /// by the client, like `Accept`.
+    pub fn default_header<K, V>(mut self, name: K, value: V) -> Result<Self>
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<http::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+    {
+        let name = HeaderName::try_from(name).map_err(Into::<http::Error>::into)?;
+        let mut value = HeaderValue::try_from(value).map_err(Into::<http::Error>::into)?;
+        if crate::redact::is_sensitive_header(&name) {
+            value.set_sensitive(true);
+        }
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Send this `User-Agent` with every request.
+    pub fn user_agent<V>(self, agent: V) -> Result<Self>
+    where
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+    {
+        self.default_header(reqwest::header::USER_AGENT, agent)
+    }
+
+    /// Redact the values of these query parameters from the URLs of the errors, for
+    /// APIs taking a credential in the query string.
+    pub fn redact_url_params<S: ToString>(mut self, params: &[S]) -> Self {
+        self.redact_url_params
+            .extend(params.iter().map(|p| p.to_string()));
+        self
+    }
+
+    /// Send a request with the given client, applying the options.
+    pub(crate) async fn execute(
+        &self,
+        client: &reqwest_middleware::ClientWithMiddleware,
+        mut req: reqwest::Request,
+    ) -> Result<reqwest::Response> {
+        for (name, value) in &self.headers {
+            req.headers_mut().insert(name.clone(), value.clone());
+        }
+        // Keep the credentials out of what middleware and logs print of the request.
+        crate::redact::redact_headers(req.headers_mut());
+
+        let start = web_time::Instant::now();
+        let method = req.method().clone();
+        let path = req.url().path().to_string();
+
+        let timeout = async {
+            match self.timeout {
+                Some(timeout) => crate::runtime::sleep(timeout).await,
+                None => future::pending().await,
+            }
+        };
+        let cancelled = async {
+            match &self.cancel_token {
+                Some(token) => token.cancelled().await,
+                None => future::pending().await,
+            }
+        };
+
+        // The token is polled first, so that a request is not sent at all once the
+        // token was cancelled.
+        let send = async {
+            match &self.cache {
+                Some(cache) => cache.execute(req, |req| self.send(client, req)).await,
+                None => self.send(client, req).await,
+            }
+        };
+        futures::pin_mut!(send, timeout, cancelled);
+        let mut result = match future::select(future::select(cancelled, timeout), send).await {
+            Either::Left((Either::Left(_), _)) => Err(anyhow!("request was cancelled")),
+            Either::Left((Either::Right(_), _)) => Err(anyhow!(
+                "request timed out after {:?}",
+                self.timeout.unwrap_or_default()
+            )),
+            Either::Right((resp, _)) => resp,
+        };
+        if let Err(e) = &mut result {
+            crate::redact::redact_error(e, &self.redact_url_params);
+        }
+
+        if let Some(sink) = &self.metrics {
+            crate::metrics::record(sink.as_ref(), method, &path, &result, start.elapsed());
+        }
+
+        result
+    }
+
+    async fn send(
+        &self,
+        client: &reqwest_middleware::ClientWithMiddleware,
+        req: reqwest::Request,
+    ) -> Result<reqwest::Response> {
+        match &self.single_flight {
+            Some(single_flight) => single_flight.execute(client, req).await,
+            None => Ok(client.execute(req).await?),
+        }
+    }
+}
+
+impl crate::Client {
+    /// Send the header with every request made with the returned client, replacing
+    /// the one with the same name set by the client.
+    pub fn with_default_header<K, V>(&self, name: K, value: V) -> Result<Self>
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<http::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+    {
+        let mut c = self.clone();
+        c.request_options = c.request_options.default_header(name, value)?;
+        Ok(c)
+    }
+
+    /// Send this `User-Agent` with every request made with the returned client.
+    pub fn with_user_agent<V>(&self, agent: V) -> Result<Self>
+    where
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+    {
+        let mut c = self.clone();
+        c.request_options = c.request_options.user_agent(agent)?;
+        Ok(c)
+    }
+
+    /// Redact the values of these query parameters from the URLs of the errors of the
+    /// returned client, for APIs taking a credential in the query string.
+    pub fn with_redact_url_params<S: ToString>(&self, params: &[S]) -> Self {
+        let mut c = self.clone();
+        c.request_options = c.request_options.redact_url_params(params);
+        c
+    }
+}
@@ -37,10 +37,12 @@ impl ResponsiveHtmlPreview {
     */
     pub async fn responsive_html_post_preview(
         &self,
-        account_id: &str,
-        envelope_id: &str,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
         body: &crate::types::DocumentHtmlDefinition,
     ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/responsive_html_preview",
             crate::progenitor_support::encode_path(account_id),
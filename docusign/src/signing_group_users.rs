@@ -28,9 +28,11 @@ impl SigningGroupUsers {
     */
     pub async fn signing_groups_get_group_user(
         &self,
-        account_id: &str,
-        signing_group_id: &str,
+        account_id: impl AsRef<str>,
+        signing_group_id: impl AsRef<str>,
     ) -> Result<crate::types::SigningGroupUsersData> {
+        let account_id = account_id.as_ref();
+        let signing_group_id = signing_group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}/users",
             crate::progenitor_support::encode_path(account_id),
@@ -56,10 +58,12 @@ impl SigningGroupUsers {
     */
     pub async fn signing_groups_put_group_users(
         &self,
-        account_id: &str,
-        signing_group_id: &str,
+        account_id: impl AsRef<str>,
+        signing_group_id: impl AsRef<str>,
         body: &crate::types::SigningGroupUsersData,
     ) -> Result<crate::types::SigningGroupUsersData> {
+        let account_id = account_id.as_ref();
+        let signing_group_id = signing_group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}/users",
             crate::progenitor_support::encode_path(account_id),
@@ -87,10 +91,12 @@ impl SigningGroupUsers {
     */
     pub async fn signing_groups_delete_group_users(
         &self,
-        account_id: &str,
-        signing_group_id: &str,
+        account_id: impl AsRef<str>,
+        signing_group_id: impl AsRef<str>,
         body: &crate::types::SigningGroupUsersData,
     ) -> Result<crate::types::SigningGroupUsersData> {
+        let account_id = account_id.as_ref();
+        let signing_group_id = signing_group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}/users",
             crate::progenitor_support::encode_path(account_id),
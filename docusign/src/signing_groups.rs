@@ -27,22 +27,24 @@ impl SigningGroups {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         group_type: &str,
         include_users: &str,
     ) -> Result<crate::types::SigningGroupInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !group_type.is_empty() {
-            query_args.push(("group_type".to_string(), group_type.to_string()));
+            query_args.push(("group_type", group_type.to_string()));
         }
         if !include_users.is_empty() {
-            query_args.push(("include_users".to_string(), include_users.to_string()));
+            query_args.push(("include_users", include_users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signing_groups?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/signing_groups",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -61,9 +63,10 @@ impl SigningGroups {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::SigningGroupInformation,
     ) -> Result<crate::types::SigningGroupInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups",
             crate::progenitor_support::encode_path(account_id),
@@ -93,9 +96,10 @@ impl SigningGroups {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::SigningGroupInformation,
     ) -> Result<crate::types::SigningGroupInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups",
             crate::progenitor_support::encode_path(account_id),
@@ -119,9 +123,10 @@ impl SigningGroups {
     */
     pub async fn delete(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::SigningGroupInformation,
     ) -> Result<crate::types::SigningGroupInformation> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups",
             crate::progenitor_support::encode_path(account_id),
@@ -148,9 +153,11 @@ impl SigningGroups {
     */
     pub async fn get_group(
         &self,
-        account_id: &str,
-        signing_group_id: &str,
+        account_id: impl AsRef<str>,
+        signing_group_id: impl AsRef<str>,
     ) -> Result<crate::types::SigningGroup> {
+        let account_id = account_id.as_ref();
+        let signing_group_id = signing_group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -176,10 +183,12 @@ impl SigningGroups {
     */
     pub async fn put_group(
         &self,
-        account_id: &str,
-        signing_group_id: &str,
+        account_id: impl AsRef<str>,
+        signing_group_id: impl AsRef<str>,
         body: &crate::types::SigningGroup,
     ) -> Result<crate::types::SigningGroup> {
+        let account_id = account_id.as_ref();
+        let signing_group_id = signing_group_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -24,7 +24,13 @@ impl TabsBlob {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn get(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+    pub async fn get(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
             crate::progenitor_support::encode_path(account_id),
@@ -46,7 +52,13 @@ impl TabsBlob {
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn put(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+    pub async fn put(
+        &self,
+        account_id: impl AsRef<str>,
+        envelope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let envelope_id = envelope_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
             crate::progenitor_support::encode_path(account_id),
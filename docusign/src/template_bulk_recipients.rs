@@ -29,26 +29,30 @@ impl TemplateBulkRecipients {
     */
     pub async fn recipients_get_template_bulk(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         include_tabs: &str,
         start_position: &str,
     ) -> Result<crate::types::BulkRecipientsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            crate::progenitor_support::encode_path(recipient_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -71,11 +75,14 @@ impl TemplateBulkRecipients {
     */
     pub async fn recipients_put_template_bulk(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::BulkRecipientsRequest,
     ) -> Result<crate::types::BulkRecipientsSummaryResponse> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
             crate::progenitor_support::encode_path(account_id),
@@ -103,10 +110,13 @@ impl TemplateBulkRecipients {
     */
     pub async fn recipients_delete_template_bulk_file(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::BulkRecipientsUpdateResponse> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
             crate::progenitor_support::encode_path(account_id),
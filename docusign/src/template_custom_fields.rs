@@ -26,9 +26,11 @@ impl TemplateCustomFields {
     */
     pub async fn custom_fields_get_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::CustomFields> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -54,10 +56,12 @@ impl TemplateCustomFields {
     */
     pub async fn custom_fields_put_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateCustomFieldsData,
     ) -> Result<crate::types::CustomFields> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -83,10 +87,12 @@ impl TemplateCustomFields {
     */
     pub async fn custom_fields_post_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateCustomFieldsData,
     ) -> Result<crate::types::CustomFields> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -112,10 +118,12 @@ impl TemplateCustomFields {
     */
     pub async fn custom_fields_delete_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateCustomFieldsData,
     ) -> Result<crate::types::CustomFields> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
             crate::progenitor_support::encode_path(account_id),
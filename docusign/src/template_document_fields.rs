@@ -27,10 +27,13 @@ impl TemplateDocumentFields {
     */
     pub async fn document_fields_get_template(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::DocumentFieldsInformation> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
@@ -56,11 +59,14 @@ impl TemplateDocumentFields {
     */
     pub async fn document_fields_put_template(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::DocumentFieldsInformation,
     ) -> Result<crate::types::DocumentFieldsInformation> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
@@ -88,11 +94,14 @@ impl TemplateDocumentFields {
     */
     pub async fn document_fields_post_template(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::DocumentFieldsInformation,
     ) -> Result<crate::types::DocumentFieldsInformation> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
@@ -120,11 +129,14 @@ impl TemplateDocumentFields {
     */
     pub async fn document_fields_delete_template(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::DocumentFieldsInformation,
     ) -> Result<crate::types::DocumentFieldsInformation> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
             crate::progenitor_support::encode_path(account_id),
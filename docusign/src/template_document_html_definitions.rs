@@ -27,10 +27,13 @@ impl TemplateDocumentHtmlDefinitions {
     */
     pub async fn responsive_html_get_template_document_definition(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::DocumentHtmlDefinitionOriginals> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/html_definitions",
             crate::progenitor_support::encode_path(account_id),
@@ -37,11 +37,14 @@ impl TemplateDocumentResponsiveHtmlPreview {
     */
     pub async fn responsive_html_post_template_document_preview(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::DocumentHtmlDefinition,
     ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/responsive_html_preview",
             crate::progenitor_support::encode_path(account_id),
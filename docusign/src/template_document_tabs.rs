@@ -28,11 +28,15 @@ impl TemplateDocumentTabs {
     */
     pub async fn tabs_get_template_page(
         &self,
-        account_id: &str,
-        document_id: &str,
-        page_number: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::TemplateDocumentTabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let page_number = page_number.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -62,22 +66,26 @@ impl TemplateDocumentTabs {
     */
     pub async fn tabs_get_template_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         page_numbers: &str,
     ) -> Result<crate::types::TemplateDocumentTabs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_numbers.is_empty() {
-            query_args.push(("page_numbers".to_string(), page_numbers.to_string()));
+            query_args.push(("page_numbers", page_numbers.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}/tabs?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -98,11 +106,14 @@ impl TemplateDocumentTabs {
     */
     pub async fn tabs_put_template_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateTabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -130,11 +141,14 @@ impl TemplateDocumentTabs {
     */
     pub async fn tabs_post_template_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateTabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -162,11 +176,14 @@ impl TemplateDocumentTabs {
     */
     pub async fn tabs_delete_template_document(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateTabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
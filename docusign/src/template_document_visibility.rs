@@ -29,10 +29,12 @@ impl TemplateDocumentVisibility {
     */
     pub async fn recipients_put(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateDocumentVisibilityList,
     ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/document_visibility",
             crate::progenitor_support::encode_path(account_id),
@@ -59,10 +61,13 @@ impl TemplateDocumentVisibility {
     */
     pub async fn recipients_get_template_recipient_document_visibility(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::DocumentVisibilityList> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
             crate::progenitor_support::encode_path(account_id),
@@ -90,11 +95,14 @@ impl TemplateDocumentVisibility {
     */
     pub async fn recipients_put_template_recipient_document_visibility(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateDocumentVisibilityList,
     ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
             crate::progenitor_support::encode_path(account_id),
@@ -27,20 +27,23 @@ impl TemplateDocuments {
     */
     pub async fn documents_get_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         include_tabs: &str,
     ) -> Result<crate::types::TemplateDocumentsResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -60,10 +63,12 @@ impl TemplateDocuments {
     */
     pub async fn documents_put_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::TemplateDocumentsResult> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents",
             crate::progenitor_support::encode_path(account_id),
@@ -103,10 +108,12 @@ impl TemplateDocuments {
     */
     pub async fn documents_delete_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::TemplateDocumentsResult> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents",
             crate::progenitor_support::encode_path(account_id),
@@ -137,26 +144,30 @@ impl TemplateDocuments {
     */
     pub async fn documents_get(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         encrypt: &str,
         show_changes: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encrypt.is_empty() {
-            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+            query_args.push(("encrypt", encrypt.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -178,26 +189,27 @@ impl TemplateDocuments {
     */
     pub async fn documents_put(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         is_envelope_definition: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeDocument> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !is_envelope_definition.is_empty() {
-            query_args.push((
-                "is_envelope_definition".to_string(),
-                is_envelope_definition.to_string(),
-            ));
+            query_args.push(("is_envelope_definition", is_envelope_definition.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -26,9 +26,11 @@ impl TemplateHtmlDefinitions {
     */
     pub async fn responsive_html_get_template_definition(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::DocumentHtmlDefinitionOriginals> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/html_definitions",
             crate::progenitor_support::encode_path(account_id),
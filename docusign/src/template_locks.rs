@@ -39,9 +39,11 @@ impl TemplateLocks {
     */
     pub async fn lock_get_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::LockInformation> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
             crate::progenitor_support::encode_path(account_id),
@@ -87,10 +89,12 @@ impl TemplateLocks {
     */
     pub async fn lock_put_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::LockRequest,
     ) -> Result<crate::types::LockInformation> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
             crate::progenitor_support::encode_path(account_id),
@@ -172,10 +176,12 @@ impl TemplateLocks {
     */
     pub async fn lock_post_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::LockRequest,
     ) -> Result<crate::types::LockInformation> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
             crate::progenitor_support::encode_path(account_id),
@@ -218,10 +224,12 @@ impl TemplateLocks {
     */
     pub async fn lock_delete_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::LockRequest,
     ) -> Result<crate::types::LockInformation> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
             crate::progenitor_support::encode_path(account_id),
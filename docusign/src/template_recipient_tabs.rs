@@ -29,29 +29,33 @@ impl TemplateRecipientTabs {
     */
     pub async fn recipients_get(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         include_anchor_tab_locations: &str,
         include_metadata: &str,
     ) -> Result<crate::types::Tabs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
-                "include_anchor_tab_locations".to_string(),
+                "include_anchor_tab_locations",
                 include_anchor_tab_locations.to_string(),
             ));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            crate::progenitor_support::encode_path(recipient_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -72,11 +76,14 @@ impl TemplateRecipientTabs {
     */
     pub async fn recipients_put(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateTabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -104,11 +111,14 @@ impl TemplateRecipientTabs {
     */
     pub async fn recipients_post(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateTabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -136,11 +146,14 @@ impl TemplateRecipientTabs {
     */
     pub async fn recipients_delete(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateTabs,
     ) -> Result<crate::types::Tabs> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
             crate::progenitor_support::encode_path(account_id),
@@ -29,31 +29,34 @@ impl TemplateRecipients {
     */
     pub async fn recipients_get_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         include_anchor_tab_locations: &str,
         include_extended: &str,
         include_tabs: &str,
     ) -> Result<crate::types::Recipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
-                "include_anchor_tab_locations".to_string(),
+                "include_anchor_tab_locations",
                 include_anchor_tab_locations.to_string(),
             ));
         }
         if !include_extended.is_empty() {
-            query_args.push(("include_extended".to_string(), include_extended.to_string()));
+            query_args.push(("include_extended", include_extended.to_string()));
         }
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -78,21 +81,24 @@ impl TemplateRecipients {
     */
     pub async fn recipients_put_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         resend_envelope: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::RecipientsUpdateSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -117,21 +123,24 @@ impl TemplateRecipients {
     */
     pub async fn recipients_post_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         resend_envelope: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::Recipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -153,10 +162,12 @@ impl TemplateRecipients {
     */
     pub async fn recipients_delete_template(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::Recipients> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients",
             crate::progenitor_support::encode_path(account_id),
@@ -183,11 +194,14 @@ impl TemplateRecipients {
     */
     pub async fn recipients_delete(
         &self,
-        account_id: &str,
-        recipient_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        recipient_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::Recipients> {
+        let account_id = account_id.as_ref();
+        let recipient_id = recipient_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -216,10 +230,12 @@ impl TemplateRecipients {
     */
     pub async fn views_post_preview(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::RecipientPreviewRequest,
     ) -> Result<crate::types::ViewUrl> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/views/recipient_preview",
             crate::progenitor_support::encode_path(account_id),
@@ -26,10 +26,12 @@ impl TemplateViews {
     */
     pub async fn views_post_template_edit_view(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::ReturnUrlRequest,
     ) -> Result<crate::types::ViewUrl> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/views/edit",
             crate::progenitor_support::encode_path(account_id),
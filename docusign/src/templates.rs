@@ -93,7 +93,7 @@ impl Templates {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         count: &str,
         created_from_date: &str,
         created_to_date: &str,
@@ -118,90 +118,86 @@ impl Templates {
         user_filter: &str,
         user_id: &str,
     ) -> Result<crate::types::EnvelopeTemplateResults> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_from_date.is_empty() {
-            query_args.push((
-                "created_from_date".to_string(),
-                created_from_date.to_string(),
-            ));
+            query_args.push(("created_from_date", created_from_date.to_string()));
         }
         if !created_to_date.is_empty() {
-            query_args.push(("created_to_date".to_string(), created_to_date.to_string()));
+            query_args.push(("created_to_date", created_to_date.to_string()));
         }
         if !folder_ids.is_empty() {
-            query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
+            query_args.push(("folder_ids", folder_ids.to_string()));
         }
         if !folder_types.is_empty() {
-            query_args.push(("folder_types".to_string(), folder_types.to_string()));
+            query_args.push(("folder_types", folder_types.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !is_deleted_template_only.is_empty() {
             query_args.push((
-                "is_deleted_template_only".to_string(),
+                "is_deleted_template_only",
                 is_deleted_template_only.to_string(),
             ));
         }
         if !is_download.is_empty() {
-            query_args.push(("is_download".to_string(), is_download.to_string()));
+            query_args.push(("is_download", is_download.to_string()));
         }
         if !modified_from_date.is_empty() {
-            query_args.push((
-                "modified_from_date".to_string(),
-                modified_from_date.to_string(),
-            ));
+            query_args.push(("modified_from_date", modified_from_date.to_string()));
         }
         if !modified_to_date.is_empty() {
-            query_args.push(("modified_to_date".to_string(), modified_to_date.to_string()));
+            query_args.push(("modified_to_date", modified_to_date.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !search_fields.is_empty() {
-            query_args.push(("search_fields".to_string(), search_fields.to_string()));
+            query_args.push(("search_fields", search_fields.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !shared_by_me.is_empty() {
-            query_args.push(("shared_by_me".to_string(), shared_by_me.to_string()));
+            query_args.push(("shared_by_me", shared_by_me.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !template_ids.is_empty() {
-            query_args.push(("template_ids".to_string(), template_ids.to_string()));
+            query_args.push(("template_ids", template_ids.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
         if !used_from_date.is_empty() {
-            query_args.push(("used_from_date".to_string(), used_from_date.to_string()));
+            query_args.push(("used_from_date", used_from_date.to_string()));
         }
         if !used_to_date.is_empty() {
-            query_args.push(("used_to_date".to_string(), used_to_date.to_string()));
+            query_args.push(("used_to_date", used_to_date.to_string()));
         }
         if !user_filter.is_empty() {
-            query_args.push(("user_filter".to_string(), user_filter.to_string()));
+            query_args.push(("user_filter", user_filter.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+            query_args.push(("user_id", user_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -270,9 +266,10 @@ impl Templates {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::EnvelopeTemplate,
     ) -> Result<crate::types::TemplateSummary> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates",
             crate::progenitor_support::encode_path(account_id),
@@ -306,20 +303,23 @@ impl Templates {
     */
     pub async fn get_templates(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         include: &str,
     ) -> Result<crate::types::EnvelopeTemplate> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            query_
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -339,10 +339,12 @@ impl Templates {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::EnvelopeTemplate,
     ) -> Result<crate::types::TemplateUpdateSummary> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -376,9 +378,9 @@ impl Templates {
     */
     pub async fn pages_get_page_image(
         &self,
-        account_id: &str,
-        document_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         count: &str,
         dpi: &str,
         max_height: &str,
@@ -387,35 +389,39 @@ impl Templates {
         show_changes: &str,
         start_position: &str,
     ) -> Result<crate::types::PageImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !nocache.is_empty() {
-            query_args.push(("nocache".to_string(), nocache.to_string()));
+            query_args.push(("nocache", nocache.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}/pages?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            crate::progenitor_support::encode_path(document_id),
-            query_
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -437,12 +443,16 @@ impl Templates {
     */
     pub async fn pages_delete_page(
         &self,
-        account_id: &str,
-        document_id: &str,
-        page_number: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::PageRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let page_number = page_number.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -476,36 +486,41 @@ impl Templates {
     */
     pub async fn pages_get_page_image_templates(
         &self,
-        account_id: &str,
-        document_id: &str,
-        page_number: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         dpi: &str,
         max_height: &str,
         max_width: &str,
         show_changes: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let page_number = page_number.as_ref();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(template_id),
-            crate::progenitor_support::encode_path(document_id),
-            crate::progenitor_support::encode_path(page_number),
-            query_
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -527,12 +542,16 @@ impl Templates {
     */
     pub async fn pages_put_page_image(
         &self,
-        account_id: &str,
-        document_id: &str,
-        page_number: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        document_id: impl AsRef<str>,
+        page_number: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::PageRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let document_id = document_id.as_ref();
+        let page_number = page_number.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
             crate::progenitor_support::encode_path(account_id),
@@ -560,9 +579,11 @@ impl Templates {
     */
     pub async fn notification_get(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::Notification> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/notification",
             crate::progenitor_support::encode_path(account_id),
@@ -586,10 +607,12 @@ impl Templates {
     */
     pub async fn notification_put(
         &self,
-        account_id: &str,
-        template_id: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateNotificationRequest,
     ) -> Result<crate::types::Notification> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/notification",
             crate::progenitor_support::encode_path(account_id),
@@ -618,11 +641,14 @@ impl Templates {
     */
     pub async fn put_part(
         &self,
-        account_id: &str,
-        template_id: &str,
-        template_part: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
+        template_part: impl AsRef<str>,
         body: &crate::types::GroupInformation,
     ) -> Result<crate::types::GroupInformation> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
+        let template_part = template_part.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -650,11 +676,14 @@ impl Templates {
     */
     pub async fn delete_part(
         &self,
-        account_id: &str,
-        template_id: &str,
-        template_part: &str,
+        account_id: impl AsRef<str>,
+        template_id: impl AsRef<str>,
+        template_part: impl AsRef<str>,
         body: &crate::types::GroupInformation,
     ) -> Result<crate::types::GroupInformation> {
+        let account_id = account_id.as_ref();
+        let template_id = template_id.as_ref();
+        let template_part = template_part.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -1 +1,75 @@
+use crate::envelopes::{AnchorTab, EnvelopeBuilder, EnvelopeDocument, EnvelopeSigner};
 
+#[tokio::test]
+async fn test_send_envelope() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::CREATED,
+        r#"{"envelopeId": "4b728be4-1234", "status": "sent", "uri": "/envelopes/4b728be4-1234"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"envelopeId": "4b728be4-1234", "status": "delivered"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"envelopeId": "4b728be4-1234", "status": "completed"}"#,
+    );
+
+    // Not a multiple of 3 bytes, and more than one read buffer.
+    let contract: Vec<u8> = (0..70_000).map(|i| (i % 253) as u8).collect();
+    let envelope = EnvelopeBuilder::new("Please sign the contract")
+        .document(
+            EnvelopeDocument::from_reader("1", "Contract", "pdf", &contract[..])
+                .await
+                .unwrap(),
+        )
+        .signer(
+            EnvelopeSigner::new("1", "jane@example.com", "Jane Doe")
+                .tab(AnchorTab::sign_here("/sn1/").offset(20, -10))
+                .tab(AnchorTab::text("/title1/", "title")),
+        );
+
+    let docusign = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let envelopes = docusign.envelopes();
+    let summary = envelopes.send("1703061", &envelope).await.unwrap();
+    assert_eq!(summary.envelope_id, "4b728be4-1234");
+
+    let done = envelopes
+        .wait_for_completion(
+            "1703061",
+            &summary.envelope_id,
+            std::time::Duration::from_millis(1),
+        )
+        .await
+        .unwrap();
+    assert_eq!(done.status, "completed");
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].url.path(), "/v2.1/accounts/1703061/envelopes");
+    let body: serde_json::Value = requests[0].json().unwrap();
+    assert_eq!(body["status"], "sent");
+    assert_eq!(
+        body["documents"][0]["documentBase64"],
+        base64::encode(&contract)
+    );
+    assert_eq!(
+        body["recipients"]["signers"][0]["tabs"],
+        serde_json::json!({
+            "signHereTabs": [{
+                "anchorString": "/sn1/",
+                "anchorUnits": "pixels",
+                "anchorXOffset": "20",
+                "anchorYOffset": "-10"
+            }],
+            "textTabs": [{
+                "anchorString": "/title1/",
+                "anchorUnits": "pixels",
+                "anchorXOffset": "0",
+                "anchorYOffset": "0",
+                "tabLabel": "title"
+            }]
+        })
+    );
+}
@@ -22829,7 +22829,7 @@ pub struct BillingPaymentItem {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BillingPaymentRequest {
     /**
     *
@@ -22843,6 +22843,43 @@ pub struct BillingPaymentRequest {
     pub payment_amount: String,
 }
 
+impl std::convert::From<String> for BillingPaymentRequest {
+    fn from(payment_amount: String) -> Self {
+        BillingPaymentRequest { payment_amount }
+    }
+}
+
+impl std::convert::From<&str> for BillingPaymentRequest {
+    fn from(payment_amount: &str) -> Self {
+        BillingPaymentRequest {
+            payment_amount: payment_amount.to_string(),
+        }
+    }
+}
+
+impl BillingPaymentRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BillingPaymentRequestBuilder {
+        BillingPaymentRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BillingPaymentRequest`.
+#[derive(Debug, Clone)]
+pub struct BillingPaymentRequestBuilder(BillingPaymentRequest);
+
+impl BillingPaymentRequestBuilder {
+    pub fn payment_amount(mut self, payment_amount: impl Into<String>) -> Self {
+        self.0.payment_amount = payment_amount.into();
+        self
+    }
+
+    pub fn build(self) -> BillingPaymentRequest {
+        self.0
+    }
+}
+
 /// Defines an billing payment response object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BillingPaymentResponse {
@@ -23705,7 +23742,7 @@ pub struct BrandLogos {
 }
 
 /// This request object contains information about a specific brand.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BrandRequest {
     /**
     * This request object contains information about a specific brand.
@@ -23719,6 +23756,43 @@ pub struct BrandRequest {
     pub brand_id: String,
 }
 
+impl std::convert::From<String> for BrandRequest {
+    fn from(brand_id: String) -> Self {
+        BrandRequest { brand_id }
+    }
+}
+
+impl std::convert::From<&str> for BrandRequest {
+    fn from(brand_id: &str) -> Self {
+        BrandRequest {
+            brand_id: brand_id.to_string(),
+        }
+    }
+}
+
+impl BrandRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BrandRequestBuilder {
+        BrandRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BrandRequest`.
+#[derive(Debug, Clone)]
+pub struct BrandRequestBuilder(BrandRequest);
+
+impl BrandRequestBuilder {
+    pub fn brand_id(mut self, brand_id: impl Into<String>) -> Self {
+        self.0.brand_id = brand_id.into();
+        self
+    }
+
+    pub fn build(self) -> BrandRequest {
+        self.0
+    }
+}
+
 /// Brands use resource files to style the following experiences:
 ///
 /// - Email
@@ -23895,7 +23969,7 @@ pub struct BrandResourcesList {
 }
 
 /// Details about one or more brands.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BrandsRequest {
     /**
     * Details about one or more brands.
@@ -23908,6 +23982,29 @@ pub struct BrandsRequest {
     pub brands: Vec<BrandRequest>,
 }
 
+impl BrandsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BrandsRequestBuilder {
+        BrandsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BrandsRequest`.
+#[derive(Debug, Clone)]
+pub struct BrandsRequestBuilder(BrandsRequest);
+
+impl BrandsRequestBuilder {
+    pub fn brands(mut self, brands: Vec<BrandRequest>) -> Self {
+        self.0.brands = brands;
+        self
+    }
+
+    pub fn build(self) -> BrandsRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BrandsResponse {
@@ -24324,7 +24421,7 @@ pub struct BulkRecipientTabLabel {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BulkRecipientsRequest {
     /**
     *
@@ -24338,6 +24435,29 @@ pub struct BulkRecipientsRequest {
     pub bulk_recipients: Vec<BulkRecipient>,
 }
 
+impl BulkRecipientsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BulkRecipientsRequestBuilder {
+        BulkRecipientsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BulkRecipientsRequest`.
+#[derive(Debug, Clone)]
+pub struct BulkRecipientsRequestBuilder(BulkRecipientsRequest);
+
+impl BulkRecipientsRequestBuilder {
+    pub fn bulk_recipients(mut self, bulk_recipients: Vec<BulkRecipient>) -> Self {
+        self.0.bulk_recipients = bulk_recipients;
+        self
+    }
+
+    pub fn build(self) -> BulkRecipientsRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BulkRecipientsResponse {
@@ -24469,7 +24589,7 @@ pub struct BulkRecipientsUpdateResponse {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BulkSendBatchRequest {
     /**
     *
@@ -24483,6 +24603,43 @@ pub struct BulkSendBatchRequest {
     pub batch_name: String,
 }
 
+impl std::convert::From<String> for BulkSendBatchRequest {
+    fn from(batch_name: String) -> Self {
+        BulkSendBatchRequest { batch_name }
+    }
+}
+
+impl std::convert::From<&str> for BulkSendBatchRequest {
+    fn from(batch_name: &str) -> Self {
+        BulkSendBatchRequest {
+            batch_name: batch_name.to_string(),
+        }
+    }
+}
+
+impl BulkSendBatchRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BulkSendBatchRequestBuilder {
+        BulkSendBatchRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BulkSendBatchRequest`.
+#[derive(Debug, Clone)]
+pub struct BulkSendBatchRequestBuilder(BulkSendBatchRequest);
+
+impl BulkSendBatchRequestBuilder {
+    pub fn batch_name(mut self, batch_name: impl Into<String>) -> Self {
+        self.0.batch_name = batch_name.into();
+        self
+    }
+
+    pub fn build(self) -> BulkSendBatchRequest {
+        self.0
+    }
+}
+
 /// Result of `getBulkSendBatchStatus`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BulkSendBatchStatus {
@@ -24837,7 +24994,7 @@ pub struct BulkSendErrorStatus {
 }
 
 /// This object contains information about the envelope or template that you want to send in bulk.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BulkSendRequest {
     /**
     * This object contains information about the envelope or template that you want to send in bulk.
@@ -24861,6 +25018,34 @@ pub struct BulkSendRequest {
     pub envelope_or_template_id: String,
 }
 
+impl BulkSendRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BulkSendRequestBuilder {
+        BulkSendRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BulkSendRequest`.
+#[derive(Debug, Clone)]
+pub struct BulkSendRequestBuilder(BulkSendRequest);
+
+impl BulkSendRequestBuilder {
+    pub fn batch_name(mut self, batch_name: impl Into<String>) -> Self {
+        self.0.batch_name = batch_name.into();
+        self
+    }
+
+    pub fn envelope_or_template_id(mut self, envelope_or_template_id: impl Into<String>) -> Self {
+        self.0.envelope_or_template_id = envelope_or_template_id.into();
+        self
+    }
+
+    pub fn build(self) -> BulkSendRequest {
+        self.0
+    }
+}
+
 /// The object contains the response to a bulk send request.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BulkSendResponse {
@@ -27863,7 +28048,7 @@ pub struct ChunkedUploadPart {
 }
 
 /// This is the request object for uploading a chunked upload.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChunkedUploadRequest {
     /**
     * This is the request object for uploading a chunked upload.
@@ -27886,6 +28071,34 @@ pub struct ChunkedUploadRequest {
     pub data: String,
 }
 
+impl ChunkedUploadRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ChunkedUploadRequestBuilder {
+        ChunkedUploadRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ChunkedUploadRequest`.
+#[derive(Debug, Clone)]
+pub struct ChunkedUploadRequestBuilder(ChunkedUploadRequest);
+
+impl ChunkedUploadRequestBuilder {
+    pub fn chunked_upload_id(mut self, chunked_upload_id: impl Into<String>) -> Self {
+        self.0.chunked_upload_id = chunked_upload_id.into();
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.0.data = data.into();
+        self
+    }
+
+    pub fn build(self) -> ChunkedUploadRequest {
+        self.0
+    }
+}
+
 /// This response object is returned after you upload a chunked upload.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChunkedUploadResponse {
@@ -35217,7 +35430,7 @@ pub struct ConnectUserObject {
 }
 
 /// The request object for the EnvelopeViews::createConsole method.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConsoleViewRequest {
     /**
     * The request object for the EnvelopeViews::createConsole method.
@@ -35241,6 +35454,34 @@ pub struct ConsoleViewRequest {
     pub return_url: String,
 }
 
+impl ConsoleViewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ConsoleViewRequestBuilder {
+        ConsoleViewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ConsoleViewRequest`.
+#[derive(Debug, Clone)]
+pub struct ConsoleViewRequestBuilder(ConsoleViewRequest);
+
+impl ConsoleViewRequestBuilder {
+    pub fn envelope_id(mut self, envelope_id: impl Into<String>) -> Self {
+        self.0.envelope_id = envelope_id.into();
+        self
+    }
+
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.0.return_url = return_url.into();
+        self
+    }
+
+    pub fn build(self) -> ConsoleViewRequest {
+        self.0
+    }
+}
+
 /// Details about consumer disclosures.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConsumerDisclosure {
@@ -35767,7 +36008,7 @@ pub struct ContactGetResponse {
 }
 
 /// The request object containing the new information for the contacts.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactModRequest {
     /**
     * The request object containing the new information for the contacts.
@@ -35781,6 +36022,29 @@ pub struct ContactModRequest {
     pub contact_list: Vec<Contact>,
 }
 
+impl ContactModRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ContactModRequestBuilder {
+        ContactModRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ContactModRequest`.
+#[derive(Debug, Clone)]
+pub struct ContactModRequestBuilder(ContactModRequest);
+
+impl ContactModRequestBuilder {
+    pub fn contact_list(mut self, contact_list: Vec<Contact>) -> Self {
+        self.0.contact_list = contact_list;
+        self
+    }
+
+    pub fn build(self) -> ContactModRequest {
+        self.0
+    }
+}
+
 /// Details about the phone numbers associated with a specific contact.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactPhoneNumber {
@@ -35821,7 +36085,7 @@ pub struct ContactUpdateResponse {
 }
 
 /// The request body for the EnvelopeViews::createCorrect method.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CorrectViewRequest {
     /**
     * The request body for the EnvelopeViews::createCorrect method.
@@ -35855,6 +36119,39 @@ pub struct CorrectViewRequest {
     pub view_url: String,
 }
 
+impl CorrectViewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CorrectViewRequestBuilder {
+        CorrectViewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CorrectViewRequest`.
+#[derive(Debug, Clone)]
+pub struct CorrectViewRequestBuilder(CorrectViewRequest);
+
+impl CorrectViewRequestBuilder {
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.0.return_url = return_url.into();
+        self
+    }
+
+    pub fn suppress_navigation(mut self, suppress_navigation: impl Into<String>) -> Self {
+        self.0.suppress_navigation = suppress_navigation.into();
+        self
+    }
+
+    pub fn view_url(mut self, view_url: impl Into<String>) -> Self {
+        self.0.view_url = view_url.into();
+        self
+    }
+
+    pub fn build(self) -> CorrectViewRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Country {
@@ -47985,7 +48282,7 @@ pub struct EnvelopeAttachment {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeAttachmentsRequest {
     /**
     *
@@ -47998,6 +48295,29 @@ pub struct EnvelopeAttachmentsRequest {
     pub attachments: Vec<Attachment>,
 }
 
+impl EnvelopeAttachmentsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnvelopeAttachmentsRequestBuilder {
+        EnvelopeAttachmentsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnvelopeAttachmentsRequest`.
+#[derive(Debug, Clone)]
+pub struct EnvelopeAttachmentsRequestBuilder(EnvelopeAttachmentsRequest);
+
+impl EnvelopeAttachmentsRequestBuilder {
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.0.attachments = attachments;
+        self
+    }
+
+    pub fn build(self) -> EnvelopeAttachmentsRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeAttachmentsResult {
@@ -50201,7 +50521,7 @@ pub struct EnvelopeId {
 }
 
 /// Lists of envelope and transaction IDs to use in the results.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeIdsRequest {
     /**
     * Lists of envelope and transaction IDs to use in the results.
@@ -50225,6 +50545,34 @@ pub struct EnvelopeIdsRequest {
     pub transaction_ids: Vec<String>,
 }
 
+impl EnvelopeIdsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnvelopeIdsRequestBuilder {
+        EnvelopeIdsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnvelopeIdsRequest`.
+#[derive(Debug, Clone)]
+pub struct EnvelopeIdsRequestBuilder(EnvelopeIdsRequest);
+
+impl EnvelopeIdsRequestBuilder {
+    pub fn envelope_ids(mut self, envelope_ids: Vec<String>) -> Self {
+        self.0.envelope_ids = envelope_ids;
+        self
+    }
+
+    pub fn transaction_ids(mut self, transaction_ids: Vec<String>) -> Self {
+        self.0.transaction_ids = transaction_ids;
+        self
+    }
+
+    pub fn build(self) -> EnvelopeIdsRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeMetadata {
@@ -50261,7 +50609,7 @@ pub struct EnvelopeMetadata {
 }
 
 /// A complex element that specifies the notification settings for the envelope.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeNotificationRequest {
     /**
     * A complex element that specifies the notification settings for the envelope.
@@ -50285,6 +50633,39 @@ pub struct EnvelopeNotificationRequest {
     pub use_account_defaults: String,
 }
 
+impl EnvelopeNotificationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnvelopeNotificationRequestBuilder {
+        EnvelopeNotificationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnvelopeNotificationRequest`.
+#[derive(Debug, Clone)]
+pub struct EnvelopeNotificationRequestBuilder(EnvelopeNotificationRequest);
+
+impl EnvelopeNotificationRequestBuilder {
+    pub fn expirations(mut self, expirations: impl Into<Expirations>) -> Self {
+        self.0.expirations = Some(expirations.into());
+        self
+    }
+
+    pub fn reminders(mut self, reminders: impl Into<Reminders>) -> Self {
+        self.0.reminders = Some(reminders.into());
+        self
+    }
+
+    pub fn use_account_defaults(mut self, use_account_defaults: impl Into<String>) -> Self {
+        self.0.use_account_defaults = use_account_defaults.into();
+        self
+    }
+
+    pub fn build(self) -> EnvelopeNotificationRequest {
+        self.0
+    }
+}
+
 /// Contains information about the current envelope purge configuration for an account, which enables account administrators to purge documents from completed and voided envelopes after a set number of days (`retentionDays`).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopePurgeConfiguration {
@@ -51615,7 +51996,7 @@ pub struct EnvelopeTransferRuleInformation {
 }
 
 /// This object contains details about the envelope transfer rule that you want to create.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeTransferRuleRequest {
     /**
     * This object contains details about the envelope transfer rule that you want to create.
@@ -51707,6 +52088,80 @@ pub struct EnvelopeTransferRuleRequest {
     pub to_user: Option<UserInformation>,
 }
 
+impl EnvelopeTransferRuleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnvelopeTransferRuleRequestBuilder {
+        EnvelopeTransferRuleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnvelopeTransferRuleRequest`.
+#[derive(Debug, Clone)]
+pub struct EnvelopeTransferRuleRequestBuilder(EnvelopeTransferRuleRequest);
+
+impl EnvelopeTransferRuleRequestBuilder {
+    pub fn carbon_copy_original_owner(
+        mut self,
+        carbon_copy_original_owner: impl Into<String>,
+    ) -> Self {
+        self.0.carbon_copy_original_owner = carbon_copy_original_owner.into();
+        self
+    }
+
+    pub fn enabled(mut self, enabled: impl Into<String>) -> Self {
+        self.0.enabled = enabled.into();
+        self
+    }
+
+    pub fn envelope_transfer_rule_id(
+        mut self,
+        envelope_transfer_rule_id: impl Into<String>,
+    ) -> Self {
+        self.0.envelope_transfer_rule_id = envelope_transfer_rule_id.into();
+        self
+    }
+
+    pub fn event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.0.event_type = event_type.into();
+        self
+    }
+
+    pub fn from_groups(mut self, from_groups: Vec<Group>) -> Self {
+        self.0.from_groups = from_groups;
+        self
+    }
+
+    pub fn from_users(mut self, from_users: Vec<UserInformation>) -> Self {
+        self.0.from_users = from_users;
+        self
+    }
+
+    pub fn modified_date(mut self, modified_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.modified_date = Some(modified_date.into());
+        self
+    }
+
+    pub fn modified_user(mut self, modified_user: impl Into<UserInformation>) -> Self {
+        self.0.modified_user = Some(modified_user.into());
+        self
+    }
+
+    pub fn to_folder(mut self, to_folder: impl Into<Folder>) -> Self {
+        self.0.to_folder = Some(to_folder.into());
+        self
+    }
+
+    pub fn to_user(mut self, to_user: impl Into<UserInformation>) -> Self {
+        self.0.to_user = Some(to_user.into());
+        self
+    }
+
+    pub fn build(self) -> EnvelopeTransferRuleRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeUpdateSummary {
@@ -54628,7 +55083,7 @@ pub struct FolderSharedItem {
 }
 
 /// Information for a folder request.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FoldersRequest {
     /**
     * Information for a folder request.
@@ -54661,6 +55116,39 @@ pub struct FoldersRequest {
     pub from_folder_id: String,
 }
 
+impl FoldersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> FoldersRequestBuilder {
+        FoldersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `FoldersRequest`.
+#[derive(Debug, Clone)]
+pub struct FoldersRequestBuilder(FoldersRequest);
+
+impl FoldersRequestBuilder {
+    pub fn envelope_ids(mut self, envelope_ids: Vec<String>) -> Self {
+        self.0.envelope_ids = envelope_ids;
+        self
+    }
+
+    pub fn folders(mut self, folders: Vec<Folder>) -> Self {
+        self.0.folders = folders;
+        self
+    }
+
+    pub fn from_folder_id(mut self, from_folder_id: impl Into<String>) -> Self {
+        self.0.from_folder_id = from_folder_id.into();
+        self
+    }
+
+    pub fn build(self) -> FoldersRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FoldersResponse {
@@ -66629,7 +67117,7 @@ pub struct LockInformation {
 }
 
 /// This request object contains information about the lock that you want to create or update.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LockRequest {
     /**
     * This request object contains information about the lock that you want to create or update.
@@ -66683,6 +67171,49 @@ pub struct LockRequest {
     pub use_scratch_pad: String,
 }
 
+impl LockRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> LockRequestBuilder {
+        LockRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `LockRequest`.
+#[derive(Debug, Clone)]
+pub struct LockRequestBuilder(LockRequest);
+
+impl LockRequestBuilder {
+    pub fn lock_duration_in_seconds(mut self, lock_duration_in_seconds: impl Into<String>) -> Self {
+        self.0.lock_duration_in_seconds = lock_duration_in_seconds.into();
+        self
+    }
+
+    pub fn lock_type(mut self, lock_type: impl Into<String>) -> Self {
+        self.0.lock_type = lock_type.into();
+        self
+    }
+
+    pub fn locked_by_app(mut self, locked_by_app: impl Into<String>) -> Self {
+        self.0.locked_by_app = locked_by_app.into();
+        self
+    }
+
+    pub fn template_password(mut self, template_password: impl Into<String>) -> Self {
+        self.0.template_password = template_password.into();
+        self
+    }
+
+    pub fn use_scratch_pad(mut self, use_scratch_pad: impl Into<String>) -> Self {
+        self.0.use_scratch_pad = use_scratch_pad.into();
+        self
+    }
+
+    pub fn build(self) -> LockRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LoginAccount {
@@ -74160,7 +74691,7 @@ pub struct PageImages {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PageRequest {
     /**
     *
@@ -74182,6 +74713,34 @@ pub struct PageRequest {
     pub rotate: String,
 }
 
+impl PageRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PageRequestBuilder {
+        PageRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PageRequest`.
+#[derive(Debug, Clone)]
+pub struct PageRequestBuilder(PageRequest);
+
+impl PageRequestBuilder {
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.0.password = password.into();
+        self
+    }
+
+    pub fn rotate(mut self, rotate: impl Into<String>) -> Self {
+        self.0.rotate = rotate.into();
+        self
+    }
+
+    pub fn build(self) -> PageRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PathExtendedElement {
@@ -77319,7 +77878,7 @@ pub struct PowerFormsFormDataResponse {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PowerFormsRequest {
     /**
     *
@@ -77333,6 +77892,29 @@ pub struct PowerFormsRequest {
     pub power_forms: Vec<PowerForm>,
 }
 
+impl PowerFormsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PowerFormsRequestBuilder {
+        PowerFormsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PowerFormsRequest`.
+#[derive(Debug, Clone)]
+pub struct PowerFormsRequestBuilder(PowerFormsRequest);
+
+impl PowerFormsRequestBuilder {
+    pub fn power_forms(mut self, power_forms: Vec<PowerForm>) -> Self {
+        self.0.power_forms = power_forms;
+        self
+    }
+
+    pub fn build(self) -> PowerFormsRequest {
+        self.0
+    }
+}
+
 /// A list of PowerForms.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PowerFormsResponse {
@@ -79499,7 +80081,7 @@ pub struct RecipientPhoneNumber {
 }
 
 /// This request object contains the information necessary to create a recipient preview.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecipientPreviewRequest {
     /**
     * This request object contains the information necessary to create a recipient preview.
@@ -79603,6 +80185,77 @@ pub struct RecipientPreviewRequest {
     pub x_frame_options_allow_from_url: String,
 }
 
+impl RecipientPreviewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> RecipientPreviewRequestBuilder {
+        RecipientPreviewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `RecipientPreviewRequest`.
+#[derive(Debug, Clone)]
+pub struct RecipientPreviewRequestBuilder(RecipientPreviewRequest);
+
+impl RecipientPreviewRequestBuilder {
+    pub fn assertion_id(mut self, assertion_id: impl Into<String>) -> Self {
+        self.0.assertion_id = assertion_id.into();
+        self
+    }
+
+    pub fn authentication_instant(mut self, authentication_instant: impl Into<String>) -> Self {
+        self.0.authentication_instant = authentication_instant.into();
+        self
+    }
+
+    pub fn authentication_method(mut self, authentication_method: impl Into<String>) -> Self {
+        self.0.authentication_method = authentication_method.into();
+        self
+    }
+
+    pub fn ping_frequency(mut self, ping_frequency: impl Into<String>) -> Self {
+        self.0.ping_frequency = ping_frequency.into();
+        self
+    }
+
+    pub fn ping_url(mut self, ping_url: impl Into<String>) -> Self {
+        self.0.ping_url = ping_url.into();
+        self
+    }
+
+    pub fn recipient_id(mut self, recipient_id: impl Into<String>) -> Self {
+        self.0.recipient_id = recipient_id.into();
+        self
+    }
+
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.0.return_url = return_url.into();
+        self
+    }
+
+    pub fn security_domain(mut self, security_domain: impl Into<String>) -> Self {
+        self.0.security_domain = security_domain.into();
+        self
+    }
+
+    pub fn x_frame_options(mut self, x_frame_options: impl Into<String>) -> Self {
+        self.0.x_frame_options = x_frame_options.into();
+        self
+    }
+
+    pub fn x_frame_options_allow_from_url(
+        mut self,
+        x_frame_options_allow_from_url: impl Into<String>,
+    ) -> Self {
+        self.0.x_frame_options_allow_from_url = x_frame_options_allow_from_url.into();
+        self
+    }
+
+    pub fn build(self) -> RecipientPreviewRequest {
+        self.0
+    }
+}
+
 /// The proof file of the recipient. [ID Evidence](https://developers.docusign.com/idevidence-api) uses proof files to store the identification data that recipients submit when verifying their ID with [ID Verification](https://developers.docusign.com/docs/esign-rest-api/reference/Accounts/IdentityVerifications)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecipientProofFile {
@@ -79898,7 +80551,7 @@ pub struct RecipientUpdateResponse {
 }
 
 /// The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecipientViewRequest {
     /**
     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
@@ -80061,6 +80714,107 @@ pub struct RecipientViewRequest {
     pub x_frame_options_allow_from_url: String,
 }
 
+impl RecipientViewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> RecipientViewRequestBuilder {
+        RecipientViewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `RecipientViewRequest`.
+#[derive(Debug, Clone)]
+pub struct RecipientViewRequestBuilder(RecipientViewRequest);
+
+impl RecipientViewRequestBuilder {
+    pub fn assertion_id(mut self, assertion_id: impl Into<String>) -> Self {
+        self.0.assertion_id = assertion_id.into();
+        self
+    }
+
+    pub fn authentication_instant(mut self, authentication_instant: impl Into<String>) -> Self {
+        self.0.authentication_instant = authentication_instant.into();
+        self
+    }
+
+    pub fn authentication_method(mut self, authentication_method: impl Into<String>) -> Self {
+        self.0.authentication_method = authentication_method.into();
+        self
+    }
+
+    pub fn client_user_id(mut self, client_user_id: impl Into<String>) -> Self {
+        self.0.client_user_id = client_user_id.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn frame_ancestors(mut self, frame_ancestors: Vec<String>) -> Self {
+        self.0.frame_ancestors = frame_ancestors;
+        self
+    }
+
+    pub fn message_origins(mut self, message_origins: Vec<String>) -> Self {
+        self.0.message_origins = message_origins;
+        self
+    }
+
+    pub fn ping_frequency(mut self, ping_frequency: impl Into<String>) -> Self {
+        self.0.ping_frequency = ping_frequency.into();
+        self
+    }
+
+    pub fn ping_url(mut self, ping_url: impl Into<String>) -> Self {
+        self.0.ping_url = ping_url.into();
+        self
+    }
+
+    pub fn recipient_id(mut self, recipient_id: impl Into<String>) -> Self {
+        self.0.recipient_id = recipient_id.into();
+        self
+    }
+
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.0.return_url = return_url.into();
+        self
+    }
+
+    pub fn security_domain(mut self, security_domain: impl Into<String>) -> Self {
+        self.0.security_domain = security_domain.into();
+        self
+    }
+
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.0.user_id = user_id.into();
+        self
+    }
+
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.0.user_name = user_name.into();
+        self
+    }
+
+    pub fn x_frame_options(mut self, x_frame_options: impl Into<String>) -> Self {
+        self.0.x_frame_options = x_frame_options.into();
+        self
+    }
+
+    pub fn x_frame_options_allow_from_url(
+        mut self,
+        x_frame_options_allow_from_url: impl Into<String>,
+    ) -> Self {
+        self.0.x_frame_options_allow_from_url = x_frame_options_allow_from_url.into();
+        self
+    }
+
+    pub fn build(self) -> RecipientViewRequest {
+        self.0
+    }
+}
+
 /// Specifies the envelope recipients.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Recipients {
@@ -80461,7 +81215,7 @@ pub struct Reminders {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReportInProductCsvRunRequest {
     /**
     *
@@ -80722,6 +81476,170 @@ pub struct ReportInProductCsvRunRequest {
     pub start_position: String,
 }
 
+impl ReportInProductCsvRunRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReportInProductCsvRunRequestBuilder {
+        ReportInProductCsvRunRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReportInProductCsvRunRequest`.
+#[derive(Debug, Clone)]
+pub struct ReportInProductCsvRunRequestBuilder(ReportInProductCsvRunRequest);
+
+impl ReportInProductCsvRunRequestBuilder {
+    pub fn custom_field_filter(mut self, custom_field_filter: impl Into<String>) -> Self {
+        self.0.custom_field_filter = custom_field_filter.into();
+        self
+    }
+
+    pub fn date_range_custom_from_date(
+        mut self,
+        date_range_custom_from_date: impl Into<chrono::NaiveDate>,
+    ) -> Self {
+        self.0.date_range_custom_from_date = Some(date_range_custom_from_date.into());
+        self
+    }
+
+    pub fn date_range_custom_to_date(
+        mut self,
+        date_range_custom_to_date: impl Into<chrono::NaiveDate>,
+    ) -> Self {
+        self.0.date_range_custom_to_date = Some(date_range_custom_to_date.into());
+        self
+    }
+
+    pub fn date_range_filter(mut self, date_range_filter: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.date_range_filter = Some(date_range_filter.into());
+        self
+    }
+
+    pub fn envelope_date_type_filter(
+        mut self,
+        envelope_date_type_filter: impl Into<String>,
+    ) -> Self {
+        self.0.envelope_date_type_filter = envelope_date_type_filter.into();
+        self
+    }
+
+    pub fn envelope_recipient_name_contains_filter(
+        mut self,
+        envelope_recipient_name_contains_filter: impl Into<String>,
+    ) -> Self {
+        self.0.envelope_recipient_name_contains_filter =
+            envelope_recipient_name_contains_filter.into();
+        self
+    }
+
+    pub fn envelope_status_filter(mut self, envelope_status_filter: impl Into<String>) -> Self {
+        self.0.envelope_status_filter = envelope_status_filter.into();
+        self
+    }
+
+    pub fn envelope_subject_contains_filter(
+        mut self,
+        envelope_subject_contains_filter: impl Into<String>,
+    ) -> Self {
+        self.0.envelope_subject_contains_filter = envelope_subject_contains_filter.into();
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<ReportInProductField>) -> Self {
+        self.0.fields = fields;
+        self
+    }
+
+    pub fn for_download(mut self, for_download: impl Into<String>) -> Self {
+        self.0.for_download = for_download.into();
+        self
+    }
+
+    pub fn is_dashboard(mut self, is_dashboard: impl Into<String>) -> Self {
+        self.0.is_dashboard = is_dashboard.into();
+        self
+    }
+
+    pub fn new_line(mut self, new_line: impl Into<String>) -> Self {
+        self.0.new_line = new_line.into();
+        self
+    }
+
+    pub fn override_timezone_key(mut self, override_timezone_key: impl Into<String>) -> Self {
+        self.0.override_timezone_key = override_timezone_key.into();
+        self
+    }
+
+    pub fn period_length_filter(mut self, period_length_filter: impl Into<String>) -> Self {
+        self.0.period_length_filter = period_length_filter.into();
+        self
+    }
+
+    pub fn quote(mut self, quote: impl Into<String>) -> Self {
+        self.0.quote = quote.into();
+        self
+    }
+
+    pub fn report_customized_id(mut self, report_customized_id: impl Into<String>) -> Self {
+        self.0.report_customized_id = report_customized_id.into();
+        self
+    }
+
+    pub fn report_description(mut self, report_description: impl Into<String>) -> Self {
+        self.0.report_description = report_description.into();
+        self
+    }
+
+    pub fn report_id(mut self, report_id: impl Into<String>) -> Self {
+        self.0.report_id = report_id.into();
+        self
+    }
+
+    pub fn report_invocation_type(mut self, report_invocation_type: impl Into<String>) -> Self {
+        self.0.report_invocation_type = report_invocation_type.into();
+        self
+    }
+
+    pub fn report_name(mut self, report_name: impl Into<String>) -> Self {
+        self.0.report_name = report_name.into();
+        self
+    }
+
+    pub fn sent_by_filter(mut self, sent_by_filter: impl Into<String>) -> Self {
+        self.0.sent_by_filter = sent_by_filter.into();
+        self
+    }
+
+    pub fn sent_by_ids(mut self, sent_by_ids: impl Into<String>) -> Self {
+        self.0.sent_by_ids = sent_by_ids.into();
+        self
+    }
+
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.0.separator = separator.into();
+        self
+    }
+
+    pub fn sort_direction(mut self, sort_direction: impl Into<String>) -> Self {
+        self.0.sort_direction = sort_direction.into();
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: impl Into<String>) -> Self {
+        self.0.sort_field = sort_field.into();
+        self
+    }
+
+    pub fn start_position(mut self, start_position: impl Into<String>) -> Self {
+        self.0.start_position = start_position.into();
+        self
+    }
+
+    pub fn build(self) -> ReportInProductCsvRunRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReportInProductField {
@@ -81218,7 +82136,7 @@ pub struct ReportInProductListItem {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReportInProductRunRequest {
     /**
     *
@@ -81441,6 +82359,150 @@ pub struct ReportInProductRunRequest {
     pub start_position: String,
 }
 
+impl ReportInProductRunRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReportInProductRunRequestBuilder {
+        ReportInProductRunRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReportInProductRunRequest`.
+#[derive(Debug, Clone)]
+pub struct ReportInProductRunRequestBuilder(ReportInProductRunRequest);
+
+impl ReportInProductRunRequestBuilder {
+    pub fn custom_field_filter(mut self, custom_field_filter: impl Into<String>) -> Self {
+        self.0.custom_field_filter = custom_field_filter.into();
+        self
+    }
+
+    pub fn date_range_custom_from_date(
+        mut self,
+        date_range_custom_from_date: impl Into<chrono::NaiveDate>,
+    ) -> Self {
+        self.0.date_range_custom_from_date = Some(date_range_custom_from_date.into());
+        self
+    }
+
+    pub fn date_range_custom_to_date(
+        mut self,
+        date_range_custom_to_date: impl Into<chrono::NaiveDate>,
+    ) -> Self {
+        self.0.date_range_custom_to_date = Some(date_range_custom_to_date.into());
+        self
+    }
+
+    pub fn date_range_filter(mut self, date_range_filter: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.date_range_filter = Some(date_range_filter.into());
+        self
+    }
+
+    pub fn envelope_date_type_filter(
+        mut self,
+        envelope_date_type_filter: impl Into<String>,
+    ) -> Self {
+        self.0.envelope_date_type_filter = envelope_date_type_filter.into();
+        self
+    }
+
+    pub fn envelope_recipient_name_contains_filter(
+        mut self,
+        envelope_recipient_name_contains_filter: impl Into<String>,
+    ) -> Self {
+        self.0.envelope_recipient_name_contains_filter =
+            envelope_recipient_name_contains_filter.into();
+        self
+    }
+
+    pub fn envelope_status_filter(mut self, envelope_status_filter: impl Into<String>) -> Self {
+        self.0.envelope_status_filter = envelope_status_filter.into();
+        self
+    }
+
+    pub fn envelope_subject_contains_filter(
+        mut self,
+        envelope_subject_contains_filter: impl Into<String>,
+    ) -> Self {
+        self.0.envelope_subject_contains_filter = envelope_subject_contains_filter.into();
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<ReportInProductField>) -> Self {
+        self.0.fields = fields;
+        self
+    }
+
+    pub fn for_download(mut self, for_download: impl Into<String>) -> Self {
+        self.0.for_download = for_download.into();
+        self
+    }
+
+    pub fn is_dashboard(mut self, is_dashboard: impl Into<String>) -> Self {
+        self.0.is_dashboard = is_dashboard.into();
+        self
+    }
+
+    pub fn period_length_filter(mut self, period_length_filter: impl Into<String>) -> Self {
+        self.0.period_length_filter = period_length_filter.into();
+        self
+    }
+
+    pub fn report_customized_id(mut self, report_customized_id: impl Into<String>) -> Self {
+        self.0.report_customized_id = report_customized_id.into();
+        self
+    }
+
+    pub fn report_description(mut self, report_description: impl Into<String>) -> Self {
+        self.0.report_description = report_description.into();
+        self
+    }
+
+    pub fn report_id(mut self, report_id: impl Into<String>) -> Self {
+        self.0.report_id = report_id.into();
+        self
+    }
+
+    pub fn report_invocation_type(mut self, report_invocation_type: impl Into<String>) -> Self {
+        self.0.report_invocation_type = report_invocation_type.into();
+        self
+    }
+
+    pub fn report_name(mut self, report_name: impl Into<String>) -> Self {
+        self.0.report_name = report_name.into();
+        self
+    }
+
+    pub fn sent_by_filter(mut self, sent_by_filter: impl Into<String>) -> Self {
+        self.0.sent_by_filter = sent_by_filter.into();
+        self
+    }
+
+    pub fn sent_by_ids(mut self, sent_by_ids: impl Into<String>) -> Self {
+        self.0.sent_by_ids = sent_by_ids.into();
+        self
+    }
+
+    pub fn sort_direction(mut self, sort_direction: impl Into<String>) -> Self {
+        self.0.sort_direction = sort_direction.into();
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: impl Into<String>) -> Self {
+        self.0.sort_field = sort_field.into();
+        self
+    }
+
+    pub fn start_position(mut self, start_position: impl Into<String>) -> Self {
+        self.0.start_position = start_position.into();
+        self
+    }
+
+    pub fn build(self) -> ReportInProductRunRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReportInProductRunResponse {
@@ -82805,7 +83867,7 @@ pub struct ResourceInformation {
 }
 
 /// The request body for the EnvelopeViews::createSender method.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReturnUrlRequest {
     /**
     * The request body for the EnvelopeViews::createSender method.
@@ -82819,6 +83881,43 @@ pub struct ReturnUrlRequest {
     pub return_url: String,
 }
 
+impl std::convert::From<String> for ReturnUrlRequest {
+    fn from(return_url: String) -> Self {
+        ReturnUrlRequest { return_url }
+    }
+}
+
+impl std::convert::From<&str> for ReturnUrlRequest {
+    fn from(return_url: &str) -> Self {
+        ReturnUrlRequest {
+            return_url: return_url.to_string(),
+        }
+    }
+}
+
+impl ReturnUrlRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReturnUrlRequestBuilder {
+        ReturnUrlRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReturnUrlRequest`.
+#[derive(Debug, Clone)]
+pub struct ReturnUrlRequestBuilder(ReturnUrlRequest);
+
+impl ReturnUrlRequestBuilder {
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.0.return_url = return_url.into();
+        self
+    }
+
+    pub fn build(self) -> ReturnUrlRequest {
+        self.0
+    }
+}
+
 /// Set of information related to the electronic seal used by the Trust Service Provider (TSP).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Seal {
@@ -93277,7 +94376,7 @@ pub struct TemplateMatch {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TemplateNotificationRequest {
     /**
     *
@@ -93310,6 +94409,44 @@ pub struct TemplateNotificationRequest {
     pub use_account_defaults: String,
 }
 
+impl TemplateNotificationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> TemplateNotificationRequestBuilder {
+        TemplateNotificationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `TemplateNotificationRequest`.
+#[derive(Debug, Clone)]
+pub struct TemplateNotificationRequestBuilder(TemplateNotificationRequest);
+
+impl TemplateNotificationRequestBuilder {
+    pub fn expirations(mut self, expirations: impl Into<Expirations>) -> Self {
+        self.0.expirations = Some(expirations.into());
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.0.password = password.into();
+        self
+    }
+
+    pub fn reminders(mut self, reminders: impl Into<Reminders>) -> Self {
+        self.0.reminders = Some(reminders.into());
+        self
+    }
+
+    pub fn use_account_defaults(mut self, use_account_defaults: impl Into<String>) -> Self {
+        self.0.use_account_defaults = use_account_defaults.into();
+        self
+    }
+
+    pub fn build(self) -> TemplateNotificationRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TemplateRecipientsData {
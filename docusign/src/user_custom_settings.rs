@@ -41,9 +41,11 @@ impl UserCustomSettings {
     */
     pub async fn get(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::CustomSettingsInformation> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/custom_settings",
             crate::progenitor_support::encode_path(account_id),
@@ -88,10 +90,12 @@ impl UserCustomSettings {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::CustomSettingsInformation,
     ) -> Result<crate::types::CustomSettingsInformation> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/custom_settings",
             crate::progenitor_support::encode_path(account_id),
@@ -128,10 +132,12 @@ impl UserCustomSettings {
     */
     pub async fn delete(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::CustomSettingsInformation,
     ) -> Result<crate::types::CustomSettingsInformation> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/custom_settings",
             crate::progenitor_support::encode_path(account_id),
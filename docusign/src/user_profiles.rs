@@ -27,7 +27,13 @@ impl UserProfiles {
     * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
     *   .
     */
-    pub async fn get(&self, account_id: &str, user_id: &str) -> Result<crate::types::UserProfile> {
+    pub async fn get(
+        &self,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<crate::types::UserProfile> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile",
             crate::progenitor_support::encode_path(account_id),
@@ -54,10 +60,12 @@ impl UserProfiles {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserProfile,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile",
             crate::progenitor_support::encode_path(account_id),
@@ -38,20 +38,23 @@ impl UserSignatures {
     */
     pub async fn get(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         stamp_type: &str,
     ) -> Result<crate::types::UserSignaturesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !stamp_type.is_empty() {
-            query_args.push(("stamp_type".to_string(), stamp_type.to_string()));
+            query_args.push(("stamp_type", stamp_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/signatures",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -72,10 +75,12 @@ impl UserSignatures {
     */
     pub async fn put_signature(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserSignaturesInformation,
     ) -> Result<crate::types::UserSignaturesInformation> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures",
             crate::progenitor_support::encode_path(account_id),
@@ -120,10 +125,12 @@ impl UserSignatures {
     */
     pub async fn post(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserSignaturesInformation,
     ) -> Result<crate::types::UserSignaturesInformation> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures",
             crate::progenitor_support::encode_path(account_id),
@@ -157,10 +164,13 @@ impl UserSignatures {
     */
     pub async fn get_signature(
         &self,
-        account_id: &str,
-        signature_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::UserSignature> {
+        let account_id = account_id.as_ref();
+        let signature_id = signature_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -196,26 +206,30 @@ impl UserSignatures {
     */
     pub async fn put_signature_user_signatures(
         &self,
-        account_id: &str,
-        signature_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         close_existing_signature: &str,
         body: &crate::types::UserSignatureDefinition,
     ) -> Result<crate::types::UserSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let signature_id = signature_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !close_existing_signature.is_empty() {
             query_args.push((
-                "close_existing_signature".to_string(),
+                "close_existing_signature",
                 close_existing_signature.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(signature_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -245,10 +259,13 @@ impl UserSignatures {
     */
     pub async fn delete_signature(
         &self,
-        account_id: &str,
-        signature_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let signature_id = signature_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -288,24 +305,29 @@ impl UserSignatures {
     */
     pub async fn get_signature_image(
         &self,
-        account_id: &str,
-        image_type: &str,
-        signature_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        image_type: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let image_type = image_type.as_ref();
+        let signature_id = signature_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(signature_id),
-            crate::progenitor_support::encode_path(image_type),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -339,24 +361,29 @@ impl UserSignatures {
     */
     pub async fn put_signature_image(
         &self,
-        account_id: &str,
-        image_type: &str,
-        signature_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        image_type: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         transparent_png: &str,
     ) -> Result<crate::types::UserSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let image_type = image_type.as_ref();
+        let signature_id = signature_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !transparent_png.is_empty() {
-            query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
+            query_args.push(("transparent_png", transparent_png.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(signature_id),
-            crate::progenitor_support::encode_path(image_type),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -390,11 +417,15 @@ impl UserSignatures {
     */
     pub async fn delete_signature_image(
         &self,
-        account_id: &str,
-        image_type: &str,
-        signature_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        image_type: impl AsRef<str>,
+        signature_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::UserSignature> {
+        let account_id = account_id.as_ref();
+        let image_type = image_type.as_ref();
+        let signature_id = signature_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -51,7 +51,7 @@ impl Users {
     */
     pub async fn get(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         additional_info: &str,
         count: &str,
         email: &str,
@@ -64,51 +64,50 @@ impl Users {
         status: &str,
         user_name_substring: &str,
     ) -> Result<crate::types::UserInformationList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !additional_info.is_empty() {
-            query_args.push(("additional_info".to_string(), additional_info.to_string()));
+            query_args.push(("additional_info", additional_info.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !email_substring.is_empty() {
-            query_args.push(("email_substring".to_string(), email_substring.to_string()));
+            query_args.push(("email_substring", email_substring.to_string()));
         }
         if !group_id.is_empty() {
-            query_args.push(("group_id".to_string(), group_id.to_string()));
+            query_args.push(("group_id", group_id.to_string()));
         }
         if !include_usersettings_for_csv.is_empty() {
             query_args.push((
-                "include_usersettings_for_csv".to_string(),
+                "include_usersettings_for_csv",
                 include_usersettings_for_csv.to_string(),
             ));
         }
         if !login_status.is_empty() {
-            query_args.push(("login_status".to_string(), login_status.to_string()));
+            query_args.push(("login_status", login_status.to_string()));
         }
         if !not_group_id.is_empty() {
-            query_args.push(("not_group_id".to_string(), not_group_id.to_string()));
+            query_args.push(("not_group_id", not_group_id.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !user_name_substring.is_empty() {
-            query_args.push((
-                "user_name_substring".to_string(),
-                user_name_substring.to_string(),
-            ));
+            query_args.push(("user_name_substring", user_name_substring.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -127,9 +126,10 @@ impl Users {
     */
     pub async fn put(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::UserInformationList,
     ) -> Result<crate::types::UserInformationList> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users",
             crate::progenitor_support::encode_path(account_id),
@@ -211,9 +211,10 @@ impl Users {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::NewUsersDefinition,
     ) -> Result<crate::types::NewUsersSummary> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users",
             crate::progenitor_support::encode_path(account_id),
@@ -240,19 +241,21 @@ impl Users {
     */
     pub async fn delete(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         delete: &str,
         body: &crate::types::UserInfoList,
     ) -> Result<crate::types::UsersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !delete.is_empty() {
-            query_args.push(("delete".to_string(), delete.to_string()));
+            query_args.push(("delete", delete.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -279,24 +282,27 @@ impl Users {
     */
     pub async fn get_users(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         additional_info: &str,
         email: &str,
     ) -> Result<crate::types::UserInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !additional_info.is_empty() {
-            query_args.push(("additional_info".to_string(), additional_info.to_string()));
+            query_args.push(("additional_info", additional_info.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -317,10 +323,12 @@ impl Users {
     */
     pub async fn put_users(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserInformation,
     ) -> Result<crate::types::UserInformation> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -352,20 +360,23 @@ impl Users {
     */
     pub async fn profile_image_get(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         encoding: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/profile/image?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/users/{}/profile/image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -386,7 +397,13 @@ impl Users {
     * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
     *   .
     */
-    pub async fn profile_image_put(&self, account_id: &str, user_id: &str) -> Result<()> {
+    pub async fn profile_image_put(
+        &self,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile/image",
             crate::progenitor_support::encode_path(account_id),
@@ -411,7 +428,13 @@ impl Users {
     * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
     *   .
     */
-    pub async fn profile_image_delete(&self, account_id: &str, user_id: &str) -> Result<()> {
+    pub async fn profile_image_delete(
+        &self,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile/image",
             crate::progenitor_support::encode_path(account_id),
@@ -446,9 +469,11 @@ impl Users {
     */
     pub async fn settings_get(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::UserSettingsInformation> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/settings",
             crate::progenitor_support::encode_path(account_id),
@@ -473,10 +498,12 @@ impl Users {
     */
     pub async fn settings_put(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserSettingsInformation,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/users/{}/settings",
             crate::progenitor_support::encode_path(account_id),
@@ -34,9 +34,9 @@ impl WorkspaceItems {
     */
     pub async fn workspace_folder_get(
         &self,
-        account_id: &str,
-        folder_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
         count: &str,
         include_files: &str,
         include_sub_folders: &str,
@@ -45,47 +45,39 @@ impl WorkspaceItems {
         start_position: &str,
         workspace_user_id: &str,
     ) -> Result<crate::types::WorkspaceFolderContents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !include_files.is_empty() {
-            query_args.push(("include_files".to_string(), include_files.to_string()));
+            query_args.push(("include_files", include_files.to_string()));
         }
         if !include_sub_folders.is_empty() {
-            query_args.push((
-                "include_sub_folders".to_string(),
-                include_sub_folders.to_string(),
-            ));
+            query_args.push(("include_sub_folders", include_sub_folders.to_string()));
         }
         if !include_thumbnails.is_empty() {
-            query_args.push((
-                "include_thumbnails".to_string(),
-                include_thumbnails.to_string(),
-            ));
+            query_args.push(("include_thumbnails", include_thumbnails.to_string()));
         }
         if !include_user_detail.is_empty() {
-            query_args.push((
-                "include_user_detail".to_string(),
-                include_user_detail.to_string(),
-            ));
+            query_args.push(("include_user_detail", include_user_detail.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !workspace_user_id.is_empty() {
-            query_args.push((
-                "workspace_user_id".to_string(),
-                workspace_user_id.to_string(),
-            ));
+            query_args.push(("workspace_user_id", workspace_user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/workspaces/{}/folders/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(workspace_id),
-            crate::progenitor_support::encode_path(folder_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -108,11 +100,14 @@ impl WorkspaceItems {
     */
     pub async fn workspace_folder_delete_items(
         &self,
-        account_id: &str,
-        folder_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
         body: &crate::types::WorkspaceItemList,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}/folders/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -140,10 +135,13 @@ impl WorkspaceItems {
     */
     pub async fn workspace_file_post_files(
         &self,
-        account_id: &str,
-        folder_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
     ) -> Result<crate::types::WorkspaceItem> {
+        let account_id = account_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}/folders/{}/files",
             crate::progenitor_support::encode_path(account_id),
@@ -172,28 +170,33 @@ impl WorkspaceItems {
     */
     pub async fn workspace_file_get(
         &self,
-        account_id: &str,
-        file_id: &str,
-        folder_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        file_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
         is_download: &str,
         pdf_version: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let file_id = file_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !is_download.is_empty() {
-            query_args.push(("is_download".to_string(), is_download.to_string()));
+            query_args.push(("is_download", is_download.to_string()));
         }
         if !pdf_version.is_empty() {
-            query_args.push(("pdf_version".to_string(), pdf_version.to_string()));
+            query_args.push(("pdf_version", pdf_version.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(workspace_id),
-            crate::progenitor_support::encode_path(folder_id),
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -215,11 +218,15 @@ impl WorkspaceItems {
     */
     pub async fn workspace_file_put(
         &self,
-        account_id: &str,
-        file_id: &str,
-        folder_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        file_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
     ) -> Result<crate::types::WorkspaceItem> {
+        let account_id = account_id.as_ref();
+        let file_id = file_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -252,40 +259,45 @@ impl WorkspaceItems {
     */
     pub async fn workspace_file_pages_get(
         &self,
-        account_id: &str,
-        file_id: &str,
-        folder_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        file_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
         count: &str,
         dpi: &str,
         max_height: &str,
         max_width: &str,
         start_position: &str,
     ) -> Result<crate::types::PageImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let file_id = file_id.as_ref();
+        let folder_id = folder_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}/pages?{}",
-            crate::progenitor_support::encode_path(account_id),
-            crate::progenitor_support::encode_path(workspace_id),
-            crate::progenitor_support::encode_path(folder_id),
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}/pages",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -23,7 +23,8 @@ impl Workspaces {
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
     */
-    pub async fn get(&self, account_id: &str) -> Result<crate::types::WorkspaceList> {
+    pub async fn get(&self, account_id: impl AsRef<str>) -> Result<crate::types::WorkspaceList> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces",
             crate::progenitor_support::encode_path(account_id),
@@ -45,9 +46,10 @@ impl Workspaces {
     */
     pub async fn post(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::Workspace,
     ) -> Result<crate::types::Workspace> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces",
             crate::progenitor_support::encode_path(account_id),
@@ -72,9 +74,11 @@ impl Workspaces {
     */
     pub async fn get_workspaces(
         &self,
-        account_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
     ) -> Result<crate::types::Workspace> {
+        let account_id = account_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -98,10 +102,12 @@ impl Workspaces {
     */
     pub async fn put(
         &self,
-        account_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
         body: &crate::types::Workspace,
     ) -> Result<crate::types::Workspace> {
+        let account_id = account_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -127,9 +133,11 @@ impl Workspaces {
     */
     pub async fn delete(
         &self,
-        account_id: &str,
-        workspace_id: &str,
+        account_id: impl AsRef<str>,
+        workspace_id: impl AsRef<str>,
     ) -> Result<crate::types::Workspace> {
+        let account_id = account_id.as_ref();
+        let workspace_id = workspace_id.as_ref();
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -0,0 +1,50 @@
+const TEMPLATE: &str = r#"//! A blocking client, for code that is not async.
+//!
+//! As with `reqwest::blocking`, the requests are run to completion on a runtime owned
+//! by the client, so the blocking client must not be used from within an async
+//! runtime. Every function of the async client is available through it, and
+//! `block_on` waits for the request it is given.
+use std::{future::Future, ops::Deref};
+
+use anyhow::Result;
+
+/// A client whose requests block the current thread until they complete.
+pub struct Client {
+    client: crate::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Client {
+    /// Create a blocking client from an async client.
+    pub fn new(client: crate::Client) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client { client, runtime })
+    }
+
+    /// Run a request of the client, blocking until it completes.
+    pub fn block_on<F: Future>(&self, request: F) -> F::Output {
+        self.runtime.block_on(request)
+    }
+
+    /// Return the async client.
+    pub fn into_async(self) -> crate::Client {
+        self.client
+    }
+}
+
+impl Deref for Client {
+    type Target = crate::Client;
+
+    fn deref(&self) -> &crate::Client {
+        &self.client
+    }
+}
+"#;
+
+/// Generate the blocking module, shared by all the clients.
+pub fn generate_blocking() -> String {
+    TEMPLATE.to_string()
+}
@@ -0,0 +1,331 @@
+const TEMPLATE: &str = r#"//! An in-memory cache of the responses to `GET` requests.
+//!
+//! Dashboards and other read-heavy callers often fetch the same resources over and
+//! over. With a cache, the responses are kept for a while and the same request
+//! made again is answered from memory instead of reaching the API.
+//!
+//! Responses are cached by method, URL, credentials and the headers choosing their
+//! representation, like `Accept`, so clients authenticated as different users never
+//! share responses and a raw download is never answered with JSON. Only successful
+//! responses are kept, up to `max_body_size`, and not the ones varying on other
+//! headers of the request. Streamed downloads skip the cache. A request with another
+//! method, like a `PATCH`, evicts the cached responses for its path and the paths
+//! below it, whatever their query.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use {crate_name}::cache::ResponseCache;
+//!
+//! // Keep responses for a minute, the ones of a single user for ten and do not
+//! // cache the searches at all.
+//! let cache = ResponseCache::new(Duration::from_secs(60))
+//!     .ttl_for("/users/{id}", Duration::from_secs(10 * 60))
+//!     .ttl_for("/search/{kind}", Duration::ZERO);
+//!
+//! // Pass `cache` to `Client::with_cache`. Clients given clones of it share the
+//! // cached responses.
+//! ```
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    future::Future,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Result;
+use bytes::Bytes;
+use reqwest::{
+    header::{HeaderMap, VARY},
+    Method, Request, Response, StatusCode, Url,
+};
+use web_time::Instant;
+
+/// A cache of the responses to `GET` requests, with a time to live per endpoint.
+///
+/// Clones share the same cached responses.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    default_ttl: Duration,
+    ttls: Vec<(String, Duration)>,
+    max_body_size: u64,
+    entries: Arc<Mutex<HashMap<CacheKey, CachedResponse>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    method: Method,
+    url: String,
+    /// A hash of the credentials and of the other `KEY_HEADERS`, the credentials are
+    /// not kept around in the clear.
+    variant: u64,
+}
+
+/// The headers of a request that the response depends on, which are part of the
+/// key of the cached responses.
+const KEY_HEADERS: &[&str] = &[
+    "accept",
+    "accept-encoding",
+    "accept-language",
+    "authorization",
+];
+
+/// The size of the largest body cached by default, in bytes.
+pub const DEFAULT_MAX_BODY_SIZE: u64 = 1024 * 1024;
+
+#[derive(Debug)]
+struct CachedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    expires_at: Instant,
+}
+
+impl ResponseCache {
+    /// A cache keeping responses for `default_ttl`, unless the endpoint has a time
+    /// to live of its own.
+    pub fn new(default_ttl: Duration) -> Self {
+        ResponseCache {
+            default_ttl,
+            ttls: Vec::new(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            entries: Default::default(),
+        }
+    }
+
+    /// Keep the responses of an endpoint for `ttl`, or not at all when it is zero.
+    ///
+    /// The endpoint is given by its path as in the documentation of the functions,
+    /// where the parameters in braces match any segment: `/users/{id}` matches the
+    /// path `/users/42`. When more than one endpoint matches, the one with the most
+    /// segments wins.
+    pub fn ttl_for(mut self, endpoint: &str, ttl: Duration) -> Self {
+        self.ttls.push((endpoint.to_string(), ttl));
+        self.ttls
+            .sort_by_key(|(e, _)| std::cmp::Reverse(segments(e).count()));
+        self
+    }
+
+    /// Do not cache the responses with a body larger than `max_body_size` bytes.
+    pub fn max_body_size(mut self, max_body_size: u64) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// The time to live of the responses for the given URL path.
+    pub fn ttl(&self, path: &str) -> Duration {
+        self.ttls
+            .iter()
+            .find(|(endpoint, _)| matches_endpoint(endpoint, path))
+            .map(|(_, ttl)| *ttl)
+            .unwrap_or(self.default_ttl)
+    }
+
+    /// Drop all the cached responses.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// The number of cached responses, expired ones included.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Send a request with `send`, answering it from the cache when possible.
+    pub(crate) async fn execute<S, F>(&self, req: Request, send: S) -> Result<Response>
+    where
+        S: FnOnce(Request) -> F,
+        F: Future<Output = Result<Response>>,
+    {
+        let key = CacheKey {
+            method: req.method().clone(),
+            url: req.url().to_string(),
+            variant: variant(req.headers()),
+        };
+
+        if key.method != Method::GET {
+            // The request likely changes the resource, whoever reads it next.
+            let url = req.url();
+            self.entries
+                .lock()
+                .unwrap()
+                .retain(|k, _| !is_below(&k.url, url));
+            return send(req).await;
+        }
+
+        let ttl = self.ttl(req.url().path());
+        if ttl.is_zero() {
+            return send(req).await;
+        }
+
+        if let Some(resp) = self.lookup(&key) {
+            return resp;
+        }
+
+        let req_headers = req.headers().clone();
+        let resp = send(req).await?;
+        if !resp.status().is_success()
+            || resp.content_length().unwrap_or_default() > self.max_body_size
+            || varies(resp.headers(), &req_headers)
+        {
+            return Ok(resp);
+        }
+
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?;
+        let resp = to_response(status, &headers, body.clone());
+        if body.len() as u64 > self.max_body_size {
+            return resp;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        let now = Instant::now();
+        entries.retain(|_, e| e.expires_at > now);
+        entries.insert(
+            key,
+            CachedResponse {
+                status,
+                headers,
+                body,
+                expires_at: now + ttl,
+            },
+        );
+
+        resp
+    }
+
+    fn lookup(&self, key: &CacheKey) -> Option<Result<Response>> {
+        let entries = self.entries.lock().unwrap();
+        let entry = entries.get(key)?;
+        if entry.expires_at <= Instant::now() {
+            return None;
+        }
+
+        Some(to_response(
+            entry.status,
+            &entry.headers,
+            entry.body.clone(),
+        ))
+    }
+}
+
+pub(crate) fn to_response(
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: Bytes,
+) -> Result<Response> {
+    let mut resp = http::Response::builder().status(status).body(body)?;
+    *resp.headers_mut() = headers.clone();
+    Ok(Response::from(resp))
+}
+
+/// A hash of the `KEY_HEADERS` of a request.
+pub(crate) fn variant(headers: &HeaderMap) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for name in KEY_HEADERS {
+        for value in headers.get_all(*name) {
+            name.hash(&mut hasher);
+            value.as_bytes().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// Whether the response varies on headers of the request which are not part of the
+/// key, so that it can not be cached.
+fn varies(headers: &HeaderMap, req_headers: &HeaderMap) -> bool {
+    headers
+        .get_all(VARY)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .any(|name| {
+            name == "*"
+                || (!KEY_HEADERS.iter().any(|k| k.eq_ignore_ascii_case(name))
+                    && req_headers.contains_key(name))
+        })
+}
+
+/// Whether the cached URL is the one of the resource at `url` or of a resource
+/// below it, like its comments, with any query.
+fn is_below(cached: &str, url: &Url) -> bool {
+    let cached = match Url::parse(cached) {
+        Ok(cached) => cached,
+        Err(_) => return true,
+    };
+    if cached.origin() != url.origin() {
+        return false;
+    }
+
+    let mut cached = segments(cached.path());
+    segments(url.path()).all(|s| cached.next() == Some(s))
+}
+
+pub(crate) fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|s| !s.is_empty())
+}
+
+/// Whether the path ends with the segments of the endpoint, so that endpoints can
+/// be given without the base path of the API.
+pub(crate) fn matches_endpoint(endpoint: &str, path: &str) -> bool {
+    let endpoint: Vec<&str> = segments(endpoint).collect();
+    let path: Vec<&str> = segments(path).collect();
+    if endpoint.is_empty() || endpoint.len() > path.len() {
+        return false;
+    }
+
+    endpoint
+        .iter()
+        .zip(&path[path.len() - endpoint.len()..])
+        .all(|(e, p)| matches_segment(e, p))
+}
+
+/// Whether a segment of a path matches a segment of an endpoint, like
+/// `{spreadsheetId}:batchUpdate`, where a parameter matches one character or more.
+fn matches_segment(endpoint: &str, segment: &str) -> bool {
+    let (start, end) = match endpoint.find('{').zip(endpoint.find('}')) {
+        Some((start, end)) if start < end => (start, end),
+        _ => return endpoint == segment,
+    };
+
+    let segment = match segment.strip_prefix(&endpoint[..start]) {
+        Some(segment) => segment,
+        None => return false,
+    };
+    (1..=segment.len())
+        .filter(|i| segment.is_char_boundary(*i))
+        .any(|i| matches_segment(&endpoint[end + 1..], &segment[i..]))
+}
+
+impl crate::Client {
+    /// Answer the `GET` requests made with the returned client from the given cache
+    /// when it has a fresh response for them.
+    pub fn with_cache(&self, cache: ResponseCache) -> Self {
+        let mut c = self.clone();
+        c.request_options.cache = Some(cache);
+        c
+    }
+
+    /// The client for the requests whose response is streamed, which skip the cache
+    /// and the coalescing of requests in flight since both read responses in memory.
+    pub(crate) fn streaming(&self) -> Self {
+        let mut c = self.clone();
+        c.request_options.cache = None;
+        c.request_options.single_flight = None;
+        c
+    }
+}
+"#;
+
+/// Generate the response cache module, shared by all the clients.
+pub fn generate_cache(crate_name: &str) -> String {
+    TEMPLATE.replace("{crate_name}", &crate_name.replace('-', "_"))
+}
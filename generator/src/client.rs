@@ -9,9 +9,14 @@ pub const GITHUB_TEMPLATE: &str = r#"/// Entrypoint for interacting with the API
 #[derive(Clone)]
 pub struct Client {
     host: String,
+    upload_host: String,
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
     credentials: Option<crate::auth::Credentials>,
+    api_version: Option<String>,
+    rate_limits: crate::rate_budget::RateLimits,
+    page_concurrency: usize,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -25,6 +30,22 @@ impl Client {
         Self::host(DEFAULT_HOST, agent, credentials)
     }
 
+    /// Create a new Client for a GitHub Enterprise Server instance.
+    ///
+    /// `base_url` is the URL of the instance, for example `https://ghe.example.com`.
+    /// The REST API is served from `/api/v3` and uploads from `/api/uploads`.
+    pub fn enterprise<B, A, C>(base_url: B, agent: A, credentials: C) -> Result<Self>
+    where
+        B: Into<String>,
+        A: Into<String>,
+        C: Into<Option<crate::auth::Credentials>>,
+    {
+        let base_url = base_url.into();
+        let base_url = base_url.trim_end_matches('/');
+        let base_url = base_url.strip_suffix("/api/v3").unwrap_or(base_url);
+        Self::host(format!("{}/api/v3", base_url), agent, credentials)
+    }
+
     pub fn host<H, A, C>(host: H, agent: A, credentials: C) -> Result<Self>
     where
         H: Into<String>,
@@ -32,12 +53,14 @@ impl Client {
         C: Into<Option<crate::auth::Credentials>>,
     {
         let http = reqwest::Client::builder().build()?;
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
                 let client = reqwest_middleware::ClientBuilder::new(http)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware)
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
         #[cfg(feature = "httpcache")]
         {
@@ -55,6 +78,9 @@ impl Client {
         }
     }
 
+    /// Create a new Client from a custom HTTP client. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics.
     #[cfg(feature = "httpcache")]
     pub fn custom<H, A, CR>(
         host: H,
@@ -68,15 +94,24 @@ impl Client {
         A: Into<String>,
         CR: Into<Option<crate::auth::Credentials>>,
     {
+        let host = host.into().trim_end_matches('/').to_string();
         Self {
-            host: host.into(),
+            upload_host: Self::default_upload_host(&host),
+            host,
             agent: agent.into(),
             client: http,
+            request_options: Default::default(),
             credentials: credentials.into(),
+            api_version: None,
+            rate_limits: Default::default(),
+            page_concurrency: 1,
             http_cache,
         }
     }
 
+    /// Create a new Client from a custom HTTP client. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics.
     #[cfg(not(feature = "httpcache"))]
     pub fn custom<H, A, CR>(host: H, agent: A, credentials: CR, http: reqwest_middleware::ClientWithMiddleware) -> Self
     where
@@ -84,11 +119,17 @@ impl Client {
         A: Into<String>,
         CR: Into<Option<crate::auth::Credentials>>,
     {
+        let host = host.into().trim_end_matches('/').to_string();
         Self {
-            host: host.into(),
+            upload_host: Self::default_upload_host(&host),
+            host,
             agent: agent.into(),
             client: http,
+            request_options: Default::default(),
             credentials: credentials.into(),
+            api_version: None,
+            rate_limits: Default::default(),
+            page_concurrency: 1,
         }
     }
 
@@ -99,6 +140,100 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// Override the host release assets are uploaded to, for instances that serve
+    /// uploads from somewhere else than the default for the host of the client.
+    pub fn set_upload_host<H>(&mut self, upload_host: H)
+    where
+        H: Into<String>,
+    {
+        self.upload_host = upload_host.into().trim_end_matches('/').to_string();
+    }
+
+    /// Send an `X-GitHub-Api-Version` header with every request, for example
+    /// `2022-11-28`, to pin the version of the REST API.
+    pub fn set_api_version<V>(&mut self, api_version: V)
+    where
+        V: Into<String>,
+    {
+        self.api_version = Some(api_version.into());
+    }
+
+    /// Ask for the given media type with the requests made with the returned client,
+    /// like `application/vnd.github.star+json` to get the times stargazers starred a
+    /// repository.
+    pub fn with_accept<V>(&self, media_type: V) -> Result<Self>
+    where
+        http::HeaderValue: std::convert::TryFrom<V>,
+        <http::HeaderValue as std::convert::TryFrom<V>>::Error: Into<http::Error>,
+    {
+        self.with_default_header(http::header::ACCEPT, media_type)
+    }
+
+    /// Ask for the preview of an API with the requests made with the returned client,
+    /// like `squirrel-girl` for the reactions of issues and comments.
+    pub fn with_preview(&self, codename: &'static str) -> Self {
+        let mut c = self.clone();
+        let media_type = mime::Mime::from(crate::utils::MediaType::Preview(codename));
+        c.request_options.headers.insert(
+            http::header::ACCEPT,
+            // A parsed media type is a valid header value.
+            http::HeaderValue::from_str(media_type.as_ref()).unwrap(),
+        );
+        c
+    }
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client given
+    /// to `custom`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }
+
+    /// Request up to `concurrency` pages at once in the functions that return all the
+    /// pages of a list, once the first page links to the last one. The items are
+    /// still returned in order.
+    ///
+    /// This defaults to 1, one page after the other, as GitHub asks for the requests
+    /// of a user to be made serially. Endpoints paginated with a cursor are always
+    /// followed one page after the other.
+    pub fn with_page_concurrency(&self, concurrency: usize) -> Self {
+        let mut c = self.clone();
+        c.page_concurrency = concurrency.max(1);
+        c
+    }
+
+    /// Send a request, applying the request options of the client and keeping track
+    /// of the rate limit in the response.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        let resp = self.request_options.execute(&self.client, req).await?;
+        self.rate_limits.observe(resp.headers());
+        Ok(resp)
+    }
+
+    /// Replace the cache used for conditional `GET` requests.
+    #[cfg(feature = "httpcache")]
+    pub fn set_http_cache(&mut self, http_cache: crate::http_cache::BoxedHttpCache) {
+        self.http_cache = http_cache;
+    }
+
     fn credentials(&self, authentication: crate::auth::AuthenticationConstraint) -> Option<&crate::auth::Credentials> {
         match (authentication, self.credentials.as_ref()) {
             (crate::auth::AuthenticationConstraint::Unconstrained, creds) => creds,
@@ -147,7 +282,6 @@ impl Client {
                     parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
                 } else {
                     log::debug!("app token is stale, refreshing");
-                    let token_ref = apptoken.access_key.clone();
 
                     let token = self.apps().create_installation_access_token(apptoken.installation_id as i64,
                     &types::AppsCreateInstallationAccessTokenRequest{
@@ -156,7 +290,7 @@ impl Client {
                         repository_ids: Default::default(),
                     }).await?;
                     let auth = format!("token {}", &token.token);
-                    *token_ref.lock().unwrap() = Some(token.token);
+                    apptoken.set_token(token.token, &token.expires_at);
                     parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
                 }
             }
@@ -206,11 +340,19 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
 
+        if let Some(api_version) = &instance.api_version {
+            req = req.header("X-GitHub-Api-Version", api_version.as_str());
+        }
+
         if let Some(body) = body {
-            log::debug!("body: {:?}", String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap());
+            // The bodies of these requests are in memory, uploads stream theirs separately.
+            let bytes = body
+                .as_bytes()
+                .ok_or_else(|| anyhow!("the body of the request must be in memory"))?;
+            log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
             req = req.body(body);
         }
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         #[cfg(feature = "httpcache")]
         let instance2 = <&Client>::clone(&self);
@@ -262,8 +404,8 @@ impl Client {
                 // header when cargo builds with --cfg feature="httpcache"
                 #[cfg(feature = "httpcache")]
                 {
-                    let body = instance2.http_cache.lookup_body(&uri3).unwrap();
-                    let out = serde_json::from_str::<Out>(&body).unwrap();
+                    let body = instance2.http_cache.lookup_body(&uri3)?;
+                    let out = serde_json::from_str::<Out>(&body)?;
                     let link = match link {
                         Some(link) => Ok(Some(link)),
                         None => instance2.http_cache.lookup_next_link(&uri3)
@@ -281,19 +423,15 @@ impl Client {
         } else {
             let error = match (remaining, reset) {
                 (Some(remaining), Some(reset)) if remaining == 0 => {
-                    let now = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
+                    let now = web_time::SystemTime::now()
+                        .duration_since(web_time::UNIX_EPOCH)
                         .unwrap()
                         .as_secs();
-                    anyhow!("rate limit exceeded, will reset in {} seconds", u64::from(reset) - now)
+                    anyhow!(crate::utils::RateLimitExceeded {
+                        reset_in: u64::from(reset).saturating_sub(now),
+                    })
                 },
-                _ => {
-                    if response_body.is_empty() {
-                        anyhow!("code: {}, empty response", status)
-                    } else {
-                        anyhow!("code: {}, error: {:?}", status, String::from_utf8_lossy(&response_body),)
-                    }
-                }
+                _ => crate::error::from_response(status, &response_body),
             };
             Err(error)
         }
@@ -314,6 +452,98 @@ impl Client {
         Ok(r)
     }
 
+    /// Send a request and return the response without reading the body.
+    ///
+    /// This is used for responses that are not JSON, or that are too large to be
+    /// buffered in memory.
+    async fn request_raw(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        media_type: crate::utils::MediaType,
+        authentication: crate::auth::AuthenticationConstraint,
+        headers: http::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let response = self.send_raw(method, uri, body, media_type, authentication, headers).await?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let response_body = response.bytes().await?;
+        Err(crate::error::from_response(status, &response_body))
+    }
+
+    /// Send a request and return the response, whatever its status.
+    async fn send_raw(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        media_type: crate::utils::MediaType,
+        authentication: crate::auth::AuthenticationConstraint,
+        headers: http::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let (url, auth) = self.url_and_auth(uri, authentication).await?;
+
+        let mut req = self.client.request(method, url);
+        req = req.header(http::header::USER_AGENT, &*self.agent);
+        req = req.header(
+            http::header::ACCEPT,
+            &*format!("{}", hyperx::header::qitem::<mime::Mime>(From::from(media_type))),
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(api_version) = &self.api_version {
+            req = req.header("X-GitHub-Api-Version", api_version.as_str());
+        }
+
+        for (key, value) in headers.iter() {
+            req = req.header(key, value);
+        }
+
+        if let Some(body) = body {
+            req = req.body(body);
+        }
+        self.execute(req.build()?).await
+    }
+
+    /// The host release assets are uploaded to by default. GitHub Enterprise Server
+    /// serves uploads from `/api/uploads` rather than from a separate host.
+    fn default_upload_host(host: &str) -> String {
+        match host.strip_suffix("/api/v3") {
+            Some(base) => format!("{}/api/uploads", base),
+            None => host.replacen("://api.", "://uploads.", 1),
+        }
+    }
+
+    async fn post_upload<D>(&self, uri: &str, content_type: &str, content_length: Option<u64>, body: reqwest::Body) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::CONTENT_TYPE, content_type.parse()?);
+        if let Some(content_length) = content_length {
+            headers.insert(http::header::CONTENT_LENGTH, content_length.into());
+        }
+
+        let response = self.request_raw(
+            http::Method::POST,
+            &(self.upload_host.clone() + uri),
+            Some(body),
+            crate::utils::MediaType::Json,
+            crate::auth::AuthenticationConstraint::Unconstrained,
+            headers,
+        ).await?;
+
+        Ok(serde_json::from_slice(&response.bytes().await?)?)
+    }
+
     async fn get<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -334,6 +564,30 @@ impl Client {
         ).await
     }
 
+    /// Whether the resource at `uri` exists, for the endpoints which answer with
+    /// `204 No Content` when it does and with `404 Not Found` when it does not.
+    async fn get_exists(&self, uri: &str) -> Result<bool> {
+        let response = self.send_raw(
+            http::Method::GET,
+            &(self.host.clone() + uri),
+            None,
+            crate::utils::MediaType::Json,
+            crate::auth::AuthenticationConstraint::Unconstrained,
+            http::HeaderMap::new(),
+        ).await?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        if status.is_success() {
+            return Ok(true);
+        }
+
+        let response_body = response.bytes().await?;
+        Err(crate::error::from_response(status, &response_body))
+    }
+
     async fn get_all_pages<D>(&self, uri: &str,  _message: Option<reqwest::Body>) -> Result<Vec<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -341,7 +595,28 @@ impl Client {
         self.unfold(uri).await
     }
 
-    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+    /// Stream the items of the JSON array in the response to a `GET`, parsing each of
+    /// them as soon as it downloaded rather than once the whole body is in memory. The
+    /// array is either the body itself, or the first field of the body holding an
+    /// array, like the `workflow_runs` of a listing of runs. Only the given page is
+    /// read, the pages after it are not followed.
+    pub async fn get_stream_items<T>(&self, uri: &str) -> Result<impl futures::Stream<Item = Result<T>>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self.streaming().request_raw(
+            http::Method::GET,
+            &(self.host.clone() + uri),
+            None,
+            crate::utils::MediaType::Json,
+            crate::auth::AuthenticationConstraint::Unconstrained,
+            http::HeaderMap::new(),
+        ).await?;
+
+        Ok(crate::json_stream::items(response))
+    }
+
+    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, D)>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -354,7 +629,7 @@ impl Client {
         ).await
     }
 
-    async fn get_pages_url<D>(&self, url: &reqwest::Url) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+    async fn get_pages_url<D>(&self, url: &reqwest::Url) -> Result<(Option<hyperx::header::Link>, D)>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -459,18 +734,54 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref().and_then(|l| crate::utils::next_link(l)) {
+        self.unfold_with(uri, |items: Vec<D>| items).await
+    }
+
+    /// "unfold" paginated results where every page wraps the items in an object,
+    /// like the `repositories` of `/installation/repositories`. `items` takes the
+    /// items out of a page.
+    ///
+    /// Pages are followed through the `rel="next"` link of the `Link` header until
+    /// there is none, which also works for endpoints paginated with a cursor.
+    async fn unfold_with<P, D, F>(
+        &self,
+        uri: &str,
+        items: F,
+    ) -> Result<Vec<D>>
+    where
+        P: serde::de::DeserializeOwned + 'static + Send,
+        D: Send,
+        F: Fn(P) -> Vec<D> + Send,
+    {
+        let (mut link, page) = self.get_pages(uri).await?;
+        let mut global_items = items(page);
+
+        // With numbered pages, the first one tells how many there are, so the others
+        // can be requested at once.
+        if let Some(urls) = link
+            .as_ref()
+            .filter(|_| self.page_concurrency > 1)
+            .and_then(crate::utils::page_urls)
+        {
+            let pages = urls.into_iter().map(|url| async move {
                 let url = reqwest::Url::parse(&url)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
+                let (_, page) = self.get_pages_url::<P>(&url).await?;
+                Ok::<_, Error>(page)
+            });
+            let mut pages =
+                futures::StreamExt::buffered(futures::stream::iter(pages), self.page_concurrency);
+            while let Some(page) = futures::TryStreamExt::try_next(&mut pages).await? {
+                global_items.append(&mut items(page));
             }
+
+            return Ok(global_items);
+        }
+
+        while let Some(url) = link.as_ref().and_then(|l| crate::utils::next_link(l)) {
+            let url = reqwest::Url::parse(&url)?;
+            let (new_link, page) = self.get_pages_url(&url).await?;
+            link = new_link;
+            global_items.append(&mut items(page));
         }
 
         Ok(global_items)
@@ -515,6 +826,60 @@ pub fn generate_client_generic_token(
         )
     };
 
+    // Ramp applies a `POST` only once per idempotency key, which makes retrying them safe.
+    let extra_middleware = if proper_name == "Ramp" {
+        r#"
+                    // Send `POST` requests with an idempotency key, kept across retries.
+                    .with(crate::idempotency::IdempotencyMiddleware)"#
+    } else {
+        ""
+    };
+
+    // Ramp, Gusto and DocuSign have a sandbox, with its own host and its own
+    // endpoints to get tokens from.
+    let (environment_type, environment_field, environment_init, with_environment, token_auth) =
+        if let Some((host, token_endpoint, user_consent_endpoint)) = sandbox_endpoints(proper_name)
+        {
+            (
+                format!(
+                    r#"
+const SANDBOX_HOST: &str = "https://{}";
+const SANDBOX_TOKEN_ENDPOINT: &str = "https://{}";
+const SANDBOX_USER_CONSENT_ENDPOINT: &str = "https://{}";
+
+{}"#,
+                    host, token_endpoint, user_consent_endpoint, ENVIRONMENT_TEMPLATE
+                ),
+                "environment: Environment,",
+                "environment: Environment::Production,",
+                WITH_ENVIRONMENT_TEMPLATE,
+                TOKEN_AUTH_TEMPLATE
+                    .replace("(TOKEN_ENDPOINT)", "(self.environment.token_endpoint())")
+                    .replace(
+                        "(USER_CONSENT_ENDPOINT,",
+                        "(self.environment.user_consent_endpoint(),",
+                    ),
+            )
+        } else if proper_name.starts_with("Google") {
+            // Google clients can also authenticate as a service account, which gets
+            // its access tokens from a signed assertion instead of a refresh token.
+            (
+                String::new(),
+                "service_account: Option<crate::service_account::ServiceAccount>,",
+                "service_account: None,",
+                NEW_FROM_SERVICE_ACCOUNT_TEMPLATE,
+                TOKEN_AUTH_TEMPLATE.replace(
+                    "async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {\n",
+                    FETCH_SERVICE_ACCOUNT_TOKEN_TEMPLATE,
+                ),
+            )
+        } else {
+            (String::new(), "", "", "", TOKEN_AUTH_TEMPLATE.to_string())
+        };
+
+    // A token endpoint which refuses the grant says why, like a request does.
+    let token_auth = token_auth.replace("TOKEN_ERROR", &response_error(proper_name, "body"));
+
     let add_post_header_type = if !add_post_header.is_empty() {
         ", P".to_string()
     } else {
@@ -526,11 +891,13 @@ pub fn generate_client_generic_token(
 use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
-use std::time::{{Duration, Instant}};
+use std::time::Duration;
 use tokio::sync::RwLock;
+use web_time::Instant;
 
 const TOKEN_ENDPOINT: &str = "https://{}";
 const USER_CONSENT_ENDPOINT: &str = "https://{}";
+{}
 
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
@@ -542,7 +909,11 @@ pub struct Client {{
     redirect_uri: String,
     {}
     auto_refresh: bool,
+    on_token_refresh: Option<Arc<dyn Fn(&AccessToken) + Send + Sync>>,
+    token_store: Option<Arc<dyn crate::token_store::TokenStore>>,
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
+    {}
 }}
 
 {}
@@ -552,7 +923,7 @@ pub struct Client {{
 /// the provider prior to storing
 const REFRESH_THRESHOLD: Duration = Duration::from_secs(60);
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct InnerToken {{
     access_token: String,
     refresh_token: String,
@@ -580,15 +951,17 @@ impl Client {{
         {}
     {{
         // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
         let client = reqwest::Client::builder().build();
         match client {{
             Ok(c) => {{
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware){}
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
 
                 Client {{
@@ -603,7 +976,11 @@ impl Client {{
                     }})),
                     {}
                     auto_refresh: false,
+                    on_token_refresh: None,
+                    token_store: None,
                     client,
+                    request_options: Default::default(),
+                    {}
                 }}
             }}
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
@@ -616,6 +993,24 @@ impl Client {{
         self
     }}
 
+    /// Sets a callback that is invoked with the new `AccessToken` every time the client
+    /// fetches or refreshes an access token. This can be used to persist the tokens.
+    pub fn set_token_refresh_callback<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&AccessToken) + Send + Sync + 'static,
+    {{
+        self.on_token_refresh = Some(Arc::new(callback));
+        self
+    }}
+
+    /// Keep the tokens in the given store, shared with the other clients using it.
+    /// See the `token_store` module.
+    pub fn with_token_store(&self, store: Arc<dyn crate::token_store::TokenStore>) -> Self {{
+        let mut c = self.clone();
+        c.token_store = Some(store);
+        c
+    }}
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -678,6 +1073,48 @@ impl Client {{
 
     {}
 
+    /// Override the HTTP client used to make requests. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics. The default client traces and
+    /// retries requests, add the same middleware to keep that behavior.
+    pub fn with_http_client(&self, client: reqwest_middleware::ClientWithMiddleware) -> Self {{
+        let mut c = self.clone();
+        c.client = client;
+        c
+    }}
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client set
+    /// with `with_http_client`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {{
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }}
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {{
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }}
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {{
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }}
+
+    /// Send a request, applying the request options of the client.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {{
+        self.request_options.execute(&self.client, req).await
+    }}
+
+    {}
+
 
 
     {}
@@ -685,18 +1122,87 @@ impl Client {{
     {}"#,
         token_endpoint.trim_start_matches("https://"),
         user_consent_endpoint.trim_start_matches("https://"),
+        environment_type,
         add_post_header_struct,
+        environment_field,
         ACCESS_TOKEN_STRUCT_TEMPLATE,
         add_post_header_type,
         add_post_header_args,
         add_post_header_args_where,
+        extra_middleware,
         add_post_header_fn,
+        environment_init,
+        with_environment,
         new_from_env,
-        TOKEN_AUTH_TEMPLATE,
+        token_auth,
         get_shared_functions(proper_name, add_post_header)
     )
 }
 
+/// The host, token endpoint and user consent endpoint of the sandbox of the
+/// providers which have one.
+fn sandbox_endpoints(proper_name: &str) -> Option<(&'static str, &'static str, &'static str)> {
+    match proper_name {
+        "DocuSign" => Some((
+            "demo.docusign.net",
+            "account-d.docusign.com/oauth/token",
+            "account-d.docusign.com/oauth/auth",
+        )),
+        "Gusto" => Some((
+            "api.gusto-demo.com",
+            "api.gusto-demo.com/oauth/token",
+            "api.gusto-demo.com/oauth/authorize",
+        )),
+        "Ramp" => Some((
+            "demo-api.ramp.com/developer/v1",
+            "demo-api.ramp.com/v1/public/customer/token",
+            "demo.ramp.com/v1/authorize",
+        )),
+        _ => None,
+    }
+}
+
+const ENVIRONMENT_TEMPLATE: &str = r#"/// The environment a client makes its requests to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Production,
+    /// The sandbox, to try the API out on data kept apart from production.
+    Sandbox,
+}
+
+impl Environment {
+    /// The host of the API in the environment.
+    pub fn host(&self) -> &'static str {
+        match self {
+            Environment::Production => DEFAULT_HOST,
+            Environment::Sandbox => SANDBOX_HOST,
+        }
+    }
+
+    fn token_endpoint(&self) -> &'static str {
+        match self {
+            Environment::Production => TOKEN_ENDPOINT,
+            Environment::Sandbox => SANDBOX_TOKEN_ENDPOINT,
+        }
+    }
+
+    fn user_consent_endpoint(&self) -> &'static str {
+        match self {
+            Environment::Production => USER_CONSENT_ENDPOINT,
+            Environment::Sandbox => SANDBOX_USER_CONSENT_ENDPOINT,
+        }
+    }
+}"#;
+
+const WITH_ENVIRONMENT_TEMPLATE: &str = r#"/// Make the requests to the given environment, and get the access tokens from it
+    /// as well. This replaces a host set with `with_host`.
+    pub fn with_environment(&self, environment: Environment) -> Self {
+        let mut c = self.clone();
+        c.host = environment.host().to_string();
+        c.environment = environment;
+        c
+    }"#;
+
 fn basic_new_from_env(proper_name: &str, add_post_header: &str) -> String {
     let add_post_header_type = if !add_post_header.is_empty() {
         ", P".to_string()
@@ -777,15 +1283,17 @@ where
         .expect("failed to read from google credential env var");
 
     let client = reqwest::Client::builder().build();
-    let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+    let retry_policy = crate::retry::RetryPolicy::default();
 
     match client {
         Ok(c) => {
             let client = reqwest_middleware::ClientBuilder::new(c)
                 // Trace HTTP requests. See the tracing crate to make use of these traces.
                 .with(reqwest_tracing::TracingMiddleware)
+                // Record a span for every request when the `tracing` feature is enabled.
+                .with(crate::trace::TraceMiddleware)
                 // Retry failed requests.
-                .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy))
+                .with(crate::retry::RetryMiddleware::new(retry_policy))
                 .build();
 
             Client {
@@ -799,7 +1307,11 @@ where
                     expires_at: None
                 })),
                 auto_refresh: false,
+                on_token_refresh: None,
+                token_store: None,
                 client,
+                request_options: Default::default(),
+                service_account: None,
             }
         },
         Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -807,6 +1319,124 @@ where
 }
 "#;
 
+const NEW_FROM_SERVICE_ACCOUNT_TEMPLATE: &str = r#"/// Create a client authenticated as a service account, from its JSON key, with
+    /// access to the given scopes. With domain-wide delegation, `subject` is the email
+    /// address of the user of the domain to act as.
+    ///
+    /// The client gets an access token before its first request, and a new one when
+    /// it expires. See the `service_account` module.
+    pub fn new_from_service_account(
+        key: &[u8],
+        scopes: &[&str],
+        subject: Option<&str>,
+    ) -> Result<Self> {
+        let key = crate::service_account::ServiceAccountKey::from_json(key)?;
+        Self::new_from_service_account_key(key, scopes, subject)
+    }
+
+    /// Create a client authenticated as a service account, with the key read from
+    /// the file `GOOGLE_APPLICATION_CREDENTIALS` points to. See
+    /// `new_from_service_account`.
+    pub fn new_from_service_account_env(scopes: &[&str], subject: Option<&str>) -> Result<Self> {
+        let key = crate::service_account::ServiceAccountKey::from_env()?;
+        Self::new_from_service_account_key(key, scopes, subject)
+    }
+
+    /// Create a client authenticated as a service account, from its parsed key. See
+    /// `new_from_service_account`.
+    pub fn new_from_service_account_key(
+        key: crate::service_account::ServiceAccountKey,
+        scopes: &[&str],
+        subject: Option<&str>,
+    ) -> Result<Self> {
+        let service_account = crate::service_account::ServiceAccount::new(key, scopes, subject)?;
+        let mut c = Client::new("", "", "", "", "");
+        c.set_service_account(service_account);
+        Ok(c)
+    }
+
+    /// Act as another user of the domain, with the same service account. The returned
+    /// client gets its own access tokens, and does not use the token store.
+    pub fn with_subject(&self, subject: &str) -> Result<Self> {
+        let service_account = match &self.service_account {
+            Some(service_account) => service_account.with_subject(subject),
+            None => {
+                return Err(anyhow!(
+                    "only clients authenticated as a service account can act as another user"
+                ))
+            }
+        };
+        let mut c = self.clone();
+        // The tokens of the store are the ones of the other user.
+        c.token_store = None;
+        c.set_service_account(service_account);
+        Ok(c)
+    }
+
+    fn set_service_account(&mut self, service_account: crate::service_account::ServiceAccount) {
+        self.service_account = Some(service_account);
+        self.auto_refresh = true;
+        // The client has no access token yet, expire it to get one.
+        self.token = Arc::new(RwLock::new(InnerToken {
+            access_token: String::new(),
+            refresh_token: String::new(),
+            expires_at: Some(Instant::now()),
+        }));
+    }"#;
+
+const FETCH_SERVICE_ACCOUNT_TOKEN_TEMPLATE: &str = r#"async fn fetch_service_account_token(
+    &self,
+    service_account: &crate::service_account::ServiceAccount,
+) -> Result<AccessToken> {
+    // Requests finding the token expired at the same time get a single new one: the
+    // ones which waited for the lock use the token fetched meanwhile.
+    let stale = self.token.read().await.access_token.clone();
+    let _refresh = service_account.lock_refresh().await;
+    {
+        let token = self.token.read().await;
+        let now = Instant::now();
+        if let Some(expires_at) = token.expires_at.filter(|e| *e > now) {
+            if token.access_token != stale {
+                return Ok(AccessToken {
+                    token_type: "Bearer".to_string(),
+                    access_token: token.access_token.clone(),
+                    expires_in: (expires_at.duration_since(now) + REFRESH_THRESHOLD).as_secs()
+                        as i64,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    let response = self.execute(service_account.token_request()?).await?;
+    let status = response.status();
+    let body = response.bytes().await?;
+    if !status.is_success() {
+        return Err(crate::error::from_response(status, &body));
+    }
+
+    // Service accounts get no refresh token, they sign a new assertion instead.
+    let t: AccessToken = serde_json::from_slice(&body)?;
+    *self.token.write().await = InnerToken {
+        access_token: t.access_token.clone(),
+        refresh_token: String::new(),
+        expires_at: Self::compute_expires_at(t.expires_in),
+    };
+
+    if let Some(callback) = &self.on_token_refresh {
+        callback(&t);
+    }
+
+    Ok(t)
+}
+
+async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {
+    if let Some(service_account) = &self.service_account {
+        return self.fetch_service_account_token(service_account).await;
+    }
+
+"#;
+
 pub fn generate_client_generic_api_key(proper_name: &str, add_post_header: &str) -> String {
     format!(
         r#"use std::env;
@@ -818,6 +1448,7 @@ pub struct Client {{
     token: String,
 
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
 }}
 
 impl Client {{
@@ -831,14 +1462,16 @@ impl Client {{
         T: ToString,
     {{
         let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
         match client {{
             Ok(c) => {{
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware)
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
 
                 Client {{
@@ -846,6 +1479,7 @@ impl Client {{
                     token: token.to_string(),
 
                     client,
+                    request_options: Default::default(),
                 }}
             }}
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
@@ -862,6 +1496,46 @@ impl Client {{
         c
      }}
 
+    /// Override the HTTP client used to make requests. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics. The default client traces and
+    /// retries requests, add the same middleware to keep that behavior.
+    pub fn with_http_client(&self, client: reqwest_middleware::ClientWithMiddleware) -> Self {{
+        let mut c = self.clone();
+        c.client = client;
+        c
+    }}
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client set
+    /// with `with_http_client`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {{
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }}
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {{
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }}
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {{
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }}
+
+    /// Send a request, applying the request options of the client.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {{
+        self.request_options.execute(&self.client, req).await
+    }}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -884,6 +1558,29 @@ impl Client {{
     )
 }
 
+/// The error of a response which did not succeed, from its `status` and the body in
+/// the variable of the given name.
+fn response_error(proper_name: &str, body: &str) -> String {
+    // Okta, Ramp and Google describe what went wrong in an error object, parse it so
+    // that callers can match on it.
+    if proper_name == "Okta" || proper_name == "Ramp" || proper_name.starts_with("Google") {
+        format!("crate::error::from_response(status, &{})", body)
+    } else {
+        format!(
+            r#"if {body}.is_empty() {{
+            anyhow!("code: {{}}, empty response", status)
+        }} else {{
+            anyhow!(
+                "code: {{}}, error: {{:?}}",
+                status,
+                String::from_utf8_lossy(&{body}),
+            )
+        }}"#,
+            body = body
+        )
+    }
+}
+
 fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
     let post_header_args = if !add_post_header.is_empty() {
         format!(
@@ -900,23 +1597,34 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         String::new()
     };
 
+    // Service accounts get a new token from a signed assertion, without a refresh
+    // token.
+    let cannot_refresh = if proper_name.starts_with("Google") {
+        "(self.token.read().await.refresh_token.is_empty() && self.service_account.is_none())"
+    } else {
+        "self.token.read().await.refresh_token.is_empty()"
+    };
+
     let bearer = if proper_name == "Okta" {
         "SSWS".to_string()
     } else {
         "Bearer".to_string()
     };
 
+    let error = response_error(proper_name, "response_body");
+
     // Add auto refresh functionality to clients that support it
     let raw_request = if
         proper_name.starts_with("Google") ||
         proper_name == "DocuSign" ||
         proper_name == "Gusto" ||
         proper_name == "MailChimp" ||
+        proper_name == "Ramp" ||
         proper_name == "Shopify" ||
         proper_name == "Slack" ||
         proper_name == "Zoom"
     {
-        get_shared_raw_functions_with_refresh("Bearer", &post_header_args)
+        get_shared_raw_functions_with_refresh("Bearer", &post_header_args, cannot_refresh)
     } else {
         get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
     };
@@ -949,15 +1657,7 @@ async fn request<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error};
 
         Err(error)
     }}
@@ -993,15 +1693,7 @@ where
         }};
         parsed_response.map(|out| (link, out)).map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error};
         Err(error)
     }}
 }}
@@ -1015,6 +1707,21 @@ async fn post_form<Out>(
 ) -> Result<Out>
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
+{{
+    self.post_multipart(uri, form).await
+}}
+
+/// Send a `multipart/form-data` `POST` request, for example to upload a file
+/// along with other fields. The content type, with the boundary of the parts,
+/// is set from the form.
+#[allow(dead_code)]
+async fn post_multipart<Out>(
+    &self,
+    uri: &str,
+    form: reqwest::multipart::Form,
+) -> Result<Out>
+    where
+    Out: serde::de::DeserializeOwned + 'static + Send,
 {{
     let u = if uri.starts_with("https://") {{
         uri.to_string()
@@ -1027,15 +1734,10 @@ async fn post_form<Out>(
 
     let mut req = instance.client.request(http::Method::POST, url);
 
-    // Set the default headers.
     req = req.header(
         reqwest::header::ACCEPT,
         reqwest::header::HeaderValue::from_static("application/json"),
     );
-    req = req.header(
-        reqwest::header::CONTENT_TYPE,
-        reqwest::header::HeaderValue::from_static("application/json"),
-    );
 
     if let Some(auth_str) = auth {{
         req = req.header(http::header::AUTHORIZATION, &*auth_str);
@@ -1044,7 +1746,7 @@ async fn post_form<Out>(
     log::debug!("form: {{:?}}", form);
     req = req.multipart(form);
 
-    let response = req.send().await?;
+    let response = self.execute(req.build()?).await?;
 
     let status = response.status();
 
@@ -1062,15 +1764,7 @@ async fn post_form<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error};
 
         Err(error)
     }}
@@ -1108,7 +1802,7 @@ async fn request_with_accept_mime<Out>(
         req = req.header(http::header::AUTHORIZATION, &*auth_str);
     }}
 
-    let response = req.send().await?;
+    let response = self.execute(req.build()?).await?;
 
     let status = response.status();
 
@@ -1126,15 +1820,7 @@ async fn request_with_accept_mime<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error};
 
         Err(error)
     }}
@@ -1192,7 +1878,7 @@ async fn request_with_mime<Out>(
         req = req.body(b);
     }}
 
-    let response = req.send().await?;
+    let response = self.execute(req.build()?).await?;
 
     let status = response.status();
 
@@ -1207,15 +1893,7 @@ async fn request_with_mime<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error};
 
         Err(error)
     }}
@@ -1257,6 +1935,31 @@ where
     self.unfold(uri).await
 }}
 
+/// Stream the items of the JSON array in the response to a `GET`, parsing each of
+/// them as soon as it downloaded rather than once the whole body is in memory. The
+/// array is either the body itself, or the first field of the body holding an
+/// array, like the `data` of a page of results.
+pub async fn get_stream_items<T>(&self, uri: &str) -> Result<impl futures::Stream<Item = Result<T>>>
+where
+    T: serde::de::DeserializeOwned,
+{{
+    let response = self.streaming().request_raw(
+        http::Method::GET,
+        &(self.host.to_string() + uri),
+        None,
+    ).await?;
+
+    let status = response.status();
+    if !status.is_success() {{
+        let response_body = response.bytes().await?;
+        let error = {error};
+
+        return Err(error);
+    }}
+
+    Ok(crate::json_stream::items(response))
+}}
+
 /// "unfold" paginated results of a vector of items
 #[allow(dead_code)]
 async fn unfold<D>(
@@ -1353,7 +2056,7 @@ where
         &(self.host.to_string() + uri),
         message,
     ).await
-}}"#, raw_request)
+}}"#, raw_request, error = error)
 }
 
 fn get_shared_raw_functions_without_refresh(bearer: &str, post_header_args: &str) -> String {
@@ -1396,15 +2099,23 @@ async fn request_raw(
         req = req.header(http::header::AUTHORIZATION, &*auth_str);
     }}
     if let Some(body) = body {{
-        log::debug!("body: {{:?}}", String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap());
+        // The bodies of these requests are in memory, uploads stream theirs separately.
+        let bytes = body
+            .as_bytes()
+            .ok_or_else(|| anyhow!("the body of the request must be in memory"))?;
+        log::debug!("body: {{:?}}", String::from_utf8_lossy(bytes));
         req = req.body(body);
     }}
-    Ok(req.send().await?)
+    self.execute(req.build()?).await
 }}
 "#, bearer, post_header_args)
 }
 
-fn get_shared_raw_functions_with_refresh(bearer: &str, post_header_args: &str) -> String {
+fn get_shared_raw_functions_with_refresh(
+    bearer: &str,
+    post_header_args: &str,
+    cannot_refresh: &str,
+) -> String {
     format!(r#"
 async fn url_and_auth(
     &self,
@@ -1449,10 +2160,11 @@ async fn make_request(
     }}
 
     if let Some(body) = body {{
-        log::debug!(
-            "body: {{:?}}",
-            String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap()
-        );
+        // The bodies of these requests are in memory, uploads stream theirs separately.
+        let bytes = body
+            .as_bytes()
+            .ok_or_else(|| anyhow!("the body of the request must be in memory"))?;
+        log::debug!("body: {{:?}}", String::from_utf8_lossy(bytes));
 
         req = req.body(body);
     }}
@@ -1466,69 +2178,145 @@ async fn request_raw(
     uri: &str,
     body: Option<reqwest::Body>,
 ) -> Result<reqwest::Response> {{
+    // Start from the token of the store when the client was created without one.
+    if self.token_store.is_some() && self.token.read().await.access_token.is_empty() {{
+        self.load_stored_token().await?;
+    }}
+
+    // We have a known expired token, there is no point in trying to make a request
+    // without refreshing it first.
+    let refreshed = self.auto_refresh && self.is_expired().await == Some(true);
+    if refreshed {{
+        self.refresh_access_token().await?;
+    }}
+
     let req = self.make_request(&method, uri, body).await?;
+    if !self.auto_refresh || refreshed {{
+        return self.execute(req).await;
+    }}
 
-    let resp = if self.auto_refresh {{
-        let expired = self.is_expired().await;
+    // We either have a (theoretically) known good token or we do not know the expiration
+    // of the token at all. We make an optimistic attempt at the request, keeping a copy of
+    // it around so it can be replayed if the provider rejects the token. Streaming bodies
+    // can not be copied, in which case the failure is deferred to the caller.
+    let retry = req.try_clone();
+    let resp = self.execute(req).await?;
 
-        match expired {{
-            // We have a known expired token, there is no point in trying to make a request
-            // without refreshing it first
-            Some(true) => {{
-                self.refresh_access_token().await?;
-                self.client.execute(req).await?
-            }}
-            // We have a (theoretically) known good token available. We make an optimistic
-            // attempting at the request. If the token is no longer good, then something other
-            // than the expiration is triggering the failure. We defer handling of these errors
-            // to the caller
-            Some(false) => self.client.execute(req).await?,
-
-            // We do not know what state we are in. We could have a valid or expired token.
-            // Generally this means we are in one of two cases:
-            //   1. We have not yet performed a token refresh (and do not know the expiration
-            //      of the user provided token)
-            //   2. The provider is returning unusable expiration times, at which point we
-            //      choose to ignore them
-            None => {{
-                self.client.execute(req).await?
+    if resp.status() != http::StatusCode::UNAUTHORIZED || {cannot_refresh} {{
+        return Ok(resp);
+    }}
+
+    match retry {{
+        Some(mut retry) => {{
+            self.refresh_access_token().await?;
+
+            let (_, auth) = self.url_and_auth(retry.url().as_str()).await?;
+            if let Some(auth_str) = auth {{
+                retry.headers_mut().insert(
+                    http::header::AUTHORIZATION,
+                    reqwest::header::HeaderValue::from_str(&auth_str)?,
+                );
             }}
-        }}
-    }} else {{
-        self.client.execute(req).await?
-    }};
 
-    Ok(resp)
-}}"#, bearer, post_header_args)
+            self.execute(retry).await
+        }}
+        None => Ok(resp),
+    }}
+}}"#,
+        bearer,
+        post_header_args,
+        cannot_refresh = cannot_refresh,
+    )
 }
 
 const TOKEN_AUTH_TEMPLATE: &str = r#"
 /// Return a user consent url with an optional set of scopes.
 /// If no scopes are provided, they will not be passed in the url.
 pub fn user_consent_url(&self, scopes: &[String]) -> String {
-    let state = uuid::Uuid::new_v4();
-
-    let url = format!(
-        "{}?client_id={}&response_type=code&redirect_uri={}&state={}",
-        USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
-    );
+    let state = uuid::Uuid::new_v4().to_string();
+    let scope = scopes.join(" ");
+
+    let mut query_args = vec![
+        ("client_id", self.client_id.as_str()),
+        ("response_type", "code"),
+        ("redirect_uri", self.redirect_uri.as_str()),
+        ("state", state.as_str()),
+    ];
 
-    if scopes.is_empty() {
-        return url;
+    // Add the scopes.
+    if !scopes.is_empty() {
+        query_args.push(("scope", scope.as_str()));
     }
 
-    // Add the scopes.
-    format!("{}&scope={}", url, scopes.join(" "))
+    crate::progenitor_support::append_query(USER_CONSENT_ENDPOINT, &query_args)
 }
 
 /// Refresh an access token from a refresh token. Client must have a refresh token
 /// for this to work.
 pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+    let store = match &self.token_store {
+        Some(store) => store,
+        None => return self.fetch_refreshed_access_token().await,
+    };
+
+    store.lock_refresh().await?;
+    let t = self.refresh_stored_access_token(store.as_ref()).await;
+    store.unlock_refresh().await?;
+    t
+}
+
+async fn refresh_stored_access_token(
+    &self,
+    store: &dyn crate::token_store::TokenStore,
+) -> Result<AccessToken> {
+    if let Some(stored) = store.get().await? {
+        // Another client refreshed the token while this one waited for the lock.
+        let expired = stored
+            .expires_in()
+            .map(|e| e <= REFRESH_THRESHOLD.as_secs() as i64);
+        if stored.access_token != self.token.read().await.access_token && expired != Some(true) {
+            self.set_stored_token(&stored).await;
+            return Ok(stored.to_access_token());
+        }
+
+        // Providers that rotate refresh tokens revoke the ones that were used, refresh
+        // from the last one.
+        if !stored.refresh_token.is_empty() {
+            self.token.write().await.refresh_token = stored.refresh_token;
+        }
+    }
+
+    let t = self.fetch_refreshed_access_token().await?;
+    store
+        .put(&crate::token_store::StoredToken::from_access_token(&t))
+        .await?;
+    Ok(t)
+}
+
+async fn load_stored_token(&self) -> Result<()> {
+    if let Some(store) = &self.token_store {
+        if let Some(stored) = store.get().await? {
+            self.set_stored_token(&stored).await;
+        }
+    }
+
+    Ok(())
+}
+
+async fn set_stored_token(&self, stored: &crate::token_store::StoredToken) {
+    *self.token.write().await = InnerToken {
+        access_token: stored.access_token.clone(),
+        refresh_token: stored.refresh_token.clone(),
+        expires_at: stored.expires_in().and_then(Self::compute_expires_at),
+    };
+}
+
+async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {
     let response = {
         let refresh_token = &self.token.read().await.refresh_token;
 
         if refresh_token.is_empty() {
-            anyhow!("refresh token cannot be empty");
+            return Err(anyhow!("refresh token cannot be empty"));
         }
 
         let mut headers = reqwest::header::HeaderMap::new();
@@ -1544,8 +2332,7 @@ pub async fn refresh_access_token(&self) -> Result<AccessToken> {
             ("client_secret", &self.client_secret),
             ("redirect_uri", &self.redirect_uri),
         ];
-        let client = reqwest::Client::new();
-        client
+        self.client
             .post(TOKEN_ENDPOINT)
             .headers(headers)
             .form(&params)
@@ -1555,16 +2342,29 @@ pub async fn refresh_access_token(&self) -> Result<AccessToken> {
     };
 
     // Unwrap the response.
-    let t: AccessToken = response.json().await?;
+    let status = response.status();
+    let body = response.bytes().await?;
+    if !status.is_success() {
+        return Err(TOKEN_ERROR);
+    }
+    let mut t: AccessToken = serde_json::from_slice(&body)?;
 
-    let refresh_token = self.token.read().await.refresh_token.clone();
+    // Some providers rotate the refresh token on every refresh, keep the one we
+    // have if a new one was not returned.
+    if t.refresh_token.is_empty() {
+        t.refresh_token = self.token.read().await.refresh_token.clone();
+    }
 
     *self.token.write().await = InnerToken {
         access_token: t.access_token.clone(),
-        refresh_token,
+        refresh_token: t.refresh_token.clone(),
         expires_at: Self::compute_expires_at(t.expires_in),
     };
 
+    if let Some(callback) = &self.on_token_refresh {
+        callback(&t);
+    }
+
     Ok(t)
 }
 
@@ -1585,8 +2385,8 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<Acce
         ("redirect_uri", &self.redirect_uri),
         ("state", state),
     ];
-    let client = reqwest::Client::new();
-    let resp = client
+    let resp = self
+        .client
         .post(TOKEN_ENDPOINT)
         .headers(headers)
         .form(&params)
@@ -1595,7 +2395,12 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<Acce
         .await?;
 
     // Unwrap the response.
-    let t: AccessToken = resp.json().await?;
+    let status = resp.status();
+    let body = resp.bytes().await?;
+    if !status.is_success() {
+        return Err(TOKEN_ERROR);
+    }
+    let t: AccessToken = serde_json::from_slice(&body)?;
 
     *self.token.write().await = InnerToken {
         access_token: t.access_token.clone(),
@@ -1603,6 +2408,16 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<Acce
         expires_at: Self::compute_expires_at(t.expires_in),
     };
 
+    if let Some(store) = &self.token_store {
+        store
+            .put(&crate::token_store::StoredToken::from_access_token(&t))
+            .await?;
+    }
+
+    if let Some(callback) = &self.on_token_refresh {
+        callback(&t);
+    }
+
     Ok(t)
 }"#;
 
@@ -1621,8 +2436,8 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
         ("client_id", &self.client_id),
         ("client_secret", &self.client_secret),
     ];
-    let client = reqwest::Client::new();
-    let resp = client
+    let resp = self
+        .client
         .post(TOKEN_ENDPOINT)
         .headers(headers)
         .form(&params)
@@ -1631,7 +2446,12 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
         .await?;
 
     // Unwrap the response.
-    let t: AccessToken = resp.json().await?;
+    let status = resp.status();
+    let body = resp.bytes().await?;
+    if !status.is_success() {
+        return Err(TOKEN_ERROR);
+    }
+    let t: AccessToken = serde_json::from_slice(&body)?;
 
     self.token = t.access_token.to_string();
 
@@ -1657,6 +2477,7 @@ pub struct Client {{
     client_id: String,
     client_secret: String,
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
 }}
 
 {}
@@ -1676,14 +2497,16 @@ impl Client {{
         T: ToString,
     {{
         let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
         match client {{
             Ok(c) => {{
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware)
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
 
                 Client {{
@@ -1693,6 +2516,7 @@ impl Client {{
                     token: token.to_string(),
 
                     client,
+                    request_options: Default::default(),
                 }}
             }}
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
@@ -1709,6 +2533,46 @@ impl Client {{
         c
     }}
 
+    /// Override the HTTP client used to make requests. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics. The default client traces and
+    /// retries requests, add the same middleware to keep that behavior.
+    pub fn with_http_client(&self, client: reqwest_middleware::ClientWithMiddleware) -> Self {{
+        let mut c = self.clone();
+        c.client = client;
+        c
+    }}
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client set
+    /// with `with_http_client`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {{
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }}
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {{
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }}
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {{
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }}
+
+    /// Send a request, applying the request options of the client.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {{
+        self.request_options.execute(&self.client, req).await
+    }}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -1738,7 +2602,7 @@ impl Client {{
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
-        CLIENT_AUTH_TEMPLATE,
+        CLIENT_AUTH_TEMPLATE.replace("TOKEN_ERROR", &response_error(proper_name, "body")),
         get_shared_functions(proper_name, add_post_header)
     )
 }
@@ -1747,7 +2611,7 @@ const ACCESS_TOKEN_STRUCT_TEMPLATE: &str = r#"use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 
-#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+#[derive(JsonSchema, Clone, Default, Serialize, Deserialize)]
 pub struct AccessToken {
     #[serde(
         default,
@@ -1780,4 +2644,288 @@ pub struct AccessToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub scope: String,
+}
+
+// The tokens are redacted, so that access tokens can be logged.
+impl std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("token_type", &self.token_type)
+            .field("access_token", &crate::redact::Secret(&self.access_token))
+            .field("expires_in", &self.expires_in)
+            .field("refresh_token", &crate::redact::Secret(&self.refresh_token))
+            .field("refresh_token_expires_in", &self.refresh_token_expires_in)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}"#;
+
+/// Generate the `Debug` implementation of the client, which redacts its credentials.
+pub fn generate_client_debug(proper_name: &str) -> String {
+    if proper_name == "GitHub" {
+        GITHUB_DEBUG_TEMPLATE.to_string()
+    } else if proper_name == "SendGrid"
+        || proper_name == "Giphy"
+        || proper_name == "Rev.ai"
+        || proper_name == "Okta"
+        || proper_name == "ShipBob"
+        || proper_name == "Stripe"
+    {
+        API_KEY_DEBUG_TEMPLATE.to_string()
+    } else if proper_name == "TripActions" {
+        CLIENT_CREDENTIALS_DEBUG_TEMPLATE.to_string()
+    } else {
+        TOKEN_DEBUG_TEMPLATE.to_string()
+    }
+}
+
+const GITHUB_DEBUG_TEMPLATE: &str = r#"// The credentials redact their secrets.
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("host", &self.host)
+            .field("upload_host", &self.upload_host)
+            .field("agent", &self.agent)
+            .field("credentials", &self.credentials)
+            .field("api_version", &self.api_version)
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}"#;
+
+const API_KEY_DEBUG_TEMPLATE: &str = r#"impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("host", &self.host)
+            .field("token", &crate::redact::Secret(&self.token))
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}"#;
+
+const CLIENT_CREDENTIALS_DEBUG_TEMPLATE: &str = r#"impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("host", &self.host)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &crate::redact::Secret(&self.client_secret))
+            .field("token", &crate::redact::Secret(&self.token))
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}"#;
+
+const TOKEN_DEBUG_TEMPLATE: &str = r#"impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("Client");
+        d.field("host", &self.host)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &crate::redact::Secret(&self.client_secret))
+            .field("redirect_uri", &self.redirect_uri);
+        // Rather than waiting for a refresh of the token to complete, leave it out.
+        match self.token.try_read() {
+            Ok(token) => d.field("token", &*token),
+            Err(_) => d.field("token", &format_args!("<refreshing>")),
+        };
+        d.field("auto_refresh", &self.auto_refresh)
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for InnerToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerToken")
+            .field("access_token", &crate::redact::Secret(&self.access_token))
+            .field("refresh_token", &crate::redact::Secret(&self.refresh_token))
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}"#;
+
+/// Generate the implementation of `FromEnv` for the client, reading the variables
+/// named after the provider.
+pub fn generate_from_env_impl(proper_name: &str, add_post_header: &str) -> String {
+    if proper_name == "GitHub" {
+        return GITHUB_FROM_ENV_TEMPLATE.to_string();
+    }
+
+    let prefix = if proper_name.starts_with("Google") {
+        "GOOGLE".to_string()
+    } else {
+        proper_name.to_uppercase().replace('.', "")
+    };
+
+    // The value of the header sent with `POST` requests, when there is one.
+    let (post_header_var, post_header_arg) = if add_post_header.is_empty() {
+        (String::new(), String::new())
+    } else {
+        let var = format!(
+            "{}_{}",
+            prefix,
+            to_snake_case(add_post_header).to_uppercase()
+        );
+        (
+            format!(r#", "{}""#, var),
+            format!(r#", crate::from_env::required("{}")?"#, var),
+        )
+    };
+
+    let (docs, vars, client) = if proper_name == "SendGrid"
+        || proper_name == "Giphy"
+        || proper_name == "Rev.ai"
+        || proper_name == "Okta"
+        || proper_name == "ShipBob"
+        || proper_name == "Stripe"
+    {
+        (
+            format!("/// - `{}_API_KEY`, the API key.", prefix),
+            format!(r#""{}_API_KEY""#, prefix),
+            format!(
+                r#"let mut client = Client::new(crate::from_env::required("{}_API_KEY")?);"#,
+                prefix
+            ),
+        )
+    } else if proper_name == "TripActions" {
+        (
+            format!(
+                "/// - `{p}_CLIENT_ID` and `{p}_CLIENT_SECRET`, the app.\n\
+                 /// - `{p}_TOKEN`, the access token, when it is set.",
+                p = prefix
+            ),
+            format!(
+                r#""{p}_CLIENT_ID", "{p}_CLIENT_SECRET"{h}, "{p}_TOKEN""#,
+                p = prefix,
+                h = post_header_var
+            ),
+            format!(
+                r#"let mut client = Client::new(
+            crate::from_env::required("{p}_CLIENT_ID")?,
+            crate::from_env::required("{p}_CLIENT_SECRET")?,
+            crate::from_env::optional("{p}_TOKEN"){h}
+        );"#,
+                p = prefix,
+                h = post_header_arg
+            ),
+        )
+    } else if proper_name.starts_with("Google") {
+        return GOOGLE_FROM_ENV_TEMPLATE.to_string();
+    } else {
+        (
+            format!(
+                "/// - `{p}_CLIENT_ID` and `{p}_CLIENT_SECRET`, the app.\n\
+                 /// - `{p}_REDIRECT_URI`, `{p}_TOKEN` and `{p}_REFRESH_TOKEN`, when they are set.",
+                p = prefix
+            ),
+            format!(
+                r#""{p}_CLIENT_ID", "{p}_CLIENT_SECRET"{h}, "{p}_REDIRECT_URI", "{p}_TOKEN", "{p}_REFRESH_TOKEN""#,
+                p = prefix,
+                h = post_header_var
+            ),
+            format!(
+                r#"let refresh_token = crate::from_env::optional("{p}_REFRESH_TOKEN");
+        let mut client = Client::new(
+            crate::from_env::required("{p}_CLIENT_ID")?,
+            crate::from_env::required("{p}_CLIENT_SECRET")?,
+            crate::from_env::optional("{p}_REDIRECT_URI"),
+            crate::from_env::optional("{p}_TOKEN"),
+            &refresh_token{h}
+        );
+        // With a refresh token, the client gets a new access token when it expires.
+        if !refresh_token.is_empty() {{
+            client.set_auto_access_token_refresh(true);
+        }}"#,
+                p = prefix,
+                h = post_header_arg
+            ),
+        )
+    };
+
+    format!(
+        r#"/// Create a client from the environment variables:
+///
+{docs}
+/// - `{p}_HOST`, the host to make the requests to, when it is set.
+impl crate::from_env::FromEnv for Client {{
+    fn env_vars() -> &'static [&'static str] {{
+        &[{vars}, "{p}_HOST"]
+    }}
+
+    fn from_env() -> Result<Self> {{
+        {client}
+
+        let host = crate::from_env::optional("{p}_HOST");
+        if !host.is_empty() {{
+            client = client.with_host(host);
+        }}
+        Ok(client)
+    }}
+}}"#,
+        docs = docs,
+        vars = vars,
+        client = client,
+        p = prefix,
+    )
+}
+
+const GITHUB_FROM_ENV_TEMPLATE: &str = r#"/// Create a client authenticated with the token of `GITHUB_TOKEN`, a personal access
+/// token or the token of an app.
+impl crate::from_env::FromEnv for Client {
+    fn env_vars() -> &'static [&'static str] {
+        &["GITHUB_TOKEN"]
+    }
+
+    fn from_env() -> Result<Self> {
+        let token = crate::from_env::required("GITHUB_TOKEN")?;
+        Client::new(
+            concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+            crate::auth::Credentials::Token(token),
+        )
+    }
+}"#;
+
+const GOOGLE_FROM_ENV_TEMPLATE: &str = r#"/// Create a client authenticated as the service account whose key
+/// `GOOGLE_APPLICATION_CREDENTIALS` points to, with the scopes of `GOOGLE_SCOPES`
+/// separated by spaces, acting as the user of `GOOGLE_SUBJECT` when it is set.
+///
+/// Without a service account, the client is the app of the base64 encoded secret of
+/// `GOOGLE_KEY_ENCODED`, with the tokens of `GOOGLE_TOKEN` and `GOOGLE_REFRESH_TOKEN`
+/// when they are set.
+impl crate::from_env::FromEnv for Client {
+    fn env_vars() -> &'static [&'static str] {
+        &[
+            "GOOGLE_APPLICATION_CREDENTIALS",
+            "GOOGLE_SCOPES",
+            "GOOGLE_SUBJECT",
+            "GOOGLE_KEY_ENCODED",
+            "GOOGLE_TOKEN",
+            "GOOGLE_REFRESH_TOKEN",
+        ]
+    }
+
+    fn from_env() -> Result<Self> {
+        if !crate::from_env::optional(crate::service_account::CREDENTIALS_ENV).is_empty() {
+            let scopes = crate::from_env::required("GOOGLE_SCOPES")?;
+            let scopes: Vec<&str> = scopes.split_whitespace().collect();
+            let subject = crate::from_env::optional("GOOGLE_SUBJECT");
+            let subject = Some(subject.as_str()).filter(|s| !s.is_empty());
+            return Client::new_from_service_account_env(&scopes, subject);
+        }
+
+        let secret = base64::decode(crate::from_env::required("GOOGLE_KEY_ENCODED")?)?;
+        let secret = yup_oauth2::parse_application_secret(secret)?;
+        let refresh_token = crate::from_env::optional("GOOGLE_REFRESH_TOKEN");
+        let mut client = Client::new(
+            secret.client_id,
+            secret.client_secret,
+            secret.redirect_uris.first().cloned().unwrap_or_default(),
+            crate::from_env::optional("GOOGLE_TOKEN"),
+            &refresh_token,
+        );
+        // With a refresh token, the client gets a new access token when it expires.
+        if !refresh_token.is_empty() {
+            client.set_auto_access_token_refresh(true);
+        }
+        Ok(client)
+    }
 }"#;
@@ -126,7 +126,7 @@ pub fn generate_files(
                 a("");
             };
 
-            let docs = get_fn_docs(o, m, p, parameters, ts)?;
+            let mut docs = get_fn_docs(o, m, p, parameters, ts)?;
 
             let mut bounds: Vec<String> = Vec::new();
 
@@ -221,14 +221,14 @@ pub fn generate_files(
             /*
              * Get the function parameters.
              */
-            let (fn_params_str, query_params) =
+            let (mut fn_params_str, query_params) =
                 get_fn_params(ts, o, parameters, false, op.parameters.clone(), proper_name)?;
 
             /*
              * Generate the URL for the request.
              */
             let tmp = parse(p)?;
-            let template = tmp.compile(query_params);
+            let template = compile_url(&tmp, &fn_params_str, query_params);
 
             /*
              * Get the response type.
@@ -258,6 +258,18 @@ pub fn generate_files(
                 false,
             )?;
 
+            // The endpoints checking whether something exists answer with a status
+            // code and no body, so they return whether it does.
+            if proper_name == "GitHub" && response_type == "()" && is_existence_check(m, o) {
+                response_type = "bool".to_string();
+                fn_inner = "self.client.get_exists(&url).await".to_string();
+                docs = docs.replacen(
+                    " endpoint.\n",
+                    " endpoint.\n*\n* Returns `false` when GitHub answers with `404 Not Found`.\n",
+                    1,
+                );
+            }
+
             // TODO: don't special case this.
             if p == "/jobs/{id}/transcript" || p == "/jobs/{id}/captions" {
                 fn_inner =
@@ -266,6 +278,18 @@ pub fn generate_files(
                 response_type = "String".to_string();
             }
 
+            // Release assets are uploaded to a separate host, with the media type of
+            // the asset instead of JSON.
+            if oid == "repos_upload_release_asset" {
+                fn_params_str.push("content_type: &str,".to_string());
+                docs = docs.replace(
+                    "* * `label: &str`\n*/",
+                    "* * `label: &str`\n* * `content_type: &str` -- The media type of the asset, for example `application/zip`.\n*/",
+                );
+                fn_inner = "self.client.post_upload(&url, content_type, None, body.into()).await"
+                    .to_string();
+            }
+
             if let Some(te) = ts.id_to_entry.get(&tid) {
                 // If we have a one of, we can generate a few different subfunctions to
                 // help as well.
@@ -344,6 +368,38 @@ pub fn generate_files(
                 &fn_name,
             );
 
+            // Ramp returns the cursor for the next page alongside the data, so also
+            // print a function that hands back the whole page for manual pagination.
+            if proper_name == "Ramp"
+                && frt.starts_with("Vec<")
+                && !inner_response_type.is_empty()
+                && http::Method::GET == m
+            {
+                let performs = format!("* This function performs a `{}` to the `{}` endpoint.", m, p);
+                let docs = docs.replacen(
+                    &performs,
+                    &format!(
+                        "{}\n*\n* As opposed to `{}`, this function also returns the `page` of the response, which holds the cursor for the next page.",
+                        performs, fn_name
+                    ),
+                    1,
+                );
+
+                let fn_name = format!("{}_with_cursor", fn_name);
+                fn_names.push(fn_name.clone() + &tag);
+
+                print_fn(
+                    &docs,
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    &response_type,
+                    &template,
+                    "self.client.get(&url, None).await",
+                    &fn_name,
+                );
+            }
+
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
             if frt.starts_with("Vec<") && http::Method::GET == m {
@@ -358,7 +414,7 @@ pub fn generate_files(
                     get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
 
                 let tmp = parse(p)?;
-                let template = tmp.compile(query_params);
+                let template = compile_url(&tmp, &fn_params_str, query_params);
 
                 let fn_inner = get_fn_inner(
                     proper_name,
@@ -559,6 +615,17 @@ fn get_response_type_from_object(
     Ok((og_rt, tid, "".to_string(), "".to_string()))
 }
 
+/// Whether the operation tells if a resource exists, answering with `204 No Content`
+/// when it does and with `404 Not Found` when it does not.
+fn is_existence_check(m: &str, o: &openapiv3::Operation) -> bool {
+    let has = |code| {
+        o.responses
+            .responses
+            .contains_key(&openapiv3::StatusCode::Code(code))
+    };
+    http::Method::GET == m && has(204) && has(404) && !has(200)
+}
+
 fn get_response_type(
     od: &str,
     ts: &mut TypeSpace,
@@ -689,6 +756,9 @@ fn get_fn_params(
 
         if !fn_params.contains(nam) && !fn_params.contains(&format!("{}_", nam)) {
             let typ = parameter_data.render_type(&param_name, ts)?;
+            // Path parameters can be given as anything that borrows as a string, so
+            // callers holding a `String` do not need to borrow it themselves.
+            let is_str_path = typ == "&str" && matches!(item, openapiv3::Parameter::Path { .. });
             if nam == "ref"
                 || nam == "type"
                 || nam == "foo"
@@ -696,7 +766,11 @@ fn get_fn_params(
                 || nam == "const"
                 || nam == "use"
             {
-                fn_params_str.push(format!("{}_: {},", nam, typ));
+                if is_str_path {
+                    fn_params_str.push(format!("{}_: impl AsRef<str>,", nam));
+                } else {
+                    fn_params_str.push(format!("{}_: {},", nam, typ));
+                }
                 fn_params.push(nam.to_string() + "_");
             } else if nam == "i_ds" {
                 fn_params_str.push(format!("ids: {},", typ));
@@ -712,7 +786,22 @@ fn get_fn_params(
                 && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
                 && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
             {
-                if typ == "chrono::DateTime<chrono::Utc>" {
+                // GitHub functions take typed IDs, so that an ID of one kind can not be
+                // passed where another is expected.
+                let typed_id = match item {
+                    openapiv3::Parameter::Path { .. } if proper_name == "GitHub" => {
+                        crate::types::github_typed_id(nam)
+                    }
+                    _ => None,
+                };
+
+                if let Some(id) = typed_id {
+                    fn_params_str.push(format!("{}: impl Into<crate::types::{}>,", nam, id));
+                    fn_params.push(nam.to_string());
+                } else if is_str_path {
+                    fn_params_str.push(format!("{}: impl AsRef<str>,", nam));
+                    fn_params.push(nam.to_string());
+                } else if typ == "chrono::DateTime<chrono::Utc>" {
                     fn_params_str.push(format!("{}: Option<{}>,", nam, typ));
                     fn_params.push(nam.to_string());
                 } else {
@@ -782,6 +871,50 @@ fn get_fn_params(
     Ok((fn_params_str, query_params))
 }
 
+/// Convert the parameters a function takes as `impl Into<..>` or `impl AsRef<str>`,
+/// before they are used.
+fn param_conversions(fn_params_str: &[String]) -> String {
+    fn_params_str
+        .iter()
+        .filter_map(|p| {
+            let p = p.trim_end_matches(',');
+            if let Some(nam) = p.strip_suffix(": impl AsRef<str>") {
+                return Some(format!("let {} = {}.as_ref();\n", nam, nam));
+            }
+
+            let (nam, typ) = p.split_once(": impl Into<")?;
+            Some(format!(
+                "let {}: {} = {}.into();\n",
+                nam,
+                typ.trim_end_matches('>'),
+                nam
+            ))
+        })
+        .collect()
+}
+
+/// Build the URL for a request, after its parameters have been converted.
+///
+/// Path parameters taken as `impl AsRef<str>` are already a `&str` by then, so they
+/// are encoded as they are rather than borrowed again.
+fn compile_url(
+    tmp: &crate::template::Template,
+    fn_params_str: &[String],
+    query_params: BTreeMap<String, (String, String)>,
+) -> String {
+    let mut url = tmp.compile(query_params);
+    for p in fn_params_str {
+        if let Some(nam) = p.trim_end_matches(',').strip_suffix(": impl AsRef<str>") {
+            url = url.replace(
+                &format!("encode_path(&{}),", nam),
+                &format!("encode_path({}),", nam),
+            );
+        }
+    }
+
+    param_conversions(fn_params_str) + &url
+}
+
 /*
  * Perform the function.
  */
@@ -830,11 +963,7 @@ fn get_fn_inner(
                    }}
                 }}
 
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?startng_after={{}}", url, page), {}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&starting_after={{}}", url, page), {}).await?;
-                }}
+                resp = self.client.{}(&crate::progenitor_support::append_query(&url, &[("starting_after", &page)]), {}).await?;
 
 
                 {}.append(&mut resp.{});
@@ -853,8 +982,6 @@ fn get_fn_inner(
             pagination_property,
             m.to_lowercase(),
             body,
-            m.to_lowercase(),
-            body,
             pagination_property,
             pagination_property,
             pagination_property,
@@ -871,11 +998,7 @@ fn get_fn_inner(
 
             // Paginate if we should.
             while !page.is_empty() {{
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?pageToken={{}}", url, page), {}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&pageToken={{}}", url, page), {}).await?;
-                }}
+                resp = self.client.{}(&crate::progenitor_support::append_query(&url, &[("pageToken", &page)]), {}).await?;
 
 
                 {}.append(&mut resp.{});
@@ -896,8 +1019,6 @@ fn get_fn_inner(
             pagination_property,
             m.to_lowercase(),
             body,
-            m.to_lowercase(),
-            body,
             pagination_property,
             pagination_property,
             pagination_property,
@@ -914,7 +1035,7 @@ fn get_fn_inner(
 
             // Paginate if we should.
             while !page.is_empty() {{
-                match self.client.{}::<{}>(page.trim_start_matches(crate::DEFAULT_HOST), {}).await {{
+                match self.client.{}::<{}>(page.trim_start_matches(self.client.host.as_str()), {}).await {{
                     Ok(mut resp) => {{
                         {}.append(&mut resp.{});
 
@@ -954,22 +1075,23 @@ fn get_fn_inner(
         // We will do a custom function here.
         let inner = format!(
             r#"
-            let mut resp: {} = if !url.contains('?') {{
-                self.client.{}(&format!("{{}}?page=0&size=100", url), {}).await?
-            }} else {{
-                self.client.{}(&format!("{{}}&page=0&size=100", url), {}).await?
-            }};
+            let mut resp: {} = self.client.{}(
+                &crate::progenitor_support::append_query(&url, &[("page", "0"), ("size", "100")]),
+                {},
+            ).await?;
 
             let mut {} = resp.{};
             let mut page = resp.page.current_page + 1;
 
             // Paginate if we should.
             while page <= (resp.page.total_pages - 1) {{
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?page={{}}&size=100", url, page), {}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&page={{}}&size=100", url, page), {}).await?;
-                }}
+                resp = self.client.{}(
+                    &crate::progenitor_support::append_query(
+                        &url,
+                        &[("page", &page.to_string()), ("size", "100")],
+                    ),
+                    {},
+                ).await?;
 
                 {}.append(&mut resp.{});
 
@@ -981,14 +1103,10 @@ fn get_fn_inner(
             response_type,
             m.to_lowercase(),
             body,
-            m.to_lowercase(),
-            body,
             pagination_property,
             pagination_property,
             m.to_lowercase(),
             body,
-            m.to_lowercase(),
-            body,
             pagination_property,
             pagination_property,
             pagination_property,
@@ -1005,12 +1123,7 @@ fn get_fn_inner(
 
             // Paginate if we should.
             while !page.is_empty() {{
-                // Check if we already have URL params and need to concat the token.
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?next_page_token={{}}", url, page), {}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&next_page_token={{}}", url, page), {}).await?;
-                }}
+                resp = self.client.{}(&crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]), {}).await?;
 
                 {}.append(&mut resp.{});
 
@@ -1030,8 +1143,6 @@ fn get_fn_inner(
             pagination_property,
             m.to_lowercase(),
             body,
-            m.to_lowercase(),
-            body,
             pagination_property,
             pagination_property,
             pagination_property,
@@ -1,11 +1,33 @@
+mod batch;
+mod blocking;
+mod cache;
 mod client;
+mod client_config;
+mod fixtures;
+mod from_env;
 mod functions;
+mod google_error;
+mod json_stream;
+mod message;
+mod metrics;
+mod overrides;
+mod page;
+mod redact;
+mod request_options;
+mod retry;
+mod runtime;
+mod service_account;
+mod single_flight;
 mod template;
+mod token_store;
+mod trace;
+mod transport;
 mod types;
 mod utils;
+mod webhook_verify;
 
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     ffi::OsStr,
     fs::{File, OpenOptions},
     io::Write,
@@ -723,6 +745,16 @@ pub struct TypeSpace {
      */
     name_to_id: BTreeMap<String, TypeId>,
     id_to_entry: BTreeMap<TypeId, TypeEntry>,
+    /*
+     * The types defined inline in the request bodies of the operations, which are
+     * only ever sent.
+     */
+    request_types: BTreeSet<TypeId>,
+    /*
+     * The request bodies of the PATCH operations, with the names of their
+     * nullable properties, which are cleared when they are sent as `null`.
+     */
+    patch_types: BTreeMap<TypeId, BTreeSet<String>>,
 }
 
 impl TypeSpace {
@@ -731,6 +763,8 @@ impl TypeSpace {
             next_id: 1,
             name_to_id: BTreeMap::new(),
             id_to_entry: BTreeMap::new(),
+            request_types: BTreeSet::new(),
+            patch_types: BTreeMap::new(),
         }
     }
 
@@ -2193,9 +2227,15 @@ fn render_param(
         a("Noop,");
     }
 
-    // Let's add the wildcard.
-    a("#[serde(other)]");
-    a("FallthroughString");
+    // Keep the values we do not know about, so that new values added by the vendor
+    // do not fail to deserialize. The spec itself might have an `unknown` value.
+    let unknown = if enums.iter().any(|e| struct_name(e) == "Unknown") {
+        "UnknownValue"
+    } else {
+        "Unknown"
+    };
+    a("#[serde(untagged)]");
+    a(&format!("{}(String),", unknown));
 
     a("}");
     a("");
@@ -2214,8 +2254,8 @@ fn render_param(
         a(&format!(r#"{}::Noop => "","#, sn));
     }
 
-    // Let's add the display format for the wildcard.
-    a(&format!(r#"{}::FallthroughString => "*","#, sn));
+    // Display the unknown values as they were given.
+    a(&format!(r#"{}::{}(s) => s.as_str(),"#, sn, unknown));
 
     a("}");
     a(".fmt(f)");
@@ -2286,10 +2326,79 @@ fn gen(
     a("#![cfg_attr(docsrs, feature(doc_cfg))]");
     a("");
     if proper_name == "GitHub" {
+        a(r#"#[cfg(feature = "actions")]"#);
+        a("mod actions_downloads;");
+        a(r#"#[cfg(all(feature = "actions", feature = "sodium"))]"#);
+        a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "sodium")))]"#);
+        a("mod actions_secrets;");
         a("pub mod auth;");
+        a(r#"#[cfg(feature = "repos")]"#);
+        a("mod branch_protection;");
+        a(r#"#[cfg(feature = "checks")]"#);
+        a("mod check_runs;");
+        a(r#"#[cfg(feature = "git")]"#);
+        a("mod commit_builder;");
+        a(r#"#[cfg(all(feature = "checks", feature = "repos"))]"#);
+        a("mod commit_status;");
+        a(r#"#[cfg(feature = "repos")]"#);
+        a("mod contents;");
+        a(r#"#[cfg(any(feature = "pulls", feature = "repos"))]"#);
+        a("mod diffs;");
+        a("/// The errors returned by GitHub.");
+        a("pub mod error;");
+        a("/// Export the metadata of a repository, for backups.");
+        a(r#"#[cfg(all(feature = "issues", feature = "pulls", feature = "repos"))]"#);
+        a(
+            r#"#[cfg_attr(docsrs, doc(cfg(all(feature = "issues", feature = "pulls", feature = "repos"))))]"#,
+        );
+        a("pub mod export;");
+        a(r#"#[cfg(feature = "gists")]"#);
+        a("mod gist_files;");
+        a("/// Query the GitHub GraphQL API.");
+        a("pub mod graphql;");
         a(r#"#[cfg(feature = "httpcache")]"#);
         a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
         a("pub mod http_cache;");
+        a(r#"#[cfg(feature = "issues")]"#);
+        a("mod issues_bulk;");
+        a("mod pagination;");
+        a("/// The query parameters of list endpoints as structs.");
+        a(
+            r#"#[cfg(any(feature = "gists", feature = "issues", feature = "pulls", feature = "repos"))]"#,
+        );
+        a("pub mod params;");
+        a("/// Manage projects with the GraphQL API.");
+        a("pub mod projects_v2;");
+        a("/// Keep track of the rate limit and share it between critical and background work.");
+        a("pub mod rate_budget;");
+        a(r#"#[cfg(feature = "repos")]"#);
+        a("mod release_assets;");
+        a(r#"#[cfg(feature = "repos")]"#);
+        a("mod repo_access;");
+        a(r#"#[cfg(feature = "repos")]"#);
+        a("mod repo_iter;");
+        a("/// Create repositories from templates and configure them.");
+        a(r#"#[cfg(all(feature = "issues", feature = "repos", feature = "teams"))]"#);
+        a(
+            r#"#[cfg_attr(docsrs, doc(cfg(all(feature = "issues", feature = "repos", feature = "teams"))))]"#,
+        );
+        a("pub mod repo_setup;");
+        a(r#"#[cfg(feature = "pulls")]"#);
+        a("mod review_builder;");
+        a(r#"#[cfg(feature = "scim")]"#);
+        a("mod scim_provisioning;");
+        a(r#"#[cfg(feature = "sodium")]"#);
+        a("mod sealed_box;");
+        a(r#"#[cfg(feature = "search")]"#);
+        a("mod search_query;");
+        a(r#"#[cfg(feature = "teams")]"#);
+        a("mod team_sync;");
+        a("/// Check request bodies before they are sent.");
+        a("pub mod validate;");
+        a("/// Typed webhook event payloads and signature verification.");
+        a("pub mod webhooks;");
+        a(r#"#[cfg(feature = "actions")]"#);
+        a("mod workflow_dispatch;");
     }
     if proper_name == "Google Drive"
         || proper_name == "Google Sheets"
@@ -2298,12 +2407,116 @@ fn gen(
     {
         a("pub mod traits;");
     }
+    if proper_name.starts_with("Google") {
+        a("/// The errors returned by Google.");
+        a("pub mod error;");
+        a("/// Email messages in the raw form the Gmail API sends.");
+        a("pub mod message;");
+        a("/// Authentication as a service account, for server to server requests.");
+        a("pub mod service_account;");
+    }
+    if proper_name == "Google Admin" {
+        a("/// Users, their aliases and the members of groups, changing only what differs.");
+        a("pub mod directory;");
+    }
+    if proper_name == "Google Calendar" {
+        a("/// Push notifications of changes to events, on channels renewed before they expire.");
+        a("pub mod watch;");
+    }
+    if proper_name == "Google Drive" {
+        a("/// Resumable uploads of large files.");
+        a("pub mod upload;");
+    }
+    if proper_name == "Google Sheets" {
+        a("/// Ranges of cells in A1 notation.");
+        a("pub mod a1;");
+    }
+    a("pub mod batch;");
+    a(r#"#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]"#);
+    a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]"#);
+    a("pub mod blocking;");
+    a("pub mod cache;");
+    a("pub mod client_config;");
+    a(r#"#[cfg(not(target_arch = "wasm32"))]"#);
+    a("pub mod fixtures;");
+    a("pub mod from_env;");
+    a("mod json_stream;");
+    if proper_name == "DocuSign" {
+        a("mod envelope_builder;");
+    }
+    if proper_name == "Gusto" {
+        a("pub mod onboarding;");
+        a("mod payroll_runs;");
+    }
+    if proper_name == "MailChimp" {
+        a("mod batch_operations;");
+    }
+    if proper_name == "Okta" {
+        a("mod access_provisioning;");
+        a("/// The errors returned by Okta.");
+        a("pub mod error;");
+        a("mod log_stream;");
+    }
+    a("pub mod metrics;");
+    a("/// One page of the results of a list endpoint.");
+    a("pub mod page;");
+    if proper_name == "Ramp" {
+        a("mod card_controls;");
+        a("/// The errors returned by Ramp.");
+        a("pub mod error;");
+        a(r#"#[cfg(feature = "export")]"#);
+        a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "export")))]"#);
+        a("pub mod export;");
+        a("/// Idempotency keys for requests that create resources.");
+        a("pub mod idempotency;");
+        a("mod org_units;");
+        a("/// Helpers for paginating through the API manually.");
+        a("pub mod pagination;");
+        a("mod receipt_upload;");
+        a("/// The statements of the business, for monthly reconciliation.");
+        a("pub mod statements;");
+        a("/// Incrementally sync transactions into another system.");
+        a("pub mod sync;");
+        a("mod transaction_query;");
+    }
+    if proper_name == "SendGrid" {
+        a("mod contact_exports;");
+        a("/// Build and send mail with personalizations and attachments.");
+        a("pub mod mail;");
+    }
+    if proper_name == "Slack" {
+        a("/// Build messages out of Block Kit blocks.");
+        a("pub mod blocks;");
+    }
+    if proper_name == "TripActions" {
+        a("mod booking_reports;");
+    }
+    if proper_name == "Zoom" {
+        a("mod quality_metrics;");
+        a("mod recordings;");
+        a("mod registrants;");
+        a("mod webinar_management;");
+    }
+    a("pub mod redact;");
+    a("pub mod request_options;");
+    a("pub mod retry;");
+    a("mod runtime;");
+    a("pub mod single_flight;");
+    if has_token_store(proper_name) {
+        a("pub mod token_store;");
+    }
+    a("pub mod trace;");
+    a("pub mod transport;");
     a("#[cfg(test)]");
     a("mod tests;");
     // Hopefully there is never a "tag" named after these reserved libs.
     a("pub mod types;");
     a("#[doc(hidden)]");
     a("pub mod utils;");
+    if has_webhook_verify(proper_name) {
+        a("/// Verify the signatures of webhook deliveries.");
+        a("pub mod webhook_verify;");
+    }
 
     /*
      * Import the module for each tag.
@@ -2332,10 +2545,15 @@ fn gen(
         if !docs.is_empty() {
             a(&format!("/// {}", docs.replace('\n', "\n///"),));
         }
-        a(&format!(
-            "pub mod {};",
-            to_snake_case(&clean_name(&tag.name))
-        ));
+        let module = to_snake_case(&clean_name(&tag.name));
+        if let Some(feature) = tag_feature(proper_name, &module) {
+            a(&format!(r#"#[cfg(feature = "{}")]"#, feature));
+            a(&format!(
+                r#"#[cfg_attr(docsrs, doc(cfg(feature = "{}")))]"#,
+                feature
+            ));
+        }
+        a(&format!("pub mod {};", module));
     }
     if api.tags.is_empty() {
         // If the spec didn't call out tags explicitly, we need to use the
@@ -2359,6 +2577,8 @@ fn gen(
     a("");
 
     a("mod progenitor_support {");
+    a("    use std::fmt::{self, Write};");
+    a("");
     a("    use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};");
     a("");
     /*
@@ -2385,9 +2605,78 @@ fn gen(
     a("        .add(b'{')");
     a("        .add(b'}');");
     a("");
+    a("    /// A path component, percent-encoded as it is formatted.");
+    a("    struct EncodePath<'a, T: ?Sized>(&'a T);");
+    a("");
+    a("    impl<T: fmt::Display + ?Sized> fmt::Display for EncodePath<'_, T> {");
+    a("        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {");
+    a("            struct Encoder<'a, 'b>(&'a mut fmt::Formatter<'b>);");
+    a("");
+    a("            impl Write for Encoder<'_, '_> {");
+    a("                fn write_str(&mut self, s: &str) -> fmt::Result {");
+    a("                    write!(self.0, \"{}\", utf8_percent_encode(s, PATH_SET))");
+    a("                }");
+    a("            }");
+    a("");
+    a("            write!(Encoder(f), \"{}\", self.0)");
+    a("        }");
+    a("    }");
+    a("");
+    a("    /// Percent-encode a path component, a `&str` or any value that implements");
+    a("    /// `Display`, without copying it into a `String` first.");
     a("    #[allow(dead_code)]");
-    a("    pub(crate) fn encode_path(pc: &str) -> String {");
-    a("        utf8_percent_encode(pc, PATH_SET).to_string()");
+    a("    pub(crate) fn encode_path<T: fmt::Display + ?Sized>(pc: &T) -> impl fmt::Display + '_ {");
+    a("        EncodePath(pc)");
+    a("    }");
+    a("");
+    a("    /// Write a path and its percent-encoded query parameters into a single `String`.");
+    a("    #[allow(dead_code)]");
+    a("    pub(crate) fn build_url(path: fmt::Arguments<'_>, query_args: &[(&str, String)]) -> String {");
+    a("        let mut url = String::new();");
+    a("        url.write_fmt(path).unwrap();");
+    a("        push_query(");
+    a("            &mut url,");
+    a("            query_args.iter().map(|(name, value)| (*name, value.as_str())),");
+    a("        );");
+    a("        url");
+    a("    }");
+    a("");
+    a("    /// Add percent-encoded query parameters to a URL that may already have a query");
+    a("    /// string.");
+    a("    #[allow(dead_code)]");
+    a("    pub(crate) fn append_query(url: &str, query_args: &[(&str, &str)]) -> String {");
+    a("        let mut url = url.to_string();");
+    a("        push_query(&mut url, query_args.iter().copied());");
+    a("        url");
+    a("    }");
+    a("");
+    a("    fn push_query<'a, I>(url: &mut String, query_args: I)");
+    a("    where");
+    a("        I: Iterator<Item = (&'a str, &'a str)>,");
+    a("    {");
+    a("        for (name, value) in query_args {");
+    a("            if !url.contains('?') {");
+    a("                url.push('?');");
+    a("            } else if !url.ends_with('?') && !url.ends_with('&') {");
+    a("                url.push('&');");
+    a("            }");
+    a("            push_form_encoded(url, name);");
+    a("            url.push('=');");
+    a("            push_form_encoded(url, value);");
+    a("        }");
+    a("    }");
+    a("");
+    a("    /// Encode as `application/x-www-form-urlencoded`, like `serde_urlencoded` does.");
+    a("    fn push_form_encoded(url: &mut String, s: &str) {");
+    a("        for b in s.bytes() {");
+    a("            match b {");
+    a("                b'*' | b'-' | b'.' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' => {");
+    a("                    url.push(b as char)");
+    a("                }");
+    a("                b' ' => url.push('+'),");
+    a("                _ => write!(url, \"%{:02X}\", b).unwrap(),");
+    a("            }");
+    a("        }");
     a("    }");
     a("}");
     a("");
@@ -2451,16 +2740,24 @@ fn gen(
             }
         }
 
+        let module = to_snake_case(&clean_name(&tag.name));
+        if let Some(feature) = tag_feature(proper_name, &module) {
+            a(&format!(r#"#[cfg(feature = "{}")]"#, feature));
+            a(&format!(
+                r#"#[cfg_attr(docsrs, doc(cfg(feature = "{}")))]"#,
+                feature
+            ));
+        }
         a(&format!(
             r#"/// {}
                pub fn {}(&self) -> {}::{} {{
                     {}::{}::new(self.clone())
                }}"#,
             docs.replace('\n', "\n///"),
-            to_snake_case(&clean_name(&tag.name)),
-            to_snake_case(&clean_name(&tag.name)),
+            module,
+            module,
             struct_name(&tag.name),
-            to_snake_case(&clean_name(&tag.name)),
+            module,
             struct_name(&tag.name),
         ));
         a("");
@@ -2486,10 +2783,67 @@ fn gen(
     }
 
     a("}");
+    a("");
+    a(&crate::client::generate_client_debug(proper_name));
+    a("");
+    a(&crate::client::generate_from_env_impl(proper_name, add_post_header));
 
     Ok(out)
 }
 
+/// Whether the client has OAuth 2.0 tokens it refreshes, which can be kept in a
+/// token store.
+fn has_token_store(proper_name: &str) -> bool {
+    !matches!(
+        proper_name,
+        "GitHub" | "Giphy" | "Okta" | "Rev.ai" | "SendGrid" | "ShipBob" | "Stripe" | "TripActions"
+    )
+}
+
+/// Whether the provider signs its webhook deliveries in a way the webhook
+/// verification module checks.
+fn has_webhook_verify(proper_name: &str) -> bool {
+    matches!(proper_name, "Okta" | "Slack" | "Stripe")
+}
+
+/// The cargo feature a tag module is compiled behind, so that a client using a few
+/// API groups does not compile all of them. The client itself mints installation
+/// tokens with `apps` and reads the rate limit with `rate_limit`, so those are
+/// always there.
+fn tag_feature(proper_name: &str, tag: &str) -> Option<String> {
+    if proper_name != "GitHub" || tag == "apps" || tag == "rate_limit" {
+        return None;
+    }
+
+    Some(tag.to_string())
+}
+
+/// The cargo features always enabled when a module of the GitHub client written by
+/// hand is compiled, as in its `cfg` in `lib.rs`.
+fn github_module_features(module: &str) -> &'static [&'static str] {
+    match module {
+        "actions_downloads" | "workflow_dispatch" => &["actions"],
+        "actions_secrets" => &["actions", "sodium"],
+        "branch_protection" | "contents" | "release_assets" | "repo_access" | "repo_iter" => {
+            &["repos"]
+        }
+        "check_runs" => &["checks"],
+        "commit_builder" => &["git"],
+        "commit_status" => &["checks", "repos"],
+        "export" => &["issues", "pulls", "repos"],
+        "gist_files" => &["gists"],
+        "issues_bulk" => &["issues"],
+        "repo_setup" => &["issues", "repos", "teams"],
+        "review_builder" => &["pulls"],
+        "scim_provisioning" => &["scim"],
+        "sealed_box" => &["sodium"],
+        "search_query" => &["search"],
+        "team_sync" => &["teams"],
+        // The modules compiled with any of several features, or always.
+        _ => &[],
+    }
+}
+
 pub fn make_plural(proper_name: &str, s: &str) -> String {
     // Only fix the ramp names.
     if proper_name != "Ramp" && proper_name != "Okta" {
@@ -2750,6 +3104,21 @@ pub fn clean_fn_name(proper_name: &str, oid: &str, tag: &str) -> String {
     f
 }
 
+/// The names of the properties of an object schema which are nullable.
+fn nullable_properties(s: &openapiv3::ReferenceOr<openapiv3::Schema>) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    if let Ok(schema) = s.item() {
+        if let openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) = &schema.schema_kind {
+            for (n, p) in o.properties.iter() {
+                if matches!(p, openapiv3::ReferenceOr::Item(p) if p.schema_data.nullable) {
+                    names.insert(n.to_string());
+                }
+            }
+        }
+    }
+    names
+}
+
 fn oid_to_object_name(s: &str) -> String {
     let cleaned = s
         .to_lowercase()
@@ -2832,7 +3201,8 @@ fn main() -> Result<()> {
         }
     };
 
-    let api = load_api(&args.opt_str("i").unwrap())?;
+    let mut api = load_api(&args.opt_str("i").unwrap())?;
+    overrides::apply(&args.opt_str("proper-name").unwrap(), &mut api);
 
     let debug = |s: &str| {
         if args.opt_present("debug") {
@@ -3056,7 +3426,12 @@ fn main() -> Result<()> {
                         if ct == "application/json" {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
+                                let first = ts.next_id;
                                 let id = ts.select(Some(&object_name), s, "")?;
+                                ts.request_types.extend((first..ts.next_id).map(TypeId));
+                                if m == "PATCH" && id.0 >= first {
+                                    ts.patch_types.insert(id.clone(), nullable_properties(s));
+                                }
                                 let rt = ts.render_type(&id, true)?;
                                 req.push(format!("{} {:?}", rt, id));
                             }
@@ -3213,6 +3588,89 @@ uuid = { version = "^0.8", features = ["serde", "v4"] }"#
                     .to_string();
             }
 
+            let mut github_lib = "".to_string();
+            let mut reqwest_features = "".to_string();
+            let mut github_features = "".to_string();
+            let mut default_features = "".to_string();
+            if proper_name == "GitHub" {
+                github_features = r#"
+# encrypt GitHub Actions secrets with libsodium
+sodium = ["sodiumoxide"]"#
+                    .to_string();
+                // Every API group is a feature, all of them on by default.
+                let api_features = api
+                    .tags
+                    .iter()
+                    .filter_map(|tag| {
+                        tag_feature(&proper_name, &to_snake_case(&clean_name(&tag.name)))
+                    })
+                    .collect::<Vec<_>>();
+                github_features.push_str(&format!(
+                    "\n# every API group, each of which can be enabled on its own to compile less\nall-apis = [{}]",
+                    api_features
+                        .iter()
+                        .map(|f| format!("{:?}", f))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+                for feature in &api_features {
+                    github_features.push_str(&format!("\n{} = []", feature));
+                }
+                default_features = r#""all-apis", "#.to_string();
+                // Release assets can be uploaded from a stream.
+                reqwest_features = r#", "stream""#.to_string();
+                github_lib = r#"
+async-trait = "^0.1.51"
+base64 = "^0.12"
+bytes = { version = "1", features = ["serde"] }
+hex = "0.4"
+hmac = "0.12"
+sha2 = "0.10"
+sodiumoxide = { version = "0.2", optional = true }"#
+                    .to_string();
+            }
+
+            if proper_name == "Ramp" {
+                github_features = r#"
+# turn transactions and receipts into provider-neutral accounting entries
+export = []"#
+                    .to_string();
+            }
+
+            let mut base64_lib = "".to_string();
+            if proper_name == "SendGrid" || proper_name == "DocuSign" {
+                // Mail attachments and envelope documents are sent base64 encoded.
+                base64_lib = r#"
+base64 = "^0.12""#
+                    .to_string();
+            }
+
+            let mut webhook_lib = "".to_string();
+            if has_webhook_verify(&proper_name) {
+                webhook_lib = r#"
+hex = "0.4"
+hmac = "0.12"
+sha2 = "0.10""#
+                    .to_string();
+            }
+
+            if proper_name == "Zoom" {
+                // Recordings are downloaded as streams.
+                reqwest_features = r#", "stream""#.to_string();
+            }
+
+            let mut flate2_lib = "".to_string();
+            if proper_name == "SendGrid" {
+                // Contact exports are downloaded as streams of gzipped files.
+                reqwest_features = r#", "stream""#.to_string();
+            }
+            if proper_name == "SendGrid" || proper_name == "MailChimp" {
+                // Contact exports and the results of batches are gzipped.
+                flate2_lib = r#"
+flate2 = "1""#
+                    .to_string();
+            }
+
             if proper_name.starts_with("Google") {
                 yup_oauth2_lib = r#"
 base64 = "^0.12"
@@ -3234,37 +3692,51 @@ edition = "2018"
 license = "MIT"
 
 [features]
-default = ["rustls-tls"]
+default = [{}"rustls-tls"]
+# run requests to completion with a blocking client
+blocking = []
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
-native-tls = ["reqwest/default-tls", "openssl"]
-rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
+native-tls = ["reqwest/native-tls", "openssl"]
+rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]{}
 
 [dependencies]
 anyhow = "1"
 async-recursion = "^0.3.2"
 chrono = {{ version = "0.4", features = ["serde"] }}
 dirs = {{ version = "^3.0.2", optional = true }}
+futures = "0.3"
 http = "^0.2.4"
 hyperx = "1"
 jsonwebtoken = "7"
 log = {{ version = "^0.4", features = ["serde"] }}
 mime = "0.3"
-openssl = {{ version = "0.10", default-features = false, optional = true }}
 pem = {{ version = "0.8",  default-features = false, optional = true }}
 percent-encoding = "2.1"
-reqwest = {{ version = "0.11", default-features = false, features = ["json", "multipart"] }}
+rand = "0.8"
+reqwest = {{ version = "0.11", default-features = false, features = ["json", "multipart", "gzip", "deflate", "brotli"{}] }}
 reqwest-middleware = "0.1.5"
-reqwest-retry = "0.1.4"
 reqwest-tracing = {{ version = "0.2.1", features = ["opentelemetry_0_17"] }}
 ring = {{ version = "0.16", default-features = false, optional = true }}
-schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid"] }}
-serde = {{ version = "1", features = ["derive"] }}
+schemars = {{ version = "0.8.13", features = ["bytes", "chrono", "url", "uuid"] }}
+serde = {{ version = "1.0.181", features = ["derive"] }}
 serde_json = "1"
 serde_urlencoded = "^0.7"
-url = {{ version = "2", features = ["serde"] }}{}{}
+task-local-extensions = "0.1.1"
+tokio-util = "0.7"
+tracing = {{ version = "0.1", optional = true }}
+url = {{ version = "2", features = ["serde"] }}
+web-time = "1"{}{}{}{}{}{}
+
+[target.'cfg(not(target_arch = "wasm32"))'.dependencies]
+openssl = {{ version = "0.10", default-features = false, optional = true }}
 tokio = {{ version = "1.8.0", features = ["full"] }}
 
+[target.'cfg(target_arch = "wasm32")'.dependencies]
+getrandom = {{ version = "0.2", features = ["js"] }}
+gloo-timers = {{ version = "0.2", features = ["futures"] }}
+tokio = {{ version = "1.8.0", features = ["sync"] }}
+
 [dev-dependencies]
 base64 = "^0.12"
 dirs = "^3.0.2"
@@ -3274,7 +3746,20 @@ nom_pem = "4"
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                default_features,
+                github_features,
+                reqwest_features,
+                uuid_lib,
+                yup_oauth2_lib,
+                github_lib,
+                base64_lib,
+                webhook_lib,
+                flate2_lib
             );
             save(&toml, tomlout.as_str())?;
 
@@ -3357,28 +3842,308 @@ rustdoc-args = ["--cfg", "docsrs"]
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
 
+            /*
+             * Create the Rust batch module:
+             */
+            let batch = batch::generate_batch(&name);
+            let mut batchrs = src.clone();
+            batchrs.push("batch.rs");
+            save(batchrs, batch.as_str())?;
+
+            /*
+             * Create the Rust blocking module:
+             */
+            let blocking = blocking::generate_blocking();
+            let mut blockingrs = src.clone();
+            blockingrs.push("blocking.rs");
+            save(blockingrs, blocking.as_str())?;
+
+            /*
+             * Create the Rust response cache module:
+             */
+            let cache = cache::generate_cache(&name);
+            let mut cachers = src.clone();
+            cachers.push("cache.rs");
+            save(cachers, cache.as_str())?;
+
+            /*
+             * Create the Rust client configuration module:
+             */
+            let client_config = client_config::generate_client_config(&name, &proper_name);
+            let mut client_configrs = src.clone();
+            client_configrs.push("client_config.rs");
+            save(client_configrs, client_config.as_str())?;
+
+            /*
+             * Create the Rust fixtures module:
+             */
+            let fixtures = fixtures::generate_fixtures(&name);
+            let mut fixturesrs = src.clone();
+            fixturesrs.push("fixtures.rs");
+            save(fixturesrs, fixtures.as_str())?;
+
+            /*
+             * Create the Rust module creating clients from environment variables:
+             */
+            let from_env = from_env::generate_from_env(&name);
+            let mut from_envrs = src.clone();
+            from_envrs.push("from_env.rs");
+            save(from_envrs, from_env.as_str())?;
+
+            /*
+             * Create the Rust JSON stream module:
+             */
+            let json_stream = json_stream::generate_json_stream();
+            let mut json_streamrs = src.clone();
+            json_streamrs.push("json_stream.rs");
+            save(json_streamrs, json_stream.as_str())?;
+
+            /*
+             * Create the Rust metrics module:
+             */
+            let metrics = metrics::generate_metrics(&name, &proper_name, &api);
+            let mut metricsrs = src.clone();
+            metricsrs.push("metrics.rs");
+            save(metricsrs, metrics.as_str())?;
+
+            /*
+             * Create the Rust redaction module:
+             */
+            let redact = redact::generate_redact(&name);
+            let mut redactrs = src.clone();
+            redactrs.push("redact.rs");
+            save(redactrs, redact.as_str())?;
+
+            /*
+             * Create the Rust request options module:
+             */
+            let request_options = request_options::generate_request_options(&name);
+            let mut request_optionsrs = src.clone();
+            request_optionsrs.push("request_options.rs");
+            save(request_optionsrs, request_options.as_str())?;
+
+            /*
+             * Create the Rust retry module:
+             */
+            let retry = retry::generate_retry(&name);
+            let mut retryrs = src.clone();
+            retryrs.push("retry.rs");
+            save(retryrs, retry.as_str())?;
+
+            /*
+             * Create the Rust runtime module:
+             */
+            let runtime = runtime::generate_runtime();
+            let mut runtimers = src.clone();
+            runtimers.push("runtime.rs");
+            save(runtimers, runtime.as_str())?;
+
+            if has_token_store(&proper_name) {
+                /*
+                 * Create the Rust token store module:
+                 */
+                let token_store = token_store::generate_token_store(&name);
+                let mut token_storers = src.clone();
+                token_storers.push("token_store.rs");
+                save(token_storers, token_store.as_str())?;
+            }
+
+            /*
+             * Create the Rust single-flight module:
+             */
+            let single_flight = single_flight::generate_single_flight(&name);
+            let mut single_flightrs = src.clone();
+            single_flightrs.push("single_flight.rs");
+            save(single_flightrs, single_flight.as_str())?;
+
+            /*
+             * Create the Rust trace module:
+             */
+            let trace = trace::generate_trace();
+            let mut tracers = src.clone();
+            tracers.push("trace.rs");
+            save(tracers, trace.as_str())?;
+
+            /*
+             * Create the Rust transport module:
+             */
+            let transport = transport::generate_transport();
+            let mut transportrs = src.clone();
+            transportrs.push("transport.rs");
+            save(transportrs, transport.as_str())?;
+
+            if has_webhook_verify(&proper_name) {
+                /*
+                 * Create the Rust webhook verification module:
+                 */
+                let webhook_verify = webhook_verify::generate_webhook_verify(&name, &proper_name);
+                let mut webhook_verifyrs = src.clone();
+                webhook_verifyrs.push("webhook_verify.rs");
+                save(webhook_verifyrs, webhook_verify.as_str())?;
+            }
+
             /*
              * Create the Rust source types file containing the generated types:
              */
             let types = types::generate_types(&mut ts, &proper_name)?;
-            let mut typesrs = src.clone();
-            typesrs.push("types.rs");
-            save(typesrs, types.as_str())?;
+            if proper_name != "GitHub" {
+                let mut typesrs = src.clone();
+                typesrs.push("types.rs");
+                save(typesrs, types::render_types(&types).as_str())?;
+            }
+
+            if proper_name.starts_with("Google") {
+                /*
+                 * Create the Rust error module:
+                 */
+                let error = google_error::generate_google_error(&name);
+                let mut errorrs = src.clone();
+                errorrs.push("error.rs");
+                save(errorrs, error.as_str())?;
+
+                /*
+                 * Create the Rust service account module:
+                 */
+                let service_account = service_account::generate_service_account(&name);
+                let mut service_accountrs = src.clone();
+                service_accountrs.push("service_account.rs");
+                save(service_accountrs, service_account.as_str())?;
+
+                /*
+                 * Create the Rust message module:
+                 */
+                let message = message::generate_message(&name);
+                let mut messagers = src.clone();
+                messagers.push("message.rs");
+                save(messagers, message.as_str())?;
+            }
+
+            /*
+             * Create the Rust page module, which implements `Paginated` for the types:
+             */
+            let page = page::generate_page(&proper_name, &ts)?;
+            let mut pagers = src.clone();
+            pagers.push("page.rs");
+            save(pagers, page.as_str())?;
 
             /*
              * Create the Rust source files for each of the tags functions:
              */
             let fail = match functions::generate_files(&api, &proper_name, &mut ts, &parameters) {
                 Ok(files) => {
+                    if proper_name == "GitHub" {
+                        /*
+                         * GitHub has too many types for one file, split them by the
+                         * tags using them.
+                         */
+                        let tags: BTreeMap<String, String> = files
+                            .iter()
+                            .map(|(f, content)| {
+                                (to_snake_case(&clean_name(f)), content.to_string())
+                            })
+                            .collect();
+                        // The helpers written by hand use types as well.
+                        let mut helpers = Vec::new();
+                        for entry in std::fs::read_dir(&src)? {
+                            let path = entry?.path();
+                            let module = match path.file_stem().and_then(OsStr::to_str) {
+                                Some(module) => module,
+                                None => continue,
+                            };
+                            if path.extension() != Some(OsStr::new("rs"))
+                                || tags.contains_key(module)
+                                || module == "tests"
+                            {
+                                continue;
+                            }
+                            let features = github_module_features(module);
+                            helpers.push((features, std::fs::read_to_string(&path)?));
+                        }
+                        let mut typesrs = src.clone();
+                        typesrs.push("types.rs");
+                        if typesrs.exists() {
+                            std::fs::remove_file(&typesrs)?;
+                        }
+                        let mut typesdir = src.clone();
+                        typesdir.push("types");
+                        std::fs::create_dir_all(&typesdir)?;
+                        for (file, content) in types::split_types(&types, &tags, &helpers) {
+                            let mut typesrs = typesdir.clone();
+                            typesrs.push(file);
+                            save(typesrs, content.as_str())?;
+                        }
+                    }
+
                     // We have a map of our files, let's write to them.
                     for (f, content) in files {
                         let mut tagrs = src.clone();
                         tagrs.push(format!("{}.rs", to_snake_case(&clean_name(&f))));
 
+                        // Hand-written helpers that belong to a tag are re-exported
+                        // from the module of the tag.
+                        let reexports = match (proper_name.as_str(), f.as_str()) {
+                            ("DocuSign", "envelopes") => {
+                                "pub use crate::envelope_builder::{\n    AnchorTab, EnvelopeBuilder, EnvelopeDocument, EnvelopeSigner, TabKind,\n};\n"
+                            }
+                            ("GitHub", "checks") => {
+                                "pub use crate::check_runs::{\n    Annotation, CheckConclusion, CheckRunOutput, CheckRunRequest, MAX_ANNOTATIONS_PER_REQUEST,\n};\n"
+                            }
+                            ("GitHub", "git") => "pub use crate::commit_builder::CommitBuilder;\n",
+                            ("GitHub", "issues") => {
+                                "pub use crate::issues_bulk::{BulkFailure, BulkResult, NewIssue, MUTATION_INTERVAL};\n"
+                            }
+                            ("GitHub", "pulls") => {
+                                "pub use crate::review_builder::{diff_position, ReviewBuilder, ReviewEvent};\n"
+                            }
+                            ("GitHub", "repos") => {
+                                "#[cfg(feature = \"checks\")]\npub use crate::commit_status::{CommitState, CommitStatusSummary, STATUS_POLL_INTERVAL};\npub use crate::{branch_protection::BranchProtectionBuilder, repo_iter::RepoIter};\n"
+                            }
+                            ("GitHub", "scim") => "pub use crate::scim_provisioning::ScimFilter;\n",
+                            ("GitHub", "search") => {
+                                "pub use crate::search_query::{SearchQuery, SearchResult};\n"
+                            }
+                            ("GitHub", "teams") => "pub use crate::team_sync::MemberChange;\n",
+                            ("Gusto", "payroll") => {
+                                "pub use crate::payroll_runs::{OffCyclePayroll, PayrollQuery};\n"
+                            }
+                            ("MailChimp", "batches") => {
+                                "pub use crate::batch_operations::{BatchOperation, OperationResult, MAX_BATCH_OPERATIONS};\n"
+                            }
+                            ("Okta", "applications") => {
+                                "pub use crate::access_provisioning::GroupAssignmentChanges;\n"
+                            }
+                            ("Okta", "logs") => {
+                                "pub use crate::log_stream::{CursorStore, LogStream, MemoryCursorStore};\n"
+                            }
+                            ("Ramp", "cards") => {
+                                "pub use crate::card_controls::SpendingRestrictionsUpdate;\n"
+                            }
+                            ("Ramp", "transactions") => {
+                                "pub use crate::transaction_query::{TransactionOrder, TransactionQuery};\n"
+                            }
+                            ("SendGrid", "contacts") => {
+                                "pub use crate::contact_exports::ExportedContact;\n"
+                            }
+                            ("TripActions", "booking_data") => {
+                                "pub use crate::booking_reports::{BookingReportQuery, DateRange};\n"
+                            }
+                            ("Zoom", "dashboards") => {
+                                "pub use crate::quality_metrics::{MeetingQualityScores, QualityScore, \
+                                 QualityScores};\n"
+                            }
+                            ("Zoom", "meetings") => {
+                                "pub use crate::registrants::MeetingRegistrant;\n"
+                            }
+                            ("Zoom", "webinars") => {
+                                "pub use crate::webinar_management::WebinarRegistrant;\n"
+                            }
+                            _ => "",
+                        };
+
                         let output = format!(
                             r#"use anyhow::Result;
 
-use crate::Client;
+{}use crate::Client;
 
 pub struct {} {{
     pub client: Client,
@@ -3395,6 +4160,7 @@ impl {} {{
 
     {}
 }}"#,
+                            reexports,
                             struct_name(&f),
                             struct_name(&f),
                             struct_name(&f),
@@ -0,0 +1,138 @@
+const TEMPLATE: &str = r#"//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: {crate_name}::Client) {
+//! use {crate_name}::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "{proper_name}";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+{endpoints}];
+"#;
+
+/// Generate the metrics module, shared by all the clients, with the endpoints of
+/// the API.
+pub fn generate_metrics(crate_name: &str, proper_name: &str, api: &openapiv3::OpenAPI) -> String {
+    let mut paths: Vec<&String> = api.paths.iter().map(|(p, _)| p).collect();
+    paths.sort();
+
+    let endpoints: String = paths.iter().map(|p| format!("    {:?},\n", p)).collect();
+
+    TEMPLATE
+        .replace("{crate_name}", &crate_name.replace('-', "_"))
+        .replace("{proper_name}", proper_name)
+        .replace("{endpoints}", &endpoints)
+}
@@ -0,0 +1,93 @@
+//! Fixes for the specs of the vendors, applied before the types are generated.
+use openapiv3::{
+    OpenAPI, ReferenceOr, Schema, SchemaData, SchemaKind, StringFormat, StringType, Type,
+    VariantOrUnknownOrEmpty,
+};
+
+/// Apply the fixes for the spec of the vendor with the given proper name.
+pub fn apply(proper_name: &str, api: &mut OpenAPI) {
+    if proper_name == "GitHub" {
+        github(api);
+    } else if proper_name == "Gusto" {
+        gusto(api);
+    }
+}
+
+/*
+ * The GitHub spec describes some fields that only ever have a few values as
+ * plain strings, give them enums instead.
+ */
+fn github(api: &mut OpenAPI) {
+    // These have the same values as the state of milestones and pull requests, so
+    // they end up sharing the `State` enum.
+    for schema in &[
+        "issue",
+        "issue-simple",
+        "issue-search-result-item",
+        "pull-request-simple",
+    ] {
+        if let Some(ReferenceOr::Item(s)) = property_mut(api, schema, "state") {
+            s.schema_kind = string_enum(&["closed", "open"]);
+        }
+    }
+
+    if let Some(components) = api.components.as_mut() {
+        components.schemas.insert(
+            "repository-visibility".to_string(),
+            ReferenceOr::Item(Schema {
+                schema_data: SchemaData {
+                    description: Some(
+                        "The repository visibility: public, private, or internal.".to_string(),
+                    ),
+                    ..Default::default()
+                },
+                schema_kind: string_enum(&["internal", "private", "public"]),
+            }),
+        );
+    }
+    for schema in &[
+        "repository",
+        "minimal-repository",
+        "full-repository",
+        "team-repository",
+    ] {
+        if let Some(prop) = property_mut(api, schema, "visibility") {
+            *prop = ReferenceOr::Reference {
+                reference: "#/components/schemas/repository-visibility".to_string(),
+            };
+        }
+    }
+}
+
+// The times of a payroll are described as plain strings.
+fn gusto(api: &mut OpenAPI) {
+    for name in &["calculated_at", "payroll_deadline"] {
+        if let Some(ReferenceOr::Item(s)) = property_mut(api, "Payroll", name) {
+            s.schema_kind = SchemaKind::Type(Type::String(StringType {
+                format: VariantOrUnknownOrEmpty::Item(StringFormat::DateTime),
+                ..Default::default()
+            }));
+        }
+    }
+}
+
+fn property_mut<'a>(
+    api: &'a mut OpenAPI,
+    schema: &str,
+    name: &str,
+) -> Option<&'a mut ReferenceOr<Box<Schema>>> {
+    match api.components.as_mut()?.schemas.get_mut(schema)? {
+        ReferenceOr::Item(Schema {
+            schema_kind: SchemaKind::Type(Type::Object(o)),
+            ..
+        }) => o.properties.get_mut(name),
+        _ => None,
+    }
+}
+
+fn string_enum(values: &[&str]) -> SchemaKind {
+    SchemaKind::Type(Type::String(StringType {
+        enumeration: values.iter().map(|v| Some(v.to_string())).collect(),
+        ..Default::default()
+    }))
+}
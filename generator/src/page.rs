@@ -0,0 +1,191 @@
+use anyhow::Result;
+use inflector::cases::snakecase::to_snake_case;
+
+use crate::{struct_name, TypeDetails, TypeSpace};
+
+const TEMPLATE: &str = r#"//! One page of the results of a list endpoint, with the cursor to the next page.
+//!
+//! The APIs paginate in different ways: with a `Link` header, with a cursor or a
+//! `nextPageToken` in the body of the response, or with page numbers. A `Page` holds
+//! the items of a page and where the next one is the same way for all of them.
+
+/// Where the next page of a list endpoint is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageCursor {
+    /// The URL of the next page.
+    Url(String),
+    /// The token or cursor to pass back to the list function to get the next page.
+    Token(String),
+}
+
+impl PageCursor {
+    /// The URL or the token of the next page.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PageCursor::Url(s) | PageCursor::Token(s) => s,
+        }
+    }
+}
+
+/// One page of the results of a list endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Where the next page is, or `None` if this is the last page.
+    pub next: Option<PageCursor>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, next: Option<PageCursor>) -> Self {
+        Page { items, next }
+    }
+
+    /// Whether there are no more pages after this one.
+    pub fn is_last(&self) -> bool {
+        self.next.is_none()
+    }
+
+    /// Convert the items of the page, keeping the cursor.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next: self.next,
+        }
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// A response that holds one page of items, along with the cursor to the next page.
+pub trait Paginated {
+    type Item;
+
+    fn into_page(self) -> Page<Self::Item>;
+}
+"#;
+
+const LINK_TEMPLATE: &str = r#"
+impl crate::Client {
+    /// Get the first page of the list endpoint at `uri`, for example
+    /// `/users?limit=100`.
+    ///
+    /// The cursor to the next page is the `rel="next"` link of the `Link` header of
+    /// the response.
+    pub async fn get_page<T>(&self, uri: &str) -> anyhow::Result<Page<T>>
+    where
+        T: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let (link, items) = self.get_pages(uri).await?;
+        Ok(Page::new(items, next_cursor(link)))
+    }
+
+    /// Get the page the cursor of the previous page points to.
+    pub async fn next_page<T>(&self, cursor: &PageCursor) -> anyhow::Result<Page<T>>
+    where
+        T: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let url = match cursor {
+            PageCursor::Url(url) => reqwest::Url::parse(url)?,
+            PageCursor::Token(token) => anyhow::bail!("expected the URL of a page, got {}", token),
+        };
+        let (link, items) = self.get_pages_url(&url).await?;
+        Ok(Page::new(items, next_cursor(link)))
+    }
+}
+
+fn next_cursor(link: Option<hyperx::header::Link>) -> Option<PageCursor> {
+    link.as_ref()
+        .and_then(crate::utils::next_link)
+        .map(PageCursor::Url)
+}
+"#;
+
+/// Whether the list endpoints of the API are paginated with `Link` headers only.
+fn has_link_pages(proper_name: &str) -> bool {
+    proper_name == "GitHub" || proper_name == "Okta"
+}
+
+/// Generate the page module, shared by all the clients.
+///
+/// The responses that hold a page of items and a cursor in their body implement
+/// `Paginated`, for the APIs that paginate that way.
+pub fn generate_page(proper_name: &str, ts: &TypeSpace) -> Result<String> {
+    let mut out = TEMPLATE.to_string();
+    if has_link_pages(proper_name) {
+        out.push_str(LINK_TEMPLATE);
+    }
+
+    let mut impls = Vec::new();
+    for te in ts.id_to_entry.values() {
+        let (sn, props) = match (&te.name, &te.details) {
+            (Some(sn), TypeDetails::Object(props, _)) => (struct_name(sn), props),
+            _ => continue,
+        };
+
+        let mut vecs = Vec::new();
+        for (name, id) in props {
+            let rt = ts.render_type(id, false)?;
+            if rt.starts_with("Vec<") {
+                vecs.push((name.as_str(), rt));
+            }
+        }
+
+        let next = if proper_name == "Ramp" {
+            // The cursor is the `start` query parameter of the URL of the next page.
+            match props.get("page") {
+                Some(id) if ts.render_type(id, false)?.ends_with("Page") => {
+                    "self.page.next_start().map(PageCursor::Token)"
+                }
+                _ => continue,
+            }
+        } else if proper_name.starts_with("Google") {
+            match props.get("nextPageToken") {
+                Some(id) if ts.render_type(id, false)? == "String" => {
+                    "Some(self.next_page_token).filter(|t| !t.is_empty()).map(PageCursor::Token)"
+                }
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+
+        let items = if let Some(v) = vecs.iter().find(|(n, _)| *n == "data" || *n == "items") {
+            v
+        } else if let Some(v) = vecs.first() {
+            v
+        } else {
+            continue;
+        };
+
+        impls.push(format!(
+            r#"
+impl Paginated for crate::types::{} {{
+    type Item = {};
+
+    fn into_page(self) -> Page<Self::Item> {{
+        let next = {};
+        Page::new(self.{}, next)
+    }}
+}}
+"#,
+            sn,
+            items.1.trim_start_matches("Vec<").trim_end_matches('>'),
+            next,
+            to_snake_case(items.0),
+        ));
+    }
+
+    impls.sort();
+    for i in impls {
+        out.push_str(&i);
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,158 @@
+const TEMPLATE: &str = r#"//! Redaction of the credentials of a client, so that they stay out of logs, traces,
+//! errors and `Debug` output.
+//!
+//! The `Authorization` header, and the other headers carrying credentials, are
+//! marked as sensitive on every request: middleware and logs printing the request
+//! see `Sensitive` rather than their value. The URLs of the errors have the values
+//! of the query parameters with credentials, like the `key` of Google APIs, replaced
+//! with `REDACTED`.
+//!
+//! An API taking a credential in a query parameter of another name needs it added
+//! to the parameters of the client:
+//!
+//! ```
+//! use {crate_name}::{redact, request_options::RequestOptions};
+//!
+//! let options = RequestOptions::default().redact_url_params(&["signing_secret"]);
+//! // Pass `options` to `Client::with_request_options`, or call
+//! // `Client::with_redact_url_params` with the same parameters.
+//!
+//! let url = reqwest::Url::parse("https://example.com/files?key=abc&q=report").unwrap();
+//! assert_eq!(
+//!     redact::redact_url(&url, &options.redact_url_params),
+//!     "https://example.com/files?key=REDACTED&q=report"
+//! );
+//! ```
+use std::fmt;
+
+use reqwest::{
+    header::{HeaderMap, HeaderName},
+    Url,
+};
+
+/// What the credentials are replaced with.
+pub const REDACTED: &str = "REDACTED";
+
+/// The query parameters whose values are always redacted.
+pub const DEFAULT_URL_PARAMS: &[&str] = &[
+    "access_token",
+    "api_key",
+    "apikey",
+    "client_secret",
+    "key",
+    "refresh_token",
+    "signature",
+    "token",
+];
+
+/// A credential, which `Debug` shows as `REDACTED`, or as an empty string when it
+/// is not set.
+pub struct Secret<'a>(pub &'a str);
+
+impl fmt::Debug for Secret<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            f.write_str("\"\"")
+        } else {
+            f.write_str(REDACTED)
+        }
+    }
+}
+
+/// The headers with credentials, besides the ones named after an API key, a token or
+/// a secret.
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "cookie",
+    "proxy-authorization",
+    "set-cookie",
+];
+
+/// Whether the header carries credentials.
+pub fn is_sensitive_header(name: &HeaderName) -> bool {
+    // Header names are lowercase.
+    let name = name.as_str();
+    SENSITIVE_HEADERS.contains(&name)
+        || name.contains("api-key")
+        || name.contains("apikey")
+        || name.contains("token")
+        || name.contains("secret")
+}
+
+/// Mark the headers carrying credentials as sensitive, so that `Debug` does not
+/// show their value.
+pub fn redact_headers(headers: &mut HeaderMap) {
+    for (name, value) in headers.iter_mut() {
+        if is_sensitive_header(name) {
+            value.set_sensitive(true);
+        }
+    }
+}
+
+/// The URL, with its password and the values of the query parameters with
+/// credentials redacted. `params` are redacted along with `DEFAULT_URL_PARAMS`,
+/// the names are compared ignoring case.
+pub fn redact_url(url: &Url, params: &[String]) -> String {
+    let mut url = url.clone();
+    redact_url_mut(&mut url, params);
+    url.to_string()
+}
+
+fn redact_url_mut(url: &mut Url, params: &[String]) {
+    if url.password().is_some() {
+        let _ = url.set_password(Some(REDACTED));
+    }
+
+    let is_redacted = |name: &str| {
+        DEFAULT_URL_PARAMS
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(name))
+            || params.iter().any(|p| p.eq_ignore_ascii_case(name))
+    };
+    if !url.query_pairs().any(|(name, _)| is_redacted(&name)) {
+        // Leave the query as it was written.
+        return;
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let value = if is_redacted(&name) {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (name.into_owned(), value)
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+}
+
+/// Redact the URL of the request an error of `reqwest` is about, which shows in its
+/// message.
+pub(crate) fn redact_error(err: &mut anyhow::Error, params: &[String]) {
+    if let Some(err) = err.downcast_mut::<reqwest_middleware::Error>() {
+        match err {
+            reqwest_middleware::Error::Reqwest(err) => {
+                if let Some(url) = err.url_mut() {
+                    redact_url_mut(url, params);
+                }
+            }
+            reqwest_middleware::Error::Middleware(err) => redact_error(err, params),
+        }
+        return;
+    }
+
+    if let Some(url) = err
+        .downcast_mut::<reqwest::Error>()
+        .and_then(|e| e.url_mut())
+    {
+        redact_url_mut(url, params);
+    }
+}
+"#;
+
+/// Generate the redaction module, shared by all the clients.
+pub fn generate_redact(crate_name: &str) -> String {
+    TEMPLATE.replace("{crate_name}", &crate_name.replace('-', "_"))
+}
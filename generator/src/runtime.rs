@@ -0,0 +1,23 @@
+const TEMPLATE: &str = r#"//! Timers that work on native targets as well as in the browser.
+//!
+//! There is no tokio timer on `wasm32` targets, so the timers of the browser are
+//! used there instead.
+use std::time::Duration;
+
+/// Wait until the given duration has elapsed.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// Wait until the given duration has elapsed.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await
+}
+"#;
+
+/// Generate the runtime module, shared by all the clients.
+pub fn generate_runtime() -> String {
+    TEMPLATE.to_string()
+}
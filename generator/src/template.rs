@@ -25,42 +25,42 @@ impl Template {
 
         if !query_params.is_empty() {
             // Format the query params if they exist.
-            a("let mut query_args: Vec<(String, String)> = Default::default();");
+            a("let mut query_args: Vec<(&str, String)> = Default::default();");
 
             for (nam, (value, prop)) in &query_params {
                 if value == "Option<chrono::DateTime<chrono::Utc>>" {
                     a(&format!(
-                        r#"if let Some(date) = {} {{ query_args.push(("{}".to_string(), date.to_rfc3339())); }}"#,
+                        r#"if let Some(date) = {} {{ query_args.push(("{}", date.to_rfc3339())); }}"#,
                         nam, prop
                     ));
                 } else if value == "Option<uuid::Uuid>" {
                     a(&format!(
-                        r#"if let Some(u) = {} {{ query_args.push(("{}".to_string(), u.to_string())); }}"#,
+                        r#"if let Some(u) = {} {{ query_args.push(("{}", u.to_string())); }}"#,
                         nam, prop
                     ));
                 } else if value == "uuid::Uuid" {
                     a(&format!(
-                        r#"if {}.to_string() != uuid::Uuid::nil().to_string() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if {}.to_string() != uuid::Uuid::nil().to_string() {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 } else if value == "i64" || value == "i32" {
                     a(&format!(
-                        r#"if {} > 0 {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if {} > 0 {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 } else if value == "bool" && prop == "sendNotificationEmail" {
                     a(&format!(
-                        r#"query_args.push(("{}".to_string(), {}.to_string()));"#,
+                        r#"query_args.push(("{}", {}.to_string()));"#,
                         prop, nam
                     ));
                 } else if value == "bool" {
                     a(&format!(
-                        r#"if {} {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if {} {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 } else if value == "&str" {
                     a(&format!(
-                        r#"if !{}.is_empty() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if !{}.is_empty() {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 } else if value == "&[String]" {
@@ -69,18 +69,16 @@ impl Template {
                     // params.
                     // https://docs.github.com/en/rest/reference/migrations
                     a(&format!(
-                        r#"if !{}.is_empty() {{ query_args.push(("{}".to_string(), {}.join(" "))); }}"#,
+                        r#"if !{}.is_empty() {{ query_args.push(("{}", {}.join(" "))); }}"#,
                         nam, prop, nam
                     ));
                 } else {
                     a(&format!(
-                        r#"if !{}.to_string().is_empty() {{  query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if !{}.to_string().is_empty() {{  query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 }
             }
-
-            a("let query_ = serde_urlencoded::to_string(&query_args).unwrap();");
         }
 
         a("let url =");
@@ -115,7 +113,13 @@ impl Template {
             return out.to_string();
         }
 
-        out.push_str("format!(\"");
+        // With a query, the path is written into the same `String` as the query
+        // parameters, rather than formatted into one of its own first.
+        if query_params.is_empty() {
+            out.push_str("format!(\"");
+        } else {
+            out.push_str("crate::progenitor_support::build_url(\nformat_args!(\"");
+        }
         for c in self.components.iter() {
             out.push('/');
             match c {
@@ -126,11 +130,10 @@ impl Template {
             }
         }
 
-        if !query_params.is_empty() {
-            out.push_str("?{}");
+        out.push('"');
+        if has_params {
+            out.push_str(",\n");
         }
-
-        out.push_str("\",\n");
         for c in self.components.iter() {
             if let Component::Parameter(n) = &c {
                 if n == "type"
@@ -141,12 +144,12 @@ impl Template {
                     || n == "use"
                 {
                     out.push_str(&format!(
-                        "crate::progenitor_support::encode_path(&{}_.to_string()),",
+                        "crate::progenitor_support::encode_path(&{}_),",
                         to_snake_case(n)
                     ));
                 } else {
                     out.push_str(&format!(
-                        "crate::progenitor_support::encode_path(&{}.to_string()),",
+                        "crate::progenitor_support::encode_path(&{}),",
                         to_snake_case(n)
                     ));
                 }
@@ -154,7 +157,7 @@ impl Template {
         }
 
         if !query_params.is_empty() {
-            out.push_str("query_");
+            out.push_str("),\n&query_args");
         }
 
         out.push_str(");\n");
@@ -246,6 +249,8 @@ fn parse_inner(t: &str) -> Result<Template> {
 
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeMap;
+
     use anyhow::{anyhow, Context, Result};
 
     use super::{parse, Component, Template};
@@ -294,7 +299,22 @@ mod test {
         let out = t.compile(Default::default());
         let want = "let url =
 format!(\"/measure/{}\",
-crate::progenitor_support::encode_path(&number.to_string()),);\n";
+crate::progenitor_support::encode_path(&number),);\n";
+        assert_eq!(want, &out);
+
+        let mut query_params = BTreeMap::new();
+        query_params.insert(
+            "per_page".to_string(),
+            ("i64".to_string(), "per_page".to_string()),
+        );
+        let out = t.compile(query_params);
+        let want = "let mut query_args: Vec<(&str, String)> = Default::default();
+if per_page > 0 { query_args.push((\"per_page\", per_page.to_string())); }
+let url =
+crate::progenitor_support::build_url(
+format_args!(\"/measure/{}\",
+crate::progenitor_support::encode_path(&number),),
+&query_args);\n";
         assert_eq!(want, &out);
         Ok(())
     }
@@ -337,7 +357,12 @@ pub fn generate_docs_github(
 //! ```
 //!
 //! If you are a GitHub enterprise customer, you will want to create a client with the
+//! [Client#enterprise](https://docs.rs/{}/{}/{}/struct.Client.html#method.enterprise) method, which takes the URL of
+//! your GitHub Enterprise Server instance, or with the
 //! [Client#host](https://docs.rs/{}/{}/{}/struct.Client.html#method.host) method.
+//! `Client::set_upload_host` overrides the host release assets are uploaded to and
+//! `Client::set_api_version` pins the version of the REST API with the
+//! `X-GitHub-Api-Version` header.
 //!
 //! ## Feature flags
 //!
@@ -356,6 +381,10 @@ pub fn generate_docs_github(
 //!
 //! Then use the `Client::custom` constructor to provide a cache implementation.
 //!
+//! `HttpCache::in_home_dir()` persists responses to disk, while `HttpCache::in_memory()`
+//! keeps them in process memory, which suits long-running services polling the same endpoints.
+//! A cache can also be swapped in later with `Client::set_http_cache`.
+//!
 //! Here is an example:
 //!
 //! ```
@@ -387,6 +416,31 @@ pub fn generate_docs_github(
 //!     http_cache
 //! );
 //! ```
+//!
+//! ### blocking
+//!
+//! The `blocking` feature adds `blocking::Client`, which runs requests to completion on
+//! a runtime of its own for code that is not async.
+//!
+//! ### API groups
+//!
+//! Every group of endpoints, like `repos`, `issues` or `actions`, is a feature of its
+//! own. They are all on by default; to compile only the groups in use, turn the
+//! default features off and list them:
+//!
+//! ```toml
+//! [dependencies]
+//! {} = {{ version = "{}", default-features = false, features = ["rustls-tls", "repos", "pulls"] }}
+//! ```
+//!
+//! `apps` and `rate_limit` are always there, as the client itself uses them.
+//!
+//! ## WebAssembly
+//!
+//! On `wasm32` targets requests are sent with the `fetch` API of the browser, and retries
+//! and polling wait on the timers of the browser rather than on tokio. The `blocking`,
+//! `httpcache` and `native-tls` features are not available there.
+//!
 //! ## Authenticating GitHub apps
 //!
 //! You can also authenticate via a GitHub app.
@@ -462,11 +516,16 @@ pub fn generate_docs_github(
         version,
         name,
         name,
+        version,
+        name,
+        name,
         proper_name.to_lowercase(),
         host,
         proper_name.to_lowercase(),
         host,
         name,
+        version,
+        name,
         name,
         proper_name.to_lowercase(),
         host,
@@ -0,0 +1,83 @@
+const TEMPLATE: &str = r#"//! Instrumentation of requests with `tracing`.
+//!
+//! With the `tracing` feature enabled, every request is recorded in an `http_request`
+//! span with the method, the URL, the response status, the latency and the number of
+//! retries. The URL is recorded without credentials, query string or fragment since
+//! those might contain secrets.
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+
+/// Middleware recording a span for every request. Without the `tracing` feature,
+/// requests are passed through untouched.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TraceMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for TraceMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        #[cfg(feature = "tracing")]
+        {
+            handle_instrumented(req, extensions, next).await
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            next.run(req, extensions).await
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+async fn handle_instrumented(
+    req: Request,
+    extensions: &mut Extensions,
+    next: Next<'_>,
+) -> reqwest_middleware::Result<Response> {
+    use tracing::Instrument;
+
+    let span = tracing::info_span!(
+        "http_request",
+        method = %req.method(),
+        url = %sanitize_url(req.url()),
+        status = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+        retry_count = 0u32,
+    );
+
+    let start = web_time::Instant::now();
+    let result = next.run(req, extensions).instrument(span.clone()).await;
+    span.record("latency_ms", &(start.elapsed().as_millis() as u64));
+
+    match &result {
+        Ok(resp) => {
+            span.record("status", &resp.status().as_u16());
+        }
+        Err(e) => {
+            span.in_scope(|| tracing::warn!(error = %e, "request failed"));
+        }
+    }
+
+    result
+}
+
+#[cfg(feature = "tracing")]
+fn sanitize_url(url: &reqwest::Url) -> String {
+    let mut url = url.clone();
+    // These only fail for URLs that can not have credentials in the first place.
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url.set_query(None);
+    url.set_fragment(None);
+    url.to_string()
+}
+"#;
+
+/// Generate the trace module, shared by all the clients.
+pub fn generate_trace() -> String {
+    TEMPLATE.to_string()
+}
@@ -1,29 +1,34 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::{bail, Result};
 use inflector::cases::snakecase::to_snake_case;
 
-use crate::{render_param, struct_name, TypeDetails, TypeSpace};
+use crate::{render_param, struct_name, tag_feature, TypeDetails, TypeSpace};
 
 /*
  * Declare named types we know about:
  */
-pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
-    let mut out = String::new();
-
-    let mut a = |s: &str| {
-        out.push_str(s);
-        out.push('\n');
-    };
+/// A generated type, with its impls.
+pub struct TypeItem {
+    pub name: String,
+    pub code: String,
+}
 
-    a("//! The data types sent to and returned from the API client.");
-    a("    use schemars::JsonSchema;");
-    a("    use serde::{Serialize, Deserialize};");
-    a("");
+pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<Vec<TypeItem>> {
+    let mut items = Vec::new();
+    let defaults = request_types(ts);
+    let sent_only = ts.request_types.clone();
 
-    for te in ts.clone().id_to_entry.values() {
+    for (id, te) in ts.clone().id_to_entry.iter() {
         if let Some(sn) = te.name.as_deref() {
             let sn = struct_name(sn);
+            let name = sn.clone();
+
+            let mut out = String::new();
+            let mut a = |s: &str| {
+                out.push_str(s);
+                out.push('\n');
+            };
 
             match &te.details {
                 TypeDetails::Enum(vals, schema_data) => {
@@ -40,17 +45,24 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     );
                     a(&p);
                 }
-                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)),
+                TypeDetails::OneOf(omap, _) => {
+                    let default = defaults.contains(&sn);
+                    a(&do_one_of_type(ts, omap, sn, default))
+                }
                 TypeDetails::AnyOf(omap, _) => {
                     // When Stripe uses anyof what they really mean is one of.
                     // Because something can not both be a string and an object.
+                    let default = defaults.contains(&sn);
                     if proper_name == "Stripe" {
-                        a(&do_one_of_type(ts, omap, sn));
+                        a(&do_one_of_type(ts, omap, sn, default));
                     } else {
-                        a(&do_all_of_type(ts, omap, sn));
+                        a(&do_all_of_type(ts, omap, sn, default));
                     }
                 }
-                TypeDetails::AllOf(omap, _) => a(&do_all_of_type(ts, omap, sn)),
+                TypeDetails::AllOf(omap, _) => {
+                    let default = defaults.contains(&sn);
+                    a(&do_all_of_type(ts, omap, sn, default))
+                }
                 TypeDetails::Object(omap, schema_data) => {
                     /*
                      * TODO: This breaks things so ignore for now.
@@ -96,6 +108,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                         || sn == "DescriptionlessJobOptionsDataType"
                         || sn == "SubmitJobOptions"
                         || sn == "SubmitJobOptionsData"
+                        || defaults.contains(&sn)
                     {
                         a(
                             "#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, \
@@ -106,6 +119,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                              JsonSchema)]");
                     }
                     a(&format!("pub struct {} {{", sn));
+                    let mut fields: Vec<(String, String)> = Default::default();
                     for (name, tid) in omap.iter() {
                         if let Ok(mut rt) = ts.render_type(tid, true) {
                             // Stripe has some really weird recursive types.
@@ -171,8 +185,31 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
 
                             let te = ts.id_to_entry.get(tid).unwrap();
 
+                            // The nullable fields of a `PATCH` body are cleared when they
+                            // are sent as `null`, so they can be left out, cleared or set.
+                            let nullable = ts
+                                .patch_types
+                                .get(id)
+                                .is_some_and(|names| names.contains(name));
+                            if nullable {
+                                let inner = rt
+                                    .strip_prefix("Option<")
+                                    .and_then(|t| t.strip_suffix('>'))
+                                    .unwrap_or(&rt);
+                                rt = format!("crate::utils::Nullable<{}>", inner);
+                            }
+
+                            // Ramp's transaction time keeps its offset, so that the day
+                            // of a transaction is the one the card holder saw.
+                            if proper_name == "Ramp" && prop == "user_transaction_time" {
+                                rt = "Option<chrono::DateTime<chrono::FixedOffset>>".to_string();
+                            }
+
                             // Render the serde string.
-                            if rt == "String"
+                            if rt.starts_with("crate::utils::Nullable<") {
+                                a(r#"#[serde(default,
+                                    skip_serializing_if = "crate::utils::Nullable::is_unset","#);
+                            } else if rt == "String"
                                 || rt.starts_with("Vec<")
                                 || rt.starts_with("Option<")
                                 || rt.starts_with("HashMap<")
@@ -194,6 +231,8 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 } else if rt.starts_with("Option<chrono::NaiveDate") {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::date_format::deserialize","#);
+                                } else if rt.ends_with("chrono::FixedOffset>>") {
+                                    a(r#"skip_serializing_if = "Option::is_none","#);
                                 } else if rt.starts_with("Option<chrono::DateTime") {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::date_time_format::deserialize","#);
@@ -291,12 +330,36 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                             }
 
                             a(&format!("pub {}: {},", prop, rt));
+                            fields.push((prop, rt));
                         } else {
                             bail!("rendering type {} {:?} failed", name, tid);
                         }
                     }
+
+                    // GitHub adds fields to its responses before they are in the spec,
+                    // keep them so they are not lost. The types of the request bodies
+                    // are only ever sent, so they do not need it.
+                    if proper_name == "GitHub" && !sent_only.contains(id) {
+                        a("/**");
+                        a("* The fields that are not described by the spec.");
+                        a("*/");
+                        a("#[serde(flatten)]");
+                        a("pub extra: std::collections::HashMap<String, serde_json::Value>,");
+                    }
                     a("}");
                     a("");
+
+                    // A request with a single string, like the new name of something, can
+                    // be built from the string.
+                    if let [(prop, rt)] = fields.as_slice() {
+                        if sn.ends_with("Request") && rt == "String" {
+                            a(&render_from_string(&sn, prop));
+                        }
+                    }
+
+                    if sn.ends_with("Request") {
+                        a(&render_request_builder(&sn, &fields));
+                    }
                 }
                 TypeDetails::Basic(..) => {}
                 TypeDetails::Unknown => {}
@@ -304,13 +367,523 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                 TypeDetails::Array(..) => {}
                 TypeDetails::Optional(..) => {}
             }
+
+            if !out.is_empty() {
+                items.push(TypeItem { name, code: out });
+            }
+        }
+    }
+
+    if proper_name == "GitHub" {
+        items.extend(render_github_typed_ids());
+    }
+
+    Ok(items)
+}
+
+/// Build the request `sn`, whose only field is the string `prop`, from a string.
+fn render_from_string(sn: &str, prop: &str) -> String {
+    format!(
+        r#"impl std::convert::From<String> for {sn} {{
+    fn from({prop}: String) -> Self {{
+        {sn} {{ {prop} }}
+    }}
+}}
+
+impl std::convert::From<&str> for {sn} {{
+    fn from({prop}: &str) -> Self {{
+        {sn} {{
+            {prop}: {prop}.to_string(),
+        }}
+    }}
+}}
+"#,
+        sn = sn,
+        prop = prop,
+    )
+}
+
+/// The request bodies, and the types of the fields they can not be sent without,
+/// which all implement `Default` so that a request only sets the fields it needs.
+fn request_types(ts: &TypeSpace) -> BTreeSet<String> {
+    let mut details: BTreeMap<String, &TypeDetails> = Default::default();
+    for te in ts.id_to_entry.values() {
+        if let Some(sn) = te.name.as_deref() {
+            details.insert(struct_name(sn), &te.details);
+        }
+    }
+
+    let mut todo: Vec<String> = details
+        .iter()
+        .filter(|(sn, d)| sn.ends_with("Request") && matches!(d, TypeDetails::Object(..)))
+        .map(|(sn, _)| sn.to_string())
+        .collect();
+    let mut types: BTreeSet<String> = Default::default();
+    while let Some(sn) = todo.pop() {
+        if types.contains(&sn) {
+            continue;
+        }
+        let fields: Vec<&crate::TypeId> = match details.get(&sn) {
+            Some(TypeDetails::Object(omap, _)) => omap.values().collect(),
+            // Enums of several types default to their first type.
+            Some(TypeDetails::OneOf(omap, _)) => omap.iter().take(1).collect(),
+            Some(TypeDetails::AllOf(omap, _)) | Some(TypeDetails::AnyOf(omap, _)) => {
+                omap.iter().collect()
+            }
+            _ => Default::default(),
+        };
+        for tid in fields {
+            // Optional fields and vectors have a default whatever their type.
+            if let Ok(rt) = ts.render_type(tid, true) {
+                let rt = rt.trim_start_matches("Box<").trim_end_matches('>');
+                if details.contains_key(rt) {
+                    todo.push(rt.to_string());
+                }
+            }
+        }
+        types.insert(sn);
+    }
+
+    types
+}
+
+/// The builder of the request `sn`, with a method setting each of its `fields`.
+fn render_request_builder(sn: &str, fields: &[(String, String)]) -> String {
+    let mut setters = String::new();
+    for (prop, rt) in fields {
+        let primitive =
+            |t: &str| matches!(t, "bool" | "i32" | "i64" | "u32" | "u64" | "f32" | "f64");
+        let (param, value) =
+            if let Some(t) = rt.strip_prefix("Option<").and_then(|t| t.strip_suffix('>')) {
+                if primitive(t) {
+                    (t.to_string(), format!("Some({})", prop))
+                } else {
+                    (
+                        format!("impl Into<{}>", t),
+                        format!("Some({}.into())", prop),
+                    )
+                }
+            } else if primitive(rt) || rt.starts_with("Vec<") || rt.contains("HashMap<") {
+                (rt.to_string(), prop.to_string())
+            } else {
+                (format!("impl Into<{}>", rt), format!("{}.into()", prop))
+            };
+        setters.push_str(&format!(
+            r#"
+    pub fn {prop}(mut self, {prop}: {param}) -> Self {{
+        self.0.{prop} = {value};
+        self
+    }}
+"#,
+            prop = prop,
+            param = param,
+            value = value,
+        ));
+    }
+
+    format!(
+        r#"impl {sn} {{
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> {sn}Builder {{
+        {sn}Builder(Default::default())
+    }}
+}}
+
+/// The builder of a `{sn}`.
+#[derive(Debug, Clone)]
+pub struct {sn}Builder({sn});
+
+impl {sn}Builder {{{setters}
+    pub fn build(self) -> {sn} {{
+        self.0
+    }}
+}}
+"#,
+        sn = sn,
+        setters = setters,
+    )
+}
+
+/// The types module, with all the types in one file.
+pub fn render_types(items: &[TypeItem]) -> String {
+    let mut out = String::new();
+
+    out.push_str("//! The data types sent to and returned from the API client.\n");
+    out.push_str("    use schemars::JsonSchema;\n");
+    out.push_str("    use serde::{Serialize, Deserialize};\n");
+    out.push('\n');
+    for item in items {
+        out.push_str(&item.code);
+    }
+
+    out
+}
+
+/// The module the types shared by several API groups go in.
+const SHARED_TYPES: &str = "shared";
+
+/// Split the types module in a file per API group, for the APIs whose types are too
+/// many to edit in one file. `tags` holds the functions of every tag module, and
+/// `helpers` the code written by hand with the features it is compiled behind.
+///
+/// A type goes in the group its name starts with, like `GistsCreateRequest`, or else
+/// in the only group whose functions use it, or else in the only group of the types
+/// it is a field of. The module of a group is compiled behind the feature of the
+/// group, so a type used by code compiled without that feature is shared instead.
+/// The other types are shared. The types are all re-exported from `types`, so where
+/// they go does not change their path.
+///
+/// Returns the files of the module, `mod.rs` first, by name.
+pub fn split_types(
+    items: &[TypeItem],
+    tags: &BTreeMap<String, String>,
+    helpers: &[(&[&str], String)],
+) -> BTreeMap<String, String> {
+    let names = items
+        .iter()
+        .map(|i| i.name.as_str())
+        .collect::<BTreeSet<_>>();
+    let words = |code: &str| {
+        code.split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|w| names.contains(w))
+            .map(|w| w.to_string())
+            .collect::<BTreeSet<_>>()
+    };
+
+    // The groups whose functions use each type.
+    let mut used_by: BTreeMap<String, BTreeSet<&str>> = Default::default();
+    for (tag, content) in tags {
+        for rest in content.split("crate::types::").skip(1) {
+            let name = rest
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+                .unwrap_or_default();
+            if names.contains(name) {
+                used_by
+                    .entry(name.to_string())
+                    .or_default()
+                    .insert(tag.as_str());
+            }
+        }
+    }
+
+    // The types each type is a field of.
+    let mut fields_of: BTreeMap<String, BTreeSet<String>> = Default::default();
+    for item in items {
+        for name in words(&item.code) {
+            if name != item.name {
+                fields_of
+                    .entry(name)
+                    .or_default()
+                    .insert(item.name.to_string());
+            }
+        }
+    }
+
+    let mut groups: BTreeMap<String, String> = Default::default();
+    for item in items {
+        if GITHUB_TYPED_IDS.iter().any(|(n, _, _, _)| *n == item.name) {
+            // The IDs are taken by the functions of many groups.
+            continue;
+        }
+        if let Some(tag) = tag_of_name(&item.name, tags) {
+            groups.insert(item.name.to_string(), tag);
+        } else if let Some(users) = used_by.get(&item.name) {
+            if users.len() == 1 {
+                groups.insert(
+                    item.name.to_string(),
+                    users.iter().next().unwrap().to_string(),
+                );
+            }
+        }
+    }
+    // Types only used as fields follow the types they are fields of, which may have
+    // just been placed themselves.
+    loop {
+        let mut placed = false;
+        for item in items {
+            if groups.contains_key(&item.name) || used_by.contains_key(&item.name) {
+                continue;
+            }
+            let owners = match fields_of.get(&item.name) {
+                Some(owners) => owners,
+                None => continue,
+            };
+            let owner_groups = owners
+                .iter()
+                .map(|o| groups.get(o))
+                .collect::<Option<BTreeSet<_>>>();
+            if let Some(owner_groups) = owner_groups {
+                if owner_groups.len() == 1 {
+                    let group = owner_groups.into_iter().next().unwrap().to_string();
+                    groups.insert(item.name.to_string(), group);
+                    placed = true;
+                }
+            }
+        }
+        if !placed {
+            break;
         }
     }
 
-    Ok(out.to_string())
+    // The features always enabled where each type is used.
+    let mut used_with: BTreeMap<String, Vec<BTreeSet<String>>> = Default::default();
+    for (name, users) in &used_by {
+        for tag in users {
+            let features = tag_feature("GitHub", tag).into_iter().collect();
+            used_with.entry(name.to_string()).or_default().push(features);
+        }
+    }
+    for (features, code) in helpers {
+        for name in words(code) {
+            let features = features.iter().map(|f| f.to_string()).collect();
+            used_with.entry(name).or_default().push(features);
+        }
+    }
+    // A type used without the feature of its group is shared, and so are then the
+    // types it has as fields.
+    loop {
+        let mut moved = false;
+        for item in items {
+            let feature = match groups.get(&item.name).and_then(|g| tag_feature("GitHub", g)) {
+                Some(feature) => feature,
+                None => continue,
+            };
+            let owners = fields_of.get(&item.name).into_iter().flatten().map(|owner| {
+                groups
+                    .get(owner)
+                    .and_then(|g| tag_feature("GitHub", g))
+                    .into_iter()
+                    .collect::<BTreeSet<_>>()
+            });
+            let mut users = used_with
+                .get(&item.name)
+                .into_iter()
+                .flatten()
+                .cloned()
+                .chain(owners);
+            if !users.all(|features| features.contains(&feature)) {
+                groups.remove(&item.name);
+                moved = true;
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+
+    let mut modules: BTreeMap<String, Vec<&TypeItem>> = Default::default();
+    for item in items {
+        let group = groups
+            .get(&item.name)
+            .map(|g| g.as_str())
+            .unwrap_or(SHARED_TYPES);
+        modules.entry(group.to_string()).or_default().push(item);
+    }
+
+    let mut files: BTreeMap<String, String> = Default::default();
+    let mut mod_rs = String::new();
+    mod_rs.push_str("//! The data types sent to and returned from the API client.\n");
+    mod_rs.push_str("//!\n");
+    mod_rs.push_str("//! The types are split by the API group using them, and compiled along with\n");
+    mod_rs.push_str("//! its functions. The types used by several groups are in `shared`, which is\n");
+    mod_rs.push_str("//! always compiled. They are all re-exported here.\n");
+    let cfg = |module: &str| match tag_feature("GitHub", module) {
+        Some(feature) if module != SHARED_TYPES => format!("#[cfg(feature = \"{}\")]\n", feature),
+        _ => String::new(),
+    };
+    for module in modules.keys() {
+        mod_rs.push_str(&cfg(module));
+        mod_rs.push_str(&format!("mod {};\n", module));
+    }
+    mod_rs.push('\n');
+    for module in modules.keys() {
+        mod_rs.push_str(&cfg(module));
+        mod_rs.push_str(&format!("pub use self::{}::*;\n", module));
+    }
+    files.insert("mod.rs".to_string(), mod_rs);
+
+    for (module, items) in &modules {
+        let mut out = String::new();
+        if module == SHARED_TYPES {
+            out.push_str("//! The data types used by several API groups.\n");
+        } else {
+            out.push_str(&format!(
+                "//! The data types of the `{}` API group.\n",
+                module
+            ));
+        }
+        out.push_str("use schemars::JsonSchema;\n");
+        out.push_str("use serde::{Deserialize, Serialize};\n");
+        // Only import the other modules when a type of theirs is used, so that the
+        // import is never unused.
+        let uses_others = items.iter().any(|item| {
+            bare_names(&item.code, &names)
+                .iter()
+                .any(|name| groups.get(*name).map(|g| g.as_str()).unwrap_or(SHARED_TYPES) != module)
+        });
+        if uses_others {
+            out.push_str("\nuse super::*;\n");
+        }
+        out.push('\n');
+        for item in items {
+            out.push_str(&item.code);
+        }
+        files.insert(format!("{}.rs", module), out);
+    }
+
+    files
+}
+
+/// The names of the types the code refers to without a path, like `Label` but not
+/// `serde_json::Value`.
+fn bare_names<'a>(code: &'a str, names: &BTreeSet<&str>) -> BTreeSet<&'a str> {
+    let mut found = BTreeSet::new();
+    let mut rest = code;
+    while let Some(start) = rest.find(|c: char| c.is_alphanumeric() || c == '_') {
+        let len = rest[start..]
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(rest.len() - start);
+        let word = &rest[start..start + len];
+        if !rest[..start].ends_with("::") && names.contains(word) {
+            found.insert(word);
+        }
+        rest = &rest[start + len..];
+    }
+
+    found
+}
+
+/// The tag a type name starts with, as a whole word and in the singular or the
+/// plural, like `Gists` or `Gist` for `gists`. The longest tag wins, so that
+/// `GitignoreTemplate` is not put in `git`.
+fn tag_of_name(name: &str, tags: &BTreeMap<String, String>) -> Option<String> {
+    let mut found: Option<(&str, usize)> = None;
+    for tag in tags.keys() {
+        let plural = struct_name(tag);
+        let singular = plural.strip_suffix('s').unwrap_or(&plural).to_string();
+        for prefix in [&plural, &singular] {
+            let rest = match name.strip_prefix(prefix.as_str()) {
+                Some(rest) => rest,
+                None => continue,
+            };
+            let whole_word = rest
+                .chars()
+                .next()
+                .map(|c| c.is_uppercase())
+                .unwrap_or(true);
+            if whole_word && found.map(|(_, len)| prefix.len() > len).unwrap_or(true) {
+                found = Some((tag.as_str(), prefix.len()));
+            }
+        }
+    }
+
+    found.map(|(tag, _)| tag.to_string())
+}
+
+/*
+ * The IDs of GitHub taken by the functions as `(type, path parameter, inner type,
+ * description)`, so that an ID of one kind can not be passed where another is expected.
+ */
+const GITHUB_TYPED_IDS: &[(&str, &str, &str, &str)] = &[
+    (
+        "CommentId",
+        "comment_id",
+        "i64",
+        "The ID of a comment on an issue, a pull request, a commit or a gist.",
+    ),
+    ("GistId", "gist_id", "String", "The ID of a gist."),
+    ("RepoId", "repository_id", "i64", "The ID of a repository."),
+    (
+        "UserId",
+        "account_id",
+        "i64",
+        "The ID of a user or an organization account.",
+    ),
+];
+
+/// The typed ID a GitHub function takes for a path parameter, if any.
+pub fn github_typed_id(param: &str) -> Option<&'static str> {
+    GITHUB_TYPED_IDS
+        .iter()
+        .find(|(_, p, _, _)| *p == param)
+        .map(|(name, _, _, _)| *name)
 }
 
-fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+fn render_github_typed_ids() -> Vec<TypeItem> {
+    let mut items = Vec::new();
+
+    for (name, _, inner, description) in GITHUB_TYPED_IDS {
+        let (derive, from) = if *inner == "String" {
+            (
+                "Clone",
+                format!(
+                    r#"impl From<&str> for {name} {{
+    fn from(id: &str) -> Self {{
+        {name}(id.to_string())
+    }}
+}}
+
+impl From<&String> for {name} {{
+    fn from(id: &String) -> Self {{
+        {name}(id.to_string())
+    }}
+}}
+
+"#,
+                    name = name
+                ),
+            )
+        } else {
+            ("Clone, Copy", String::new())
+        };
+
+        let code = format!(
+            r#"/// {description}
+///
+/// Functions taking it accept the bare `{inner}` as well.
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, {derive}, JsonSchema,
+)]
+#[serde(transparent)]
+pub struct {name}(pub {inner});
+
+impl From<{inner}> for {name} {{
+    fn from(id: {inner}) -> Self {{
+        {name}(id)
+    }}
+}}
+
+{from}impl From<{name}> for {inner} {{
+    fn from(id: {name}) -> Self {{
+        id.0
+    }}
+}}
+
+impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        self.0.fmt(f)
+    }}
+}}
+
+"#,
+            description = description,
+            inner = inner,
+            derive = derive,
+            name = name,
+            from = from,
+        );
+        items.push(TypeItem {
+            name: name.to_string(),
+            code,
+        });
+    }
+
+    items
+}
+
+fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String, default: bool) -> String {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -436,7 +1009,19 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     a("");
 
     // TODO: Implement defaults for these then turn on defaults for
-    // the objects above.
+    // the objects above. Only the ones requests are built with have one for now,
+    // with their first type.
+    if default {
+        a(&format!(
+            r#"impl Default for {} {{
+                fn default() -> Self {{
+                    {}::{}(Default::default())
+                }}
+            }}"#,
+            sn, sn, fns[0],
+        ));
+        a("");
+    }
 
     // Render the implementation to easily unpack these things for the end user.
     for (fn_name, name) in &name_map {
@@ -496,7 +1081,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     out
 }
 
-fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String, default: bool) -> String {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -515,7 +1100,7 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     description = format!("/// {}", description.replace('\n', "\n/// "));
     a(&description);
 
-    if sn == "SubmitJobOptionsAllOf" || sn == "DescriptionlessJobOptionsAllOf" {
+    if sn == "SubmitJobOptionsAllOf" || sn == "DescriptionlessJobOptionsAllOf" || default {
         a("#[derive(Serialize, Deserialize, Default, PartialEq, Debug, Clone, JsonSchema)]");
     } else {
         a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
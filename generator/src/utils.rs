@@ -643,6 +643,82 @@ pub mod deserialize_null_vector {
         Ok(Default::default())
     }
 }
+
+/// A nullable field of a `PATCH` request, which the API clears when it is sent as
+/// `null`, like the milestone of a GitHub issue. Unlike an `Option`, it tells a field
+/// left as it is from a field to clear.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Nullable<T> {
+    /// Leave the field as it is, it is not sent.
+    Unset,
+    /// Clear the field, it is sent as `null`.
+    Null,
+    /// Set the field to the value.
+    Set(T),
+}
+
+impl<T> Default for Nullable<T> {
+    fn default() -> Self {
+        Nullable::Unset
+    }
+}
+
+impl<T> Nullable<T> {
+    pub fn is_unset(&self) -> bool {
+        matches!(self, Nullable::Unset)
+    }
+
+    /// The value of the field, when it is set.
+    pub fn as_option(&self) -> Option<&T> {
+        match self {
+            Nullable::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for Nullable<T> {
+    fn from(value: T) -> Self {
+        Nullable::Set(value)
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&self.as_option(), serializer)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A missing field is unset, thanks to `#[serde(default)]`.
+        let value = <Option<T> as serde::Deserialize<'de>>::deserialize(deserializer)?;
+        Ok(match value {
+            Some(value) => Nullable::Set(value),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: schemars::JsonSchema> schemars::JsonSchema for Nullable<T> {
+    fn is_referenceable() -> bool {
+        <Option<T> as schemars::JsonSchema>::is_referenceable()
+    }
+
+    fn schema_name() -> String {
+        <Option<T> as schemars::JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <Option<T> as schemars::JsonSchema>::json_schema(gen)
+    }
+}
 "#;
 
 const GITHUB_TEMPLATE: &str = r#"//const X_GITHUB_REQUEST_ID: &str = "x-github-request-id";
@@ -701,6 +777,12 @@ pub enum MediaType {
     Json,
     /// Return json in preview form
     Preview(&'static str),
+    /// Return the raw contents of a file
+    Raw,
+    /// Return the changes of a pull request or commit as a diff
+    Diff,
+    /// Return the changes of a pull request or commit as a series of patches
+    Patch,
 }
 
 impl Default for MediaType {
@@ -713,6 +795,9 @@ impl From<MediaType> for mime::Mime {
     fn from(media: MediaType) -> mime::Mime {
         match media {
             MediaType::Json => "application/vnd.github.v3+json".parse().unwrap(),
+            MediaType::Raw => "application/vnd.github.raw".parse().unwrap(),
+            MediaType::Diff => "application/vnd.github.v3.diff".parse().unwrap(),
+            MediaType::Patch => "application/vnd.github.v3.patch".parse().unwrap(),
             MediaType::Preview(codename) => {
                 format!("application/vnd.github.{}-preview+json", codename)
                     .parse()
@@ -724,6 +809,73 @@ impl From<MediaType> for mime::Mime {
     }
 }
 
+/// Returned when the rate limit of the API has been exhausted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitExceeded {
+    /// Seconds until the rate limit resets.
+    pub reset_in: u64,
+}
+
+impl fmt::Display for RateLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rate limit exceeded, will reset in {} seconds",
+            self.reset_in
+        )
+    }
+}
+
+impl std::error::Error for RateLimitExceeded {}
+
+/// Turn the body of a response into a stream of chunks, without buffering it in memory.
+pub fn response_stream(
+    resp: reqwest::Response,
+) -> impl futures::Stream<Item = anyhow::Result<bytes::Bytes>> {
+    futures::stream::try_unfold(resp, |mut resp| async move {
+        let chunk = resp.chunk().await?;
+        Ok::<_, anyhow::Error>(chunk.map(|chunk| (chunk, resp)))
+    })
+}
+
+/// The URLs of the pages from the `rel="next"` link to the `rel="last"` one, when
+/// the pages are numbered with a `page` parameter. Returns `None` when there is no
+/// last page, like for the endpoints paginated with a cursor.
+pub fn page_urls(l: &hyperx::header::Link) -> Option<Vec<String>> {
+    let link = |relation: hyperx::header::RelationType| {
+        l.values()
+            .iter()
+            .find(|value| value.rel().map_or(false, |rels| rels.contains(&relation)))
+            .and_then(|value| reqwest::Url::parse(value.link()).ok())
+    };
+    let page = |url: &reqwest::Url| {
+        url.query_pairs()
+            .find(|(name, _)| name == "page")
+            .and_then(|(_, value)| value.parse::<u64>().ok())
+    };
+
+    let next = link(hyperx::header::RelationType::Next)?;
+    let last = link(hyperx::header::RelationType::Last)?;
+    let pages = page(&next)?..=page(&last)?;
+
+    Some(
+        pages
+            .map(|n| {
+                let mut url = next.clone();
+                url.query_pairs_mut()
+                    .clear()
+                    .extend_pairs(next.query_pairs().map(|(name, value)| {
+                        if name == "page" {
+                            (name, n.to_string().into())
+                        } else {
+                            (name, value)
+                        }
+                    }));
+                url.to_string()
+            })
+            .collect(),
+    )
+}
 "#;
 
 pub fn generate_utils(proper_name: &str) -> String {
@@ -0,0 +1,194 @@
+const TEMPLATE: &str = r#"//! Verification of the signatures of webhook deliveries.
+//!
+//! The body must be verified exactly as it was received, before it is parsed.
+//!
+//! ```
+//! # fn run(headers: &reqwest::header::HeaderMap, body: &[u8]) -> anyhow::Result<()> {
+//! use std::time::Duration;
+//!
+//! use {crate_name}::webhook_verify::{verify_webhook, WebhookVerifier};
+//!
+//! verify_webhook(b"the signing secret", headers, body)?;
+//!
+//! // Or accept deliveries signed longer ago.
+//! WebhookVerifier::new(b"the signing secret")
+//!     .tolerance(Duration::from_secs(15 * 60))
+//!     .verify(headers, body)?;
+//! # Ok(())
+//! # }
+//! ```
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use hmac::{Hmac, Mac};
+use reqwest::header::HeaderMap;
+use sha2::Sha256;
+
+/// How long after it was signed a delivery is accepted by default.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// The ways providers sign their webhook deliveries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// A `Stripe-Signature: t=<timestamp>,v1=<signature>` header, where the signature
+    /// is the HMAC SHA-256 of `<timestamp>.<body>`. There are more `v1` signatures
+    /// while the secret is being rolled.
+    Stripe,
+    /// The `X-Slack-Request-Timestamp` and `X-Slack-Signature: v0=<signature>` headers,
+    /// where the signature is the HMAC SHA-256 of `v0:<timestamp>:<body>`.
+    Slack,
+    /// The secret itself is sent in the given header, like Okta does for event hooks.
+    SharedSecret(&'static str),
+}
+
+/// Verifies the deliveries of a webhook with its secret.
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    scheme: Scheme,
+    secret: Vec<u8>,
+    tolerance: Duration,
+}
+
+/// Verify a delivery signed the way {proper_name} signs them, with the default
+/// tolerance.
+pub fn verify_webhook(secret: &[u8], headers: &HeaderMap, body: &[u8]) -> Result<()> {
+    WebhookVerifier::new(secret).verify(headers, body)
+}
+
+/// Compare two byte strings in a time that does not depend on where they differ.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+impl WebhookVerifier {
+    /// A verifier for deliveries signed the way {proper_name} signs them.
+    pub fn new(secret: &[u8]) -> Self {
+        WebhookVerifier::with_scheme({scheme}, secret)
+    }
+
+    pub fn with_scheme(scheme: Scheme, secret: &[u8]) -> Self {
+        WebhookVerifier {
+            scheme,
+            secret: secret.to_vec(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Accept deliveries signed at most this long ago, or this far in the future
+    /// to allow for clock skew. Replayed deliveries are rejected once they are older.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Verify the headers of a delivery against its raw body.
+    pub fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<()> {
+        let now = web_time::SystemTime::now()
+            .duration_since(web_time::UNIX_EPOCH)?
+            .as_secs() as i64;
+
+        match self.scheme {
+            Scheme::Stripe => {
+                let header = header(headers, "Stripe-Signature")?;
+                let mut timestamp = None;
+                let mut signatures = Vec::new();
+                for part in header.split(',') {
+                    match part.trim().split_once('=') {
+                        Some(("t", t)) => timestamp = Some(t),
+                        Some(("v1", s)) => signatures.push(s),
+                        _ => {}
+                    }
+                }
+                let timestamp = timestamp
+                    .ok_or_else(|| anyhow!("`Stripe-Signature` header has no timestamp"))?;
+                self.check_timestamp(timestamp, now)?;
+
+                let expected = self.sign(&[timestamp.as_bytes(), b".", body])?;
+                self.check_signatures(&signatures, &expected)
+            }
+            Scheme::Slack => {
+                let timestamp = header(headers, "X-Slack-Request-Timestamp")?;
+                self.check_timestamp(timestamp, now)?;
+
+                let signature = header(headers, "X-Slack-Signature")?
+                    .strip_prefix("v0=")
+                    .ok_or_else(|| anyhow!("`X-Slack-Signature` header must start with `v0=`"))?;
+                let expected = self.sign(&[b"v0:", timestamp.as_bytes(), b":", body])?;
+                self.check_signatures(&[signature], &expected)
+            }
+            Scheme::SharedSecret(name) => {
+                if constant_time_eq(header(headers, name)?.as_bytes(), &self.secret) {
+                    Ok(())
+                } else {
+                    bail!("`{}` header does not match the secret", name)
+                }
+            }
+        }
+    }
+
+    fn check_timestamp(&self, timestamp: &str, now: i64) -> Result<()> {
+        let timestamp: i64 = timestamp
+            .trim()
+            .parse()
+            .map_err(|_| anyhow!("invalid webhook timestamp: {:?}", timestamp))?;
+        if (now - timestamp).unsigned_abs() > self.tolerance.as_secs() {
+            bail!(
+                "webhook timestamp {} is outside of the tolerance of {:?}",
+                timestamp,
+                self.tolerance
+            );
+        }
+
+        Ok(())
+    }
+
+    fn sign(&self, parts: &[&[u8]]) -> Result<Vec<u8>> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+            .map_err(|e| anyhow!("invalid webhook secret: {}", e))?;
+        for part in parts {
+            mac.update(part);
+        }
+
+        Ok(mac.finalize().into_bytes().to_vec())
+    }
+
+    fn check_signatures(&self, signatures: &[&str], expected: &[u8]) -> Result<()> {
+        let matches = signatures.iter().any(|s| match hex::decode(s.trim()) {
+            Ok(s) => constant_time_eq(&s, expected),
+            Err(_) => false,
+        });
+        if !matches {
+            bail!("signature does not match the body");
+        }
+
+        Ok(())
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Result<&'a str> {
+    headers
+        .get(name)
+        .ok_or_else(|| anyhow!("missing `{}` header", name))?
+        .to_str()
+        .map_err(|e| anyhow!("invalid `{}` header: {}", name, e))
+}
+"#;
+
+/// Generate the webhook verification module, shared by the clients of providers
+/// that sign their webhooks.
+pub fn generate_webhook_verify(crate_name: &str, proper_name: &str) -> String {
+    let scheme = match proper_name {
+        "Stripe" => "Scheme::Stripe",
+        "Slack" => "Scheme::Slack",
+        _ => r#"Scheme::SharedSecret("Authorization")"#,
+    };
+
+    TEMPLATE
+        .replace("{crate_name}", &crate_name.replace('-', "_"))
+        .replace("{proper_name}", proper_name)
+        .replace("{scheme}", scheme)
+}
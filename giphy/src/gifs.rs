@@ -25,12 +25,11 @@ impl Gifs {
     * * `ids: &str` -- Filters results by specified GIF IDs, separated by commas.
     */
     pub async fn get(&self, ids: &str) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gifs"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -49,15 +48,14 @@ impl Gifs {
     * * `rating: &str` -- The unique bit.ly URL for this GIF.
     */
     pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/random?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gifs/random"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -86,24 +84,23 @@ impl Gifs {
         rating: &str,
         lang: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang.is_empty() {
-            query_args.push(("lang".to_string(), lang.to_string()));
+            query_args.push(("lang", lang.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/search?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gifs/search"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -121,12 +118,12 @@ impl Gifs {
     * * `s: &str` -- The unique bit.ly URL for this GIF.
     */
     pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !s.is_empty() {
-            query_args.push(("s".to_string(), s.to_string()));
+            query_args.push(("s", s.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/translate?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/gifs/translate"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -151,18 +148,17 @@ impl Gifs {
         offset: i64,
         rating: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/trending?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gifs/trending"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -180,10 +176,7 @@ impl Gifs {
     * * `gif_id: i64` -- Filters results by specified GIF ID.
     */
     pub async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::RandomGifResponse> {
-        let url = format!(
-            "/gifs/{}",
-            crate::progenitor_support::encode_path(&gif_id.to_string()),
-        );
+        let url = format!("/gifs/{}", crate::progenitor_support::encode_path(&gif_id),);
 
         self.client.get(&url, None).await
     }
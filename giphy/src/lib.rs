@@ -65,10 +65,30 @@
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod batch;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+pub mod cache;
+pub mod client_config;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fixtures;
+pub mod from_env;
 pub mod gifs;
+mod json_stream;
+pub mod metrics;
+/// One page of the results of a list endpoint.
+pub mod page;
+pub mod redact;
+pub mod request_options;
+pub mod retry;
+mod runtime;
+pub mod single_flight;
 pub mod stickers;
 #[cfg(test)]
 mod tests;
+pub mod trace;
+pub mod transport;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
@@ -78,6 +98,8 @@ use anyhow::{anyhow, Error, Result};
 pub const DEFAULT_HOST: &str = "https://api.giphy.com/v1";
 
 mod progenitor_support {
+    use std::fmt::{self, Write};
+
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
     const PATH_SET: &AsciiSet = &CONTROLS
@@ -91,9 +113,80 @@ mod progenitor_support {
         .add(b'{')
         .add(b'}');
 
+    /// A path component, percent-encoded as it is formatted.
+    struct EncodePath<'a, T: ?Sized>(&'a T);
+
+    impl<T: fmt::Display + ?Sized> fmt::Display for EncodePath<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            struct Encoder<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+            impl Write for Encoder<'_, '_> {
+                fn write_str(&mut self, s: &str) -> fmt::Result {
+                    write!(self.0, "{}", utf8_percent_encode(s, PATH_SET))
+                }
+            }
+
+            write!(Encoder(f), "{}", self.0)
+        }
+    }
+
+    /// Percent-encode a path component, a `&str` or any value that implements
+    /// `Display`, without copying it into a `String` first.
+    #[allow(dead_code)]
+    pub(crate) fn encode_path<T: fmt::Display + ?Sized>(pc: &T) -> impl fmt::Display + '_ {
+        EncodePath(pc)
+    }
+
+    /// Write a path and its percent-encoded query parameters into a single `String`.
+    #[allow(dead_code)]
+    pub(crate) fn build_url(path: fmt::Arguments<'_>, query_args: &[(&str, String)]) -> String {
+        let mut url = String::new();
+        url.write_fmt(path).unwrap();
+        push_query(
+            &mut url,
+            query_args
+                .iter()
+                .map(|(name, value)| (*name, value.as_str())),
+        );
+        url
+    }
+
+    /// Add percent-encoded query parameters to a URL that may already have a query
+    /// string.
     #[allow(dead_code)]
-    pub(crate) fn encode_path(pc: &str) -> String {
-        utf8_percent_encode(pc, PATH_SET).to_string()
+    pub(crate) fn append_query(url: &str, query_args: &[(&str, &str)]) -> String {
+        let mut url = url.to_string();
+        push_query(&mut url, query_args.iter().copied());
+        url
+    }
+
+    fn push_query<'a, I>(url: &mut String, query_args: I)
+    where
+        I: Iterator<Item = (&'a str, &'a str)>,
+    {
+        for (name, value) in query_args {
+            if !url.contains('?') {
+                url.push('?');
+            } else if !url.ends_with('?') && !url.ends_with('&') {
+                url.push('&');
+            }
+            push_form_encoded(url, name);
+            url.push('=');
+            push_form_encoded(url, value);
+        }
+    }
+
+    /// Encode as `application/x-www-form-urlencoded`, like `serde_urlencoded` does.
+    fn push_form_encoded(url: &mut String, s: &str) {
+        for b in s.bytes() {
+            match b {
+                b'*' | b'-' | b'.' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' => {
+                    url.push(b as char)
+                }
+                b' ' => url.push('+'),
+                _ => write!(url, "%{:02X}", b).unwrap(),
+            }
+        }
     }
 }
 
@@ -106,6 +199,7 @@ pub struct Client {
     token: String,
 
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
 }
 
 impl Client {
@@ -117,17 +211,16 @@ impl Client {
         T: ToString,
     {
         let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
         match client {
             Ok(c) => {
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware)
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-                        retry_policy,
-                    ))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
 
                 Client {
@@ -135,6 +228,7 @@ impl Client {
                     token: token.to_string(),
 
                     client,
+                    request_options: Default::default(),
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -151,6 +245,46 @@ impl Client {
         c
     }
 
+    /// Override the HTTP client used to make requests. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics. The default client traces and
+    /// retries requests, add the same middleware to keep that behavior.
+    pub fn with_http_client(&self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        let mut c = self.clone();
+        c.client = client;
+        c
+    }
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client set
+    /// with `with_http_client`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }
+
+    /// Send a request, applying the request options of the client.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        self.request_options.execute(&self.client, req).await
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -197,13 +331,14 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
         if let Some(body) = body {
-            log::debug!(
-                "body: {:?}",
-                String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap()
-            );
+            // The bodies of these requests are in memory, uploads stream theirs separately.
+            let bytes = body
+                .as_bytes()
+                .ok_or_else(|| anyhow!("the body of the request must be in memory"))?;
+            log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
             req = req.body(body);
         }
-        Ok(req.send().await?)
+        self.execute(req.build()?).await
     }
 
     async fn request<Out>(
@@ -300,6 +435,17 @@ impl Client {
     /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.post_multipart(uri, form).await
+    }
+
+    /// Send a `multipart/form-data` `POST` request, for example to upload a file
+    /// along with other fields. The content type, with the boundary of the parts,
+    /// is set from the form.
+    #[allow(dead_code)]
+    async fn post_multipart<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -314,15 +460,10 @@ impl Client {
 
         let mut req = instance.client.request(http::Method::POST, url);
 
-        // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
             reqwest::header::HeaderValue::from_static("application/json"),
         );
-        req = req.header(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
 
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
@@ -331,7 +472,7 @@ impl Client {
         log::debug!("form: {:?}", form);
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -402,7 +543,7 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -494,7 +635,7 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -559,6 +700,41 @@ impl Client {
         self.unfold(uri).await
     }
 
+    /// Stream the items of the JSON array in the response to a `GET`, parsing each of
+    /// them as soon as it downloaded rather than once the whole body is in memory. The
+    /// array is either the body itself, or the first field of the body holding an
+    /// array, like the `data` of a page of results.
+    pub async fn get_stream_items<T>(
+        &self,
+        uri: &str,
+    ) -> Result<impl futures::Stream<Item = Result<T>>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self
+            .streaming()
+            .request_raw(http::Method::GET, &(self.host.to_string() + uri), None)
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let response_body = response.bytes().await?;
+            let error = if response_body.is_empty() {
+                anyhow!("code: {}, empty response", status)
+            } else {
+                anyhow!(
+                    "code: {}, error: {:?}",
+                    status,
+                    String::from_utf8_lossy(&response_body),
+                )
+            };
+
+            return Err(error);
+        }
+
+        Ok(crate::json_stream::items(response))
+    }
+
     /// "unfold" paginated results of a vector of items
     #[allow(dead_code)]
     async fn unfold<D>(&self, uri: &str) -> Result<Vec<D>>
@@ -653,3 +829,33 @@ impl Client {
         stickers::Stickers::new(self.clone())
     }
 }
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("host", &self.host)
+            .field("token", &crate::redact::Secret(&self.token))
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}
+
+/// Create a client from the environment variables:
+///
+/// - `GIPHY_API_KEY`, the API key.
+/// - `GIPHY_HOST`, the host to make the requests to, when it is set.
+impl crate::from_env::FromEnv for Client {
+    fn env_vars() -> &'static [&'static str] {
+        &["GIPHY_API_KEY", "GIPHY_HOST"]
+    }
+
+    fn from_env() -> Result<Self> {
+        let mut client = Client::new(crate::from_env::required("GIPHY_API_KEY")?);
+
+        let host = crate::from_env::optional("GIPHY_HOST");
+        if !host.is_empty() {
+            client = client.with_host(host);
+        }
+        Ok(client)
+    }
+}
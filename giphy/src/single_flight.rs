@@ -0,0 +1,157 @@
+//! Coalescing of identical `GET` requests in flight.
+//!
+//! When many tasks fetch the same resource at the same time, only the first one
+//! sends the request and the others wait for its response. Requests are identical
+//! when they have the same URL, credentials and `Accept` headers, the ones of the
+//! key of the response cache.
+//!
+//! ```no_run
+//! # async fn run(client: giphy_api::Client) -> anyhow::Result<()> {
+//! // The clones of the returned client coalesce their requests as well.
+//! let client = client.with_single_flight(true);
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, Result};
+use bytes::Bytes;
+use reqwest::{header::HeaderMap, Method, Request, Response, StatusCode};
+use tokio::sync::oneshot;
+
+/// The requests in flight, by URL, credentials and `Accept` headers.
+///
+/// Clones share the same requests in flight.
+#[derive(Debug, Clone, Default)]
+pub struct SingleFlight {
+    in_flight: Arc<Mutex<HashMap<FlightKey, Vec<Waiter>>>>,
+}
+
+type FlightKey = (String, u64);
+
+/// A task waiting for the response of a request in flight. Errors are shared as
+/// their message, since they can not be cloned.
+type Waiter = oneshot::Sender<Result<SharedResponse, String>>;
+
+#[derive(Debug, Clone)]
+struct SharedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+/// Removes a request from the ones in flight, even when the task sending it is
+/// dropped before it completes.
+struct Flight<'a> {
+    group: &'a SingleFlight,
+    key: FlightKey,
+    done: bool,
+}
+
+impl Flight<'_> {
+    fn finish(&mut self) -> Vec<Waiter> {
+        self.done = true;
+        self.group
+            .in_flight
+            .lock()
+            .unwrap()
+            .remove(&self.key)
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for Flight<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.finish();
+        }
+    }
+}
+
+impl SingleFlight {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Send a request with the given client, or wait for the response of an
+    /// identical request in flight.
+    pub(crate) async fn execute(
+        &self,
+        client: &reqwest_middleware::ClientWithMiddleware,
+        req: Request,
+    ) -> Result<Response> {
+        if req.method() != Method::GET {
+            return Ok(client.execute(req).await?);
+        }
+
+        let key = (req.url().to_string(), crate::cache::variant(req.headers()));
+        let waiting = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            match in_flight.get_mut(&key) {
+                Some(waiters) => {
+                    let (tx, rx) = oneshot::channel();
+                    waiters.push(tx);
+                    Some(rx)
+                }
+                None => {
+                    in_flight.insert(key.clone(), Vec::new());
+                    None
+                }
+            }
+        };
+
+        if let Some(rx) = waiting {
+            return match rx.await {
+                Ok(Ok(resp)) => crate::cache::to_response(resp.status, &resp.headers, resp.body),
+                Ok(Err(e)) => Err(anyhow!(e)),
+                // The task sending the request was dropped, send it again.
+                Err(_) => Ok(client.execute(req).await?),
+            };
+        }
+
+        let mut flight = Flight {
+            group: self,
+            key,
+            done: false,
+        };
+        let result = match client.execute(req).await {
+            Ok(resp) => read(resp).await,
+            Err(e) => Err(e.into()),
+        };
+        for waiter in flight.finish() {
+            let shared = match &result {
+                Ok(resp) => Ok(resp.clone()),
+                Err(e) => Err(format!("{:#}", e)),
+            };
+            let _ = waiter.send(shared);
+        }
+
+        let resp = result?;
+        crate::cache::to_response(resp.status, &resp.headers, resp.body)
+    }
+}
+
+async fn read(resp: Response) -> Result<SharedResponse> {
+    Ok(SharedResponse {
+        status: resp.status(),
+        headers: resp.headers().clone(),
+        body: resp.bytes().await?,
+    })
+}
+
+impl crate::Client {
+    /// Coalesce the identical `GET` requests that the returned client, and its
+    /// clones, have in flight at the same time into a single request.
+    pub fn with_single_flight(&self, enabled: bool) -> Self {
+        let mut c = self.clone();
+        c.request_options.single_flight = if enabled {
+            Some(SingleFlight::new())
+        } else {
+            None
+        };
+        c
+    }
+}
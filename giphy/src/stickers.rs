@@ -26,15 +26,15 @@ impl Stickers {
     * * `rating: &str` -- The unique bit.ly URL for this GIF.
     */
     pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/random?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/stickers/random"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -63,24 +63,24 @@ impl Stickers {
         rating: &str,
         lang: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang.is_empty() {
-            query_args.push(("lang".to_string(), lang.to_string()));
+            query_args.push(("lang", lang.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/stickers/search"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -98,12 +98,12 @@ impl Stickers {
     * * `s: &str` -- The unique bit.ly URL for this GIF.
     */
     pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !s.is_empty() {
-            query_args.push(("s".to_string(), s.to_string()));
+            query_args.push(("s", s.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/translate?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/stickers/translate"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -128,18 +128,18 @@ impl Stickers {
         offset: i64,
         rating: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/trending?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/stickers/trending"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -130,15 +130,15 @@ pub struct Images {
 pub enum Type {
     #[serde(rename = "gif")]
     Gif,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &*self {
             Type::Gif => "gif",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26,15 +26,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let jwt = JWTCredentials::new(app_id, key.data).unwrap();
 
     let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+    let retry_policy = octorust::retry::RetryPolicy::default();
     let client = reqwest_middleware::ClientBuilder::new(http)
         // Trace HTTP requests. See the tracing crate to make use of these traces.
         .with(reqwest_tracing::TracingMiddleware)
+        // Record a span for every request when the `tracing` feature is enabled.
+        .with(octorust::trace::TraceMiddleware)
         // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
+        .with(octorust::retry::RetryMiddleware::new(retry_policy))
         .build();
 
     // Create the HTTP cache.
@@ -29,8 +29,9 @@ impl Actions {
     */
     pub async fn get_github_actions_permissions_organization(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::ActionsOrganizationPermissions> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/permissions",
             crate::progenitor_support::encode_path(org),
@@ -58,9 +59,10 @@ impl Actions {
     */
     pub async fn set_github_actions_permissions_organization(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::ActionsSetGithubPermissionsOrganizationRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/permissions",
             crate::progenitor_support::encode_path(org),
@@ -90,23 +92,25 @@ impl Actions {
     */
     pub async fn list_selected_repositories_enabled_github_actions_organization(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>
     {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/permissions/repositories?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/actions/permissions/repositories",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -129,9 +133,10 @@ impl Actions {
     */
     pub async fn set_selected_repositories_enabled_github_actions_organization(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/permissions/repositories",
             crate::progenitor_support::encode_path(org),
@@ -160,13 +165,15 @@ impl Actions {
     */
     pub async fn enable_selected_repository_github_actions_organization(
         &self,
-        org: &str,
-        repository_id: i64,
+        org: impl AsRef<str>,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/orgs/{}/actions/permissions/repositories/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.put(&url, None).await
@@ -190,13 +197,15 @@ impl Actions {
     */
     pub async fn disable_selected_repository_github_actions_organization(
         &self,
-        org: &str,
-        repository_id: i64,
+        org: impl AsRef<str>,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/orgs/{}/actions/permissions/repositories/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.delete(&url, None).await
@@ -219,8 +228,9 @@ impl Actions {
     */
     pub async fn get_allowed_actions_organization(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::SelectedActions> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/permissions/selected-actions",
             crate::progenitor_support::encode_path(org),
@@ -250,9 +260,10 @@ impl Actions {
     */
     pub async fn set_allowed_actions_organization(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::SelectedActions,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/permissions/selected-actions",
             crate::progenitor_support::encode_path(org),
@@ -284,22 +295,24 @@ impl Actions {
     */
     pub async fn list_self_hosted_runner_groups_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runner-groups?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -324,9 +337,10 @@ impl Actions {
     */
     pub async fn create_self_hosted_runner_group_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::ActionsCreateSelfHostedRunnerGroupOrgRequest,
     ) -> Result<crate::types::RunnerGroupsOrg> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups",
             crate::progenitor_support::encode_path(org),
@@ -357,13 +371,14 @@ impl Actions {
     */
     pub async fn get_self_hosted_runner_group_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
     ) -> Result<crate::types::RunnerGroupsOrg> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client.get(&url, None).await
@@ -389,13 +404,14 @@ impl Actions {
     */
     pub async fn delete_self_hosted_runner_group_from_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client.delete(&url, None).await
@@ -421,14 +437,15 @@ impl Actions {
     */
     pub async fn update_self_hosted_runner_group_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
         body: &crate::types::ActionsUpdateSelfHostedRunnerGroupOrgRequest,
     ) -> Result<crate::types::RunnerGroupsOrg> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client
@@ -458,24 +475,26 @@ impl Actions {
     */
     pub async fn list_repo_access_to_self_hosted_runner_group_in_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
         page: i64,
         per_page: i64,
     ) -> Result<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runner-groups/{}/repositories?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/actions/runner-groups/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -501,14 +520,15 @@ impl Actions {
     */
     pub async fn set_repo_access_to_self_hosted_runner_group_in_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
         body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}/repositories",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client
@@ -539,15 +559,17 @@ impl Actions {
     */
     pub async fn add_repo_access_to_self_hosted_runner_group_in_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
-        repository_id: i64,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}/repositories/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.put(&url, None).await
@@ -575,15 +597,17 @@ impl Actions {
     */
     pub async fn remove_repo_access_to_self_hosted_runner_group_in_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
-        repository_id: i64,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}/repositories/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.delete(&url, None).await
@@ -611,24 +635,26 @@ impl Actions {
     */
     pub async fn list_self_hosted_runners_in_group_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runner-groups/{}/runners?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -654,14 +680,15 @@ impl Actions {
     */
     pub async fn set_self_hosted_runners_in_group_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
         body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}/runners",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client
@@ -692,15 +719,16 @@ impl Actions {
     */
     pub async fn add_self_hosted_runner_to_group_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
         runner_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}/runners/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.put(&url, None).await
@@ -728,15 +756,16 @@ impl Actions {
     */
     pub async fn remove_self_hosted_runner_from_group_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_group_id: i64,
         runner_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runner-groups/{}/runners/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.delete(&url, None).await
@@ -761,22 +790,24 @@ impl Actions {
     */
     pub async fn list_self_hosted_runners_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runners?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/actions/runners",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -799,8 +830,9 @@ impl Actions {
     */
     pub async fn list_runner_applications_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::RunnerApplication>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runners/downloads",
             crate::progenitor_support::encode_path(org),
@@ -824,8 +856,9 @@ impl Actions {
     */
     pub async fn list_all_runner_applications_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::RunnerApplication>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runners/downloads",
             crate::progenitor_support::encode_path(org),
@@ -859,8 +892,9 @@ impl Actions {
     */
     pub async fn create_registration_token_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::AuthenticationToken> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runners/registration-token",
             crate::progenitor_support::encode_path(org),
@@ -895,8 +929,9 @@ impl Actions {
     */
     pub async fn create_remove_token_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::AuthenticationToken> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runners/remove-token",
             crate::progenitor_support::encode_path(org),
@@ -923,13 +958,14 @@ impl Actions {
     */
     pub async fn get_self_hosted_runner_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_id: i64,
     ) -> Result<crate::types::Runner> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runners/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.get(&url, None).await
@@ -953,13 +989,14 @@ impl Actions {
     */
     pub async fn delete_self_hosted_runner_from_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         runner_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/runners/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.delete(&url, None).await
@@ -982,22 +1019,24 @@ impl Actions {
     */
     pub async fn list_org_secrets(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListOrgSecretsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/secrets?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/actions/secrets",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1016,7 +1055,11 @@ impl Actions {
     *
     * * `org: &str`
     */
-    pub async fn get_org_public_key(&self, org: &str) -> Result<crate::types::ActionsPublicKey> {
+    pub async fn get_org_public_key(
+        &self,
+        org: impl AsRef<str>,
+    ) -> Result<crate::types::ActionsPublicKey> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/actions/secrets/public-key",
             crate::progenitor_support::encode_path(org),
@@ -1041,9 +1084,11 @@ impl Actions {
     */
     pub async fn get_org_secret(
         &self,
-        org: &str,
-        secret_name: &str,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
     ) -> Result<crate::types::OrganizationActionsSecret> {
+        let org = org.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/orgs/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(org),
@@ -1143,10 +1188,12 @@ impl Actions {
     */
     pub async fn create_or_update_org_secret(
         &self,
-        org: &str,
-        secret_name: &str,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
         body: &crate::types::ActionsCreateUpdateOrgSecretRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/orgs/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(org),
@@ -1172,7 +1219,13 @@ impl Actions {
     * * `org: &str`
     * * `secret_name: &str` -- secret_name parameter.
     */
-    pub async fn delete_org_secret(&self, org: &str, secret_name: &str) -> Result<()> {
+    pub async fn delete_org_secret(
+        &self,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+    ) -> Result<()> {
+        let org = org.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/orgs/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(org),
@@ -1200,24 +1253,27 @@ impl Actions {
     */
     pub async fn list_selected_repos_for_org_secret(
         &self,
-        org: &str,
-        secret_name: &str,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
         page: i64,
         per_page: i64,
     ) -> Result<crate::types::ActionsListSelectedReposOrgSecretResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let secret_name = secret_name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/secrets/{}/repositories?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(secret_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/actions/secrets/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1239,10 +1295,12 @@ impl Actions {
     */
     pub async fn set_selected_repos_for_org_secret(
         &self,
-        org: &str,
-        secret_name: &str,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
         body: &crate::types::ActionsSetSelectedReposOrgSecretRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/orgs/{}/actions/secrets/{}/repositories",
             crate::progenitor_support::encode_path(org),
@@ -1271,15 +1329,18 @@ impl Actions {
     */
     pub async fn add_selected_repo_to_org_secret(
         &self,
-        org: &str,
-        secret_name: &str,
-        repository_id: i64,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let secret_name = secret_name.as_ref();
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/orgs/{}/actions/secrets/{}/repositories/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(secret_name),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.put(&url, None).await
@@ -1302,15 +1363,18 @@ impl Actions {
     */
     pub async fn remove_selected_repo_from_org_secret(
         &self,
-        org: &str,
-        secret_name: &str,
-        repository_id: i64,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let secret_name = secret_name.as_ref();
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/orgs/{}/actions/secrets/{}/repositories/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(secret_name),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.delete(&url, None).await
@@ -1334,24 +1398,27 @@ impl Actions {
     */
     pub async fn list_artifacts_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/artifacts?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/artifacts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1374,15 +1441,17 @@ impl Actions {
     */
     pub async fn get_artifact(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         artifact_id: i64,
     ) -> Result<crate::types::Artifact> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/artifacts/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&artifact_id.to_string()),
+            crate::progenitor_support::encode_path(&artifact_id),
         );
 
         self.client.get(&url, None).await
@@ -1403,12 +1472,19 @@ impl Actions {
     * * `repo: &str`
     * * `artifact_id: i64` -- artifact_id parameter.
     */
-    pub async fn delete_artifact(&self, owner: &str, repo: &str, artifact_id: i64) -> Result<()> {
+    pub async fn delete_artifact(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        artifact_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/artifacts/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&artifact_id.to_string()),
+            crate::progenitor_support::encode_path(&artifact_id),
         );
 
         self.client.delete(&url, None).await
@@ -1435,16 +1511,19 @@ impl Actions {
     */
     pub async fn download_artifact(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         artifact_id: i64,
-        archive_format: &str,
+        archive_format: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let archive_format = archive_format.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/artifacts/{}/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&artifact_id.to_string()),
+            crate::progenitor_support::encode_path(&artifact_id),
             crate::progenitor_support::encode_path(archive_format),
         );
 
@@ -1468,15 +1547,17 @@ impl Actions {
     */
     pub async fn get_job_for_workflow_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         job_id: i64,
     ) -> Result<crate::types::Job> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/jobs/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&job_id.to_string()),
+            crate::progenitor_support::encode_path(&job_id),
         );
 
         self.client.get(&url, None).await
@@ -1502,15 +1583,17 @@ impl Actions {
     */
     pub async fn download_job_logs_for_workflow_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         job_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/jobs/{}/logs",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&job_id.to_string()),
+            crate::progenitor_support::encode_path(&job_id),
         );
 
         self.client.get(&url, None).await
@@ -1535,9 +1618,11 @@ impl Actions {
     */
     pub async fn get_github_actions_permissions_repository(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::ActionsRepositoryPermissions> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/permissions",
             crate::progenitor_support::encode_path(owner),
@@ -1567,10 +1652,12 @@ impl Actions {
     */
     pub async fn set_github_actions_permissions_repository(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ActionsSetGithubPermissionsRepositoryRequest,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/permissions",
             crate::progenitor_support::encode_path(owner),
@@ -1600,9 +1687,11 @@ impl Actions {
     */
     pub async fn get_allowed_actions_repository(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::SelectedActions> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/permissions/selected-actions",
             crate::progenitor_support::encode_path(owner),
@@ -1634,10 +1723,12 @@ impl Actions {
     */
     pub async fn set_allowed_actions_repository(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::SelectedActions,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/permissions/selected-actions",
             crate::progenitor_support::encode_path(owner),
@@ -1667,24 +1758,27 @@ impl Actions {
     */
     pub async fn list_self_hosted_runners_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runners?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/runners",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1708,9 +1802,11 @@ impl Actions {
     */
     pub async fn list_runner_applications_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::RunnerApplication>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runners/downloads",
             crate::progenitor_support::encode_path(owner),
@@ -1735,9 +1831,11 @@ impl Actions {
     */
     pub async fn list_all_runner_applications_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::RunnerApplication>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runners/downloads",
             crate::progenitor_support::encode_path(owner),
@@ -1772,9 +1870,11 @@ impl Actions {
     */
     pub async fn create_registration_token_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::AuthenticationToken> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runners/registration-token",
             crate::progenitor_support::encode_path(owner),
@@ -1809,9 +1909,11 @@ impl Actions {
     */
     pub async fn create_remove_token_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::AuthenticationToken> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runners/remove-token",
             crate::progenitor_support::encode_path(owner),
@@ -1841,15 +1943,17 @@ impl Actions {
     */
     pub async fn get_self_hosted_runner_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         runner_id: i64,
     ) -> Result<crate::types::Runner> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runners/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.get(&url, None).await
@@ -1875,15 +1979,17 @@ impl Actions {
     */
     pub async fn delete_self_hosted_runner_from_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         runner_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runners/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.delete(&url, None).await
@@ -1914,8 +2020,8 @@ impl Actions {
     */
     pub async fn list_workflow_runs_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         actor: &str,
         branch: &str,
         event: &str,
@@ -1924,34 +2030,37 @@ impl Actions {
         page: i64,
         created: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !actor.is_empty() {
-            query_args.push(("actor".to_string(), actor.to_string()));
+            query_args.push(("actor", actor.to_string()));
         }
         if !branch.is_empty() {
-            query_args.push(("branch".to_string(), branch.to_string()));
+            query_args.push(("branch", branch.to_string()));
         }
         if let Some(date) = created {
-            query_args.push(("created".to_string(), date.to_rfc3339()));
+            query_args.push(("created", date.to_rfc3339()));
         }
         if !event.is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runs?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1974,15 +2083,17 @@ impl Actions {
     */
     pub async fn get_workflow_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<crate::types::WorkflowRun> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.get(&url, None).await
@@ -2005,12 +2116,19 @@ impl Actions {
     * * `repo: &str`
     * * `run_id: i64` -- The id of the workflow run.
     */
-    pub async fn delete_workflow_run(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+    pub async fn delete_workflow_run(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        run_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.delete(&url, None).await
@@ -2033,15 +2151,17 @@ impl Actions {
     */
     pub async fn get_reviews_for_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<Vec<crate::types::EnvironmentApproval>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/approvals",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.get(&url, None).await
@@ -2060,15 +2180,17 @@ impl Actions {
     */
     pub async fn get_all_reviews_for_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<Vec<crate::types::EnvironmentApproval>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/approvals",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2091,12 +2213,19 @@ impl Actions {
     * * `repo: &str`
     * * `run_id: i64` -- The id of the workflow run.
     */
-    pub async fn approve_workflow_run(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+    pub async fn approve_workflow_run(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        run_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/approve",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.post(&url, None).await
@@ -2121,26 +2250,29 @@ impl Actions {
     */
     pub async fn list_workflow_run_artifacts(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runs/{}/artifacts?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/runs/{}/artifacts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2161,12 +2293,19 @@ impl Actions {
     * * `repo: &str`
     * * `run_id: i64` -- The id of the workflow run.
     */
-    pub async fn cancel_workflow_run(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+    pub async fn cancel_workflow_run(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        run_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/cancel",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.post(&url, None).await
@@ -2194,30 +2333,33 @@ impl Actions {
     */
     pub async fn list_jobs_for_workflow_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
         filter: crate::types::ActionsListJobsWorkflowRunFilter,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListJobsWorkflowRunResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runs/{}/jobs?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/runs/{}/jobs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2243,15 +2385,17 @@ impl Actions {
     */
     pub async fn download_workflow_run_logs(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/logs",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.get(&url, None).await
@@ -2274,15 +2418,17 @@ impl Actions {
     */
     pub async fn delete_workflow_run_logs(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/logs",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.delete(&url, None).await
@@ -2307,15 +2453,17 @@ impl Actions {
     */
     pub async fn get_pending_deployments_for_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<Vec<crate::types::PendingDeployment>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/pending_deployments",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.get(&url, None).await
@@ -2336,15 +2484,17 @@ impl Actions {
     */
     pub async fn get_all_pending_deployments_for_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<Vec<crate::types::PendingDeployment>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/pending_deployments",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2369,16 +2519,18 @@ impl Actions {
     */
     pub async fn review_pending_deployments_for_run(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
         body: &crate::types::ActionsReviewPendingDeploymentsRunRequest,
     ) -> Result<Vec<crate::types::Deployment>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/pending_deployments",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client
@@ -2401,12 +2553,19 @@ impl Actions {
     * * `repo: &str`
     * * `run_id: i64` -- The id of the workflow run.
     */
-    pub async fn re_run_workflow(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+    pub async fn re_run_workflow(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        run_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/rerun",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.post(&url, None).await
@@ -2431,15 +2590,17 @@ impl Actions {
     */
     pub async fn get_workflow_run_usage(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         run_id: i64,
     ) -> Result<crate::types::WorkflowRunUsage> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/timing",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
+            crate::progenitor_support::encode_path(&run_id),
         );
 
         self.client.get(&url, None).await
@@ -2463,24 +2624,27 @@ impl Actions {
     */
     pub async fn list_repo_secrets(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/secrets?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/secrets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2502,9 +2666,11 @@ impl Actions {
     */
     pub async fn get_repo_public_key(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::ActionsPublicKey> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/secrets/public-key",
             crate::progenitor_support::encode_path(owner),
@@ -2531,10 +2697,13 @@ impl Actions {
     */
     pub async fn get_repo_secret(
         &self,
-        owner: &str,
-        repo: &str,
-        secret_name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
     ) -> Result<crate::types::ActionsSecret> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(owner),
@@ -2636,11 +2805,14 @@ impl Actions {
     */
     pub async fn create_or_update_repo_secret(
         &self,
-        owner: &str,
-        repo: &str,
-        secret_name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
         body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(owner),
@@ -2670,10 +2842,13 @@ impl Actions {
     */
     pub async fn delete_repo_secret(
         &self,
-        owner: &str,
-        repo: &str,
-        secret_name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(owner),
@@ -2702,24 +2877,27 @@ impl Actions {
     */
     pub async fn list_repo_workflows(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListRepoWorkflowsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/workflows?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/workflows",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2742,10 +2920,13 @@ impl Actions {
     */
     pub async fn get_workflow(
         &self,
-        owner: &str,
-        repo: &str,
-        workflow_id: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        workflow_id: impl AsRef<str>,
     ) -> Result<crate::types::Workflow> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/workflows/{}",
             crate::progenitor_support::encode_path(owner),
@@ -2773,7 +2954,15 @@ impl Actions {
     * * `repo: &str`
     * * `workflow_id: &str` -- The ID of the workflow. You can also pass the workflow file name as a string.
     */
-    pub async fn disable_workflow(&self, owner: &str, repo: &str, workflow_id: &str) -> Result<()> {
+    pub async fn disable_workflow(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        workflow_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/workflows/{}/disable",
             crate::progenitor_support::encode_path(owner),
@@ -2805,11 +2994,14 @@ impl Actions {
     */
     pub async fn create_workflow_dispatch(
         &self,
-        owner: &str,
-        repo: &str,
-        workflow_id: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        workflow_id: impl AsRef<str>,
         body: &crate::types::ActionsCreateWorkflowDispatchRequest,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/workflows/{}/dispatches",
             crate::progenitor_support::encode_path(owner),
@@ -2839,7 +3031,15 @@ impl Actions {
     * * `repo: &str`
     * * `workflow_id: &str` -- The ID of the workflow. You can also pass the workflow file name as a string.
     */
-    pub async fn enable_workflow(&self, owner: &str, repo: &str, workflow_id: &str) -> Result<()> {
+    pub async fn enable_workflow(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        workflow_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/workflows/{}/enable",
             crate::progenitor_support::encode_path(owner),
@@ -2876,9 +3076,9 @@ impl Actions {
     */
     pub async fn list_workflow_runs(
         &self,
-        owner: &str,
-        repo: &str,
-        workflow_id: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        workflow_id: impl AsRef<str>,
         actor: &str,
         branch: &str,
         event: &str,
@@ -2887,35 +3087,39 @@ impl Actions {
         page: i64,
         created: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let workflow_id = workflow_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !actor.is_empty() {
-            query_args.push(("actor".to_string(), actor.to_string()));
+            query_args.push(("actor", actor.to_string()));
         }
         if !branch.is_empty() {
-            query_args.push(("branch".to_string(), branch.to_string()));
+            query_args.push(("branch", branch.to_string()));
         }
         if let Some(date) = created {
-            query_args.push(("created".to_string(), date.to_rfc3339()));
+            query_args.push(("created", date.to_rfc3339()));
         }
         if !event.is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/workflows/{}/runs?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(workflow_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/actions/workflows/{}/runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2940,10 +3144,13 @@ impl Actions {
     */
     pub async fn get_workflow_usage(
         &self,
-        owner: &str,
-        repo: &str,
-        workflow_id: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        workflow_id: impl AsRef<str>,
     ) -> Result<crate::types::WorkflowUsage> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/repos/{}/{}/actions/workflows/{}/timing",
             crate::progenitor_support::encode_path(owner),
@@ -2972,24 +3179,27 @@ impl Actions {
     */
     pub async fn list_environment_secrets(
         &self,
-        repository_id: i64,
-        environment_name: &str,
+        repository_id: impl Into<crate::types::RepoId>,
+        environment_name: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let repository_id: crate::types::RepoId = repository_id.into();
+        let environment_name = environment_name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repositories/{}/environments/{}/secrets?{}",
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
-            crate::progenitor_support::encode_path(environment_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repositories/{}/environments/{}/secrets",
+                crate::progenitor_support::encode_path(&repository_id),
+                crate::progenitor_support::encode_path(environment_name),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3011,12 +3221,14 @@ impl Actions {
     */
     pub async fn get_environment_public_key(
         &self,
-        repository_id: i64,
-        environment_name: &str,
+        repository_id: impl Into<crate::types::RepoId>,
+        environment_name: impl AsRef<str>,
     ) -> Result<crate::types::ActionsPublicKey> {
+        let repository_id: crate::types::RepoId = repository_id.into();
+        let environment_name = environment_name.as_ref();
         let url = format!(
             "/repositories/{}/environments/{}/secrets/public-key",
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
             crate::progenitor_support::encode_path(environment_name),
         );
 
@@ -3040,13 +3252,16 @@ impl Actions {
     */
     pub async fn get_environment_secret(
         &self,
-        repository_id: i64,
-        environment_name: &str,
-        secret_name: &str,
+        repository_id: impl Into<crate::types::RepoId>,
+        environment_name: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
     ) -> Result<crate::types::ActionsSecret> {
+        let repository_id: crate::types::RepoId = repository_id.into();
+        let environment_name = environment_name.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/repositories/{}/environments/{}/secrets/{}",
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
             crate::progenitor_support::encode_path(environment_name),
             crate::progenitor_support::encode_path(secret_name),
         );
@@ -3145,14 +3360,17 @@ impl Actions {
     */
     pub async fn create_or_update_environment_secret(
         &self,
-        repository_id: i64,
-        environment_name: &str,
-        secret_name: &str,
+        repository_id: impl Into<crate::types::RepoId>,
+        environment_name: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
         body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
     ) -> Result<()> {
+        let repository_id: crate::types::RepoId = repository_id.into();
+        let environment_name = environment_name.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/repositories/{}/environments/{}/secrets/{}",
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
             crate::progenitor_support::encode_path(environment_name),
             crate::progenitor_support::encode_path(secret_name),
         );
@@ -3179,13 +3397,16 @@ impl Actions {
     */
     pub async fn delete_environment_secret(
         &self,
-        repository_id: i64,
-        environment_name: &str,
-        secret_name: &str,
+        repository_id: impl Into<crate::types::RepoId>,
+        environment_name: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
     ) -> Result<()> {
+        let repository_id: crate::types::RepoId = repository_id.into();
+        let environment_name = environment_name.as_ref();
+        let secret_name = secret_name.as_ref();
         let url = format!(
             "/repositories/{}/environments/{}/secrets/{}",
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&repository_id),
             crate::progenitor_support::encode_path(environment_name),
             crate::progenitor_support::encode_path(secret_name),
         );
@@ -0,0 +1,105 @@
+//! Streaming downloads of artifacts and logs of GitHub Actions.
+//!
+//! The download endpoints redirect to a short lived URL of the archive or log file,
+//! which is followed and returned as a stream of chunks.
+use anyhow::Result;
+
+use crate::actions::Actions;
+
+impl Actions {
+    async fn download_stream(
+        &self,
+        url: &str,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let resp = self
+            .client
+            .streaming()
+            .request_raw(
+                http::Method::GET,
+                &(self.client.host.clone() + url),
+                None,
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+                Default::default(),
+            )
+            .await?;
+
+        Ok(crate::utils::response_stream(resp))
+    }
+
+    /**
+     * Download an artifact as a stream of chunks of its zip archive.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `artifact_id: i64` -- artifact_id parameter.
+     */
+    pub async fn download_artifact_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let url = format!(
+            "/repos/{}/{}/actions/artifacts/{}/zip",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&artifact_id),
+        );
+
+        self.download_stream(&url).await
+    }
+
+    /**
+     * Download the plain text log of a job as a stream of chunks.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `job_id: i64` -- job_id parameter.
+     */
+    pub async fn download_job_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let url = format!(
+            "/repos/{}/{}/actions/jobs/{}/logs",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&job_id),
+        );
+
+        self.download_stream(&url).await
+    }
+
+    /**
+     * Download the logs of all the jobs of a workflow run as a stream of chunks of
+     * a zip archive.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `run_id: i64`
+     */
+    pub async fn download_workflow_run_logs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let url = format!(
+            "/repos/{}/{}/actions/runs/{}/logs",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&run_id),
+        );
+
+        self.download_stream(&url).await
+    }
+}
@@ -0,0 +1,79 @@
+//! Encrypt and store secrets for GitHub Actions.
+//!
+//! Secrets must be encrypted with the public key of the repository or organization
+//! as a libsodium sealed box before they are sent to GitHub, see `sealed_box`.
+//!
+//! https://docs.github.com/en/rest/reference/actions#create-or-update-a-repository-secret
+use anyhow::Result;
+
+use crate::{actions::Actions, sealed_box};
+
+impl Actions {
+    /**
+     * Create or update a repository secret from its plain text value.
+     *
+     * Fetches the public key of the repository, encrypts the value with it and
+     * stores the secret with `create_or_update_repo_secret`.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `secret_name: &str` -- secret_name parameter.
+     * * `value: &str` -- The plain text value of the secret.
+     */
+    pub async fn create_or_update_secret_plaintext(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+        value: &str,
+    ) -> Result<()> {
+        let public_key = self.get_repo_public_key(owner, repo).await?;
+
+        self.create_or_update_repo_secret(
+            owner,
+            repo,
+            secret_name,
+            &sealed_box::secret_request(public_key, value)?,
+        )
+        .await
+    }
+
+    /**
+     * Create or update an organization secret from its plain text value.
+     *
+     * Fetches the public key of the organization, encrypts the value with it and
+     * stores the secret with `create_or_update_org_secret`.
+     *
+     * **Parameters:**
+     *
+     * * `org: &str`
+     * * `secret_name: &str` -- secret_name parameter.
+     * * `value: &str` -- The plain text value of the secret.
+     * * `visibility: crate::types::Visibility` -- Which repositories can access the secret.
+     * * `selected_repository_ids: &[String]` -- The repositories that can access the secret, when `visibility` is `selected`.
+     */
+    pub async fn create_or_update_org_secret_plaintext(
+        &self,
+        org: &str,
+        secret_name: &str,
+        value: &str,
+        visibility: crate::types::Visibility,
+        selected_repository_ids: &[String],
+    ) -> Result<()> {
+        let public_key = self.get_org_public_key(org).await?;
+
+        self.create_or_update_org_secret(
+            org,
+            secret_name,
+            &crate::types::ActionsCreateUpdateOrgSecretRequest {
+                encrypted_value: sealed_box::seal(&public_key.key, value.as_bytes())?,
+                key_id: public_key.key_id,
+                selected_repository_ids: selected_repository_ids.to_vec(),
+                visibility,
+            },
+        )
+        .await
+    }
+}
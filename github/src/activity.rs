@@ -31,15 +31,14 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/events?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/events"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -102,24 +101,27 @@ impl Activity {
     */
     pub async fn list_public_events_for_repo_network(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/networks/{}/{}/events?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/networks/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -138,9 +140,11 @@ impl Activity {
     */
     pub async fn list_all_public_events_for_repo_network(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Event>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/networks/{}/{}/events",
             crate::progenitor_support::encode_path(owner),
@@ -177,27 +181,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/notifications?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/notifications"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -220,21 +223,20 @@ impl Activity {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/notifications?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/notifications"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -274,7 +276,7 @@ impl Activity {
     pub async fn get_thread(&self, thread_id: i64) -> Result<crate::types::Thread> {
         let url = format!(
             "/notifications/threads/{}",
-            crate::progenitor_support::encode_path(&thread_id.to_string()),
+            crate::progenitor_support::encode_path(&thread_id),
         );
 
         self.client.get(&url, None).await
@@ -296,7 +298,7 @@ impl Activity {
     pub async fn mark_thread_as_read(&self, thread_id: i64) -> Result<()> {
         let url = format!(
             "/notifications/threads/{}",
-            crate::progenitor_support::encode_path(&thread_id.to_string()),
+            crate::progenitor_support::encode_path(&thread_id),
         );
 
         self.client.patch(&url, None).await
@@ -323,7 +325,7 @@ impl Activity {
     ) -> Result<crate::types::ThreadSubscription> {
         let url = format!(
             "/notifications/threads/{}/subscription",
-            crate::progenitor_support::encode_path(&thread_id.to_string()),
+            crate::progenitor_support::encode_path(&thread_id),
         );
 
         self.client.get(&url, None).await
@@ -353,7 +355,7 @@ impl Activity {
     ) -> Result<crate::types::ThreadSubscription> {
         let url = format!(
             "/notifications/threads/{}/subscription",
-            crate::progenitor_support::encode_path(&thread_id.to_string()),
+            crate::progenitor_support::encode_path(&thread_id),
         );
 
         self.client
@@ -377,7 +379,7 @@ impl Activity {
     pub async fn delete_thread_subscription(&self, thread_id: i64) -> Result<()> {
         let url = format!(
             "/notifications/threads/{}/subscription",
-            crate::progenitor_support::encode_path(&thread_id.to_string()),
+            crate::progenitor_support::encode_path(&thread_id),
         );
 
         self.client.delete(&url, None).await
@@ -400,22 +402,24 @@ impl Activity {
     */
     pub async fn list_public_org_events(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/events?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/events",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -432,7 +436,11 @@ impl Activity {
     *
     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
     */
-    pub async fn list_all_public_org_events(&self, org: &str) -> Result<Vec<crate::types::Event>> {
+    pub async fn list_all_public_org_events(
+        &self,
+        org: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Event>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/events",
             crate::progenitor_support::encode_path(org),
@@ -459,24 +467,27 @@ impl Activity {
     */
     pub async fn list_repo_events(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/events?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -495,9 +506,11 @@ impl Activity {
     */
     pub async fn list_all_repo_events(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Event>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/events",
             crate::progenitor_support::encode_path(owner),
@@ -529,8 +542,8 @@ impl Activity {
     */
     pub async fn list_repo_notifications_for_authenticated_user(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         all: bool,
         participating: bool,
         since: Option<chrono::DateTime<chrono::Utc>>,
@@ -538,31 +551,34 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/notifications?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/notifications",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -581,32 +597,35 @@ impl Activity {
     */
     pub async fn list_all_repo_notifications_for_authenticated_user(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         all: bool,
         participating: bool,
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/notifications?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/notifications",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -628,10 +647,12 @@ impl Activity {
     */
     pub async fn mark_repo_notifications_as_read(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
     ) -> Result<crate::types::PullsUpdateBranchResponse> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/notifications",
             crate::progenitor_support::encode_path(owner),
@@ -663,24 +684,27 @@ impl Activity {
     */
     pub async fn list_stargazers_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/stargazers?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/stargazers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -704,24 +728,27 @@ impl Activity {
     */
     pub async fn list_watchers_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/subscribers?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/subscribers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -740,9 +767,11 @@ impl Activity {
     */
     pub async fn list_all_watchers_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/subscribers",
             crate::progenitor_support::encode_path(owner),
@@ -768,9 +797,11 @@ impl Activity {
     */
     pub async fn get_repo_subscription(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::RepositorySubscription> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/subscription",
             crate::progenitor_support::encode_path(owner),
@@ -796,10 +827,12 @@ impl Activity {
     */
     pub async fn set_repo_subscription(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ActivitySetRepoSubscriptionRequest,
     ) -> Result<crate::types::RepositorySubscription> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/subscription",
             crate::progenitor_support::encode_path(owner),
@@ -825,7 +858,13 @@ impl Activity {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn delete_repo_subscription(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn delete_repo_subscription(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/subscription",
             crate::progenitor_support::encode_path(owner),
@@ -862,21 +901,20 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Repository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/starred?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/starred"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -899,15 +937,14 @@ impl Activity {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::Repository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/starred?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/starred"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -917,6 +954,8 @@ impl Activity {
     *
     * This function performs a `GET` to the `/user/starred/{owner}/{repo}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/activity#check-if-a-repository-is-starred-by-the-authenticated-user>
@@ -928,16 +967,18 @@ impl Activity {
     */
     pub async fn check_repo_is_starred_by_authenticated_user(
         &self,
-        owner: &str,
-        repo: &str,
-    ) -> Result<()> {
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<bool> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/user/starred/{}/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -954,7 +995,13 @@ impl Activity {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn star_repo_for_authenticated_user(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn star_repo_for_authenticated_user(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/user/starred/{}/{}",
             crate::progenitor_support::encode_path(owner),
@@ -978,7 +1025,13 @@ impl Activity {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn unstar_repo_for_authenticated_user(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn unstar_repo_for_authenticated_user(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/user/starred/{}/{}",
             crate::progenitor_support::encode_path(owner),
@@ -1007,15 +1060,15 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/subscriptions?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/user/subscriptions"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1055,22 +1108,24 @@ impl Activity {
     */
     pub async fn list_events_for_authenticated_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/events?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1089,8 +1144,9 @@ impl Activity {
     */
     pub async fn list_all_events_for_authenticated_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Event>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/events",
             crate::progenitor_support::encode_path(username),
@@ -1117,24 +1173,27 @@ impl Activity {
     */
     pub async fn list_org_events_for_authenticated_user(
         &self,
-        username: &str,
-        org: &str,
+        username: impl AsRef<str>,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/events/orgs/{}?{}",
-            crate::progenitor_support::encode_path(username),
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/events/orgs/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1153,9 +1212,11 @@ impl Activity {
     */
     pub async fn list_all_org_events_for_authenticated_user(
         &self,
-        username: &str,
-        org: &str,
+        username: impl AsRef<str>,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Event>> {
+        let username = username.as_ref();
+        let org = org.as_ref();
         let url = format!(
             "/users/{}/events/orgs/{}",
             crate::progenitor_support::encode_path(username),
@@ -1182,22 +1243,24 @@ impl Activity {
     */
     pub async fn list_public_events_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/events/public?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1216,8 +1279,9 @@ impl Activity {
     */
     pub async fn list_all_public_events_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Event>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/events/public",
             crate::progenitor_support::encode_path(username),
@@ -1243,22 +1307,24 @@ impl Activity {
     */
     pub async fn list_received_events_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/received_events?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/received_events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1277,8 +1343,9 @@ impl Activity {
     */
     pub async fn list_all_received_events_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Event>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/received_events",
             crate::progenitor_support::encode_path(username),
@@ -1304,22 +1371,24 @@ impl Activity {
     */
     pub async fn list_received_public_events_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/received_events/public?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/received_events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1338,8 +1407,9 @@ impl Activity {
     */
     pub async fn list_all_received_public_events_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Event>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/received_events/public",
             crate::progenitor_support::encode_path(username),
@@ -1371,30 +1441,32 @@ impl Activity {
     */
     pub async fn list_repos_starred_by_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         sort: crate::types::Sort,
         direction: crate::types::Order,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/starred?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/starred",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1417,22 +1489,24 @@ impl Activity {
     */
     pub async fn list_repos_watched_by_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/subscriptions?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/subscriptions",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1451,8 +1525,9 @@ impl Activity {
     */
     pub async fn list_all_repos_watched_by_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/subscriptions",
             crate::progenitor_support::encode_path(username),
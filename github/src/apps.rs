@@ -43,8 +43,9 @@ impl Apps {
     */
     pub async fn create_from_manifest(
         &self,
-        code: &str,
+        code: impl AsRef<str>,
     ) -> Result<crate::types::AppsCreateFromManifestResponseAllOf> {
+        let code = code.as_ref();
         let url = format!(
             "/app-manifests/{}/conversions",
             crate::progenitor_support::encode_path(code),
@@ -111,15 +112,15 @@ impl Apps {
         per_page: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/hook/deliveries?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/app/hook/deliveries"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -141,12 +142,12 @@ impl Apps {
         &self,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/hook/deliveries?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/app/hook/deliveries"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -172,7 +173,7 @@ impl Apps {
     ) -> Result<crate::types::HookDelivery> {
         let url = format!(
             "/app/hook/deliveries/{}",
-            crate::progenitor_support::encode_path(&delivery_id.to_string()),
+            crate::progenitor_support::encode_path(&delivery_id),
         );
 
         self.client.get(&url, None).await
@@ -196,7 +197,7 @@ impl Apps {
     pub async fn redeliver_webhook_delivery(&self, delivery_id: i64) -> Result<()> {
         let url = format!(
             "/app/hook/deliveries/{}/attempts",
-            crate::progenitor_support::encode_path(&delivery_id.to_string()),
+            crate::progenitor_support::encode_path(&delivery_id),
         );
 
         self.client.post(&url, None).await
@@ -227,21 +228,21 @@ impl Apps {
         since: Option<chrono::DateTime<chrono::Utc>>,
         outdated: &str,
     ) -> Result<Vec<crate::types::Installation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !outdated.is_empty() {
-            query_args.push(("outdated".to_string(), outdated.to_string()));
+            query_args.push(("outdated", outdated.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/installations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/app/installations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -264,15 +265,15 @@ impl Apps {
         since: Option<chrono::DateTime<chrono::Utc>>,
         outdated: &str,
     ) -> Result<Vec<crate::types::Installation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !outdated.is_empty() {
-            query_args.push(("outdated".to_string(), outdated.to_string()));
+            query_args.push(("outdated", outdated.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/installations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/app/installations"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -298,7 +299,7 @@ impl Apps {
     ) -> Result<crate::types::Installation> {
         let url = format!(
             "/app/installations/{}",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
+            crate::progenitor_support::encode_path(&installation_id),
         );
 
         self.client.get(&url, None).await
@@ -322,7 +323,7 @@ impl Apps {
     pub async fn delete_installation(&self, installation_id: i64) -> Result<()> {
         let url = format!(
             "/app/installations/{}",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
+            crate::progenitor_support::encode_path(&installation_id),
         );
 
         self.client.delete(&url, None).await
@@ -351,7 +352,7 @@ impl Apps {
     ) -> Result<crate::types::InstallationToken> {
         let url = format!(
             "/app/installations/{}/access_tokens",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
+            crate::progenitor_support::encode_path(&installation_id),
         );
 
         self.client
@@ -382,7 +383,7 @@ impl Apps {
     pub async fn suspend_installation(&self, installation_id: i64) -> Result<()> {
         let url = format!(
             "/app/installations/{}/suspended",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
+            crate::progenitor_support::encode_path(&installation_id),
         );
 
         self.client.put(&url, None).await
@@ -406,7 +407,7 @@ impl Apps {
     pub async fn unsuspend_installation(&self, installation_id: i64) -> Result<()> {
         let url = format!(
             "/app/installations/{}/suspended",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
+            crate::progenitor_support::encode_path(&installation_id),
         );
 
         self.client.delete(&url, None).await
@@ -428,9 +429,10 @@ impl Apps {
     */
     pub async fn delete_authorization(
         &self,
-        client_id: &str,
+        client_id: impl AsRef<str>,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> Result<()> {
+        let client_id = client_id.as_ref();
         let url = format!(
             "/applications/{}/grant",
             crate::progenitor_support::encode_path(client_id),
@@ -461,9 +463,11 @@ impl Apps {
     */
     pub async fn revoke_grant_for_application(
         &self,
-        client_id: &str,
-        access_token: &str,
+        client_id: impl AsRef<str>,
+        access_token: impl AsRef<str>,
     ) -> Result<()> {
+        let client_id = client_id.as_ref();
+        let access_token = access_token.as_ref();
         let url = format!(
             "/applications/{}/grants/{}",
             crate::progenitor_support::encode_path(client_id),
@@ -488,9 +492,10 @@ impl Apps {
     */
     pub async fn check_token(
         &self,
-        client_id: &str,
+        client_id: impl AsRef<str>,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> Result<crate::types::Authorization> {
+        let client_id = client_id.as_ref();
         let url = format!(
             "/applications/{}/token",
             crate::progenitor_support::encode_path(client_id),
@@ -516,9 +521,10 @@ impl Apps {
     */
     pub async fn delete_token(
         &self,
-        client_id: &str,
+        client_id: impl AsRef<str>,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> Result<()> {
+        let client_id = client_id.as_ref();
         let url = format!(
             "/applications/{}/token",
             crate::progenitor_support::encode_path(client_id),
@@ -544,9 +550,10 @@ impl Apps {
     */
     pub async fn reset_token(
         &self,
-        client_id: &str,
+        client_id: impl AsRef<str>,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> Result<crate::types::Authorization> {
+        let client_id = client_id.as_ref();
         let url = format!(
             "/applications/{}/token",
             crate::progenitor_support::encode_path(client_id),
@@ -572,9 +579,10 @@ impl Apps {
     */
     pub async fn scope_token(
         &self,
-        client_id: &str,
+        client_id: impl AsRef<str>,
         body: &crate::types::AppsScopeTokenRequest,
     ) -> Result<crate::types::Authorization> {
+        let client_id = client_id.as_ref();
         let url = format!(
             "/applications/{}/token/scoped",
             crate::progenitor_support::encode_path(client_id),
@@ -603,9 +611,11 @@ impl Apps {
     */
     pub async fn check_authorization(
         &self,
-        client_id: &str,
-        access_token: &str,
+        client_id: impl AsRef<str>,
+        access_token: impl AsRef<str>,
     ) -> Result<crate::types::Authorization> {
+        let client_id = client_id.as_ref();
+        let access_token = access_token.as_ref();
         let url = format!(
             "/applications/{}/tokens/{}",
             crate::progenitor_support::encode_path(client_id),
@@ -633,9 +643,11 @@ impl Apps {
     */
     pub async fn reset_authorization(
         &self,
-        client_id: &str,
-        access_token: &str,
+        client_id: impl AsRef<str>,
+        access_token: impl AsRef<str>,
     ) -> Result<crate::types::Authorization> {
+        let client_id = client_id.as_ref();
+        let access_token = access_token.as_ref();
         let url = format!(
             "/applications/{}/tokens/{}",
             crate::progenitor_support::encode_path(client_id),
@@ -663,9 +675,11 @@ impl Apps {
     */
     pub async fn revoke_authorization_for_application(
         &self,
-        client_id: &str,
-        access_token: &str,
+        client_id: impl AsRef<str>,
+        access_token: impl AsRef<str>,
     ) -> Result<()> {
+        let client_id = client_id.as_ref();
+        let access_token = access_token.as_ref();
         let url = format!(
             "/applications/{}/tokens/{}",
             crate::progenitor_support::encode_path(client_id),
@@ -690,11 +704,9 @@ impl Apps {
     *
     * * `app_slug: &str`
     */
-    pub async fn get_by_slug(&self, app_slug: &str) -> Result<crate::types::GitHubApp> {
-        let url = format!(
-            "/apps/{}",
-            crate::progenitor_support::encode_path(app_slug),
-        );
+    pub async fn get_by_slug(&self, app_slug: impl AsRef<str>) -> Result<crate::types::GitHubApp> {
+        let app_slug = app_slug.as_ref();
+        let url = format!("/apps/{}", crate::progenitor_support::encode_path(app_slug),);
 
         self.client.get(&url, None).await
     }
@@ -720,15 +732,17 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationReposResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/installation/repositories?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/installation/repositories"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -768,11 +782,12 @@ impl Apps {
     */
     pub async fn get_subscription_plan_for_account(
         &self,
-        account_id: i64,
+        account_id: impl Into<crate::types::UserId>,
     ) -> Result<crate::types::MarketplacePurchaseData> {
+        let account_id: crate::types::UserId = account_id.into();
         let url = format!(
             "/marketplace_listing/accounts/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&account_id),
         );
 
         self.client.get(&url, None).await
@@ -799,15 +814,17 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketplace_listing/plans?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketplace_listing/plans"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -859,24 +876,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketplace_listing/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -901,18 +919,19 @@ impl Apps {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketplace_listing/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -935,11 +954,12 @@ impl Apps {
     */
     pub async fn get_subscription_plan_for_account_stubbed(
         &self,
-        account_id: i64,
+        account_id: impl Into<crate::types::UserId>,
     ) -> Result<crate::types::MarketplacePurchaseData> {
+        let account_id: crate::types::UserId = account_id.into();
         let url = format!(
             "/marketplace_listing/stubbed/accounts/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&account_id),
         );
 
         self.client.get(&url, None).await
@@ -966,15 +986,17 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketplace_listing/stubbed/plans?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketplace_listing/stubbed/plans"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1028,24 +1050,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/stubbed/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketplace_listing/stubbed/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1070,18 +1093,19 @@ impl Apps {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/stubbed/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketplace_listing/stubbed/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1102,7 +1126,11 @@ impl Apps {
     *
     * * `org: &str`
     */
-    pub async fn get_org_installation(&self, org: &str) -> Result<crate::types::Installation> {
+    pub async fn get_org_installation(
+        &self,
+        org: impl AsRef<str>,
+    ) -> Result<crate::types::Installation> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/installation",
             crate::progenitor_support::encode_path(org),
@@ -1132,16 +1160,18 @@ impl Apps {
     */
     pub async fn create_content_attachment(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         content_reference_id: i64,
         body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
     ) -> Result<crate::types::ContentReferenceAttachment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/content_references/{}/attachments",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&content_reference_id.to_string()),
+            crate::progenitor_support::encode_path(&content_reference_id),
         );
 
         self.client
@@ -1167,9 +1197,11 @@ impl Apps {
     */
     pub async fn get_repo_installation(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::Installation> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/installation",
             crate::progenitor_support::encode_path(owner),
@@ -1204,15 +1236,15 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/installations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/user/installations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1244,18 +1276,19 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationReposResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/installations/{}/repositories?{}",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/user/installations/{}/repositories",
+                crate::progenitor_support::encode_path(&installation_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1280,12 +1313,13 @@ impl Apps {
     pub async fn add_repo_to_installation(
         &self,
         installation_id: i64,
-        repository_id: i64,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/user/installations/{}/repositories/{}",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&installation_id),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.put(&url, None).await
@@ -1310,12 +1344,13 @@ impl Apps {
     pub async fn remove_repo_from_installation(
         &self,
         installation_id: i64,
-        repository_id: i64,
+        repository_id: impl Into<crate::types::RepoId>,
     ) -> Result<()> {
+        let repository_id: crate::types::RepoId = repository_id.into();
         let url = format!(
             "/user/installations/{}/repositories/{}",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
+            crate::progenitor_support::encode_path(&installation_id),
+            crate::progenitor_support::encode_path(&repository_id),
         );
 
         self.client.delete(&url, None).await
@@ -1340,15 +1375,17 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/marketplace_purchases?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/user/marketplace_purchases"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1390,15 +1427,17 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/marketplace_purchases/stubbed?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/user/marketplace_purchases/stubbed"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1438,8 +1477,9 @@ impl Apps {
     */
     pub async fn get_user_installation(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::Installation> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/installation",
             crate::progenitor_support::encode_path(username),
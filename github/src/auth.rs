@@ -5,15 +5,23 @@ use std::{
     time,
 };
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use jsonwebtoken as jwt;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 // We use 9 minutes for the life to give some buffer for clock drift between
 // our clock and GitHub's. The absolute max is 10 minutes.
 const MAX_JWT_TOKEN_LIFE: time::Duration = time::Duration::from_secs(60 * 9);
 // 8 minutes so we refresh sooner than it actually expires
 const JWT_TOKEN_REFRESH_PERIOD: time::Duration = time::Duration::from_secs(60 * 8);
+// Installation tokens live for an hour, refresh them 5 minutes early so a
+// request in flight never carries an expired token.
+pub(crate) const INSTALLATION_TOKEN_REFRESH_MARGIN: time::Duration =
+    time::Duration::from_secs(60 * 5);
+
+/// The host serving the OAuth endpoints of github.com. GitHub Enterprise Server
+/// serves them from the root of its own host.
+pub const OAUTH_HOST: &str = "https://github.com";
 
 /// Controls what sort of authentication is required for this request.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -42,6 +50,24 @@ pub enum Credentials {
     InstallationToken(InstallationTokenGenerator),
 }
 
+impl Credentials {
+    /// Credentials for a GitHub App installation.
+    ///
+    /// JWTs are minted from the app id and DER encoded private key, then exchanged
+    /// for an installation access token. The token is cached and refreshed shortly
+    /// before it expires.
+    pub fn installation(
+        app_id: u64,
+        installation_id: u64,
+        private_key: Vec<u8>,
+    ) -> Result<Credentials> {
+        let jwt = JWTCredentials::new(app_id, private_key)?;
+        Ok(Credentials::InstallationToken(
+            InstallationTokenGenerator::new(installation_id, jwt),
+        ))
+    }
+}
+
 impl fmt::Debug for Credentials {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -121,7 +147,7 @@ impl PartialEq for JWTCredentials {
 #[derive(Debug)]
 struct ExpiringJWTCredential {
     token: String,
-    created_at: time::Instant,
+    created_at: web_time::Instant,
 }
 
 #[derive(Serialize)]
@@ -135,9 +161,9 @@ impl ExpiringJWTCredential {
     fn calculate(app_id: u64, private_key: &[u8]) -> Result<ExpiringJWTCredential> {
         // SystemTime can go backwards, Instant can't, so always use
         // Instant for ensuring regular cycling.
-        let created_at = time::Instant::now();
-        let now = time::SystemTime::now()
-            .duration_since(time::UNIX_EPOCH)
+        let created_at = web_time::Instant::now();
+        let now = web_time::SystemTime::now()
+            .duration_since(web_time::UNIX_EPOCH)
             .unwrap();
         let expires = now + MAX_JWT_TOKEN_LIFE;
 
@@ -171,11 +197,22 @@ impl ExpiringJWTCredential {
 /// JWT credentials to fetch a new installation token.
 ///
 /// The Mutex<Option> access key is for interior mutability.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct InstallationTokenGenerator {
     pub installation_id: u64,
     pub jwt_credential: Box<Credentials>,
     pub access_key: Arc<Mutex<Option<String>>>,
+    expires_at: Arc<Mutex<Option<chrono::DateTime<chrono::Utc>>>>,
+}
+
+impl fmt::Debug for InstallationTokenGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstallationTokenGenerator")
+            .field("installation_id", &self.installation_id)
+            .field("jwt_credential", &"***")
+            .field("access_key", &"***")
+            .finish()
+    }
 }
 
 impl InstallationTokenGenerator {
@@ -184,18 +221,43 @@ impl InstallationTokenGenerator {
             installation_id,
             jwt_credential: Box::new(Credentials::JWT(creds)),
             access_key: Arc::new(Mutex::new(None)),
+            expires_at: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Returns the cached installation token, or `None` when a new one needs to
+    /// be requested.
     pub fn token(&self) -> Option<String> {
-        if let Credentials::JWT(ref creds) = *self.jwt_credential {
-            if creds.is_stale() {
-                return None;
+        match *self.expires_at.lock().unwrap() {
+            Some(expires_at) => {
+                let margin = chrono::Duration::from_std(INSTALLATION_TOKEN_REFRESH_MARGIN).unwrap();
+                if chrono::Utc::now() + margin >= expires_at {
+                    return None;
+                }
+            }
+            // Without a known expiration, fall back to cycling the token along
+            // with the JWT.
+            None => {
+                if let Credentials::JWT(ref creds) = *self.jwt_credential {
+                    if creds.is_stale() {
+                        return None;
+                    }
+                }
             }
         }
         self.access_key.lock().unwrap().clone()
     }
 
+    /// Store a freshly minted installation token along with its `expires_at`
+    /// timestamp as returned by GitHub.
+    pub fn set_token(&self, token: String, expires_at: &str) {
+        let expires_at = chrono::DateTime::parse_from_rfc3339(expires_at)
+            .ok()
+            .map(|expires_at| expires_at.with_timezone(&chrono::Utc));
+        *self.expires_at.lock().unwrap() = expires_at;
+        *self.access_key.lock().unwrap() = Some(token);
+    }
+
     pub fn jwt(&self) -> &Credentials {
         &*self.jwt_credential
     }
@@ -206,3 +268,220 @@ impl PartialEq for InstallationTokenGenerator {
         self.installation_id == other.installation_id && self.jwt_credential == other.jwt_credential
     }
 }
+
+/// The codes returned when starting the device flow.
+///
+/// Show `user_code` to the user and ask them to enter it at `verification_uri`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCode {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    /// Seconds until the codes expire.
+    pub expires_in: u64,
+    /// Minimum number of seconds to wait between two polls for the token.
+    pub interval: u64,
+}
+
+/// An access token obtained through one of the OAuth flows.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthToken {
+    pub access_token: String,
+    #[serde(default)]
+    pub token_type: String,
+    /// The scopes that were granted, separated by commas.
+    #[serde(default)]
+    pub scope: String,
+}
+
+impl fmt::Debug for OAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthToken")
+            .field("access_token", &"*".repeat(self.access_token.len()))
+            .field("token_type", &self.token_type)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+impl OAuthToken {
+    /// Create a client authenticated with this token.
+    pub fn client<A: Into<String>>(&self, agent: A) -> Result<crate::Client> {
+        crate::Client::new(agent, Credentials::Token(self.access_token.clone()))
+    }
+}
+
+/// The response of the token endpoint, which returns a 200 with an `error` while the
+/// user has not finished authorizing.
+#[derive(Deserialize)]
+struct TokenResponse {
+    #[serde(default)]
+    access_token: String,
+    #[serde(default)]
+    token_type: String,
+    #[serde(default)]
+    scope: String,
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    error_description: String,
+    #[serde(default)]
+    interval: Option<u64>,
+}
+
+/// The OAuth web application flow and device flow of an OAuth or GitHub App, for
+/// obtaining a token that acts on behalf of a user.
+///
+/// https://docs.github.com/en/developers/apps/building-oauth-apps/authorizing-oauth-apps
+#[derive(Clone)]
+pub struct OAuthFlow {
+    client_id: String,
+    client_secret: String,
+    host: String,
+    http: reqwest::Client,
+}
+
+impl OAuthFlow {
+    /// The client secret is only needed for the web application flow, pass an empty
+    /// string when only using the device flow.
+    pub fn new<I, S>(client_id: I, client_secret: S) -> Self
+    where
+        I: Into<String>,
+        S: Into<String>,
+    {
+        OAuthFlow {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            host: OAUTH_HOST.to_string(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Use the OAuth endpoints of a GitHub Enterprise Server instance, for example
+    /// `https://github.example.com`.
+    pub fn with_host<H: Into<String>>(mut self, host: H) -> Self {
+        self.host = host.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The URL to send the user to, to authorize the app in the web application flow.
+    /// GitHub redirects back to `redirect_uri` with a `code` to pass to
+    /// `exchange_code`, and the `state`, which should be checked against the one
+    /// passed here.
+    pub fn authorize_url(&self, redirect_uri: &str, scopes: &[&str], state: &str) -> String {
+        let mut query_args: Vec<(&str, String)> = vec![("client_id", self.client_id.clone())];
+        if !redirect_uri.is_empty() {
+            query_args.push(("redirect_uri", redirect_uri.to_string()));
+        }
+        if !scopes.is_empty() {
+            query_args.push(("scope", scopes.join(" ")));
+        }
+        if !state.is_empty() {
+            query_args.push(("state", state.to_string()));
+        }
+        format!("{}/login/oauth/authorize?{}", self.host, query_)
+    }
+
+    /// Exchange the `code` GitHub redirected back with for an access token.
+    pub async fn exchange_code(&self, code: &str, redirect_uri: &str) -> Result<OAuthToken> {
+        let mut params = vec![
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("code", code),
+        ];
+        if !redirect_uri.is_empty() {
+            params.push(("redirect_uri", redirect_uri));
+        }
+
+        let resp = self.token(&params).await?;
+        if !resp.error.is_empty() {
+            return Err(anyhow!(
+                "exchanging the code failed: {}: {}",
+                resp.error,
+                resp.error_description
+            ));
+        }
+
+        Ok(OAuthToken {
+            access_token: resp.access_token,
+            token_type: resp.token_type,
+            scope: resp.scope,
+        })
+    }
+
+    /// Start the device flow, returning the code to show to the user.
+    pub async fn request_device_code(&self, scopes: &[&str]) -> Result<DeviceCode> {
+        let scope = scopes.join(" ");
+        let resp = self
+            .http
+            .post(&format!("{}/login/device/code", self.host))
+            .header(http::header::ACCEPT, "application/json")
+            .form(&[("client_id", self.client_id.as_str()), ("scope", &scope)])
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(resp.json().await?)
+    }
+
+    /// Poll for the access token until the user entered the code from
+    /// `request_device_code`, or the code expired.
+    pub async fn poll_device_token(&self, device_code: &DeviceCode) -> Result<OAuthToken> {
+        let deadline = web_time::Instant::now() + time::Duration::from_secs(device_code.expires_in);
+        let mut interval = time::Duration::from_secs(device_code.interval.max(1));
+
+        loop {
+            crate::runtime::sleep(interval).await;
+
+            let resp = self
+                .token(&[
+                    ("client_id", self.client_id.as_str()),
+                    ("device_code", device_code.device_code.as_str()),
+                    ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+                ])
+                .await?;
+
+            match resp.error.as_str() {
+                "" => {
+                    return Ok(OAuthToken {
+                        access_token: resp.access_token,
+                        token_type: resp.token_type,
+                        scope: resp.scope,
+                    })
+                }
+                "authorization_pending" => {}
+                // We polled too fast, GitHub tells us how long to wait from now on.
+                "slow_down" => {
+                    interval = resp
+                        .interval
+                        .map(time::Duration::from_secs)
+                        .unwrap_or(interval + time::Duration::from_secs(5));
+                }
+                _ => {
+                    return Err(anyhow!(
+                        "device flow failed: {}: {}",
+                        resp.error,
+                        resp.error_description
+                    ))
+                }
+            }
+
+            if web_time::Instant::now() + interval > deadline {
+                return Err(anyhow!("device code expired before it was entered"));
+            }
+        }
+    }
+
+    async fn token(&self, params: &[(&str, &str)]) -> Result<TokenResponse> {
+        let resp = self
+            .http
+            .post(&format!("{}/login/oauth/access_token", self.host))
+            .header(http::header::ACCEPT, "application/json")
+            .form(params)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(resp.json().await?)
+    }
+}
@@ -0,0 +1,35 @@
+//! Running many requests at once with a bound on concurrency.
+//!
+//! The requests are futures of the methods of the client, each holding a clone of
+//! it. Clones share their connections.
+//!
+//! ```
+//! # async fn fetch(id: u64) -> anyhow::Result<u64> {
+//! #     Ok(id)
+//! # }
+//! # async fn run() {
+//! let results = octorust::batch::batch((1..=100).map(fetch), 8).await;
+//! assert_eq!(results.len(), 100);
+//! # }
+//! ```
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+
+/// Run the given requests with at most `limit` of them in flight at once.
+///
+/// The results are returned in the same order as the requests, a request failing
+/// does not stop the other ones. Every request is retried by the `RetryMiddleware`
+/// of the client it is made with: transient failures and `429 Too Many Requests`
+/// responses are retried after the `Retry-After` the server asks for, while the
+/// request keeps its slot, so a rate limited batch slows down instead of sending
+/// more requests.
+pub async fn batch<I, F, T>(requests: I, limit: usize) -> Vec<Result<T>>
+where
+    I: IntoIterator<Item = F>,
+    F: std::future::Future<Output = Result<T>>,
+{
+    stream::iter(requests)
+        .buffered(limit.max(1))
+        .collect()
+        .await
+}
@@ -31,8 +31,9 @@ impl Billing {
     */
     pub async fn get_github_actions_billing_ghe(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<crate::types::ActionsBillingUsage> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/settings/billing/actions",
             crate::progenitor_support::encode_path(enterprise),
@@ -60,8 +61,9 @@ impl Billing {
     */
     pub async fn get_github_packages_billing_ghe(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<crate::types::PackagesBillingUsage> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/settings/billing/packages",
             crate::progenitor_support::encode_path(enterprise),
@@ -89,8 +91,9 @@ impl Billing {
     */
     pub async fn get_shared_storage_billing_ghe(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<crate::types::CombinedBillingUsage> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/settings/billing/shared-storage",
             crate::progenitor_support::encode_path(enterprise),
@@ -118,8 +121,9 @@ impl Billing {
     */
     pub async fn get_github_actions_billing_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::ActionsBillingUsage> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/settings/billing/actions",
             crate::progenitor_support::encode_path(org),
@@ -147,8 +151,9 @@ impl Billing {
     */
     pub async fn get_github_packages_billing_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::PackagesBillingUsage> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/settings/billing/packages",
             crate::progenitor_support::encode_path(org),
@@ -176,8 +181,9 @@ impl Billing {
     */
     pub async fn get_shared_storage_billing_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::CombinedBillingUsage> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/settings/billing/shared-storage",
             crate::progenitor_support::encode_path(org),
@@ -205,8 +211,9 @@ impl Billing {
     */
     pub async fn get_github_actions_billing_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::ActionsBillingUsage> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/settings/billing/actions",
             crate::progenitor_support::encode_path(username),
@@ -234,8 +241,9 @@ impl Billing {
     */
     pub async fn get_github_packages_billing_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::PackagesBillingUsage> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/settings/billing/packages",
             crate::progenitor_support::encode_path(username),
@@ -263,8 +271,9 @@ impl Billing {
     */
     pub async fn get_shared_storage_billing_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::CombinedBillingUsage> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/settings/billing/shared-storage",
             crate::progenitor_support::encode_path(username),
@@ -0,0 +1,325 @@
+//! Protect a branch with typed rules instead of the nested body of the API.
+//!
+//! The builder describes the whole protection of a branch, so that the protection
+//! read from GitHub can be turned into a builder too and compared with the desired
+//! one before it is updated.
+//!
+//! https://docs.github.com/en/rest/reference/repos#update-branch-protection
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+
+use crate::{
+    repos::Repos,
+    types::{
+        BranchProtection, ProtectedBranch, ReposUpdateBranchProtectionRequest,
+        ReposUpdateBranchProtectionRequestRequiredPullReviews,
+        ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions,
+        ReposUpdateBranchProtectionRequestRequiredStatusChecks, Restrictions,
+    },
+};
+
+/// The reviews a pull request needs before it is merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ReviewRules {
+    count: i64,
+    dismiss_stale_reviews: bool,
+    require_code_owner_reviews: bool,
+    dismissal_restrictions: Option<(BTreeSet<String>, BTreeSet<String>)>,
+}
+
+impl ReviewRules {
+    fn new() -> Self {
+        // GitHub requires one approving review when no count is given.
+        ReviewRules {
+            count: 1,
+            dismiss_stale_reviews: false,
+            require_code_owner_reviews: false,
+            dismissal_restrictions: None,
+        }
+    }
+
+    fn request(&self) -> ReposUpdateBranchProtectionRequestRequiredPullReviews {
+        ReposUpdateBranchProtectionRequestRequiredPullReviews {
+            dismiss_stale_reviews: self.dismiss_stale_reviews,
+            dismissal_restrictions: self.dismissal_restrictions.as_ref().map(|(users, teams)| {
+                ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions {
+                    teams: teams.iter().cloned().collect(),
+                    users: users.iter().cloned().collect(),
+                }
+            }),
+            require_code_owner_reviews: self.require_code_owner_reviews,
+            required_approving_review_count: self.count,
+        }
+    }
+}
+
+/// The status checks which must pass before a pull request is merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct StatusCheckRules {
+    contexts: BTreeSet<String>,
+    strict: bool,
+}
+
+/// The users, teams and apps allowed to push to the branch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct PushRestrictions {
+    users: BTreeSet<String>,
+    teams: BTreeSet<String>,
+    apps: BTreeSet<String>,
+}
+
+fn set(values: &[&str]) -> BTreeSet<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+/**
+ * Builds the protection of a branch.
+ *
+ * A rule which is not set is disabled, like GitHub does when it is missing from
+ * the body of the request. Builders compare equal when they protect the branch in
+ * the same way, whatever the order of their lists.
+ *
+ * ```no_run
+ * # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+ * use octorust::repos::BranchProtectionBuilder;
+ *
+ * let desired = BranchProtectionBuilder::new()
+ *     .require_reviews(2)
+ *     .require_status_checks(&["ci"])
+ *     .enforce_admins(true);
+ *
+ * let repos = github.repos();
+ * if repos.get_branch_protection_builder("owner", "repo", "main").await? != desired {
+ *     repos.protect_branch("owner", "repo", "main", &desired).await?;
+ * }
+ * # Ok(())
+ * # }
+ * ```
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchProtectionBuilder {
+    reviews: Option<ReviewRules>,
+    status_checks: Option<StatusCheckRules>,
+    enforce_admins: bool,
+    restrictions: Option<PushRestrictions>,
+    required_linear_history: bool,
+    allow_force_pushes: bool,
+    allow_deletions: bool,
+    required_conversation_resolution: bool,
+}
+
+impl BranchProtectionBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Require `count` approving reviews, from 1 to 6, before merging.
+    pub fn require_reviews(mut self, count: i64) -> Self {
+        self.reviews.get_or_insert_with(ReviewRules::new).count = count;
+        self
+    }
+
+    /// Dismiss the approving reviews when new commits are pushed. Requires reviews.
+    pub fn dismiss_stale_reviews(mut self, dismiss: bool) -> Self {
+        self.reviews
+            .get_or_insert_with(ReviewRules::new)
+            .dismiss_stale_reviews = dismiss;
+        self
+    }
+
+    /// Require a review from the code owners. Requires reviews.
+    pub fn require_code_owner_reviews(mut self, require: bool) -> Self {
+        self.reviews
+            .get_or_insert_with(ReviewRules::new)
+            .require_code_owner_reviews = require;
+        self
+    }
+
+    /// Only let the users with the given logins and the teams with the given slugs
+    /// dismiss reviews. Only available for repositories of organizations.
+    pub fn restrict_review_dismissals(mut self, users: &[&str], teams: &[&str]) -> Self {
+        self.reviews
+            .get_or_insert_with(ReviewRules::new)
+            .dismissal_restrictions = Some((set(users), set(teams)));
+        self
+    }
+
+    /// Require the status checks with the given contexts to pass before merging.
+    pub fn require_status_checks(mut self, contexts: &[&str]) -> Self {
+        self.status_checks
+            .get_or_insert_with(Default::default)
+            .contexts = set(contexts);
+        self
+    }
+
+    /// Require branches to be up to date with the base branch before merging.
+    /// Requires status checks.
+    pub fn strict_status_checks(mut self, strict: bool) -> Self {
+        self.status_checks
+            .get_or_insert_with(Default::default)
+            .strict = strict;
+        self
+    }
+
+    /// Apply the rules to the administrators of the repository too.
+    pub fn enforce_admins(mut self, enforce: bool) -> Self {
+        self.enforce_admins = enforce;
+        self
+    }
+
+    /// Only let the users with the given logins, the teams and the apps with the
+    /// given slugs push to the branch. Only available for repositories of
+    /// organizations.
+    pub fn restrict_pushes(mut self, users: &[&str], teams: &[&str], apps: &[&str]) -> Self {
+        self.restrictions = Some(PushRestrictions {
+            users: set(users),
+            teams: set(teams),
+            apps: set(apps),
+        });
+        self
+    }
+
+    /// Forbid merge commits on the branch.
+    pub fn require_linear_history(mut self, require: bool) -> Self {
+        self.required_linear_history = require;
+        self
+    }
+
+    /// Allow force pushes to the branch, by everyone with push access.
+    pub fn allow_force_pushes(mut self, allow: bool) -> Self {
+        self.allow_force_pushes = allow;
+        self
+    }
+
+    /// Allow deleting the branch, by everyone with push access.
+    pub fn allow_deletions(mut self, allow: bool) -> Self {
+        self.allow_deletions = allow;
+        self
+    }
+
+    /// Require all the conversations on the code to be resolved before merging.
+    pub fn require_conversation_resolution(mut self, require: bool) -> Self {
+        self.required_conversation_resolution = require;
+        self
+    }
+
+    /// The body of the request updating the protection of the branch.
+    pub fn build(&self) -> ReposUpdateBranchProtectionRequest {
+        ReposUpdateBranchProtectionRequest {
+            allow_deletions: Some(self.allow_deletions),
+            allow_force_pushes: Some(self.allow_force_pushes),
+            enforce_admins: Some(self.enforce_admins),
+            required_conversation_resolution: Some(self.required_conversation_resolution),
+            required_linear_history: Some(self.required_linear_history),
+            required_pull_request_reviews: self.reviews.as_ref().map(ReviewRules::request),
+            required_status_checks: self.status_checks.as_ref().map(|s| {
+                ReposUpdateBranchProtectionRequestRequiredStatusChecks {
+                    contexts: s.contexts.iter().cloned().collect(),
+                    strict: s.strict,
+                }
+            }),
+            restrictions: self.restrictions.as_ref().map(|r| Restrictions {
+                apps: r.apps.iter().cloned().collect(),
+                teams: r.teams.iter().cloned().collect(),
+                users: r.users.iter().cloned().collect(),
+            }),
+        }
+    }
+}
+
+impl From<&BranchProtection> for BranchProtectionBuilder {
+    fn from(protection: &BranchProtection) -> Self {
+        BranchProtectionBuilder {
+            reviews: protection
+                .required_pull_request_reviews
+                .as_ref()
+                .map(|r| ReviewRules {
+                    count: r.required_approving_review_count,
+                    dismiss_stale_reviews: r.dismiss_stale_reviews,
+                    require_code_owner_reviews: r.require_code_owner_reviews,
+                    dismissal_restrictions: r.dismissal_restrictions.as_ref().map(|d| {
+                        (
+                            d.users.iter().map(|u| u.login.to_string()).collect(),
+                            d.teams.iter().map(|t| t.slug.to_string()).collect(),
+                        )
+                    }),
+                }),
+            status_checks: protection
+                .required_status_checks
+                .as_ref()
+                .map(|s| StatusCheckRules {
+                    contexts: s.contexts.iter().cloned().collect(),
+                    strict: s.strict,
+                }),
+            enforce_admins: protection
+                .enforce_admins
+                .as_ref()
+                .map_or(false, |e| e.enabled),
+            restrictions: protection.restrictions.as_ref().map(|r| PushRestrictions {
+                users: r.users.iter().map(|u| u.login.to_string()).collect(),
+                teams: r.teams.iter().map(|t| t.slug.to_string()).collect(),
+                apps: r.apps.iter().map(|a| a.slug.to_string()).collect(),
+            }),
+            required_linear_history: protection
+                .required_linear_history
+                .as_ref()
+                .map_or(false, |r| r.enabled),
+            allow_force_pushes: protection
+                .allow_force_pushes
+                .as_ref()
+                .map_or(false, |a| a.enabled),
+            allow_deletions: protection
+                .allow_deletions
+                .as_ref()
+                .map_or(false, |a| a.enabled),
+            required_conversation_resolution: protection
+                .required_conversation_resolution
+                .as_ref()
+                .map_or(false, |r| r.enabled),
+        }
+    }
+}
+
+impl Repos {
+    /**
+     * Get the protection of a branch as a builder, to compare it with the desired
+     * protection or to change some of its rules.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `branch: &str` -- The name of the branch.
+     */
+    pub async fn get_branch_protection_builder(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<BranchProtectionBuilder> {
+        let protection = self.get_branch_protection(owner, repo, branch).await?;
+        Ok(BranchProtectionBuilder::from(&protection))
+    }
+
+    /**
+     * Replace the protection of a branch with the one of the builder.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `branch: &str` -- The name of the branch.
+     * * `protection: &BranchProtectionBuilder` -- All the rules protecting the branch.
+     */
+    pub async fn protect_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        protection: &BranchProtectionBuilder,
+    ) -> Result<ProtectedBranch> {
+        self.update_branch_protection(owner, repo, branch, &protection.build())
+            .await
+    }
+}
@@ -0,0 +1,351 @@
+//! Create and update check runs with any number of annotations.
+//!
+//! GitHub takes at most 50 annotations per request. `Checks::create_run` and
+//! `Checks::update_run` send the first 50 along with the rest of the check run and
+//! add the others with more updates, 50 at a time.
+//!
+//! https://docs.github.com/en/rest/reference/checks#runs
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checks::Checks,
+    types::{AnnotationLevel, CheckRun, JobStatus},
+};
+
+/// The number of annotations GitHub takes in a single request.
+pub const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+/// How a completed check run turned out.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckConclusion {
+    ActionRequired,
+    Cancelled,
+    Failure,
+    Neutral,
+    Skipped,
+    Success,
+    TimedOut,
+}
+
+/// A comment on lines of a file, shown with the diff of the commit.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Annotation {
+    /// The path of the file, relative to the root of the repository.
+    pub path: String,
+    pub start_line: i64,
+    pub end_line: i64,
+    /// Columns can only be given for an annotation on a single line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+    pub annotation_level: AnnotationLevel,
+    pub message: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub title: String,
+    /// Details shown when the annotation is expanded, up to 64 KB.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub raw_details: String,
+}
+
+/// What a check run shows: a title, a summary in Markdown and annotations.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CheckRunOutput {
+    pub title: String,
+    pub summary: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+}
+
+/**
+ * The fields of a check run to create or update, the ones not set are left out.
+ *
+ * ```no_run
+ * # async fn run(github: octorust::Client, lints: Vec<(String, i64, String)>) -> anyhow::Result<()> {
+ * use octorust::checks::{Annotation, CheckConclusion, CheckRunOutput, CheckRunRequest};
+ *
+ * let annotations = lints
+ *     .into_iter()
+ *     .map(|(path, line, message)| Annotation::warning(&path, line, &message));
+ * let run = CheckRunRequest::new("lint", "6113728f27ae82c7b1a177c8d03f9e96e0adf246")
+ *     .conclusion(CheckConclusion::Neutral)
+ *     .output(CheckRunOutput::new("Lints", "Some lints were found.").annotations(annotations));
+ * github.checks().create_run("owner", "repo", &run).await?;
+ * # Ok(())
+ * # }
+ * ```
+ */
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct CheckRunRequest {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    head_sha: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    details_url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    external_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<JobStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    completed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<CheckRunOutput>,
+}
+
+impl Annotation {
+    pub fn new(
+        path: &str,
+        start_line: i64,
+        end_line: i64,
+        annotation_level: AnnotationLevel,
+        message: &str,
+    ) -> Self {
+        Annotation {
+            path: path.to_string(),
+            start_line,
+            end_line,
+            start_column: None,
+            end_column: None,
+            annotation_level,
+            message: message.to_string(),
+            title: String::new(),
+            raw_details: String::new(),
+        }
+    }
+
+    pub fn notice(path: &str, line: i64, message: &str) -> Self {
+        Annotation::new(path, line, line, AnnotationLevel::Notice, message)
+    }
+
+    pub fn warning(path: &str, line: i64, message: &str) -> Self {
+        Annotation::new(path, line, line, AnnotationLevel::Warning, message)
+    }
+
+    pub fn failure(path: &str, line: i64, message: &str) -> Self {
+        Annotation::new(path, line, line, AnnotationLevel::Failure, message)
+    }
+
+    pub fn columns(mut self, start_column: i64, end_column: i64) -> Self {
+        self.start_column = Some(start_column);
+        self.end_column = Some(end_column);
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn raw_details(mut self, raw_details: &str) -> Self {
+        self.raw_details = raw_details.to_string();
+        self
+    }
+}
+
+impl CheckRunOutput {
+    pub fn new(title: &str, summary: &str) -> Self {
+        CheckRunOutput {
+            title: title.to_string(),
+            summary: summary.to_string(),
+            text: String::new(),
+            annotations: Vec::new(),
+        }
+    }
+
+    /// The details of the check run, in Markdown.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    pub fn annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    pub fn annotations<I>(mut self, annotations: I) -> Self
+    where
+        I: IntoIterator<Item = Annotation>,
+    {
+        self.annotations.extend(annotations);
+        self
+    }
+}
+
+impl CheckRunRequest {
+    /// A check run to create for the commit `head_sha`.
+    pub fn new(name: &str, head_sha: &str) -> Self {
+        CheckRunRequest {
+            name: name.to_string(),
+            head_sha: head_sha.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Rename the check run.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The page with the full details of the check on the site of the integration.
+    pub fn details_url(mut self, details_url: &str) -> Self {
+        self.details_url = details_url.to_string();
+        self
+    }
+
+    /// The reference of the check run in the system of the integration.
+    pub fn external_id(mut self, external_id: &str) -> Self {
+        self.external_id = external_id.to_string();
+        self
+    }
+
+    pub fn status(mut self, status: JobStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn started_at(mut self, started_at: DateTime<Utc>) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Complete the check run with this conclusion.
+    pub fn conclusion(mut self, conclusion: CheckConclusion) -> Self {
+        self.conclusion = Some(conclusion);
+        self
+    }
+
+    pub fn completed_at(mut self, completed_at: DateTime<Utc>) -> Self {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    pub fn output(mut self, output: CheckRunOutput) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// Split the request in requests with at most `MAX_ANNOTATIONS_PER_REQUEST`
+    /// annotations each. The first one has all the other fields, the next ones
+    /// only the output, since GitHub requires its title and summary.
+    fn batches(&self) -> Vec<CheckRunRequest> {
+        let output = match &self.output {
+            Some(output) if output.annotations.len() > MAX_ANNOTATIONS_PER_REQUEST => output,
+            _ => return vec![self.clone()],
+        };
+
+        output
+            .annotations
+            .chunks(MAX_ANNOTATIONS_PER_REQUEST)
+            .enumerate()
+            .map(|(i, annotations)| {
+                let output = Some(CheckRunOutput {
+                    title: output.title.to_string(),
+                    summary: output.summary.to_string(),
+                    text: output.text.to_string(),
+                    annotations: annotations.to_vec(),
+                });
+                if i == 0 {
+                    CheckRunRequest {
+                        output,
+                        ..self.clone()
+                    }
+                } else {
+                    CheckRunRequest {
+                        output,
+                        ..Default::default()
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl Checks {
+    /**
+     * Create a check run, with any number of annotations.
+     *
+     * The first 50 annotations are sent with the check run, the others are added
+     * by updating it. The check run is returned as of the last request.
+     */
+    pub async fn create_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run: &CheckRunRequest,
+    ) -> Result<CheckRun> {
+        let url = format!(
+            "/repos/{}/{}/check-runs",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let batches = run.batches();
+        let mut check_run: CheckRun = self
+            .client
+            .post(
+                &url,
+                Some(reqwest::Body::from(serde_json::to_vec(&batches[0])?)),
+            )
+            .await?;
+        for batch in &batches[1..] {
+            check_run = self.patch_run(owner, repo, check_run.id, batch).await?;
+        }
+
+        Ok(check_run)
+    }
+
+    /**
+     * Update a check run, adding any number of annotations to the ones it has.
+     *
+     * The annotations are added 50 at a time. The check run is returned as of the
+     * last request.
+     */
+    pub async fn update_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        run: &CheckRunRequest,
+    ) -> Result<CheckRun> {
+        let batches = run.batches();
+        let mut check_run = self
+            .patch_run(owner, repo, check_run_id, &batches[0])
+            .await?;
+        for batch in &batches[1..] {
+            check_run = self.patch_run(owner, repo, check_run_id, batch).await?;
+        }
+
+        Ok(check_run)
+    }
+
+    async fn patch_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        run: &CheckRunRequest,
+    ) -> Result<CheckRun> {
+        let url = format!(
+            "/repos/{}/{}/check-runs/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&check_run_id),
+        );
+
+        self.client
+            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(run)?)))
+            .await
+    }
+}
@@ -1,5 +1,8 @@
 use anyhow::Result;
 
+pub use crate::check_runs::{
+    Annotation, CheckConclusion, CheckRunOutput, CheckRunRequest, MAX_ANNOTATIONS_PER_REQUEST,
+};
 use crate::Client;
 
 pub struct Checks {
@@ -32,10 +35,12 @@ impl Checks {
     */
     pub async fn create(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ChecksCreateRequest,
     ) -> Result<crate::types::CheckRun> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-runs",
             crate::progenitor_support::encode_path(owner),
@@ -66,15 +71,17 @@ impl Checks {
     */
     pub async fn get(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         check_run_id: i64,
     ) -> Result<crate::types::CheckRun> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-runs/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&check_run_id.to_string()),
+            crate::progenitor_support::encode_path(&check_run_id),
         );
 
         self.client.get(&url, None).await
@@ -99,16 +106,18 @@ impl Checks {
     */
     pub async fn update(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         check_run_id: i64,
         body: &crate::types::ChecksUpdateRequest,
     ) -> Result<crate::types::CheckRun> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-runs/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&check_run_id.to_string()),
+            crate::progenitor_support::encode_path(&check_run_id),
         );
 
         self.client
@@ -135,26 +144,29 @@ impl Checks {
     */
     pub async fn list_annotations(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         check_run_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::CheckAnnotation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/check-runs/{}/annotations?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&check_run_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -173,15 +185,17 @@ impl Checks {
     */
     pub async fn list_all_annotations(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         check_run_id: i64,
     ) -> Result<Vec<crate::types::CheckAnnotation>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-runs/{}/annotations",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&check_run_id.to_string()),
+            crate::progenitor_support::encode_path(&check_run_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -205,10 +219,12 @@ impl Checks {
     */
     pub async fn create_suite(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ChecksCreateSuiteRequest,
     ) -> Result<crate::types::CheckSuiteData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-suites",
             crate::progenitor_support::encode_path(owner),
@@ -236,10 +252,12 @@ impl Checks {
     */
     pub async fn set_suites_preferences(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::Preferences,
     ) -> Result<crate::types::CheckSuitePreference> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-suites/preferences",
             crate::progenitor_support::encode_path(owner),
@@ -270,15 +288,17 @@ impl Checks {
     */
     pub async fn get_suite(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         check_suite_id: i64,
     ) -> Result<crate::types::CheckSuiteData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-suites/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&check_suite_id.to_string()),
+            crate::progenitor_support::encode_path(&check_suite_id),
         );
 
         self.client.get(&url, None).await
@@ -310,8 +330,8 @@ impl Checks {
     */
     pub async fn list_for_suite(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         check_suite_id: i64,
         check_name: &str,
         status: crate::types::JobStatus,
@@ -319,29 +339,32 @@ impl Checks {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ChecksListRefResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+            query_args.push(("check_name", check_name.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/check-suites/{}/check-runs?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&check_suite_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/check-suites/{}/check-runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_suite_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -366,15 +389,17 @@ impl Checks {
     */
     pub async fn rerequest_suite(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         check_suite_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/check-suites/{}/rerequest",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&check_suite_id.to_string()),
+            crate::progenitor_support::encode_path(&check_suite_id),
         );
 
         self.client.post(&url, None).await
@@ -407,9 +432,9 @@ impl Checks {
     */
     pub async fn list_for_ref(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
         check_name: &str,
         status: crate::types::JobStatus,
         filter: crate::types::ActionsListJobsWorkflowRunFilter,
@@ -417,32 +442,36 @@ impl Checks {
         page: i64,
         app_id: i64,
     ) -> Result<crate::types::ChecksListRefResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if app_id > 0 {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+            query_args.push(("app_id", app_id.to_string()));
         }
         if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+            query_args.push(("check_name", check_name.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/check-runs?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(ref_),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits/{}/check-runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -471,34 +500,38 @@ impl Checks {
     */
     pub async fn list_suites_for_ref(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
         app_id: i64,
         check_name: &str,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ChecksListSuitesRefResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if app_id > 0 {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+            query_args.push(("app_id", app_id.to_string()));
         }
         if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+            query_args.push(("check_name", check_name.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/check-suites?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(ref_),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits/{}/check-suites",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
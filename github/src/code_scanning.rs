@@ -42,8 +42,8 @@ impl CodeScanning {
     */
     pub async fn list_alerts_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         tool_name: &str,
         tool_guid: &str,
         page: i64,
@@ -51,31 +51,34 @@ impl CodeScanning {
         ref_: &str,
         state: crate::types::CodeScanningAlertState,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/code-scanning/alerts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -102,32 +105,35 @@ impl CodeScanning {
     */
     pub async fn list_all_alerts_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         tool_name: &str,
         tool_guid: &str,
         ref_: &str,
         state: crate::types::CodeScanningAlertState,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/code-scanning/alerts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -153,15 +159,17 @@ impl CodeScanning {
     */
     pub async fn get_alert(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         alert_number: i64,
     ) -> Result<crate::types::CodeScanningAlert> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/code-scanning/alerts/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&alert_number.to_string()),
+            crate::progenitor_support::encode_path(&alert_number),
         );
 
         self.client.get(&url, None).await
@@ -184,16 +192,18 @@ impl CodeScanning {
     */
     pub async fn update_alert(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         alert_number: i64,
         body: &crate::types::CodeScanningUpdateAlertRequest,
     ) -> Result<crate::types::CodeScanningAlert> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/code-scanning/alerts/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&alert_number.to_string()),
+            crate::progenitor_support::encode_path(&alert_number),
         );
 
         self.client
@@ -221,30 +231,33 @@ impl CodeScanning {
     */
     pub async fn list_alert_instances(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         alert_number: i64,
         page: i64,
         per_page: i64,
         ref_: &str,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&alert_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -263,22 +276,25 @@ impl CodeScanning {
     */
     pub async fn list_all_alert_instances(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         alert_number: i64,
         ref_: &str,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&alert_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -321,8 +337,8 @@ impl CodeScanning {
     */
     pub async fn list_recent_analyses(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         tool_name: &str,
         tool_guid: &str,
         page: i64,
@@ -330,31 +346,34 @@ impl CodeScanning {
         ref_: &str,
         sarif_id: &str,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !sarif_id.is_empty() {
-            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+            query_args.push(("sarif_id", sarif_id.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/analyses?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/code-scanning/analyses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -388,32 +407,35 @@ impl CodeScanning {
     */
     pub async fn list_all_recent_analyses(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         tool_name: &str,
         tool_guid: &str,
         ref_: &str,
         sarif_id: &str,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !sarif_id.is_empty() {
-            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+            query_args.push(("sarif_id", sarif_id.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/analyses?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/code-scanning/analyses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -456,15 +478,17 @@ impl CodeScanning {
     */
     pub async fn get_analysis(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         analysis_id: i64,
     ) -> Result<crate::types::CodeScanningAnalysis> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/code-scanning/analyses/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&analysis_id.to_string()),
+            crate::progenitor_support::encode_path(&analysis_id),
         );
 
         self.client.get(&url, None).await
@@ -551,22 +575,25 @@ impl CodeScanning {
     */
     pub async fn delete_analysis(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         analysis_id: i64,
         confirm_delete: &str,
     ) -> Result<crate::types::AnalysisDeletion> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !confirm_delete.is_empty() {
-            query_args.push(("confirm_delete".to_string(), confirm_delete.to_string()));
+            query_args.push(("confirm_delete", confirm_delete.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/analyses/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&analysis_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/code-scanning/analyses/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&analysis_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -604,10 +631,12 @@ impl CodeScanning {
     */
     pub async fn upload_sarif(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::CodeScanningUploadSarifRequest,
     ) -> Result<crate::types::CodeScanningSarifsReceipt> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/code-scanning/sarifs",
             crate::progenitor_support::encode_path(owner),
@@ -636,10 +665,13 @@ impl CodeScanning {
     */
     pub async fn get_sarif(
         &self,
-        owner: &str,
-        repo: &str,
-        sarif_id: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        sarif_id: impl AsRef<str>,
     ) -> Result<crate::types::CodeScanningSarifsStatus> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let sarif_id = sarif_id.as_ref();
         let url = format!(
             "/repos/{}/{}/code-scanning/sarifs/{}",
             crate::progenitor_support::encode_path(owner),
@@ -55,7 +55,11 @@ impl CodesOfConduct {
     *
     * * `key: &str`
     */
-    pub async fn get_conduct_code(&self, key: &str) -> Result<crate::types::CodeOfConduct> {
+    pub async fn get_conduct_code(
+        &self,
+        key: impl AsRef<str>,
+    ) -> Result<crate::types::CodeOfConduct> {
+        let key = key.as_ref();
         let url = format!(
             "/codes_of_conduct/{}",
             crate::progenitor_support::encode_path(key),
@@ -82,9 +86,11 @@ impl CodesOfConduct {
     */
     pub async fn get_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::CodeOfConduct> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/community/code_of_conduct",
             crate::progenitor_support::encode_path(owner),
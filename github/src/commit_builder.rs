@@ -0,0 +1,195 @@
+//! Create a commit from a set of files with the Git database API.
+//!
+//! https://docs.github.com/en/rest/guides/getting-started-with-the-git-database-api
+use anyhow::{anyhow, Result};
+
+use crate::git::Git;
+
+/// A file to add or replace in the commit.
+struct CommitFile {
+    path: String,
+    content: Vec<u8>,
+    mode: crate::types::GitCreateTreeRequestMode,
+}
+
+/**
+ * Builds a commit on top of a branch and moves the branch to it.
+ *
+ * Creating a commit with the Git database API takes a blob for every file, a tree
+ * on top of the tree of the current commit, the commit itself and finally an update
+ * of the branch. `CommitBuilder` makes these calls in the right order.
+ *
+ * ```no_run
+ * # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+ * let sha = github
+ *     .git()
+ *     .commit_builder("owner", "repo", "main")
+ *     .message("Update the docs")
+ *     .file("README.md", "# Hello\n")
+ *     .file("docs/logo.png", std::fs::read("logo.png")?)
+ *     .commit()
+ *     .await?;
+ * # Ok(())
+ * # }
+ * ```
+ */
+pub struct CommitBuilder {
+    git: Git,
+    owner: String,
+    repo: String,
+    branch: String,
+    message: String,
+    files: Vec<CommitFile>,
+    author: Option<crate::types::GitCreateCommitRequestAuthor>,
+    force: bool,
+}
+
+impl Git {
+    /// Start building a commit on top of `branch`.
+    pub fn commit_builder(&self, owner: &str, repo: &str, branch: &str) -> CommitBuilder {
+        CommitBuilder::new(Git::new(self.client.clone()), owner, repo, branch)
+    }
+}
+
+impl CommitBuilder {
+    pub fn new(git: Git, owner: &str, repo: &str, branch: &str) -> Self {
+        CommitBuilder {
+            git,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            branch: branch.trim_start_matches("refs/heads/").to_string(),
+            message: String::new(),
+            files: Vec::new(),
+            author: None,
+            force: false,
+        }
+    }
+
+    /// Set the commit message.
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Add or replace a file at `path`.
+    pub fn file<C: Into<Vec<u8>>>(mut self, path: &str, content: C) -> Self {
+        self.files.push(CommitFile {
+            path: path.trim_start_matches('/').to_string(),
+            content: content.into(),
+            mode: crate::types::GitCreateTreeRequestMode::FileBlob,
+        });
+        self
+    }
+
+    /// Add or replace an executable file at `path`.
+    pub fn executable<C: Into<Vec<u8>>>(mut self, path: &str, content: C) -> Self {
+        self.files.push(CommitFile {
+            path: path.trim_start_matches('/').to_string(),
+            content: content.into(),
+            mode: crate::types::GitCreateTreeRequestMode::ExecutableBlob,
+        });
+        self
+    }
+
+    /// Set the author of the commit. By default, the author is the authenticated user.
+    pub fn author(mut self, name: &str, email: &str) -> Self {
+        self.author = Some(crate::types::GitCreateCommitRequestAuthor {
+            date: None,
+            email: email.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Move the branch to the new commit even if it is no longer a fast-forward,
+    /// because the branch moved while the commit was being built.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Create the blobs, the tree and the commit, then update the branch to point
+    /// to the new commit. Returns the SHA of the new commit.
+    pub async fn commit(self) -> Result<String> {
+        if self.message.is_empty() {
+            return Err(anyhow!("a commit message is required"));
+        }
+
+        let ref_ = format!("heads/{}", self.branch);
+        let parent = self.git.get_ref(&self.owner, &self.repo, &ref_).await?;
+        let parent = parent.object.sha;
+        let base = self
+            .git
+            .get_commit(&self.owner, &self.repo, &parent)
+            .await?;
+
+        let blobs: Vec<crate::types::GitCreateBlobRequest> = self
+            .files
+            .iter()
+            .map(|file| crate::types::GitCreateBlobRequest {
+                content: base64::encode(&file.content),
+                encoding: "base64".to_string(),
+            })
+            .collect();
+        let blobs = futures::future::try_join_all(
+            blobs
+                .iter()
+                .map(|blob| self.git.create_blob(&self.owner, &self.repo, blob)),
+        )
+        .await?;
+
+        let tree = self
+            .files
+            .iter()
+            .zip(blobs)
+            .map(|(file, blob)| crate::types::GitCreateTreeRequest {
+                content: String::new(),
+                mode: Some(file.mode.clone()),
+                path: file.path.clone(),
+                sha: blob.sha,
+                type_: Some(crate::types::GitCreateTagRequestType::Blob),
+            })
+            .collect();
+        let tree = self
+            .git
+            .create_tree(
+                &self.owner,
+                &self.repo,
+                &crate::types::GitCreateTreeRequestData {
+                    base_tree: base.tree.sha,
+                    tree,
+                },
+            )
+            .await?;
+
+        let commit = self
+            .git
+            .create_commit(
+                &self.owner,
+                &self.repo,
+                &crate::types::GitCreateCommitRequest {
+                    author: self.author.clone(),
+                    committer: None,
+                    message: self.message.clone(),
+                    parents: vec![parent],
+                    signature: String::new(),
+                    tree: tree.sha,
+                },
+            )
+            .await?;
+
+        self.git
+            .update_ref(
+                &self.owner,
+                &self.repo,
+                &ref_,
+                &crate::types::GitUpdateRefRequest {
+                    force: Some(self.force),
+                    sha: commit.sha.clone(),
+                },
+            )
+            .await?;
+
+        Ok(commit.sha)
+    }
+}
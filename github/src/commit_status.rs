@@ -0,0 +1,200 @@
+//! Wait for the statuses and the check runs of a commit, for example before
+//! deploying it.
+//!
+//! A commit is reported on in two ways: with commit statuses, combined by
+//! `Repos::get_combined_status_for_ref`, and with the check runs of GitHub Apps,
+//! like the jobs of Actions. Both are summed up in a `CommitStatusSummary`.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use std::time::Duration;
+//!
+//! use octorust::repos::CommitState;
+//!
+//! let sha = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+//! let summary = github
+//!     .repos()
+//!     .combined_status_wait("owner", "repo", sha, Duration::from_secs(1800))
+//!     .await?;
+//! match summary.state() {
+//!     CommitState::Success => println!("deploying"),
+//!     CommitState::Failure => anyhow::bail!("failed: {}", summary.failed.join(", ")),
+//!     CommitState::Pending => anyhow::bail!("still running: {}", summary.pending.join(", ")),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::{
+    checks::Checks,
+    repos::Repos,
+    types::{ActionsListJobsWorkflowRunFilter, CheckRun, Conclusion, JobStatus},
+};
+
+/// The time to wait between two polls of the statuses of a commit.
+pub const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The number of statuses or check runs requested per page.
+const PER_PAGE: i64 = 100;
+
+/// The overall state of a commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitState {
+    /// Every status and check run succeeded, or there are none.
+    Success,
+    /// A status or a check run failed.
+    Failure,
+    /// None failed, but some are not done yet.
+    Pending,
+}
+
+/// The statuses and check runs of a commit, by outcome. Statuses are named by
+/// their context and check runs by their name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitStatusSummary {
+    /// The successful statuses and check runs. Check runs concluded as neutral or
+    /// skipped count as successful.
+    pub succeeded: Vec<String>,
+    /// The statuses which failed or errored, and the check runs which did not
+    /// succeed, including the cancelled and timed out ones.
+    pub failed: Vec<String>,
+    /// The statuses and check runs which are not done yet.
+    pub pending: Vec<String>,
+}
+
+impl CommitStatusSummary {
+    pub fn state(&self) -> CommitState {
+        if !self.failed.is_empty() {
+            CommitState::Failure
+        } else if !self.pending.is_empty() {
+            CommitState::Pending
+        } else {
+            CommitState::Success
+        }
+    }
+
+    fn add_status(&mut self, context: String, state: &str) {
+        match state {
+            "success" => self.succeeded.push(context),
+            "failure" | "error" => self.failed.push(context),
+            _ => self.pending.push(context),
+        }
+    }
+
+    fn add_check_run(&mut self, run: CheckRun) {
+        if run.status != JobStatus::Completed {
+            self.pending.push(run.name);
+            return;
+        }
+        match run.conclusion {
+            Some(Conclusion::Success) | Some(Conclusion::Neutral) | Some(Conclusion::Skipped) => {
+                self.succeeded.push(run.name)
+            }
+            _ => self.failed.push(run.name),
+        }
+    }
+}
+
+impl Repos {
+    /**
+     * Get the statuses and the latest check runs of a commit, by outcome.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `ref_: &str` -- The SHA of the commit, or the name of a branch or a tag.
+     */
+    pub async fn commit_status_summary(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<CommitStatusSummary> {
+        let mut summary = CommitStatusSummary::default();
+
+        let mut seen = 0;
+        for page in 1.. {
+            let combined = self
+                .get_combined_status_for_ref(owner, repo, ref_, PER_PAGE, page)
+                .await?;
+            if combined.statuses.is_empty() {
+                break;
+            }
+            seen += combined.statuses.len() as i64;
+            for status in combined.statuses {
+                summary.add_status(status.context, &status.state);
+            }
+            if seen >= combined.total_count {
+                break;
+            }
+        }
+
+        let checks = Checks::new(self.client.clone());
+        let mut seen = 0;
+        for page in 1.. {
+            let runs = checks
+                .list_for_ref(
+                    owner,
+                    repo,
+                    ref_,
+                    "",
+                    JobStatus::Noop,
+                    ActionsListJobsWorkflowRunFilter::Latest,
+                    PER_PAGE,
+                    page,
+                    0,
+                )
+                .await?;
+            if runs.check_runs.is_empty() {
+                break;
+            }
+            seen += runs.check_runs.len() as i64;
+            for run in runs.check_runs {
+                summary.add_check_run(run);
+            }
+            if seen >= runs.total_count {
+                break;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /**
+     * Wait until the statuses and the latest check runs of a commit are all done.
+     *
+     * The commit is polled every `STATUS_POLL_INTERVAL`. When `timeout` elapses
+     * first, the last summary is returned, with the statuses and check runs which
+     * are not done yet in `pending`. Check `CommitStatusSummary::state` before
+     * relying on the commit.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `sha: &str` -- The SHA of the commit.
+     * * `timeout: Duration` -- How long to wait at most.
+     */
+    pub async fn combined_status_wait(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        timeout: Duration,
+    ) -> Result<CommitStatusSummary> {
+        let deadline = web_time::Instant::now() + timeout;
+        loop {
+            let summary = self.commit_status_summary(owner, repo, sha).await?;
+            if summary.pending.is_empty()
+                || web_time::Instant::now() + STATUS_POLL_INTERVAL > deadline
+            {
+                return Ok(summary);
+            }
+            crate::runtime::sleep(STATUS_POLL_INTERVAL).await;
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Binary-safe helpers for the repository contents API.
+//!
+//! https://docs.github.com/en/rest/reference/repos#contents
+use anyhow::Result;
+
+use crate::repos::Repos;
+
+impl Repos {
+    /// The URL of a file in a repository, at the given commit, branch or tag.
+    fn content_url(&self, owner: &str, repo: &str, path: &str, ref_: &str) -> String {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !ref_.is_empty() {
+            query_args.push(("ref", ref_.to_string()));
+        }
+        crate::progenitor_support::build_url(
+            format_args!(
+                "{}/repos/{}/{}/contents/{}",
+                self.client.host,
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path),
+            ),
+            &query_args,
+        )
+    }
+
+    /**
+     * Get the raw contents of a file.
+     *
+     * As opposed to `get_content_file`, the contents are requested with the
+     * `application/vnd.github.raw` media type and returned as is, rather than base64
+     * encoded inside of a JSON object. This also works for files between 1 and 100 MB.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `path: &str` -- path parameter.
+     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     */
+    pub async fn get_content_raw(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<bytes::Bytes> {
+        let resp = self
+            .client
+            .request_raw(
+                http::Method::GET,
+                &self.content_url(owner, repo, path, ref_),
+                None,
+                crate::utils::MediaType::Raw,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+                Default::default(),
+            )
+            .await?;
+
+        Ok(resp.bytes().await?)
+    }
+
+    /**
+     * Download the raw contents of a file as a stream of chunks.
+     *
+     * Like `get_content_raw`, but the file is not buffered in memory, which makes it
+     * suitable for large files.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `path: &str` -- path parameter.
+     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     */
+    pub async fn download_content(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let resp = self
+            .client
+            .streaming()
+            .request_raw(
+                http::Method::GET,
+                &self.content_url(owner, repo, path, ref_),
+                None,
+                crate::utils::MediaType::Raw,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+                Default::default(),
+            )
+            .await?;
+
+        Ok(crate::utils::response_stream(resp))
+    }
+
+    /**
+     * Create or update a file.
+     *
+     * Like `create_or_update_file_contents`, but takes the contents as bytes and takes
+     * care of base64 encoding them.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `path: &str` -- path parameter.
+     * * `message: &str` -- The commit message.
+     * * `content: &[u8]` -- The new contents of the file.
+     * * `branch: &str` -- The branch name. Default: the repository’s default branch (usually `master`).
+     * * `sha: &str` -- The blob SHA of the file being replaced. Required when updating a file.
+     */
+    pub async fn create_or_update_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        message: &str,
+        content: &[u8],
+        branch: &str,
+        sha: &str,
+    ) -> Result<crate::types::FileCommitData> {
+        self.create_or_update_file_contents(
+            owner,
+            repo,
+            path,
+            &crate::types::ReposCreateUpdateFileContentsRequest {
+                author: None,
+                branch: branch.to_string(),
+                committer: None,
+                content: base64::encode(content),
+                message: message.to_string(),
+                sha: sha.to_string(),
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,183 @@
+//! The changes of pull requests and commits as diffs and patches.
+//!
+//! The JSON endpoints return the same changes as text when they are requested with
+//! the `application/vnd.github.v3.diff` or `application/vnd.github.v3.patch` media
+//! types, which is what the methods here do.
+//!
+//! https://docs.github.com/en/rest/overview/media-types#commits-commit-comparison-and-pull-requests
+use anyhow::Result;
+
+#[cfg(feature = "pulls")]
+use crate::pulls::Pulls;
+#[cfg(feature = "repos")]
+use crate::repos::Repos;
+use crate::{utils::MediaType, Client};
+
+/// Get `uri` with the given media type and return the body as text.
+async fn get_text(client: &Client, uri: &str, media: MediaType) -> Result<String> {
+    let resp = client
+        .request_raw(
+            http::Method::GET,
+            &(client.host.clone() + uri),
+            None,
+            media,
+            crate::auth::AuthenticationConstraint::Unconstrained,
+            Default::default(),
+        )
+        .await?;
+
+    Ok(resp.text().await?)
+}
+
+#[cfg(feature = "pulls")]
+impl Pulls {
+    /**
+     * Get the changes of a pull request as a unified diff.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `pull_number: i64`
+     */
+    pub async fn get_diff(&self, owner: &str, repo: &str, pull_number: i64) -> Result<String> {
+        get_text(
+            &self.client,
+            &pull_url(owner, repo, pull_number),
+            MediaType::Diff,
+        )
+        .await
+    }
+
+    /**
+     * Get the changes of a pull request as one patch per commit, in the format of
+     * `git format-patch`.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `pull_number: i64`
+     */
+    pub async fn get_patch(&self, owner: &str, repo: &str, pull_number: i64) -> Result<String> {
+        get_text(
+            &self.client,
+            &pull_url(owner, repo, pull_number),
+            MediaType::Patch,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "repos")]
+impl Repos {
+    /**
+     * Get the changes of a commit as a unified diff.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `ref_: &str` -- ref parameter.
+     */
+    pub async fn get_commit_diff(&self, owner: &str, repo: &str, ref_: &str) -> Result<String> {
+        get_text(
+            &self.client,
+            &commit_url(owner, repo, ref_),
+            MediaType::Diff,
+        )
+        .await
+    }
+
+    /**
+     * Get the changes of a commit as a patch, in the format of `git format-patch`.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `ref_: &str` -- ref parameter.
+     */
+    pub async fn get_commit_patch(&self, owner: &str, repo: &str, ref_: &str) -> Result<String> {
+        get_text(
+            &self.client,
+            &commit_url(owner, repo, ref_),
+            MediaType::Patch,
+        )
+        .await
+    }
+
+    /**
+     * Get the changes between two commits as a unified diff.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `basehead: &str` -- The base branch and head branch to compare, in the format `BASE...HEAD`.
+     */
+    pub async fn compare_commits_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        basehead: &str,
+    ) -> Result<String> {
+        get_text(
+            &self.client,
+            &compare_url(owner, repo, basehead),
+            MediaType::Diff,
+        )
+        .await
+    }
+
+    /**
+     * Get the changes between two commits as one patch per commit, in the format
+     * of `git format-patch`.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `basehead: &str` -- The base branch and head branch to compare, in the format `BASE...HEAD`.
+     */
+    pub async fn compare_commits_patch(
+        &self,
+        owner: &str,
+        repo: &str,
+        basehead: &str,
+    ) -> Result<String> {
+        get_text(
+            &self.client,
+            &compare_url(owner, repo, basehead),
+            MediaType::Patch,
+        )
+        .await
+    }
+}
+
+fn pull_url(owner: &str, repo: &str, pull_number: i64) -> String {
+    format!(
+        "/repos/{}/{}/pulls/{}",
+        crate::progenitor_support::encode_path(owner),
+        crate::progenitor_support::encode_path(repo),
+        crate::progenitor_support::encode_path(&pull_number),
+    )
+}
+
+fn commit_url(owner: &str, repo: &str, ref_: &str) -> String {
+    format!(
+        "/repos/{}/{}/commits/{}",
+        crate::progenitor_support::encode_path(owner),
+        crate::progenitor_support::encode_path(repo),
+        crate::progenitor_support::encode_path(ref_),
+    )
+}
+
+fn compare_url(owner: &str, repo: &str, basehead: &str) -> String {
+    format!(
+        "/repos/{}/{}/compare/{}",
+        crate::progenitor_support::encode_path(owner),
+        crate::progenitor_support::encode_path(repo),
+        crate::progenitor_support::encode_path(basehead),
+    )
+}
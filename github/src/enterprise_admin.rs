@@ -29,8 +29,9 @@ impl EnterpriseAdmin {
     */
     pub async fn get_github_actions_permissions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<crate::types::ActionsEnterprisePermissions> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/permissions",
             crate::progenitor_support::encode_path(enterprise),
@@ -56,9 +57,10 @@ impl EnterpriseAdmin {
     */
     pub async fn set_github_actions_permissions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminSetGithubActionsPermissionsRequest,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/permissions",
             crate::progenitor_support::encode_path(enterprise),
@@ -88,22 +90,24 @@ impl EnterpriseAdmin {
     */
     pub async fn list_selected_organizations_enabled_github_actions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/permissions/organizations?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/enterprises/{}/actions/permissions/organizations",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -126,9 +130,10 @@ impl EnterpriseAdmin {
     */
     pub async fn set_selected_organizations_enabled_github_actions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/permissions/organizations",
             crate::progenitor_support::encode_path(enterprise),
@@ -157,13 +162,14 @@ impl EnterpriseAdmin {
     */
     pub async fn enable_selected_organization_github_actions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         org_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/permissions/organizations/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&org_id.to_string()),
+            crate::progenitor_support::encode_path(&org_id),
         );
 
         self.client.put(&url, None).await
@@ -187,13 +193,14 @@ impl EnterpriseAdmin {
     */
     pub async fn disable_selected_organization_github_actions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         org_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/permissions/organizations/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&org_id.to_string()),
+            crate::progenitor_support::encode_path(&org_id),
         );
 
         self.client.delete(&url, None).await
@@ -216,8 +223,9 @@ impl EnterpriseAdmin {
     */
     pub async fn get_allowed_actions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<crate::types::SelectedActions> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/permissions/selected-actions",
             crate::progenitor_support::encode_path(enterprise),
@@ -243,9 +251,10 @@ impl EnterpriseAdmin {
     */
     pub async fn set_allowed_actions_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         body: &crate::types::SelectedActions,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/permissions/selected-actions",
             crate::progenitor_support::encode_path(enterprise),
@@ -275,22 +284,24 @@ impl EnterpriseAdmin {
     */
     pub async fn list_self_hosted_runner_groups_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runner-groups?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/enterprises/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -313,9 +324,10 @@ impl EnterpriseAdmin {
     */
     pub async fn create_self_hosted_runner_group_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
     ) -> Result<crate::types::RunnerGroupsEnterprise> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups",
             crate::progenitor_support::encode_path(enterprise),
@@ -344,13 +356,14 @@ impl EnterpriseAdmin {
     */
     pub async fn get_self_hosted_runner_group_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
     ) -> Result<crate::types::RunnerGroupsEnterprise> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client.get(&url, None).await
@@ -374,13 +387,14 @@ impl EnterpriseAdmin {
     */
     pub async fn delete_self_hosted_runner_group_from_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client.delete(&url, None).await
@@ -404,14 +418,15 @@ impl EnterpriseAdmin {
     */
     pub async fn update_self_hosted_runner_group_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         body: &crate::types::EnterpriseAdminUpdateSelfHostedRunnerGroupRequest,
     ) -> Result<crate::types::RunnerGroupsEnterprise> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client
@@ -439,24 +454,26 @@ impl EnterpriseAdmin {
     */
     pub async fn list_org_access_to_self_hosted_runner_group_in_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runner-groups/{}/organizations?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/enterprises/{}/actions/runner-groups/{}/organizations",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -480,14 +497,15 @@ impl EnterpriseAdmin {
     */
     pub async fn set_org_access_to_self_hosted_runner_group_in_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}/organizations",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client
@@ -514,15 +532,16 @@ impl EnterpriseAdmin {
     */
     pub async fn add_org_access_to_self_hosted_runner_group_in_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         org_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}/organizations/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&org_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&org_id),
         );
 
         self.client.put(&url, None).await
@@ -547,15 +566,16 @@ impl EnterpriseAdmin {
     */
     pub async fn remove_org_access_to_self_hosted_runner_group_in_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         org_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}/organizations/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&org_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&org_id),
         );
 
         self.client.delete(&url, None).await
@@ -581,24 +601,26 @@ impl EnterpriseAdmin {
     */
     pub async fn list_self_hosted_runners_in_group_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runner-groups/{}/runners?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/enterprises/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -622,14 +644,15 @@ impl EnterpriseAdmin {
     */
     pub async fn set_self_hosted_runners_in_group_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}/runners",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
         );
 
         self.client
@@ -657,15 +680,16 @@ impl EnterpriseAdmin {
     */
     pub async fn add_self_hosted_runner_to_group_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         runner_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}/runners/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.put(&url, None).await
@@ -690,15 +714,16 @@ impl EnterpriseAdmin {
     */
     pub async fn remove_self_hosted_runner_from_group_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_group_id: i64,
         runner_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}/runners/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_group_id),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.delete(&url, None).await
@@ -723,22 +748,24 @@ impl EnterpriseAdmin {
     */
     pub async fn list_self_hosted_runners_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runners?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/enterprises/{}/actions/runners",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -761,8 +788,9 @@ impl EnterpriseAdmin {
     */
     pub async fn list_runner_applications_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<Vec<crate::types::RunnerApplication>> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runners/downloads",
             crate::progenitor_support::encode_path(enterprise),
@@ -786,8 +814,9 @@ impl EnterpriseAdmin {
     */
     pub async fn list_all_runner_applications_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<Vec<crate::types::RunnerApplication>> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runners/downloads",
             crate::progenitor_support::encode_path(enterprise),
@@ -821,8 +850,9 @@ impl EnterpriseAdmin {
     */
     pub async fn create_registration_token_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<crate::types::AuthenticationToken> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runners/registration-token",
             crate::progenitor_support::encode_path(enterprise),
@@ -857,8 +887,9 @@ impl EnterpriseAdmin {
     */
     pub async fn create_remove_token_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
     ) -> Result<crate::types::AuthenticationToken> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runners/remove-token",
             crate::progenitor_support::encode_path(enterprise),
@@ -885,13 +916,14 @@ impl EnterpriseAdmin {
     */
     pub async fn get_self_hosted_runner_for_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_id: i64,
     ) -> Result<crate::types::Runner> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runners/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.get(&url, None).await
@@ -915,13 +947,14 @@ impl EnterpriseAdmin {
     */
     pub async fn delete_self_hosted_runner_from_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         runner_id: i64,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/enterprises/{}/actions/runners/{}",
             crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(&runner_id.to_string()),
+            crate::progenitor_support::encode_path(&runner_id),
         );
 
         self.client.delete(&url, None).await
@@ -957,7 +990,7 @@ impl EnterpriseAdmin {
     */
     pub async fn get_audit_log(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         phrase: &str,
         include: crate::types::Include,
         after: &str,
@@ -966,33 +999,35 @@ impl EnterpriseAdmin {
         page: i64,
         per_page: i64,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/enterprises/{}/audit-log",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1011,34 +1046,36 @@ impl EnterpriseAdmin {
     */
     pub async fn get_all_audit_log(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         phrase: &str,
         include: crate::types::Include,
         after: &str,
         before: &str,
         order: crate::types::Order,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/enterprises/{}/audit-log",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1063,33 +1100,32 @@ impl EnterpriseAdmin {
     */
     pub async fn list_provisioned_groups_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         start_index: i64,
         count: i64,
         filter: &str,
         excluded_attributes: &str,
     ) -> Result<crate::types::ScimGroupListEnterprise> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !excluded_attributes.is_empty() {
-            query_args.push((
-                "excludedAttributes".to_string(),
-                excluded_attributes.to_string(),
-            ));
+            query_args.push(("excludedAttributes", excluded_attributes.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+            query_args.push(("startIndex", start_index.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/scim/v2/enterprises/{}/Groups?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/scim/v2/enterprises/{}/Groups",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1112,9 +1148,10 @@ impl EnterpriseAdmin {
     */
     pub async fn provision_and_invite_enterprise_group(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Groups",
             crate::progenitor_support::encode_path(enterprise),
@@ -1142,23 +1179,23 @@ impl EnterpriseAdmin {
     */
     pub async fn get_provisioning_information_for_enterprise_group(
         &self,
-        enterprise: &str,
-        scim_group_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_group_id: impl AsRef<str>,
         excluded_attributes: &str,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let scim_group_id = scim_group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !excluded_attributes.is_empty() {
-            query_args.push((
-                "excludedAttributes".to_string(),
-                excluded_attributes.to_string(),
-            ));
+            query_args.push(("excludedAttributes", excluded_attributes.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/scim/v2/enterprises/{}/Groups/{}?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            crate::progenitor_support::encode_path(scim_group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/scim/v2/enterprises/{}/Groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1182,10 +1219,12 @@ impl EnterpriseAdmin {
     */
     pub async fn set_information_for_provisioned_enterprise_group(
         &self,
-        enterprise: &str,
-        scim_group_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_group_id: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
+        let enterprise = enterprise.as_ref();
+        let scim_group_id = scim_group_id.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Groups/{}",
             crate::progenitor_support::encode_path(enterprise),
@@ -1213,9 +1252,11 @@ impl EnterpriseAdmin {
     */
     pub async fn delete_scim_group_from_enterprise(
         &self,
-        enterprise: &str,
-        scim_group_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_group_id: impl AsRef<str>,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
+        let scim_group_id = scim_group_id.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Groups/{}",
             crate::progenitor_support::encode_path(enterprise),
@@ -1243,10 +1284,12 @@ impl EnterpriseAdmin {
     */
     pub async fn update_attribute_for_enterprise_group(
         &self,
-        enterprise: &str,
-        scim_group_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_group_id: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminUpdateAttributeGroupRequest,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
+        let enterprise = enterprise.as_ref();
+        let scim_group_id = scim_group_id.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Groups/{}",
             crate::progenitor_support::encode_path(enterprise),
@@ -1293,26 +1336,28 @@ impl EnterpriseAdmin {
     */
     pub async fn list_provisioned_identities_enterprise(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         start_index: i64,
         count: i64,
         filter: &str,
     ) -> Result<crate::types::ScimUserListEnterprise> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let enterprise = enterprise.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+            query_args.push(("startIndex", start_index.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/scim/v2/enterprises/{}/Users?{}",
-            crate::progenitor_support::encode_path(enterprise),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/scim/v2/enterprises/{}/Users",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1337,9 +1382,10 @@ impl EnterpriseAdmin {
     */
     pub async fn provision_and_invite_enterprise_user(
         &self,
-        enterprise: &str,
+        enterprise: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
     ) -> Result<crate::types::ScimEnterpriseUser> {
+        let enterprise = enterprise.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Users",
             crate::progenitor_support::encode_path(enterprise),
@@ -1366,9 +1412,11 @@ impl EnterpriseAdmin {
     */
     pub async fn get_provisioning_information_for_enterprise_user(
         &self,
-        enterprise: &str,
-        scim_user_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
     ) -> Result<crate::types::ScimEnterpriseUser> {
+        let enterprise = enterprise.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Users/{}",
             crate::progenitor_support::encode_path(enterprise),
@@ -1400,10 +1448,12 @@ impl EnterpriseAdmin {
     */
     pub async fn set_information_for_provisioned_enterprise_user(
         &self,
-        enterprise: &str,
-        scim_user_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
     ) -> Result<crate::types::ScimEnterpriseUser> {
+        let enterprise = enterprise.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Users/{}",
             crate::progenitor_support::encode_path(enterprise),
@@ -1431,9 +1481,11 @@ impl EnterpriseAdmin {
     */
     pub async fn delete_user_from_enterprise(
         &self,
-        enterprise: &str,
-        scim_user_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
     ) -> Result<()> {
+        let enterprise = enterprise.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Users/{}",
             crate::progenitor_support::encode_path(enterprise),
@@ -1476,10 +1528,12 @@ impl EnterpriseAdmin {
     */
     pub async fn update_attribute_for_enterprise_user(
         &self,
-        enterprise: &str,
-        scim_user_id: &str,
+        enterprise: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
         body: &crate::types::EnterpriseAdminUpdateAttributeUserRequest,
     ) -> Result<crate::types::ScimEnterpriseUser> {
+        let enterprise = enterprise.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/enterprises/{}/Users/{}",
             crate::progenitor_support::encode_path(enterprise),
@@ -0,0 +1,123 @@
+//! When a request fails, GitHub describes what went wrong in the body of the
+//! response. The error of the request is then an `ApiError`, which can be matched on
+//! with `downcast_ref`:
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client, body: octorust::types::IssuesCreateLabelRequest) -> anyhow::Result<()> {
+//! if let Err(err) = github.issues().create_label("owner", "repo", &body).await {
+//!     match err.downcast_ref::<octorust::error::ApiError>() {
+//!         // The repository has the label already.
+//!         Some(e) if e.has_code("already_exists") => {}
+//!         _ => return Err(err),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! https://docs.github.com/en/rest/overview/resources-in-the-rest-api#client-errors
+use std::fmt;
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+/// An error returned by GitHub.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// The status code of the response.
+    pub status: reqwest::StatusCode,
+    /// A description of the error.
+    pub message: String,
+    /// The documentation of the endpoint that failed.
+    pub documentation_url: String,
+    /// What was wrong with the request, for validation errors.
+    pub errors: Vec<ErrorDetail>,
+}
+
+/// What was wrong with a request.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ErrorDetail {
+    /// The kind of resource the error is about, for example `Issue`.
+    #[serde(default)]
+    pub resource: String,
+    /// The field the error is about.
+    #[serde(default)]
+    pub field: String,
+    /// What was wrong, for example `missing_field`, `already_exists` or `custom`.
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl ApiError {
+    /// Whether one of the errors has the given code.
+    pub fn has_code(&self, code: &str) -> bool {
+        self.errors.iter().any(|e| e.code == code)
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    message: String,
+    #[serde(default)]
+    documentation_url: String,
+    #[serde(default)]
+    errors: Vec<Detail>,
+}
+
+/// Some endpoints give the errors as plain strings.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Detail {
+    Object(ErrorDetail),
+    Message(String),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "code: {}, {}", self.status, self.message)?;
+        for e in &self.errors {
+            match (e.message.is_empty(), e.field.is_empty()) {
+                (false, _) => write!(f, "; {}", e.message)?,
+                (true, false) => write!(f, "; {}.{}: {}", e.resource, e.field, e.code)?,
+                (true, true) => write!(f, "; {}", e.code)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Build the error for a response that failed, from the error in its body if there
+/// is one.
+pub(crate) fn from_response(status: reqwest::StatusCode, body: &[u8]) -> anyhow::Error {
+    if body.is_empty() {
+        return anyhow!("code: {}, empty response", status);
+    }
+
+    match serde_json::from_slice::<ErrorBody>(body) {
+        Ok(e) => anyhow!(ApiError {
+            status,
+            message: e.message,
+            documentation_url: e.documentation_url,
+            errors: e
+                .errors
+                .into_iter()
+                .map(|d| match d {
+                    Detail::Object(d) => d,
+                    Detail::Message(message) => ErrorDetail {
+                        message,
+                        ..Default::default()
+                    },
+                })
+                .collect(),
+        }),
+        Err(_) => anyhow!(
+            "code: {}, error: {:?}",
+            status,
+            String::from_utf8_lossy(body),
+        ),
+    }
+}
@@ -0,0 +1,235 @@
+//! Export the metadata of a repository, for backups and migrations.
+//!
+//! The labels, milestones, issues, pull requests, issue comments and releases of a
+//! repository are requested with every page, either all at once into a
+//! `RepoSnapshot`, or kind by kind as a stream of `ExportRecord`s.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use std::io::Write;
+//!
+//! use futures::StreamExt;
+//!
+//! let exporter = github.export("owner", "repo");
+//!
+//! // Write one JSON record per line.
+//! let mut out = std::fs::File::create("backup.jsonl")?;
+//! let mut records = Box::pin(exporter.records());
+//! while let Some(record) = records.next().await {
+//!     serde_json::to_writer(&mut out, &record?)?;
+//!     out.write_all(b"\n")?;
+//! }
+//!
+//! // Or keep everything in a single JSON document.
+//! let snapshot = exporter.snapshot().await?;
+//! serde_json::to_writer(std::fs::File::create("backup.json")?, &snapshot)?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream, StreamExt};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{
+        IssueComment, IssueSimple, IssuesListMilestonesSort, IssuesListSort, IssuesListState,
+        Label, Milestone, Order, PullRequestSimple, PullsListSort, Release, Sort,
+    },
+    Client,
+};
+
+/// The metadata of a repository, as of `exported_at`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RepoSnapshot {
+    pub owner: String,
+    pub repo: String,
+    pub exported_at: DateTime<Utc>,
+    pub labels: Vec<Label>,
+    pub milestones: Vec<Milestone>,
+    /// The issues, without the pull requests.
+    pub issues: Vec<IssueSimple>,
+    pub pull_requests: Vec<PullRequestSimple>,
+    /// The comments of the issues and pull requests.
+    pub comments: Vec<IssueComment>,
+    pub releases: Vec<Release>,
+}
+
+/// A single exported item, serialized as `{"type": "issue", "record": {...}}`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(tag = "type", content = "record", rename_all = "snake_case")]
+pub enum ExportRecord {
+    Label(Label),
+    Milestone(Milestone),
+    Issue(IssueSimple),
+    PullRequest(PullRequestSimple),
+    Comment(IssueComment),
+    Release(Release),
+}
+
+/// The kinds of items, in the order they are exported.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Labels,
+    Milestones,
+    Issues,
+    PullRequests,
+    Comments,
+    Releases,
+}
+
+const KINDS: [Kind; 6] = [
+    Kind::Labels,
+    Kind::Milestones,
+    Kind::Issues,
+    Kind::PullRequests,
+    Kind::Comments,
+    Kind::Releases,
+];
+
+/// Exports the metadata of a repository.
+pub struct Exporter {
+    client: Client,
+    owner: String,
+    repo: String,
+}
+
+impl Client {
+    /// Export the metadata of a repository.
+    pub fn export(&self, owner: &str, repo: &str) -> Exporter {
+        Exporter {
+            client: self.clone(),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+}
+
+impl Exporter {
+    /// Request everything and return it as a single snapshot.
+    pub async fn snapshot(&self) -> Result<RepoSnapshot> {
+        let exported_at = Utc::now();
+        Ok(RepoSnapshot {
+            owner: self.owner.to_string(),
+            repo: self.repo.to_string(),
+            exported_at,
+            labels: self.labels().await?,
+            milestones: self.milestones().await?,
+            issues: self.issues().await?,
+            pull_requests: self.pull_requests().await?,
+            comments: self.comments().await?,
+            releases: self.releases().await?,
+        })
+    }
+
+    /**
+     * Request the items kind by kind and return them as a stream of records, so
+     * that only the items of one kind are held in memory at a time.
+     *
+     * The stream ends after the first error.
+     */
+    pub fn records(&self) -> impl Stream<Item = Result<ExportRecord>> + '_ {
+        stream::unfold(Some(0), move |next: Option<usize>| async move {
+            let kind = *KINDS.get(next?)?;
+            match self.fetch(kind).await {
+                Ok(records) => Some((records.into_iter().map(Ok).collect(), next.map(|i| i + 1))),
+                Err(e) => Some((vec![Err(e)], None)),
+            }
+        })
+        .map(stream::iter)
+        .flatten()
+    }
+
+    async fn fetch(&self, kind: Kind) -> Result<Vec<ExportRecord>> {
+        Ok(match kind {
+            Kind::Labels => to_records(self.labels().await?, ExportRecord::Label),
+            Kind::Milestones => to_records(self.milestones().await?, ExportRecord::Milestone),
+            Kind::Issues => to_records(self.issues().await?, ExportRecord::Issue),
+            Kind::PullRequests => {
+                to_records(self.pull_requests().await?, ExportRecord::PullRequest)
+            }
+            Kind::Comments => to_records(self.comments().await?, ExportRecord::Comment),
+            Kind::Releases => to_records(self.releases().await?, ExportRecord::Release),
+        })
+    }
+
+    async fn labels(&self) -> Result<Vec<Label>> {
+        self.client
+            .issues()
+            .list_all_labels_for_repo(&self.owner, &self.repo)
+            .await
+    }
+
+    async fn milestones(&self) -> Result<Vec<Milestone>> {
+        self.client
+            .issues()
+            .list_all_milestones(
+                &self.owner,
+                &self.repo,
+                IssuesListState::All,
+                IssuesListMilestonesSort::DueOn,
+                Order::Asc,
+            )
+            .await
+    }
+
+    async fn issues(&self) -> Result<Vec<IssueSimple>> {
+        let issues = self
+            .client
+            .issues()
+            .list_all_for_repo(
+                &self.owner,
+                &self.repo,
+                "",
+                IssuesListState::All,
+                "",
+                "",
+                "",
+                "",
+                IssuesListSort::Created,
+                Order::Asc,
+                None,
+            )
+            .await?;
+
+        // The issues endpoint returns the pull requests too.
+        Ok(issues
+            .into_iter()
+            .filter(|issue| issue.pull_request.is_none())
+            .collect())
+    }
+
+    async fn pull_requests(&self) -> Result<Vec<PullRequestSimple>> {
+        self.client
+            .pulls()
+            .list_all(
+                &self.owner,
+                &self.repo,
+                IssuesListState::All,
+                "",
+                "",
+                PullsListSort::Created,
+                Order::Asc,
+            )
+            .await
+    }
+
+    async fn comments(&self) -> Result<Vec<IssueComment>> {
+        self.client
+            .issues()
+            .list_all_comments_for_repo(&self.owner, &self.repo, Sort::Created, Order::Asc, None)
+            .await
+    }
+
+    async fn releases(&self) -> Result<Vec<Release>> {
+        self.client
+            .repos()
+            .list_all_releases(&self.owner, &self.repo)
+            .await
+    }
+}
+
+fn to_records<T>(items: Vec<T>, record: fn(T) -> ExportRecord) -> Vec<ExportRecord> {
+    items.into_iter().map(record).collect()
+}
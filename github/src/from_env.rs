@@ -0,0 +1,37 @@
+//! Create clients from environment variables, the same way for every provider.
+//!
+//! Each client knows the variables it reads, see the implementation of `FromEnv`
+//! for `Client`. An app using the clients of several providers can create them all
+//! alike:
+//!
+//! ```no_run
+//! # fn run() -> anyhow::Result<()> {
+//! use octorust::from_env::FromEnv;
+//!
+//! let client = octorust::Client::from_env()?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, Result};
+
+/// A client which can be created from environment variables.
+pub trait FromEnv: Sized {
+    /// The names of the environment variables the client is created from, the
+    /// required ones first.
+    fn env_vars() -> &'static [&'static str];
+
+    /// Create a client from the environment, failing when a required variable is
+    /// not set.
+    fn from_env() -> Result<Self>;
+}
+
+/// The value of a required environment variable.
+pub(crate) fn required(name: &str) -> Result<String> {
+    std::env::var(name).map_err(|_| anyhow!("{} must be set", name))
+}
+
+/// The value of an optional environment variable, empty when it is not set.
+#[allow(dead_code)]
+pub(crate) fn optional(name: &str) -> String {
+    std::env::var(name).unwrap_or_default()
+}
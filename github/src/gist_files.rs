@@ -0,0 +1,112 @@
+//! Fetch the whole content of the files of a gist.
+//!
+//! The API gives the content of a gist file up to one megabyte, and sets
+//! `truncated` for larger files. Their whole content is then fetched from their
+//! `raw_url`, which serves files up to ten megabytes. Larger files can only be
+//! cloned with git, from the `git_pull_url` of the gist.
+//!
+//! https://docs.github.com/en/rest/reference/gists#truncation
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+use crate::{
+    gists::Gists,
+    types::{FilesAdditionalProperties, GistId},
+};
+
+/// The files of a gist, by file name.
+#[derive(Deserialize)]
+struct GistFiles {
+    #[serde(default)]
+    files: HashMap<String, Option<FilesAdditionalProperties>>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+impl Gists {
+    /**
+     * Get the whole content of a file of a gist.
+     *
+     * When the content given by the API is truncated, it is fetched from the
+     * `raw_url` of the file, with the credentials of the client, so this works for
+     * secret gists too. Fails when the file is too large to be fetched whole.
+     *
+     * **Parameters:**
+     *
+     * * `gist_id: &str` -- gist_id parameter.
+     * * `filename: &str` -- The name of the file.
+     */
+    pub async fn get_file_bytes(
+        &self,
+        gist_id: impl Into<GistId>,
+        filename: &str,
+    ) -> Result<bytes::Bytes> {
+        let gist_id: GistId = gist_id.into();
+        let url = format!(
+            "/gists/{}",
+            crate::progenitor_support::encode_path(&gist_id),
+        );
+        let mut gist: GistFiles = self.client.get(&url, None).await?;
+
+        let file = match gist.files.remove(filename).flatten() {
+            Some(file) => file,
+            // Only the first 300 files of a gist are listed.
+            None if gist.truncated => {
+                return Err(anyhow!(
+                    "gist {} has too many files to list `{}`, clone it instead",
+                    gist_id,
+                    filename
+                ))
+            }
+            None => return Err(anyhow!("gist {} has no file `{}`", gist_id, filename)),
+        };
+        if !file.truncated {
+            return Ok(bytes::Bytes::from(file.content));
+        }
+
+        let resp = self
+            .client
+            .request_raw(
+                http::Method::GET,
+                &file.raw_url,
+                None,
+                crate::utils::MediaType::Raw,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+                Default::default(),
+            )
+            .await?;
+        let content = resp.bytes().await?;
+        if (content.len() as i64) < file.size {
+            return Err(anyhow!(
+                "file `{}` of gist {} is truncated to {} of its {} bytes, clone the gist instead",
+                filename,
+                gist_id,
+                content.len(),
+                file.size
+            ));
+        }
+
+        Ok(content)
+    }
+
+    /**
+     * Get the whole content of a text file of a gist.
+     *
+     * Like `get_file_bytes`, but fails when the content is not valid UTF-8.
+     *
+     * **Parameters:**
+     *
+     * * `gist_id: &str` -- gist_id parameter.
+     * * `filename: &str` -- The name of the file.
+     */
+    pub async fn get_file_content(
+        &self,
+        gist_id: impl Into<GistId>,
+        filename: &str,
+    ) -> Result<String> {
+        let content = self.get_file_bytes(gist_id, filename).await?;
+        Ok(String::from_utf8(content.to_vec())?)
+    }
+}
@@ -33,18 +33,17 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gists"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -64,12 +63,11 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gists"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -118,18 +116,17 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/public?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gists/public"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -151,12 +148,11 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/public?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gists/public"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -182,18 +178,17 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/starred?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gists/starred"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -213,12 +208,11 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/starred?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/gists/starred"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -236,10 +230,14 @@ impl Gists {
     *
     * * `gist_id: &str` -- gist_id parameter.
     */
-    pub async fn get(&self, gist_id: &str) -> Result<crate::types::GistSimple> {
+    pub async fn get(
+        &self,
+        gist_id: impl Into<crate::types::GistId>,
+    ) -> Result<crate::types::GistSimple> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.get(&url, None).await
@@ -258,10 +256,11 @@ impl Gists {
     *
     * * `gist_id: &str` -- gist_id parameter.
     */
-    pub async fn delete(&self, gist_id: &str) -> Result<()> {
+    pub async fn delete(&self, gist_id: impl Into<crate::types::GistId>) -> Result<()> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.delete(&url, None).await
@@ -282,12 +281,13 @@ impl Gists {
     */
     pub async fn update(
         &self,
-        gist_id: &str,
+        gist_id: impl Into<crate::types::GistId>,
         body: &crate::types::GistsUpdateRequest,
     ) -> Result<crate::types::GistSimple> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client
@@ -312,22 +312,24 @@ impl Gists {
     */
     pub async fn list_comments(
         &self,
-        gist_id: &str,
+        gist_id: impl Into<crate::types::GistId>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GistComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let gist_id: crate::types::GistId = gist_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/gists/{}/comments?{}",
-            crate::progenitor_support::encode_path(gist_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/gists/{}/comments",
+                crate::progenitor_support::encode_path(&gist_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -344,10 +346,14 @@ impl Gists {
     *
     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
     */
-    pub async fn list_all_comments(&self, gist_id: &str) -> Result<Vec<crate::types::GistComment>> {
+    pub async fn list_all_comments(
+        &self,
+        gist_id: impl Into<crate::types::GistId>,
+    ) -> Result<Vec<crate::types::GistComment>> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/comments",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -368,12 +374,13 @@ impl Gists {
     */
     pub async fn create_comment(
         &self,
-        gist_id: &str,
+        gist_id: impl Into<crate::types::GistId>,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::GistComment> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/comments",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client
@@ -397,13 +404,15 @@ impl Gists {
     */
     pub async fn get_comment(
         &self,
-        gist_id: &str,
-        comment_id: i64,
+        gist_id: impl Into<crate::types::GistId>,
+        comment_id: impl Into<crate::types::CommentId>,
     ) -> Result<crate::types::GistComment> {
+        let gist_id: crate::types::GistId = gist_id.into();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/gists/{}/comments/{}",
-            crate::progenitor_support::encode_path(gist_id),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&gist_id),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.get(&url, None).await
@@ -423,11 +432,17 @@ impl Gists {
     * * `gist_id: &str` -- gist_id parameter.
     * * `comment_id: i64` -- comment_id parameter.
     */
-    pub async fn delete_comment(&self, gist_id: &str, comment_id: i64) -> Result<()> {
+    pub async fn delete_comment(
+        &self,
+        gist_id: impl Into<crate::types::GistId>,
+        comment_id: impl Into<crate::types::CommentId>,
+    ) -> Result<()> {
+        let gist_id: crate::types::GistId = gist_id.into();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/gists/{}/comments/{}",
-            crate::progenitor_support::encode_path(gist_id),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&gist_id),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.delete(&url, None).await
@@ -449,14 +464,16 @@ impl Gists {
     */
     pub async fn update_comment(
         &self,
-        gist_id: &str,
-        comment_id: i64,
+        gist_id: impl Into<crate::types::GistId>,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::GistComment> {
+        let gist_id: crate::types::GistId = gist_id.into();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/gists/{}/comments/{}",
-            crate::progenitor_support::encode_path(gist_id),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&gist_id),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -481,22 +498,24 @@ impl Gists {
     */
     pub async fn list_commits(
         &self,
-        gist_id: &str,
+        gist_id: impl Into<crate::types::GistId>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GistCommit>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let gist_id: crate::types::GistId = gist_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/gists/{}/commits?{}",
-            crate::progenitor_support::encode_path(gist_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/gists/{}/commits",
+                crate::progenitor_support::encode_path(&gist_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -513,10 +532,14 @@ impl Gists {
     *
     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
     */
-    pub async fn list_all_commits(&self, gist_id: &str) -> Result<Vec<crate::types::GistCommit>> {
+    pub async fn list_all_commits(
+        &self,
+        gist_id: impl Into<crate::types::GistId>,
+    ) -> Result<Vec<crate::types::GistCommit>> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/commits",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -539,22 +562,24 @@ impl Gists {
     */
     pub async fn list_forks(
         &self,
-        gist_id: &str,
+        gist_id: impl Into<crate::types::GistId>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GistSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let gist_id: crate::types::GistId = gist_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/gists/{}/forks?{}",
-            crate::progenitor_support::encode_path(gist_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/gists/{}/forks",
+                crate::progenitor_support::encode_path(&gist_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -571,10 +596,14 @@ impl Gists {
     *
     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
     */
-    pub async fn list_all_forks(&self, gist_id: &str) -> Result<Vec<crate::types::GistSimple>> {
+    pub async fn list_all_forks(
+        &self,
+        gist_id: impl Into<crate::types::GistId>,
+    ) -> Result<Vec<crate::types::GistSimple>> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/forks",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -593,10 +622,14 @@ impl Gists {
     *
     * * `gist_id: &str` -- gist_id parameter.
     */
-    pub async fn fork(&self, gist_id: &str) -> Result<crate::types::BaseGist> {
+    pub async fn fork(
+        &self,
+        gist_id: impl Into<crate::types::GistId>,
+    ) -> Result<crate::types::BaseGist> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/forks",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.post(&url, None).await
@@ -607,6 +640,8 @@ impl Gists {
     *
     * This function performs a `GET` to the `/gists/{gist_id}/star` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/gists#check-if-a-gist-is-starred>
@@ -615,13 +650,14 @@ impl Gists {
     *
     * * `gist_id: &str` -- gist_id parameter.
     */
-    pub async fn check_is_starred(&self, gist_id: &str) -> Result<()> {
+    pub async fn check_is_starred(&self, gist_id: impl Into<crate::types::GistId>) -> Result<bool> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/star",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -637,10 +673,11 @@ impl Gists {
     *
     * * `gist_id: &str` -- gist_id parameter.
     */
-    pub async fn star(&self, gist_id: &str) -> Result<()> {
+    pub async fn star(&self, gist_id: impl Into<crate::types::GistId>) -> Result<()> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/star",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.put(&url, None).await
@@ -659,10 +696,11 @@ impl Gists {
     *
     * * `gist_id: &str` -- gist_id parameter.
     */
-    pub async fn unstar(&self, gist_id: &str) -> Result<()> {
+    pub async fn unstar(&self, gist_id: impl Into<crate::types::GistId>) -> Result<()> {
+        let gist_id: crate::types::GistId = gist_id.into();
         let url = format!(
             "/gists/{}/star",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
         );
 
         self.client.delete(&url, None).await
@@ -682,10 +720,16 @@ impl Gists {
     * * `gist_id: &str` -- gist_id parameter.
     * * `sha: &str`
     */
-    pub async fn get_revision(&self, gist_id: &str, sha: &str) -> Result<crate::types::GistSimple> {
+    pub async fn get_revision(
+        &self,
+        gist_id: impl Into<crate::types::GistId>,
+        sha: impl AsRef<str>,
+    ) -> Result<crate::types::GistSimple> {
+        let gist_id: crate::types::GistId = gist_id.into();
+        let sha = sha.as_ref();
         let url = format!(
             "/gists/{}/{}",
-            crate::progenitor_support::encode_path(gist_id),
+            crate::progenitor_support::encode_path(&gist_id),
             crate::progenitor_support::encode_path(sha),
         );
 
@@ -710,26 +754,28 @@ impl Gists {
     */
     pub async fn list_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         since: Option<chrono::DateTime<chrono::Utc>>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/gists?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/gists",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -748,18 +794,20 @@ impl Gists {
     */
     pub async fn list_all_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/gists?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/gists",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::commit_builder::CommitBuilder;
 use crate::Client;
 
 pub struct Git {
@@ -28,10 +29,12 @@ impl Git {
     */
     pub async fn create_blob(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::GitCreateBlobRequest,
     ) -> Result<crate::types::Tree> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/git/blobs",
             crate::progenitor_support::encode_path(owner),
@@ -62,10 +65,13 @@ impl Git {
     */
     pub async fn get_blob(
         &self,
-        owner: &str,
-        repo: &str,
-        file_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        file_sha: impl AsRef<str>,
     ) -> Result<crate::types::Blob> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let file_sha = file_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/git/blobs/{}",
             crate::progenitor_support::encode_path(owner),
@@ -121,10 +127,12 @@ impl Git {
     */
     pub async fn create_commit(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::GitCreateCommitRequest,
     ) -> Result<crate::types::GitCommit> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/git/commits",
             crate::progenitor_support::encode_path(owner),
@@ -182,10 +190,13 @@ impl Git {
     */
     pub async fn get_commit(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
     ) -> Result<crate::types::GitCommit> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/git/commits/{}",
             crate::progenitor_support::encode_path(owner),
@@ -221,26 +232,30 @@ impl Git {
     */
     pub async fn list_matching_refs(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GitRef>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/git/matching-refs/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(ref_),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/git/matching-refs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -265,10 +280,13 @@ impl Git {
     */
     pub async fn list_all_matching_refs(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
     ) -> Result<Vec<crate::types::GitRef>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
         let url = format!(
             "/repos/{}/{}/git/matching-refs/{}",
             crate::progenitor_support::encode_path(owner),
@@ -298,10 +316,13 @@ impl Git {
     */
     pub async fn get_ref(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
     ) -> Result<crate::types::GitRef> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
         let url = format!(
             "/repos/{}/{}/git/ref/{}",
             crate::progenitor_support::encode_path(owner),
@@ -328,10 +349,12 @@ impl Git {
     */
     pub async fn create_ref(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::GitCreateRefRequest,
     ) -> Result<crate::types::GitRef> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/git/refs",
             crate::progenitor_support::encode_path(owner),
@@ -358,7 +381,15 @@ impl Git {
     * * `repo: &str`
     * * `ref_: &str` -- ref parameter.
     */
-    pub async fn delete_ref(&self, owner: &str, repo: &str, ref_: &str) -> Result<()> {
+    pub async fn delete_ref(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
         let url = format!(
             "/repos/{}/{}/git/refs/{}",
             crate::progenitor_support::encode_path(owner),
@@ -386,11 +417,14 @@ impl Git {
     */
     pub async fn update_ref(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
         body: &crate::types::GitUpdateRefRequest,
     ) -> Result<crate::types::GitRef> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
         let url = format!(
             "/repos/{}/{}/git/refs/{}",
             crate::progenitor_support::encode_path(owner),
@@ -448,10 +482,12 @@ impl Git {
     */
     pub async fn create_tag(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::GitCreateTagRequest,
     ) -> Result<crate::types::GitTag> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/git/tags",
             crate::progenitor_support::encode_path(owner),
@@ -507,10 +543,13 @@ impl Git {
     */
     pub async fn get_tag(
         &self,
-        owner: &str,
-        repo: &str,
-        tag_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        tag_sha: impl AsRef<str>,
     ) -> Result<crate::types::GitTag> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let tag_sha = tag_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/git/tags/{}",
             crate::progenitor_support::encode_path(owner),
@@ -539,10 +578,12 @@ impl Git {
     */
     pub async fn create_tree(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::GitCreateTreeRequestData,
     ) -> Result<crate::types::GitTreeData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/git/trees",
             crate::progenitor_support::encode_path(owner),
@@ -574,22 +615,26 @@ impl Git {
     */
     pub async fn get_tree(
         &self,
-        owner: &str,
-        repo: &str,
-        tree_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        tree_sha: impl AsRef<str>,
         recursive: &str,
     ) -> Result<crate::types::GitTreeData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let tree_sha = tree_sha.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !recursive.is_empty() {
-            query_args.push(("recursive".to_string(), recursive.to_string()));
+            query_args.push(("recursive", recursive.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/git/trees/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(tree_sha),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/git/trees/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(tree_sha),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
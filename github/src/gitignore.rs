@@ -56,7 +56,11 @@ impl Gitignore {
     *
     * * `name: &str`
     */
-    pub async fn get_template(&self, name: &str) -> Result<crate::types::GitignoreTemplate> {
+    pub async fn get_template(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<crate::types::GitignoreTemplate> {
+        let name = name.as_ref();
         let url = format!(
             "/gitignore/templates/{}",
             crate::progenitor_support::encode_path(name),
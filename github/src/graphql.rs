@@ -0,0 +1,155 @@
+//! For making requests to the GitHub GraphQL API.
+//!
+//! https://docs.github.com/en/graphql
+use anyhow::{anyhow, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::Client;
+
+pub struct Graphql {
+    pub client: Client,
+}
+
+impl Client {
+    /// Make requests to the GraphQL API, using the same credentials as the rest of the client.
+    pub fn graphql(&self) -> Graphql {
+        Graphql::new(self.clone())
+    }
+}
+
+/// An error returned by the GraphQL API alongside, or instead of, the data.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GraphqlError {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    #[serde(default, rename = "type", skip_serializing_if = "String::is_empty")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct GraphqlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+/// Information about the current page of a connection.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    #[serde(default)]
+    pub has_next_page: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_cursor: Option<String>,
+}
+
+/// A page of a connection, as selected with `nodes` and `pageInfo`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Connection<T> {
+    #[serde(default = "Vec::new")]
+    pub nodes: Vec<T>,
+    #[serde(default)]
+    pub page_info: PageInfo,
+}
+
+impl Graphql {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Graphql { client }
+    }
+
+    /// The GraphQL endpoint for the host of the client. GitHub Enterprise Server
+    /// serves the REST API from `/api/v3` and the GraphQL API from `/api/graphql`.
+    fn url(&self) -> String {
+        let host = self.client.host.trim_end_matches('/');
+        match host.strip_suffix("/api/v3") {
+            Some(base) => format!("{}/api/graphql", base),
+            None => format!("{}/graphql", host),
+        }
+    }
+
+    /**
+     * Run a query or mutation against the GraphQL API.
+     *
+     * Returns the `data` of the response, deserialized as `T`. If the response contains
+     * any errors, they are returned as an error instead.
+     */
+    pub async fn query<T>(&self, query: &str, variables: serde_json::Value) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let body = serde_json::json!({
+            "query": query,
+            "variables": variables,
+        });
+
+        let resp: GraphqlResponse<T> = self
+            .client
+            .request_entity(
+                http::Method::POST,
+                &self.url(),
+                Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+
+        if !resp.errors.is_empty() {
+            let messages: Vec<String> = resp.errors.into_iter().map(|e| e.message).collect();
+            return Err(anyhow!("graphql errors: {}", messages.join("; ")));
+        }
+
+        resp.data
+            .ok_or_else(|| anyhow!("graphql response did not contain any data"))
+    }
+
+    /**
+     * Run a query against the GraphQL API and collect every node of a connection,
+     * following the cursors until there are no pages left.
+     *
+     * The query must declare a `$cursor: String` variable and pass it as the `after`
+     * argument of the connection, which must select `nodes` and
+     * `pageInfo { hasNextPage endCursor }`. `path` is the list of fields leading
+     * from `data` to the connection, for example `["repository", "discussions"]`.
+     */
+    pub async fn query_all<T>(
+        &self,
+        query: &str,
+        mut variables: serde_json::Value,
+        path: &[&str],
+    ) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned + 'static + Send,
+    {
+        if !variables.is_object() {
+            variables = serde_json::json!({});
+        }
+
+        let mut global_items = Vec::new();
+        loop {
+            let data: serde_json::Value = self.query(query, variables.clone()).await?;
+
+            let mut connection = &data;
+            for field in path {
+                connection = connection
+                    .get(field)
+                    .ok_or_else(|| anyhow!("graphql response has no field `{}`", field))?;
+            }
+            let mut page: Connection<T> = serde_json::from_value(connection.clone())?;
+            global_items.append(&mut page.nodes);
+
+            match page.page_info.end_cursor {
+                Some(cursor) if page.page_info.has_next_page => {
+                    variables["cursor"] = serde_json::Value::String(cursor);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(global_items)
+    }
+}
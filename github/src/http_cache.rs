@@ -1,12 +1,13 @@
 //! Implements <https://tools.ietf.org/html/rfc7232> Conditional Requests.
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, HashMap},
     ffi::OsStr,
     fmt::Debug,
     fs,
     hash::{Hash, Hasher},
     io,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 
 use anyhow::{Error, Result};
@@ -39,6 +40,10 @@ impl dyn HttpCache {
         dir.push(".github/cache");
         Box::new(FileBasedCache::new(dir))
     }
+
+    pub fn in_memory() -> BoxedHttpCache {
+        Box::new(InMemoryCache::default())
+    }
 }
 
 impl Clone for BoxedHttpCache {
@@ -66,6 +71,77 @@ impl HttpCache for NoCache {
     }
 }
 
+/// An in-memory cache.
+///
+/// Clones share the same entries, so a single cache can back several clients
+/// polling the same endpoints.
+///
+/// # Examples
+///
+/// ```
+/// # use octorust::http_cache::{HttpCache, InMemoryCache};
+/// let cache = InMemoryCache::default();
+/// let uri = "https://api.github.com/gists/aa5a315d61ae9438b18d";
+/// assert!(cache.lookup_etag(uri).is_err());
+///
+/// cache
+///     .cache_response(uri, b"{}", b"W/\"abc\"", &None)
+///     .unwrap();
+/// assert_eq!(cache.lookup_etag(uri).unwrap(), "W/\"abc\"");
+/// assert_eq!(cache.lookup_body(uri).unwrap(), "{}");
+/// assert_eq!(cache.lookup_next_link(uri).unwrap(), None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCache {
+    entries: Arc<Mutex<HashMap<String, CachedResponse>>>,
+}
+
+#[derive(Clone, Debug)]
+struct CachedResponse {
+    body: String,
+    etag: String,
+    next_link: Option<String>,
+}
+
+impl InMemoryCache {
+    fn lookup<T>(&self, uri: &str, f: impl FnOnce(&CachedResponse) -> T) -> Result<T> {
+        match self.entries.lock().unwrap().get(uri) {
+            Some(entry) => Ok(f(entry)),
+            None => no_read(format!("Nothing cached for {}", uri)),
+        }
+    }
+}
+
+impl HttpCache for InMemoryCache {
+    fn cache_response(
+        &self,
+        uri: &str,
+        body: &[u8],
+        etag: &[u8],
+        next_link: &Option<String>,
+    ) -> Result<()> {
+        let entry = CachedResponse {
+            body: String::from_utf8(body.to_vec())?,
+            etag: String::from_utf8(etag.to_vec())?,
+            next_link: next_link.clone(),
+        };
+        self.entries.lock().unwrap().insert(uri.to_string(), entry);
+        Ok(())
+    }
+
+    fn lookup_etag(&self, uri: &str) -> Result<String> {
+        self.lookup(uri, |entry| entry.etag.clone())
+    }
+
+    fn lookup_body(&self, uri: &str) -> Result<String> {
+        self.lookup(uri, |entry| entry.body.clone())
+    }
+
+    fn lookup_next_link(&self, uri: &str) -> Result<Option<String>> {
+        self.lookup(uri, |entry| entry.next_link.clone())
+    }
+}
+
 /// A file based cache.
 #[derive(Clone, Debug)]
 pub struct FileBasedCache {
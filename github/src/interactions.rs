@@ -27,8 +27,9 @@ impl Interactions {
     */
     pub async fn get_restrictions_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/interaction-limits",
             crate::progenitor_support::encode_path(org),
@@ -52,9 +53,10 @@ impl Interactions {
     */
     pub async fn set_restrictions_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::InteractionLimit,
     ) -> Result<crate::types::InteractionLimits> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/interaction-limits",
             crate::progenitor_support::encode_path(org),
@@ -78,7 +80,8 @@ impl Interactions {
     *
     * * `org: &str`
     */
-    pub async fn remove_restrictions_for_org(&self, org: &str) -> Result<()> {
+    pub async fn remove_restrictions_for_org(&self, org: impl AsRef<str>) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/interaction-limits",
             crate::progenitor_support::encode_path(org),
@@ -103,9 +106,11 @@ impl Interactions {
     */
     pub async fn get_restrictions_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/interaction-limits",
             crate::progenitor_support::encode_path(owner),
@@ -131,10 +136,12 @@ impl Interactions {
     */
     pub async fn set_restrictions_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::InteractionLimit,
     ) -> Result<crate::types::InteractionLimits> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/interaction-limits",
             crate::progenitor_support::encode_path(owner),
@@ -160,7 +167,13 @@ impl Interactions {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn remove_restrictions_for_repo(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn remove_restrictions_for_repo(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/interaction-limits",
             crate::progenitor_support::encode_path(owner),
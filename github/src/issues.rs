@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::issues_bulk::{BulkFailure, BulkResult, NewIssue, MUTATION_INTERVAL};
 use crate::Client;
 
 pub struct Issues {
@@ -66,45 +67,44 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Issue>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collab {
-            query_args.push(("collab".to_string(), collab.to_string()));
+            query_args.push(("collab", collab.to_string()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if orgs {
-            query_args.push(("orgs".to_string(), orgs.to_string()));
+            query_args.push(("orgs", orgs.to_string()));
         }
         if owned {
-            query_args.push(("owned".to_string(), owned.to_string()));
+            query_args.push(("owned", owned.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if pulls {
-            query_args.push(("pulls".to_string(), pulls.to_string()));
+            query_args.push(("pulls", pulls.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/issues?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/issues"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -141,39 +141,38 @@ impl Issues {
         owned: bool,
         pulls: bool,
     ) -> Result<Vec<crate::types::Issue>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collab {
-            query_args.push(("collab".to_string(), collab.to_string()));
+            query_args.push(("collab", collab.to_string()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if orgs {
-            query_args.push(("orgs".to_string(), orgs.to_string()));
+            query_args.push(("orgs", orgs.to_string()));
         }
         if owned {
-            query_args.push(("owned".to_string(), owned.to_string()));
+            query_args.push(("owned", owned.to_string()));
         }
         if pulls {
-            query_args.push(("pulls".to_string(), pulls.to_string()));
+            query_args.push(("pulls", pulls.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/issues?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/issues"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -213,7 +212,7 @@ impl Issues {
     */
     pub async fn list_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         filter: crate::types::Filter,
         state: crate::types::IssuesListState,
         labels: &str,
@@ -223,36 +222,38 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Issue>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/issues?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+            query_args.push(("state", state.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/issues",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -276,7 +277,7 @@ impl Issues {
     */
     pub async fn list_all_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         filter: crate::types::Filter,
         state: crate::types::IssuesListState,
         labels: &str,
@@ -284,30 +285,32 @@ impl Issues {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Issue>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/issues?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+            query_args.push(("state", state.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/issues",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -331,24 +334,27 @@ impl Issues {
     */
     pub async fn list_assignees(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/assignees?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/assignees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -367,9 +373,11 @@ impl Issues {
     */
     pub async fn list_all_assignees(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/assignees",
             crate::progenitor_support::encode_path(owner),
@@ -384,6 +392,8 @@ impl Issues {
     *
     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees/{assignee}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     * Checks if a user has permission to be assigned to an issue in this repository.
     *
     * If the `assignee` can be assigned to issues in the repository, a `204` header with no content is returned.
@@ -400,10 +410,13 @@ impl Issues {
     */
     pub async fn check_user_can_be_assigned(
         &self,
-        owner: &str,
-        repo: &str,
-        assignee: &str,
-    ) -> Result<()> {
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        assignee: impl AsRef<str>,
+    ) -> Result<bool> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let assignee = assignee.as_ref();
         let url = format!(
             "/repos/{}/{}/assignees/{}",
             crate::progenitor_support::encode_path(owner),
@@ -411,7 +424,7 @@ impl Issues {
             crate::progenitor_support::encode_path(assignee),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -448,8 +461,8 @@ impl Issues {
     */
     pub async fn list_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         milestone: &str,
         state: crate::types::IssuesListState,
         assignee: &str,
@@ -462,46 +475,49 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::IssueSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignee.is_empty() {
-            query_args.push(("assignee".to_string(), assignee.to_string()));
+            query_args.push(("assignee", assignee.to_string()));
         }
         if !creator.is_empty() {
-            query_args.push(("creator".to_string(), creator.to_string()));
+            query_args.push(("creator", creator.to_string()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if !mentioned.is_empty() {
-            query_args.push(("mentioned".to_string(), mentioned.to_string()));
+            query_args.push(("mentioned", mentioned.to_string()));
         }
         if !milestone.is_empty() {
-            query_args.push(("milestone".to_string(), milestone.to_string()));
+            query_args.push(("milestone", milestone.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("state", state.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -525,8 +541,8 @@ impl Issues {
     */
     pub async fn list_all_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         milestone: &str,
         state: crate::types::IssuesListState,
         assignee: &str,
@@ -537,40 +553,43 @@ impl Issues {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::IssueSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignee.is_empty() {
-            query_args.push(("assignee".to_string(), assignee.to_string()));
+            query_args.push(("assignee", assignee.to_string()));
         }
         if !creator.is_empty() {
-            query_args.push(("creator".to_string(), creator.to_string()));
+            query_args.push(("creator", creator.to_string()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if !mentioned.is_empty() {
-            query_args.push(("mentioned".to_string(), mentioned.to_string()));
+            query_args.push(("mentioned", mentioned.to_string()));
         }
         if !milestone.is_empty() {
-            query_args.push(("milestone".to_string(), milestone.to_string()));
+            query_args.push(("milestone", milestone.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("state", state.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -594,10 +613,12 @@ impl Issues {
     */
     pub async fn create(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::IssuesCreateRequest,
     ) -> Result<crate::types::Issue> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues",
             crate::progenitor_support::encode_path(owner),
@@ -632,36 +653,39 @@ impl Issues {
     */
     pub async fn list_comments_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sort: crate::types::Sort,
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::IssueComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("sort", sort.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -680,28 +704,31 @@ impl Issues {
     */
     pub async fn list_all_comments_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sort: crate::types::Sort,
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::IssueComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("sort", sort.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -724,15 +751,18 @@ impl Issues {
     */
     pub async fn get_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
     ) -> Result<crate::types::IssueComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/issues/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.get(&url, None).await
@@ -753,12 +783,20 @@ impl Issues {
     * * `repo: &str`
     * * `comment_id: i64` -- comment_id parameter.
     */
-    pub async fn delete_comment(&self, owner: &str, repo: &str, comment_id: i64) -> Result<()> {
+    pub async fn delete_comment(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/issues/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.delete(&url, None).await
@@ -781,16 +819,19 @@ impl Issues {
     */
     pub async fn update_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::IssueComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/issues/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -816,24 +857,27 @@ impl Issues {
     */
     pub async fn list_events_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::IssueEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/events?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -852,9 +896,11 @@ impl Issues {
     */
     pub async fn list_all_events_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::IssueEvent>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/events",
             crate::progenitor_support::encode_path(owner),
@@ -881,15 +927,17 @@ impl Issues {
     */
     pub async fn get_event(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         event_id: i64,
     ) -> Result<crate::types::IssueEvent> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/events/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            crate::progenitor_support::encode_path(&event_id),
         );
 
         self.client.get(&url, None).await
@@ -922,15 +970,17 @@ impl Issues {
     */
     pub async fn get(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
     ) -> Result<crate::types::Issue> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client.get(&url, None).await
@@ -953,16 +1003,18 @@ impl Issues {
     */
     pub async fn update(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::IssuesUpdateRequest,
     ) -> Result<crate::types::Issue> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -987,16 +1039,18 @@ impl Issues {
     */
     pub async fn add_assignees(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::IssuesAddAssigneesRequest,
     ) -> Result<crate::types::IssueSimple> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/assignees",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -1021,16 +1075,18 @@ impl Issues {
     */
     pub async fn remove_assignees(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::IssuesAddAssigneesRequest,
     ) -> Result<crate::types::IssueSimple> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/assignees",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -1058,30 +1114,33 @@ impl Issues {
     */
     pub async fn list_comments(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         since: Option<chrono::DateTime<chrono::Utc>>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::IssueComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/{}/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            query_
+            query_args.push(("since", date.to_rfc3339()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1100,22 +1159,25 @@ impl Issues {
     */
     pub async fn list_all_comments(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::IssueComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/{}/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            query_
+            query_args.push(("since", date.to_rfc3339()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1138,16 +1200,18 @@ impl Issues {
     */
     pub async fn create_comment(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::IssueComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/comments",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -1174,26 +1238,29 @@ impl Issues {
     */
     pub async fn list_events(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::IssueEventAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/{}/events?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            query_
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1212,15 +1279,17 @@ impl Issues {
     */
     pub async fn list_all_events(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
     ) -> Result<Vec<crate::types::IssueEventAnyOf>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/events",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1245,26 +1314,29 @@ impl Issues {
     */
     pub async fn list_labels_on_issue(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Label>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/{}/labels?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            query_
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1283,15 +1355,17 @@ impl Issues {
     */
     pub async fn list_all_labels_on_issue(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
     ) -> Result<Vec<crate::types::Label>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/labels",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1314,16 +1388,18 @@ impl Issues {
     */
     pub async fn set_labels(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::IssuesSetLabelsRequestAnyOf,
     ) -> Result<Vec<crate::types::Label>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/labels",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -1348,16 +1424,18 @@ impl Issues {
     */
     pub async fn add_labels(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::IssuesAddLabelsRequestOneOf,
     ) -> Result<Vec<crate::types::Label>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/labels",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -1382,15 +1460,17 @@ impl Issues {
     */
     pub async fn remove_all_labels(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/labels",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client.delete(&url, None).await
@@ -1414,16 +1494,19 @@ impl Issues {
     */
     pub async fn remove_label(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
-        name: &str,
+        name: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Label>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let name = name.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/labels/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
             crate::progenitor_support::encode_path(name),
         );
 
@@ -1449,16 +1532,18 @@ impl Issues {
     */
     pub async fn lock(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::IssuesLockRequest,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/lock",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -1481,12 +1566,19 @@ impl Issues {
     * * `repo: &str`
     * * `issue_number: i64` -- issue_number parameter.
     */
-    pub async fn unlock(&self, owner: &str, repo: &str, issue_number: i64) -> Result<()> {
+    pub async fn unlock(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        issue_number: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/lock",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client.delete(&url, None).await
@@ -1511,26 +1603,29 @@ impl Issues {
     */
     pub async fn list_events_for_timeline(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Data>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/{}/timeline?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            query_
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/{}/timeline",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1549,15 +1644,17 @@ impl Issues {
     */
     pub async fn list_all_events_for_timeline(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
     ) -> Result<Vec<crate::types::Data>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/timeline",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1581,24 +1678,27 @@ impl Issues {
     */
     pub async fn list_labels_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Label>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/labels?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1617,9 +1717,11 @@ impl Issues {
     */
     pub async fn list_all_labels_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Label>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/labels",
             crate::progenitor_support::encode_path(owner),
@@ -1645,10 +1747,12 @@ impl Issues {
     */
     pub async fn create_label(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::IssuesCreateLabelRequest,
     ) -> Result<crate::types::Label> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/labels",
             crate::progenitor_support::encode_path(owner),
@@ -1677,10 +1781,13 @@ impl Issues {
     */
     pub async fn get_label(
         &self,
-        owner: &str,
-        repo: &str,
-        name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        name: impl AsRef<str>,
     ) -> Result<crate::types::Label> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let name = name.as_ref();
         let url = format!(
             "/repos/{}/{}/labels/{}",
             crate::progenitor_support::encode_path(owner),
@@ -1706,7 +1813,15 @@ impl Issues {
     * * `repo: &str`
     * * `name: &str`
     */
-    pub async fn delete_label(&self, owner: &str, repo: &str, name: &str) -> Result<()> {
+    pub async fn delete_label(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        name: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let name = name.as_ref();
         let url = format!(
             "/repos/{}/{}/labels/{}",
             crate::progenitor_support::encode_path(owner),
@@ -1734,11 +1849,14 @@ impl Issues {
     */
     pub async fn update_label(
         &self,
-        owner: &str,
-        repo: &str,
-        name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        name: impl AsRef<str>,
         body: &crate::types::IssuesUpdateLabelRequest,
     ) -> Result<crate::types::Label> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let name = name.as_ref();
         let url = format!(
             "/repos/{}/{}/labels/{}",
             crate::progenitor_support::encode_path(owner),
@@ -1774,36 +1892,39 @@ impl Issues {
     */
     pub async fn list_milestones(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         state: crate::types::IssuesListState,
         sort: crate::types::IssuesListMilestonesSort,
         direction: crate::types::Order,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Milestone>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/milestones?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("state", state.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/milestones",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1822,28 +1943,31 @@ impl Issues {
     */
     pub async fn list_all_milestones(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         state: crate::types::IssuesListState,
         sort: crate::types::IssuesListMilestonesSort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::Milestone>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/milestones?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+            query_args.push(("state", state.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/milestones",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1865,10 +1989,12 @@ impl Issues {
     */
     pub async fn create_milestone(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::IssuesCreateMilestoneRequest,
     ) -> Result<crate::types::Milestone> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/milestones",
             crate::progenitor_support::encode_path(owner),
@@ -1897,15 +2023,17 @@ impl Issues {
     */
     pub async fn get_milestone(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         milestone_number: i64,
     ) -> Result<crate::types::Milestone> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/milestones/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&milestone_number.to_string()),
+            crate::progenitor_support::encode_path(&milestone_number),
         );
 
         self.client.get(&url, None).await
@@ -1928,15 +2056,17 @@ impl Issues {
     */
     pub async fn delete_milestone(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         milestone_number: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/milestones/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&milestone_number.to_string()),
+            crate::progenitor_support::encode_path(&milestone_number),
         );
 
         self.client.delete(&url, None).await
@@ -1959,16 +2089,18 @@ impl Issues {
     */
     pub async fn update_milestone(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         milestone_number: i64,
         body: &crate::types::IssuesCreateMilestoneRequest,
     ) -> Result<crate::types::Milestone> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/milestones/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&milestone_number.to_string()),
+            crate::progenitor_support::encode_path(&milestone_number),
         );
 
         self.client
@@ -1995,26 +2127,29 @@ impl Issues {
     */
     pub async fn list_labels_for_milestone(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         milestone_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Label>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/milestones/{}/labels?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&milestone_number.to_string()),
-            query_
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/milestones/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2033,15 +2168,17 @@ impl Issues {
     */
     pub async fn list_all_labels_for_milestone(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         milestone_number: i64,
     ) -> Result<Vec<crate::types::Label>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/milestones/{}/labels",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&milestone_number.to_string()),
+            crate::progenitor_support::encode_path(&milestone_number),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2090,33 +2227,32 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Issue>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/issues?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/issues"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -2146,27 +2282,26 @@ impl Issues {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Issue>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+            query_args.push(("labels", labels.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/issues?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/issues"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
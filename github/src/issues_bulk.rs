@@ -0,0 +1,290 @@
+//! Create, label and close many issues at once, for repository maintenance.
+//!
+//! GitHub limits how fast content is created or changed with its secondary rate
+//! limits, and recommends making such requests one at a time, a second apart. The
+//! helpers here do that, and carry on with the other issues when one of them fails.
+//!
+//! https://docs.github.com/en/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client, alerts: Vec<(String, String)>) -> anyhow::Result<()> {
+//! use octorust::issues::NewIssue;
+//!
+//! // The issues borrow the titles and bodies, which are not copied.
+//! let issues: Vec<_> = alerts
+//!     .iter()
+//!     .map(|(title, body)| NewIssue::new(title).body(body).label("alert"))
+//!     .collect();
+//! for created in github.issues().bulk_create("owner", "repo", &issues).await {
+//!     println!("#{}", created?.number);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::{borrow::Cow, time::Duration};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    issues::Issues,
+    types::{
+        Issue, IssuesAddLabelsRequest, IssuesAddLabelsRequestOneOf, IssuesUpdateRequest, State,
+    },
+};
+
+/// How long to wait between two requests changing issues.
+pub const MUTATION_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The outcome of a bulk operation, issue by issue.
+#[derive(Debug, Default)]
+pub struct BulkResult {
+    /// The issues the operation succeeded for, in order.
+    pub succeeded: Vec<i64>,
+    pub failed: Vec<BulkFailure>,
+}
+
+/// An issue a bulk operation failed for.
+#[derive(Debug)]
+pub struct BulkFailure {
+    pub issue_number: i64,
+    pub error: anyhow::Error,
+}
+
+impl BulkResult {
+    /// Whether the operation succeeded for every issue.
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    fn push(&mut self, issue_number: i64, result: Result<()>) {
+        match result {
+            Ok(()) => self.succeeded.push(issue_number),
+            Err(error) => self.failed.push(BulkFailure {
+                issue_number,
+                error,
+            }),
+        }
+    }
+}
+
+/// An issue to create. It borrows its fields where it can, so that many issues can
+/// be created out of existing data without copying it.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct NewIssue<'a> {
+    title: Cow<'a, str>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    body: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    assignees: Vec<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone: Option<i64>,
+}
+
+impl<'a> NewIssue<'a> {
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        NewIssue {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn body(mut self, body: impl Into<Cow<'a, str>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Assign the issue to a user, by login.
+    pub fn assignee(mut self, login: impl Into<Cow<'a, str>>) -> Self {
+        self.assignees.push(login.into());
+        self
+    }
+
+    /// Add a label to the issue, by name. Labels are silently dropped unless the
+    /// user has push access.
+    pub fn label(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.labels.push(name.into());
+        self
+    }
+
+    /// Add the issue to a milestone, by number.
+    pub fn milestone(mut self, number: i64) -> Self {
+        self.milestone = Some(number);
+        self
+    }
+}
+
+/// Spaces out the requests changing issues by `MUTATION_INTERVAL`.
+#[derive(Default)]
+struct Pacer {
+    last: Option<web_time::Instant>,
+}
+
+impl Pacer {
+    async fn wait(&mut self) {
+        if let Some(last) = self.last {
+            let elapsed = last.elapsed();
+            if elapsed < MUTATION_INTERVAL {
+                crate::runtime::sleep(MUTATION_INTERVAL - elapsed).await;
+            }
+        }
+        self.last = Some(web_time::Instant::now());
+    }
+}
+
+impl Issues {
+    /**
+     * Create an issue.
+     *
+     * Same as `create`, with a body that borrows its fields.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     */
+    pub async fn create_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue: &NewIssue<'_>,
+    ) -> Result<Issue> {
+        let url = format!(
+            "/repos/{}/{}/issues",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        self.client
+            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(issue)?)))
+            .await
+    }
+
+    /**
+     * Create the given issues, one after the other. Returns the outcome of every
+     * issue, in order.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `issues: &[NewIssue]`
+     */
+    pub async fn bulk_create(
+        &self,
+        owner: &str,
+        repo: &str,
+        issues: &[NewIssue<'_>],
+    ) -> Vec<Result<Issue>> {
+        let mut pacer = Pacer::default();
+        let mut created = Vec::with_capacity(issues.len());
+        for issue in issues {
+            pacer.wait().await;
+            created.push(self.create_with(owner, repo, issue).await);
+        }
+
+        created
+    }
+
+    /**
+     * Add the labels in `add` to the given issues and remove the ones in `remove`.
+     *
+     * The labels of every issue are read first, so the labels it already has are
+     * not added again and the ones it does not have are not removed. The labels to
+     * add are added in a single request per issue.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `add: &[&str]` -- The names of the labels to add.
+     * * `remove: &[&str]` -- The names of the labels to remove.
+     * * `issue_numbers: &[i64]`
+     */
+    pub async fn bulk_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        add: &[&str],
+        remove: &[&str],
+        issue_numbers: &[i64],
+    ) -> BulkResult {
+        let mut pacer = Pacer::default();
+        let mut result = BulkResult::default();
+        for &issue_number in issue_numbers {
+            let labeled = self
+                .label_issue(&mut pacer, owner, repo, issue_number, add, remove)
+                .await;
+            result.push(issue_number, labeled);
+        }
+
+        result
+    }
+
+    /**
+     * Close the given issues.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `issue_numbers: &[i64]`
+     */
+    pub async fn bulk_close(&self, owner: &str, repo: &str, issue_numbers: &[i64]) -> BulkResult {
+        let body = IssuesUpdateRequest {
+            state: Some(State::Closed),
+            ..Default::default()
+        };
+
+        let mut pacer = Pacer::default();
+        let mut result = BulkResult::default();
+        for &issue_number in issue_numbers {
+            pacer.wait().await;
+            let closed = self.update(owner, repo, issue_number, &body).await;
+            result.push(issue_number, closed.map(|_| ()));
+        }
+
+        result
+    }
+
+    async fn label_issue(
+        &self,
+        pacer: &mut Pacer,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        add: &[&str],
+        remove: &[&str],
+    ) -> Result<()> {
+        let current: Vec<String> = self
+            .list_all_labels_on_issue(owner, repo, issue_number)
+            .await?
+            .into_iter()
+            .map(|label| label.name)
+            .collect();
+        let has = |name: &str| current.iter().any(|c| c.eq_ignore_ascii_case(name));
+
+        let missing: Vec<String> = add
+            .iter()
+            .filter(|&&name| !has(name))
+            .map(|name| name.to_string())
+            .collect();
+        if !missing.is_empty() {
+            pacer.wait().await;
+            let body =
+                IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequest(IssuesAddLabelsRequest {
+                    labels: missing,
+                });
+            self.add_labels(owner, repo, issue_number, &body).await?;
+        }
+
+        for name in remove.iter().filter(|&&name| has(name)) {
+            pacer.wait().await;
+            self.remove_label(owner, repo, issue_number, name).await?;
+        }
+
+        Ok(())
+    }
+}
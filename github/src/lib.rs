@@ -55,7 +55,12 @@
 //! ```
 //!
 //! If you are a GitHub enterprise customer, you will want to create a client with the
+//! [Client#enterprise](https://docs.rs/octorust/0.1.36/octorust/struct.Client.html#method.enterprise) method, which takes the URL of
+//! your GitHub Enterprise Server instance, or with the
 //! [Client#host](https://docs.rs/octorust/0.1.36/octorust/struct.Client.html#method.host) method.
+//! `Client::set_upload_host` overrides the host release assets are uploaded to and
+//! `Client::set_api_version` pins the version of the REST API with the
+//! `X-GitHub-Api-Version` header.
 //!
 //! ## Feature flags
 //!
@@ -74,6 +79,10 @@
 //!
 //! Then use the `Client::custom` constructor to provide a cache implementation.
 //!
+//! `HttpCache::in_home_dir()` persists responses to disk, while `HttpCache::in_memory()`
+//! keeps them in process memory, which suits long-running services polling the same endpoints.
+//! A cache can also be swapped in later with `Client::set_http_cache`.
+//!
 //! Here is an example:
 //!
 //! ```
@@ -105,6 +114,31 @@
 //!     http_cache
 //! );
 //! ```
+//!
+//! ### blocking
+//!
+//! The `blocking` feature adds `blocking::Client`, which runs requests to completion on
+//! a runtime of its own for code that is not async.
+//!
+//! ### API groups
+//!
+//! Every group of endpoints, like `repos`, `issues` or `actions`, is a feature of its
+//! own. They are all on by default; to compile only the groups in use, turn the
+//! default features off and list them:
+//!
+//! ```toml
+//! [dependencies]
+//! octorust = { version = "0.1.36", default-features = false, features = ["rustls-tls", "repos", "pulls"] }
+//! ```
+//!
+//! `apps` and `rate_limit` are always there, as the client itself uses them.
+//!
+//! ## WebAssembly
+//!
+//! On `wasm32` targets requests are sent with the `fetch` API of the browser, and retries
+//! and polling wait on the timers of the browser rather than on tokio. The `blocking`,
+//! `httpcache` and `native-tls` features are not available there.
+//!
 //! ## Authenticating GitHub apps
 //!
 //! You can also authenticate via a GitHub app.
@@ -176,84 +210,238 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 /// Endpoints to manage GitHub Actions using the REST API.
+#[cfg(feature = "actions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "actions")))]
 pub mod actions;
+#[cfg(feature = "actions")]
+mod actions_downloads;
+#[cfg(all(feature = "actions", feature = "sodium"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sodium")))]
+mod actions_secrets;
 /// Activity APIs provide access to notifications, subscriptions, and timelines.
+#[cfg(feature = "activity")]
+#[cfg_attr(docsrs, doc(cfg(feature = "activity")))]
 pub mod activity;
 /// Information for integrations and installations.
 pub mod apps;
 pub mod auth;
+pub mod batch;
 /// Monitor charges and usage from Actions and Packages.
+#[cfg(feature = "billing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "billing")))]
 pub mod billing;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+#[cfg(feature = "repos")]
+mod branch_protection;
+pub mod cache;
+#[cfg(feature = "checks")]
+mod check_runs;
 /// Rich interactions with checks run by your integrations.
+#[cfg(feature = "checks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "checks")))]
 pub mod checks;
+pub mod client_config;
 /// Retrieve code scanning alerts from a repository.
+#[cfg(feature = "code_scanning")]
+#[cfg_attr(docsrs, doc(cfg(feature = "code_scanning")))]
 pub mod code_scanning;
 /// Insight into codes of conduct for your communities.
+#[cfg(feature = "codes_of_conduct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "codes_of_conduct")))]
 pub mod codes_of_conduct;
+#[cfg(feature = "git")]
+mod commit_builder;
+#[cfg(all(feature = "checks", feature = "repos"))]
+mod commit_status;
+#[cfg(feature = "repos")]
+mod contents;
+#[cfg(any(feature = "pulls", feature = "repos"))]
+mod diffs;
 /// List emojis available to use on GitHub.
+#[cfg(feature = "emojis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "emojis")))]
 pub mod emojis;
 /// Administer a GitHub enterprise.
+#[cfg(feature = "enterprise_admin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "enterprise_admin")))]
 pub mod enterprise_admin;
+/// The errors returned by GitHub.
+pub mod error;
+/// Export the metadata of a repository, for backups.
+#[cfg(all(feature = "issues", feature = "pulls", feature = "repos"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "issues", feature = "pulls", feature = "repos")))
+)]
+pub mod export;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fixtures;
+pub mod from_env;
+#[cfg(feature = "gists")]
+mod gist_files;
 /// View, modify your gists.
+#[cfg(feature = "gists")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gists")))]
 pub mod gists;
 /// Raw Git functionality.
+#[cfg(feature = "git")]
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
 pub mod git;
 /// View gitignore templates.
+#[cfg(feature = "gitignore")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gitignore")))]
 pub mod gitignore;
+/// Query the GitHub GraphQL API.
+pub mod graphql;
 #[cfg(feature = "httpcache")]
 #[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
 pub mod http_cache;
 /// Owner or admin management of users interactions.
+#[cfg(feature = "interactions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "interactions")))]
 pub mod interactions;
 /// Interact with GitHub Issues.
+#[cfg(feature = "issues")]
+#[cfg_attr(docsrs, doc(cfg(feature = "issues")))]
 pub mod issues;
+#[cfg(feature = "issues")]
+mod issues_bulk;
+mod json_stream;
 /// View various OSS licenses.
+#[cfg(feature = "licenses")]
+#[cfg_attr(docsrs, doc(cfg(feature = "licenses")))]
 pub mod licenses;
 /// Render Github flavored markdown.
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
 pub mod markdown;
 /// Endpoints that give information about the API.
+#[cfg(feature = "meta")]
+#[cfg_attr(docsrs, doc(cfg(feature = "meta")))]
 pub mod meta;
+pub mod metrics;
 /// Move projects to or from GitHub.
+#[cfg(feature = "migrations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "migrations")))]
 pub mod migrations;
 /// Manage access of OAuth applications.
+#[cfg(feature = "oauth_authorizations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oauth_authorizations")))]
 pub mod oauth_authorizations;
 /// Interact with GitHub Orgs.
+#[cfg(feature = "orgs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "orgs")))]
 pub mod orgs;
 /// Manage packages for authenticated users and organizations.
+#[cfg(feature = "packages")]
+#[cfg_attr(docsrs, doc(cfg(feature = "packages")))]
 pub mod packages;
+/// One page of the results of a list endpoint.
+pub mod page;
+mod pagination;
+/// The query parameters of list endpoints as structs.
+#[cfg(any(
+    feature = "gists",
+    feature = "issues",
+    feature = "pulls",
+    feature = "repos"
+))]
+pub mod params;
 /// Interact with GitHub Projects.
+#[cfg(feature = "projects")]
+#[cfg_attr(docsrs, doc(cfg(feature = "projects")))]
 pub mod projects;
+/// Manage projects with the GraphQL API.
+pub mod projects_v2;
 /// Interact with GitHub Pull Requests.
+#[cfg(feature = "pulls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pulls")))]
 pub mod pulls;
+/// Keep track of the rate limit and share it between critical and background work.
+pub mod rate_budget;
 /// Check your current rate limit status.
 pub mod rate_limit;
 /// Interact with reactions to various GitHub entities.
+#[cfg(feature = "reactions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reactions")))]
 pub mod reactions;
+pub mod redact;
+#[cfg(feature = "repos")]
+mod release_assets;
+#[cfg(feature = "repos")]
+mod repo_access;
+#[cfg(feature = "repos")]
+mod repo_iter;
+/// Create repositories from templates and configure them.
+#[cfg(all(feature = "issues", feature = "repos", feature = "teams"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "issues", feature = "repos", feature = "teams")))
+)]
+pub mod repo_setup;
 /// Interact with GitHub Repos.
+#[cfg(feature = "repos")]
+#[cfg_attr(docsrs, doc(cfg(feature = "repos")))]
 pub mod repos;
+pub mod request_options;
+pub mod retry;
+#[cfg(feature = "pulls")]
+mod review_builder;
+mod runtime;
 /// Provisioning of GitHub organization membership for SCIM-enabled providers.
+#[cfg(feature = "scim")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scim")))]
 pub mod scim;
+#[cfg(feature = "scim")]
+mod scim_provisioning;
 /// Provides exactly what type of access you have by a given token.
 pub mod scopes;
+#[cfg(feature = "sodium")]
+mod sealed_box;
 /// Look for stuff on GitHub.
+#[cfg(feature = "search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "search")))]
 pub mod search;
+#[cfg(feature = "search")]
+mod search_query;
 /// Retrieve secret scanning alerts from a repository.
+#[cfg(feature = "secret_scanning")]
+#[cfg_attr(docsrs, doc(cfg(feature = "secret_scanning")))]
 pub mod secret_scanning;
+pub mod single_flight;
+#[cfg(feature = "teams")]
+mod team_sync;
 /// Interact with GitHub Teams.
+#[cfg(feature = "teams")]
+#[cfg_attr(docsrs, doc(cfg(feature = "teams")))]
 pub mod teams;
 #[cfg(test)]
 mod tests;
+pub mod trace;
+pub mod transport;
 pub mod types;
 /// Interact with and view information about users and also current user.
+#[cfg(feature = "users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "users")))]
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
+/// Check request bodies before they are sent.
+pub mod validate;
+/// Typed webhook event payloads and signature verification.
+pub mod webhooks;
+#[cfg(feature = "actions")]
+mod workflow_dispatch;
 
 use anyhow::{anyhow, Error, Result};
 
 pub const DEFAULT_HOST: &str = "https://api.github.com";
 
 mod progenitor_support {
+    use std::fmt::{self, Write};
+
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
     const PATH_SET: &AsciiSet = &CONTROLS
@@ -267,9 +455,80 @@ mod progenitor_support {
         .add(b'{')
         .add(b'}');
 
+    /// A path component, percent-encoded as it is formatted.
+    struct EncodePath<'a, T: ?Sized>(&'a T);
+
+    impl<T: fmt::Display + ?Sized> fmt::Display for EncodePath<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            struct Encoder<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+            impl Write for Encoder<'_, '_> {
+                fn write_str(&mut self, s: &str) -> fmt::Result {
+                    write!(self.0, "{}", utf8_percent_encode(s, PATH_SET))
+                }
+            }
+
+            write!(Encoder(f), "{}", self.0)
+        }
+    }
+
+    /// Percent-encode a path component, a `&str` or any value that implements
+    /// `Display`, without copying it into a `String` first.
     #[allow(dead_code)]
-    pub(crate) fn encode_path(pc: &str) -> String {
-        utf8_percent_encode(pc, PATH_SET).to_string()
+    pub(crate) fn encode_path<T: fmt::Display + ?Sized>(pc: &T) -> impl fmt::Display + '_ {
+        EncodePath(pc)
+    }
+
+    /// Write a path and its percent-encoded query parameters into a single `String`.
+    #[allow(dead_code)]
+    pub(crate) fn build_url(path: fmt::Arguments<'_>, query_args: &[(&str, String)]) -> String {
+        let mut url = String::new();
+        url.write_fmt(path).unwrap();
+        push_query(
+            &mut url,
+            query_args
+                .iter()
+                .map(|(name, value)| (*name, value.as_str())),
+        );
+        url
+    }
+
+    /// Add percent-encoded query parameters to a URL that may already have a query
+    /// string.
+    #[allow(dead_code)]
+    pub(crate) fn append_query(url: &str, query_args: &[(&str, &str)]) -> String {
+        let mut url = url.to_string();
+        push_query(&mut url, query_args.iter().copied());
+        url
+    }
+
+    fn push_query<'a, I>(url: &mut String, query_args: I)
+    where
+        I: Iterator<Item = (&'a str, &'a str)>,
+    {
+        for (name, value) in query_args {
+            if !url.contains('?') {
+                url.push('?');
+            } else if !url.ends_with('?') && !url.ends_with('&') {
+                url.push('&');
+            }
+            push_form_encoded(url, name);
+            url.push('=');
+            push_form_encoded(url, value);
+        }
+    }
+
+    /// Encode as `application/x-www-form-urlencoded`, like `serde_urlencoded` does.
+    fn push_form_encoded(url: &mut String, s: &str) {
+        for b in s.bytes() {
+            match b {
+                b'*' | b'-' | b'.' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' => {
+                    url.push(b as char)
+                }
+                b' ' => url.push('+'),
+                _ => write!(url, "%{:02X}", b).unwrap(),
+            }
+        }
     }
 }
 
@@ -277,9 +536,14 @@ mod progenitor_support {
 #[derive(Clone)]
 pub struct Client {
     host: String,
+    upload_host: String,
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
     credentials: Option<crate::auth::Credentials>,
+    api_version: Option<String>,
+    rate_limits: crate::rate_budget::RateLimits,
+    page_concurrency: usize,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -293,6 +557,22 @@ impl Client {
         Self::host(DEFAULT_HOST, agent, credentials)
     }
 
+    /// Create a new Client for a GitHub Enterprise Server instance.
+    ///
+    /// `base_url` is the URL of the instance, for example `https://ghe.example.com`.
+    /// The REST API is served from `/api/v3` and uploads from `/api/uploads`.
+    pub fn enterprise<B, A, C>(base_url: B, agent: A, credentials: C) -> Result<Self>
+    where
+        B: Into<String>,
+        A: Into<String>,
+        C: Into<Option<crate::auth::Credentials>>,
+    {
+        let base_url = base_url.into();
+        let base_url = base_url.trim_end_matches('/');
+        let base_url = base_url.strip_suffix("/api/v3").unwrap_or(base_url);
+        Self::host(format!("{}/api/v3", base_url), agent, credentials)
+    }
+
     pub fn host<H, A, C>(host: H, agent: A, credentials: C) -> Result<Self>
     where
         H: Into<String>,
@@ -300,15 +580,14 @@ impl Client {
         C: Into<Option<crate::auth::Credentials>>,
     {
         let http = reqwest::Client::builder().build()?;
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
         let client = reqwest_middleware::ClientBuilder::new(http)
             // Trace HTTP requests. See the tracing crate to make use of these traces.
             .with(reqwest_tracing::TracingMiddleware)
+            // Record a span for every request when the `tracing` feature is enabled.
+            .with(crate::trace::TraceMiddleware)
             // Retry failed requests.
-            .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-                retry_policy,
-            ))
+            .with(crate::retry::RetryMiddleware::new(retry_policy))
             .build();
         #[cfg(feature = "httpcache")]
         {
@@ -326,6 +605,9 @@ impl Client {
         }
     }
 
+    /// Create a new Client from a custom HTTP client. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics.
     #[cfg(feature = "httpcache")]
     pub fn custom<H, A, CR>(
         host: H,
@@ -339,15 +621,24 @@ impl Client {
         A: Into<String>,
         CR: Into<Option<crate::auth::Credentials>>,
     {
+        let host = host.into().trim_end_matches('/').to_string();
         Self {
-            host: host.into(),
+            upload_host: Self::default_upload_host(&host),
+            host,
             agent: agent.into(),
             client: http,
+            request_options: Default::default(),
             credentials: credentials.into(),
+            api_version: None,
+            rate_limits: Default::default(),
+            page_concurrency: 1,
             http_cache,
         }
     }
 
+    /// Create a new Client from a custom HTTP client. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics.
     #[cfg(not(feature = "httpcache"))]
     pub fn custom<H, A, CR>(
         host: H,
@@ -360,11 +651,17 @@ impl Client {
         A: Into<String>,
         CR: Into<Option<crate::auth::Credentials>>,
     {
+        let host = host.into().trim_end_matches('/').to_string();
         Self {
-            host: host.into(),
+            upload_host: Self::default_upload_host(&host),
+            host,
             agent: agent.into(),
             client: http,
+            request_options: Default::default(),
             credentials: credentials.into(),
+            api_version: None,
+            rate_limits: Default::default(),
+            page_concurrency: 1,
         }
     }
 
@@ -375,6 +672,100 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// Override the host release assets are uploaded to, for instances that serve
+    /// uploads from somewhere else than the default for the host of the client.
+    pub fn set_upload_host<H>(&mut self, upload_host: H)
+    where
+        H: Into<String>,
+    {
+        self.upload_host = upload_host.into().trim_end_matches('/').to_string();
+    }
+
+    /// Send an `X-GitHub-Api-Version` header with every request, for example
+    /// `2022-11-28`, to pin the version of the REST API.
+    pub fn set_api_version<V>(&mut self, api_version: V)
+    where
+        V: Into<String>,
+    {
+        self.api_version = Some(api_version.into());
+    }
+
+    /// Ask for the given media type with the requests made with the returned client,
+    /// like `application/vnd.github.star+json` to get the times stargazers starred a
+    /// repository.
+    pub fn with_accept<V>(&self, media_type: V) -> Result<Self>
+    where
+        http::HeaderValue: std::convert::TryFrom<V>,
+        <http::HeaderValue as std::convert::TryFrom<V>>::Error: Into<http::Error>,
+    {
+        self.with_default_header(http::header::ACCEPT, media_type)
+    }
+
+    /// Ask for the preview of an API with the requests made with the returned client,
+    /// like `squirrel-girl` for the reactions of issues and comments.
+    pub fn with_preview(&self, codename: &'static str) -> Self {
+        let mut c = self.clone();
+        let media_type = mime::Mime::from(crate::utils::MediaType::Preview(codename));
+        c.request_options.headers.insert(
+            http::header::ACCEPT,
+            // A parsed media type is a valid header value.
+            http::HeaderValue::from_str(media_type.as_ref()).unwrap(),
+        );
+        c
+    }
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client given
+    /// to `custom`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }
+
+    /// Request up to `concurrency` pages at once in the functions that return all the
+    /// pages of a list, once the first page links to the last one. The items are
+    /// still returned in order.
+    ///
+    /// This defaults to 1, one page after the other, as GitHub asks for the requests
+    /// of a user to be made serially. Endpoints paginated with a cursor are always
+    /// followed one page after the other.
+    pub fn with_page_concurrency(&self, concurrency: usize) -> Self {
+        let mut c = self.clone();
+        c.page_concurrency = concurrency.max(1);
+        c
+    }
+
+    /// Send a request, applying the request options of the client and keeping track
+    /// of the rate limit in the response.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        let resp = self.request_options.execute(&self.client, req).await?;
+        self.rate_limits.observe(resp.headers());
+        Ok(resp)
+    }
+
+    /// Replace the cache used for conditional `GET` requests.
+    #[cfg(feature = "httpcache")]
+    pub fn set_http_cache(&mut self, http_cache: crate::http_cache::BoxedHttpCache) {
+        self.http_cache = http_cache;
+    }
+
     fn credentials(
         &self,
         authentication: crate::auth::AuthenticationConstraint,
@@ -429,7 +820,6 @@ impl Client {
                     parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
                 } else {
                     log::debug!("app token is stale, refreshing");
-                    let token_ref = apptoken.access_key.clone();
 
                     let token = self
                         .apps()
@@ -443,7 +833,7 @@ impl Client {
                         )
                         .await?;
                     let auth = format!("token {}", &token.token);
-                    *token_ref.lock().unwrap() = Some(token.token);
+                    apptoken.set_token(token.token, &token.expires_at);
                     parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
                 }
             }
@@ -496,14 +886,19 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
 
+        if let Some(api_version) = &instance.api_version {
+            req = req.header("X-GitHub-Api-Version", api_version.as_str());
+        }
+
         if let Some(body) = body {
-            log::debug!(
-                "body: {:?}",
-                String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap()
-            );
+            // The bodies of these requests are in memory, uploads stream theirs separately.
+            let bytes = body
+                .as_bytes()
+                .ok_or_else(|| anyhow!("the body of the request must be in memory"))?;
+            log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
             req = req.body(body);
         }
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         #[cfg(feature = "httpcache")]
         let instance2 = <&Client>::clone(&self);
@@ -560,8 +955,8 @@ impl Client {
             // header when cargo builds with --cfg feature="httpcache"
             #[cfg(feature = "httpcache")]
             {
-                let body = instance2.http_cache.lookup_body(&uri3).unwrap();
-                let out = serde_json::from_str::<Out>(&body).unwrap();
+                let body = instance2.http_cache.lookup_body(&uri3)?;
+                let out = serde_json::from_str::<Out>(&body)?;
                 let link = match link {
                     Some(link) => Ok(Some(link)),
                     None => instance2
@@ -584,26 +979,15 @@ impl Client {
         } else {
             let error = match (remaining, reset) {
                 (Some(remaining), Some(reset)) if remaining == 0 => {
-                    let now = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
+                    let now = web_time::SystemTime::now()
+                        .duration_since(web_time::UNIX_EPOCH)
                         .unwrap()
                         .as_secs();
-                    anyhow!(
-                        "rate limit exceeded, will reset in {} seconds",
-                        u64::from(reset) - now
-                    )
-                }
-                _ => {
-                    if response_body.is_empty() {
-                        anyhow!("code: {}, empty response", status)
-                    } else {
-                        anyhow!(
-                            "code: {}, error: {:?}",
-                            status,
-                            String::from_utf8_lossy(&response_body),
-                        )
-                    }
+                    anyhow!(crate::utils::RateLimitExceeded {
+                        reset_in: u64::from(reset).saturating_sub(now),
+                    })
                 }
+                _ => crate::error::from_response(status, &response_body),
             };
             Err(error)
         }
@@ -626,6 +1010,111 @@ impl Client {
         Ok(r)
     }
 
+    /// Send a request and return the response without reading the body.
+    ///
+    /// This is used for responses that are not JSON, or that are too large to be
+    /// buffered in memory.
+    async fn request_raw(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        media_type: crate::utils::MediaType,
+        authentication: crate::auth::AuthenticationConstraint,
+        headers: http::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let response = self
+            .send_raw(method, uri, body, media_type, authentication, headers)
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let response_body = response.bytes().await?;
+        Err(crate::error::from_response(status, &response_body))
+    }
+
+    /// Send a request and return the response, whatever its status.
+    async fn send_raw(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        media_type: crate::utils::MediaType,
+        authentication: crate::auth::AuthenticationConstraint,
+        headers: http::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let (url, auth) = self.url_and_auth(uri, authentication).await?;
+
+        let mut req = self.client.request(method, url);
+        req = req.header(http::header::USER_AGENT, &*self.agent);
+        req = req.header(
+            http::header::ACCEPT,
+            &*format!(
+                "{}",
+                hyperx::header::qitem::<mime::Mime>(From::from(media_type))
+            ),
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(api_version) = &self.api_version {
+            req = req.header("X-GitHub-Api-Version", api_version.as_str());
+        }
+
+        for (key, value) in headers.iter() {
+            req = req.header(key, value);
+        }
+
+        if let Some(body) = body {
+            req = req.body(body);
+        }
+        self.execute(req.build()?).await
+    }
+
+    /// The host release assets are uploaded to by default. GitHub Enterprise Server
+    /// serves uploads from `/api/uploads` rather than from a separate host.
+    fn default_upload_host(host: &str) -> String {
+        match host.strip_suffix("/api/v3") {
+            Some(base) => format!("{}/api/uploads", base),
+            None => host.replacen("://api.", "://uploads.", 1),
+        }
+    }
+
+    async fn post_upload<D>(
+        &self,
+        uri: &str,
+        content_type: &str,
+        content_length: Option<u64>,
+        body: reqwest::Body,
+    ) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::CONTENT_TYPE, content_type.parse()?);
+        if let Some(content_length) = content_length {
+            headers.insert(http::header::CONTENT_LENGTH, content_length.into());
+        }
+
+        let response = self
+            .request_raw(
+                http::Method::POST,
+                &(self.upload_host.clone() + uri),
+                Some(body),
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+                headers,
+            )
+            .await?;
+
+        Ok(serde_json::from_slice(&response.bytes().await?)?)
+    }
+
     async fn get<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -653,6 +1142,32 @@ impl Client {
         .await
     }
 
+    /// Whether the resource at `uri` exists, for the endpoints which answer with
+    /// `204 No Content` when it does and with `404 Not Found` when it does not.
+    async fn get_exists(&self, uri: &str) -> Result<bool> {
+        let response = self
+            .send_raw(
+                http::Method::GET,
+                &(self.host.clone() + uri),
+                None,
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+                http::HeaderMap::new(),
+            )
+            .await?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        if status.is_success() {
+            return Ok(true);
+        }
+
+        let response_body = response.bytes().await?;
+        Err(crate::error::from_response(status, &response_body))
+    }
+
     async fn get_all_pages<D>(&self, uri: &str, _message: Option<reqwest::Body>) -> Result<Vec<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -660,7 +1175,34 @@ impl Client {
         self.unfold(uri).await
     }
 
-    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+    /// Stream the items of the JSON array in the response to a `GET`, parsing each of
+    /// them as soon as it downloaded rather than once the whole body is in memory. The
+    /// array is either the body itself, or the first field of the body holding an
+    /// array, like the `workflow_runs` of a listing of runs. Only the given page is
+    /// read, the pages after it are not followed.
+    pub async fn get_stream_items<T>(
+        &self,
+        uri: &str,
+    ) -> Result<impl futures::Stream<Item = Result<T>>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self
+            .streaming()
+            .request_raw(
+                http::Method::GET,
+                &(self.host.clone() + uri),
+                None,
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+                http::HeaderMap::new(),
+            )
+            .await?;
+
+        Ok(crate::json_stream::items(response))
+    }
+
+    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, D)>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -677,7 +1219,7 @@ impl Client {
     async fn get_pages_url<D>(
         &self,
         url: &reqwest::Url,
-    ) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+    ) -> Result<(Option<hyperx::header::Link>, D)>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -797,28 +1339,64 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {
+        self.unfold_with(uri, |items: Vec<D>| items).await
+    }
+
+    /// "unfold" paginated results where every page wraps the items in an object,
+    /// like the `repositories` of `/installation/repositories`. `items` takes the
+    /// items out of a page.
+    ///
+    /// Pages are followed through the `rel="next"` link of the `Link` header until
+    /// there is none, which also works for endpoints paginated with a cursor.
+    async fn unfold_with<P, D, F>(&self, uri: &str, items: F) -> Result<Vec<D>>
+    where
+        P: serde::de::DeserializeOwned + 'static + Send,
+        D: Send,
+        F: Fn(P) -> Vec<D> + Send,
+    {
+        let (mut link, page) = self.get_pages(uri).await?;
+        let mut global_items = items(page);
+
+        // With numbered pages, the first one tells how many there are, so the others
+        // can be requested at once.
+        if let Some(urls) = link
+            .as_ref()
+            .filter(|_| self.page_concurrency > 1)
+            .and_then(crate::utils::page_urls)
+        {
+            let pages = urls.into_iter().map(|url| async move {
                 let url = reqwest::Url::parse(&url)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
+                let (_, page) = self.get_pages_url::<P>(&url).await?;
+                Ok::<_, Error>(page)
+            });
+            let mut pages =
+                futures::StreamExt::buffered(futures::stream::iter(pages), self.page_concurrency);
+            while let Some(page) = futures::TryStreamExt::try_next(&mut pages).await? {
+                global_items.append(&mut items(page));
             }
+
+            return Ok(global_items);
+        }
+
+        while let Some(url) = link.as_ref().and_then(crate::utils::next_link) {
+            let url = reqwest::Url::parse(&url)?;
+            let (new_link, page) = self.get_pages_url(&url).await?;
+            link = new_link;
+            global_items.append(&mut items(page));
         }
 
         Ok(global_items)
     }
 
+    #[cfg(feature = "actions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "actions")))]
     /// Endpoints to manage GitHub Actions using the REST API.
     pub fn actions(&self) -> actions::Actions {
         actions::Actions::new(self.clone())
     }
 
+    #[cfg(feature = "activity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "activity")))]
     /// Activity APIs provide access to notifications, subscriptions, and timelines.
     pub fn activity(&self) -> activity::Activity {
         activity::Activity::new(self.clone())
@@ -829,101 +1407,141 @@ impl Client {
         apps::Apps::new(self.clone())
     }
 
+    #[cfg(feature = "billing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "billing")))]
     /// Monitor charges and usage from Actions and Packages.
     pub fn billing(&self) -> billing::Billing {
         billing::Billing::new(self.clone())
     }
 
+    #[cfg(feature = "checks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "checks")))]
     /// Rich interactions with checks run by your integrations.
     pub fn checks(&self) -> checks::Checks {
         checks::Checks::new(self.clone())
     }
 
+    #[cfg(feature = "code_scanning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "code_scanning")))]
     /// Retrieve code scanning alerts from a repository.
     pub fn code_scanning(&self) -> code_scanning::CodeScanning {
         code_scanning::CodeScanning::new(self.clone())
     }
 
+    #[cfg(feature = "codes_of_conduct")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "codes_of_conduct")))]
     /// Insight into codes of conduct for your communities.
     pub fn codes_of_conduct(&self) -> codes_of_conduct::CodesOfConduct {
         codes_of_conduct::CodesOfConduct::new(self.clone())
     }
 
+    #[cfg(feature = "emojis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "emojis")))]
     /// List emojis available to use on GitHub.
     pub fn emojis(&self) -> emojis::Emojis {
         emojis::Emojis::new(self.clone())
     }
 
+    #[cfg(feature = "enterprise_admin")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "enterprise_admin")))]
     /// Administer a GitHub enterprise.
     pub fn enterprise_admin(&self) -> enterprise_admin::EnterpriseAdmin {
         enterprise_admin::EnterpriseAdmin::new(self.clone())
     }
 
+    #[cfg(feature = "gists")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gists")))]
     /// View, modify your gists.
     pub fn gists(&self) -> gists::Gists {
         gists::Gists::new(self.clone())
     }
 
+    #[cfg(feature = "git")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
     /// Raw Git functionality.
     pub fn git(&self) -> git::Git {
         git::Git::new(self.clone())
     }
 
+    #[cfg(feature = "gitignore")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gitignore")))]
     /// View gitignore templates.
     pub fn gitignore(&self) -> gitignore::Gitignore {
         gitignore::Gitignore::new(self.clone())
     }
 
+    #[cfg(feature = "interactions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "interactions")))]
     /// Owner or admin management of users interactions.
     pub fn interactions(&self) -> interactions::Interactions {
         interactions::Interactions::new(self.clone())
     }
 
+    #[cfg(feature = "issues")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "issues")))]
     /// Interact with GitHub Issues.
     pub fn issues(&self) -> issues::Issues {
         issues::Issues::new(self.clone())
     }
 
+    #[cfg(feature = "licenses")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "licenses")))]
     /// View various OSS licenses.
     pub fn licenses(&self) -> licenses::Licenses {
         licenses::Licenses::new(self.clone())
     }
 
+    #[cfg(feature = "markdown")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
     /// Render Github flavored markdown.
     pub fn markdown(&self) -> markdown::Markdown {
         markdown::Markdown::new(self.clone())
     }
 
+    #[cfg(feature = "meta")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "meta")))]
     /// Endpoints that give information about the API.
     pub fn meta(&self) -> meta::Meta {
         meta::Meta::new(self.clone())
     }
 
+    #[cfg(feature = "migrations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "migrations")))]
     /// Move projects to or from GitHub.
     pub fn migrations(&self) -> migrations::Migrations {
         migrations::Migrations::new(self.clone())
     }
 
+    #[cfg(feature = "oauth_authorizations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oauth_authorizations")))]
     /// Manage access of OAuth applications.
     pub fn oauth_authorizations(&self) -> oauth_authorizations::OauthAuthorizations {
         oauth_authorizations::OauthAuthorizations::new(self.clone())
     }
 
+    #[cfg(feature = "orgs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "orgs")))]
     /// Interact with GitHub Orgs.
     pub fn orgs(&self) -> orgs::Orgs {
         orgs::Orgs::new(self.clone())
     }
 
+    #[cfg(feature = "packages")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "packages")))]
     /// Manage packages for authenticated users and organizations.
     pub fn packages(&self) -> packages::Packages {
         packages::Packages::new(self.clone())
     }
 
+    #[cfg(feature = "projects")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "projects")))]
     /// Interact with GitHub Projects.
     pub fn projects(&self) -> projects::Projects {
         projects::Projects::new(self.clone())
     }
 
+    #[cfg(feature = "pulls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pulls")))]
     /// Interact with GitHub Pull Requests.
     pub fn pulls(&self) -> pulls::Pulls {
         pulls::Pulls::new(self.clone())
@@ -934,38 +1552,82 @@ impl Client {
         rate_limit::RateLimit::new(self.clone())
     }
 
+    #[cfg(feature = "reactions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reactions")))]
     /// Interact with reactions to various GitHub entities.
     pub fn reactions(&self) -> reactions::Reactions {
         reactions::Reactions::new(self.clone())
     }
 
+    #[cfg(feature = "repos")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "repos")))]
     /// Interact with GitHub Repos.
     pub fn repos(&self) -> repos::Repos {
         repos::Repos::new(self.clone())
     }
 
+    #[cfg(feature = "scim")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "scim")))]
     /// Provisioning of GitHub organization membership for SCIM-enabled providers.
     pub fn scim(&self) -> scim::Scim {
         scim::Scim::new(self.clone())
     }
 
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
     /// Look for stuff on GitHub.
     pub fn search(&self) -> search::Search {
         search::Search::new(self.clone())
     }
 
+    #[cfg(feature = "secret_scanning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "secret_scanning")))]
     /// Retrieve secret scanning alerts from a repository.
     pub fn secret_scanning(&self) -> secret_scanning::SecretScanning {
         secret_scanning::SecretScanning::new(self.clone())
     }
 
+    #[cfg(feature = "teams")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "teams")))]
     /// Interact with GitHub Teams.
     pub fn teams(&self) -> teams::Teams {
         teams::Teams::new(self.clone())
     }
 
+    #[cfg(feature = "users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "users")))]
     /// Interact with and view information about users and also current user.
     pub fn users(&self) -> users::Users {
         users::Users::new(self.clone())
     }
 }
+
+// The credentials redact their secrets.
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("host", &self.host)
+            .field("upload_host", &self.upload_host)
+            .field("agent", &self.agent)
+            .field("credentials", &self.credentials)
+            .field("api_version", &self.api_version)
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}
+
+/// Create a client authenticated with the token of `GITHUB_TOKEN`, a personal access
+/// token or the token of an app.
+impl crate::from_env::FromEnv for Client {
+    fn env_vars() -> &'static [&'static str] {
+        &["GITHUB_TOKEN"]
+    }
+
+    fn from_env() -> Result<Self> {
+        let token = crate::from_env::required("GITHUB_TOKEN")?;
+        Client::new(
+            concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+            crate::auth::Credentials::Token(token),
+        )
+    }
+}
@@ -33,18 +33,17 @@ impl Licenses {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if featured {
-            query_args.push(("featured".to_string(), featured.to_string()));
+            query_args.push(("featured", featured.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/licenses?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/licenses"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -64,12 +63,11 @@ impl Licenses {
         &self,
         featured: bool,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if featured {
-            query_args.push(("featured".to_string(), featured.to_string()));
+            query_args.push(("featured", featured.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/licenses?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/licenses"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -87,7 +85,8 @@ impl Licenses {
     *
     * * `license: &str`
     */
-    pub async fn get(&self, license: &str) -> Result<crate::types::LicenseData> {
+    pub async fn get(&self, license: impl AsRef<str>) -> Result<crate::types::LicenseData> {
+        let license = license.as_ref();
         let url = format!(
             "/licenses/{}",
             crate::progenitor_support::encode_path(license),
@@ -114,9 +113,11 @@ impl Licenses {
     */
     pub async fn get_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::LicenseContent> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/license",
             crate::progenitor_support::encode_path(owner),
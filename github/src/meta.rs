@@ -57,12 +57,11 @@ impl Meta {
     * * `s: &str` -- The words to show in Octocat's speech bubble.
     */
     pub async fn get_octocat(&self, s: &str) -> Result<String> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !s.is_empty() {
-            query_args.push(("s".to_string(), s.to_string()));
+            query_args.push(("s", s.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/octocat?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/octocat"), &query_args);
 
         self.client.get(&url, None).await
     }
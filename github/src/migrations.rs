@@ -30,26 +30,28 @@ impl Migrations {
     */
     pub async fn list_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
         exclude: &[String],
     ) -> Result<Vec<crate::types::Migration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+            query_args.push(("exclude", exclude.join(" ")));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/migrations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -68,18 +70,20 @@ impl Migrations {
     */
     pub async fn list_all_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         exclude: &[String],
     ) -> Result<Vec<crate::types::Migration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+            query_args.push(("exclude", exclude.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/migrations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -100,9 +104,10 @@ impl Migrations {
     */
     pub async fn start_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::MigrationsStartRequest,
     ) -> Result<crate::types::Migration> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/migrations",
             crate::progenitor_support::encode_path(org),
@@ -137,20 +142,22 @@ impl Migrations {
     */
     pub async fn get_status_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         migration_id: i64,
         exclude: &[String],
     ) -> Result<crate::types::Migration> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+            query_args.push(("exclude", exclude.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations/{}?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/migrations/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -170,11 +177,16 @@ impl Migrations {
     * * `org: &str`
     * * `migration_id: i64` -- migration_id parameter.
     */
-    pub async fn download_archive_for_org(&self, org: &str, migration_id: i64) -> Result<()> {
+    pub async fn download_archive_for_org(
+        &self,
+        org: impl AsRef<str>,
+        migration_id: i64,
+    ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/migrations/{}/archive",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
         );
 
         self.client.get(&url, None).await
@@ -194,11 +206,16 @@ impl Migrations {
     * * `org: &str`
     * * `migration_id: i64` -- migration_id parameter.
     */
-    pub async fn delete_archive_for_org(&self, org: &str, migration_id: i64) -> Result<()> {
+    pub async fn delete_archive_for_org(
+        &self,
+        org: impl AsRef<str>,
+        migration_id: i64,
+    ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/migrations/{}/archive",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
         );
 
         self.client.delete(&url, None).await
@@ -221,14 +238,16 @@ impl Migrations {
     */
     pub async fn unlock_repo_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         migration_id: i64,
-        repo_name: &str,
+        repo_name: impl AsRef<str>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let repo_name = repo_name.as_ref();
         let url = format!(
             "/orgs/{}/migrations/{}/repos/{}/lock",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
             crate::progenitor_support::encode_path(repo_name),
         );
 
@@ -253,24 +272,26 @@ impl Migrations {
     */
     pub async fn list_repos_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         migration_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations/{}/repositories?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -289,13 +310,14 @@ impl Migrations {
     */
     pub async fn list_all_repos_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         migration_id: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/migrations/{}/repositories",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -348,7 +370,13 @@ impl Migrations {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn get_import_status(&self, owner: &str, repo: &str) -> Result<crate::types::Import> {
+    pub async fn get_import_status(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<crate::types::Import> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import",
             crate::progenitor_support::encode_path(owner),
@@ -374,10 +402,12 @@ impl Migrations {
     */
     pub async fn start_import(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::MigrationsStartImportRequest,
     ) -> Result<crate::types::Import> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import",
             crate::progenitor_support::encode_path(owner),
@@ -403,7 +433,9 @@ impl Migrations {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn cancel_import(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn cancel_import(&self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import",
             crate::progenitor_support::encode_path(owner),
@@ -430,10 +462,12 @@ impl Migrations {
     */
     pub async fn update_import(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::MigrationsUpdateImportRequest,
     ) -> Result<crate::types::Import> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import",
             crate::progenitor_support::encode_path(owner),
@@ -464,20 +498,23 @@ impl Migrations {
     */
     pub async fn get_commit_authors(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         since: i64,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/import/authors?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/import/authors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -498,20 +535,23 @@ impl Migrations {
     */
     pub async fn get_all_commit_authors(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         since: i64,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/import/authors?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/import/authors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -534,16 +574,18 @@ impl Migrations {
     */
     pub async fn map_commit_author(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         author_id: i64,
         body: &crate::types::Author,
     ) -> Result<crate::types::PorterAuthor> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import/authors/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&author_id.to_string()),
+            crate::progenitor_support::encode_path(&author_id),
         );
 
         self.client
@@ -567,9 +609,11 @@ impl Migrations {
     */
     pub async fn get_large_files(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PorterLargeFile>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import/large_files",
             crate::progenitor_support::encode_path(owner),
@@ -592,9 +636,11 @@ impl Migrations {
     */
     pub async fn get_all_large_files(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PorterLargeFile>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import/large_files",
             crate::progenitor_support::encode_path(owner),
@@ -620,10 +666,12 @@ impl Migrations {
     */
     pub async fn set_lfs_preference(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::MigrationsSetLfsPreferenceRequest,
     ) -> Result<crate::types::Import> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/import/lfs",
             crate::progenitor_support::encode_path(owner),
@@ -654,15 +702,15 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Migration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/migrations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/user/migrations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -728,15 +776,16 @@ impl Migrations {
         migration_id: i64,
         exclude: &[String],
     ) -> Result<crate::types::Migration> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+            query_args.push(("exclude", exclude.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/migrations/{}?{}",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/user/migrations/{}",
+                crate::progenitor_support::encode_path(&migration_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -778,7 +827,7 @@ impl Migrations {
     pub async fn get_archive_for_authenticated_user(&self, migration_id: i64) -> Result<()> {
         let url = format!(
             "/user/migrations/{}/archive",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
         );
 
         self.client.get(&url, None).await
@@ -800,7 +849,7 @@ impl Migrations {
     pub async fn delete_archive_for_authenticated_user(&self, migration_id: i64) -> Result<()> {
         let url = format!(
             "/user/migrations/{}/archive",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
         );
 
         self.client.delete(&url, None).await
@@ -823,11 +872,12 @@ impl Migrations {
     pub async fn unlock_repo_for_authenticated_user(
         &self,
         migration_id: i64,
-        repo_name: &str,
+        repo_name: impl AsRef<str>,
     ) -> Result<()> {
+        let repo_name = repo_name.as_ref();
         let url = format!(
             "/user/migrations/{}/repos/{}/lock",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
             crate::progenitor_support::encode_path(repo_name),
         );
 
@@ -855,18 +905,19 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/migrations/{}/repositories?{}",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/user/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(&migration_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -889,7 +940,7 @@ impl Migrations {
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let url = format!(
             "/user/migrations/{}/repositories",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
+            crate::progenitor_support::encode_path(&migration_id),
         );
 
         self.client.get_all_pages(&url, None).await
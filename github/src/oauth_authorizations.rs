@@ -35,18 +35,18 @@ impl OauthAuthorizations {
         page: i64,
         client_id: &str,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/applications/grants?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/applications/grants"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -68,12 +68,12 @@ impl OauthAuthorizations {
         &self,
         client_id: &str,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/applications/grants?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/applications/grants"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -94,7 +94,7 @@ impl OauthAuthorizations {
     pub async fn get_grant(&self, grant_id: i64) -> Result<crate::types::ApplicationGrant> {
         let url = format!(
             "/applications/grants/{}",
-            crate::progenitor_support::encode_path(&grant_id.to_string()),
+            crate::progenitor_support::encode_path(&grant_id),
         );
 
         self.client.get(&url, None).await
@@ -118,7 +118,7 @@ impl OauthAuthorizations {
     pub async fn delete_grant(&self, grant_id: i64) -> Result<()> {
         let url = format!(
             "/applications/grants/{}",
-            crate::progenitor_support::encode_path(&grant_id.to_string()),
+            crate::progenitor_support::encode_path(&grant_id),
         );
 
         self.client.delete(&url, None).await
@@ -145,18 +145,18 @@ impl OauthAuthorizations {
         page: i64,
         client_id: &str,
     ) -> Result<Vec<crate::types::Authorization>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/authorizations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/authorizations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -176,12 +176,12 @@ impl OauthAuthorizations {
         &self,
         client_id: &str,
     ) -> Result<Vec<crate::types::Authorization>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/authorizations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/authorizations"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -238,9 +238,10 @@ impl OauthAuthorizations {
     */
     pub async fn get_or_create_authorization_for_app(
         &self,
-        client_id: &str,
+        client_id: impl AsRef<str>,
         body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppRequest,
     ) -> Result<crate::types::Authorization> {
+        let client_id = client_id.as_ref();
         let url = format!(
             "/authorizations/clients/{}",
             crate::progenitor_support::encode_path(client_id),
@@ -273,10 +274,12 @@ impl OauthAuthorizations {
     */
     pub async fn get_or_create_authorization_for_app_and_fingerprint(
         &self,
-        client_id: &str,
-        fingerprint: &str,
+        client_id: impl AsRef<str>,
+        fingerprint: impl AsRef<str>,
         body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
     ) -> Result<crate::types::Authorization> {
+        let client_id = client_id.as_ref();
+        let fingerprint = fingerprint.as_ref();
         let url = format!(
             "/authorizations/clients/{}/{}",
             crate::progenitor_support::encode_path(client_id),
@@ -307,7 +310,7 @@ impl OauthAuthorizations {
     ) -> Result<crate::types::Authorization> {
         let url = format!(
             "/authorizations/{}",
-            crate::progenitor_support::encode_path(&authorization_id.to_string()),
+            crate::progenitor_support::encode_path(&authorization_id),
         );
 
         self.client.get(&url, None).await
@@ -329,7 +332,7 @@ impl OauthAuthorizations {
     pub async fn delete_authorization(&self, authorization_id: i64) -> Result<()> {
         let url = format!(
             "/authorizations/{}",
-            crate::progenitor_support::encode_path(&authorization_id.to_string()),
+            crate::progenitor_support::encode_path(&authorization_id),
         );
 
         self.client.delete(&url, None).await
@@ -359,7 +362,7 @@ impl OauthAuthorizations {
     ) -> Result<crate::types::Authorization> {
         let url = format!(
             "/authorizations/{}",
-            crate::progenitor_support::encode_path(&authorization_id.to_string()),
+            crate::progenitor_support::encode_path(&authorization_id),
         );
 
         self.client
@@ -33,15 +33,14 @@ impl Orgs {
         since: i64,
         per_page: i64,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/organizations?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/organizations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -60,12 +59,11 @@ impl Orgs {
     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
     */
     pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/organizations?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/organizations"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -85,11 +83,9 @@ impl Orgs {
     *
     * * `org: &str`
     */
-    pub async fn get(&self, org: &str) -> Result<crate::types::OrganizationFull> {
-        let url = format!(
-            "/orgs/{}",
-            crate::progenitor_support::encode_path(org),
-        );
+    pub async fn get(&self, org: impl AsRef<str>) -> Result<crate::types::OrganizationFull> {
+        let org = org.as_ref();
+        let url = format!("/orgs/{}", crate::progenitor_support::encode_path(org),);
 
         self.client.get(&url, None).await
     }
@@ -111,13 +107,11 @@ impl Orgs {
     */
     pub async fn update(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::OrgsUpdateRequest,
     ) -> Result<crate::types::OrganizationFull> {
-        let url = format!(
-            "/orgs/{}",
-            crate::progenitor_support::encode_path(org),
-        );
+        let org = org.as_ref();
+        let url = format!("/orgs/{}", crate::progenitor_support::encode_path(org),);
 
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -156,7 +150,7 @@ impl Orgs {
     */
     pub async fn get_audit_log(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         phrase: &str,
         include: crate::types::Include,
         after: &str,
@@ -165,33 +159,35 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/audit-log",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -212,34 +208,36 @@ impl Orgs {
     */
     pub async fn get_all_audit_log(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         phrase: &str,
         include: crate::types::Include,
         after: &str,
         before: &str,
         order: crate::types::Order,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/audit-log",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -258,7 +256,11 @@ impl Orgs {
     *
     * * `org: &str`
     */
-    pub async fn list_blocked_users(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>> {
+    pub async fn list_blocked_users(
+        &self,
+        org: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/blocks",
             crate::progenitor_support::encode_path(org),
@@ -278,7 +280,11 @@ impl Orgs {
     *
     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
     */
-    pub async fn list_all_blocked_users(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>> {
+    pub async fn list_all_blocked_users(
+        &self,
+        org: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/blocks",
             crate::progenitor_support::encode_path(org),
@@ -292,6 +298,8 @@ impl Orgs {
     *
     * This function performs a `GET` to the `/orgs/{org}/blocks/{username}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/orgs#check-if-a-user-is-blocked-by-an-organization>
@@ -301,14 +309,20 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn check_blocked_user(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn check_blocked_user(
+        &self,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<bool> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/blocks/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(username),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -325,7 +339,9 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn block_user(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn block_user(&self, org: impl AsRef<str>, username: impl AsRef<str>) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/blocks/{}",
             crate::progenitor_support::encode_path(org),
@@ -349,7 +365,13 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn unblock_user(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn unblock_user(
+        &self,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/blocks/{}",
             crate::progenitor_support::encode_path(org),
@@ -376,8 +398,9 @@ impl Orgs {
     */
     pub async fn list_saml_sso_authorizations(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CredentialAuthorization>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/credential-authorizations",
             crate::progenitor_support::encode_path(org),
@@ -401,8 +424,9 @@ impl Orgs {
     */
     pub async fn list_all_saml_sso_authorizations(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CredentialAuthorization>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/credential-authorizations",
             crate::progenitor_support::encode_path(org),
@@ -427,11 +451,16 @@ impl Orgs {
     * * `org: &str`
     * * `credential_id: i64`
     */
-    pub async fn remove_saml_sso_authorization(&self, org: &str, credential_id: i64) -> Result<()> {
+    pub async fn remove_saml_sso_authorization(
+        &self,
+        org: impl AsRef<str>,
+        credential_id: i64,
+    ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/credential-authorizations/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&credential_id.to_string()),
+            crate::progenitor_support::encode_path(&credential_id),
         );
 
         self.client.delete(&url, None).await
@@ -454,22 +483,24 @@ impl Orgs {
     */
     pub async fn list_failed_invitations(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/failed_invitations?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/failed_invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -488,8 +519,9 @@ impl Orgs {
     */
     pub async fn list_all_failed_invitations(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/failed_invitations",
             crate::progenitor_support::encode_path(org),
@@ -515,22 +547,24 @@ impl Orgs {
     */
     pub async fn list_webhooks(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrgHook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/hooks?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/hooks",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -547,7 +581,11 @@ impl Orgs {
     *
     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
     */
-    pub async fn list_all_webhooks(&self, org: &str) -> Result<Vec<crate::types::OrgHook>> {
+    pub async fn list_all_webhooks(
+        &self,
+        org: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::OrgHook>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks",
             crate::progenitor_support::encode_path(org),
@@ -571,9 +609,10 @@ impl Orgs {
     */
     pub async fn create_webhook(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::OrgsCreateWebhookRequest,
     ) -> Result<crate::types::OrgHook> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks",
             crate::progenitor_support::encode_path(org),
@@ -598,11 +637,16 @@ impl Orgs {
     * * `org: &str`
     * * `hook_id: i64`
     */
-    pub async fn get_webhook(&self, org: &str, hook_id: i64) -> Result<crate::types::OrgHook> {
+    pub async fn get_webhook(
+        &self,
+        org: impl AsRef<str>,
+        hook_id: i64,
+    ) -> Result<crate::types::OrgHook> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.get(&url, None).await
@@ -622,11 +666,12 @@ impl Orgs {
     * * `org: &str`
     * * `hook_id: i64`
     */
-    pub async fn delete_webhook(&self, org: &str, hook_id: i64) -> Result<()> {
+    pub async fn delete_webhook(&self, org: impl AsRef<str>, hook_id: i64) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.delete(&url, None).await
@@ -648,14 +693,15 @@ impl Orgs {
     */
     pub async fn update_webhook(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         hook_id: i64,
         body: &crate::types::OrgsUpdateWebhookRequest,
     ) -> Result<crate::types::OrgHook> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client
@@ -681,13 +727,14 @@ impl Orgs {
     */
     pub async fn get_webhook_config_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         hook_id: i64,
     ) -> Result<crate::types::WebhookConfig> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}/config",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.get(&url, None).await
@@ -711,14 +758,15 @@ impl Orgs {
     */
     pub async fn update_webhook_config_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         hook_id: i64,
         body: &crate::types::AppsUpdateWebhookConfigAppRequest,
     ) -> Result<crate::types::WebhookConfig> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}/config",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client
@@ -744,24 +792,26 @@ impl Orgs {
     */
     pub async fn list_webhook_deliveries(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         hook_id: i64,
         per_page: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/hooks/{}/deliveries?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -780,20 +830,22 @@ impl Orgs {
     */
     pub async fn list_all_webhook_deliveries(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         hook_id: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/hooks/{}/deliveries?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -816,15 +868,16 @@ impl Orgs {
     */
     pub async fn get_webhook_delivery(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         hook_id: i64,
         delivery_id: i64,
     ) -> Result<crate::types::HookDelivery> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}/deliveries/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            crate::progenitor_support::encode_path(&delivery_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
+            crate::progenitor_support::encode_path(&delivery_id),
         );
 
         self.client.get(&url, None).await
@@ -847,15 +900,16 @@ impl Orgs {
     */
     pub async fn redeliver_webhook_delivery(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         hook_id: i64,
         delivery_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}/deliveries/{}/attempts",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            crate::progenitor_support::encode_path(&delivery_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
+            crate::progenitor_support::encode_path(&delivery_id),
         );
 
         self.client.post(&url, None).await
@@ -875,11 +929,12 @@ impl Orgs {
     * * `org: &str`
     * * `hook_id: i64`
     */
-    pub async fn ping_webhook(&self, org: &str, hook_id: i64) -> Result<()> {
+    pub async fn ping_webhook(&self, org: impl AsRef<str>, hook_id: i64) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/hooks/{}/pings",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.post(&url, None).await
@@ -902,22 +957,24 @@ impl Orgs {
     */
     pub async fn list_app_installations(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/installations?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/installations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -940,22 +997,24 @@ impl Orgs {
     */
     pub async fn list_pending_invitations(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/invitations?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -974,8 +1033,9 @@ impl Orgs {
     */
     pub async fn list_all_pending_invitations(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/invitations",
             crate::progenitor_support::encode_path(org),
@@ -1001,9 +1061,10 @@ impl Orgs {
     */
     pub async fn create_invitation(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::OrgsCreateInvitationRequest,
     ) -> Result<crate::types::OrganizationInvitation> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/invitations",
             crate::progenitor_support::encode_path(org),
@@ -1030,11 +1091,12 @@ impl Orgs {
     * * `org: &str`
     * * `invitation_id: i64` -- invitation_id parameter.
     */
-    pub async fn cancel_invitation(&self, org: &str, invitation_id: i64) -> Result<()> {
+    pub async fn cancel_invitation(&self, org: impl AsRef<str>, invitation_id: i64) -> Result<()> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/invitations/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            crate::progenitor_support::encode_path(&invitation_id),
         );
 
         self.client.delete(&url, None).await
@@ -1058,24 +1120,26 @@ impl Orgs {
     */
     pub async fn list_invitation_teams(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         invitation_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Team>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/invitations/{}/teams?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/invitations/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&invitation_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1094,13 +1158,14 @@ impl Orgs {
     */
     pub async fn list_all_invitation_teams(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         invitation_id: i64,
     ) -> Result<Vec<crate::types::Team>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/invitations/{}/teams",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            crate::progenitor_support::encode_path(&invitation_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1130,30 +1195,32 @@ impl Orgs {
     */
     pub async fn list_members(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         filter: crate::types::OrgsListMembersFilter,
         role: crate::types::OrgsListMembersRole,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/members?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/members",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1172,22 +1239,24 @@ impl Orgs {
     */
     pub async fn list_all_members(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         filter: crate::types::OrgsListMembersFilter,
         role: crate::types::OrgsListMembersRole,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/members?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/members",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1198,6 +1267,8 @@ impl Orgs {
     *
     * This function performs a `GET` to the `/orgs/{org}/members/{username}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     * Check if a user is, publicly or privately, a member of the organization.
     *
     * FROM: <https://docs.github.com/rest/reference/orgs#check-organization-membership-for-a-user>
@@ -1207,14 +1278,20 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn check_membership_for_user(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn check_membership_for_user(
+        &self,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<bool> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/members/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(username),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -1231,7 +1308,13 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn remove_member(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn remove_member(
+        &self,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/members/{}",
             crate::progenitor_support::encode_path(org),
@@ -1257,9 +1340,11 @@ impl Orgs {
     */
     pub async fn get_membership_for_user(
         &self,
-        org: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::OrgMembership> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/memberships/{}",
             crate::progenitor_support::encode_path(org),
@@ -1293,10 +1378,12 @@ impl Orgs {
     */
     pub async fn set_membership_for_user(
         &self,
-        org: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
         body: &crate::types::OrgsSetMembershipUserRequest,
     ) -> Result<crate::types::OrgMembership> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/memberships/{}",
             crate::progenitor_support::encode_path(org),
@@ -1324,7 +1411,13 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn remove_membership_for_user(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn remove_membership_for_user(
+        &self,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/memberships/{}",
             crate::progenitor_support::encode_path(org),
@@ -1354,26 +1447,28 @@ impl Orgs {
     */
     pub async fn list_outside_collaborators(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         filter: crate::types::OrgsListMembersFilter,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/outside_collaborators?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/outside_collaborators",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1392,18 +1487,20 @@ impl Orgs {
     */
     pub async fn list_all_outside_collaborators(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         filter: crate::types::OrgsListMembersFilter,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/outside_collaborators?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/outside_collaborators",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1425,9 +1522,11 @@ impl Orgs {
     */
     pub async fn convert_member_to_outside_collaborator(
         &self,
-        org: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/outside_collaborators/{}",
             crate::progenitor_support::encode_path(org),
@@ -1451,7 +1550,13 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn remove_outside_collaborator(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn remove_outside_collaborator(
+        &self,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/outside_collaborators/{}",
             crate::progenitor_support::encode_path(org),
@@ -1478,22 +1583,24 @@ impl Orgs {
     */
     pub async fn list_public_members(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/public_members?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/public_members",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1512,8 +1619,9 @@ impl Orgs {
     */
     pub async fn list_all_public_members(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/public_members",
             crate::progenitor_support::encode_path(org),
@@ -1527,6 +1635,8 @@ impl Orgs {
     *
     * This function performs a `GET` to the `/orgs/{org}/public_members/{username}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/orgs#check-public-organization-membership-for-a-user>
@@ -1536,14 +1646,20 @@ impl Orgs {
     * * `org: &str`
     * * `username: &str`
     */
-    pub async fn check_public_membership_for_user(&self, org: &str, username: &str) -> Result<()> {
+    pub async fn check_public_membership_for_user(
+        &self,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<bool> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/public_members/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(username),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -1564,9 +1680,11 @@ impl Orgs {
     */
     pub async fn set_public_membership_for_authenticated_user(
         &self,
-        org: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/public_members/{}",
             crate::progenitor_support::encode_path(org),
@@ -1592,9 +1710,11 @@ impl Orgs {
     */
     pub async fn remove_public_membership_for_authenticated_user(
         &self,
-        org: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/public_members/{}",
             crate::progenitor_support::encode_path(org),
@@ -1625,18 +1745,20 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrgMembership>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/memberships/orgs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/user/memberships/orgs"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1656,12 +1778,14 @@ impl Orgs {
         &self,
         state: crate::types::OrgMembershipState,
     ) -> Result<Vec<crate::types::OrgMembership>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/memberships/orgs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/user/memberships/orgs"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -1681,8 +1805,9 @@ impl Orgs {
     */
     pub async fn get_membership_for_authenticated_user(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::OrgMembership> {
+        let org = org.as_ref();
         let url = format!(
             "/user/memberships/orgs/{}",
             crate::progenitor_support::encode_path(org),
@@ -1706,9 +1831,10 @@ impl Orgs {
     */
     pub async fn update_membership_for_authenticated_user(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::OrgsUpdateMembershipRequest,
     ) -> Result<crate::types::OrgMembership> {
+        let org = org.as_ref();
         let url = format!(
             "/user/memberships/orgs/{}",
             crate::progenitor_support::encode_path(org),
@@ -1742,15 +1868,14 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/orgs?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/orgs"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1796,22 +1921,24 @@ impl Orgs {
     */
     pub async fn list_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/orgs?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/orgs",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1832,8 +1959,9 @@ impl Orgs {
     */
     pub async fn list_all_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/orgs",
             crate::progenitor_support::encode_path(username),
@@ -33,13 +33,15 @@ impl Packages {
     pub async fn get_package_for_organization(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
     ) -> Result<crate::types::Package> {
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/packages/{}/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
         );
 
@@ -68,13 +70,15 @@ impl Packages {
     pub async fn delete_package_for_org(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
     ) -> Result<()> {
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/packages/{}/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
         );
 
@@ -108,21 +112,24 @@ impl Packages {
     pub async fn restore_package_for_org(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
         token: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !token.is_empty() {
-            query_args.push(("token".to_string(), token.to_string()));
+            query_args.push(("token", token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/packages/{}/{}/restore?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(package_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/packages/{}/{}/restore",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -152,29 +159,32 @@ impl Packages {
     pub async fn get_all_package_versions_for_package_owned_by_org(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
         page: i64,
         per_page: i64,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(package_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -197,21 +207,24 @@ impl Packages {
     pub async fn get_all_all_package_versions_for_package_owned_by_org(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(package_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -239,16 +252,18 @@ impl Packages {
     pub async fn get_package_version_for_organization(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
         package_version_id: i64,
     ) -> Result<crate::types::PackageVersion> {
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/packages/{}/{}/versions/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
-            crate::progenitor_support::encode_path(&package_version_id.to_string()),
+            crate::progenitor_support::encode_path(&package_version_id),
         );
 
         self.client.get(&url, None).await
@@ -277,16 +292,18 @@ impl Packages {
     pub async fn delete_package_version_for_org(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
         package_version_id: i64,
     ) -> Result<()> {
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/packages/{}/{}/versions/{}",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
-            crate::progenitor_support::encode_path(&package_version_id.to_string()),
+            crate::progenitor_support::encode_path(&package_version_id),
         );
 
         self.client.delete(&url, None).await
@@ -319,16 +336,18 @@ impl Packages {
     pub async fn restore_package_version_for_org(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        org: &str,
+        package_name: impl AsRef<str>,
+        org: impl AsRef<str>,
         package_version_id: i64,
     ) -> Result<()> {
+        let package_name = package_name.as_ref();
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/packages/{}/{}/versions/{}/restore",
             crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
-            crate::progenitor_support::encode_path(&package_version_id.to_string()),
+            crate::progenitor_support::encode_path(&package_version_id),
         );
 
         self.client.post(&url, None).await
@@ -354,11 +373,12 @@ impl Packages {
     pub async fn get_package_for_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
     ) -> Result<crate::types::Package> {
+        let package_name = package_name.as_ref();
         let url = format!(
             "/user/packages/{}/{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
         );
 
@@ -385,11 +405,12 @@ impl Packages {
     pub async fn delete_package_for_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
     ) -> Result<()> {
+        let package_name = package_name.as_ref();
         let url = format!(
             "/user/packages/{}/{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
         );
 
@@ -420,19 +441,21 @@ impl Packages {
     pub async fn restore_package_for_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
         token: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let package_name = package_name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !token.is_empty() {
-            query_args.push(("token".to_string(), token.to_string()));
+            query_args.push(("token", token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/packages/{}/{}/restore?{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(package_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/user/packages/{}/{}/restore",
+                crate::progenitor_support::encode_path(&package_type),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -461,27 +484,29 @@ impl Packages {
     pub async fn get_all_package_versions_for_package_owned_by_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
         page: i64,
         per_page: i64,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let package_name = package_name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(package_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/user/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(&package_type),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -504,19 +529,21 @@ impl Packages {
     pub async fn get_all_all_package_versions_for_package_owned_by_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let package_name = package_name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(package_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/user/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(&package_type),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -543,14 +570,15 @@ impl Packages {
     pub async fn get_package_version_for_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
         package_version_id: i64,
     ) -> Result<crate::types::PackageVersion> {
+        let package_name = package_name.as_ref();
         let url = format!(
             "/user/packages/{}/{}/versions/{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
-            crate::progenitor_support::encode_path(&package_version_id.to_string()),
+            crate::progenitor_support::encode_path(&package_version_id),
         );
 
         self.client.get(&url, None).await
@@ -577,14 +605,15 @@ impl Packages {
     pub async fn delete_package_version_for_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
         package_version_id: i64,
     ) -> Result<()> {
+        let package_name = package_name.as_ref();
         let url = format!(
             "/user/packages/{}/{}/versions/{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
-            crate::progenitor_support::encode_path(&package_version_id.to_string()),
+            crate::progenitor_support::encode_path(&package_version_id),
         );
 
         self.client.delete(&url, None).await
@@ -614,14 +643,15 @@ impl Packages {
     pub async fn restore_package_version_for_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
         package_version_id: i64,
     ) -> Result<()> {
+        let package_name = package_name.as_ref();
         let url = format!(
             "/user/packages/{}/{}/versions/{}/restore",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
-            crate::progenitor_support::encode_path(&package_version_id.to_string()),
+            crate::progenitor_support::encode_path(&package_version_id),
         );
 
         self.client.post(&url, None).await
@@ -648,13 +678,15 @@ impl Packages {
     pub async fn get_package_for_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        username: &str,
+        package_name: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::Package> {
+        let package_name = package_name.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/packages/{}/{}",
             crate::progenitor_support::encode_path(username),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
         );
 
@@ -682,13 +714,15 @@ impl Packages {
     pub async fn get_all_package_versions_for_package_owned_by_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        username: &str,
+        package_name: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PackageVersion>> {
+        let package_name = package_name.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/packages/{}/{}/versions",
             crate::progenitor_support::encode_path(username),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
         );
 
@@ -712,13 +746,15 @@ impl Packages {
     pub async fn get_all_all_package_versions_for_package_owned_by_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
-        username: &str,
+        package_name: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PackageVersion>> {
+        let package_name = package_name.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/packages/{}/{}/versions",
             crate::progenitor_support::encode_path(username),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
         );
 
@@ -747,16 +783,18 @@ impl Packages {
     pub async fn get_package_version_for_user(
         &self,
         package_type: crate::types::PackageType,
-        package_name: &str,
+        package_name: impl AsRef<str>,
         package_version_id: i64,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::PackageVersion> {
+        let package_name = package_name.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/packages/{}/{}/versions/{}",
             crate::progenitor_support::encode_path(username),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(&package_type),
             crate::progenitor_support::encode_path(package_name),
-            crate::progenitor_support::encode_path(&package_version_id.to_string()),
+            crate::progenitor_support::encode_path(&package_version_id),
         );
 
         self.client.get(&url, None).await
@@ -0,0 +1,62 @@
+//! Get all the pages of listings that wrap their items in an object.
+//!
+//! https://docs.github.com/en/rest/overview/resources-in-the-rest-api#pagination
+use anyhow::Result;
+
+use crate::apps::Apps;
+
+impl Apps {
+    /**
+     * List repositories accessible to the app installation.
+     *
+     * This function performs a `GET` to the `/installation/repositories` endpoint.
+     *
+     * As opposed to `list_repos_accessible_to_installation`, this function returns all the
+     * pages of the request at once.
+     *
+     * You must use an [installation access token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-an-installation) to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-repositories-accessible-to-the-app-installation>
+     */
+    pub async fn list_all_repos_accessible_to_installation(
+        &self,
+    ) -> Result<Vec<crate::types::Repository>> {
+        self.client
+            .unfold_with(
+                "/installation/repositories",
+                |page: crate::types::AppsListInstallationReposResponse| page.repositories,
+            )
+            .await
+    }
+
+    /**
+     * List repositories accessible to the user access token.
+     *
+     * This function performs a `GET` to the `/user/installations/{installation_id}/repositories` endpoint.
+     *
+     * As opposed to `list_installation_repos_for_authenticated_user`, this function returns
+     * all the pages of the request at once.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-repositories-accessible-to-the-user-access-token>
+     *
+     * **Parameters:**
+     *
+     * * `installation_id: i64` -- installation_id parameter.
+     */
+    pub async fn list_all_installation_repos_for_authenticated_user(
+        &self,
+        installation_id: i64,
+    ) -> Result<Vec<crate::types::Repository>> {
+        let url = format!(
+            "/user/installations/{}/repositories",
+            crate::progenitor_support::encode_path(&installation_id),
+        );
+
+        self.client
+            .unfold_with(
+                &url,
+                |page: crate::types::AppsListInstallationReposResponse| page.repositories,
+            )
+            .await
+    }
+}
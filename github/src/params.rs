@@ -0,0 +1,316 @@
+//! The query parameters of list endpoints as structs.
+//!
+//! The generated list functions take every query parameter in order, which makes the
+//! call sites hard to read and breaks them whenever a parameter is added. The
+//! functions here take a struct instead, where only the parameters that are set are
+//! sent. When the struct is built with `..Default::default()`, adding a parameter to
+//! it does not break the call site.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use octorust::{params::ListIssuesParams, types::IssuesListState};
+//!
+//! let issues = github
+//!     .issues()
+//!     .list_all_for_repo_with(
+//!         "owner",
+//!         "repo",
+//!         &ListIssuesParams {
+//!             state: Some(IssuesListState::All),
+//!             labels: vec!["bug".to_string()],
+//!             ..Default::default()
+//!         },
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+
+use crate::types::{IssuesListSort, IssuesListState, Order, PullsListSort};
+#[cfg(feature = "gists")]
+use crate::{gists::Gists, page::Page, types::BaseGist};
+#[cfg(feature = "issues")]
+use crate::{issues::Issues, types::IssueSimple};
+#[cfg(feature = "pulls")]
+use crate::{pulls::Pulls, types::PullRequestSimple};
+#[cfg(feature = "repos")]
+use crate::{repos::Repos, types::CommitDataType};
+
+/// The query parameters of `Gists::list_with` and `Gists::list_page`.
+#[derive(Serialize, Default, Debug, Clone, PartialEq)]
+pub struct ListGistsParams {
+    /// Only show the gists updated after this time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    /// Results per page (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<i64>,
+    /// Page number of the results to fetch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+}
+
+/// The query parameters of `Issues::list_for_repo_with`.
+#[derive(Serialize, Default, Debug, Clone, PartialEq)]
+pub struct ListIssuesParams {
+    /// The number of a milestone, `*` for issues with any milestone or `none` for
+    /// issues without one.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub milestone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssuesListState>,
+    /// The login of the assignee, `*` for assigned issues or `none` for unassigned
+    /// ones.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub assignee: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub creator: String,
+    /// The login of a user mentioned in the issues.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub mentioned: String,
+    /// Only show the issues with all these labels.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "comma_separated"
+    )]
+    pub labels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<IssuesListSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Order>,
+    /// Only show the issues updated after this time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    /// Results per page (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<i64>,
+    /// Page number of the results to fetch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+}
+
+/// The query parameters of `Pulls::list_with`.
+#[derive(Serialize, Default, Debug, Clone, PartialEq)]
+pub struct ListPullsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssuesListState>,
+    /// Only show the pull requests from this branch, as `user:ref-name` or
+    /// `organization:ref-name`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub head: String,
+    /// Only show the pull requests into this branch.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub base: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<PullsListSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Order>,
+    /// Results per page (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<i64>,
+    /// Page number of the results to fetch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+}
+
+/// The query parameters of `Repos::list_commits_with`.
+#[derive(Serialize, Default, Debug, Clone, PartialEq)]
+pub struct ListCommitsParams {
+    /// The SHA or branch to start listing the commits from.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub sha: String,
+    /// Only show the commits containing this file path.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub path: String,
+    /// The login or email address of the author of the commits.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub author: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<DateTime<Utc>>,
+    /// Results per page (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<i64>,
+    /// Page number of the results to fetch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+}
+
+fn comma_separated<S: Serializer>(values: &[String], s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(&values.join(","))
+}
+
+/// The URI of `path` with the parameters as its query.
+fn with_query<P: Serialize>(path: &str, params: &P) -> Result<String> {
+    Ok(format!("{}?{}", path, serde_urlencoded::to_string(params)?))
+}
+
+#[cfg(any(feature = "issues", feature = "pulls", feature = "repos"))]
+fn repo_path(owner: &str, repo: &str, rest: &str) -> String {
+    format!(
+        "/repos/{}/{}/{}",
+        crate::progenitor_support::encode_path(owner),
+        crate::progenitor_support::encode_path(repo),
+        rest
+    )
+}
+
+#[cfg(feature = "gists")]
+impl Gists {
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list`, this function takes the query parameters as a struct.
+     */
+    pub async fn list_with(&self, params: &ListGistsParams) -> Result<Vec<BaseGist>> {
+        self.client.get(&with_query("/gists", params)?, None).await
+    }
+
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list_with`, this function returns all the pages of the request at
+     * once, from the page of the parameters on.
+     */
+    pub async fn list_all_with(&self, params: &ListGistsParams) -> Result<Vec<BaseGist>> {
+        self.client
+            .get_all_pages(&with_query("/gists", params)?, None)
+            .await
+    }
+
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * Gets the single page of the parameters, with the cursor to the next page, which
+     * is fetched with `Client::next_page`. Use `list_all` to get every page at once.
+     */
+    pub async fn list_page(&self, params: &ListGistsParams) -> Result<Page<BaseGist>> {
+        self.client.get_page(&with_query("/gists", params)?).await
+    }
+}
+
+#[cfg(feature = "issues")]
+impl Issues {
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_for_repo`, this function takes the query parameters as a
+     * struct.
+     */
+    pub async fn list_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ListIssuesParams,
+    ) -> Result<Vec<IssueSimple>> {
+        let url = with_query(&repo_path(owner, repo, "issues"), params)?;
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_for_repo_with`, this function returns all the pages of the
+     * request at once, from the page of the parameters on.
+     */
+    pub async fn list_all_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ListIssuesParams,
+    ) -> Result<Vec<IssueSimple>> {
+        let url = with_query(&repo_path(owner, repo, "issues"), params)?;
+        self.client.get_all_pages(&url, None).await
+    }
+}
+
+#[cfg(feature = "pulls")]
+impl Pulls {
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list`, this function takes the query parameters as a struct.
+     */
+    pub async fn list_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ListPullsParams,
+    ) -> Result<Vec<PullRequestSimple>> {
+        let url = with_query(&repo_path(owner, repo, "pulls"), params)?;
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list_with`, this function returns all the pages of the request at
+     * once, from the page of the parameters on.
+     */
+    pub async fn list_all_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ListPullsParams,
+    ) -> Result<Vec<PullRequestSimple>> {
+        let url = with_query(&repo_path(owner, repo, "pulls"), params)?;
+        self.client.get_all_pages(&url, None).await
+    }
+}
+
+#[cfg(feature = "repos")]
+impl Repos {
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function takes the query parameters as a
+     * struct.
+     */
+    pub async fn list_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ListCommitsParams,
+    ) -> Result<Vec<CommitDataType>> {
+        let url = with_query(&repo_path(owner, repo, "commits"), params)?;
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_commits_with`, this function returns all the pages of the
+     * request at once, from the page of the parameters on.
+     */
+    pub async fn list_all_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ListCommitsParams,
+    ) -> Result<Vec<CommitDataType>> {
+        let url = with_query(&repo_path(owner, repo, "commits"), params)?;
+        self.client.get_all_pages(&url, None).await
+    }
+}
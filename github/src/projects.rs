@@ -30,26 +30,28 @@ impl Projects {
     */
     pub async fn list_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         state: crate::types::IssuesListState,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/projects?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/projects",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -68,18 +70,20 @@ impl Projects {
     */
     pub async fn list_all_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         state: crate::types::IssuesListState,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/projects?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/projects",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -100,9 +104,10 @@ impl Projects {
     */
     pub async fn create_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::ProjectsCreateRequest,
     ) -> Result<crate::types::Project> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/projects",
             crate::progenitor_support::encode_path(org),
@@ -129,7 +134,7 @@ impl Projects {
     pub async fn get_card(&self, card_id: i64) -> Result<crate::types::ProjectCard> {
         let url = format!(
             "/projects/columns/cards/{}",
-            crate::progenitor_support::encode_path(&card_id.to_string()),
+            crate::progenitor_support::encode_path(&card_id),
         );
 
         self.client.get(&url, None).await
@@ -151,7 +156,7 @@ impl Projects {
     pub async fn delete_card(&self, card_id: i64) -> Result<()> {
         let url = format!(
             "/projects/columns/cards/{}",
-            crate::progenitor_support::encode_path(&card_id.to_string()),
+            crate::progenitor_support::encode_path(&card_id),
         );
 
         self.client.delete(&url, None).await
@@ -177,7 +182,7 @@ impl Projects {
     ) -> Result<crate::types::ProjectCard> {
         let url = format!(
             "/projects/columns/cards/{}",
-            crate::progenitor_support::encode_path(&card_id.to_string()),
+            crate::progenitor_support::encode_path(&card_id),
         );
 
         self.client
@@ -205,7 +210,7 @@ impl Projects {
     ) -> Result<()> {
         let url = format!(
             "/projects/columns/cards/{}/moves",
-            crate::progenitor_support::encode_path(&card_id.to_string()),
+            crate::progenitor_support::encode_path(&card_id),
         );
 
         self.client
@@ -229,7 +234,7 @@ impl Projects {
     pub async fn get_column(&self, column_id: i64) -> Result<crate::types::ProjectColumn> {
         let url = format!(
             "/projects/columns/{}",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
+            crate::progenitor_support::encode_path(&column_id),
         );
 
         self.client.get(&url, None).await
@@ -251,7 +256,7 @@ impl Projects {
     pub async fn delete_column(&self, column_id: i64) -> Result<()> {
         let url = format!(
             "/projects/columns/{}",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
+            crate::progenitor_support::encode_path(&column_id),
         );
 
         self.client.delete(&url, None).await
@@ -277,7 +282,7 @@ impl Projects {
     ) -> Result<crate::types::ProjectColumn> {
         let url = format!(
             "/projects/columns/{}",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
+            crate::progenitor_support::encode_path(&column_id),
         );
 
         self.client
@@ -308,21 +313,22 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::ProjectCard>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !archived_state.to_string().is_empty() {
-            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+            query_args.push(("archived_state", archived_state.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/columns/{}/cards?{}",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/projects/columns/{}/cards",
+                crate::progenitor_support::encode_path(&column_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -344,15 +350,16 @@ impl Projects {
         column_id: i64,
         archived_state: crate::types::ArchivedState,
     ) -> Result<Vec<crate::types::ProjectCard>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !archived_state.to_string().is_empty() {
-            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+            query_args.push(("archived_state", archived_state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/columns/{}/cards?{}",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/projects/columns/{}/cards",
+                crate::progenitor_support::encode_path(&column_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -378,7 +385,7 @@ impl Projects {
     ) -> Result<crate::types::ProjectCard> {
         let url = format!(
             "/projects/columns/{}/cards",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
+            crate::progenitor_support::encode_path(&column_id),
         );
 
         self.client
@@ -406,7 +413,7 @@ impl Projects {
     ) -> Result<()> {
         let url = format!(
             "/projects/columns/{}/moves",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
+            crate::progenitor_support::encode_path(&column_id),
         );
 
         self.client
@@ -430,7 +437,7 @@ impl Projects {
     pub async fn get(&self, project_id: i64) -> Result<crate::types::Project> {
         let url = format!(
             "/projects/{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client.get(&url, None).await
@@ -452,7 +459,7 @@ impl Projects {
     pub async fn delete(&self, project_id: i64) -> Result<()> {
         let url = format!(
             "/projects/{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client.delete(&url, None).await
@@ -478,7 +485,7 @@ impl Projects {
     ) -> Result<crate::types::Project> {
         let url = format!(
             "/projects/{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client
@@ -512,21 +519,22 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/{}/collaborators?{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/projects/{}/collaborators",
+                crate::progenitor_support::encode_path(&project_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -548,15 +556,16 @@ impl Projects {
         project_id: i64,
         affiliation: crate::types::Affiliation,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/{}/collaborators?{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/projects/{}/collaborators",
+                crate::progenitor_support::encode_path(&project_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -579,12 +588,13 @@ impl Projects {
     pub async fn add_collaborator(
         &self,
         project_id: i64,
-        username: &str,
+        username: impl AsRef<str>,
         body: &crate::types::ProjectsAddCollaboratorRequest,
     ) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/projects/{}/collaborators/{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -607,10 +617,15 @@ impl Projects {
     * * `project_id: i64`
     * * `username: &str`
     */
-    pub async fn remove_collaborator(&self, project_id: i64, username: &str) -> Result<()> {
+    pub async fn remove_collaborator(
+        &self,
+        project_id: i64,
+        username: impl AsRef<str>,
+    ) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/projects/{}/collaborators/{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -634,11 +649,12 @@ impl Projects {
     pub async fn get_permission_for_user(
         &self,
         project_id: i64,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::RepositoryCollaboratorPermission> {
+        let username = username.as_ref();
         let url = format!(
             "/projects/{}/collaborators/{}/permission",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -666,18 +682,19 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::ProjectColumn>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/{}/columns?{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/projects/{}/columns",
+                crate::progenitor_support::encode_path(&project_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -700,7 +717,7 @@ impl Projects {
     ) -> Result<Vec<crate::types::ProjectColumn>> {
         let url = format!(
             "/projects/{}/columns",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -726,7 +743,7 @@ impl Projects {
     ) -> Result<crate::types::ProjectColumn> {
         let url = format!(
             "/projects/{}/columns",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client
@@ -753,28 +770,31 @@ impl Projects {
     */
     pub async fn list_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         state: crate::types::IssuesListState,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/projects?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/projects",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -793,20 +813,23 @@ impl Projects {
     */
     pub async fn list_all_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         state: crate::types::IssuesListState,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/projects?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/projects",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -828,10 +851,12 @@ impl Projects {
     */
     pub async fn create_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ProjectsCreateRequest,
     ) -> Result<crate::types::Project> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/projects",
             crate::progenitor_support::encode_path(owner),
@@ -880,26 +905,28 @@ impl Projects {
     */
     pub async fn list_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         state: crate::types::IssuesListState,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/projects?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/projects",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -918,18 +945,20 @@ impl Projects {
     */
     pub async fn list_all_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         state: crate::types::IssuesListState,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/projects?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/projects",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -0,0 +1,432 @@
+//! Typed operations on projects, which are only available through the GraphQL
+//! API. The `projects` module is for classic projects.
+//!
+//! https://docs.github.com/en/issues/planning-and-tracking-with-projects/automating-your-project/using-the-api-to-manage-projects
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client, issue_node_id: &str) -> anyhow::Result<()> {
+//! use octorust::projects_v2::FieldValue;
+//!
+//! let projects = github.projects_v2();
+//! let project = projects.get_org_project("my-org", 5).await?;
+//! let item_id = projects.add_item(&project.id, issue_node_id).await?;
+//!
+//! let fields = projects.list_fields(&project.id).await?;
+//! let status = fields.iter().find(|f| f.name == "Status").unwrap();
+//! let done = status.options.iter().find(|o| o.name == "Done").unwrap();
+//! projects
+//!     .update_field_value(
+//!         &project.id,
+//!         &item_id,
+//!         &status.id,
+//!         &FieldValue::SingleSelectOption(done.id.to_string()),
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{graphql::Connection, Client};
+
+pub struct ProjectsV2 {
+    pub client: Client,
+}
+
+impl Client {
+    /// Manage projects with the GraphQL API.
+    pub fn projects_v2(&self) -> ProjectsV2 {
+        ProjectsV2::new(self.clone())
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectV2 {
+    /// The node ID of the project, which the other operations take.
+    pub id: String,
+    #[serde(default)]
+    pub number: i64,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub closed: bool,
+}
+
+/// A field of a project, like `Status` or `Iteration`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Field {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// The type of the field, like `TEXT`, `NUMBER`, `DATE`, `SINGLE_SELECT` or
+    /// `ITERATION`.
+    #[serde(default)]
+    pub data_type: String,
+    /// The options of a single select field.
+    #[serde(default)]
+    pub options: Vec<ProjectV2FieldOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<ProjectV2IterationConfiguration>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectV2FieldOption {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// The iterations of an iteration field.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectV2IterationConfiguration {
+    #[serde(default)]
+    pub iterations: Vec<ProjectV2Iteration>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Iteration {
+    pub id: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<chrono::NaiveDate>,
+    #[serde(default)]
+    pub duration: i64,
+}
+
+/// An issue, pull request or draft issue in a project.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Item {
+    pub id: String,
+    /// `ISSUE`, `PULL_REQUEST`, `DRAFT_ISSUE` or `REDACTED`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<ProjectV2ItemContent>,
+    #[serde(default, deserialize_with = "deserialize_field_values")]
+    pub field_values: Vec<ProjectV2ItemFieldValue>,
+}
+
+/// What an item is about. Draft issues have no number nor URL.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectV2ItemContent {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub number: i64,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub url: String,
+}
+
+/// The value of a field for an item, only the member for the type of the field is
+/// set.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectV2ItemFieldValue {
+    /// The name of the field.
+    pub field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<chrono::NaiveDate>,
+    /// The name of the option of a single select field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The title of the iteration of an iteration field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// A value to set a field of an item to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Number(f64),
+    Date(chrono::NaiveDate),
+    /// The ID of an option of a single select field.
+    SingleSelectOption(String),
+    /// The ID of an iteration of an iteration field.
+    Iteration(String),
+}
+
+impl FieldValue {
+    /// The `ProjectV2FieldValue` input object of the GraphQL API.
+    fn to_input(&self) -> serde_json::Value {
+        match self {
+            FieldValue::Text(text) => serde_json::json!({ "text": text }),
+            FieldValue::Number(number) => serde_json::json!({ "number": number }),
+            FieldValue::Date(date) => serde_json::json!({ "date": date.to_string() }),
+            FieldValue::SingleSelectOption(id) => {
+                serde_json::json!({ "singleSelectOptionId": id })
+            }
+            FieldValue::Iteration(id) => serde_json::json!({ "iterationId": id }),
+        }
+    }
+}
+
+const PROJECT_FIELDS: &str = "id number title url closed";
+
+const ITEMS_QUERY: &str = r#"query($project: ID!, $cursor: String) {
+  node(id: $project) {
+    ... on ProjectV2 {
+      items(first: 100, after: $cursor) {
+        nodes {
+          id
+          type
+          content {
+            ... on Issue { id number title url }
+            ... on PullRequest { id number title url }
+            ... on DraftIssue { id title }
+          }
+          fieldValues(first: 50) {
+            nodes {
+              ... on ProjectV2ItemFieldTextValue { text field { ... on ProjectV2FieldCommon { name } } }
+              ... on ProjectV2ItemFieldNumberValue { number field { ... on ProjectV2FieldCommon { name } } }
+              ... on ProjectV2ItemFieldDateValue { date field { ... on ProjectV2FieldCommon { name } } }
+              ... on ProjectV2ItemFieldSingleSelectValue { name field { ... on ProjectV2FieldCommon { name } } }
+              ... on ProjectV2ItemFieldIterationValue { title field { ... on ProjectV2FieldCommon { name } } }
+            }
+          }
+        }
+        pageInfo { hasNextPage endCursor }
+      }
+    }
+  }
+}"#;
+
+const FIELDS_QUERY: &str = r#"query($project: ID!, $cursor: String) {
+  node(id: $project) {
+    ... on ProjectV2 {
+      fields(first: 100, after: $cursor) {
+        nodes {
+          ... on ProjectV2FieldCommon { id name dataType }
+          ... on ProjectV2SingleSelectField { options { id name } }
+          ... on ProjectV2IterationField { configuration { iterations { id title startDate duration } } }
+        }
+        pageInfo { hasNextPage endCursor }
+      }
+    }
+  }
+}"#;
+
+impl ProjectsV2 {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ProjectsV2 { client }
+    }
+
+    /// Get a project of an organization by its number, as found in its URL.
+    pub async fn get_org_project(&self, org: &str, number: i64) -> Result<ProjectV2> {
+        self.get_project("organization", org, number).await
+    }
+
+    /// Get a project of a user by its number, as found in its URL.
+    pub async fn get_user_project(&self, login: &str, number: i64) -> Result<ProjectV2> {
+        self.get_project("user", login, number).await
+    }
+
+    async fn get_project(&self, owner_type: &str, login: &str, number: i64) -> Result<ProjectV2> {
+        let query = format!(
+            "query($login: String!, $number: Int!) {{ {}(login: $login) {{ projectV2(number: $number) {{ {} }} }} }}",
+            owner_type, PROJECT_FIELDS
+        );
+        let data: serde_json::Value = self
+            .client
+            .graphql()
+            .query(
+                &query,
+                serde_json::json!({ "login": login, "number": number }),
+            )
+            .await?;
+
+        let project = data[owner_type]["projectV2"].clone();
+        if project.is_null() {
+            return Err(anyhow!("project {} of `{}` not found", number, login));
+        }
+        Ok(serde_json::from_value(project)?)
+    }
+
+    /// List all the items of a project, with the values of their fields.
+    pub async fn list_items(&self, project_id: &str) -> Result<Vec<ProjectV2Item>> {
+        self.client
+            .graphql()
+            .query_all(
+                ITEMS_QUERY,
+                serde_json::json!({ "project": project_id }),
+                &["node", "items"],
+            )
+            .await
+    }
+
+    /// List all the fields of a project, with the options of the single select
+    /// fields and the iterations of the iteration fields.
+    pub async fn list_fields(&self, project_id: &str) -> Result<Vec<ProjectV2Field>> {
+        self.client
+            .graphql()
+            .query_all(
+                FIELDS_QUERY,
+                serde_json::json!({ "project": project_id }),
+                &["node", "fields"],
+            )
+            .await
+    }
+
+    /**
+     * Add an issue or a pull request to a project, given their node ID, and return
+     * the ID of the item. Adding content that is already in the project returns
+     * the existing item.
+     */
+    pub async fn add_item(&self, project_id: &str, content_id: &str) -> Result<String> {
+        let data: serde_json::Value = self
+            .client
+            .graphql()
+            .query(
+                r#"mutation($project: ID!, $content: ID!) {
+  addProjectV2ItemById(input: {projectId: $project, contentId: $content}) { item { id } }
+}"#,
+                serde_json::json!({ "project": project_id, "content": content_id }),
+            )
+            .await?;
+
+        node_id(&data, "/addProjectV2ItemById/item/id")
+    }
+
+    /// Add a draft issue to a project and return the ID of the item.
+    pub async fn add_draft_issue(
+        &self,
+        project_id: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<String> {
+        let data: serde_json::Value = self
+            .client
+            .graphql()
+            .query(
+                r#"mutation($project: ID!, $title: String!, $body: String) {
+  addProjectV2DraftIssue(input: {projectId: $project, title: $title, body: $body}) { projectItem { id } }
+}"#,
+                serde_json::json!({ "project": project_id, "title": title, "body": body }),
+            )
+            .await?;
+
+        node_id(&data, "/addProjectV2DraftIssue/projectItem/id")
+    }
+
+    /// Set the value of a field of an item.
+    pub async fn update_field_value(
+        &self,
+        project_id: &str,
+        item_id: &str,
+        field_id: &str,
+        value: &FieldValue,
+    ) -> Result<()> {
+        let _: serde_json::Value = self
+            .client
+            .graphql()
+            .query(
+                r#"mutation($project: ID!, $item: ID!, $field: ID!, $value: ProjectV2FieldValue!) {
+  updateProjectV2ItemFieldValue(input: {projectId: $project, itemId: $item, fieldId: $field, value: $value}) { projectV2Item { id } }
+}"#,
+                serde_json::json!({
+                    "project": project_id,
+                    "item": item_id,
+                    "field": field_id,
+                    "value": value.to_input(),
+                }),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Clear the value of a field of an item.
+    pub async fn clear_field_value(
+        &self,
+        project_id: &str,
+        item_id: &str,
+        field_id: &str,
+    ) -> Result<()> {
+        let _: serde_json::Value = self
+            .client
+            .graphql()
+            .query(
+                r#"mutation($project: ID!, $item: ID!, $field: ID!) {
+  clearProjectV2ItemFieldValue(input: {projectId: $project, itemId: $item, fieldId: $field}) { projectV2Item { id } }
+}"#,
+                serde_json::json!({ "project": project_id, "item": item_id, "field": field_id }),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove an item from a project.
+    pub async fn delete_item(&self, project_id: &str, item_id: &str) -> Result<()> {
+        let _: serde_json::Value = self
+            .client
+            .graphql()
+            .query(
+                r#"mutation($project: ID!, $item: ID!) {
+  deleteProjectV2Item(input: {projectId: $project, itemId: $item}) { deletedItemId }
+}"#,
+                serde_json::json!({ "project": project_id, "item": item_id }),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// The ID at the given JSON pointer in the data of a response.
+fn node_id(data: &serde_json::Value, pointer: &str) -> Result<String> {
+    data.pointer(pointer)
+        .and_then(|id| id.as_str())
+        .map(|id| id.to_string())
+        .ok_or_else(|| anyhow!("graphql response has no id at `{}`", pointer))
+}
+
+/// The field values of an item, as selected under `fieldValues { nodes }`. The
+/// values of the types of fields that are not selected come as empty objects and
+/// are left out.
+fn deserialize_field_values<'de, D>(
+    deserializer: D,
+) -> Result<Vec<ProjectV2ItemFieldValue>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct FieldName {
+        #[serde(default)]
+        name: String,
+    }
+
+    #[derive(Deserialize)]
+    struct RawValue {
+        #[serde(default)]
+        field: Option<FieldName>,
+        #[serde(flatten)]
+        value: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let connection: Connection<RawValue> = Deserialize::deserialize(deserializer)?;
+    connection
+        .nodes
+        .into_iter()
+        .filter_map(|raw| {
+            let field = raw.field?;
+            let mut value = raw.value;
+            value.insert("field".to_string(), field.name.into());
+            Some(serde_json::from_value(value.into()).map_err(serde::de::Error::custom))
+        })
+        .collect()
+}
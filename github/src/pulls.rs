@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::review_builder::{diff_position, ReviewBuilder, ReviewEvent};
 use crate::Client;
 
 pub struct Pulls {
@@ -37,8 +38,8 @@ impl Pulls {
     */
     pub async fn list(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         state: crate::types::IssuesListState,
         head: &str,
         base: &str,
@@ -47,34 +48,37 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !base.is_empty() {
-            query_args.push(("base".to_string(), base.to_string()));
+            query_args.push(("base", base.to_string()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !head.is_empty() {
-            query_args.push(("head".to_string(), head.to_string()));
+            query_args.push(("head", head.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -93,36 +97,39 @@ impl Pulls {
     */
     pub async fn list_all(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         state: crate::types::IssuesListState,
         head: &str,
         base: &str,
         sort: crate::types::PullsListSort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !base.is_empty() {
-            query_args.push(("base".to_string(), base.to_string()));
+            query_args.push(("base", base.to_string()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !head.is_empty() {
-            query_args.push(("head".to_string(), head.to_string()));
+            query_args.push(("head", head.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -150,10 +157,12 @@ impl Pulls {
     */
     pub async fn create(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::PullsCreateRequest,
     ) -> Result<crate::types::PullRequestData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls",
             crate::progenitor_support::encode_path(owner),
@@ -188,36 +197,39 @@ impl Pulls {
     */
     pub async fn list_review_comments_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sort: crate::types::PullsListReviewCommentsRepoSort,
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -236,28 +248,31 @@ impl Pulls {
     */
     pub async fn list_all_review_comments_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sort: crate::types::PullsListReviewCommentsRepoSort,
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -280,15 +295,18 @@ impl Pulls {
     */
     pub async fn get_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
     ) -> Result<crate::types::PullRequestReviewComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/pulls/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.get(&url, None).await
@@ -311,15 +329,18 @@ impl Pulls {
     */
     pub async fn delete_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/pulls/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.delete(&url, None).await
@@ -342,16 +363,19 @@ impl Pulls {
     */
     pub async fn update_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::PullRequestReviewComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/pulls/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -390,15 +414,17 @@ impl Pulls {
     */
     pub async fn get(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
     ) -> Result<crate::types::PullRequestData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client.get(&url, None).await
@@ -423,16 +449,18 @@ impl Pulls {
     */
     pub async fn update(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         body: &crate::types::PullsUpdateRequest,
     ) -> Result<crate::types::PullRequestData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client
@@ -464,8 +492,8 @@ impl Pulls {
     */
     pub async fn list_review_comments(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         sort: crate::types::Sort,
         direction: crate::types::Order,
@@ -473,29 +501,32 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/{}/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -514,30 +545,33 @@ impl Pulls {
     */
     pub async fn list_all_review_comments(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         sort: crate::types::Sort,
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/{}/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -567,16 +601,18 @@ impl Pulls {
     */
     pub async fn create_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         body: &crate::types::PullsCreateReviewCommentRequest,
     ) -> Result<crate::types::PullRequestReviewComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/comments",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client
@@ -604,18 +640,21 @@ impl Pulls {
     */
     pub async fn create_reply_for_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
-        comment_id: i64,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::PullRequestReviewComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/pulls/{}/comments/{}/replies",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -642,26 +681,29 @@ impl Pulls {
     */
     pub async fn list_commits(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::CommitDataType>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/{}/commits?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -680,15 +722,17 @@ impl Pulls {
     */
     pub async fn list_all_commits(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
     ) -> Result<Vec<crate::types::CommitDataType>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/commits",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -713,26 +757,29 @@ impl Pulls {
     */
     pub async fn list_files(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::DiffEntry>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/{}/files?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/{}/files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -751,15 +798,17 @@ impl Pulls {
     */
     pub async fn list_all_files(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
     ) -> Result<Vec<crate::types::DiffEntry>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/files",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -770,6 +819,8 @@ impl Pulls {
     *
     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/merge` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/pulls#check-if-a-pull-request-has-been-merged>
@@ -780,15 +831,22 @@ impl Pulls {
     * * `repo: &str`
     * * `pull_number: i64`
     */
-    pub async fn check_if_merged(&self, owner: &str, repo: &str, pull_number: i64) -> Result<()> {
+    pub async fn check_if_merged(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        pull_number: i64,
+    ) -> Result<bool> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/merge",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -808,16 +866,18 @@ impl Pulls {
     */
     pub async fn merge(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         body: &crate::types::PullsMergeRequest,
     ) -> Result<crate::types::PullRequestMergeResult> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/merge",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client
@@ -844,26 +904,29 @@ impl Pulls {
     */
     pub async fn list_requested_reviewers(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::PullRequestReview> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/{}/requested_reviewers?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/{}/requested_reviewers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -886,16 +949,18 @@ impl Pulls {
     */
     pub async fn request_reviewers(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         body: &crate::types::PullsRequestReviewers,
     ) -> Result<crate::types::PullRequestSimple> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/requested_reviewers",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client
@@ -920,16 +985,18 @@ impl Pulls {
     */
     pub async fn remove_requested_reviewers(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         body: &crate::types::PullsRemoveRequestedReviewersRequest,
     ) -> Result<crate::types::PullRequestSimple> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/requested_reviewers",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client
@@ -956,26 +1023,29 @@ impl Pulls {
     */
     pub async fn list_reviews(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::PullRequestReviewData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/{}/reviews?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/{}/reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -994,15 +1064,17 @@ impl Pulls {
     */
     pub async fn list_all_reviews(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
     ) -> Result<Vec<crate::types::PullRequestReviewData>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1031,16 +1103,18 @@ impl Pulls {
     */
     pub async fn create_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         body: &crate::types::PullsCreateReviewRequest,
     ) -> Result<crate::types::PullRequestReviewData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client
@@ -1066,17 +1140,19 @@ impl Pulls {
     */
     pub async fn get_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         review_id: i64,
     ) -> Result<crate::types::PullRequestReviewData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&review_id.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
+            crate::progenitor_support::encode_path(&review_id),
         );
 
         self.client.get(&url, None).await
@@ -1100,18 +1176,20 @@ impl Pulls {
     */
     pub async fn update_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         review_id: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::PullRequestReviewData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&review_id.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
+            crate::progenitor_support::encode_path(&review_id),
         );
 
         self.client
@@ -1137,17 +1215,19 @@ impl Pulls {
     */
     pub async fn delete_pending_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         review_id: i64,
     ) -> Result<crate::types::PullRequestReviewData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&review_id.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
+            crate::progenitor_support::encode_path(&review_id),
         );
 
         self.client.delete(&url, None).await
@@ -1173,28 +1253,31 @@ impl Pulls {
     */
     pub async fn list_comments_for_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         review_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::ReviewComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/{}/reviews/{}/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&review_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number),
+                crate::progenitor_support::encode_path(&review_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1213,17 +1296,19 @@ impl Pulls {
     */
     pub async fn list_all_comments_for_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         review_id: i64,
     ) -> Result<Vec<crate::types::ReviewComment>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews/{}/comments",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&review_id.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
+            crate::progenitor_support::encode_path(&review_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1247,18 +1332,20 @@ impl Pulls {
     */
     pub async fn dismiss_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         review_id: i64,
         body: &crate::types::PullsDismissReviewRequest,
     ) -> Result<crate::types::PullRequestReviewData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews/{}/dismissals",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&review_id.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
+            crate::progenitor_support::encode_path(&review_id),
         );
 
         self.client
@@ -1284,18 +1371,20 @@ impl Pulls {
     */
     pub async fn submit_review(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         review_id: i64,
         body: &crate::types::PullsSubmitReviewRequest,
     ) -> Result<crate::types::PullRequestReviewData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/reviews/{}/events",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
-            crate::progenitor_support::encode_path(&review_id.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
+            crate::progenitor_support::encode_path(&review_id),
         );
 
         self.client
@@ -1320,16 +1409,18 @@ impl Pulls {
     */
     pub async fn update_branch(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         pull_number: i64,
         body: &crate::types::PullsUpdateBranchRequest,
     ) -> Result<crate::types::PullsUpdateBranchResponse> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pulls/{}/update-branch",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&pull_number),
         );
 
         self.client
@@ -0,0 +1,227 @@
+//! Keep track of the rate limit, and share it between critical and background work.
+//!
+//! The client remembers the rate limit GitHub sends with every response, which
+//! `Client::rate_limit_status` returns without making a request.
+//!
+//! A `RateBudget` keeps part of the requests of the rate limit for a critical path.
+//! Background jobs acquire a request from the budget before each call, and wait for
+//! the rate limit to reset once only the reserved requests are left. The critical
+//! path makes its requests as usual. Tasks sharing clones of a client share the
+//! same rate limit, so they all throttle together.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use octorust::rate_budget::RateBudget;
+//!
+//! // Keep 500 requests for the webhooks handler.
+//! let budget = RateBudget::new(&github, 500);
+//! for repo in ["one", "two", "three"] {
+//!     budget.acquire().await?;
+//!     github.repos().get("owner", repo).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Result;
+
+use crate::Client;
+
+const X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+const X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const X_RATELIMIT_RESET: &str = "x-ratelimit-reset";
+const X_RATELIMIT_USED: &str = "x-ratelimit-used";
+const X_RATELIMIT_RESOURCE: &str = "x-ratelimit-resource";
+
+/// The resource of the REST API, which most requests count against.
+const CORE: &str = "core";
+
+/// The state of the rate limit of a resource, like `core` or `search`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub resource: String,
+    /// The number of requests allowed per hour.
+    pub limit: u32,
+    pub remaining: u32,
+    pub used: u32,
+    /// When the rate limit resets, in seconds since the Unix epoch.
+    pub reset: u64,
+}
+
+impl RateLimitStatus {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let number = |name: &str| header(name).and_then(|v| v.parse::<u64>().ok());
+
+        let limit = number(X_RATELIMIT_LIMIT)? as u32;
+        let remaining = number(X_RATELIMIT_REMAINING)? as u32;
+        Some(RateLimitStatus {
+            resource: header(X_RATELIMIT_RESOURCE).unwrap_or(CORE).to_string(),
+            limit,
+            remaining,
+            used: number(X_RATELIMIT_USED)
+                .map(|u| u as u32)
+                .unwrap_or_else(|| limit.saturating_sub(remaining)),
+            reset: number(X_RATELIMIT_RESET)?,
+        })
+    }
+
+    fn from_rate_limit(resource: &str, rate: &crate::types::RateLimit) -> Self {
+        RateLimitStatus {
+            resource: resource.to_string(),
+            limit: rate.limit.max(0) as u32,
+            remaining: rate.remaining.max(0) as u32,
+            used: rate.used.max(0) as u32,
+            reset: rate.reset.max(0) as u64,
+        }
+    }
+
+    /// How long until the rate limit resets.
+    pub fn reset_in(&self) -> Duration {
+        Duration::from_secs(self.reset.saturating_sub(now()))
+    }
+}
+
+fn now() -> u64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// The last rate limits seen by a client and its clones, by resource.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimits {
+    statuses: Arc<Mutex<HashMap<String, RateLimitStatus>>>,
+}
+
+impl RateLimits {
+    /// Remember the rate limit in the headers of a response.
+    pub(crate) fn observe(&self, headers: &reqwest::header::HeaderMap) {
+        if let Some(status) = RateLimitStatus::from_headers(headers) {
+            self.set(status);
+        }
+    }
+
+    fn set(&self, status: RateLimitStatus) {
+        self.statuses
+            .lock()
+            .unwrap()
+            .insert(status.resource.clone(), status);
+    }
+
+    fn get(&self, resource: &str) -> Option<RateLimitStatus> {
+        self.statuses.lock().unwrap().get(resource).cloned()
+    }
+
+    /// Count a request against the rate limit of a resource, unless that would leave
+    /// fewer than `reserved` requests. Then return how long to wait for the reset.
+    fn try_acquire(&self, resource: &str, reserved: u32) -> Option<Duration> {
+        let mut statuses = self.statuses.lock().unwrap();
+        let status = statuses.get_mut(resource)?;
+
+        if status.reset <= now() {
+            // The next response tells when the new window resets.
+            status.remaining = status.limit;
+            status.used = 0;
+        }
+        if status.remaining <= reserved {
+            // Wait a bit past the reset, for clock skew.
+            return Some(status.reset_in() + Duration::from_secs(1));
+        }
+
+        status.remaining -= 1;
+        status.used += 1;
+        None
+    }
+}
+
+impl Client {
+    /// The rate limit of the REST API as of the last response, without making a
+    /// request. This is `None` until a response with the rate limit was received.
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.rate_limits.get(CORE)
+    }
+
+    /// The rate limit of a resource, like `search` or `graphql`, as of the last
+    /// response.
+    pub fn rate_limit_status_for(&self, resource: &str) -> Option<RateLimitStatus> {
+        self.rate_limits.get(resource)
+    }
+
+    /// Ask GitHub for the rate limits of all the resources, which does not count
+    /// against them, and return the one of the REST API.
+    pub async fn fetch_rate_limit_status(&self) -> Result<RateLimitStatus> {
+        let overview = self.rate_limit().get().await?;
+
+        let resources = &overview.resources;
+        let mut statuses = vec![
+            RateLimitStatus::from_rate_limit(CORE, &resources.core),
+            RateLimitStatus::from_rate_limit("search", &resources.search),
+        ];
+        for (resource, rate) in [
+            ("graphql", &resources.graphql),
+            ("code_scanning_upload", &resources.code_scanning_upload),
+            ("integration_manifest", &resources.integration_manifest),
+            ("source_import", &resources.source_import),
+        ] {
+            if let Some(rate) = rate {
+                statuses.push(RateLimitStatus::from_rate_limit(resource, rate));
+            }
+        }
+
+        let core = statuses[0].clone();
+        for status in statuses {
+            self.rate_limits.set(status);
+        }
+        Ok(core)
+    }
+}
+
+/// A share of the rate limit of the REST API for background work, which leaves a
+/// number of requests for a critical path.
+///
+/// Clones share the rate limit of the client, and the reserve.
+#[derive(Clone)]
+pub struct RateBudget {
+    client: Client,
+    reserved: u32,
+}
+
+impl RateBudget {
+    /// A budget leaving `reserved` requests of the rate limit of the client.
+    pub fn new(client: &Client, reserved: u32) -> Self {
+        RateBudget {
+            client: client.clone(),
+            reserved,
+        }
+    }
+
+    /// The number of requests the background work can make before the reserve,
+    /// if the rate limit is known.
+    pub fn available(&self) -> Option<u32> {
+        self.client
+            .rate_limit_status()
+            .map(|s| s.remaining.saturating_sub(self.reserved))
+    }
+
+    /// Count a request against the budget, waiting for the rate limit to reset when
+    /// only the reserved requests are left.
+    pub async fn acquire(&self) -> Result<()> {
+        if self.client.rate_limit_status().is_none() {
+            self.client.fetch_rate_limit_status().await?;
+        }
+
+        while let Some(wait) = self.client.rate_limits.try_acquire(CORE, self.reserved) {
+            log::debug!("rate budget exhausted, waiting {:?} for the reset", wait);
+            crate::runtime::sleep(wait).await;
+        }
+
+        Ok(())
+    }
+}
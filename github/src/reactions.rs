@@ -35,32 +35,35 @@ impl Reactions {
     */
     pub async fn list_for_team_discussion_comment_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         comment_number: i64,
         content: crate::types::Content,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number),
+                crate::progenitor_support::encode_path(&comment_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -81,24 +84,27 @@ impl Reactions {
     */
     pub async fn list_all_for_team_discussion_comment_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         comment_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number),
+                crate::progenitor_support::encode_path(&comment_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -124,18 +130,20 @@ impl Reactions {
     */
     pub async fn create_for_team_discussion_comment_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         comment_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> Result<crate::types::Reaction> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client
@@ -164,19 +172,21 @@ impl Reactions {
     */
     pub async fn delete_for_team_discussion_comment(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         comment_number: i64,
         reaction_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
-            crate::progenitor_support::encode_path(&reaction_id.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
+            crate::progenitor_support::encode_path(&reaction_id),
         );
 
         self.client.delete(&url, None).await
@@ -204,30 +214,33 @@ impl Reactions {
     */
     pub async fn list_for_team_discussion_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         content: crate::types::Content,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions/{}/reactions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -248,22 +261,25 @@ impl Reactions {
     */
     pub async fn list_all_for_team_discussion_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions/{}/reactions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -288,16 +304,18 @@ impl Reactions {
     */
     pub async fn create_for_team_discussion_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> Result<crate::types::Reaction> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/reactions",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client
@@ -325,17 +343,19 @@ impl Reactions {
     */
     pub async fn delete_for_team_discussion(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         reaction_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/reactions/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&reaction_id.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&reaction_id),
         );
 
         self.client.delete(&url, None).await
@@ -359,7 +379,7 @@ impl Reactions {
     pub async fn delete_legacy(&self, reaction_id: i64) -> Result<()> {
         let url = format!(
             "/reactions/{}",
-            crate::progenitor_support::encode_path(&reaction_id.to_string()),
+            crate::progenitor_support::encode_path(&reaction_id),
         );
 
         self.client.delete(&url, None).await
@@ -385,30 +405,34 @@ impl Reactions {
     */
     pub async fn list_for_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         content: crate::types::Content,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -427,22 +451,26 @@ impl Reactions {
     */
     pub async fn list_all_for_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -465,16 +493,19 @@ impl Reactions {
     */
     pub async fn create_for_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> Result<crate::types::Reaction> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/comments/{}/reactions",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -502,17 +533,20 @@ impl Reactions {
     */
     pub async fn delete_for_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         reaction_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/comments/{}/reactions/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            crate::progenitor_support::encode_path(&reaction_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
+            crate::progenitor_support::encode_path(&reaction_id),
         );
 
         self.client.delete(&url, None).await
@@ -538,30 +572,34 @@ impl Reactions {
     */
     pub async fn list_for_issue_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         content: crate::types::Content,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -580,22 +618,26 @@ impl Reactions {
     */
     pub async fn list_all_for_issue_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -618,16 +660,19 @@ impl Reactions {
     */
     pub async fn create_for_issue_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> Result<crate::types::Reaction> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/issues/comments/{}/reactions",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -655,17 +700,20 @@ impl Reactions {
     */
     pub async fn delete_for_issue_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         reaction_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/issues/comments/{}/reactions/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            crate::progenitor_support::encode_path(&reaction_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
+            crate::progenitor_support::encode_path(&reaction_id),
         );
 
         self.client.delete(&url, None).await
@@ -691,30 +739,33 @@ impl Reactions {
     */
     pub async fn list_for_issue(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         content: crate::types::Content,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -733,22 +784,25 @@ impl Reactions {
     */
     pub async fn list_all_for_issue(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/issues/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/issues/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -771,16 +825,18 @@ impl Reactions {
     */
     pub async fn create_for_issue(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> Result<crate::types::Reaction> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/reactions",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
         );
 
         self.client
@@ -808,17 +864,19 @@ impl Reactions {
     */
     pub async fn delete_for_issue(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         issue_number: i64,
         reaction_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/issues/{}/reactions/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&issue_number.to_string()),
-            crate::progenitor_support::encode_path(&reaction_id.to_string()),
+            crate::progenitor_support::encode_path(&issue_number),
+            crate::progenitor_support::encode_path(&reaction_id),
         );
 
         self.client.delete(&url, None).await
@@ -844,30 +902,34 @@ impl Reactions {
     */
     pub async fn list_for_pull_request_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         content: crate::types::Content,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -886,22 +948,26 @@ impl Reactions {
     */
     pub async fn list_all_for_pull_request_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pulls/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pulls/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -924,16 +990,19 @@ impl Reactions {
     */
     pub async fn create_for_pull_request_review_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> Result<crate::types::Reaction> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/pulls/comments/{}/reactions",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -961,17 +1030,20 @@ impl Reactions {
     */
     pub async fn delete_for_pull_request_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         reaction_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/pulls/comments/{}/reactions/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            crate::progenitor_support::encode_path(&reaction_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
+            crate::progenitor_support::encode_path(&reaction_id),
         );
 
         self.client.delete(&url, None).await
@@ -994,16 +1066,18 @@ impl Reactions {
     */
     pub async fn create_for_release(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         release_id: i64,
         body: &crate::types::ReactionsCreateReleaseRequest,
     ) -> Result<crate::types::Reaction> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/{}/reactions",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&release_id.to_string()),
+            crate::progenitor_support::encode_path(&release_id),
         );
 
         self.client
@@ -1040,23 +1114,24 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions/{}/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id),
+                crate::progenitor_support::encode_path(&discussion_number),
+                crate::progenitor_support::encode_path(&comment_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1082,17 +1157,18 @@ impl Reactions {
         comment_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions/{}/comments/{}/reactions?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id),
+                crate::progenitor_support::encode_path(&discussion_number),
+                crate::progenitor_support::encode_path(&comment_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1124,9 +1200,9 @@ impl Reactions {
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/teams/{}/discussions/{}/comments/{}/reactions",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client
@@ -1161,22 +1237,23 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions/{}/reactions?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1201,16 +1278,17 @@ impl Reactions {
         discussion_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+            query_args.push(("content", content.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions/{}/reactions?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1240,8 +1318,8 @@ impl Reactions {
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/teams/{}/discussions/{}/reactions",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client
@@ -0,0 +1,68 @@
+//! Streaming uploads of release assets.
+//!
+//! https://docs.github.com/en/rest/reference/repos#upload-a-release-asset
+use anyhow::Result;
+
+use crate::repos::Repos;
+
+impl Repos {
+    /**
+     * Upload a release asset from a stream.
+     *
+     * Like `upload_release_asset`, but the asset is streamed from `body` instead of
+     * being held in memory. GitHub needs to know the size of the asset up front, so
+     * it must be passed as `content_length`.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `release_id: i64` -- release_id parameter.
+     * * `name: &str`
+     * * `label: &str`
+     * * `content_type: &str` -- The media type of the asset, for example `application/zip`.
+     * * `content_length: u64` -- The size of the asset in bytes.
+     */
+    pub async fn upload_release_asset_stream<S>(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        name: &str,
+        label: &str,
+        content_type: &str,
+        content_length: u64,
+        body: S,
+    ) -> Result<crate::types::ReleaseAsset>
+    where
+        S: futures::TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        bytes::Bytes: From<S::Ok>,
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !label.is_empty() {
+            query_args.push(("label", label.to_string()));
+        }
+        if !name.is_empty() {
+            query_args.push(("name", name.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id),
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post_upload(
+                &url,
+                content_type,
+                Some(content_length),
+                reqwest::Body::wrap_stream(body),
+            )
+            .await
+    }
+}
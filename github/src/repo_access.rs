@@ -0,0 +1,341 @@
+//! Manage who and what can access a repository: its deploy keys and the secrets
+//! of Dependabot.
+//!
+//! Deploy keys can not be updated, so a key is kept as long as it is the one
+//! requested and is deleted and added again otherwise. Dependabot secrets are
+//! encrypted like the secrets of Actions, with the public key of the repository.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use octorust::types::ReposCreateDeployKeyRequest;
+//!
+//! let deploy = ReposCreateDeployKeyRequest {
+//!     key: "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIBc8Il9Vz1Sm3ZNUxlTyyh9DSt1F6HpL deploy".to_string(),
+//!     read_only: Some(true),
+//!     title: "deploy".to_string(),
+//! };
+//! github.repos().sync_deploy_keys("owner", "repo", &[deploy]).await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+
+use crate::{
+    repos::Repos,
+    types::{
+        ActionsCreateUpdateRepoSecretRequest, ActionsListRepoSecretsResponse, ActionsPublicKey,
+        ActionsSecret, DeployKey, ReposCreateDeployKeyRequest,
+    },
+};
+
+/// The type and the data of an SSH public key, without its comment, which GitHub
+/// does not keep.
+fn key_material(key: &str) -> String {
+    key.split_whitespace().take(2).collect::<Vec<_>>().join(" ")
+}
+
+/// Whether the deploy key is the one requested. The access is only compared when
+/// it was requested.
+fn is_requested(key: &DeployKey, request: &ReposCreateDeployKeyRequest) -> bool {
+    key_material(&key.key) == key_material(&request.key)
+        && key.title == request.title
+        && request
+            .read_only
+            .map_or(true, |read_only| read_only == key.read_only)
+}
+
+impl Repos {
+    /**
+     * Add a deploy key to a repository, unless it has it already.
+     *
+     * The key is found among the deploy keys of the repository by its type and its
+     * data. When its title or its access differ from the requested ones, it is
+     * deleted and added again.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `key: &crate::types::ReposCreateDeployKeyRequest` -- The deploy key to add.
+     */
+    pub async fn ensure_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        key: &ReposCreateDeployKeyRequest,
+    ) -> Result<DeployKey> {
+        let current = self.list_all_deploy_keys(owner, repo).await?;
+        self.ensure_deploy_key_among(owner, repo, &current, key)
+            .await
+    }
+
+    /**
+     * Make the deploy keys of a repository the given ones.
+     *
+     * The keys which are missing are added, the ones which differ are added again
+     * and the ones which are not given are deleted. Returns the deploy keys of the
+     * repository, in the order of `keys`.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `keys: &[crate::types::ReposCreateDeployKeyRequest]` -- All the deploy keys the repository should have.
+     */
+    pub async fn sync_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+        keys: &[ReposCreateDeployKeyRequest],
+    ) -> Result<Vec<DeployKey>> {
+        let current = self.list_all_deploy_keys(owner, repo).await?;
+        for k in &current {
+            let material = key_material(&k.key);
+            if !keys.iter().any(|key| key_material(&key.key) == material) {
+                self.delete_deploy_key(owner, repo, k.id).await?;
+            }
+        }
+
+        let mut synced = Vec::with_capacity(keys.len());
+        for key in keys {
+            synced.push(
+                self.ensure_deploy_key_among(owner, repo, &current, key)
+                    .await?,
+            );
+        }
+
+        Ok(synced)
+    }
+
+    async fn ensure_deploy_key_among(
+        &self,
+        owner: &str,
+        repo: &str,
+        current: &[DeployKey],
+        key: &ReposCreateDeployKeyRequest,
+    ) -> Result<DeployKey> {
+        let material = key_material(&key.key);
+        for k in current.iter().filter(|k| key_material(&k.key) == material) {
+            if is_requested(k, key) {
+                return Ok(k.clone());
+            }
+            // A repository can not have the same key twice.
+            self.delete_deploy_key(owner, repo, k.id).await?;
+        }
+
+        self.create_deploy_key(owner, repo, key).await
+    }
+
+    /**
+     * List Dependabot repository secrets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/dependabot/secrets` endpoint.
+     *
+     * Lists all secrets available to Dependabot in a repository without revealing their
+     * encrypted values.
+     *
+     * FROM: <https://docs.github.com/rest/reference/dependabot#list-repository-secrets>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `per_page: i64` -- Results per page (max 100).
+     * * `page: i64` -- Page number of the results to fetch.
+     */
+    pub async fn list_dependabot_secrets(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<ActionsListRepoSecretsResponse> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if page > 0 {
+            query_args.push(("page", page.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page", per_page.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/dependabot/secrets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * Get a Dependabot repository public key.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/dependabot/secrets/public-key` endpoint.
+     *
+     * Gets the public key to encrypt the Dependabot secrets of the repository with.
+     *
+     * FROM: <https://docs.github.com/rest/reference/dependabot#get-a-repository-public-key>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     */
+    pub async fn get_dependabot_public_key(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<ActionsPublicKey> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let url = format!(
+            "/repos/{}/{}/dependabot/secrets/public-key",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * Get a Dependabot repository secret.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/dependabot/secrets/{secret_name}` endpoint.
+     *
+     * Gets a single Dependabot secret of the repository without revealing its encrypted
+     * value.
+     *
+     * FROM: <https://docs.github.com/rest/reference/dependabot#get-a-repository-secret>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `secret_name: &str` -- secret_name parameter.
+     */
+    pub async fn get_dependabot_secret(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+    ) -> Result<ActionsSecret> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let secret_name = secret_name.as_ref();
+        let url = format!(
+            "/repos/{}/{}/dependabot/secrets/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(secret_name),
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * Create or update a Dependabot repository secret.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/dependabot/secrets/{secret_name}` endpoint.
+     *
+     * Creates or updates a Dependabot secret of the repository with a value encrypted
+     * with the public key from `get_dependabot_public_key`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/dependabot#create-or-update-a-repository-secret>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `secret_name: &str` -- secret_name parameter.
+     */
+    pub async fn create_or_update_dependabot_secret(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+        body: &ActionsCreateUpdateRepoSecretRequest,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let secret_name = secret_name.as_ref();
+        let url = format!(
+            "/repos/{}/{}/dependabot/secrets/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(secret_name),
+        );
+
+        self.client
+            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .await
+    }
+
+    /**
+     * Create or update a Dependabot repository secret from its plain text value.
+     *
+     * Fetches the Dependabot public key of the repository, encrypts the value with it
+     * and stores the secret with `create_or_update_dependabot_secret`.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `secret_name: &str` -- secret_name parameter.
+     * * `value: &str` -- The plain text value of the secret.
+     */
+    #[cfg(feature = "sodium")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sodium")))]
+    pub async fn create_or_update_dependabot_secret_plaintext(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+        value: &str,
+    ) -> Result<()> {
+        let public_key = self.get_dependabot_public_key(owner, repo).await?;
+
+        self.create_or_update_dependabot_secret(
+            owner,
+            repo,
+            secret_name,
+            &crate::sealed_box::secret_request(public_key, value)?,
+        )
+        .await
+    }
+
+    /**
+     * Delete a Dependabot repository secret.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/dependabot/secrets/{secret_name}` endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/dependabot#delete-a-repository-secret>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `secret_name: &str` -- secret_name parameter.
+     */
+    pub async fn delete_dependabot_secret(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let secret_name = secret_name.as_ref();
+        let url = format!(
+            "/repos/{}/{}/dependabot/secrets/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(secret_name),
+        );
+
+        self.client.delete(&url, None).await
+    }
+}
@@ -0,0 +1,175 @@
+//! Go through all the repositories of an organization or a user.
+//!
+//! The pages of the listing are requested as the stream is polled, and the
+//! repositories are filtered on the client for the properties the API can not
+//! filter on.
+use std::{collections::VecDeque, fmt, sync::Arc};
+
+use anyhow::Result;
+
+use crate::{repos::Repos, types::MinimalRepository, Client};
+
+type Predicate = Arc<dyn Fn(&MinimalRepository) -> bool + Send + Sync>;
+
+/**
+ * A listing of the repositories of an organization or a user, created with
+ * `Repos::iter_for_org` or `Repos::iter_for_user`.
+ *
+ * ```no_run
+ * # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+ * use futures::TryStreamExt;
+ * use octorust::types::ReposListOrgType;
+ *
+ * let mut repos = Box::pin(
+ *     github
+ *         .repos()
+ *         .iter_for_org("oxidecomputer")
+ *         .type_(ReposListOrgType::Private)
+ *         .language("rust")
+ *         .archived(false)
+ *         .stream(),
+ * );
+ * while let Some(repo) = repos.try_next().await? {
+ *     println!("{}", repo.full_name);
+ * }
+ * # Ok(())
+ * # }
+ * ```
+ */
+#[derive(Clone)]
+pub struct RepoIter {
+    client: Client,
+    uri: String,
+    query: Vec<(String, String)>,
+    filters: Vec<Predicate>,
+}
+
+impl Repos {
+    /// List the repositories of an organization, getting the pages as they are needed.
+    pub fn iter_for_org(&self, org: &str) -> RepoIter {
+        RepoIter::new(
+            self.client.clone(),
+            format!(
+                "/orgs/{}/repos",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// List the public repositories of a user, getting the pages as they are needed.
+    pub fn iter_for_user(&self, username: &str) -> RepoIter {
+        RepoIter::new(
+            self.client.clone(),
+            format!(
+                "/users/{}/repos",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+impl RepoIter {
+    fn new(client: Client, uri: String) -> Self {
+        RepoIter {
+            client,
+            uri,
+            query: vec![("per_page".to_string(), "100".to_string())],
+            filters: Vec::new(),
+        }
+    }
+
+    fn param<T: fmt::Display>(mut self, name: &str, value: T) -> Self {
+        let value = value.to_string();
+        self.query.retain(|(n, _)| n != name);
+        if !value.is_empty() {
+            self.query.push((name.to_string(), value));
+        }
+        self
+    }
+
+    /// Only list the repositories of the given type, for example
+    /// `ReposListOrgType::Private` or `ReposListUserType::Owner`.
+    pub fn type_<T: fmt::Display>(self, type_: T) -> Self {
+        self.param("type", type_)
+    }
+
+    /// The property to sort the repositories by.
+    pub fn sort(self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.param("sort", sort)
+    }
+
+    /// The direction to sort the repositories in.
+    pub fn direction(self, direction: crate::types::Order) -> Self {
+        self.param("direction", direction)
+    }
+
+    /// The number of repositories requested per page, at most 100.
+    pub fn per_page(self, per_page: u8) -> Self {
+        self.param("per_page", per_page.min(100))
+    }
+
+    /// Only keep the repositories for which the predicate returns `true`.
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&MinimalRepository) -> bool + Send + Sync + 'static,
+    {
+        self.filters.push(Arc::new(predicate));
+        self
+    }
+
+    /// Only keep the repositories whose main language is the given one, ignoring case.
+    pub fn language(self, language: &str) -> Self {
+        let language = language.to_string();
+        self.filter(move |repo| repo.language.eq_ignore_ascii_case(&language))
+    }
+
+    /// Only keep the repositories with the given topic.
+    pub fn topic(self, topic: &str) -> Self {
+        let topic = topic.to_string();
+        self.filter(move |repo| repo.topics.contains(&topic))
+    }
+
+    /// Only keep the repositories that are archived, or the ones that are not.
+    pub fn archived(self, archived: bool) -> Self {
+        self.filter(move |repo| repo.archived == archived)
+    }
+
+    /// Only keep the repositories that are forks, or the ones that are not.
+    pub fn fork(self, fork: bool) -> Self {
+        self.filter(move |repo| repo.fork == fork)
+    }
+
+    /// Returns the repositories that pass all the filters, requesting the next page
+    /// when the repositories of the previous one were handed out.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<MinimalRepository>> {
+        let first = format!(
+            "{}{}?{}",
+            self.client.host,
+            self.uri,
+            serde_urlencoded::to_string(&self.query).unwrap()
+        );
+        let state = (self, Some(first), VecDeque::new());
+
+        futures::stream::try_unfold(state, |(iter, mut next, mut pending)| async move {
+            loop {
+                if let Some(repo) = pending.pop_front() {
+                    return Ok(Some((repo, (iter, next, pending))));
+                }
+
+                let url = match next.take() {
+                    Some(url) => url,
+                    None => return Ok(None),
+                };
+                let url = reqwest::Url::parse(&url)?;
+                let (link, page): (_, Vec<MinimalRepository>) =
+                    iter.client.get_pages_url(&url).await?;
+                next = link.as_ref().and_then(crate::utils::next_link);
+
+                pending.extend(
+                    page.into_iter()
+                        .filter(|repo| iter.filters.iter().all(|keep| keep(repo))),
+                );
+            }
+        })
+    }
+}
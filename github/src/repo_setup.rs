@@ -0,0 +1,230 @@
+//! Create a repository from a template and configure it in one call.
+//!
+//! GitHub creates the repository before its content is copied from the template,
+//! so the requests configuring it can fail for a while with `404 Not Found` or
+//! `409 Conflict`. These requests are retried until `RepoSetup::ready_timeout` has
+//! elapsed.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use octorust::{
+//!     repo_setup::RepoSetup,
+//!     repos::BranchProtectionBuilder,
+//!     types::{
+//!         IssuesCreateLabelRequest, ReposCreateUsingTemplateRequest,
+//!         TeamsAddUpdateRepoPermissionsInOrgRequestPermission,
+//!     },
+//! };
+//!
+//! let setup = RepoSetup {
+//!     labels: vec![IssuesCreateLabelRequest {
+//!         name: "incident".to_string(),
+//!         color: "b60205".to_string(),
+//!         description: String::new(),
+//!     }],
+//!     branch_protection: vec![(
+//!         "main".to_string(),
+//!         BranchProtectionBuilder::new().require_reviews(1),
+//!     )],
+//!     topics: vec!["service".to_string()],
+//!     team_permissions: vec![(
+//!         "platform".to_string(),
+//!         TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Maintain,
+//!     )],
+//!     ..Default::default()
+//! };
+//!
+//! let body = ReposCreateUsingTemplateRequest {
+//!     owner: "org".to_string(),
+//!     name: "payments".to_string(),
+//!     description: String::new(),
+//!     include_all_branches: None,
+//!     private: Some(true),
+//! };
+//! github
+//!     .repos()
+//!     .create_from_template_configured("org", "service-template", &body, &setup)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use std::{future::Future, time::Duration};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    branch_protection::BranchProtectionBuilder,
+    error::ApiError,
+    issues::Issues,
+    repos::Repos,
+    teams::Teams,
+    types::{
+        IssuesCreateLabelRequest, IssuesUpdateLabelRequest, ReposCreateUsingTemplateRequest,
+        ReposCreateWebhookRequest, Repository, TeamsAddUpdateRepoPermissionsInOrgRequest,
+        TeamsAddUpdateRepoPermissionsInOrgRequestPermission, Topic,
+    },
+};
+
+/// How a new repository is configured after it is created.
+#[derive(Debug, Clone)]
+pub struct RepoSetup {
+    /// The labels to create, or to update when the template has them already.
+    pub labels: Vec<IssuesCreateLabelRequest>,
+    /// The protection of branches, by branch name.
+    pub branch_protection: Vec<(String, BranchProtectionBuilder)>,
+    /// The topics of the repository. Left as they are when empty.
+    pub topics: Vec<String>,
+    pub webhooks: Vec<ReposCreateWebhookRequest>,
+    /// The permissions of teams of the organization owning the repository, by team
+    /// slug.
+    pub team_permissions: Vec<(String, TeamsAddUpdateRepoPermissionsInOrgRequestPermission)>,
+    /// How long to retry the requests which fail because the repository is not
+    /// ready yet.
+    pub ready_timeout: Duration,
+    /// Time to wait between two attempts.
+    pub poll_interval: Duration,
+}
+
+impl Default for RepoSetup {
+    fn default() -> Self {
+        RepoSetup {
+            labels: Default::default(),
+            branch_protection: Default::default(),
+            topics: Default::default(),
+            webhooks: Default::default(),
+            team_permissions: Default::default(),
+            ready_timeout: Duration::from_secs(60),
+            poll_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RepoSetup {
+    /// Run `request` until it does not fail because the repository is not ready.
+    async fn when_ready<T, F, Fut>(&self, deadline: web_time::Instant, mut request: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        loop {
+            let err = match request().await {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+            let not_ready = matches!(
+                err.downcast_ref::<ApiError>(),
+                Some(e) if e.status == reqwest::StatusCode::NOT_FOUND
+                    || e.status == reqwest::StatusCode::CONFLICT
+            );
+            if !not_ready || web_time::Instant::now() + self.poll_interval > deadline {
+                return Err(err);
+            }
+            crate::runtime::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+impl Repos {
+    /**
+     * Create a repository from a template, then configure it.
+     *
+     * Once the repository is created, the labels are created, the topics replaced,
+     * the branches protected, the webhooks created and the teams given access, in
+     * that order. The first request failing for another reason than the repository
+     * not being ready stops the configuration, and its error is returned. The
+     * repository is not deleted then.
+     *
+     * **Parameters:**
+     *
+     * * `template_owner: &str`
+     * * `template_repo: &str`
+     * * `body: &crate::types::ReposCreateUsingTemplateRequest` -- The repository to create.
+     * * `setup: &RepoSetup` -- How to configure the repository.
+     */
+    pub async fn create_from_template_configured(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        body: &ReposCreateUsingTemplateRequest,
+        setup: &RepoSetup,
+    ) -> Result<Repository> {
+        let repository = self
+            .create_using_template(template_owner, template_repo, body)
+            .await?;
+        let deadline = web_time::Instant::now() + setup.ready_timeout;
+
+        let owner = match &repository.owner {
+            Some(owner) => owner.login.to_string(),
+            None => body.owner.to_string(),
+        };
+        let owner = owner.as_str();
+        let repo = repository.name.as_str();
+        if owner.is_empty() {
+            return Err(anyhow!("the owner of repository `{}` is unknown", repo));
+        }
+
+        let issues = &Issues::new(self.client.clone());
+        for label in &setup.labels {
+            setup
+                .when_ready(deadline, || async move {
+                    match issues.create_label(owner, repo, label).await {
+                        Err(err)
+                            if matches!(
+                                err.downcast_ref::<ApiError>(),
+                                Some(e) if e.has_code("already_exists")
+                            ) =>
+                        {
+                            let update = IssuesUpdateLabelRequest {
+                                color: label.color.to_string(),
+                                description: label.description.to_string(),
+                                new_name: String::new(),
+                            };
+                            issues.update_label(owner, repo, &label.name, &update).await
+                        }
+                        result => result,
+                    }
+                })
+                .await?;
+        }
+
+        if !setup.topics.is_empty() {
+            let topics = Topic {
+                names: setup.topics.clone(),
+                extra: Default::default(),
+            };
+            setup
+                .when_ready(deadline, || self.replace_all_topics(owner, repo, &topics))
+                .await?;
+        }
+
+        for (branch, protection) in &setup.branch_protection {
+            setup
+                .when_ready(deadline, || {
+                    self.protect_branch(owner, repo, branch, protection)
+                })
+                .await?;
+        }
+
+        for webhook in &setup.webhooks {
+            setup
+                .when_ready(deadline, || self.create_webhook(owner, repo, webhook))
+                .await?;
+        }
+
+        let teams = Teams::new(self.client.clone());
+        for (team_slug, permission) in &setup.team_permissions {
+            let request = TeamsAddUpdateRepoPermissionsInOrgRequest {
+                permission: Some(permission.clone()),
+            };
+            setup
+                .when_ready(deadline, || {
+                    teams.add_or_update_repo_permissions_in_org(
+                        owner, team_slug, owner, repo, &request,
+                    )
+                })
+                .await?;
+        }
+
+        Ok(repository)
+    }
+}
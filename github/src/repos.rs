@@ -1,6 +1,9 @@
 use anyhow::Result;
 
+#[cfg(feature = "checks")]
+pub use crate::commit_status::{CommitState, CommitStatusSummary, STATUS_POLL_INTERVAL};
 use crate::Client;
+pub use crate::{branch_protection::BranchProtectionBuilder, repo_iter::RepoIter};
 
 pub struct Repos {
     pub client: Client,
@@ -34,34 +37,36 @@ impl Repos {
     */
     pub async fn list_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         type_: crate::types::ReposListOrgType,
         sort: crate::types::ReposListOrgSort,
         direction: crate::types::Order,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/repos?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/repos",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -80,26 +85,28 @@ impl Repos {
     */
     pub async fn list_all_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         type_: crate::types::ReposListOrgType,
         sort: crate::types::ReposListOrgSort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/repos?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/repos",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -127,9 +134,10 @@ impl Repos {
     */
     pub async fn create_in_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::ReposCreateInOrgRequest,
     ) -> Result<crate::types::Repository> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/repos",
             crate::progenitor_support::encode_path(org),
@@ -156,7 +164,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn get(&self, owner: &str, repo: &str) -> Result<crate::types::FullRepository> {
+    pub async fn get(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<crate::types::FullRepository> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}",
             crate::progenitor_support::encode_path(owner),
@@ -183,7 +197,9 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn delete(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn delete(&self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}",
             crate::progenitor_support::encode_path(owner),
@@ -209,10 +225,12 @@ impl Repos {
     */
     pub async fn update(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposUpdateRequest,
     ) -> Result<crate::types::FullRepository> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}",
             crate::progenitor_support::encode_path(owner),
@@ -243,20 +261,23 @@ impl Repos {
     */
     pub async fn list_autolinks(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         page: i64,
     ) -> Result<Vec<crate::types::Autolink>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/autolinks?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/autolinks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -277,9 +298,11 @@ impl Repos {
     */
     pub async fn list_all_autolinks(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Autolink>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/autolinks",
             crate::progenitor_support::encode_path(owner),
@@ -305,10 +328,12 @@ impl Repos {
     */
     pub async fn create_autolink(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreateAutolinkRequest,
     ) -> Result<crate::types::Autolink> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/autolinks",
             crate::progenitor_support::encode_path(owner),
@@ -339,15 +364,17 @@ impl Repos {
     */
     pub async fn get_autolink(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         autolink_id: i64,
     ) -> Result<crate::types::Autolink> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/autolinks/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&autolink_id.to_string()),
+            crate::progenitor_support::encode_path(&autolink_id),
         );
 
         self.client.get(&url, None).await
@@ -370,12 +397,19 @@ impl Repos {
     * * `repo: &str`
     * * `autolink_id: i64` -- autolink_id parameter.
     */
-    pub async fn delete_autolink(&self, owner: &str, repo: &str, autolink_id: i64) -> Result<()> {
+    pub async fn delete_autolink(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        autolink_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/autolinks/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&autolink_id.to_string()),
+            crate::progenitor_support::encode_path(&autolink_id),
         );
 
         self.client.delete(&url, None).await
@@ -395,7 +429,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn enable_automated_security_fixes(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn enable_automated_security_fixes(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/automated-security-fixes",
             crate::progenitor_support::encode_path(owner),
@@ -419,7 +459,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn disable_automated_security_fixes(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn disable_automated_security_fixes(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/automated-security-fixes",
             crate::progenitor_support::encode_path(owner),
@@ -448,28 +494,31 @@ impl Repos {
     */
     pub async fn list_branches(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         protected: bool,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::ShortBranch>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if protected {
-            query_args.push(("protected".to_string(), protected.to_string()));
+            query_args.push(("protected", protected.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/branches?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/branches",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -488,20 +537,23 @@ impl Repos {
     */
     pub async fn list_all_branches(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         protected: bool,
     ) -> Result<Vec<crate::types::ShortBranch>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if protected {
-            query_args.push(("protected".to_string(), protected.to_string()));
+            query_args.push(("protected", protected.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/branches?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/branches",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -524,10 +576,13 @@ impl Repos {
     */
     pub async fn get_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::BranchWithProtection> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}",
             crate::progenitor_support::encode_path(owner),
@@ -555,10 +610,13 @@ impl Repos {
     */
     pub async fn get_branch_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::BranchProtection> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection",
             crate::progenitor_support::encode_path(owner),
@@ -592,11 +650,14 @@ impl Repos {
     */
     pub async fn update_branch_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposUpdateBranchProtectionRequest,
     ) -> Result<crate::types::ProtectedBranch> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection",
             crate::progenitor_support::encode_path(owner),
@@ -626,10 +687,13 @@ impl Repos {
     */
     pub async fn delete_branch_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection",
             crate::progenitor_support::encode_path(owner),
@@ -657,10 +721,13 @@ impl Repos {
     */
     pub async fn get_admin_branch_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::EnforceAdmins> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/enforce_admins",
             crate::progenitor_support::encode_path(owner),
@@ -690,10 +757,13 @@ impl Repos {
     */
     pub async fn set_admin_branch_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::EnforceAdmins> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/enforce_admins",
             crate::progenitor_support::encode_path(owner),
@@ -723,10 +793,13 @@ impl Repos {
     */
     pub async fn delete_admin_branch_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/enforce_admins",
             crate::progenitor_support::encode_path(owner),
@@ -754,10 +827,13 @@ impl Repos {
     */
     pub async fn get_pull_request_review_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::ProtectedBranchPullRequestReview> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
             crate::progenitor_support::encode_path(owner),
@@ -785,10 +861,13 @@ impl Repos {
     */
     pub async fn delete_pull_request_review_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
             crate::progenitor_support::encode_path(owner),
@@ -820,11 +899,14 @@ impl Repos {
     */
     pub async fn update_pull_request_review_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposUpdatePullRequestReviewProtection,
     ) -> Result<crate::types::ProtectedBranchPullRequestReview> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
             crate::progenitor_support::encode_path(owner),
@@ -858,10 +940,13 @@ impl Repos {
     */
     pub async fn get_commit_signature_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::EnforceAdmins> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_signatures",
             crate::progenitor_support::encode_path(owner),
@@ -891,10 +976,13 @@ impl Repos {
     */
     pub async fn create_commit_signature_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::EnforceAdmins> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_signatures",
             crate::progenitor_support::encode_path(owner),
@@ -924,10 +1012,13 @@ impl Repos {
     */
     pub async fn delete_commit_signature_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_signatures",
             crate::progenitor_support::encode_path(owner),
@@ -955,10 +1046,13 @@ impl Repos {
     */
     pub async fn get_status_checks_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::StatusCheckPolicy> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks",
             crate::progenitor_support::encode_path(owner),
@@ -986,10 +1080,13 @@ impl Repos {
     */
     pub async fn remove_status_check_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks",
             crate::progenitor_support::encode_path(owner),
@@ -1019,11 +1116,14 @@ impl Repos {
     */
     pub async fn update_status_check_protection(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposUpdateStatusCheckProtectionRequest,
     ) -> Result<crate::types::StatusCheckPolicy> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks",
             crate::progenitor_support::encode_path(owner),
@@ -1053,10 +1153,13 @@ impl Repos {
     */
     pub async fn get_all_status_check_contexts(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<String>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
             crate::progenitor_support::encode_path(owner),
@@ -1080,10 +1183,13 @@ impl Repos {
     */
     pub async fn get_all_all_status_check_contexts(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<String>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
             crate::progenitor_support::encode_path(owner),
@@ -1111,11 +1217,14 @@ impl Repos {
     */
     pub async fn set_status_check_contexts(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
     ) -> Result<Vec<String>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
             crate::progenitor_support::encode_path(owner),
@@ -1145,11 +1254,14 @@ impl Repos {
     */
     pub async fn add_status_check_contexts(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
     ) -> Result<Vec<String>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
             crate::progenitor_support::encode_path(owner),
@@ -1179,11 +1291,14 @@ impl Repos {
     */
     pub async fn remove_status_check_contexts(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
     ) -> Result<Vec<String>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
             crate::progenitor_support::encode_path(owner),
@@ -1217,10 +1332,13 @@ impl Repos {
     */
     pub async fn get_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<crate::types::BranchRestrictionPolicy> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions",
             crate::progenitor_support::encode_path(owner),
@@ -1250,10 +1368,13 @@ impl Repos {
     */
     pub async fn delete_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions",
             crate::progenitor_support::encode_path(owner),
@@ -1283,10 +1404,13 @@ impl Repos {
     */
     pub async fn get_apps_with_access_to_protected_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<crate::types::GitHubApp>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1312,10 +1436,13 @@ impl Repos {
     */
     pub async fn get_all_apps_with_access_to_protected_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<crate::types::GitHubApp>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1349,11 +1476,14 @@ impl Repos {
     */
     pub async fn set_app_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::GitHubApp>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1389,11 +1519,14 @@ impl Repos {
     */
     pub async fn add_app_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::GitHubApp>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1429,11 +1562,14 @@ impl Repos {
     */
     pub async fn remove_app_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::GitHubApp>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1465,10 +1601,13 @@ impl Repos {
     */
     pub async fn get_teams_with_access_to_protected_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Team>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/teams",
             crate::progenitor_support::encode_path(owner),
@@ -1494,10 +1633,13 @@ impl Repos {
     */
     pub async fn get_all_teams_with_access_to_protected_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Team>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/teams",
             crate::progenitor_support::encode_path(owner),
@@ -1531,11 +1673,14 @@ impl Repos {
     */
     pub async fn set_team_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::Team>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/teams",
             crate::progenitor_support::encode_path(owner),
@@ -1571,11 +1716,14 @@ impl Repos {
     */
     pub async fn add_team_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::Team>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/teams",
             crate::progenitor_support::encode_path(owner),
@@ -1611,11 +1759,14 @@ impl Repos {
     */
     pub async fn remove_team_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::Team>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/teams",
             crate::progenitor_support::encode_path(owner),
@@ -1647,10 +1798,13 @@ impl Repos {
     */
     pub async fn get_users_with_access_to_protected_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/users",
             crate::progenitor_support::encode_path(owner),
@@ -1676,10 +1830,13 @@ impl Repos {
     */
     pub async fn get_all_users_with_access_to_protected_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/users",
             crate::progenitor_support::encode_path(owner),
@@ -1713,11 +1870,14 @@ impl Repos {
     */
     pub async fn set_user_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/users",
             crate::progenitor_support::encode_path(owner),
@@ -1753,11 +1913,14 @@ impl Repos {
     */
     pub async fn add_user_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/users",
             crate::progenitor_support::encode_path(owner),
@@ -1793,11 +1956,14 @@ impl Repos {
     */
     pub async fn remove_user_access_restrictions(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/users",
             crate::progenitor_support::encode_path(owner),
@@ -1841,11 +2007,14 @@ impl Repos {
     */
     pub async fn rename_branch(
         &self,
-        owner: &str,
-        repo: &str,
-        branch: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        branch: impl AsRef<str>,
         body: &crate::types::ReposRenameBranchRequest,
     ) -> Result<crate::types::BranchWithProtection> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let branch = branch.as_ref();
         let url = format!(
             "/repos/{}/{}/branches/{}/rename",
             crate::progenitor_support::encode_path(owner),
@@ -1882,28 +2051,31 @@ impl Repos {
     */
     pub async fn list_collaborators(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         affiliation: crate::types::Affiliation,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Collaborator>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/collaborators?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/collaborators",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1924,20 +2096,23 @@ impl Repos {
     */
     pub async fn list_all_collaborators(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         affiliation: crate::types::Affiliation,
     ) -> Result<Vec<crate::types::Collaborator>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/collaborators?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/collaborators",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1948,6 +2123,8 @@ impl Repos {
     *
     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators/{username}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     * For organization-owned repositories, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners.
     *
     * Team members will include the members of child teams.
@@ -1960,7 +2137,15 @@ impl Repos {
     * * `repo: &str`
     * * `username: &str`
     */
-    pub async fn check_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<()> {
+    pub async fn check_collaborator(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<bool> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/repos/{}/{}/collaborators/{}",
             crate::progenitor_support::encode_path(owner),
@@ -1968,7 +2153,7 @@ impl Repos {
             crate::progenitor_support::encode_path(username),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -1998,11 +2183,14 @@ impl Repos {
     */
     pub async fn add_collaborator(
         &self,
-        owner: &str,
-        repo: &str,
-        username: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        username: impl AsRef<str>,
         body: &crate::types::ReposAddCollaboratorRequest,
     ) -> Result<crate::types::RepositoryInvitation> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/repos/{}/{}/collaborators/{}",
             crate::progenitor_support::encode_path(owner),
@@ -2030,7 +2218,15 @@ impl Repos {
     * * `repo: &str`
     * * `username: &str`
     */
-    pub async fn remove_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<()> {
+    pub async fn remove_collaborator(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        username: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/repos/{}/{}/collaborators/{}",
             crate::progenitor_support::encode_path(owner),
@@ -2058,10 +2254,13 @@ impl Repos {
     */
     pub async fn get_collaborator_permission_level(
         &self,
-        owner: &str,
-        repo: &str,
-        username: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::RepositoryCollaboratorPermission> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/repos/{}/{}/collaborators/{}/permission",
             crate::progenitor_support::encode_path(owner),
@@ -2092,24 +2291,27 @@ impl Repos {
     */
     pub async fn list_commit_comments_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::CommitComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2130,9 +2332,11 @@ impl Repos {
     */
     pub async fn list_all_commit_comments_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CommitComment>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/comments",
             crate::progenitor_support::encode_path(owner),
@@ -2159,15 +2363,18 @@ impl Repos {
     */
     pub async fn get_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
     ) -> Result<crate::types::CommitComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.get(&url, None).await
@@ -2190,15 +2397,18 @@ impl Repos {
     */
     pub async fn delete_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client.delete(&url, None).await
@@ -2221,16 +2431,19 @@ impl Repos {
     */
     pub async fn update_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        comment_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        comment_id: impl Into<crate::types::CommentId>,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::CommitComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let comment_id: crate::types::CommentId = comment_id.into();
         let url = format!(
             "/repos/{}/{}/comments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id),
         );
 
         self.client
@@ -2288,8 +2501,8 @@ impl Repos {
     */
     pub async fn list_commits(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sha: &str,
         path: &str,
         author: &str,
@@ -2298,34 +2511,37 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::CommitDataType>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+            query_args.push(("sha", sha.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+            query_args.push(("until", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2371,36 +2587,39 @@ impl Repos {
     */
     pub async fn list_all_commits(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sha: &str,
         path: &str,
         author: &str,
         since: Option<chrono::DateTime<chrono::Utc>>,
         until: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::CommitDataType>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+            query_args.push(("sha", sha.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+            query_args.push(("until", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2425,10 +2644,13 @@ impl Repos {
     */
     pub async fn list_branches_for_head_commit(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
     ) -> Result<Vec<crate::types::BranchShort>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/commits/{}/branches-where-head",
             crate::progenitor_support::encode_path(owner),
@@ -2454,10 +2676,13 @@ impl Repos {
     */
     pub async fn list_all_branches_for_head_commit(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
     ) -> Result<Vec<crate::types::BranchShort>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/commits/{}/branches-where-head",
             crate::progenitor_support::encode_path(owner),
@@ -2487,26 +2712,30 @@ impl Repos {
     */
     pub async fn list_comments_for_commit(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::CommitComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/comments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(commit_sha),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2525,10 +2754,13 @@ impl Repos {
     */
     pub async fn list_all_comments_for_commit(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CommitComment>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/commits/{}/comments",
             crate::progenitor_support::encode_path(owner),
@@ -2558,11 +2790,14 @@ impl Repos {
     */
     pub async fn create_commit_comment(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
         body: &crate::types::ReposCreateCommitCommentRequest,
     ) -> Result<crate::types::CommitComment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/commits/{}/comments",
             crate::progenitor_support::encode_path(owner),
@@ -2594,26 +2829,30 @@ impl Repos {
     */
     pub async fn list_pull_requests_associated_with_commit(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/pulls?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(commit_sha),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2632,10 +2871,13 @@ impl Repos {
     */
     pub async fn list_all_pull_requests_associated_with_commit(
         &self,
-        owner: &str,
-        repo: &str,
-        commit_sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        commit_sha: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let commit_sha = commit_sha.as_ref();
         let url = format!(
             "/repos/{}/{}/commits/{}/pulls",
             crate::progenitor_support::encode_path(owner),
@@ -2700,26 +2942,30 @@ impl Repos {
     */
     pub async fn get_commit(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         page: i64,
         per_page: i64,
-        ref_: &str,
+        ref_: impl AsRef<str>,
     ) -> Result<crate::types::CommitDataType> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(ref_),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2752,26 +2998,30 @@ impl Repos {
     */
     pub async fn get_combined_status_for_ref(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::CombinedCommitStatus> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/status?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(ref_),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits/{}/status",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2798,26 +3048,30 @@ impl Repos {
     */
     pub async fn list_commit_statuses_for_ref(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::StatusData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/statuses?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(ref_),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/commits/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2838,10 +3092,13 @@ impl Repos {
     */
     pub async fn list_all_commit_statuses_for_ref(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
     ) -> Result<Vec<crate::types::StatusData>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
         let url = format!(
             "/repos/{}/{}/commits/{}/statuses",
             crate::progenitor_support::encode_path(owner),
@@ -2879,9 +3136,11 @@ impl Repos {
     */
     pub async fn get_community_profile_metrics(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::CommunityProfile> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/community/profile",
             crate::progenitor_support::encode_path(owner),
@@ -2949,26 +3208,30 @@ impl Repos {
     */
     pub async fn compare_commits(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         page: i64,
         per_page: i64,
-        basehead: &str,
+        basehead: impl AsRef<str>,
     ) -> Result<crate::types::CommitComparison> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let basehead = basehead.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/compare/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(basehead),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/compare/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(basehead),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3023,22 +3286,26 @@ impl Repos {
     */
     pub async fn get_content_vec_entries(
         &self,
-        owner: &str,
-        repo: &str,
-        path: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        path: impl AsRef<str>,
         ref_: &str,
     ) -> Result<Vec<crate::types::Entries>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let path = path.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/contents/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(path),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3093,22 +3360,26 @@ impl Repos {
     */
     pub async fn get_content_file(
         &self,
-        owner: &str,
-        repo: &str,
-        path: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        path: impl AsRef<str>,
         ref_: &str,
     ) -> Result<crate::types::ContentFile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let path = path.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/contents/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(path),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3163,22 +3434,26 @@ impl Repos {
     */
     pub async fn get_content_symlink(
         &self,
-        owner: &str,
-        repo: &str,
-        path: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        path: impl AsRef<str>,
         ref_: &str,
     ) -> Result<crate::types::SymlinkContent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let path = path.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/contents/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(path),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3233,22 +3508,26 @@ impl Repos {
     */
     pub async fn get_content_submodule(
         &self,
-        owner: &str,
-        repo: &str,
-        path: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        path: impl AsRef<str>,
         ref_: &str,
     ) -> Result<crate::types::ContentSubmodule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let path = path.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/contents/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(path),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3303,22 +3582,26 @@ impl Repos {
     */
     pub async fn get_content(
         &self,
-        owner: &str,
-        repo: &str,
-        path: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        path: impl AsRef<str>,
         ref_: &str,
     ) -> Result<crate::types::ReposGetContentResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let path = path.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/contents/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(path),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3341,11 +3624,14 @@ impl Repos {
     */
     pub async fn create_or_update_file_contents(
         &self,
-        owner: &str,
-        repo: &str,
-        path: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        path: impl AsRef<str>,
         body: &crate::types::ReposCreateUpdateFileContentsRequest,
     ) -> Result<crate::types::FileCommitData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let path = path.as_ref();
         let url = format!(
             "/repos/{}/{}/contents/{}",
             crate::progenitor_support::encode_path(owner),
@@ -3381,11 +3667,14 @@ impl Repos {
     */
     pub async fn delete_file(
         &self,
-        owner: &str,
-        repo: &str,
-        path: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        path: impl AsRef<str>,
         body: &crate::types::ReposDeleteFileRequest,
     ) -> Result<crate::types::FileCommitData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let path = path.as_ref();
         let url = format!(
             "/repos/{}/{}/contents/{}",
             crate::progenitor_support::encode_path(owner),
@@ -3419,28 +3708,31 @@ impl Repos {
     */
     pub async fn list_contributors(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         anon: &str,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Contributor>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !anon.is_empty() {
-            query_args.push(("anon".to_string(), anon.to_string()));
+            query_args.push(("anon", anon.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/contributors?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/contributors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3461,20 +3753,23 @@ impl Repos {
     */
     pub async fn list_all_contributors(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         anon: &str,
     ) -> Result<Vec<crate::types::Contributor>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !anon.is_empty() {
-            query_args.push(("anon".to_string(), anon.to_string()));
+            query_args.push(("anon", anon.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/contributors?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/contributors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -3502,8 +3797,8 @@ impl Repos {
     */
     pub async fn list_deployments(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sha: &str,
         ref_: &str,
         task: &str,
@@ -3511,31 +3806,34 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Deployment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !environment.is_empty() {
-            query_args.push(("environment".to_string(), environment.to_string()));
+            query_args.push(("environment", environment.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+            query_args.push(("sha", sha.to_string()));
         }
         if !task.is_empty() {
-            query_args.push(("task".to_string(), task.to_string()));
+            query_args.push(("task", task.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/deployments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3554,32 +3852,35 @@ impl Repos {
     */
     pub async fn list_all_deployments(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sha: &str,
         ref_: &str,
         task: &str,
         environment: &str,
     ) -> Result<Vec<crate::types::Deployment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !environment.is_empty() {
-            query_args.push(("environment".to_string(), environment.to_string()));
+            query_args.push(("environment", environment.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+            query_args.push(("sha", sha.to_string()));
         }
         if !task.is_empty() {
-            query_args.push(("task".to_string(), task.to_string()));
+            query_args.push(("task", task.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/deployments?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -3645,10 +3946,12 @@ impl Repos {
     */
     pub async fn create_deployment(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreateDeploymentRequest,
     ) -> Result<crate::types::Deployment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/deployments",
             crate::progenitor_support::encode_path(owner),
@@ -3677,15 +3980,17 @@ impl Repos {
     */
     pub async fn get_deployment(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         deployment_id: i64,
     ) -> Result<crate::types::Deployment> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/deployments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&deployment_id.to_string()),
+            crate::progenitor_support::encode_path(&deployment_id),
         );
 
         self.client.get(&url, None).await
@@ -3715,15 +4020,17 @@ impl Repos {
     */
     pub async fn delete_deployment(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         deployment_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/deployments/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&deployment_id.to_string()),
+            crate::progenitor_support::encode_path(&deployment_id),
         );
 
         self.client.delete(&url, None).await
@@ -3748,26 +4055,29 @@ impl Repos {
     */
     pub async fn list_deployment_statuses(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         deployment_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::DeploymentStatus>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/deployments/{}/statuses?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&deployment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/deployments/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3786,15 +4096,17 @@ impl Repos {
     */
     pub async fn list_all_deployment_statuses(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         deployment_id: i64,
     ) -> Result<Vec<crate::types::DeploymentStatus>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/deployments/{}/statuses",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&deployment_id.to_string()),
+            crate::progenitor_support::encode_path(&deployment_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -3819,16 +4131,18 @@ impl Repos {
     */
     pub async fn create_deployment_status(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         deployment_id: i64,
         body: &crate::types::ReposCreateDeploymentStatusRequest,
     ) -> Result<crate::types::DeploymentStatus> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/deployments/{}/statuses",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&deployment_id.to_string()),
+            crate::progenitor_support::encode_path(&deployment_id),
         );
 
         self.client
@@ -3854,17 +4168,19 @@ impl Repos {
     */
     pub async fn get_deployment_status(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         deployment_id: i64,
         status_id: i64,
     ) -> Result<crate::types::DeploymentStatus> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/deployments/{}/statuses/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&deployment_id.to_string()),
-            crate::progenitor_support::encode_path(&status_id.to_string()),
+            crate::progenitor_support::encode_path(&deployment_id),
+            crate::progenitor_support::encode_path(&status_id),
         );
 
         self.client.get(&url, None).await
@@ -3895,10 +4211,12 @@ impl Repos {
     */
     pub async fn create_dispatch_event(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreateDispatchEventRequest,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/dispatches",
             crate::progenitor_support::encode_path(owner),
@@ -3928,9 +4246,11 @@ impl Repos {
     */
     pub async fn get_all_environments(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::ReposGetAllEnvironmentsResponse> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/environments",
             crate::progenitor_support::encode_path(owner),
@@ -3957,10 +4277,13 @@ impl Repos {
     */
     pub async fn get_environment(
         &self,
-        owner: &str,
-        repo: &str,
-        environment_name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        environment_name: impl AsRef<str>,
     ) -> Result<crate::types::EnvironmentData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let environment_name = environment_name.as_ref();
         let url = format!(
             "/repos/{}/{}/environments/{}",
             crate::progenitor_support::encode_path(owner),
@@ -3994,11 +4317,14 @@ impl Repos {
     */
     pub async fn create_or_update_environment(
         &self,
-        owner: &str,
-        repo: &str,
-        environment_name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        environment_name: impl AsRef<str>,
         body: &crate::types::ReposCreateUpdateEnvironmentRequest,
     ) -> Result<crate::types::EnvironmentData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let environment_name = environment_name.as_ref();
         let url = format!(
             "/repos/{}/{}/environments/{}",
             crate::progenitor_support::encode_path(owner),
@@ -4028,10 +4354,13 @@ impl Repos {
     */
     pub async fn delete_an_environment(
         &self,
-        owner: &str,
-        repo: &str,
-        environment_name: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        environment_name: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let environment_name = environment_name.as_ref();
         let url = format!(
             "/repos/{}/{}/environments/{}",
             crate::progenitor_support::encode_path(owner),
@@ -4061,28 +4390,31 @@ impl Repos {
     */
     pub async fn list_forks(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sort: crate::types::ReposListForksSort,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/forks?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/forks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4101,20 +4433,23 @@ impl Repos {
     */
     pub async fn list_all_forks(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         sort: crate::types::ReposListForksSort,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/forks?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/forks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -4138,10 +4473,12 @@ impl Repos {
     */
     pub async fn create_fork(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreateForkRequest,
     ) -> Result<crate::types::FullRepository> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/forks",
             crate::progenitor_support::encode_path(owner),
@@ -4171,24 +4508,27 @@ impl Repos {
     */
     pub async fn list_webhooks(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Hook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/hooks?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/hooks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4207,9 +4547,11 @@ impl Repos {
     */
     pub async fn list_all_webhooks(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Hook>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks",
             crate::progenitor_support::encode_path(owner),
@@ -4236,10 +4578,12 @@ impl Repos {
     */
     pub async fn create_webhook(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreateWebhookRequest,
     ) -> Result<crate::types::Hook> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks",
             crate::progenitor_support::encode_path(owner),
@@ -4268,15 +4612,17 @@ impl Repos {
     */
     pub async fn get_webhook(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
     ) -> Result<crate::types::Hook> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.get(&url, None).await
@@ -4297,12 +4643,19 @@ impl Repos {
     * * `repo: &str`
     * * `hook_id: i64`
     */
-    pub async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()> {
+    pub async fn delete_webhook(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        hook_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.delete(&url, None).await
@@ -4325,16 +4678,18 @@ impl Repos {
     */
     pub async fn update_webhook(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
         body: &crate::types::ReposUpdateWebhookRequest,
     ) -> Result<crate::types::Hook> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client
@@ -4361,15 +4716,17 @@ impl Repos {
     */
     pub async fn get_webhook_config_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
     ) -> Result<crate::types::WebhookConfig> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}/config",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.get(&url, None).await
@@ -4394,16 +4751,18 @@ impl Repos {
     */
     pub async fn update_webhook_config_for_repo(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
         body: &crate::types::AppsUpdateWebhookConfigAppRequest,
     ) -> Result<crate::types::WebhookConfig> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}/config",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client
@@ -4430,26 +4789,29 @@ impl Repos {
     */
     pub async fn list_webhook_deliveries(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
         per_page: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/hooks/{}/deliveries?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4468,22 +4830,25 @@ impl Repos {
     */
     pub async fn list_all_webhook_deliveries(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/hooks/{}/deliveries?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -4507,17 +4872,19 @@ impl Repos {
     */
     pub async fn get_webhook_delivery(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
         delivery_id: i64,
     ) -> Result<crate::types::HookDelivery> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}/deliveries/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            crate::progenitor_support::encode_path(&delivery_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
+            crate::progenitor_support::encode_path(&delivery_id),
         );
 
         self.client.get(&url, None).await
@@ -4541,17 +4908,19 @@ impl Repos {
     */
     pub async fn redeliver_webhook_delivery(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         hook_id: i64,
         delivery_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}/deliveries/{}/attempts",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            crate::progenitor_support::encode_path(&delivery_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
+            crate::progenitor_support::encode_path(&delivery_id),
         );
 
         self.client.post(&url, None).await
@@ -4572,12 +4941,19 @@ impl Repos {
     * * `repo: &str`
     * * `hook_id: i64`
     */
-    pub async fn ping_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()> {
+    pub async fn ping_webhook(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        hook_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}/pings",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.post(&url, None).await
@@ -4600,12 +4976,19 @@ impl Repos {
     * * `repo: &str`
     * * `hook_id: i64`
     */
-    pub async fn test_push_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()> {
+    pub async fn test_push_webhook(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        hook_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/hooks/{}/tests",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            crate::progenitor_support::encode_path(&hook_id),
         );
 
         self.client.post(&url, None).await
@@ -4629,24 +5012,27 @@ impl Repos {
     */
     pub async fn list_invitations(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::RepositoryInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/invitations?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/invitations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4665,9 +5051,11 @@ impl Repos {
     */
     pub async fn list_all_invitations(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::RepositoryInvitation>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/invitations",
             crate::progenitor_support::encode_path(owner),
@@ -4694,15 +5082,17 @@ impl Repos {
     */
     pub async fn delete_invitation(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         invitation_id: i64,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/invitations/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            crate::progenitor_support::encode_path(&invitation_id),
         );
 
         self.client.delete(&url, None).await
@@ -4725,16 +5115,18 @@ impl Repos {
     */
     pub async fn update_invitation(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         invitation_id: i64,
         body: &crate::types::ReposUpdateInvitationRequest,
     ) -> Result<crate::types::RepositoryInvitation> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/invitations/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            crate::progenitor_support::encode_path(&invitation_id),
         );
 
         self.client
@@ -4760,24 +5152,27 @@ impl Repos {
     */
     pub async fn list_deploy_keys(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::DeployKey>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/keys?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/keys",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4796,9 +5191,11 @@ impl Repos {
     */
     pub async fn list_all_deploy_keys(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::DeployKey>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/keys",
             crate::progenitor_support::encode_path(owner),
@@ -4824,10 +5221,12 @@ impl Repos {
     */
     pub async fn create_deploy_key(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreateDeployKeyRequest,
     ) -> Result<crate::types::DeployKey> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/keys",
             crate::progenitor_support::encode_path(owner),
@@ -4856,15 +5255,17 @@ impl Repos {
     */
     pub async fn get_deploy_key(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         key_id: i64,
     ) -> Result<crate::types::DeployKey> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/keys/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            crate::progenitor_support::encode_path(&key_id),
         );
 
         self.client.get(&url, None).await
@@ -4885,12 +5286,19 @@ impl Repos {
     * * `repo: &str`
     * * `key_id: i64` -- key_id parameter.
     */
-    pub async fn delete_deploy_key(&self, owner: &str, repo: &str, key_id: i64) -> Result<()> {
+    pub async fn delete_deploy_key(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        key_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/keys/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            crate::progenitor_support::encode_path(&key_id),
         );
 
         self.client.delete(&url, None).await
@@ -4910,7 +5318,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn list_languages(&self, owner: &str, repo: &str) -> Result<i64> {
+    pub async fn list_languages(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<i64> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/languages",
             crate::progenitor_support::encode_path(owner),
@@ -4936,10 +5350,12 @@ impl Repos {
     */
     pub async fn merge(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposMergeRequest,
     ) -> Result<crate::types::CommitDataType> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/merges",
             crate::progenitor_support::encode_path(owner),
@@ -4965,7 +5381,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn get_pages(&self, owner: &str, repo: &str) -> Result<crate::types::Page> {
+    pub async fn get_pages(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<crate::types::Page> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages",
             crate::progenitor_support::encode_path(owner),
@@ -4991,10 +5413,12 @@ impl Repos {
     */
     pub async fn update_information_about_pages_site(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposUpdateInformationAboutPagesSiteRequest,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages",
             crate::progenitor_support::encode_path(owner),
@@ -5022,10 +5446,12 @@ impl Repos {
     */
     pub async fn create_pages_site(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreatePagesSiteRequest,
     ) -> Result<crate::types::Page> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages",
             crate::progenitor_support::encode_path(owner),
@@ -5051,7 +5477,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn delete_pages_site(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn delete_pages_site(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages",
             crate::progenitor_support::encode_path(owner),
@@ -5079,24 +5511,27 @@ impl Repos {
     */
     pub async fn list_pages_builds(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::PageBuild>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/pages/builds?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/pages/builds",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5115,9 +5550,11 @@ impl Repos {
     */
     pub async fn list_all_pages_builds(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PageBuild>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages/builds",
             crate::progenitor_support::encode_path(owner),
@@ -5145,9 +5582,11 @@ impl Repos {
     */
     pub async fn request_pages_build(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::PageBuildStatus> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages/builds",
             crate::progenitor_support::encode_path(owner),
@@ -5173,9 +5612,11 @@ impl Repos {
     */
     pub async fn get_latest_pages_build(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::PageBuild> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages/builds/latest",
             crate::progenitor_support::encode_path(owner),
@@ -5202,15 +5643,17 @@ impl Repos {
     */
     pub async fn get_pages_build(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         build_id: i64,
     ) -> Result<crate::types::PageBuild> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages/builds/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&build_id.to_string()),
+            crate::progenitor_support::encode_path(&build_id),
         );
 
         self.client.get(&url, None).await
@@ -5236,9 +5679,11 @@ impl Repos {
     */
     pub async fn get_pages_health_check(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::PagesHealthCheck> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/pages/health",
             crate::progenitor_support::encode_path(owner),
@@ -5267,20 +5712,23 @@ impl Repos {
     */
     pub async fn get_readme(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         ref_: &str,
     ) -> Result<crate::types::ContentFile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/readme?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/readme",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5306,22 +5754,26 @@ impl Repos {
     */
     pub async fn get_readme_in_directory(
         &self,
-        owner: &str,
-        repo: &str,
-        dir: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        dir: impl AsRef<str>,
         ref_: &str,
     ) -> Result<crate::types::ContentFile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let dir = dir.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/readme/{}?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(dir),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/readme/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(dir),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5347,24 +5799,27 @@ impl Repos {
     */
     pub async fn list_releases(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Release>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/releases?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/releases",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5385,9 +5840,11 @@ impl Repos {
     */
     pub async fn list_all_releases(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Release>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases",
             crate::progenitor_support::encode_path(owner),
@@ -5415,10 +5872,12 @@ impl Repos {
     */
     pub async fn create_release(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposCreateReleaseRequest,
     ) -> Result<crate::types::Release> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases",
             crate::progenitor_support::encode_path(owner),
@@ -5447,15 +5906,17 @@ impl Repos {
     */
     pub async fn get_release_asset(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         asset_id: i64,
     ) -> Result<crate::types::ReleaseAsset> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/assets/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&asset_id.to_string()),
+            crate::progenitor_support::encode_path(&asset_id),
         );
 
         self.client.get(&url, None).await
@@ -5476,12 +5937,19 @@ impl Repos {
     * * `repo: &str`
     * * `asset_id: i64` -- asset_id parameter.
     */
-    pub async fn delete_release_asset(&self, owner: &str, repo: &str, asset_id: i64) -> Result<()> {
+    pub async fn delete_release_asset(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        asset_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/assets/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&asset_id.to_string()),
+            crate::progenitor_support::encode_path(&asset_id),
         );
 
         self.client.delete(&url, None).await
@@ -5504,16 +5972,18 @@ impl Repos {
     */
     pub async fn update_release_asset(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         asset_id: i64,
         body: &crate::types::ReposUpdateReleaseAssetRequest,
     ) -> Result<crate::types::ReleaseAsset> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/assets/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&asset_id.to_string()),
+            crate::progenitor_support::encode_path(&asset_id),
         );
 
         self.client
@@ -5539,9 +6009,11 @@ impl Repos {
     */
     pub async fn get_latest_release(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::Release> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/latest",
             crate::progenitor_support::encode_path(owner),
@@ -5568,10 +6040,13 @@ impl Repos {
     */
     pub async fn get_release_by_tag(
         &self,
-        owner: &str,
-        repo: &str,
-        tag: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        tag: impl AsRef<str>,
     ) -> Result<crate::types::Release> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let tag = tag.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/tags/{}",
             crate::progenitor_support::encode_path(owner),
@@ -5599,15 +6074,17 @@ impl Repos {
     */
     pub async fn get_release(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         release_id: i64,
     ) -> Result<crate::types::Release> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&release_id.to_string()),
+            crate::progenitor_support::encode_path(&release_id),
         );
 
         self.client.get(&url, None).await
@@ -5628,12 +6105,19 @@ impl Repos {
     * * `repo: &str`
     * * `release_id: i64` -- release_id parameter.
     */
-    pub async fn delete_release(&self, owner: &str, repo: &str, release_id: i64) -> Result<()> {
+    pub async fn delete_release(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        release_id: i64,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&release_id.to_string()),
+            crate::progenitor_support::encode_path(&release_id),
         );
 
         self.client.delete(&url, None).await
@@ -5656,16 +6140,18 @@ impl Repos {
     */
     pub async fn update_release(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         release_id: i64,
         body: &crate::types::ReposCreateReleaseRequest,
     ) -> Result<crate::types::Release> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&release_id.to_string()),
+            crate::progenitor_support::encode_path(&release_id),
         );
 
         self.client
@@ -5692,26 +6178,29 @@ impl Repos {
     */
     pub async fn list_release_assets(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         release_id: i64,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::ReleaseAsset>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/releases/{}/assets?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&release_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5730,15 +6219,17 @@ impl Repos {
     */
     pub async fn list_all_release_assets(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         release_id: i64,
     ) -> Result<Vec<crate::types::ReleaseAsset>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/releases/{}/assets",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&release_id.to_string()),
+            crate::progenitor_support::encode_path(&release_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -5777,33 +6268,40 @@ impl Repos {
     * * `release_id: i64` -- release_id parameter.
     * * `name: &str`
     * * `label: &str`
+    * * `content_type: &str` -- The media type of the asset, for example `application/zip`.
     */
     pub async fn upload_release_asset<T: Into<reqwest::Body>>(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         release_id: i64,
         name: &str,
         label: &str,
+        content_type: &str,
         body: T,
     ) -> Result<crate::types::ReleaseAsset> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !label.is_empty() {
-            query_args.push(("label".to_string(), label.to_string()));
+            query_args.push(("label", label.to_string()));
         }
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/releases/{}/assets?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            crate::progenitor_support::encode_path(&release_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id),
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, Some(body.into())).await
+        self.client
+            .post_upload(&url, content_type, None, body.into())
+            .await
     }
 
     /**
@@ -5820,7 +6318,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn get_code_frequency_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>> {
+    pub async fn get_code_frequency_stats(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<Vec<Vec<i64>>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/code_frequency",
             crate::progenitor_support::encode_path(owner),
@@ -5843,9 +6347,11 @@ impl Repos {
     */
     pub async fn get_all_code_frequency_stats(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<Vec<i64>>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/code_frequency",
             crate::progenitor_support::encode_path(owner),
@@ -5871,9 +6377,11 @@ impl Repos {
     */
     pub async fn get_commit_activity_stats(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CommitActivity>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/commit_activity",
             crate::progenitor_support::encode_path(owner),
@@ -5896,9 +6404,11 @@ impl Repos {
     */
     pub async fn get_all_commit_activity_stats(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CommitActivity>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/commit_activity",
             crate::progenitor_support::encode_path(owner),
@@ -5930,9 +6440,11 @@ impl Repos {
     */
     pub async fn get_contributors_stats(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ContributorActivity>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/contributors",
             crate::progenitor_support::encode_path(owner),
@@ -5961,9 +6473,11 @@ impl Repos {
     */
     pub async fn get_all_contributors_stats(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ContributorActivity>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/contributors",
             crate::progenitor_support::encode_path(owner),
@@ -5991,9 +6505,11 @@ impl Repos {
     */
     pub async fn get_participation_stats(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::ParticipationStats> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/participation",
             crate::progenitor_support::encode_path(owner),
@@ -6023,7 +6539,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn get_punch_card_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>> {
+    pub async fn get_punch_card_stats(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<Vec<Vec<i64>>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/punch_card",
             crate::progenitor_support::encode_path(owner),
@@ -6050,7 +6572,13 @@ impl Repos {
     *
     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
     */
-    pub async fn get_all_punch_card_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>> {
+    pub async fn get_all_punch_card_stats(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<Vec<Vec<i64>>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/stats/punch_card",
             crate::progenitor_support::encode_path(owner),
@@ -6079,11 +6607,14 @@ impl Repos {
     */
     pub async fn create_commit_status(
         &self,
-        owner: &str,
-        repo: &str,
-        sha: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        sha: impl AsRef<str>,
         body: &crate::types::ReposCreateCommitStatusRequest,
     ) -> Result<crate::types::StatusData> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let sha = sha.as_ref();
         let url = format!(
             "/repos/{}/{}/statuses/{}",
             crate::progenitor_support::encode_path(owner),
@@ -6114,24 +6645,27 @@ impl Repos {
     */
     pub async fn list_tags(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Tag>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/tags?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/tags",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6148,7 +6682,13 @@ impl Repos {
     *
     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
     */
-    pub async fn list_all_tags(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Tag>> {
+    pub async fn list_all_tags(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Tag>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/tags",
             crate::progenitor_support::encode_path(owner),
@@ -6178,10 +6718,13 @@ impl Repos {
     */
     pub async fn download_tarball_archive(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
         let url = format!(
             "/repos/{}/{}/tarball/{}",
             crate::progenitor_support::encode_path(owner),
@@ -6210,24 +6753,27 @@ impl Repos {
     */
     pub async fn list_teams(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Team>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/teams?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6244,7 +6790,13 @@ impl Repos {
     *
     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
     */
-    pub async fn list_all_teams(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Team>> {
+    pub async fn list_all_teams(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Team>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/teams",
             crate::progenitor_support::encode_path(owner),
@@ -6272,24 +6824,27 @@ impl Repos {
     */
     pub async fn get_all_topics(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         page: i64,
         per_page: i64,
     ) -> Result<crate::types::Topic> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/topics?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/topics",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6311,10 +6866,12 @@ impl Repos {
     */
     pub async fn replace_all_topics(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::Topic,
     ) -> Result<crate::types::Topic> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/topics",
             crate::progenitor_support::encode_path(owner),
@@ -6343,20 +6900,23 @@ impl Repos {
     */
     pub async fn get_clones(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per: crate::types::Per,
     ) -> Result<crate::types::CloneTraffic> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !per.to_string().is_empty() {
-            query_args.push(("per".to_string(), per.to_string()));
+            query_args.push(("per", per.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/traffic/clones?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/traffic/clones",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6378,9 +6938,11 @@ impl Repos {
     */
     pub async fn get_top_paths(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ContentTraffic>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/traffic/popular/paths",
             crate::progenitor_support::encode_path(owner),
@@ -6403,9 +6965,11 @@ impl Repos {
     */
     pub async fn get_all_top_paths(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ContentTraffic>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/traffic/popular/paths",
             crate::progenitor_support::encode_path(owner),
@@ -6431,9 +6995,11 @@ impl Repos {
     */
     pub async fn get_top_referrers(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ReferrerTraffic>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/traffic/popular/referrers",
             crate::progenitor_support::encode_path(owner),
@@ -6456,9 +7022,11 @@ impl Repos {
     */
     pub async fn get_all_top_referrers(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ReferrerTraffic>> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/traffic/popular/referrers",
             crate::progenitor_support::encode_path(owner),
@@ -6485,20 +7053,23 @@ impl Repos {
     */
     pub async fn get_views(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         per: crate::types::Per,
     ) -> Result<crate::types::ViewTraffic> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !per.to_string().is_empty() {
-            query_args.push(("per".to_string(), per.to_string()));
+            query_args.push(("per", per.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/traffic/views?{}",
-            crate::progenitor_support::encode_path(owner),
-            crate::progenitor_support::encode_path(repo),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/repos/{}/{}/traffic/views",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6520,10 +7091,12 @@ impl Repos {
     */
     pub async fn transfer(
         &self,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::ReposTransferRequest,
     ) -> Result<crate::types::MinimalRepository> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/transfer",
             crate::progenitor_support::encode_path(owner),
@@ -6540,6 +7113,8 @@ impl Repos {
     *
     * This function performs a `GET` to the `/repos/{owner}/{repo}/vulnerability-alerts` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     * Shows whether dependency alerts are enabled or disabled for a repository. The authenticated user must have admin access to the repository. For more information, see "[About security alerts for vulnerable dependencies](https://help.github.com/en/articles/about-security-alerts-for-vulnerable-dependencies)".
     *
     * FROM: <https://docs.github.com/rest/reference/repos#check-if-vulnerability-alerts-are-enabled-for-a-repository>
@@ -6549,14 +7124,20 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn check_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn check_vulnerability_alerts(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<bool> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/vulnerability-alerts",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -6573,7 +7154,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn enable_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn enable_vulnerability_alerts(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/vulnerability-alerts",
             crate::progenitor_support::encode_path(owner),
@@ -6597,7 +7184,13 @@ impl Repos {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn disable_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()> {
+    pub async fn disable_vulnerability_alerts(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/repos/{}/{}/vulnerability-alerts",
             crate::progenitor_support::encode_path(owner),
@@ -6627,10 +7220,13 @@ impl Repos {
     */
     pub async fn download_zipball_archive(
         &self,
-        owner: &str,
-        repo: &str,
-        ref_: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        ref_: impl AsRef<str>,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
+        let ref_ = ref_.as_ref();
         let url = format!(
             "/repos/{}/{}/zipball/{}",
             crate::progenitor_support::encode_path(owner),
@@ -6664,10 +7260,12 @@ impl Repos {
     */
     pub async fn create_using_template(
         &self,
-        template_owner: &str,
-        template_repo: &str,
+        template_owner: impl AsRef<str>,
+        template_repo: impl AsRef<str>,
         body: &crate::types::ReposCreateUsingTemplateRequest,
     ) -> Result<crate::types::Repository> {
+        let template_owner = template_owner.as_ref();
+        let template_repo = template_repo.as_ref();
         let url = format!(
             "/repos/{}/{}/generate",
             crate::progenitor_support::encode_path(template_owner),
@@ -6697,12 +7295,11 @@ impl Repos {
     * * `since: i64` -- A repository ID. Only return repositories with an ID greater than this ID.
     */
     pub async fn list_public(&self, since: i64) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/repositories?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/repositories"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -6726,12 +7323,11 @@ impl Repos {
         &self,
         since: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/repositories?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/repositories"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -6778,36 +7374,35 @@ impl Repos {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Repository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if !visibility.to_string().is_empty() {
-            query_args.push(("visibility".to_string(), visibility.to_string()));
+            query_args.push(("visibility", visibility.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/repos?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/repos"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -6835,30 +7430,29 @@ impl Repos {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Repository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if !visibility.to_string().is_empty() {
-            query_args.push(("visibility".to_string(), visibility.to_string()));
+            query_args.push(("visibility", visibility.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/repos?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/repos"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -6908,15 +7502,17 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::RepositoryInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/repository_invitations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/user/repository_invitations"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6955,7 +7551,7 @@ impl Repos {
     pub async fn decline_invitation(&self, invitation_id: i64) -> Result<()> {
         let url = format!(
             "/user/repository_invitations/{}",
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            crate::progenitor_support::encode_path(&invitation_id),
         );
 
         self.client.delete(&url, None).await
@@ -6977,7 +7573,7 @@ impl Repos {
     pub async fn accept_invitation(&self, invitation_id: i64) -> Result<()> {
         let url = format!(
             "/user/repository_invitations/{}",
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            crate::progenitor_support::encode_path(&invitation_id),
         );
 
         self.client.patch(&url, None).await
@@ -7005,34 +7601,36 @@ impl Repos {
     */
     pub async fn list_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         type_: crate::types::ReposListUserType,
         sort: crate::types::ReposListOrgSort,
         direction: crate::types::Order,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/repos?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/repos",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -7051,26 +7649,28 @@ impl Repos {
     */
     pub async fn list_all_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         type_: crate::types::ReposListUserType,
         sort: crate::types::ReposListOrgSort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/repos?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/repos",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -0,0 +1,219 @@
+//! Retrying requests that fail in a transient manner.
+//!
+//! Every client retries requests with the default `RetryPolicy`. To use a different
+//! policy, build an HTTP client with a `RetryMiddleware` and pass it to the client
+//! with `with_http_client`.
+//!
+//! A `429 Too Many Requests` or `503 Service Unavailable` response with a
+//! `Retry-After` header is retried after the time the server asks for, capped at
+//! `max_backoff`, instead of the exponential backoff.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use octorust::retry::{RetryMiddleware, RetryPolicy};
+//!
+//! let policy = RetryPolicy {
+//!     max_retries: 5,
+//!     backoff_base: Duration::from_millis(500),
+//!     ..Default::default()
+//! };
+//!
+//! let http = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+//!     .with(reqwest_tracing::TracingMiddleware)
+//!     .with(RetryMiddleware::new(policy))
+//!     .build();
+//! ```
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{header::RETRY_AFTER, Method, Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+
+/// Controls which requests are retried, how many times and how long to wait in between.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of times a request is retried after the first attempt.
+    pub max_retries: u32,
+    /// Time to wait before the first retry. The wait time is doubled for every
+    /// retry after that.
+    pub backoff_base: Duration,
+    /// Upper bound for the time to wait between two attempts.
+    pub max_backoff: Duration,
+    /// Randomize the time to wait between `backoff / 2` and `backoff` so that clients
+    /// failing at the same time do not all retry at the same time.
+    pub jitter: bool,
+    /// Response status codes that are considered transient and are retried.
+    pub retryable_status_codes: Vec<StatusCode>,
+    /// Also retry requests with methods that are not idempotent, like `POST` and `PATCH`.
+    /// Retrying these might apply the request twice.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            backoff_base: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            retryable_status_codes: vec![
+                StatusCode::REQUEST_TIMEOUT,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries requests.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether requests with the given method may be retried.
+    pub fn is_retryable_method(&self, method: &Method) -> bool {
+        self.retry_non_idempotent
+            || matches!(
+                *method,
+                Method::GET
+                    | Method::HEAD
+                    | Method::OPTIONS
+                    | Method::TRACE
+                    | Method::PUT
+                    | Method::DELETE
+            )
+    }
+
+    /// Returns whether the request may be retried. Requests with a method that is not
+    /// idempotent are retried when they carry an `Idempotency-Key` header, since the
+    /// server then applies them only once.
+    pub fn is_retryable(&self, req: &Request) -> bool {
+        self.is_retryable_method(req.method()) || req.headers().contains_key("idempotency-key")
+    }
+
+    /// Returns whether the result of an attempt is a transient failure.
+    pub fn is_transient(&self, result: &reqwest_middleware::Result<Response>) -> bool {
+        match result {
+            Ok(resp) => self.retryable_status_codes.contains(&resp.status()),
+            Err(reqwest_middleware::Error::Reqwest(e)) => {
+                e.is_timeout() || e.is_connect() || e.is_request()
+            }
+            Err(reqwest_middleware::Error::Middleware(_)) => false,
+        }
+    }
+
+    /// Returns the time to wait before the given retry, starting at `0`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let backoff = 2u32
+            .checked_pow(retry)
+            .and_then(|factor| self.backoff_base.checked_mul(factor))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+
+        if self.jitter {
+            backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+        } else {
+            backoff
+        }
+    }
+
+    /// Returns the time to wait before the given retry, starting at `0`, after the
+    /// given result. A `429 Too Many Requests` or `503 Service Unavailable` response
+    /// with a `Retry-After` header is retried after the time the server asks for,
+    /// up to `max_backoff`.
+    pub fn wait(&self, retry: u32, result: &reqwest_middleware::Result<Response>) -> Duration {
+        match result {
+            Ok(resp)
+                if resp.status() == StatusCode::TOO_MANY_REQUESTS
+                    || resp.status() == StatusCode::SERVICE_UNAVAILABLE =>
+            {
+                match retry_after(resp) {
+                    Some(wait) => wait.min(self.max_backoff),
+                    None => self.backoff(retry),
+                }
+            }
+            _ => self.backoff(retry),
+        }
+    }
+}
+
+/// The time to wait given by the `Retry-After` header of the response, either as a
+/// number of seconds or as an HTTP date. A date in the past is no wait at all.
+pub fn retry_after(resp: &Response) -> Option<Duration> {
+    let value = resp.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Middleware retrying requests according to a `RetryPolicy`.
+#[derive(Debug, Clone)]
+pub struct RetryMiddleware {
+    policy: RetryPolicy,
+}
+
+impl RetryMiddleware {
+    pub fn new(policy: RetryPolicy) -> Self {
+        RetryMiddleware { policy }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if !self.policy.is_retryable(&req) {
+            return next.run(req, extensions).await;
+        }
+
+        let mut req = req;
+        let mut retry = 0;
+        loop {
+            // Requests with a streaming body can not be copied, so they are only
+            // attempted once.
+            let duplicate = match req.try_clone() {
+                Some(duplicate) if retry < self.policy.max_retries => duplicate,
+                _ => return next.run(req, extensions).await,
+            };
+
+            let result = next.clone().run(req, extensions).await;
+            if !self.policy.is_transient(&result) {
+                return result;
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("retry_count", &(retry + 1));
+
+            let wait = self.policy.wait(retry, &result);
+            log::warn!(
+                "retry attempt #{}, sleeping {:?} before the next attempt",
+                retry + 1,
+                wait
+            );
+            crate::runtime::sleep(wait).await;
+
+            req = duplicate;
+            retry += 1;
+        }
+    }
+}
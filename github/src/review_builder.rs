@@ -0,0 +1,246 @@
+//! Review a pull request with comments on lines of its files.
+//!
+//! https://docs.github.com/en/rest/reference/pulls#reviews
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    pulls::Pulls,
+    types::{
+        Comments, PullRequestReviewData, PullRequestSimple, PullsCreateReviewRequest,
+        PullsCreateReviewRequestEvent, PullsDismissReviewRequest, PullsRequestReviewers,
+    },
+};
+
+/// What a review does to the pull request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl From<ReviewEvent> for PullsCreateReviewRequestEvent {
+    fn from(event: ReviewEvent) -> Self {
+        match event {
+            ReviewEvent::Approve => PullsCreateReviewRequestEvent::Approve,
+            ReviewEvent::RequestChanges => PullsCreateReviewRequestEvent::RequestChanges,
+            ReviewEvent::Comment => PullsCreateReviewRequestEvent::Comment,
+        }
+    }
+}
+
+/// A comment on a line of a file, as given to the builder.
+struct LineComment {
+    path: String,
+    line: i64,
+    body: String,
+}
+
+/**
+ * Builds a review out of comments on lines of the files of a pull request, and
+ * submits it in one call.
+ *
+ * GitHub places the comments of a review by their position in the diff of the
+ * file, the number of lines below its first `@@` hunk header. The comments are
+ * given with the line of the file instead, and the builder computes the positions
+ * from the diff of the pull request.
+ *
+ * ```no_run
+ * # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+ * use octorust::pulls::ReviewEvent;
+ *
+ * github
+ *     .pulls()
+ *     .review_builder("owner", "repo", 1347)
+ *     .body("Looks good, a few nits.")
+ *     .comment("src/lib.rs", 42, "This could be a constant.")
+ *     .submit(ReviewEvent::Approve)
+ *     .await?;
+ * # Ok(())
+ * # }
+ * ```
+ */
+pub struct ReviewBuilder {
+    pulls: Pulls,
+    owner: String,
+    repo: String,
+    pull_number: i64,
+    body: String,
+    commit_id: String,
+    comments: Vec<LineComment>,
+    patches: HashMap<String, String>,
+}
+
+/**
+ * The position of a line of the new version of a file in its diff, as GitHub
+ * counts them, or `None` if the line is not part of the diff.
+ *
+ * The line below the first `@@` hunk header is at position 1, and the position
+ * keeps increasing through the following hunks and their headers.
+ */
+pub fn diff_position(patch: &str, line: i64) -> Option<i64> {
+    let mut new_line = 0;
+    for (position, l) in patch.lines().enumerate() {
+        if l.starts_with("@@") {
+            // `@@ -start,count +start,count @@`, the count may be left out.
+            let start = l
+                .split(' ')
+                .find_map(|r| r.strip_prefix('+'))?
+                .split(',')
+                .next()?;
+            new_line = start.parse::<i64>().ok()? - 1;
+            continue;
+        }
+        if l.starts_with('-') || l.starts_with('\\') {
+            continue;
+        }
+
+        new_line += 1;
+        if new_line == line {
+            return Some(position as i64);
+        }
+    }
+
+    None
+}
+
+impl Pulls {
+    /// Start building a review of a pull request.
+    pub fn review_builder(&self, owner: &str, repo: &str, pull_number: i64) -> ReviewBuilder {
+        ReviewBuilder::new(Pulls::new(self.client.clone()), owner, repo, pull_number)
+    }
+
+    /// Request reviews from users and teams, given by their slugs.
+    pub async fn request_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        reviewers: &[&str],
+        team_reviewers: &[&str],
+    ) -> Result<PullRequestSimple> {
+        let body = PullsRequestReviewers {
+            reviewers: reviewers.iter().map(|r| r.to_string()).collect(),
+            team_reviewers: team_reviewers.iter().map(|t| t.to_string()).collect(),
+        };
+
+        self.request_reviewers(owner, repo, pull_number, &body)
+            .await
+    }
+
+    /// Dismiss a review, telling its author why.
+    pub async fn dismiss_review_with_message(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        message: &str,
+    ) -> Result<PullRequestReviewData> {
+        let body = PullsDismissReviewRequest {
+            event: "DISMISS".to_string(),
+            message: message.to_string(),
+        };
+
+        self.dismiss_review(owner, repo, pull_number, review_id, &body)
+            .await
+    }
+}
+
+impl ReviewBuilder {
+    pub fn new(pulls: Pulls, owner: &str, repo: &str, pull_number: i64) -> Self {
+        ReviewBuilder {
+            pulls,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            pull_number,
+            body: String::new(),
+            commit_id: String::new(),
+            comments: Vec::new(),
+            patches: HashMap::new(),
+        }
+    }
+
+    /// Set the text of the review, required to request changes or comment.
+    pub fn body(mut self, body: &str) -> Self {
+        self.body = body.to_string();
+        self
+    }
+
+    /// Review this commit of the pull request instead of the latest one.
+    pub fn commit_id(mut self, commit_id: &str) -> Self {
+        self.commit_id = commit_id.to_string();
+        self
+    }
+
+    /// Comment on a line of the new version of the file at `path`.
+    pub fn comment(mut self, path: &str, line: i64, body: &str) -> Self {
+        self.comments.push(LineComment {
+            path: path.to_string(),
+            line,
+            body: body.to_string(),
+        });
+        self
+    }
+
+    /// Use this diff for the file at `path`, like the `patch` of the files of the
+    /// pull request, instead of getting the files of the pull request.
+    pub fn patch(mut self, path: &str, patch: &str) -> Self {
+        self.patches.insert(path.to_string(), patch.to_string());
+        self
+    }
+
+    /// Compute the positions of the comments and submit the review.
+    pub async fn submit(mut self, event: ReviewEvent) -> Result<PullRequestReviewData> {
+        if self
+            .comments
+            .iter()
+            .any(|c| !self.patches.contains_key(&c.path))
+        {
+            let files = self
+                .pulls
+                .list_all_files(&self.owner, &self.repo, self.pull_number)
+                .await?;
+            for file in files {
+                self.patches.entry(file.filename).or_insert(file.patch);
+            }
+        }
+
+        let mut comments = Vec::new();
+        for comment in &self.comments {
+            let position = self
+                .patches
+                .get(&comment.path)
+                .and_then(|patch| diff_position(patch, comment.line))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "line {} of `{}` is not part of the diff of the pull request",
+                        comment.line,
+                        comment.path
+                    )
+                })?;
+            comments.push(Comments {
+                body: comment.body.to_string(),
+                line: 0,
+                path: comment.path.to_string(),
+                position,
+                side: String::new(),
+                start_line: 0,
+                start_side: String::new(),
+                extra: Default::default(),
+            });
+        }
+
+        let body = PullsCreateReviewRequest {
+            body: self.body,
+            comments,
+            commit_id: self.commit_id,
+            event: Some(event.into()),
+        };
+        self.pulls
+            .create_review(&self.owner, &self.repo, self.pull_number, &body)
+            .await
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::scim_provisioning::ScimFilter;
 use crate::Client;
 
 pub struct Scim {
@@ -51,26 +52,28 @@ impl Scim {
     */
     pub async fn list_provisioned_identities(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         start_index: i64,
         count: i64,
         filter: &str,
     ) -> Result<crate::types::ScimUserList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+            query_args.push(("startIndex", start_index.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/scim/v2/organizations/{}/Users?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/scim/v2/organizations/{}/Users",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -91,9 +94,10 @@ impl Scim {
     */
     pub async fn provision_and_invite_user(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::ScimProvisionInviteUserRequest,
     ) -> Result<crate::types::ScimUser> {
+        let org = org.as_ref();
         let url = format!(
             "/scim/v2/organizations/{}/Users",
             crate::progenitor_support::encode_path(org),
@@ -120,9 +124,11 @@ impl Scim {
     */
     pub async fn get_provisioning_information_for_user(
         &self,
-        org: &str,
-        scim_user_id: &str,
+        org: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
     ) -> Result<crate::types::ScimUser> {
+        let org = org.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/organizations/{}/Users/{}",
             crate::progenitor_support::encode_path(org),
@@ -152,10 +158,12 @@ impl Scim {
     */
     pub async fn set_information_for_provisioned_user(
         &self,
-        org: &str,
-        scim_user_id: &str,
+        org: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
         body: &crate::types::ScimProvisionInviteUserRequest,
     ) -> Result<crate::types::ScimUser> {
+        let org = org.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/organizations/{}/Users/{}",
             crate::progenitor_support::encode_path(org),
@@ -181,7 +189,13 @@ impl Scim {
     * * `org: &str`
     * * `scim_user_id: &str` -- scim_user_id parameter.
     */
-    pub async fn delete_user_from_org(&self, org: &str, scim_user_id: &str) -> Result<()> {
+    pub async fn delete_user_from_org(
+        &self,
+        org: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let org = org.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/organizations/{}/Users/{}",
             crate::progenitor_support::encode_path(org),
@@ -222,10 +236,12 @@ impl Scim {
     */
     pub async fn update_attribute_for_user(
         &self,
-        org: &str,
-        scim_user_id: &str,
+        org: impl AsRef<str>,
+        scim_user_id: impl AsRef<str>,
         body: &crate::types::ScimUpdateAttributeUserRequest,
     ) -> Result<crate::types::ScimUser> {
+        let org = org.as_ref();
+        let scim_user_id = scim_user_id.as_ref();
         let url = format!(
             "/scim/v2/organizations/{}/Users/{}",
             crate::progenitor_support::encode_path(org),
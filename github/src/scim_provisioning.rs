@@ -0,0 +1,181 @@
+//! Provision and deprovision the members of an organization with SCIM, for
+//! organizations of GitHub Enterprise Cloud using SAML single sign-on.
+//!
+//! SCIM lists are paged by index rather than with links, and filtered with a small
+//! expression language of which GitHub supports equality on a few attributes only:
+//! `ScimFilter` writes these expressions.
+//!
+//! https://docs.github.com/en/rest/reference/scim
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use octorust::{scim::ScimFilter, types::ScimProvisionInviteUserRequest};
+//!
+//! let scim = github.scim();
+//! let filter = ScimFilter::Email("mona@example.com".to_string());
+//! if scim.find_provisioned_identity("org", &filter).await?.is_none() {
+//!     let user =
+//!         ScimProvisionInviteUserRequest::user("mona", "Mona", "Octocat", "mona@example.com");
+//!     scim.provision_and_invite_user("org", &user).await?;
+//! }
+//!
+//! let filter = ScimFilter::UserName("hubot".to_string());
+//! if let Some(user) = scim.find_provisioned_identity("org", &filter).await? {
+//!     scim.deprovision("org", &user.id).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use anyhow::Result;
+
+use crate::{
+    scim::Scim,
+    types::{
+        Emails, Op, ScimProvisionInviteUserRequest, ScimUpdateAttributeUserRequest,
+        ScimUpdateAttributeUserRequestOperations,
+        ScimUpdateAttributeUserRequestOperationsValueOneOf, ScimUser, ScimUserName, Value,
+    },
+};
+
+/// The schema of SCIM users.
+const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+
+/// The schema of the requests patching SCIM resources.
+const PATCH_OP_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+
+/// The number of identities requested per page.
+const PER_PAGE: i64 = 100;
+
+/// Selects the provisioned identities whose attribute equals a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScimFilter {
+    Id(String),
+    UserName(String),
+    Email(String),
+    ExternalId(String),
+}
+
+impl fmt::Display for ScimFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (attribute, value) = match self {
+            ScimFilter::Id(value) => ("id", value),
+            ScimFilter::UserName(value) => ("userName", value),
+            ScimFilter::Email(value) => ("emails", value),
+            ScimFilter::ExternalId(value) => ("externalId", value),
+        };
+        let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+        write!(f, "{} eq \"{}\"", attribute, value)
+    }
+}
+
+impl ScimProvisionInviteUserRequest {
+    /// A user with a single, primary email address.
+    pub fn user(user_name: &str, given_name: &str, family_name: &str, email: &str) -> Self {
+        ScimProvisionInviteUserRequest {
+            user_name: user_name.to_string(),
+            name: ScimUserName {
+                given_name: given_name.to_string(),
+                family_name: family_name.to_string(),
+                ..Default::default()
+            },
+            emails: vec![Emails {
+                primary: true,
+                type_: "work".to_string(),
+                value: email.to_string(),
+                extra: Default::default(),
+            }],
+            schemas: vec![USER_SCHEMA.to_string()],
+            ..Default::default()
+        }
+    }
+}
+
+impl Scim {
+    /**
+     * List all the identities provisioned in an organization, including the pending
+     * invitations, requesting every page.
+     *
+     * **Parameters:**
+     *
+     * * `org: &str`
+     * * `filter: Option<&ScimFilter>` -- Only list the identities it selects.
+     */
+    pub async fn list_all_provisioned_identities(
+        &self,
+        org: &str,
+        filter: Option<&ScimFilter>,
+    ) -> Result<Vec<ScimUser>> {
+        let filter = filter.map(|f| f.to_string()).unwrap_or_default();
+        let mut users = Vec::new();
+        loop {
+            // SCIM indexes start at 1.
+            let start_index = users.len() as i64 + 1;
+            let page = self
+                .list_provisioned_identities(org, start_index, PER_PAGE, &filter)
+                .await?;
+            if page.resources.is_empty() {
+                break;
+            }
+            users.extend(page.resources);
+            if users.len() as i64 >= page.total_results {
+                break;
+            }
+        }
+        Ok(users)
+    }
+
+    /**
+     * Get the first identity provisioned in an organization which the filter
+     * selects, if any.
+     *
+     * **Parameters:**
+     *
+     * * `org: &str`
+     * * `filter: &ScimFilter`
+     */
+    pub async fn find_provisioned_identity(
+        &self,
+        org: &str,
+        filter: &ScimFilter,
+    ) -> Result<Option<ScimUser>> {
+        let page = self
+            .list_provisioned_identities(org, 1, 1, &filter.to_string())
+            .await?;
+        Ok(page.resources.into_iter().next())
+    }
+
+    /**
+     * Deactivate a provisioned identity.
+     *
+     * This removes the user from the organization, and deletes their external
+     * identity along with its `scim_user_id`.
+     *
+     * **Parameters:**
+     *
+     * * `org: &str`
+     * * `scim_user_id: &str`
+     */
+    pub async fn deprovision(&self, org: &str, scim_user_id: &str) -> Result<ScimUser> {
+        let body = ScimUpdateAttributeUserRequest {
+            operations: vec![ScimUpdateAttributeUserRequestOperations {
+                op: Op::Replace,
+                path: String::new(),
+                value: Some(ScimUpdateAttributeUserRequestOperationsValueOneOf::Value(
+                    Value {
+                        active: false,
+                        external_id: String::new(),
+                        family_name: String::new(),
+                        given_name: String::new(),
+                        user_name: String::new(),
+                        extra: Default::default(),
+                    },
+                )),
+            }],
+            schemas: vec![PATCH_OP_SCHEMA.to_string()],
+        };
+        self.update_attribute_for_user(org, scim_user_id, &body)
+            .await
+    }
+}
@@ -0,0 +1,34 @@
+//! Encrypt secrets as libsodium sealed boxes.
+//!
+//! The secrets of Actions and of Dependabot are encrypted with the public key of
+//! the repository or organization storing them before they are sent to GitHub.
+//!
+//! https://docs.github.com/en/rest/reference/actions#create-or-update-a-repository-secret
+use anyhow::{anyhow, Result};
+use sodiumoxide::crypto::{box_, sealedbox};
+
+use crate::types::{ActionsCreateUpdateRepoSecretRequest, ActionsPublicKey};
+
+/// Encrypt `value` for the base64 encoded `public_key`, returning the base64
+/// encoded sealed box.
+pub(crate) fn seal(public_key: &str, value: &[u8]) -> Result<String> {
+    sodiumoxide::init().map_err(|_| anyhow!("failed to initialize libsodium"))?;
+
+    let public_key = base64::decode(public_key)?;
+    let public_key = box_::PublicKey::from_slice(&public_key)
+        .ok_or_else(|| anyhow!("invalid public key of {} bytes", public_key.len()))?;
+
+    Ok(base64::encode(sealedbox::seal(value, &public_key)))
+}
+
+/// The request storing the plain text `value` as a repository secret, encrypted
+/// with the given public key.
+pub(crate) fn secret_request(
+    public_key: ActionsPublicKey,
+    value: &str,
+) -> Result<ActionsCreateUpdateRepoSecretRequest> {
+    Ok(ActionsCreateUpdateRepoSecretRequest {
+        encrypted_value: seal(&public_key.key, value.as_bytes())?,
+        key_id: public_key.key_id,
+    })
+}
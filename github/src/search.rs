@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::search_query::{SearchQuery, SearchResult};
 use crate::Client;
 
 pub struct Search {
@@ -54,24 +55,23 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::SearchCodeResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search/code?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/search/code"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -108,24 +108,24 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::SearchCommitsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search/commits?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/search/commits"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -166,24 +166,23 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::SearchIssuesPullRequestsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search/issues?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/search/issues"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -223,27 +222,26 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::SearchLabelsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if repository_id > 0 {
-            query_args.push(("repository_id".to_string(), repository_id.to_string()));
+            query_args.push(("repository_id", repository_id.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search/labels?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/search/labels"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -285,24 +283,24 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::SearchReposResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search/repositories?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/search/repositories"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -336,18 +334,17 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::SearchTopicsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search/topics?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/search/topics"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -385,24 +382,23 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::SearchUsersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/search/users"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -0,0 +1,260 @@
+//! Build search queries with qualifiers and get typed pages of results.
+//!
+//! https://docs.github.com/en/rest/reference/search
+use std::{fmt, time::Duration};
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::search::Search;
+
+/// Maximum number of times a search is retried after hitting the rate limit.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+/// The search rate limit resets every minute. Waiting longer than that means a
+/// different rate limit was hit, so the error is returned instead.
+const MAX_RATE_LIMIT_WAIT: u64 = 60;
+
+/**
+ * A search query made of keywords and qualifiers.
+ *
+ * Values are quoted when needed, so that a label like `help wanted` is sent as
+ * `label:"help wanted"`.
+ *
+ * ```
+ * use octorust::search::SearchQuery;
+ *
+ * let query = SearchQuery::new("addClass")
+ *     .repo("jquery", "jquery")
+ *     .language("js")
+ *     .label("help wanted")
+ *     .created(">=2021-01-01");
+ * assert_eq!(
+ *     query.to_string(),
+ *     r#"addClass repo:jquery/jquery language:js label:"help wanted" created:>=2021-01-01"#
+ * );
+ * ```
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+    sort: String,
+    order: Option<crate::types::Order>,
+}
+
+impl SearchQuery {
+    /// Start a query with the given keywords, which are sent as is.
+    pub fn new(keywords: &str) -> Self {
+        let mut query = SearchQuery::default();
+        if !keywords.trim().is_empty() {
+            query.terms.push(keywords.trim().to_string());
+        }
+        query
+    }
+
+    /// Add a keyword, quoted if it contains whitespace.
+    pub fn keyword(mut self, keyword: &str) -> Self {
+        self.terms.push(quote(keyword));
+        self
+    }
+
+    /// Add a `name:value` qualifier.
+    pub fn qualifier(mut self, name: &str, value: &str) -> Self {
+        self.terms.push(format!("{}:{}", name, quote(value)));
+        self
+    }
+
+    /// Add a `-name:value` qualifier, excluding the results that match it.
+    pub fn exclude(mut self, name: &str, value: &str) -> Self {
+        self.terms.push(format!("-{}:{}", name, quote(value)));
+        self
+    }
+
+    /// Only search in the given repository.
+    pub fn repo(self, owner: &str, repo: &str) -> Self {
+        self.qualifier("repo", &format!("{}/{}", owner, repo))
+    }
+
+    /// Only search in the repositories of the given organization.
+    pub fn org(self, org: &str) -> Self {
+        self.qualifier("org", org)
+    }
+
+    /// Only search in the repositories of the given user.
+    pub fn user(self, user: &str) -> Self {
+        self.qualifier("user", user)
+    }
+
+    pub fn language(self, language: &str) -> Self {
+        self.qualifier("language", language)
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        self.qualifier("label", label)
+    }
+
+    /// Add an `is:` qualifier, for example `is:pr` or `is:open`.
+    pub fn is(self, value: &str) -> Self {
+        self.qualifier("is", value)
+    }
+
+    /// Filter on the creation date, for example `>=2021-01-01` or
+    /// `2021-01-01..2021-02-01`.
+    pub fn created(self, range: &str) -> Self {
+        self.qualifier("created", range)
+    }
+
+    /// Filter on the date of the last update, like `created`.
+    pub fn updated(self, range: &str) -> Self {
+        self.qualifier("updated", range)
+    }
+
+    /// Sort the results by the given field. The fields depend on what is searched.
+    pub fn sort(mut self, sort: &str) -> Self {
+        self.sort = sort.to_string();
+        self
+    }
+
+    /// Order the sorted results. Only used when `sort` is set.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.terms.join(" "))
+    }
+}
+
+/// Quote a value when it would otherwise be split into separate terms.
+fn quote(value: &str) -> String {
+    if value.is_empty() || value.contains(char::is_whitespace) || value.contains('"') {
+        format!("\"{}\"", value.replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// A page of search results.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SearchResult<T> {
+    #[serde(default)]
+    pub total_count: i64,
+    /// Whether the search timed out before all the matches were found.
+    #[serde(default)]
+    pub incomplete_results: bool,
+    #[serde(default = "Vec::new")]
+    pub items: Vec<T>,
+}
+
+impl Search {
+    /// Search code with a `SearchQuery`. See `code` for the details of the search.
+    pub async fn code_query(
+        &self,
+        query: &SearchQuery,
+        per_page: i64,
+        page: i64,
+    ) -> Result<SearchResult<crate::types::CodeSearchResultItem>> {
+        self.search("code", query, per_page, page).await
+    }
+
+    /// Search commits with a `SearchQuery`. See `commits` for the details of the search.
+    pub async fn commits_query(
+        &self,
+        query: &SearchQuery,
+        per_page: i64,
+        page: i64,
+    ) -> Result<SearchResult<crate::types::CommitSearchResultItem>> {
+        self.search("commits", query, per_page, page).await
+    }
+
+    /// Search issues and pull requests with a `SearchQuery`. See
+    /// `issues_and_pull_requests` for the details of the search.
+    pub async fn issues_query(
+        &self,
+        query: &SearchQuery,
+        per_page: i64,
+        page: i64,
+    ) -> Result<SearchResult<crate::types::IssueSearchResultItem>> {
+        self.search("issues", query, per_page, page).await
+    }
+
+    /// Search repositories with a `SearchQuery`. See `repos` for the details of the search.
+    pub async fn repos_query(
+        &self,
+        query: &SearchQuery,
+        per_page: i64,
+        page: i64,
+    ) -> Result<SearchResult<crate::types::RepoSearchResultItem>> {
+        self.search("repositories", query, per_page, page).await
+    }
+
+    /// Search users with a `SearchQuery`. See `users` for the details of the search.
+    pub async fn users_query(
+        &self,
+        query: &SearchQuery,
+        per_page: i64,
+        page: i64,
+    ) -> Result<SearchResult<crate::types::UserSearchResultItem>> {
+        self.search("users", query, per_page, page).await
+    }
+
+    /// Run a search, waiting for the rate limit to reset when it is exhausted.
+    ///
+    /// The search API has a much lower rate limit than the rest of the API, 30
+    /// requests per minute when authenticated, so it is hit a lot sooner.
+    async fn search<T>(
+        &self,
+        kind: &str,
+        query: &SearchQuery,
+        per_page: i64,
+        page: i64,
+    ) -> Result<SearchResult<T>>
+    where
+        T: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(order) = &query.order {
+            if !order.to_string().is_empty() && !query.sort.is_empty() {
+                query_args.push(("order".to_string(), order.to_string()));
+            }
+        }
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        query_args.push(("q".to_string(), query.to_string()));
+        if !query.sort.is_empty() {
+            query_args.push(("sort".to_string(), query.sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/search/{}?{}", kind, query_);
+
+        let mut retries = 0;
+        loop {
+            let err = match self.client.get(&url, None).await {
+                Ok(result) => return Ok(result),
+                Err(err) => err,
+            };
+
+            let wait = match err.downcast_ref::<crate::utils::RateLimitExceeded>() {
+                Some(limit)
+                    if retries < MAX_RATE_LIMIT_RETRIES
+                        && limit.reset_in <= MAX_RATE_LIMIT_WAIT =>
+                {
+                    // The reset time is only precise to the second.
+                    Duration::from_secs(limit.reset_in + 1)
+                }
+                _ => return Err(err),
+            };
+
+            log::warn!("search rate limit exceeded, sleeping {:?}", wait);
+            crate::runtime::sleep(wait).await;
+            retries += 1;
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Keep the members of a team in line with a list of logins, for example the
+//! members of a group of an identity provider.
+//!
+//! The current members are compared to the desired ones, and only the users who
+//! should join or leave the team are changed. In a dry run, the changes are
+//! returned without being made.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! use octorust::teams::MemberChange;
+//!
+//! let desired = ["octocat", "hubot"];
+//! for change in github
+//!     .teams()
+//!     .sync_members("org", "platform", &desired, true)
+//!     .await?
+//! {
+//!     match change {
+//!         MemberChange::Add(login) => println!("+ {}", login),
+//!         MemberChange::Remove(login) => println!("- {}", login),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+
+use crate::{
+    teams::Teams,
+    types::{TeamsAddUpdateMembershipUserInOrgRequest, TeamsListMembersInOrgRole},
+};
+
+/// A change to the members of a team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberChange {
+    /// Add the user with the login to the team, or invite them when they are not a
+    /// member of the organization.
+    Add(String),
+    /// Remove the user with the login from the team.
+    Remove(String),
+}
+
+/// The logins, in lower case.
+fn lowercase<'a>(logins: impl Iterator<Item = &'a str>) -> BTreeSet<String> {
+    logins.map(str::to_lowercase).collect()
+}
+
+/// The changes turning the `current` members of a team into the `desired` ones,
+/// the additions first. Logins are compared without their case, like GitHub does.
+/// The users with a pending invitation to the team are not added again.
+fn plan(current: &[String], invited: &[String], desired: &[&str]) -> Vec<MemberChange> {
+    let current_set = lowercase(current.iter().map(String::as_str));
+    let invited_set = lowercase(invited.iter().map(String::as_str));
+    let desired_set = lowercase(desired.iter().copied());
+
+    let mut changes = Vec::new();
+    let mut added = BTreeSet::new();
+    for login in desired {
+        let l = login.to_lowercase();
+        if !current_set.contains(&l) && !invited_set.contains(&l) && added.insert(l) {
+            changes.push(MemberChange::Add(login.to_string()));
+        }
+    }
+    for login in current {
+        if !desired_set.contains(&login.to_lowercase()) {
+            changes.push(MemberChange::Remove(login.to_string()));
+        }
+    }
+
+    changes
+}
+
+impl Teams {
+    /**
+     * Make the members of a team the users with the given logins.
+     *
+     * The users who are missing are added with the `member` role, and the members
+     * who are not desired are removed. The roles of the members who stay are kept.
+     * The members of the child teams are listed as members of the team too, so they
+     * are removed unless they are desired.
+     *
+     * Returns the changes, in the order they are made. With `dry_run`, nothing is
+     * changed and the changes that would be made are returned.
+     *
+     * **Parameters:**
+     *
+     * * `org: &str`
+     * * `team_slug: &str` -- team_slug parameter.
+     * * `desired: &[&str]` -- The logins of all the users who should be members of the team.
+     * * `dry_run: bool` -- Only plan the changes, without making them.
+     */
+    pub async fn sync_members(
+        &self,
+        org: &str,
+        team_slug: &str,
+        desired: &[&str],
+        dry_run: bool,
+    ) -> Result<Vec<MemberChange>> {
+        let current: Vec<String> = self
+            .list_all_members_in_org(org, team_slug, TeamsListMembersInOrgRole::All)
+            .await?
+            .into_iter()
+            .map(|user| user.login)
+            .collect();
+        let invited: Vec<String> = self
+            .list_all_pending_invitations_in_org(org, team_slug)
+            .await?
+            .into_iter()
+            .map(|invitation| invitation.login)
+            .filter(|login| !login.is_empty())
+            .collect();
+
+        let changes = plan(&current, &invited, desired);
+        if dry_run {
+            return Ok(changes);
+        }
+
+        for change in &changes {
+            match change {
+                MemberChange::Add(login) => {
+                    self.add_or_update_membership_for_user_in_org(
+                        org,
+                        team_slug,
+                        login,
+                        &TeamsAddUpdateMembershipUserInOrgRequest { role: None },
+                    )
+                    .await?;
+                }
+                MemberChange::Remove(login) => {
+                    self.remove_membership_for_user_in_org(org, team_slug, login)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+}
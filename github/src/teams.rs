@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::team_sync::MemberChange;
 use crate::Client;
 
 pub struct Teams {
@@ -33,22 +34,24 @@ impl Teams {
     */
     pub async fn list_idp_groups_for_org(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: &str,
     ) -> Result<crate::types::GroupMapping> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/team-sync/groups?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/team-sync/groups",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -71,22 +74,24 @@ impl Teams {
     */
     pub async fn list(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Team>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams?{}",
-            crate::progenitor_support::encode_path(org),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams",
+                crate::progenitor_support::encode_path(org),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -103,7 +108,8 @@ impl Teams {
     *
     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
     */
-    pub async fn list_all(&self, org: &str) -> Result<Vec<crate::types::Team>> {
+    pub async fn list_all(&self, org: impl AsRef<str>) -> Result<Vec<crate::types::Team>> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/teams",
             crate::progenitor_support::encode_path(org),
@@ -129,9 +135,10 @@ impl Teams {
     */
     pub async fn create(
         &self,
-        org: &str,
+        org: impl AsRef<str>,
         body: &crate::types::TeamsCreateRequest,
     ) -> Result<crate::types::FullTeam> {
+        let org = org.as_ref();
         let url = format!(
             "/orgs/{}/teams",
             crate::progenitor_support::encode_path(org),
@@ -158,7 +165,13 @@ impl Teams {
     * * `org: &str`
     * * `team_slug: &str` -- team_slug parameter.
     */
-    pub async fn get_by_name(&self, org: &str, team_slug: &str) -> Result<crate::types::FullTeam> {
+    pub async fn get_by_name(
+        &self,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+    ) -> Result<crate::types::FullTeam> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}",
             crate::progenitor_support::encode_path(org),
@@ -186,7 +199,13 @@ impl Teams {
     * * `org: &str`
     * * `team_slug: &str` -- team_slug parameter.
     */
-    pub async fn delete_in_org(&self, org: &str, team_slug: &str) -> Result<()> {
+    pub async fn delete_in_org(
+        &self,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+    ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}",
             crate::progenitor_support::encode_path(org),
@@ -214,10 +233,12 @@ impl Teams {
     */
     pub async fn update_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         body: &crate::types::TeamsUpdateInOrgRequest,
     ) -> Result<crate::types::FullTeam> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}",
             crate::progenitor_support::encode_path(org),
@@ -253,32 +274,35 @@ impl Teams {
     */
     pub async fn list_discussions_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         direction: crate::types::Order,
         per_page: i64,
         page: i64,
         pinned: &str,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !pinned.is_empty() {
-            query_args.push(("pinned".to_string(), pinned.to_string()));
+            query_args.push(("pinned", pinned.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -299,24 +323,27 @@ impl Teams {
     */
     pub async fn list_all_discussions_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         direction: crate::types::Order,
         pinned: &str,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !pinned.is_empty() {
-            query_args.push(("pinned".to_string(), pinned.to_string()));
+            query_args.push(("pinned", pinned.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -342,10 +369,12 @@ impl Teams {
     */
     pub async fn create_discussion_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         body: &crate::types::TeamsCreateDiscussionInOrgRequest,
     ) -> Result<crate::types::TeamDiscussion> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions",
             crate::progenitor_support::encode_path(org),
@@ -376,15 +405,17 @@ impl Teams {
     */
     pub async fn get_discussion_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
     ) -> Result<crate::types::TeamDiscussion> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client.get(&url, None).await
@@ -409,15 +440,17 @@ impl Teams {
     */
     pub async fn delete_discussion_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client.delete(&url, None).await
@@ -442,16 +475,18 @@ impl Teams {
     */
     pub async fn update_discussion_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
     ) -> Result<crate::types::TeamDiscussion> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client
@@ -483,30 +518,33 @@ impl Teams {
     */
     pub async fn list_discussion_comments_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         direction: crate::types::Order,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions/{}/comments?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -527,22 +565,25 @@ impl Teams {
     */
     pub async fn list_all_discussion_comments_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/discussions/{}/comments?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -569,16 +610,18 @@ impl Teams {
     */
     pub async fn create_discussion_comment_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::TeamDiscussionComment> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client
@@ -606,17 +649,19 @@ impl Teams {
     */
     pub async fn get_discussion_comment_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         comment_number: i64,
     ) -> Result<crate::types::TeamDiscussionComment> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client.get(&url, None).await
@@ -642,17 +687,19 @@ impl Teams {
     */
     pub async fn delete_discussion_comment_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         comment_number: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client.delete(&url, None).await
@@ -678,18 +725,20 @@ impl Teams {
     */
     pub async fn update_discussion_comment_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         discussion_number: i64,
         comment_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> Result<crate::types::TeamDiscussionComment> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client
@@ -717,24 +766,27 @@ impl Teams {
     */
     pub async fn list_pending_invitations_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/invitations?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -755,9 +807,11 @@ impl Teams {
     */
     pub async fn list_all_pending_invitations_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/invitations",
             crate::progenitor_support::encode_path(org),
@@ -791,28 +845,31 @@ impl Teams {
     */
     pub async fn list_members_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         role: crate::types::TeamsListMembersInOrgRole,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/members?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/members",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -833,20 +890,23 @@ impl Teams {
     */
     pub async fn list_all_members_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         role: crate::types::TeamsListMembersInOrgRole,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/members?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/members",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -878,10 +938,13 @@ impl Teams {
     */
     pub async fn get_membership_for_user_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::TeamMembership> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/memberships/{}",
             crate::progenitor_support::encode_path(org),
@@ -919,11 +982,14 @@ impl Teams {
     */
     pub async fn add_or_update_membership_for_user_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+        username: impl AsRef<str>,
         body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
     ) -> Result<crate::types::TeamMembership> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/memberships/{}",
             crate::progenitor_support::encode_path(org),
@@ -959,10 +1025,13 @@ impl Teams {
     */
     pub async fn remove_membership_for_user_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
-        username: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+        username: impl AsRef<str>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let username = username.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/memberships/{}",
             crate::progenitor_support::encode_path(org),
@@ -993,24 +1062,27 @@ impl Teams {
     */
     pub async fn list_projects_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::TeamProject>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/projects?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/projects",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1031,9 +1103,11 @@ impl Teams {
     */
     pub async fn list_all_projects_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
     ) -> Result<Vec<crate::types::TeamProject>> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/projects",
             crate::progenitor_support::encode_path(org),
@@ -1062,15 +1136,17 @@ impl Teams {
     */
     pub async fn check_permissions_for_project_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         project_id: i64,
     ) -> Result<crate::types::TeamProject> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/projects/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client.get(&url, None).await
@@ -1095,16 +1171,18 @@ impl Teams {
     */
     pub async fn add_or_update_project_permissions_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         project_id: i64,
         body: &crate::types::ProjectsAddCollaboratorRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/projects/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client
@@ -1131,15 +1209,17 @@ impl Teams {
     */
     pub async fn remove_project_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         project_id: i64,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/projects/{}",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(team_slug),
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client.delete(&url, None).await
@@ -1165,24 +1245,27 @@ impl Teams {
     */
     pub async fn list_repos_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/repos?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/repos",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1203,9 +1286,11 @@ impl Teams {
     */
     pub async fn list_all_repos_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/repos",
             crate::progenitor_support::encode_path(org),
@@ -1239,11 +1324,15 @@ impl Teams {
     */
     pub async fn check_permissions_for_repo_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
-        owner: &str,
-        repo: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::TeamRepository> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/repos/{}/{}",
             crate::progenitor_support::encode_path(org),
@@ -1277,12 +1366,16 @@ impl Teams {
     */
     pub async fn add_or_update_repo_permissions_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
-        owner: &str,
-        repo: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::TeamsAddUpdateRepoPermissionsInOrgRequest,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/repos/{}/{}",
             crate::progenitor_support::encode_path(org),
@@ -1316,11 +1409,15 @@ impl Teams {
     */
     pub async fn remove_repo_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
-        owner: &str,
-        repo: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<()> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/repos/{}/{}",
             crate::progenitor_support::encode_path(org),
@@ -1352,9 +1449,11 @@ impl Teams {
     */
     pub async fn list_idp_groups_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
     ) -> Result<crate::types::GroupMapping> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/team-sync/group-mappings",
             crate::progenitor_support::encode_path(org),
@@ -1384,10 +1483,12 @@ impl Teams {
     */
     pub async fn create_or_update_idp_group_connections_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsInOrgRequest,
     ) -> Result<crate::types::GroupMapping> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/team-sync/group-mappings",
             crate::progenitor_support::encode_path(org),
@@ -1419,24 +1520,27 @@ impl Teams {
     */
     pub async fn list_child_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Team>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/teams/{}/teams?{}",
-            crate::progenitor_support::encode_path(org),
-            crate::progenitor_support::encode_path(team_slug),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/orgs/{}/teams/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1457,9 +1561,11 @@ impl Teams {
     */
     pub async fn list_all_child_in_org(
         &self,
-        org: &str,
-        team_slug: &str,
+        org: impl AsRef<str>,
+        team_slug: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Team>> {
+        let org = org.as_ref();
+        let team_slug = team_slug.as_ref();
         let url = format!(
             "/orgs/{}/teams/{}/teams",
             crate::progenitor_support::encode_path(org),
@@ -1485,7 +1591,7 @@ impl Teams {
     pub async fn get_legacy(&self, team_id: i64) -> Result<crate::types::FullTeam> {
         let url = format!(
             "/teams/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client.get(&url, None).await
@@ -1511,7 +1617,7 @@ impl Teams {
     pub async fn delete_legacy(&self, team_id: i64) -> Result<()> {
         let url = format!(
             "/teams/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client.delete(&url, None).await
@@ -1541,7 +1647,7 @@ impl Teams {
     ) -> Result<crate::types::FullTeam> {
         let url = format!(
             "/teams/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client
@@ -1576,21 +1682,22 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1614,15 +1721,16 @@ impl Teams {
         team_id: i64,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1652,7 +1760,7 @@ impl Teams {
     ) -> Result<crate::types::TeamDiscussion> {
         let url = format!(
             "/teams/{}/discussions",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client
@@ -1683,8 +1791,8 @@ impl Teams {
     ) -> Result<crate::types::TeamDiscussion> {
         let url = format!(
             "/teams/{}/discussions/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client.get(&url, None).await
@@ -1713,8 +1821,8 @@ impl Teams {
     ) -> Result<()> {
         let url = format!(
             "/teams/{}/discussions/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client.delete(&url, None).await
@@ -1744,8 +1852,8 @@ impl Teams {
     ) -> Result<crate::types::TeamDiscussion> {
         let url = format!(
             "/teams/{}/discussions/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client
@@ -1782,22 +1890,23 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions/{}/comments?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(&team_id),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1822,16 +1931,17 @@ impl Teams {
         discussion_number: i64,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/discussions/{}/comments?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(&team_id),
+                crate::progenitor_support::encode_path(&discussion_number),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1863,8 +1973,8 @@ impl Teams {
     ) -> Result<crate::types::TeamDiscussionComment> {
         let url = format!(
             "/teams/{}/discussions/{}/comments",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
         );
 
         self.client
@@ -1897,9 +2007,9 @@ impl Teams {
     ) -> Result<crate::types::TeamDiscussionComment> {
         let url = format!(
             "/teams/{}/discussions/{}/comments/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client.get(&url, None).await
@@ -1930,9 +2040,9 @@ impl Teams {
     ) -> Result<()> {
         let url = format!(
             "/teams/{}/discussions/{}/comments/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client.delete(&url, None).await
@@ -1964,9 +2074,9 @@ impl Teams {
     ) -> Result<crate::types::TeamDiscussionComment> {
         let url = format!(
             "/teams/{}/discussions/{}/comments/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&discussion_number),
+            crate::progenitor_support::encode_path(&comment_number),
         );
 
         self.client
@@ -1997,18 +2107,19 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/invitations?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/invitations",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2033,7 +2144,7 @@ impl Teams {
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let url = format!(
             "/teams/{}/invitations",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2067,21 +2178,22 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/members?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/members",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2105,15 +2217,16 @@ impl Teams {
         team_id: i64,
         role: crate::types::TeamsListMembersInOrgRole,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/members?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/members",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2124,6 +2237,8 @@ impl Teams {
     *
     * This function performs a `GET` to the `/teams/{team_id}/members/{username}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     * The "Get team member" endpoint (described below) is deprecated.
     *
     * We recommend using the [Get team membership for a user](https://docs.github.com/rest/reference/teams#get-team-membership-for-a-user) endpoint instead. It allows you to get both active and pending memberships.
@@ -2137,14 +2252,15 @@ impl Teams {
     * * `team_id: i64`
     * * `username: &str`
     */
-    pub async fn get_member_legacy(&self, team_id: i64, username: &str) -> Result<()> {
+    pub async fn get_member_legacy(&self, team_id: i64, username: impl AsRef<str>) -> Result<bool> {
+        let username = username.as_ref();
         let url = format!(
             "/teams/{}/members/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(username),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -2171,10 +2287,11 @@ impl Teams {
     * * `team_id: i64`
     * * `username: &str`
     */
-    pub async fn add_member_legacy(&self, team_id: i64, username: &str) -> Result<()> {
+    pub async fn add_member_legacy(&self, team_id: i64, username: impl AsRef<str>) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/teams/{}/members/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -2203,10 +2320,15 @@ impl Teams {
     * * `team_id: i64`
     * * `username: &str`
     */
-    pub async fn remove_member_legacy(&self, team_id: i64, username: &str) -> Result<()> {
+    pub async fn remove_member_legacy(
+        &self,
+        team_id: i64,
+        username: impl AsRef<str>,
+    ) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/teams/{}/members/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -2239,11 +2361,12 @@ impl Teams {
     pub async fn get_membership_for_user_legacy(
         &self,
         team_id: i64,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::TeamMembership> {
+        let username = username.as_ref();
         let url = format!(
             "/teams/{}/memberships/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -2277,12 +2400,13 @@ impl Teams {
     pub async fn add_or_update_membership_for_user_legacy(
         &self,
         team_id: i64,
-        username: &str,
+        username: impl AsRef<str>,
         body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
     ) -> Result<crate::types::TeamMembership> {
+        let username = username.as_ref();
         let url = format!(
             "/teams/{}/memberships/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -2314,11 +2438,12 @@ impl Teams {
     pub async fn remove_membership_for_user_legacy(
         &self,
         team_id: i64,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/teams/{}/memberships/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(username),
         );
 
@@ -2348,18 +2473,19 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::TeamProject>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/projects?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/projects",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2384,7 +2510,7 @@ impl Teams {
     ) -> Result<Vec<crate::types::TeamProject>> {
         let url = format!(
             "/teams/{}/projects",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2413,8 +2539,8 @@ impl Teams {
     ) -> Result<crate::types::TeamProject> {
         let url = format!(
             "/teams/{}/projects/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client.get(&url, None).await
@@ -2444,8 +2570,8 @@ impl Teams {
     ) -> Result<()> {
         let url = format!(
             "/teams/{}/projects/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client
@@ -2472,8 +2598,8 @@ impl Teams {
     pub async fn remove_project_legacy(&self, team_id: i64, project_id: i64) -> Result<()> {
         let url = format!(
             "/teams/{}/projects/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&project_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
+            crate::progenitor_support::encode_path(&project_id),
         );
 
         self.client.delete(&url, None).await
@@ -2500,18 +2626,19 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/repos?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/repos",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2534,7 +2661,7 @@ impl Teams {
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let url = format!(
             "/teams/{}/repos",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2562,12 +2689,14 @@ impl Teams {
     pub async fn check_permissions_for_repo_legacy(
         &self,
         team_id: i64,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
     ) -> Result<crate::types::TeamRepository> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/teams/{}/repos/{}/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
         );
@@ -2597,13 +2726,15 @@ impl Teams {
     pub async fn add_or_update_repo_permissions_legacy(
         &self,
         team_id: i64,
-        owner: &str,
-        repo: &str,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
         body: &crate::types::TeamsAddUpdateRepoPermissionsLegacyRequest,
     ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/teams/{}/repos/{}/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
         );
@@ -2630,10 +2761,17 @@ impl Teams {
     * * `owner: &str`
     * * `repo: &str`
     */
-    pub async fn remove_repo_legacy(&self, team_id: i64, owner: &str, repo: &str) -> Result<()> {
+    pub async fn remove_repo_legacy(
+        &self,
+        team_id: i64,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<()> {
+        let owner = owner.as_ref();
+        let repo = repo.as_ref();
         let url = format!(
             "/teams/{}/repos/{}/{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
         );
@@ -2664,7 +2802,7 @@ impl Teams {
     ) -> Result<crate::types::GroupMapping> {
         let url = format!(
             "/teams/{}/team-sync/group-mappings",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client.get(&url, None).await
@@ -2694,7 +2832,7 @@ impl Teams {
     ) -> Result<crate::types::GroupMapping> {
         let url = format!(
             "/teams/{}/team-sync/group-mappings",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client
@@ -2723,18 +2861,19 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Team>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teams/{}/teams?{}",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teams/{}/teams",
+                crate::progenitor_support::encode_path(&team_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2754,7 +2893,7 @@ impl Teams {
     pub async fn list_all_child_legacy(&self, team_id: i64) -> Result<Vec<crate::types::Team>> {
         let url = format!(
             "/teams/{}/teams",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&team_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -2779,15 +2918,14 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::FullTeam>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/teams?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/teams"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -0,0 +1,150 @@
+//! Pluggable transports for sending requests, mostly useful for testing code
+//! that uses the client without a live network.
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, Result};
+use reqwest::{header::HeaderMap, Method, Request, Response, StatusCode, Url};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+
+/// Sends a request and returns the response.
+#[async_trait::async_trait]
+pub trait HttpTransport: Send + Sync + 'static {
+    async fn send(&self, req: Request) -> Result<Response>;
+}
+
+/// Middleware handing every request to a `HttpTransport` instead of the network.
+pub struct TransportMiddleware {
+    transport: Arc<dyn HttpTransport>,
+}
+
+impl TransportMiddleware {
+    pub fn new<T>(transport: T) -> Self
+    where
+        T: HttpTransport,
+    {
+        TransportMiddleware {
+            transport: Arc::new(transport),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for TransportMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        _extensions: &mut Extensions,
+        _next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.transport
+            .send(req)
+            .await
+            .map_err(reqwest_middleware::Error::Middleware)
+    }
+}
+
+impl crate::Client {
+    /// Send all requests through the given transport instead of the network.
+    ///
+    /// Requests are not retried, so canned error responses are returned right away.
+    pub fn with_transport<T>(&self, transport: T) -> Self
+    where
+        T: HttpTransport,
+    {
+        let mut c = self.clone();
+        c.client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(TransportMiddleware::new(transport))
+            .build();
+        c
+    }
+}
+
+/// A request recorded by `MockTransport`.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Deserialize the JSON body of the request.
+    pub fn json<T>(&self) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+/// A transport returning canned responses in the order they were added and
+/// recording every request it receives. Clones share the same state, so a clone
+/// can be handed to the client and the original kept around to make assertions.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    responses: Arc<Mutex<VecDeque<(StatusCode, Vec<u8>)>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Queue a response with the given status and body.
+    pub fn push_response<B>(&self, status: StatusCode, body: B) -> &Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responses
+            .lock()
+            .unwrap()
+            .push_back((status, body.into()));
+        self
+    }
+
+    /// Queue a response with the given status and a JSON body.
+    pub fn push_json<T>(&self, status: StatusCode, body: &T) -> Result<&Self>
+    where
+        T: serde::Serialize,
+    {
+        Ok(self.push_response(status, serde_json::to_vec(body)?))
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for MockTransport {
+    async fn send(&self, req: Request) -> Result<Response> {
+        let body = req
+            .body()
+            .and_then(|b| b.as_bytes())
+            .map(|b| b.to_vec())
+            .unwrap_or_default();
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method: req.method().clone(),
+            url: req.url().clone(),
+            headers: req.headers().clone(),
+            body,
+        });
+
+        let (status, body) = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| anyhow!("no response queued for {} {}", req.method(), req.url()))?;
+
+        let resp = http::Response::builder().status(status).body(body)?;
+        Ok(Response::from(resp))
+    }
+}
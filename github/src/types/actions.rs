@@ -0,0 +1,1401 @@
+//! The data types of the `actions` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsOrganizationPermissions {
+    /**
+    * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /**
+    * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+    */
+    #[serde()]
+    pub enabled_repositories: EnabledRepositories,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_actions_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_repositories_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RunnerGroupsOrg {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allows_public_repositories: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub inherited: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub inherited_allows_public_repositories: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub runners_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_repositories_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub visibility: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Secrets for GitHub Actions for an organization.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrganizationActionsSecret {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_repositories_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * Visibility of a secret
+    */
+    #[serde(default, skip_serializing_if = "Visibility::is_noop")]
+    pub visibility: Visibility,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// An artifact
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Artifact {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub archive_download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub expired: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size_in_bytes: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Steps {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub conclusion: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The phase of the lifecycle that the job is currently in.
+    */
+    #[serde(default, skip_serializing_if = "JobStatus::is_noop")]
+    pub status: JobStatus,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Information of a job execution in a workflow run
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Job {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub check_run_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub conclusion: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub run_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub run_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The phase of the lifecycle that the job is currently in.
+    */
+    #[serde(default, skip_serializing_if = "JobStatus::is_noop")]
+    pub status: JobStatus,
+    /**
+    * Information of a job execution in a workflow run
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub steps: Vec<Steps>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsRepositoryPermissions {
+    /**
+    * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_actions_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Environments {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* Whether deployment to the environment(s) was approved or rejected
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum EnvironmentApprovalState {
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "rejected")]
+    Rejected,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for EnvironmentApprovalState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            EnvironmentApprovalState::Approved => "approved",
+            EnvironmentApprovalState::Rejected => "rejected",
+            EnvironmentApprovalState::Noop => "",
+            EnvironmentApprovalState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for EnvironmentApprovalState {
+    fn default() -> EnvironmentApprovalState {
+        EnvironmentApprovalState::Noop
+    }
+}
+impl EnvironmentApprovalState {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, EnvironmentApprovalState::Noop)
+    }
+}
+
+/// An entry in the reviews log for environment deployments
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvironmentApproval {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub comment: String,
+    /**
+    * The list of environments that were approved or rejected
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub environments: Vec<Environments>,
+    /**
+    * Whether deployment to the environment(s) was approved or rejected
+    */
+    #[serde(default, skip_serializing_if = "EnvironmentApprovalState::is_noop")]
+    pub state: EnvironmentApprovalState,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Environment {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Details of a deployment that is waiting for protection rules to pass
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PendingDeployment {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub current_user_can_approve: bool,
+    #[serde()]
+    pub environment: Environment,
+    /**
+    * The people or teams that may approve jobs that reference the environment. You can list up to six users or teams as reviewers. The reviewers must have at least read access to the repository. Only one of the required reviewers needs to approve the job for it to proceed.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reviewers: Vec<Reviewers>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub wait_timer: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub wait_timer_started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Macos {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub jobs: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Billable {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "MACOS")]
+    pub macos: Option<Macos>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "UBUNTU")]
+    pub ubuntu: Option<Macos>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "WINDOWS")]
+    pub windows: Option<Macos>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Workflow Run Usage
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowRunUsage {
+    #[serde()]
+    pub billable: Billable,
+    /**
+    * Workflow Run Usage
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub run_duration_ms: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Windows {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowUsageBillable {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "MACOS")]
+    pub macos: Option<Windows>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "UBUNTU")]
+    pub ubuntu: Option<Windows>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "WINDOWS")]
+    pub windows: Option<Windows>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Workflow Usage
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowUsage {
+    #[serde()]
+    pub billable: WorkflowUsageBillable,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum WorkflowRunStatus {
+    #[serde(rename = "action_required")]
+    ActionRequired,
+    #[serde(rename = "cancelled")]
+    Cancelled,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "neutral")]
+    Neutral,
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "requested")]
+    Requested,
+    #[serde(rename = "skipped")]
+    Skipped,
+    #[serde(rename = "stale")]
+    Stale,
+    #[serde(rename = "success")]
+    Success,
+    #[serde(rename = "timed_out")]
+    TimedOut,
+    #[serde(rename = "waiting")]
+    Waiting,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for WorkflowRunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            WorkflowRunStatus::ActionRequired => "action_required",
+            WorkflowRunStatus::Cancelled => "cancelled",
+            WorkflowRunStatus::Completed => "completed",
+            WorkflowRunStatus::Failure => "failure",
+            WorkflowRunStatus::InProgress => "in_progress",
+            WorkflowRunStatus::Neutral => "neutral",
+            WorkflowRunStatus::Queued => "queued",
+            WorkflowRunStatus::Requested => "requested",
+            WorkflowRunStatus::Skipped => "skipped",
+            WorkflowRunStatus::Stale => "stale",
+            WorkflowRunStatus::Success => "success",
+            WorkflowRunStatus::TimedOut => "timed_out",
+            WorkflowRunStatus::Waiting => "waiting",
+            WorkflowRunStatus::Noop => "",
+            WorkflowRunStatus::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for WorkflowRunStatus {
+    fn default() -> WorkflowRunStatus {
+        WorkflowRunStatus::Noop
+    }
+}
+impl WorkflowRunStatus {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, WorkflowRunStatus::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetGithubPermissionsOrganizationRequest {
+    /**
+    * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /**
+    * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+    */
+    #[serde()]
+    pub enabled_repositories: EnabledRepositories,
+}
+
+impl ActionsSetGithubPermissionsOrganizationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsSetGithubPermissionsOrganizationRequestBuilder {
+        ActionsSetGithubPermissionsOrganizationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsSetGithubPermissionsOrganizationRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsSetGithubPermissionsOrganizationRequestBuilder(
+    ActionsSetGithubPermissionsOrganizationRequest,
+);
+
+impl ActionsSetGithubPermissionsOrganizationRequestBuilder {
+    pub fn allowed_actions(mut self, allowed_actions: impl Into<AllowedActions>) -> Self {
+        self.0.allowed_actions = Some(allowed_actions.into());
+        self
+    }
+
+    pub fn enabled_repositories(
+        mut self,
+        enabled_repositories: impl Into<EnabledRepositories>,
+    ) -> Self {
+        self.0.enabled_repositories = enabled_repositories.into();
+        self
+    }
+
+    pub fn build(self) -> ActionsSetGithubPermissionsOrganizationRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest {
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<i64>,
+}
+
+impl ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequestBuilder {
+        ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequestBuilder(
+    ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+);
+
+impl ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequestBuilder {
+    pub fn selected_repository_ids(mut self, selected_repository_ids: Vec<i64>) -> Self {
+        self.0.selected_repository_ids = selected_repository_ids;
+        self
+    }
+
+    pub fn build(self) -> ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelfHostedRunnerGroupsOrgResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runner_groups: Vec<RunnerGroupsOrg>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<i64>,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<i64>,
+    /**
+    * Visibility of a secret
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl ActionsCreateSelfHostedRunnerGroupOrgRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsCreateSelfHostedRunnerGroupOrgRequestBuilder {
+        ActionsCreateSelfHostedRunnerGroupOrgRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsCreateSelfHostedRunnerGroupOrgRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsCreateSelfHostedRunnerGroupOrgRequestBuilder(
+    ActionsCreateSelfHostedRunnerGroupOrgRequest,
+);
+
+impl ActionsCreateSelfHostedRunnerGroupOrgRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn runners(mut self, runners: Vec<i64>) -> Self {
+        self.0.runners = runners;
+        self
+    }
+
+    pub fn selected_repository_ids(mut self, selected_repository_ids: Vec<i64>) -> Self {
+        self.0.selected_repository_ids = selected_repository_ids;
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<Visibility>) -> Self {
+        self.0.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn build(self) -> ActionsCreateSelfHostedRunnerGroupOrgRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsUpdateSelfHostedRunnerGroupOrgRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * Visibility of a secret
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl ActionsUpdateSelfHostedRunnerGroupOrgRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsUpdateSelfHostedRunnerGroupOrgRequestBuilder {
+        ActionsUpdateSelfHostedRunnerGroupOrgRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsUpdateSelfHostedRunnerGroupOrgRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsUpdateSelfHostedRunnerGroupOrgRequestBuilder(
+    ActionsUpdateSelfHostedRunnerGroupOrgRequest,
+);
+
+impl ActionsUpdateSelfHostedRunnerGroupOrgRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<Visibility>) -> Self {
+        self.0.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn build(self) -> ActionsUpdateSelfHostedRunnerGroupOrgRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<MinimalRepository>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelfHostedRunnersOrgResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<Runner>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListOrgSecretsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub secrets: Vec<OrganizationActionsSecret>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateUpdateOrgSecretRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub encrypted_value: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key_id: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<String>,
+    /**
+    * Visibility of a secret
+    */
+    #[serde(default, skip_serializing_if = "Visibility::is_noop")]
+    pub visibility: Visibility,
+}
+
+impl ActionsCreateUpdateOrgSecretRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsCreateUpdateOrgSecretRequestBuilder {
+        ActionsCreateUpdateOrgSecretRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsCreateUpdateOrgSecretRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsCreateUpdateOrgSecretRequestBuilder(ActionsCreateUpdateOrgSecretRequest);
+
+impl ActionsCreateUpdateOrgSecretRequestBuilder {
+    pub fn encrypted_value(mut self, encrypted_value: impl Into<String>) -> Self {
+        self.0.encrypted_value = encrypted_value.into();
+        self
+    }
+
+    pub fn key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.0.key_id = key_id.into();
+        self
+    }
+
+    pub fn selected_repository_ids(mut self, selected_repository_ids: Vec<String>) -> Self {
+        self.0.selected_repository_ids = selected_repository_ids;
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<Visibility>) -> Self {
+        self.0.visibility = visibility.into();
+        self
+    }
+
+    pub fn build(self) -> ActionsCreateUpdateOrgSecretRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelectedReposOrgSecretResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<MinimalRepository>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetSelectedReposOrgSecretRequest {
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<i64>,
+}
+
+impl ActionsSetSelectedReposOrgSecretRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsSetSelectedReposOrgSecretRequestBuilder {
+        ActionsSetSelectedReposOrgSecretRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsSetSelectedReposOrgSecretRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsSetSelectedReposOrgSecretRequestBuilder(ActionsSetSelectedReposOrgSecretRequest);
+
+impl ActionsSetSelectedReposOrgSecretRequestBuilder {
+    pub fn selected_repository_ids(mut self, selected_repository_ids: Vec<i64>) -> Self {
+        self.0.selected_repository_ids = selected_repository_ids;
+        self
+    }
+
+    pub fn build(self) -> ActionsSetSelectedReposOrgSecretRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListArtifactsRepoResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub artifacts: Vec<Artifact>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetGithubPermissionsRepositoryRequest {
+    /**
+    * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl ActionsSetGithubPermissionsRepositoryRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsSetGithubPermissionsRepositoryRequestBuilder {
+        ActionsSetGithubPermissionsRepositoryRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsSetGithubPermissionsRepositoryRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsSetGithubPermissionsRepositoryRequestBuilder(
+    ActionsSetGithubPermissionsRepositoryRequest,
+);
+
+impl ActionsSetGithubPermissionsRepositoryRequestBuilder {
+    pub fn allowed_actions(mut self, allowed_actions: impl Into<AllowedActions>) -> Self {
+        self.0.allowed_actions = Some(allowed_actions.into());
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn build(self) -> ActionsSetGithubPermissionsRepositoryRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListWorkflowRunsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub workflow_runs: Vec<WorkflowRun>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListJobsWorkflowRunResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub jobs: Vec<Job>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsReviewPendingDeploymentsRunRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub comment: String,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub environment_ids: Vec<i64>,
+    /**
+    * Whether deployment to the environment(s) was approved or rejected
+    */
+    #[serde(default, skip_serializing_if = "EnvironmentApprovalState::is_noop")]
+    pub state: EnvironmentApprovalState,
+}
+
+impl ActionsReviewPendingDeploymentsRunRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsReviewPendingDeploymentsRunRequestBuilder {
+        ActionsReviewPendingDeploymentsRunRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsReviewPendingDeploymentsRunRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsReviewPendingDeploymentsRunRequestBuilder(
+    ActionsReviewPendingDeploymentsRunRequest,
+);
+
+impl ActionsReviewPendingDeploymentsRunRequestBuilder {
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.0.comment = comment.into();
+        self
+    }
+
+    pub fn environment_ids(mut self, environment_ids: Vec<i64>) -> Self {
+        self.0.environment_ids = environment_ids;
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<EnvironmentApprovalState>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn build(self) -> ActionsReviewPendingDeploymentsRunRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListRepoWorkflowsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub workflows: Vec<Workflow>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateWorkflowDispatchRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub inputs: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+}
+
+impl ActionsCreateWorkflowDispatchRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActionsCreateWorkflowDispatchRequestBuilder {
+        ActionsCreateWorkflowDispatchRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActionsCreateWorkflowDispatchRequest`.
+#[derive(Debug, Clone)]
+pub struct ActionsCreateWorkflowDispatchRequestBuilder(ActionsCreateWorkflowDispatchRequest);
+
+impl ActionsCreateWorkflowDispatchRequestBuilder {
+    pub fn inputs(mut self, inputs: impl Into<String>) -> Self {
+        self.0.inputs = inputs.into();
+        self
+    }
+
+    pub fn ref_(mut self, ref_: impl Into<String>) -> Self {
+        self.0.ref_ = ref_.into();
+        self
+    }
+
+    pub fn build(self) -> ActionsCreateWorkflowDispatchRequest {
+        self.0
+    }
+}
@@ -0,0 +1,532 @@
+//! The data types of the `activity` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Hypermedia Link with Type
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LinkWithType {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub href: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Links {
+    /**
+    * Hypermedia Link with Type
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_user: Option<LinkWithType>,
+    /**
+    * Hypermedia Link with Type
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_user_actor: Option<LinkWithType>,
+    /**
+    * Hypermedia Link with Type
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_user_organization: Option<LinkWithType>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub current_user_organizations: Vec<LinkWithType>,
+    /**
+    * Hypermedia Link with Type
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_user_public: Option<LinkWithType>,
+    /**
+    * Hypermedia Link with Type
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_advisories: Option<LinkWithType>,
+    /**
+    * Hypermedia Link with Type
+    */
+    #[serde()]
+    pub timeline: LinkWithType,
+    /**
+    * Hypermedia Link with Type
+    */
+    #[serde()]
+    pub user: LinkWithType,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Feed
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Feed {
+    #[serde(rename = "_links")]
+    pub links: Links,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub current_user_actor_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub current_user_organization_url: String,
+    /**
+    * Feed
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub current_user_organization_urls: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub current_user_public_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub current_user_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub security_advisories_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timeline_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Subject {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub latest_comment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Thread
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Thread {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_read_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    /**
+    * Minimal Repository
+    */
+    #[serde()]
+    pub repository: MinimalRepository,
+    #[serde()]
+    pub subject: Subject,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscription_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub unread: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub updated_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Thread Subscription
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ThreadSubscription {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ignored: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub subscribed: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub thread_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Stargazer
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Stargazer {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub starred_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Repository invitations let you manage who you collaborate with.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RepositorySubscription {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ignored: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub subscribed: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Starred Repository
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StarredRepository {
+    /**
+    * A git repository
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub starred_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActivityMarkNotificationsAsReadRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub last_read_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read: Option<bool>,
+}
+
+impl ActivityMarkNotificationsAsReadRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActivityMarkNotificationsAsReadRequestBuilder {
+        ActivityMarkNotificationsAsReadRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActivityMarkNotificationsAsReadRequest`.
+#[derive(Debug, Clone)]
+pub struct ActivityMarkNotificationsAsReadRequestBuilder(ActivityMarkNotificationsAsReadRequest);
+
+impl ActivityMarkNotificationsAsReadRequestBuilder {
+    pub fn last_read_at(mut self, last_read_at: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.0.last_read_at = Some(last_read_at.into());
+        self
+    }
+
+    pub fn read(mut self, read: bool) -> Self {
+        self.0.read = Some(read);
+        self
+    }
+
+    pub fn build(self) -> ActivityMarkNotificationsAsReadRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActivitySetThreadSubscriptionRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignored: Option<bool>,
+}
+
+impl ActivitySetThreadSubscriptionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActivitySetThreadSubscriptionRequestBuilder {
+        ActivitySetThreadSubscriptionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActivitySetThreadSubscriptionRequest`.
+#[derive(Debug, Clone)]
+pub struct ActivitySetThreadSubscriptionRequestBuilder(ActivitySetThreadSubscriptionRequest);
+
+impl ActivitySetThreadSubscriptionRequestBuilder {
+    pub fn ignored(mut self, ignored: bool) -> Self {
+        self.0.ignored = Some(ignored);
+        self
+    }
+
+    pub fn build(self) -> ActivitySetThreadSubscriptionRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActivityMarkRepoNotificationsAsReadRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub last_read_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ActivityMarkRepoNotificationsAsReadRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActivityMarkRepoNotificationsAsReadRequestBuilder {
+        ActivityMarkRepoNotificationsAsReadRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActivityMarkRepoNotificationsAsReadRequest`.
+#[derive(Debug, Clone)]
+pub struct ActivityMarkRepoNotificationsAsReadRequestBuilder(
+    ActivityMarkRepoNotificationsAsReadRequest,
+);
+
+impl ActivityMarkRepoNotificationsAsReadRequestBuilder {
+    pub fn last_read_at(mut self, last_read_at: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.0.last_read_at = Some(last_read_at.into());
+        self
+    }
+
+    pub fn build(self) -> ActivityMarkRepoNotificationsAsReadRequest {
+        self.0
+    }
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Vec<SimpleUser>`
+/// - `Vec<Stargazer>`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActivityListStargazersRepoResponseAnyOf {
+    #[serde(flatten)]
+    pub simple_user_vector: Vec<SimpleUser>,
+    #[serde(flatten)]
+    pub stargazer_vector: Vec<Stargazer>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActivitySetRepoSubscriptionRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignored: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed: Option<bool>,
+}
+
+impl ActivitySetRepoSubscriptionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActivitySetRepoSubscriptionRequestBuilder {
+        ActivitySetRepoSubscriptionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActivitySetRepoSubscriptionRequest`.
+#[derive(Debug, Clone)]
+pub struct ActivitySetRepoSubscriptionRequestBuilder(ActivitySetRepoSubscriptionRequest);
+
+impl ActivitySetRepoSubscriptionRequestBuilder {
+    pub fn ignored(mut self, ignored: bool) -> Self {
+        self.0.ignored = Some(ignored);
+        self
+    }
+
+    pub fn subscribed(mut self, subscribed: bool) -> Self {
+        self.0.subscribed = Some(subscribed);
+        self
+    }
+
+    pub fn build(self) -> ActivitySetRepoSubscriptionRequest {
+        self.0
+    }
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Vec<Repository>`
+/// - `Vec<StarredRepository>`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActivityListReposStarredByUserResponseAnyOf {
+    #[serde(flatten)]
+    pub repository_vector: Vec<Repository>,
+    #[serde(flatten)]
+    pub starred_repository_vector: Vec<StarredRepository>,
+}
@@ -0,0 +1,1358 @@
+//! The data types of the `apps` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/**
+* The level of permission to grant the access token for viewing an organization's plan. Can be one of: `read`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum OrganizationPlan {
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for OrganizationPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            OrganizationPlan::Read => "read",
+            OrganizationPlan::Noop => "",
+            OrganizationPlan::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for OrganizationPlan {
+    fn default() -> OrganizationPlan {
+        OrganizationPlan::Noop
+    }
+}
+impl OrganizationPlan {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, OrganizationPlan::Noop)
+    }
+}
+
+/**
+* The level of permission to grant the access token to update GitHub Actions workflow files. Can be one of: `write`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum Workflows {
+    #[serde(rename = "write")]
+    Write,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Workflows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Workflows::Write => "write",
+            Workflows::Noop => "",
+            Workflows::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Workflows {
+    fn default() -> Workflows {
+        Workflows::Noop
+    }
+}
+impl Workflows {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, Workflows::Noop)
+    }
+}
+
+/// The permissions granted to the user-to-server access token.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppPermissions {
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub administration: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checks: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_references: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contents: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployments: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environments: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_administration: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_hooks: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_packages: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_plan: Option<OrganizationPlan>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_projects: Option<RepositoryProjects>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_secrets: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_self_hosted_runners: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_user_blocking: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pages: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_requests: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_hooks: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_projects: Option<RepositoryProjects>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning_alerts: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_events: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub single_file: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_discussions: Option<Pages>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vulnerability_alerts: Option<OrganizationPlan>,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflows: Option<Workflows>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `SimpleUser`
+/// - `Enterprise`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AccountAnyOf {
+    /**
+    * Simple User
+    */
+    #[serde(flatten)]
+    pub simple_user: SimpleUser,
+    /**
+    * An enterprise account
+    */
+    #[serde(flatten)]
+    pub enterprise: Enterprise,
+}
+
+/// Installation
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Installation {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub access_tokens_url: String,
+    #[serde()]
+    pub account: AccountAnyOf,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub app_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub app_slug: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contact_email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    /**
+    * Installation
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_multiple_single_files: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde()]
+    pub permissions: AppPermissions,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repositories_url: String,
+    /**
+    * Describe whether all repositories have been selected or there's a selection involved
+    */
+    #[serde(default, skip_serializing_if = "RepositorySelection::is_noop")]
+    pub repository_selection: RepositorySelection,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub single_file_name: String,
+    /**
+    * Installation
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub single_file_paths: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub suspended_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspended_by: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub target_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Authentication token for a GitHub App installed on a user or org.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InstallationToken {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub expires_at: String,
+    /**
+    * Authentication token for a GitHub App installed on a user or org.
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_multiple_single_files: bool,
+    /**
+    * Authentication token for a GitHub App installed on a user or org.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<AppPermissions>,
+    /**
+    * Authentication token for a GitHub App installed on a user or org.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<Repository>,
+    /**
+    * Authentication token for a GitHub App installed on a user or org.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_selection: Option<RepositorySelection>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub single_file: String,
+    /**
+    * Authentication token for a GitHub App installed on a user or org.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub single_file_paths: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct App {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Marketplace Listing Plan
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MarketplaceListingPlan {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub accounts_url: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub bullets: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_free_trial: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub monthly_price_in_cents: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub price_model: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub unit_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub yearly_price_in_cents: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MarketplacePendingChange {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub effective_date: Option<chrono::NaiveDate>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_installed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<MarketplaceListingPlan>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub unit_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MarketplacePurchase {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub billing_cycle: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub free_trial_ends_on: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_installed: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub next_billing_date: Option<chrono::NaiveDate>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub on_free_trial: bool,
+    /**
+    * Marketplace Listing Plan
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<MarketplaceListingPlan>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub unit_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub updated_at: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Marketplace Purchase
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MarketplacePurchaseData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marketplace_pending_change: Option<MarketplacePendingChange>,
+    #[serde()]
+    pub marketplace_purchase: MarketplacePurchase,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organization_billing_email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchRestrictionPolicyAppsOwner {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub followers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub following_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gists_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gravatar_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub hooks_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issues_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub members_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organizations_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub public_members_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub received_events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repos_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub site_admin: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub starred_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscriptions_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchRestrictionPolicyAppsPermissions {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contents: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issues: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub metadata: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub single_file: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Apps {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<BranchRestrictionPolicyAppsOwner>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<BranchRestrictionPolicyAppsPermissions>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub slug: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub updated_at: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Content Reference attachments allow you to provide context around URLs posted in comments
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentReferenceAttachment {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsCreateFromManifestResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pem: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub webhook_secret: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `GitHubApp`
+/// - `AppsCreateFromManifestResponse`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsCreateFromManifestResponseAllOf {
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(flatten)]
+    pub git_hub_app: GitHubApp,
+    #[serde(flatten)]
+    pub apps_create_from_manifest_response: AppsCreateFromManifestResponse,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsUpdateWebhookConfigAppRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+impl AppsUpdateWebhookConfigAppRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AppsUpdateWebhookConfigAppRequestBuilder {
+        AppsUpdateWebhookConfigAppRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AppsUpdateWebhookConfigAppRequest`.
+#[derive(Debug, Clone)]
+pub struct AppsUpdateWebhookConfigAppRequestBuilder(AppsUpdateWebhookConfigAppRequest);
+
+impl AppsUpdateWebhookConfigAppRequestBuilder {
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.0.content_type = content_type.into();
+        self
+    }
+
+    pub fn insecure_ssl(mut self, insecure_ssl: impl Into<WebhookConfigInsecureSslOneOf>) -> Self {
+        self.0.insecure_ssl = Some(insecure_ssl.into());
+        self
+    }
+
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.0.secret = secret.into();
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = url.into();
+        self
+    }
+
+    pub fn build(self) -> AppsUpdateWebhookConfigAppRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsCreateInstallationAccessTokenRequest {
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<AppPermissions>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<String>,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repository_ids: Vec<i64>,
+}
+
+impl AppsCreateInstallationAccessTokenRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AppsCreateInstallationAccessTokenRequestBuilder {
+        AppsCreateInstallationAccessTokenRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AppsCreateInstallationAccessTokenRequest`.
+#[derive(Debug, Clone)]
+pub struct AppsCreateInstallationAccessTokenRequestBuilder(
+    AppsCreateInstallationAccessTokenRequest,
+);
+
+impl AppsCreateInstallationAccessTokenRequestBuilder {
+    pub fn permissions(mut self, permissions: impl Into<AppPermissions>) -> Self {
+        self.0.permissions = Some(permissions.into());
+        self
+    }
+
+    pub fn repositories(mut self, repositories: Vec<String>) -> Self {
+        self.0.repositories = repositories;
+        self
+    }
+
+    pub fn repository_ids(mut self, repository_ids: Vec<i64>) -> Self {
+        self.0.repository_ids = repository_ids;
+        self
+    }
+
+    pub fn build(self) -> AppsCreateInstallationAccessTokenRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsCheckTokenRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub access_token: String,
+}
+
+impl std::convert::From<String> for AppsCheckTokenRequest {
+    fn from(access_token: String) -> Self {
+        AppsCheckTokenRequest { access_token }
+    }
+}
+
+impl std::convert::From<&str> for AppsCheckTokenRequest {
+    fn from(access_token: &str) -> Self {
+        AppsCheckTokenRequest {
+            access_token: access_token.to_string(),
+        }
+    }
+}
+
+impl AppsCheckTokenRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AppsCheckTokenRequestBuilder {
+        AppsCheckTokenRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AppsCheckTokenRequest`.
+#[derive(Debug, Clone)]
+pub struct AppsCheckTokenRequestBuilder(AppsCheckTokenRequest);
+
+impl AppsCheckTokenRequestBuilder {
+    pub fn access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.0.access_token = access_token.into();
+        self
+    }
+
+    pub fn build(self) -> AppsCheckTokenRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsScopeTokenRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub access_token: String,
+    /**
+    * The permissions granted to the user-to-server access token.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<AppPermissions>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<String>,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repository_ids: Vec<i64>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub target_id: i64,
+}
+
+impl AppsScopeTokenRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AppsScopeTokenRequestBuilder {
+        AppsScopeTokenRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AppsScopeTokenRequest`.
+#[derive(Debug, Clone)]
+pub struct AppsScopeTokenRequestBuilder(AppsScopeTokenRequest);
+
+impl AppsScopeTokenRequestBuilder {
+    pub fn access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.0.access_token = access_token.into();
+        self
+    }
+
+    pub fn permissions(mut self, permissions: impl Into<AppPermissions>) -> Self {
+        self.0.permissions = Some(permissions.into());
+        self
+    }
+
+    pub fn repositories(mut self, repositories: Vec<String>) -> Self {
+        self.0.repositories = repositories;
+        self
+    }
+
+    pub fn repository_ids(mut self, repository_ids: Vec<i64>) -> Self {
+        self.0.repository_ids = repository_ids;
+        self
+    }
+
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.0.target = target.into();
+        self
+    }
+
+    pub fn target_id(mut self, target_id: i64) -> Self {
+        self.0.target_id = target_id;
+        self
+    }
+
+    pub fn build(self) -> AppsScopeTokenRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsListInstallationReposResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_selection: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsListInstallationsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub installations: Vec<Installation>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
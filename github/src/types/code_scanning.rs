@@ -0,0 +1,961 @@
+//! The data types of the `code_scanning` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/**
+* State of a code scanning alert.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum CodeScanningAlertState {
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "dismissed")]
+    Dismissed,
+    #[serde(rename = "fixed")]
+    Fixed,
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for CodeScanningAlertState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            CodeScanningAlertState::Closed => "closed",
+            CodeScanningAlertState::Dismissed => "dismissed",
+            CodeScanningAlertState::Fixed => "fixed",
+            CodeScanningAlertState::Open => "open",
+            CodeScanningAlertState::Noop => "",
+            CodeScanningAlertState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for CodeScanningAlertState {
+    fn default() -> CodeScanningAlertState {
+        CodeScanningAlertState::Noop
+    }
+}
+impl CodeScanningAlertState {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, CodeScanningAlertState::Noop)
+    }
+}
+
+/**
+* **Required when the state is dismissed.** The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum CodeScanningAlertDismissedReason {
+    #[serde(rename = "false positive")]
+    FalsePositive,
+    #[serde(rename = "used in tests")]
+    UsedInTests,
+    #[serde(rename = "won't fix")]
+    WonTFix,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for CodeScanningAlertDismissedReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            CodeScanningAlertDismissedReason::FalsePositive => "false positive",
+            CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
+            CodeScanningAlertDismissedReason::WonTFix => "won't fix",
+            CodeScanningAlertDismissedReason::Noop => "",
+            CodeScanningAlertDismissedReason::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for CodeScanningAlertDismissedReason {
+    fn default() -> CodeScanningAlertDismissedReason {
+        CodeScanningAlertDismissedReason::Noop
+    }
+}
+impl CodeScanningAlertDismissedReason {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, CodeScanningAlertDismissedReason::Noop)
+    }
+}
+
+/**
+* The severity of the alert.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum Severity {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "note")]
+    Note,
+    #[serde(rename = "warning")]
+    Warning,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Severity::Error => "error",
+            Severity::None => "none",
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Noop => "",
+            Severity::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Severity {
+    fn default() -> Severity {
+        Severity::Noop
+    }
+}
+impl Severity {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, Severity::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertRuleSummary {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * The severity of the alert.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAnalysisTool {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub guid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Describe a region within a file for the alert.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertLocation {
+    /**
+    * Describe a region within a file for the alert.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub end_column: i64,
+    /**
+    * Describe a region within a file for the alert.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub end_line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    /**
+    * Describe a region within a file for the alert.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub start_column: i64,
+    /**
+    * Describe a region within a file for the alert.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub start_line: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* A classification of the file. For example to identify it as generated.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum CodeScanningAlertClassification {
+    #[serde(rename = "generated")]
+    Generated,
+    #[serde(rename = "library")]
+    Library,
+    #[serde(rename = "source")]
+    Source,
+    #[serde(rename = "test")]
+    Test,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for CodeScanningAlertClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            CodeScanningAlertClassification::Generated => "generated",
+            CodeScanningAlertClassification::Library => "library",
+            CodeScanningAlertClassification::Source => "source",
+            CodeScanningAlertClassification::Test => "test",
+            CodeScanningAlertClassification::Noop => "",
+            CodeScanningAlertClassification::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for CodeScanningAlertClassification {
+    fn default() -> CodeScanningAlertClassification {
+        CodeScanningAlertClassification::Noop
+    }
+}
+impl CodeScanningAlertClassification {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, CodeScanningAlertClassification::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertInstance {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub analysis_key: String,
+    /**
+    * Classifications that have been applied to the file that triggered the alert.
+    *  For example identifying it as documentation, or a generated file.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub classifications: Vec<CodeScanningAlertClassification>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    /**
+    * Describe a region within a file for the alert.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<CodeScanningAlertLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    /**
+    * State of a code scanning alert.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<CodeScanningAlertState>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertItems {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub dismissed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_by: Option<SimpleUser>,
+    /**
+    * \*\*Required when the state is dismissed.\*\* The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instances_url: String,
+    #[serde()]
+    pub most_recent_instance: CodeScanningAlertInstance,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde()]
+    pub rule: CodeScanningAlertRuleSummary,
+    /**
+    * State of a code scanning alert.
+    */
+    #[serde()]
+    pub state: CodeScanningAlertState,
+    #[serde()]
+    pub tool: CodeScanningAnalysisTool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* The security severity of the alert.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum SecuritySeverityLevel {
+    #[serde(rename = "critical")]
+    Critical,
+    #[serde(rename = "high")]
+    High,
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "medium")]
+    Medium,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for SecuritySeverityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            SecuritySeverityLevel::Critical => "critical",
+            SecuritySeverityLevel::High => "high",
+            SecuritySeverityLevel::Low => "low",
+            SecuritySeverityLevel::Medium => "medium",
+            SecuritySeverityLevel::Noop => "",
+            SecuritySeverityLevel::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for SecuritySeverityLevel {
+    fn default() -> SecuritySeverityLevel {
+        SecuritySeverityLevel::Noop
+    }
+}
+impl SecuritySeverityLevel {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, SecuritySeverityLevel::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertRule {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub full_description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub help: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * The security severity of the alert.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_severity_level: Option<SecuritySeverityLevel>,
+    /**
+    * The severity of the alert.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tags: Vec<String>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlert {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub dismissed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_by: Option<SimpleUser>,
+    /**
+    * \*\*Required when the state is dismissed.\*\* The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instances: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instances_url: String,
+    #[serde()]
+    pub most_recent_instance: CodeScanningAlertInstance,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde()]
+    pub rule: CodeScanningAlertRule,
+    /**
+    * State of a code scanning alert.
+    */
+    #[serde()]
+    pub state: CodeScanningAlertState,
+    #[serde()]
+    pub tool: CodeScanningAnalysisTool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum CodeScanningAlertSetState {
+    #[serde(rename = "dismissed")]
+    Dismissed,
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for CodeScanningAlertSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            CodeScanningAlertSetState::Dismissed => "dismissed",
+            CodeScanningAlertSetState::Open => "open",
+            CodeScanningAlertSetState::Noop => "",
+            CodeScanningAlertSetState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for CodeScanningAlertSetState {
+    fn default() -> CodeScanningAlertSetState {
+        CodeScanningAlertSetState::Noop
+    }
+}
+impl CodeScanningAlertSetState {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, CodeScanningAlertSetState::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAnalysis {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub analysis_key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub category: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub deletable: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub error: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub results_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub rules_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sarif_id: String,
+    #[serde()]
+    pub tool: CodeScanningAnalysisTool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tool_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub warning: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Successful deletion of a code scanning analysis
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AnalysisDeletion {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub confirm_delete_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_analysis_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningSarifsReceipt {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* `pending` files have not yet been processed, while `complete` means all results in the SARIF have been stored.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ProcessingStatus {
+    #[serde(rename = "complete")]
+    Complete,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ProcessingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ProcessingStatus::Complete => "complete",
+            ProcessingStatus::Pending => "pending",
+            ProcessingStatus::Noop => "",
+            ProcessingStatus::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ProcessingStatus {
+    fn default() -> ProcessingStatus {
+        ProcessingStatus::Noop
+    }
+}
+impl ProcessingStatus {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ProcessingStatus::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningSarifsStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub analyses_url: String,
+    /**
+    * `pending` files have not yet been processed, while `complete` means all results in the SARIF have been stored.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processing_status: Option<ProcessingStatus>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningUpdateAlertRequest {
+    /**
+    * \*\*Required when the state is dismissed.\*\* The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
+    /**
+    * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
+    */
+    #[serde()]
+    pub state: CodeScanningAlertSetState,
+}
+
+impl CodeScanningUpdateAlertRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CodeScanningUpdateAlertRequestBuilder {
+        CodeScanningUpdateAlertRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CodeScanningUpdateAlertRequest`.
+#[derive(Debug, Clone)]
+pub struct CodeScanningUpdateAlertRequestBuilder(CodeScanningUpdateAlertRequest);
+
+impl CodeScanningUpdateAlertRequestBuilder {
+    pub fn dismissed_reason(
+        mut self,
+        dismissed_reason: impl Into<CodeScanningAlertDismissedReason>,
+    ) -> Self {
+        self.0.dismissed_reason = Some(dismissed_reason.into());
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<CodeScanningAlertSetState>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn build(self) -> CodeScanningUpdateAlertRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningUploadSarifRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub checkout_uri: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sarif: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tool_name: String,
+}
+
+impl CodeScanningUploadSarifRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CodeScanningUploadSarifRequestBuilder {
+        CodeScanningUploadSarifRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CodeScanningUploadSarifRequest`.
+#[derive(Debug, Clone)]
+pub struct CodeScanningUploadSarifRequestBuilder(CodeScanningUploadSarifRequest);
+
+impl CodeScanningUploadSarifRequestBuilder {
+    pub fn checkout_uri(mut self, checkout_uri: impl Into<String>) -> Self {
+        self.0.checkout_uri = checkout_uri.into();
+        self
+    }
+
+    pub fn commit_sha(mut self, commit_sha: impl Into<String>) -> Self {
+        self.0.commit_sha = commit_sha.into();
+        self
+    }
+
+    pub fn ref_(mut self, ref_: impl Into<String>) -> Self {
+        self.0.ref_ = ref_.into();
+        self
+    }
+
+    pub fn sarif(mut self, sarif: impl Into<String>) -> Self {
+        self.0.sarif = sarif.into();
+        self
+    }
+
+    pub fn started_at(mut self, started_at: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.0.started_at = Some(started_at.into());
+        self
+    }
+
+    pub fn tool_name(mut self, tool_name: impl Into<String>) -> Self {
+        self.0.tool_name = tool_name.into();
+        self
+    }
+
+    pub fn build(self) -> CodeScanningUploadSarifRequest {
+        self.0
+    }
+}
@@ -0,0 +1,652 @@
+//! The data types of the `enterprise_admin` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RunnerGroupsEnterprise {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allows_public_repositories: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub runners_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_organizations_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub visibility: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetGithubActionsPermissionsRequest {
+    /**
+    * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /**
+    * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+    */
+    #[serde()]
+    pub enabled_organizations: EnabledRepositories,
+}
+
+impl EnterpriseAdminSetGithubActionsPermissionsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminSetGithubActionsPermissionsRequestBuilder {
+        EnterpriseAdminSetGithubActionsPermissionsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminSetGithubActionsPermissionsRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminSetGithubActionsPermissionsRequestBuilder(
+    EnterpriseAdminSetGithubActionsPermissionsRequest,
+);
+
+impl EnterpriseAdminSetGithubActionsPermissionsRequestBuilder {
+    pub fn allowed_actions(mut self, allowed_actions: impl Into<AllowedActions>) -> Self {
+        self.0.allowed_actions = Some(allowed_actions.into());
+        self
+    }
+
+    pub fn enabled_organizations(
+        mut self,
+        enabled_organizations: impl Into<EnabledRepositories>,
+    ) -> Self {
+        self.0.enabled_organizations = enabled_organizations.into();
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminSetGithubActionsPermissionsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub organizations: Vec<OrganizationSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest {
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_organization_ids: Vec<i64>,
+}
+
+impl EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequestBuilder {
+        EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequestBuilder(
+    EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+);
+
+impl EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequestBuilder {
+    pub fn selected_organization_ids(mut self, selected_organization_ids: Vec<i64>) -> Self {
+        self.0.selected_organization_ids = selected_organization_ids;
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListSelfHostedRunnerGroupsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runner_groups: Vec<RunnerGroupsEnterprise>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<i64>,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_organization_ids: Vec<i64>,
+    /**
+    * Describe whether all repositories have been selected or there's a selection involved
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<RepositorySelection>,
+}
+
+impl EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminCreateSelfHostedRunnerGroupRequestBuilder {
+        EnterpriseAdminCreateSelfHostedRunnerGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminCreateSelfHostedRunnerGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequestBuilder(
+    EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
+);
+
+impl EnterpriseAdminCreateSelfHostedRunnerGroupRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn runners(mut self, runners: Vec<i64>) -> Self {
+        self.0.runners = runners;
+        self
+    }
+
+    pub fn selected_organization_ids(mut self, selected_organization_ids: Vec<i64>) -> Self {
+        self.0.selected_organization_ids = selected_organization_ids;
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<RepositorySelection>) -> Self {
+        self.0.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateSelfHostedRunnerGroupRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * Describe whether all repositories have been selected or there's a selection involved
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<RepositorySelection>,
+}
+
+impl EnterpriseAdminUpdateSelfHostedRunnerGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminUpdateSelfHostedRunnerGroupRequestBuilder {
+        EnterpriseAdminUpdateSelfHostedRunnerGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminUpdateSelfHostedRunnerGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminUpdateSelfHostedRunnerGroupRequestBuilder(
+    EnterpriseAdminUpdateSelfHostedRunnerGroupRequest,
+);
+
+impl EnterpriseAdminUpdateSelfHostedRunnerGroupRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<RepositorySelection>) -> Self {
+        self.0.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminUpdateSelfHostedRunnerGroupRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListSelfHostedRunnersResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<Runner>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminProvisionInviteGroupRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub members: Vec<ScimUserListEnterpriseResourcesGroups>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+impl EnterpriseAdminProvisionInviteGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminProvisionInviteGroupRequestBuilder {
+        EnterpriseAdminProvisionInviteGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminProvisionInviteGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminProvisionInviteGroupRequestBuilder(
+    EnterpriseAdminProvisionInviteGroupRequest,
+);
+
+impl EnterpriseAdminProvisionInviteGroupRequestBuilder {
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn members(mut self, members: Vec<ScimUserListEnterpriseResourcesGroups>) -> Self {
+        self.0.members = members;
+        self
+    }
+
+    pub fn schemas(mut self, schemas: Vec<String>) -> Self {
+        self.0.schemas = schemas;
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminProvisionInviteGroupRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    #[serde(rename = "Add")]
+    Add,
+    #[serde(rename = "Remove")]
+    Remove,
+    #[serde(rename = "Replace")]
+    Replace,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add => "Add",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove => "Remove",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace => "Replace",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop => "",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn default() -> EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+        EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop
+    }
+}
+impl EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    pub fn is_noop(&self) -> bool {
+        matches!(
+            self,
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateAttributeGroupRequestOperations {
+    #[serde(
+        default,
+        skip_serializing_if = "EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::is_noop"
+    )]
+    pub op: EnterpriseAdminUpdateAttributeGroupRequestOperationsOp,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<ScimUserOperationsValueOneOf>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateAttributeGroupRequest {
+    /**
+    * Array of [SCIM operations](https://tools.ietf.org/html/rfc7644#section-3.5.2).
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Operations"
+    )]
+    pub operations: Vec<EnterpriseAdminUpdateAttributeGroupRequestOperations>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+impl EnterpriseAdminUpdateAttributeGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminUpdateAttributeGroupRequestBuilder {
+        EnterpriseAdminUpdateAttributeGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminUpdateAttributeGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminUpdateAttributeGroupRequestBuilder(
+    EnterpriseAdminUpdateAttributeGroupRequest,
+);
+
+impl EnterpriseAdminUpdateAttributeGroupRequestBuilder {
+    pub fn operations(
+        mut self,
+        operations: Vec<EnterpriseAdminUpdateAttributeGroupRequestOperations>,
+    ) -> Self {
+        self.0.operations = operations;
+        self
+    }
+
+    pub fn schemas(mut self, schemas: Vec<String>) -> Self {
+        self.0.schemas = schemas;
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminUpdateAttributeGroupRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminProvisionInviteUserRequestEmails {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub primary: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminProvisionInviteUserRequest {
+    /**
+    * List of user emails.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<EnterpriseAdminProvisionInviteUserRequestEmails>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<ScimUserListEnterpriseResourcesGroups>,
+    #[serde()]
+    pub name: Name,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userName"
+    )]
+    pub user_name: String,
+}
+
+impl EnterpriseAdminProvisionInviteUserRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminProvisionInviteUserRequestBuilder {
+        EnterpriseAdminProvisionInviteUserRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminProvisionInviteUserRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminProvisionInviteUserRequestBuilder(
+    EnterpriseAdminProvisionInviteUserRequest,
+);
+
+impl EnterpriseAdminProvisionInviteUserRequestBuilder {
+    pub fn emails(mut self, emails: Vec<EnterpriseAdminProvisionInviteUserRequestEmails>) -> Self {
+        self.0.emails = emails;
+        self
+    }
+
+    pub fn groups(mut self, groups: Vec<ScimUserListEnterpriseResourcesGroups>) -> Self {
+        self.0.groups = groups;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<Name>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn schemas(mut self, schemas: Vec<String>) -> Self {
+        self.0.schemas = schemas;
+        self
+    }
+
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.0.user_name = user_name.into();
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminProvisionInviteUserRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateAttributeUserRequest {
+    /**
+    * Array of [SCIM operations](https://tools.ietf.org/html/rfc7644#section-3.5.2).
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Operations"
+    )]
+    pub operations: Vec<Data>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+impl EnterpriseAdminUpdateAttributeUserRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EnterpriseAdminUpdateAttributeUserRequestBuilder {
+        EnterpriseAdminUpdateAttributeUserRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EnterpriseAdminUpdateAttributeUserRequest`.
+#[derive(Debug, Clone)]
+pub struct EnterpriseAdminUpdateAttributeUserRequestBuilder(
+    EnterpriseAdminUpdateAttributeUserRequest,
+);
+
+impl EnterpriseAdminUpdateAttributeUserRequestBuilder {
+    pub fn operations(mut self, operations: Vec<Data>) -> Self {
+        self.0.operations = operations;
+        self
+    }
+
+    pub fn schemas(mut self, schemas: Vec<String>) -> Self {
+        self.0.schemas = schemas;
+        self
+    }
+
+    pub fn build(self) -> EnterpriseAdminUpdateAttributeUserRequest {
+        self.0
+    }
+}
@@ -0,0 +1,847 @@
+//! The data types of the `git` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Blob
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Blob {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub encoding: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub highlighted_content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Low-level Git commit operations within a repository
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCommit {
+    /**
+    * Identifying information for the git-user
+    */
+    #[serde()]
+    pub author: Committer,
+    /**
+    * Identifying information for the git-user
+    */
+    #[serde()]
+    pub committer: Committer,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub parents: Vec<Parents>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde()]
+    pub tree: Tree,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde()]
+    pub verification: Verification,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Git references within a repository
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitRef {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde()]
+    pub object: Object,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Metadata for a Git tag
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitTag {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde()]
+    pub object: Object,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tag: String,
+    #[serde()]
+    pub tagger: Tagger,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * Metadata for a Git tag
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitTree {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub mode: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// The hierarchy between files in a Git repository.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitTreeData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    /**
+    * Objects specifying a tree structure
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tree: Vec<GitTree>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub truncated: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateBlobRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub encoding: String,
+}
+
+impl GitCreateBlobRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GitCreateBlobRequestBuilder {
+        GitCreateBlobRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GitCreateBlobRequest`.
+#[derive(Debug, Clone)]
+pub struct GitCreateBlobRequestBuilder(GitCreateBlobRequest);
+
+impl GitCreateBlobRequestBuilder {
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+
+    pub fn encoding(mut self, encoding: impl Into<String>) -> Self {
+        self.0.encoding = encoding.into();
+        self
+    }
+
+    pub fn build(self) -> GitCreateBlobRequest {
+        self.0
+    }
+}
+
+/// Information about the author of the commit. By default, the `author` will be the authenticated user and the current date. See the `author` and `committer` object below for details.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateCommitRequestAuthor {
+    /**
+    * Information about the author of the commit. By default, the `author` will be the authenticated user and the current date. See the `author` and `committer` object below for details.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub date: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// Information about the person who is making the commit. By default, `committer` will use the information set in `author`. See the `author` and `committer` object below for details.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateCommitRequestCommitter {
+    /**
+    * Information about the person who is making the commit. By default, `committer` will use the information set in `author`. See the `author` and `committer` object below for details.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub date: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateCommitRequest {
+    /**
+    * Information about the author of the commit. By default, the `author` will be the authenticated user and the current date. See the `author` and `committer` object below for details.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<GitCreateCommitRequestAuthor>,
+    /**
+    * Information about the person who is making the commit. By default, `committer` will use the information set in `author`. See the `author` and `committer` object below for details.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<GitCreateCommitRequestCommitter>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub parents: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub signature: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tree: String,
+}
+
+impl GitCreateCommitRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GitCreateCommitRequestBuilder {
+        GitCreateCommitRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GitCreateCommitRequest`.
+#[derive(Debug, Clone)]
+pub struct GitCreateCommitRequestBuilder(GitCreateCommitRequest);
+
+impl GitCreateCommitRequestBuilder {
+    pub fn author(mut self, author: impl Into<GitCreateCommitRequestAuthor>) -> Self {
+        self.0.author = Some(author.into());
+        self
+    }
+
+    pub fn committer(mut self, committer: impl Into<GitCreateCommitRequestCommitter>) -> Self {
+        self.0.committer = Some(committer.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn parents(mut self, parents: Vec<String>) -> Self {
+        self.0.parents = parents;
+        self
+    }
+
+    pub fn signature(mut self, signature: impl Into<String>) -> Self {
+        self.0.signature = signature.into();
+        self
+    }
+
+    pub fn tree(mut self, tree: impl Into<String>) -> Self {
+        self.0.tree = tree.into();
+        self
+    }
+
+    pub fn build(self) -> GitCreateCommitRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateRefRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+}
+
+impl GitCreateRefRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GitCreateRefRequestBuilder {
+        GitCreateRefRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GitCreateRefRequest`.
+#[derive(Debug, Clone)]
+pub struct GitCreateRefRequestBuilder(GitCreateRefRequest);
+
+impl GitCreateRefRequestBuilder {
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.0.key = key.into();
+        self
+    }
+
+    pub fn ref_(mut self, ref_: impl Into<String>) -> Self {
+        self.0.ref_ = ref_.into();
+        self
+    }
+
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.0.sha = sha.into();
+        self
+    }
+
+    pub fn build(self) -> GitCreateRefRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitUpdateRefRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+}
+
+impl GitUpdateRefRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GitUpdateRefRequestBuilder {
+        GitUpdateRefRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GitUpdateRefRequest`.
+#[derive(Debug, Clone)]
+pub struct GitUpdateRefRequestBuilder(GitUpdateRefRequest);
+
+impl GitUpdateRefRequestBuilder {
+    pub fn force(mut self, force: bool) -> Self {
+        self.0.force = Some(force);
+        self
+    }
+
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.0.sha = sha.into();
+        self
+    }
+
+    pub fn build(self) -> GitUpdateRefRequest {
+        self.0
+    }
+}
+
+/**
+* The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum GitCreateTagRequestType {
+    #[serde(rename = "blob")]
+    Blob,
+    #[serde(rename = "commit")]
+    Commit,
+    #[serde(rename = "tree")]
+    Tree,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for GitCreateTagRequestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            GitCreateTagRequestType::Blob => "blob",
+            GitCreateTagRequestType::Commit => "commit",
+            GitCreateTagRequestType::Tree => "tree",
+            GitCreateTagRequestType::Noop => "",
+            GitCreateTagRequestType::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for GitCreateTagRequestType {
+    fn default() -> GitCreateTagRequestType {
+        GitCreateTagRequestType::Noop
+    }
+}
+impl GitCreateTagRequestType {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, GitCreateTagRequestType::Noop)
+    }
+}
+
+/// An object with information about the individual creating the tag.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateTagRequestTagger {
+    /**
+    * An object with information about the individual creating the tag.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub date: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateTagRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub object: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tag: String,
+    /**
+    * An object with information about the individual creating the tag.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tagger: Option<GitCreateTagRequestTagger>,
+    /**
+    * The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "GitCreateTagRequestType::is_noop",
+        rename = "type"
+    )]
+    pub type_: GitCreateTagRequestType,
+}
+
+impl GitCreateTagRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GitCreateTagRequestBuilder {
+        GitCreateTagRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GitCreateTagRequest`.
+#[derive(Debug, Clone)]
+pub struct GitCreateTagRequestBuilder(GitCreateTagRequest);
+
+impl GitCreateTagRequestBuilder {
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn object(mut self, object: impl Into<String>) -> Self {
+        self.0.object = object.into();
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.0.tag = tag.into();
+        self
+    }
+
+    pub fn tagger(mut self, tagger: impl Into<GitCreateTagRequestTagger>) -> Self {
+        self.0.tagger = Some(tagger.into());
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<GitCreateTagRequestType>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> GitCreateTagRequest {
+        self.0
+    }
+}
+
+/**
+* The file mode; one of `100644` for file (blob), `100755` for executable (blob), `040000` for subdirectory (tree), `160000` for submodule (commit), or `120000` for a blob that specifies the path of a symlink.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum GitCreateTreeRequestMode {
+    #[serde(rename = "040000")]
+    SubdirectoryTree,
+    #[serde(rename = "100644")]
+    FileBlob,
+    #[serde(rename = "100755")]
+    ExecutableBlob,
+    #[serde(rename = "120000")]
+    SymlinkPathBlob,
+    #[serde(rename = "160000")]
+    SubmoduleCommit,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for GitCreateTreeRequestMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            GitCreateTreeRequestMode::SubdirectoryTree => "040000",
+            GitCreateTreeRequestMode::FileBlob => "100644",
+            GitCreateTreeRequestMode::ExecutableBlob => "100755",
+            GitCreateTreeRequestMode::SymlinkPathBlob => "120000",
+            GitCreateTreeRequestMode::SubmoduleCommit => "160000",
+            GitCreateTreeRequestMode::Noop => "",
+            GitCreateTreeRequestMode::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for GitCreateTreeRequestMode {
+    fn default() -> GitCreateTreeRequestMode {
+        GitCreateTreeRequestMode::Noop
+    }
+}
+impl GitCreateTreeRequestMode {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, GitCreateTreeRequestMode::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateTreeRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    /**
+    * The file mode; one of `100644` for file (blob), `100755` for executable (blob), `040000` for subdirectory (tree), `160000` for submodule (commit), or `120000` for a blob that specifies the path of a symlink.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<GitCreateTreeRequestMode>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    /**
+    * The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<GitCreateTagRequestType>,
+}
+
+impl GitCreateTreeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GitCreateTreeRequestBuilder {
+        GitCreateTreeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GitCreateTreeRequest`.
+#[derive(Debug, Clone)]
+pub struct GitCreateTreeRequestBuilder(GitCreateTreeRequest);
+
+impl GitCreateTreeRequestBuilder {
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<GitCreateTreeRequestMode>) -> Self {
+        self.0.mode = Some(mode.into());
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.0.path = path.into();
+        self
+    }
+
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.0.sha = sha.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<GitCreateTagRequestType>) -> Self {
+        self.0.type_ = Some(type_.into());
+        self
+    }
+
+    pub fn build(self) -> GitCreateTreeRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GitCreateTreeRequestData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub base_tree: String,
+    /**
+    * Objects (of `path`, `mode`, `type`, and `sha`) specifying a tree structure.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tree: Vec<GitCreateTreeRequest>,
+}
@@ -0,0 +1,156 @@
+//! The data types of the `interactions` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/**
+* The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum InteractionGroup {
+    #[serde(rename = "collaborators_only")]
+    CollaboratorsOnly,
+    #[serde(rename = "contributors_only")]
+    ContributorsOnly,
+    #[serde(rename = "existing_users")]
+    ExistingUsers,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for InteractionGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            InteractionGroup::CollaboratorsOnly => "collaborators_only",
+            InteractionGroup::ContributorsOnly => "contributors_only",
+            InteractionGroup::ExistingUsers => "existing_users",
+            InteractionGroup::Noop => "",
+            InteractionGroup::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for InteractionGroup {
+    fn default() -> InteractionGroup {
+        InteractionGroup::Noop
+    }
+}
+impl InteractionGroup {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, InteractionGroup::Noop)
+    }
+}
+
+/// Interaction limit settings.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionLimits {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
+    */
+    #[serde()]
+    pub limit: InteractionGroup,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub origin: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* The duration of the interaction restriction. Can be one of: `one_day`, `three_days`, `one_week`, `one_month`, `six_months`. Default: `one_day`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum InteractionExpiry {
+    #[serde(rename = "one_day")]
+    OneDay,
+    #[serde(rename = "one_month")]
+    OneMonth,
+    #[serde(rename = "one_week")]
+    OneWeek,
+    #[serde(rename = "six_months")]
+    SixMonths,
+    #[serde(rename = "three_days")]
+    ThreeDays,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for InteractionExpiry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            InteractionExpiry::OneDay => "one_day",
+            InteractionExpiry::OneMonth => "one_month",
+            InteractionExpiry::OneWeek => "one_week",
+            InteractionExpiry::SixMonths => "six_months",
+            InteractionExpiry::ThreeDays => "three_days",
+            InteractionExpiry::Noop => "",
+            InteractionExpiry::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for InteractionExpiry {
+    fn default() -> InteractionExpiry {
+        InteractionExpiry::Noop
+    }
+}
+impl InteractionExpiry {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, InteractionExpiry::Noop)
+    }
+}
+
+/// Limit interactions to a specific type of user for a specified duration
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionLimit {
+    /**
+    * Limit interactions to a specific type of user for a specified duration
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<InteractionExpiry>,
+    /**
+    * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
+    */
+    #[serde()]
+    pub limit: InteractionGroup,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Data`
+/// - `InteractionLimits`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionsGetRestrictionsResponseAnyOf {
+    #[serde(flatten)]
+    pub data: Data,
+    /**
+    * Interaction limit settings.
+    */
+    #[serde(flatten)]
+    pub interaction_limits: InteractionLimits,
+}
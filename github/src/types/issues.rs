@@ -0,0 +1,1614 @@
+//! The data types of the `issues` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Issue Event Label
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueEventLabel {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub color: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DismissedReview {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dismissal_commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dismissal_message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub review_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Issue Event Milestone
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueEventMilestone {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Issue Event Project Card
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueEventProjectCard {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub column_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub previous_column_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub project_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub project_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigner: Option<SimpleUser>,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_association: Option<AuthorAssociation>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_review: Option<DismissedReview>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueSimple>,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<IssueEventLabel>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub lock_reason: String,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<IssueEventMilestone>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<IssueEventProjectCard>,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rename: Option<Rename>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_reviewer: Option<SimpleUser>,
+    /**
+    * Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_team: Option<Team>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_requester: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Labeled Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LabeledIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * Issue Event Label
+    */
+    #[serde()]
+    pub label: IssueEventLabel,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Assigned Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AssignedIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<SimpleUser>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigner: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Milestoned Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MilestonedIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * Issue Event Milestone
+    */
+    #[serde()]
+    pub milestone: IssueEventMilestone,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Renamed Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RenamedIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    #[serde()]
+    pub rename: Rename,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Review Requested Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewRequestedIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_reviewer: Option<SimpleUser>,
+    /**
+    * Review Requested Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_team: Option<Team>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_requester: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Review Request Removed Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewRequestRemovedIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_reviewer: Option<SimpleUser>,
+    /**
+    * Review Request Removed Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_team: Option<Team>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_requester: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Review Dismissed Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewDismissedIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde()]
+    pub dismissed_review: DismissedReview,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Locked Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LockedIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub lock_reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Added to Project Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AddedProjectIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    /**
+    * Added to Project Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<IssueEventProjectCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Moved Column in Project Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MovedColumnInProjectIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    /**
+    * Moved Column in Project Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<IssueEventProjectCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Removed from Project Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RemovedFromProjectIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    /**
+    * Removed from Project Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<IssueEventProjectCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Converted Note to Issue Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConvertedNoteIssueEvent {
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    /**
+    * Converted Note to Issue Issue Event
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<IssueEventProjectCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `LabeledIssueEvent`
+/// - `LabeledIssueEvent`
+/// - `AssignedIssueEvent`
+/// - `AssignedIssueEvent`
+/// - `MilestonedIssueEvent`
+/// - `MilestonedIssueEvent`
+/// - `RenamedIssueEvent`
+/// - `ReviewRequestedIssueEvent`
+/// - `ReviewRequestRemovedIssueEvent`
+/// - `ReviewDismissedIssueEvent`
+/// - `LockedIssueEvent`
+/// - `AddedProjectIssueEvent`
+/// - `MovedColumnInProjectIssueEvent`
+/// - `RemovedFromProjectIssueEvent`
+/// - `ConvertedNoteIssueEvent`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueEventAnyOf {
+    /**
+    * Labeled Issue Event
+    */
+    #[serde(flatten)]
+    pub labeled_issue_event: LabeledIssueEvent,
+    /**
+    * Assigned Issue Event
+    */
+    #[serde(flatten)]
+    pub assigned_issue_event: AssignedIssueEvent,
+    /**
+    * Milestoned Issue Event
+    */
+    #[serde(flatten)]
+    pub milestoned_issue_event: MilestonedIssueEvent,
+    /**
+    * Renamed Issue Event
+    */
+    #[serde(flatten)]
+    pub renamed_issue_event: RenamedIssueEvent,
+    /**
+    * Review Requested Issue Event
+    */
+    #[serde(flatten)]
+    pub review_requested_issue_event: ReviewRequestedIssueEvent,
+    /**
+    * Review Request Removed Issue Event
+    */
+    #[serde(flatten)]
+    pub review_request_removed_issue_event: ReviewRequestRemovedIssueEvent,
+    /**
+    * Review Dismissed Issue Event
+    */
+    #[serde(flatten)]
+    pub review_dismissed_issue_event: ReviewDismissedIssueEvent,
+    /**
+    * Locked Issue Event
+    */
+    #[serde(flatten)]
+    pub locked_issue_event: LockedIssueEvent,
+    /**
+    * Added to Project Issue Event
+    */
+    #[serde(flatten)]
+    pub added_project_issue_event: AddedProjectIssueEvent,
+    /**
+    * Moved Column in Project Issue Event
+    */
+    #[serde(flatten)]
+    pub moved_column_in_project_issue_event: MovedColumnInProjectIssueEvent,
+    /**
+    * Removed from Project Issue Event
+    */
+    #[serde(flatten)]
+    pub removed_from_project_issue_event: RemovedFromProjectIssueEvent,
+    /**
+    * Converted Note to Issue Issue Event
+    */
+    #[serde(flatten)]
+    pub converted_note_issue_event: ConvertedNoteIssueEvent,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesUpdateRequest {
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub assignee: crate::utils::Nullable<String>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignees: Vec<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub body: crate::utils::Nullable<String>,
+    /**
+    * Labels to associate with this issue. _NOTE: Only users with push access can set labels for new issues. Labels are silently dropped otherwise._
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<IssuesCreateRequestLabelsOneOf>,
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub milestone: crate::utils::Nullable<TitleOneOf>,
+    /**
+    * The state of the milestone.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub title: crate::utils::Nullable<TitleOneOf>,
+}
+
+impl IssuesUpdateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuesUpdateRequestBuilder {
+        IssuesUpdateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuesUpdateRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuesUpdateRequestBuilder(IssuesUpdateRequest);
+
+impl IssuesUpdateRequestBuilder {
+    pub fn assignee(mut self, assignee: impl Into<crate::utils::Nullable<String>>) -> Self {
+        self.0.assignee = assignee.into();
+        self
+    }
+
+    pub fn assignees(mut self, assignees: Vec<String>) -> Self {
+        self.0.assignees = assignees;
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<crate::utils::Nullable<String>>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn labels(mut self, labels: Vec<IssuesCreateRequestLabelsOneOf>) -> Self {
+        self.0.labels = labels;
+        self
+    }
+
+    pub fn milestone(mut self, milestone: impl Into<crate::utils::Nullable<TitleOneOf>>) -> Self {
+        self.0.milestone = milestone.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<State>) -> Self {
+        self.0.state = Some(state.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<crate::utils::Nullable<TitleOneOf>>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn build(self) -> IssuesUpdateRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesAddAssigneesRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignees: Vec<String>,
+}
+
+impl IssuesAddAssigneesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuesAddAssigneesRequestBuilder {
+        IssuesAddAssigneesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuesAddAssigneesRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuesAddAssigneesRequestBuilder(IssuesAddAssigneesRequest);
+
+impl IssuesAddAssigneesRequestBuilder {
+    pub fn assignees(mut self, assignees: Vec<String>) -> Self {
+        self.0.assignees = assignees;
+        self
+    }
+
+    pub fn build(self) -> IssuesAddAssigneesRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesAddLabelsRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<String>,
+}
+
+impl IssuesAddLabelsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuesAddLabelsRequestBuilder {
+        IssuesAddLabelsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuesAddLabelsRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuesAddLabelsRequestBuilder(IssuesAddLabelsRequest);
+
+impl IssuesAddLabelsRequestBuilder {
+    pub fn labels(mut self, labels: Vec<String>) -> Self {
+        self.0.labels = labels;
+        self
+    }
+
+    pub fn build(self) -> IssuesAddLabelsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesSetLabelsRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<ProjectsUpdateColumnRequest>,
+}
+
+impl IssuesSetLabelsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuesSetLabelsRequestBuilder {
+        IssuesSetLabelsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuesSetLabelsRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuesSetLabelsRequestBuilder(IssuesSetLabelsRequest);
+
+impl IssuesSetLabelsRequestBuilder {
+    pub fn labels(mut self, labels: Vec<ProjectsUpdateColumnRequest>) -> Self {
+        self.0.labels = labels;
+        self
+    }
+
+    pub fn build(self) -> IssuesSetLabelsRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `Vec<String>`
+/// - `IssuesAddLabelsRequest`
+/// - `Vec<ProjectsUpdateColumnRequest>`
+/// - `IssuesSetLabelsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum IssuesAddLabelsRequestOneOf {
+    String(String),
+    /**
+    * The list of events for the GitHub app
+    */
+    StringVector(Vec<String>),
+    IssuesAddLabelsRequest(IssuesAddLabelsRequest),
+    ProjectsUpdateColumnRequestVector(Vec<ProjectsUpdateColumnRequest>),
+    IssuesSetLabelsRequest(IssuesSetLabelsRequest),
+}
+
+impl IssuesAddLabelsRequestOneOf {
+    pub fn issues_add_labels_request(&self) -> Option<&IssuesAddLabelsRequest> {
+        if let IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequest(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn issues_set_labels_request(&self) -> Option<&IssuesSetLabelsRequest> {
+        if let IssuesAddLabelsRequestOneOf::IssuesSetLabelsRequest(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_projects_update_column_request(&self) -> Option<&Vec<ProjectsUpdateColumnRequest>> {
+        if let IssuesAddLabelsRequestOneOf::ProjectsUpdateColumnRequestVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let IssuesAddLabelsRequestOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let IssuesAddLabelsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<ProjectsUpdateColumnRequest>> for IssuesAddLabelsRequestOneOf {
+    fn from(f: Vec<ProjectsUpdateColumnRequest>) -> Self {
+        IssuesAddLabelsRequestOneOf::ProjectsUpdateColumnRequestVector(f)
+    }
+}
+
+impl std::convert::From<String> for IssuesAddLabelsRequestOneOf {
+    fn from(f: String) -> Self {
+        IssuesAddLabelsRequestOneOf::String(f)
+    }
+}
+
+impl std::convert::From<Vec<String>> for IssuesAddLabelsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        IssuesAddLabelsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<IssuesAddLabelsRequestOneOf> for Vec<ProjectsUpdateColumnRequest> {
+    fn from(f: IssuesAddLabelsRequestOneOf) -> Self {
+        f.vec_projects_update_column_request().unwrap().clone()
+    }
+}
+
+impl std::convert::From<IssuesAddLabelsRequestOneOf> for String {
+    fn from(f: IssuesAddLabelsRequestOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+impl std::convert::From<IssuesAddLabelsRequestOneOf> for Vec<String> {
+    fn from(f: IssuesAddLabelsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `String`
+/// - `Vec<String>`
+/// - `IssuesAddLabelsRequest`
+/// - `Vec<ProjectsUpdateColumnRequest>`
+/// - `IssuesSetLabelsRequest`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesSetLabelsRequestAnyOf {
+    #[serde(flatten)]
+    pub string: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(flatten)]
+    pub string_vector: Vec<String>,
+    #[serde(flatten)]
+    pub issues_add_labels_request: IssuesAddLabelsRequest,
+    #[serde(flatten)]
+    pub projects_update_column_request_vector: Vec<ProjectsUpdateColumnRequest>,
+    #[serde(flatten)]
+    pub issues_set_labels_request: IssuesSetLabelsRequest,
+}
+
+/**
+* The reason for locking the issue or pull request conversation. Lock will fail if you don't use one of these reasons:  
+*   \* `off-topic`  
+*   \* `too heated`  
+*   \* `resolved`  
+*   \* `spam`
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum LockReason {
+    #[serde(rename = "off-topic")]
+    OffTopic,
+    #[serde(rename = "resolved")]
+    Resolved,
+    #[serde(rename = "spam")]
+    Spam,
+    #[serde(rename = "too heated")]
+    TooHeated,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for LockReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            LockReason::OffTopic => "off-topic",
+            LockReason::Resolved => "resolved",
+            LockReason::Spam => "spam",
+            LockReason::TooHeated => "too heated",
+            LockReason::Noop => "",
+            LockReason::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for LockReason {
+    fn default() -> LockReason {
+        LockReason::Noop
+    }
+}
+impl LockReason {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, LockReason::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesLockRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_reason: Option<LockReason>,
+}
+
+impl IssuesLockRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuesLockRequestBuilder {
+        IssuesLockRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuesLockRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuesLockRequestBuilder(IssuesLockRequest);
+
+impl IssuesLockRequestBuilder {
+    pub fn lock_reason(mut self, lock_reason: impl Into<LockReason>) -> Self {
+        self.0.lock_reason = Some(lock_reason.into());
+        self
+    }
+
+    pub fn build(self) -> IssuesLockRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesUpdateLabelRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub color: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub new_name: String,
+}
+
+impl IssuesUpdateLabelRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuesUpdateLabelRequestBuilder {
+        IssuesUpdateLabelRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuesUpdateLabelRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuesUpdateLabelRequestBuilder(IssuesUpdateLabelRequest);
+
+impl IssuesUpdateLabelRequestBuilder {
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.0.color = color.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn new_name(mut self, new_name: impl Into<String>) -> Self {
+        self.0.new_name = new_name.into();
+        self
+    }
+
+    pub fn build(self) -> IssuesUpdateLabelRequest {
+        self.0
+    }
+}
+
+/**
+* What to sort results by. Either `due_on` or `completeness`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum IssuesListMilestonesSort {
+    #[serde(rename = "completeness")]
+    Completeness,
+    #[serde(rename = "due_on")]
+    DueOn,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for IssuesListMilestonesSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            IssuesListMilestonesSort::Completeness => "completeness",
+            IssuesListMilestonesSort::DueOn => "due_on",
+            IssuesListMilestonesSort::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for IssuesListMilestonesSort {
+    fn default() -> IssuesListMilestonesSort {
+        IssuesListMilestonesSort::DueOn
+    }
+}
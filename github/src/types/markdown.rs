@@ -0,0 +1,87 @@
+//! The data types of the `markdown` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/**
+* The rendering mode.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum Mode {
+    #[serde(rename = "gfm")]
+    Gfm,
+    #[serde(rename = "markdown")]
+    Markdown,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Mode::Gfm => "gfm",
+            Mode::Markdown => "markdown",
+            Mode::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Mode {
+    fn default() -> Mode {
+        Mode::Markdown
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MarkdownRenderRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub context: String,
+    /**
+    * The rendering mode.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+}
+
+impl MarkdownRenderRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MarkdownRenderRequestBuilder {
+        MarkdownRenderRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MarkdownRenderRequest`.
+#[derive(Debug, Clone)]
+pub struct MarkdownRenderRequestBuilder(MarkdownRenderRequest);
+
+impl MarkdownRenderRequestBuilder {
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.0.context = context.into();
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<Mode>) -> Self {
+        self.0.mode = Some(mode.into());
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.0.text = text.into();
+        self
+    }
+
+    pub fn build(self) -> MarkdownRenderRequest {
+        self.0
+    }
+}
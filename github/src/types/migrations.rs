@@ -0,0 +1,777 @@
+//! The data types of the `migrations` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// A migration.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Migration {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub archive_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * A migration.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub exclude_attachments: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub guid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub lock_repositories: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ImportStatus {
+    #[serde(rename = "auth")]
+    Auth,
+    #[serde(rename = "auth_failed")]
+    AuthFailed,
+    #[serde(rename = "choose")]
+    Choose,
+    #[serde(rename = "complete")]
+    Complete,
+    #[serde(rename = "detecting")]
+    Detecting,
+    #[serde(rename = "detection_found_multiple")]
+    DetectionFoundMultiple,
+    #[serde(rename = "detection_found_nothing")]
+    DetectionFoundNothing,
+    #[serde(rename = "detection_needs_auth")]
+    DetectionNeedsAuth,
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "importing")]
+    Importing,
+    #[serde(rename = "mapping")]
+    Mapping,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "pushing")]
+    Pushing,
+    #[serde(rename = "setup")]
+    Setup,
+    #[serde(rename = "unknown")]
+    Unknown,
+    #[serde(rename = "waiting_to_push")]
+    WaitingToPush,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl std::fmt::Display for ImportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ImportStatus::Auth => "auth",
+            ImportStatus::AuthFailed => "auth_failed",
+            ImportStatus::Choose => "choose",
+            ImportStatus::Complete => "complete",
+            ImportStatus::Detecting => "detecting",
+            ImportStatus::DetectionFoundMultiple => "detection_found_multiple",
+            ImportStatus::DetectionFoundNothing => "detection_found_nothing",
+            ImportStatus::DetectionNeedsAuth => "detection_needs_auth",
+            ImportStatus::Error => "error",
+            ImportStatus::Importing => "importing",
+            ImportStatus::Mapping => "mapping",
+            ImportStatus::None => "none",
+            ImportStatus::Pushing => "pushing",
+            ImportStatus::Setup => "setup",
+            ImportStatus::Unknown => "unknown",
+            ImportStatus::WaitingToPush => "waiting_to_push",
+            ImportStatus::Noop => "",
+            ImportStatus::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ImportStatus {
+    fn default() -> ImportStatus {
+        ImportStatus::Noop
+    }
+}
+impl ImportStatus {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ImportStatus::Noop)
+    }
+}
+
+/// A repository import from an external source.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Import {
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub authors_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub authors_url: String,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub commit_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub error_message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub failed_step: String,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_large_files: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub import_percent: i64,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub large_files_count: i64,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub large_files_size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub project_choices: Vec<ProjectChoices>,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub push_percent: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_url: String,
+    #[serde(default, skip_serializing_if = "ImportStatus::is_noop")]
+    pub status: ImportStatus,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status_text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub svc_root: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub svn_root: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tfvc_project: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * A repository import from an external source.
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub use_lfs: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Porter Author
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PorterAuthor {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub import_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub remote_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub remote_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Porter Large File
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PorterLargeFile {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub oid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ref_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* Allowed values that can be passed to the exclude param.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum Exclude {
+    #[serde(rename = "repositories")]
+    Repositories,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Exclude {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Exclude::Repositories => "repositories",
+            Exclude::Noop => "",
+            Exclude::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Exclude {
+    fn default() -> Exclude {
+        Exclude::Noop
+    }
+}
+impl Exclude {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, Exclude::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MigrationsStartRequest {
+    /**
+    * Exclude attributes from the API response to improve performance
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<Exclude>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_attachments: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_repositories: Option<bool>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<String>,
+}
+
+impl MigrationsStartRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MigrationsStartRequestBuilder {
+        MigrationsStartRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MigrationsStartRequest`.
+#[derive(Debug, Clone)]
+pub struct MigrationsStartRequestBuilder(MigrationsStartRequest);
+
+impl MigrationsStartRequestBuilder {
+    pub fn exclude(mut self, exclude: Vec<Exclude>) -> Self {
+        self.0.exclude = exclude;
+        self
+    }
+
+    pub fn exclude_attachments(mut self, exclude_attachments: bool) -> Self {
+        self.0.exclude_attachments = Some(exclude_attachments);
+        self
+    }
+
+    pub fn lock_repositories(mut self, lock_repositories: bool) -> Self {
+        self.0.lock_repositories = Some(lock_repositories);
+        self
+    }
+
+    pub fn repositories(mut self, repositories: Vec<String>) -> Self {
+        self.0.repositories = repositories;
+        self
+    }
+
+    pub fn build(self) -> MigrationsStartRequest {
+        self.0
+    }
+}
+
+/**
+* The originating VCS type. Can be one of `subversion`, `git`, `mercurial`, or `tfvc`. Please be aware that without this parameter, the import job will take additional time to detect the VCS type before beginning the import. This detection step will be reflected in the response.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum Vcs {
+    #[serde(rename = "git")]
+    Git,
+    #[serde(rename = "mercurial")]
+    Mercurial,
+    #[serde(rename = "subversion")]
+    Subversion,
+    #[serde(rename = "tfvc")]
+    Tfvc,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Vcs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Vcs::Git => "git",
+            Vcs::Mercurial => "mercurial",
+            Vcs::Subversion => "subversion",
+            Vcs::Tfvc => "tfvc",
+            Vcs::Noop => "",
+            Vcs::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Vcs {
+    fn default() -> Vcs {
+        Vcs::Noop
+    }
+}
+impl Vcs {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, Vcs::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MigrationsStartImportRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tfvc_project: String,
+    /**
+    * The originating VCS type. Can be one of `subversion`, `git`, `mercurial`, or `tfvc`. Please be aware that without this parameter, the import job will take additional time to detect the VCS type before beginning the import. This detection step will be reflected in the response.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vcs: Option<Vcs>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs_password: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs_username: String,
+}
+
+impl MigrationsStartImportRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MigrationsStartImportRequestBuilder {
+        MigrationsStartImportRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MigrationsStartImportRequest`.
+#[derive(Debug, Clone)]
+pub struct MigrationsStartImportRequestBuilder(MigrationsStartImportRequest);
+
+impl MigrationsStartImportRequestBuilder {
+    pub fn tfvc_project(mut self, tfvc_project: impl Into<String>) -> Self {
+        self.0.tfvc_project = tfvc_project.into();
+        self
+    }
+
+    pub fn vcs(mut self, vcs: impl Into<Vcs>) -> Self {
+        self.0.vcs = Some(vcs.into());
+        self
+    }
+
+    pub fn vcs_password(mut self, vcs_password: impl Into<String>) -> Self {
+        self.0.vcs_password = vcs_password.into();
+        self
+    }
+
+    pub fn vcs_url(mut self, vcs_url: impl Into<String>) -> Self {
+        self.0.vcs_url = vcs_url.into();
+        self
+    }
+
+    pub fn vcs_username(mut self, vcs_username: impl Into<String>) -> Self {
+        self.0.vcs_username = vcs_username.into();
+        self
+    }
+
+    pub fn build(self) -> MigrationsStartImportRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MigrationsUpdateImportRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tfvc_project: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs_password: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vcs_username: String,
+}
+
+impl MigrationsUpdateImportRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MigrationsUpdateImportRequestBuilder {
+        MigrationsUpdateImportRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MigrationsUpdateImportRequest`.
+#[derive(Debug, Clone)]
+pub struct MigrationsUpdateImportRequestBuilder(MigrationsUpdateImportRequest);
+
+impl MigrationsUpdateImportRequestBuilder {
+    pub fn tfvc_project(mut self, tfvc_project: impl Into<String>) -> Self {
+        self.0.tfvc_project = tfvc_project.into();
+        self
+    }
+
+    pub fn vcs(mut self, vcs: impl Into<String>) -> Self {
+        self.0.vcs = vcs.into();
+        self
+    }
+
+    pub fn vcs_password(mut self, vcs_password: impl Into<String>) -> Self {
+        self.0.vcs_password = vcs_password.into();
+        self
+    }
+
+    pub fn vcs_username(mut self, vcs_username: impl Into<String>) -> Self {
+        self.0.vcs_username = vcs_username.into();
+        self
+    }
+
+    pub fn build(self) -> MigrationsUpdateImportRequest {
+        self.0
+    }
+}
+
+/**
+* Can be one of `opt_in` (large files will be stored using Git LFS) or `opt_out` (large files will be removed during the import).
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum UseLfs {
+    #[serde(rename = "opt_in")]
+    OptIn,
+    #[serde(rename = "opt_out")]
+    OptOut,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for UseLfs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            UseLfs::OptIn => "opt_in",
+            UseLfs::OptOut => "opt_out",
+            UseLfs::Noop => "",
+            UseLfs::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for UseLfs {
+    fn default() -> UseLfs {
+        UseLfs::Noop
+    }
+}
+impl UseLfs {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, UseLfs::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MigrationsSetLfsPreferenceRequest {
+    /**
+    * Can be one of `opt_in` (large files will be stored using Git LFS) or `opt_out` (large files will be removed during the import).
+    */
+    #[serde(default, skip_serializing_if = "UseLfs::is_noop")]
+    pub use_lfs: UseLfs,
+}
+
+impl MigrationsSetLfsPreferenceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MigrationsSetLfsPreferenceRequestBuilder {
+        MigrationsSetLfsPreferenceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MigrationsSetLfsPreferenceRequest`.
+#[derive(Debug, Clone)]
+pub struct MigrationsSetLfsPreferenceRequestBuilder(MigrationsSetLfsPreferenceRequest);
+
+impl MigrationsSetLfsPreferenceRequestBuilder {
+    pub fn use_lfs(mut self, use_lfs: impl Into<UseLfs>) -> Self {
+        self.0.use_lfs = use_lfs.into();
+        self
+    }
+
+    pub fn build(self) -> MigrationsSetLfsPreferenceRequest {
+        self.0
+    }
+}
@@ -0,0 +1,120 @@
+//! The data types sent to and returned from the API client.
+//!
+//! The types are split by the API group using them, and compiled along with
+//! its functions. The types used by several groups are in `shared`, which is
+//! always compiled. They are all re-exported here.
+#[cfg(feature = "actions")]
+mod actions;
+#[cfg(feature = "activity")]
+mod activity;
+mod apps;
+#[cfg(feature = "billing")]
+mod billing;
+#[cfg(feature = "checks")]
+mod checks;
+#[cfg(feature = "code_scanning")]
+mod code_scanning;
+#[cfg(feature = "enterprise_admin")]
+mod enterprise_admin;
+#[cfg(feature = "gists")]
+mod gists;
+#[cfg(feature = "git")]
+mod git;
+#[cfg(feature = "gitignore")]
+mod gitignore;
+#[cfg(feature = "interactions")]
+mod interactions;
+#[cfg(feature = "issues")]
+mod issues;
+#[cfg(feature = "licenses")]
+mod licenses;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "meta")]
+mod meta;
+#[cfg(feature = "migrations")]
+mod migrations;
+#[cfg(feature = "oauth_authorizations")]
+mod oauth_authorizations;
+#[cfg(feature = "orgs")]
+mod orgs;
+#[cfg(feature = "packages")]
+mod packages;
+#[cfg(feature = "projects")]
+mod projects;
+#[cfg(feature = "pulls")]
+mod pulls;
+mod rate_limit;
+#[cfg(feature = "reactions")]
+mod reactions;
+#[cfg(feature = "repos")]
+mod repos;
+#[cfg(feature = "scim")]
+mod scim;
+#[cfg(feature = "search")]
+mod search;
+#[cfg(feature = "secret_scanning")]
+mod secret_scanning;
+mod shared;
+#[cfg(feature = "teams")]
+mod teams;
+#[cfg(feature = "users")]
+mod users;
+
+#[cfg(feature = "actions")]
+pub use self::actions::*;
+#[cfg(feature = "activity")]
+pub use self::activity::*;
+pub use self::apps::*;
+#[cfg(feature = "billing")]
+pub use self::billing::*;
+#[cfg(feature = "checks")]
+pub use self::checks::*;
+#[cfg(feature = "code_scanning")]
+pub use self::code_scanning::*;
+#[cfg(feature = "enterprise_admin")]
+pub use self::enterprise_admin::*;
+#[cfg(feature = "gists")]
+pub use self::gists::*;
+#[cfg(feature = "git")]
+pub use self::git::*;
+#[cfg(feature = "gitignore")]
+pub use self::gitignore::*;
+#[cfg(feature = "interactions")]
+pub use self::interactions::*;
+#[cfg(feature = "issues")]
+pub use self::issues::*;
+#[cfg(feature = "licenses")]
+pub use self::licenses::*;
+#[cfg(feature = "markdown")]
+pub use self::markdown::*;
+#[cfg(feature = "meta")]
+pub use self::meta::*;
+#[cfg(feature = "migrations")]
+pub use self::migrations::*;
+#[cfg(feature = "oauth_authorizations")]
+pub use self::oauth_authorizations::*;
+#[cfg(feature = "orgs")]
+pub use self::orgs::*;
+#[cfg(feature = "packages")]
+pub use self::packages::*;
+#[cfg(feature = "projects")]
+pub use self::projects::*;
+#[cfg(feature = "pulls")]
+pub use self::pulls::*;
+pub use self::rate_limit::*;
+#[cfg(feature = "reactions")]
+pub use self::reactions::*;
+#[cfg(feature = "repos")]
+pub use self::repos::*;
+#[cfg(feature = "scim")]
+pub use self::scim::*;
+#[cfg(feature = "search")]
+pub use self::search::*;
+#[cfg(feature = "secret_scanning")]
+pub use self::secret_scanning::*;
+pub use self::shared::*;
+#[cfg(feature = "teams")]
+pub use self::teams::*;
+#[cfg(feature = "users")]
+pub use self::users::*;
@@ -0,0 +1,393 @@
+//! The data types of the `oauth_authorizations` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// The authorization associated with an OAuth Access.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationGrant {
+    #[serde()]
+    pub app: App,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OauthAuthorizationsCreateAuthorizationRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub fingerprint: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note_url: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+impl OauthAuthorizationsCreateAuthorizationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OauthAuthorizationsCreateAuthorizationRequestBuilder {
+        OauthAuthorizationsCreateAuthorizationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OauthAuthorizationsCreateAuthorizationRequest`.
+#[derive(Debug, Clone)]
+pub struct OauthAuthorizationsCreateAuthorizationRequestBuilder(
+    OauthAuthorizationsCreateAuthorizationRequest,
+);
+
+impl OauthAuthorizationsCreateAuthorizationRequestBuilder {
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.0.client_id = client_id.into();
+        self
+    }
+
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.0.client_secret = client_secret.into();
+        self
+    }
+
+    pub fn fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
+        self.0.fingerprint = fingerprint.into();
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.0.note = note.into();
+        self
+    }
+
+    pub fn note_url(mut self, note_url: impl Into<String>) -> Self {
+        self.0.note_url = note_url.into();
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> OauthAuthorizationsCreateAuthorizationRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OauthAuthorizationsGetCreateAuthorizationAppRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub fingerprint: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note_url: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+impl OauthAuthorizationsGetCreateAuthorizationAppRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OauthAuthorizationsGetCreateAuthorizationAppRequestBuilder {
+        OauthAuthorizationsGetCreateAuthorizationAppRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OauthAuthorizationsGetCreateAuthorizationAppRequest`.
+#[derive(Debug, Clone)]
+pub struct OauthAuthorizationsGetCreateAuthorizationAppRequestBuilder(
+    OauthAuthorizationsGetCreateAuthorizationAppRequest,
+);
+
+impl OauthAuthorizationsGetCreateAuthorizationAppRequestBuilder {
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.0.client_secret = client_secret.into();
+        self
+    }
+
+    pub fn fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
+        self.0.fingerprint = fingerprint.into();
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.0.note = note.into();
+        self
+    }
+
+    pub fn note_url(mut self, note_url: impl Into<String>) -> Self {
+        self.0.note_url = note_url.into();
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> OauthAuthorizationsGetCreateAuthorizationAppRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note_url: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+impl OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequestBuilder {
+        OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest`.
+#[derive(Debug, Clone)]
+pub struct OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequestBuilder(
+    OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
+);
+
+impl OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequestBuilder {
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.0.client_secret = client_secret.into();
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.0.note = note.into();
+        self
+    }
+
+    pub fn note_url(mut self, note_url: impl Into<String>) -> Self {
+        self.0.note_url = note_url.into();
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OauthAuthorizationsUpdateAuthorizationRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub add_scopes: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub fingerprint: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note_url: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub remove_scopes: Vec<String>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub scopes: crate::utils::Nullable<Vec<String>>,
+}
+
+impl OauthAuthorizationsUpdateAuthorizationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OauthAuthorizationsUpdateAuthorizationRequestBuilder {
+        OauthAuthorizationsUpdateAuthorizationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OauthAuthorizationsUpdateAuthorizationRequest`.
+#[derive(Debug, Clone)]
+pub struct OauthAuthorizationsUpdateAuthorizationRequestBuilder(
+    OauthAuthorizationsUpdateAuthorizationRequest,
+);
+
+impl OauthAuthorizationsUpdateAuthorizationRequestBuilder {
+    pub fn add_scopes(mut self, add_scopes: Vec<String>) -> Self {
+        self.0.add_scopes = add_scopes;
+        self
+    }
+
+    pub fn fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
+        self.0.fingerprint = fingerprint.into();
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.0.note = note.into();
+        self
+    }
+
+    pub fn note_url(mut self, note_url: impl Into<String>) -> Self {
+        self.0.note_url = note_url.into();
+        self
+    }
+
+    pub fn remove_scopes(mut self, remove_scopes: Vec<String>) -> Self {
+        self.0.remove_scopes = remove_scopes;
+        self
+    }
+
+    pub fn scopes(mut self, scopes: impl Into<crate::utils::Nullable<Vec<String>>>) -> Self {
+        self.0.scopes = scopes.into();
+        self
+    }
+
+    pub fn build(self) -> OauthAuthorizationsUpdateAuthorizationRequest {
+        self.0
+    }
+}
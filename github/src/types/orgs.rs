@@ -0,0 +1,1141 @@
+//! The data types of the `orgs` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Validation Error Simple
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ValidationErrorSimple {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    /**
+    * Validation Error Simple
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Credential Authorization
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CredentialAuthorization {
+    /**
+    * Credential Authorization
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub authorized_credential_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub authorized_credential_note: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub authorized_credential_title: String,
+    /**
+    * Credential Authorization
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub credential_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub credential_authorized_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub credential_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub credential_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub fingerprint: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    /**
+    * Credential Authorization
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token_last_eight: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub insecure_ssl: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Org Hook
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgHook {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde()]
+    pub config: Config,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deliveries_url: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ping_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* The user's membership type in the organization.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum Role {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "billing_manager")]
+    BillingManager,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Role::Admin => "admin",
+            Role::BillingManager => "billing_manager",
+            Role::Member => "member",
+            Role::Noop => "",
+            Role::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Role {
+    fn default() -> Role {
+        Role::Noop
+    }
+}
+impl Role {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, Role::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgMembershipPermissions {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_create_repository: bool,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Org Membership
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgMembership {
+    /**
+    * Organization Simple
+    */
+    #[serde()]
+    pub organization: OrganizationSimple,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organization_url: String,
+    /**
+    * Org Membership
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<OrgMembershipPermissions>,
+    /**
+    * The user's membership type in the organization.
+    */
+    #[serde(default, skip_serializing_if = "Role::is_noop")]
+    pub role: Role,
+    /**
+    * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
+    */
+    #[serde(default, skip_serializing_if = "OrgMembershipState::is_noop")]
+    pub state: OrgMembershipState,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* Specifies which types of repositories non-admin organization members can create. Can be one of:  
+*   \* `all` - all organization members can create public and private repositories.  
+*   \* `private` - members can create private repositories. This option is only available to repositories that are part of an organization on GitHub Enterprise Cloud.  
+*   \* `none` - only admin members can create repositories.  
+*   **Note:** This parameter is deprecated and will be removed in the future. Its return value ignores internal repositories. Using this parameter overrides values set in `members_can_create_repositories`. See the parameter deprecation notice in the operation description for details.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum MembersAllowedRepositoryCreationType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for MembersAllowedRepositoryCreationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            MembersAllowedRepositoryCreationType::All => "all",
+            MembersAllowedRepositoryCreationType::None => "none",
+            MembersAllowedRepositoryCreationType::Private => "private",
+            MembersAllowedRepositoryCreationType::Noop => "",
+            MembersAllowedRepositoryCreationType::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for MembersAllowedRepositoryCreationType {
+    fn default() -> MembersAllowedRepositoryCreationType {
+        MembersAllowedRepositoryCreationType::Noop
+    }
+}
+impl MembersAllowedRepositoryCreationType {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, MembersAllowedRepositoryCreationType::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub billing_email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blog: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company: String,
+    /**
+    * The baseline permission that all organization members have on this project. Only present if owner is an organization.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_repository_permission: Option<OrganizationPermission>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_organization_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_repository_projects: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    /**
+    * Specifies which types of repositories non-admin organization members can create. Can be one of:  
+    *  \\* `all` - all organization members can create public and private repositories.  
+    *  \\* `private` - members can create private repositories. This option is only available to repositories that are part of an organization on GitHub Enterprise Cloud.  
+    *  \\* `none` - only admin members can create repositories.  
+    *  \*\*Note:\*\* This parameter is deprecated and will be removed in the future. Its return value ignores internal repositories. Using this parameter overrides values set in `members_can_create_repositories`. See the parameter deprecation notice in the operation description for details.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_allowed_repository_creation_type: Option<MembersAllowedRepositoryCreationType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_internal_repositories: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_pages: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_private_pages: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_private_repositories: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_public_pages: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_public_repositories: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_repositories: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub twitter_username: String,
+}
+
+impl OrgsUpdateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OrgsUpdateRequestBuilder {
+        OrgsUpdateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OrgsUpdateRequest`.
+#[derive(Debug, Clone)]
+pub struct OrgsUpdateRequestBuilder(OrgsUpdateRequest);
+
+impl OrgsUpdateRequestBuilder {
+    pub fn billing_email(mut self, billing_email: impl Into<String>) -> Self {
+        self.0.billing_email = billing_email.into();
+        self
+    }
+
+    pub fn blog(mut self, blog: impl Into<String>) -> Self {
+        self.0.blog = blog.into();
+        self
+    }
+
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.0.company = company.into();
+        self
+    }
+
+    pub fn default_repository_permission(
+        mut self,
+        default_repository_permission: impl Into<OrganizationPermission>,
+    ) -> Self {
+        self.0.default_repository_permission = Some(default_repository_permission.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn has_organization_projects(mut self, has_organization_projects: bool) -> Self {
+        self.0.has_organization_projects = Some(has_organization_projects);
+        self
+    }
+
+    pub fn has_repository_projects(mut self, has_repository_projects: bool) -> Self {
+        self.0.has_repository_projects = Some(has_repository_projects);
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.0.location = location.into();
+        self
+    }
+
+    pub fn members_allowed_repository_creation_type(
+        mut self,
+        members_allowed_repository_creation_type: impl Into<MembersAllowedRepositoryCreationType>,
+    ) -> Self {
+        self.0.members_allowed_repository_creation_type =
+            Some(members_allowed_repository_creation_type.into());
+        self
+    }
+
+    pub fn members_can_create_internal_repositories(
+        mut self,
+        members_can_create_internal_repositories: bool,
+    ) -> Self {
+        self.0.members_can_create_internal_repositories =
+            Some(members_can_create_internal_repositories);
+        self
+    }
+
+    pub fn members_can_create_pages(mut self, members_can_create_pages: bool) -> Self {
+        self.0.members_can_create_pages = Some(members_can_create_pages);
+        self
+    }
+
+    pub fn members_can_create_private_pages(
+        mut self,
+        members_can_create_private_pages: bool,
+    ) -> Self {
+        self.0.members_can_create_private_pages = Some(members_can_create_private_pages);
+        self
+    }
+
+    pub fn members_can_create_private_repositories(
+        mut self,
+        members_can_create_private_repositories: bool,
+    ) -> Self {
+        self.0.members_can_create_private_repositories =
+            Some(members_can_create_private_repositories);
+        self
+    }
+
+    pub fn members_can_create_public_pages(
+        mut self,
+        members_can_create_public_pages: bool,
+    ) -> Self {
+        self.0.members_can_create_public_pages = Some(members_can_create_public_pages);
+        self
+    }
+
+    pub fn members_can_create_public_repositories(
+        mut self,
+        members_can_create_public_repositories: bool,
+    ) -> Self {
+        self.0.members_can_create_public_repositories =
+            Some(members_can_create_public_repositories);
+        self
+    }
+
+    pub fn members_can_create_repositories(
+        mut self,
+        members_can_create_repositories: bool,
+    ) -> Self {
+        self.0.members_can_create_repositories = Some(members_can_create_repositories);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn twitter_username(mut self, twitter_username: impl Into<String>) -> Self {
+        self.0.twitter_username = twitter_username.into();
+        self
+    }
+
+    pub fn build(self) -> OrgsUpdateRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `ValidationErrorSimple`
+/// - `ValidationError`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OrgsUpdateResponseOneOf {
+    /**
+    * Validation Error Simple
+    */
+    ValidationErrorSimple(ValidationErrorSimple),
+    /**
+    * Validation Error
+    */
+    ValidationError(ValidationError),
+}
+
+impl OrgsUpdateResponseOneOf {
+    pub fn validation_error(&self) -> Option<&ValidationError> {
+        if let OrgsUpdateResponseOneOf::ValidationError(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn validation_error_simple(&self) -> Option<&ValidationErrorSimple> {
+        if let OrgsUpdateResponseOneOf::ValidationErrorSimple(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+/// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsCreateWebhookRequestConfig {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    /**
+    * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub password: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsCreateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+    * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
+    */
+    #[serde()]
+    pub config: OrgsCreateWebhookRequestConfig,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+impl OrgsCreateWebhookRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OrgsCreateWebhookRequestBuilder {
+        OrgsCreateWebhookRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OrgsCreateWebhookRequest`.
+#[derive(Debug, Clone)]
+pub struct OrgsCreateWebhookRequestBuilder(OrgsCreateWebhookRequest);
+
+impl OrgsCreateWebhookRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn config(mut self, config: impl Into<OrgsCreateWebhookRequestConfig>) -> Self {
+        self.0.config = config.into();
+        self
+    }
+
+    pub fn events(mut self, events: Vec<String>) -> Self {
+        self.0.events = events;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> OrgsCreateWebhookRequest {
+        self.0
+    }
+}
+
+/// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#update-hook-config-params).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateWebhookRequestConfig {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    /**
+    * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#update-hook-config-params).
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+    * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#update-hook-config-params).
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<OrgsUpdateWebhookRequestConfig>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+impl OrgsUpdateWebhookRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OrgsUpdateWebhookRequestBuilder {
+        OrgsUpdateWebhookRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OrgsUpdateWebhookRequest`.
+#[derive(Debug, Clone)]
+pub struct OrgsUpdateWebhookRequestBuilder(OrgsUpdateWebhookRequest);
+
+impl OrgsUpdateWebhookRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn config(mut self, config: impl Into<OrgsUpdateWebhookRequestConfig>) -> Self {
+        self.0.config = Some(config.into());
+        self
+    }
+
+    pub fn events(mut self, events: Vec<String>) -> Self {
+        self.0.events = events;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> OrgsUpdateWebhookRequest {
+        self.0
+    }
+}
+
+/**
+* Specify role for new member. Can be one of:  
+*   \* `admin` - Organization owners with full administrative rights to the organization and complete access to all repositories and teams.  
+*   \* `direct_member` - Non-owner organization members with ability to see other members and join teams by invitation.  
+*   \* `billing_manager` - Non-owner organization members with ability to manage the billing settings of your organization.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum OrgsCreateInvitationRequestRole {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "billing_manager")]
+    BillingManager,
+    #[serde(rename = "direct_member")]
+    DirectMember,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for OrgsCreateInvitationRequestRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            OrgsCreateInvitationRequestRole::Admin => "admin",
+            OrgsCreateInvitationRequestRole::BillingManager => "billing_manager",
+            OrgsCreateInvitationRequestRole::DirectMember => "direct_member",
+            OrgsCreateInvitationRequestRole::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for OrgsCreateInvitationRequestRole {
+    fn default() -> OrgsCreateInvitationRequestRole {
+        OrgsCreateInvitationRequestRole::DirectMember
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsCreateInvitationRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub invitee_id: i64,
+    /**
+    * Specify role for new member. Can be one of:  
+    *  \\* `admin` - Organization owners with full administrative rights to the organization and complete access to all repositories and teams.  
+    *  \\* `direct_member` - Non-owner organization members with ability to see other members and join teams by invitation.  
+    *  \\* `billing_manager` - Non-owner organization members with ability to manage the billing settings of your organization.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<OrgsCreateInvitationRequestRole>,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team_ids: Vec<i64>,
+}
+
+impl OrgsCreateInvitationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OrgsCreateInvitationRequestBuilder {
+        OrgsCreateInvitationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OrgsCreateInvitationRequest`.
+#[derive(Debug, Clone)]
+pub struct OrgsCreateInvitationRequestBuilder(OrgsCreateInvitationRequest);
+
+impl OrgsCreateInvitationRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn invitee_id(mut self, invitee_id: i64) -> Self {
+        self.0.invitee_id = invitee_id;
+        self
+    }
+
+    pub fn role(mut self, role: impl Into<OrgsCreateInvitationRequestRole>) -> Self {
+        self.0.role = Some(role.into());
+        self
+    }
+
+    pub fn team_ids(mut self, team_ids: Vec<i64>) -> Self {
+        self.0.team_ids = team_ids;
+        self
+    }
+
+    pub fn build(self) -> OrgsCreateInvitationRequest {
+        self.0
+    }
+}
+
+/**
+* Filter members returned in the list. Can be one of:  
+*   \* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
+*   \* `all` - All members the authenticated user can see.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum OrgsListMembersFilter {
+    #[serde(rename = "2fa_disabled")]
+    TwoFaDisabled,
+    #[serde(rename = "all")]
+    All,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for OrgsListMembersFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            OrgsListMembersFilter::TwoFaDisabled => "2fa_disabled",
+            OrgsListMembersFilter::All => "all",
+            OrgsListMembersFilter::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for OrgsListMembersFilter {
+    fn default() -> OrgsListMembersFilter {
+        OrgsListMembersFilter::All
+    }
+}
+
+/**
+* Filter members returned by their role. Can be one of:  
+*   \* `all` - All members of the organization, regardless of role.  
+*   \* `admin` - Organization owners.  
+*   \* `member` - Non-owner organization members.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum OrgsListMembersRole {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for OrgsListMembersRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            OrgsListMembersRole::Admin => "admin",
+            OrgsListMembersRole::All => "all",
+            OrgsListMembersRole::Member => "member",
+            OrgsListMembersRole::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for OrgsListMembersRole {
+    fn default() -> OrgsListMembersRole {
+        OrgsListMembersRole::All
+    }
+}
+
+/**
+* The role to give the user in the organization. Can be one of:  
+*   \* `admin` - The user will become an owner of the organization.  
+*   \* `member` - The user will become a non-owner member of the organization.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum OrgsSetMembershipUserRequestRole {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            OrgsSetMembershipUserRequestRole::Admin => "admin",
+            OrgsSetMembershipUserRequestRole::Member => "member",
+            OrgsSetMembershipUserRequestRole::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for OrgsSetMembershipUserRequestRole {
+    fn default() -> OrgsSetMembershipUserRequestRole {
+        OrgsSetMembershipUserRequestRole::Member
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsSetMembershipUserRequest {
+    /**
+    * The role to give the user in the organization. Can be one of:  
+    *  \\* `admin` - The user will become an owner of the organization.  
+    *  \\* `member` - The user will become a non-owner member of the organization.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<OrgsSetMembershipUserRequestRole>,
+}
+
+impl OrgsSetMembershipUserRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OrgsSetMembershipUserRequestBuilder {
+        OrgsSetMembershipUserRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OrgsSetMembershipUserRequest`.
+#[derive(Debug, Clone)]
+pub struct OrgsSetMembershipUserRequestBuilder(OrgsSetMembershipUserRequest);
+
+impl OrgsSetMembershipUserRequestBuilder {
+    pub fn role(mut self, role: impl Into<OrgsSetMembershipUserRequestRole>) -> Self {
+        self.0.role = Some(role.into());
+        self
+    }
+
+    pub fn build(self) -> OrgsSetMembershipUserRequest {
+        self.0
+    }
+}
+
+/**
+* The state that the membership should be in. Only `"active"` will be accepted.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum OrgsUpdateMembershipRequestState {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for OrgsUpdateMembershipRequestState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            OrgsUpdateMembershipRequestState::Active => "active",
+            OrgsUpdateMembershipRequestState::Noop => "",
+            OrgsUpdateMembershipRequestState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for OrgsUpdateMembershipRequestState {
+    fn default() -> OrgsUpdateMembershipRequestState {
+        OrgsUpdateMembershipRequestState::Noop
+    }
+}
+impl OrgsUpdateMembershipRequestState {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, OrgsUpdateMembershipRequestState::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateMembershipRequest {
+    /**
+    * The state that the membership should be in. Only `"active"` will be accepted.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "OrgsUpdateMembershipRequestState::is_noop"
+    )]
+    pub state: OrgsUpdateMembershipRequestState,
+}
+
+impl OrgsUpdateMembershipRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> OrgsUpdateMembershipRequestBuilder {
+        OrgsUpdateMembershipRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `OrgsUpdateMembershipRequest`.
+#[derive(Debug, Clone)]
+pub struct OrgsUpdateMembershipRequestBuilder(OrgsUpdateMembershipRequest);
+
+impl OrgsUpdateMembershipRequestBuilder {
+    pub fn state(mut self, state: impl Into<OrgsUpdateMembershipRequestState>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn build(self) -> OrgsUpdateMembershipRequest {
+        self.0
+    }
+}
@@ -0,0 +1,661 @@
+//! The data types of the `projects` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Project cards represent a scope of work.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectCard {
+    /**
+    * Project cards represent a scope of work.
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub archived: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub column_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub column_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub project_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub project_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Project columns contain cards of work.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectColumn {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cards_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub project_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsCreateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+impl ProjectsCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ProjectsCreateRequestBuilder {
+        ProjectsCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ProjectsCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct ProjectsCreateRequestBuilder(ProjectsCreateRequest);
+
+impl ProjectsCreateRequestBuilder {
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> ProjectsCreateRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsDeleteResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsUpdateCardRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub note: crate::utils::Nullable<String>,
+}
+
+impl ProjectsUpdateCardRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ProjectsUpdateCardRequestBuilder {
+        ProjectsUpdateCardRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ProjectsUpdateCardRequest`.
+#[derive(Debug, Clone)]
+pub struct ProjectsUpdateCardRequestBuilder(ProjectsUpdateCardRequest);
+
+impl ProjectsUpdateCardRequestBuilder {
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.0.archived = Some(archived);
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<crate::utils::Nullable<String>>) -> Self {
+        self.0.note = note.into();
+        self
+    }
+
+    pub fn build(self) -> ProjectsUpdateCardRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsMoveCardRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub column_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub position: String,
+}
+
+impl ProjectsMoveCardRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ProjectsMoveCardRequestBuilder {
+        ProjectsMoveCardRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ProjectsMoveCardRequest`.
+#[derive(Debug, Clone)]
+pub struct ProjectsMoveCardRequestBuilder(ProjectsMoveCardRequest);
+
+impl ProjectsMoveCardRequestBuilder {
+    pub fn column_id(mut self, column_id: i64) -> Self {
+        self.0.column_id = column_id;
+        self
+    }
+
+    pub fn position(mut self, position: impl Into<String>) -> Self {
+        self.0.position = position.into();
+        self
+    }
+
+    pub fn build(self) -> ProjectsMoveCardRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsMoveCardResponseErrors {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub field: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub resource: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsMoveCardResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<ProjectsMoveCardResponseErrors>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsMoveCardResponseErrorsData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsCreateCardResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<ProjectsMoveCardResponseErrorsData>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ArchivedState {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "archived")]
+    Archived,
+    #[serde(rename = "not_archived")]
+    NotArchived,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ArchivedState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ArchivedState::All => "all",
+            ArchivedState::Archived => "archived",
+            ArchivedState::NotArchived => "not_archived",
+            ArchivedState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ArchivedState {
+    fn default() -> ArchivedState {
+        ArchivedState::NotArchived
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsCreateCardRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub note: String,
+}
+
+impl std::convert::From<String> for ProjectsCreateCardRequest {
+    fn from(note: String) -> Self {
+        ProjectsCreateCardRequest { note }
+    }
+}
+
+impl std::convert::From<&str> for ProjectsCreateCardRequest {
+    fn from(note: &str) -> Self {
+        ProjectsCreateCardRequest {
+            note: note.to_string(),
+        }
+    }
+}
+
+impl ProjectsCreateCardRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ProjectsCreateCardRequestBuilder {
+        ProjectsCreateCardRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ProjectsCreateCardRequest`.
+#[derive(Debug, Clone)]
+pub struct ProjectsCreateCardRequestBuilder(ProjectsCreateCardRequest);
+
+impl ProjectsCreateCardRequestBuilder {
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.0.note = note.into();
+        self
+    }
+
+    pub fn build(self) -> ProjectsCreateCardRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsCreateCardRequestData {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub content_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+}
+
+/// All of the following types:
+///
+/// - `ProjectsCreateCardRequest`
+/// - `ProjectsCreateCardRequestData`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ProjectsCreateCardRequestOneOf {
+    ProjectsCreateCardRequest(ProjectsCreateCardRequest),
+    ProjectsCreateCardRequestData(ProjectsCreateCardRequestData),
+}
+
+impl ProjectsCreateCardRequestOneOf {
+    pub fn projects_create_card_request(&self) -> Option<&ProjectsCreateCardRequest> {
+        if let ProjectsCreateCardRequestOneOf::ProjectsCreateCardRequest(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn projects_create_card_request_data(&self) -> Option<&ProjectsCreateCardRequestData> {
+        if let ProjectsCreateCardRequestOneOf::ProjectsCreateCardRequestData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsMoveColumnRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub position: String,
+}
+
+impl std::convert::From<String> for ProjectsMoveColumnRequest {
+    fn from(position: String) -> Self {
+        ProjectsMoveColumnRequest { position }
+    }
+}
+
+impl std::convert::From<&str> for ProjectsMoveColumnRequest {
+    fn from(position: &str) -> Self {
+        ProjectsMoveColumnRequest {
+            position: position.to_string(),
+        }
+    }
+}
+
+impl ProjectsMoveColumnRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ProjectsMoveColumnRequestBuilder {
+        ProjectsMoveColumnRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ProjectsMoveColumnRequest`.
+#[derive(Debug, Clone)]
+pub struct ProjectsMoveColumnRequestBuilder(ProjectsMoveColumnRequest);
+
+impl ProjectsMoveColumnRequestBuilder {
+    pub fn position(mut self, position: impl Into<String>) -> Self {
+        self.0.position = position.into();
+        self
+    }
+
+    pub fn build(self) -> ProjectsMoveColumnRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProjectsUpdateRequest {
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub body: crate::utils::Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+    * The baseline permission that all organization members have on this project. Only present if owner is an organization.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_permission: Option<OrganizationPermission>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+}
+
+impl ProjectsUpdateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ProjectsUpdateRequestBuilder {
+        ProjectsUpdateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ProjectsUpdateRequest`.
+#[derive(Debug, Clone)]
+pub struct ProjectsUpdateRequestBuilder(ProjectsUpdateRequest);
+
+impl ProjectsUpdateRequestBuilder {
+    pub fn body(mut self, body: impl Into<crate::utils::Nullable<String>>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn organization_permission(
+        mut self,
+        organization_permission: impl Into<OrganizationPermission>,
+    ) -> Self {
+        self.0.organization_permission = Some(organization_permission.into());
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.0.private = Some(private);
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn build(self) -> ProjectsUpdateRequest {
+        self.0
+    }
+}
@@ -0,0 +1,1018 @@
+//! The data types of the `pulls` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Pull Request Merge Result
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestMergeResult {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub merged: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Pull Request Review Request
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestReview {
+    /**
+    * The list of teams with review dismissal access.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<Team>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Pull Request Reviews are reviews on pull requests.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestReviewData {
+    #[serde(rename = "_links")]
+    pub links: TimelineReviewedEventLinks,
+    /**
+    * How the author is associated with the repository.
+    */
+    #[serde()]
+    pub author_association: AuthorAssociation,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pull_request_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    /**
+    * Pull Request Reviews are reviews on pull requests.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub submitted_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewCommentLinks {
+    /**
+    * Hypermedia Link
+    */
+    #[serde()]
+    pub html: Link,
+    /**
+    * Hypermedia Link
+    */
+    #[serde()]
+    pub pull_request: Link,
+    /**
+    * Hypermedia Link
+    */
+    #[serde(rename = "self")]
+    pub self_: Link,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Legacy Review Comment
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewComment {
+    #[serde(rename = "_links")]
+    pub links: ReviewCommentLinks,
+    /**
+    * How the author is associated with the repository.
+    */
+    #[serde()]
+    pub author_association: AuthorAssociation,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub diff_hunk: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub in_reply_to_id: i64,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub original_commit_id: String,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub original_line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub original_position: i64,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub original_start_line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub position: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub pull_request_review_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pull_request_url: String,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<ReactionRollup>,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub start_line: i64,
+    /**
+    * Legacy Review Comment
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_side: Option<Side>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsMergeResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum PullsListReviewCommentsRepoSort {
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    #[serde(rename = "updated")]
+    Updated,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for PullsListReviewCommentsRepoSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            PullsListReviewCommentsRepoSort::Created => "created",
+            PullsListReviewCommentsRepoSort::CreatedAt => "created_at",
+            PullsListReviewCommentsRepoSort::Updated => "updated",
+            PullsListReviewCommentsRepoSort::Noop => "",
+            PullsListReviewCommentsRepoSort::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for PullsListReviewCommentsRepoSort {
+    fn default() -> PullsListReviewCommentsRepoSort {
+        PullsListReviewCommentsRepoSort::Noop
+    }
+}
+impl PullsListReviewCommentsRepoSort {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, PullsListReviewCommentsRepoSort::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsUpdateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub base: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+    /**
+    * The state of the milestone.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+impl PullsUpdateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsUpdateRequestBuilder {
+        PullsUpdateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsUpdateRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsUpdateRequestBuilder(PullsUpdateRequest);
+
+impl PullsUpdateRequestBuilder {
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.0.base = base.into();
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn maintainer_can_modify(mut self, maintainer_can_modify: bool) -> Self {
+        self.0.maintainer_can_modify = Some(maintainer_can_modify);
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<State>) -> Self {
+        self.0.state = Some(state.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn build(self) -> PullsUpdateRequest {
+        self.0
+    }
+}
+
+/**
+* **Required when using multi-line comments**. To create multi-line comments, you must use the `comfort-fade` preview header. The `start_side` is the starting side of the diff that the comment applies to. Can be `LEFT` or `RIGHT`. To learn more about multi-line comments, see "[Commenting on a pull request](https://help.github.com/en/articles/commenting-on-a-pull-request#adding-line-comments-to-a-pull-request)" in the GitHub Help documentation. See `side` in this table for additional context.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum PullsCreateReviewCommentRequestStartSide {
+    #[serde(rename = "LEFT")]
+    Left,
+    #[serde(rename = "RIGHT")]
+    Right,
+    #[serde(rename = "side")]
+    Side,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            PullsCreateReviewCommentRequestStartSide::Left => "LEFT",
+            PullsCreateReviewCommentRequestStartSide::Right => "RIGHT",
+            PullsCreateReviewCommentRequestStartSide::Side => "side",
+            PullsCreateReviewCommentRequestStartSide::Noop => "",
+            PullsCreateReviewCommentRequestStartSide::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for PullsCreateReviewCommentRequestStartSide {
+    fn default() -> PullsCreateReviewCommentRequestStartSide {
+        PullsCreateReviewCommentRequestStartSide::Noop
+    }
+}
+impl PullsCreateReviewCommentRequestStartSide {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, PullsCreateReviewCommentRequestStartSide::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsCreateReviewCommentRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub in_reply_to: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub position: i64,
+    /**
+    * The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub start_line: i64,
+    /**
+    * \*\*Required when using multi-line comments\*\*. To create multi-line comments, you must use the `comfort-fade` preview header. The `start_side` is the starting side of the diff that the comment applies to. Can be `LEFT` or `RIGHT`. To learn more about multi-line comments, see "[Commenting on a pull request](https://help.github.com/en/articles/commenting-on-a-pull-request#adding-line-comments-to-a-pull-request)" in the GitHub Help documentation. See `side` in this table for additional context.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_side: Option<PullsCreateReviewCommentRequestStartSide>,
+}
+
+impl PullsCreateReviewCommentRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsCreateReviewCommentRequestBuilder {
+        PullsCreateReviewCommentRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsCreateReviewCommentRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsCreateReviewCommentRequestBuilder(PullsCreateReviewCommentRequest);
+
+impl PullsCreateReviewCommentRequestBuilder {
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.0.commit_id = commit_id.into();
+        self
+    }
+
+    pub fn in_reply_to(mut self, in_reply_to: i64) -> Self {
+        self.0.in_reply_to = in_reply_to;
+        self
+    }
+
+    pub fn line(mut self, line: i64) -> Self {
+        self.0.line = line;
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.0.path = path.into();
+        self
+    }
+
+    pub fn position(mut self, position: i64) -> Self {
+        self.0.position = position;
+        self
+    }
+
+    pub fn side(mut self, side: impl Into<Side>) -> Self {
+        self.0.side = Some(side.into());
+        self
+    }
+
+    pub fn start_line(mut self, start_line: i64) -> Self {
+        self.0.start_line = start_line;
+        self
+    }
+
+    pub fn start_side(
+        mut self,
+        start_side: impl Into<PullsCreateReviewCommentRequestStartSide>,
+    ) -> Self {
+        self.0.start_side = Some(start_side.into());
+        self
+    }
+
+    pub fn build(self) -> PullsCreateReviewCommentRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsMergeRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_method: Option<MergeMethod>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+}
+
+impl PullsMergeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsMergeRequestBuilder {
+        PullsMergeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsMergeRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsMergeRequestBuilder(PullsMergeRequest);
+
+impl PullsMergeRequestBuilder {
+    pub fn commit_message(mut self, commit_message: impl Into<String>) -> Self {
+        self.0.commit_message = commit_message.into();
+        self
+    }
+
+    pub fn commit_title(mut self, commit_title: impl Into<String>) -> Self {
+        self.0.commit_title = commit_title.into();
+        self
+    }
+
+    pub fn merge_method(mut self, merge_method: impl Into<MergeMethod>) -> Self {
+        self.0.merge_method = Some(merge_method.into());
+        self
+    }
+
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.0.sha = sha.into();
+        self
+    }
+
+    pub fn build(self) -> PullsMergeRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsRequestReviewers {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reviewers: Vec<String>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team_reviewers: Vec<String>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsRemoveRequestedReviewersRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reviewers: Vec<String>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team_reviewers: Vec<String>,
+}
+
+impl PullsRemoveRequestedReviewersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsRemoveRequestedReviewersRequestBuilder {
+        PullsRemoveRequestedReviewersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsRemoveRequestedReviewersRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsRemoveRequestedReviewersRequestBuilder(PullsRemoveRequestedReviewersRequest);
+
+impl PullsRemoveRequestedReviewersRequestBuilder {
+    pub fn reviewers(mut self, reviewers: Vec<String>) -> Self {
+        self.0.reviewers = reviewers;
+        self
+    }
+
+    pub fn team_reviewers(mut self, team_reviewers: Vec<String>) -> Self {
+        self.0.team_reviewers = team_reviewers;
+        self
+    }
+
+    pub fn build(self) -> PullsRemoveRequestedReviewersRequest {
+        self.0
+    }
+}
+
+/**
+* The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum PullsCreateReviewRequestEvent {
+    #[serde(rename = "APPROVE")]
+    Approve,
+    #[serde(rename = "COMMENT")]
+    Comment,
+    #[serde(rename = "REQUEST_CHANGES")]
+    RequestChanges,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for PullsCreateReviewRequestEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            PullsCreateReviewRequestEvent::Approve => "APPROVE",
+            PullsCreateReviewRequestEvent::Comment => "COMMENT",
+            PullsCreateReviewRequestEvent::RequestChanges => "REQUEST_CHANGES",
+            PullsCreateReviewRequestEvent::Noop => "",
+            PullsCreateReviewRequestEvent::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for PullsCreateReviewRequestEvent {
+    fn default() -> PullsCreateReviewRequestEvent {
+        PullsCreateReviewRequestEvent::Noop
+    }
+}
+impl PullsCreateReviewRequestEvent {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, PullsCreateReviewRequestEvent::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsCreateReviewRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    /**
+    * Use the following table to specify the location, destination, and contents of the draft review comment.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub comments: Vec<Comments>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    /**
+    * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<PullsCreateReviewRequestEvent>,
+}
+
+impl PullsCreateReviewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsCreateReviewRequestBuilder {
+        PullsCreateReviewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsCreateReviewRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsCreateReviewRequestBuilder(PullsCreateReviewRequest);
+
+impl PullsCreateReviewRequestBuilder {
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn comments(mut self, comments: Vec<Comments>) -> Self {
+        self.0.comments = comments;
+        self
+    }
+
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.0.commit_id = commit_id.into();
+        self
+    }
+
+    pub fn event(mut self, event: impl Into<PullsCreateReviewRequestEvent>) -> Self {
+        self.0.event = Some(event.into());
+        self
+    }
+
+    pub fn build(self) -> PullsCreateReviewRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsDismissReviewRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+impl PullsDismissReviewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsDismissReviewRequestBuilder {
+        PullsDismissReviewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsDismissReviewRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsDismissReviewRequestBuilder(PullsDismissReviewRequest);
+
+impl PullsDismissReviewRequestBuilder {
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        self.0.event = event.into();
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn build(self) -> PullsDismissReviewRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsSubmitReviewRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    /**
+    * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "PullsCreateReviewRequestEvent::is_noop"
+    )]
+    pub event: PullsCreateReviewRequestEvent,
+}
+
+impl PullsSubmitReviewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsSubmitReviewRequestBuilder {
+        PullsSubmitReviewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsSubmitReviewRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsSubmitReviewRequestBuilder(PullsSubmitReviewRequest);
+
+impl PullsSubmitReviewRequestBuilder {
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn event(mut self, event: impl Into<PullsCreateReviewRequestEvent>) -> Self {
+        self.0.event = event.into();
+        self
+    }
+
+    pub fn build(self) -> PullsSubmitReviewRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullsUpdateBranchRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub expected_head_sha: String,
+}
+
+impl std::convert::From<String> for PullsUpdateBranchRequest {
+    fn from(expected_head_sha: String) -> Self {
+        PullsUpdateBranchRequest { expected_head_sha }
+    }
+}
+
+impl std::convert::From<&str> for PullsUpdateBranchRequest {
+    fn from(expected_head_sha: &str) -> Self {
+        PullsUpdateBranchRequest {
+            expected_head_sha: expected_head_sha.to_string(),
+        }
+    }
+}
+
+impl PullsUpdateBranchRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PullsUpdateBranchRequestBuilder {
+        PullsUpdateBranchRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PullsUpdateBranchRequest`.
+#[derive(Debug, Clone)]
+pub struct PullsUpdateBranchRequestBuilder(PullsUpdateBranchRequest);
+
+impl PullsUpdateBranchRequestBuilder {
+    pub fn expected_head_sha(mut self, expected_head_sha: impl Into<String>) -> Self {
+        self.0.expected_head_sha = expected_head_sha.into();
+        self
+    }
+
+    pub fn build(self) -> PullsUpdateBranchRequest {
+        self.0
+    }
+}
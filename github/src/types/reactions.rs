@@ -0,0 +1,157 @@
+//! The data types of the `reactions` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Reactions to conversations provide a way to help people express their feelings more simply and effectively.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Reaction {
+    /**
+    * The reaction to use
+    */
+    #[serde(default, skip_serializing_if = "Content::is_noop")]
+    pub content: Content,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateIssueRequest {
+    /**
+    * The reaction to use
+    */
+    #[serde(default, skip_serializing_if = "Content::is_noop")]
+    pub content: Content,
+}
+
+impl ReactionsCreateIssueRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReactionsCreateIssueRequestBuilder {
+        ReactionsCreateIssueRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReactionsCreateIssueRequest`.
+#[derive(Debug, Clone)]
+pub struct ReactionsCreateIssueRequestBuilder(ReactionsCreateIssueRequest);
+
+impl ReactionsCreateIssueRequestBuilder {
+    pub fn content(mut self, content: impl Into<Content>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+
+    pub fn build(self) -> ReactionsCreateIssueRequest {
+        self.0
+    }
+}
+
+/**
+* The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReactionsCreateReleaseRequestContent {
+    #[serde(rename = "+1")]
+    PlusOne,
+    #[serde(rename = "eyes")]
+    Eyes,
+    #[serde(rename = "heart")]
+    Heart,
+    #[serde(rename = "hooray")]
+    Hooray,
+    #[serde(rename = "laugh")]
+    Laugh,
+    #[serde(rename = "rocket")]
+    Rocket,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReactionsCreateReleaseRequestContent::PlusOne => "+1",
+            ReactionsCreateReleaseRequestContent::Eyes => "eyes",
+            ReactionsCreateReleaseRequestContent::Heart => "heart",
+            ReactionsCreateReleaseRequestContent::Hooray => "hooray",
+            ReactionsCreateReleaseRequestContent::Laugh => "laugh",
+            ReactionsCreateReleaseRequestContent::Rocket => "rocket",
+            ReactionsCreateReleaseRequestContent::Noop => "",
+            ReactionsCreateReleaseRequestContent::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReactionsCreateReleaseRequestContent {
+    fn default() -> ReactionsCreateReleaseRequestContent {
+        ReactionsCreateReleaseRequestContent::Noop
+    }
+}
+impl ReactionsCreateReleaseRequestContent {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ReactionsCreateReleaseRequestContent::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateReleaseRequest {
+    /**
+    * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "ReactionsCreateReleaseRequestContent::is_noop"
+    )]
+    pub content: ReactionsCreateReleaseRequestContent,
+}
+
+impl ReactionsCreateReleaseRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReactionsCreateReleaseRequestBuilder {
+        ReactionsCreateReleaseRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReactionsCreateReleaseRequest`.
+#[derive(Debug, Clone)]
+pub struct ReactionsCreateReleaseRequestBuilder(ReactionsCreateReleaseRequest);
+
+impl ReactionsCreateReleaseRequestBuilder {
+    pub fn content(mut self, content: impl Into<ReactionsCreateReleaseRequestContent>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+
+    pub fn build(self) -> ReactionsCreateReleaseRequest {
+        self.0
+    }
+}
@@ -0,0 +1,6416 @@
+//! The data types of the `repos` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Code of Conduct Simple
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeOfConductSimple {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecurityAnalysis {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advanced_security: Option<SecretScanning>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning: Option<SecretScanning>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Full Repository
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FullRepository {
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_auto_merge: bool,
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_merge_commit: bool,
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_rebase_merge: bool,
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_squash_merge: bool,
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub anonymous_access_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub archive_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub archived: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub assignees_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blobs_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub branches_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub clone_url: String,
+    /**
+    * Full Repository
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_of_conduct: Option<CodeOfConductSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub collaborators_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub comments_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commits_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub compare_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contents_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contributors_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_branch: String,
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub delete_branch_on_merge: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deployments_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub disabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub downloads_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub fork: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub forks: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub forks_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub forks_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub full_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_commits_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_refs_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_tags_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_downloads: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_issues: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_pages: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_projects: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_wiki: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub homepage: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub hooks_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_template: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issue_comment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issue_events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issues_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub keys_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub labels_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub language: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub languages_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<LicenseSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub master_branch: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub merges_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub milestones_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub mirror_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub network_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub notifications_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub open_issues: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub open_issues_count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<SimpleUser>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<SimpleUser>,
+    /**
+    * Full Repository
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Repository>,
+    /**
+    * Full Repository
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<FullRepositoryPermissions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub private: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pulls_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub pushed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub releases_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_and_analysis: Option<SecurityAnalysis>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    /**
+    * Full Repository
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ssh_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub stargazers_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub stargazers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub statuses_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub subscribers_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscribers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscription_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub svn_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tags_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub teams_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub temp_clone_token: String,
+    /**
+    * Full Repository
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_repository: Option<Data>,
+    /**
+    * Full Repository
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub topics: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trees_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The repository visibility: public, private, or internal.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<RepositoryVisibility>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub watchers: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub watchers_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// An autolink reference.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Autolink {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key_prefix: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url_template: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnforceAdmins {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DismissalRestrictions {
+    /**
+    * The list of teams with review dismissal access.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<Team>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub teams_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Protected Branch Pull Request Review
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectedBranchPullRequestReview {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dismiss_stale_reviews: bool,
+    /**
+    * Protected Branch Pull Request Review
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions: Option<DismissalRestrictions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub require_code_owner_reviews: bool,
+    /**
+    * Protected Branch Pull Request Review
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Branch Restriction Policy
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchRestrictionPolicy {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub apps: Vec<Apps>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub apps_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<Teams>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub teams_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<Users>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RequiredStatusChecks {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contexts_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub enforcement_level: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub strict: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AllowDeletions {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enabled: bool,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Branch Protection
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchProtection {
+    /**
+    * Branch Protection
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_deletions: Option<AllowDeletions>,
+    /**
+    * Branch Protection
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_force_pushes: Option<AllowDeletions>,
+    /**
+    * Branch Protection
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enabled: bool,
+    /**
+    * Branch Protection
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_admins: Option<EnforceAdmins>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub protection_url: String,
+    /**
+    * Branch Protection
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_conversation_resolution: Option<AllowDeletions>,
+    /**
+    * Branch Protection
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_linear_history: Option<AllowDeletions>,
+    /**
+    * Branch Protection
+    */
+    #[serde(default)]
+    pub required_pull_request_reviews: Option<ProtectedBranchPullRequestReview>,
+    /**
+    * Branch Protection
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_signatures: Option<EnforceAdmins>,
+    /**
+    * Branch Protection
+    */
+    #[serde(default)]
+    pub required_status_checks: Option<RequiredStatusChecks>,
+    /**
+    * Branch Protection
+    */
+    #[serde(default)]
+    pub restrictions: Option<BranchRestrictionPolicy>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Short Branch
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ShortBranch {
+    #[serde()]
+    pub commit: Tree,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected: bool,
+    /**
+    * Short Branch
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protection: Option<BranchProtection>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub protection_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchWithProtectionLinks {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
+    )]
+    pub self_: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Branch With Protection
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchWithProtection {
+    #[serde(rename = "_links")]
+    pub links: BranchWithProtectionLinks,
+    /**
+    * Commit
+    */
+    #[serde()]
+    pub commit: CommitDataType,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pattern: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected: bool,
+    /**
+    * Branch Protection
+    */
+    #[serde()]
+    pub protection: BranchProtection,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub protection_url: String,
+    /**
+    * Branch With Protection
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Status Check Policy
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StatusCheckPolicy {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contexts_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub strict: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectedBranchRequiredPullRequestReviewsDismissalRestrictions {
+    /**
+    * The list of teams with review dismissal access.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<Team>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub teams_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RequiredPullRequestReviews {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dismiss_stale_reviews: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions:
+        Option<ProtectedBranchRequiredPullRequestReviewsDismissalRestrictions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub require_code_owner_reviews: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectedBranchRequiredLinearHistory {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enabled: bool,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Branch protections protect branches
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectedBranch {
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_deletions: Option<ProtectedBranchRequiredLinearHistory>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_force_pushes: Option<ProtectedBranchRequiredLinearHistory>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_admins: Option<EnforceAdmins>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_conversation_resolution: Option<AllowDeletions>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_linear_history: Option<ProtectedBranchRequiredLinearHistory>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default)]
+    pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_signatures: Option<EnforceAdmins>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default)]
+    pub required_status_checks: Option<StatusCheckPolicy>,
+    /**
+    * Branch protections protect branches
+    */
+    #[serde(default)]
+    pub restrictions: Option<BranchRestrictionPolicy>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* The permission associated with the invitation.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum RepositoryInvitationPermissions {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "maintain")]
+    Maintain,
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "triage")]
+    Triage,
+    #[serde(rename = "write")]
+    Write,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for RepositoryInvitationPermissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            RepositoryInvitationPermissions::Admin => "admin",
+            RepositoryInvitationPermissions::Maintain => "maintain",
+            RepositoryInvitationPermissions::Read => "read",
+            RepositoryInvitationPermissions::Triage => "triage",
+            RepositoryInvitationPermissions::Write => "write",
+            RepositoryInvitationPermissions::Noop => "",
+            RepositoryInvitationPermissions::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for RepositoryInvitationPermissions {
+    fn default() -> RepositoryInvitationPermissions {
+        RepositoryInvitationPermissions::Noop
+    }
+}
+impl RepositoryInvitationPermissions {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, RepositoryInvitationPermissions::Noop)
+    }
+}
+
+/// Repository invitations let you manage who you collaborate with.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RepositoryInvitation {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * Repository invitations let you manage who you collaborate with.
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub expired: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invitee: Option<SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * The permission associated with the invitation.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "RepositoryInvitationPermissions::is_noop"
+    )]
+    pub permissions: RepositoryInvitationPermissions,
+    /**
+    * Minimal Repository
+    */
+    #[serde()]
+    pub repository: MinimalRepository,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Branch Short
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchShort {
+    #[serde()]
+    pub commit: Tree,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected: bool,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SimpleCommitStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub context: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub required: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Combined Commit Status
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CombinedCommitStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    /**
+    * Minimal Repository
+    */
+    #[serde()]
+    pub repository: MinimalRepository,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub statuses: Vec<SimpleCommitStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// The status of a commit.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StatusData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub context: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub updated_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommunityHealthFile {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommunityProfileFiles {
+    /**
+    * Code of Conduct Simple
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_of_conduct: Option<CodeOfConductSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_of_conduct_file: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contributing: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_template: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<LicenseSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_template: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub readme: Option<CommunityHealthFile>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Community Profile
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommunityProfile {
+    /**
+    * Community Profile
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub content_reports_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation: String,
+    #[serde()]
+    pub files: CommunityProfileFiles,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub health_percentage: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum CommitComparisonStatus {
+    #[serde(rename = "ahead")]
+    Ahead,
+    #[serde(rename = "behind")]
+    Behind,
+    #[serde(rename = "diverged")]
+    Diverged,
+    #[serde(rename = "identical")]
+    Identical,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for CommitComparisonStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            CommitComparisonStatus::Ahead => "ahead",
+            CommitComparisonStatus::Behind => "behind",
+            CommitComparisonStatus::Diverged => "diverged",
+            CommitComparisonStatus::Identical => "identical",
+            CommitComparisonStatus::Noop => "",
+            CommitComparisonStatus::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for CommitComparisonStatus {
+    fn default() -> CommitComparisonStatus {
+        CommitComparisonStatus::Noop
+    }
+}
+impl CommitComparisonStatus {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, CommitComparisonStatus::Noop)
+    }
+}
+
+/// Commit Comparison
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommitComparison {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub ahead_by: i64,
+    /**
+    * Commit
+    */
+    #[serde()]
+    pub base_commit: CommitDataType,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub behind_by: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub commits: Vec<CommitDataType>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub diff_url: String,
+    /**
+    * Commit Comparison
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub files: Vec<DiffEntry>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    /**
+    * Commit
+    */
+    #[serde()]
+    pub merge_base_commit: CommitDataType,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub patch_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub permalink_url: String,
+    #[serde(default, skip_serializing_if = "CommitComparisonStatus::is_noop")]
+    pub status: CommitComparisonStatus,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_commits: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Content File
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentFile {
+    #[serde(rename = "_links")]
+    pub links: ContentTreeEntriesLinks,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub encoding: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub submodule_git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// An object describing a symlink
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SymlinkContent {
+    #[serde(rename = "_links")]
+    pub links: ContentTreeEntriesLinks,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// An object describing a symlink
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentSubmodule {
+    #[serde(rename = "_links")]
+    pub links: ContentTreeEntriesLinks,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub submodule_git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitContent {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
+    pub links: Option<ContentTreeEntriesLinks>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitVerification {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payload: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub signature: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified: bool,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommit {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<Tagger>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<Tagger>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub parents: Vec<Parents>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tree: Option<Tree>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<FileCommitVerification>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// File Commit
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitData {
+    #[serde()]
+    pub commit: FileCommit,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<FileCommitContent>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* The state of the status.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum DeploymentStatusState {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "inactive")]
+    Inactive,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "success")]
+    Success,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for DeploymentStatusState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            DeploymentStatusState::Error => "error",
+            DeploymentStatusState::Failure => "failure",
+            DeploymentStatusState::InProgress => "in_progress",
+            DeploymentStatusState::Inactive => "inactive",
+            DeploymentStatusState::Pending => "pending",
+            DeploymentStatusState::Queued => "queued",
+            DeploymentStatusState::Success => "success",
+            DeploymentStatusState::Noop => "",
+            DeploymentStatusState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for DeploymentStatusState {
+    fn default() -> DeploymentStatusState {
+        DeploymentStatusState::Noop
+    }
+}
+impl DeploymentStatusState {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, DeploymentStatusState::Noop)
+    }
+}
+
+/// The status of a deployment.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeploymentStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deployment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub log_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<GitHubApp>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_url: String,
+    /**
+    * The state of the status.
+    */
+    #[serde(default, skip_serializing_if = "DeploymentStatusState::is_noop")]
+    pub state: DeploymentStatusState,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeploymentBranchPolicy {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub custom_branch_policies: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected_branches: bool,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectionRules {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub wait_timer: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectionRulesData {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * The people or teams that may approve jobs that reference the environment. You can list up to six users or teams as reviewers. The reviewers must have at least read access to the repository. Only one of the required reviewers needs to approve the job for it to proceed.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reviewers: Vec<Reviewers>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectionRulesDataType {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `ProtectionRules`
+/// - `ProtectionRulesData`
+/// - `ProtectionRulesDataType`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectionRulesAnyOf {
+    #[serde(flatten)]
+    pub protection_rules: ProtectionRules,
+    #[serde(flatten)]
+    pub protection_rules_data: ProtectionRulesData,
+    #[serde(flatten)]
+    pub protection_rules_data_type: ProtectionRulesDataType,
+}
+
+/// Details of a deployment environment
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvironmentData {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+    * Details of a deployment environment
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub protection_rules: Vec<ProtectionRulesAnyOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// An SSH key granting access to a single repository.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeployKey {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub read_only: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified: bool,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Page Build
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PageBuild {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub duration: i64,
+    #[serde()]
+    pub error: Error,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pusher: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Page Build Status
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PageBuildStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Domain {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub caa_error: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dns_resolves: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enforces_https: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_cname_record: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_mx_records_present: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub host: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub https_error: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_a_record: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_apex_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cloudflare_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_fastly: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_github_user_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_pages_dot_github_dot_com: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_fastly_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_https_eligible: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_non_github_pages_ip_present: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_old_ip_address: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_pages_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_pointed_to_github_pages_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_proxied: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_served_by_pages: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_valid: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_valid_domain: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub nameservers: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub responds_to_https: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub should_be_a_record: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uri: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AltDomain {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub caa_error: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dns_resolves: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enforces_https: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_cname_record: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_mx_records_present: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub host: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub https_error: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_a_record: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_apex_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cloudflare_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_fastly: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_github_user_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_pages_dot_github_dot_com: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_fastly_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_https_eligible: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_non_github_pages_ip_present: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_old_ip_address: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_pages_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_pointed_to_github_pages_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_proxied: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_served_by_pages: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_valid: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_valid_domain: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub nameservers: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub responds_to_https: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub should_be_a_record: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uri: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Pages Health Check Status
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PagesHealthCheck {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt_domain: Option<AltDomain>,
+    /**
+    * Pages Health Check Status
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<Domain>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Commit Activity
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommitActivity {
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub days: Vec<i64>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub week: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Weeks {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub a: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub c: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub d: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub w: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Contributor Activity
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContributorActivity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub weeks: Vec<Weeks>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ParticipationStats {
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub all: Vec<i64>,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub owner: Vec<i64>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Tag
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Tag {
+    #[serde()]
+    pub commit: Tree,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tarball_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub zipball_url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Traffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Clone Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CloneTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub clones: Vec<Traffic>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Content Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Referrer Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReferrerTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub referrer: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// View Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ViewTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub views: Vec<Traffic>,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/**
+* Must be one of: `day`, `week`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum Per {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "week")]
+    Week,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Per {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Per::Day => "day",
+            Per::Week => "week",
+            Per::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Per {
+    fn default() -> Per {
+        Per::Day
+    }
+}
+
+/**
+* Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposListOrgType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "forks")]
+    Forks,
+    #[serde(rename = "internal")]
+    Internal,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(rename = "sources")]
+    Sources,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposListOrgType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposListOrgType::All => "all",
+            ReposListOrgType::Forks => "forks",
+            ReposListOrgType::Internal => "internal",
+            ReposListOrgType::Member => "member",
+            ReposListOrgType::Private => "private",
+            ReposListOrgType::Public => "public",
+            ReposListOrgType::Sources => "sources",
+            ReposListOrgType::Noop => "",
+            ReposListOrgType::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposListOrgType {
+    fn default() -> ReposListOrgType {
+        ReposListOrgType::Noop
+    }
+}
+impl ReposListOrgType {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ReposListOrgType::Noop)
+    }
+}
+
+/**
+* Can be one of `created`, `updated`, `pushed`, `full_name`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposListOrgSort {
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "full_name")]
+    FullName,
+    #[serde(rename = "pushed")]
+    Pushed,
+    #[serde(rename = "updated")]
+    Updated,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposListOrgSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposListOrgSort::Created => "created",
+            ReposListOrgSort::FullName => "full_name",
+            ReposListOrgSort::Pushed => "pushed",
+            ReposListOrgSort::Updated => "updated",
+            ReposListOrgSort::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposListOrgSort {
+    fn default() -> ReposListOrgSort {
+        ReposListOrgSort::Created
+    }
+}
+
+/**
+* Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
+*   The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposCreateInOrgRequestVisibility {
+    #[serde(rename = "internal")]
+    Internal,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(rename = "visibility")]
+    Visibility,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposCreateInOrgRequestVisibility::Internal => "internal",
+            ReposCreateInOrgRequestVisibility::Private => "private",
+            ReposCreateInOrgRequestVisibility::Public => "public",
+            ReposCreateInOrgRequestVisibility::Visibility => "visibility",
+            ReposCreateInOrgRequestVisibility::Noop => "",
+            ReposCreateInOrgRequestVisibility::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposCreateInOrgRequestVisibility {
+    fn default() -> ReposCreateInOrgRequestVisibility {
+        ReposCreateInOrgRequestVisibility::Noop
+    }
+}
+impl ReposCreateInOrgRequestVisibility {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ReposCreateInOrgRequestVisibility::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateInOrgRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_init: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gitignore_template: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub homepage: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub license_template: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub team_id: i64,
+    /**
+    * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
+    *  The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ReposCreateInOrgRequestVisibility>,
+}
+
+impl ReposCreateInOrgRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateInOrgRequestBuilder {
+        ReposCreateInOrgRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateInOrgRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateInOrgRequestBuilder(ReposCreateInOrgRequest);
+
+impl ReposCreateInOrgRequestBuilder {
+    pub fn allow_auto_merge(mut self, allow_auto_merge: bool) -> Self {
+        self.0.allow_auto_merge = Some(allow_auto_merge);
+        self
+    }
+
+    pub fn allow_merge_commit(mut self, allow_merge_commit: bool) -> Self {
+        self.0.allow_merge_commit = Some(allow_merge_commit);
+        self
+    }
+
+    pub fn allow_rebase_merge(mut self, allow_rebase_merge: bool) -> Self {
+        self.0.allow_rebase_merge = Some(allow_rebase_merge);
+        self
+    }
+
+    pub fn allow_squash_merge(mut self, allow_squash_merge: bool) -> Self {
+        self.0.allow_squash_merge = Some(allow_squash_merge);
+        self
+    }
+
+    pub fn auto_init(mut self, auto_init: bool) -> Self {
+        self.0.auto_init = Some(auto_init);
+        self
+    }
+
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: bool) -> Self {
+        self.0.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn gitignore_template(mut self, gitignore_template: impl Into<String>) -> Self {
+        self.0.gitignore_template = gitignore_template.into();
+        self
+    }
+
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.0.has_issues = Some(has_issues);
+        self
+    }
+
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.0.has_projects = Some(has_projects);
+        self
+    }
+
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.0.has_wiki = Some(has_wiki);
+        self
+    }
+
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.0.homepage = homepage.into();
+        self
+    }
+
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.0.is_template = Some(is_template);
+        self
+    }
+
+    pub fn license_template(mut self, license_template: impl Into<String>) -> Self {
+        self.0.license_template = license_template.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.0.private = Some(private);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: i64) -> Self {
+        self.0.team_id = team_id;
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<ReposCreateInOrgRequestVisibility>) -> Self {
+        self.0.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn build(self) -> ReposCreateInOrgRequest {
+        self.0
+    }
+}
+
+/// Use the `status` property to enable or disable GitHub Advanced Security for this repository. For more information, see "[About GitHub Advanced Security](/github/getting-started-with-github/learning-about-github/about-github-advanced-security)."
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateRequestSecurityAnalysisAdvanced {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+}
+
+/// Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateRequestSecurityAnalysis {
+    /**
+    * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advanced_security: Option<ReposUpdateRequestSecurityAnalysisAdvanced>,
+    /**
+    * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning: Option<ReposUpdateRequestSecurityAnalysisAdvanced>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_branch: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub homepage: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    /**
+    * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+    */
+    #[serde(default, skip_serializing_if = "crate::utils::Nullable::is_unset")]
+    pub security_and_analysis: crate::utils::Nullable<ReposUpdateRequestSecurityAnalysis>,
+    /**
+    * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
+    *  The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ReposCreateInOrgRequestVisibility>,
+}
+
+impl ReposUpdateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposUpdateRequestBuilder {
+        ReposUpdateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposUpdateRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposUpdateRequestBuilder(ReposUpdateRequest);
+
+impl ReposUpdateRequestBuilder {
+    pub fn allow_auto_merge(mut self, allow_auto_merge: bool) -> Self {
+        self.0.allow_auto_merge = Some(allow_auto_merge);
+        self
+    }
+
+    pub fn allow_merge_commit(mut self, allow_merge_commit: bool) -> Self {
+        self.0.allow_merge_commit = Some(allow_merge_commit);
+        self
+    }
+
+    pub fn allow_rebase_merge(mut self, allow_rebase_merge: bool) -> Self {
+        self.0.allow_rebase_merge = Some(allow_rebase_merge);
+        self
+    }
+
+    pub fn allow_squash_merge(mut self, allow_squash_merge: bool) -> Self {
+        self.0.allow_squash_merge = Some(allow_squash_merge);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.0.archived = Some(archived);
+        self
+    }
+
+    pub fn default_branch(mut self, default_branch: impl Into<String>) -> Self {
+        self.0.default_branch = default_branch.into();
+        self
+    }
+
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: bool) -> Self {
+        self.0.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.0.has_issues = Some(has_issues);
+        self
+    }
+
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.0.has_projects = Some(has_projects);
+        self
+    }
+
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.0.has_wiki = Some(has_wiki);
+        self
+    }
+
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.0.homepage = homepage.into();
+        self
+    }
+
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.0.is_template = Some(is_template);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.0.private = Some(private);
+        self
+    }
+
+    pub fn security_and_analysis(
+        mut self,
+        security_and_analysis: impl Into<crate::utils::Nullable<ReposUpdateRequestSecurityAnalysis>>,
+    ) -> Self {
+        self.0.security_and_analysis = security_and_analysis.into();
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<ReposCreateInOrgRequestVisibility>) -> Self {
+        self.0.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn build(self) -> ReposUpdateRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateAutolinkRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key_prefix: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url_template: String,
+}
+
+impl ReposCreateAutolinkRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateAutolinkRequestBuilder {
+        ReposCreateAutolinkRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateAutolinkRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateAutolinkRequestBuilder(ReposCreateAutolinkRequest);
+
+impl ReposCreateAutolinkRequestBuilder {
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.0.key_prefix = key_prefix.into();
+        self
+    }
+
+    pub fn url_template(mut self, url_template: impl Into<String>) -> Self {
+        self.0.url_template = url_template.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateAutolinkRequest {
+        self.0
+    }
+}
+
+/// Require status checks to pass before merging. Set to `null` to disable.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateBranchProtectionRequestRequiredStatusChecks {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub strict: bool,
+}
+
+/// Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions {
+    /**
+    * Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<String>,
+    /**
+    * Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<String>,
+}
+
+/// Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateBranchProtectionRequestRequiredPullReviews {
+    /**
+    * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dismiss_stale_reviews: bool,
+    /**
+    * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions:
+        Option<ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions>,
+    /**
+    * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+    */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub require_code_owner_reviews: bool,
+    /**
+    * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+}
+
+/// Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Restrictions {
+    /**
+    * Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub apps: Vec<String>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<String>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<String>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateBranchProtectionRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_deletions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_force_pushes: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_admins: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_conversation_resolution: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_linear_history: Option<bool>,
+    /**
+    * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+    */
+    #[serde(default)]
+    pub required_pull_request_reviews:
+        Option<ReposUpdateBranchProtectionRequestRequiredPullReviews>,
+    /**
+    * Require status checks to pass before merging. Set to `null` to disable.
+    */
+    #[serde(default)]
+    pub required_status_checks: Option<ReposUpdateBranchProtectionRequestRequiredStatusChecks>,
+    /**
+    * Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
+    */
+    #[serde(default)]
+    pub restrictions: Option<Restrictions>,
+}
+
+impl ReposUpdateBranchProtectionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposUpdateBranchProtectionRequestBuilder {
+        ReposUpdateBranchProtectionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposUpdateBranchProtectionRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposUpdateBranchProtectionRequestBuilder(ReposUpdateBranchProtectionRequest);
+
+impl ReposUpdateBranchProtectionRequestBuilder {
+    pub fn allow_deletions(mut self, allow_deletions: bool) -> Self {
+        self.0.allow_deletions = Some(allow_deletions);
+        self
+    }
+
+    pub fn allow_force_pushes(mut self, allow_force_pushes: bool) -> Self {
+        self.0.allow_force_pushes = Some(allow_force_pushes);
+        self
+    }
+
+    pub fn enforce_admins(mut self, enforce_admins: bool) -> Self {
+        self.0.enforce_admins = Some(enforce_admins);
+        self
+    }
+
+    pub fn required_conversation_resolution(
+        mut self,
+        required_conversation_resolution: bool,
+    ) -> Self {
+        self.0.required_conversation_resolution = Some(required_conversation_resolution);
+        self
+    }
+
+    pub fn required_linear_history(mut self, required_linear_history: bool) -> Self {
+        self.0.required_linear_history = Some(required_linear_history);
+        self
+    }
+
+    pub fn required_pull_request_reviews(
+        mut self,
+        required_pull_request_reviews: impl Into<ReposUpdateBranchProtectionRequestRequiredPullReviews>,
+    ) -> Self {
+        self.0.required_pull_request_reviews = Some(required_pull_request_reviews.into());
+        self
+    }
+
+    pub fn required_status_checks(
+        mut self,
+        required_status_checks: impl Into<ReposUpdateBranchProtectionRequestRequiredStatusChecks>,
+    ) -> Self {
+        self.0.required_status_checks = Some(required_status_checks.into());
+        self
+    }
+
+    pub fn restrictions(mut self, restrictions: impl Into<Restrictions>) -> Self {
+        self.0.restrictions = Some(restrictions.into());
+        self
+    }
+
+    pub fn build(self) -> ReposUpdateBranchProtectionRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdatePullRequestReviewProtection {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dismiss_stale_reviews: bool,
+    /**
+    * Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions:
+        Option<ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub require_code_owner_reviews: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateStatusCheckProtectionRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+impl ReposUpdateStatusCheckProtectionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposUpdateStatusCheckProtectionRequestBuilder {
+        ReposUpdateStatusCheckProtectionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposUpdateStatusCheckProtectionRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposUpdateStatusCheckProtectionRequestBuilder(ReposUpdateStatusCheckProtectionRequest);
+
+impl ReposUpdateStatusCheckProtectionRequestBuilder {
+    pub fn contexts(mut self, contexts: Vec<String>) -> Self {
+        self.0.contexts = contexts;
+        self
+    }
+
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.0.strict = Some(strict);
+        self
+    }
+
+    pub fn build(self) -> ReposUpdateStatusCheckProtectionRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddStatusCheckContextsRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+}
+
+impl ReposAddStatusCheckContextsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposAddStatusCheckContextsRequestBuilder {
+        ReposAddStatusCheckContextsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposAddStatusCheckContextsRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposAddStatusCheckContextsRequestBuilder(ReposAddStatusCheckContextsRequest);
+
+impl ReposAddStatusCheckContextsRequestBuilder {
+    pub fn contexts(mut self, contexts: Vec<String>) -> Self {
+        self.0.contexts = contexts;
+        self
+    }
+
+    pub fn build(self) -> ReposAddStatusCheckContextsRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddStatusCheckContextsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddStatusCheckContextsRequestOneOf {
+    /**
+    * The list of events for the GitHub app
+    */
+    StringVector(Vec<String>),
+    ReposAddStatusCheckContextsRequest(ReposAddStatusCheckContextsRequest),
+}
+
+impl ReposAddStatusCheckContextsRequestOneOf {
+    pub fn repos_add_status_check_contexts_request(
+        &self,
+    ) -> Option<&ReposAddStatusCheckContextsRequest> {
+        if let ReposAddStatusCheckContextsRequestOneOf::ReposAddStatusCheckContextsRequest(ref_) =
+            self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddStatusCheckContextsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddStatusCheckContextsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddStatusCheckContextsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddStatusCheckContextsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddStatusCheckContextsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddAppAccessRestrictionsRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub apps: Vec<String>,
+}
+
+impl ReposAddAppAccessRestrictionsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposAddAppAccessRestrictionsRequestBuilder {
+        ReposAddAppAccessRestrictionsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposAddAppAccessRestrictionsRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposAddAppAccessRestrictionsRequestBuilder(ReposAddAppAccessRestrictionsRequest);
+
+impl ReposAddAppAccessRestrictionsRequestBuilder {
+    pub fn apps(mut self, apps: Vec<String>) -> Self {
+        self.0.apps = apps;
+        self
+    }
+
+    pub fn build(self) -> ReposAddAppAccessRestrictionsRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddAppAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddAppAccessRestrictionsRequestOneOf {
+    /**
+    * The list of events for the GitHub app
+    */
+    StringVector(Vec<String>),
+    ReposAddAppAccessRestrictionsRequest(ReposAddAppAccessRestrictionsRequest),
+}
+
+impl ReposAddAppAccessRestrictionsRequestOneOf {
+    pub fn repos_add_app_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposAddAppAccessRestrictionsRequest> {
+        if let ReposAddAppAccessRestrictionsRequestOneOf::ReposAddAppAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddAppAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddAppAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddAppAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddAppAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddAppAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddTeamAccessRestrictionsRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<String>,
+}
+
+impl ReposAddTeamAccessRestrictionsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposAddTeamAccessRestrictionsRequestBuilder {
+        ReposAddTeamAccessRestrictionsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposAddTeamAccessRestrictionsRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposAddTeamAccessRestrictionsRequestBuilder(ReposAddTeamAccessRestrictionsRequest);
+
+impl ReposAddTeamAccessRestrictionsRequestBuilder {
+    pub fn teams(mut self, teams: Vec<String>) -> Self {
+        self.0.teams = teams;
+        self
+    }
+
+    pub fn build(self) -> ReposAddTeamAccessRestrictionsRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddTeamAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddTeamAccessRestrictionsRequestOneOf {
+    /**
+    * The list of events for the GitHub app
+    */
+    StringVector(Vec<String>),
+    ReposAddTeamAccessRestrictionsRequest(ReposAddTeamAccessRestrictionsRequest),
+}
+
+impl ReposAddTeamAccessRestrictionsRequestOneOf {
+    pub fn repos_add_team_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposAddTeamAccessRestrictionsRequest> {
+        if let ReposAddTeamAccessRestrictionsRequestOneOf::ReposAddTeamAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddTeamAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddTeamAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddTeamAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddTeamAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddTeamAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddUserAccessRestrictionsRequest {
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<String>,
+}
+
+impl ReposAddUserAccessRestrictionsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposAddUserAccessRestrictionsRequestBuilder {
+        ReposAddUserAccessRestrictionsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposAddUserAccessRestrictionsRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposAddUserAccessRestrictionsRequestBuilder(ReposAddUserAccessRestrictionsRequest);
+
+impl ReposAddUserAccessRestrictionsRequestBuilder {
+    pub fn users(mut self, users: Vec<String>) -> Self {
+        self.0.users = users;
+        self
+    }
+
+    pub fn build(self) -> ReposAddUserAccessRestrictionsRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddUserAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddUserAccessRestrictionsRequestOneOf {
+    /**
+    * The list of events for the GitHub app
+    */
+    StringVector(Vec<String>),
+    ReposAddUserAccessRestrictionsRequest(ReposAddUserAccessRestrictionsRequest),
+}
+
+impl ReposAddUserAccessRestrictionsRequestOneOf {
+    pub fn repos_add_user_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposAddUserAccessRestrictionsRequest> {
+        if let ReposAddUserAccessRestrictionsRequestOneOf::ReposAddUserAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddUserAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddUserAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddUserAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddUserAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddUserAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposRenameBranchRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub new_name: String,
+}
+
+impl std::convert::From<String> for ReposRenameBranchRequest {
+    fn from(new_name: String) -> Self {
+        ReposRenameBranchRequest { new_name }
+    }
+}
+
+impl std::convert::From<&str> for ReposRenameBranchRequest {
+    fn from(new_name: &str) -> Self {
+        ReposRenameBranchRequest {
+            new_name: new_name.to_string(),
+        }
+    }
+}
+
+impl ReposRenameBranchRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposRenameBranchRequestBuilder {
+        ReposRenameBranchRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposRenameBranchRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposRenameBranchRequestBuilder(ReposRenameBranchRequest);
+
+impl ReposRenameBranchRequestBuilder {
+    pub fn new_name(mut self, new_name: impl Into<String>) -> Self {
+        self.0.new_name = new_name.into();
+        self
+    }
+
+    pub fn build(self) -> ReposRenameBranchRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddCollaboratorRequest {
+    /**
+    * The permission to grant the team on this repository. Can be one of:  
+    *  \\* `pull` - team members can pull, but not push to or administer this repository.  
+    *  \\* `push` - team members can pull and push, but not administer this repository.  
+    *  \\* `admin` - team members can pull, push and administer this repository.  
+    *  \\* `maintain` - team members can manage the repository without access to sensitive or destructive actions. Recommended for project managers. Only applies to repositories owned by organizations.  
+    *  \\* `triage` - team members can proactively manage issues and pull requests without write access. Recommended for contributors who triage a repository. Only applies to repositories owned by organizations.  
+    *    
+    *  If no permission is specified, the team's `permission` attribute will be used to determine what permission to grant the team on this repository.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission: Option<TeamsAddUpdateRepoPermissionsInOrgRequestPermission>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub permissions: String,
+}
+
+impl ReposAddCollaboratorRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposAddCollaboratorRequestBuilder {
+        ReposAddCollaboratorRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposAddCollaboratorRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposAddCollaboratorRequestBuilder(ReposAddCollaboratorRequest);
+
+impl ReposAddCollaboratorRequestBuilder {
+    pub fn permission(
+        mut self,
+        permission: impl Into<TeamsAddUpdateRepoPermissionsInOrgRequestPermission>,
+    ) -> Self {
+        self.0.permission = Some(permission.into());
+        self
+    }
+
+    pub fn permissions(mut self, permissions: impl Into<String>) -> Self {
+        self.0.permissions = permissions.into();
+        self
+    }
+
+    pub fn build(self) -> ReposAddCollaboratorRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateCommitCommentRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub position: i64,
+}
+
+impl ReposCreateCommitCommentRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateCommitCommentRequestBuilder {
+        ReposCreateCommitCommentRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateCommitCommentRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateCommitCommentRequestBuilder(ReposCreateCommitCommentRequest);
+
+impl ReposCreateCommitCommentRequestBuilder {
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.0.body = body.into();
+        self
+    }
+
+    pub fn line(mut self, line: i64) -> Self {
+        self.0.line = line;
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.0.path = path.into();
+        self
+    }
+
+    pub fn position(mut self, position: i64) -> Self {
+        self.0.position = position;
+        self
+    }
+
+    pub fn build(self) -> ReposCreateCommitCommentRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `Vec<Entries>`
+/// - `ContentFile`
+/// - `SymlinkContent`
+/// - `ContentSubmodule`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposGetContentResponseOneOf {
+    EntriesVector(Vec<Entries>),
+    /**
+    * Content File
+    */
+    ContentFile(ContentFile),
+    /**
+    * An object describing a symlink
+    */
+    SymlinkContent(SymlinkContent),
+    /**
+    * An object describing a symlink
+    */
+    ContentSubmodule(ContentSubmodule),
+}
+
+impl ReposGetContentResponseOneOf {
+    pub fn content_file(&self) -> Option<&ContentFile> {
+        if let ReposGetContentResponseOneOf::ContentFile(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn content_submodule(&self) -> Option<&ContentSubmodule> {
+        if let ReposGetContentResponseOneOf::ContentSubmodule(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_entries(&self) -> Option<&Vec<Entries>> {
+        if let ReposGetContentResponseOneOf::EntriesVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn symlink_content(&self) -> Option<&SymlinkContent> {
+        if let ReposGetContentResponseOneOf::SymlinkContent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<Entries>> for ReposGetContentResponseOneOf {
+    fn from(f: Vec<Entries>) -> Self {
+        ReposGetContentResponseOneOf::EntriesVector(f)
+    }
+}
+
+impl std::convert::From<ReposGetContentResponseOneOf> for Vec<Entries> {
+    fn from(f: ReposGetContentResponseOneOf) -> Self {
+        f.vec_entries().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUpdateFileContentsRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<Tagger>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub branch: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<Tagger>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+}
+
+impl ReposCreateUpdateFileContentsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateUpdateFileContentsRequestBuilder {
+        ReposCreateUpdateFileContentsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateUpdateFileContentsRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateUpdateFileContentsRequestBuilder(ReposCreateUpdateFileContentsRequest);
+
+impl ReposCreateUpdateFileContentsRequestBuilder {
+    pub fn author(mut self, author: impl Into<Tagger>) -> Self {
+        self.0.author = Some(author.into());
+        self
+    }
+
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.0.branch = branch.into();
+        self
+    }
+
+    pub fn committer(mut self, committer: impl Into<Tagger>) -> Self {
+        self.0.committer = Some(committer.into());
+        self
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.0.sha = sha.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateUpdateFileContentsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposDeleteFileRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<Author>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub branch: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<Author>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+}
+
+impl ReposDeleteFileRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposDeleteFileRequestBuilder {
+        ReposDeleteFileRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposDeleteFileRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposDeleteFileRequestBuilder(ReposDeleteFileRequest);
+
+impl ReposDeleteFileRequestBuilder {
+    pub fn author(mut self, author: impl Into<Author>) -> Self {
+        self.0.author = Some(author.into());
+        self
+    }
+
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.0.branch = branch.into();
+        self
+    }
+
+    pub fn committer(mut self, committer: impl Into<Author>) -> Self {
+        self.0.committer = Some(committer.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.0.sha = sha.into();
+        self
+    }
+
+    pub fn build(self) -> ReposDeleteFileRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDeploymentRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_merge: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<PayloadOneOf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub production_environment: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub required_contexts: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub task: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transient_environment: Option<bool>,
+}
+
+impl ReposCreateDeploymentRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateDeploymentRequestBuilder {
+        ReposCreateDeploymentRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateDeploymentRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateDeploymentRequestBuilder(ReposCreateDeploymentRequest);
+
+impl ReposCreateDeploymentRequestBuilder {
+    pub fn auto_merge(mut self, auto_merge: bool) -> Self {
+        self.0.auto_merge = Some(auto_merge);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.0.environment = environment.into();
+        self
+    }
+
+    pub fn payload(mut self, payload: impl Into<PayloadOneOf>) -> Self {
+        self.0.payload = Some(payload.into());
+        self
+    }
+
+    pub fn production_environment(mut self, production_environment: bool) -> Self {
+        self.0.production_environment = Some(production_environment);
+        self
+    }
+
+    pub fn ref_(mut self, ref_: impl Into<String>) -> Self {
+        self.0.ref_ = ref_.into();
+        self
+    }
+
+    pub fn required_contexts(mut self, required_contexts: Vec<String>) -> Self {
+        self.0.required_contexts = required_contexts;
+        self
+    }
+
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.0.task = task.into();
+        self
+    }
+
+    pub fn transient_environment(mut self, transient_environment: bool) -> Self {
+        self.0.transient_environment = Some(transient_environment);
+        self
+    }
+
+    pub fn build(self) -> ReposCreateDeploymentRequest {
+        self.0
+    }
+}
+
+/**
+* Name for the target deployment environment, which can be changed when setting a deploy status. For example, `production`, `staging`, or `qa`. **Note:** This parameter requires you to use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposCreateDeploymentStatusRequestEnvironment {
+    #[serde(rename = "production")]
+    Production,
+    #[serde(rename = "qa")]
+    Qa,
+    #[serde(rename = "staging")]
+    Staging,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposCreateDeploymentStatusRequestEnvironment::Production => "production",
+            ReposCreateDeploymentStatusRequestEnvironment::Qa => "qa",
+            ReposCreateDeploymentStatusRequestEnvironment::Staging => "staging",
+            ReposCreateDeploymentStatusRequestEnvironment::Noop => "",
+            ReposCreateDeploymentStatusRequestEnvironment::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposCreateDeploymentStatusRequestEnvironment {
+    fn default() -> ReposCreateDeploymentStatusRequestEnvironment {
+        ReposCreateDeploymentStatusRequestEnvironment::Noop
+    }
+}
+impl ReposCreateDeploymentStatusRequestEnvironment {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ReposCreateDeploymentStatusRequestEnvironment::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDeploymentStatusRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_inactive: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+    * Name for the target deployment environment, which can be changed when setting a deploy status. For example, `production`, `staging`, or `qa`. \*\*Note:\*\* This parameter requires you to use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<ReposCreateDeploymentStatusRequestEnvironment>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub log_url: String,
+    /**
+    * The state of the status.
+    */
+    #[serde(default, skip_serializing_if = "DeploymentStatusState::is_noop")]
+    pub state: DeploymentStatusState,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+}
+
+impl ReposCreateDeploymentStatusRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateDeploymentStatusRequestBuilder {
+        ReposCreateDeploymentStatusRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateDeploymentStatusRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateDeploymentStatusRequestBuilder(ReposCreateDeploymentStatusRequest);
+
+impl ReposCreateDeploymentStatusRequestBuilder {
+    pub fn auto_inactive(mut self, auto_inactive: bool) -> Self {
+        self.0.auto_inactive = Some(auto_inactive);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn environment(
+        mut self,
+        environment: impl Into<ReposCreateDeploymentStatusRequestEnvironment>,
+    ) -> Self {
+        self.0.environment = Some(environment.into());
+        self
+    }
+
+    pub fn environment_url(mut self, environment_url: impl Into<String>) -> Self {
+        self.0.environment_url = environment_url.into();
+        self
+    }
+
+    pub fn log_url(mut self, log_url: impl Into<String>) -> Self {
+        self.0.log_url = log_url.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<DeploymentStatusState>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn target_url(mut self, target_url: impl Into<String>) -> Self {
+        self.0.target_url = target_url.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateDeploymentStatusRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDispatchEventRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_payload: Option<Data>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_type: String,
+}
+
+impl ReposCreateDispatchEventRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateDispatchEventRequestBuilder {
+        ReposCreateDispatchEventRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateDispatchEventRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateDispatchEventRequestBuilder(ReposCreateDispatchEventRequest);
+
+impl ReposCreateDispatchEventRequestBuilder {
+    pub fn client_payload(mut self, client_payload: impl Into<Data>) -> Self {
+        self.0.client_payload = Some(client_payload.into());
+        self
+    }
+
+    pub fn event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.0.event_type = event_type.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateDispatchEventRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposGetAllEnvironmentsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub environments: Vec<EnvironmentData>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUpdateEnvironmentRequestReviewers {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+    * The type of reviewer. Must be one of: `User` or `Team`
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<DeploymentReviewerType>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUpdateEnvironmentRequest {
+    /**
+    * The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+    /**
+    * The people or teams that may review jobs that reference the environment. You can list up to six users or teams as reviewers. The reviewers must have at least read access to the repository. Only one of the required reviewers needs to approve the job for it to proceed.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reviewers: Vec<ReposCreateUpdateEnvironmentRequestReviewers>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub wait_timer: i64,
+}
+
+impl ReposCreateUpdateEnvironmentRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateUpdateEnvironmentRequestBuilder {
+        ReposCreateUpdateEnvironmentRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateUpdateEnvironmentRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateUpdateEnvironmentRequestBuilder(ReposCreateUpdateEnvironmentRequest);
+
+impl ReposCreateUpdateEnvironmentRequestBuilder {
+    pub fn deployment_branch_policy(
+        mut self,
+        deployment_branch_policy: impl Into<DeploymentBranchPolicy>,
+    ) -> Self {
+        self.0.deployment_branch_policy = Some(deployment_branch_policy.into());
+        self
+    }
+
+    pub fn reviewers(
+        mut self,
+        reviewers: Vec<ReposCreateUpdateEnvironmentRequestReviewers>,
+    ) -> Self {
+        self.0.reviewers = reviewers;
+        self
+    }
+
+    pub fn wait_timer(mut self, wait_timer: i64) -> Self {
+        self.0.wait_timer = wait_timer;
+        self
+    }
+
+    pub fn build(self) -> ReposCreateUpdateEnvironmentRequest {
+        self.0
+    }
+}
+
+/**
+* The sort order. Can be either `newest`, `oldest`, or `stargazers`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposListForksSort {
+    #[serde(rename = "newest")]
+    Newest,
+    #[serde(rename = "oldest")]
+    Oldest,
+    #[serde(rename = "stargazers")]
+    Stargazers,
+    #[serde(rename = "watchers")]
+    Watchers,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposListForksSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposListForksSort::Newest => "newest",
+            ReposListForksSort::Oldest => "oldest",
+            ReposListForksSort::Stargazers => "stargazers",
+            ReposListForksSort::Watchers => "watchers",
+            ReposListForksSort::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposListForksSort {
+    fn default() -> ReposListForksSort {
+        ReposListForksSort::Newest
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateForkRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organization: String,
+}
+
+impl std::convert::From<String> for ReposCreateForkRequest {
+    fn from(organization: String) -> Self {
+        ReposCreateForkRequest { organization }
+    }
+}
+
+impl std::convert::From<&str> for ReposCreateForkRequest {
+    fn from(organization: &str) -> Self {
+        ReposCreateForkRequest {
+            organization: organization.to_string(),
+        }
+    }
+}
+
+impl ReposCreateForkRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateForkRequestBuilder {
+        ReposCreateForkRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateForkRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateForkRequestBuilder(ReposCreateForkRequest);
+
+impl ReposCreateForkRequestBuilder {
+    pub fn organization(mut self, organization: impl Into<String>) -> Self {
+        self.0.organization = organization.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateForkRequest {
+        self.0
+    }
+}
+
+/// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateWebhookRequestConfig {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub digest: String,
+    /**
+    * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<ReposCreateWebhookRequestConfig>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+impl ReposCreateWebhookRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateWebhookRequestBuilder {
+        ReposCreateWebhookRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateWebhookRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateWebhookRequestBuilder(ReposCreateWebhookRequest);
+
+impl ReposCreateWebhookRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn config(mut self, config: impl Into<ReposCreateWebhookRequestConfig>) -> Self {
+        self.0.config = Some(config.into());
+        self
+    }
+
+    pub fn events(mut self, events: Vec<String>) -> Self {
+        self.0.events = events;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateWebhookRequest {
+        self.0
+    }
+}
+
+/// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateWebhookRequestConfig {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub address: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    /**
+    * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub room: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub add_events: Vec<String>,
+    /**
+    * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<ReposUpdateWebhookRequestConfig>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub remove_events: Vec<String>,
+}
+
+impl ReposUpdateWebhookRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposUpdateWebhookRequestBuilder {
+        ReposUpdateWebhookRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposUpdateWebhookRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposUpdateWebhookRequestBuilder(ReposUpdateWebhookRequest);
+
+impl ReposUpdateWebhookRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn add_events(mut self, add_events: Vec<String>) -> Self {
+        self.0.add_events = add_events;
+        self
+    }
+
+    pub fn config(mut self, config: impl Into<ReposUpdateWebhookRequestConfig>) -> Self {
+        self.0.config = Some(config.into());
+        self
+    }
+
+    pub fn events(mut self, events: Vec<String>) -> Self {
+        self.0.events = events;
+        self
+    }
+
+    pub fn remove_events(mut self, remove_events: Vec<String>) -> Self {
+        self.0.remove_events = remove_events;
+        self
+    }
+
+    pub fn build(self) -> ReposUpdateWebhookRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateInvitationRequest {
+    /**
+    * The permission associated with the invitation.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<RepositoryInvitationPermissions>,
+}
+
+impl ReposUpdateInvitationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposUpdateInvitationRequestBuilder {
+        ReposUpdateInvitationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposUpdateInvitationRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposUpdateInvitationRequestBuilder(ReposUpdateInvitationRequest);
+
+impl ReposUpdateInvitationRequestBuilder {
+    pub fn permissions(mut self, permissions: impl Into<RepositoryInvitationPermissions>) -> Self {
+        self.0.permissions = Some(permissions.into());
+        self
+    }
+
+    pub fn build(self) -> ReposUpdateInvitationRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDeployKeyRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+impl ReposCreateDeployKeyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateDeployKeyRequestBuilder {
+        ReposCreateDeployKeyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateDeployKeyRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateDeployKeyRequestBuilder(ReposCreateDeployKeyRequest);
+
+impl ReposCreateDeployKeyRequestBuilder {
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.0.key = key.into();
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.0.read_only = Some(read_only);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateDeployKeyRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposMergeRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub base: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head: String,
+}
+
+impl ReposMergeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposMergeRequestBuilder {
+        ReposMergeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposMergeRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposMergeRequestBuilder(ReposMergeRequest);
+
+impl ReposMergeRequestBuilder {
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.0.base = base.into();
+        self
+    }
+
+    pub fn commit_message(mut self, commit_message: impl Into<String>) -> Self {
+        self.0.commit_message = commit_message.into();
+        self
+    }
+
+    pub fn head(mut self, head: impl Into<String>) -> Self {
+        self.0.head = head.into();
+        self
+    }
+
+    pub fn build(self) -> ReposMergeRequest {
+        self.0
+    }
+}
+
+/// The source branch and directory used to publish your Pages site.
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreatePagesSiteRequestSource {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub branch: String,
+    /**
+    * The source branch and directory used to publish your Pages site.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<Path>,
+}
+
+/// The source branch and directory used to publish your Pages site.
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreatePagesSiteRequest {
+    /**
+    * The source branch and directory used to publish your Pages site.
+    */
+    #[serde()]
+    pub source: ReposCreatePagesSiteRequestSource,
+}
+
+impl ReposCreatePagesSiteRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreatePagesSiteRequestBuilder {
+        ReposCreatePagesSiteRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreatePagesSiteRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreatePagesSiteRequestBuilder(ReposCreatePagesSiteRequest);
+
+impl ReposCreatePagesSiteRequestBuilder {
+    pub fn source(mut self, source: impl Into<ReposCreatePagesSiteRequestSource>) -> Self {
+        self.0.source = source.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreatePagesSiteRequest {
+        self.0
+    }
+}
+
+/**
+* Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum SourceData {
+    #[serde(rename = "gh-pages")]
+    GhPages,
+    #[serde(rename = "master")]
+    Master,
+    #[serde(rename = "master /docs")]
+    MasterDocs,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for SourceData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            SourceData::GhPages => "gh-pages",
+            SourceData::Master => "master",
+            SourceData::MasterDocs => "master /docs",
+            SourceData::Noop => "",
+            SourceData::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for SourceData {
+    fn default() -> SourceData {
+        SourceData::Noop
+    }
+}
+impl SourceData {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, SourceData::Noop)
+    }
+}
+
+/// Update the source for the repository. Must include the branch name and path.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SourceDataType {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub branch: String,
+    /**
+    * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`. Default: `/`
+    */
+    #[serde(default)]
+    pub path: Path,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `SourceData`
+/// - `SourceDataType`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SourceAnyOf {
+    /**
+    * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
+    */
+    #[serde(flatten)]
+    pub source_data: SourceData,
+    /**
+    * Update the source for the repository. Must include the branch name and path.
+    */
+    #[serde(flatten)]
+    pub source_data_type: SourceDataType,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateInformationAboutPagesSiteRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cname: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub https_enforced: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<SourceAnyOf>,
+}
+
+impl ReposUpdateInformationAboutPagesSiteRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposUpdateInformationAboutPagesSiteRequestBuilder {
+        ReposUpdateInformationAboutPagesSiteRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposUpdateInformationAboutPagesSiteRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposUpdateInformationAboutPagesSiteRequestBuilder(
+    ReposUpdateInformationAboutPagesSiteRequest,
+);
+
+impl ReposUpdateInformationAboutPagesSiteRequestBuilder {
+    pub fn cname(mut self, cname: impl Into<String>) -> Self {
+        self.0.cname = cname.into();
+        self
+    }
+
+    pub fn https_enforced(mut self, https_enforced: bool) -> Self {
+        self.0.https_enforced = Some(https_enforced);
+        self
+    }
+
+    pub fn public(mut self, public: bool) -> Self {
+        self.0.public = Some(public);
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<SourceAnyOf>) -> Self {
+        self.0.source = Some(source.into());
+        self
+    }
+
+    pub fn build(self) -> ReposUpdateInformationAboutPagesSiteRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateReleaseAssetRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub label: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+}
+
+impl ReposUpdateReleaseAssetRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposUpdateReleaseAssetRequestBuilder {
+        ReposUpdateReleaseAssetRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposUpdateReleaseAssetRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposUpdateReleaseAssetRequestBuilder(ReposUpdateReleaseAssetRequest);
+
+impl ReposUpdateReleaseAssetRequestBuilder {
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.0.label = label.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn build(self) -> ReposUpdateReleaseAssetRequest {
+        self.0
+    }
+}
+
+/**
+* The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposCreateCommitStatusRequestState {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "success")]
+    Success,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposCreateCommitStatusRequestState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposCreateCommitStatusRequestState::Error => "error",
+            ReposCreateCommitStatusRequestState::Failure => "failure",
+            ReposCreateCommitStatusRequestState::Pending => "pending",
+            ReposCreateCommitStatusRequestState::Success => "success",
+            ReposCreateCommitStatusRequestState::Noop => "",
+            ReposCreateCommitStatusRequestState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposCreateCommitStatusRequestState {
+    fn default() -> ReposCreateCommitStatusRequestState {
+        ReposCreateCommitStatusRequestState::Noop
+    }
+}
+impl ReposCreateCommitStatusRequestState {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ReposCreateCommitStatusRequestState::Noop)
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateCommitStatusRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub context: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+    * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "ReposCreateCommitStatusRequestState::is_noop"
+    )]
+    pub state: ReposCreateCommitStatusRequestState,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+}
+
+impl ReposCreateCommitStatusRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateCommitStatusRequestBuilder {
+        ReposCreateCommitStatusRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateCommitStatusRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateCommitStatusRequestBuilder(ReposCreateCommitStatusRequest);
+
+impl ReposCreateCommitStatusRequestBuilder {
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.0.context = context.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<ReposCreateCommitStatusRequestState>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn target_url(mut self, target_url: impl Into<String>) -> Self {
+        self.0.target_url = target_url.into();
+        self
+    }
+
+    pub fn build(self) -> ReposCreateCommitStatusRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposTransferRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub new_owner: String,
+    /**
+    * Code Frequency Stat
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team_ids: Vec<i64>,
+}
+
+impl ReposTransferRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposTransferRequestBuilder {
+        ReposTransferRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposTransferRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposTransferRequestBuilder(ReposTransferRequest);
+
+impl ReposTransferRequestBuilder {
+    pub fn new_owner(mut self, new_owner: impl Into<String>) -> Self {
+        self.0.new_owner = new_owner.into();
+        self
+    }
+
+    pub fn team_ids(mut self, team_ids: Vec<i64>) -> Self {
+        self.0.team_ids = team_ids;
+        self
+    }
+
+    pub fn build(self) -> ReposTransferRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUsingTemplateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_all_branches: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub owner: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+}
+
+impl ReposCreateUsingTemplateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateUsingTemplateRequestBuilder {
+        ReposCreateUsingTemplateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateUsingTemplateRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateUsingTemplateRequestBuilder(ReposCreateUsingTemplateRequest);
+
+impl ReposCreateUsingTemplateRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn include_all_branches(mut self, include_all_branches: bool) -> Self {
+        self.0.include_all_branches = Some(include_all_branches);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.0.owner = owner.into();
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.0.private = Some(private);
+        self
+    }
+
+    pub fn build(self) -> ReposCreateUsingTemplateRequest {
+        self.0
+    }
+}
+
+/**
+* Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposListVisibility {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposListVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposListVisibility::All => "all",
+            ReposListVisibility::Private => "private",
+            ReposListVisibility::Public => "public",
+            ReposListVisibility::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposListVisibility {
+    fn default() -> ReposListVisibility {
+        ReposListVisibility::All
+    }
+}
+
+/**
+* Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
+*     
+*   Will cause a `422` error if used in the same request as **visibility** or **affiliation**. Will cause a `422` error if used in the same request as **visibility** or **affiliation**.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposListType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposListType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposListType::All => "all",
+            ReposListType::Member => "member",
+            ReposListType::Owner => "owner",
+            ReposListType::Private => "private",
+            ReposListType::Public => "public",
+            ReposListType::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposListType {
+    fn default() -> ReposListType {
+        ReposListType::All
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_init: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gitignore_template: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_downloads: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub homepage: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub license_template: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub team_id: i64,
+}
+
+impl ReposCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReposCreateRequestBuilder {
+        ReposCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReposCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct ReposCreateRequestBuilder(ReposCreateRequest);
+
+impl ReposCreateRequestBuilder {
+    pub fn allow_auto_merge(mut self, allow_auto_merge: bool) -> Self {
+        self.0.allow_auto_merge = Some(allow_auto_merge);
+        self
+    }
+
+    pub fn allow_merge_commit(mut self, allow_merge_commit: bool) -> Self {
+        self.0.allow_merge_commit = Some(allow_merge_commit);
+        self
+    }
+
+    pub fn allow_rebase_merge(mut self, allow_rebase_merge: bool) -> Self {
+        self.0.allow_rebase_merge = Some(allow_rebase_merge);
+        self
+    }
+
+    pub fn allow_squash_merge(mut self, allow_squash_merge: bool) -> Self {
+        self.0.allow_squash_merge = Some(allow_squash_merge);
+        self
+    }
+
+    pub fn auto_init(mut self, auto_init: bool) -> Self {
+        self.0.auto_init = Some(auto_init);
+        self
+    }
+
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: bool) -> Self {
+        self.0.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn gitignore_template(mut self, gitignore_template: impl Into<String>) -> Self {
+        self.0.gitignore_template = gitignore_template.into();
+        self
+    }
+
+    pub fn has_downloads(mut self, has_downloads: bool) -> Self {
+        self.0.has_downloads = Some(has_downloads);
+        self
+    }
+
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.0.has_issues = Some(has_issues);
+        self
+    }
+
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.0.has_projects = Some(has_projects);
+        self
+    }
+
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.0.has_wiki = Some(has_wiki);
+        self
+    }
+
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.0.homepage = homepage.into();
+        self
+    }
+
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.0.is_template = Some(is_template);
+        self
+    }
+
+    pub fn license_template(mut self, license_template: impl Into<String>) -> Self {
+        self.0.license_template = license_template.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.0.private = Some(private);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: i64) -> Self {
+        self.0.team_id = team_id;
+        self
+    }
+
+    pub fn build(self) -> ReposCreateRequest {
+        self.0
+    }
+}
+
+/**
+* Can be one of `all`, `owner`, `member`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum ReposListUserType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ReposListUserType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            ReposListUserType::All => "all",
+            ReposListUserType::Member => "member",
+            ReposListUserType::Owner => "owner",
+            ReposListUserType::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for ReposListUserType {
+    fn default() -> ReposListUserType {
+        ReposListUserType::Owner
+    }
+}
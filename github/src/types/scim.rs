@@ -0,0 +1,527 @@
+//! The data types of the `scim` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Scim Error
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimError {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub detail: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+    * Scim Error
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "scimType"
+    )]
+    pub scim_type: String,
+    /**
+    * Scim Error
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub status: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUserName {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "familyName"
+    )]
+    pub family_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub formatted: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "givenName"
+    )]
+    pub given_name: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUserEmails {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub primary: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUserMeta {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastModified"
+    )]
+    pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "resourceType"
+    )]
+    pub resource_type: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// SCIM /Users provisioning endpoints
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUser {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    /**
+    * user emails
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<ScimUserEmails>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalId"
+    )]
+    pub external_id: String,
+    /**
+    * SCIM /Users provisioning endpoints
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde()]
+    pub meta: ScimUserMeta,
+    #[serde()]
+    pub name: ScimUserName,
+    /**
+    * SCIM /Users provisioning endpoints
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub operations: Vec<Operations>,
+    /**
+    * SCIM /Users provisioning endpoints
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub organization_id: i64,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userName"
+    )]
+    pub user_name: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// SCIM User List
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUserList {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Resources"
+    )]
+    pub resources: Vec<ScimUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "itemsPerPage"
+    )]
+    pub items_per_page: i64,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "startIndex"
+    )]
+    pub start_index: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalResults"
+    )]
+    pub total_results: i64,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimProvisionInviteUserRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<Emails>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalId"
+    )]
+    pub external_id: String,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<String>,
+    #[serde()]
+    pub name: ScimUserName,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userName"
+    )]
+    pub user_name: String,
+}
+
+impl ScimProvisionInviteUserRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ScimProvisionInviteUserRequestBuilder {
+        ScimProvisionInviteUserRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ScimProvisionInviteUserRequest`.
+#[derive(Debug, Clone)]
+pub struct ScimProvisionInviteUserRequestBuilder(ScimProvisionInviteUserRequest);
+
+impl ScimProvisionInviteUserRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn emails(mut self, emails: Vec<Emails>) -> Self {
+        self.0.emails = emails;
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.0.external_id = external_id.into();
+        self
+    }
+
+    pub fn groups(mut self, groups: Vec<String>) -> Self {
+        self.0.groups = groups;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<ScimUserName>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn schemas(mut self, schemas: Vec<String>) -> Self {
+        self.0.schemas = schemas;
+        self
+    }
+
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.0.user_name = user_name.into();
+        self
+    }
+
+    pub fn build(self) -> ScimProvisionInviteUserRequest {
+        self.0
+    }
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `Vec<ScimUserEmails>`
+/// - `Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ScimUpdateAttributeUserRequestOperationsValueOneOf {
+    String(String),
+    /**
+    * user emails
+    */
+    ScimUserEmailsVector(Vec<ScimUserEmails>),
+    Value(Value),
+}
+
+impl ScimUpdateAttributeUserRequestOperationsValueOneOf {
+    pub fn vec_scim_user_emails(&self) -> Option<&Vec<ScimUserEmails>> {
+        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::ScimUserEmailsVector(ref_) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn value(&self) -> Option<&Value> {
+        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<ScimUserEmails>>
+    for ScimUpdateAttributeUserRequestOperationsValueOneOf
+{
+    fn from(f: Vec<ScimUserEmails>) -> Self {
+        ScimUpdateAttributeUserRequestOperationsValueOneOf::ScimUserEmailsVector(f)
+    }
+}
+
+impl std::convert::From<String> for ScimUpdateAttributeUserRequestOperationsValueOneOf {
+    fn from(f: String) -> Self {
+        ScimUpdateAttributeUserRequestOperationsValueOneOf::String(f)
+    }
+}
+
+impl std::convert::From<ScimUpdateAttributeUserRequestOperationsValueOneOf>
+    for Vec<ScimUserEmails>
+{
+    fn from(f: ScimUpdateAttributeUserRequestOperationsValueOneOf) -> Self {
+        f.vec_scim_user_emails().unwrap().clone()
+    }
+}
+
+impl std::convert::From<ScimUpdateAttributeUserRequestOperationsValueOneOf> for String {
+    fn from(f: ScimUpdateAttributeUserRequestOperationsValueOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUpdateAttributeUserRequestOperations {
+    #[serde(default, skip_serializing_if = "Op::is_noop")]
+    pub op: Op,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<ScimUpdateAttributeUserRequestOperationsValueOneOf>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUpdateAttributeUserRequest {
+    /**
+    * Set of operations to be performed
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Operations"
+    )]
+    pub operations: Vec<ScimUpdateAttributeUserRequestOperations>,
+    /**
+    * The list of events for the GitHub app
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+impl ScimUpdateAttributeUserRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ScimUpdateAttributeUserRequestBuilder {
+        ScimUpdateAttributeUserRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ScimUpdateAttributeUserRequest`.
+#[derive(Debug, Clone)]
+pub struct ScimUpdateAttributeUserRequestBuilder(ScimUpdateAttributeUserRequest);
+
+impl ScimUpdateAttributeUserRequestBuilder {
+    pub fn operations(mut self, operations: Vec<ScimUpdateAttributeUserRequestOperations>) -> Self {
+        self.0.operations = operations;
+        self
+    }
+
+    pub fn schemas(mut self, schemas: Vec<String>) -> Self {
+        self.0.schemas = schemas;
+        self
+    }
+
+    pub fn build(self) -> ScimUpdateAttributeUserRequest {
+        self.0
+    }
+}
@@ -0,0 +1,195 @@
+//! The data types of the `secret_scanning` API group.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/**
+* Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum SecretScanningAlertState {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "resolved")]
+    Resolved,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for SecretScanningAlertState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            SecretScanningAlertState::Open => "open",
+            SecretScanningAlertState::Resolved => "resolved",
+            SecretScanningAlertState::Noop => "",
+            SecretScanningAlertState::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for SecretScanningAlertState {
+    fn default() -> SecretScanningAlertState {
+        SecretScanningAlertState::Noop
+    }
+}
+impl SecretScanningAlertState {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, SecretScanningAlertState::Noop)
+    }
+}
+
+/**
+* **Required when the `state` is `resolved`.** The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum SecretScanningAlertResolution {
+    #[serde(rename = "false_positive")]
+    FalsePositive,
+    #[serde(rename = "revoked")]
+    Revoked,
+    #[serde(rename = "used_in_tests")]
+    UsedInTests,
+    #[serde(rename = "wont_fix")]
+    WontFix,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for SecretScanningAlertResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            SecretScanningAlertResolution::FalsePositive => "false_positive",
+            SecretScanningAlertResolution::Revoked => "revoked",
+            SecretScanningAlertResolution::UsedInTests => "used_in_tests",
+            SecretScanningAlertResolution::WontFix => "wont_fix",
+            SecretScanningAlertResolution::Noop => "",
+            SecretScanningAlertResolution::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for SecretScanningAlertResolution {
+    fn default() -> SecretScanningAlertResolution {
+        SecretScanningAlertResolution::Noop
+    }
+}
+impl SecretScanningAlertResolution {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, SecretScanningAlertResolution::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecretScanningAlert {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    /**
+    * \*\*Required when the `state` is `resolved`.\*\* The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<SecretScanningAlertResolution>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+    * Simple User
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_by: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret_type: String,
+    /**
+    * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<SecretScanningAlertState>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+    * The fields that are not described by the spec.
+    */
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecretScanningUpdateAlertRequest {
+    /**
+    * \*\*Required when the `state` is `resolved`.\*\* The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<SecretScanningAlertResolution>,
+    /**
+    * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
+    */
+    #[serde()]
+    pub state: SecretScanningAlertState,
+}
+
+impl SecretScanningUpdateAlertRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SecretScanningUpdateAlertRequestBuilder {
+        SecretScanningUpdateAlertRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SecretScanningUpdateAlertRequest`.
+#[derive(Debug, Clone)]
+pub struct SecretScanningUpdateAlertRequestBuilder(SecretScanningUpdateAlertRequest);
+
+impl SecretScanningUpdateAlertRequestBuilder {
+    pub fn resolution(mut self, resolution: impl Into<SecretScanningAlertResolution>) -> Self {
+        self.0.resolution = Some(resolution.into());
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<SecretScanningAlertState>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn build(self) -> SecretScanningUpdateAlertRequest {
+        self.0
+    }
+}
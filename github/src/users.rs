@@ -114,6 +114,8 @@ impl Users {
     *
     * This function performs a `GET` to the `/user/blocks/{username}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/users#check-if-a-user-is-blocked-by-the-authenticated-user>
@@ -122,13 +124,14 @@ impl Users {
     *
     * * `username: &str`
     */
-    pub async fn check_blocked(&self, username: &str) -> Result<()> {
+    pub async fn check_blocked(&self, username: impl AsRef<str>) -> Result<bool> {
+        let username = username.as_ref();
         let url = format!(
             "/user/blocks/{}",
             crate::progenitor_support::encode_path(username),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -144,7 +147,8 @@ impl Users {
     *
     * * `username: &str`
     */
-    pub async fn block(&self, username: &str) -> Result<()> {
+    pub async fn block(&self, username: impl AsRef<str>) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/user/blocks/{}",
             crate::progenitor_support::encode_path(username),
@@ -166,7 +170,8 @@ impl Users {
     *
     * * `username: &str`
     */
-    pub async fn unblock(&self, username: &str) -> Result<()> {
+    pub async fn unblock(&self, username: impl AsRef<str>) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/user/blocks/{}",
             crate::progenitor_support::encode_path(username),
@@ -213,15 +218,14 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Email>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/emails?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/emails"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -299,15 +303,15 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/followers?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/user/followers"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -349,15 +353,15 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/following?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/user/following"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -385,6 +389,8 @@ impl Users {
     *
     * This function performs a `GET` to the `/user/following/{username}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/users#check-if-a-person-is-followed-by-the-authenticated-user>
@@ -393,13 +399,17 @@ impl Users {
     *
     * * `username: &str`
     */
-    pub async fn check_person_is_followed_by_authenticated(&self, username: &str) -> Result<()> {
+    pub async fn check_person_is_followed_by_authenticated(
+        &self,
+        username: impl AsRef<str>,
+    ) -> Result<bool> {
+        let username = username.as_ref();
         let url = format!(
             "/user/following/{}",
             crate::progenitor_support::encode_path(username),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -417,7 +427,8 @@ impl Users {
     *
     * * `username: &str`
     */
-    pub async fn follow(&self, username: &str) -> Result<()> {
+    pub async fn follow(&self, username: impl AsRef<str>) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/user/following/{}",
             crate::progenitor_support::encode_path(username),
@@ -439,7 +450,8 @@ impl Users {
     *
     * * `username: &str`
     */
-    pub async fn unfollow(&self, username: &str) -> Result<()> {
+    pub async fn unfollow(&self, username: impl AsRef<str>) -> Result<()> {
+        let username = username.as_ref();
         let url = format!(
             "/user/following/{}",
             crate::progenitor_support::encode_path(username),
@@ -467,15 +479,14 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GpgKey>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/gpg_keys?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/gpg_keys"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -534,7 +545,7 @@ impl Users {
     ) -> Result<crate::types::GpgKey> {
         let url = format!(
             "/user/gpg_keys/{}",
-            crate::progenitor_support::encode_path(&gpg_key_id.to_string()),
+            crate::progenitor_support::encode_path(&gpg_key_id),
         );
 
         self.client.get(&url, None).await
@@ -556,7 +567,7 @@ impl Users {
     pub async fn delete_gpg_key_for_authenticated(&self, gpg_key_id: i64) -> Result<()> {
         let url = format!(
             "/user/gpg_keys/{}",
-            crate::progenitor_support::encode_path(&gpg_key_id.to_string()),
+            crate::progenitor_support::encode_path(&gpg_key_id),
         );
 
         self.client.delete(&url, None).await
@@ -581,15 +592,14 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Key>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/keys?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/user/keys"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -650,7 +660,7 @@ impl Users {
     ) -> Result<crate::types::Key> {
         let url = format!(
             "/user/keys/{}",
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            crate::progenitor_support::encode_path(&key_id),
         );
 
         self.client.get(&url, None).await
@@ -672,7 +682,7 @@ impl Users {
     pub async fn delete_public_ssh_key_for_authenticated(&self, key_id: i64) -> Result<()> {
         let url = format!(
             "/user/keys/{}",
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            crate::progenitor_support::encode_path(&key_id),
         );
 
         self.client.delete(&url, None).await
@@ -697,15 +707,15 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Email>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/public_emails?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/user/public_emails"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -745,15 +755,14 @@ impl Users {
     * * `per_page: i64` -- Results per page (max 100).
     */
     pub async fn list(&self, since: i64, per_page: i64) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -772,12 +781,11 @@ impl Users {
     * FROM: <https://docs.github.com/rest/reference/users#list-users>
     */
     pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -803,8 +811,9 @@ impl Users {
     */
     pub async fn get_by_username_public_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::PublicUser> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}",
             crate::progenitor_support::encode_path(username),
@@ -834,8 +843,9 @@ impl Users {
     */
     pub async fn get_by_username_private_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::PrivateUser> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}",
             crate::progenitor_support::encode_path(username),
@@ -865,8 +875,9 @@ impl Users {
     */
     pub async fn get_by_username(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::UsersGetByUsernameResponseOneOf> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}",
             crate::progenitor_support::encode_path(username),
@@ -892,22 +903,24 @@ impl Users {
     */
     pub async fn list_followers_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/followers?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/followers",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -926,8 +939,9 @@ impl Users {
     */
     pub async fn list_all_followers_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/followers",
             crate::progenitor_support::encode_path(username),
@@ -953,22 +967,24 @@ impl Users {
     */
     pub async fn list_following_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/following?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/following",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -987,8 +1003,9 @@ impl Users {
     */
     pub async fn list_all_following_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/following",
             crate::progenitor_support::encode_path(username),
@@ -1002,6 +1019,8 @@ impl Users {
     *
     * This function performs a `GET` to the `/users/{username}/following/{target_user}` endpoint.
     *
+    * Returns `false` when GitHub answers with `404 Not Found`.
+    *
     *
     *
     * FROM: <https://docs.github.com/rest/reference/users#check-if-a-user-follows-another-user>
@@ -1011,14 +1030,20 @@ impl Users {
     * * `username: &str`
     * * `target_user: &str`
     */
-    pub async fn check_following_for_user(&self, username: &str, target_user: &str) -> Result<()> {
+    pub async fn check_following_for_user(
+        &self,
+        username: impl AsRef<str>,
+        target_user: impl AsRef<str>,
+    ) -> Result<bool> {
+        let username = username.as_ref();
+        let target_user = target_user.as_ref();
         let url = format!(
             "/users/{}/following/{}",
             crate::progenitor_support::encode_path(username),
             crate::progenitor_support::encode_path(target_user),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_exists(&url).await
     }
 
     /**
@@ -1038,22 +1063,24 @@ impl Users {
     */
     pub async fn list_gpg_keys_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GpgKey>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/gpg_keys?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/gpg_keys",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1072,8 +1099,9 @@ impl Users {
     */
     pub async fn list_all_gpg_keys_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::GpgKey>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/gpg_keys",
             crate::progenitor_support::encode_path(username),
@@ -1106,22 +1134,24 @@ impl Users {
     */
     pub async fn get_context_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         subject_type: crate::types::SubjectType,
         subject_id: &str,
     ) -> Result<crate::types::Hovercard> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !subject_id.is_empty() {
-            query_args.push(("subject_id".to_string(), subject_id.to_string()));
+            query_args.push(("subject_id", subject_id.to_string()));
         }
         if !subject_type.to_string().is_empty() {
-            query_args.push(("subject_type".to_string(), subject_type.to_string()));
+            query_args.push(("subject_type", subject_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/hovercard?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/hovercard",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1144,22 +1174,24 @@ impl Users {
     */
     pub async fn list_public_keys_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::KeySimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let username = username.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/keys?{}",
-            crate::progenitor_support::encode_path(username),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/keys",
+                crate::progenitor_support::encode_path(username),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1178,8 +1210,9 @@ impl Users {
     */
     pub async fn list_all_public_keys_for_user(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<Vec<crate::types::KeySimple>> {
+        let username = username.as_ref();
         let url = format!(
             "/users/{}/keys",
             crate::progenitor_support::encode_path(username),
@@ -0,0 +1,172 @@
+//! Check request bodies before they are sent.
+//!
+//! The request types accept any value, and GitHub answers a body that is missing a
+//! required field or breaks a limit with a `422 Unprocessable Entity` that does not
+//! always say what is wrong. The request types that implement `Validate` can be
+//! checked first, which fails without a request and names the field at fault.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client, body: octorust::types::GistsCreateRequest) -> anyhow::Result<()> {
+//! use octorust::validate::Validate;
+//!
+//! // Fails with "invalid GistsCreateRequest: `files` must have at least one file"
+//! // instead of sending the request when there are no files.
+//! let gist = github.gists().create(body.validated()?).await?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use crate::types::{
+    GistsCreateRequest, IssuesCreateLabelRequest, IssuesCreateMilestoneRequest,
+    IssuesCreateRequest, PullsCreateRequest, ReposCreateReleaseRequest, TitleOneOf,
+};
+
+/// The longest description a label can have, in characters.
+pub const MAX_LABEL_DESCRIPTION_LENGTH: usize = 100;
+
+/// A field of a request body that GitHub would reject.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// The name of the request type.
+    pub type_name: &'static str,
+    pub field: &'static str,
+    /// What is wrong with the field.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid {}: `{}` {}",
+            self.type_name, self.field, self.message
+        )
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A request body that can be checked before it is sent.
+pub trait Validate {
+    /// Check the body against the requirements of the endpoint.
+    fn validate(&self) -> Result<(), ValidationError>;
+
+    /// Check the body and return it, to pass it on to the function sending it.
+    fn validated(&self) -> anyhow::Result<&Self>
+    where
+        Self: Sized,
+    {
+        self.validate()?;
+        Ok(self)
+    }
+}
+
+fn invalid(type_name: &'static str, field: &'static str, message: &str) -> ValidationError {
+    ValidationError {
+        type_name,
+        field,
+        message: message.to_string(),
+    }
+}
+
+fn required(
+    type_name: &'static str,
+    field: &'static str,
+    value: &str,
+) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(invalid(type_name, field, "is required"));
+    }
+    Ok(())
+}
+
+impl Validate for GistsCreateRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let files = &self.files.extra;
+        if files.is_empty() {
+            return Err(invalid(
+                "GistsCreateRequest",
+                "files",
+                "must have at least one file",
+            ));
+        }
+
+        for (name, file) in files {
+            let content = file.get("content").and_then(|c| c.as_str()).unwrap_or("");
+            if content.is_empty() {
+                return Err(ValidationError {
+                    type_name: "GistsCreateRequest",
+                    field: "files",
+                    message: format!("has no content for {}", name),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for IssuesCreateRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match &self.title {
+            TitleOneOf::String(title) => required("IssuesCreateRequest", "title", title),
+            TitleOneOf::I64(_) => Ok(()),
+        }
+    }
+}
+
+impl Validate for IssuesCreateLabelRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        required("IssuesCreateLabelRequest", "name", &self.name)?;
+
+        if !self.color.is_empty()
+            && (self.color.len() != 6 || !self.color.chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            return Err(invalid(
+                "IssuesCreateLabelRequest",
+                "color",
+                "must be 6 hexadecimal digits, without the leading #",
+            ));
+        }
+
+        if self.description.chars().count() > MAX_LABEL_DESCRIPTION_LENGTH {
+            return Err(ValidationError {
+                type_name: "IssuesCreateLabelRequest",
+                field: "description",
+                message: format!(
+                    "must be at most {} characters",
+                    MAX_LABEL_DESCRIPTION_LENGTH
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for IssuesCreateMilestoneRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        required("IssuesCreateMilestoneRequest", "title", &self.title)
+    }
+}
+
+impl Validate for PullsCreateRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        required("PullsCreateRequest", "head", &self.head)?;
+        required("PullsCreateRequest", "base", &self.base)?;
+
+        // The title of the issue is used when the pull request is made from one.
+        if self.issue == 0 {
+            required("PullsCreateRequest", "title", &self.title)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for ReposCreateReleaseRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        required("ReposCreateReleaseRequest", "tag_name", &self.tag_name)
+    }
+}
@@ -0,0 +1,255 @@
+//! Typed payloads for GitHub webhook events and verification of their signatures.
+//!
+//! https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, Mac};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// The header containing the HMAC SHA-256 signature of the payload.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+/// The header containing the name of the event that triggered the delivery.
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+/// The header containing the unique identifier of the delivery.
+pub const DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+
+/// Verify the value of the `X-Hub-Signature-256` header against the raw payload
+/// of a webhook delivery and the secret configured for the webhook.
+///
+/// The payload must be the body exactly as it was received, before it is parsed.
+///
+/// ```
+/// use octorust::webhooks::verify_signature;
+///
+/// let header = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+/// assert!(verify_signature(b"It's a Secret to Everybody", b"Hello, World!", header).is_ok());
+/// assert!(verify_signature(b"not the secret", b"Hello, World!", header).is_err());
+/// ```
+pub fn verify_signature(secret: &[u8], payload: &[u8], header: &str) -> Result<()> {
+    let signature = header
+        .trim()
+        .strip_prefix("sha256=")
+        .ok_or_else(|| anyhow!("signature header must start with `sha256=`"))?;
+    let signature = hex::decode(signature)?;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret)
+        .map_err(|e| anyhow!("invalid webhook secret: {}", e))?;
+    mac.update(payload);
+
+    // `verify_slice` compares the signatures in constant time.
+    mac.verify_slice(&signature)
+        .map_err(|_| anyhow!("signature does not match the payload"))
+}
+
+/// A webhook event, parsed from the `X-GitHub-Event` header and the payload.
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    Ping(PingEvent),
+    Push(PushEvent),
+    PullRequest(PullRequestEvent),
+    Issues(IssuesEvent),
+    IssueComment(IssueCommentEvent),
+    WorkflowRun(WorkflowRunEvent),
+    Release(ReleaseEvent),
+    /// An event without a typed payload, along with the name of the event.
+    Other(String, serde_json::Value),
+}
+
+impl WebhookEvent {
+    /// Parse the payload of a delivery based on the value of the `X-GitHub-Event` header.
+    pub fn parse(event: &str, payload: &[u8]) -> Result<Self> {
+        Ok(match event {
+            "ping" => WebhookEvent::Ping(serde_json::from_slice(payload)?),
+            "push" => WebhookEvent::Push(serde_json::from_slice(payload)?),
+            "pull_request" => WebhookEvent::PullRequest(serde_json::from_slice(payload)?),
+            "issues" => WebhookEvent::Issues(serde_json::from_slice(payload)?),
+            "issue_comment" => WebhookEvent::IssueComment(serde_json::from_slice(payload)?),
+            "workflow_run" => WebhookEvent::WorkflowRun(serde_json::from_slice(payload)?),
+            "release" => WebhookEvent::Release(serde_json::from_slice(payload)?),
+            _ => WebhookEvent::Other(event.to_string(), serde_json::from_slice(payload)?),
+        })
+    }
+}
+
+/// The installation of the GitHub App the event was delivered to.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct EventInstallation {
+    #[serde(default)]
+    pub id: i64,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub node_id: String,
+}
+
+/// Sent when a new webhook is created.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PingEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub zen: String,
+    #[serde(default)]
+    pub hook_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<crate::types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+}
+
+/// A commit included in a push.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PushCommit {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub tree_id: String,
+    #[serde(default)]
+    pub distinct: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<crate::types::Author>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<crate::types::Author>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modified: Vec<String>,
+}
+
+/// The repository a push was made to.
+///
+/// Push events send the repository timestamps as unix timestamps rather than
+/// dates, so `crate::types::Repository` can not be used here.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PushRepository {
+    #[serde(default)]
+    pub id: i64,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub full_name: String,
+    #[serde(default)]
+    pub private: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub html_url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub default_branch: String,
+}
+
+/// Sent when one or more commits are pushed to a branch or tag.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PushEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty", rename = "ref")]
+    pub ref_: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub before: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub after: String,
+    #[serde(default)]
+    pub created: bool,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub forced: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub compare: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commits: Vec<PushCommit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_commit: Option<PushCommit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pusher: Option<crate::types::Author>,
+    pub repository: PushRepository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+}
+
+/// Sent when there is activity on a pull request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action: String,
+    #[serde(default)]
+    pub number: i64,
+    pub pull_request: crate::types::PullRequestData,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+}
+
+/// Sent when there is activity on an issue.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action: String,
+    pub issue: crate::types::Issue,
+    /// The label that was added or removed, for `labeled` and `unlabeled` actions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<crate::types::Label>,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+}
+
+/// Sent when there is activity on a comment on an issue or pull request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueCommentEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action: String,
+    pub issue: crate::types::Issue,
+    pub comment: crate::types::IssueComment,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+}
+
+/// Sent when a GitHub Actions workflow run is requested or completed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowRunEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action: String,
+    pub workflow_run: crate::types::WorkflowRun,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<crate::types::Workflow>,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+}
+
+/// Sent when there is activity on a release.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReleaseEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action: String,
+    pub release: crate::types::Release,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+}
@@ -0,0 +1,129 @@
+//! Trigger a workflow and wait for the run it creates to complete.
+//!
+//! https://docs.github.com/en/rest/reference/actions#create-a-workflow-dispatch-event
+use std::{collections::HashSet, time::Duration};
+
+use anyhow::{anyhow, Result};
+
+use crate::actions::Actions;
+
+/// Number of recent runs compared to find the run created by a dispatch.
+const RECENT_RUNS: i64 = 20;
+
+impl Actions {
+    /**
+     * Trigger a `workflow_dispatch` event and wait for the workflow run it creates
+     * to complete.
+     *
+     * The dispatch endpoint does not return the run it creates, so the runs of the
+     * workflow are polled until a new run shows up. That run is then polled every
+     * `poll_interval` until its status is `completed`, and returned. If that takes
+     * longer than `timeout` overall, an error is returned. The run keeps going on
+     * GitHub regardless.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `workflow_id: &str` -- The ID of the workflow. You can also pass the workflow file name as a string.
+     * * `ref_: &str` -- The git reference for the workflow. The reference can be a branch or tag name.
+     * * `inputs: serde_json::Value` -- Input keys and values configured in the workflow file, as an object.
+     * * `poll_interval: Duration` -- Time to wait between two status checks.
+     * * `timeout: Duration` -- Maximum time to wait for the run to complete.
+     */
+    pub async fn dispatch_workflow_and_wait(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        ref_: &str,
+        inputs: serde_json::Value,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<crate::types::WorkflowRun> {
+        let deadline = web_time::Instant::now() + timeout;
+
+        // Remember the runs that already exist, the new run is the one that is not
+        // in this list.
+        let existing: HashSet<i64> = self
+            .recent_dispatch_runs(owner, repo, workflow_id)
+            .await?
+            .into_iter()
+            .map(|run| run.id)
+            .collect();
+
+        let url = format!(
+            "/repos/{}/{}/actions/workflows/{}/dispatches",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(workflow_id),
+        );
+        let mut body = serde_json::json!({ "ref": ref_ });
+        if !inputs.is_null() {
+            body["inputs"] = inputs;
+        }
+        self.client
+            .post::<()>(&url, Some(reqwest::Body::from(serde_json::to_vec(&body)?)))
+            .await?;
+
+        let mut run = loop {
+            let created = self
+                .recent_dispatch_runs(owner, repo, workflow_id)
+                .await?
+                .into_iter()
+                .filter(|run| !existing.contains(&run.id))
+                .min_by_key(|run| run.id);
+            if let Some(run) = created {
+                break run;
+            }
+
+            if web_time::Instant::now() + poll_interval > deadline {
+                return Err(anyhow!(
+                    "timed out waiting for the run of workflow `{}` to be created",
+                    workflow_id
+                ));
+            }
+            crate::runtime::sleep(poll_interval).await;
+        };
+
+        while run.status != "completed" {
+            if web_time::Instant::now() + poll_interval > deadline {
+                return Err(anyhow!(
+                    "timed out waiting for workflow run {} to complete, last status: {}",
+                    run.id,
+                    run.status
+                ));
+            }
+            crate::runtime::sleep(poll_interval).await;
+
+            run = self.get_workflow_run(owner, repo, run.id).await?;
+        }
+
+        Ok(run)
+    }
+
+    /// The most recent runs of a workflow that were triggered by a dispatch.
+    async fn recent_dispatch_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> Result<Vec<crate::types::WorkflowRun>> {
+        let resp = self
+            .list_workflow_runs(
+                owner,
+                repo,
+                workflow_id,
+                "",
+                "",
+                "workflow_dispatch",
+                crate::types::WorkflowRunStatus::Noop,
+                RECENT_RUNS,
+                1,
+                None,
+            )
+            .await?;
+
+        Ok(resp.workflow_runs)
+    }
+}
@@ -21,7 +21,8 @@ impl Asps {
     *
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     */
-    pub async fn list(&self, user_key: &str) -> Result<crate::types::Asps> {
+    pub async fn list(&self, user_key: impl AsRef<str>) -> Result<crate::types::Asps> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/asps",
             crate::progenitor_support::encode_path(user_key),
@@ -40,11 +41,12 @@ impl Asps {
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     * * `code_id: i64` -- The unique ID of the ASP.
     */
-    pub async fn get(&self, user_key: &str, code_id: i64) -> Result<crate::types::Asp> {
+    pub async fn get(&self, user_key: impl AsRef<str>, code_id: i64) -> Result<crate::types::Asp> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/asps/{}",
             crate::progenitor_support::encode_path(user_key),
-            crate::progenitor_support::encode_path(&code_id.to_string()),
+            crate::progenitor_support::encode_path(&code_id),
         );
 
         self.client.get(&url, None).await
@@ -60,11 +62,12 @@ impl Asps {
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     * * `code_id: i64` -- The unique ID of the ASP to be deleted.
     */
-    pub async fn delete(&self, user_key: &str, code_id: i64) -> Result<()> {
+    pub async fn delete(&self, user_key: impl AsRef<str>, code_id: i64) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/asps/{}",
             crate::progenitor_support::encode_path(user_key),
-            crate::progenitor_support::encode_path(&code_id.to_string()),
+            crate::progenitor_support::encode_path(&code_id),
         );
 
         self.client.delete(&url, None).await
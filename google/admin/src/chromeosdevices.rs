@@ -30,7 +30,7 @@ impl Chromeosdevices {
     */
     pub async fn list(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         max_results: i64,
         order_by: crate::types::OrderBy,
         org_unit_path: &str,
@@ -39,33 +39,35 @@ impl Chromeosdevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::ChromeOsDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/chromeos",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ChromeOsDevices = self.client.get(&url, None).await?;
@@ -83,34 +85,36 @@ impl Chromeosdevices {
     */
     pub async fn list_all(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         order_by: crate::types::OrderBy,
         org_unit_path: &str,
         projection: crate::types::Projection,
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::ChromeOsDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/chromeos",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ChromeOsDevices = self.client.get(&url, None).await?;
@@ -120,17 +124,13 @@ impl Chromeosdevices {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             chromeosdevices.append(&mut resp.chromeosdevices);
 
@@ -157,19 +157,21 @@ impl Chromeosdevices {
     */
     pub async fn move_devices_ou(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         org_unit_path: &str,
         body: &crate::types::ChromeOsMoveDevicesOu,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/moveDevicesToOu?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/moveDevicesToOu",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -190,20 +192,23 @@ impl Chromeosdevices {
     */
     pub async fn get(
         &self,
-        customer_id: &str,
-        device_id: &str,
+        customer_id: impl AsRef<str>,
+        device_id: impl AsRef<str>,
         projection: crate::types::Projection,
     ) -> Result<crate::types::ChromeOsDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let device_id = device_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            crate::progenitor_support::encode_path(device_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -222,21 +227,24 @@ impl Chromeosdevices {
     */
     pub async fn update(
         &self,
-        customer_id: &str,
-        device_id: &str,
+        customer_id: impl AsRef<str>,
+        device_id: impl AsRef<str>,
         projection: crate::types::Projection,
         body: &crate::types::ChromeOsDevice,
     ) -> Result<crate::types::ChromeOsDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let device_id = device_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            crate::progenitor_support::encode_path(device_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -257,21 +265,24 @@ impl Chromeosdevices {
     */
     pub async fn patch(
         &self,
-        customer_id: &str,
-        device_id: &str,
+        customer_id: impl AsRef<str>,
+        device_id: impl AsRef<str>,
         projection: crate::types::Projection,
         body: &crate::types::ChromeOsDevice,
     ) -> Result<crate::types::ChromeOsDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let device_id = device_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            crate::progenitor_support::encode_path(device_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -291,10 +302,12 @@ impl Chromeosdevices {
     */
     pub async fn action(
         &self,
-        customer_id: &str,
-        resource_id: &str,
+        customer_id: impl AsRef<str>,
+        resource_id: impl AsRef<str>,
         body: &crate::types::ChromeOsDeviceAction,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let resource_id = resource_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/chromeos/{}/action",
             crate::progenitor_support::encode_path(customer_id),
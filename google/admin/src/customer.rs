@@ -25,10 +25,13 @@ impl Customer {
     */
     pub async fn admin_devices_chromeos_commands_get(
         &self,
-        customer_id: &str,
-        device_id: &str,
-        command_id: &str,
+        customer_id: impl AsRef<str>,
+        device_id: impl AsRef<str>,
+        command_id: impl AsRef<str>,
     ) -> Result<crate::types::DirectoryChromeosdevicesCommand> {
+        let customer_id = customer_id.as_ref();
+        let device_id = device_id.as_ref();
+        let command_id = command_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/chromeos/{}/commands/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -51,10 +54,12 @@ impl Customer {
     */
     pub async fn admin_devices_chromeos_issue_command(
         &self,
-        customer_id: &str,
-        device_id: &str,
+        customer_id: impl AsRef<str>,
+        device_id: impl AsRef<str>,
         body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
     ) -> Result<crate::types::DirectoryChromeosdevicesIssueCommandResponse> {
+        let customer_id = customer_id.as_ref();
+        let device_id = device_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/chromeos/{}/issueCommand",
             crate::progenitor_support::encode_path(customer_id),
@@ -21,7 +21,8 @@ impl Customers {
     *
     * * `customer_key: &str` -- Id of the customer to be retrieved.
     */
-    pub async fn get(&self, customer_key: &str) -> Result<crate::types::Customer> {
+    pub async fn get(&self, customer_key: impl AsRef<str>) -> Result<crate::types::Customer> {
+        let customer_key = customer_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customers/{}",
             crate::progenitor_support::encode_path(customer_key),
@@ -41,9 +42,10 @@ impl Customers {
     */
     pub async fn update(
         &self,
-        customer_key: &str,
+        customer_key: impl AsRef<str>,
         body: &crate::types::Customer,
     ) -> Result<crate::types::Customer> {
+        let customer_key = customer_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customers/{}",
             crate::progenitor_support::encode_path(customer_key),
@@ -65,9 +67,10 @@ impl Customers {
     */
     pub async fn patch(
         &self,
-        customer_key: &str,
+        customer_key: impl AsRef<str>,
         body: &crate::types::Customer,
     ) -> Result<crate::types::Customer> {
+        let customer_key = customer_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customers/{}",
             crate::progenitor_support::encode_path(customer_key),
@@ -87,7 +90,11 @@ impl Customers {
     *
     * * `name: &str` -- Required. The name of the printer to retrieve. Format: customers/{customer_id}/chrome/printers/{printer_id}.
     */
-    pub async fn admin_chrome_printers_get(&self, name: &str) -> Result<crate::types::Printer> {
+    pub async fn admin_chrome_printers_get(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<crate::types::Printer> {
+        let name = name.as_ref();
         let url = format!(
             "/admin/directory/v1/{}",
             crate::progenitor_support::encode_path(name),
@@ -105,7 +112,11 @@ impl Customers {
     *
     * * `name: &str` -- Required. The name of the printer to be updated. Format: customers/{customer_id}/chrome/printers/{printer_id}.
     */
-    pub async fn admin_chrome_printers_delete(&self, name: &str) -> Result<crate::types::Empty> {
+    pub async fn admin_chrome_printers_delete(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<crate::types::Empty> {
+        let name = name.as_ref();
         let url = format!(
             "/admin/directory/v1/{}",
             crate::progenitor_support::encode_path(name),
@@ -127,23 +138,25 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_patch(
         &self,
-        name: &str,
+        name: impl AsRef<str>,
         clear_mask: &str,
         update_mask: &str,
         body: &crate::types::Printer,
     ) -> Result<crate::types::Printer> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let name = name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !clear_mask.is_empty() {
-            query_args.push(("clearMask".to_string(), clear_mask.to_string()));
+            query_args.push(("clearMask", clear_mask.to_string()));
         }
         if !update_mask.is_empty() {
-            query_args.push(("updateMask".to_string(), update_mask.to_string()));
+            query_args.push(("updateMask", update_mask.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}?{}",
-            crate::progenitor_support::encode_path(name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/{}",
+                crate::progenitor_support::encode_path(name),
+            ),
+            &query_args,
         );
 
         self.client
@@ -166,30 +179,32 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_list(
         &self,
-        parent: &str,
+        parent: impl AsRef<str>,
         filter: &str,
         org_unit_id: &str,
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Printer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let parent = parent.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !org_unit_id.is_empty() {
-            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+            query_args.push(("orgUnitId", org_unit_id.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers?{}",
-            crate::progenitor_support::encode_path(parent),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/{}/chrome/printers",
+                crate::progenitor_support::encode_path(parent),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListPrintersResponse = self.client.get(&url, None).await?;
@@ -207,22 +222,24 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_list_all(
         &self,
-        parent: &str,
+        parent: impl AsRef<str>,
         filter: &str,
         org_unit_id: &str,
     ) -> Result<Vec<crate::types::Printer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let parent = parent.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !org_unit_id.is_empty() {
-            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+            query_args.push(("orgUnitId", org_unit_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers?{}",
-            crate::progenitor_support::encode_path(parent),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/{}/chrome/printers",
+                crate::progenitor_support::encode_path(parent),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ListPrintersResponse = self.client.get(&url, None).await?;
@@ -232,17 +249,13 @@ impl Customers {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             printers.append(&mut resp.printers);
 
@@ -268,9 +281,10 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_create(
         &self,
-        parent: &str,
+        parent: impl AsRef<str>,
         body: &crate::types::Printer,
     ) -> Result<crate::types::Printer> {
+        let parent = parent.as_ref();
         let url = format!(
             "/admin/directory/v1/{}/chrome/printers",
             crate::progenitor_support::encode_path(parent),
@@ -292,9 +306,10 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_batch_create(
         &self,
-        parent: &str,
+        parent: impl AsRef<str>,
         body: &crate::types::BatchCreatePrintersRequest,
     ) -> Result<crate::types::BatchCreatePrintersResponse> {
+        let parent = parent.as_ref();
         let url = format!(
             "/admin/directory/v1/{}/chrome/printers:batchCreatePrinters",
             crate::progenitor_support::encode_path(parent),
@@ -316,9 +331,10 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_batch_delete(
         &self,
-        parent: &str,
+        parent: impl AsRef<str>,
         body: &crate::types::BatchDeletePrintersRequest,
     ) -> Result<crate::types::BatchDeletePrintersResponse> {
+        let parent = parent.as_ref();
         let url = format!(
             "/admin/directory/v1/{}/chrome/printers:batchDeletePrinters",
             crate::progenitor_support::encode_path(parent),
@@ -343,26 +359,28 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_list_printer_models(
         &self,
-        parent: &str,
+        parent: impl AsRef<str>,
         filter: &str,
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::PrinterModel>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let parent = parent.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
-            crate::progenitor_support::encode_path(parent),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels",
+                crate::progenitor_support::encode_path(parent),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListPrinterModelsResponse = self.client.get(&url, None).await?;
@@ -380,18 +398,20 @@ impl Customers {
     */
     pub async fn admin_chrome_printers_list_all_printer_models(
         &self,
-        parent: &str,
+        parent: impl AsRef<str>,
         filter: &str,
     ) -> Result<Vec<crate::types::PrinterModel>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let parent = parent.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
-            crate::progenitor_support::encode_path(parent),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels",
+                crate::progenitor_support::encode_path(parent),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ListPrinterModelsResponse = self.client.get(&url, None).await?;
@@ -401,17 +421,13 @@ impl Customers {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             printer_models.append(&mut resp.printer_models);
 
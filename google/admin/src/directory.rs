@@ -0,0 +1,251 @@
+//! Manage the users of a domain, their aliases and the members of its groups.
+//!
+//! The users of the API serialize all their booleans, so patching a user with a
+//! `User` would also reset the flags left to `false`, like `isAdmin` or
+//! `includeInGlobalAddressList`. The helpers here only send the fields they change.
+//!
+//! Managing a domain usually goes through a service account with domain-wide
+//! delegation, acting as an administrator of the domain:
+//!
+//! ```no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! use gsuite_api::types::User;
+//!
+//! let key = std::fs::read("service-account.json")?;
+//! let admin = gsuite_api::Client::new_from_service_account(
+//!     &key,
+//!     &[
+//!         "https://www.googleapis.com/auth/admin.directory.user",
+//!         "https://www.googleapis.com/auth/admin.directory.group.member",
+//!     ],
+//!     Some("admin@example.com"),
+//! )?;
+//!
+//! let users = admin.users();
+//! let user = User::account("ada@example.com", "Ada", "Lovelace", "a-long-password");
+//! users.insert(&user).await?;
+//! users
+//!     .set_aliases("ada@example.com", &["countess@example.com"])
+//!     .await?;
+//! admin
+//!     .members()
+//!     .set_members("engineering@example.com", "MEMBER", &["ada@example.com"])
+//!     .await?;
+//!
+//! users.suspend("charles@example.com").await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::{
+    members::Members,
+    types::{Alias, Member, User, UserName},
+    users::Users,
+};
+
+/// The aliases of a user, as listed by the API. The generated `Aliases` expects
+/// strings where the API returns objects.
+#[derive(Deserialize)]
+struct AliasList {
+    #[serde(default)]
+    aliases: Vec<Alias>,
+}
+
+/// The aliases or the members which were changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirectoryChanges {
+    /// The email addresses which were added.
+    pub added: Vec<String>,
+    /// The email addresses which were removed.
+    pub removed: Vec<String>,
+}
+
+impl User {
+    /// A new user of the domain, with their name and initial password, listed in the
+    /// global address list.
+    pub fn account(
+        primary_email: &str,
+        given_name: &str,
+        family_name: &str,
+        password: &str,
+    ) -> Self {
+        User {
+            primary_email: primary_email.to_string(),
+            name: Some(UserName {
+                given_name: given_name.to_string(),
+                family_name: family_name.to_string(),
+                full_name: String::new(),
+            }),
+            password: password.to_string(),
+            include_in_global_address_list: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl Users {
+    /**
+     * Update the given fields of a user only, leaving the others as they are.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- The primary email address, an alias or the ID of the
+     *   user.
+     * * `fields: &serde_json::Value` -- The fields to update, named as in the API,
+     *   like `{"orgUnitPath": "/Engineering"}`.
+     */
+    pub async fn patch_fields(&self, user_key: &str, fields: &serde_json::Value) -> Result<User> {
+        let url = format!(
+            "/admin/directory/v1/users/{}",
+            crate::progenitor_support::encode_path(user_key),
+        );
+
+        self.client
+            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(fields)?)))
+            .await
+    }
+
+    /**
+     * Suspend a user, who cannot sign in anymore until unsuspended.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- The primary email address, an alias or the ID of the
+     *   user.
+     */
+    pub async fn suspend(&self, user_key: &str) -> Result<User> {
+        self.patch_fields(user_key, &serde_json::json!({ "suspended": true }))
+            .await
+    }
+
+    /**
+     * Lift the suspension of a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- The primary email address, an alias or the ID of the
+     *   user.
+     */
+    pub async fn unsuspend(&self, user_key: &str) -> Result<User> {
+        self.patch_fields(user_key, &serde_json::json!({ "suspended": false }))
+            .await
+    }
+
+    /**
+     * List the aliases of a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- The primary email address, an alias or the ID of the
+     *   user.
+     */
+    pub async fn list_aliases(&self, user_key: &str) -> Result<Vec<Alias>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/aliases",
+            crate::progenitor_support::encode_path(user_key),
+        );
+
+        let list: AliasList = self.client.get(&url, None).await?;
+        Ok(list.aliases)
+    }
+
+    /**
+     * Give a user exactly the given aliases, removing the others.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- The primary email address, an alias or the ID of the
+     *   user.
+     * * `aliases: &[&str]` -- The email addresses the user should have as aliases.
+     */
+    pub async fn set_aliases(&self, user_key: &str, aliases: &[&str]) -> Result<DirectoryChanges> {
+        let current = self.list_aliases(user_key).await?;
+        let mut changes = DirectoryChanges::default();
+
+        for alias in aliases {
+            if current.iter().any(|a| a.alias.eq_ignore_ascii_case(alias))
+                || changes.added.iter().any(|a| a.eq_ignore_ascii_case(alias))
+            {
+                continue;
+            }
+            let body = Alias {
+                alias: alias.to_string(),
+                etag: String::new(),
+                id: String::new(),
+                kind: String::new(),
+                primary_email: String::new(),
+            };
+            self.aliases_insert(user_key, &body).await?;
+            changes.added.push(alias.to_string());
+        }
+
+        for alias in current {
+            if aliases.iter().any(|a| a.eq_ignore_ascii_case(&alias.alias)) {
+                continue;
+            }
+            self.aliases_delete(user_key, &alias.alias).await?;
+            changes.removed.push(alias.alias);
+        }
+
+        Ok(changes)
+    }
+}
+
+impl Members {
+    /**
+     * Make the given email addresses exactly the members of a group with a role,
+     * removing the other members with that role.
+     *
+     * The members with another role are left as they are, and adding one of them
+     * fails as they are a member already.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- The email address, an alias or the ID of the group.
+     * * `role: &str` -- The role of the members: `MEMBER`, `MANAGER` or `OWNER`.
+     * * `emails: &[&str]` -- The email addresses of the users or groups which should
+     *   be members.
+     */
+    pub async fn set_members(
+        &self,
+        group_key: &str,
+        role: &str,
+        emails: &[&str],
+    ) -> Result<DirectoryChanges> {
+        let current = self.list_all(group_key, false, role).await?;
+        let mut changes = DirectoryChanges::default();
+
+        for email in emails {
+            if current.iter().any(|m| m.email.eq_ignore_ascii_case(email))
+                || changes.added.iter().any(|e| e.eq_ignore_ascii_case(email))
+            {
+                continue;
+            }
+            let member = Member {
+                delivery_settings: String::new(),
+                email: email.to_string(),
+                etag: String::new(),
+                id: String::new(),
+                kind: String::new(),
+                role: role.to_string(),
+                status: String::new(),
+                type_: String::new(),
+            };
+            self.insert(group_key, &member).await?;
+            changes.added.push(email.to_string());
+        }
+
+        for member in current {
+            if emails.iter().any(|e| e.eq_ignore_ascii_case(&member.email)) {
+                continue;
+            }
+            // Members are removed by ID, as the email address of a member can change.
+            self.delete(group_key, &member.id).await?;
+            changes.removed.push(member.email);
+        }
+
+        Ok(changes)
+    }
+}
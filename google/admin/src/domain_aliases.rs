@@ -24,21 +24,20 @@ impl DomainAliases {
     */
     pub async fn list(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         parent_domain_name: &str,
     ) -> Result<crate::types::DomainAliases> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent_domain_name.is_empty() {
-            query_args.push((
-                "parentDomainName".to_string(),
-                parent_domain_name.to_string(),
-            ));
+            query_args.push(("parentDomainName", parent_domain_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/domainaliases?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/domainaliases",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -55,9 +54,10 @@ impl DomainAliases {
     */
     pub async fn insert(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         body: &crate::types::DomainAlias,
     ) -> Result<crate::types::DomainAlias> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/domainaliases",
             crate::progenitor_support::encode_path(customer),
@@ -80,9 +80,11 @@ impl DomainAliases {
     */
     pub async fn get(
         &self,
-        customer: &str,
-        domain_alias_name: &str,
+        customer: impl AsRef<str>,
+        domain_alias_name: impl AsRef<str>,
     ) -> Result<crate::types::DomainAlias> {
+        let customer = customer.as_ref();
+        let domain_alias_name = domain_alias_name.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/domainaliases/{}",
             crate::progenitor_support::encode_path(customer),
@@ -102,7 +104,13 @@ impl DomainAliases {
     * * `customer: &str` -- Immutable ID of the Google Workspace account.
     * * `domain_alias_name: &str` -- Name of domain alias to be retrieved.
     */
-    pub async fn delete(&self, customer: &str, domain_alias_name: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        customer: impl AsRef<str>,
+        domain_alias_name: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer = customer.as_ref();
+        let domain_alias_name = domain_alias_name.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/domainaliases/{}",
             crate::progenitor_support::encode_path(customer),
@@ -21,7 +21,8 @@ impl Domains {
     *
     * * `customer: &str` -- Immutable ID of the Google Workspace account.
     */
-    pub async fn list(&self, customer: &str) -> Result<crate::types::Domains2> {
+    pub async fn list(&self, customer: impl AsRef<str>) -> Result<crate::types::Domains2> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/domains",
             crate::progenitor_support::encode_path(customer),
@@ -41,9 +42,10 @@ impl Domains {
     */
     pub async fn insert(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         body: &crate::types::Domains,
     ) -> Result<crate::types::Domains> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/domains",
             crate::progenitor_support::encode_path(customer),
@@ -64,7 +66,13 @@ impl Domains {
     * * `customer: &str` -- Immutable ID of the Google Workspace account.
     * * `domain_name: &str` -- Name of domain to be retrieved.
     */
-    pub async fn get(&self, customer: &str, domain_name: &str) -> Result<crate::types::Domains> {
+    pub async fn get(
+        &self,
+        customer: impl AsRef<str>,
+        domain_name: impl AsRef<str>,
+    ) -> Result<crate::types::Domains> {
+        let customer = customer.as_ref();
+        let domain_name = domain_name.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/domains/{}",
             crate::progenitor_support::encode_path(customer),
@@ -84,7 +92,13 @@ impl Domains {
     * * `customer: &str` -- Immutable ID of the Google Workspace account.
     * * `domain_name: &str` -- Name of domain to be deleted.
     */
-    pub async fn delete(&self, customer: &str, domain_name: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        customer: impl AsRef<str>,
+        domain_name: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer = customer.as_ref();
+        let domain_name = domain_name.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/domains/{}",
             crate::progenitor_support::encode_path(customer),
@@ -0,0 +1,281 @@
+//! Recorded fixtures, to test code using the client without a live network.
+//!
+//! A `FixtureTransport` in record mode sends the requests to the API and writes
+//! every request along with its response to a fixture file. In replay mode, it
+//! answers the requests from the file instead, so that tests are fast and
+//! deterministic.
+//!
+//! The credentials in headers are never written to the fixtures, nor are the
+//! values of query parameters with `key` or `token` in their name.
+//!
+//! ```no_run
+//! # fn run(client: gsuite_api::Client) -> anyhow::Result<()> {
+//! use gsuite_api::fixtures::FixtureTransport;
+//!
+//! // Record the fixtures with `FIXTURES=record cargo test`, and replay them with
+//! // `cargo test`.
+//! let transport = FixtureTransport::from_env("tests/fixtures/list_users.json")?;
+//! let client = client.with_transport(transport);
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::{Request, Response, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::transport::HttpTransport;
+
+/// Whether a `FixtureTransport` records or replays the fixtures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureMode {
+    /// Send the requests to the API and write them to the fixture file, replacing
+    /// it.
+    Record,
+    /// Answer the requests from the fixture file.
+    Replay,
+}
+
+/// The requests and responses of a fixture file, in the order they were made.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Fixture {
+    pub interactions: Vec<Interaction>,
+}
+
+/// A request along with its response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// The URL of the request, with the values of the credentials redacted.
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Body>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    pub body: Body,
+}
+
+/// A body, as text when it is valid UTF-8 and as bytes otherwise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Body {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl Body {
+    fn new(bytes: &[u8]) -> Self {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Body::Text(text.to_string()),
+            Err(_) => Body::Bytes(bytes.to_vec()),
+        }
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Body::Text(text) => text.into_bytes(),
+            Body::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+/// A transport recording the requests made with the client to a fixture file, or
+/// replaying them from it.
+///
+/// Clones share the same fixture.
+#[derive(Debug, Clone)]
+pub struct FixtureTransport {
+    mode: FixtureMode,
+    path: PathBuf,
+    client: reqwest::Client,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    fixture: Fixture,
+    /// Which of the interactions were replayed already.
+    replayed: Vec<bool>,
+}
+
+impl FixtureTransport {
+    /// Send the requests to the API and record them to the file at `path`.
+    pub fn record<P>(path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        FixtureTransport {
+            mode: FixtureMode::Record,
+            path: path.as_ref().to_path_buf(),
+            client: reqwest::Client::new(),
+            state: Default::default(),
+        }
+    }
+
+    /// Answer the requests from the fixture file at `path`.
+    pub fn replay<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let contents =
+            fs::read(path).with_context(|| format!("reading fixture {}", path.display()))?;
+        let fixture: Fixture = serde_json::from_slice(&contents)?;
+
+        Ok(FixtureTransport {
+            mode: FixtureMode::Replay,
+            path: path.to_path_buf(),
+            client: reqwest::Client::new(),
+            state: Arc::new(Mutex::new(State {
+                replayed: vec![false; fixture.interactions.len()],
+                fixture,
+            })),
+        })
+    }
+
+    /// Record when the `FIXTURES` environment variable is `record`, and replay
+    /// otherwise.
+    pub fn from_env<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        match std::env::var("FIXTURES").as_deref() {
+            Ok("record") => Ok(FixtureTransport::record(path)),
+            _ => FixtureTransport::replay(path),
+        }
+    }
+
+    pub fn mode(&self) -> FixtureMode {
+        self.mode
+    }
+
+    /// The requests and responses recorded or loaded so far.
+    pub fn fixture(&self) -> Fixture {
+        self.state.lock().unwrap().fixture.clone()
+    }
+
+    async fn record_request(&self, req: Request) -> Result<Response> {
+        let request = recorded_request(&req);
+        let resp = self.client.execute(req).await?;
+
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?;
+
+        let mut state = self.state.lock().unwrap();
+        state.fixture.interactions.push(Interaction {
+            request,
+            response: RecordedResponse {
+                status: status.as_u16(),
+                headers: headers
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.to_string(), value.to_str().ok()?.to_string()))
+                    })
+                    .collect(),
+                body: Body::new(&body),
+            },
+        });
+        // Write the file after every request, so that nothing is lost when a test
+        // fails halfway.
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_vec_pretty(&state.fixture)?)
+            .with_context(|| format!("writing fixture {}", self.path.display()))?;
+
+        let mut builder = http::Response::builder().status(status);
+        *builder.headers_mut().unwrap() = headers;
+        Ok(Response::from(builder.body(body)?))
+    }
+
+    fn replay_request(&self, req: Request) -> Result<Response> {
+        let request = recorded_request(&req);
+
+        let mut state = self.state.lock().unwrap();
+        let State { fixture, replayed } = &mut *state;
+        let (i, interaction) = fixture
+            .interactions
+            .iter()
+            .enumerate()
+            .find(|(i, interaction)| {
+                !replayed[*i]
+                    && interaction.request.method == request.method
+                    && interaction.request.url == request.url
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "no interaction recorded in {} for {} {}",
+                    self.path.display(),
+                    request.method,
+                    request.url
+                )
+            })?;
+        replayed[i] = true;
+
+        let response = interaction.response.clone();
+        let mut builder = http::Response::builder().status(response.status);
+        for (name, value) in &response.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        Ok(Response::from(builder.body(response.body.into_bytes())?))
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for FixtureTransport {
+    async fn send(&self, req: Request) -> Result<Response> {
+        match self.mode {
+            FixtureMode::Record => self.record_request(req).await,
+            FixtureMode::Replay => self.replay_request(req),
+        }
+    }
+}
+
+fn recorded_request(req: &Request) -> RecordedRequest {
+    RecordedRequest {
+        method: req.method().to_string(),
+        url: redact(req.url()),
+        body: req.body().and_then(|b| b.as_bytes()).map(Body::new),
+    }
+}
+
+/// The URL without the values of the query parameters that might be credentials.
+fn redact(url: &Url) -> String {
+    let mut url = url.clone();
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+
+    if url.query().is_some() {
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(name, value)| {
+                let lower = name.to_lowercase();
+                if lower.contains("key") || lower.contains("token") {
+                    (name.to_string(), "REDACTED".to_string())
+                } else {
+                    (name.to_string(), value.to_string())
+                }
+            })
+            .collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    url.to_string()
+}
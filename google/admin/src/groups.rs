@@ -39,33 +39,35 @@ impl Groups {
         sort_order: crate::types::SortOrder,
         user_key: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/groups?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/directory/v1/groups"),
+            &query_args,
+        );
 
         let resp: crate::types::Groups = self.client.get(&url, None).await?;
 
@@ -89,27 +91,29 @@ impl Groups {
         sort_order: crate::types::SortOrder,
         user_key: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/groups?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/directory/v1/groups"),
+            &query_args,
+        );
 
         let mut resp: crate::types::Groups = self.client.get(&url, None).await?;
 
@@ -118,17 +122,13 @@ impl Groups {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             groups.append(&mut resp.groups);
 
@@ -164,7 +164,8 @@ impl Groups {
     *
     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
     */
-    pub async fn get(&self, group_key: &str) -> Result<crate::types::Group> {
+    pub async fn get(&self, group_key: impl AsRef<str>) -> Result<crate::types::Group> {
+        let group_key = group_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -184,9 +185,10 @@ impl Groups {
     */
     pub async fn update(
         &self,
-        group_key: &str,
+        group_key: impl AsRef<str>,
         body: &crate::types::Group,
     ) -> Result<crate::types::Group> {
+        let group_key = group_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -206,7 +208,8 @@ impl Groups {
     *
     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
     */
-    pub async fn delete(&self, group_key: &str) -> Result<()> {
+    pub async fn delete(&self, group_key: impl AsRef<str>) -> Result<()> {
+        let group_key = group_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -226,9 +229,10 @@ impl Groups {
     */
     pub async fn patch(
         &self,
-        group_key: &str,
+        group_key: impl AsRef<str>,
         body: &crate::types::Group,
     ) -> Result<crate::types::Group> {
+        let group_key = group_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -248,7 +252,8 @@ impl Groups {
     *
     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
     */
-    pub async fn aliases_list(&self, group_key: &str) -> Result<crate::types::Aliases> {
+    pub async fn aliases_list(&self, group_key: impl AsRef<str>) -> Result<crate::types::Aliases> {
+        let group_key = group_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/aliases",
             crate::progenitor_support::encode_path(group_key),
@@ -268,9 +273,10 @@ impl Groups {
     */
     pub async fn aliases_insert(
         &self,
-        group_key: &str,
+        group_key: impl AsRef<str>,
         body: &crate::types::Alias,
     ) -> Result<crate::types::Alias> {
+        let group_key = group_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/aliases",
             crate::progenitor_support::encode_path(group_key),
@@ -291,7 +297,13 @@ impl Groups {
     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
     * * `alias: &str` -- The alias to be removed.
     */
-    pub async fn aliases_delete(&self, group_key: &str, alias: &str) -> Result<()> {
+    pub async fn aliases_delete(
+        &self,
+        group_key: impl AsRef<str>,
+        alias: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_key = group_key.as_ref();
+        let alias = alias.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/aliases/{}",
             crate::progenitor_support::encode_path(group_key),
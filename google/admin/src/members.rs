@@ -24,9 +24,11 @@ impl Members {
     */
     pub async fn has(
         &self,
-        group_key: &str,
-        member_key: &str,
+        group_key: impl AsRef<str>,
+        member_key: impl AsRef<str>,
     ) -> Result<crate::types::MembersHasMember> {
+        let group_key = group_key.as_ref();
+        let member_key = member_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/hasMember/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -51,33 +53,35 @@ impl Members {
     */
     pub async fn list(
         &self,
-        group_key: &str,
+        group_key: impl AsRef<str>,
         include_derived_membership: bool,
         max_results: i64,
         page_token: &str,
         roles: &str,
     ) -> Result<Vec<crate::types::Member>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_key = group_key.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_derived_membership {
             query_args.push((
-                "includeDerivedMembership".to_string(),
+                "includeDerivedMembership",
                 include_derived_membership.to_string(),
             ));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !roles.is_empty() {
-            query_args.push(("roles".to_string(), roles.to_string()));
+            query_args.push(("roles", roles.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(group_key),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/groups/{}/members",
+                crate::progenitor_support::encode_path(group_key),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Members = self.client.get(&url, None).await?;
@@ -95,25 +99,27 @@ impl Members {
     */
     pub async fn list_all(
         &self,
-        group_key: &str,
+        group_key: impl AsRef<str>,
         include_derived_membership: bool,
         roles: &str,
     ) -> Result<Vec<crate::types::Member>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_key = group_key.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_derived_membership {
             query_args.push((
-                "includeDerivedMembership".to_string(),
+                "includeDerivedMembership",
                 include_derived_membership.to_string(),
             ));
         }
         if !roles.is_empty() {
-            query_args.push(("roles".to_string(), roles.to_string()));
+            query_args.push(("roles", roles.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(group_key),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/groups/{}/members",
+                crate::progenitor_support::encode_path(group_key),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::Members = self.client.get(&url, None).await?;
@@ -123,17 +129,13 @@ impl Members {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             members.append(&mut resp.members);
 
@@ -159,9 +161,10 @@ impl Members {
     */
     pub async fn insert(
         &self,
-        group_key: &str,
+        group_key: impl AsRef<str>,
         body: &crate::types::Member,
     ) -> Result<crate::types::Member> {
+        let group_key = group_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/members",
             crate::progenitor_support::encode_path(group_key),
@@ -182,7 +185,13 @@ impl Members {
     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
     * * `member_key: &str` -- Identifies the group member in the API request. A group member can be a user or another group. The value can be the member's (group or user) primary email address, alias, or unique ID.
     */
-    pub async fn get(&self, group_key: &str, member_key: &str) -> Result<crate::types::Member> {
+    pub async fn get(
+        &self,
+        group_key: impl AsRef<str>,
+        member_key: impl AsRef<str>,
+    ) -> Result<crate::types::Member> {
+        let group_key = group_key.as_ref();
+        let member_key = member_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -204,10 +213,12 @@ impl Members {
     */
     pub async fn update(
         &self,
-        group_key: &str,
-        member_key: &str,
+        group_key: impl AsRef<str>,
+        member_key: impl AsRef<str>,
         body: &crate::types::Member,
     ) -> Result<crate::types::Member> {
+        let group_key = group_key.as_ref();
+        let member_key = member_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -229,7 +240,13 @@ impl Members {
     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
     * * `member_key: &str` -- Identifies the group member in the API request. A group member can be a user or another group. The value can be the member's (group or user) primary email address, alias, or unique ID.
     */
-    pub async fn delete(&self, group_key: &str, member_key: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        group_key: impl AsRef<str>,
+        member_key: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_key = group_key.as_ref();
+        let member_key = member_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -251,10 +268,12 @@ impl Members {
     */
     pub async fn patch(
         &self,
-        group_key: &str,
-        member_key: &str,
+        group_key: impl AsRef<str>,
+        member_key: impl AsRef<str>,
         body: &crate::types::Member,
     ) -> Result<crate::types::Member> {
+        let group_key = group_key.as_ref();
+        let member_key = member_key.as_ref();
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
             crate::progenitor_support::encode_path(group_key),
@@ -0,0 +1,184 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: gsuite_api::Client) {
+//! use gsuite_api::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "Google Admin";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/admin/directory/v1/customer/{customerId}/devices/chromeos",
+    "/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu",
+    "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}",
+    "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}/commands/{commandId}",
+    "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}:issueCommand",
+    "/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action",
+    "/admin/directory/v1/customer/{customerId}/devices/mobile",
+    "/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}",
+    "/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}/action",
+    "/admin/directory/v1/customer/{customerId}/orgunits",
+    "/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}",
+    "/admin/directory/v1/customer/{customerId}/schemas",
+    "/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}",
+    "/admin/directory/v1/customer/{customer}/domainaliases",
+    "/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}",
+    "/admin/directory/v1/customer/{customer}/domains",
+    "/admin/directory/v1/customer/{customer}/domains/{domainName}",
+    "/admin/directory/v1/customer/{customer}/resources/buildings",
+    "/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}",
+    "/admin/directory/v1/customer/{customer}/resources/calendars",
+    "/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}",
+    "/admin/directory/v1/customer/{customer}/resources/features",
+    "/admin/directory/v1/customer/{customer}/resources/features/{featureKey}",
+    "/admin/directory/v1/customer/{customer}/resources/features/{oldName}/rename",
+    "/admin/directory/v1/customer/{customer}/roleassignments",
+    "/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}",
+    "/admin/directory/v1/customer/{customer}/roles",
+    "/admin/directory/v1/customer/{customer}/roles/ALL/privileges",
+    "/admin/directory/v1/customer/{customer}/roles/{roleId}",
+    "/admin/directory/v1/customers/{customerKey}",
+    "/admin/directory/v1/groups",
+    "/admin/directory/v1/groups/{groupKey}",
+    "/admin/directory/v1/groups/{groupKey}/aliases",
+    "/admin/directory/v1/groups/{groupKey}/aliases/{alias}",
+    "/admin/directory/v1/groups/{groupKey}/hasMember/{memberKey}",
+    "/admin/directory/v1/groups/{groupKey}/members",
+    "/admin/directory/v1/groups/{groupKey}/members/{memberKey}",
+    "/admin/directory/v1/users",
+    "/admin/directory/v1/users/watch",
+    "/admin/directory/v1/users/{userKey}",
+    "/admin/directory/v1/users/{userKey}/aliases",
+    "/admin/directory/v1/users/{userKey}/aliases/watch",
+    "/admin/directory/v1/users/{userKey}/aliases/{alias}",
+    "/admin/directory/v1/users/{userKey}/asps",
+    "/admin/directory/v1/users/{userKey}/asps/{codeId}",
+    "/admin/directory/v1/users/{userKey}/makeAdmin",
+    "/admin/directory/v1/users/{userKey}/photos/thumbnail",
+    "/admin/directory/v1/users/{userKey}/signOut",
+    "/admin/directory/v1/users/{userKey}/tokens",
+    "/admin/directory/v1/users/{userKey}/tokens/{clientId}",
+    "/admin/directory/v1/users/{userKey}/twoStepVerification/turnOff",
+    "/admin/directory/v1/users/{userKey}/undelete",
+    "/admin/directory/v1/users/{userKey}/verificationCodes",
+    "/admin/directory/v1/users/{userKey}/verificationCodes/generate",
+    "/admin/directory/v1/users/{userKey}/verificationCodes/invalidate",
+    "/admin/directory/v1/{name}",
+    "/admin/directory/v1/{parent}/chrome/printers",
+    "/admin/directory/v1/{parent}/chrome/printers:batchCreatePrinters",
+    "/admin/directory/v1/{parent}/chrome/printers:batchDeletePrinters",
+    "/admin/directory/v1/{parent}/chrome/printers:listPrinterModels",
+    "/admin/directory_v1/channels/stop",
+];
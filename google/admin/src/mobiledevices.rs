@@ -29,7 +29,7 @@ impl Mobiledevices {
     */
     pub async fn list(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         max_results: i64,
         order_by: crate::types::DirectoryMobiledevicesListOrderBy,
         page_token: &str,
@@ -37,30 +37,32 @@ impl Mobiledevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::MobileDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/mobile?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/mobile",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::MobileDevices = self.client.get(&url, None).await?;
@@ -78,30 +80,32 @@ impl Mobiledevices {
     */
     pub async fn list_all(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         order_by: crate::types::DirectoryMobiledevicesListOrderBy,
         projection: crate::types::Projection,
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::MobileDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/mobile?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/mobile",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::MobileDevices = self.client.get(&url, None).await?;
@@ -111,17 +115,13 @@ impl Mobiledevices {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             mobiledevices.append(&mut resp.mobiledevices);
 
@@ -149,20 +149,23 @@ impl Mobiledevices {
     */
     pub async fn get(
         &self,
-        customer_id: &str,
-        resource_id: &str,
+        customer_id: impl AsRef<str>,
+        resource_id: impl AsRef<str>,
         projection: crate::types::Projection,
     ) -> Result<crate::types::MobileDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let resource_id = resource_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/mobile/{}?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            crate::progenitor_support::encode_path(resource_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/devices/mobile/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(resource_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -178,7 +181,13 @@ impl Mobiledevices {
     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
     * * `resource_id: &str` -- The unique ID the API service uses to identify the mobile device.
     */
-    pub async fn delete(&self, customer_id: &str, resource_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        customer_id: impl AsRef<str>,
+        resource_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let resource_id = resource_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/mobile/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -200,10 +209,12 @@ impl Mobiledevices {
     */
     pub async fn action(
         &self,
-        customer_id: &str,
-        resource_id: &str,
+        customer_id: impl AsRef<str>,
+        resource_id: impl AsRef<str>,
         body: &crate::types::MobileDeviceAction,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let resource_id = resource_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/mobile/{}/action",
             crate::progenitor_support::encode_path(customer_id),
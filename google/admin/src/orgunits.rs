@@ -25,22 +25,24 @@ impl Orgunits {
     */
     pub async fn list(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         org_unit_path: &str,
         type_: crate::types::DirectoryOrgunitsListType,
     ) -> Result<crate::types::OrgUnits> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/orgunits?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/orgunits",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -57,9 +59,10 @@ impl Orgunits {
     */
     pub async fn insert(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &crate::types::OrgUnit,
     ) -> Result<crate::types::OrgUnit> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits",
             crate::progenitor_support::encode_path(customer_id),
@@ -82,9 +85,11 @@ impl Orgunits {
     */
     pub async fn get(
         &self,
-        customer_id: &str,
-        org_unit_path: &str,
+        customer_id: impl AsRef<str>,
+        org_unit_path: impl AsRef<str>,
     ) -> Result<crate::types::OrgUnit> {
+        let customer_id = customer_id.as_ref();
+        let org_unit_path = org_unit_path.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -106,10 +111,12 @@ impl Orgunits {
     */
     pub async fn update(
         &self,
-        customer_id: &str,
-        org_unit_path: &str,
+        customer_id: impl AsRef<str>,
+        org_unit_path: impl AsRef<str>,
         body: &crate::types::OrgUnit,
     ) -> Result<crate::types::OrgUnit> {
+        let customer_id = customer_id.as_ref();
+        let org_unit_path = org_unit_path.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -131,7 +138,13 @@ impl Orgunits {
     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
     * * `org_unit_path: &str` -- The full path of the organizational unit or its unique ID.
     */
-    pub async fn delete(&self, customer_id: &str, org_unit_path: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        customer_id: impl AsRef<str>,
+        org_unit_path: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let org_unit_path = org_unit_path.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -153,10 +166,12 @@ impl Orgunits {
     */
     pub async fn patch(
         &self,
-        customer_id: &str,
-        org_unit_path: &str,
+        customer_id: impl AsRef<str>,
+        org_unit_path: impl AsRef<str>,
         body: &crate::types::OrgUnit,
     ) -> Result<crate::types::OrgUnit> {
+        let customer_id = customer_id.as_ref();
+        let org_unit_path = org_unit_path.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -25,22 +25,24 @@ impl Resources {
     */
     pub async fn buildings_list(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Building>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/resources/buildings",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Buildings = self.client.get(&url, None).await?;
@@ -56,7 +58,11 @@ impl Resources {
     *
     * Retrieves a list of buildings for an account.
     */
-    pub async fn buildings_list_all(&self, customer: &str) -> Result<Vec<crate::types::Building>> {
+    pub async fn buildings_list_all(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Building>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/buildings",
             crate::progenitor_support::encode_path(customer),
@@ -69,17 +75,13 @@ impl Resources {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             buildings.append(&mut resp.buildings);
 
@@ -106,22 +108,21 @@ impl Resources {
     */
     pub async fn buildings_insert(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
-            query_args.push((
-                "coordinatesSource".to_string(),
-                coordinates_source.to_string(),
-            ));
+            query_args.push(("coordinatesSource", coordinates_source.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/resources/buildings",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         self.client
@@ -141,9 +142,11 @@ impl Resources {
     */
     pub async fn buildings_get(
         &self,
-        customer: &str,
-        building_id: &str,
+        customer: impl AsRef<str>,
+        building_id: impl AsRef<str>,
     ) -> Result<crate::types::Building> {
+        let customer = customer.as_ref();
+        let building_id = building_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/buildings/{}",
             crate::progenitor_support::encode_path(customer),
@@ -166,24 +169,24 @@ impl Resources {
     */
     pub async fn buildings_update(
         &self,
-        customer: &str,
-        building_id: &str,
+        customer: impl AsRef<str>,
+        building_id: impl AsRef<str>,
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let building_id = building_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
-            query_args.push((
-                "coordinatesSource".to_string(),
-                coordinates_source.to_string(),
-            ));
+            query_args.push(("coordinatesSource", coordinates_source.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
-            crate::progenitor_support::encode_path(customer),
-            crate::progenitor_support::encode_path(building_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -201,7 +204,13 @@ impl Resources {
     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
     * * `building_id: &str` -- The id of the building to delete.
     */
-    pub async fn buildings_delete(&self, customer: &str, building_id: &str) -> Result<()> {
+    pub async fn buildings_delete(
+        &self,
+        customer: impl AsRef<str>,
+        building_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer = customer.as_ref();
+        let building_id = building_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/buildings/{}",
             crate::progenitor_support::encode_path(customer),
@@ -224,24 +233,24 @@ impl Resources {
     */
     pub async fn buildings_patch(
         &self,
-        customer: &str,
-        building_id: &str,
+        customer: impl AsRef<str>,
+        building_id: impl AsRef<str>,
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let building_id = building_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
-            query_args.push((
-                "coordinatesSource".to_string(),
-                coordinates_source.to_string(),
-            ));
+            query_args.push(("coordinatesSource", coordinates_source.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
-            crate::progenitor_support::encode_path(customer),
-            crate::progenitor_support::encode_path(building_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -264,30 +273,32 @@ impl Resources {
     */
     pub async fn calendars_list(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         max_results: i64,
         order_by: &str,
         page_token: &str,
         query: &str,
     ) -> Result<Vec<crate::types::CalendarResource>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/calendars?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/resources/calendars",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::CalendarResources = self.client.get(&url, None).await?;
@@ -305,22 +316,24 @@ impl Resources {
     */
     pub async fn calendars_list_all(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         order_by: &str,
         query: &str,
     ) -> Result<Vec<crate::types::CalendarResource>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/calendars?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/resources/calendars",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::CalendarResources = self.client.get(&url, None).await?;
@@ -330,17 +343,13 @@ impl Resources {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -366,9 +375,10 @@ impl Resources {
     */
     pub async fn calendars_insert(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         body: &crate::types::CalendarResource,
     ) -> Result<crate::types::CalendarResource> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars",
             crate::progenitor_support::encode_path(customer),
@@ -391,9 +401,11 @@ impl Resources {
     */
     pub async fn calendars_get(
         &self,
-        customer: &str,
-        calendar_resource_id: &str,
+        customer: impl AsRef<str>,
+        calendar_resource_id: impl AsRef<str>,
     ) -> Result<crate::types::CalendarResource> {
+        let customer = customer.as_ref();
+        let calendar_resource_id = calendar_resource_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
             crate::progenitor_support::encode_path(customer),
@@ -415,10 +427,12 @@ impl Resources {
     */
     pub async fn calendars_update(
         &self,
-        customer: &str,
-        calendar_resource_id: &str,
+        customer: impl AsRef<str>,
+        calendar_resource_id: impl AsRef<str>,
         body: &crate::types::CalendarResource,
     ) -> Result<crate::types::CalendarResource> {
+        let customer = customer.as_ref();
+        let calendar_resource_id = calendar_resource_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
             crate::progenitor_support::encode_path(customer),
@@ -440,7 +454,13 @@ impl Resources {
     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
     * * `calendar_resource_id: &str` -- The unique ID of the calendar resource to delete.
     */
-    pub async fn calendars_delete(&self, customer: &str, calendar_resource_id: &str) -> Result<()> {
+    pub async fn calendars_delete(
+        &self,
+        customer: impl AsRef<str>,
+        calendar_resource_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer = customer.as_ref();
+        let calendar_resource_id = calendar_resource_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
             crate::progenitor_support::encode_path(customer),
@@ -462,10 +482,12 @@ impl Resources {
     */
     pub async fn calendars_patch(
         &self,
-        customer: &str,
-        calendar_resource_id: &str,
+        customer: impl AsRef<str>,
+        calendar_resource_id: impl AsRef<str>,
         body: &crate::types::CalendarResource,
     ) -> Result<crate::types::CalendarResource> {
+        let customer = customer.as_ref();
+        let calendar_resource_id = calendar_resource_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
             crate::progenitor_support::encode_path(customer),
@@ -490,22 +512,24 @@ impl Resources {
     */
     pub async fn features_list(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Feature>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/features?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/resources/features",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Features = self.client.get(&url, None).await?;
@@ -521,7 +545,11 @@ impl Resources {
     *
     * Retrieves a list of features for an account.
     */
-    pub async fn features_list_all(&self, customer: &str) -> Result<Vec<crate::types::Feature>> {
+    pub async fn features_list_all(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Feature>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features",
             crate::progenitor_support::encode_path(customer),
@@ -534,17 +562,13 @@ impl Resources {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             features.append(&mut resp.features);
 
@@ -570,9 +594,10 @@ impl Resources {
     */
     pub async fn features_insert(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         body: &crate::types::Feature,
     ) -> Result<crate::types::Feature> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features",
             crate::progenitor_support::encode_path(customer),
@@ -595,9 +620,11 @@ impl Resources {
     */
     pub async fn features_get(
         &self,
-        customer: &str,
-        feature_key: &str,
+        customer: impl AsRef<str>,
+        feature_key: impl AsRef<str>,
     ) -> Result<crate::types::Feature> {
+        let customer = customer.as_ref();
+        let feature_key = feature_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
             crate::progenitor_support::encode_path(customer),
@@ -619,10 +646,12 @@ impl Resources {
     */
     pub async fn features_update(
         &self,
-        customer: &str,
-        feature_key: &str,
+        customer: impl AsRef<str>,
+        feature_key: impl AsRef<str>,
         body: &crate::types::Feature,
     ) -> Result<crate::types::Feature> {
+        let customer = customer.as_ref();
+        let feature_key = feature_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
             crate::progenitor_support::encode_path(customer),
@@ -644,7 +673,13 @@ impl Resources {
     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
     * * `feature_key: &str` -- The unique ID of the feature to delete.
     */
-    pub async fn features_delete(&self, customer: &str, feature_key: &str) -> Result<()> {
+    pub async fn features_delete(
+        &self,
+        customer: impl AsRef<str>,
+        feature_key: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer = customer.as_ref();
+        let feature_key = feature_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
             crate::progenitor_support::encode_path(customer),
@@ -666,10 +701,12 @@ impl Resources {
     */
     pub async fn features_patch(
         &self,
-        customer: &str,
-        feature_key: &str,
+        customer: impl AsRef<str>,
+        feature_key: impl AsRef<str>,
         body: &crate::types::Feature,
     ) -> Result<crate::types::Feature> {
+        let customer = customer.as_ref();
+        let feature_key = feature_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
             crate::progenitor_support::encode_path(customer),
@@ -693,10 +730,12 @@ impl Resources {
     */
     pub async fn features_rename(
         &self,
-        customer: &str,
-        old_name: &str,
+        customer: impl AsRef<str>,
+        old_name: impl AsRef<str>,
         body: &crate::types::FeatureRename,
     ) -> Result<()> {
+        let customer = customer.as_ref();
+        let old_name = old_name.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}/rename",
             crate::progenitor_support::encode_path(customer),
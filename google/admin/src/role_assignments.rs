@@ -27,30 +27,32 @@ impl RoleAssignments {
     */
     pub async fn list(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         max_results: i64,
         page_token: &str,
         role_id: &str,
         user_key: &str,
     ) -> Result<Vec<crate::types::RoleAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !role_id.is_empty() {
-            query_args.push(("roleId".to_string(), role_id.to_string()));
+            query_args.push(("roleId", role_id.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/roleassignments?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/roleassignments",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::RoleAssignments = self.client.get(&url, None).await?;
@@ -68,22 +70,24 @@ impl RoleAssignments {
     */
     pub async fn list_all(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         role_id: &str,
         user_key: &str,
     ) -> Result<Vec<crate::types::RoleAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !role_id.is_empty() {
-            query_args.push(("roleId".to_string(), role_id.to_string()));
+            query_args.push(("roleId", role_id.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/roleassignments?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/roleassignments",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::RoleAssignments = self.client.get(&url, None).await?;
@@ -93,17 +97,13 @@ impl RoleAssignments {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -129,9 +129,10 @@ impl RoleAssignments {
     */
     pub async fn insert(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         body: &crate::types::RoleAssignment,
     ) -> Result<crate::types::RoleAssignment> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roleassignments",
             crate::progenitor_support::encode_path(customer),
@@ -154,9 +155,11 @@ impl RoleAssignments {
     */
     pub async fn get(
         &self,
-        customer: &str,
-        role_assignment_id: &str,
+        customer: impl AsRef<str>,
+        role_assignment_id: impl AsRef<str>,
     ) -> Result<crate::types::RoleAssignment> {
+        let customer = customer.as_ref();
+        let role_assignment_id = role_assignment_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roleassignments/{}",
             crate::progenitor_support::encode_path(customer),
@@ -176,7 +179,13 @@ impl RoleAssignments {
     * * `customer: &str` -- Immutable ID of the Google Workspace account.
     * * `role_assignment_id: &str` -- Immutable ID of the role assignment.
     */
-    pub async fn delete(&self, customer: &str, role_assignment_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        customer: impl AsRef<str>,
+        role_assignment_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer = customer.as_ref();
+        let role_assignment_id = role_assignment_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roleassignments/{}",
             crate::progenitor_support::encode_path(customer),
@@ -25,22 +25,24 @@ impl Roles {
     */
     pub async fn list(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/roles?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/customer/{}/roles",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Roles = self.client.get(&url, None).await?;
@@ -56,7 +58,8 @@ impl Roles {
     *
     * Retrieves a paginated list of all the roles in a domain.
     */
-    pub async fn list_all(&self, customer: &str) -> Result<Vec<crate::types::Role>> {
+    pub async fn list_all(&self, customer: impl AsRef<str>) -> Result<Vec<crate::types::Role>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roles",
             crate::progenitor_support::encode_path(customer),
@@ -69,17 +72,13 @@ impl Roles {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -105,9 +104,10 @@ impl Roles {
     */
     pub async fn insert(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         body: &crate::types::Role,
     ) -> Result<crate::types::Role> {
+        let customer = customer.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roles",
             crate::progenitor_support::encode_path(customer),
@@ -128,7 +128,13 @@ impl Roles {
     * * `customer: &str` -- Immutable ID of the Google Workspace account.
     * * `role_id: &str` -- Immutable ID of the role.
     */
-    pub async fn get(&self, customer: &str, role_id: &str) -> Result<crate::types::Role> {
+    pub async fn get(
+        &self,
+        customer: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<crate::types::Role> {
+        let customer = customer.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
             crate::progenitor_support::encode_path(customer),
@@ -150,10 +156,12 @@ impl Roles {
     */
     pub async fn update(
         &self,
-        customer: &str,
-        role_id: &str,
+        customer: impl AsRef<str>,
+        role_id: impl AsRef<str>,
         body: &crate::types::Role,
     ) -> Result<crate::types::Role> {
+        let customer = customer.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
             crate::progenitor_support::encode_path(customer),
@@ -175,7 +183,9 @@ impl Roles {
     * * `customer: &str` -- Immutable ID of the Google Workspace account.
     * * `role_id: &str` -- Immutable ID of the role.
     */
-    pub async fn delete(&self, customer: &str, role_id: &str) -> Result<()> {
+    pub async fn delete(&self, customer: impl AsRef<str>, role_id: impl AsRef<str>) -> Result<()> {
+        let customer = customer.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
             crate::progenitor_support::encode_path(customer),
@@ -197,10 +207,12 @@ impl Roles {
     */
     pub async fn patch(
         &self,
-        customer: &str,
-        role_id: &str,
+        customer: impl AsRef<str>,
+        role_id: impl AsRef<str>,
         body: &crate::types::Role,
     ) -> Result<crate::types::Role> {
+        let customer = customer.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
             crate::progenitor_support::encode_path(customer),
@@ -21,7 +21,8 @@ impl Schemas {
     *
     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
     */
-    pub async fn list(&self, customer_id: &str) -> Result<crate::types::Schemas> {
+    pub async fn list(&self, customer_id: impl AsRef<str>) -> Result<crate::types::Schemas> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas",
             crate::progenitor_support::encode_path(customer_id),
@@ -41,9 +42,10 @@ impl Schemas {
     */
     pub async fn insert(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &crate::types::Schema,
     ) -> Result<crate::types::Schema> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas",
             crate::progenitor_support::encode_path(customer_id),
@@ -64,7 +66,13 @@ impl Schemas {
     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
     * * `schema_key: &str` -- Name or immutable ID of the schema.
     */
-    pub async fn get(&self, customer_id: &str, schema_key: &str) -> Result<crate::types::Schema> {
+    pub async fn get(
+        &self,
+        customer_id: impl AsRef<str>,
+        schema_key: impl AsRef<str>,
+    ) -> Result<crate::types::Schema> {
+        let customer_id = customer_id.as_ref();
+        let schema_key = schema_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -86,10 +94,12 @@ impl Schemas {
     */
     pub async fn update(
         &self,
-        customer_id: &str,
-        schema_key: &str,
+        customer_id: impl AsRef<str>,
+        schema_key: impl AsRef<str>,
         body: &crate::types::Schema,
     ) -> Result<crate::types::Schema> {
+        let customer_id = customer_id.as_ref();
+        let schema_key = schema_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -111,7 +121,13 @@ impl Schemas {
     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
     * * `schema_key: &str` -- Name or immutable ID of the schema.
     */
-    pub async fn delete(&self, customer_id: &str, schema_key: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        customer_id: impl AsRef<str>,
+        schema_key: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let schema_key = schema_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -133,10 +149,12 @@ impl Schemas {
     */
     pub async fn patch(
         &self,
-        customer_id: &str,
-        schema_key: &str,
+        customer_id: impl AsRef<str>,
+        schema_key: impl AsRef<str>,
         body: &crate::types::Schema,
     ) -> Result<crate::types::Schema> {
+        let customer_id = customer_id.as_ref();
+        let schema_key = schema_key.as_ref();
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
             crate::progenitor_support::encode_path(customer_id),
@@ -21,7 +21,8 @@ impl Tokens {
     *
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     */
-    pub async fn list(&self, user_key: &str) -> Result<crate::types::Tokens> {
+    pub async fn list(&self, user_key: impl AsRef<str>) -> Result<crate::types::Tokens> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/tokens",
             crate::progenitor_support::encode_path(user_key),
@@ -40,7 +41,13 @@ impl Tokens {
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     * * `client_id: &str` -- The Client ID of the application the token is issued to.
     */
-    pub async fn get(&self, user_key: &str, client_id: &str) -> Result<crate::types::Token> {
+    pub async fn get(
+        &self,
+        user_key: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+    ) -> Result<crate::types::Token> {
+        let user_key = user_key.as_ref();
+        let client_id = client_id.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/tokens/{}",
             crate::progenitor_support::encode_path(user_key),
@@ -60,7 +67,13 @@ impl Tokens {
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     * * `client_id: &str` -- The Client ID of the application the token is issued to.
     */
-    pub async fn delete(&self, user_key: &str, client_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        user_key: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_key = user_key.as_ref();
+        let client_id = client_id.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/tokens/{}",
             crate::progenitor_support::encode_path(user_key),
@@ -197,8 +197,8 @@ pub enum Severity {
     SeverityWarning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -209,7 +209,7 @@ impl std::fmt::Display for Severity {
             Severity::SeverityUnspecified => "SEVERITY_UNSPECIFIED",
             Severity::SeverityWarning => "SEVERITY_WARNING",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -257,7 +257,7 @@ pub struct AuxiliaryMessage {
 }
 
 /// Request for adding new printers in batch.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchCreatePrintersRequest {
     /**
     * Request for adding new printers in batch.
@@ -270,6 +270,29 @@ pub struct BatchCreatePrintersRequest {
     pub requests: Vec<CreatePrinterRequest>,
 }
 
+impl BatchCreatePrintersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchCreatePrintersRequestBuilder {
+        BatchCreatePrintersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchCreatePrintersRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchCreatePrintersRequestBuilder(BatchCreatePrintersRequest);
+
+impl BatchCreatePrintersRequestBuilder {
+    pub fn requests(mut self, requests: Vec<CreatePrinterRequest>) -> Self {
+        self.0.requests = requests;
+        self
+    }
+
+    pub fn build(self) -> BatchCreatePrintersRequest {
+        self.0
+    }
+}
+
 /// Response for adding new printers in batch.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchCreatePrintersResponse {
@@ -294,7 +317,7 @@ pub struct BatchCreatePrintersResponse {
 }
 
 /// Request for deleting existing printers in batch.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchDeletePrintersRequest {
     /**
     * Request for deleting existing printers in batch.
@@ -308,6 +331,29 @@ pub struct BatchDeletePrintersRequest {
     pub printer_ids: Vec<String>,
 }
 
+impl BatchDeletePrintersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchDeletePrintersRequestBuilder {
+        BatchDeletePrintersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchDeletePrintersRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchDeletePrintersRequestBuilder(BatchDeletePrintersRequest);
+
+impl BatchDeletePrintersRequestBuilder {
+    pub fn printer_ids(mut self, printer_ids: Vec<String>) -> Self {
+        self.0.printer_ids = printer_ids;
+        self
+    }
+
+    pub fn build(self) -> BatchDeletePrintersRequest {
+        self.0
+    }
+}
+
 /// Response for deleting existing printers in batch.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchDeletePrintersResponse {
@@ -1546,7 +1592,7 @@ pub struct ChromeOsMoveDevicesOu {
 }
 
 /// Request for adding a new printer.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreatePrinterRequest {
     /**
     * Request for adding a new printer.
@@ -1564,6 +1610,34 @@ pub struct CreatePrinterRequest {
     pub printer: Option<Printer>,
 }
 
+impl CreatePrinterRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreatePrinterRequestBuilder {
+        CreatePrinterRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreatePrinterRequest`.
+#[derive(Debug, Clone)]
+pub struct CreatePrinterRequestBuilder(CreatePrinterRequest);
+
+impl CreatePrinterRequestBuilder {
+    pub fn parent(mut self, parent: impl Into<String>) -> Self {
+        self.0.parent = parent.into();
+        self
+    }
+
+    pub fn printer(mut self, printer: impl Into<Printer>) -> Self {
+        self.0.printer = Some(printer.into());
+        self
+    }
+
+    pub fn build(self) -> CreatePrinterRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Customer {
     #[serde(
@@ -1715,8 +1789,8 @@ pub enum State {
     StateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -1730,7 +1804,7 @@ impl std::fmt::Display for State {
             State::SentToClient => "SENT_TO_CLIENT",
             State::StateUnspecified => "STATE_UNSPECIFIED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1766,8 +1840,8 @@ pub enum Type {
     WipeUsers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1780,7 +1854,7 @@ impl std::fmt::Display for Type {
             Type::TakeAScreenshot => "TAKE_A_SCREENSHOT",
             Type::WipeUsers => "WIPE_USERS",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1875,8 +1949,8 @@ pub enum Result {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -1887,7 +1961,7 @@ impl std::fmt::Display for Result {
             Result::Ignored => "IGNORED",
             Result::Success => "SUCCESS",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1935,7 +2009,7 @@ pub struct DirectoryChromeosdevicesCommandResult {
 }
 
 /// A request for issuing a command.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DirectoryChromeosdevicesIssueCommandRequest {
     /**
     * A request for issuing a command.
@@ -1957,6 +2031,36 @@ pub struct DirectoryChromeosdevicesIssueCommandRequest {
     pub payload: String,
 }
 
+impl DirectoryChromeosdevicesIssueCommandRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DirectoryChromeosdevicesIssueCommandRequestBuilder {
+        DirectoryChromeosdevicesIssueCommandRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DirectoryChromeosdevicesIssueCommandRequest`.
+#[derive(Debug, Clone)]
+pub struct DirectoryChromeosdevicesIssueCommandRequestBuilder(
+    DirectoryChromeosdevicesIssueCommandRequest,
+);
+
+impl DirectoryChromeosdevicesIssueCommandRequestBuilder {
+    pub fn command_type(mut self, command_type: impl Into<Type>) -> Self {
+        self.0.command_type = Some(command_type.into());
+        self
+    }
+
+    pub fn payload(mut self, payload: impl Into<String>) -> Self {
+        self.0.payload = payload.into();
+        self
+    }
+
+    pub fn build(self) -> DirectoryChromeosdevicesIssueCommandRequest {
+        self.0
+    }
+}
+
 /// A response for issuing a command.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DirectoryChromeosdevicesIssueCommandResponse {
@@ -2176,8 +2280,8 @@ pub enum ErrorCode {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -2201,7 +2305,7 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::Unimplemented => "UNIMPLEMENTED",
             ErrorCode::Unknown => "UNKNOWN",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5478,8 +5582,8 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -5488,7 +5592,7 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5518,8 +5622,8 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -5529,7 +5633,7 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5567,8 +5671,8 @@ pub enum OrderBy {
     SupportEndDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -5582,7 +5686,7 @@ impl std::fmt::Display for OrderBy {
             OrderBy::Status => "status",
             OrderBy::SupportEndDate => "supportEndDate",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5610,8 +5714,8 @@ pub enum Projection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Projection {
@@ -5620,7 +5724,7 @@ impl std::fmt::Display for Projection {
             Projection::Basic => "BASIC",
             Projection::Full => "FULL",
             Projection::Noop => "",
-            Projection::FallthroughString => "*",
+            Projection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5648,8 +5752,8 @@ pub enum SortOrder {
     Descending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -5658,7 +5762,7 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Ascending => "ASCENDING",
             SortOrder::Descending => "DESCENDING",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5698,8 +5802,8 @@ pub enum DirectoryMobiledevicesListOrderBy {
     Type,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
@@ -5714,7 +5818,7 @@ impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
             DirectoryMobiledevicesListOrderBy::Status => "status",
             DirectoryMobiledevicesListOrderBy::Type => "type",
             DirectoryMobiledevicesListOrderBy::Noop => "",
-            DirectoryMobiledevicesListOrderBy::FallthroughString => "*",
+            DirectoryMobiledevicesListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5742,8 +5846,8 @@ pub enum DirectoryOrgunitsListType {
     Children,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryOrgunitsListType {
@@ -5752,7 +5856,7 @@ impl std::fmt::Display for DirectoryOrgunitsListType {
             DirectoryOrgunitsListType::All => "all",
             DirectoryOrgunitsListType::Children => "children",
             DirectoryOrgunitsListType::Noop => "",
-            DirectoryOrgunitsListType::FallthroughString => "*",
+            DirectoryOrgunitsListType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5782,8 +5886,8 @@ pub enum CoordinatesSource {
     SourceUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CoordinatesSource {
@@ -5793,7 +5897,7 @@ impl std::fmt::Display for CoordinatesSource {
             CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
             CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
             CoordinatesSource::Noop => "",
-            CoordinatesSource::FallthroughString => "*",
+            CoordinatesSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5819,8 +5923,8 @@ pub enum DirectoryGroupsListOrderBy {
     Email,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryGroupsListOrderBy {
@@ -5828,7 +5932,7 @@ impl std::fmt::Display for DirectoryGroupsListOrderBy {
         match &*self {
             DirectoryGroupsListOrderBy::Email => "email",
             DirectoryGroupsListOrderBy::Noop => "",
-            DirectoryGroupsListOrderBy::FallthroughString => "*",
+            DirectoryGroupsListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5862,8 +5966,8 @@ pub enum Event {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Event {
@@ -5875,7 +5979,7 @@ impl std::fmt::Display for Event {
             Event::Undelete => "undelete",
             Event::Update => "update",
             Event::Noop => "",
-            Event::FallthroughString => "*",
+            Event::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5905,8 +6009,8 @@ pub enum DirectoryUsersListOrderBy {
     GivenName,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListOrderBy {
@@ -5916,7 +6020,7 @@ impl std::fmt::Display for DirectoryUsersListOrderBy {
             DirectoryUsersListOrderBy::FamilyName => "familyName",
             DirectoryUsersListOrderBy::GivenName => "givenName",
             DirectoryUsersListOrderBy::Noop => "",
-            DirectoryUsersListOrderBy::FallthroughString => "*",
+            DirectoryUsersListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5946,8 +6050,8 @@ pub enum DirectoryUsersListProjection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListProjection {
@@ -5957,7 +6061,7 @@ impl std::fmt::Display for DirectoryUsersListProjection {
             DirectoryUsersListProjection::Custom => "custom",
             DirectoryUsersListProjection::Full => "full",
             DirectoryUsersListProjection::Noop => "",
-            DirectoryUsersListProjection::FallthroughString => "*",
+            DirectoryUsersListProjection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5985,8 +6089,8 @@ pub enum ViewType {
     DomainPublic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewType {
@@ -5995,7 +6099,7 @@ impl std::fmt::Display for ViewType {
             ViewType::AdminView => "admin_view",
             ViewType::DomainPublic => "domain_public",
             ViewType::Noop => "",
-            ViewType::FallthroughString => "*",
+            ViewType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6023,8 +6127,8 @@ pub enum DirectoryUsersAliasesListEvent {
     Delete,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersAliasesListEvent {
@@ -6033,7 +6137,7 @@ impl std::fmt::Display for DirectoryUsersAliasesListEvent {
             DirectoryUsersAliasesListEvent::Add => "add",
             DirectoryUsersAliasesListEvent::Delete => "delete",
             DirectoryUsersAliasesListEvent::Noop => "",
-            DirectoryUsersAliasesListEvent::FallthroughString => "*",
+            DirectoryUsersAliasesListEvent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
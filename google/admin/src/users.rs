@@ -46,42 +46,44 @@ impl Users {
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !show_deleted.is_empty() {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
+            query_args.push(("viewType", view_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/users?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/directory/v1/users"),
+            &query_args,
+        );
 
         let resp: crate::types::Users = self.client.get(&url, None).await?;
 
@@ -108,36 +110,38 @@ impl Users {
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !show_deleted.is_empty() {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
+            query_args.push(("viewType", view_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/users?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/directory/v1/users"),
+            &query_args,
+        );
 
         let mut resp: crate::types::Users = self.client.get(&url, None).await?;
 
@@ -146,17 +150,13 @@ impl Users {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             users.append(&mut resp.users);
 
@@ -218,42 +218,44 @@ impl Users {
         view_type: crate::types::ViewType,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !show_deleted.is_empty() {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
+            query_args.push(("viewType", view_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/users/watch?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/directory/v1/users/watch"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -274,22 +276,24 @@ impl Users {
     */
     pub async fn get(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         projection: crate::types::DirectoryUsersListProjection,
         view_type: crate::types::ViewType,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_key = user_key.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(user_key),
-            query_
+            query_args.push(("viewType", view_type.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/users/{}",
+                crate::progenitor_support::encode_path(user_key),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -306,9 +310,10 @@ impl Users {
     */
     pub async fn update(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         body: &crate::types::User,
     ) -> Result<crate::types::User> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}",
             crate::progenitor_support::encode_path(user_key),
@@ -328,7 +333,8 @@ impl Users {
     *
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     */
-    pub async fn delete(&self, user_key: &str) -> Result<()> {
+    pub async fn delete(&self, user_key: impl AsRef<str>) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}",
             crate::progenitor_support::encode_path(user_key),
@@ -348,9 +354,10 @@ impl Users {
     */
     pub async fn patch(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         body: &crate::types::User,
     ) -> Result<crate::types::User> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}",
             crate::progenitor_support::encode_path(user_key),
@@ -373,18 +380,20 @@ impl Users {
     */
     pub async fn aliases_list(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         event: crate::types::DirectoryUsersAliasesListEvent,
     ) -> Result<crate::types::Aliases> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_key = user_key.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/users/{}/aliases?{}",
-            crate::progenitor_support::encode_path(user_key),
-            query_
+            query_args.push(("event", event.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/users/{}/aliases",
+                crate::progenitor_support::encode_path(user_key),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -401,9 +410,10 @@ impl Users {
     */
     pub async fn aliases_insert(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         body: &crate::types::Alias,
     ) -> Result<crate::types::Alias> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/aliases",
             crate::progenitor_support::encode_path(user_key),
@@ -426,19 +436,21 @@ impl Users {
     */
     pub async fn aliases_watch(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         event: crate::types::DirectoryUsersAliasesListEvent,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_key = user_key.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/users/{}/aliases/watch?{}",
-            crate::progenitor_support::encode_path(user_key),
-            query_
+            query_args.push(("event", event.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/directory/v1/users/{}/aliases/watch",
+                crate::progenitor_support::encode_path(user_key),
+            ),
+            &query_args,
         );
 
         self.client
@@ -456,7 +468,13 @@ impl Users {
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     * * `alias: &str` -- The alias to be removed.
     */
-    pub async fn aliases_delete(&self, user_key: &str, alias: &str) -> Result<()> {
+    pub async fn aliases_delete(
+        &self,
+        user_key: impl AsRef<str>,
+        alias: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_key = user_key.as_ref();
+        let alias = alias.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/aliases/{}",
             crate::progenitor_support::encode_path(user_key),
@@ -477,9 +495,10 @@ impl Users {
     */
     pub async fn make_admin(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         body: &crate::types::UserMakeAdmin,
     ) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/makeAdmin",
             crate::progenitor_support::encode_path(user_key),
@@ -499,7 +518,8 @@ impl Users {
     *
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     */
-    pub async fn photos_get(&self, user_key: &str) -> Result<crate::types::UserPhoto> {
+    pub async fn photos_get(&self, user_key: impl AsRef<str>) -> Result<crate::types::UserPhoto> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
             crate::progenitor_support::encode_path(user_key),
@@ -519,9 +539,10 @@ impl Users {
     */
     pub async fn photos_update(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         body: &crate::types::UserPhoto,
     ) -> Result<crate::types::UserPhoto> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
             crate::progenitor_support::encode_path(user_key),
@@ -541,7 +562,8 @@ impl Users {
     *
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     */
-    pub async fn photos_delete(&self, user_key: &str) -> Result<()> {
+    pub async fn photos_delete(&self, user_key: impl AsRef<str>) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
             crate::progenitor_support::encode_path(user_key),
@@ -561,9 +583,10 @@ impl Users {
     */
     pub async fn photos_patch(
         &self,
-        user_key: &str,
+        user_key: impl AsRef<str>,
         body: &crate::types::UserPhoto,
     ) -> Result<crate::types::UserPhoto> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
             crate::progenitor_support::encode_path(user_key),
@@ -583,7 +606,8 @@ impl Users {
     *
     * * `user_key: &str` -- Identifies the target user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     */
-    pub async fn sign_out(&self, user_key: &str) -> Result<()> {
+    pub async fn sign_out(&self, user_key: impl AsRef<str>) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/signOut",
             crate::progenitor_support::encode_path(user_key),
@@ -601,7 +625,12 @@ impl Users {
     *
     * * `user_key: &str` -- The immutable id of the user.
     */
-    pub async fn undelete(&self, user_key: &str, body: &crate::types::UserUndelete) -> Result<()> {
+    pub async fn undelete(
+        &self,
+        user_key: impl AsRef<str>,
+        body: &crate::types::UserUndelete,
+    ) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/undelete",
             crate::progenitor_support::encode_path(user_key),
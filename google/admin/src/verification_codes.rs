@@ -21,7 +21,8 @@ impl VerificationCodes {
     *
     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
     */
-    pub async fn list(&self, user_key: &str) -> Result<crate::types::VerificationCodes> {
+    pub async fn list(&self, user_key: impl AsRef<str>) -> Result<crate::types::VerificationCodes> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/verificationCodes",
             crate::progenitor_support::encode_path(user_key),
@@ -39,7 +40,8 @@ impl VerificationCodes {
     *
     * * `user_key: &str` -- Email or immutable ID of the user.
     */
-    pub async fn generate(&self, user_key: &str) -> Result<()> {
+    pub async fn generate(&self, user_key: impl AsRef<str>) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/verificationCodes/generate",
             crate::progenitor_support::encode_path(user_key),
@@ -57,7 +59,8 @@ impl VerificationCodes {
     *
     * * `user_key: &str` -- Email or immutable ID of the user.
     */
-    pub async fn invalidate(&self, user_key: &str) -> Result<()> {
+    pub async fn invalidate(&self, user_key: impl AsRef<str>) -> Result<()> {
+        let user_key = user_key.as_ref();
         let url = format!(
             "/admin/directory/v1/users/{}/verificationCodes/invalidate",
             crate::progenitor_support::encode_path(user_key),
@@ -30,26 +30,28 @@ impl Acl {
     */
     pub async fn list(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         max_results: i64,
         page_token: &str,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::AclRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/acl",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Acl = self.client.get(&url, None).await?;
@@ -67,18 +69,20 @@ impl Acl {
     */
     pub async fn list_all(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::AclRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/acl",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::Acl = self.client.get(&url, None).await?;
@@ -88,17 +92,13 @@ impl Acl {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -125,22 +125,21 @@ impl Acl {
     */
     pub async fn insert(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/acl",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -166,27 +165,29 @@ impl Acl {
     */
     pub async fn watch(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         max_results: i64,
         page_token: &str,
         show_deleted: bool,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl/watch?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/acl/watch",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -204,7 +205,13 @@ impl Acl {
     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
     * * `rule_id: &str` -- ETag of the collection.
     */
-    pub async fn get(&self, calendar_id: &str, rule_id: &str) -> Result<crate::types::AclRule> {
+    pub async fn get(
+        &self,
+        calendar_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
+    ) -> Result<crate::types::AclRule> {
+        let calendar_id = calendar_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/calendars/{}/acl/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -227,24 +234,24 @@ impl Acl {
     */
     pub async fn update(
         &self,
-        calendar_id: &str,
-        rule_id: &str,
+        calendar_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let rule_id = rule_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(rule_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/acl/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -262,7 +269,13 @@ impl Acl {
     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
     * * `rule_id: &str` -- ETag of the collection.
     */
-    pub async fn delete(&self, calendar_id: &str, rule_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        calendar_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let calendar_id = calendar_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/calendars/{}/acl/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -285,24 +298,24 @@ impl Acl {
     */
     pub async fn patch(
         &self,
-        calendar_id: &str,
-        rule_id: &str,
+        calendar_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let rule_id = rule_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(rule_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/acl/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id),
+            ),
+            &query_args,
         );
 
         self.client
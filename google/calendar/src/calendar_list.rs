@@ -38,24 +38,26 @@ impl CalendarList {
         show_deleted: bool,
         show_hidden: bool,
     ) -> Result<Vec<crate::types::CalendarListEntry>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !min_access_role.to_string().is_empty() {
-            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+            query_args.push(("minAccessRole", min_access_role.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden {
-            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+            query_args.push(("showHidden", show_hidden.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/me/calendarList"),
+            &query_args,
+        );
 
         let resp: crate::types::CalendarList = self.client.get(&url, None).await?;
 
@@ -76,18 +78,20 @@ impl CalendarList {
         show_deleted: bool,
         show_hidden: bool,
     ) -> Result<Vec<crate::types::CalendarListEntry>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !min_access_role.to_string().is_empty() {
-            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+            query_args.push(("minAccessRole", min_access_role.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden {
-            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+            query_args.push(("showHidden", show_hidden.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/me/calendarList"),
+            &query_args,
+        );
 
         let mut resp: crate::types::CalendarList = self.client.get(&url, None).await?;
 
@@ -96,17 +100,13 @@ impl CalendarList {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -135,12 +135,14 @@ impl CalendarList {
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if color_rgb_format {
-            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+            query_args.push(("colorRgbFormat", color_rgb_format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/me/calendarList"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -174,24 +176,26 @@ impl CalendarList {
         show_hidden: bool,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !min_access_role.to_string().is_empty() {
-            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+            query_args.push(("minAccessRole", min_access_role.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden {
-            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+            query_args.push(("showHidden", show_hidden.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList/watch?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/me/calendarList/watch"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -207,7 +211,11 @@ impl CalendarList {
     *
     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
     */
-    pub async fn list_get(&self, calendar_id: &str) -> Result<crate::types::CalendarListEntry> {
+    pub async fn list_get(
+        &self,
+        calendar_id: impl AsRef<str>,
+    ) -> Result<crate::types::CalendarListEntry> {
+        let calendar_id = calendar_id.as_ref();
         let url = format!(
             "/users/me/calendarList/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -228,19 +236,21 @@ impl CalendarList {
     */
     pub async fn list_update(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if color_rgb_format {
-            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+            query_args.push(("colorRgbFormat", color_rgb_format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/me/calendarList/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/me/calendarList/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -257,7 +267,8 @@ impl CalendarList {
     *
     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
     */
-    pub async fn list_delete(&self, calendar_id: &str) -> Result<()> {
+    pub async fn list_delete(&self, calendar_id: impl AsRef<str>) -> Result<()> {
+        let calendar_id = calendar_id.as_ref();
         let url = format!(
             "/users/me/calendarList/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -278,19 +289,21 @@ impl CalendarList {
     */
     pub async fn list_patch(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if color_rgb_format {
-            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+            query_args.push(("colorRgbFormat", color_rgb_format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/me/calendarList/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/me/calendarList/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client
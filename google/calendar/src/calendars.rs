@@ -33,7 +33,8 @@ impl Calendars {
     *
     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
     */
-    pub async fn get(&self, calendar_id: &str) -> Result<crate::types::Calendar> {
+    pub async fn get(&self, calendar_id: impl AsRef<str>) -> Result<crate::types::Calendar> {
+        let calendar_id = calendar_id.as_ref();
         let url = format!(
             "/calendars/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -53,9 +54,10 @@ impl Calendars {
     */
     pub async fn update(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         body: &crate::types::Calendar,
     ) -> Result<crate::types::Calendar> {
+        let calendar_id = calendar_id.as_ref();
         let url = format!(
             "/calendars/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -75,7 +77,8 @@ impl Calendars {
     *
     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
     */
-    pub async fn delete(&self, calendar_id: &str) -> Result<()> {
+    pub async fn delete(&self, calendar_id: impl AsRef<str>) -> Result<()> {
+        let calendar_id = calendar_id.as_ref();
         let url = format!(
             "/calendars/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -95,9 +98,10 @@ impl Calendars {
     */
     pub async fn patch(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         body: &crate::types::Calendar,
     ) -> Result<crate::types::Calendar> {
+        let calendar_id = calendar_id.as_ref();
         let url = format!(
             "/calendars/{}",
             crate::progenitor_support::encode_path(calendar_id),
@@ -117,7 +121,8 @@ impl Calendars {
     *
     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
     */
-    pub async fn clear(&self, calendar_id: &str) -> Result<()> {
+    pub async fn clear(&self, calendar_id: impl AsRef<str>) -> Result<()> {
+        let calendar_id = calendar_id.as_ref();
         let url = format!(
             "/calendars/{}/clear",
             crate::progenitor_support::encode_path(calendar_id),
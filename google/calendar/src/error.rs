@@ -0,0 +1,107 @@
+//! When a request fails, Google describes what went wrong in an error object. The
+//! error of the request is then an `ApiError`, which can be matched on with
+//! `downcast_ref`:
+//!
+//! ```no_run
+//! # fn handle(err: anyhow::Error) -> anyhow::Result<()> {
+//! match err.downcast_ref::<google_calendar::error::ApiError>() {
+//!     // Too many requests were made, they can be tried again later.
+//!     Some(e) if e.has_reason("rateLimitExceeded") => {}
+//!     _ => return Err(err),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! https://cloud.google.com/apis/design/errors
+use std::fmt;
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+/// An error object returned by Google.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// The status code of the response.
+    pub status: reqwest::StatusCode,
+    /// A description of the error.
+    pub message: String,
+    /// The canonical code of the error, for example `PERMISSION_DENIED`.
+    pub code: String,
+    pub errors: Vec<ErrorDetail>,
+}
+
+/// A detail of what went wrong.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorDetail {
+    /// The service the error comes from, for example `usageLimits`.
+    #[serde(default)]
+    pub domain: String,
+    /// Why the request failed, for example `rateLimitExceeded` or `notFound`.
+    #[serde(default)]
+    pub reason: String,
+    #[serde(default)]
+    pub message: String,
+    /// The parameter or header the error is about.
+    #[serde(default)]
+    pub location: String,
+    #[serde(default)]
+    pub location_type: String,
+}
+
+impl ApiError {
+    /// Whether one of the errors has the given reason.
+    pub fn has_reason(&self, reason: &str) -> bool {
+        self.errors.iter().any(|e| e.reason == reason)
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: ErrorObject,
+}
+
+#[derive(Deserialize)]
+struct ErrorObject {
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    errors: Vec<ErrorDetail>,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "code: {}", self.status)?;
+        if !self.code.is_empty() {
+            write!(f, ", {}", self.code)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Build the error for a response that failed, from the error object in its body
+/// if there is one.
+pub(crate) fn from_response(status: reqwest::StatusCode, body: &[u8]) -> anyhow::Error {
+    if body.is_empty() {
+        return anyhow!("code: {}, empty response", status);
+    }
+
+    match serde_json::from_slice::<ErrorBody>(body) {
+        Ok(e) => anyhow!(ApiError {
+            status,
+            message: e.error.message,
+            code: e.error.status,
+            errors: e.error.errors,
+        }),
+        Err(_) => anyhow!(
+            "code: {}, error: {:?}",
+            status,
+            String::from_utf8_lossy(body),
+        ),
+    }
+}
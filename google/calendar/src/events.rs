@@ -53,7 +53,7 @@ impl Events {
     */
     pub async fn list(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         i_cal_uid: &str,
         max_attendees: i64,
         max_results: i64,
@@ -70,66 +70,62 @@ impl Events {
         time_zone: &str,
         updated_min: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !i_cal_uid.is_empty() {
-            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+            query_args.push(("iCalUID", i_cal_uid.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !private_extended_property.is_empty() {
             query_args.push((
-                "privateExtendedProperty".to_string(),
+                "privateExtendedProperty",
                 private_extended_property.join(" "),
             ));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !shared_extended_property.is_empty() {
-            query_args.push((
-                "sharedExtendedProperty".to_string(),
-                shared_extended_property.join(" "),
-            ));
+            query_args.push(("sharedExtendedProperty", shared_extended_property.join(" ")));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden_invitations {
-            query_args.push((
-                "showHiddenInvitations".to_string(),
-                show_hidden_invitations.to_string(),
-            ));
+            query_args.push(("showHiddenInvitations", show_hidden_invitations.to_string()));
         }
         if single_events {
-            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+            query_args.push(("singleEvents", single_events.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+            query_args.push(("timeZone", time_zone.to_string()));
         }
         if !updated_min.is_empty() {
-            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+            query_args.push(("updatedMin", updated_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Events = self.client.get(&url, None).await?;
@@ -147,7 +143,7 @@ impl Events {
     */
     pub async fn list_all(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         i_cal_uid: &str,
         max_attendees: i64,
         order_by: crate::types::OrderBy,
@@ -162,60 +158,56 @@ impl Events {
         time_zone: &str,
         updated_min: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !i_cal_uid.is_empty() {
-            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+            query_args.push(("iCalUID", i_cal_uid.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !private_extended_property.is_empty() {
             query_args.push((
-                "privateExtendedProperty".to_string(),
+                "privateExtendedProperty",
                 private_extended_property.join(" "),
             ));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !shared_extended_property.is_empty() {
-            query_args.push((
-                "sharedExtendedProperty".to_string(),
-                shared_extended_property.join(" "),
-            ));
+            query_args.push(("sharedExtendedProperty", shared_extended_property.join(" ")));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden_invitations {
-            query_args.push((
-                "showHiddenInvitations".to_string(),
-                show_hidden_invitations.to_string(),
-            ));
+            query_args.push(("showHiddenInvitations", show_hidden_invitations.to_string()));
         }
         if single_events {
-            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+            query_args.push(("singleEvents", single_events.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+            query_args.push(("timeZone", time_zone.to_string()));
         }
         if !updated_min.is_empty() {
-            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+            query_args.push(("updatedMin", updated_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::Events = self.client.get(&url, None).await?;
@@ -225,17 +217,13 @@ impl Events {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -268,7 +256,7 @@ impl Events {
     */
     pub async fn insert(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         conference_data_version: u64,
         max_attendees: i64,
         send_notifications: bool,
@@ -276,36 +264,29 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -326,29 +307,25 @@ impl Events {
     */
     pub async fn import(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         conference_data_version: u64,
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/import?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/import",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -372,29 +349,28 @@ impl Events {
     */
     pub async fn quick_add(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         text: &str,
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if !text.is_empty() {
-            query_args.push(("text".to_string(), text.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/quickAdd?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+            query_args.push(("text", text.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/quickAdd",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -441,7 +417,7 @@ impl Events {
     */
     pub async fn watch(
         &self,
-        calendar_id: &str,
+        calendar_id: impl AsRef<str>,
         i_cal_uid: &str,
         max_attendees: i64,
         max_results: i64,
@@ -459,66 +435,62 @@ impl Events {
         updated_min: &str,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !i_cal_uid.is_empty() {
-            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+            query_args.push(("iCalUID", i_cal_uid.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !private_extended_property.is_empty() {
             query_args.push((
-                "privateExtendedProperty".to_string(),
+                "privateExtendedProperty",
                 private_extended_property.join(" "),
             ));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !shared_extended_property.is_empty() {
-            query_args.push((
-                "sharedExtendedProperty".to_string(),
-                shared_extended_property.join(" "),
-            ));
+            query_args.push(("sharedExtendedProperty", shared_extended_property.join(" ")));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden_invitations {
-            query_args.push((
-                "showHiddenInvitations".to_string(),
-                show_hidden_invitations.to_string(),
-            ));
+            query_args.push(("showHiddenInvitations", show_hidden_invitations.to_string()));
         }
         if single_events {
-            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+            query_args.push(("singleEvents", single_events.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+            query_args.push(("timeZone", time_zone.to_string()));
         }
         if !updated_min.is_empty() {
-            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/watch?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            query_
+            query_args.push(("updatedMin", updated_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/watch",
+                crate::progenitor_support::encode_path(calendar_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -541,24 +513,27 @@ impl Events {
     */
     pub async fn get(
         &self,
-        calendar_id: &str,
-        event_id: &str,
+        calendar_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
         max_attendees: i64,
         time_zone: &str,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("timeZone", time_zone.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -584,8 +559,8 @@ impl Events {
     */
     pub async fn update(
         &self,
-        calendar_id: &str,
-        event_id: &str,
+        calendar_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
         conference_data_version: u64,
         max_attendees: i64,
         send_notifications: bool,
@@ -593,37 +568,31 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -647,27 +616,27 @@ impl Events {
     */
     pub async fn delete(
         &self,
-        calendar_id: &str,
-        event_id: &str,
+        calendar_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("sendUpdates", send_updates.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -693,8 +662,8 @@ impl Events {
     */
     pub async fn patch(
         &self,
-        calendar_id: &str,
-        event_id: &str,
+        calendar_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
         conference_data_version: u64,
         max_attendees: i64,
         send_notifications: bool,
@@ -702,37 +671,31 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -761,8 +724,8 @@ impl Events {
     */
     pub async fn instances(
         &self,
-        calendar_id: &str,
-        event_id: &str,
+        calendar_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
         max_attendees: i64,
         max_results: i64,
         original_start: &str,
@@ -772,37 +735,40 @@ impl Events {
         time_min: &str,
         time_zone: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !original_start.is_empty() {
-            query_args.push(("originalStart".to_string(), original_start.to_string()));
+            query_args.push(("originalStart", original_start.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}/instances?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("timeZone", time_zone.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/{}/instances",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Events = self.client.get(&url, None).await?;
@@ -820,8 +786,8 @@ impl Events {
     */
     pub async fn get_all_instances(
         &self,
-        calendar_id: &str,
-        event_id: &str,
+        calendar_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
         max_attendees: i64,
         original_start: &str,
         show_deleted: bool,
@@ -829,31 +795,34 @@ impl Events {
         time_min: &str,
         time_zone: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if !original_start.is_empty() {
-            query_args.push(("originalStart".to_string(), original_start.to_string()));
+            query_args.push(("originalStart", original_start.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}/instances?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("timeZone", time_zone.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/{}/instances",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::Events = self.client.get(&url, None).await?;
@@ -863,17 +832,13 @@ impl Events {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -905,31 +870,31 @@ impl Events {
     */
     pub async fn mv(
         &self,
-        calendar_id: &str,
-        event_id: &str,
+        calendar_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
         destination: &str,
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let calendar_id = calendar_id.as_ref();
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !destination.is_empty() {
-            query_args.push(("destination".to_string(), destination.to_string()));
+            query_args.push(("destination", destination.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}/move?{}",
-            crate::progenitor_support::encode_path(calendar_id),
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("sendUpdates", send_updates.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/calendars/{}/events/{}/move",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
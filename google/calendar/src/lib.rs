@@ -109,24 +109,55 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod acl;
+pub mod batch;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+pub mod cache;
 pub mod calendar_list;
 pub mod calendars;
 pub mod channels;
+pub mod client_config;
 pub mod colors;
+/// The errors returned by Google.
+pub mod error;
 pub mod events;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fixtures;
 pub mod freebusy;
+pub mod from_env;
+mod json_stream;
+/// Email messages in the raw form the Gmail API sends.
+pub mod message;
+pub mod metrics;
+/// One page of the results of a list endpoint.
+pub mod page;
+pub mod redact;
+pub mod request_options;
+pub mod retry;
+mod runtime;
+/// Authentication as a service account, for server to server requests.
+pub mod service_account;
 pub mod settings;
+pub mod single_flight;
 #[cfg(test)]
 mod tests;
+pub mod token_store;
+pub mod trace;
+pub mod transport;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
+/// Push notifications of changes to events, on channels renewed before they expire.
+pub mod watch;
 
 use anyhow::{anyhow, Error, Result};
 
 pub const DEFAULT_HOST: &str = "https://www.googleapis.com/calendar/v3";
 
 mod progenitor_support {
+    use std::fmt::{self, Write};
+
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
     const PATH_SET: &AsciiSet = &CONTROLS
@@ -140,9 +171,80 @@ mod progenitor_support {
         .add(b'{')
         .add(b'}');
 
+    /// A path component, percent-encoded as it is formatted.
+    struct EncodePath<'a, T: ?Sized>(&'a T);
+
+    impl<T: fmt::Display + ?Sized> fmt::Display for EncodePath<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            struct Encoder<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+            impl Write for Encoder<'_, '_> {
+                fn write_str(&mut self, s: &str) -> fmt::Result {
+                    write!(self.0, "{}", utf8_percent_encode(s, PATH_SET))
+                }
+            }
+
+            write!(Encoder(f), "{}", self.0)
+        }
+    }
+
+    /// Percent-encode a path component, a `&str` or any value that implements
+    /// `Display`, without copying it into a `String` first.
+    #[allow(dead_code)]
+    pub(crate) fn encode_path<T: fmt::Display + ?Sized>(pc: &T) -> impl fmt::Display + '_ {
+        EncodePath(pc)
+    }
+
+    /// Write a path and its percent-encoded query parameters into a single `String`.
     #[allow(dead_code)]
-    pub(crate) fn encode_path(pc: &str) -> String {
-        utf8_percent_encode(pc, PATH_SET).to_string()
+    pub(crate) fn build_url(path: fmt::Arguments<'_>, query_args: &[(&str, String)]) -> String {
+        let mut url = String::new();
+        url.write_fmt(path).unwrap();
+        push_query(
+            &mut url,
+            query_args
+                .iter()
+                .map(|(name, value)| (*name, value.as_str())),
+        );
+        url
+    }
+
+    /// Add percent-encoded query parameters to a URL that may already have a query
+    /// string.
+    #[allow(dead_code)]
+    pub(crate) fn append_query(url: &str, query_args: &[(&str, &str)]) -> String {
+        let mut url = url.to_string();
+        push_query(&mut url, query_args.iter().copied());
+        url
+    }
+
+    fn push_query<'a, I>(url: &mut String, query_args: I)
+    where
+        I: Iterator<Item = (&'a str, &'a str)>,
+    {
+        for (name, value) in query_args {
+            if !url.contains('?') {
+                url.push('?');
+            } else if !url.ends_with('?') && !url.ends_with('&') {
+                url.push('&');
+            }
+            push_form_encoded(url, name);
+            url.push('=');
+            push_form_encoded(url, value);
+        }
+    }
+
+    /// Encode as `application/x-www-form-urlencoded`, like `serde_urlencoded` does.
+    fn push_form_encoded(url: &mut String, s: &str) {
+        for b in s.bytes() {
+            match b {
+                b'*' | b'-' | b'.' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' => {
+                    url.push(b as char)
+                }
+                b' ' => url.push('+'),
+                _ => write!(url, "%{:02X}", b).unwrap(),
+            }
+        }
     }
 }
 
@@ -150,8 +252,9 @@ use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use tokio::sync::RwLock;
+use web_time::Instant;
 
 const TOKEN_ENDPOINT: &str = "https://oauth2.googleapis.com/token";
 const USER_CONSENT_ENDPOINT: &str = "https://accounts.google.com/o/oauth2/v2/auth";
@@ -166,13 +269,17 @@ pub struct Client {
     redirect_uri: String,
 
     auto_refresh: bool,
+    on_token_refresh: Option<Arc<dyn Fn(&AccessToken) + Send + Sync>>,
+    token_store: Option<Arc<dyn crate::token_store::TokenStore>>,
     client: reqwest_middleware::ClientWithMiddleware,
+    request_options: crate::request_options::RequestOptions,
+    service_account: Option<crate::service_account::ServiceAccount>,
 }
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+#[derive(JsonSchema, Clone, Default, Serialize, Deserialize)]
 pub struct AccessToken {
     #[serde(
         default,
@@ -207,12 +314,26 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+// The tokens are redacted, so that access tokens can be logged.
+impl std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("token_type", &self.token_type)
+            .field("access_token", &crate::redact::Secret(&self.access_token))
+            .field("expires_in", &self.expires_in)
+            .field("refresh_token", &crate::redact::Secret(&self.refresh_token))
+            .field("refresh_token_expires_in", &self.refresh_token_expires_in)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
 const REFRESH_THRESHOLD: Duration = Duration::from_secs(60);
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct InnerToken {
     access_token: String,
     refresh_token: String,
@@ -238,18 +359,17 @@ impl Client {
         Q: ToString,
     {
         // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
         let client = reqwest::Client::builder().build();
         match client {
             Ok(c) => {
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware)
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-                        retry_policy,
-                    ))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
 
                 Client {
@@ -264,7 +384,11 @@ impl Client {
                     })),
 
                     auto_refresh: false,
+                    on_token_refresh: None,
+                    token_store: None,
                     client,
+                    request_options: Default::default(),
+                    service_account: None,
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -277,6 +401,24 @@ impl Client {
         self
     }
 
+    /// Sets a callback that is invoked with the new `AccessToken` every time the client
+    /// fetches or refreshes an access token. This can be used to persist the tokens.
+    pub fn set_token_refresh_callback<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&AccessToken) + Send + Sync + 'static,
+    {
+        self.on_token_refresh = Some(Arc::new(callback));
+        self
+    }
+
+    /// Keep the tokens in the given store, shared with the other clients using it.
+    /// See the `token_store` module.
+    pub fn with_token_store(&self, store: Arc<dyn crate::token_store::TokenStore>) -> Self {
+        let mut c = self.clone();
+        c.token_store = Some(store);
+        c
+    }
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -341,6 +483,111 @@ impl Client {
         c
     }
 
+    /// Create a client authenticated as a service account, from its JSON key, with
+    /// access to the given scopes. With domain-wide delegation, `subject` is the email
+    /// address of the user of the domain to act as.
+    ///
+    /// The client gets an access token before its first request, and a new one when
+    /// it expires. See the `service_account` module.
+    pub fn new_from_service_account(
+        key: &[u8],
+        scopes: &[&str],
+        subject: Option<&str>,
+    ) -> Result<Self> {
+        let key = crate::service_account::ServiceAccountKey::from_json(key)?;
+        Self::new_from_service_account_key(key, scopes, subject)
+    }
+
+    /// Create a client authenticated as a service account, with the key read from
+    /// the file `GOOGLE_APPLICATION_CREDENTIALS` points to. See
+    /// `new_from_service_account`.
+    pub fn new_from_service_account_env(scopes: &[&str], subject: Option<&str>) -> Result<Self> {
+        let key = crate::service_account::ServiceAccountKey::from_env()?;
+        Self::new_from_service_account_key(key, scopes, subject)
+    }
+
+    /// Create a client authenticated as a service account, from its parsed key. See
+    /// `new_from_service_account`.
+    pub fn new_from_service_account_key(
+        key: crate::service_account::ServiceAccountKey,
+        scopes: &[&str],
+        subject: Option<&str>,
+    ) -> Result<Self> {
+        let service_account = crate::service_account::ServiceAccount::new(key, scopes, subject)?;
+        let mut c = Client::new("", "", "", "", "");
+        c.set_service_account(service_account);
+        Ok(c)
+    }
+
+    /// Act as another user of the domain, with the same service account. The returned
+    /// client gets its own access tokens, and does not use the token store.
+    pub fn with_subject(&self, subject: &str) -> Result<Self> {
+        let service_account = match &self.service_account {
+            Some(service_account) => service_account.with_subject(subject),
+            None => {
+                return Err(anyhow!(
+                    "only clients authenticated as a service account can act as another user"
+                ))
+            }
+        };
+        let mut c = self.clone();
+        // The tokens of the store are the ones of the other user.
+        c.token_store = None;
+        c.set_service_account(service_account);
+        Ok(c)
+    }
+
+    fn set_service_account(&mut self, service_account: crate::service_account::ServiceAccount) {
+        self.service_account = Some(service_account);
+        self.auto_refresh = true;
+        // The client has no access token yet, expire it to get one.
+        self.token = Arc::new(RwLock::new(InnerToken {
+            access_token: String::new(),
+            refresh_token: String::new(),
+            expires_at: Some(Instant::now()),
+        }));
+    }
+
+    /// Override the HTTP client used to make requests. This can be used to attach
+    /// additional `reqwest_middleware` middleware to the client, for example to log
+    /// requests, inject headers or record metrics. The default client traces and
+    /// retries requests, add the same middleware to keep that behavior.
+    pub fn with_http_client(&self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        let mut c = self.clone();
+        c.client = client;
+        c
+    }
+
+    /// Make requests with an HTTP client built from the given configuration of its
+    /// connections, with the default middleware. This replaces an HTTP client set
+    /// with `with_http_client`.
+    pub fn with_config(&self, config: &crate::client_config::ClientConfig) -> Result<Self> {
+        let mut c = self.clone();
+        c.client = config.build()?;
+        Ok(c)
+    }
+
+    /// Apply the given options, like a timeout, extra headers or a cancellation token,
+    /// to every request made with the returned client.
+    pub fn with_request_options(&self, options: crate::request_options::RequestOptions) -> Self {
+        let mut c = self.clone();
+        c.request_options = options;
+        c
+    }
+
+    /// Abort the requests made with the returned client that do not complete within
+    /// the given time, retries included.
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        let mut c = self.clone();
+        c.request_options.timeout = Some(timeout);
+        c
+    }
+
+    /// Send a request, applying the request options of the client.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        self.request_options.execute(&self.client, req).await
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -358,18 +605,17 @@ impl Client {
             .expect("failed to read from google credential env var");
 
         let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let retry_policy = crate::retry::RetryPolicy::default();
 
         match client {
             Ok(c) => {
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware)
+                    // Record a span for every request when the `tracing` feature is enabled.
+                    .with(crate::trace::TraceMiddleware)
                     // Retry failed requests.
-                    .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-                        retry_policy,
-                    ))
+                    .with(crate::retry::RetryMiddleware::new(retry_policy))
                     .build();
 
                 Client {
@@ -383,7 +629,11 @@ impl Client {
                         expires_at: None,
                     })),
                     auto_refresh: false,
+                    on_token_refresh: None,
+                    token_store: None,
                     client,
+                    request_options: Default::default(),
+                    service_account: None,
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -393,29 +643,141 @@ impl Client {
     /// Return a user consent url with an optional set of scopes.
     /// If no scopes are provided, they will not be passed in the url.
     pub fn user_consent_url(&self, scopes: &[String]) -> String {
-        let state = uuid::Uuid::new_v4();
-
-        let url = format!(
-            "{}?client_id={}&response_type=code&redirect_uri={}&state={}",
-            USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
-        );
+        let state = uuid::Uuid::new_v4().to_string();
+        let scope = scopes.join(" ");
+
+        let mut query_args = vec![
+            ("client_id", self.client_id.as_str()),
+            ("response_type", "code"),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("state", state.as_str()),
+        ];
 
-        if scopes.is_empty() {
-            return url;
+        // Add the scopes.
+        if !scopes.is_empty() {
+            query_args.push(("scope", scope.as_str()));
         }
 
-        // Add the scopes.
-        format!("{}&scope={}", url, scopes.join(" "))
+        crate::progenitor_support::append_query(USER_CONSENT_ENDPOINT, &query_args)
     }
 
     /// Refresh an access token from a refresh token. Client must have a refresh token
     /// for this to work.
     pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+        let store = match &self.token_store {
+            Some(store) => store,
+            None => return self.fetch_refreshed_access_token().await,
+        };
+
+        store.lock_refresh().await?;
+        let t = self.refresh_stored_access_token(store.as_ref()).await;
+        store.unlock_refresh().await?;
+        t
+    }
+
+    async fn refresh_stored_access_token(
+        &self,
+        store: &dyn crate::token_store::TokenStore,
+    ) -> Result<AccessToken> {
+        if let Some(stored) = store.get().await? {
+            // Another client refreshed the token while this one waited for the lock.
+            let expired = stored
+                .expires_in()
+                .map(|e| e <= REFRESH_THRESHOLD.as_secs() as i64);
+            if stored.access_token != self.token.read().await.access_token && expired != Some(true)
+            {
+                self.set_stored_token(&stored).await;
+                return Ok(stored.to_access_token());
+            }
+
+            // Providers that rotate refresh tokens revoke the ones that were used, refresh
+            // from the last one.
+            if !stored.refresh_token.is_empty() {
+                self.token.write().await.refresh_token = stored.refresh_token;
+            }
+        }
+
+        let t = self.fetch_refreshed_access_token().await?;
+        store
+            .put(&crate::token_store::StoredToken::from_access_token(&t))
+            .await?;
+        Ok(t)
+    }
+
+    async fn load_stored_token(&self) -> Result<()> {
+        if let Some(store) = &self.token_store {
+            if let Some(stored) = store.get().await? {
+                self.set_stored_token(&stored).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set_stored_token(&self, stored: &crate::token_store::StoredToken) {
+        *self.token.write().await = InnerToken {
+            access_token: stored.access_token.clone(),
+            refresh_token: stored.refresh_token.clone(),
+            expires_at: stored.expires_in().and_then(Self::compute_expires_at),
+        };
+    }
+
+    async fn fetch_service_account_token(
+        &self,
+        service_account: &crate::service_account::ServiceAccount,
+    ) -> Result<AccessToken> {
+        // Requests finding the token expired at the same time get a single new one: the
+        // ones which waited for the lock use the token fetched meanwhile.
+        let stale = self.token.read().await.access_token.clone();
+        let _refresh = service_account.lock_refresh().await;
+        {
+            let token = self.token.read().await;
+            let now = Instant::now();
+            if let Some(expires_at) = token.expires_at.filter(|e| *e > now) {
+                if token.access_token != stale {
+                    return Ok(AccessToken {
+                        token_type: "Bearer".to_string(),
+                        access_token: token.access_token.clone(),
+                        expires_in: (expires_at.duration_since(now) + REFRESH_THRESHOLD).as_secs()
+                            as i64,
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        let response = self.execute(service_account.token_request()?).await?;
+        let status = response.status();
+        let body = response.bytes().await?;
+        if !status.is_success() {
+            return Err(crate::error::from_response(status, &body));
+        }
+
+        // Service accounts get no refresh token, they sign a new assertion instead.
+        let t: AccessToken = serde_json::from_slice(&body)?;
+        *self.token.write().await = InnerToken {
+            access_token: t.access_token.clone(),
+            refresh_token: String::new(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        };
+
+        if let Some(callback) = &self.on_token_refresh {
+            callback(&t);
+        }
+
+        Ok(t)
+    }
+
+    async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {
+        if let Some(service_account) = &self.service_account {
+            return self.fetch_service_account_token(service_account).await;
+        }
+
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
             if refresh_token.is_empty() {
-                anyhow!("refresh token cannot be empty");
+                return Err(anyhow!("refresh token cannot be empty"));
             }
 
             let mut headers = reqwest::header::HeaderMap::new();
@@ -431,8 +793,7 @@ impl Client {
                 ("client_secret", &self.client_secret),
                 ("redirect_uri", &self.redirect_uri),
             ];
-            let client = reqwest::Client::new();
-            client
+            self.client
                 .post(TOKEN_ENDPOINT)
                 .headers(headers)
                 .form(&params)
@@ -442,16 +803,29 @@ impl Client {
         };
 
         // Unwrap the response.
-        let t: AccessToken = response.json().await?;
+        let status = response.status();
+        let body = response.bytes().await?;
+        if !status.is_success() {
+            return Err(crate::error::from_response(status, &body));
+        }
+        let mut t: AccessToken = serde_json::from_slice(&body)?;
 
-        let refresh_token = self.token.read().await.refresh_token.clone();
+        // Some providers rotate the refresh token on every refresh, keep the one we
+        // have if a new one was not returned.
+        if t.refresh_token.is_empty() {
+            t.refresh_token = self.token.read().await.refresh_token.clone();
+        }
 
         *self.token.write().await = InnerToken {
             access_token: t.access_token.clone(),
-            refresh_token,
+            refresh_token: t.refresh_token.clone(),
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        if let Some(callback) = &self.on_token_refresh {
+            callback(&t);
+        }
+
         Ok(t)
     }
 
@@ -472,8 +846,8 @@ impl Client {
             ("redirect_uri", &self.redirect_uri),
             ("state", state),
         ];
-        let client = reqwest::Client::new();
-        let resp = client
+        let resp = self
+            .client
             .post(TOKEN_ENDPOINT)
             .headers(headers)
             .form(&params)
@@ -482,7 +856,12 @@ impl Client {
             .await?;
 
         // Unwrap the response.
-        let t: AccessToken = resp.json().await?;
+        let status = resp.status();
+        let body = resp.bytes().await?;
+        if !status.is_success() {
+            return Err(crate::error::from_response(status, &body));
+        }
+        let t: AccessToken = serde_json::from_slice(&body)?;
 
         *self.token.write().await = InnerToken {
             access_token: t.access_token.clone(),
@@ -490,6 +869,16 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        if let Some(store) = &self.token_store {
+            store
+                .put(&crate::token_store::StoredToken::from_access_token(&t))
+                .await?;
+        }
+
+        if let Some(callback) = &self.on_token_refresh {
+            callback(&t);
+        }
+
         Ok(t)
     }
 
@@ -532,10 +921,11 @@ impl Client {
         }
 
         if let Some(body) = body {
-            log::debug!(
-                "body: {:?}",
-                String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap()
-            );
+            // The bodies of these requests are in memory, uploads stream theirs separately.
+            let bytes = body
+                .as_bytes()
+                .ok_or_else(|| anyhow!("the body of the request must be in memory"))?;
+            log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
 
             req = req.body(body);
         }
@@ -549,37 +939,52 @@ impl Client {
         uri: &str,
         body: Option<reqwest::Body>,
     ) -> Result<reqwest::Response> {
+        // Start from the token of the store when the client was created without one.
+        if self.token_store.is_some() && self.token.read().await.access_token.is_empty() {
+            self.load_stored_token().await?;
+        }
+
+        // We have a known expired token, there is no point in trying to make a request
+        // without refreshing it first.
+        let refreshed = self.auto_refresh && self.is_expired().await == Some(true);
+        if refreshed {
+            self.refresh_access_token().await?;
+        }
+
         let req = self.make_request(&method, uri, body).await?;
+        if !self.auto_refresh || refreshed {
+            return self.execute(req).await;
+        }
+
+        // We either have a (theoretically) known good token or we do not know the expiration
+        // of the token at all. We make an optimistic attempt at the request, keeping a copy of
+        // it around so it can be replayed if the provider rejects the token. Streaming bodies
+        // can not be copied, in which case the failure is deferred to the caller.
+        let retry = req.try_clone();
+        let resp = self.execute(req).await?;
+
+        if resp.status() != http::StatusCode::UNAUTHORIZED
+            || (self.token.read().await.refresh_token.is_empty() && self.service_account.is_none())
+        {
+            return Ok(resp);
+        }
 
-        let resp = if self.auto_refresh {
-            let expired = self.is_expired().await;
+        match retry {
+            Some(mut retry) => {
+                self.refresh_access_token().await?;
 
-            match expired {
-                // We have a known expired token, there is no point in trying to make a request
-                // without refreshing it first
-                Some(true) => {
-                    self.refresh_access_token().await?;
-                    self.client.execute(req).await?
+                let (_, auth) = self.url_and_auth(retry.url().as_str()).await?;
+                if let Some(auth_str) = auth {
+                    retry.headers_mut().insert(
+                        http::header::AUTHORIZATION,
+                        reqwest::header::HeaderValue::from_str(&auth_str)?,
+                    );
                 }
-                // We have a (theoretically) known good token available. We make an optimistic
-                // attempting at the request. If the token is no longer good, then something other
-                // than the expiration is triggering the failure. We defer handling of these errors
-                // to the caller
-                Some(false) => self.client.execute(req).await?,
-
-                // We do not know what state we are in. We could have a valid or expired token.
-                // Generally this means we are in one of two cases:
-                //   1. We have not yet performed a token refresh (and do not know the expiration
-                //      of the user provided token)
-                //   2. The provider is returning unusable expiration times, at which point we
-                //      choose to ignore them
-                None => self.client.execute(req).await?,
-            }
-        } else {
-            self.client.execute(req).await?
-        };
 
-        Ok(resp)
+                self.execute(retry).await
+            }
+            None => Ok(resp),
+        }
     }
 
     async fn request<Out>(
@@ -611,15 +1016,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::error::from_response(status, &response_body);
 
             Err(error)
         }
@@ -660,15 +1057,7 @@ impl Client {
             };
             parsed_response.map(|out| (link, out)).map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::error::from_response(status, &response_body);
             Err(error)
         }
     }
@@ -676,6 +1065,17 @@ impl Client {
     /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.post_multipart(uri, form).await
+    }
+
+    /// Send a `multipart/form-data` `POST` request, for example to upload a file
+    /// along with other fields. The content type, with the boundary of the parts,
+    /// is set from the form.
+    #[allow(dead_code)]
+    async fn post_multipart<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -690,15 +1090,10 @@ impl Client {
 
         let mut req = instance.client.request(http::Method::POST, url);
 
-        // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
             reqwest::header::HeaderValue::from_static("application/json"),
         );
-        req = req.header(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
 
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
@@ -707,7 +1102,7 @@ impl Client {
         log::debug!("form: {:?}", form);
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -732,15 +1127,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::error::from_response(status, &response_body);
 
             Err(error)
         }
@@ -778,7 +1165,7 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -803,15 +1190,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::error::from_response(status, &response_body);
 
             Err(error)
         }
@@ -870,7 +1249,7 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = self.execute(req.build()?).await?;
 
         let status = response.status();
 
@@ -890,15 +1269,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::error::from_response(status, &response_body);
 
             Err(error)
         }
@@ -935,6 +1306,33 @@ impl Client {
         self.unfold(uri).await
     }
 
+    /// Stream the items of the JSON array in the response to a `GET`, parsing each of
+    /// them as soon as it downloaded rather than once the whole body is in memory. The
+    /// array is either the body itself, or the first field of the body holding an
+    /// array, like the `data` of a page of results.
+    pub async fn get_stream_items<T>(
+        &self,
+        uri: &str,
+    ) -> Result<impl futures::Stream<Item = Result<T>>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self
+            .streaming()
+            .request_raw(http::Method::GET, &(self.host.to_string() + uri), None)
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let response_body = response.bytes().await?;
+            let error = crate::error::from_response(status, &response_body);
+
+            return Err(error);
+        }
+
+        Ok(crate::json_stream::items(response))
+    }
+
     /// "unfold" paginated results of a vector of items
     #[allow(dead_code)]
     async fn unfold<D>(&self, uri: &str) -> Result<Vec<D>>
@@ -1059,3 +1457,77 @@ impl Client {
         settings::Settings::new(self.clone())
     }
 }
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("Client");
+        d.field("host", &self.host)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &crate::redact::Secret(&self.client_secret))
+            .field("redirect_uri", &self.redirect_uri);
+        // Rather than waiting for a refresh of the token to complete, leave it out.
+        match self.token.try_read() {
+            Ok(token) => d.field("token", &*token),
+            Err(_) => d.field("token", &format_args!("<refreshing>")),
+        };
+        d.field("auto_refresh", &self.auto_refresh)
+            .field("request_options", &self.request_options)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for InnerToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerToken")
+            .field("access_token", &crate::redact::Secret(&self.access_token))
+            .field("refresh_token", &crate::redact::Secret(&self.refresh_token))
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Create a client authenticated as the service account whose key
+/// `GOOGLE_APPLICATION_CREDENTIALS` points to, with the scopes of `GOOGLE_SCOPES`
+/// separated by spaces, acting as the user of `GOOGLE_SUBJECT` when it is set.
+///
+/// Without a service account, the client is the app of the base64 encoded secret of
+/// `GOOGLE_KEY_ENCODED`, with the tokens of `GOOGLE_TOKEN` and `GOOGLE_REFRESH_TOKEN`
+/// when they are set.
+impl crate::from_env::FromEnv for Client {
+    fn env_vars() -> &'static [&'static str] {
+        &[
+            "GOOGLE_APPLICATION_CREDENTIALS",
+            "GOOGLE_SCOPES",
+            "GOOGLE_SUBJECT",
+            "GOOGLE_KEY_ENCODED",
+            "GOOGLE_TOKEN",
+            "GOOGLE_REFRESH_TOKEN",
+        ]
+    }
+
+    fn from_env() -> Result<Self> {
+        if !crate::from_env::optional(crate::service_account::CREDENTIALS_ENV).is_empty() {
+            let scopes = crate::from_env::required("GOOGLE_SCOPES")?;
+            let scopes: Vec<&str> = scopes.split_whitespace().collect();
+            let subject = crate::from_env::optional("GOOGLE_SUBJECT");
+            let subject = Some(subject.as_str()).filter(|s| !s.is_empty());
+            return Client::new_from_service_account_env(&scopes, subject);
+        }
+
+        let secret = base64::decode(crate::from_env::required("GOOGLE_KEY_ENCODED")?)?;
+        let secret = yup_oauth2::parse_application_secret(secret)?;
+        let refresh_token = crate::from_env::optional("GOOGLE_REFRESH_TOKEN");
+        let mut client = Client::new(
+            secret.client_id,
+            secret.client_secret,
+            secret.redirect_uris.first().cloned().unwrap_or_default(),
+            crate::from_env::optional("GOOGLE_TOKEN"),
+            &refresh_token,
+        );
+        // With a refresh token, the client gets a new access token when it expires.
+        if !refresh_token.is_empty() {
+            client.set_auto_access_token_refresh(true);
+        }
+        Ok(client)
+    }
+}
@@ -0,0 +1,257 @@
+//! Build email messages in the raw form the Gmail API sends.
+//!
+//! `users.messages.send` and `users.drafts.create` take the whole message, headers
+//! included, as RFC 2822 text encoded in base64url. `MessageBuilder` writes it from
+//! the sender, the recipients, the subject, the bodies and the attachments, so the
+//! MIME structure does not have to be put together by hand.
+//!
+//! ```
+//! use google_calendar::message::MessageBuilder;
+//!
+//! let raw = MessageBuilder::new("me@example.com")
+//!     .to("you@example.com")
+//!     .subject("Quarterly report")
+//!     .text("The report is attached.")
+//!     .html("<p>The report is attached.</p>")
+//!     .attachment("report.csv", "text/csv", b"quarter,total\nQ1,42\n".to_vec())
+//!     .build_raw()
+//!     .unwrap();
+//!
+//! // Send `{"raw": raw}` to `users.messages.send`.
+//! ```
+//!
+//! https://developers.google.com/gmail/api/guides/sending
+use anyhow::{bail, Result};
+
+/// The longest line of base64 in a body, as RFC 2045 requires.
+const LINE_LENGTH: usize = 76;
+
+/// The most bytes of text in an encoded word, so that it is at most 75 characters
+/// long as RFC 2047 requires.
+const WORD_LENGTH: usize = 45;
+
+/// A file attached to a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attachment {
+    pub filename: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/**
+ * Builds an email message.
+ *
+ * With both a plain text and an HTML body, mail clients show the one they can.
+ * Addresses are given as they appear in the headers, like `me@example.com` or
+ * `Jane Doe <jane@example.com>`.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MessageBuilder {
+    from: String,
+    to: Vec<String>,
+    cc: Vec<String>,
+    bcc: Vec<String>,
+    subject: String,
+    text: Option<String>,
+    html: Option<String>,
+    attachments: Vec<Attachment>,
+}
+
+impl MessageBuilder {
+    /// A message sent by `from`.
+    pub fn new(from: &str) -> Self {
+        MessageBuilder {
+            from: from.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Send the message to `address`.
+    pub fn to(mut self, address: &str) -> Self {
+        self.to.push(address.to_string());
+        self
+    }
+
+    /// Send a copy of the message to `address`.
+    pub fn cc(mut self, address: &str) -> Self {
+        self.cc.push(address.to_string());
+        self
+    }
+
+    /// Send a blind copy of the message to `address`. Gmail removes the header from
+    /// the messages it delivers.
+    pub fn bcc(mut self, address: &str) -> Self {
+        self.bcc.push(address.to_string());
+        self
+    }
+
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.subject = subject.to_string();
+        self
+    }
+
+    /// The plain text body.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// The HTML body.
+    pub fn html(mut self, html: &str) -> Self {
+        self.html = Some(html.to_string());
+        self
+    }
+
+    /// Attach a file, given its name and its media type, like `application/pdf`.
+    pub fn attachment(mut self, filename: &str, content_type: &str, data: Vec<u8>) -> Self {
+        self.attachments.push(Attachment {
+            filename: filename.to_string(),
+            content_type: content_type.to_string(),
+            data,
+        });
+        self
+    }
+
+    /// The message as RFC 2822 text, with CRLF line endings.
+    pub fn build(&self) -> Result<String> {
+        if self.to.is_empty() && self.cc.is_empty() && self.bcc.is_empty() {
+            bail!("a message needs at least one recipient");
+        }
+
+        let mut message = String::new();
+        header(&mut message, "From", &self.from)?;
+        for (name, addresses) in [("To", &self.to), ("Cc", &self.cc), ("Bcc", &self.bcc)] {
+            if !addresses.is_empty() {
+                header(&mut message, name, &addresses.join(", "))?;
+            }
+        }
+        header(&mut message, "Subject", &encode_words(&self.subject))?;
+        header(&mut message, "MIME-Version", "1.0")?;
+        message.push_str(&self.body()?);
+        Ok(message)
+    }
+
+    /// The message encoded in base64url, for the `raw` field of a Gmail message.
+    pub fn build_raw(&self) -> Result<String> {
+        Ok(base64::encode_config(self.build()?, base64::URL_SAFE))
+    }
+
+    /// The body of the message with the headers describing it.
+    fn body(&self) -> Result<String> {
+        let mut bodies = Vec::new();
+        if let Some(text) = &self.text {
+            bodies.push(text_part("text/plain", text));
+        }
+        if let Some(html) = &self.html {
+            bodies.push(text_part("text/html", html));
+        }
+        let body = match bodies.len() {
+            0 => text_part("text/plain", ""),
+            1 => bodies.remove(0),
+            _ => multipart("alternative", &bodies),
+        };
+        if self.attachments.is_empty() {
+            return Ok(body);
+        }
+
+        let mut parts = vec![body];
+        for attachment in &self.attachments {
+            parts.push(attachment_part(attachment)?);
+        }
+        Ok(multipart("mixed", &parts))
+    }
+}
+
+/// Append a header to the message, refusing values which would start another one.
+fn header(message: &mut String, name: &str, value: &str) -> Result<()> {
+    if value.contains(&['\r', '\n'][..]) {
+        bail!("invalid {} header: {:?}", name, value);
+    }
+
+    message.push_str(name);
+    message.push_str(": ");
+    message.push_str(value);
+    message.push_str("\r\n");
+    Ok(())
+}
+
+/// The value of a header, in RFC 2047 encoded words when it is not ASCII. The words
+/// never split a character.
+fn encode_words(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        if i + c.len_utf8() - start > WORD_LENGTH {
+            words.push(&value[start..i]);
+            start = i;
+        }
+    }
+    words.push(&value[start..]);
+
+    words
+        .iter()
+        .map(|word| format!("=?UTF-8?B?{}?=", base64::encode(word)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Data encoded in base64, in lines of `LINE_LENGTH` characters.
+fn encode_lines(data: &[u8]) -> String {
+    let encoded = base64::encode(data);
+    let lines: Vec<&str> = encoded
+        .as_bytes()
+        .chunks(LINE_LENGTH)
+        .map(|line| std::str::from_utf8(line).unwrap())
+        .collect();
+    lines.join("\r\n")
+}
+
+fn text_part(content_type: &str, text: &str) -> String {
+    format!(
+        "Content-Type: {}; charset=\"UTF-8\"\r\nContent-Transfer-Encoding: base64\r\n\r\n{}\r\n",
+        content_type,
+        encode_lines(text.as_bytes())
+    )
+}
+
+fn attachment_part(attachment: &Attachment) -> Result<String> {
+    let mut part = String::new();
+    let filename = encode_words(&attachment.filename)
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    header(
+        &mut part,
+        "Content-Type",
+        &format!("{}; name=\"{}\"", attachment.content_type, filename),
+    )?;
+    header(
+        &mut part,
+        "Content-Disposition",
+        &format!("attachment; filename=\"{}\"", filename),
+    )?;
+    header(&mut part, "Content-Transfer-Encoding", "base64")?;
+    part.push_str("\r\n");
+    part.push_str(&encode_lines(&attachment.data));
+    part.push_str("\r\n");
+    Ok(part)
+}
+
+/// A multipart body made of the given parts. The boundary can not appear in them,
+/// their bodies being in base64, which has no `_`.
+fn multipart(subtype: &str, parts: &[String]) -> String {
+    let boundary = format!("=_{}", uuid::Uuid::new_v4().to_simple());
+
+    let mut body = format!(
+        "Content-Type: multipart/{}; boundary=\"{}\"\r\n\r\n",
+        subtype, boundary
+    );
+    for part in parts {
+        body.push_str(&format!("--{}\r\n{}", boundary, part));
+    }
+    body.push_str(&format!("--{}--\r\n", boundary));
+    body
+}
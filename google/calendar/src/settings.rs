@@ -31,15 +31,15 @@ impl Settings {
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Setting>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/settings?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/users/me/settings"), &query_args);
 
         let resp: crate::types::Settings = self.client.get(&url, None).await?;
 
@@ -63,17 +63,13 @@ impl Settings {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             items.append(&mut resp.items);
 
@@ -108,15 +104,17 @@ impl Settings {
         page_token: &str,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/settings/watch?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/me/settings/watch"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -132,7 +130,8 @@ impl Settings {
     *
     * * `setting: &str` -- The id of the user setting.
     */
-    pub async fn get(&self, setting: &str) -> Result<crate::types::Setting> {
+    pub async fn get(&self, setting: impl AsRef<str>) -> Result<crate::types::Setting> {
+        let setting = setting.as_ref();
         let url = format!(
             "/users/me/settings/{}",
             crate::progenitor_support::encode_path(setting),
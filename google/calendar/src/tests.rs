@@ -99,3 +99,52 @@ fn test_deserialize() {
     let deserialized: crate::types::Event = serde_json::from_str(EVENT).unwrap();
     println!("event = {:?}", deserialized);
 }
+
+#[tokio::test]
+async fn test_watch_channel() {
+    let mock = crate::transport::MockTransport::new();
+    // The channel being renewed expires in a minute.
+    let expiration = chrono::Utc::now().timestamp_millis() + 60 * 1000;
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        format!(
+            r#"{{"kind": "api#channel", "id": "old", "resourceId": "o3hgv1538sdjfh", "resourceUri": "https://www.googleapis.com/calendar/v3/calendars/primary/events", "token": "secret", "expiration": "{}"}}"#,
+            expiration
+        ),
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"kind": "api#channel", "id": "new", "resourceId": "o3hgv1538sdjfh", "expiration": "4102444800000"}"#,
+    );
+    mock.push_response(reqwest::StatusCode::NO_CONTENT, "");
+
+    let calendar = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let events = calendar.events();
+    let channel = crate::watch::WatchChannel::web_hook("https://example.com/notifications")
+        .token("secret")
+        .ttl(std::time::Duration::from_secs(3600));
+    let channel = events.watch_channel("primary", &channel).await.unwrap();
+    assert_eq!(channel.resource_id, "o3hgv1538sdjfh");
+    assert!(channel.expires_within(std::time::Duration::from_secs(60)));
+
+    let renewed = events
+        .renew_channel("primary", &channel, std::time::Duration::from_secs(3600))
+        .await
+        .unwrap();
+    assert_eq!(renewed.id, "new");
+    assert!(!renewed.expires_within(std::time::Duration::from_secs(3600)));
+
+    let requests = mock.requests();
+    let watch = requests[0].json::<serde_json::Value>().unwrap();
+    assert_eq!(watch["type"], "web_hook");
+    assert_eq!(watch["params"]["ttl"], "3600");
+    assert_eq!(
+        requests[1].json::<serde_json::Value>().unwrap()["token"],
+        "secret"
+    );
+    assert_eq!(requests[2].url.path(), "/calendar/v3/channels/stop");
+    assert_eq!(
+        requests[2].json::<serde_json::Value>().unwrap(),
+        serde_json::json!({"id": "old", "resourceId": "o3hgv1538sdjfh"})
+    );
+}
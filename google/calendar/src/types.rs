@@ -762,7 +762,7 @@ pub struct ConferenceSolutionKey {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateConferenceRequest {
     #[serde(
         default,
@@ -784,6 +784,42 @@ pub struct CreateConferenceRequest {
     pub status: Option<ConferenceRequestStatus>,
 }
 
+impl CreateConferenceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateConferenceRequestBuilder {
+        CreateConferenceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateConferenceRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateConferenceRequestBuilder(CreateConferenceRequest);
+
+impl CreateConferenceRequestBuilder {
+    pub fn conference_solution_key(
+        mut self,
+        conference_solution_key: impl Into<ConferenceSolutionKey>,
+    ) -> Self {
+        self.0.conference_solution_key = Some(conference_solution_key.into());
+        self
+    }
+
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.0.request_id = request_id.into();
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<ConferenceRequestStatus>) -> Self {
+        self.0.status = Some(status.into());
+        self
+    }
+
+    pub fn build(self) -> CreateConferenceRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EntryPoint {
     /**
@@ -1821,7 +1857,7 @@ pub struct FreeBusyGroup {
     pub errors: Vec<Error>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FreeBusyRequest {
     /**
     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
@@ -1886,6 +1922,54 @@ pub struct FreeBusyRequest {
     pub time_zone: String,
 }
 
+impl FreeBusyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> FreeBusyRequestBuilder {
+        FreeBusyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `FreeBusyRequest`.
+#[derive(Debug, Clone)]
+pub struct FreeBusyRequestBuilder(FreeBusyRequest);
+
+impl FreeBusyRequestBuilder {
+    pub fn calendar_expansion_max(mut self, calendar_expansion_max: i64) -> Self {
+        self.0.calendar_expansion_max = calendar_expansion_max;
+        self
+    }
+
+    pub fn group_expansion_max(mut self, group_expansion_max: i64) -> Self {
+        self.0.group_expansion_max = group_expansion_max;
+        self
+    }
+
+    pub fn items(mut self, items: Vec<FreeBusyRequestItem>) -> Self {
+        self.0.items = items;
+        self
+    }
+
+    pub fn time_max(mut self, time_max: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.0.time_max = Some(time_max.into());
+        self
+    }
+
+    pub fn time_min(mut self, time_min: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.0.time_min = Some(time_min.into());
+        self
+    }
+
+    pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+        self.0.time_zone = time_zone.into();
+        self
+    }
+
+    pub fn build(self) -> FreeBusyRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FreeBusyRequestItem {
     /**
@@ -2068,8 +2152,8 @@ pub enum Alt {
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -2077,7 +2161,7 @@ impl std::fmt::Display for Alt {
         match &*self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2105,8 +2189,8 @@ pub enum OrderBy {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -2115,7 +2199,7 @@ impl std::fmt::Display for OrderBy {
             OrderBy::StartTime => "startTime",
             OrderBy::Updated => "updated",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2145,8 +2229,8 @@ pub enum SendUpdates {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendUpdates {
@@ -2156,7 +2240,7 @@ impl std::fmt::Display for SendUpdates {
             SendUpdates::ExternalOnly => "externalOnly",
             SendUpdates::None => "none",
             SendUpdates::Noop => "",
-            SendUpdates::FallthroughString => "*",
+            SendUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2188,8 +2272,8 @@ pub enum MinAccessRole {
     Writer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MinAccessRole {
@@ -2200,7 +2284,7 @@ impl std::fmt::Display for MinAccessRole {
             MinAccessRole::Reader => "reader",
             MinAccessRole::Writer => "writer",
             MinAccessRole::Noop => "",
-            MinAccessRole::FallthroughString => "*",
+            MinAccessRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -638,3 +638,79 @@ pub mod deserialize_null_vector {
         Ok(Default::default())
     }
 }
+
+/// A nullable field of a `PATCH` request, which the API clears when it is sent as
+/// `null`, like the milestone of a GitHub issue. Unlike an `Option`, it tells a field
+/// left as it is from a field to clear.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Nullable<T> {
+    /// Leave the field as it is, it is not sent.
+    Unset,
+    /// Clear the field, it is sent as `null`.
+    Null,
+    /// Set the field to the value.
+    Set(T),
+}
+
+impl<T> Default for Nullable<T> {
+    fn default() -> Self {
+        Nullable::Unset
+    }
+}
+
+impl<T> Nullable<T> {
+    pub fn is_unset(&self) -> bool {
+        matches!(self, Nullable::Unset)
+    }
+
+    /// The value of the field, when it is set.
+    pub fn as_option(&self) -> Option<&T> {
+        match self {
+            Nullable::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for Nullable<T> {
+    fn from(value: T) -> Self {
+        Nullable::Set(value)
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&self.as_option(), serializer)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A missing field is unset, thanks to `#[serde(default)]`.
+        let value = <Option<T> as serde::Deserialize<'de>>::deserialize(deserializer)?;
+        Ok(match value {
+            Some(value) => Nullable::Set(value),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: schemars::JsonSchema> schemars::JsonSchema for Nullable<T> {
+    fn is_referenceable() -> bool {
+        <Option<T> as schemars::JsonSchema>::is_referenceable()
+    }
+
+    fn schema_name() -> String {
+        <Option<T> as schemars::JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <Option<T> as schemars::JsonSchema>::json_schema(gen)
+    }
+}
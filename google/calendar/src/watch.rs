@@ -0,0 +1,285 @@
+//! Push notifications, so that apps learn about changes to events without polling.
+//!
+//! Watching the events of a calendar opens a channel: Google then sends a `POST`
+//! request to the address of the channel whenever an event changes, and the app
+//! lists the events again with a sync token. Channels expire, after a week at
+//! most, so they have to be renewed before that.
+//!
+//! ```no_run
+//! # async fn run(calendar: google_calendar::Client) -> anyhow::Result<()> {
+//! use std::time::Duration;
+//!
+//! use google_calendar::watch::WatchChannel;
+//!
+//! let events = calendar.events();
+//! let channel = WatchChannel::web_hook("https://example.com/notifications")
+//!     .token("a secret, to check the notifications come from the channel");
+//! let mut channel = events.watch_channel("primary", &channel).await?;
+//!
+//! loop {
+//!     tokio::time::sleep(Duration::from_secs(60 * 60)).await;
+//!     // Replace the channel a day before it expires.
+//!     channel = events
+//!         .renew_channel("primary", &channel, Duration::from_secs(24 * 60 * 60))
+//!         .await?;
+//! }
+//! # }
+//! ```
+use std::{collections::HashMap, time::Duration};
+
+use anyhow::{anyhow, Result};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{channels::Channels, events::Events};
+
+/// A notification channel, on which Google sends a request to `address` whenever
+/// the watched resource changes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchChannel {
+    /// The ID of the channel, unique among the channels of the app.
+    pub id: String,
+    /// How notifications are delivered, always `web_hook`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The HTTPS URL the notifications are sent to.
+    pub address: String,
+    /// Sent back with every notification, in the `X-Goog-Channel-Token` header.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub token: String,
+    /// When the channel expires. Google may pick an earlier time than the one asked
+    /// for.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "expiration_millis"
+    )]
+    pub expiration: Option<chrono::DateTime<chrono::Utc>>,
+    /// Parameters of the delivery, like the `ttl` of the channel in seconds.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, String>,
+    /// The ID of the watched resource, set by Google. It is needed to stop the
+    /// channel.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub resource_id: String,
+    /// The URL of the watched resource, set by Google.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub resource_uri: String,
+}
+
+impl WatchChannel {
+    /// A channel delivering the notifications to an HTTPS URL, with a random ID.
+    pub fn web_hook(address: &str) -> Self {
+        WatchChannel {
+            id: uuid::Uuid::new_v4().to_string(),
+            type_: "web_hook".to_string(),
+            address: address.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Send a token back with every notification, to check where it comes from.
+    pub fn token(mut self, token: &str) -> Self {
+        self.token = token.to_string();
+        self
+    }
+
+    /// Ask for the channel to expire after `ttl`, instead of the default of Google.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.params
+            .insert("ttl".to_string(), ttl.as_secs().to_string());
+        self
+    }
+
+    /// How long until the channel expires, or `None` when it never does. This is
+    /// zero once it expired.
+    pub fn expires_in(&self) -> Option<Duration> {
+        let expiration = self.expiration?.timestamp_millis();
+        Some(Duration::from_millis(
+            expiration.saturating_sub(now_millis()).max(0) as u64,
+        ))
+    }
+
+    /// Whether the channel expires within `margin`.
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        self.expires_in().map(|e| e <= margin).unwrap_or(false)
+    }
+}
+
+fn now_millis() -> i64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+/// Google sends the expiration as a string of milliseconds since the epoch.
+mod expiration_millis {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(
+        expiration: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match expiration {
+            Some(e) => serializer.serialize_str(&e.timestamp_millis().to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let millis = match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(s)) => s.parse::<i64>().map_err(de::Error::custom)?,
+            Some(serde_json::Value::Number(n)) => n
+                .as_i64()
+                .ok_or_else(|| de::Error::custom("expiration out of range"))?,
+            _ => return Ok(None),
+        };
+        Ok(Utc.timestamp_millis_opt(millis).single())
+    }
+}
+
+/// What a notification tells about the watched resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// The first notification of a channel, sent when it is opened.
+    Sync,
+    /// The resource changed.
+    Exists,
+    /// The resource was deleted.
+    NotExists,
+    Unknown(String),
+}
+
+/// A notification received on a channel, read from the headers of the request
+/// Google sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub channel_id: String,
+    /// The token of the channel, to check against the one it was opened with.
+    pub token: String,
+    pub resource_id: String,
+    pub resource_uri: String,
+    pub resource_state: ResourceState,
+    /// The number of the notification on the channel, starting at 1.
+    pub message_number: u64,
+    pub channel_expiration: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl Notification {
+    /// Read a notification from the headers of a request sent by Google.
+    pub fn from_headers(headers: &HeaderMap) -> Result<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let channel_id = header("x-goog-channel-id");
+        if channel_id.is_empty() {
+            return Err(anyhow!("not a notification, X-Goog-Channel-ID is missing"));
+        }
+
+        Ok(Notification {
+            channel_id,
+            token: header("x-goog-channel-token"),
+            resource_id: header("x-goog-resource-id"),
+            resource_uri: header("x-goog-resource-uri"),
+            resource_state: match header("x-goog-resource-state").as_str() {
+                "sync" => ResourceState::Sync,
+                "exists" => ResourceState::Exists,
+                "not_exists" => ResourceState::NotExists,
+                s => ResourceState::Unknown(s.to_string()),
+            },
+            message_number: header("x-goog-message-number").parse().unwrap_or_default(),
+            channel_expiration: chrono::DateTime::parse_from_rfc2822(&header(
+                "x-goog-channel-expiration",
+            ))
+            .ok()
+            .map(|e| e.with_timezone(&chrono::Utc)),
+        })
+    }
+}
+
+impl Events {
+    /// Watch the events of a calendar: Google sends a notification to the address
+    /// of the channel whenever one of them changes. Returns the channel as opened,
+    /// with its expiration and the ID of the resource.
+    pub async fn watch_channel(
+        &self,
+        calendar_id: &str,
+        channel: &WatchChannel,
+    ) -> Result<WatchChannel> {
+        let url = format!(
+            "/calendars/{}/events/watch",
+            crate::progenitor_support::encode_path(calendar_id),
+        );
+
+        self.client
+            .post(
+                &url,
+                Some(reqwest::Body::from(serde_json::to_vec(channel)?)),
+            )
+            .await
+    }
+
+    /// Replace a channel that expires within `margin` with a new one, which has
+    /// the same address, token and parameters, then stop the old channel. Returns
+    /// the channel to keep, which is the same one when it does not expire yet.
+    pub async fn renew_channel(
+        &self,
+        calendar_id: &str,
+        channel: &WatchChannel,
+        margin: Duration,
+    ) -> Result<WatchChannel> {
+        if !channel.expires_within(margin) {
+            return Ok(channel.clone());
+        }
+
+        let renewed = WatchChannel {
+            id: uuid::Uuid::new_v4().to_string(),
+            type_: channel.type_.clone(),
+            address: channel.address.clone(),
+            token: channel.token.clone(),
+            params: channel.params.clone(),
+            ..Default::default()
+        };
+        let renewed = self.watch_channel(calendar_id, &renewed).await?;
+
+        // Both channels deliver notifications until the old one is stopped, which
+        // is harmless. It expires soon anyway, so failing to stop it is not an
+        // error.
+        if let Err(e) = self.client.channels().stop_channel(channel).await {
+            log::warn!("stopping channel {} failed: {}", channel.id, e);
+        }
+
+        Ok(renewed)
+    }
+}
+
+impl Channels {
+    /// Stop the notifications of a channel.
+    pub async fn stop_channel(&self, channel: &WatchChannel) -> Result<()> {
+        let body = serde_json::json!({
+            "id": channel.id,
+            "resourceId": channel.resource_id,
+        });
+
+        self.client
+            .post(
+                "/channels/stop",
+                Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+            )
+            .await
+    }
+}
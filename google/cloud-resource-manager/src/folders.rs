@@ -31,21 +31,20 @@ impl Folders {
         parent: &str,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::Folder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !parent.is_empty() {
-            query_args.push(("parent".to_string(), parent.to_string()));
+            query_args.push(("parent", parent.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2/folders?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v2/folders"), &query_args);
 
         let resp: crate::types::ListFoldersResponse = self.client.get(&url, None).await?;
 
@@ -65,15 +64,14 @@ impl Folders {
         parent: &str,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::Folder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent.is_empty() {
-            query_args.push(("parent".to_string(), parent.to_string()));
+            query_args.push(("parent", parent.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2/folders?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v2/folders"), &query_args);
 
         let mut resp: crate::types::ListFoldersResponse = self.client.get(&url, None).await?;
 
@@ -82,17 +80,13 @@ impl Folders {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             folders.append(&mut resp.folders);
 
@@ -121,12 +115,11 @@ impl Folders {
         parent: &str,
         body: &crate::types::Folder,
     ) -> Result<crate::types::Operation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent.is_empty() {
-            query_args.push(("parent".to_string(), parent.to_string()));
+            query_args.push(("parent", parent.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2/folders?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v2/folders"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -161,11 +154,9 @@ impl Folders {
     *
     * * `name: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
     */
-    pub async fn get(&self, name: &str) -> Result<crate::types::Folder> {
-        let url = format!(
-            "/v2/{}",
-            crate::progenitor_support::encode_path(name),
-        );
+    pub async fn get(&self, name: impl AsRef<str>) -> Result<crate::types::Folder> {
+        let name = name.as_ref();
+        let url = format!("/v2/{}", crate::progenitor_support::encode_path(name),);
 
         self.client.get(&url, None).await
     }
@@ -179,11 +170,9 @@ impl Folders {
     *
     * * `name: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
     */
-    pub async fn delete(&self, name: &str) -> Result<crate::types::Folder> {
-        let url = format!(
-            "/v2/{}",
-            crate::progenitor_support::encode_path(name),
-        );
+    pub async fn delete(&self, name: impl AsRef<str>) -> Result<crate::types::Folder> {
+        let name = name.as_ref();
+        let url = format!("/v2/{}", crate::progenitor_support::encode_path(name),);
 
         self.client.delete(&url, None).await
     }
@@ -200,19 +189,18 @@ impl Folders {
     */
     pub async fn patch(
         &self,
-        name: &str,
+        name: impl AsRef<str>,
         update_mask: &str,
         body: &crate::types::Folder,
     ) -> Result<crate::types::Folder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let name = name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !update_mask.is_empty() {
-            query_args.push(("updateMask".to_string(), update_mask.to_string()));
+            query_args.push(("updateMask", update_mask.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2/{}?{}",
-            crate::progenitor_support::encode_path(name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v2/{}", crate::progenitor_support::encode_path(name),),
+            &query_args,
         );
 
         self.client
@@ -231,13 +219,11 @@ impl Folders {
     */
     pub async fn mv(
         &self,
-        name: &str,
+        name: impl AsRef<str>,
         body: &crate::types::MoveFolderRequest,
     ) -> Result<crate::types::Operation> {
-        let url = format!(
-            "/v2/{}/move",
-            crate::progenitor_support::encode_path(name),
-        );
+        let name = name.as_ref();
+        let url = format!("/v2/{}/move", crate::progenitor_support::encode_path(name),);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -255,9 +241,10 @@ impl Folders {
     */
     pub async fn undelete(
         &self,
-        name: &str,
+        name: impl AsRef<str>,
         body: &crate::types::MoveProjectMetadata,
     ) -> Result<crate::types::Folder> {
+        let name = name.as_ref();
         let url = format!(
             "/v2/{}/undelete",
             crate::progenitor_support::encode_path(name),
@@ -279,9 +266,10 @@ impl Folders {
     */
     pub async fn get_iam_policy(
         &self,
-        resource: &str,
+        resource: impl AsRef<str>,
         body: &crate::types::GetIamPolicyRequest,
     ) -> Result<crate::types::Policy> {
+        let resource = resource.as_ref();
         let url = format!(
             "/v2/{}/getIamPolicy",
             crate::progenitor_support::encode_path(resource),
@@ -303,9 +291,10 @@ impl Folders {
     */
     pub async fn set_iam_policy(
         &self,
-        resource: &str,
+        resource: impl AsRef<str>,
         body: &crate::types::SetIamPolicyRequest,
     ) -> Result<crate::types::Policy> {
+        let resource = resource.as_ref();
         let url = format!(
             "/v2/{}/setIamPolicy",
             crate::progenitor_support::encode_path(resource),
@@ -327,9 +316,10 @@ impl Folders {
     */
     pub async fn test_iam_permissions(
         &self,
-        resource: &str,
+        resource: impl AsRef<str>,
         body: &crate::types::TestIamPermissionsRequest,
     ) -> Result<crate::types::TestIamPermissionsResponse> {
+        let resource = resource.as_ref();
         let url = format!(
             "/v2/{}/testIamPermissions",
             crate::progenitor_support::encode_path(resource),
@@ -21,11 +21,9 @@ impl Operations {
     *
     * * `name: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
     */
-    pub async fn get(&self, name: &str) -> Result<crate::types::Operation> {
-        let url = format!(
-            "/v1/{}",
-            crate::progenitor_support::encode_path(name),
-        );
+    pub async fn get(&self, name: impl AsRef<str>) -> Result<crate::types::Operation> {
+        let name = name.as_ref();
+        let url = format!("/v1/{}", crate::progenitor_support::encode_path(name),);
 
         self.client.get(&url, None).await
     }
@@ -0,0 +1,138 @@
+//! Keeping the tokens of a client in a store shared with other clients.
+//!
+//! A multi-tenant application can implement `TokenStore` on top of a database or
+//! a cache like Redis, with one store per user, so that the tokens outlive the
+//! clients and every process acting for the user uses the same ones. A client
+//! without an access token starts from the one in the store, and a client only
+//! refreshes the token while holding the refresh lock of the store. The other
+//! clients then use the token it got instead of refreshing it again, which
+//! matters with providers that revoke a refresh token once it is used.
+//!
+//! ```no_run
+//! # async fn run(client: google_cloud_resource_manager::Client) -> anyhow::Result<()> {
+//! use std::sync::Arc;
+//!
+//! use google_cloud_resource_manager::token_store::MemoryTokenStore;
+//!
+//! let store = Arc::new(MemoryTokenStore::new());
+//! let client = client.with_token_store(store.clone());
+//! client.refresh_access_token().await?;
+//! println!("{:?}", store.token());
+//! # Ok(())
+//! # }
+//! ```
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The tokens of a client, as they are stored.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StoredToken {
+    pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: String,
+    /// When the access token expires, if the provider said.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl StoredToken {
+    /// The token the client got or refreshed, expiring `expires_in` seconds from now.
+    pub fn from_access_token(token: &crate::AccessToken) -> Self {
+        StoredToken {
+            access_token: token.access_token.to_string(),
+            refresh_token: token.refresh_token.to_string(),
+            expires_at: if token.expires_in > 0 {
+                Some(Utc::now() + chrono::Duration::seconds(token.expires_in))
+            } else {
+                None
+            },
+        }
+    }
+
+    /// The number of seconds until the access token expires, negative once it has.
+    pub fn expires_in(&self) -> Option<i64> {
+        self.expires_at.map(|e| (e - Utc::now()).num_seconds())
+    }
+
+    pub fn to_access_token(&self) -> crate::AccessToken {
+        crate::AccessToken {
+            token_type: "Bearer".to_string(),
+            access_token: self.access_token.to_string(),
+            expires_in: self.expires_in().unwrap_or_default(),
+            refresh_token: self.refresh_token.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Stores the tokens of a client, for example in a database or a cache.
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync {
+    /// The stored token, or `None` if no token was stored yet.
+    async fn get(&self) -> Result<Option<StoredToken>>;
+
+    /// Store a token the client got or refreshed.
+    async fn put(&self, token: &StoredToken) -> Result<()>;
+
+    /// Wait until no other client is refreshing the token, and keep the others from
+    /// refreshing it until `unlock_refresh` is called. A lock shared between
+    /// processes should expire, in case the process holding it dies.
+    async fn lock_refresh(&self) -> Result<()>;
+
+    /// Let the other clients refresh the token, called once after every
+    /// `lock_refresh`.
+    async fn unlock_refresh(&self) -> Result<()>;
+}
+
+/// A `TokenStore` that keeps the token in memory, for the clients of a single
+/// process. Clones share the same token and refresh lock.
+#[derive(Clone, Debug)]
+pub struct MemoryTokenStore {
+    token: Arc<Mutex<Option<StoredToken>>>,
+    refresh: Arc<tokio::sync::Semaphore>,
+}
+
+impl Default for MemoryTokenStore {
+    fn default() -> Self {
+        MemoryTokenStore {
+            token: Default::default(),
+            refresh: Arc::new(tokio::sync::Semaphore::new(1)),
+        }
+    }
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The stored token.
+    pub fn token(&self) -> Option<StoredToken> {
+        self.token.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for MemoryTokenStore {
+    async fn get(&self) -> Result<Option<StoredToken>> {
+        Ok(self.token())
+    }
+
+    async fn put(&self, token: &StoredToken) -> Result<()> {
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(())
+    }
+
+    async fn lock_refresh(&self) -> Result<()> {
+        self.refresh.acquire().await?.forget();
+        Ok(())
+    }
+
+    async fn unlock_refresh(&self) -> Result<()> {
+        self.refresh.add_permits(1);
+        Ok(())
+    }
+}
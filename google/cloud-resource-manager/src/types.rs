@@ -41,8 +41,8 @@ pub enum LogType {
     LogTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogType {
@@ -53,7 +53,7 @@ impl std::fmt::Display for LogType {
             LogType::DataWrite => "DATA_WRITE",
             LogType::LogTypeUnspecified => "LOG_TYPE_UNSPECIFIED",
             LogType::Noop => "",
-            LogType::FallthroughString => "*",
+            LogType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -131,8 +131,8 @@ pub enum OperationType {
     OperationTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OperationType {
@@ -142,7 +142,7 @@ impl std::fmt::Display for OperationType {
             OperationType::Move => "MOVE",
             OperationType::OperationTypeUnspecified => "OPERATION_TYPE_UNSPECIFIED",
             OperationType::Noop => "",
-            OperationType::FallthroughString => "*",
+            OperationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -316,8 +316,8 @@ pub enum LifecycleState {
     LifecycleStateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LifecycleState {
@@ -327,7 +327,7 @@ impl std::fmt::Display for LifecycleState {
             LifecycleState::DeleteRequested => "DELETE_REQUESTED",
             LifecycleState::LifecycleStateUnspecified => "LIFECYCLE_STATE_UNSPECIFIED",
             LifecycleState::Noop => "",
-            LifecycleState::FallthroughString => "*",
+            LifecycleState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -423,8 +423,8 @@ pub enum ErrorMessageId {
     ResourceDeletedViolation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorMessageId {
@@ -443,7 +443,7 @@ impl std::fmt::Display for ErrorMessageId {
             ErrorMessageId::ParentDeletedViolation => "PARENT_DELETED_VIOLATION",
             ErrorMessageId::ResourceDeletedViolation => "RESOURCE_DELETED_VIOLATION",
             ErrorMessageId::Noop => "",
-            ErrorMessageId::FallthroughString => "*",
+            ErrorMessageId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -475,7 +475,7 @@ pub struct FolderOperationError {
 }
 
 /// Request message for `GetIamPolicy` method.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIamPolicyRequest {
     /**
     * Request message for `GetIamPolicy` method.
@@ -484,6 +484,29 @@ pub struct GetIamPolicyRequest {
     pub options: Option<GetPolicyOptions>,
 }
 
+impl GetIamPolicyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GetIamPolicyRequestBuilder {
+        GetIamPolicyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GetIamPolicyRequest`.
+#[derive(Debug, Clone)]
+pub struct GetIamPolicyRequestBuilder(GetIamPolicyRequest);
+
+impl GetIamPolicyRequestBuilder {
+    pub fn options(mut self, options: impl Into<GetPolicyOptions>) -> Self {
+        self.0.options = Some(options.into());
+        self
+    }
+
+    pub fn build(self) -> GetIamPolicyRequest {
+        self.0
+    }
+}
+
 /// Encapsulates settings provided to GetIamPolicy.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetPolicyOptions {
@@ -559,7 +582,7 @@ pub struct MoveFolderMetadata {
 }
 
 /// The MoveFolder request message.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MoveFolderRequest {
     /**
     * The MoveFolder request message.
@@ -573,6 +596,43 @@ pub struct MoveFolderRequest {
     pub destination_parent: String,
 }
 
+impl std::convert::From<String> for MoveFolderRequest {
+    fn from(destination_parent: String) -> Self {
+        MoveFolderRequest { destination_parent }
+    }
+}
+
+impl std::convert::From<&str> for MoveFolderRequest {
+    fn from(destination_parent: &str) -> Self {
+        MoveFolderRequest {
+            destination_parent: destination_parent.to_string(),
+        }
+    }
+}
+
+impl MoveFolderRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MoveFolderRequestBuilder {
+        MoveFolderRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MoveFolderRequest`.
+#[derive(Debug, Clone)]
+pub struct MoveFolderRequestBuilder(MoveFolderRequest);
+
+impl MoveFolderRequestBuilder {
+    pub fn destination_parent(mut self, destination_parent: impl Into<String>) -> Self {
+        self.0.destination_parent = destination_parent.into();
+        self
+    }
+
+    pub fn build(self) -> MoveFolderRequest {
+        self.0
+    }
+}
+
 /// This resource represents a long-running operation that is the result of a network API call.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Operation {
@@ -649,7 +709,7 @@ pub struct Policy {
 }
 
 /// The request message for searching folders.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchFoldersRequest {
     /**
     * The request message for searching folders.
@@ -682,6 +742,39 @@ pub struct SearchFoldersRequest {
     pub query: String,
 }
 
+impl SearchFoldersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SearchFoldersRequestBuilder {
+        SearchFoldersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SearchFoldersRequest`.
+#[derive(Debug, Clone)]
+pub struct SearchFoldersRequestBuilder(SearchFoldersRequest);
+
+impl SearchFoldersRequestBuilder {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.0.page_size = page_size;
+        self
+    }
+
+    pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+        self.0.page_token = page_token.into();
+        self
+    }
+
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.0.query = query.into();
+        self
+    }
+
+    pub fn build(self) -> SearchFoldersRequest {
+        self.0
+    }
+}
+
 /// The response message for searching folders.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchFoldersResponse {
@@ -707,7 +800,7 @@ pub struct SearchFoldersResponse {
 }
 
 /// Request message for `SetIamPolicy` method.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetIamPolicyRequest {
     /**
     * Request message for `SetIamPolicy` method.
@@ -726,6 +819,34 @@ pub struct SetIamPolicyRequest {
     pub update_mask: String,
 }
 
+impl SetIamPolicyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SetIamPolicyRequestBuilder {
+        SetIamPolicyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SetIamPolicyRequest`.
+#[derive(Debug, Clone)]
+pub struct SetIamPolicyRequestBuilder(SetIamPolicyRequest);
+
+impl SetIamPolicyRequestBuilder {
+    pub fn policy(mut self, policy: impl Into<Policy>) -> Self {
+        self.0.policy = Some(policy.into());
+        self
+    }
+
+    pub fn update_mask(mut self, update_mask: impl Into<String>) -> Self {
+        self.0.update_mask = update_mask.into();
+        self
+    }
+
+    pub fn build(self) -> SetIamPolicyRequest {
+        self.0
+    }
+}
+
 /// The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Status {
@@ -759,7 +880,7 @@ pub struct Status {
 }
 
 /// Request message for `TestIamPermissions` method.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TestIamPermissionsRequest {
     /**
     * Request message for `TestIamPermissions` method.
@@ -772,6 +893,29 @@ pub struct TestIamPermissionsRequest {
     pub permissions: Vec<String>,
 }
 
+impl TestIamPermissionsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> TestIamPermissionsRequestBuilder {
+        TestIamPermissionsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `TestIamPermissionsRequest`.
+#[derive(Debug, Clone)]
+pub struct TestIamPermissionsRequestBuilder(TestIamPermissionsRequest);
+
+impl TestIamPermissionsRequestBuilder {
+    pub fn permissions(mut self, permissions: Vec<String>) -> Self {
+        self.0.permissions = permissions;
+        self
+    }
+
+    pub fn build(self) -> TestIamPermissionsRequest {
+        self.0
+    }
+}
+
 /// Response message for `TestIamPermissions` method.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TestIamPermissionsResponse {
@@ -797,8 +941,8 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -807,7 +951,7 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -837,8 +981,8 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -848,7 +992,7 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -49,69 +49,56 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::Change>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
+            query_args.push(("includeCorpusRemovals", include_corpus_removals.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+            query_args.push(("includeRemoved", include_removed.to_string()));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
+            query_args.push(("restrictToMyDrive", restrict_to_my_drive.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/changes"), &query_args);
 
         let resp: crate::types::ChangeList = self.client.get(&url, None).await?;
 
@@ -140,63 +127,50 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::Change>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
+            query_args.push(("includeCorpusRemovals", include_corpus_removals.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+            query_args.push(("includeRemoved", include_removed.to_string()));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
+            query_args.push(("restrictToMyDrive", restrict_to_my_drive.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/changes"), &query_args);
 
         let mut resp: crate::types::ChangeList = self.client.get(&url, None).await?;
 
@@ -205,17 +179,13 @@ impl Changes {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             changes.append(&mut resp.changes);
 
@@ -249,27 +219,23 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<crate::types::StartPageToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes/startPageToken?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/changes/startPageToken"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -312,69 +278,56 @@ impl Changes {
         team_drive_id: &str,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
+            query_args.push(("includeCorpusRemovals", include_corpus_removals.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+            query_args.push(("includeRemoved", include_removed.to_string()));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
+            query_args.push(("restrictToMyDrive", restrict_to_my_drive.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes/watch?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/changes/watch"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
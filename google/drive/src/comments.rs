@@ -27,33 +27,32 @@ impl Comments {
     */
     pub async fn list(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         include_deleted: bool,
         page_size: i64,
         page_token: &str,
         start_modified_time: &str,
     ) -> Result<Vec<crate::types::Comment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !start_modified_time.is_empty() {
-            query_args.push((
-                "startModifiedTime".to_string(),
-                start_modified_time.to_string(),
-            ));
+            query_args.push(("startModifiedTime", start_modified_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/comments",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::CommentList = self.client.get(&url, None).await?;
@@ -71,25 +70,24 @@ impl Comments {
     */
     pub async fn list_all(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         include_deleted: bool,
         start_modified_time: &str,
     ) -> Result<Vec<crate::types::Comment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
         if !start_modified_time.is_empty() {
-            query_args.push((
-                "startModifiedTime".to_string(),
-                start_modified_time.to_string(),
-            ));
+            query_args.push(("startModifiedTime", start_modified_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/comments",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::CommentList = self.client.get(&url, None).await?;
@@ -99,17 +97,13 @@ impl Comments {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             comments.append(&mut resp.comments);
 
@@ -135,9 +129,10 @@ impl Comments {
     */
     pub async fn create(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         body: &crate::types::Comment,
     ) -> Result<crate::types::Comment> {
+        let file_id = file_id.as_ref();
         let url = format!(
             "/files/{}/comments",
             crate::progenitor_support::encode_path(file_id),
@@ -161,20 +156,23 @@ impl Comments {
     */
     pub async fn get(
         &self,
-        file_id: &str,
-        comment_id: &str,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
         include_deleted: bool,
     ) -> Result<crate::types::Comment> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(comment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/comments/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -190,7 +188,13 @@ impl Comments {
     * * `file_id: &str` -- A link to this theme's background image.
     * * `comment_id: &str` -- A link to this theme's background image.
     */
-    pub async fn delete(&self, file_id: &str, comment_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/files/{}/comments/{}",
             crate::progenitor_support::encode_path(file_id),
@@ -212,10 +216,12 @@ impl Comments {
     */
     pub async fn update(
         &self,
-        file_id: &str,
-        comment_id: &str,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
         body: &crate::types::Comment,
     ) -> Result<crate::types::Comment> {
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/files/{}/comments/{}",
             crate::progenitor_support::encode_path(file_id),
@@ -31,24 +31,20 @@ impl Drives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Drive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/drives?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/drives"), &query_args);
 
         let resp: crate::types::DriveList = self.client.get(&url, None).await?;
 
@@ -68,18 +64,14 @@ impl Drives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Drive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/drives?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/drives"), &query_args);
 
         let mut resp: crate::types::DriveList = self.client.get(&url, None).await?;
 
@@ -88,17 +80,13 @@ impl Drives {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             drives.append(&mut resp.drives);
 
@@ -127,12 +115,11 @@ impl Drives {
         request_id: &str,
         body: &crate::types::Drive,
     ) -> Result<crate::types::Drive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !request_id.is_empty() {
-            query_args.push(("requestId".to_string(), request_id.to_string()));
+            query_args.push(("requestId", request_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/drives?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/drives"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -151,21 +138,20 @@ impl Drives {
     */
     pub async fn get(
         &self,
-        drive_id: &str,
+        drive_id: impl AsRef<str>,
         use_domain_admin_access: bool,
     ) -> Result<crate::types::Drive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let drive_id = drive_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/drives/{}?{}",
-            crate::progenitor_support::encode_path(drive_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/drives/{}",
+                crate::progenitor_support::encode_path(drive_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -180,7 +166,8 @@ impl Drives {
     *
     * * `drive_id: &str` -- A link to this theme's background image.
     */
-    pub async fn delete(&self, drive_id: &str) -> Result<()> {
+    pub async fn delete(&self, drive_id: impl AsRef<str>) -> Result<()> {
+        let drive_id = drive_id.as_ref();
         let url = format!(
             "/drives/{}",
             crate::progenitor_support::encode_path(drive_id),
@@ -201,22 +188,21 @@ impl Drives {
     */
     pub async fn update(
         &self,
-        drive_id: &str,
+        drive_id: impl AsRef<str>,
         use_domain_admin_access: bool,
         body: &crate::types::Drive,
     ) -> Result<crate::types::Drive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let drive_id = drive_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/drives/{}?{}",
-            crate::progenitor_support::encode_path(drive_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/drives/{}",
+                crate::progenitor_support::encode_path(drive_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -233,7 +219,8 @@ impl Drives {
     *
     * * `drive_id: &str` -- A link to this theme's background image.
     */
-    pub async fn hide(&self, drive_id: &str) -> Result<crate::types::Drive> {
+    pub async fn hide(&self, drive_id: impl AsRef<str>) -> Result<crate::types::Drive> {
+        let drive_id = drive_id.as_ref();
         let url = format!(
             "/drives/{}/hide",
             crate::progenitor_support::encode_path(drive_id),
@@ -251,7 +238,8 @@ impl Drives {
     *
     * * `drive_id: &str` -- A link to this theme's background image.
     */
-    pub async fn unhide(&self, drive_id: &str) -> Result<crate::types::Drive> {
+    pub async fn unhide(&self, drive_id: impl AsRef<str>) -> Result<crate::types::Drive> {
+        let drive_id = drive_id.as_ref();
         let url = format!(
             "/drives/{}/unhide",
             crate::progenitor_support::encode_path(drive_id),
@@ -50,63 +50,56 @@ impl Files {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !corpora.is_empty() {
-            query_args.push(("corpora".to_string(), corpora.to_string()));
+            query_args.push(("corpora", corpora.to_string()));
         }
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/files"), &query_args);
 
         let resp: crate::types::FileList = self.client.get(&url, None).await?;
 
@@ -135,57 +128,50 @@ impl Files {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !corpora.is_empty() {
-            query_args.push(("corpora".to_string(), corpora.to_string()));
+            query_args.push(("corpora", corpora.to_string()));
         }
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/files"), &query_args);
 
         let mut resp: crate::types::FileList = self.client.get(&url, None).await?;
 
@@ -194,17 +180,13 @@ impl Files {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             files.append(&mut resp.files);
 
@@ -246,48 +228,38 @@ impl Files {
         use_content_as_indexable_text: bool,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ignore_default_visibility {
             query_args.push((
-                "ignoreDefaultVisibility".to_string(),
+                "ignoreDefaultVisibility",
                 ignore_default_visibility.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
+            query_args.push(("keepRevisionForever", keep_revision_forever.to_string()));
         }
         if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+            query_args.push(("ocrLanguage", ocr_language.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_content_as_indexable_text {
             query_args.push((
-                "useContentAsIndexableText".to_string(),
+                "useContentAsIndexableText",
                 use_content_as_indexable_text.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/files"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -311,18 +283,18 @@ impl Files {
         space: &str,
         type_: &str,
     ) -> Result<crate::types::GeneratedIds> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !space.is_empty() {
-            query_args.push(("space".to_string(), space.to_string()));
+            query_args.push(("space", space.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files/generateIds?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/files/generateIds"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -356,42 +328,32 @@ impl Files {
     */
     pub async fn get(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         acknowledge_abuse: bool,
         include_permissions_for_view: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
+            query_args.push(("acknowledgeAbuse", acknowledge_abuse.to_string()));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/files/{}", crate::progenitor_support::encode_path(file_id),),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -411,28 +373,21 @@ impl Files {
     */
     pub async fn delete(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         supports_all_drives: bool,
         supports_team_drives: bool,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/files/{}", crate::progenitor_support::encode_path(file_id),),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -458,7 +413,7 @@ impl Files {
     */
     pub async fn update(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         add_parents: &str,
         include_permissions_for_view: &str,
         keep_revision_forever: bool,
@@ -469,51 +424,41 @@ impl Files {
         use_content_as_indexable_text: bool,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !add_parents.is_empty() {
-            query_args.push(("addParents".to_string(), add_parents.to_string()));
+            query_args.push(("addParents", add_parents.to_string()));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
+            query_args.push(("keepRevisionForever", keep_revision_forever.to_string()));
         }
         if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+            query_args.push(("ocrLanguage", ocr_language.to_string()));
         }
         if !remove_parents.is_empty() {
-            query_args.push(("removeParents".to_string(), remove_parents.to_string()));
+            query_args.push(("removeParents", remove_parents.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_content_as_indexable_text {
             query_args.push((
-                "useContentAsIndexableText".to_string(),
+                "useContentAsIndexableText",
                 use_content_as_indexable_text.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/files/{}", crate::progenitor_support::encode_path(file_id),),
+            &query_args,
         );
 
         self.client
@@ -539,7 +484,7 @@ impl Files {
     */
     pub async fn copy(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         ignore_default_visibility: bool,
         include_permissions_for_view: &str,
         keep_revision_forever: bool,
@@ -548,45 +493,38 @@ impl Files {
         supports_team_drives: bool,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ignore_default_visibility {
             query_args.push((
-                "ignoreDefaultVisibility".to_string(),
+                "ignoreDefaultVisibility",
                 ignore_default_visibility.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
+            query_args.push(("keepRevisionForever", keep_revision_forever.to_string()));
         }
         if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+            query_args.push(("ocrLanguage", ocr_language.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/copy?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/copy",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -604,16 +542,18 @@ impl Files {
     * * `file_id: &str` -- A link to this theme's background image.
     * * `mime_type: &str` -- The MIME type of the format requested for this export.
     */
-    pub async fn export(&self, file_id: &str, mime_type: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn export(&self, file_id: impl AsRef<str>, mime_type: &str) -> Result<()> {
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !mime_type.is_empty() {
-            query_args.push(("mimeType".to_string(), mime_type.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/export?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+            query_args.push(("mimeType", mime_type.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/export",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -634,43 +574,36 @@ impl Files {
     */
     pub async fn watch(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         acknowledge_abuse: bool,
         include_permissions_for_view: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
+            query_args.push(("acknowledgeAbuse", acknowledge_abuse.to_string()));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/watch?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/watch",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -29,7 +29,7 @@ impl Permissions {
     */
     pub async fn list(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         include_permissions_for_view: &str,
         page_size: i64,
         page_token: &str,
@@ -37,42 +37,35 @@ impl Permissions {
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Permission>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/permissions",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::PermissionList = self.client.get(&url, None).await?;
@@ -90,42 +83,35 @@ impl Permissions {
     */
     pub async fn list_all(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         include_permissions_for_view: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Permission>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/permissions",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::PermissionList = self.client.get(&url, None).await?;
@@ -135,17 +121,13 @@ impl Permissions {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             permissions.append(&mut resp.permissions);
 
@@ -179,7 +161,7 @@ impl Permissions {
     */
     pub async fn create(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         email_message: &str,
         move_to_new_owners_root: bool,
         send_notification_email: bool,
@@ -189,49 +171,33 @@ impl Permissions {
         use_domain_admin_access: bool,
         body: &crate::types::Permission,
     ) -> Result<crate::types::Permission> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email_message.is_empty() {
-            query_args.push(("emailMessage".to_string(), email_message.to_string()));
+            query_args.push(("emailMessage", email_message.to_string()));
         }
         if move_to_new_owners_root {
-            query_args.push((
-                "moveToNewOwnersRoot".to_string(),
-                move_to_new_owners_root.to_string(),
-            ));
+            query_args.push(("moveToNewOwnersRoot", move_to_new_owners_root.to_string()));
         }
-        query_args.push((
-            "sendNotificationEmail".to_string(),
-            send_notification_email.to_string(),
-        ));
+        query_args.push(("sendNotificationEmail", send_notification_email.to_string()));
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if transfer_ownership {
-            query_args.push((
-                "transferOwnership".to_string(),
-                transfer_ownership.to_string(),
-            ));
+            query_args.push(("transferOwnership", transfer_ownership.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/permissions",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -254,37 +220,31 @@ impl Permissions {
     */
     pub async fn get(
         &self,
-        file_id: &str,
-        permission_id: &str,
+        file_id: impl AsRef<str>,
+        permission_id: impl AsRef<str>,
         supports_all_drives: bool,
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<crate::types::Permission> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let permission_id = permission_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(permission_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/permissions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -305,37 +265,31 @@ impl Permissions {
     */
     pub async fn delete(
         &self,
-        file_id: &str,
-        permission_id: &str,
+        file_id: impl AsRef<str>,
+        permission_id: impl AsRef<str>,
         supports_all_drives: bool,
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let permission_id = permission_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(permission_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/permissions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -358,8 +312,8 @@ impl Permissions {
     */
     pub async fn update(
         &self,
-        file_id: &str,
-        permission_id: &str,
+        file_id: impl AsRef<str>,
+        permission_id: impl AsRef<str>,
         remove_expiration: bool,
         supports_all_drives: bool,
         supports_team_drives: bool,
@@ -367,43 +321,31 @@ impl Permissions {
         use_domain_admin_access: bool,
         body: &crate::types::Permission,
     ) -> Result<crate::types::Permission> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let permission_id = permission_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if remove_expiration {
-            query_args.push((
-                "removeExpiration".to_string(),
-                remove_expiration.to_string(),
-            ));
+            query_args.push(("removeExpiration", remove_expiration.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if transfer_ownership {
-            query_args.push((
-                "transferOwnership".to_string(),
-                transfer_ownership.to_string(),
-            ));
+            query_args.push(("transferOwnership", transfer_ownership.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(permission_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/permissions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id),
+            ),
+            &query_args,
         );
 
         self.client
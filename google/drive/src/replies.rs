@@ -27,28 +27,31 @@ impl Replies {
     */
     pub async fn list(
         &self,
-        file_id: &str,
-        comment_id: &str,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
         include_deleted: bool,
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Reply>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}/replies?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(comment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/comments/{}/replies",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ReplyList = self.client.get(&url, None).await?;
@@ -66,20 +69,23 @@ impl Replies {
     */
     pub async fn list_all(
         &self,
-        file_id: &str,
-        comment_id: &str,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
         include_deleted: bool,
     ) -> Result<Vec<crate::types::Reply>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}/replies?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(comment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/comments/{}/replies",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ReplyList = self.client.get(&url, None).await?;
@@ -89,17 +95,13 @@ impl Replies {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             replies.append(&mut resp.replies);
 
@@ -126,10 +128,12 @@ impl Replies {
     */
     pub async fn create(
         &self,
-        file_id: &str,
-        comment_id: &str,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
         body: &crate::types::Reply,
     ) -> Result<crate::types::Reply> {
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/files/{}/comments/{}/replies",
             crate::progenitor_support::encode_path(file_id),
@@ -155,22 +159,26 @@ impl Replies {
     */
     pub async fn get(
         &self,
-        file_id: &str,
-        comment_id: &str,
-        reply_id: &str,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
+        reply_id: impl AsRef<str>,
         include_deleted: bool,
     ) -> Result<crate::types::Reply> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
+        let reply_id = reply_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}/replies/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(comment_id),
-            crate::progenitor_support::encode_path(reply_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/comments/{}/replies/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                crate::progenitor_support::encode_path(reply_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -187,7 +195,15 @@ impl Replies {
     * * `comment_id: &str` -- A link to this theme's background image.
     * * `reply_id: &str` -- A link to this theme's background image.
     */
-    pub async fn delete(&self, file_id: &str, comment_id: &str, reply_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
+        reply_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
+        let reply_id = reply_id.as_ref();
         let url = format!(
             "/files/{}/comments/{}/replies/{}",
             crate::progenitor_support::encode_path(file_id),
@@ -211,11 +227,14 @@ impl Replies {
     */
     pub async fn update(
         &self,
-        file_id: &str,
-        comment_id: &str,
-        reply_id: &str,
+        file_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
+        reply_id: impl AsRef<str>,
         body: &crate::types::Reply,
     ) -> Result<crate::types::Reply> {
+        let file_id = file_id.as_ref();
+        let comment_id = comment_id.as_ref();
+        let reply_id = reply_id.as_ref();
         let url = format!(
             "/files/{}/comments/{}/replies/{}",
             crate::progenitor_support::encode_path(file_id),
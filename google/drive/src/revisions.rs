@@ -25,22 +25,24 @@ impl Revisions {
     */
     pub async fn list(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Revision>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/revisions?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/revisions",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::RevisionList = self.client.get(&url, None).await?;
@@ -56,7 +58,8 @@ impl Revisions {
     *
     * Lists a file's revisions.
     */
-    pub async fn list_all(&self, file_id: &str) -> Result<Vec<crate::types::Revision>> {
+    pub async fn list_all(&self, file_id: impl AsRef<str>) -> Result<Vec<crate::types::Revision>> {
+        let file_id = file_id.as_ref();
         let url = format!(
             "/files/{}/revisions",
             crate::progenitor_support::encode_path(file_id),
@@ -69,17 +72,13 @@ impl Revisions {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             revisions.append(&mut resp.revisions);
 
@@ -107,23 +106,23 @@ impl Revisions {
     */
     pub async fn get(
         &self,
-        file_id: &str,
-        revision_id: &str,
+        file_id: impl AsRef<str>,
+        revision_id: impl AsRef<str>,
         acknowledge_abuse: bool,
     ) -> Result<crate::types::Revision> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let revision_id = revision_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
+            query_args.push(("acknowledgeAbuse", acknowledge_abuse.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/revisions/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            crate::progenitor_support::encode_path(revision_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/revisions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(revision_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -139,7 +138,13 @@ impl Revisions {
     * * `file_id: &str` -- A link to this theme's background image.
     * * `revision_id: &str` -- A link to this theme's background image.
     */
-    pub async fn delete(&self, file_id: &str, revision_id: &str) -> Result<()> {
+    pub async fn delete(
+        &self,
+        file_id: impl AsRef<str>,
+        revision_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let file_id = file_id.as_ref();
+        let revision_id = revision_id.as_ref();
         let url = format!(
             "/files/{}/revisions/{}",
             crate::progenitor_support::encode_path(file_id),
@@ -161,10 +166,12 @@ impl Revisions {
     */
     pub async fn update(
         &self,
-        file_id: &str,
-        revision_id: &str,
+        file_id: impl AsRef<str>,
+        revision_id: impl AsRef<str>,
         body: &crate::types::Revision,
     ) -> Result<crate::types::Revision> {
+        let file_id = file_id.as_ref();
+        let revision_id = revision_id.as_ref();
         let url = format!(
             "/files/{}/revisions/{}",
             crate::progenitor_support::encode_path(file_id),
@@ -0,0 +1,181 @@
+//! Authenticate as a service account, from the JSON key Google gives for it, rather
+//! than with the consent of a user.
+//!
+//! The client signs an assertion with the private key of the service account, and
+//! exchanges it for an access token before its first request. The token is shared
+//! by the clones of the client, and replaced by a new one when it expires or is
+//! rejected. Requests finding it expired at the same time get a single new token.
+//!
+//! With domain-wide delegation, the service account acts as a user of a Google
+//! Workspace domain, the subject, to manage the domain without an administrator
+//! going through consent. `Client::with_subject` gives a client acting as another
+//! user, with its own token.
+//!
+//! ```no_run
+//! # fn run() -> anyhow::Result<()> {
+//! // The key is read from the file `GOOGLE_APPLICATION_CREDENTIALS` points to.
+//! let client = google_drive::Client::new_from_service_account_env(
+//!     &["https://www.googleapis.com/auth/admin.directory.user"],
+//!     Some("admin@example.com"),
+//! )?;
+//! let other_admin = client.with_subject("other.admin@example.com")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! https://developers.google.com/identity/protocols/oauth2/service-account
+use std::{fmt, path::Path, sync::Arc};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, MutexGuard};
+
+/// How long the signed assertions are valid for, the most Google accepts.
+const ASSERTION_LIFETIME: i64 = 60 * 60;
+
+/// The grant type of the exchange of an assertion for an access token.
+const JWT_BEARER: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+
+/// The environment variable with the path of the key of the service account to use,
+/// as read by the Google Cloud SDKs.
+pub const CREDENTIALS_ENV: &str = "GOOGLE_APPLICATION_CREDENTIALS";
+
+/// The fields of the JSON key of a service account used to authenticate.
+#[derive(Clone, Deserialize)]
+pub struct ServiceAccountKey {
+    /// The email address of the service account, the issuer of the assertions.
+    pub client_email: String,
+    /// The PEM encoded RSA key the assertions are signed with.
+    pub private_key: String,
+    #[serde(default)]
+    pub private_key_id: String,
+    /// Where assertions are exchanged for access tokens.
+    #[serde(default = "default_token_uri")]
+    pub token_uri: String,
+}
+
+fn default_token_uri() -> String {
+    "https://oauth2.googleapis.com/token".to_string()
+}
+
+impl ServiceAccountKey {
+    /// Parse the JSON key of a service account.
+    pub fn from_json(json: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(json)?)
+    }
+
+    /// Read the JSON key of a service account from a file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read(path)
+            .map_err(|e| anyhow!("reading the key {} failed: {}", path.display(), e))?;
+        Self::from_json(&json)
+    }
+
+    /// Read the JSON key of a service account from the file `CREDENTIALS_ENV` points
+    /// to.
+    pub fn from_env() -> Result<Self> {
+        let path = std::env::var(CREDENTIALS_ENV)
+            .map_err(|_| anyhow!("{} must be set to the path of a key", CREDENTIALS_ENV))?;
+        Self::from_file(path)
+    }
+}
+
+// The private key is left out, so that keys can be logged.
+impl fmt::Debug for ServiceAccountKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceAccountKey")
+            .field("client_email", &self.client_email)
+            .field("private_key_id", &self.private_key_id)
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    scope: String,
+    aud: &'a str,
+    iat: i64,
+    exp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<&'a str>,
+}
+
+/// A service account, with the scopes its access tokens are asked for and the user
+/// it acts as.
+#[derive(Clone, Debug)]
+pub(crate) struct ServiceAccount {
+    key: ServiceAccountKey,
+    scopes: Vec<String>,
+    subject: Option<String>,
+    /// Held while fetching a token, shared by the clones of the client.
+    refresh: Arc<Mutex<()>>,
+}
+
+impl ServiceAccount {
+    pub(crate) fn new(
+        key: ServiceAccountKey,
+        scopes: &[&str],
+        subject: Option<&str>,
+    ) -> Result<Self> {
+        // Fail on a malformed private key now, rather than on the first request.
+        jsonwebtoken::EncodingKey::from_rsa_pem(key.private_key.as_bytes())?;
+
+        Ok(ServiceAccount {
+            key,
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+            subject: subject.map(|s| s.to_string()),
+            refresh: Default::default(),
+        })
+    }
+
+    /// The same service account acting as another user, fetching its own tokens.
+    pub(crate) fn with_subject(&self, subject: &str) -> Self {
+        ServiceAccount {
+            key: self.key.clone(),
+            scopes: self.scopes.clone(),
+            subject: Some(subject.to_string()),
+            refresh: Default::default(),
+        }
+    }
+
+    /// Wait for the other fetches of a token to complete.
+    pub(crate) async fn lock_refresh(&self) -> MutexGuard<'_, ()> {
+        self.refresh.lock().await
+    }
+
+    /// The assertion of the service account, signed at `now`, in seconds since the
+    /// epoch.
+    fn assertion(&self, now: i64) -> Result<String> {
+        let claims = Claims {
+            iss: &self.key.client_email,
+            scope: self.scopes.join(" "),
+            aud: &self.key.token_uri,
+            iat: now,
+            exp: now + ASSERTION_LIFETIME,
+            sub: self.subject.as_deref(),
+        };
+
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        if !self.key.private_key_id.is_empty() {
+            header.kid = Some(self.key.private_key_id.to_string());
+        }
+        let key = jsonwebtoken::EncodingKey::from_rsa_pem(self.key.private_key.as_bytes())?;
+
+        Ok(jsonwebtoken::encode(&header, &claims, &key)?)
+    }
+
+    /// The request exchanging a new assertion for an access token.
+    pub(crate) fn token_request(&self) -> Result<reqwest::Request> {
+        let assertion = self.assertion(chrono::Utc::now().timestamp())?;
+        let params = [("grant_type", JWT_BEARER), ("assertion", &assertion)];
+
+        Ok(reqwest::Client::new()
+            .post(&self.key.token_uri)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&params)
+            .build()?)
+    }
+}
@@ -31,24 +31,20 @@ impl Teamdrives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::TeamDrive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teamdrives?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/teamdrives"), &query_args);
 
         let resp: crate::types::TeamDriveList = self.client.get(&url, None).await?;
 
@@ -68,18 +64,14 @@ impl Teamdrives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::TeamDrive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teamdrives?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/teamdrives"), &query_args);
 
         let mut resp: crate::types::TeamDriveList = self.client.get(&url, None).await?;
 
@@ -88,17 +80,13 @@ impl Teamdrives {
 
         // Paginate if we should.
         while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?pageToken={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&pageToken={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("pageToken", &page)]),
+                    None,
+                )
+                .await?;
 
             team_drives.append(&mut resp.team_drives);
 
@@ -127,12 +115,11 @@ impl Teamdrives {
         request_id: &str,
         body: &crate::types::TeamDrive,
     ) -> Result<crate::types::TeamDrive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !request_id.is_empty() {
-            query_args.push(("requestId".to_string(), request_id.to_string()));
+            query_args.push(("requestId", request_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teamdrives?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/teamdrives"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -151,21 +138,20 @@ impl Teamdrives {
     */
     pub async fn get(
         &self,
-        team_drive_id: &str,
+        team_drive_id: impl AsRef<str>,
         use_domain_admin_access: bool,
     ) -> Result<crate::types::TeamDrive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let team_drive_id = team_drive_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teamdrives/{}?{}",
-            crate::progenitor_support::encode_path(team_drive_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teamdrives/{}",
+                crate::progenitor_support::encode_path(team_drive_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -180,7 +166,8 @@ impl Teamdrives {
     *
     * * `team_drive_id: &str` -- A link to this theme's background image.
     */
-    pub async fn delete(&self, team_drive_id: &str) -> Result<()> {
+    pub async fn delete(&self, team_drive_id: impl AsRef<str>) -> Result<()> {
+        let team_drive_id = team_drive_id.as_ref();
         let url = format!(
             "/teamdrives/{}",
             crate::progenitor_support::encode_path(team_drive_id),
@@ -201,22 +188,21 @@ impl Teamdrives {
     */
     pub async fn update(
         &self,
-        team_drive_id: &str,
+        team_drive_id: impl AsRef<str>,
         use_domain_admin_access: bool,
         body: &crate::types::TeamDrive,
     ) -> Result<crate::types::TeamDrive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let team_drive_id = team_drive_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teamdrives/{}?{}",
-            crate::progenitor_support::encode_path(team_drive_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/teamdrives/{}",
+                crate::progenitor_support::encode_path(team_drive_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1 +1,102 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
 
+use crate::upload::CHUNK_SIZE_UNIT;
+
+/// Drive answers the uploads with headers, which `MockTransport` cannot return.
+#[derive(Clone, Default)]
+struct UploadTransport {
+    // `None` fails the request as if the connection was lost.
+    responses: Arc<Mutex<VecDeque<Option<(u16, &'static str, &'static str)>>>>,
+    ranges: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait::async_trait]
+impl crate::transport::HttpTransport for UploadTransport {
+    async fn send(&self, req: reqwest::Request) -> anyhow::Result<reqwest::Response> {
+        if let Some(range) = req.headers().get(reqwest::header::CONTENT_RANGE) {
+            self.ranges
+                .lock()
+                .unwrap()
+                .push(range.to_str()?.to_string());
+        }
+
+        let (status, header, body) = match self.responses.lock().unwrap().pop_front() {
+            Some(Some(resp)) => resp,
+            _ => anyhow::bail!("connection reset"),
+        };
+        let mut resp = http::Response::builder().status(status);
+        if let Some((name, value)) = header.split_once(": ") {
+            resp = resp.header(name, value);
+        }
+        Ok(reqwest::Response::from(resp.body(body)?))
+    }
+}
+
+#[tokio::test]
+async fn test_resumable_upload() {
+    let transport = UploadTransport::default();
+    transport.responses.lock().unwrap().extend(vec![
+        Some((
+            200,
+            "Location: https://www.googleapis.com/upload/drive/v3/files?upload_id=xa298sd",
+            "",
+        )),
+        Some((308, "Range: bytes=0-262143", "")),
+        None,
+        // Drive kept half of the chunk that failed.
+        Some((308, "Range: bytes=0-393215", "")),
+        Some((200, "", r#"{"id": "1Abc", "name": "backup.tar"}"#)),
+    ]);
+
+    let drive = crate::Client::new("", "", "", "token", "").with_transport(transport.clone());
+    let metadata = crate::types::File {
+        name: "backup.tar".to_string(),
+        ..Default::default()
+    };
+    let contents = vec![7u8; 600 * 1024];
+    let upload = drive
+        .files()
+        .start_resumable_upload(&metadata, contents.len() as u64)
+        .await
+        .unwrap()
+        .with_chunk_size(CHUNK_SIZE_UNIT + 1);
+    assert_eq!(
+        upload.session_uri(),
+        "https://www.googleapis.com/upload/drive/v3/files?upload_id=xa298sd"
+    );
+
+    let file = upload.upload(&contents[..]).await.unwrap();
+    assert_eq!(file.id, "1Abc");
+    assert_eq!(
+        *transport.ranges.lock().unwrap(),
+        vec![
+            "bytes 0-262143/614400",
+            "bytes 262144-524287/614400",
+            "bytes */614400",
+            "bytes 393216-614399/614400",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_api_error() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::NOT_FOUND,
+        r#"{"error": {"code": 404, "message": "File not found: 1Abc.", "status": "NOT_FOUND", "errors": [{"domain": "global", "reason": "notFound", "message": "File not found: 1Abc.", "locationType": "parameter", "location": "fileId"}]}}"#,
+    );
+
+    let drive = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let err = drive
+        .files()
+        .get("1Abc", false, "", true, false)
+        .await
+        .unwrap_err();
+    let api_error = err.downcast_ref::<crate::error::ApiError>().unwrap();
+    assert!(api_error.has_reason("notFound"));
+    assert_eq!(api_error.errors[0].location_type, "parameter");
+    assert_eq!(api_error.code, "NOT_FOUND");
+}
@@ -341,10 +341,12 @@ impl FileOps for crate::files::Files {
             }
             query_.push_str(n);
         }
-        let url = format!(
-            "/files/{}/export?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/files/{}/export",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
         let resp = self
             .client
@@ -3262,8 +3262,8 @@ pub enum Alt {
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -3271,7 +3271,7 @@ impl std::fmt::Display for Alt {
         match &*self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3299,8 +3299,8 @@ pub enum Corpus {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Corpus {
@@ -3309,7 +3309,7 @@ impl std::fmt::Display for Corpus {
             Corpus::Domain => "domain",
             Corpus::User => "user",
             Corpus::Noop => "",
-            Corpus::FallthroughString => "*",
+            Corpus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -0,0 +1,287 @@
+//! Resumable uploads, for files too large to be sent in a single request.
+//!
+//! An upload session is started with `Files::start_resumable_upload`, then the
+//! contents are sent in chunks. When sending a chunk fails, Drive is asked how much
+//! of the file it received and the upload goes on from there. The URI of the
+//! session can be kept to go on with the upload later, with
+//! `ResumableUpload::from_session`.
+//!
+//! ```no_run
+//! # async fn run(drive: google_drive::Client) -> anyhow::Result<()> {
+//! let contents = tokio::fs::File::open("backup.tar").await?;
+//! let size = contents.metadata().await?.len();
+//! let metadata = google_drive::types::File {
+//!     name: "backup.tar".to_string(),
+//!     mime_type: "application/x-tar".to_string(),
+//!     ..Default::default()
+//! };
+//!
+//! let upload = drive.files().start_resumable_upload(&metadata, size).await?;
+//! let file = upload.upload(contents).await?;
+//! println!("uploaded {}", file.id);
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, bail, Result};
+use reqwest::header::{HeaderValue, CONTENT_RANGE, LOCATION, RANGE};
+
+use crate::{files::Files, types::File, Client};
+
+/// The size of the chunks must be a multiple of this, except for the last chunk.
+pub const CHUNK_SIZE_UNIT: usize = 256 * 1024;
+
+const DEFAULT_CHUNK_SIZE: usize = 32 * CHUNK_SIZE_UNIT;
+
+/// The number of times in a row sending a chunk can fail before giving up.
+const MAX_FAILURES: u32 = 5;
+
+/// Where an upload is at after a chunk was sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UploadStatus {
+    /// Drive received this many bytes of the file so far.
+    InProgress(u64),
+    /// The whole file was received.
+    Complete(File),
+}
+
+/// An upload session, to send the contents of a file in chunks.
+#[derive(Clone)]
+pub struct ResumableUpload {
+    client: Client,
+    session_uri: String,
+    size: u64,
+    offset: u64,
+    chunk_size: usize,
+}
+
+impl Files {
+    /// Start a session to upload a new file of `size` bytes.
+    pub async fn start_resumable_upload(
+        &self,
+        metadata: &File,
+        size: u64,
+    ) -> Result<ResumableUpload> {
+        self.start_session(reqwest::Method::POST, "/files", metadata, size)
+            .await
+    }
+
+    /// Start a session to replace the contents of a file with `size` bytes.
+    pub async fn start_resumable_update(
+        &self,
+        file_id: &str,
+        metadata: &File,
+        size: u64,
+    ) -> Result<ResumableUpload> {
+        let path = format!("/files/{}", crate::progenitor_support::encode_path(file_id));
+        self.start_session(reqwest::Method::PATCH, &path, metadata, size)
+            .await
+    }
+
+    async fn start_session(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        metadata: &File,
+        size: u64,
+    ) -> Result<ResumableUpload> {
+        // The uploads go to the same host, under `/upload`.
+        let mut url = reqwest::Url::parse(&self.client.host)?;
+        url.set_path(&format!(
+            "/upload{}{}",
+            url.path().trim_end_matches('/'),
+            path
+        ));
+        url.set_query(Some("uploadType=resumable&supportsAllDrives=true"));
+
+        let body = reqwest::Body::from(serde_json::to_vec(metadata)?);
+        let mut req = self
+            .client
+            .make_request(&method, url.as_str(), Some(body))
+            .await?;
+        if !metadata.mime_type.is_empty() {
+            req.headers_mut().insert(
+                "x-upload-content-type",
+                HeaderValue::from_str(&metadata.mime_type)?,
+            );
+        }
+        req.headers_mut()
+            .insert("x-upload-content-length", HeaderValue::from(size));
+
+        let resp = self.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_success() {
+            bail!("code: {}, error: {:?}", status, resp.text().await?);
+        }
+
+        let session_uri = match resp.headers().get(LOCATION) {
+            Some(location) => location.to_str()?,
+            None => bail!("no Location header for the upload session"),
+        };
+        Ok(ResumableUpload::from_session(
+            self.client.clone(),
+            session_uri,
+            size,
+        ))
+    }
+}
+
+impl ResumableUpload {
+    /// Go on with an upload session that was started before, for a file of `size`
+    /// bytes. Call `resume` to find out where the upload is at.
+    pub fn from_session(client: Client, session_uri: &str, size: u64) -> Self {
+        ResumableUpload {
+            client,
+            session_uri: session_uri.to_string(),
+            size,
+            offset: 0,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Send the contents in chunks of this many bytes, rounded down to a multiple of
+    /// `CHUNK_SIZE_UNIT`. The default is 8 MiB.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = (chunk_size / CHUNK_SIZE_UNIT).max(1) * CHUNK_SIZE_UNIT;
+        self
+    }
+
+    /// The URI of the session, to go on with the upload later.
+    pub fn session_uri(&self) -> &str {
+        &self.session_uri
+    }
+
+    /// The number of bytes of the file Drive received so far.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Send the chunk of the contents that starts at the offset of the upload.
+    pub async fn upload_chunk(&mut self, chunk: &[u8]) -> Result<UploadStatus> {
+        let end = self.offset + chunk.len() as u64;
+        if end > self.size {
+            bail!(
+                "the contents are larger than the {} bytes of the upload",
+                self.size
+            );
+        }
+
+        let range = if chunk.is_empty() {
+            format!("bytes */{}", self.size)
+        } else {
+            format!("bytes {}-{}/{}", self.offset, end - 1, self.size)
+        };
+        self.put(&range, bytes::Bytes::copy_from_slice(chunk)).await
+    }
+
+    /// Ask Drive how much of the file it received, after sending a chunk failed.
+    pub async fn resume(&mut self) -> Result<UploadStatus> {
+        let range = format!("bytes */{}", self.size);
+        self.put(&range, bytes::Bytes::new()).await
+    }
+
+    /// Upload the contents read from `reader`, which starts at the offset of the
+    /// upload, resuming when sending a chunk fails. Returns the uploaded file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn upload<R>(mut self, mut reader: R) -> Result<File>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        // The bytes read that Drive did not receive yet.
+        let mut pending: Vec<u8> = Vec::with_capacity(self.chunk_size);
+        let mut eof = false;
+        let mut failures = 0;
+        loop {
+            while !eof && pending.len() < self.chunk_size {
+                let wanted = (self.chunk_size - pending.len()) as u64;
+                if (&mut reader).take(wanted).read_to_end(&mut pending).await? == 0 {
+                    eof = true;
+                }
+            }
+            if eof && pending.is_empty() && self.offset < self.size {
+                bail!(
+                    "the contents ended after {} of the {} bytes of the upload",
+                    self.offset,
+                    self.size
+                );
+            }
+
+            let start = self.offset;
+            let len = pending.len().min(self.chunk_size);
+            let status = match self.upload_chunk(&pending[..len]).await {
+                Ok(status) => {
+                    failures = 0;
+                    status
+                }
+                Err(e) => {
+                    failures += 1;
+                    if failures >= MAX_FAILURES {
+                        return Err(e);
+                    }
+                    match self.resume().await {
+                        Ok(status) => status,
+                        Err(_) => continue,
+                    }
+                }
+            };
+
+            match status {
+                UploadStatus::Complete(file) => return Ok(file),
+                UploadStatus::InProgress(offset) => {
+                    if offset < start {
+                        bail!("Drive lost the bytes of the upload after {}", offset);
+                    }
+                    // Drive might have kept only part of the chunk.
+                    pending.drain(..(offset - start) as usize);
+                }
+            }
+        }
+    }
+
+    async fn put(&mut self, range: &str, body: bytes::Bytes) -> Result<UploadStatus> {
+        // Uploads can take longer than the access token is valid.
+        if self.client.auto_refresh && self.client.is_expired().await == Some(true) {
+            self.client.refresh_access_token().await?;
+        }
+
+        let (url, auth) = self.client.url_and_auth(&self.session_uri).await?;
+        let mut req = self
+            .client
+            .client
+            .request(reqwest::Method::PUT, url)
+            .header(CONTENT_RANGE, range)
+            .body(body);
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        let resp = self.client.execute(req.build()?).await?;
+        match resp.status().as_u16() {
+            200 | 201 => {
+                self.offset = self.size;
+                Ok(UploadStatus::Complete(resp.json().await?))
+            }
+            308 => {
+                // The range received so far, there is none when nothing was received.
+                self.offset = match resp.headers().get(RANGE) {
+                    Some(range) => received(range.to_str()?)?,
+                    None => 0,
+                };
+                Ok(UploadStatus::InProgress(self.offset))
+            }
+            404 | 410 => bail!("the upload session expired, a new one has to be started"),
+            _ => bail!("code: {}, error: {:?}", resp.status(), resp.text().await?),
+        }
+    }
+}
+
+/// The number of bytes received, from a range header like `bytes=0-42`.
+fn received(range: &str) -> Result<u64> {
+    range
+        .rsplit('-')
+        .next()
+        .and_then(|last| last.parse::<u64>().ok())
+        .map(|last| last + 1)
+        .ok_or_else(|| anyhow!("invalid Range header: {}", range))
+}
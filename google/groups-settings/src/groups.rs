@@ -26,17 +26,19 @@ impl Groups {
     pub async fn get(
         &self,
         alt: crate::types::Alt,
-        group_unique_id: &str,
+        group_unique_id: impl AsRef<str>,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_unique_id = group_unique_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alt.to_string().is_empty() {
-            query_args.push(("alt".to_string(), alt.to_string()));
+            query_args.push(("alt", alt.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/{}?{}",
-            crate::progenitor_support::encode_path(group_unique_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/{}",
+                crate::progenitor_support::encode_path(group_unique_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -56,18 +58,20 @@ impl Groups {
     pub async fn update(
         &self,
         alt: crate::types::Alt,
-        group_unique_id: &str,
+        group_unique_id: impl AsRef<str>,
         body: &crate::types::Groups,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_unique_id = group_unique_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alt.to_string().is_empty() {
-            query_args.push(("alt".to_string(), alt.to_string()));
+            query_args.push(("alt", alt.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/{}?{}",
-            crate::progenitor_support::encode_path(group_unique_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/{}",
+                crate::progenitor_support::encode_path(group_unique_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -89,18 +93,20 @@ impl Groups {
     pub async fn patch(
         &self,
         alt: crate::types::Alt,
-        group_unique_id: &str,
+        group_unique_id: impl AsRef<str>,
         body: &crate::types::Groups,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_unique_id = group_unique_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alt.to_string().is_empty() {
-            query_args.push(("alt".to_string(), alt.to_string()));
+            query_args.push(("alt", alt.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/{}?{}",
-            crate::progenitor_support::encode_path(group_unique_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/{}",
+                crate::progenitor_support::encode_path(group_unique_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -633,8 +633,8 @@ pub enum Alt {
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -643,7 +643,7 @@ impl std::fmt::Display for Alt {
             Alt::Atom => "atom",
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -0,0 +1,340 @@
+//! Build ranges of cells in A1 notation instead of writing the strings by hand,
+//! and read or write the values of several ranges at once.
+//!
+//! ```no_run
+//! # async fn run(sheets: sheets::Client) -> anyhow::Result<()> {
+//! use sheets::a1::A1Range;
+//!
+//! // 'Sales 2021'!A1:C10
+//! let sales = A1Range::cell(0, 0).to(2, 9).on_sheet("Sales 2021");
+//! // Totals!B:B
+//! let totals = A1Range::columns(1, 1).on_sheet("Totals");
+//!
+//! let values = sheets
+//!     .spreadsheets()
+//!     .values_batch_get_ranges(
+//!         "1BxiMVs0XRA5nFMdKvBdBZjgmUUqptlbs74OgvE2upms",
+//!         &[sales, totals],
+//!         sheets::types::ValueRenderOption::FormattedValue,
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, bail, Error, Result};
+
+use crate::{
+    spreadsheets::Spreadsheets,
+    types::{
+        BatchUpdateValuesRequest, BatchUpdateValuesResponse, ValueInputOption, ValueRange,
+        ValueRenderOption,
+    },
+};
+
+/**
+ * A range of cells in A1 notation, like `'Sales 2021'!A1:C10`.
+ *
+ * The columns and rows are counted from zero, like in a `GridRange`, and the
+ * ranges include their last column and row.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct A1Range {
+    sheet: Option<String>,
+    start: Option<CellRef>,
+    end: Option<CellRef>,
+}
+
+// A cell, or a whole column or row when the other is not set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CellRef {
+    column: Option<u32>,
+    row: Option<u32>,
+}
+
+impl A1Range {
+    /// All the cells of a sheet.
+    pub fn sheet(name: &str) -> Self {
+        A1Range {
+            sheet: Some(name.to_string()),
+            start: None,
+            end: None,
+        }
+    }
+
+    /// A single cell, `cell(1, 0)` is `B1`.
+    pub fn cell(column: u32, row: u32) -> Self {
+        A1Range {
+            sheet: None,
+            start: Some(CellRef {
+                column: Some(column),
+                row: Some(row),
+            }),
+            end: None,
+        }
+    }
+
+    /// Whole columns, `columns(0, 2)` is `A:C`.
+    pub fn columns(start: u32, end: u32) -> Self {
+        A1Range {
+            sheet: None,
+            start: Some(CellRef {
+                column: Some(start),
+                row: None,
+            }),
+            end: Some(CellRef {
+                column: Some(end),
+                row: None,
+            }),
+        }
+    }
+
+    /// Whole rows, `rows(1, 4)` is `2:5`.
+    pub fn rows(start: u32, end: u32) -> Self {
+        A1Range {
+            sheet: None,
+            start: Some(CellRef {
+                column: None,
+                row: Some(start),
+            }),
+            end: Some(CellRef {
+                column: None,
+                row: Some(end),
+            }),
+        }
+    }
+
+    /// The cells from the start of the range to this cell, `cell(0, 0).to(2, 9)` is
+    /// `A1:C10`. The range starts at `A1` when it was a whole sheet.
+    pub fn to(mut self, column: u32, row: u32) -> Self {
+        if self.start.is_none() {
+            self.start = Some(CellRef {
+                column: Some(0),
+                row: Some(0),
+            });
+        }
+        self.end = Some(CellRef {
+            column: Some(column),
+            row: Some(row),
+        });
+        self
+    }
+
+    /// The same cells, on the given sheet.
+    pub fn on_sheet(mut self, name: &str) -> Self {
+        self.sheet = Some(name.to_string());
+        self
+    }
+
+    /// The name of the sheet of the range, the first visible sheet is used when
+    /// there is none.
+    pub fn sheet_name(&self) -> Option<&str> {
+        self.sheet.as_deref()
+    }
+}
+
+/// The letters of a column, `column_letters(0)` is `A` and `column_letters(26)`
+/// is `AA`.
+pub fn column_letters(column: u32) -> String {
+    let mut letters = Vec::new();
+    let mut n = column + 1;
+    while n > 0 {
+        n -= 1;
+        letters.push(b'A' + (n % 26) as u8);
+        n /= 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+/// The index of a column from its letters, ignoring case. Returns `None` if these
+/// are not the letters of a column, a sheet has at most the columns up to `ZZZ`.
+pub fn column_index(letters: &str) -> Option<u32> {
+    if letters.is_empty() || letters.len() > 3 {
+        return None;
+    }
+
+    let mut n: u32 = 0;
+    for c in letters.chars() {
+        if !c.is_ascii_alphabetic() {
+            return None;
+        }
+        n = n * 26 + (c.to_ascii_uppercase() as u32 - 'A' as u32 + 1);
+    }
+    Some(n - 1)
+}
+
+impl fmt::Display for CellRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(column) = self.column {
+            write!(f, "{}", column_letters(column))?;
+        }
+        if let Some(row) = self.row {
+            write!(f, "{}", row + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CellRef {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let digits = s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len());
+        let (letters, digits) = s.split_at(digits);
+
+        let column = if letters.is_empty() {
+            None
+        } else {
+            Some(column_index(letters).ok_or_else(|| anyhow!("invalid column: {}", letters))?)
+        };
+        let row = match digits.parse::<u32>() {
+            Ok(row) if row > 0 => Some(row - 1),
+            _ if digits.is_empty() => None,
+            _ => bail!("invalid row: {}", digits),
+        };
+        if column.is_none() && row.is_none() {
+            bail!("empty cell reference");
+        }
+
+        Ok(CellRef { column, row })
+    }
+}
+
+// Parse the part of a range after the sheet name, like `A1:C10`.
+fn parse_cells(s: &str) -> Result<(CellRef, Option<CellRef>)> {
+    match s.split_once(':') {
+        Some((start, end)) => Ok((start.parse()?, Some(end.parse()?))),
+        None => Ok((s.parse()?, None)),
+    }
+}
+
+fn needs_quotes(sheet: &str) -> bool {
+    // A name that could be read as cells has to be quoted as well.
+    sheet.is_empty()
+        || sheet.starts_with(|c: char| c.is_ascii_digit())
+        || !sheet.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        || parse_cells(sheet).is_ok()
+}
+
+impl fmt::Display for A1Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(sheet) = &self.sheet {
+            if needs_quotes(sheet) {
+                write!(f, "'{}'", sheet.replace('\'', "''"))?;
+            } else {
+                write!(f, "{}", sheet)?;
+            }
+            if self.start.is_some() {
+                write!(f, "!")?;
+            }
+        }
+        if let Some(start) = &self.start {
+            write!(f, "{}", start)?;
+        }
+        if let Some(end) = &self.end {
+            write!(f, ":{}", end)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for A1Range {
+    type Err = Error;
+
+    /// Parse a range in A1 notation, like the ones returned in a `ValueRange`. A
+    /// range without a `!` is read as cells when it can be, and as the name of a
+    /// sheet otherwise.
+    fn from_str(s: &str) -> Result<Self> {
+        let (sheet, cells) = if let Some(quoted) = s.strip_prefix('\'') {
+            // The quotes in the name are doubled.
+            let mut name = String::new();
+            let mut chars = quoted.char_indices().peekable();
+            let rest = loop {
+                match chars.next() {
+                    Some((_, '\'')) if matches!(chars.peek(), Some((_, '\''))) => {
+                        chars.next();
+                        name.push('\'');
+                    }
+                    Some((i, '\'')) => break &quoted[i + 1..],
+                    Some((_, c)) => name.push(c),
+                    None => bail!("unterminated sheet name: {}", s),
+                }
+            };
+            let cells = match rest.strip_prefix('!') {
+                Some(cells) => Some(cells),
+                None if rest.is_empty() => None,
+                None => bail!("invalid range: {}", s),
+            };
+            (Some(name), cells)
+        } else {
+            match s.split_once('!') {
+                Some((name, cells)) => (Some(name.to_string()), Some(cells)),
+                None if parse_cells(s).is_ok() => (None, Some(s)),
+                None => (Some(s.to_string()), None),
+            }
+        };
+
+        let (start, end) = match cells {
+            Some(cells) => {
+                let (start, end) = parse_cells(cells)?;
+                (Some(start), end)
+            }
+            None => (None, None),
+        };
+        Ok(A1Range { sheet, start, end })
+    }
+}
+
+impl Spreadsheets {
+    /// Get the values of several ranges at once, in rows.
+    pub async fn values_batch_get_ranges(
+        &self,
+        spreadsheet_id: &str,
+        ranges: &[A1Range],
+        value_render_option: ValueRenderOption,
+    ) -> Result<Vec<ValueRange>> {
+        // Each range is its own query parameter.
+        let mut query_args: Vec<(&str, String)> =
+            ranges.iter().map(|r| ("ranges", r.to_string())).collect();
+        if !value_render_option.to_string().is_empty() {
+            query_args.push(("valueRenderOption", value_render_option.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v4/spreadsheets/{}/values:batchGet",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::BatchGetValuesResponse = self.client.get(&url, None).await?;
+        Ok(resp.value_ranges)
+    }
+
+    /// Set the values of several ranges at once, each given as rows.
+    pub async fn values_batch_update_ranges(
+        &self,
+        spreadsheet_id: &str,
+        value_input_option: ValueInputOption,
+        data: &[(A1Range, Vec<Vec<String>>)],
+    ) -> Result<BatchUpdateValuesResponse> {
+        let body = BatchUpdateValuesRequest {
+            data: data
+                .iter()
+                .map(|(range, values)| ValueRange {
+                    major_dimension: Some(crate::types::Dimension::Rows),
+                    range: range.to_string(),
+                    values: values.clone(),
+                })
+                .collect(),
+            include_values_in_response: None,
+            response_date_time_render_option: None,
+            response_value_render_option: None,
+            value_input_option: Some(value_input_option),
+        };
+
+        self.values_batch_update(spreadsheet_id, &body).await
+    }
+}
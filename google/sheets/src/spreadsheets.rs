@@ -40,22 +40,24 @@ impl Spreadsheets {
     */
     pub async fn get(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         include_grid_data: bool,
         ranges: &[String],
     ) -> Result<crate::types::Spreadsheet> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let spreadsheet_id = spreadsheet_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_grid_data {
-            query_args.push(("includeGridData".to_string(), include_grid_data.to_string()));
+            query_args.push(("includeGridData", include_grid_data.to_string()));
         }
         if !ranges.is_empty() {
-            query_args.push(("ranges".to_string(), ranges.join(" ")));
+            query_args.push(("ranges", ranges.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}?{}",
-            crate::progenitor_support::encode_path(spreadsheet_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v4/spreadsheets/{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -73,13 +75,14 @@ impl Spreadsheets {
     */
     pub async fn developer_metadata_get(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         metadata_id: i64,
     ) -> Result<crate::types::DeveloperMetadata> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/developerMetadata/{}",
             crate::progenitor_support::encode_path(spreadsheet_id),
-            crate::progenitor_support::encode_path(&metadata_id.to_string()),
+            crate::progenitor_support::encode_path(&metadata_id),
         );
 
         self.client.get(&url, None).await
@@ -96,9 +99,10 @@ impl Spreadsheets {
     */
     pub async fn developer_metadata_search(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::SearchDeveloperMetadataRequest,
     ) -> Result<crate::types::SearchDeveloperMetadataResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/developerMetadata:search",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -121,14 +125,15 @@ impl Spreadsheets {
     */
     pub async fn sheets_copy(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         sheet_id: i64,
         body: &crate::types::CopySheetAnotherSpreadsheetRequest,
     ) -> Result<crate::types::SheetProperties> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/sheets/{}/copyTo",
             crate::progenitor_support::encode_path(spreadsheet_id),
-            crate::progenitor_support::encode_path(&sheet_id.to_string()),
+            crate::progenitor_support::encode_path(&sheet_id),
         );
 
         self.client
@@ -151,34 +156,31 @@ impl Spreadsheets {
     */
     pub async fn values_get(
         &self,
-        spreadsheet_id: &str,
-        range: &str,
+        spreadsheet_id: impl AsRef<str>,
+        range: impl AsRef<str>,
         date_time_render_option: crate::types::DateTimeRenderOption,
         major_dimension: crate::types::Dimension,
         value_render_option: crate::types::ValueRenderOption,
     ) -> Result<crate::types::ValueRange> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let spreadsheet_id = spreadsheet_id.as_ref();
+        let range = range.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date_time_render_option.to_string().is_empty() {
-            query_args.push((
-                "dateTimeRenderOption".to_string(),
-                date_time_render_option.to_string(),
-            ));
+            query_args.push(("dateTimeRenderOption", date_time_render_option.to_string()));
         }
         if !major_dimension.to_string().is_empty() {
-            query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
+            query_args.push(("majorDimension", major_dimension.to_string()));
         }
         if !value_render_option.to_string().is_empty() {
-            query_args.push((
-                "valueRenderOption".to_string(),
-                value_render_option.to_string(),
-            ));
+            query_args.push(("valueRenderOption", value_render_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values/{}?{}",
-            crate::progenitor_support::encode_path(spreadsheet_id),
-            crate::progenitor_support::encode_path(range),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v4/spreadsheets/{}/values/{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -200,45 +202,45 @@ impl Spreadsheets {
     */
     pub async fn values_update(
         &self,
-        spreadsheet_id: &str,
-        range: &str,
+        spreadsheet_id: impl AsRef<str>,
+        range: impl AsRef<str>,
         include_values_in_response: bool,
         response_date_time_render_option: crate::types::DateTimeRenderOption,
         response_value_render_option: crate::types::ValueRenderOption,
         value_input_option: crate::types::ValueInputOption,
         body: &crate::types::ValueRange,
     ) -> Result<crate::types::UpdateValuesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let spreadsheet_id = spreadsheet_id.as_ref();
+        let range = range.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_values_in_response {
             query_args.push((
-                "includeValuesInResponse".to_string(),
+                "includeValuesInResponse",
                 include_values_in_response.to_string(),
             ));
         }
         if !response_date_time_render_option.to_string().is_empty() {
             query_args.push((
-                "responseDateTimeRenderOption".to_string(),
+                "responseDateTimeRenderOption",
                 response_date_time_render_option.to_string(),
             ));
         }
         if !response_value_render_option.to_string().is_empty() {
             query_args.push((
-                "responseValueRenderOption".to_string(),
+                "responseValueRenderOption",
                 response_value_render_option.to_string(),
             ));
         }
         if !value_input_option.to_string().is_empty() {
-            query_args.push((
-                "valueInputOption".to_string(),
-                value_input_option.to_string(),
-            ));
+            query_args.push(("valueInputOption", value_input_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values/{}?{}",
-            crate::progenitor_support::encode_path(spreadsheet_id),
-            crate::progenitor_support::encode_path(range),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v4/spreadsheets/{}/values/{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range),
+            ),
+            &query_args,
         );
 
         self.client
@@ -263,8 +265,8 @@ impl Spreadsheets {
     */
     pub async fn values_append(
         &self,
-        spreadsheet_id: &str,
-        range: &str,
+        spreadsheet_id: impl AsRef<str>,
+        range: impl AsRef<str>,
         include_values_in_response: bool,
         insert_data_option: crate::types::InsertDataOption,
         response_date_time_render_option: crate::types::DateTimeRenderOption,
@@ -272,43 +274,40 @@ impl Spreadsheets {
         value_input_option: crate::types::ValueInputOption,
         body: &crate::types::ValueRange,
     ) -> Result<crate::types::AppendValuesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let spreadsheet_id = spreadsheet_id.as_ref();
+        let range = range.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_values_in_response {
             query_args.push((
-                "includeValuesInResponse".to_string(),
+                "includeValuesInResponse",
                 include_values_in_response.to_string(),
             ));
         }
         if !insert_data_option.to_string().is_empty() {
-            query_args.push((
-                "insertDataOption".to_string(),
-                insert_data_option.to_string(),
-            ));
+            query_args.push(("insertDataOption", insert_data_option.to_string()));
         }
         if !response_date_time_render_option.to_string().is_empty() {
             query_args.push((
-                "responseDateTimeRenderOption".to_string(),
+                "responseDateTimeRenderOption",
                 response_date_time_render_option.to_string(),
             ));
         }
         if !response_value_render_option.to_string().is_empty() {
             query_args.push((
-                "responseValueRenderOption".to_string(),
+                "responseValueRenderOption",
                 response_value_render_option.to_string(),
             ));
         }
         if !value_input_option.to_string().is_empty() {
-            query_args.push((
-                "valueInputOption".to_string(),
-                value_input_option.to_string(),
-            ));
+            query_args.push(("valueInputOption", value_input_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values/{}/append?{}",
-            crate::progenitor_support::encode_path(spreadsheet_id),
-            crate::progenitor_support::encode_path(range),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v4/spreadsheets/{}/values/{}/append",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range),
+            ),
+            &query_args,
         );
 
         self.client
@@ -328,10 +327,12 @@ impl Spreadsheets {
     */
     pub async fn values_clear(
         &self,
-        spreadsheet_id: &str,
-        range: &str,
+        spreadsheet_id: impl AsRef<str>,
+        range: impl AsRef<str>,
         body: &crate::types::ClearValuesRequest,
     ) -> Result<crate::types::ClearValuesResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
+        let range = range.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/values/{}/clear",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -354,9 +355,10 @@ impl Spreadsheets {
     */
     pub async fn values_batch_clear(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::BatchClearValuesRequest,
     ) -> Result<crate::types::BatchClearValuesResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/values:batchClear",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -378,9 +380,10 @@ impl Spreadsheets {
     */
     pub async fn values_batch_clear_data_filter(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::BatchClearValuesByDataFilterRequest,
     ) -> Result<crate::types::BatchClearValuesByDataFilterResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/values:batchClearByDataFilter",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -406,36 +409,32 @@ impl Spreadsheets {
     */
     pub async fn values_batch_get(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         date_time_render_option: crate::types::DateTimeRenderOption,
         major_dimension: crate::types::Dimension,
         ranges: &[String],
         value_render_option: crate::types::ValueRenderOption,
     ) -> Result<crate::types::BatchGetValuesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let spreadsheet_id = spreadsheet_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date_time_render_option.to_string().is_empty() {
-            query_args.push((
-                "dateTimeRenderOption".to_string(),
-                date_time_render_option.to_string(),
-            ));
+            query_args.push(("dateTimeRenderOption", date_time_render_option.to_string()));
         }
         if !major_dimension.to_string().is_empty() {
-            query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
+            query_args.push(("majorDimension", major_dimension.to_string()));
         }
         if !ranges.is_empty() {
-            query_args.push(("ranges".to_string(), ranges.join(" ")));
+            query_args.push(("ranges", ranges.join(" ")));
         }
         if !value_render_option.to_string().is_empty() {
-            query_args.push((
-                "valueRenderOption".to_string(),
-                value_render_option.to_string(),
-            ));
+            query_args.push(("valueRenderOption", value_render_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values:batchGet?{}",
-            crate::progenitor_support::encode_path(spreadsheet_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v4/spreadsheets/{}/values:batchGet",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -452,9 +451,10 @@ impl Spreadsheets {
     */
     pub async fn values_batch_get_data_filter(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::BatchGetValuesByDataFilterRequest,
     ) -> Result<crate::types::BatchGetValuesByDataFilterResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/values:batchGetByDataFilter",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -476,9 +476,10 @@ impl Spreadsheets {
     */
     pub async fn values_batch_update(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::BatchUpdateValuesRequest,
     ) -> Result<crate::types::BatchUpdateValuesResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/values:batchUpdate",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -500,9 +501,10 @@ impl Spreadsheets {
     */
     pub async fn values_batch_update_data_filter(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::BatchUpdateValuesByDataFilterRequest,
     ) -> Result<crate::types::BatchUpdateValuesByDataFilterResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/values:batchUpdateByDataFilter",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -524,9 +526,10 @@ impl Spreadsheets {
     */
     pub async fn batch_update(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::BatchUpdateSpreadsheetRequest,
     ) -> Result<crate::types::BatchUpdateSpreadsheetResponse> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/batchUpdate",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -548,9 +551,10 @@ impl Spreadsheets {
     */
     pub async fn get_data_filter(
         &self,
-        spreadsheet_id: &str,
+        spreadsheet_id: impl AsRef<str>,
         body: &crate::types::GetSpreadsheetByDataFilterRequest,
     ) -> Result<crate::types::Spreadsheet> {
+        let spreadsheet_id = spreadsheet_id.as_ref();
         let url = format!(
             "/v4/spreadsheets/{}/getByDataFilter",
             crate::progenitor_support::encode_path(spreadsheet_id),
@@ -1 +1,78 @@
+use crate::a1::{column_index, column_letters, A1Range};
 
+#[test]
+fn test_a1_range() {
+    assert_eq!(column_letters(0), "A");
+    assert_eq!(column_letters(25), "Z");
+    assert_eq!(column_letters(26), "AA");
+    assert_eq!(column_letters(701), "ZZ");
+    assert_eq!(column_letters(702), "AAA");
+    assert_eq!(column_index("aa"), Some(26));
+    assert_eq!(column_index("ZZZ"), Some(18277));
+    assert_eq!(column_index("AAAA"), None);
+
+    assert_eq!(A1Range::cell(1, 0).to_string(), "B1");
+    assert_eq!(A1Range::columns(0, 2).to_string(), "A:C");
+    assert_eq!(
+        A1Range::rows(1, 4).on_sheet("Totals").to_string(),
+        "Totals!2:5"
+    );
+    assert_eq!(
+        A1Range::cell(0, 0)
+            .to(2, 9)
+            .on_sheet("Sales 2021")
+            .to_string(),
+        "'Sales 2021'!A1:C10"
+    );
+    assert_eq!(A1Range::sheet("Bob's").to_string(), "'Bob''s'");
+    // A sheet named like a cell.
+    assert_eq!(A1Range::sheet("Q1").to_string(), "'Q1'");
+
+    for range in &["'Bob''s'!A1:B2", "Sheet1!C:C", "A2:D", "'Q1'", "Sheet1"] {
+        assert_eq!(range.parse::<A1Range>().unwrap().to_string(), *range);
+    }
+    assert_eq!(
+        "'Bob''s'!A1".parse::<A1Range>().unwrap().sheet_name(),
+        Some("Bob's")
+    );
+    assert!("Sheet1!A0".parse::<A1Range>().is_err());
+    assert!("'Sheet1".parse::<A1Range>().is_err());
+}
+
+#[tokio::test]
+async fn test_values_batch_get_ranges() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"spreadsheetId": "abc", "valueRanges": [{"range": "'Sales 2021'!A1:B1", "values": [["1", "2"]]}]}"#,
+    );
+
+    let sheets = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let ranges = sheets
+        .spreadsheets()
+        .values_batch_get_ranges(
+            "abc",
+            &[
+                A1Range::cell(0, 0).to(1, 0).on_sheet("Sales 2021"),
+                A1Range::columns(1, 1).on_sheet("Totals"),
+            ],
+            crate::types::ValueRenderOption::FormattedValue,
+        )
+        .await
+        .unwrap();
+    assert_eq!(ranges[0].values, vec![vec!["1", "2"]]);
+
+    let url = &mock.requests()[0].url;
+    let params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    assert_eq!(
+        params,
+        vec![
+            ("ranges".to_string(), "'Sales 2021'!A1:B1".to_string()),
+            ("ranges".to_string(), "Totals!B:B".to_string()),
+            (
+                "valueRenderOption".to_string(),
+                "FORMATTED_VALUE".to_string()
+            ),
+        ]
+    );
+}
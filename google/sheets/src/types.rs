@@ -3,7 +3,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Adds a new banded range to the spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBandingRequest {
     /**
     * Adds a new banded range to the spreadsheet.
@@ -16,6 +16,29 @@ pub struct AddBandingRequest {
     pub banded_range: Option<BandedRange>,
 }
 
+impl AddBandingRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddBandingRequestBuilder {
+        AddBandingRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddBandingRequest`.
+#[derive(Debug, Clone)]
+pub struct AddBandingRequestBuilder(AddBandingRequest);
+
+impl AddBandingRequestBuilder {
+    pub fn banded_range(mut self, banded_range: impl Into<BandedRange>) -> Self {
+        self.0.banded_range = Some(banded_range.into());
+        self
+    }
+
+    pub fn build(self) -> AddBandingRequest {
+        self.0
+    }
+}
+
 /// The result of adding a banded range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBandingResponse {
@@ -31,7 +54,7 @@ pub struct AddBandingResponse {
 }
 
 /// Adds a chart to a sheet in the spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddChartRequest {
     /**
     * Adds a chart to a sheet in the spreadsheet.
@@ -40,6 +63,29 @@ pub struct AddChartRequest {
     pub chart: Option<EmbeddedChart>,
 }
 
+impl AddChartRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddChartRequestBuilder {
+        AddChartRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddChartRequest`.
+#[derive(Debug, Clone)]
+pub struct AddChartRequestBuilder(AddChartRequest);
+
+impl AddChartRequestBuilder {
+    pub fn chart(mut self, chart: impl Into<EmbeddedChart>) -> Self {
+        self.0.chart = Some(chart.into());
+        self
+    }
+
+    pub fn build(self) -> AddChartRequest {
+        self.0
+    }
+}
+
 /// The result of adding a chart to a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddChartResponse {
@@ -51,7 +97,7 @@ pub struct AddChartResponse {
 }
 
 /// Adds a new conditional format rule at the given index. All subsequent rules' indexes are incremented.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddConditionalFormatRuleRequest {
     /**
     * Adds a new conditional format rule at the given index. All subsequent rules' indexes are incremented.
@@ -69,8 +115,36 @@ pub struct AddConditionalFormatRuleRequest {
     pub rule: Option<ConditionalFormatRule>,
 }
 
+impl AddConditionalFormatRuleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddConditionalFormatRuleRequestBuilder {
+        AddConditionalFormatRuleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddConditionalFormatRuleRequest`.
+#[derive(Debug, Clone)]
+pub struct AddConditionalFormatRuleRequestBuilder(AddConditionalFormatRuleRequest);
+
+impl AddConditionalFormatRuleRequestBuilder {
+    pub fn index(mut self, index: i64) -> Self {
+        self.0.index = index;
+        self
+    }
+
+    pub fn rule(mut self, rule: impl Into<ConditionalFormatRule>) -> Self {
+        self.0.rule = Some(rule.into());
+        self
+    }
+
+    pub fn build(self) -> AddConditionalFormatRuleRequest {
+        self.0
+    }
+}
+
 /// Adds a data source. After the data source is added successfully, an associated DATA_SOURCE sheet is created and an execution is triggered to refresh the sheet to read data from the data source. The request requires an additional `bigquery.readonly` OAuth scope.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDataSourceRequest {
     /**
     * Adds a data source. After the data source is added successfully, an associated DATA_SOURCE sheet is created and an execution is triggered to refresh the sheet to read data from the data source. The request requires an additional `bigquery.readonly` OAuth scope.
@@ -83,6 +157,29 @@ pub struct AddDataSourceRequest {
     pub data_source: Option<DataSource>,
 }
 
+impl AddDataSourceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddDataSourceRequestBuilder {
+        AddDataSourceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddDataSourceRequest`.
+#[derive(Debug, Clone)]
+pub struct AddDataSourceRequestBuilder(AddDataSourceRequest);
+
+impl AddDataSourceRequestBuilder {
+    pub fn data_source(mut self, data_source: impl Into<DataSource>) -> Self {
+        self.0.data_source = Some(data_source.into());
+        self
+    }
+
+    pub fn build(self) -> AddDataSourceRequest {
+        self.0
+    }
+}
+
 /// The result of adding a data source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDataSourceResponse {
@@ -107,7 +204,7 @@ pub struct AddDataSourceResponse {
 }
 
 /// Creates a group over the specified range. If the requested range is a superset of the range of an existing group G, then the depth of G is incremented and this new group G' has the depth of that group. For example, a group [C:D, depth 1] + [B:E] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range is a subset of the range of an existing group G, then the depth of the new group G' becomes one greater than the depth of G. For example, a group [B:E, depth 1] + [C:D] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range starts before and ends within, or starts within and ends after, the range of an existing group G, then the range of the existing group G becomes the union of the ranges, and the new group G' has depth one greater than the depth of G and range as the intersection of the ranges. For example, a group [B:D, depth 1] + [C:E] results in groups [B:E, depth 1] and [C:D, depth 2].
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDimensionGroupRequest {
     /**
     * Creates a group over the specified range. If the requested range is a superset of the range of an existing group G, then the depth of G is incremented and this new group G' has the depth of that group. For example, a group [C:D, depth 1] + [B:E] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range is a subset of the range of an existing group G, then the depth of the new group G' becomes one greater than the depth of G. For example, a group [B:E, depth 1] + [C:D] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range starts before and ends within, or starts within and ends after, the range of an existing group G, then the range of the existing group G becomes the union of the ranges, and the new group G' has depth one greater than the depth of G and range as the intersection of the ranges. For example, a group [B:D, depth 1] + [C:E] results in groups [B:E, depth 1] and [C:D, depth 2].
@@ -116,6 +213,29 @@ pub struct AddDimensionGroupRequest {
     pub range: Option<DimensionRange>,
 }
 
+impl AddDimensionGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddDimensionGroupRequestBuilder {
+        AddDimensionGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddDimensionGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct AddDimensionGroupRequestBuilder(AddDimensionGroupRequest);
+
+impl AddDimensionGroupRequestBuilder {
+    pub fn range(mut self, range: impl Into<DimensionRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> AddDimensionGroupRequest {
+        self.0
+    }
+}
+
 /// The result of adding a group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDimensionGroupResponse {
@@ -132,7 +252,7 @@ pub struct AddDimensionGroupResponse {
 }
 
 /// Adds a filter view.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddFilterViewRequest {
     /**
     * Adds a filter view.
@@ -141,6 +261,29 @@ pub struct AddFilterViewRequest {
     pub filter: Option<FilterView>,
 }
 
+impl AddFilterViewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddFilterViewRequestBuilder {
+        AddFilterViewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddFilterViewRequest`.
+#[derive(Debug, Clone)]
+pub struct AddFilterViewRequestBuilder(AddFilterViewRequest);
+
+impl AddFilterViewRequestBuilder {
+    pub fn filter(mut self, filter: impl Into<FilterView>) -> Self {
+        self.0.filter = Some(filter.into());
+        self
+    }
+
+    pub fn build(self) -> AddFilterViewRequest {
+        self.0
+    }
+}
+
 /// The result of adding a filter view.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddFilterViewResponse {
@@ -152,7 +295,7 @@ pub struct AddFilterViewResponse {
 }
 
 /// Adds a named range to the spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddNamedRangeRequest {
     /**
     * Adds a named range to the spreadsheet.
@@ -165,6 +308,29 @@ pub struct AddNamedRangeRequest {
     pub named_range: Option<NamedRange>,
 }
 
+impl AddNamedRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddNamedRangeRequestBuilder {
+        AddNamedRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddNamedRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct AddNamedRangeRequestBuilder(AddNamedRangeRequest);
+
+impl AddNamedRangeRequestBuilder {
+    pub fn named_range(mut self, named_range: impl Into<NamedRange>) -> Self {
+        self.0.named_range = Some(named_range.into());
+        self
+    }
+
+    pub fn build(self) -> AddNamedRangeRequest {
+        self.0
+    }
+}
+
 /// The result of adding a named range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddNamedRangeResponse {
@@ -180,7 +346,7 @@ pub struct AddNamedRangeResponse {
 }
 
 /// Adds a new protected range.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddProtectedRangeRequest {
     /**
     * Adds a new protected range.
@@ -193,6 +359,29 @@ pub struct AddProtectedRangeRequest {
     pub protected_range: Option<ProtectedRange>,
 }
 
+impl AddProtectedRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddProtectedRangeRequestBuilder {
+        AddProtectedRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddProtectedRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct AddProtectedRangeRequestBuilder(AddProtectedRangeRequest);
+
+impl AddProtectedRangeRequestBuilder {
+    pub fn protected_range(mut self, protected_range: impl Into<ProtectedRange>) -> Self {
+        self.0.protected_range = Some(protected_range.into());
+        self
+    }
+
+    pub fn build(self) -> AddProtectedRangeRequest {
+        self.0
+    }
+}
+
 /// The result of adding a new protected range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddProtectedRangeResponse {
@@ -208,7 +397,7 @@ pub struct AddProtectedRangeResponse {
 }
 
 /// Adds a new sheet. When a sheet is added at a given index, all subsequent sheets' indexes are incremented. To add an object sheet, use AddChartRequest instead and specify EmbeddedObjectPosition.sheetId or EmbeddedObjectPosition.newSheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSheetRequest {
     /**
     * Adds a new sheet. When a sheet is added at a given index, all subsequent sheets' indexes are incremented. To add an object sheet, use AddChartRequest instead and specify EmbeddedObjectPosition.sheetId or EmbeddedObjectPosition.newSheet.
@@ -217,6 +406,29 @@ pub struct AddSheetRequest {
     pub properties: Option<SheetProperties>,
 }
 
+impl AddSheetRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddSheetRequestBuilder {
+        AddSheetRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddSheetRequest`.
+#[derive(Debug, Clone)]
+pub struct AddSheetRequestBuilder(AddSheetRequest);
+
+impl AddSheetRequestBuilder {
+    pub fn properties(mut self, properties: impl Into<SheetProperties>) -> Self {
+        self.0.properties = Some(properties.into());
+        self
+    }
+
+    pub fn build(self) -> AddSheetRequest {
+        self.0
+    }
+}
+
 /// The result of adding a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSheetResponse {
@@ -228,7 +440,7 @@ pub struct AddSheetResponse {
 }
 
 /// Adds a slicer to a sheet in the spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSlicerRequest {
     /**
     * Adds a slicer to a sheet in the spreadsheet.
@@ -237,6 +449,29 @@ pub struct AddSlicerRequest {
     pub slicer: Option<Slicer>,
 }
 
+impl AddSlicerRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddSlicerRequestBuilder {
+        AddSlicerRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddSlicerRequest`.
+#[derive(Debug, Clone)]
+pub struct AddSlicerRequestBuilder(AddSlicerRequest);
+
+impl AddSlicerRequestBuilder {
+    pub fn slicer(mut self, slicer: impl Into<Slicer>) -> Self {
+        self.0.slicer = Some(slicer.into());
+        self
+    }
+
+    pub fn build(self) -> AddSlicerRequest {
+        self.0
+    }
+}
+
 /// The result of adding a slicer to a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSlicerResponse {
@@ -248,7 +483,7 @@ pub struct AddSlicerResponse {
 }
 
 /// Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppendCellsRequest {
     /**
     * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
@@ -280,6 +515,39 @@ pub struct AppendCellsRequest {
     pub sheet_id: i64,
 }
 
+impl AppendCellsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AppendCellsRequestBuilder {
+        AppendCellsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AppendCellsRequest`.
+#[derive(Debug, Clone)]
+pub struct AppendCellsRequestBuilder(AppendCellsRequest);
+
+impl AppendCellsRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn rows(mut self, rows: Vec<RowData>) -> Self {
+        self.0.rows = rows;
+        self
+    }
+
+    pub fn sheet_id(mut self, sheet_id: i64) -> Self {
+        self.0.sheet_id = sheet_id;
+        self
+    }
+
+    pub fn build(self) -> AppendCellsRequest {
+        self.0
+    }
+}
+
 /**
 * Whether rows or columns should be appended.
 */
@@ -293,8 +561,8 @@ pub enum Dimension {
     Rows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Dimension {
@@ -304,7 +572,7 @@ impl std::fmt::Display for Dimension {
             Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
             Dimension::Rows => "ROWS",
             Dimension::Noop => "",
-            Dimension::FallthroughString => "*",
+            Dimension::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -322,7 +590,7 @@ impl Dimension {
 }
 
 /// Appends rows or columns to the end of a sheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppendDimensionRequest {
     /**
     * Appends rows or columns to the end of a sheet.
@@ -350,6 +618,39 @@ pub struct AppendDimensionRequest {
     pub sheet_id: i64,
 }
 
+impl AppendDimensionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AppendDimensionRequestBuilder {
+        AppendDimensionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AppendDimensionRequest`.
+#[derive(Debug, Clone)]
+pub struct AppendDimensionRequestBuilder(AppendDimensionRequest);
+
+impl AppendDimensionRequestBuilder {
+    pub fn dimension(mut self, dimension: impl Into<Dimension>) -> Self {
+        self.0.dimension = Some(dimension.into());
+        self
+    }
+
+    pub fn length(mut self, length: i64) -> Self {
+        self.0.length = length;
+        self
+    }
+
+    pub fn sheet_id(mut self, sheet_id: i64) -> Self {
+        self.0.sheet_id = sheet_id;
+        self
+    }
+
+    pub fn build(self) -> AppendDimensionRequest {
+        self.0
+    }
+}
+
 /// The response when updating a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppendValuesResponse {
@@ -381,7 +682,7 @@ pub struct AppendValuesResponse {
 }
 
 /// Fills in more data based on existing data.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutoFillRequest {
     /**
     * Fills in more data based on existing data.
@@ -408,8 +709,44 @@ pub struct AutoFillRequest {
     pub use_alternate_series: Option<bool>,
 }
 
+impl AutoFillRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AutoFillRequestBuilder {
+        AutoFillRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AutoFillRequest`.
+#[derive(Debug, Clone)]
+pub struct AutoFillRequestBuilder(AutoFillRequest);
+
+impl AutoFillRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn source_and_destination(
+        mut self,
+        source_and_destination: impl Into<SourceDestination>,
+    ) -> Self {
+        self.0.source_and_destination = Some(source_and_destination.into());
+        self
+    }
+
+    pub fn use_alternate_series(mut self, use_alternate_series: bool) -> Self {
+        self.0.use_alternate_series = Some(use_alternate_series);
+        self
+    }
+
+    pub fn build(self) -> AutoFillRequest {
+        self.0
+    }
+}
+
 /// Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutoResizeDimensionsRequest {
     /**
     * Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
@@ -427,6 +764,37 @@ pub struct AutoResizeDimensionsRequest {
     pub dimensions: Option<DimensionRange>,
 }
 
+impl AutoResizeDimensionsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AutoResizeDimensionsRequestBuilder {
+        AutoResizeDimensionsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AutoResizeDimensionsRequest`.
+#[derive(Debug, Clone)]
+pub struct AutoResizeDimensionsRequestBuilder(AutoResizeDimensionsRequest);
+
+impl AutoResizeDimensionsRequestBuilder {
+    pub fn data_source_sheet_dimensions(
+        mut self,
+        data_source_sheet_dimensions: impl Into<DataSourceSheetDimensionRange>,
+    ) -> Self {
+        self.0.data_source_sheet_dimensions = Some(data_source_sheet_dimensions.into());
+        self
+    }
+
+    pub fn dimensions(mut self, dimensions: impl Into<DimensionRange>) -> Self {
+        self.0.dimensions = Some(dimensions.into());
+        self
+    }
+
+    pub fn build(self) -> AutoResizeDimensionsRequest {
+        self.0
+    }
+}
+
 /// A banded (alternating colors) range in a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BandedRange {
@@ -555,8 +923,8 @@ pub enum ComparisonType {
     PercentageDifference,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ComparisonType {
@@ -566,7 +934,7 @@ impl std::fmt::Display for ComparisonType {
             ComparisonType::ComparisonTypeUndefined => "COMPARISON_TYPE_UNDEFINED",
             ComparisonType::PercentageDifference => "PERCENTAGE_DIFFERENCE",
             ComparisonType::Noop => "",
-            ComparisonType::FallthroughString => "*",
+            ComparisonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -671,8 +1039,8 @@ pub enum Position {
     RightAxis,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Position {
@@ -683,7 +1051,7 @@ impl std::fmt::Display for Position {
             Position::LeftAxis => "LEFT_AXIS",
             Position::RightAxis => "RIGHT_AXIS",
             Position::Noop => "",
-            Position::FallthroughString => "*",
+            Position::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -783,8 +1151,8 @@ pub enum Type {
     SteppedArea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -799,7 +1167,7 @@ impl std::fmt::Display for Type {
             Type::Scatter => "SCATTER",
             Type::SteppedArea => "STEPPED_AREA",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -896,8 +1264,8 @@ pub enum CompareMode {
     Datum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompareMode {
@@ -907,7 +1275,7 @@ impl std::fmt::Display for CompareMode {
             CompareMode::Category => "CATEGORY",
             CompareMode::Datum => "DATUM",
             CompareMode::Noop => "",
-            CompareMode::FallthroughString => "*",
+            CompareMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -943,8 +1311,8 @@ pub enum LegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LegendPosition {
@@ -959,7 +1327,7 @@ impl std::fmt::Display for LegendPosition {
             LegendPosition::RightLegend => "RIGHT_LEGEND",
             LegendPosition::TopLegend => "TOP_LEGEND",
             LegendPosition::Noop => "",
-            LegendPosition::FallthroughString => "*",
+            LegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -991,8 +1359,8 @@ pub enum StackedType {
     Stacked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StackedType {
@@ -1003,7 +1371,7 @@ impl std::fmt::Display for StackedType {
             StackedType::PercentStacked => "PERCENT_STACKED",
             StackedType::Stacked => "STACKED",
             StackedType::Noop => "",
-            StackedType::FallthroughString => "*",
+            StackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1203,7 +1571,7 @@ pub struct BasicSeriesDataPointStyleOverride {
 }
 
 /// The request for clearing more than one range selected by a DataFilter in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesByDataFilterRequest {
     /**
     * The request for clearing more than one range selected by a DataFilter in a spreadsheet.
@@ -1217,6 +1585,29 @@ pub struct BatchClearValuesByDataFilterRequest {
     pub data_filters: Vec<DataFilter>,
 }
 
+impl BatchClearValuesByDataFilterRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchClearValuesByDataFilterRequestBuilder {
+        BatchClearValuesByDataFilterRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchClearValuesByDataFilterRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchClearValuesByDataFilterRequestBuilder(BatchClearValuesByDataFilterRequest);
+
+impl BatchClearValuesByDataFilterRequestBuilder {
+    pub fn data_filters(mut self, data_filters: Vec<DataFilter>) -> Self {
+        self.0.data_filters = data_filters;
+        self
+    }
+
+    pub fn build(self) -> BatchClearValuesByDataFilterRequest {
+        self.0
+    }
+}
+
 /// The response when clearing a range of values selected with DataFilters in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesByDataFilterResponse {
@@ -1243,7 +1634,7 @@ pub struct BatchClearValuesByDataFilterResponse {
 }
 
 /// The request for clearing more than one range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesRequest {
     /**
     * The request for clearing more than one range of values in a spreadsheet.
@@ -1256,6 +1647,29 @@ pub struct BatchClearValuesRequest {
     pub ranges: Vec<String>,
 }
 
+impl BatchClearValuesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchClearValuesRequestBuilder {
+        BatchClearValuesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchClearValuesRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchClearValuesRequestBuilder(BatchClearValuesRequest);
+
+impl BatchClearValuesRequestBuilder {
+    pub fn ranges(mut self, ranges: Vec<String>) -> Self {
+        self.0.ranges = ranges;
+        self
+    }
+
+    pub fn build(self) -> BatchClearValuesRequest {
+        self.0
+    }
+}
+
 /// The response when clearing a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesResponse {
@@ -1292,8 +1706,8 @@ pub enum DateTimeRenderOption {
     SerialNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRenderOption {
@@ -1302,7 +1716,7 @@ impl std::fmt::Display for DateTimeRenderOption {
             DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
             DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
             DateTimeRenderOption::Noop => "",
-            DateTimeRenderOption::FallthroughString => "*",
+            DateTimeRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1332,8 +1746,8 @@ pub enum ValueRenderOption {
     UnformattedValue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueRenderOption {
@@ -1343,7 +1757,7 @@ impl std::fmt::Display for ValueRenderOption {
             ValueRenderOption::Formula => "FORMULA",
             ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
             ValueRenderOption::Noop => "",
-            ValueRenderOption::FallthroughString => "*",
+            ValueRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1361,7 +1775,7 @@ impl ValueRenderOption {
 }
 
 /// The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchGetValuesByDataFilterRequest {
     /**
     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
@@ -1402,6 +1816,50 @@ pub struct BatchGetValuesByDataFilterRequest {
     pub value_render_option: Option<ValueRenderOption>,
 }
 
+impl BatchGetValuesByDataFilterRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchGetValuesByDataFilterRequestBuilder {
+        BatchGetValuesByDataFilterRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchGetValuesByDataFilterRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchGetValuesByDataFilterRequestBuilder(BatchGetValuesByDataFilterRequest);
+
+impl BatchGetValuesByDataFilterRequestBuilder {
+    pub fn data_filters(mut self, data_filters: Vec<DataFilter>) -> Self {
+        self.0.data_filters = data_filters;
+        self
+    }
+
+    pub fn date_time_render_option(
+        mut self,
+        date_time_render_option: impl Into<DateTimeRenderOption>,
+    ) -> Self {
+        self.0.date_time_render_option = Some(date_time_render_option.into());
+        self
+    }
+
+    pub fn major_dimension(mut self, major_dimension: impl Into<Dimension>) -> Self {
+        self.0.major_dimension = Some(major_dimension.into());
+        self
+    }
+
+    pub fn value_render_option(
+        mut self,
+        value_render_option: impl Into<ValueRenderOption>,
+    ) -> Self {
+        self.0.value_render_option = Some(value_render_option.into());
+        self
+    }
+
+    pub fn build(self) -> BatchGetValuesByDataFilterRequest {
+        self.0
+    }
+}
+
 /// The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchGetValuesByDataFilterResponse {
@@ -1453,7 +1911,7 @@ pub struct BatchGetValuesResponse {
 }
 
 /// The request for updating any aspect of a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateSpreadsheetRequest {
     /**
     * The request for updating any aspect of a spreadsheet.
@@ -1494,6 +1952,47 @@ pub struct BatchUpdateSpreadsheetRequest {
     pub response_ranges: Vec<String>,
 }
 
+impl BatchUpdateSpreadsheetRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchUpdateSpreadsheetRequestBuilder {
+        BatchUpdateSpreadsheetRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchUpdateSpreadsheetRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchUpdateSpreadsheetRequestBuilder(BatchUpdateSpreadsheetRequest);
+
+impl BatchUpdateSpreadsheetRequestBuilder {
+    pub fn include_spreadsheet_in_response(
+        mut self,
+        include_spreadsheet_in_response: bool,
+    ) -> Self {
+        self.0.include_spreadsheet_in_response = Some(include_spreadsheet_in_response);
+        self
+    }
+
+    pub fn requests(mut self, requests: Vec<Request>) -> Self {
+        self.0.requests = requests;
+        self
+    }
+
+    pub fn response_include_grid_data(mut self, response_include_grid_data: bool) -> Self {
+        self.0.response_include_grid_data = Some(response_include_grid_data);
+        self
+    }
+
+    pub fn response_ranges(mut self, response_ranges: Vec<String>) -> Self {
+        self.0.response_ranges = response_ranges;
+        self
+    }
+
+    pub fn build(self) -> BatchUpdateSpreadsheetRequest {
+        self.0
+    }
+}
+
 /// The reply for batch updating a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateSpreadsheetResponse {
@@ -1540,8 +2039,8 @@ pub enum ValueInputOption {
     UserEntered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueInputOption {
@@ -1551,7 +2050,7 @@ impl std::fmt::Display for ValueInputOption {
             ValueInputOption::Raw => "RAW",
             ValueInputOption::UserEntered => "USER_ENTERED",
             ValueInputOption::Noop => "",
-            ValueInputOption::FallthroughString => "*",
+            ValueInputOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1569,7 +2068,7 @@ impl ValueInputOption {
 }
 
 /// The request for updating more than one range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesByDataFilterRequest {
     /**
     * The request for updating more than one range of values in a spreadsheet.
@@ -1618,6 +2117,55 @@ pub struct BatchUpdateValuesByDataFilterRequest {
     pub value_input_option: Option<ValueInputOption>,
 }
 
+impl BatchUpdateValuesByDataFilterRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchUpdateValuesByDataFilterRequestBuilder {
+        BatchUpdateValuesByDataFilterRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchUpdateValuesByDataFilterRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchUpdateValuesByDataFilterRequestBuilder(BatchUpdateValuesByDataFilterRequest);
+
+impl BatchUpdateValuesByDataFilterRequestBuilder {
+    pub fn data(mut self, data: Vec<DataFilterValueRange>) -> Self {
+        self.0.data = data;
+        self
+    }
+
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.0.include_values_in_response = Some(include_values_in_response);
+        self
+    }
+
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: impl Into<DateTimeRenderOption>,
+    ) -> Self {
+        self.0.response_date_time_render_option = Some(response_date_time_render_option.into());
+        self
+    }
+
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: impl Into<ValueRenderOption>,
+    ) -> Self {
+        self.0.response_value_render_option = Some(response_value_render_option.into());
+        self
+    }
+
+    pub fn value_input_option(mut self, value_input_option: impl Into<ValueInputOption>) -> Self {
+        self.0.value_input_option = Some(value_input_option.into());
+        self
+    }
+
+    pub fn build(self) -> BatchUpdateValuesByDataFilterRequest {
+        self.0
+    }
+}
+
 /// The response when updating a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesByDataFilterResponse {
@@ -1683,7 +2231,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
 }
 
 /// The request for updating more than one range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesRequest {
     /**
     * The request for updating more than one range of values in a spreadsheet.
@@ -1732,6 +2280,55 @@ pub struct BatchUpdateValuesRequest {
     pub value_input_option: Option<ValueInputOption>,
 }
 
+impl BatchUpdateValuesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchUpdateValuesRequestBuilder {
+        BatchUpdateValuesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchUpdateValuesRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchUpdateValuesRequestBuilder(BatchUpdateValuesRequest);
+
+impl BatchUpdateValuesRequestBuilder {
+    pub fn data(mut self, data: Vec<ValueRange>) -> Self {
+        self.0.data = data;
+        self
+    }
+
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.0.include_values_in_response = Some(include_values_in_response);
+        self
+    }
+
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: impl Into<DateTimeRenderOption>,
+    ) -> Self {
+        self.0.response_date_time_render_option = Some(response_date_time_render_option.into());
+        self
+    }
+
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: impl Into<ValueRenderOption>,
+    ) -> Self {
+        self.0.response_value_render_option = Some(response_value_render_option.into());
+        self
+    }
+
+    pub fn value_input_option(mut self, value_input_option: impl Into<ValueInputOption>) -> Self {
+        self.0.value_input_option = Some(value_input_option.into());
+        self
+    }
+
+    pub fn build(self) -> BatchUpdateValuesRequest {
+        self.0
+    }
+}
+
 /// The response when updating a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesResponse {
@@ -1942,8 +2539,8 @@ pub enum BooleanConditionType {
     TextStartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BooleanConditionType {
@@ -1982,7 +2579,7 @@ impl std::fmt::Display for BooleanConditionType {
             BooleanConditionType::TextNotEq => "TEXT_NOT_EQ",
             BooleanConditionType::TextStartsWith => "TEXT_STARTS_WITH",
             BooleanConditionType::Noop => "",
-            BooleanConditionType::FallthroughString => "*",
+            BooleanConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2056,8 +2653,8 @@ pub enum Style {
     StyleUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Style {
@@ -2072,7 +2669,7 @@ impl std::fmt::Display for Style {
             Style::SolidThick => "SOLID_THICK",
             Style::StyleUnspecified => "STYLE_UNSPECIFIED",
             Style::Noop => "",
-            Style::FallthroughString => "*",
+            Style::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2168,8 +2765,8 @@ pub enum BubbleChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BubbleChartSpecLegendPosition {
@@ -2185,7 +2782,7 @@ impl std::fmt::Display for BubbleChartSpecLegendPosition {
             BubbleChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             BubbleChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             BubbleChartSpecLegendPosition::Noop => "",
-            BubbleChartSpecLegendPosition::FallthroughString => "*",
+            BubbleChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2520,8 +3117,8 @@ pub enum HorizontalAlignment {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HorizontalAlignment {
@@ -2532,7 +3129,7 @@ impl std::fmt::Display for HorizontalAlignment {
             HorizontalAlignment::Left => "LEFT",
             HorizontalAlignment::Right => "RIGHT",
             HorizontalAlignment::Noop => "",
-            HorizontalAlignment::FallthroughString => "*",
+            HorizontalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2562,8 +3159,8 @@ pub enum HyperlinkDisplayType {
     PlainText,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HyperlinkDisplayType {
@@ -2575,7 +3172,7 @@ impl std::fmt::Display for HyperlinkDisplayType {
             HyperlinkDisplayType::Linked => "LINKED",
             HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
             HyperlinkDisplayType::Noop => "",
-            HyperlinkDisplayType::FallthroughString => "*",
+            HyperlinkDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2605,8 +3202,8 @@ pub enum TextDirection {
     TextDirectionUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextDirection {
@@ -2616,7 +3213,7 @@ impl std::fmt::Display for TextDirection {
             TextDirection::RightToLeft => "RIGHT_TO_LEFT",
             TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
             TextDirection::Noop => "",
-            TextDirection::FallthroughString => "*",
+            TextDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2648,8 +3245,8 @@ pub enum VerticalAlignment {
     VerticalAlignUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerticalAlignment {
@@ -2660,7 +3257,7 @@ impl std::fmt::Display for VerticalAlignment {
             VerticalAlignment::Top => "TOP",
             VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
             VerticalAlignment::Noop => "",
-            VerticalAlignment::FallthroughString => "*",
+            VerticalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2694,8 +3291,8 @@ pub enum WrapStrategy {
     WrapStrategyUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WrapStrategy {
@@ -2707,7 +3304,7 @@ impl std::fmt::Display for WrapStrategy {
             WrapStrategy::Wrap => "WRAP",
             WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
             WrapStrategy::Noop => "",
-            WrapStrategy::FallthroughString => "*",
+            WrapStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2844,8 +3441,8 @@ pub enum ViewWindowMode {
     ViewWindowModeUnsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewWindowMode {
@@ -2856,7 +3453,7 @@ impl std::fmt::Display for ViewWindowMode {
             ViewWindowMode::Pretty => "PRETTY",
             ViewWindowMode::ViewWindowModeUnsupported => "VIEW_WINDOW_MODE_UNSUPPORTED",
             ViewWindowMode::Noop => "",
-            ViewWindowMode::FallthroughString => "*",
+            ViewWindowMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2951,8 +3548,8 @@ pub enum AggregateType {
     Sum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregateType {
@@ -2966,7 +3563,7 @@ impl std::fmt::Display for AggregateType {
             AggregateType::Min => "MIN",
             AggregateType::Sum => "SUM",
             AggregateType::Noop => "",
-            AggregateType::FallthroughString => "*",
+            AggregateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3059,8 +3656,8 @@ pub enum ChartDateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChartDateTimeRuleType {
@@ -3085,7 +3682,7 @@ impl std::fmt::Display for ChartDateTimeRuleType {
             ChartDateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             ChartDateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             ChartDateTimeRuleType::Noop => "",
-            ChartDateTimeRuleType::FallthroughString => "*",
+            ChartDateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3201,8 +3798,8 @@ pub enum HiddenDimensionStrategy {
     SkipHiddenRowsAndColumns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HiddenDimensionStrategy {
@@ -3216,7 +3813,7 @@ impl std::fmt::Display for HiddenDimensionStrategy {
             HiddenDimensionStrategy::SkipHiddenRows => "SKIP_HIDDEN_ROWS",
             HiddenDimensionStrategy::SkipHiddenRowsAndColumns => "SKIP_HIDDEN_ROWS_AND_COLUMNS",
             HiddenDimensionStrategy::Noop => "",
-            HiddenDimensionStrategy::FallthroughString => "*",
+            HiddenDimensionStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3450,7 +4047,7 @@ pub struct ChartSpec {
 }
 
 /// Clears the basic filter, if any exists on the sheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ClearBasicFilterRequest {
     /**
     * Clears the basic filter, if any exists on the sheet.
@@ -3464,11 +4061,52 @@ pub struct ClearBasicFilterRequest {
     pub sheet_id: i64,
 }
 
+impl ClearBasicFilterRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ClearBasicFilterRequestBuilder {
+        ClearBasicFilterRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ClearBasicFilterRequest`.
+#[derive(Debug, Clone)]
+pub struct ClearBasicFilterRequestBuilder(ClearBasicFilterRequest);
+
+impl ClearBasicFilterRequestBuilder {
+    pub fn sheet_id(mut self, sheet_id: i64) -> Self {
+        self.0.sheet_id = sheet_id;
+        self
+    }
+
+    pub fn build(self) -> ClearBasicFilterRequest {
+        self.0
+    }
+}
+
 /// The request for clearing a range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ClearValuesRequest {}
 
-/// The response when clearing a range of values in a spreadsheet.
+impl ClearValuesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ClearValuesRequestBuilder {
+        ClearValuesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ClearValuesRequest`.
+#[derive(Debug, Clone)]
+pub struct ClearValuesRequestBuilder(ClearValuesRequest);
+
+impl ClearValuesRequestBuilder {
+    pub fn build(self) -> ClearValuesRequest {
+        self.0
+    }
+}
+
+/// The response when clearing a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ClearValuesResponse {
     /**
@@ -3561,8 +4199,8 @@ pub enum ColorType {
     ThemeColorTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColorType {
@@ -3579,7 +4217,7 @@ impl std::fmt::Display for ColorType {
             ColorType::Text => "TEXT",
             ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
             ColorType::Noop => "",
-            ColorType::FallthroughString => "*",
+            ColorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3636,8 +4274,8 @@ pub enum RelativeDate {
     Yesterday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RelativeDate {
@@ -3651,7 +4289,7 @@ impl std::fmt::Display for RelativeDate {
             RelativeDate::Tomorrow => "TOMORROW",
             RelativeDate::Yesterday => "YESTERDAY",
             RelativeDate::Noop => "",
-            RelativeDate::FallthroughString => "*",
+            RelativeDate::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3735,8 +4373,8 @@ pub enum PasteOrientation {
     Transpose,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteOrientation {
@@ -3745,7 +4383,7 @@ impl std::fmt::Display for PasteOrientation {
             PasteOrientation::Normal => "NORMAL",
             PasteOrientation::Transpose => "TRANSPOSE",
             PasteOrientation::Noop => "",
-            PasteOrientation::FallthroughString => "*",
+            PasteOrientation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3783,8 +4421,8 @@ pub enum PasteType {
     PasteValues,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteType {
@@ -3798,7 +4436,7 @@ impl std::fmt::Display for PasteType {
             PasteType::PasteNoBorders => "PASTE_NO_BORDERS",
             PasteType::PasteValues => "PASTE_VALUES",
             PasteType::Noop => "",
-            PasteType::FallthroughString => "*",
+            PasteType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3816,7 +4454,7 @@ impl PasteType {
 }
 
 /// Copies data from the source to the destination.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CopyPasteRequest {
     /**
     * Copies data from the source to the destination.
@@ -3844,8 +4482,46 @@ pub struct CopyPasteRequest {
     pub source: Option<GridRange>,
 }
 
+impl CopyPasteRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CopyPasteRequestBuilder {
+        CopyPasteRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CopyPasteRequest`.
+#[derive(Debug, Clone)]
+pub struct CopyPasteRequestBuilder(CopyPasteRequest);
+
+impl CopyPasteRequestBuilder {
+    pub fn destination(mut self, destination: impl Into<GridRange>) -> Self {
+        self.0.destination = Some(destination.into());
+        self
+    }
+
+    pub fn paste_orientation(mut self, paste_orientation: impl Into<PasteOrientation>) -> Self {
+        self.0.paste_orientation = Some(paste_orientation.into());
+        self
+    }
+
+    pub fn paste_type(mut self, paste_type: impl Into<PasteType>) -> Self {
+        self.0.paste_type = Some(paste_type.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<GridRange>) -> Self {
+        self.0.source = Some(source.into());
+        self
+    }
+
+    pub fn build(self) -> CopyPasteRequest {
+        self.0
+    }
+}
+
 /// The request to copy a sheet across spreadsheets.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CopySheetAnotherSpreadsheetRequest {
     /**
     * The request to copy a sheet across spreadsheets.
@@ -3859,8 +4535,50 @@ pub struct CopySheetAnotherSpreadsheetRequest {
     pub destination_spreadsheet_id: String,
 }
 
+impl std::convert::From<String> for CopySheetAnotherSpreadsheetRequest {
+    fn from(destination_spreadsheet_id: String) -> Self {
+        CopySheetAnotherSpreadsheetRequest {
+            destination_spreadsheet_id,
+        }
+    }
+}
+
+impl std::convert::From<&str> for CopySheetAnotherSpreadsheetRequest {
+    fn from(destination_spreadsheet_id: &str) -> Self {
+        CopySheetAnotherSpreadsheetRequest {
+            destination_spreadsheet_id: destination_spreadsheet_id.to_string(),
+        }
+    }
+}
+
+impl CopySheetAnotherSpreadsheetRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CopySheetAnotherSpreadsheetRequestBuilder {
+        CopySheetAnotherSpreadsheetRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CopySheetAnotherSpreadsheetRequest`.
+#[derive(Debug, Clone)]
+pub struct CopySheetAnotherSpreadsheetRequestBuilder(CopySheetAnotherSpreadsheetRequest);
+
+impl CopySheetAnotherSpreadsheetRequestBuilder {
+    pub fn destination_spreadsheet_id(
+        mut self,
+        destination_spreadsheet_id: impl Into<String>,
+    ) -> Self {
+        self.0.destination_spreadsheet_id = destination_spreadsheet_id.into();
+        self
+    }
+
+    pub fn build(self) -> CopySheetAnotherSpreadsheetRequest {
+        self.0
+    }
+}
+
 /// A request to create developer metadata.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateDeveloperMetadataRequest {
     /**
     * A request to create developer metadata.
@@ -3873,6 +4591,29 @@ pub struct CreateDeveloperMetadataRequest {
     pub developer_metadata: Option<DeveloperMetadata>,
 }
 
+impl CreateDeveloperMetadataRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateDeveloperMetadataRequestBuilder {
+        CreateDeveloperMetadataRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateDeveloperMetadataRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateDeveloperMetadataRequestBuilder(CreateDeveloperMetadataRequest);
+
+impl CreateDeveloperMetadataRequestBuilder {
+    pub fn developer_metadata(mut self, developer_metadata: impl Into<DeveloperMetadata>) -> Self {
+        self.0.developer_metadata = Some(developer_metadata.into());
+        self
+    }
+
+    pub fn build(self) -> CreateDeveloperMetadataRequest {
+        self.0
+    }
+}
+
 /// The response from creating developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateDeveloperMetadataResponse {
@@ -3888,7 +4629,7 @@ pub struct CreateDeveloperMetadataResponse {
 }
 
 /// Moves data from the source to the destination.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CutPasteRequest {
     /**
     * Moves data from the source to the destination.
@@ -3907,6 +4648,39 @@ pub struct CutPasteRequest {
     pub source: Option<GridRange>,
 }
 
+impl CutPasteRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CutPasteRequestBuilder {
+        CutPasteRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CutPasteRequest`.
+#[derive(Debug, Clone)]
+pub struct CutPasteRequestBuilder(CutPasteRequest);
+
+impl CutPasteRequestBuilder {
+    pub fn destination(mut self, destination: impl Into<GridCoordinate>) -> Self {
+        self.0.destination = Some(destination.into());
+        self
+    }
+
+    pub fn paste_type(mut self, paste_type: impl Into<PasteType>) -> Self {
+        self.0.paste_type = Some(paste_type.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<GridRange>) -> Self {
+        self.0.source = Some(source.into());
+        self
+    }
+
+    pub fn build(self) -> CutPasteRequest {
+        self.0
+    }
+}
+
 /**
 * The error code.
 */
@@ -3950,8 +4724,8 @@ pub enum ErrorCode {
     UnsupportedDataType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -3976,7 +4750,7 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::TooManyRows => "TOO_MANY_ROWS",
             ErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4010,8 +4784,8 @@ pub enum State {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -4023,7 +4797,7 @@ impl std::fmt::Display for State {
             State::Running => "RUNNING",
             State::Succeeded => "SUCCEEDED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4161,8 +4935,8 @@ pub enum Placement {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Placement {
@@ -4178,7 +4952,7 @@ impl std::fmt::Display for Placement {
             Placement::OutsideEnd => "OUTSIDE_END",
             Placement::Right => "RIGHT",
             Placement::Noop => "",
-            Placement::FallthroughString => "*",
+            Placement::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4210,8 +4984,8 @@ pub enum DataLabelType {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DataLabelType {
@@ -4222,7 +4996,7 @@ impl std::fmt::Display for DataLabelType {
             DataLabelType::DataLabelTypeUnspecified => "DATA_LABEL_TYPE_UNSPECIFIED",
             DataLabelType::None => "NONE",
             DataLabelType::Noop => "",
-            DataLabelType::FallthroughString => "*",
+            DataLabelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4529,8 +5303,8 @@ pub enum RefreshScope {
     DataSourceRefreshScopeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefreshScope {
@@ -4541,7 +5315,7 @@ impl std::fmt::Display for RefreshScope {
                 "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"
             }
             RefreshScope::Noop => "",
-            RefreshScope::FallthroughString => "*",
+            RefreshScope::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4632,8 +5406,8 @@ pub enum DaysOfWeek {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DaysOfWeek {
@@ -4648,7 +5422,7 @@ impl std::fmt::Display for DaysOfWeek {
             DaysOfWeek::Tuesday => "TUESDAY",
             DaysOfWeek::Wednesday => "WEDNESDAY",
             DaysOfWeek::Noop => "",
-            DaysOfWeek::FallthroughString => "*",
+            DaysOfWeek::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4775,8 +5549,8 @@ pub enum ColumnSelectionType {
     SyncAll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColumnSelectionType {
@@ -4788,7 +5562,7 @@ impl std::fmt::Display for ColumnSelectionType {
             ColumnSelectionType::Selected => "SELECTED",
             ColumnSelectionType::SyncAll => "SYNC_ALL",
             ColumnSelectionType::Noop => "",
-            ColumnSelectionType::FallthroughString => "*",
+            ColumnSelectionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4953,8 +5727,8 @@ pub enum DateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRuleType {
@@ -4977,7 +5751,7 @@ impl std::fmt::Display for DateTimeRuleType {
             DateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             DateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             DateTimeRuleType::Noop => "",
-            DateTimeRuleType::FallthroughString => "*",
+            DateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5005,7 +5779,7 @@ pub struct DateTimeRule {
 }
 
 /// Removes the banded range with the given ID from the spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteBandingRequest {
     /**
     * Removes the banded range with the given ID from the spreadsheet.
@@ -5019,8 +5793,31 @@ pub struct DeleteBandingRequest {
     pub banded_range_id: i64,
 }
 
+impl DeleteBandingRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteBandingRequestBuilder {
+        DeleteBandingRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteBandingRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteBandingRequestBuilder(DeleteBandingRequest);
+
+impl DeleteBandingRequestBuilder {
+    pub fn banded_range_id(mut self, banded_range_id: i64) -> Self {
+        self.0.banded_range_id = banded_range_id;
+        self
+    }
+
+    pub fn build(self) -> DeleteBandingRequest {
+        self.0
+    }
+}
+
 /// Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteConditionalFormatRuleRequest {
     /**
     * Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
@@ -5043,6 +5840,34 @@ pub struct DeleteConditionalFormatRuleRequest {
     pub sheet_id: i64,
 }
 
+impl DeleteConditionalFormatRuleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteConditionalFormatRuleRequestBuilder {
+        DeleteConditionalFormatRuleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteConditionalFormatRuleRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteConditionalFormatRuleRequestBuilder(DeleteConditionalFormatRuleRequest);
+
+impl DeleteConditionalFormatRuleRequestBuilder {
+    pub fn index(mut self, index: i64) -> Self {
+        self.0.index = index;
+        self
+    }
+
+    pub fn sheet_id(mut self, sheet_id: i64) -> Self {
+        self.0.sheet_id = sheet_id;
+        self
+    }
+
+    pub fn build(self) -> DeleteConditionalFormatRuleRequest {
+        self.0
+    }
+}
+
 /// The result of deleting a conditional format rule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteConditionalFormatRuleResponse {
@@ -5054,7 +5879,7 @@ pub struct DeleteConditionalFormatRuleResponse {
 }
 
 /// Deletes a data source. The request also deletes the associated data source sheet, and unlinks all associated data source objects.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDataSourceRequest {
     /**
     * Deletes a data source. The request also deletes the associated data source sheet, and unlinks all associated data source objects.
@@ -5068,8 +5893,45 @@ pub struct DeleteDataSourceRequest {
     pub data_source_id: String,
 }
 
+impl std::convert::From<String> for DeleteDataSourceRequest {
+    fn from(data_source_id: String) -> Self {
+        DeleteDataSourceRequest { data_source_id }
+    }
+}
+
+impl std::convert::From<&str> for DeleteDataSourceRequest {
+    fn from(data_source_id: &str) -> Self {
+        DeleteDataSourceRequest {
+            data_source_id: data_source_id.to_string(),
+        }
+    }
+}
+
+impl DeleteDataSourceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteDataSourceRequestBuilder {
+        DeleteDataSourceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteDataSourceRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteDataSourceRequestBuilder(DeleteDataSourceRequest);
+
+impl DeleteDataSourceRequestBuilder {
+    pub fn data_source_id(mut self, data_source_id: impl Into<String>) -> Self {
+        self.0.data_source_id = data_source_id.into();
+        self
+    }
+
+    pub fn build(self) -> DeleteDataSourceRequest {
+        self.0
+    }
+}
+
 /// A request to delete developer metadata.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDeveloperMetadataRequest {
     /**
     * A request to delete developer metadata.
@@ -5082,6 +5944,29 @@ pub struct DeleteDeveloperMetadataRequest {
     pub data_filter: Option<DataFilter>,
 }
 
+impl DeleteDeveloperMetadataRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteDeveloperMetadataRequestBuilder {
+        DeleteDeveloperMetadataRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteDeveloperMetadataRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteDeveloperMetadataRequestBuilder(DeleteDeveloperMetadataRequest);
+
+impl DeleteDeveloperMetadataRequestBuilder {
+    pub fn data_filter(mut self, data_filter: impl Into<DataFilter>) -> Self {
+        self.0.data_filter = Some(data_filter.into());
+        self
+    }
+
+    pub fn build(self) -> DeleteDeveloperMetadataRequest {
+        self.0
+    }
+}
+
 /// The response from deleting developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDeveloperMetadataResponse {
@@ -5098,7 +5983,7 @@ pub struct DeleteDeveloperMetadataResponse {
 }
 
 /// Deletes a group over the specified range by decrementing the depth of the dimensions in the range. For example, assume the sheet has a depth-1 group over B:E and a depth-2 group over C:D. Deleting a group over D:E leaves the sheet with a depth-1 group over B:D and a depth-2 group over C:C.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDimensionGroupRequest {
     /**
     * Deletes a group over the specified range by decrementing the depth of the dimensions in the range. For example, assume the sheet has a depth-1 group over B:E and a depth-2 group over C:D. Deleting a group over D:E leaves the sheet with a depth-1 group over B:D and a depth-2 group over C:C.
@@ -5107,6 +5992,29 @@ pub struct DeleteDimensionGroupRequest {
     pub range: Option<DimensionRange>,
 }
 
+impl DeleteDimensionGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteDimensionGroupRequestBuilder {
+        DeleteDimensionGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteDimensionGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteDimensionGroupRequestBuilder(DeleteDimensionGroupRequest);
+
+impl DeleteDimensionGroupRequestBuilder {
+    pub fn range(mut self, range: impl Into<DimensionRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> DeleteDimensionGroupRequest {
+        self.0
+    }
+}
+
 /// The result of deleting a group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDimensionGroupResponse {
@@ -5123,7 +6031,7 @@ pub struct DeleteDimensionGroupResponse {
 }
 
 /// Deletes the dimensions from the sheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDimensionRequest {
     /**
     * Deletes the dimensions from the sheet.
@@ -5132,8 +6040,31 @@ pub struct DeleteDimensionRequest {
     pub range: Option<DimensionRange>,
 }
 
+impl DeleteDimensionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteDimensionRequestBuilder {
+        DeleteDimensionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteDimensionRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteDimensionRequestBuilder(DeleteDimensionRequest);
+
+impl DeleteDimensionRequestBuilder {
+    pub fn range(mut self, range: impl Into<DimensionRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> DeleteDimensionRequest {
+        self.0
+    }
+}
+
 /// Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDuplicatesRequest {
     /**
     * Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
@@ -5152,6 +6083,34 @@ pub struct DeleteDuplicatesRequest {
     pub range: Option<GridRange>,
 }
 
+impl DeleteDuplicatesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteDuplicatesRequestBuilder {
+        DeleteDuplicatesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteDuplicatesRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteDuplicatesRequestBuilder(DeleteDuplicatesRequest);
+
+impl DeleteDuplicatesRequestBuilder {
+    pub fn comparison_columns(mut self, comparison_columns: Vec<DimensionRange>) -> Self {
+        self.0.comparison_columns = comparison_columns;
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> DeleteDuplicatesRequest {
+        self.0
+    }
+}
+
 /// The result of removing duplicates in a range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDuplicatesResponse {
@@ -5168,7 +6127,7 @@ pub struct DeleteDuplicatesResponse {
 }
 
 /// Deletes the embedded object with the given ID.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteEmbeddedObjectRequest {
     /**
     * Deletes the embedded object with the given ID.
@@ -5182,8 +6141,31 @@ pub struct DeleteEmbeddedObjectRequest {
     pub object_id: i64,
 }
 
+impl DeleteEmbeddedObjectRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteEmbeddedObjectRequestBuilder {
+        DeleteEmbeddedObjectRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteEmbeddedObjectRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteEmbeddedObjectRequestBuilder(DeleteEmbeddedObjectRequest);
+
+impl DeleteEmbeddedObjectRequestBuilder {
+    pub fn object_id(mut self, object_id: i64) -> Self {
+        self.0.object_id = object_id;
+        self
+    }
+
+    pub fn build(self) -> DeleteEmbeddedObjectRequest {
+        self.0
+    }
+}
+
 /// Deletes a particular filter view.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteFilterViewRequest {
     /**
     * Deletes a particular filter view.
@@ -5197,8 +6179,31 @@ pub struct DeleteFilterViewRequest {
     pub filter_id: i64,
 }
 
+impl DeleteFilterViewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteFilterViewRequestBuilder {
+        DeleteFilterViewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteFilterViewRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteFilterViewRequestBuilder(DeleteFilterViewRequest);
+
+impl DeleteFilterViewRequestBuilder {
+    pub fn filter_id(mut self, filter_id: i64) -> Self {
+        self.0.filter_id = filter_id;
+        self
+    }
+
+    pub fn build(self) -> DeleteFilterViewRequest {
+        self.0
+    }
+}
+
 /// Removes the named range with the given ID from the spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteNamedRangeRequest {
     /**
     * Removes the named range with the given ID from the spreadsheet.
@@ -5212,8 +6217,45 @@ pub struct DeleteNamedRangeRequest {
     pub named_range_id: String,
 }
 
+impl std::convert::From<String> for DeleteNamedRangeRequest {
+    fn from(named_range_id: String) -> Self {
+        DeleteNamedRangeRequest { named_range_id }
+    }
+}
+
+impl std::convert::From<&str> for DeleteNamedRangeRequest {
+    fn from(named_range_id: &str) -> Self {
+        DeleteNamedRangeRequest {
+            named_range_id: named_range_id.to_string(),
+        }
+    }
+}
+
+impl DeleteNamedRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteNamedRangeRequestBuilder {
+        DeleteNamedRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteNamedRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteNamedRangeRequestBuilder(DeleteNamedRangeRequest);
+
+impl DeleteNamedRangeRequestBuilder {
+    pub fn named_range_id(mut self, named_range_id: impl Into<String>) -> Self {
+        self.0.named_range_id = named_range_id.into();
+        self
+    }
+
+    pub fn build(self) -> DeleteNamedRangeRequest {
+        self.0
+    }
+}
+
 /// Deletes the protected range with the given ID.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteProtectedRangeRequest {
     /**
     * Deletes the protected range with the given ID.
@@ -5227,8 +6269,31 @@ pub struct DeleteProtectedRangeRequest {
     pub protected_range_id: i64,
 }
 
+impl DeleteProtectedRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteProtectedRangeRequestBuilder {
+        DeleteProtectedRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteProtectedRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteProtectedRangeRequestBuilder(DeleteProtectedRangeRequest);
+
+impl DeleteProtectedRangeRequestBuilder {
+    pub fn protected_range_id(mut self, protected_range_id: i64) -> Self {
+        self.0.protected_range_id = protected_range_id;
+        self
+    }
+
+    pub fn build(self) -> DeleteProtectedRangeRequest {
+        self.0
+    }
+}
+
 /// Deletes a range of cells, shifting other cells into the deleted area.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteRangeRequest {
     /**
     * Deletes a range of cells, shifting other cells into the deleted area.
@@ -5246,8 +6311,36 @@ pub struct DeleteRangeRequest {
     pub shift_dimension: Option<Dimension>,
 }
 
+impl DeleteRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteRangeRequestBuilder {
+        DeleteRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteRangeRequestBuilder(DeleteRangeRequest);
+
+impl DeleteRangeRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn shift_dimension(mut self, shift_dimension: impl Into<Dimension>) -> Self {
+        self.0.shift_dimension = Some(shift_dimension.into());
+        self
+    }
+
+    pub fn build(self) -> DeleteRangeRequest {
+        self.0
+    }
+}
+
 /// Deletes the requested sheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteSheetRequest {
     /**
     * Deletes the requested sheet.
@@ -5261,6 +6354,29 @@ pub struct DeleteSheetRequest {
     pub sheet_id: i64,
 }
 
+impl DeleteSheetRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteSheetRequestBuilder {
+        DeleteSheetRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteSheetRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteSheetRequestBuilder(DeleteSheetRequest);
+
+impl DeleteSheetRequestBuilder {
+    pub fn sheet_id(mut self, sheet_id: i64) -> Self {
+        self.0.sheet_id = sheet_id;
+        self
+    }
+
+    pub fn build(self) -> DeleteSheetRequest {
+        self.0
+    }
+}
+
 /**
 * The metadata visibility. Developer metadata must always have a visibility specified.
 */
@@ -5274,8 +6390,8 @@ pub enum Visibility {
     Project,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -5287,7 +6403,7 @@ impl std::fmt::Display for Visibility {
             Visibility::Document => "DOCUMENT",
             Visibility::Project => "PROJECT",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5366,8 +6482,8 @@ pub enum LocationType {
     Spreadsheet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationType {
@@ -5381,7 +6497,7 @@ impl std::fmt::Display for LocationType {
             LocationType::Sheet => "SHEET",
             LocationType::Spreadsheet => "SPREADSHEET",
             LocationType::Noop => "",
-            LocationType::FallthroughString => "*",
+            LocationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5452,8 +6568,8 @@ pub enum LocationMatchingStrategy {
     IntersectingLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationMatchingStrategy {
@@ -5465,7 +6581,7 @@ impl std::fmt::Display for LocationMatchingStrategy {
             LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
             LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
             LocationMatchingStrategy::Noop => "",
-            LocationMatchingStrategy::FallthroughString => "*",
+            LocationMatchingStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5669,7 +6785,7 @@ pub struct DimensionRange {
 }
 
 /// Duplicates a particular filter view.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateFilterViewRequest {
     /**
     * Duplicates a particular filter view.
@@ -5683,6 +6799,29 @@ pub struct DuplicateFilterViewRequest {
     pub filter_id: i64,
 }
 
+impl DuplicateFilterViewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DuplicateFilterViewRequestBuilder {
+        DuplicateFilterViewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DuplicateFilterViewRequest`.
+#[derive(Debug, Clone)]
+pub struct DuplicateFilterViewRequestBuilder(DuplicateFilterViewRequest);
+
+impl DuplicateFilterViewRequestBuilder {
+    pub fn filter_id(mut self, filter_id: i64) -> Self {
+        self.0.filter_id = filter_id;
+        self
+    }
+
+    pub fn build(self) -> DuplicateFilterViewRequest {
+        self.0
+    }
+}
+
 /// The result of a filter view being duplicated.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateFilterViewResponse {
@@ -5694,7 +6833,7 @@ pub struct DuplicateFilterViewResponse {
 }
 
 /// Duplicates the contents of a sheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateSheetRequest {
     /**
     * Duplicates the contents of a sheet.
@@ -5738,6 +6877,44 @@ pub struct DuplicateSheetRequest {
     pub source_sheet_id: i64,
 }
 
+impl DuplicateSheetRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DuplicateSheetRequestBuilder {
+        DuplicateSheetRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DuplicateSheetRequest`.
+#[derive(Debug, Clone)]
+pub struct DuplicateSheetRequestBuilder(DuplicateSheetRequest);
+
+impl DuplicateSheetRequestBuilder {
+    pub fn insert_sheet_index(mut self, insert_sheet_index: i64) -> Self {
+        self.0.insert_sheet_index = insert_sheet_index;
+        self
+    }
+
+    pub fn new_sheet_id(mut self, new_sheet_id: i64) -> Self {
+        self.0.new_sheet_id = new_sheet_id;
+        self
+    }
+
+    pub fn new_sheet_name(mut self, new_sheet_name: impl Into<String>) -> Self {
+        self.0.new_sheet_name = new_sheet_name.into();
+        self
+    }
+
+    pub fn source_sheet_id(mut self, source_sheet_id: i64) -> Self {
+        self.0.source_sheet_id = source_sheet_id;
+        self
+    }
+
+    pub fn build(self) -> DuplicateSheetRequest {
+        self.0
+    }
+}
+
 /// The result of duplicating a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateSheetResponse {
@@ -5889,8 +7066,8 @@ pub enum ErrorValueType {
     Value,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorValueType {
@@ -5907,7 +7084,7 @@ impl std::fmt::Display for ErrorValueType {
             ErrorValueType::Ref => "REF",
             ErrorValueType::Value => "VALUE",
             ErrorValueType::Noop => "",
-            ErrorValueType::FallthroughString => "*",
+            ErrorValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6150,7 +7327,7 @@ pub struct FilterView {
 }
 
 /// Finds and replaces data in cells over a range, sheet, or all sheets.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FindReplaceRequest {
     /**
     * Finds and replaces data in cells over a range, sheet, or all sheets.
@@ -6224,19 +7401,82 @@ pub struct FindReplaceRequest {
     pub sheet_id: i64,
 }
 
-/// The result of the find/replace.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FindReplaceResponse {
-    /**
-    * The result of the find/replace.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "formulasChanged"
-    )]
-    pub formulas_changed: i64,
+impl FindReplaceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> FindReplaceRequestBuilder {
+        FindReplaceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `FindReplaceRequest`.
+#[derive(Debug, Clone)]
+pub struct FindReplaceRequestBuilder(FindReplaceRequest);
+
+impl FindReplaceRequestBuilder {
+    pub fn all_sheets(mut self, all_sheets: bool) -> Self {
+        self.0.all_sheets = Some(all_sheets);
+        self
+    }
+
+    pub fn find(mut self, find: impl Into<String>) -> Self {
+        self.0.find = find.into();
+        self
+    }
+
+    pub fn include_formulas(mut self, include_formulas: bool) -> Self {
+        self.0.include_formulas = Some(include_formulas);
+        self
+    }
+
+    pub fn match_case(mut self, match_case: bool) -> Self {
+        self.0.match_case = Some(match_case);
+        self
+    }
+
+    pub fn match_entire_cell(mut self, match_entire_cell: bool) -> Self {
+        self.0.match_entire_cell = Some(match_entire_cell);
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn replacement(mut self, replacement: impl Into<String>) -> Self {
+        self.0.replacement = replacement.into();
+        self
+    }
+
+    pub fn search_by_regex(mut self, search_by_regex: bool) -> Self {
+        self.0.search_by_regex = Some(search_by_regex);
+        self
+    }
+
+    pub fn sheet_id(mut self, sheet_id: i64) -> Self {
+        self.0.sheet_id = sheet_id;
+        self
+    }
+
+    pub fn build(self) -> FindReplaceRequest {
+        self.0
+    }
+}
+
+/// The result of the find/replace.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FindReplaceResponse {
+    /**
+    * The result of the find/replace.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "formulasChanged"
+    )]
+    pub formulas_changed: i64,
     /**
     * The result of the find/replace.
     */
@@ -6280,7 +7520,7 @@ pub struct FindReplaceResponse {
 }
 
 /// The request for retrieving a Spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSpreadsheetByDataFilterRequest {
     /**
     * The request for retrieving a Spreadsheet.
@@ -6303,6 +7543,34 @@ pub struct GetSpreadsheetByDataFilterRequest {
     pub include_grid_data: Option<bool>,
 }
 
+impl GetSpreadsheetByDataFilterRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GetSpreadsheetByDataFilterRequestBuilder {
+        GetSpreadsheetByDataFilterRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GetSpreadsheetByDataFilterRequest`.
+#[derive(Debug, Clone)]
+pub struct GetSpreadsheetByDataFilterRequestBuilder(GetSpreadsheetByDataFilterRequest);
+
+impl GetSpreadsheetByDataFilterRequestBuilder {
+    pub fn data_filters(mut self, data_filters: Vec<DataFilter>) -> Self {
+        self.0.data_filters = data_filters;
+        self
+    }
+
+    pub fn include_grid_data(mut self, include_grid_data: bool) -> Self {
+        self.0.include_grid_data = Some(include_grid_data);
+        self
+    }
+
+    pub fn build(self) -> GetSpreadsheetByDataFilterRequest {
+        self.0
+    }
+}
+
 /// A rule that applies a gradient color scale format, based on the interpolation points listed. The format of a cell will vary based on its contents as compared to the values of the interpolation points.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GradientRule {
@@ -6561,8 +7829,8 @@ pub enum HistogramChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HistogramChartSpecLegendPosition {
@@ -6578,7 +7846,7 @@ impl std::fmt::Display for HistogramChartSpecLegendPosition {
             HistogramChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             HistogramChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             HistogramChartSpecLegendPosition::Noop => "",
-            HistogramChartSpecLegendPosition::FallthroughString => "*",
+            HistogramChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6704,7 +7972,7 @@ pub struct HistogramSeries {
 }
 
 /// Inserts rows or columns in a sheet at a particular index.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InsertDimensionRequest {
     /**
     * Inserts rows or columns in a sheet at a particular index.
@@ -6722,8 +7990,36 @@ pub struct InsertDimensionRequest {
     pub range: Option<DimensionRange>,
 }
 
+impl InsertDimensionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> InsertDimensionRequestBuilder {
+        InsertDimensionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `InsertDimensionRequest`.
+#[derive(Debug, Clone)]
+pub struct InsertDimensionRequestBuilder(InsertDimensionRequest);
+
+impl InsertDimensionRequestBuilder {
+    pub fn inherit_from_before(mut self, inherit_from_before: bool) -> Self {
+        self.0.inherit_from_before = Some(inherit_from_before);
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<DimensionRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> InsertDimensionRequest {
+        self.0
+    }
+}
+
 /// Inserts cells into a range, shifting the existing cells over or down.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InsertRangeRequest {
     /**
     * Inserts cells into a range, shifting the existing cells over or down.
@@ -6741,6 +8037,34 @@ pub struct InsertRangeRequest {
     pub shift_dimension: Option<Dimension>,
 }
 
+impl InsertRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> InsertRangeRequestBuilder {
+        InsertRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `InsertRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct InsertRangeRequestBuilder(InsertRangeRequest);
+
+impl InsertRangeRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn shift_dimension(mut self, shift_dimension: impl Into<Dimension>) -> Self {
+        self.0.shift_dimension = Some(shift_dimension.into());
+        self
+    }
+
+    pub fn build(self) -> InsertRangeRequest {
+        self.0
+    }
+}
+
 /**
 * How the value should be interpreted.
 */
@@ -6760,8 +8084,8 @@ pub enum InterpolationPointType {
     Percentile,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterpolationPointType {
@@ -6776,7 +8100,7 @@ impl std::fmt::Display for InterpolationPointType {
             InterpolationPointType::Percent => "PERCENT",
             InterpolationPointType::Percentile => "PERCENTILE",
             InterpolationPointType::Noop => "",
-            InterpolationPointType::FallthroughString => "*",
+            InterpolationPointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6920,8 +8244,8 @@ pub enum LineStyleType {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineStyleType {
@@ -6937,7 +8261,7 @@ impl std::fmt::Display for LineStyleType {
             LineStyleType::MediumDashedDotted => "MEDIUM_DASHED_DOTTED",
             LineStyleType::Solid => "SOLID",
             LineStyleType::Noop => "",
-            LineStyleType::FallthroughString => "*",
+            LineStyleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7081,8 +8405,8 @@ pub enum MergeType {
     MergeRows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeType {
@@ -7092,7 +8416,7 @@ impl std::fmt::Display for MergeType {
             MergeType::MergeColumns => "MERGE_COLUMNS",
             MergeType::MergeRows => "MERGE_ROWS",
             MergeType::Noop => "",
-            MergeType::FallthroughString => "*",
+            MergeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7110,7 +8434,7 @@ impl MergeType {
 }
 
 /// Merges all cells in the range.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MergeCellsRequest {
     /**
     * Merges all cells in the range.
@@ -7124,8 +8448,36 @@ pub struct MergeCellsRequest {
     pub range: Option<GridRange>,
 }
 
+impl MergeCellsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MergeCellsRequestBuilder {
+        MergeCellsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MergeCellsRequest`.
+#[derive(Debug, Clone)]
+pub struct MergeCellsRequestBuilder(MergeCellsRequest);
+
+impl MergeCellsRequestBuilder {
+    pub fn merge_type(mut self, merge_type: impl Into<MergeType>) -> Self {
+        self.0.merge_type = Some(merge_type.into());
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> MergeCellsRequest {
+        self.0
+    }
+}
+
 /// Moves one or more rows or columns.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MoveDimensionRequest {
     /**
     * Moves one or more rows or columns.
@@ -7144,6 +8496,34 @@ pub struct MoveDimensionRequest {
     pub source: Option<DimensionRange>,
 }
 
+impl MoveDimensionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MoveDimensionRequestBuilder {
+        MoveDimensionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MoveDimensionRequest`.
+#[derive(Debug, Clone)]
+pub struct MoveDimensionRequestBuilder(MoveDimensionRequest);
+
+impl MoveDimensionRequestBuilder {
+    pub fn destination_index(mut self, destination_index: i64) -> Self {
+        self.0.destination_index = destination_index;
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<DimensionRange>) -> Self {
+        self.0.source = Some(source.into());
+        self
+    }
+
+    pub fn build(self) -> MoveDimensionRequest {
+        self.0
+    }
+}
+
 /// A named range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NamedRange {
@@ -7198,8 +8578,8 @@ pub enum NumberFormatType {
     Time,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatType {
@@ -7215,7 +8595,7 @@ impl std::fmt::Display for NumberFormatType {
             NumberFormatType::Text => "TEXT",
             NumberFormatType::Time => "TIME",
             NumberFormatType::Noop => "",
-            NumberFormatType::FallthroughString => "*",
+            NumberFormatType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7266,8 +8646,8 @@ pub enum NodeSize {
     Small,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NodeSize {
@@ -7278,7 +8658,7 @@ impl std::fmt::Display for NodeSize {
             NodeSize::OrgChartLabelSizeUnspecified => "ORG_CHART_LABEL_SIZE_UNSPECIFIED",
             NodeSize::Small => "SMALL",
             NodeSize::Noop => "",
-            NodeSize::FallthroughString => "*",
+            NodeSize::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7452,7 +8832,7 @@ pub struct Padding {
 }
 
 /// Inserts data into the spreadsheet starting at the specified coordinate.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasteDataRequest {
     /**
     * Inserts data into the spreadsheet starting at the specified coordinate.
@@ -7489,6 +8869,49 @@ pub struct PasteDataRequest {
     pub type_: Option<PasteType>,
 }
 
+impl PasteDataRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PasteDataRequestBuilder {
+        PasteDataRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PasteDataRequest`.
+#[derive(Debug, Clone)]
+pub struct PasteDataRequestBuilder(PasteDataRequest);
+
+impl PasteDataRequestBuilder {
+    pub fn coordinate(mut self, coordinate: impl Into<GridCoordinate>) -> Self {
+        self.0.coordinate = Some(coordinate.into());
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.0.data = data.into();
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.0.delimiter = delimiter.into();
+        self
+    }
+
+    pub fn html(mut self, html: bool) -> Self {
+        self.0.html = Some(html);
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<PasteType>) -> Self {
+        self.0.type_ = Some(type_.into());
+        self
+    }
+
+    pub fn build(self) -> PasteDataRequest {
+        self.0
+    }
+}
+
 /**
 * Where the legend of the pie chart should be drawn.
 */
@@ -7510,8 +8933,8 @@ pub enum PieChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PieChartSpecLegendPosition {
@@ -7527,7 +8950,7 @@ impl std::fmt::Display for PieChartSpecLegendPosition {
             PieChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             PieChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             PieChartSpecLegendPosition::Noop => "",
-            PieChartSpecLegendPosition::FallthroughString => "*",
+            PieChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7662,8 +9085,8 @@ pub enum SortOrder {
     SortOrderUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -7673,7 +9096,7 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Descending => "DESCENDING",
             SortOrder::SortOrderUnspecified => "SORT_ORDER_UNSPECIFIED",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7889,8 +9312,8 @@ pub enum ValueLayout {
     Vertical,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueLayout {
@@ -7899,7 +9322,7 @@ impl std::fmt::Display for ValueLayout {
             ValueLayout::Horizontal => "HORIZONTAL",
             ValueLayout::Vertical => "VERTICAL",
             ValueLayout::Noop => "",
-            ValueLayout::FallthroughString => "*",
+            ValueLayout::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8011,8 +9434,8 @@ pub enum CalculatedDisplayType {
     PivotValueCalculatedDisplayTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CalculatedDisplayType {
@@ -8025,7 +9448,7 @@ impl std::fmt::Display for CalculatedDisplayType {
                 "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED"
             }
             CalculatedDisplayType::Noop => "",
-            CalculatedDisplayType::FallthroughString => "*",
+            CalculatedDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8079,8 +9502,8 @@ pub enum SummarizeFunction {
     Varp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SummarizeFunction {
@@ -8104,7 +9527,7 @@ impl std::fmt::Display for SummarizeFunction {
             SummarizeFunction::Var => "VAR",
             SummarizeFunction::Varp => "VARP",
             SummarizeFunction::Noop => "",
-            SummarizeFunction::FallthroughString => "*",
+            SummarizeFunction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8206,8 +9629,8 @@ pub enum Shape {
     XMark,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Shape {
@@ -8223,7 +9646,7 @@ impl std::fmt::Display for Shape {
             Shape::Triangle => "TRIANGLE",
             Shape::XMark => "X_MARK",
             Shape::Noop => "",
-            Shape::FallthroughString => "*",
+            Shape::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8332,7 +9755,7 @@ pub struct ProtectedRange {
 }
 
 /// Randomizes the order of the rows in a range.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RandomizeRangeRequest {
     /**
     * Randomizes the order of the rows in a range.
@@ -8341,6 +9764,29 @@ pub struct RandomizeRangeRequest {
     pub range: Option<GridRange>,
 }
 
+impl RandomizeRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> RandomizeRangeRequestBuilder {
+        RandomizeRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `RandomizeRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct RandomizeRangeRequestBuilder(RandomizeRangeRequest);
+
+impl RandomizeRangeRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> RandomizeRangeRequest {
+        self.0
+    }
+}
+
 /// The execution status of refreshing one data source object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RefreshDataSourceObjectExecutionStatus {
@@ -8361,7 +9807,7 @@ pub struct RefreshDataSourceObjectExecutionStatus {
 }
 
 /// Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RefreshDataSourceRequest {
     /**
     * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
@@ -8390,6 +9836,44 @@ pub struct RefreshDataSourceRequest {
     pub references: Option<DataSourceObjectReferences>,
 }
 
+impl RefreshDataSourceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> RefreshDataSourceRequestBuilder {
+        RefreshDataSourceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `RefreshDataSourceRequest`.
+#[derive(Debug, Clone)]
+pub struct RefreshDataSourceRequestBuilder(RefreshDataSourceRequest);
+
+impl RefreshDataSourceRequestBuilder {
+    pub fn data_source_id(mut self, data_source_id: impl Into<String>) -> Self {
+        self.0.data_source_id = data_source_id.into();
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.0.force = Some(force);
+        self
+    }
+
+    pub fn is_all(mut self, is_all: bool) -> Self {
+        self.0.is_all = Some(is_all);
+        self
+    }
+
+    pub fn references(mut self, references: impl Into<DataSourceObjectReferences>) -> Self {
+        self.0.references = Some(references.into());
+        self
+    }
+
+    pub fn build(self) -> RefreshDataSourceRequest {
+        self.0
+    }
+}
+
 /// The response from refreshing one or multiple data source objects.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RefreshDataSourceResponse {
@@ -8405,7 +9889,7 @@ pub struct RefreshDataSourceResponse {
 }
 
 /// Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RepeatCellRequest {
     /**
     * Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
@@ -8428,8 +9912,41 @@ pub struct RepeatCellRequest {
     pub range: Option<GridRange>,
 }
 
+impl RepeatCellRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> RepeatCellRequestBuilder {
+        RepeatCellRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `RepeatCellRequest`.
+#[derive(Debug, Clone)]
+pub struct RepeatCellRequestBuilder(RepeatCellRequest);
+
+impl RepeatCellRequestBuilder {
+    pub fn cell(mut self, cell: impl Into<CellData>) -> Self {
+        self.0.cell = Some(cell.into());
+        self
+    }
+
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> RepeatCellRequest {
+        self.0
+    }
+}
+
 /// A single kind of update to apply to a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Request {
     /**
     * A single kind of update to apply to a spreadsheet.
@@ -8986,105 +10503,544 @@ pub struct Request {
     pub update_spreadsheet_properties: Option<UpdateSpreadsheetPropertiesRequest>,
 }
 
-/// A single response from an update.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Response {
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addBanding"
-    )]
-    pub add_banding: Option<AddBandingResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addChart")]
-    pub add_chart: Option<AddChartResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addDataSource"
-    )]
-    pub add_data_source: Option<AddDataSourceResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addDimensionGroup"
-    )]
-    pub add_dimension_group: Option<AddDimensionGroupResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addFilterView"
-    )]
-    pub add_filter_view: Option<AddFilterViewResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addNamedRange"
-    )]
-    pub add_named_range: Option<AddNamedRangeResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addProtectedRange"
-    )]
-    pub add_protected_range: Option<AddProtectedRangeResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSheet")]
-    pub add_sheet: Option<AddSheetResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSlicer")]
-    pub add_slicer: Option<AddSlicerResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "createDeveloperMetadata"
-    )]
-    pub create_developer_metadata: Option<CreateDeveloperMetadataResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteConditionalFormatRule"
-    )]
-    pub delete_conditional_format_rule: Option<DeleteConditionalFormatRuleResponse>,
-    /**
-    * A single response from an update.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDeveloperMetadata"
-    )]
-    pub delete_developer_metadata: Option<DeleteDeveloperMetadataResponse>,
+impl Request {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `Request`.
+#[derive(Debug, Clone)]
+pub struct RequestBuilder(Request);
+
+impl RequestBuilder {
+    pub fn add_banding(mut self, add_banding: impl Into<AddBandingRequest>) -> Self {
+        self.0.add_banding = Some(add_banding.into());
+        self
+    }
+
+    pub fn add_chart(mut self, add_chart: impl Into<AddChartRequest>) -> Self {
+        self.0.add_chart = Some(add_chart.into());
+        self
+    }
+
+    pub fn add_conditional_format_rule(
+        mut self,
+        add_conditional_format_rule: impl Into<AddConditionalFormatRuleRequest>,
+    ) -> Self {
+        self.0.add_conditional_format_rule = Some(add_conditional_format_rule.into());
+        self
+    }
+
+    pub fn add_data_source(mut self, add_data_source: impl Into<AddDataSourceRequest>) -> Self {
+        self.0.add_data_source = Some(add_data_source.into());
+        self
+    }
+
+    pub fn add_dimension_group(
+        mut self,
+        add_dimension_group: impl Into<AddDimensionGroupRequest>,
+    ) -> Self {
+        self.0.add_dimension_group = Some(add_dimension_group.into());
+        self
+    }
+
+    pub fn add_filter_view(mut self, add_filter_view: impl Into<AddFilterViewRequest>) -> Self {
+        self.0.add_filter_view = Some(add_filter_view.into());
+        self
+    }
+
+    pub fn add_named_range(mut self, add_named_range: impl Into<AddNamedRangeRequest>) -> Self {
+        self.0.add_named_range = Some(add_named_range.into());
+        self
+    }
+
+    pub fn add_protected_range(
+        mut self,
+        add_protected_range: impl Into<AddProtectedRangeRequest>,
+    ) -> Self {
+        self.0.add_protected_range = Some(add_protected_range.into());
+        self
+    }
+
+    pub fn add_sheet(mut self, add_sheet: impl Into<AddSheetRequest>) -> Self {
+        self.0.add_sheet = Some(add_sheet.into());
+        self
+    }
+
+    pub fn add_slicer(mut self, add_slicer: impl Into<AddSlicerRequest>) -> Self {
+        self.0.add_slicer = Some(add_slicer.into());
+        self
+    }
+
+    pub fn append_cells(mut self, append_cells: impl Into<AppendCellsRequest>) -> Self {
+        self.0.append_cells = Some(append_cells.into());
+        self
+    }
+
+    pub fn append_dimension(mut self, append_dimension: impl Into<AppendDimensionRequest>) -> Self {
+        self.0.append_dimension = Some(append_dimension.into());
+        self
+    }
+
+    pub fn auto_fill(mut self, auto_fill: impl Into<AutoFillRequest>) -> Self {
+        self.0.auto_fill = Some(auto_fill.into());
+        self
+    }
+
+    pub fn auto_resize_dimensions(
+        mut self,
+        auto_resize_dimensions: impl Into<AutoResizeDimensionsRequest>,
+    ) -> Self {
+        self.0.auto_resize_dimensions = Some(auto_resize_dimensions.into());
+        self
+    }
+
+    pub fn clear_basic_filter(
+        mut self,
+        clear_basic_filter: impl Into<ClearBasicFilterRequest>,
+    ) -> Self {
+        self.0.clear_basic_filter = Some(clear_basic_filter.into());
+        self
+    }
+
+    pub fn copy_paste(mut self, copy_paste: impl Into<CopyPasteRequest>) -> Self {
+        self.0.copy_paste = Some(copy_paste.into());
+        self
+    }
+
+    pub fn create_developer_metadata(
+        mut self,
+        create_developer_metadata: impl Into<CreateDeveloperMetadataRequest>,
+    ) -> Self {
+        self.0.create_developer_metadata = Some(create_developer_metadata.into());
+        self
+    }
+
+    pub fn cut_paste(mut self, cut_paste: impl Into<CutPasteRequest>) -> Self {
+        self.0.cut_paste = Some(cut_paste.into());
+        self
+    }
+
+    pub fn delete_banding(mut self, delete_banding: impl Into<DeleteBandingRequest>) -> Self {
+        self.0.delete_banding = Some(delete_banding.into());
+        self
+    }
+
+    pub fn delete_conditional_format_rule(
+        mut self,
+        delete_conditional_format_rule: impl Into<DeleteConditionalFormatRuleRequest>,
+    ) -> Self {
+        self.0.delete_conditional_format_rule = Some(delete_conditional_format_rule.into());
+        self
+    }
+
+    pub fn delete_data_source(
+        mut self,
+        delete_data_source: impl Into<DeleteDataSourceRequest>,
+    ) -> Self {
+        self.0.delete_data_source = Some(delete_data_source.into());
+        self
+    }
+
+    pub fn delete_developer_metadata(
+        mut self,
+        delete_developer_metadata: impl Into<DeleteDeveloperMetadataRequest>,
+    ) -> Self {
+        self.0.delete_developer_metadata = Some(delete_developer_metadata.into());
+        self
+    }
+
+    pub fn delete_dimension(mut self, delete_dimension: impl Into<DeleteDimensionRequest>) -> Self {
+        self.0.delete_dimension = Some(delete_dimension.into());
+        self
+    }
+
+    pub fn delete_dimension_group(
+        mut self,
+        delete_dimension_group: impl Into<DeleteDimensionGroupRequest>,
+    ) -> Self {
+        self.0.delete_dimension_group = Some(delete_dimension_group.into());
+        self
+    }
+
+    pub fn delete_duplicates(
+        mut self,
+        delete_duplicates: impl Into<DeleteDuplicatesRequest>,
+    ) -> Self {
+        self.0.delete_duplicates = Some(delete_duplicates.into());
+        self
+    }
+
+    pub fn delete_embedded_object(
+        mut self,
+        delete_embedded_object: impl Into<DeleteEmbeddedObjectRequest>,
+    ) -> Self {
+        self.0.delete_embedded_object = Some(delete_embedded_object.into());
+        self
+    }
+
+    pub fn delete_filter_view(
+        mut self,
+        delete_filter_view: impl Into<DeleteFilterViewRequest>,
+    ) -> Self {
+        self.0.delete_filter_view = Some(delete_filter_view.into());
+        self
+    }
+
+    pub fn delete_named_range(
+        mut self,
+        delete_named_range: impl Into<DeleteNamedRangeRequest>,
+    ) -> Self {
+        self.0.delete_named_range = Some(delete_named_range.into());
+        self
+    }
+
+    pub fn delete_protected_range(
+        mut self,
+        delete_protected_range: impl Into<DeleteProtectedRangeRequest>,
+    ) -> Self {
+        self.0.delete_protected_range = Some(delete_protected_range.into());
+        self
+    }
+
+    pub fn delete_range(mut self, delete_range: impl Into<DeleteRangeRequest>) -> Self {
+        self.0.delete_range = Some(delete_range.into());
+        self
+    }
+
+    pub fn delete_sheet(mut self, delete_sheet: impl Into<DeleteSheetRequest>) -> Self {
+        self.0.delete_sheet = Some(delete_sheet.into());
+        self
+    }
+
+    pub fn duplicate_filter_view(
+        mut self,
+        duplicate_filter_view: impl Into<DuplicateFilterViewRequest>,
+    ) -> Self {
+        self.0.duplicate_filter_view = Some(duplicate_filter_view.into());
+        self
+    }
+
+    pub fn duplicate_sheet(mut self, duplicate_sheet: impl Into<DuplicateSheetRequest>) -> Self {
+        self.0.duplicate_sheet = Some(duplicate_sheet.into());
+        self
+    }
+
+    pub fn find_replace(mut self, find_replace: impl Into<FindReplaceRequest>) -> Self {
+        self.0.find_replace = Some(find_replace.into());
+        self
+    }
+
+    pub fn insert_dimension(mut self, insert_dimension: impl Into<InsertDimensionRequest>) -> Self {
+        self.0.insert_dimension = Some(insert_dimension.into());
+        self
+    }
+
+    pub fn insert_range(mut self, insert_range: impl Into<InsertRangeRequest>) -> Self {
+        self.0.insert_range = Some(insert_range.into());
+        self
+    }
+
+    pub fn merge_cells(mut self, merge_cells: impl Into<MergeCellsRequest>) -> Self {
+        self.0.merge_cells = Some(merge_cells.into());
+        self
+    }
+
+    pub fn move_dimension(mut self, move_dimension: impl Into<MoveDimensionRequest>) -> Self {
+        self.0.move_dimension = Some(move_dimension.into());
+        self
+    }
+
+    pub fn paste_data(mut self, paste_data: impl Into<PasteDataRequest>) -> Self {
+        self.0.paste_data = Some(paste_data.into());
+        self
+    }
+
+    pub fn randomize_range(mut self, randomize_range: impl Into<RandomizeRangeRequest>) -> Self {
+        self.0.randomize_range = Some(randomize_range.into());
+        self
+    }
+
+    pub fn refresh_data_source(
+        mut self,
+        refresh_data_source: impl Into<RefreshDataSourceRequest>,
+    ) -> Self {
+        self.0.refresh_data_source = Some(refresh_data_source.into());
+        self
+    }
+
+    pub fn repeat_cell(mut self, repeat_cell: impl Into<RepeatCellRequest>) -> Self {
+        self.0.repeat_cell = Some(repeat_cell.into());
+        self
+    }
+
+    pub fn set_basic_filter(mut self, set_basic_filter: impl Into<SetBasicFilterRequest>) -> Self {
+        self.0.set_basic_filter = Some(set_basic_filter.into());
+        self
+    }
+
+    pub fn set_data_validation(
+        mut self,
+        set_data_validation: impl Into<SetDataValidationRequest>,
+    ) -> Self {
+        self.0.set_data_validation = Some(set_data_validation.into());
+        self
+    }
+
+    pub fn sort_range(mut self, sort_range: impl Into<SortRangeRequest>) -> Self {
+        self.0.sort_range = Some(sort_range.into());
+        self
+    }
+
+    pub fn text_to_columns(mut self, text_to_columns: impl Into<TextColumnsRequest>) -> Self {
+        self.0.text_to_columns = Some(text_to_columns.into());
+        self
+    }
+
+    pub fn trim_whitespace(mut self, trim_whitespace: impl Into<TrimWhitespaceRequest>) -> Self {
+        self.0.trim_whitespace = Some(trim_whitespace.into());
+        self
+    }
+
+    pub fn unmerge_cells(mut self, unmerge_cells: impl Into<UnmergeCellsRequest>) -> Self {
+        self.0.unmerge_cells = Some(unmerge_cells.into());
+        self
+    }
+
+    pub fn update_banding(mut self, update_banding: impl Into<UpdateBandingRequest>) -> Self {
+        self.0.update_banding = Some(update_banding.into());
+        self
+    }
+
+    pub fn update_borders(mut self, update_borders: impl Into<UpdateBordersRequest>) -> Self {
+        self.0.update_borders = Some(update_borders.into());
+        self
+    }
+
+    pub fn update_cells(mut self, update_cells: impl Into<UpdateCellsRequest>) -> Self {
+        self.0.update_cells = Some(update_cells.into());
+        self
+    }
+
+    pub fn update_chart_spec(
+        mut self,
+        update_chart_spec: impl Into<UpdateChartSpecRequest>,
+    ) -> Self {
+        self.0.update_chart_spec = Some(update_chart_spec.into());
+        self
+    }
+
+    pub fn update_conditional_format_rule(
+        mut self,
+        update_conditional_format_rule: impl Into<UpdateConditionalFormatRuleRequest>,
+    ) -> Self {
+        self.0.update_conditional_format_rule = Some(update_conditional_format_rule.into());
+        self
+    }
+
+    pub fn update_data_source(
+        mut self,
+        update_data_source: impl Into<UpdateDataSourceRequest>,
+    ) -> Self {
+        self.0.update_data_source = Some(update_data_source.into());
+        self
+    }
+
+    pub fn update_developer_metadata(
+        mut self,
+        update_developer_metadata: impl Into<UpdateDeveloperMetadataRequest>,
+    ) -> Self {
+        self.0.update_developer_metadata = Some(update_developer_metadata.into());
+        self
+    }
+
+    pub fn update_dimension_group(
+        mut self,
+        update_dimension_group: impl Into<UpdateDimensionGroupRequest>,
+    ) -> Self {
+        self.0.update_dimension_group = Some(update_dimension_group.into());
+        self
+    }
+
+    pub fn update_dimension_properties(
+        mut self,
+        update_dimension_properties: impl Into<UpdateDimensionPropertiesRequest>,
+    ) -> Self {
+        self.0.update_dimension_properties = Some(update_dimension_properties.into());
+        self
+    }
+
+    pub fn update_embedded_object_border(
+        mut self,
+        update_embedded_object_border: impl Into<UpdateEmbeddedObjectBorderRequest>,
+    ) -> Self {
+        self.0.update_embedded_object_border = Some(update_embedded_object_border.into());
+        self
+    }
+
+    pub fn update_embedded_object_position(
+        mut self,
+        update_embedded_object_position: impl Into<UpdateEmbeddedObjectPositionRequest>,
+    ) -> Self {
+        self.0.update_embedded_object_position = Some(update_embedded_object_position.into());
+        self
+    }
+
+    pub fn update_filter_view(
+        mut self,
+        update_filter_view: impl Into<UpdateFilterViewRequest>,
+    ) -> Self {
+        self.0.update_filter_view = Some(update_filter_view.into());
+        self
+    }
+
+    pub fn update_named_range(
+        mut self,
+        update_named_range: impl Into<UpdateNamedRangeRequest>,
+    ) -> Self {
+        self.0.update_named_range = Some(update_named_range.into());
+        self
+    }
+
+    pub fn update_protected_range(
+        mut self,
+        update_protected_range: impl Into<UpdateProtectedRangeRequest>,
+    ) -> Self {
+        self.0.update_protected_range = Some(update_protected_range.into());
+        self
+    }
+
+    pub fn update_sheet_properties(
+        mut self,
+        update_sheet_properties: impl Into<UpdateSheetPropertiesRequest>,
+    ) -> Self {
+        self.0.update_sheet_properties = Some(update_sheet_properties.into());
+        self
+    }
+
+    pub fn update_slicer_spec(
+        mut self,
+        update_slicer_spec: impl Into<UpdateSlicerSpecRequest>,
+    ) -> Self {
+        self.0.update_slicer_spec = Some(update_slicer_spec.into());
+        self
+    }
+
+    pub fn update_spreadsheet_properties(
+        mut self,
+        update_spreadsheet_properties: impl Into<UpdateSpreadsheetPropertiesRequest>,
+    ) -> Self {
+        self.0.update_spreadsheet_properties = Some(update_spreadsheet_properties.into());
+        self
+    }
+
+    pub fn build(self) -> Request {
+        self.0
+    }
+}
+
+/// A single response from an update.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Response {
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addBanding"
+    )]
+    pub add_banding: Option<AddBandingResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addChart")]
+    pub add_chart: Option<AddChartResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addDataSource"
+    )]
+    pub add_data_source: Option<AddDataSourceResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addDimensionGroup"
+    )]
+    pub add_dimension_group: Option<AddDimensionGroupResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addFilterView"
+    )]
+    pub add_filter_view: Option<AddFilterViewResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addNamedRange"
+    )]
+    pub add_named_range: Option<AddNamedRangeResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addProtectedRange"
+    )]
+    pub add_protected_range: Option<AddProtectedRangeResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSheet")]
+    pub add_sheet: Option<AddSheetResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSlicer")]
+    pub add_slicer: Option<AddSlicerResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "createDeveloperMetadata"
+    )]
+    pub create_developer_metadata: Option<CreateDeveloperMetadataResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "deleteConditionalFormatRule"
+    )]
+    pub delete_conditional_format_rule: Option<DeleteConditionalFormatRuleResponse>,
+    /**
+    * A single response from an update.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "deleteDeveloperMetadata"
+    )]
+    pub delete_developer_metadata: Option<DeleteDeveloperMetadataResponse>,
     /**
     * A single response from an update.
     */
@@ -9213,8 +11169,8 @@ pub enum NumberFormatSource {
     FromData,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatSource {
@@ -9226,7 +11182,7 @@ impl std::fmt::Display for NumberFormatSource {
             NumberFormatSource::Custom => "CUSTOM",
             NumberFormatSource::FromData => "FROM_DATA",
             NumberFormatSource::Noop => "",
-            NumberFormatSource::FallthroughString => "*",
+            NumberFormatSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9322,7 +11278,7 @@ pub struct ScorecardChartSpec {
 }
 
 /// A request to retrieve all developer metadata matching the set of specified criteria.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchDeveloperMetadataRequest {
     /**
     * A request to retrieve all developer metadata matching the set of specified criteria.
@@ -9336,6 +11292,29 @@ pub struct SearchDeveloperMetadataRequest {
     pub data_filters: Vec<DataFilter>,
 }
 
+impl SearchDeveloperMetadataRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SearchDeveloperMetadataRequestBuilder {
+        SearchDeveloperMetadataRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SearchDeveloperMetadataRequest`.
+#[derive(Debug, Clone)]
+pub struct SearchDeveloperMetadataRequestBuilder(SearchDeveloperMetadataRequest);
+
+impl SearchDeveloperMetadataRequestBuilder {
+    pub fn data_filters(mut self, data_filters: Vec<DataFilter>) -> Self {
+        self.0.data_filters = data_filters;
+        self
+    }
+
+    pub fn build(self) -> SearchDeveloperMetadataRequest {
+        self.0
+    }
+}
+
 /// A reply to a developer metadata search request.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchDeveloperMetadataResponse {
@@ -9352,7 +11331,7 @@ pub struct SearchDeveloperMetadataResponse {
 }
 
 /// Sets the basic filter associated with a sheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetBasicFilterRequest {
     /**
     * Sets the basic filter associated with a sheet.
@@ -9361,8 +11340,31 @@ pub struct SetBasicFilterRequest {
     pub filter: Option<BasicFilter>,
 }
 
+impl SetBasicFilterRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SetBasicFilterRequestBuilder {
+        SetBasicFilterRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SetBasicFilterRequest`.
+#[derive(Debug, Clone)]
+pub struct SetBasicFilterRequestBuilder(SetBasicFilterRequest);
+
+impl SetBasicFilterRequestBuilder {
+    pub fn filter(mut self, filter: impl Into<BasicFilter>) -> Self {
+        self.0.filter = Some(filter.into());
+        self
+    }
+
+    pub fn build(self) -> SetBasicFilterRequest {
+        self.0
+    }
+}
+
 /// Sets a data validation rule to every cell in the range. To clear validation in a range, call this with no rule specified.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetDataValidationRequest {
     /**
     * Sets a data validation rule to every cell in the range. To clear validation in a range, call this with no rule specified.
@@ -9376,6 +11378,34 @@ pub struct SetDataValidationRequest {
     pub rule: Option<DataValidationRule>,
 }
 
+impl SetDataValidationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SetDataValidationRequestBuilder {
+        SetDataValidationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SetDataValidationRequest`.
+#[derive(Debug, Clone)]
+pub struct SetDataValidationRequestBuilder(SetDataValidationRequest);
+
+impl SetDataValidationRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn rule(mut self, rule: impl Into<DataValidationRule>) -> Self {
+        self.0.rule = Some(rule.into());
+        self
+    }
+
+    pub fn build(self) -> SetDataValidationRequest {
+        self.0
+    }
+}
+
 /// A sheet in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Sheet {
@@ -9516,8 +11546,8 @@ pub enum SheetType {
     SheetTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SheetType {
@@ -9528,7 +11558,7 @@ impl std::fmt::Display for SheetType {
             SheetType::Object => "OBJECT",
             SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
             SheetType::Noop => "",
-            SheetType::FallthroughString => "*",
+            SheetType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9741,7 +11771,7 @@ pub struct SlicerSpec {
 }
 
 /// Sorts data in rows based on a sort order per column.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SortRangeRequest {
     /**
     * Sorts data in rows based on a sort order per column.
@@ -9760,6 +11790,34 @@ pub struct SortRangeRequest {
     pub sort_specs: Vec<SortSpec>,
 }
 
+impl SortRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SortRangeRequestBuilder {
+        SortRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SortRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct SortRangeRequestBuilder(SortRangeRequest);
+
+impl SortRangeRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn sort_specs(mut self, sort_specs: Vec<SortSpec>) -> Self {
+        self.0.sort_specs = sort_specs;
+        self
+    }
+
+    pub fn build(self) -> SortRangeRequest {
+        self.0
+    }
+}
+
 /// A sort order associated with a specific column or row.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SortSpec {
@@ -9944,8 +12002,8 @@ pub enum AutoRecalc {
     RecalculationIntervalUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecalc {
@@ -9956,7 +12014,7 @@ impl std::fmt::Display for AutoRecalc {
             AutoRecalc::OnChange => "ON_CHANGE",
             AutoRecalc::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
             AutoRecalc::Noop => "",
-            AutoRecalc::FallthroughString => "*",
+            AutoRecalc::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10224,8 +12282,8 @@ pub enum DelimiterType {
     Space,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelimiterType {
@@ -10239,7 +12297,7 @@ impl std::fmt::Display for DelimiterType {
             DelimiterType::Semicolon => "SEMICOLON",
             DelimiterType::Space => "SPACE",
             DelimiterType::Noop => "",
-            DelimiterType::FallthroughString => "*",
+            DelimiterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10257,7 +12315,7 @@ impl DelimiterType {
 }
 
 /// Splits a column of text into multiple columns, based on a delimiter in each cell.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TextColumnsRequest {
     /**
     * Splits a column of text into multiple columns, based on a delimiter in each cell.
@@ -10284,6 +12342,39 @@ pub struct TextColumnsRequest {
     pub source: Option<GridRange>,
 }
 
+impl TextColumnsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> TextColumnsRequestBuilder {
+        TextColumnsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `TextColumnsRequest`.
+#[derive(Debug, Clone)]
+pub struct TextColumnsRequestBuilder(TextColumnsRequest);
+
+impl TextColumnsRequestBuilder {
+    pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.0.delimiter = delimiter.into();
+        self
+    }
+
+    pub fn delimiter_type(mut self, delimiter_type: impl Into<DelimiterType>) -> Self {
+        self.0.delimiter_type = Some(delimiter_type.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<GridRange>) -> Self {
+        self.0.source = Some(source.into());
+        self
+    }
+
+    pub fn build(self) -> TextColumnsRequest {
+        self.0
+    }
+}
+
 /// A pair mapping a spreadsheet theme color type to the concrete color it represents.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ThemeColorPair {
@@ -10531,7 +12622,7 @@ pub struct TreemapChartSpec {
 }
 
 /// Trims the whitespace (such as spaces, tabs, or new lines) in every cell in the specified range. This request removes all whitespace from the start and end of each cell's text, and reduces any subsequence of remaining whitespace characters to a single space. If the resulting trimmed text starts with a '+' or '=' character, the text remains as a string value and isn't interpreted as a formula.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TrimWhitespaceRequest {
     /**
     * Trims the whitespace (such as spaces, tabs, or new lines) in every cell in the specified range. This request removes all whitespace from the start and end of each cell's text, and reduces any subsequence of remaining whitespace characters to a single space. If the resulting trimmed text starts with a '+' or '=' character, the text remains as a string value and isn't interpreted as a formula.
@@ -10540,6 +12631,29 @@ pub struct TrimWhitespaceRequest {
     pub range: Option<GridRange>,
 }
 
+impl TrimWhitespaceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> TrimWhitespaceRequestBuilder {
+        TrimWhitespaceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `TrimWhitespaceRequest`.
+#[derive(Debug, Clone)]
+pub struct TrimWhitespaceRequestBuilder(TrimWhitespaceRequest);
+
+impl TrimWhitespaceRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> TrimWhitespaceRequest {
+        self.0
+    }
+}
+
 /// The result of trimming whitespace in cells.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TrimWhitespaceResponse {
@@ -10556,7 +12670,7 @@ pub struct TrimWhitespaceResponse {
 }
 
 /// Unmerges cells in the given range.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UnmergeCellsRequest {
     /**
     * Unmerges cells in the given range.
@@ -10565,8 +12679,31 @@ pub struct UnmergeCellsRequest {
     pub range: Option<GridRange>,
 }
 
+impl UnmergeCellsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UnmergeCellsRequestBuilder {
+        UnmergeCellsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UnmergeCellsRequest`.
+#[derive(Debug, Clone)]
+pub struct UnmergeCellsRequestBuilder(UnmergeCellsRequest);
+
+impl UnmergeCellsRequestBuilder {
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> UnmergeCellsRequest {
+        self.0
+    }
+}
+
 /// Updates properties of the supplied banded range.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateBandingRequest {
     /**
     * Updates properties of the supplied banded range.
@@ -10588,8 +12725,36 @@ pub struct UpdateBandingRequest {
     pub fields: String,
 }
 
+impl UpdateBandingRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateBandingRequestBuilder {
+        UpdateBandingRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateBandingRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateBandingRequestBuilder(UpdateBandingRequest);
+
+impl UpdateBandingRequestBuilder {
+    pub fn banded_range(mut self, banded_range: impl Into<BandedRange>) -> Self {
+        self.0.banded_range = Some(banded_range.into());
+        self
+    }
+
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateBandingRequest {
+        self.0
+    }
+}
+
 /// Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateBordersRequest {
     /**
     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
@@ -10636,8 +12801,61 @@ pub struct UpdateBordersRequest {
     pub top: Option<Border>,
 }
 
+impl UpdateBordersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateBordersRequestBuilder {
+        UpdateBordersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateBordersRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateBordersRequestBuilder(UpdateBordersRequest);
+
+impl UpdateBordersRequestBuilder {
+    pub fn bottom(mut self, bottom: impl Into<Border>) -> Self {
+        self.0.bottom = Some(bottom.into());
+        self
+    }
+
+    pub fn inner_horizontal(mut self, inner_horizontal: impl Into<Border>) -> Self {
+        self.0.inner_horizontal = Some(inner_horizontal.into());
+        self
+    }
+
+    pub fn inner_vertical(mut self, inner_vertical: impl Into<Border>) -> Self {
+        self.0.inner_vertical = Some(inner_vertical.into());
+        self
+    }
+
+    pub fn left(mut self, left: impl Into<Border>) -> Self {
+        self.0.left = Some(left.into());
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn right(mut self, right: impl Into<Border>) -> Self {
+        self.0.right = Some(right.into());
+        self
+    }
+
+    pub fn top(mut self, top: impl Into<Border>) -> Self {
+        self.0.top = Some(top.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateBordersRequest {
+        self.0
+    }
+}
+
 /// Updates all cells in a range with new data.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateCellsRequest {
     /**
     * Updates all cells in a range with new data.
@@ -10669,8 +12887,46 @@ pub struct UpdateCellsRequest {
     pub start: Option<GridCoordinate>,
 }
 
+impl UpdateCellsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateCellsRequestBuilder {
+        UpdateCellsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateCellsRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateCellsRequestBuilder(UpdateCellsRequest);
+
+impl UpdateCellsRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<GridRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn rows(mut self, rows: Vec<RowData>) -> Self {
+        self.0.rows = rows;
+        self
+    }
+
+    pub fn start(mut self, start: impl Into<GridCoordinate>) -> Self {
+        self.0.start = Some(start.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateCellsRequest {
+        self.0
+    }
+}
+
 /// Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateChartSpecRequest {
     /**
     * Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
@@ -10689,8 +12945,36 @@ pub struct UpdateChartSpecRequest {
     pub spec: Option<ChartSpec>,
 }
 
+impl UpdateChartSpecRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateChartSpecRequestBuilder {
+        UpdateChartSpecRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateChartSpecRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateChartSpecRequestBuilder(UpdateChartSpecRequest);
+
+impl UpdateChartSpecRequestBuilder {
+    pub fn chart_id(mut self, chart_id: i64) -> Self {
+        self.0.chart_id = chart_id;
+        self
+    }
+
+    pub fn spec(mut self, spec: impl Into<ChartSpec>) -> Self {
+        self.0.spec = Some(spec.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateChartSpecRequest {
+        self.0
+    }
+}
+
 /// Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateConditionalFormatRuleRequest {
     /**
     * Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
@@ -10728,6 +13012,44 @@ pub struct UpdateConditionalFormatRuleRequest {
     pub sheet_id: i64,
 }
 
+impl UpdateConditionalFormatRuleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateConditionalFormatRuleRequestBuilder {
+        UpdateConditionalFormatRuleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateConditionalFormatRuleRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateConditionalFormatRuleRequestBuilder(UpdateConditionalFormatRuleRequest);
+
+impl UpdateConditionalFormatRuleRequestBuilder {
+    pub fn index(mut self, index: i64) -> Self {
+        self.0.index = index;
+        self
+    }
+
+    pub fn new_index(mut self, new_index: i64) -> Self {
+        self.0.new_index = new_index;
+        self
+    }
+
+    pub fn rule(mut self, rule: impl Into<ConditionalFormatRule>) -> Self {
+        self.0.rule = Some(rule.into());
+        self
+    }
+
+    pub fn sheet_id(mut self, sheet_id: i64) -> Self {
+        self.0.sheet_id = sheet_id;
+        self
+    }
+
+    pub fn build(self) -> UpdateConditionalFormatRuleRequest {
+        self.0
+    }
+}
+
 /// The result of updating a conditional format rule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateConditionalFormatRuleResponse {
@@ -10764,7 +13086,7 @@ pub struct UpdateConditionalFormatRuleResponse {
 }
 
 /// Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDataSourceRequest {
     /**
     * Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
@@ -10786,6 +13108,34 @@ pub struct UpdateDataSourceRequest {
     pub fields: String,
 }
 
+impl UpdateDataSourceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateDataSourceRequestBuilder {
+        UpdateDataSourceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateDataSourceRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateDataSourceRequestBuilder(UpdateDataSourceRequest);
+
+impl UpdateDataSourceRequestBuilder {
+    pub fn data_source(mut self, data_source: impl Into<DataSource>) -> Self {
+        self.0.data_source = Some(data_source.into());
+        self
+    }
+
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateDataSourceRequest {
+        self.0
+    }
+}
+
 /// The response from updating data source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDataSourceResponse {
@@ -10810,7 +13160,7 @@ pub struct UpdateDataSourceResponse {
 }
 
 /// A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDeveloperMetadataRequest {
     /**
     * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
@@ -10842,6 +13192,39 @@ pub struct UpdateDeveloperMetadataRequest {
     pub fields: String,
 }
 
+impl UpdateDeveloperMetadataRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateDeveloperMetadataRequestBuilder {
+        UpdateDeveloperMetadataRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateDeveloperMetadataRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateDeveloperMetadataRequestBuilder(UpdateDeveloperMetadataRequest);
+
+impl UpdateDeveloperMetadataRequestBuilder {
+    pub fn data_filters(mut self, data_filters: Vec<DataFilter>) -> Self {
+        self.0.data_filters = data_filters;
+        self
+    }
+
+    pub fn developer_metadata(mut self, developer_metadata: impl Into<DeveloperMetadata>) -> Self {
+        self.0.developer_metadata = Some(developer_metadata.into());
+        self
+    }
+
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateDeveloperMetadataRequest {
+        self.0
+    }
+}
+
 /// The response from updating developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDeveloperMetadataResponse {
@@ -10858,7 +13241,7 @@ pub struct UpdateDeveloperMetadataResponse {
 }
 
 /// Updates the state of the specified group.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDimensionGroupRequest {
     /**
     * Updates the state of the specified group.
@@ -10880,8 +13263,36 @@ pub struct UpdateDimensionGroupRequest {
     pub fields: String,
 }
 
+impl UpdateDimensionGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateDimensionGroupRequestBuilder {
+        UpdateDimensionGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateDimensionGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateDimensionGroupRequestBuilder(UpdateDimensionGroupRequest);
+
+impl UpdateDimensionGroupRequestBuilder {
+    pub fn dimension_group(mut self, dimension_group: impl Into<DimensionGroup>) -> Self {
+        self.0.dimension_group = Some(dimension_group.into());
+        self
+    }
+
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateDimensionGroupRequest {
+        self.0
+    }
+}
+
 /// Updates properties of dimensions within the specified range.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDimensionPropertiesRequest {
     /**
     * Updates properties of dimensions within the specified range.
@@ -10913,8 +13324,49 @@ pub struct UpdateDimensionPropertiesRequest {
     pub range: Option<DimensionRange>,
 }
 
+impl UpdateDimensionPropertiesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateDimensionPropertiesRequestBuilder {
+        UpdateDimensionPropertiesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateDimensionPropertiesRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateDimensionPropertiesRequestBuilder(UpdateDimensionPropertiesRequest);
+
+impl UpdateDimensionPropertiesRequestBuilder {
+    pub fn data_source_sheet_range(
+        mut self,
+        data_source_sheet_range: impl Into<DataSourceSheetDimensionRange>,
+    ) -> Self {
+        self.0.data_source_sheet_range = Some(data_source_sheet_range.into());
+        self
+    }
+
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn properties(mut self, properties: impl Into<DimensionProperties>) -> Self {
+        self.0.properties = Some(properties.into());
+        self
+    }
+
+    pub fn range(mut self, range: impl Into<DimensionRange>) -> Self {
+        self.0.range = Some(range.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateDimensionPropertiesRequest {
+        self.0
+    }
+}
+
 /// Updates an embedded object's border property.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateEmbeddedObjectBorderRequest {
     /**
     * Updates an embedded object's border property.
@@ -10942,8 +13394,41 @@ pub struct UpdateEmbeddedObjectBorderRequest {
     pub object_id: i64,
 }
 
+impl UpdateEmbeddedObjectBorderRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateEmbeddedObjectBorderRequestBuilder {
+        UpdateEmbeddedObjectBorderRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateEmbeddedObjectBorderRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateEmbeddedObjectBorderRequestBuilder(UpdateEmbeddedObjectBorderRequest);
+
+impl UpdateEmbeddedObjectBorderRequestBuilder {
+    pub fn border(mut self, border: impl Into<EmbeddedObjectBorder>) -> Self {
+        self.0.border = Some(border.into());
+        self
+    }
+
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn object_id(mut self, object_id: i64) -> Self {
+        self.0.object_id = object_id;
+        self
+    }
+
+    pub fn build(self) -> UpdateEmbeddedObjectBorderRequest {
+        self.0
+    }
+}
+
 /// Update an embedded object's position (such as a moving or resizing a chart or image).
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateEmbeddedObjectPositionRequest {
     /**
     * Update an embedded object's position (such as a moving or resizing a chart or image).
@@ -10975,6 +13460,39 @@ pub struct UpdateEmbeddedObjectPositionRequest {
     pub object_id: i64,
 }
 
+impl UpdateEmbeddedObjectPositionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateEmbeddedObjectPositionRequestBuilder {
+        UpdateEmbeddedObjectPositionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateEmbeddedObjectPositionRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateEmbeddedObjectPositionRequestBuilder(UpdateEmbeddedObjectPositionRequest);
+
+impl UpdateEmbeddedObjectPositionRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn new_position(mut self, new_position: impl Into<EmbeddedObjectPosition>) -> Self {
+        self.0.new_position = Some(new_position.into());
+        self
+    }
+
+    pub fn object_id(mut self, object_id: i64) -> Self {
+        self.0.object_id = object_id;
+        self
+    }
+
+    pub fn build(self) -> UpdateEmbeddedObjectPositionRequest {
+        self.0
+    }
+}
+
 /// The result of updating an embedded object's position.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateEmbeddedObjectPositionResponse {
@@ -10986,7 +13504,7 @@ pub struct UpdateEmbeddedObjectPositionResponse {
 }
 
 /// Updates properties of the filter view.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateFilterViewRequest {
     /**
     * Updates properties of the filter view.
@@ -11004,8 +13522,36 @@ pub struct UpdateFilterViewRequest {
     pub filter: Option<FilterView>,
 }
 
+impl UpdateFilterViewRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateFilterViewRequestBuilder {
+        UpdateFilterViewRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateFilterViewRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateFilterViewRequestBuilder(UpdateFilterViewRequest);
+
+impl UpdateFilterViewRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn filter(mut self, filter: impl Into<FilterView>) -> Self {
+        self.0.filter = Some(filter.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateFilterViewRequest {
+        self.0
+    }
+}
+
 /// Updates properties of the named range with the specified namedRangeId.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateNamedRangeRequest {
     /**
     * Updates properties of the named range with the specified namedRangeId.
@@ -11027,8 +13573,36 @@ pub struct UpdateNamedRangeRequest {
     pub named_range: Option<NamedRange>,
 }
 
+impl UpdateNamedRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateNamedRangeRequestBuilder {
+        UpdateNamedRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateNamedRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateNamedRangeRequestBuilder(UpdateNamedRangeRequest);
+
+impl UpdateNamedRangeRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn named_range(mut self, named_range: impl Into<NamedRange>) -> Self {
+        self.0.named_range = Some(named_range.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateNamedRangeRequest {
+        self.0
+    }
+}
+
 /// Updates an existing protected range with the specified protectedRangeId.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateProtectedRangeRequest {
     /**
     * Updates an existing protected range with the specified protectedRangeId.
@@ -11050,8 +13624,36 @@ pub struct UpdateProtectedRangeRequest {
     pub protected_range: Option<ProtectedRange>,
 }
 
+impl UpdateProtectedRangeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateProtectedRangeRequestBuilder {
+        UpdateProtectedRangeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateProtectedRangeRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateProtectedRangeRequestBuilder(UpdateProtectedRangeRequest);
+
+impl UpdateProtectedRangeRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn protected_range(mut self, protected_range: impl Into<ProtectedRange>) -> Self {
+        self.0.protected_range = Some(protected_range.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateProtectedRangeRequest {
+        self.0
+    }
+}
+
 /// Updates properties of the sheet with the specified sheetId.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSheetPropertiesRequest {
     /**
     * Updates properties of the sheet with the specified sheetId.
@@ -11069,8 +13671,36 @@ pub struct UpdateSheetPropertiesRequest {
     pub properties: Option<SheetProperties>,
 }
 
+impl UpdateSheetPropertiesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateSheetPropertiesRequestBuilder {
+        UpdateSheetPropertiesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateSheetPropertiesRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateSheetPropertiesRequestBuilder(UpdateSheetPropertiesRequest);
+
+impl UpdateSheetPropertiesRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn properties(mut self, properties: impl Into<SheetProperties>) -> Self {
+        self.0.properties = Some(properties.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateSheetPropertiesRequest {
+        self.0
+    }
+}
+
 /// Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSlicerSpecRequest {
     /**
     * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
@@ -11098,8 +13728,41 @@ pub struct UpdateSlicerSpecRequest {
     pub spec: Option<SlicerSpec>,
 }
 
+impl UpdateSlicerSpecRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateSlicerSpecRequestBuilder {
+        UpdateSlicerSpecRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateSlicerSpecRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateSlicerSpecRequestBuilder(UpdateSlicerSpecRequest);
+
+impl UpdateSlicerSpecRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn slicer_id(mut self, slicer_id: i64) -> Self {
+        self.0.slicer_id = slicer_id;
+        self
+    }
+
+    pub fn spec(mut self, spec: impl Into<SlicerSpec>) -> Self {
+        self.0.spec = Some(spec.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateSlicerSpecRequest {
+        self.0
+    }
+}
+
 /// Updates properties of a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSpreadsheetPropertiesRequest {
     /**
     * Updates properties of a spreadsheet.
@@ -11117,6 +13780,34 @@ pub struct UpdateSpreadsheetPropertiesRequest {
     pub properties: Option<SpreadsheetProperties>,
 }
 
+impl UpdateSpreadsheetPropertiesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateSpreadsheetPropertiesRequestBuilder {
+        UpdateSpreadsheetPropertiesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateSpreadsheetPropertiesRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateSpreadsheetPropertiesRequestBuilder(UpdateSpreadsheetPropertiesRequest);
+
+impl UpdateSpreadsheetPropertiesRequestBuilder {
+    pub fn fields(mut self, fields: impl Into<String>) -> Self {
+        self.0.fields = fields.into();
+        self
+    }
+
+    pub fn properties(mut self, properties: impl Into<SpreadsheetProperties>) -> Self {
+        self.0.properties = Some(properties.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateSpreadsheetPropertiesRequest {
+        self.0
+    }
+}
+
 /// The response when updating a range of values by a data filter in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateValuesByDataFilterResponse {
@@ -11429,8 +14120,8 @@ pub enum WaterfallChartSpecStackedType {
     WaterfallStackedTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaterfallChartSpecStackedType {
@@ -11442,7 +14133,7 @@ impl std::fmt::Display for WaterfallChartSpecStackedType {
                 "WATERFALL_STACKED_TYPE_UNSPECIFIED"
             }
             WaterfallChartSpecStackedType::Noop => "",
-            WaterfallChartSpecStackedType::FallthroughString => "*",
+            WaterfallChartSpecStackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11534,8 +14225,8 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -11544,7 +14235,7 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11574,8 +14265,8 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -11585,7 +14276,7 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11613,8 +14304,8 @@ pub enum InsertDataOption {
     Overwrite,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InsertDataOption {
@@ -11623,7 +14314,7 @@ impl std::fmt::Display for InsertDataOption {
             InsertDataOption::InsertRows => "INSERT_ROWS",
             InsertDataOption::Overwrite => "OVERWRITE",
             InsertDataOption::Noop => "",
-            InsertDataOption::FallthroughString => "*",
+            InsertDataOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
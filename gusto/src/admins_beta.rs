@@ -21,7 +21,11 @@ impl AdminsBeta {
     *
     * Returns a list of all the admins at a company
     */
-    pub async fn get_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>> {
+    pub async fn get_company_admins(
+        &self,
+        company_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Admin>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/admins",
             crate::progenitor_support::encode_path(company_id),
@@ -43,8 +47,9 @@ impl AdminsBeta {
     */
     pub async fn get_all_company_admins(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Admin>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/admins",
             crate::progenitor_support::encode_path(company_id),
@@ -64,9 +69,10 @@ impl AdminsBeta {
     */
     pub async fn post_company_admin(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         body: &crate::types::PostCompanyAdminsRequest,
     ) -> Result<crate::types::Admin> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/admins",
             crate::progenitor_support::encode_path(company_id),
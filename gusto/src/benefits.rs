@@ -51,7 +51,8 @@ impl Benefits {
     *
     * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
     */
-    pub async fn get(&self, benefit_id: &str) -> Result<crate::types::SupportedBenefit> {
+    pub async fn get(&self, benefit_id: impl AsRef<str>) -> Result<crate::types::SupportedBenefit> {
+        let benefit_id = benefit_id.as_ref();
         let url = format!(
             "/v1/benefits/{}",
             crate::progenitor_support::encode_path(benefit_id),
@@ -69,7 +70,11 @@ impl Benefits {
     *
     * Note that company benefits can be deactivated only when no employees are enrolled.
     */
-    pub async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>> {
+    pub async fn get_company(
+        &self,
+        company_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::CompanyBenefit>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/company_benefits",
             crate::progenitor_support::encode_path(company_id),
@@ -91,8 +96,9 @@ impl Benefits {
     */
     pub async fn get_all_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CompanyBenefit>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/company_benefits",
             crate::progenitor_support::encode_path(company_id),
@@ -112,9 +118,10 @@ impl Benefits {
     */
     pub async fn post_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         body: &crate::types::PostCompanyBenefitsRequest,
     ) -> Result<crate::types::CompanyBenefit> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/company_benefits",
             crate::progenitor_support::encode_path(company_id),
@@ -136,8 +143,9 @@ impl Benefits {
     */
     pub async fn get_company_benefits(
         &self,
-        company_benefit_id: &str,
+        company_benefit_id: impl AsRef<str>,
     ) -> Result<crate::types::CompanyBenefit> {
+        let company_benefit_id = company_benefit_id.as_ref();
         let url = format!(
             "/v1/company_benefits/{}",
             crate::progenitor_support::encode_path(company_benefit_id),
@@ -157,9 +165,10 @@ impl Benefits {
     */
     pub async fn put_company(
         &self,
-        company_benefit_id: &str,
+        company_benefit_id: impl AsRef<str>,
         body: &crate::types::PutCompanyBenefitRequest,
     ) -> Result<crate::types::CompanyBenefit> {
+        let company_benefit_id = company_benefit_id.as_ref();
         let url = format!(
             "/v1/company_benefits/{}",
             crate::progenitor_support::encode_path(company_benefit_id),
@@ -181,8 +190,9 @@ impl Benefits {
     */
     pub async fn get_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::EmployeeBenefit>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/employee_benefits",
             crate::progenitor_support::encode_path(employee_id),
@@ -204,8 +214,9 @@ impl Benefits {
     */
     pub async fn get_all_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::EmployeeBenefit>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/employee_benefits",
             crate::progenitor_support::encode_path(employee_id),
@@ -223,9 +234,10 @@ impl Benefits {
     */
     pub async fn post_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
         body: &crate::types::PostEmployeeBenefitsRequest,
     ) -> Result<crate::types::EmployeeBenefit> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/employee_benefits",
             crate::progenitor_support::encode_path(employee_id),
@@ -245,9 +257,10 @@ impl Benefits {
     */
     pub async fn post_employee_ytd_amounts_from_different_company(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
         body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
     ) -> Result<()> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/ytd_benefit_amounts_from_different_company",
             crate::progenitor_support::encode_path(employee_id),
@@ -267,8 +280,9 @@ impl Benefits {
     */
     pub async fn get_employee_benefits(
         &self,
-        employee_benefit_id: &str,
+        employee_benefit_id: impl AsRef<str>,
     ) -> Result<crate::types::EmployeeBenefit> {
+        let employee_benefit_id = employee_benefit_id.as_ref();
         let url = format!(
             "/v1/employee_benefits/{}",
             crate::progenitor_support::encode_path(employee_benefit_id),
@@ -286,9 +300,10 @@ impl Benefits {
     */
     pub async fn put_employee(
         &self,
-        employee_benefit_id: &str,
+        employee_benefit_id: impl AsRef<str>,
         body: &crate::types::PutEmployeeBenefitRequest,
     ) -> Result<crate::types::EmployeeBenefit> {
+        let employee_benefit_id = employee_benefit_id.as_ref();
         let url = format!(
             "/v1/employee_benefits/{}",
             crate::progenitor_support::encode_path(employee_benefit_id),
@@ -306,7 +321,8 @@ impl Benefits {
     *
     * Employee benefits represent an employee enrolled in a particular company benefit. It includes information specific to that employee’s enrollment.
     */
-    pub async fn delete_employee(&self, employee_benefit_id: &str) -> Result<()> {
+    pub async fn delete_employee(&self, employee_benefit_id: impl AsRef<str>) -> Result<()> {
+        let employee_benefit_id = employee_benefit_id.as_ref();
         let url = format!(
             "/v1/employee_benefits/{}",
             crate::progenitor_support::encode_path(employee_benefit_id),
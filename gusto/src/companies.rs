@@ -19,7 +19,8 @@ impl Companies {
     *
     * Get a company.
     */
-    pub async fn get(&self, company_id_or_uuid: &str) -> Result<crate::types::Company> {
+    pub async fn get(&self, company_id_or_uuid: impl AsRef<str>) -> Result<crate::types::Company> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}",
             crate::progenitor_support::encode_path(company_id_or_uuid),
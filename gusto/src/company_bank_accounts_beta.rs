@@ -23,8 +23,9 @@ impl CompanyBankAccountsBeta {
     */
     pub async fn get_company_bank_accounts(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CompanyBankAccount>> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/bank_accounts",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -46,8 +47,9 @@ impl CompanyBankAccountsBeta {
     */
     pub async fn get_all_company_bank_accounts(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CompanyBankAccount>> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/bank_accounts",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -67,9 +69,10 @@ impl CompanyBankAccountsBeta {
     */
     pub async fn post_company_bank_account(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PostCompanyBankAccountsRequest,
     ) -> Result<crate::types::CompanyBankAccount> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/bank_accounts",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -91,10 +94,12 @@ impl CompanyBankAccountsBeta {
     */
     pub async fn put_company_bank_accounts_verify(
         &self,
-        company_id_or_uuid: &str,
-        bank_account_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
+        bank_account_uuid: impl AsRef<str>,
         body: &crate::types::PutCompanyBankAccountsVerifyRequest,
     ) -> Result<crate::types::CompanyBankAccount> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let bank_account_uuid = bank_account_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/bank_accounts/{}/verify",
             crate::progenitor_support::encode_path(company_id_or_uuid),
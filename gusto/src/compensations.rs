@@ -22,7 +22,11 @@ impl Compensations {
     * Note: Currently, jobs are arbitrarily limited to a single compensation as multiple compensations per job are not yet available in Gusto. The API is architected as if multiple compensations may exist, so integrations should integrate under the same assumption. The only exception is that creating a compensation with the same `job_id` as another will fail with a relevant error.
     *
     */
-    pub async fn get(&self, compensation_id: &str) -> Result<crate::types::Compensation> {
+    pub async fn get(
+        &self,
+        compensation_id: impl AsRef<str>,
+    ) -> Result<crate::types::Compensation> {
+        let compensation_id = compensation_id.as_ref();
         let url = format!(
             "/v1/compensations/{}",
             crate::progenitor_support::encode_path(compensation_id),
@@ -42,9 +46,10 @@ impl Compensations {
     */
     pub async fn put(
         &self,
-        compensation_id: &str,
+        compensation_id: impl AsRef<str>,
         body: &crate::types::PutCompensationRequest,
     ) -> Result<crate::types::Compensation> {
+        let compensation_id = compensation_id.as_ref();
         let url = format!(
             "/v1/compensations/{}",
             crate::progenitor_support::encode_path(compensation_id),
@@ -66,7 +71,11 @@ impl Compensations {
     *
     * Use the `flsa_status` to determine if an employee is elibgle for overtime.
     */
-    pub async fn get_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
+    pub async fn get_job(
+        &self,
+        job_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Compensation>> {
+        let job_id = job_id.as_ref();
         let url = format!(
             "/v1/jobs/{}/compensations",
             crate::progenitor_support::encode_path(job_id),
@@ -88,7 +97,11 @@ impl Compensations {
     *
     * Use the `flsa_status` to determine if an employee is elibgle for overtime.
     */
-    pub async fn get_all_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
+    pub async fn get_all_job(
+        &self,
+        job_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Compensation>> {
+        let job_id = job_id.as_ref();
         let url = format!(
             "/v1/jobs/{}/compensations",
             crate::progenitor_support::encode_path(job_id),
@@ -26,22 +26,24 @@ impl ContractorPayments {
     */
     pub async fn get_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         start_date: &str,
         end_date: &str,
     ) -> Result<crate::types::ContractorPaymentSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id = company_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/contractor_payments?{}",
-            crate::progenitor_support::encode_path(company_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/contractor_payments",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -67,7 +69,7 @@ impl ContractorPayments {
     */
     pub async fn post_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         date: &str,
         contractor_id: f64,
         wage: f64,
@@ -75,30 +77,32 @@ impl ContractorPayments {
         bonus: f64,
         reimbursement: f64,
     ) -> Result<crate::types::ContractorPayment> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id = company_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !bonus.to_string().is_empty() {
-            query_args.push(("bonus".to_string(), bonus.to_string()));
+            query_args.push(("bonus", bonus.to_string()));
         }
         if !contractor_id.to_string().is_empty() {
-            query_args.push(("contractor_id".to_string(), contractor_id.to_string()));
+            query_args.push(("contractor_id", contractor_id.to_string()));
         }
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !hours.to_string().is_empty() {
-            query_args.push(("hours".to_string(), hours.to_string()));
+            query_args.push(("hours", hours.to_string()));
         }
         if !reimbursement.to_string().is_empty() {
-            query_args.push(("reimbursement".to_string(), reimbursement.to_string()));
+            query_args.push(("reimbursement", reimbursement.to_string()));
         }
         if !wage.to_string().is_empty() {
-            query_args.push(("wage".to_string(), wage.to_string()));
+            query_args.push(("wage", wage.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/contractor_payments?{}",
-            crate::progenitor_support::encode_path(company_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/contractor_payments",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -113,9 +117,11 @@ impl ContractorPayments {
     */
     pub async fn get_company_contractor_payments(
         &self,
-        company_id: &str,
-        contractor_payment_id_or_uuid: &str,
+        company_id: impl AsRef<str>,
+        contractor_payment_id_or_uuid: impl AsRef<str>,
     ) -> Result<crate::types::ContractorPayment> {
+        let company_id = company_id.as_ref();
+        let contractor_payment_id_or_uuid = contractor_payment_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/contractor_payments/{}",
             crate::progenitor_support::encode_path(company_id),
@@ -136,9 +142,11 @@ impl ContractorPayments {
     */
     pub async fn delete_company(
         &self,
-        company_id: &str,
-        contractor_payment_id_or_uuid: &str,
+        company_id: impl AsRef<str>,
+        contractor_payment_id_or_uuid: impl AsRef<str>,
     ) -> Result<()> {
+        let company_id = company_id.as_ref();
+        let contractor_payment_id_or_uuid = contractor_payment_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/contractor_payments/{}",
             crate::progenitor_support::encode_path(company_id),
@@ -19,7 +19,11 @@ impl Contractors {
     *
     * Get a contractor.
     */
-    pub async fn get(&self, contractor_id_or_uuid: &str) -> Result<crate::types::Contractor> {
+    pub async fn get(
+        &self,
+        contractor_id_or_uuid: impl AsRef<str>,
+    ) -> Result<crate::types::Contractor> {
+        let contractor_id_or_uuid = contractor_id_or_uuid.as_ref();
         let url = format!(
             "/v1/contractors/{}",
             crate::progenitor_support::encode_path(contractor_id_or_uuid),
@@ -37,9 +41,10 @@ impl Contractors {
     */
     pub async fn put(
         &self,
-        contractor_id_or_uuid: &str,
+        contractor_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PutComntractorRequest,
     ) -> Result<crate::types::Contractor> {
+        let contractor_id_or_uuid = contractor_id_or_uuid.as_ref();
         let url = format!(
             "/v1/contractors/{}",
             crate::progenitor_support::encode_path(contractor_id_or_uuid),
@@ -59,8 +64,9 @@ impl Contractors {
     */
     pub async fn get_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Contractor>> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/contractors",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -80,8 +86,9 @@ impl Contractors {
     */
     pub async fn get_all_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Contractor>> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/contractors",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -99,9 +106,10 @@ impl Contractors {
     */
     pub async fn post_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PostCompanyContractorsRequest,
     ) -> Result<crate::types::Contractor> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/contractors",
             crate::progenitor_support::encode_path(company_id_or_uuid),
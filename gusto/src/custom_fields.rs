@@ -21,8 +21,9 @@ impl CustomFields {
     */
     pub async fn get_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
     ) -> Result<crate::types::GetEmployeeCustomFieldsResponse> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/custom_fields",
             crate::progenitor_support::encode_path(employee_id),
@@ -40,8 +41,9 @@ impl CustomFields {
     */
     pub async fn get_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
     ) -> Result<crate::types::GetCompanyCustomFieldsResponse> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/custom_fields",
             crate::progenitor_support::encode_path(company_id),
@@ -27,8 +27,9 @@ impl EarningType {
     */
     pub async fn get_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
     ) -> Result<crate::types::EarningTypeListResponse> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/earning_types",
             crate::progenitor_support::encode_path(company_id),
@@ -48,9 +49,10 @@ impl EarningType {
     */
     pub async fn post_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         body: &crate::types::PostCompanyEarningTypesRequest,
     ) -> Result<crate::types::EarningType> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/earning_types",
             crate::progenitor_support::encode_path(company_id),
@@ -70,10 +72,12 @@ impl EarningType {
     */
     pub async fn put_company_type(
         &self,
-        company_id: &str,
-        earning_type_uuid: &str,
+        company_id: impl AsRef<str>,
+        earning_type_uuid: impl AsRef<str>,
         body: &crate::types::PutCompanyEarningTypeRequest,
     ) -> Result<crate::types::EarningType> {
+        let company_id = company_id.as_ref();
+        let earning_type_uuid = earning_type_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/earning_types/{}",
             crate::progenitor_support::encode_path(company_id),
@@ -94,9 +98,11 @@ impl EarningType {
     */
     pub async fn delete_company_type(
         &self,
-        company_id: &str,
-        earning_type_uuid: &str,
+        company_id: impl AsRef<str>,
+        earning_type_uuid: impl AsRef<str>,
     ) -> Result<()> {
+        let company_id = company_id.as_ref();
+        let earning_type_uuid = earning_type_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/earning_types/{}",
             crate::progenitor_support::encode_path(company_id),
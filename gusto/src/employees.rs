@@ -25,18 +25,20 @@ impl Employees {
     */
     pub async fn get(
         &self,
-        employee_id_or_uuid: &str,
+        employee_id_or_uuid: impl AsRef<str>,
         include: &[String],
     ) -> Result<crate::types::Employee> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let employee_id_or_uuid = employee_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+            query_args.push(("include", include.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/employees/{}?{}",
-            crate::progenitor_support::encode_path(employee_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/employees/{}",
+                crate::progenitor_support::encode_path(employee_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -51,9 +53,10 @@ impl Employees {
     */
     pub async fn put(
         &self,
-        employee_id_or_uuid: &str,
+        employee_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PutEmployeesRequest,
     ) -> Result<crate::types::Employee> {
+        let employee_id_or_uuid = employee_id_or_uuid.as_ref();
         let url = format!(
             "/v1/employees/{}",
             crate::progenitor_support::encode_path(employee_id_or_uuid),
@@ -80,30 +83,32 @@ impl Employees {
     */
     pub async fn get_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         terminated: bool,
         page: f64,
         per: f64,
         include: &[String],
     ) -> Result<Vec<crate::types::Employee>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+            query_args.push(("include", include.join(" ")));
         }
         if !page.to_string().is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !per.to_string().is_empty() {
-            query_args.push(("per".to_string(), per.to_string()));
+            query_args.push(("per", per.to_string()));
         }
         if terminated {
-            query_args.push(("terminated".to_string(), terminated.to_string()));
+            query_args.push(("terminated", terminated.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/employees?{}",
-            crate::progenitor_support::encode_path(company_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/employees",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -120,22 +125,24 @@ impl Employees {
     */
     pub async fn get_all_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         terminated: bool,
         include: &[String],
     ) -> Result<Vec<crate::types::Employee>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+            query_args.push(("include", include.join(" ")));
         }
         if terminated {
-            query_args.push(("terminated".to_string(), terminated.to_string()));
+            query_args.push(("terminated", terminated.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/employees?{}",
-            crate::progenitor_support::encode_path(company_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/employees",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -150,9 +157,10 @@ impl Employees {
     */
     pub async fn post(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PostEmployeesRequest,
     ) -> Result<crate::types::Employee> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/employees",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -170,7 +178,11 @@ impl Employees {
     *
     * The home address of an employee is used to determine certain tax information about them. Addresses are geocoded on create and update to ensure validity.
     */
-    pub async fn get_home_address(&self, employee_id: &str) -> Result<crate::types::Location> {
+    pub async fn get_home_address(
+        &self,
+        employee_id: impl AsRef<str>,
+    ) -> Result<crate::types::Location> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/home_address",
             crate::progenitor_support::encode_path(employee_id),
@@ -188,9 +200,10 @@ impl Employees {
     */
     pub async fn put_home_address(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
         body: &crate::types::PutEmployeeHomeAddressRequest,
     ) -> Result<crate::types::Location> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/home_address",
             crate::progenitor_support::encode_path(employee_id),
@@ -19,7 +19,11 @@ impl Garnishments {
     *
     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
     */
-    pub async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>> {
+    pub async fn get_employee(
+        &self,
+        employee_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Garnishment>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/garnishments",
             crate::progenitor_support::encode_path(employee_id),
@@ -39,8 +43,9 @@ impl Garnishments {
     */
     pub async fn get_all_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Garnishment>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/garnishments",
             crate::progenitor_support::encode_path(employee_id),
@@ -58,9 +63,10 @@ impl Garnishments {
     */
     pub async fn post_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
         body: &crate::types::PostEmployeeGarnishmentsRequest,
     ) -> Result<crate::types::Garnishment> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/garnishments",
             crate::progenitor_support::encode_path(employee_id),
@@ -78,7 +84,8 @@ impl Garnishments {
     *
     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
     */
-    pub async fn get(&self, garnishment_id: &str) -> Result<crate::types::Garnishment> {
+    pub async fn get(&self, garnishment_id: impl AsRef<str>) -> Result<crate::types::Garnishment> {
+        let garnishment_id = garnishment_id.as_ref();
         let url = format!(
             "/v1/garnishments/{}",
             crate::progenitor_support::encode_path(garnishment_id),
@@ -96,9 +103,10 @@ impl Garnishments {
     */
     pub async fn put(
         &self,
-        garnishment_id: &str,
+        garnishment_id: impl AsRef<str>,
         body: &crate::types::PutGarnishmentRequest,
     ) -> Result<crate::types::Garnishment> {
+        let garnishment_id = garnishment_id.as_ref();
         let url = format!(
             "/v1/garnishments/{}",
             crate::progenitor_support::encode_path(garnishment_id),
@@ -23,8 +23,9 @@ impl JobApplicantsBeta {
     */
     pub async fn get_company_job_applicants(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::JobApplicant>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/job_applicants",
             crate::progenitor_support::encode_path(company_id),
@@ -46,8 +47,9 @@ impl JobApplicantsBeta {
     */
     pub async fn get_all_company_job_applicants(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::JobApplicant>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/job_applicants",
             crate::progenitor_support::encode_path(company_id),
@@ -67,9 +69,10 @@ impl JobApplicantsBeta {
     */
     pub async fn post_company_job_applicant(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         body: &crate::types::PostCompanyJobApplicantsRequest,
     ) -> Result<crate::types::JobApplicant> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/job_applicants",
             crate::progenitor_support::encode_path(company_id),
@@ -91,9 +94,11 @@ impl JobApplicantsBeta {
     */
     pub async fn get_company_job_applicants_applicant(
         &self,
-        company_id: &str,
-        job_applicant_uuid: &str,
+        company_id: impl AsRef<str>,
+        job_applicant_uuid: impl AsRef<str>,
     ) -> Result<crate::types::JobApplicant> {
+        let company_id = company_id.as_ref();
+        let job_applicant_uuid = job_applicant_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/job_applicants/{}",
             crate::progenitor_support::encode_path(company_id),
@@ -114,10 +119,12 @@ impl JobApplicantsBeta {
     */
     pub async fn put_company_job_applicants_applicant(
         &self,
-        company_id: &str,
-        job_applicant_uuid: &str,
+        company_id: impl AsRef<str>,
+        job_applicant_uuid: impl AsRef<str>,
         body: &crate::types::PutCompanyJobApplicantRequest,
     ) -> Result<crate::types::JobApplicant> {
+        let company_id = company_id.as_ref();
+        let job_applicant_uuid = job_applicant_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/job_applicants/{}",
             crate::progenitor_support::encode_path(company_id),
@@ -140,9 +147,11 @@ impl JobApplicantsBeta {
     */
     pub async fn delete_company_job_applicants_applicant(
         &self,
-        company_id: &str,
-        job_applicant_uuid: &str,
+        company_id: impl AsRef<str>,
+        job_applicant_uuid: impl AsRef<str>,
     ) -> Result<()> {
+        let company_id = company_id.as_ref();
+        let job_applicant_uuid = job_applicant_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/job_applicants/{}",
             crate::progenitor_support::encode_path(company_id),
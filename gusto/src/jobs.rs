@@ -19,7 +19,8 @@ impl Jobs {
     *
     * Get a job.
     */
-    pub async fn get(&self, job_id: &str) -> Result<crate::types::Job> {
+    pub async fn get(&self, job_id: impl AsRef<str>) -> Result<crate::types::Job> {
+        let job_id = job_id.as_ref();
         let url = format!(
             "/v1/jobs/{}",
             crate::progenitor_support::encode_path(job_id),
@@ -37,9 +38,10 @@ impl Jobs {
     */
     pub async fn put(
         &self,
-        job_id: &str,
+        job_id: impl AsRef<str>,
         body: &crate::types::PutJobRequest,
     ) -> Result<crate::types::Job> {
+        let job_id = job_id.as_ref();
         let url = format!(
             "/v1/jobs/{}",
             crate::progenitor_support::encode_path(job_id),
@@ -57,7 +59,8 @@ impl Jobs {
     *
     * Deletes a specific job that an employee holds.
     */
-    pub async fn delete(&self, job_id: &str) -> Result<()> {
+    pub async fn delete(&self, job_id: impl AsRef<str>) -> Result<()> {
+        let job_id = job_id.as_ref();
         let url = format!(
             "/v1/jobs/{}",
             crate::progenitor_support::encode_path(job_id),
@@ -73,7 +76,11 @@ impl Jobs {
     *
     * Get all of the jobs that an employee holds.
     */
-    pub async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
+    pub async fn get_employee(
+        &self,
+        employee_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Job>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/jobs",
             crate::progenitor_support::encode_path(employee_id),
@@ -91,7 +98,11 @@ impl Jobs {
     *
     * Get all of the jobs that an employee holds.
     */
-    pub async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
+    pub async fn get_all_employee(
+        &self,
+        employee_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Job>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/jobs",
             crate::progenitor_support::encode_path(employee_id),
@@ -109,9 +120,10 @@ impl Jobs {
     */
     pub async fn post(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
         body: &crate::types::PostJobRequest,
     ) -> Result<crate::types::Job> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/jobs",
             crate::progenitor_support::encode_path(employee_id),
@@ -133,9 +145,10 @@ impl Jobs {
     */
     pub async fn post_compensation(
         &self,
-        job_id: &str,
+        job_id: impl AsRef<str>,
         body: &crate::types::PostJobCompensationsRequest,
     ) -> Result<crate::types::Compensation> {
+        let job_id = job_id.as_ref();
         let url = format!(
             "/v1/jobs/{}/compensations",
             crate::progenitor_support::encode_path(job_id),
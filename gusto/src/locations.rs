@@ -23,8 +23,9 @@ impl Locations {
     */
     pub async fn get_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Location>> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/locations",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -46,8 +47,9 @@ impl Locations {
     */
     pub async fn get_all_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Location>> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/locations",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -67,9 +69,10 @@ impl Locations {
     */
     pub async fn post_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PostCompanyLocationsRequest,
     ) -> Result<crate::types::Location> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/locations",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -87,7 +90,8 @@ impl Locations {
     *
     * Get a location.
     */
-    pub async fn get(&self, location_id: &str) -> Result<crate::types::Location> {
+    pub async fn get(&self, location_id: impl AsRef<str>) -> Result<crate::types::Location> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/v1/locations/{}",
             crate::progenitor_support::encode_path(location_id),
@@ -105,9 +109,10 @@ impl Locations {
     */
     pub async fn put(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
         body: &crate::types::PutLocationRequest,
     ) -> Result<crate::types::Location> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/v1/locations/{}",
             crate::progenitor_support::encode_path(location_id),
@@ -0,0 +1,172 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: gusto_api::Client) {
+//! use gusto_api::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "Gusto";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/v1/benefits",
+    "/v1/benefits/{benefit_id}",
+    "/v1/companies/{company_id_or_uuid}",
+    "/v1/companies/{company_id_or_uuid}/bank_accounts",
+    "/v1/companies/{company_id_or_uuid}/bank_accounts/{bank_account_uuid}/verify",
+    "/v1/companies/{company_id_or_uuid}/contractors",
+    "/v1/companies/{company_id_or_uuid}/employees",
+    "/v1/companies/{company_id_or_uuid}/federal_tax_details",
+    "/v1/companies/{company_id_or_uuid}/locations",
+    "/v1/companies/{company_id_or_uuid}/pay_periods",
+    "/v1/companies/{company_id_or_uuid}/pay_schedules/{pay_schedule_id_or_uuid}",
+    "/v1/companies/{company_id_or_uuid}/payroll_reversals",
+    "/v1/companies/{company_id_or_uuid}/payrolls",
+    "/v1/companies/{company_id_or_uuid}/payrolls/{pay_period_start_date}/{pay_period_end_date}",
+    "/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}",
+    "/v1/companies/{company_id}/admins",
+    "/v1/companies/{company_id}/company_benefits",
+    "/v1/companies/{company_id}/contractor_payments",
+    "/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}",
+    "/v1/companies/{company_id}/custom_fields",
+    "/v1/companies/{company_id}/earning_types",
+    "/v1/companies/{company_id}/earning_types/{earning_type_uuid}",
+    "/v1/companies/{company_id}/job_applicants",
+    "/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}",
+    "/v1/companies/{company_id}/pay_schedules",
+    "/v1/companies/{company_id}/payrolls/{payroll_Id}/submit",
+    "/v1/companies/{company_id}/payrolls/{payroll_id}/calculate",
+    "/v1/companies/{company_id}/payrolls/{payroll_id}/cancel",
+    "/v1/companies/{company_id}/time_off_requests",
+    "/v1/companies/{company_id}/time_off_requests/{time_off_request_id}",
+    "/v1/company_benefits/{company_benefit_id}",
+    "/v1/compensations/{compensation_id}",
+    "/v1/contractors/{contractor_id_or_uuid}",
+    "/v1/employee_benefits/{employee_benefit_id}",
+    "/v1/employees/{employee_id_or_uuid}",
+    "/v1/employees/{employee_id}/custom_fields",
+    "/v1/employees/{employee_id}/employee_benefits",
+    "/v1/employees/{employee_id}/garnishments",
+    "/v1/employees/{employee_id}/home_address",
+    "/v1/employees/{employee_id}/jobs",
+    "/v1/employees/{employee_id}/terminations",
+    "/v1/employees/{employee_id}/ytd_benefit_amounts_from_different_company",
+    "/v1/garnishments/{garnishment_id}",
+    "/v1/jobs/{job_id}",
+    "/v1/jobs/{job_id}/compensations",
+    "/v1/locations/{location_id}",
+    "/v1/me",
+    "/v1/partner_managed_companies",
+    "/v1/provision",
+];
@@ -0,0 +1,281 @@
+//! Onboard an employee in one call: create them, then set their home address, their
+//! job and its compensation, their taxes and their payment method.
+//!
+//! Each of these is a separate request, and the steps after the creation of the
+//! employee carry on when one of them fails, so that a single call does as much of
+//! the onboarding as it can. The `OnboardingReport` tells which steps are left to
+//! do again.
+//!
+//! The taxes and the payment method of employees are missing from the API
+//! description this client is generated from, so their bodies are given as JSON,
+//! as documented by Gusto.
+//!
+//! ```no_run
+//! # async fn run(gusto: gusto_api::Client) -> anyhow::Result<()> {
+//! use gusto_api::{
+//!     onboarding::EmployeeOnboarding,
+//!     types::{PostEmployeesRequest, PostJobRequest, PutEmployeeHomeAddressRequest},
+//! };
+//!
+//! let onboarding = EmployeeOnboarding {
+//!     home_address: Some(PutEmployeeHomeAddressRequest {
+//!         street_1: "425 2nd Street".to_string(),
+//!         city: "San Francisco".to_string(),
+//!         state: "CA".to_string(),
+//!         zip: "94107".to_string(),
+//!         ..Default::default()
+//!     }),
+//!     job: Some(PostJobRequest {
+//!         title: "Software Engineer".to_string(),
+//!         location_id: 7757727716081120.0,
+//!         hire_date: None,
+//!     }),
+//!     payment_method: Some(serde_json::json!({ "type": "Check" })),
+//!     ..EmployeeOnboarding::new(PostEmployeesRequest {
+//!         first_name: "Soren".to_string(),
+//!         last_name: "Kierkegaard".to_string(),
+//!         ..Default::default()
+//!     })
+//! };
+//! let report = gusto.employees().onboard("7756341740978008", &onboarding).await?;
+//! for failure in &report.failed {
+//!     println!("{:?} failed: {}", failure.step, failure.error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+
+use crate::{
+    employees::Employees,
+    types::{
+        Compensation, Employee, Job, Location, PostEmployeesRequest, PostJobRequest,
+        PutCompensationRequest, PutEmployeeHomeAddressRequest,
+    },
+};
+
+/// An employee to onboard, with the steps to take once they are created. The steps
+/// which are `None` are skipped.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmployeeOnboarding {
+    pub employee: PostEmployeesRequest,
+    /// The home address of the employee. Its `version` is filled in when empty.
+    pub home_address: Option<PutEmployeeHomeAddressRequest>,
+    pub job: Option<PostJobRequest>,
+    /// The pay of the job, replacing the compensation Gusto creates along with the
+    /// job. Its `version` is filled in when empty. Requires `job`.
+    pub compensation: Option<PutCompensationRequest>,
+    /// The body updating the federal taxes of the employee, without its `version`.
+    pub federal_taxes: Option<serde_json::Value>,
+    /// The body updating the state taxes of the employee.
+    pub state_taxes: Option<serde_json::Value>,
+    /// The body updating the payment method of the employee, without its `version`.
+    pub payment_method: Option<serde_json::Value>,
+}
+
+impl EmployeeOnboarding {
+    /// Only create the employee.
+    pub fn new(employee: PostEmployeesRequest) -> Self {
+        EmployeeOnboarding {
+            employee,
+            ..Default::default()
+        }
+    }
+}
+
+/// A step of the onboarding of an employee, after their creation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStep {
+    HomeAddress,
+    Job,
+    Compensation,
+    FederalTaxes,
+    StateTaxes,
+    PaymentMethod,
+}
+
+/// A step of the onboarding which failed.
+#[derive(Debug)]
+pub struct OnboardingFailure {
+    pub step: OnboardingStep,
+    pub error: anyhow::Error,
+}
+
+/// What the onboarding of an employee did, step by step.
+#[derive(Debug)]
+pub struct OnboardingReport {
+    pub employee: Employee,
+    pub home_address: Option<Location>,
+    pub job: Option<Job>,
+    pub compensation: Option<Compensation>,
+    /// The steps which succeeded, in order.
+    pub completed: Vec<OnboardingStep>,
+    pub failed: Vec<OnboardingFailure>,
+    /// The steps which were not taken because a step they need failed.
+    pub skipped: Vec<OnboardingStep>,
+}
+
+impl OnboardingReport {
+    /// Whether every step of the onboarding succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+
+    fn push<T>(&mut self, step: OnboardingStep, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.completed.push(step);
+                Some(value)
+            }
+            Err(error) => {
+                self.failed.push(OnboardingFailure { step, error });
+                None
+            }
+        }
+    }
+}
+
+impl Employees {
+    /**
+     * Create an employee of a company, then take the other steps of their
+     * onboarding.
+     *
+     * Fails only when the employee cannot be created. The other steps are taken
+     * in the order of `OnboardingStep`, and the failure of one of them does not stop
+     * the next ones, except for the compensation which needs the job. The report
+     * tells how each step went.
+     *
+     * **Parameters:**
+     *
+     * * `company_id_or_uuid: &str` -- The ID or UUID of the company.
+     * * `onboarding: &EmployeeOnboarding` -- The employee and the steps to take.
+     */
+    pub async fn onboard(
+        &self,
+        company_id_or_uuid: &str,
+        onboarding: &EmployeeOnboarding,
+    ) -> Result<OnboardingReport> {
+        let employee = self.post(company_id_or_uuid, &onboarding.employee).await?;
+        let employee_id = employee.id.to_string();
+        let mut report = OnboardingReport {
+            employee,
+            home_address: None,
+            job: None,
+            compensation: None,
+            completed: Vec::new(),
+            failed: Vec::new(),
+            skipped: Vec::new(),
+        };
+
+        if let Some(address) = &onboarding.home_address {
+            let result = self.onboard_home_address(&employee_id, address).await;
+            report.home_address = report.push(OnboardingStep::HomeAddress, result);
+        }
+
+        if let Some(job) = &onboarding.job {
+            let jobs = crate::jobs::Jobs::new(self.client.clone());
+            let result = jobs.post(&employee_id, job).await;
+            report.job = report.push(OnboardingStep::Job, result);
+        }
+
+        if let Some(compensation) = &onboarding.compensation {
+            match &report.job {
+                Some(job) => {
+                    let result = self.onboard_compensation(job, compensation).await;
+                    report.compensation = report.push(OnboardingStep::Compensation, result);
+                }
+                None => report.skipped.push(OnboardingStep::Compensation),
+            }
+        }
+
+        let steps = [
+            (
+                OnboardingStep::FederalTaxes,
+                "federal_taxes",
+                &onboarding.federal_taxes,
+                true,
+            ),
+            (
+                OnboardingStep::StateTaxes,
+                "state_taxes",
+                &onboarding.state_taxes,
+                false,
+            ),
+            (
+                OnboardingStep::PaymentMethod,
+                "payment_method",
+                &onboarding.payment_method,
+                true,
+            ),
+        ];
+        for (step, resource, body, versioned) in steps.iter() {
+            if let Some(body) = body {
+                let url = format!(
+                    "/v1/employees/{}/{}",
+                    crate::progenitor_support::encode_path(&employee_id),
+                    resource
+                );
+                let result = self.put_json(&url, body, *versioned).await;
+                report.push(*step, result);
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn onboard_home_address(
+        &self,
+        employee_id: &str,
+        address: &PutEmployeeHomeAddressRequest,
+    ) -> Result<Location> {
+        if !address.version.is_empty() {
+            return self.put_home_address(employee_id, address).await;
+        }
+        let current = self.get_home_address(employee_id).await?;
+        let address = PutEmployeeHomeAddressRequest {
+            version: current.version,
+            ..address.clone()
+        };
+        self.put_home_address(employee_id, &address).await
+    }
+
+    async fn onboard_compensation(
+        &self,
+        job: &Job,
+        compensation: &PutCompensationRequest,
+    ) -> Result<Compensation> {
+        let compensations = crate::compensations::Compensations::new(self.client.clone());
+        let compensation_id = job.current_compensation_id.to_string();
+        if !compensation.version.is_empty() {
+            return compensations.put(&compensation_id, compensation).await;
+        }
+        let current = compensations.get(&compensation_id).await?;
+        let compensation = PutCompensationRequest {
+            version: current.version,
+            ..compensation.clone()
+        };
+        compensations.put(&compensation_id, &compensation).await
+    }
+
+    /// Update a resource of an employee with a JSON body. When `versioned`, the
+    /// current `version` of the resource is added to the body unless it has one.
+    async fn put_json(
+        &self,
+        url: &str,
+        body: &serde_json::Value,
+        versioned: bool,
+    ) -> Result<serde_json::Value> {
+        let mut body = body.clone();
+        if let Some(fields) = body.as_object_mut() {
+            if versioned && !fields.contains_key("version") {
+                let current: serde_json::Value = self.client.get(url, None).await?;
+                if let Some(version) = current.get("version") {
+                    fields.insert("version".to_string(), version.clone());
+                }
+            }
+        }
+        self.client
+            .put(url, Some(reqwest::Body::from(serde_json::to_vec(&body)?)))
+            .await
+    }
+}
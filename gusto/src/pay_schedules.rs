@@ -19,7 +19,11 @@ impl PaySchedules {
     *
     * The pay schedule object in Gusto captures the details of when employees work and when they should be paid. A company can have multiple pay schedules.
     */
-    pub async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>> {
+    pub async fn get_company(
+        &self,
+        company_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::PaySchedule>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/pay_schedules",
             crate::progenitor_support::encode_path(company_id),
@@ -39,8 +43,9 @@ impl PaySchedules {
     */
     pub async fn get_all_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PaySchedule>> {
+        let company_id = company_id.as_ref();
         let url = format!(
             "/v1/companies/{}/pay_schedules",
             crate::progenitor_support::encode_path(company_id),
@@ -58,9 +63,11 @@ impl PaySchedules {
     */
     pub async fn get_company_schedule(
         &self,
-        company_id_or_uuid: &str,
-        pay_schedule_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
+        pay_schedule_id_or_uuid: impl AsRef<str>,
     ) -> Result<crate::types::PaySchedule> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let pay_schedule_id_or_uuid = pay_schedule_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/pay_schedules/{}",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -81,10 +88,12 @@ impl PaySchedules {
     */
     pub async fn put_company_schedule(
         &self,
-        company_id_or_uuid: &str,
-        pay_schedule_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
+        pay_schedule_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
     ) -> Result<crate::types::PaySchedule> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let pay_schedule_id_or_uuid = pay_schedule_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/pay_schedules/{}",
             crate::progenitor_support::encode_path(company_id_or_uuid),
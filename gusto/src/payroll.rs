@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::payroll_runs::{OffCyclePayroll, PayrollQuery};
 use crate::Client;
 
 pub struct Payroll {
@@ -29,22 +30,24 @@ impl Payroll {
     */
     pub async fn get_company_pay_periods(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayPeriod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/pay_periods?{}",
-            crate::progenitor_support::encode_path(company_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/pay_periods",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -64,22 +67,24 @@ impl Payroll {
     */
     pub async fn get_all_company_pay_periods(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayPeriod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/pay_periods?{}",
-            crate::progenitor_support::encode_path(company_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/pay_periods",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -107,37 +112,36 @@ impl Payroll {
     */
     pub async fn get_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         processed: bool,
         include_off_cycle: bool,
         include: &[String],
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayrollData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+            query_args.push(("include", include.join(" ")));
         }
         if include_off_cycle {
-            query_args.push((
-                "include_off_cycle".to_string(),
-                include_off_cycle.to_string(),
-            ));
+            query_args.push(("include_off_cycle", include_off_cycle.to_string()));
         }
         if processed {
-            query_args.push(("processed".to_string(), processed.to_string()));
+            query_args.push(("processed", processed.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/payrolls?{}",
-            crate::progenitor_support::encode_path(company_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/payrolls",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -159,37 +163,36 @@ impl Payroll {
     */
     pub async fn get_all_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         processed: bool,
         include_off_cycle: bool,
         include: &[String],
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayrollData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+            query_args.push(("include", include.join(" ")));
         }
         if include_off_cycle {
-            query_args.push((
-                "include_off_cycle".to_string(),
-                include_off_cycle.to_string(),
-            ));
+            query_args.push(("include_off_cycle", include_off_cycle.to_string()));
         }
         if processed {
-            query_args.push(("processed".to_string(), processed.to_string()));
+            query_args.push(("processed", processed.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/payrolls?{}",
-            crate::progenitor_support::encode_path(company_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/payrolls",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -206,9 +209,10 @@ impl Payroll {
     */
     pub async fn post_company(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PostCompanyPayrollsRequest,
     ) -> Result<crate::types::PayrollData> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/payrolls",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -238,24 +242,27 @@ impl Payroll {
     */
     pub async fn get_company_payroll(
         &self,
-        company_id_or_uuid: &str,
-        payroll_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
+        payroll_id_or_uuid: impl AsRef<str>,
         include: crate::types::GetCompanyPayrollsInclude,
         show_calculation: &str,
     ) -> Result<crate::types::PayrollData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let payroll_id_or_uuid = payroll_id_or_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !show_calculation.is_empty() {
-            query_args.push(("show_calculation".to_string(), show_calculation.to_string()));
+            query_args.push(("show_calculation", show_calculation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/payrolls/{}?{}",
-            crate::progenitor_support::encode_path(company_id_or_uuid),
-            crate::progenitor_support::encode_path(payroll_id_or_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/payrolls/{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(payroll_id_or_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -270,10 +277,12 @@ impl Payroll {
     */
     pub async fn put_company(
         &self,
-        company_id_or_uuid: &str,
-        payroll_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
+        payroll_id_or_uuid: impl AsRef<str>,
         body: &crate::types::PutCompanyPayrollsRequest,
     ) -> Result<crate::types::PayrollData> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let payroll_id_or_uuid = payroll_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/payrolls/{}",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -296,11 +305,14 @@ impl Payroll {
     */
     pub async fn put_company_pay_period_start_date_end(
         &self,
-        company_id_or_uuid: &str,
-        pay_period_start_date: &str,
-        pay_period_end_date: &str,
+        company_id_or_uuid: impl AsRef<str>,
+        pay_period_start_date: impl AsRef<str>,
+        pay_period_end_date: impl AsRef<str>,
         body: &crate::types::PutCompanyPayrollsRequest,
     ) -> Result<crate::types::PayrollData> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
+        let pay_period_start_date = pay_period_start_date.as_ref();
+        let pay_period_end_date = pay_period_end_date.as_ref();
         let url = format!(
             "/v1/companies/{}/payrolls/{}/{}",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -324,7 +336,13 @@ impl Payroll {
     *
     * This endpoint is asynchronous and responds with only a 202 HTTP status. To view the details of the calculated payroll, use the GET /v1/companies/{company_id}/payrolls/{payroll_id} endpoint with the *show_calculation=true* and *include=taxes,benefits,deductions* params
     */
-    pub async fn put_company_calculate(&self, company_id: &str, payroll_id: &str) -> Result<()> {
+    pub async fn put_company_calculate(
+        &self,
+        company_id: impl AsRef<str>,
+        payroll_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let company_id = company_id.as_ref();
+        let payroll_id = payroll_id.as_ref();
         let url = format!(
             "/v1/companies/{}/payrolls/{}/calculate",
             crate::progenitor_support::encode_path(company_id),
@@ -343,7 +361,13 @@ impl Payroll {
     *
     * Submits an unprocessed payroll to be calculated and run. Upon success, transitions the payroll to the `processed` state.
     */
-    pub async fn put_company_submit(&self, company_id: &str, payroll_id: &str) -> Result<()> {
+    pub async fn put_company_submit(
+        &self,
+        company_id: impl AsRef<str>,
+        payroll_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let company_id = company_id.as_ref();
+        let payroll_id = payroll_id.as_ref();
         let url = format!(
             "/v1/companies/{}/payrolls/{}/submit",
             crate::progenitor_support::encode_path(company_id),
@@ -365,9 +389,11 @@ impl Payroll {
     */
     pub async fn put_company_cancel(
         &self,
-        company_id: &str,
-        payroll_id: &str,
+        company_id: impl AsRef<str>,
+        payroll_id: impl AsRef<str>,
     ) -> Result<crate::types::PayrollData> {
+        let company_id = company_id.as_ref();
+        let payroll_id = payroll_id.as_ref();
         let url = format!(
             "/v1/companies/{}/payrolls/{}/cancel",
             crate::progenitor_support::encode_path(company_id),
@@ -386,8 +412,9 @@ impl Payroll {
     */
     pub async fn get_company_or_reversals(
         &self,
-        company_id_or_uuid: &str,
+        company_id_or_uuid: impl AsRef<str>,
     ) -> Result<crate::types::GetCompanyPayrollReversalsResponse> {
+        let company_id_or_uuid = company_id_or_uuid.as_ref();
         let url = format!(
             "/v1/companies/{}/payroll_reversals",
             crate::progenitor_support::encode_path(company_id_or_uuid),
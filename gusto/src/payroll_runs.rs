@@ -0,0 +1,202 @@
+//! Run payrolls: list the payrolls of a company with typed filters and create
+//! off-cycle payrolls.
+//!
+//! The payrolls are requested page by page as the stream is polled. Unlike the
+//! generated functions, the filters are dates and the unprocessed payrolls can be
+//! asked for.
+use std::collections::VecDeque;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+
+use crate::{
+    payroll::Payroll,
+    types::{
+        GetCompanyPayrollsInclude, OffCycleReason, PayPeriod, PayrollData,
+        PostCompanyPayrollsRequest,
+    },
+    Client,
+};
+
+/**
+ * A listing of the payrolls of a company, created with `Payroll::list`.
+ *
+ * ```no_run
+ * # async fn run(gusto: gusto_api::Client) -> anyhow::Result<()> {
+ * use chrono::NaiveDate;
+ * use futures::TryStreamExt;
+ *
+ * let mut payrolls = Box::pin(
+ *     gusto
+ *         .payroll()
+ *         .list("7756341740978008")
+ *         .processed(false)
+ *         .start_date(NaiveDate::from_ymd(2021, 1, 1))
+ *         .stream(),
+ * );
+ * while let Some(payroll) = payrolls.try_next().await? {
+ *     println!("{} is due {:?}", payroll.payroll_uuid, payroll.payroll_deadline);
+ * }
+ * # Ok(())
+ * # }
+ * ```
+ */
+#[derive(Clone)]
+pub struct PayrollQuery {
+    client: Client,
+    uri: String,
+    query: Vec<(String, String)>,
+    include: Vec<GetCompanyPayrollsInclude>,
+}
+
+/// An off-cycle payroll to create, to pay a bonus or to correct a payroll.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OffCyclePayroll {
+    pub reason: OffCycleReason,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// The date the employees are paid on.
+    pub check_date: Option<NaiveDate>,
+    /// The employees to pay, all of them when empty.
+    pub employee_ids: Vec<i64>,
+}
+
+impl Payroll {
+    /// List the payrolls of a company, getting the pages as they are needed.
+    pub fn list(&self, company_id_or_uuid: &str) -> PayrollQuery {
+        PayrollQuery {
+            client: self.client.clone(),
+            uri: format!(
+                "/v1/companies/{}/payrolls",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+            query: Vec::new(),
+            include: Vec::new(),
+        }
+    }
+
+    /// Get the pay periods of a company between two dates.
+    pub async fn pay_periods_between(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<PayPeriod>> {
+        self.get_company_pay_periods(
+            company_id_or_uuid,
+            &start_date.to_string(),
+            &end_date.to_string(),
+        )
+        .await
+    }
+
+    /// Create an unprocessed off-cycle payroll.
+    pub async fn create_off_cycle(
+        &self,
+        company_id_or_uuid: &str,
+        payroll: &OffCyclePayroll,
+    ) -> Result<PayrollData> {
+        self.post_company(company_id_or_uuid, &payroll.into()).await
+    }
+}
+
+impl PayrollQuery {
+    fn param<T: ToString>(mut self, name: &str, value: T) -> Self {
+        self.query.retain(|(n, _)| n != name);
+        self.query.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Only list the payrolls that were processed, or the ones that were not.
+    pub fn processed(self, processed: bool) -> Self {
+        self.param("processed", processed)
+    }
+
+    /// Whether to list the off-cycle payrolls as well.
+    pub fn include_off_cycle(self, include_off_cycle: bool) -> Self {
+        self.param("include_off_cycle", include_off_cycle)
+    }
+
+    /// Include the given details in the employee compensations of the payrolls.
+    pub fn include(mut self, include: GetCompanyPayrollsInclude) -> Self {
+        if !include.is_noop() && !self.include.contains(&include) {
+            self.include.push(include);
+        }
+        self
+    }
+
+    /// Only list the payrolls whose pay period is after this date.
+    pub fn start_date(self, start_date: NaiveDate) -> Self {
+        self.param("start_date", start_date)
+    }
+
+    /// Only list the payrolls whose pay period is before this date.
+    pub fn end_date(self, end_date: NaiveDate) -> Self {
+        self.param("end_date", end_date)
+    }
+
+    /// The number of payrolls requested per page.
+    pub fn per_page(self, per_page: u32) -> Self {
+        self.param("per", per_page)
+    }
+
+    /// Returns the payrolls, requesting the next page when the payrolls of the
+    /// previous one were handed out.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<PayrollData>> {
+        let mut query = self.query.clone();
+        if !self.include.is_empty() {
+            let include: Vec<String> = self.include.iter().map(|i| i.to_string()).collect();
+            query.push(("include".to_string(), include.join(",")));
+        }
+        let first = format!(
+            "{}{}?{}",
+            self.client.host,
+            self.uri,
+            serde_urlencoded::to_string(&query).unwrap()
+        );
+        let state = (self.client, Some(first), VecDeque::new());
+
+        futures::stream::try_unfold(state, |(client, mut next, mut pending)| async move {
+            loop {
+                if let Some(payroll) = pending.pop_front() {
+                    return Ok(Some((payroll, (client, next, pending))));
+                }
+
+                let url = match next.take() {
+                    Some(url) => url,
+                    None => return Ok(None),
+                };
+                let url = reqwest::Url::parse(&url)?;
+                let (link, page): (_, Vec<PayrollData>) = client.get_pages_url(&url).await?;
+                next = link.as_ref().and_then(crate::utils::next_link);
+                pending.extend(page);
+            }
+        })
+    }
+}
+
+impl OffCyclePayroll {
+    /// An off-cycle payroll for all the employees.
+    pub fn new(reason: OffCycleReason, start_date: NaiveDate, end_date: NaiveDate) -> Self {
+        OffCyclePayroll {
+            reason,
+            start_date,
+            end_date,
+            check_date: None,
+            employee_ids: Vec::new(),
+        }
+    }
+}
+
+impl From<&OffCyclePayroll> for PostCompanyPayrollsRequest {
+    fn from(payroll: &OffCyclePayroll) -> Self {
+        PostCompanyPayrollsRequest {
+            check_date: payroll.check_date,
+            employee_ids: payroll.employee_ids.clone(),
+            end_date: Some(payroll.end_date),
+            off_cycle: "true".to_string(),
+            off_cycle_reason: Some(payroll.reason.clone()),
+            start_date: Some(payroll.start_date),
+        }
+    }
+}
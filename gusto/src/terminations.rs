@@ -21,7 +21,11 @@ impl Terminations {
     *
     * Note that some states require employees to receive their final wages within 24 hours (unless they consent otherwise,) in which case running a one-off payroll may be the only option.
     */
-    pub async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>> {
+    pub async fn get_employee(
+        &self,
+        employee_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Termination>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/terminations",
             crate::progenitor_support::encode_path(employee_id),
@@ -43,8 +47,9 @@ impl Terminations {
     */
     pub async fn get_all_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Termination>> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/terminations",
             crate::progenitor_support::encode_path(employee_id),
@@ -64,9 +69,10 @@ impl Terminations {
     */
     pub async fn post_employee(
         &self,
-        employee_id: &str,
+        employee_id: impl AsRef<str>,
         body: &crate::types::PostEmployeeTerminationsRequest,
     ) -> Result<crate::types::Termination> {
+        let employee_id = employee_id.as_ref();
         let url = format!(
             "/v1/employees/{}/terminations",
             crate::progenitor_support::encode_path(employee_id),
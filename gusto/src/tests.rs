@@ -135,12 +135,173 @@ fn test_deserialize_employee() {
             .unwrap()
             .clone()
             .to_string(),
-        // TODO: fix this better.
-        //"Commission Only Exempt".to_string()
-        "*".to_string()
+        "Commission Only Exempt".to_string()
     );
     assert_eq!(
         first_compensation.payment_unit.as_ref().unwrap().clone(),
         crate::types::PaymentUnit::Year
     );
 }
+
+#[tokio::test]
+async fn test_payroll_runs() {
+    use futures::TryStreamExt;
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"[{"payroll_uuid": "b50e611d", "processed": false, "payroll_deadline": "2021-02-18T22:00:00Z"}]"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"payroll_uuid": "c7d9f3a2", "processed": false}"#,
+    );
+
+    let gusto = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let payrolls: Vec<_> = gusto
+        .payroll()
+        .list("7756341740978008")
+        .processed(false)
+        .include(crate::types::GetCompanyPayrollsInclude::Taxes)
+        .include(crate::types::GetCompanyPayrollsInclude::Benefits)
+        .start_date(chrono::NaiveDate::from_ymd(2021, 1, 1))
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(payrolls.len(), 1);
+    assert_eq!(
+        payrolls[0].payroll_deadline.unwrap().to_rfc3339(),
+        "2021-02-18T22:00:00+00:00"
+    );
+
+    let payroll = crate::payroll::OffCyclePayroll::new(
+        crate::types::OffCycleReason::Bonus,
+        chrono::NaiveDate::from_ymd(2021, 2, 1),
+        chrono::NaiveDate::from_ymd(2021, 2, 14),
+    );
+    gusto
+        .payroll()
+        .create_off_cycle("7756341740978008", &payroll)
+        .await
+        .unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(
+        requests[0].url.query(),
+        Some("processed=false&start_date=2021-01-01&include=taxes%2Cbenefits")
+    );
+    let sent: serde_json::Value = requests[1].json().unwrap();
+    assert_eq!(sent["off_cycle"], "true");
+    assert_eq!(sent["off_cycle_reason"], "Bonus");
+    assert_eq!(sent["start_date"], "2021-02-01");
+}
+
+#[tokio::test]
+async fn test_onboard_employee() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::CREATED,
+        r#"{"id": 7757869450127620, "first_name": "Soren", "last_name": "Kierkegaard"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"version": "fe75bd065ff48b91"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"version": "2ad18a2f5c8b6e04"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::UNPROCESSABLE_ENTITY,
+        r#"{"errors": {"location_id": ["is invalid"]}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"version": "63859768485e218c"}"#,
+    );
+    mock.push_response(reqwest::StatusCode::OK, r#"{"type": "Check"}"#);
+
+    let gusto = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let onboarding = crate::onboarding::EmployeeOnboarding {
+        home_address: Some(crate::types::PutEmployeeHomeAddressRequest {
+            street_1: "425 2nd Street".to_string(),
+            ..Default::default()
+        }),
+        job: Some(Default::default()),
+        compensation: Some(Default::default()),
+        payment_method: Some(serde_json::json!({ "type": "Check" })),
+        ..Default::default()
+    };
+    let report = gusto
+        .employees()
+        .onboard("7756341740978008", &onboarding)
+        .await
+        .unwrap();
+
+    use crate::onboarding::OnboardingStep;
+    assert!(!report.is_ok());
+    assert_eq!(
+        report.completed,
+        vec![OnboardingStep::HomeAddress, OnboardingStep::PaymentMethod]
+    );
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].step, OnboardingStep::Job);
+    assert_eq!(report.skipped, vec![OnboardingStep::Compensation]);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 6);
+    let address: serde_json::Value = requests[2].json().unwrap();
+    assert_eq!(address["version"], "fe75bd065ff48b91");
+    assert_eq!(address["street_1"], "425 2nd Street");
+    assert_eq!(
+        requests[5].url.path(),
+        "/v1/employees/7757869450127620/payment_method"
+    );
+    let payment_method: serde_json::Value = requests[5].json().unwrap();
+    assert_eq!(payment_method["version"], "63859768485e218c");
+}
+
+#[tokio::test]
+async fn test_redacted_credentials() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, "{}");
+
+    let gusto = crate::Client::new(
+        "client-id",
+        "client-s3cret",
+        "redirect-uri",
+        "access-t0ken",
+        "refresh-t0ken",
+    )
+    .with_default_header("x-api-key", "default-k3y")
+    .unwrap()
+    .with_transport(mock.clone());
+    let debug = format!("{:?}", gusto);
+    assert!(debug.contains("client-id"));
+    let secrets = [
+        "client-s3cret",
+        "access-t0ken",
+        "refresh-t0ken",
+        "default-k3y",
+    ];
+    for secret in &secrets {
+        assert!(!debug.contains(secret), "{} in {}", secret, debug);
+    }
+
+    let token = crate::AccessToken {
+        access_token: "access-t0ken".to_string(),
+        ..Default::default()
+    };
+    assert!(!format!("{:?}", token).contains("access-t0ken"));
+
+    // Only the request matters here, not its response.
+    gusto.current_user().get_me().await.ok();
+    let request = &mock.requests()[0];
+    assert!(request.headers["authorization"].is_sensitive());
+    assert!(request.headers["x-api-key"].is_sensitive());
+    assert!(!request.headers["accept"].is_sensitive());
+    assert!(!format!("{:?}", request.headers).contains("access-t0ken"));
+}
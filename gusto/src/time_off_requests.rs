@@ -43,22 +43,24 @@ impl TimeOffRequests {
     */
     pub async fn get_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::TimeOffRequest>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id = company_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/time_off_requests?{}",
-            crate::progenitor_support::encode_path(company_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/time_off_requests",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -92,22 +94,24 @@ impl TimeOffRequests {
     */
     pub async fn get_all_company(
         &self,
-        company_id: &str,
+        company_id: impl AsRef<str>,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::TimeOffRequest>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let company_id = company_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/time_off_requests?{}",
-            crate::progenitor_support::encode_path(company_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/companies/{}/time_off_requests",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -122,9 +126,11 @@ impl TimeOffRequests {
     */
     pub async fn get_company_request(
         &self,
-        company_id: &str,
-        time_off_request_id: &str,
+        company_id: impl AsRef<str>,
+        time_off_request_id: impl AsRef<str>,
     ) -> Result<crate::types::TimeOffRequest> {
+        let company_id = company_id.as_ref();
+        let time_off_request_id = time_off_request_id.as_ref();
         let url = format!(
             "/v1/companies/{}/time_off_requests/{}",
             crate::progenitor_support::encode_path(company_id),
@@ -605,8 +605,8 @@ pub enum PaymentUnit {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentUnit {
@@ -618,7 +618,7 @@ impl std::fmt::Display for PaymentUnit {
             PaymentUnit::Week => "Week",
             PaymentUnit::Year => "Year",
             PaymentUnit::Noop => "",
-            PaymentUnit::FallthroughString => "*",
+            PaymentUnit::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -650,8 +650,8 @@ pub enum FlsaStatus {
     SalariedNonexempt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FlsaStatus {
@@ -662,7 +662,7 @@ impl std::fmt::Display for FlsaStatus {
             FlsaStatus::Owner => "Owner",
             FlsaStatus::SalariedNonexempt => "Salaried Nonexempt",
             FlsaStatus::Noop => "",
-            FlsaStatus::FallthroughString => "*",
+            FlsaStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -979,8 +979,8 @@ pub enum EntityType {
     Trusteeship,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntityType {
@@ -999,7 +999,7 @@ impl std::fmt::Display for EntityType {
             EntityType::SoleProprietor => "Sole proprietor",
             EntityType::Trusteeship => "Trusteeship",
             EntityType::Noop => "",
-            EntityType::FallthroughString => "*",
+            EntityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1033,8 +1033,8 @@ pub enum Tier {
     Core,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Tier {
@@ -1046,7 +1046,7 @@ impl std::fmt::Display for Tier {
             Tier::ContractorOnly => "contractor_only",
             Tier::Core => "core",
             Tier::Noop => "",
-            Tier::FallthroughString => "*",
+            Tier::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1076,8 +1076,8 @@ pub enum CompanyStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompanyStatus {
@@ -1087,7 +1087,7 @@ impl std::fmt::Display for CompanyStatus {
             CompanyStatus::NotApproved => "Not Approved",
             CompanyStatus::Suspended => "Suspended",
             CompanyStatus::Noop => "",
-            CompanyStatus::FallthroughString => "*",
+            CompanyStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1406,8 +1406,8 @@ pub enum WageType {
     Hourly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WageType {
@@ -1416,7 +1416,7 @@ impl std::fmt::Display for WageType {
             WageType::Fixed => "Fixed",
             WageType::Hourly => "Hourly",
             WageType::Noop => "",
-            WageType::FallthroughString => "*",
+            WageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1444,8 +1444,8 @@ pub enum Type {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1454,7 +1454,7 @@ impl std::fmt::Display for Type {
             Type::Business => "Business",
             Type::Individual => "Individual",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1663,8 +1663,8 @@ pub enum PaymentMethod {
     HistoricalPayment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethod {
@@ -1675,7 +1675,7 @@ impl std::fmt::Display for PaymentMethod {
             PaymentMethod::DirectDeposit => "Direct Deposit",
             PaymentMethod::HistoricalPayment => "Historical Payment",
             PaymentMethod::Noop => "",
-            PaymentMethod::FallthroughString => "*",
+            PaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1884,8 +1884,8 @@ pub enum Status {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1895,7 +1895,7 @@ impl std::fmt::Display for Status {
             Status::Denied => "denied",
             Status::Pending => "pending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1923,8 +1923,8 @@ pub enum RequestType {
     Vacation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequestType {
@@ -1933,7 +1933,7 @@ impl std::fmt::Display for RequestType {
             RequestType::Sick => "sick",
             RequestType::Vacation => "vacation",
             RequestType::Noop => "",
-            RequestType::FallthroughString => "*",
+            RequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2024,7 +2024,7 @@ pub struct Approver {
 }
 
 /// The representation of a time off request.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimeOffRequest {
     /**
     * This value will be null if the request has not been approved.
@@ -2085,6 +2085,69 @@ pub struct TimeOffRequest {
     pub status: Option<Status>,
 }
 
+impl TimeOffRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> TimeOffRequestBuilder {
+        TimeOffRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `TimeOffRequest`.
+#[derive(Debug, Clone)]
+pub struct TimeOffRequestBuilder(TimeOffRequest);
+
+impl TimeOffRequestBuilder {
+    pub fn approver(mut self, approver: impl Into<Approver>) -> Self {
+        self.0.approver = Some(approver.into());
+        self
+    }
+
+    pub fn days(mut self, days: impl Into<Days>) -> Self {
+        self.0.days = Some(days.into());
+        self
+    }
+
+    pub fn employee(mut self, employee: impl Into<TimeOffRequestEmployee>) -> Self {
+        self.0.employee = Some(employee.into());
+        self
+    }
+
+    pub fn employee_note(mut self, employee_note: impl Into<String>) -> Self {
+        self.0.employee_note = employee_note.into();
+        self
+    }
+
+    pub fn employer_note(mut self, employer_note: impl Into<String>) -> Self {
+        self.0.employer_note = employer_note.into();
+        self
+    }
+
+    pub fn id(mut self, id: i64) -> Self {
+        self.0.id = id;
+        self
+    }
+
+    pub fn initiator(mut self, initiator: impl Into<Initiator>) -> Self {
+        self.0.initiator = Some(initiator.into());
+        self
+    }
+
+    pub fn request_type(mut self, request_type: impl Into<RequestType>) -> Self {
+        self.0.request_type = Some(request_type.into());
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<Status>) -> Self {
+        self.0.status = Some(status.into());
+        self
+    }
+
+    pub fn build(self) -> TimeOffRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Companies {
     #[serde(
@@ -2173,8 +2236,8 @@ pub enum Frequency {
     TwicePerMonth,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -2185,7 +2248,7 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "Monthly",
             Frequency::TwicePerMonth => "Twice per month",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2282,8 +2345,8 @@ pub enum AccountType {
     Savings,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -2292,7 +2355,7 @@ impl std::fmt::Display for AccountType {
             AccountType::Checking => "Checking",
             AccountType::Savings => "Savings",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2326,8 +2389,8 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2337,7 +2400,7 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::ReadyForVerification => "ready_for_verification",
             VerificationStatus::Verified => "verified",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2602,8 +2665,8 @@ pub enum DeductionReducesTaxableIncome {
     ReducesTaxableIncome,
     #[serde(rename = "unset")]
     Unset,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeductionReducesTaxableIncome {
@@ -2614,7 +2677,7 @@ impl std::fmt::Display for DeductionReducesTaxableIncome {
             }
             DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
             DeductionReducesTaxableIncome::Unset => "unset",
-            DeductionReducesTaxableIncome::FallthroughString => "*",
+            DeductionReducesTaxableIncome::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3078,8 +3141,8 @@ pub enum PayrollEmployeeCompensationsPaymentMethod {
     DirectDeposit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
@@ -3088,7 +3151,7 @@ impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
             PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
             PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
             PayrollEmployeeCompensationsPaymentMethod::Noop => "",
-            PayrollEmployeeCompensationsPaymentMethod::FallthroughString => "*",
+            PayrollEmployeeCompensationsPaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3369,10 +3432,10 @@ pub struct PayrollData {
     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub calculated_at: String,
+    pub calculated_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -3416,10 +3479,10 @@ pub struct PayrollData {
     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub payroll_deadline: String,
+    pub payroll_deadline: Option<chrono::DateTime<chrono::Utc>>,
     /**
     *
     */
@@ -3482,8 +3545,8 @@ pub enum CustomFieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomFieldType {
@@ -3495,7 +3558,7 @@ impl std::fmt::Display for CustomFieldType {
             CustomFieldType::Radio => "radio",
             CustomFieldType::Text => "text",
             CustomFieldType::Noop => "",
-            CustomFieldType::FallthroughString => "*",
+            CustomFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3626,8 +3689,8 @@ pub enum GustoPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GustoPersonType {
@@ -3637,7 +3700,7 @@ impl std::fmt::Display for GustoPersonType {
             GustoPersonType::Contractor => "Contractor",
             GustoPersonType::Employee => "Employee",
             GustoPersonType::Noop => "",
-            GustoPersonType::FallthroughString => "*",
+            GustoPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3808,7 +3871,7 @@ pub struct FederalTaxDetails {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
     /**
     * The ID of the employee in Gusto.
@@ -3848,6 +3911,52 @@ pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
     pub ytd_employee_deduction_amount: String,
 }
 
+impl PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequestBuilder {
+        PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest`.
+#[derive(Debug, Clone)]
+pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequestBuilder(
+    PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
+);
+
+impl PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequestBuilder {
+    pub fn benefit_id(mut self, benefit_id: f64) -> Self {
+        self.0.benefit_id = benefit_id;
+        self
+    }
+
+    pub fn tax_year(mut self, tax_year: f64) -> Self {
+        self.0.tax_year = tax_year;
+        self
+    }
+
+    pub fn ytd_company_contribution_amount(
+        mut self,
+        ytd_company_contribution_amount: impl Into<String>,
+    ) -> Self {
+        self.0.ytd_company_contribution_amount = ytd_company_contribution_amount.into();
+        self
+    }
+
+    pub fn ytd_employee_deduction_amount(
+        mut self,
+        ytd_employee_deduction_amount: impl Into<String>,
+    ) -> Self {
+        self.0.ytd_employee_deduction_amount = ytd_employee_deduction_amount.into();
+        self
+    }
+
+    pub fn build(self) -> PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EarningTypeListResponse {
     /**
@@ -3876,8 +3985,8 @@ pub enum Include {
     CustomFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Include {
@@ -3885,7 +3994,7 @@ impl std::fmt::Display for Include {
         match &*self {
             Include::CustomFields => "custom_fields",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3903,7 +4012,7 @@ impl Include {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutEmployeesRequest {
     #[serde(
         default,
@@ -3972,8 +4081,66 @@ pub struct PutEmployeesRequest {
     pub version: String,
 }
 
+impl PutEmployeesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutEmployeesRequestBuilder {
+        PutEmployeesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutEmployeesRequest`.
+#[derive(Debug, Clone)]
+pub struct PutEmployeesRequestBuilder(PutEmployeesRequest);
+
+impl PutEmployeesRequestBuilder {
+    pub fn date_of_birth(mut self, date_of_birth: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.date_of_birth = Some(date_of_birth.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn middle_initial(mut self, middle_initial: impl Into<String>) -> Self {
+        self.0.middle_initial = middle_initial.into();
+        self
+    }
+
+    pub fn ssn(mut self, ssn: impl Into<String>) -> Self {
+        self.0.ssn = ssn.into();
+        self
+    }
+
+    pub fn two_percent_shareholder(mut self, two_percent_shareholder: bool) -> Self {
+        self.0.two_percent_shareholder = Some(two_percent_shareholder);
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutEmployeesRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeesRequest {
     #[serde(
         default,
@@ -4028,8 +4195,56 @@ pub struct PostEmployeesRequest {
     pub ssn: String,
 }
 
+impl PostEmployeesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostEmployeesRequestBuilder {
+        PostEmployeesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostEmployeesRequest`.
+#[derive(Debug, Clone)]
+pub struct PostEmployeesRequestBuilder(PostEmployeesRequest);
+
+impl PostEmployeesRequestBuilder {
+    pub fn date_of_birth(mut self, date_of_birth: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.date_of_birth = Some(date_of_birth.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn middle_initial(mut self, middle_initial: impl Into<String>) -> Self {
+        self.0.middle_initial = middle_initial.into();
+        self
+    }
+
+    pub fn ssn(mut self, ssn: impl Into<String>) -> Self {
+        self.0.ssn = ssn.into();
+        self
+    }
+
+    pub fn build(self) -> PostEmployeesRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutJobRequest {
     #[serde(
         default,
@@ -4066,8 +4281,46 @@ pub struct PutJobRequest {
     pub version: String,
 }
 
+impl PutJobRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutJobRequestBuilder {
+        PutJobRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutJobRequest`.
+#[derive(Debug, Clone)]
+pub struct PutJobRequestBuilder(PutJobRequest);
+
+impl PutJobRequestBuilder {
+    pub fn hire_date(mut self, hire_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.hire_date = Some(hire_date.into());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: f64) -> Self {
+        self.0.location_id = location_id;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutJobRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostJobRequest {
     #[serde(
         default,
@@ -4095,8 +4348,41 @@ pub struct PostJobRequest {
     pub title: String,
 }
 
+impl PostJobRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostJobRequestBuilder {
+        PostJobRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostJobRequest`.
+#[derive(Debug, Clone)]
+pub struct PostJobRequestBuilder(PostJobRequest);
+
+impl PostJobRequestBuilder {
+    pub fn hire_date(mut self, hire_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.hire_date = Some(hire_date.into());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: f64) -> Self {
+        self.0.location_id = location_id;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn build(self) -> PostJobRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyLocationsRequest {
     /**
     * A unique identifier of the employee in Gusto.
@@ -4173,8 +4459,71 @@ pub struct PostCompanyLocationsRequest {
     pub zip: String,
 }
 
+impl PostCompanyLocationsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyLocationsRequestBuilder {
+        PostCompanyLocationsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyLocationsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyLocationsRequestBuilder(PostCompanyLocationsRequest);
+
+impl PostCompanyLocationsRequestBuilder {
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.0.city = city.into();
+        self
+    }
+
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.0.country = country.into();
+        self
+    }
+
+    pub fn filing_address(mut self, filing_address: bool) -> Self {
+        self.0.filing_address = Some(filing_address);
+        self
+    }
+
+    pub fn mailing_address(mut self, mailing_address: bool) -> Self {
+        self.0.mailing_address = Some(mailing_address);
+        self
+    }
+
+    pub fn phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.0.phone_number = phone_number.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn street_1(mut self, street_1: impl Into<String>) -> Self {
+        self.0.street_1 = street_1.into();
+        self
+    }
+
+    pub fn street_2(mut self, street_2: impl Into<String>) -> Self {
+        self.0.street_2 = street_2.into();
+        self
+    }
+
+    pub fn zip(mut self, zip: impl Into<String>) -> Self {
+        self.0.zip = zip.into();
+        self
+    }
+
+    pub fn build(self) -> PostCompanyLocationsRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutLocationRequest {
     /**
     *
@@ -4251,8 +4600,71 @@ pub struct PutLocationRequest {
     pub zip: String,
 }
 
+impl PutLocationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutLocationRequestBuilder {
+        PutLocationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutLocationRequest`.
+#[derive(Debug, Clone)]
+pub struct PutLocationRequestBuilder(PutLocationRequest);
+
+impl PutLocationRequestBuilder {
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.0.city = city.into();
+        self
+    }
+
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.0.country = country.into();
+        self
+    }
+
+    pub fn filing_address(mut self, filing_address: bool) -> Self {
+        self.0.filing_address = Some(filing_address);
+        self
+    }
+
+    pub fn mailing_address(mut self, mailing_address: bool) -> Self {
+        self.0.mailing_address = Some(mailing_address);
+        self
+    }
+
+    pub fn phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.0.phone_number = phone_number.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn street_1(mut self, street_1: impl Into<String>) -> Self {
+        self.0.street_1 = street_1.into();
+        self
+    }
+
+    pub fn street_2(mut self, street_2: impl Into<String>) -> Self {
+        self.0.street_2 = street_2.into();
+        self
+    }
+
+    pub fn zip(mut self, zip: impl Into<String>) -> Self {
+        self.0.zip = zip.into();
+        self
+    }
+
+    pub fn build(self) -> PutLocationRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutComntractorRequest {
     /**
     *
@@ -4330,8 +4742,71 @@ pub struct PutComntractorRequest {
     pub wage_type: Option<WageType>,
 }
 
+impl PutComntractorRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutComntractorRequestBuilder {
+        PutComntractorRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutComntractorRequest`.
+#[derive(Debug, Clone)]
+pub struct PutComntractorRequestBuilder(PutComntractorRequest);
+
+impl PutComntractorRequestBuilder {
+    pub fn business_name(mut self, business_name: impl Into<String>) -> Self {
+        self.0.business_name = business_name.into();
+        self
+    }
+
+    pub fn ein(mut self, ein: impl Into<String>) -> Self {
+        self.0.ein = ein.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn hourly_rate(mut self, hourly_rate: impl Into<String>) -> Self {
+        self.0.hourly_rate = hourly_rate.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn middle_initial(mut self, middle_initial: impl Into<String>) -> Self {
+        self.0.middle_initial = middle_initial.into();
+        self
+    }
+
+    pub fn start_date(mut self, start_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.start_date = Some(start_date.into());
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn wage_type(mut self, wage_type: impl Into<WageType>) -> Self {
+        self.0.wage_type = Some(wage_type.into());
+        self
+    }
+
+    pub fn build(self) -> PutComntractorRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyContractorsRequest {
     /**
     *
@@ -4410,8 +4885,76 @@ pub struct PostCompanyContractorsRequest {
     pub wage_type: WageType,
 }
 
+impl PostCompanyContractorsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyContractorsRequestBuilder {
+        PostCompanyContractorsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyContractorsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyContractorsRequestBuilder(PostCompanyContractorsRequest);
+
+impl PostCompanyContractorsRequestBuilder {
+    pub fn business_name(mut self, business_name: impl Into<String>) -> Self {
+        self.0.business_name = business_name.into();
+        self
+    }
+
+    pub fn ein(mut self, ein: impl Into<String>) -> Self {
+        self.0.ein = ein.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn middle_initial(mut self, middle_initial: impl Into<String>) -> Self {
+        self.0.middle_initial = middle_initial.into();
+        self
+    }
+
+    pub fn self_onboarding(mut self, self_onboarding: bool) -> Self {
+        self.0.self_onboarding = Some(self_onboarding);
+        self
+    }
+
+    pub fn start_date(mut self, start_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.start_date = Some(start_date.into());
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<Type>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn wage_type(mut self, wage_type: impl Into<WageType>) -> Self {
+        self.0.wage_type = wage_type.into();
+        self
+    }
+
+    pub fn build(self) -> PostCompanyContractorsRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompensationRequest {
     /**
     *
@@ -4443,8 +4986,46 @@ pub struct PutCompensationRequest {
     pub version: String,
 }
 
+impl PutCompensationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompensationRequestBuilder {
+        PutCompensationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompensationRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompensationRequestBuilder(PutCompensationRequest);
+
+impl PutCompensationRequestBuilder {
+    pub fn flsa_status(mut self, flsa_status: impl Into<FlsaStatus>) -> Self {
+        self.0.flsa_status = Some(flsa_status.into());
+        self
+    }
+
+    pub fn payment_unit(mut self, payment_unit: impl Into<PaymentUnit>) -> Self {
+        self.0.payment_unit = Some(payment_unit.into());
+        self
+    }
+
+    pub fn rate(mut self, rate: impl Into<String>) -> Self {
+        self.0.rate = rate.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutCompensationRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostJobCompensationsRequest {
     #[serde(
         default,
@@ -4473,8 +5054,46 @@ pub struct PostJobCompensationsRequest {
     pub rate: String,
 }
 
+impl PostJobCompensationsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostJobCompensationsRequestBuilder {
+        PostJobCompensationsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostJobCompensationsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostJobCompensationsRequestBuilder(PostJobCompensationsRequest);
+
+impl PostJobCompensationsRequestBuilder {
+    pub fn effective_date(mut self, effective_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.effective_date = Some(effective_date.into());
+        self
+    }
+
+    pub fn flsa_status(mut self, flsa_status: impl Into<FlsaStatus>) -> Self {
+        self.0.flsa_status = flsa_status.into();
+        self
+    }
+
+    pub fn payment_unit(mut self, payment_unit: impl Into<PaymentUnit>) -> Self {
+        self.0.payment_unit = payment_unit.into();
+        self
+    }
+
+    pub fn rate(mut self, rate: impl Into<String>) -> Self {
+        self.0.rate = rate.into();
+        self
+    }
+
+    pub fn build(self) -> PostJobCompensationsRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeGarnishmentsRequest {
     /**
     *
@@ -4543,7 +5162,70 @@ pub struct PostEmployeeGarnishmentsRequest {
     pub times: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostEmployeeGarnishmentsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostEmployeeGarnishmentsRequestBuilder {
+        PostEmployeeGarnishmentsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostEmployeeGarnishmentsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostEmployeeGarnishmentsRequestBuilder(PostEmployeeGarnishmentsRequest);
+
+impl PostEmployeeGarnishmentsRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn amount(mut self, amount: f64) -> Self {
+        self.0.amount = amount;
+        self
+    }
+
+    pub fn annual_maximum(mut self, annual_maximum: f64) -> Self {
+        self.0.annual_maximum = annual_maximum;
+        self
+    }
+
+    pub fn court_ordered(mut self, court_ordered: bool) -> Self {
+        self.0.court_ordered = Some(court_ordered);
+        self
+    }
+
+    pub fn deduct_as_percentage(mut self, deduct_as_percentage: bool) -> Self {
+        self.0.deduct_as_percentage = Some(deduct_as_percentage);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn pay_period_maximum(mut self, pay_period_maximum: f64) -> Self {
+        self.0.pay_period_maximum = pay_period_maximum;
+        self
+    }
+
+    pub fn recurring(mut self, recurring: bool) -> Self {
+        self.0.recurring = Some(recurring);
+        self
+    }
+
+    pub fn times(mut self, times: i64) -> Self {
+        self.0.times = times;
+        self
+    }
+
+    pub fn build(self) -> PostEmployeeGarnishmentsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutGarnishmentRequest {
     /**
     * Whether the employee is terminated.
@@ -4621,8 +5303,76 @@ pub struct PutGarnishmentRequest {
     pub version: String,
 }
 
+impl PutGarnishmentRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutGarnishmentRequestBuilder {
+        PutGarnishmentRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutGarnishmentRequest`.
+#[derive(Debug, Clone)]
+pub struct PutGarnishmentRequestBuilder(PutGarnishmentRequest);
+
+impl PutGarnishmentRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn amount(mut self, amount: f64) -> Self {
+        self.0.amount = amount;
+        self
+    }
+
+    pub fn annual_maximum(mut self, annual_maximum: f64) -> Self {
+        self.0.annual_maximum = annual_maximum;
+        self
+    }
+
+    pub fn court_ordered(mut self, court_ordered: bool) -> Self {
+        self.0.court_ordered = Some(court_ordered);
+        self
+    }
+
+    pub fn deduct_as_percentage(mut self, deduct_as_percentage: bool) -> Self {
+        self.0.deduct_as_percentage = Some(deduct_as_percentage);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn pay_period_maximum(mut self, pay_period_maximum: f64) -> Self {
+        self.0.pay_period_maximum = pay_period_maximum;
+        self
+    }
+
+    pub fn recurring(mut self, recurring: bool) -> Self {
+        self.0.recurring = Some(recurring);
+        self
+    }
+
+    pub fn times(mut self, times: i64) -> Self {
+        self.0.times = times;
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutGarnishmentRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeTerminationsRequest {
     #[serde(
         default,
@@ -4637,8 +5387,36 @@ pub struct PostEmployeeTerminationsRequest {
     pub run_termination_payroll: Option<bool>,
 }
 
+impl PostEmployeeTerminationsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostEmployeeTerminationsRequestBuilder {
+        PostEmployeeTerminationsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostEmployeeTerminationsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostEmployeeTerminationsRequestBuilder(PostEmployeeTerminationsRequest);
+
+impl PostEmployeeTerminationsRequestBuilder {
+    pub fn effective_date(mut self, effective_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.effective_date = Some(effective_date.into());
+        self
+    }
+
+    pub fn run_termination_payroll(mut self, run_termination_payroll: bool) -> Self {
+        self.0.run_termination_payroll = Some(run_termination_payroll);
+        self
+    }
+
+    pub fn build(self) -> PostEmployeeTerminationsRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutEmployeeHomeAddressRequest {
     /**
     *
@@ -4696,8 +5474,56 @@ pub struct PutEmployeeHomeAddressRequest {
     pub zip: String,
 }
 
+impl PutEmployeeHomeAddressRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutEmployeeHomeAddressRequestBuilder {
+        PutEmployeeHomeAddressRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutEmployeeHomeAddressRequest`.
+#[derive(Debug, Clone)]
+pub struct PutEmployeeHomeAddressRequestBuilder(PutEmployeeHomeAddressRequest);
+
+impl PutEmployeeHomeAddressRequestBuilder {
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.0.city = city.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn street_1(mut self, street_1: impl Into<String>) -> Self {
+        self.0.street_1 = street_1.into();
+        self
+    }
+
+    pub fn street_2(mut self, street_2: impl Into<String>) -> Self {
+        self.0.street_2 = street_2.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn zip(mut self, zip: impl Into<String>) -> Self {
+        self.0.zip = zip.into();
+        self
+    }
+
+    pub fn build(self) -> PutEmployeeHomeAddressRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyPaySchedulesScheduleRequest {
     /**
     *
@@ -4715,8 +5541,36 @@ pub struct PutCompanyPaySchedulesScheduleRequest {
     pub version: String,
 }
 
+impl PutCompanyPaySchedulesScheduleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompanyPaySchedulesScheduleRequestBuilder {
+        PutCompanyPaySchedulesScheduleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompanyPaySchedulesScheduleRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompanyPaySchedulesScheduleRequestBuilder(PutCompanyPaySchedulesScheduleRequest);
+
+impl PutCompanyPaySchedulesScheduleRequestBuilder {
+    pub fn auto_pilot(mut self, auto_pilot: bool) -> Self {
+        self.0.auto_pilot = Some(auto_pilot);
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutCompanyPaySchedulesScheduleRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyBankAccountsRequest {
     /**
     *
@@ -4743,8 +5597,41 @@ pub struct PostCompanyBankAccountsRequest {
     pub routing_number: String,
 }
 
+impl PostCompanyBankAccountsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyBankAccountsRequestBuilder {
+        PostCompanyBankAccountsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyBankAccountsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyBankAccountsRequestBuilder(PostCompanyBankAccountsRequest);
+
+impl PostCompanyBankAccountsRequestBuilder {
+    pub fn account_number(mut self, account_number: impl Into<String>) -> Self {
+        self.0.account_number = account_number.into();
+        self
+    }
+
+    pub fn account_type(mut self, account_type: impl Into<AccountType>) -> Self {
+        self.0.account_type = Some(account_type.into());
+        self
+    }
+
+    pub fn routing_number(mut self, routing_number: impl Into<String>) -> Self {
+        self.0.routing_number = routing_number.into();
+        self
+    }
+
+    pub fn build(self) -> PostCompanyBankAccountsRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyBankAccountsVerifyRequest {
     /**
     *
@@ -4766,8 +5653,36 @@ pub struct PutCompanyBankAccountsVerifyRequest {
     pub deposit_2: f64,
 }
 
+impl PutCompanyBankAccountsVerifyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompanyBankAccountsVerifyRequestBuilder {
+        PutCompanyBankAccountsVerifyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompanyBankAccountsVerifyRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompanyBankAccountsVerifyRequestBuilder(PutCompanyBankAccountsVerifyRequest);
+
+impl PutCompanyBankAccountsVerifyRequestBuilder {
+    pub fn deposit_1(mut self, deposit_1: f64) -> Self {
+        self.0.deposit_1 = deposit_1;
+        self
+    }
+
+    pub fn deposit_2(mut self, deposit_2: f64) -> Self {
+        self.0.deposit_2 = deposit_2;
+        self
+    }
+
+    pub fn build(self) -> PutCompanyBankAccountsVerifyRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyBenefitsRequest {
     /**
     *
@@ -4808,8 +5723,51 @@ pub struct PostCompanyBenefitsRequest {
     pub responsible_for_employer_taxes: Option<bool>,
 }
 
+impl PostCompanyBenefitsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyBenefitsRequestBuilder {
+        PostCompanyBenefitsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyBenefitsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyBenefitsRequestBuilder(PostCompanyBenefitsRequest);
+
+impl PostCompanyBenefitsRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn benefit_id(mut self, benefit_id: f64) -> Self {
+        self.0.benefit_id = benefit_id;
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn responsible_for_employee_w_2(mut self, responsible_for_employee_w_2: bool) -> Self {
+        self.0.responsible_for_employee_w_2 = Some(responsible_for_employee_w_2);
+        self
+    }
+
+    pub fn responsible_for_employer_taxes(mut self, responsible_for_employer_taxes: bool) -> Self {
+        self.0.responsible_for_employer_taxes = Some(responsible_for_employer_taxes);
+        self
+    }
+
+    pub fn build(self) -> PostCompanyBenefitsRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyBenefitRequest {
     /**
     *
@@ -4836,7 +5794,40 @@ pub struct PutCompanyBenefitRequest {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PutCompanyBenefitRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompanyBenefitRequestBuilder {
+        PutCompanyBenefitRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompanyBenefitRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompanyBenefitRequestBuilder(PutCompanyBenefitRequest);
+
+impl PutCompanyBenefitRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutCompanyBenefitRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyEarningTypesRequest {
     /**
     * A unique identifier of the employee in Gusto.
@@ -4849,7 +5840,44 @@ pub struct PostCompanyEarningTypesRequest {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl std::convert::From<String> for PostCompanyEarningTypesRequest {
+    fn from(name: String) -> Self {
+        PostCompanyEarningTypesRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PostCompanyEarningTypesRequest {
+    fn from(name: &str) -> Self {
+        PostCompanyEarningTypesRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PostCompanyEarningTypesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyEarningTypesRequestBuilder {
+        PostCompanyEarningTypesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyEarningTypesRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyEarningTypesRequestBuilder(PostCompanyEarningTypesRequest);
+
+impl PostCompanyEarningTypesRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PostCompanyEarningTypesRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyEarningTypeRequest {
     /**
     * A unique identifier of the employee in Gusto.
@@ -4862,8 +5890,45 @@ pub struct PutCompanyEarningTypeRequest {
     pub name: String,
 }
 
+impl std::convert::From<String> for PutCompanyEarningTypeRequest {
+    fn from(name: String) -> Self {
+        PutCompanyEarningTypeRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PutCompanyEarningTypeRequest {
+    fn from(name: &str) -> Self {
+        PutCompanyEarningTypeRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PutCompanyEarningTypeRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompanyEarningTypeRequestBuilder {
+        PutCompanyEarningTypeRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompanyEarningTypeRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompanyEarningTypeRequestBuilder(PutCompanyEarningTypeRequest);
+
+impl PutCompanyEarningTypeRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PutCompanyEarningTypeRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeBenefitsRequest {
     /**
     *
@@ -4964,7 +6029,102 @@ pub struct PostEmployeeBenefitsRequest {
     pub limit_option: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostEmployeeBenefitsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostEmployeeBenefitsRequestBuilder {
+        PostEmployeeBenefitsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostEmployeeBenefitsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostEmployeeBenefitsRequestBuilder(PostEmployeeBenefitsRequest);
+
+impl PostEmployeeBenefitsRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn catch_up(mut self, catch_up: bool) -> Self {
+        self.0.catch_up = Some(catch_up);
+        self
+    }
+
+    pub fn company_benefit_id(mut self, company_benefit_id: f64) -> Self {
+        self.0.company_benefit_id = company_benefit_id;
+        self
+    }
+
+    pub fn company_contribution(mut self, company_contribution: impl Into<String>) -> Self {
+        self.0.company_contribution = company_contribution.into();
+        self
+    }
+
+    pub fn company_contribution_annual_maximum(
+        mut self,
+        company_contribution_annual_maximum: impl Into<String>,
+    ) -> Self {
+        self.0.company_contribution_annual_maximum = company_contribution_annual_maximum.into();
+        self
+    }
+
+    pub fn contribute_as_percentage(mut self, contribute_as_percentage: bool) -> Self {
+        self.0.contribute_as_percentage = Some(contribute_as_percentage);
+        self
+    }
+
+    pub fn coverage_amount(mut self, coverage_amount: impl Into<String>) -> Self {
+        self.0.coverage_amount = coverage_amount.into();
+        self
+    }
+
+    pub fn coverage_salary_multiplier(
+        mut self,
+        coverage_salary_multiplier: impl Into<String>,
+    ) -> Self {
+        self.0.coverage_salary_multiplier = coverage_salary_multiplier.into();
+        self
+    }
+
+    pub fn deduct_as_percentage(mut self, deduct_as_percentage: bool) -> Self {
+        self.0.deduct_as_percentage = Some(deduct_as_percentage);
+        self
+    }
+
+    pub fn deduction_reduces_taxable_income(
+        mut self,
+        deduction_reduces_taxable_income: impl Into<DeductionReducesTaxableIncome>,
+    ) -> Self {
+        self.0.deduction_reduces_taxable_income = Some(deduction_reduces_taxable_income.into());
+        self
+    }
+
+    pub fn employee_deduction(mut self, employee_deduction: impl Into<String>) -> Self {
+        self.0.employee_deduction = employee_deduction.into();
+        self
+    }
+
+    pub fn employee_deduction_annual_maximum(
+        mut self,
+        employee_deduction_annual_maximum: impl Into<String>,
+    ) -> Self {
+        self.0.employee_deduction_annual_maximum = employee_deduction_annual_maximum.into();
+        self
+    }
+
+    pub fn limit_option(mut self, limit_option: impl Into<String>) -> Self {
+        self.0.limit_option = limit_option.into();
+        self
+    }
+
+    pub fn build(self) -> PostEmployeeBenefitsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutEmployeeBenefitRequest {
     /**
     * Whether the employee is terminated.
@@ -5065,6 +6225,101 @@ pub struct PutEmployeeBenefitRequest {
     pub version: String,
 }
 
+impl PutEmployeeBenefitRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutEmployeeBenefitRequestBuilder {
+        PutEmployeeBenefitRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutEmployeeBenefitRequest`.
+#[derive(Debug, Clone)]
+pub struct PutEmployeeBenefitRequestBuilder(PutEmployeeBenefitRequest);
+
+impl PutEmployeeBenefitRequestBuilder {
+    pub fn active(mut self, active: bool) -> Self {
+        self.0.active = Some(active);
+        self
+    }
+
+    pub fn catch_up(mut self, catch_up: bool) -> Self {
+        self.0.catch_up = Some(catch_up);
+        self
+    }
+
+    pub fn company_contribution(mut self, company_contribution: impl Into<String>) -> Self {
+        self.0.company_contribution = company_contribution.into();
+        self
+    }
+
+    pub fn company_contribution_annual_maximum(
+        mut self,
+        company_contribution_annual_maximum: impl Into<String>,
+    ) -> Self {
+        self.0.company_contribution_annual_maximum = company_contribution_annual_maximum.into();
+        self
+    }
+
+    pub fn contribute_as_percentage(mut self, contribute_as_percentage: bool) -> Self {
+        self.0.contribute_as_percentage = Some(contribute_as_percentage);
+        self
+    }
+
+    pub fn coverage_amount(mut self, coverage_amount: impl Into<String>) -> Self {
+        self.0.coverage_amount = coverage_amount.into();
+        self
+    }
+
+    pub fn coverage_salary_multiplier(
+        mut self,
+        coverage_salary_multiplier: impl Into<String>,
+    ) -> Self {
+        self.0.coverage_salary_multiplier = coverage_salary_multiplier.into();
+        self
+    }
+
+    pub fn deduct_as_percentage(mut self, deduct_as_percentage: bool) -> Self {
+        self.0.deduct_as_percentage = Some(deduct_as_percentage);
+        self
+    }
+
+    pub fn deduction_reduces_taxable_income(
+        mut self,
+        deduction_reduces_taxable_income: impl Into<DeductionReducesTaxableIncome>,
+    ) -> Self {
+        self.0.deduction_reduces_taxable_income = Some(deduction_reduces_taxable_income.into());
+        self
+    }
+
+    pub fn employee_deduction(mut self, employee_deduction: impl Into<String>) -> Self {
+        self.0.employee_deduction = employee_deduction.into();
+        self
+    }
+
+    pub fn employee_deduction_annual_maximum(
+        mut self,
+        employee_deduction_annual_maximum: impl Into<String>,
+    ) -> Self {
+        self.0.employee_deduction_annual_maximum = employee_deduction_annual_maximum.into();
+        self
+    }
+
+    pub fn limit_option(mut self, limit_option: impl Into<String>) -> Self {
+        self.0.limit_option = limit_option.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutEmployeeBenefitRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetCompanyPayrollsInclude {
     #[serde(rename = "benefits")]
@@ -5075,8 +6330,8 @@ pub enum GetCompanyPayrollsInclude {
     Taxes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCompanyPayrollsInclude {
@@ -5086,7 +6341,7 @@ impl std::fmt::Display for GetCompanyPayrollsInclude {
             GetCompanyPayrollsInclude::Deductions => "deductions",
             GetCompanyPayrollsInclude::Taxes => "taxes",
             GetCompanyPayrollsInclude::Noop => "",
-            GetCompanyPayrollsInclude::FallthroughString => "*",
+            GetCompanyPayrollsInclude::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5111,8 +6366,8 @@ pub enum OffCycleReason {
     Correction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OffCycleReason {
@@ -5121,7 +6376,7 @@ impl std::fmt::Display for OffCycleReason {
             OffCycleReason::Bonus => "Bonus",
             OffCycleReason::Correction => "Correction",
             OffCycleReason::Noop => "",
-            OffCycleReason::FallthroughString => "*",
+            OffCycleReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5138,7 +6393,7 @@ impl OffCycleReason {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyPayrollsRequest {
     #[serde(
         default,
@@ -5177,6 +6432,54 @@ pub struct PostCompanyPayrollsRequest {
     pub start_date: Option<chrono::NaiveDate>,
 }
 
+impl PostCompanyPayrollsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyPayrollsRequestBuilder {
+        PostCompanyPayrollsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyPayrollsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyPayrollsRequestBuilder(PostCompanyPayrollsRequest);
+
+impl PostCompanyPayrollsRequestBuilder {
+    pub fn check_date(mut self, check_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.check_date = Some(check_date.into());
+        self
+    }
+
+    pub fn employee_ids(mut self, employee_ids: Vec<i64>) -> Self {
+        self.0.employee_ids = employee_ids;
+        self
+    }
+
+    pub fn end_date(mut self, end_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.end_date = Some(end_date.into());
+        self
+    }
+
+    pub fn off_cycle(mut self, off_cycle: impl Into<String>) -> Self {
+        self.0.off_cycle = off_cycle.into();
+        self
+    }
+
+    pub fn off_cycle_reason(mut self, off_cycle_reason: impl Into<OffCycleReason>) -> Self {
+        self.0.off_cycle_reason = Some(off_cycle_reason.into());
+        self
+    }
+
+    pub fn start_date(mut self, start_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.start_date = Some(start_date.into());
+        self
+    }
+
+    pub fn build(self) -> PostCompanyPayrollsRequest {
+        self.0
+    }
+}
+
 /// An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
@@ -5282,7 +6585,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensations {
     pub paid_time_off: Vec<PayrollEmployeeCompensationsPaidTimeOff>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyPayrollsRequest {
     #[serde(
         default,
@@ -5301,6 +6604,37 @@ pub struct PutCompanyPayrollsRequest {
     pub version: String,
 }
 
+impl PutCompanyPayrollsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompanyPayrollsRequestBuilder {
+        PutCompanyPayrollsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompanyPayrollsRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompanyPayrollsRequestBuilder(PutCompanyPayrollsRequest);
+
+impl PutCompanyPayrollsRequestBuilder {
+    pub fn employee_compensations(
+        mut self,
+        employee_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensations>,
+    ) -> Self {
+        self.0.employee_compensations = employee_compensations;
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutCompanyPayrollsRequest {
+        self.0
+    }
+}
+
 /// Information for the user who will be the primary payroll administrator for the new company.
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct User {
@@ -5342,7 +6676,7 @@ pub struct User {
     pub phone: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostPartnerManagedCompaniesRequestCompany {
     /**
     * A unique identifier of the employee in Gusto.
@@ -5374,7 +6708,7 @@ pub struct PostPartnerManagedCompaniesRequestCompany {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostPartnerManagedCompaniesRequest {
     #[serde()]
     pub company: PostPartnerManagedCompaniesRequestCompany,
@@ -5385,6 +6719,37 @@ pub struct PostPartnerManagedCompaniesRequest {
     pub user: User,
 }
 
+impl PostPartnerManagedCompaniesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostPartnerManagedCompaniesRequestBuilder {
+        PostPartnerManagedCompaniesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostPartnerManagedCompaniesRequest`.
+#[derive(Debug, Clone)]
+pub struct PostPartnerManagedCompaniesRequestBuilder(PostPartnerManagedCompaniesRequest);
+
+impl PostPartnerManagedCompaniesRequestBuilder {
+    pub fn company(
+        mut self,
+        company: impl Into<PostPartnerManagedCompaniesRequestCompany>,
+    ) -> Self {
+        self.0.company = company.into();
+        self
+    }
+
+    pub fn user(mut self, user: impl Into<User>) -> Self {
+        self.0.user = user.into();
+        self
+    }
+
+    pub fn build(self) -> PostPartnerManagedCompaniesRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostPartnerManagedCompaniesResponse {
@@ -5484,7 +6849,7 @@ pub struct Addresses {
     pub zip: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostProvisionRequestCompany {
     /**
     * The locations for the company. This includes mailing, work, and filing addresses.
@@ -5543,7 +6908,7 @@ pub struct PostProvisionRequestCompany {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostProvisionRequest {
     #[serde()]
     pub company: PostProvisionRequestCompany,
@@ -5554,6 +6919,34 @@ pub struct PostProvisionRequest {
     pub user: User,
 }
 
+impl PostProvisionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostProvisionRequestBuilder {
+        PostProvisionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostProvisionRequest`.
+#[derive(Debug, Clone)]
+pub struct PostProvisionRequestBuilder(PostProvisionRequest);
+
+impl PostProvisionRequestBuilder {
+    pub fn company(mut self, company: impl Into<PostProvisionRequestCompany>) -> Self {
+        self.0.company = company.into();
+        self
+    }
+
+    pub fn user(mut self, user: impl Into<User>) -> Self {
+        self.0.user = user.into();
+        self
+    }
+
+    pub fn build(self) -> PostProvisionRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostProvisionResponse {
@@ -5602,8 +6995,8 @@ pub enum OnboardingPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OnboardingPersonType {
@@ -5612,7 +7005,7 @@ impl std::fmt::Display for OnboardingPersonType {
             OnboardingPersonType::Contractor => "Contractor",
             OnboardingPersonType::Employee => "Employee",
             OnboardingPersonType::Noop => "",
-            OnboardingPersonType::FallthroughString => "*",
+            OnboardingPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5630,7 +7023,7 @@ impl OnboardingPersonType {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyJobApplicantsRequest {
     #[serde(
         default,
@@ -5701,8 +7094,74 @@ pub struct PostCompanyJobApplicantsRequest {
     pub start_date: Option<chrono::NaiveDate>,
 }
 
+impl PostCompanyJobApplicantsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyJobApplicantsRequestBuilder {
+        PostCompanyJobApplicantsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyJobApplicantsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyJobApplicantsRequestBuilder(PostCompanyJobApplicantsRequest);
+
+impl PostCompanyJobApplicantsRequestBuilder {
+    pub fn date_of_birth(mut self, date_of_birth: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.date_of_birth = Some(date_of_birth.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn job_title(mut self, job_title: impl Into<String>) -> Self {
+        self.0.job_title = job_title.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn onboarding_person_type(
+        mut self,
+        onboarding_person_type: impl Into<OnboardingPersonType>,
+    ) -> Self {
+        self.0.onboarding_person_type = Some(onboarding_person_type.into());
+        self
+    }
+
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.0.phone = phone.into();
+        self
+    }
+
+    pub fn send_offer(mut self, send_offer: bool) -> Self {
+        self.0.send_offer = Some(send_offer);
+        self
+    }
+
+    pub fn start_date(mut self, start_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.start_date = Some(start_date.into());
+        self
+    }
+
+    pub fn build(self) -> PostCompanyJobApplicantsRequest {
+        self.0
+    }
+}
+
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyJobApplicantRequest {
     #[serde(
         default,
@@ -5773,6 +7232,72 @@ pub struct PutCompanyJobApplicantRequest {
     pub start_date: Option<chrono::NaiveDate>,
 }
 
+impl PutCompanyJobApplicantRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompanyJobApplicantRequestBuilder {
+        PutCompanyJobApplicantRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompanyJobApplicantRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompanyJobApplicantRequestBuilder(PutCompanyJobApplicantRequest);
+
+impl PutCompanyJobApplicantRequestBuilder {
+    pub fn date_of_birth(mut self, date_of_birth: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.date_of_birth = Some(date_of_birth.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn job_title(mut self, job_title: impl Into<String>) -> Self {
+        self.0.job_title = job_title.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn onboarding_person_type(
+        mut self,
+        onboarding_person_type: impl Into<OnboardingPersonType>,
+    ) -> Self {
+        self.0.onboarding_person_type = Some(onboarding_person_type.into());
+        self
+    }
+
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.0.phone = phone.into();
+        self
+    }
+
+    pub fn send_offer(mut self, send_offer: bool) -> Self {
+        self.0.send_offer = Some(send_offer);
+        self
+    }
+
+    pub fn start_date(mut self, start_date: impl Into<chrono::NaiveDate>) -> Self {
+        self.0.start_date = Some(start_date.into());
+        self
+    }
+
+    pub fn build(self) -> PutCompanyJobApplicantRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCompanyPayrollReversalsResponse {
     /**
@@ -5829,7 +7354,7 @@ pub struct GetCompanyPayrollReversalsResponse {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyAdminsRequest {
     /**
     * A unique identifier of the employee in Gusto.
@@ -5860,6 +7385,39 @@ pub struct PostCompanyAdminsRequest {
     pub last_name: String,
 }
 
+impl PostCompanyAdminsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCompanyAdminsRequestBuilder {
+        PostCompanyAdminsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCompanyAdminsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCompanyAdminsRequestBuilder(PostCompanyAdminsRequest);
+
+impl PostCompanyAdminsRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn build(self) -> PostCompanyAdminsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCompanyFederalTaxDetailsResponse {
     /**
@@ -5925,7 +7483,7 @@ pub struct GetCompanyFederalTaxDetailsResponse {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyFederalTaxDetailsRequest {
     /**
     * A unique identifier of the employee in Gusto.
@@ -5978,3 +7536,51 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     )]
     pub version: String,
 }
+
+impl PutCompanyFederalTaxDetailsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutCompanyFederalTaxDetailsRequestBuilder {
+        PutCompanyFederalTaxDetailsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutCompanyFederalTaxDetailsRequest`.
+#[derive(Debug, Clone)]
+pub struct PutCompanyFederalTaxDetailsRequestBuilder(PutCompanyFederalTaxDetailsRequest);
+
+impl PutCompanyFederalTaxDetailsRequestBuilder {
+    pub fn ein(mut self, ein: impl Into<String>) -> Self {
+        self.0.ein = ein.into();
+        self
+    }
+
+    pub fn filing_form(mut self, filing_form: impl Into<String>) -> Self {
+        self.0.filing_form = filing_form.into();
+        self
+    }
+
+    pub fn legal_name(mut self, legal_name: impl Into<String>) -> Self {
+        self.0.legal_name = legal_name.into();
+        self
+    }
+
+    pub fn tax_payer_type(mut self, tax_payer_type: impl Into<String>) -> Self {
+        self.0.tax_payer_type = tax_payer_type.into();
+        self
+    }
+
+    pub fn taxable_as_scorp(mut self, taxable_as_scorp: bool) -> Self {
+        self.0.taxable_as_scorp = Some(taxable_as_scorp);
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.0.version = version.into();
+        self
+    }
+
+    pub fn build(self) -> PutCompanyFederalTaxDetailsRequest {
+        self.0
+    }
+}
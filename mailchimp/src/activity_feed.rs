@@ -29,15 +29,17 @@ impl ActivityFeed {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::GetActivityFeedChimpChatterResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/activity-feed/chimp-chatter?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/activity-feed/chimp-chatter"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -33,21 +33,21 @@ impl AuthorizedApps {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::GetAuthorizedAppsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/authorized-apps?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/authorized-apps"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -69,20 +69,22 @@ impl AuthorizedApps {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        app_id: &str,
+        app_id: impl AsRef<str>,
     ) -> Result<crate::types::Apps> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/authorized-apps/{}?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/authorized-apps/{}",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
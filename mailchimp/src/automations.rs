@@ -43,36 +43,35 @@ impl Automations {
         since_start_time: Option<chrono::DateTime<chrono::Utc>>,
         status: crate::types::Status,
     ) -> Result<crate::types::GetAutomationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_create_time {
-            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_create_time", date.to_rfc3339()));
         }
         if let Some(date) = before_start_time {
-            query_args.push(("before_start_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_start_time", date.to_rfc3339()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if let Some(date) = since_create_time {
-            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_create_time", date.to_rfc3339()));
         }
         if let Some(date) = since_start_time {
-            query_args.push(("since_start_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_start_time", date.to_rfc3339()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/automations?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/automations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -111,20 +110,22 @@ impl Automations {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        workflow_id: &str,
+        workflow_id: impl AsRef<str>,
     ) -> Result<crate::types::Automations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let workflow_id = workflow_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/automations/{}?{}",
-            crate::progenitor_support::encode_path(workflow_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/automations/{}",
+                crate::progenitor_support::encode_path(workflow_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -141,7 +142,8 @@ impl Automations {
     *
     * * `workflow_id: &str` -- The unique id for the Automation workflow.
     */
-    pub async fn post_actions_pause_all_email(&self, workflow_id: &str) -> Result<()> {
+    pub async fn post_actions_pause_all_email(&self, workflow_id: impl AsRef<str>) -> Result<()> {
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/automations/{}/actions/pause-all-emails",
             crate::progenitor_support::encode_path(workflow_id),
@@ -161,7 +163,8 @@ impl Automations {
     *
     * * `workflow_id: &str` -- The unique id for the Automation workflow.
     */
-    pub async fn post_actions_start_all_email(&self, workflow_id: &str) -> Result<()> {
+    pub async fn post_actions_start_all_email(&self, workflow_id: impl AsRef<str>) -> Result<()> {
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/automations/{}/actions/start-all-emails",
             crate::progenitor_support::encode_path(workflow_id),
@@ -181,7 +184,8 @@ impl Automations {
     *
     * * `workflow_id: &str` -- The unique id for the Automation workflow.
     */
-    pub async fn archive(&self, workflow_id: &str) -> Result<()> {
+    pub async fn archive(&self, workflow_id: impl AsRef<str>) -> Result<()> {
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/automations/{}/actions/archive",
             crate::progenitor_support::encode_path(workflow_id),
@@ -201,7 +205,11 @@ impl Automations {
     *
     * * `workflow_id: &str` -- The unique id for the Automation workflow.
     */
-    pub async fn get_email(&self, workflow_id: &str) -> Result<crate::types::AutomationEmails> {
+    pub async fn get_email(
+        &self,
+        workflow_id: impl AsRef<str>,
+    ) -> Result<crate::types::AutomationEmails> {
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/automations/{}/emails",
             crate::progenitor_support::encode_path(workflow_id),
@@ -224,9 +232,11 @@ impl Automations {
     */
     pub async fn get_email_automations(
         &self,
-        workflow_id: &str,
-        workflow_email_id: &str,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
     ) -> Result<crate::types::Emails> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
         let url = format!(
             "/automations/{}/emails/{}",
             crate::progenitor_support::encode_path(workflow_id),
@@ -248,7 +258,13 @@ impl Automations {
     * * `workflow_id: &str` -- The unique id for the Automation workflow.
     * * `workflow_email_id: &str` -- The unique id for the Automation workflow email.
     */
-    pub async fn delete_emails(&self, workflow_id: &str, workflow_email_id: &str) -> Result<()> {
+    pub async fn delete_emails(
+        &self,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
         let url = format!(
             "/automations/{}/emails/{}",
             crate::progenitor_support::encode_path(workflow_id),
@@ -272,10 +288,12 @@ impl Automations {
     */
     pub async fn patch_email_workflow(
         &self,
-        workflow_id: &str,
-        workflow_email_id: &str,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
         body: &crate::types::UpdateInformationAboutASpecificWorkflowEmail,
     ) -> Result<crate::types::Emails> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
         let url = format!(
             "/automations/{}/emails/{}",
             crate::progenitor_support::encode_path(workflow_id),
@@ -301,9 +319,11 @@ impl Automations {
     */
     pub async fn get_emails_queue(
         &self,
-        workflow_id: &str,
-        workflow_email_id: &str,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
     ) -> Result<crate::types::GetAutomationsEmailsQueueResponse> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
         let url = format!(
             "/automations/{}/emails/{}/queue",
             crate::progenitor_support::encode_path(workflow_id),
@@ -327,10 +347,12 @@ impl Automations {
     */
     pub async fn post_emails_queue(
         &self,
-        workflow_id: &str,
-        workflow_email_id: &str,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
         body: &crate::types::SubscriberInAutomationQueue,
     ) -> Result<crate::types::SubscriberInAutomationQueueData> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
         let url = format!(
             "/automations/{}/emails/{}/queue",
             crate::progenitor_support::encode_path(workflow_id),
@@ -357,10 +379,13 @@ impl Automations {
     */
     pub async fn get_emails_queue_automations(
         &self,
-        workflow_id: &str,
-        workflow_email_id: &str,
-        subscriber_hash: &str,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<crate::types::SubscriberInAutomationQueueData> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/automations/{}/emails/{}/queue/{}",
             crate::progenitor_support::encode_path(workflow_id),
@@ -385,9 +410,11 @@ impl Automations {
     */
     pub async fn post_emails_actions_pause(
         &self,
-        workflow_id: &str,
-        workflow_email_id: &str,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
     ) -> Result<()> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
         let url = format!(
             "/automations/{}/emails/{}/actions/pause",
             crate::progenitor_support::encode_path(workflow_id),
@@ -411,9 +438,11 @@ impl Automations {
     */
     pub async fn post_emails_actions_start(
         &self,
-        workflow_id: &str,
-        workflow_email_id: &str,
+        workflow_id: impl AsRef<str>,
+        workflow_email_id: impl AsRef<str>,
     ) -> Result<()> {
+        let workflow_id = workflow_id.as_ref();
+        let workflow_email_id = workflow_email_id.as_ref();
         let url = format!(
             "/automations/{}/emails/{}/actions/start",
             crate::progenitor_support::encode_path(workflow_id),
@@ -436,8 +465,9 @@ impl Automations {
     */
     pub async fn get_removed_subscriber(
         &self,
-        workflow_id: &str,
+        workflow_id: impl AsRef<str>,
     ) -> Result<crate::types::RemovedSubscribers> {
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/automations/{}/removed-subscribers",
             crate::progenitor_support::encode_path(workflow_id),
@@ -459,9 +489,10 @@ impl Automations {
     */
     pub async fn post_removed_subscriber(
         &self,
-        workflow_id: &str,
+        workflow_id: impl AsRef<str>,
         body: &crate::types::SubscriberInAutomationQueue,
     ) -> Result<crate::types::Subscribers> {
+        let workflow_id = workflow_id.as_ref();
         let url = format!(
             "/automations/{}/removed-subscribers",
             crate::progenitor_support::encode_path(workflow_id),
@@ -486,9 +517,11 @@ impl Automations {
     */
     pub async fn get_removed_subscriber_automations(
         &self,
-        workflow_id: &str,
-        subscriber_hash: &str,
+        workflow_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<crate::types::Subscribers> {
+        let workflow_id = workflow_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/automations/{}/removed-subscribers/{}",
             crate::progenitor_support::encode_path(workflow_id),
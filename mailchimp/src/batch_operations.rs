@@ -0,0 +1,275 @@
+//! Run many operations in batches, for example to sync a large audience.
+//!
+//! A batch runs up to 500 operations in the background. Once it finished, the
+//! responses of its operations are downloaded as a gzipped tarball of JSON files,
+//! which is unpacked into one result per operation, matched to the operation by
+//! its `operation_id`.
+//!
+//! ```no_run
+//! # async fn run(mailchimp: mailchimp_api::Client) -> anyhow::Result<()> {
+//! use mailchimp_api::batches::BatchOperation;
+//!
+//! let emails = ["jane@example.com", "john@example.com"];
+//! let operations = emails
+//!     .iter()
+//!     .map(|email| {
+//!         BatchOperation::new(reqwest::Method::POST, "/lists/57afe96172/members")
+//!             .operation_id(email)
+//!             .json(&serde_json::json!({"email_address": email, "status": "subscribed"}))
+//!     })
+//!     .collect::<anyhow::Result<Vec<_>>>()?;
+//!
+//! for result in mailchimp.batches().run(&operations).await? {
+//!     if !result.is_success() {
+//!         println!("{} failed: {}", result.operation_id, result.response);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::{collections::BTreeMap, io::Read, time::Duration};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    batches::Batches,
+    types::{Batch, BatchOperationsStatus},
+};
+
+/// The most operations a batch can hold.
+pub const MAX_BATCH_OPERATIONS: usize = 500;
+
+/// How long to wait between two checks of the status of a batch.
+const BATCH_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// An operation of a batch: a request to the API, without the host.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct BatchOperation {
+    pub method: String,
+    /// The path of the request, like `/lists/57afe96172/members`.
+    pub path: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+    /// The JSON body of the request, as a string.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+    /// Identifies the result of the operation in the results of the batch.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub operation_id: String,
+}
+
+impl BatchOperation {
+    pub fn new(method: reqwest::Method, path: &str) -> Self {
+        BatchOperation {
+            method: method.to_string(),
+            path: path.to_string(),
+            params: Default::default(),
+            body: String::new(),
+            operation_id: String::new(),
+        }
+    }
+
+    /// Add a query parameter to the request.
+    pub fn param(mut self, name: &str, value: &str) -> Self {
+        self.params.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Send the given body with the request, as JSON.
+    pub fn json<T: Serialize>(mut self, body: &T) -> Result<Self> {
+        self.body = serde_json::to_string(body)?;
+        Ok(self)
+    }
+
+    pub fn operation_id(mut self, operation_id: &str) -> Self {
+        self.operation_id = operation_id.to_string();
+        self
+    }
+}
+
+/// The outcome of an operation of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationResult {
+    pub operation_id: String,
+    pub status_code: reqwest::StatusCode,
+    /// The body of the response, `Null` when it was empty.
+    pub response: serde_json::Value,
+}
+
+impl OperationResult {
+    pub fn is_success(&self) -> bool {
+        self.status_code.is_success()
+    }
+
+    /// Deserialize the response of the operation, or return its error when the
+    /// operation failed.
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if !self.is_success() {
+            let detail = self
+                .response
+                .get("detail")
+                .and_then(|d| d.as_str())
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| self.response.to_string());
+            return Err(anyhow!(
+                "code: {}, operation {} failed: {}",
+                self.status_code,
+                self.operation_id,
+                detail
+            ));
+        }
+
+        Ok(serde_json::from_value(self.response.clone())?)
+    }
+}
+
+/// An operation result, as it is in the JSON files of the results of a batch.
+#[derive(Deserialize)]
+struct RawOperationResult {
+    #[serde(default)]
+    status_code: u16,
+    #[serde(default)]
+    operation_id: String,
+    /// The body of the response, encoded as a JSON string.
+    #[serde(default)]
+    response: String,
+}
+
+#[derive(Serialize)]
+struct BatchRequest<'a> {
+    operations: &'a [BatchOperation],
+}
+
+impl Batches {
+    /// Start a batch of at most `MAX_BATCH_OPERATIONS` operations.
+    pub async fn submit(&self, operations: &[BatchOperation]) -> Result<Batch> {
+        if operations.len() > MAX_BATCH_OPERATIONS {
+            return Err(anyhow!(
+                "a batch holds at most {} operations, got {}",
+                MAX_BATCH_OPERATIONS,
+                operations.len()
+            ));
+        }
+
+        let body = BatchRequest { operations };
+        self.client
+            .post(
+                "/batches",
+                Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+            )
+            .await
+    }
+
+    /// Wait until a batch finished running its operations.
+    pub async fn wait(&self, batch_id: &str) -> Result<Batch> {
+        loop {
+            let batch = self.get_batches(&[], &[], batch_id).await?;
+            if batch.status == Some(BatchOperationsStatus::Finished) {
+                return Ok(batch);
+            }
+            crate::runtime::sleep(BATCH_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Download the results of a finished batch, and unpack one result per
+    /// operation.
+    pub async fn results(&self, batch: &Batch) -> Result<Vec<OperationResult>> {
+        if batch.response_body_url.is_empty() {
+            return Err(anyhow!("batch {} has no results yet", batch.id));
+        }
+
+        // The URL is signed, so the request is sent without the token.
+        let req = self.client.client.get(&batch.response_body_url).build()?;
+        let resp = self.client.execute(req).await?;
+        let status = resp.status();
+        let body = resp.bytes().await?;
+        if !status.is_success() {
+            return Err(anyhow!(
+                "code: {}, downloading the results of batch {} failed: {:?}",
+                status,
+                batch.id,
+                String::from_utf8_lossy(&body),
+            ));
+        }
+
+        let mut tar = Vec::new();
+        flate2::read::GzDecoder::new(&body[..]).read_to_end(&mut tar)?;
+
+        let mut results = Vec::new();
+        for (name, contents) in tar_files(&tar)? {
+            if !name.ends_with(".json") {
+                continue;
+            }
+            let raw: Vec<RawOperationResult> = serde_json::from_slice(contents)?;
+            for r in raw {
+                results.push(OperationResult {
+                    operation_id: r.operation_id,
+                    status_code: reqwest::StatusCode::from_u16(r.status_code)?,
+                    response: if r.response.is_empty() {
+                        serde_json::Value::Null
+                    } else {
+                        serde_json::from_str(&r.response)?
+                    },
+                });
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Run any number of operations, in batches of `MAX_BATCH_OPERATIONS` one after
+    /// the other, and return the results of all of them.
+    pub async fn run(&self, operations: &[BatchOperation]) -> Result<Vec<OperationResult>> {
+        let mut results = Vec::new();
+        for chunk in operations.chunks(MAX_BATCH_OPERATIONS) {
+            let batch = self.submit(chunk).await?;
+            let batch = self.wait(&batch.id).await?;
+            results.append(&mut self.results(&batch).await?);
+        }
+
+        Ok(results)
+    }
+}
+
+/// The regular files of a tar archive, with their contents.
+fn tar_files(tar: &[u8]) -> Result<Vec<(String, &[u8])>> {
+    const BLOCK: usize = 512;
+
+    let mut files = Vec::new();
+    let mut offset = 0;
+    while offset + BLOCK <= tar.len() {
+        let header = &tar[offset..offset + BLOCK];
+        // The archive ends with empty blocks.
+        if header.iter().all(|b| *b == 0) {
+            break;
+        }
+
+        let field = |range: std::ops::Range<usize>| {
+            let f = &header[range];
+            let end = f.iter().position(|b| *b == 0).unwrap_or(f.len());
+            String::from_utf8_lossy(&f[..end]).trim().to_string()
+        };
+        let name = field(0..100);
+        let size = usize::from_str_radix(&field(124..136), 8)
+            .map_err(|e| anyhow!("invalid size of {} in the tar archive: {}", name, e))?;
+        let kind = header[156];
+
+        let start = offset + BLOCK;
+        let end = start + size;
+        if end > tar.len() {
+            return Err(anyhow!("the tar archive is truncated in {}", name));
+        }
+        if kind == b'0' || kind == 0 {
+            files.push((name, &tar[start..end]));
+        }
+
+        // The contents are padded to a whole number of blocks.
+        offset = end + (BLOCK - size % BLOCK) % BLOCK;
+    }
+
+    Ok(files)
+}
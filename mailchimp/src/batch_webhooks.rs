@@ -33,21 +33,21 @@ impl BatchWebhooks {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::BatchWebhooks> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/batch-webhooks?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/batch-webhooks"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -83,20 +83,22 @@ impl BatchWebhooks {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        batch_webhook_id: &str,
+        batch_webhook_id: impl AsRef<str>,
     ) -> Result<crate::types::Webhooks> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let batch_webhook_id = batch_webhook_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/batch-webhooks/{}?{}",
-            crate::progenitor_support::encode_path(batch_webhook_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/batch-webhooks/{}",
+                crate::progenitor_support::encode_path(batch_webhook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -113,7 +115,8 @@ impl BatchWebhooks {
     *
     * * `batch_webhook_id: &str` -- The unique id for the batch webhook.
     */
-    pub async fn delete(&self, batch_webhook_id: &str) -> Result<()> {
+    pub async fn delete(&self, batch_webhook_id: impl AsRef<str>) -> Result<()> {
+        let batch_webhook_id = batch_webhook_id.as_ref();
         let url = format!(
             "/batch-webhooks/{}",
             crate::progenitor_support::encode_path(batch_webhook_id),
@@ -135,9 +138,10 @@ impl BatchWebhooks {
     */
     pub async fn patch(
         &self,
-        batch_webhook_id: &str,
+        batch_webhook_id: impl AsRef<str>,
         body: &crate::types::BatchWebhook,
     ) -> Result<crate::types::Webhooks> {
+        let batch_webhook_id = batch_webhook_id.as_ref();
         let url = format!(
             "/batch-webhooks/{}",
             crate::progenitor_support::encode_path(batch_webhook_id),
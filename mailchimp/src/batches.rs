@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::batch_operations::{BatchOperation, OperationResult, MAX_BATCH_OPERATIONS};
 use crate::Client;
 
 pub struct Batches {
@@ -33,21 +34,20 @@ impl Batches {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::BatchOperations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/batches?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/batches"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -86,20 +86,22 @@ impl Batches {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        batch_id: &str,
+        batch_id: impl AsRef<str>,
     ) -> Result<crate::types::Batch> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let batch_id = batch_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/batches/{}?{}",
-            crate::progenitor_support::encode_path(batch_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/batches/{}",
+                crate::progenitor_support::encode_path(batch_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -116,7 +118,8 @@ impl Batches {
     *
     * * `batch_id: &str` -- The unique id for the batch operation.
     */
-    pub async fn delete(&self, batch_id: &str) -> Result<()> {
+    pub async fn delete(&self, batch_id: impl AsRef<str>) -> Result<()> {
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/batches/{}",
             crate::progenitor_support::encode_path(batch_id),
@@ -33,21 +33,21 @@ impl CampaignFolders {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::CampaignFolders> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/campaign-folders?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/campaign-folders"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -86,20 +86,22 @@ impl CampaignFolders {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        folder_id: &str,
+        folder_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let folder_id = folder_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaign-folders/{}?{}",
-            crate::progenitor_support::encode_path(folder_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/campaign-folders/{}",
+                crate::progenitor_support::encode_path(folder_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -116,7 +118,8 @@ impl CampaignFolders {
     *
     * * `folder_id: &str` -- The unique id for the campaign folder.
     */
-    pub async fn delete(&self, folder_id: &str) -> Result<()> {
+    pub async fn delete(&self, folder_id: impl AsRef<str>) -> Result<()> {
+        let folder_id = folder_id.as_ref();
         let url = format!(
             "/campaign-folders/{}",
             crate::progenitor_support::encode_path(folder_id),
@@ -138,9 +141,10 @@ impl CampaignFolders {
     */
     pub async fn patch(
         &self,
-        folder_id: &str,
+        folder_id: impl AsRef<str>,
         body: &crate::types::GalleryFolder,
     ) -> Result<crate::types::CampaignFolder> {
+        let folder_id = folder_id.as_ref();
         let url = format!(
             "/campaign-folders/{}",
             crate::progenitor_support::encode_path(folder_id),
@@ -55,54 +55,53 @@ impl Campaigns {
         sort_field: crate::types::SortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GetCampaignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_create_time {
-            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_create_time", date.to_rfc3339()));
         }
         if let Some(date) = before_send_time {
-            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_send_time", date.to_rfc3339()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if !list_id.is_empty() {
-            query_args.push(("list_id".to_string(), list_id.to_string()));
+            query_args.push(("list_id", list_id.to_string()));
         }
         if !member_id.is_empty() {
-            query_args.push(("member_id".to_string(), member_id.to_string()));
+            query_args.push(("member_id", member_id.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if let Some(date) = since_create_time {
-            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_create_time", date.to_rfc3339()));
         }
         if let Some(date) = since_send_time {
-            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_send_time", date.to_rfc3339()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/campaigns?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/campaigns"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -141,20 +140,22 @@ impl Campaigns {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::Campaign> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/campaigns/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -171,7 +172,8 @@ impl Campaigns {
     *
     * * `campaign_id: &str` -- The unique id for the campaign.
     */
-    pub async fn delete(&self, campaign_id: &str) -> Result<()> {
+    pub async fn delete(&self, campaign_id: impl AsRef<str>) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}",
             crate::progenitor_support::encode_path(campaign_id),
@@ -193,9 +195,10 @@ impl Campaigns {
     */
     pub async fn patch(
         &self,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         body: &crate::types::CampaignData,
     ) -> Result<crate::types::Campaign> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}",
             crate::progenitor_support::encode_path(campaign_id),
@@ -217,7 +220,8 @@ impl Campaigns {
     *
     * * `campaign_id: &str` -- The unique id for the campaign.
     */
-    pub async fn post_actions_cancel_send(&self, campaign_id: &str) -> Result<()> {
+    pub async fn post_actions_cancel_send(&self, campaign_id: impl AsRef<str>) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/cancel-send",
             crate::progenitor_support::encode_path(campaign_id),
@@ -239,8 +243,9 @@ impl Campaigns {
     */
     pub async fn post_actions_replicate(
         &self,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignDataType> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/replicate",
             crate::progenitor_support::encode_path(campaign_id),
@@ -260,7 +265,8 @@ impl Campaigns {
     *
     * * `campaign_id: &str` -- The unique id for the campaign.
     */
-    pub async fn post_actions_send(&self, campaign_id: &str) -> Result<()> {
+    pub async fn post_actions_send(&self, campaign_id: impl AsRef<str>) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/send",
             crate::progenitor_support::encode_path(campaign_id),
@@ -282,9 +288,10 @@ impl Campaigns {
     */
     pub async fn post_actions_schedule(
         &self,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         body: &crate::types::PostCampaignsActionsScheduleRequest,
     ) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/schedule",
             crate::progenitor_support::encode_path(campaign_id),
@@ -306,7 +313,8 @@ impl Campaigns {
     *
     * * `campaign_id: &str` -- The unique id for the campaign.
     */
-    pub async fn post_actions_unschedule(&self, campaign_id: &str) -> Result<()> {
+    pub async fn post_actions_unschedule(&self, campaign_id: impl AsRef<str>) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/unschedule",
             crate::progenitor_support::encode_path(campaign_id),
@@ -328,9 +336,10 @@ impl Campaigns {
     */
     pub async fn post_actions_test(
         &self,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         body: &crate::types::PostCampaignsActionsTestRequest,
     ) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/test",
             crate::progenitor_support::encode_path(campaign_id),
@@ -352,7 +361,8 @@ impl Campaigns {
     *
     * * `campaign_id: &str` -- The unique id for the campaign.
     */
-    pub async fn post_actions_pause(&self, campaign_id: &str) -> Result<()> {
+    pub async fn post_actions_pause(&self, campaign_id: impl AsRef<str>) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/pause",
             crate::progenitor_support::encode_path(campaign_id),
@@ -372,7 +382,8 @@ impl Campaigns {
     *
     * * `campaign_id: &str` -- The unique id for the campaign.
     */
-    pub async fn post_actions_resume(&self, campaign_id: &str) -> Result<()> {
+    pub async fn post_actions_resume(&self, campaign_id: impl AsRef<str>) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/resume",
             crate::progenitor_support::encode_path(campaign_id),
@@ -394,8 +405,9 @@ impl Campaigns {
     */
     pub async fn post_actions_create_resend(
         &self,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignDataType> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/actions/create-resend",
             crate::progenitor_support::encode_path(campaign_id),
@@ -421,20 +433,22 @@ impl Campaigns {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignContent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/content?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/campaigns/{}/content",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -453,9 +467,10 @@ impl Campaigns {
     */
     pub async fn put_content(
         &self,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         body: &crate::types::CampaignContentData,
     ) -> Result<crate::types::CampaignContent> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/content",
             crate::progenitor_support::encode_path(campaign_id),
@@ -483,20 +498,22 @@ impl Campaigns {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignReports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/feedback?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/campaigns/{}/feedback",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -515,9 +532,10 @@ impl Campaigns {
     */
     pub async fn post_feedback(
         &self,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         body: &crate::types::CampaignFeedback,
     ) -> Result<crate::types::CampaignFeedbackData> {
+        let campaign_id = campaign_id.as_ref();
         let url = format!(
             "/campaigns/{}/feedback",
             crate::progenitor_support::encode_path(campaign_id),
@@ -546,22 +564,25 @@ impl Campaigns {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        feedback_id: &str,
+        campaign_id: impl AsRef<str>,
+        feedback_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignFeedbackData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let feedback_id = feedback_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/feedback/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(feedback_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/campaigns/{}/feedback/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(feedback_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -579,7 +600,13 @@ impl Campaigns {
     * * `campaign_id: &str` -- The unique id for the campaign.
     * * `feedback_id: &str` -- The unique id for the feedback message.
     */
-    pub async fn delete_feedback(&self, campaign_id: &str, feedback_id: &str) -> Result<()> {
+    pub async fn delete_feedback(
+        &self,
+        campaign_id: impl AsRef<str>,
+        feedback_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let campaign_id = campaign_id.as_ref();
+        let feedback_id = feedback_id.as_ref();
         let url = format!(
             "/campaigns/{}/feedback/{}",
             crate::progenitor_support::encode_path(campaign_id),
@@ -603,10 +630,12 @@ impl Campaigns {
     */
     pub async fn patch_feedback(
         &self,
-        campaign_id: &str,
-        feedback_id: &str,
+        campaign_id: impl AsRef<str>,
+        feedback_id: impl AsRef<str>,
         body: &crate::types::CampaignFeedbackDataType,
     ) -> Result<crate::types::CampaignFeedbackData> {
+        let campaign_id = campaign_id.as_ref();
+        let feedback_id = feedback_id.as_ref();
         let url = format!(
             "/campaigns/{}/feedback/{}",
             crate::progenitor_support::encode_path(campaign_id),
@@ -635,20 +664,22 @@ impl Campaigns {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::SendChecklist> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/send-checklist?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/campaigns/{}/send-checklist",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
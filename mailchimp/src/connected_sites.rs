@@ -33,21 +33,21 @@ impl ConnectedSites {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::ConnectedSites> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/connected-sites?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/connected-sites"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -83,20 +83,22 @@ impl ConnectedSites {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        connected_site_id: &str,
+        connected_site_id: impl AsRef<str>,
     ) -> Result<crate::types::Sites> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let connected_site_id = connected_site_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/connected-sites/{}?{}",
-            crate::progenitor_support::encode_path(connected_site_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/connected-sites/{}",
+                crate::progenitor_support::encode_path(connected_site_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -113,7 +115,8 @@ impl ConnectedSites {
     *
     * * `connected_site_id: &str` -- The unique identifier for the site.
     */
-    pub async fn delete(&self, connected_site_id: &str) -> Result<()> {
+    pub async fn delete(&self, connected_site_id: impl AsRef<str>) -> Result<()> {
+        let connected_site_id = connected_site_id.as_ref();
         let url = format!(
             "/connected-sites/{}",
             crate::progenitor_support::encode_path(connected_site_id),
@@ -135,8 +138,9 @@ impl ConnectedSites {
     */
     pub async fn post_actions_verify_script_installation(
         &self,
-        connected_site_id: &str,
+        connected_site_id: impl AsRef<str>,
     ) -> Result<()> {
+        let connected_site_id = connected_site_id.as_ref();
         let url = format!(
             "/connected-sites/{}/actions/verify-script-installation",
             crate::progenitor_support::encode_path(connected_site_id),
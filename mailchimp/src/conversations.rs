@@ -39,33 +39,29 @@ impl Conversations {
         list_id: &str,
         campaign_id: &str,
     ) -> Result<crate::types::TrackedConversations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !campaign_id.is_empty() {
-            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+            query_args.push(("campaign_id", campaign_id.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !has_unread_messages.to_string().is_empty() {
-            query_args.push((
-                "has_unread_messages".to_string(),
-                has_unread_messages.to_string(),
-            ));
+            query_args.push(("has_unread_messages", has_unread_messages.to_string()));
         }
         if !list_id.is_empty() {
-            query_args.push(("list_id".to_string(), list_id.to_string()));
+            query_args.push(("list_id", list_id.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/conversations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -87,20 +83,22 @@ impl Conversations {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        conversation_id: &str,
+        conversation_id: impl AsRef<str>,
     ) -> Result<crate::types::Conversation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let conversation_id = conversation_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/conversations/{}?{}",
-            crate::progenitor_support::encode_path(conversation_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/conversations/{}",
+                crate::progenitor_support::encode_path(conversation_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -126,32 +124,34 @@ impl Conversations {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        conversation_id: &str,
+        conversation_id: impl AsRef<str>,
         is_read: crate::types::IsRead,
         before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
         since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::CollectionOfConversationMessages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let conversation_id = conversation_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_timestamp {
-            query_args.push(("before_timestamp".to_string(), date.to_rfc3339()));
+            query_args.push(("before_timestamp", date.to_rfc3339()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !is_read.to_string().is_empty() {
-            query_args.push(("is_read".to_string(), is_read.to_string()));
+            query_args.push(("is_read", is_read.to_string()));
         }
         if let Some(date) = since_timestamp {
-            query_args.push(("since_timestamp".to_string(), date.to_rfc3339()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/conversations/{}/messages?{}",
-            crate::progenitor_support::encode_path(conversation_id),
-            query_
+            query_args.push(("since_timestamp", date.to_rfc3339()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/conversations/{}/messages",
+                crate::progenitor_support::encode_path(conversation_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -175,22 +175,25 @@ impl Conversations {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        conversation_id: &str,
-        message_id: &str,
+        conversation_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
     ) -> Result<crate::types::ConversationMessage> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let conversation_id = conversation_id.as_ref();
+        let message_id = message_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/conversations/{}/messages/{}?{}",
-            crate::progenitor_support::encode_path(conversation_id),
-            crate::progenitor_support::encode_path(message_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/conversations/{}/messages/{}",
+                crate::progenitor_support::encode_path(conversation_id),
+                crate::progenitor_support::encode_path(message_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
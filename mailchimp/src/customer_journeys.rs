@@ -32,8 +32,8 @@ impl CustomerJourneys {
     ) -> Result<()> {
         let url = format!(
             "/customer-journeys/journeys/{}/steps/{}/actions/trigger",
-            crate::progenitor_support::encode_path(&journey_id.to_string()),
-            crate::progenitor_support::encode_path(&step_id.to_string()),
+            crate::progenitor_support::encode_path(&journey_id),
+            crate::progenitor_support::encode_path(&step_id),
         );
 
         self.client
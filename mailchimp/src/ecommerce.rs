@@ -41,33 +41,33 @@ impl Ecommerce {
         customer_id: &str,
         has_outreach: bool,
     ) -> Result<crate::types::OrdersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !campaign_id.is_empty() {
-            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+            query_args.push(("campaign_id", campaign_id.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !customer_id.is_empty() {
-            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+            query_args.push(("customer_id", customer_id.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if has_outreach {
-            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+            query_args.push(("has_outreach", has_outreach.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !outreach_id.is_empty() {
-            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
+            query_args.push(("outreach_id", outreach_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ecommerce/orders?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/ecommerce/orders"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -93,21 +93,21 @@ impl Ecommerce {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::ECommerceStores> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ecommerce/stores?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/ecommerce/stores"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -146,20 +146,22 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
+        store_id: impl AsRef<str>,
     ) -> Result<crate::types::Stores> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}",
+                crate::progenitor_support::encode_path(store_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -176,7 +178,8 @@ impl Ecommerce {
     *
     * * `store_id: &str` -- The name of the folder.
     */
-    pub async fn delete_stores(&self, store_id: &str) -> Result<()> {
+    pub async fn delete_stores(&self, store_id: impl AsRef<str>) -> Result<()> {
+        let store_id = store_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -198,9 +201,10 @@ impl Ecommerce {
     */
     pub async fn patch_stores(
         &self,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         body: &crate::types::ECommerceStoreData,
     ) -> Result<crate::types::Stores> {
+        let store_id = store_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -232,26 +236,28 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
+        store_id: impl AsRef<str>,
     ) -> Result<crate::types::CartsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts?{}",
-            crate::progenitor_support::encode_path(store_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/carts",
+                crate::progenitor_support::encode_path(store_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -270,9 +276,10 @@ impl Ecommerce {
     */
     pub async fn post_stores_cart(
         &self,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         body: &crate::types::ECommerceCart,
     ) -> Result<crate::types::Carts> {
+        let store_id = store_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/carts",
             crate::progenitor_support::encode_path(store_id),
@@ -301,22 +308,25 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        cart_id: &str,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
     ) -> Result<crate::types::Carts> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(cart_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/carts/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -334,7 +344,13 @@ impl Ecommerce {
     * * `store_id: &str` -- The name of the folder.
     * * `cart_id: &str` -- The name of the folder.
     */
-    pub async fn delete_stores_carts(&self, store_id: &str, cart_id: &str) -> Result<()> {
+    pub async fn delete_stores_carts(
+        &self,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/carts/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -358,10 +374,12 @@ impl Ecommerce {
     */
     pub async fn patch_stores_carts(
         &self,
-        store_id: &str,
-        cart_id: &str,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
         body: &crate::types::ECommerceCartData,
     ) -> Result<crate::types::Carts> {
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/carts/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -395,28 +413,31 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
-        cart_id: &str,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
     ) -> Result<crate::types::CartLines> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts/{}/lines?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(cart_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/carts/{}/lines",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -436,10 +457,12 @@ impl Ecommerce {
     */
     pub async fn post_stores_carts_line(
         &self,
-        store_id: &str,
-        cart_id: &str,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
         body: &crate::types::ECommerceCartLineItemData,
     ) -> Result<crate::types::ECommerceCartLineItem> {
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/carts/{}/lines",
             crate::progenitor_support::encode_path(store_id),
@@ -470,24 +493,28 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        cart_id: &str,
-        line_id: &str,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
+        line_id: impl AsRef<str>,
     ) -> Result<crate::types::ECommerceCartLineItem> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
+        let line_id = line_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts/{}/lines/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(cart_id),
-            crate::progenitor_support::encode_path(line_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/carts/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+                crate::progenitor_support::encode_path(line_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -508,10 +535,13 @@ impl Ecommerce {
     */
     pub async fn delete_stores_carts_lines(
         &self,
-        store_id: &str,
-        cart_id: &str,
-        line_id: &str,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
+        line_id: impl AsRef<str>,
     ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
+        let line_id = line_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/carts/{}/lines/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -537,11 +567,14 @@ impl Ecommerce {
     */
     pub async fn patch_stores_carts_lines(
         &self,
-        store_id: &str,
-        cart_id: &str,
-        line_id: &str,
+        store_id: impl AsRef<str>,
+        cart_id: impl AsRef<str>,
+        line_id: impl AsRef<str>,
         body: &crate::types::ECommerceCartLineItemDataType,
     ) -> Result<crate::types::ECommerceCartLineItem> {
+        let store_id = store_id.as_ref();
+        let cart_id = cart_id.as_ref();
+        let line_id = line_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/carts/{}/lines/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -576,30 +609,32 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         email_address: &str,
     ) -> Result<crate::types::Customers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email_address.is_empty() {
-            query_args.push(("email_address".to_string(), email_address.to_string()));
+            query_args.push(("email_address", email_address.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/customers?{}",
-            crate::progenitor_support::encode_path(store_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/customers",
+                crate::progenitor_support::encode_path(store_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -618,9 +653,10 @@ impl Ecommerce {
     */
     pub async fn post_stores_customer(
         &self,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         body: &crate::types::ECommerceCustomerData,
     ) -> Result<crate::types::Customer> {
+        let store_id = store_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/customers",
             crate::progenitor_support::encode_path(store_id),
@@ -649,22 +685,25 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        customer_id: &str,
+        store_id: impl AsRef<str>,
+        customer_id: impl AsRef<str>,
     ) -> Result<crate::types::Customer> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/customers/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/customers/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -684,10 +723,12 @@ impl Ecommerce {
     */
     pub async fn put_stores_customers(
         &self,
-        store_id: &str,
-        customer_id: &str,
+        store_id: impl AsRef<str>,
+        customer_id: impl AsRef<str>,
         body: &crate::types::ECommerceCustomerDataType,
     ) -> Result<crate::types::Customer> {
+        let store_id = store_id.as_ref();
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/customers/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -711,7 +752,13 @@ impl Ecommerce {
     * * `store_id: &str` -- The name of the folder.
     * * `customer_id: &str` -- The id for the customer of a store.
     */
-    pub async fn delete_stores_customers(&self, store_id: &str, customer_id: &str) -> Result<()> {
+    pub async fn delete_stores_customers(
+        &self,
+        store_id: impl AsRef<str>,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/customers/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -735,10 +782,12 @@ impl Ecommerce {
     */
     pub async fn patch_stores_customers(
         &self,
-        store_id: &str,
-        customer_id: &str,
+        store_id: impl AsRef<str>,
+        customer_id: impl AsRef<str>,
         body: &crate::types::ECommerceCartCustomer,
     ) -> Result<crate::types::Customer> {
+        let store_id = store_id.as_ref();
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/customers/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -771,26 +820,28 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
+        store_id: impl AsRef<str>,
     ) -> Result<crate::types::PromoRulesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules?{}",
-            crate::progenitor_support::encode_path(store_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/promo-rules",
+                crate::progenitor_support::encode_path(store_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -809,9 +860,10 @@ impl Ecommerce {
     */
     pub async fn post_stores_promorule(
         &self,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         body: &crate::types::ECommercePromoRule,
     ) -> Result<crate::types::PromoRules> {
+        let store_id = store_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/promo-rules",
             crate::progenitor_support::encode_path(store_id),
@@ -840,22 +892,25 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        promo_rule_id: &str,
+        store_id: impl AsRef<str>,
+        promo_rule_id: impl AsRef<str>,
     ) -> Result<crate::types::PromoRules> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let promo_rule_id = promo_rule_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(promo_rule_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/promo-rules/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -875,9 +930,11 @@ impl Ecommerce {
     */
     pub async fn delete_stores_promorules(
         &self,
-        store_id: &str,
-        promo_rule_id: &str,
+        store_id: impl AsRef<str>,
+        promo_rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let promo_rule_id = promo_rule_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -901,10 +958,12 @@ impl Ecommerce {
     */
     pub async fn patch_stores_promorules(
         &self,
-        store_id: &str,
-        promo_rule_id: &str,
+        store_id: impl AsRef<str>,
+        promo_rule_id: impl AsRef<str>,
         body: &crate::types::ECommercePromoRuleData,
     ) -> Result<crate::types::PromoRules> {
+        let store_id = store_id.as_ref();
+        let promo_rule_id = promo_rule_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -938,28 +997,31 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        promo_rule_id: &str,
-        store_id: &str,
+        promo_rule_id: impl AsRef<str>,
+        store_id: impl AsRef<str>,
     ) -> Result<crate::types::PromoCodesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let promo_rule_id = promo_rule_id.as_ref();
+        let store_id = store_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules/{}/promo-codes?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(promo_rule_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -979,10 +1041,12 @@ impl Ecommerce {
     */
     pub async fn post_stores_promocode(
         &self,
-        store_id: &str,
-        promo_rule_id: &str,
+        store_id: impl AsRef<str>,
+        promo_rule_id: impl AsRef<str>,
         body: &crate::types::ECommercePromoCode,
     ) -> Result<crate::types::PromoCodes> {
+        let store_id = store_id.as_ref();
+        let promo_rule_id = promo_rule_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}/promo-codes",
             crate::progenitor_support::encode_path(store_id),
@@ -1013,24 +1077,28 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        promo_rule_id: &str,
-        promo_code_id: &str,
+        store_id: impl AsRef<str>,
+        promo_rule_id: impl AsRef<str>,
+        promo_code_id: impl AsRef<str>,
     ) -> Result<crate::types::PromoCodes> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let promo_rule_id = promo_rule_id.as_ref();
+        let promo_code_id = promo_code_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(promo_rule_id),
-            crate::progenitor_support::encode_path(promo_code_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+                crate::progenitor_support::encode_path(promo_code_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1051,10 +1119,13 @@ impl Ecommerce {
     */
     pub async fn delete_stores_promocodes(
         &self,
-        store_id: &str,
-        promo_rule_id: &str,
-        promo_code_id: &str,
+        store_id: impl AsRef<str>,
+        promo_rule_id: impl AsRef<str>,
+        promo_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let promo_rule_id = promo_rule_id.as_ref();
+        let promo_code_id = promo_code_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1080,11 +1151,14 @@ impl Ecommerce {
     */
     pub async fn patch_stores_promocodes(
         &self,
-        store_id: &str,
-        promo_rule_id: &str,
-        promo_code_id: &str,
+        store_id: impl AsRef<str>,
+        promo_rule_id: impl AsRef<str>,
+        promo_code_id: impl AsRef<str>,
         body: &crate::types::ECommercePromoCodeData,
     ) -> Result<crate::types::PromoCodes> {
+        let store_id = store_id.as_ref();
+        let promo_rule_id = promo_rule_id.as_ref();
+        let promo_code_id = promo_code_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1122,42 +1196,44 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         customer_id: &str,
         has_outreach: bool,
         campaign_id: &str,
         outreach_id: &str,
     ) -> Result<crate::types::OrdersDataType> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !campaign_id.is_empty() {
-            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+            query_args.push(("campaign_id", campaign_id.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !customer_id.is_empty() {
-            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+            query_args.push(("customer_id", customer_id.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if has_outreach {
-            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+            query_args.push(("has_outreach", has_outreach.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !outreach_id.is_empty() {
-            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders?{}",
-            crate::progenitor_support::encode_path(store_id),
-            query_
+            query_args.push(("outreach_id", outreach_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/orders",
+                crate::progenitor_support::encode_path(store_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1176,9 +1252,10 @@ impl Ecommerce {
     */
     pub async fn post_stores_order(
         &self,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         body: &crate::types::ECommerceOrder,
     ) -> Result<crate::types::Orders> {
+        let store_id = store_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/orders",
             crate::progenitor_support::encode_path(store_id),
@@ -1207,22 +1284,25 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        order_id: &str,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
     ) -> Result<crate::types::Orders> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(order_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/orders/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1240,7 +1320,13 @@ impl Ecommerce {
     * * `store_id: &str` -- The name of the folder.
     * * `order_id: &str` -- The id for the order in a store.
     */
-    pub async fn delete_stores_orders(&self, store_id: &str, order_id: &str) -> Result<()> {
+    pub async fn delete_stores_orders(
+        &self,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/orders/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1264,10 +1350,12 @@ impl Ecommerce {
     */
     pub async fn patch_stores_orders(
         &self,
-        store_id: &str,
-        order_id: &str,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
         body: &crate::types::ECommerceOrderData,
     ) -> Result<crate::types::Orders> {
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/orders/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1301,28 +1389,31 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
-        order_id: &str,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
     ) -> Result<crate::types::OrderLines> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders/{}/lines?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(order_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/orders/{}/lines",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1342,10 +1433,12 @@ impl Ecommerce {
     */
     pub async fn post_stores_orders_line(
         &self,
-        store_id: &str,
-        order_id: &str,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
         body: &crate::types::ECommerceOrderLineItem,
     ) -> Result<crate::types::Lines> {
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/orders/{}/lines",
             crate::progenitor_support::encode_path(store_id),
@@ -1376,24 +1469,28 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        order_id: &str,
-        line_id: &str,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
+        line_id: impl AsRef<str>,
     ) -> Result<crate::types::Lines> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
+        let line_id = line_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders/{}/lines/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(line_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/orders/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(line_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1414,10 +1511,13 @@ impl Ecommerce {
     */
     pub async fn delete_stores_orders_lines(
         &self,
-        store_id: &str,
-        order_id: &str,
-        line_id: &str,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
+        line_id: impl AsRef<str>,
     ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
+        let line_id = line_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/orders/{}/lines/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1443,11 +1543,14 @@ impl Ecommerce {
     */
     pub async fn patch_stores_orders_lines(
         &self,
-        store_id: &str,
-        order_id: &str,
-        line_id: &str,
+        store_id: impl AsRef<str>,
+        order_id: impl AsRef<str>,
+        line_id: impl AsRef<str>,
         body: &crate::types::ECommerceOrderLineItemData,
     ) -> Result<crate::types::Lines> {
+        let store_id = store_id.as_ref();
+        let order_id = order_id.as_ref();
+        let line_id = line_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/orders/{}/lines/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1481,26 +1584,28 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
+        store_id: impl AsRef<str>,
     ) -> Result<crate::types::ProductsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products?{}",
-            crate::progenitor_support::encode_path(store_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/products",
+                crate::progenitor_support::encode_path(store_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1519,9 +1624,10 @@ impl Ecommerce {
     */
     pub async fn post_stores_product(
         &self,
-        store_id: &str,
+        store_id: impl AsRef<str>,
         body: &crate::types::ECommerceProductData,
     ) -> Result<crate::types::ECommerceProduct> {
+        let store_id = store_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products",
             crate::progenitor_support::encode_path(store_id),
@@ -1550,22 +1656,25 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        product_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
     ) -> Result<crate::types::ECommerceProduct> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/products/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1583,7 +1692,13 @@ impl Ecommerce {
     * * `store_id: &str` -- The name of the folder.
     * * `product_id: &str` -- The id for the product of a store.
     */
-    pub async fn delete_stores_products(&self, store_id: &str, product_id: &str) -> Result<()> {
+    pub async fn delete_stores_products(
+        &self,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1607,10 +1722,12 @@ impl Ecommerce {
     */
     pub async fn patch_stores_products(
         &self,
-        store_id: &str,
-        product_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
         body: &crate::types::ECommerceProductDataType,
     ) -> Result<crate::types::ECommerceProduct> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1644,28 +1761,31 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
-        product_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
     ) -> Result<crate::types::EcommerceProductVariants> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/variants?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/products/{}/variants",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1685,10 +1805,12 @@ impl Ecommerce {
     */
     pub async fn post_stores_products_variant(
         &self,
-        store_id: &str,
-        product_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
         body: &crate::types::ECommerceProductVariant,
     ) -> Result<crate::types::Variants> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants",
             crate::progenitor_support::encode_path(store_id),
@@ -1719,24 +1841,28 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        product_id: &str,
-        variant_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+        variant_id: impl AsRef<str>,
     ) -> Result<crate::types::Variants> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let variant_id = variant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/variants/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(variant_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/products/{}/variants/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(variant_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1757,11 +1883,14 @@ impl Ecommerce {
     */
     pub async fn put_stores_products_variants(
         &self,
-        store_id: &str,
-        product_id: &str,
-        variant_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+        variant_id: impl AsRef<str>,
         body: &crate::types::ECommerceProductVariant,
     ) -> Result<crate::types::Variants> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let variant_id = variant_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1789,10 +1918,13 @@ impl Ecommerce {
     */
     pub async fn delete_stores_products_variants(
         &self,
-        store_id: &str,
-        product_id: &str,
-        variant_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+        variant_id: impl AsRef<str>,
     ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let variant_id = variant_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1818,11 +1950,14 @@ impl Ecommerce {
     */
     pub async fn patch_stores_products_variants(
         &self,
-        store_id: &str,
-        product_id: &str,
-        variant_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+        variant_id: impl AsRef<str>,
         body: &crate::types::ECommerceProductVariantData,
     ) -> Result<crate::types::Variants> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let variant_id = variant_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1857,28 +1992,31 @@ impl Ecommerce {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        store_id: &str,
-        product_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
     ) -> Result<crate::types::EcommerceProductImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/images?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/products/{}/images",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1898,10 +2036,12 @@ impl Ecommerce {
     */
     pub async fn post_stores_products_image(
         &self,
-        store_id: &str,
-        product_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
         body: &crate::types::ECommerceProductImage,
     ) -> Result<crate::types::Images> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}/images",
             crate::progenitor_support::encode_path(store_id),
@@ -1932,24 +2072,28 @@ impl Ecommerce {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        store_id: &str,
-        product_id: &str,
-        image_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
     ) -> Result<crate::types::Images> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/images/{}?{}",
-            crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(image_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/ecommerce/stores/{}/products/{}/images/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1970,10 +2114,13 @@ impl Ecommerce {
     */
     pub async fn delete_stores_products_images(
         &self,
-        store_id: &str,
-        product_id: &str,
-        image_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
     ) -> Result<()> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}/images/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -1999,11 +2146,14 @@ impl Ecommerce {
     */
     pub async fn patch_stores_products_images(
         &self,
-        store_id: &str,
-        product_id: &str,
-        image_id: &str,
+        store_id: impl AsRef<str>,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         body: &crate::types::ECommerceProductImageData,
     ) -> Result<crate::types::Images> {
+        let store_id = store_id.as_ref();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/ecommerce/stores/{}/products/{}/images/{}",
             crate::progenitor_support::encode_path(store_id),
@@ -37,27 +37,26 @@ impl FacebookAds {
         sort_field: crate::types::GetAllFacebookAdsSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GetAllFacebookAdsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/facebook-ads?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/facebook-ads"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -78,21 +77,23 @@ impl FacebookAds {
     pub async fn get(
         &self,
         fields: &[String],
-        outreach_id: &str,
+        outreach_id: impl AsRef<str>,
         exclude_fields: &[String],
     ) -> Result<crate::types::FacebookAdsAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let outreach_id = outreach_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/facebook-ads/{}?{}",
-            crate::progenitor_support::encode_path(outreach_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/facebook-ads/{}",
+                crate::progenitor_support::encode_path(outreach_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -45,42 +45,39 @@ impl FileManager {
         sort_field: crate::types::GetFileManagerFilesSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::FileManager> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_created_at.is_empty() {
-            query_args.push((
-                "before_created_at".to_string(),
-                before_created_at.to_string(),
-            ));
+            query_args.push(("before_created_at", before_created_at.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_by.is_empty() {
-            query_args.push(("created_by".to_string(), created_by.to_string()));
+            query_args.push(("created_by", created_by.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_created_at.is_empty() {
-            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+            query_args.push(("since_created_at", since_created_at.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/file-manager/files?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/file-manager/files"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -116,20 +113,22 @@ impl FileManager {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        file_id: &str,
+        file_id: impl AsRef<str>,
     ) -> Result<crate::types::Files> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let file_id = file_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/file-manager/files/{}?{}",
-            crate::progenitor_support::encode_path(file_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/file-manager/files/{}",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -146,7 +145,8 @@ impl FileManager {
     *
     * * `file_id: &str` -- The unique id for the File Manager file.
     */
-    pub async fn delete_files(&self, file_id: &str) -> Result<()> {
+    pub async fn delete_files(&self, file_id: impl AsRef<str>) -> Result<()> {
+        let file_id = file_id.as_ref();
         let url = format!(
             "/file-manager/files/{}",
             crate::progenitor_support::encode_path(file_id),
@@ -168,9 +168,10 @@ impl FileManager {
     */
     pub async fn patch_files(
         &self,
-        file_id: &str,
+        file_id: impl AsRef<str>,
         body: &crate::types::GalleryFileData,
     ) -> Result<crate::types::Files> {
+        let file_id = file_id.as_ref();
         let url = format!(
             "/file-manager/files/{}",
             crate::progenitor_support::encode_path(file_id),
@@ -208,33 +209,32 @@ impl FileManager {
         before_created_at: &str,
         since_created_at: &str,
     ) -> Result<crate::types::FileManagerFolders> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_created_at.is_empty() {
-            query_args.push((
-                "before_created_at".to_string(),
-                before_created_at.to_string(),
-            ));
+            query_args.push(("before_created_at", before_created_at.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_by.is_empty() {
-            query_args.push(("created_by".to_string(), created_by.to_string()));
+            query_args.push(("created_by", created_by.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_created_at.is_empty() {
-            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+            query_args.push(("since_created_at", since_created_at.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/file-manager/folders?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/file-manager/folders"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -273,20 +273,22 @@ impl FileManager {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        folder_id: &str,
+        folder_id: impl AsRef<str>,
     ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let folder_id = folder_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/file-manager/folders/{}?{}",
-            crate::progenitor_support::encode_path(folder_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/file-manager/folders/{}",
+                crate::progenitor_support::encode_path(folder_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -303,7 +305,8 @@ impl FileManager {
     *
     * * `folder_id: &str` -- The unique id for the File Manager folder.
     */
-    pub async fn delete_folders(&self, folder_id: &str) -> Result<()> {
+    pub async fn delete_folders(&self, folder_id: impl AsRef<str>) -> Result<()> {
+        let folder_id = folder_id.as_ref();
         let url = format!(
             "/file-manager/folders/{}",
             crate::progenitor_support::encode_path(folder_id),
@@ -325,9 +328,10 @@ impl FileManager {
     */
     pub async fn patch_folders(
         &self,
-        folder_id: &str,
+        folder_id: impl AsRef<str>,
         body: &crate::types::GalleryFolder,
     ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
+        let folder_id = folder_id.as_ref();
         let url = format!(
             "/file-manager/folders/{}",
             crate::progenitor_support::encode_path(folder_id),
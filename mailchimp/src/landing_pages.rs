@@ -35,24 +35,23 @@ impl LandingPages {
         exclude_fields: &[String],
         count: i64,
     ) -> Result<crate::types::GetAllLandingPagesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/landing-pages?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/landing-pages"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -73,12 +72,11 @@ impl LandingPages {
         use_default_list: bool,
         body: &crate::types::LandingPageData,
     ) -> Result<crate::types::LandingPage> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_default_list {
-            query_args.push(("use_default_list".to_string(), use_default_list.to_string()));
+            query_args.push(("use_default_list", use_default_list.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/landing-pages?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/landing-pages"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -102,20 +100,22 @@ impl LandingPages {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        page_id: &str,
+        page_id: impl AsRef<str>,
     ) -> Result<crate::types::LandingPage> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let page_id = page_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/landing-pages/{}?{}",
-            crate::progenitor_support::encode_path(page_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/landing-pages/{}",
+                crate::progenitor_support::encode_path(page_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -132,7 +132,8 @@ impl LandingPages {
     *
     * * `page_id: &str` -- The unique id for the page.
     */
-    pub async fn delete(&self, page_id: &str) -> Result<()> {
+    pub async fn delete(&self, page_id: impl AsRef<str>) -> Result<()> {
+        let page_id = page_id.as_ref();
         let url = format!(
             "/landing-pages/{}",
             crate::progenitor_support::encode_path(page_id),
@@ -154,9 +155,10 @@ impl LandingPages {
     */
     pub async fn patch(
         &self,
-        page_id: &str,
+        page_id: impl AsRef<str>,
         body: &crate::types::LandingPageDataType,
     ) -> Result<crate::types::LandingPage> {
+        let page_id = page_id.as_ref();
         let url = format!(
             "/landing-pages/{}",
             crate::progenitor_support::encode_path(page_id),
@@ -178,7 +180,11 @@ impl LandingPages {
     *
     * * `page_id: &str` -- The unique id for the page.
     */
-    pub async fn post_actions_publish(&self, page_id: &str) -> Result<crate::types::LandingPage> {
+    pub async fn post_actions_publish(
+        &self,
+        page_id: impl AsRef<str>,
+    ) -> Result<crate::types::LandingPage> {
+        let page_id = page_id.as_ref();
         let url = format!(
             "/landing-pages/{}/actions/publish",
             crate::progenitor_support::encode_path(page_id),
@@ -198,7 +204,8 @@ impl LandingPages {
     *
     * * `page_id: &str` -- The unique id for the page.
     */
-    pub async fn post_actions_unpublish(&self, page_id: &str) -> Result<()> {
+    pub async fn post_actions_unpublish(&self, page_id: impl AsRef<str>) -> Result<()> {
+        let page_id = page_id.as_ref();
         let url = format!(
             "/landing-pages/{}/actions/unpublish",
             crate::progenitor_support::encode_path(page_id),
@@ -224,20 +231,22 @@ impl LandingPages {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        page_id: &str,
+        page_id: impl AsRef<str>,
     ) -> Result<crate::types::LandingPageContent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let page_id = page_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/landing-pages/{}/content?{}",
-            crate::progenitor_support::encode_path(page_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/landing-pages/{}/content",
+                crate::progenitor_support::encode_path(page_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
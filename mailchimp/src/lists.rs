@@ -51,66 +51,53 @@ impl Lists {
         has_ecommerce_store: bool,
         include_total_contacts: bool,
     ) -> Result<crate::types::SubscriberLists> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_campaign_last_sent.is_empty() {
             query_args.push((
-                "before_campaign_last_sent".to_string(),
+                "before_campaign_last_sent",
                 before_campaign_last_sent.to_string(),
             ));
         }
         if !before_date_created.is_empty() {
-            query_args.push((
-                "before_date_created".to_string(),
-                before_date_created.to_string(),
-            ));
+            query_args.push(("before_date_created", before_date_created.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if has_ecommerce_store {
-            query_args.push((
-                "has_ecommerce_store".to_string(),
-                has_ecommerce_store.to_string(),
-            ));
+            query_args.push(("has_ecommerce_store", has_ecommerce_store.to_string()));
         }
         if include_total_contacts {
-            query_args.push((
-                "include_total_contacts".to_string(),
-                include_total_contacts.to_string(),
-            ));
+            query_args.push(("include_total_contacts", include_total_contacts.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_campaign_last_sent.is_empty() {
             query_args.push((
-                "since_campaign_last_sent".to_string(),
+                "since_campaign_last_sent",
                 since_campaign_last_sent.to_string(),
             ));
         }
         if !since_date_created.is_empty() {
-            query_args.push((
-                "since_date_created".to_string(),
-                since_date_created.to_string(),
-            ));
+            query_args.push(("since_date_created", since_date_created.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/lists?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/lists"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -147,27 +134,23 @@ impl Lists {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        list_id: &str,
+        list_id: impl AsRef<str>,
         include_total_contacts: bool,
     ) -> Result<crate::types::Lists> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if include_total_contacts {
-            query_args.push((
-                "include_total_contacts".to_string(),
-                include_total_contacts.to_string(),
-            ));
+            query_args.push(("include_total_contacts", include_total_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/lists/{}", crate::progenitor_support::encode_path(list_id),),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -188,29 +171,22 @@ impl Lists {
     */
     pub async fn post_lists(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         skip_merge_validation: bool,
         skip_duplicate_check: bool,
         body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
     ) -> Result<crate::types::BatchUpdateListMembers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_duplicate_check {
-            query_args.push((
-                "skip_duplicate_check".to_string(),
-                skip_duplicate_check.to_string(),
-            ));
+            query_args.push(("skip_duplicate_check", skip_duplicate_check.to_string()));
         }
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/lists/{}", crate::progenitor_support::encode_path(list_id),),
+            &query_args,
         );
 
         self.client
@@ -229,11 +205,9 @@ impl Lists {
     *
     * * `list_id: &str` -- The unique ID for the list.
     */
-    pub async fn delete(&self, list_id: &str) -> Result<()> {
-        let url = format!(
-            "/lists/{}",
-            crate::progenitor_support::encode_path(list_id),
-        );
+    pub async fn delete(&self, list_id: impl AsRef<str>) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let url = format!("/lists/{}", crate::progenitor_support::encode_path(list_id),);
 
         self.client.delete(&url, None).await
     }
@@ -251,13 +225,11 @@ impl Lists {
     */
     pub async fn patch(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         body: &crate::types::SubscriberListData,
     ) -> Result<crate::types::Lists> {
-        let url = format!(
-            "/lists/{}",
-            crate::progenitor_support::encode_path(list_id),
-        );
+        let list_id = list_id.as_ref();
+        let url = format!("/lists/{}", crate::progenitor_support::encode_path(list_id),);
 
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -285,26 +257,28 @@ impl Lists {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        list_id: &str,
+        list_id: impl AsRef<str>,
     ) -> Result<crate::types::AbuseComplaints> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/abuse-reports?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/abuse-reports",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -332,28 +306,31 @@ impl Lists {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        list_id: &str,
-        report_id: &str,
+        list_id: impl AsRef<str>,
+        report_id: impl AsRef<str>,
     ) -> Result<crate::types::AbuseReports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/abuse-reports/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(report_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/abuse-reports/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -376,20 +353,22 @@ impl Lists {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        list_id: &str,
+        list_id: impl AsRef<str>,
     ) -> Result<crate::types::ListActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/activity?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/activity",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -412,20 +391,22 @@ impl Lists {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        list_id: &str,
+        list_id: impl AsRef<str>,
     ) -> Result<crate::types::EmailClients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/clients?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/clients",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -454,34 +435,36 @@ impl Lists {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         sort_field: crate::types::GetListsGrowthHistorySortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GrowthHistory> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/growth-history?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("sort_field", sort_field.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/growth-history",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -505,22 +488,25 @@ impl Lists {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        list_id: &str,
-        month: &str,
+        list_id: impl AsRef<str>,
+        month: impl AsRef<str>,
     ) -> Result<crate::types::History> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let month = month.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/growth-history/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(month),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/growth-history/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(month),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -544,34 +530,36 @@ impl Lists {
     */
     pub async fn get_interest_categorie(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
         count: i64,
         offset: i64,
         type_: &str,
     ) -> Result<crate::types::InterestGroupings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/interest-categories",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -590,9 +578,10 @@ impl Lists {
     */
     pub async fn post_interest_categorie(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         body: &crate::types::InterestCategory,
     ) -> Result<crate::types::Categories> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/lists/{}/interest-categories",
             crate::progenitor_support::encode_path(list_id),
@@ -619,24 +608,27 @@ impl Lists {
     */
     pub async fn get_interest_categorie_lists(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::Categories> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(interest_category_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/interest-categories/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -656,9 +648,11 @@ impl Lists {
     */
     pub async fn delete_interest_categories(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
     ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
         let url = format!(
             "/lists/{}/interest-categories/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -682,10 +676,12 @@ impl Lists {
     */
     pub async fn patch_interest_categories(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
         body: &crate::types::InterestCategory,
     ) -> Result<crate::types::Categories> {
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
         let url = format!(
             "/lists/{}/interest-categories/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -715,32 +711,35 @@ impl Lists {
     */
     pub async fn get_interest_categories_interest(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
         count: i64,
         offset: i64,
     ) -> Result<crate::types::InterestsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories/{}/interests?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(interest_category_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/interest-categories/{}/interests",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -760,10 +759,12 @@ impl Lists {
     */
     pub async fn post_interest_categories(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
         body: &crate::types::Interest,
     ) -> Result<crate::types::InterestsInterest> {
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
         let url = format!(
             "/lists/{}/interest-categories/{}/interests",
             crate::progenitor_support::encode_path(list_id),
@@ -792,26 +793,30 @@ impl Lists {
     */
     pub async fn get_interest_categories_interest_lists(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
-        interest_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
+        interest_id: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::InterestsInterest> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
+        let interest_id = interest_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories/{}/interests/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(interest_category_id),
-            crate::progenitor_support::encode_path(interest_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/interest-categories/{}/interests/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                crate::progenitor_support::encode_path(interest_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -832,10 +837,13 @@ impl Lists {
     */
     pub async fn delete_interest_categories_interests(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
-        interest_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
+        interest_id: impl AsRef<str>,
     ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
+        let interest_id = interest_id.as_ref();
         let url = format!(
             "/lists/{}/interest-categories/{}/interests/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -861,11 +869,14 @@ impl Lists {
     */
     pub async fn patch_interest_categories_interests(
         &self,
-        list_id: &str,
-        interest_category_id: &str,
-        interest_id: &str,
+        list_id: impl AsRef<str>,
+        interest_category_id: impl AsRef<str>,
+        interest_id: impl AsRef<str>,
         body: &crate::types::Interest,
     ) -> Result<crate::types::InterestsInterest> {
+        let list_id = list_id.as_ref();
+        let interest_category_id = interest_category_id.as_ref();
+        let interest_id = interest_id.as_ref();
         let url = format!(
             "/lists/{}/interest-categories/{}/interests/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -907,7 +918,7 @@ impl Lists {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         type_: &str,
         since_created_at: &str,
         before_created_at: &str,
@@ -917,60 +928,50 @@ impl Lists {
         since_updated_at: &str,
         before_updated_at: &str,
     ) -> Result<crate::types::CollectionOfSegments> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_created_at.is_empty() {
-            query_args.push((
-                "before_created_at".to_string(),
-                before_created_at.to_string(),
-            ));
+            query_args.push(("before_created_at", before_created_at.to_string()));
         }
         if !before_updated_at.is_empty() {
-            query_args.push((
-                "before_updated_at".to_string(),
-                before_updated_at.to_string(),
-            ));
+            query_args.push(("before_updated_at", before_updated_at.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if include_cleaned {
-            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+            query_args.push(("include_cleaned", include_cleaned.to_string()));
         }
         if include_transactional {
-            query_args.push((
-                "include_transactional".to_string(),
-                include_transactional.to_string(),
-            ));
+            query_args.push(("include_transactional", include_transactional.to_string()));
         }
         if include_unsubscribed {
-            query_args.push((
-                "include_unsubscribed".to_string(),
-                include_unsubscribed.to_string(),
-            ));
+            query_args.push(("include_unsubscribed", include_unsubscribed.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_created_at.is_empty() {
-            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+            query_args.push(("since_created_at", since_created_at.to_string()));
         }
         if !since_updated_at.is_empty() {
-            query_args.push(("since_updated_at".to_string(), since_updated_at.to_string()));
+            query_args.push(("since_updated_at", since_updated_at.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/segments?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/segments",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -989,9 +990,10 @@ impl Lists {
     */
     pub async fn post_segment(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         body: &crate::types::ListData,
     ) -> Result<crate::types::Segments> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/lists/{}/segments",
             crate::progenitor_support::encode_path(list_id),
@@ -1023,40 +1025,37 @@ impl Lists {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        list_id: &str,
-        segment_id: &str,
+        list_id: impl AsRef<str>,
+        segment_id: impl AsRef<str>,
         include_cleaned: bool,
         include_transactional: bool,
         include_unsubscribed: bool,
     ) -> Result<crate::types::Segments> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let segment_id = segment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if include_cleaned {
-            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+            query_args.push(("include_cleaned", include_cleaned.to_string()));
         }
         if include_transactional {
-            query_args.push((
-                "include_transactional".to_string(),
-                include_transactional.to_string(),
-            ));
+            query_args.push(("include_transactional", include_transactional.to_string()));
         }
         if include_unsubscribed {
-            query_args.push((
-                "include_unsubscribed".to_string(),
-                include_unsubscribed.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/segments/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(segment_id),
-            query_
+            query_args.push(("include_unsubscribed", include_unsubscribed.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/segments/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1076,10 +1075,12 @@ impl Lists {
     */
     pub async fn post_segment_lists(
         &self,
-        list_id: &str,
-        segment_id: &str,
+        list_id: impl AsRef<str>,
+        segment_id: impl AsRef<str>,
         body: &crate::types::MembersAddRemoveFromAStaticSegment,
     ) -> Result<crate::types::BatchAddRemoveListMembersFromStaticSegment> {
+        let list_id = list_id.as_ref();
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/lists/{}/segments/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -1103,7 +1104,13 @@ impl Lists {
     * * `list_id: &str` -- The unique ID for the list.
     * * `segment_id: &str` -- The unique id for the segment.
     */
-    pub async fn delete_segments(&self, list_id: &str, segment_id: &str) -> Result<()> {
+    pub async fn delete_segments(
+        &self,
+        list_id: impl AsRef<str>,
+        segment_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/lists/{}/segments/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -1127,10 +1134,12 @@ impl Lists {
     */
     pub async fn patch_segments(
         &self,
-        list_id: &str,
-        segment_id: &str,
+        list_id: impl AsRef<str>,
+        segment_id: impl AsRef<str>,
         body: &crate::types::ListDataType,
     ) -> Result<crate::types::Segments> {
+        let list_id = list_id.as_ref();
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/lists/{}/segments/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -1167,46 +1176,43 @@ impl Lists {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        list_id: &str,
-        segment_id: &str,
+        list_id: impl AsRef<str>,
+        segment_id: impl AsRef<str>,
         include_cleaned: bool,
         include_transactional: bool,
         include_unsubscribed: bool,
     ) -> Result<crate::types::SegmentMembers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let segment_id = segment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if include_cleaned {
-            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+            query_args.push(("include_cleaned", include_cleaned.to_string()));
         }
         if include_transactional {
-            query_args.push((
-                "include_transactional".to_string(),
-                include_transactional.to_string(),
-            ));
+            query_args.push(("include_transactional", include_transactional.to_string()));
         }
         if include_unsubscribed {
-            query_args.push((
-                "include_unsubscribed".to_string(),
-                include_unsubscribed.to_string(),
-            ));
+            query_args.push(("include_unsubscribed", include_unsubscribed.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/segments/{}/members?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(segment_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/segments/{}/members",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1226,10 +1232,12 @@ impl Lists {
     */
     pub async fn post_segments_member(
         &self,
-        list_id: &str,
-        segment_id: &str,
+        list_id: impl AsRef<str>,
+        segment_id: impl AsRef<str>,
         body: &crate::types::SubscriberInAutomationQueue,
     ) -> Result<crate::types::ListMembers> {
+        let list_id = list_id.as_ref();
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/lists/{}/segments/{}/members",
             crate::progenitor_support::encode_path(list_id),
@@ -1256,10 +1264,13 @@ impl Lists {
     */
     pub async fn delete_segments_members(
         &self,
-        list_id: &str,
-        segment_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        segment_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let segment_id = segment_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/lists/{}/segments/{}/members/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -1284,18 +1295,20 @@ impl Lists {
     */
     pub async fn search_tags_name(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         name: &str,
     ) -> Result<crate::types::TagSearchResults> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/tag-search?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("name", name.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/tag-search",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1337,7 +1350,7 @@ impl Lists {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         email_type: &str,
         status: crate::types::GetListsMembersStatus,
         since_timestamp_opt: &str,
@@ -1354,90 +1367,71 @@ impl Lists {
         since_last_campaign: bool,
         unsubscribed_since: &str,
     ) -> Result<crate::types::ListMembersDataType> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_last_changed.is_empty() {
-            query_args.push((
-                "before_last_changed".to_string(),
-                before_last_changed.to_string(),
-            ));
+            query_args.push(("before_last_changed", before_last_changed.to_string()));
         }
         if !before_timestamp_opt.is_empty() {
-            query_args.push((
-                "before_timestamp_opt".to_string(),
-                before_timestamp_opt.to_string(),
-            ));
+            query_args.push(("before_timestamp_opt", before_timestamp_opt.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email_type.is_empty() {
-            query_args.push(("email_type".to_string(), email_type.to_string()));
+            query_args.push(("email_type", email_type.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !interest_category_id.is_empty() {
-            query_args.push((
-                "interest_category_id".to_string(),
-                interest_category_id.to_string(),
-            ));
+            query_args.push(("interest_category_id", interest_category_id.to_string()));
         }
         if !interest_ids.is_empty() {
-            query_args.push(("interest_ids".to_string(), interest_ids.to_string()));
+            query_args.push(("interest_ids", interest_ids.to_string()));
         }
         if !interest_match.to_string().is_empty() {
-            query_args.push(("interest_match".to_string(), interest_match.to_string()));
+            query_args.push(("interest_match", interest_match.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if since_last_campaign {
-            query_args.push((
-                "since_last_campaign".to_string(),
-                since_last_campaign.to_string(),
-            ));
+            query_args.push(("since_last_campaign", since_last_campaign.to_string()));
         }
         if !since_last_changed.is_empty() {
-            query_args.push((
-                "since_last_changed".to_string(),
-                since_last_changed.to_string(),
-            ));
+            query_args.push(("since_last_changed", since_last_changed.to_string()));
         }
         if !since_timestamp_opt.is_empty() {
-            query_args.push((
-                "since_timestamp_opt".to_string(),
-                since_timestamp_opt.to_string(),
-            ));
+            query_args.push(("since_timestamp_opt", since_timestamp_opt.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !unique_email_id.is_empty() {
-            query_args.push(("unique_email_id".to_string(), unique_email_id.to_string()));
+            query_args.push(("unique_email_id", unique_email_id.to_string()));
         }
         if !unsubscribed_since.is_empty() {
-            query_args.push((
-                "unsubscribed_since".to_string(),
-                unsubscribed_since.to_string(),
-            ));
+            query_args.push(("unsubscribed_since", unsubscribed_since.to_string()));
         }
         if vip_only {
-            query_args.push(("vip_only".to_string(), vip_only.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("vip_only", vip_only.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1457,22 +1451,21 @@ impl Lists {
     */
     pub async fn post_member(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         skip_merge_validation: bool,
         body: &crate::types::AddListMembers,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1498,22 +1491,25 @@ impl Lists {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1534,24 +1530,24 @@ impl Lists {
     */
     pub async fn put_members(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         skip_merge_validation: bool,
         body: &crate::types::AddListMembersData,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1571,7 +1567,13 @@ impl Lists {
     * * `list_id: &str` -- The unique ID for the list.
     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
     */
-    pub async fn delete_members(&self, list_id: &str, subscriber_hash: &str) -> Result<()> {
+    pub async fn delete_members(
+        &self,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
+    ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/lists/{}/members/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -1596,24 +1598,24 @@ impl Lists {
     */
     pub async fn patch_members(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         skip_merge_validation: bool,
         body: &crate::types::AddListMembersDataType,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1638,28 +1640,31 @@ impl Lists {
     */
     pub async fn get_members_activity(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
         action: &[String],
     ) -> Result<crate::types::MemberActivityEvents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.is_empty() {
-            query_args.push(("action".to_string(), action.join(" ")));
+            query_args.push(("action", action.join(" ")));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/activity?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}/activity",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1684,36 +1689,39 @@ impl Lists {
     */
     pub async fn get_members_activity_feed(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
         count: i64,
         offset: i64,
         activity_filters: &[String],
     ) -> Result<crate::types::MemberActivityEventsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !activity_filters.is_empty() {
-            query_args.push(("activity_filters".to_string(), activity_filters.join(" ")));
+            query_args.push(("activity_filters", activity_filters.join(" ")));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/activity-feed?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}/activity-feed",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1737,32 +1745,35 @@ impl Lists {
     */
     pub async fn get_member_tag(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
         count: i64,
         offset: i64,
     ) -> Result<crate::types::CollectionOfTags> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/tags?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}/tags",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1782,10 +1793,12 @@ impl Lists {
     */
     pub async fn post_member_tag(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         body: &crate::types::MemberTags,
     ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/lists/{}/members/{}/tags",
             crate::progenitor_support::encode_path(list_id),
@@ -1815,32 +1828,35 @@ impl Lists {
     */
     pub async fn get_members_event(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         count: i64,
         offset: i64,
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::CollectionOfEvents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/events?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}/events",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1860,10 +1876,12 @@ impl Lists {
     */
     pub async fn post_member_event(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         body: &crate::types::EventsData,
     ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/lists/{}/members/{}/events",
             crate::progenitor_support::encode_path(list_id),
@@ -1891,24 +1909,27 @@ impl Lists {
     */
     pub async fn get_members_goal(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::CollectionOfMemberActivityEvents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/goals?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}/goals",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1934,8 +1955,8 @@ impl Lists {
     */
     pub async fn get_members_note(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         sort_field: crate::types::GetListsMembersNotesSortField,
         sort_dir: crate::types::SortDir,
         fields: &[String],
@@ -1943,31 +1964,34 @@ impl Lists {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::CollectionOfNotes> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/notes?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("sort_field", sort_field.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}/notes",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1987,10 +2011,12 @@ impl Lists {
     */
     pub async fn post_members_note(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         body: &crate::types::MemberNotes,
     ) -> Result<crate::types::CollectionOfNotesMember> {
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/lists/{}/members/{}/notes",
             crate::progenitor_support::encode_path(list_id),
@@ -2019,26 +2045,30 @@ impl Lists {
     */
     pub async fn get_members_note_lists(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
-        note_id: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
+        note_id: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::CollectionOfNotesMember> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let note_id = note_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/notes/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            crate::progenitor_support::encode_path(note_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/members/{}/notes/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                crate::progenitor_support::encode_path(note_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2059,10 +2089,13 @@ impl Lists {
     */
     pub async fn delete_members_notes(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
-        note_id: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
+        note_id: impl AsRef<str>,
     ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let note_id = note_id.as_ref();
         let url = format!(
             "/lists/{}/members/{}/notes/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -2088,11 +2121,14 @@ impl Lists {
     */
     pub async fn patch_members_notes(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
-        note_id: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
+        note_id: impl AsRef<str>,
         body: &crate::types::MemberNotes,
     ) -> Result<crate::types::CollectionOfNotesMember> {
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let note_id = note_id.as_ref();
         let url = format!(
             "/lists/{}/members/{}/notes/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -2119,9 +2155,11 @@ impl Lists {
     */
     pub async fn post_members_hash_actions_delete_permanent(
         &self,
-        list_id: &str,
-        subscriber_hash: &str,
+        list_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
         let url = format!(
             "/lists/{}/members/{}/actions/delete-permanent",
             crate::progenitor_support::encode_path(list_id),
@@ -2150,7 +2188,7 @@ impl Lists {
     */
     pub async fn get_merge_field(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         fields: &[String],
         exclude_fields: &[String],
         count: i64,
@@ -2158,30 +2196,32 @@ impl Lists {
         type_: &str,
         required: bool,
     ) -> Result<crate::types::CollectionOfMergeFields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if required {
-            query_args.push(("required".to_string(), required.to_string()));
+            query_args.push(("required", required.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/merge-fields?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/merge-fields",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2200,9 +2240,10 @@ impl Lists {
     */
     pub async fn post_merge_field(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         body: &crate::types::MergeFieldData,
     ) -> Result<crate::types::MergeField> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/lists/{}/merge-fields",
             crate::progenitor_support::encode_path(list_id),
@@ -2229,24 +2270,27 @@ impl Lists {
     */
     pub async fn get_merge_field_lists(
         &self,
-        list_id: &str,
-        merge_id: &str,
+        list_id: impl AsRef<str>,
+        merge_id: impl AsRef<str>,
         exclude_fields: &[String],
         fields: &[String],
     ) -> Result<crate::types::MergeField> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let merge_id = merge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/merge-fields/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(merge_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/merge-fields/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(merge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2264,7 +2308,13 @@ impl Lists {
     * * `list_id: &str` -- The unique ID for the list.
     * * `merge_id: &str` -- The id for the merge field.
     */
-    pub async fn delete_merge_fields(&self, list_id: &str, merge_id: &str) -> Result<()> {
+    pub async fn delete_merge_fields(
+        &self,
+        list_id: impl AsRef<str>,
+        merge_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let merge_id = merge_id.as_ref();
         let url = format!(
             "/lists/{}/merge-fields/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -2288,10 +2338,12 @@ impl Lists {
     */
     pub async fn patch_merge_fields(
         &self,
-        list_id: &str,
-        merge_id: &str,
+        list_id: impl AsRef<str>,
+        merge_id: impl AsRef<str>,
         body: &crate::types::MergeFieldDataType,
     ) -> Result<crate::types::MergeField> {
+        let list_id = list_id.as_ref();
+        let merge_id = merge_id.as_ref();
         let url = format!(
             "/lists/{}/merge-fields/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -2314,7 +2366,11 @@ impl Lists {
     *
     * * `list_id: &str` -- The unique ID for the list.
     */
-    pub async fn get_webhook(&self, list_id: &str) -> Result<crate::types::ListWebhooksData> {
+    pub async fn get_webhook(
+        &self,
+        list_id: impl AsRef<str>,
+    ) -> Result<crate::types::ListWebhooksData> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/lists/{}/webhooks",
             crate::progenitor_support::encode_path(list_id),
@@ -2336,9 +2392,10 @@ impl Lists {
     */
     pub async fn post_webhook(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         body: &crate::types::AddWebhook,
     ) -> Result<crate::types::ListWebhooks> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/lists/{}/webhooks",
             crate::progenitor_support::encode_path(list_id),
@@ -2363,9 +2420,11 @@ impl Lists {
     */
     pub async fn get_webhook_lists(
         &self,
-        list_id: &str,
-        webhook_id: &str,
+        list_id: impl AsRef<str>,
+        webhook_id: impl AsRef<str>,
     ) -> Result<crate::types::ListWebhooks> {
+        let list_id = list_id.as_ref();
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/lists/{}/webhooks/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -2387,7 +2446,13 @@ impl Lists {
     * * `list_id: &str` -- The unique ID for the list.
     * * `webhook_id: &str` -- The name of the folder.
     */
-    pub async fn delete_webhooks(&self, list_id: &str, webhook_id: &str) -> Result<()> {
+    pub async fn delete_webhooks(
+        &self,
+        list_id: impl AsRef<str>,
+        webhook_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let list_id = list_id.as_ref();
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/lists/{}/webhooks/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -2411,10 +2476,12 @@ impl Lists {
     */
     pub async fn patch_webhooks(
         &self,
-        list_id: &str,
-        webhook_id: &str,
+        list_id: impl AsRef<str>,
+        webhook_id: impl AsRef<str>,
         body: &crate::types::AddWebhook,
     ) -> Result<crate::types::ListWebhooks> {
+        let list_id = list_id.as_ref();
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/lists/{}/webhooks/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -2437,7 +2504,11 @@ impl Lists {
     *
     * * `list_id: &str` -- The unique ID for the list.
     */
-    pub async fn get_signup_form(&self, list_id: &str) -> Result<crate::types::ListSignupForms> {
+    pub async fn get_signup_form(
+        &self,
+        list_id: impl AsRef<str>,
+    ) -> Result<crate::types::ListSignupForms> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/lists/{}/signup-forms",
             crate::progenitor_support::encode_path(list_id),
@@ -2459,9 +2530,10 @@ impl Lists {
     */
     pub async fn post_signup_form(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         body: &crate::types::SignupFormData,
     ) -> Result<crate::types::SignupForm> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/lists/{}/signup-forms",
             crate::progenitor_support::encode_path(list_id),
@@ -2489,20 +2561,22 @@ impl Lists {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        list_id: &str,
+        list_id: impl AsRef<str>,
     ) -> Result<crate::types::ListLocations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/locations?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/lists/{}/locations",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -0,0 +1,274 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: mailchimp_api::Client) {
+//! use mailchimp_api::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "MailChimp";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/",
+    "/activity-feed/chimp-chatter",
+    "/authorized-apps",
+    "/authorized-apps/{app_id}",
+    "/automations",
+    "/automations/{workflow_id}",
+    "/automations/{workflow_id}/actions/archive",
+    "/automations/{workflow_id}/actions/pause-all-emails",
+    "/automations/{workflow_id}/actions/start-all-emails",
+    "/automations/{workflow_id}/emails",
+    "/automations/{workflow_id}/emails/{workflow_email_id}",
+    "/automations/{workflow_id}/emails/{workflow_email_id}/actions/pause",
+    "/automations/{workflow_id}/emails/{workflow_email_id}/actions/start",
+    "/automations/{workflow_id}/emails/{workflow_email_id}/queue",
+    "/automations/{workflow_id}/emails/{workflow_email_id}/queue/{subscriber_hash}",
+    "/automations/{workflow_id}/removed-subscribers",
+    "/automations/{workflow_id}/removed-subscribers/{subscriber_hash}",
+    "/batch-webhooks",
+    "/batch-webhooks/{batch_webhook_id}",
+    "/batches",
+    "/batches/{batch_id}",
+    "/campaign-folders",
+    "/campaign-folders/{folder_id}",
+    "/campaigns",
+    "/campaigns/{campaign_id}",
+    "/campaigns/{campaign_id}/actions/cancel-send",
+    "/campaigns/{campaign_id}/actions/create-resend",
+    "/campaigns/{campaign_id}/actions/pause",
+    "/campaigns/{campaign_id}/actions/replicate",
+    "/campaigns/{campaign_id}/actions/resume",
+    "/campaigns/{campaign_id}/actions/schedule",
+    "/campaigns/{campaign_id}/actions/send",
+    "/campaigns/{campaign_id}/actions/test",
+    "/campaigns/{campaign_id}/actions/unschedule",
+    "/campaigns/{campaign_id}/content",
+    "/campaigns/{campaign_id}/feedback",
+    "/campaigns/{campaign_id}/feedback/{feedback_id}",
+    "/campaigns/{campaign_id}/send-checklist",
+    "/connected-sites",
+    "/connected-sites/{connected_site_id}",
+    "/connected-sites/{connected_site_id}/actions/verify-script-installation",
+    "/conversations",
+    "/conversations/{conversation_id}",
+    "/conversations/{conversation_id}/messages",
+    "/conversations/{conversation_id}/messages/{message_id}",
+    "/customer-journeys/journeys/{journey_id}/steps/{step_id}/actions/trigger",
+    "/ecommerce/orders",
+    "/ecommerce/stores",
+    "/ecommerce/stores/{store_id}",
+    "/ecommerce/stores/{store_id}/carts",
+    "/ecommerce/stores/{store_id}/carts/{cart_id}",
+    "/ecommerce/stores/{store_id}/carts/{cart_id}/lines",
+    "/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}",
+    "/ecommerce/stores/{store_id}/customers",
+    "/ecommerce/stores/{store_id}/customers/{customer_id}",
+    "/ecommerce/stores/{store_id}/orders",
+    "/ecommerce/stores/{store_id}/orders/{order_id}",
+    "/ecommerce/stores/{store_id}/orders/{order_id}/lines",
+    "/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}",
+    "/ecommerce/stores/{store_id}/products",
+    "/ecommerce/stores/{store_id}/products/{product_id}",
+    "/ecommerce/stores/{store_id}/products/{product_id}/images",
+    "/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}",
+    "/ecommerce/stores/{store_id}/products/{product_id}/variants",
+    "/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}",
+    "/ecommerce/stores/{store_id}/promo-rules",
+    "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}",
+    "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes",
+    "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}",
+    "/facebook-ads",
+    "/facebook-ads/{outreach_id}",
+    "/file-manager/files",
+    "/file-manager/files/{file_id}",
+    "/file-manager/folders",
+    "/file-manager/folders/{folder_id}",
+    "/landing-pages",
+    "/landing-pages/{page_id}",
+    "/landing-pages/{page_id}/actions/publish",
+    "/landing-pages/{page_id}/actions/unpublish",
+    "/landing-pages/{page_id}/content",
+    "/lists",
+    "/lists/{list_id}",
+    "/lists/{list_id}/abuse-reports",
+    "/lists/{list_id}/abuse-reports/{report_id}",
+    "/lists/{list_id}/activity",
+    "/lists/{list_id}/clients",
+    "/lists/{list_id}/growth-history",
+    "/lists/{list_id}/growth-history/{month}",
+    "/lists/{list_id}/interest-categories",
+    "/lists/{list_id}/interest-categories/{interest_category_id}",
+    "/lists/{list_id}/interest-categories/{interest_category_id}/interests",
+    "/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}",
+    "/lists/{list_id}/locations",
+    "/lists/{list_id}/members",
+    "/lists/{list_id}/members/{subscriber_hash}",
+    "/lists/{list_id}/members/{subscriber_hash}/actions/delete-permanent",
+    "/lists/{list_id}/members/{subscriber_hash}/activity",
+    "/lists/{list_id}/members/{subscriber_hash}/activity-feed",
+    "/lists/{list_id}/members/{subscriber_hash}/events",
+    "/lists/{list_id}/members/{subscriber_hash}/goals",
+    "/lists/{list_id}/members/{subscriber_hash}/notes",
+    "/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}",
+    "/lists/{list_id}/members/{subscriber_hash}/tags",
+    "/lists/{list_id}/merge-fields",
+    "/lists/{list_id}/merge-fields/{merge_id}",
+    "/lists/{list_id}/segments",
+    "/lists/{list_id}/segments/{segment_id}",
+    "/lists/{list_id}/segments/{segment_id}/members",
+    "/lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}",
+    "/lists/{list_id}/signup-forms",
+    "/lists/{list_id}/tag-search",
+    "/lists/{list_id}/webhooks",
+    "/lists/{list_id}/webhooks/{webhook_id}",
+    "/ping",
+    "/reporting/facebook-ads",
+    "/reporting/facebook-ads/{outreach_id}",
+    "/reporting/facebook-ads/{outreach_id}/ecommerce-product-activity",
+    "/reporting/landing-pages",
+    "/reporting/landing-pages/{outreach_id}",
+    "/reports",
+    "/reports/{campaign_id}",
+    "/reports/{campaign_id}/abuse-reports",
+    "/reports/{campaign_id}/abuse-reports/{report_id}",
+    "/reports/{campaign_id}/advice",
+    "/reports/{campaign_id}/click-details",
+    "/reports/{campaign_id}/click-details/{link_id}",
+    "/reports/{campaign_id}/click-details/{link_id}/members",
+    "/reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}",
+    "/reports/{campaign_id}/domain-performance",
+    "/reports/{campaign_id}/ecommerce-product-activity",
+    "/reports/{campaign_id}/eepurl",
+    "/reports/{campaign_id}/email-activity",
+    "/reports/{campaign_id}/email-activity/{subscriber_hash}",
+    "/reports/{campaign_id}/locations",
+    "/reports/{campaign_id}/open-details",
+    "/reports/{campaign_id}/open-details/{subscriber_hash}",
+    "/reports/{campaign_id}/sent-to",
+    "/reports/{campaign_id}/sent-to/{subscriber_hash}",
+    "/reports/{campaign_id}/sub-reports",
+    "/reports/{campaign_id}/unsubscribed",
+    "/reports/{campaign_id}/unsubscribed/{subscriber_hash}",
+    "/search-campaigns",
+    "/search-members",
+    "/template-folders",
+    "/template-folders/{folder_id}",
+    "/templates",
+    "/templates/{template_id}",
+    "/templates/{template_id}/default-content",
+    "/verified-domains",
+    "/verified-domains/{domain_name}",
+    "/verified-domains/{domain_name}/actions/verify",
+];
@@ -37,27 +37,29 @@ impl Reporting {
         sort_field: crate::types::GetAllFacebookAdsSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GetReportingFacebookAdsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reporting/facebook-ads?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/reporting/facebook-ads"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -78,21 +80,23 @@ impl Reporting {
     pub async fn get_facebook_ad(
         &self,
         fields: &[String],
-        outreach_id: &str,
+        outreach_id: impl AsRef<str>,
         exclude_fields: &[String],
     ) -> Result<crate::types::GetReportingFacebookAdsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let outreach_id = outreach_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reporting/facebook-ads/{}?{}",
-            crate::progenitor_support::encode_path(outreach_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reporting/facebook-ads/{}",
+                crate::progenitor_support::encode_path(outreach_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -120,30 +124,32 @@ impl Reporting {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        outreach_id: &str,
+        outreach_id: impl AsRef<str>,
         sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
     ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let outreach_id = outreach_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reporting/facebook-ads/{}/ecommerce-product-activity?{}",
-            crate::progenitor_support::encode_path(outreach_id),
-            query_
+            query_args.push(("sort_field", sort_field.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reporting/facebook-ads/{}/ecommerce-product-activity",
+                crate::progenitor_support::encode_path(outreach_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -165,21 +171,23 @@ impl Reporting {
     pub async fn get_landing_page(
         &self,
         fields: &[String],
-        outreach_id: &str,
+        outreach_id: impl AsRef<str>,
         exclude_fields: &[String],
     ) -> Result<crate::types::LandingPages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let outreach_id = outreach_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reporting/landing-pages/{}?{}",
-            crate::progenitor_support::encode_path(outreach_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reporting/landing-pages/{}",
+                crate::progenitor_support::encode_path(outreach_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -206,21 +214,23 @@ impl Reporting {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::GetReportingLandingPagesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reporting/landing-pages?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/reporting/landing-pages"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
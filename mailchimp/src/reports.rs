@@ -39,30 +39,29 @@ impl Reports {
         before_send_time: Option<chrono::DateTime<chrono::Utc>>,
         since_send_time: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::CampaignReportsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_send_time {
-            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_send_time", date.to_rfc3339()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if let Some(date) = since_send_time {
-            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_send_time", date.to_rfc3339()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reports?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/reports"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -84,20 +83,22 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::Reports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -120,20 +121,22 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::AbuseComplaintsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/abuse-reports?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/abuse-reports",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -157,22 +160,25 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        report_id: &str,
+        campaign_id: impl AsRef<str>,
+        report_id: impl AsRef<str>,
     ) -> Result<crate::types::AbuseComplaint> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/abuse-reports/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(report_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/abuse-reports/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -195,20 +201,22 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignAdviceReport> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/advice?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/advice",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -235,26 +243,28 @@ impl Reports {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::ClickDetailReport> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/click-details",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -278,22 +288,25 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        link_id: &str,
+        campaign_id: impl AsRef<str>,
+        link_id: impl AsRef<str>,
     ) -> Result<crate::types::UrlsClicked> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let link_id = link_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(link_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/click-details/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -321,28 +334,31 @@ impl Reports {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        campaign_id: &str,
-        link_id: &str,
+        campaign_id: impl AsRef<str>,
+        link_id: impl AsRef<str>,
     ) -> Result<crate::types::ClickDetailMembers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let link_id = link_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details/{}/members?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(link_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/click-details/{}/members",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -367,24 +383,28 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        link_id: &str,
-        subscriber_hash: &str,
+        campaign_id: impl AsRef<str>,
+        link_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<crate::types::ClickDetailMember> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let link_id = link_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(link_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/click-details/{}/members/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -412,30 +432,32 @@ impl Reports {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         since: &str,
     ) -> Result<crate::types::OpenDetailReport> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since.is_empty() {
-            query_args.push(("since".to_string(), since.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/open-details?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("since", since.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/open-details",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -459,22 +481,25 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        subscriber_hash: &str,
+        campaign_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<crate::types::OpenActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/open-details/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/open-details/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -497,20 +522,22 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::DomainPerformance> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/domain-performance?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/domain-performance",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -533,20 +560,22 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::EepurlActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/eepurl?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/eepurl",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -574,30 +603,32 @@ impl Reports {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         since: &str,
     ) -> Result<crate::types::EmailActivityData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since.is_empty() {
-            query_args.push(("since".to_string(), since.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/email-activity?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("since", since.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/email-activity",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -622,26 +653,29 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        subscriber_hash: &str,
+        campaign_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
         since: &str,
     ) -> Result<crate::types::EmailActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !since.is_empty() {
-            query_args.push(("since".to_string(), since.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/email-activity/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("since", since.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/email-activity/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -666,28 +700,30 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         count: i64,
         offset: i64,
     ) -> Result<crate::types::OpenLocationsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/locations?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/locations",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -714,26 +750,28 @@ impl Reports {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::SentData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/sent-to?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/sent-to",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -757,22 +795,25 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        subscriber_hash: &str,
+        campaign_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<crate::types::SentTo> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/sent-to/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/sent-to/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -795,20 +836,22 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::CampaignSubReports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/sub-reports?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/sub-reports",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -835,26 +878,28 @@ impl Reports {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
     ) -> Result<crate::types::UnsubscribesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/unsubscribed?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/unsubscribed",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -878,22 +923,25 @@ impl Reports {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        campaign_id: &str,
-        subscriber_hash: &str,
+        campaign_id: impl AsRef<str>,
+        subscriber_hash: impl AsRef<str>,
     ) -> Result<crate::types::Unsubscribes> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let subscriber_hash = subscriber_hash.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/unsubscribed/{}?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/unsubscribed/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -921,30 +969,32 @@ impl Reports {
         exclude_fields: &[String],
         count: i64,
         offset: i64,
-        campaign_id: &str,
+        campaign_id: impl AsRef<str>,
         sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
     ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let campaign_id = campaign_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/ecommerce-product-activity?{}",
-            crate::progenitor_support::encode_path(campaign_id),
-            query_
+            query_args.push(("sort_field", sort_field.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/reports/{}/ecommerce-product-activity",
+                crate::progenitor_support::encode_path(campaign_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -29,15 +29,14 @@ impl Root {
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::ApiRoot> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!(""), &query_args);
 
         self.client.get(&url, None).await
     }
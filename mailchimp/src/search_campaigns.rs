@@ -31,18 +31,18 @@ impl SearchCampaigns {
         exclude_fields: &[String],
         query: &str,
     ) -> Result<crate::types::Campaigns> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search-campaigns?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/search-campaigns"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -49,51 +49,44 @@ impl Templates {
         sort_field: crate::types::GetTemplatesSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::TemplatesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_date_created.is_empty() {
-            query_args.push((
-                "before_date_created".to_string(),
-                before_date_created.to_string(),
-            ));
+            query_args.push(("before_date_created", before_date_created.to_string()));
         }
         if !category.is_empty() {
-            query_args.push(("category".to_string(), category.to_string()));
+            query_args.push(("category", category.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_by.is_empty() {
-            query_args.push(("created_by".to_string(), created_by.to_string()));
+            query_args.push(("created_by", created_by.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(" ")));
         }
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_date_created.is_empty() {
-            query_args.push((
-                "since_date_created".to_string(),
-                since_date_created.to_string(),
-            ));
+            query_args.push(("since_date_created", since_date_created.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/templates?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/templates"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -132,20 +125,22 @@ impl Templates {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        template_id: &str,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::Templates> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/templates/{}?{}",
-            crate::progenitor_support::encode_path(template_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -162,7 +157,8 @@ impl Templates {
     *
     * * `template_id: &str` -- The unique id for the template.
     */
-    pub async fn delete(&self, template_id: &str) -> Result<()> {
+    pub async fn delete(&self, template_id: impl AsRef<str>) -> Result<()> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -184,9 +180,10 @@ impl Templates {
     */
     pub async fn patch(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         body: &crate::types::TemplateInstance,
     ) -> Result<crate::types::Templates> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -214,20 +211,22 @@ impl Templates {
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        template_id: &str,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::TemplateDefaultContent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/templates/{}/default-content?{}",
-            crate::progenitor_support::encode_path(template_id),
-            query_
+            query_args.push(("fields", fields.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/templates/{}/default-content",
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1 +1,68 @@
+#[tokio::test]
+async fn test_batch_operations() {
+    use std::io::Write;
 
+    use crate::batches::BatchOperation;
+
+    // The results of a batch are a gzipped tarball of JSON files.
+    let results = serde_json::json!([
+        {"status_code": 200, "operation_id": "jane@example.com", "response": "{\"id\": \"m1\"}"},
+        {"status_code": 400, "operation_id": "john@example.com", "response": "{\"title\": \"Member Exists\", \"detail\": \"john@example.com is already a list member.\"}"}
+    ])
+    .to_string();
+    let mut tar = vec![0u8; 512];
+    tar[..15].copy_from_slice(b"b1/results.json");
+    tar[124..136].copy_from_slice(format!("{:011o}\0", results.len()).as_bytes());
+    tar[156] = b'0';
+    tar.extend_from_slice(results.as_bytes());
+    tar.resize(tar.len() + (512 - results.len() % 512) % 512 + 1024, 0);
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    gz.write_all(&tar).unwrap();
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "b1", "status": "pending"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "b1", "status": "finished", "response_body_url": "https://results.example.com/b1.tar.gz"}"#,
+    );
+    mock.push_response(reqwest::StatusCode::OK, gz.finish().unwrap());
+
+    let mailchimp = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let operations = vec![
+        BatchOperation::new(reqwest::Method::POST, "/lists/l1/members")
+            .operation_id("jane@example.com")
+            .json(&serde_json::json!({"email_address": "jane@example.com"}))
+            .unwrap(),
+        BatchOperation::new(reqwest::Method::POST, "/lists/l1/members")
+            .operation_id("john@example.com")
+            .json(&serde_json::json!({"email_address": "john@example.com"}))
+            .unwrap(),
+    ];
+    let results = mailchimp.batches().run(&operations).await.unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].operation_id, "jane@example.com");
+    let member: serde_json::Value = results[0].parse().unwrap();
+    assert_eq!(member["id"], "m1");
+    assert!(!results[1].is_success());
+    assert_eq!(
+        results[1].parse::<serde_json::Value>().unwrap_err().to_string(),
+        "code: 400 Bad Request, operation john@example.com failed: john@example.com is already a list member."
+    );
+
+    let requests = mock.requests();
+    let body: serde_json::Value = requests[0].json().unwrap();
+    assert_eq!(body["operations"][0]["method"], "POST");
+    assert_eq!(
+        body["operations"][1]["body"],
+        r#"{"email_address":"john@example.com"}"#
+    );
+    assert_eq!(requests[1].url.path(), "/batches/b1");
+    assert!(!requests[2].headers.contains_key("authorization"));
+
+    let too_many = vec![operations[0].clone(); 501];
+    assert!(mailchimp.batches().submit(&too_many).await.is_err());
+}
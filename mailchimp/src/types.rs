@@ -100,8 +100,8 @@ pub enum Type {
     Radio,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -112,7 +112,7 @@ impl std::fmt::Display for Type {
             Type::Hidden => "hidden",
             Type::Radio => "radio",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -376,8 +376,8 @@ pub enum PricingPlanType {
     PayAsYouGo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PricingPlanType {
@@ -387,7 +387,7 @@ impl std::fmt::Display for PricingPlanType {
             PricingPlanType::Monthly => "monthly",
             PricingPlanType::PayAsYouGo => "pay_as_you_go",
             PricingPlanType::Noop => "",
-            PricingPlanType::FallthroughString => "*",
+            PricingPlanType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -527,8 +527,8 @@ pub enum Method {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -542,7 +542,7 @@ impl std::fmt::Display for Method {
             Method::Post => "POST",
             Method::Put => "PUT",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -798,8 +798,8 @@ pub enum GetActivityFeedChimpChatterResponseType {
     ListsUnsubscribes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
@@ -816,7 +816,7 @@ impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
             GetActivityFeedChimpChatterResponseType::ListsProfileUpdates => "lists:profile-updates",
             GetActivityFeedChimpChatterResponseType::ListsUnsubscribes => "lists:unsubscribes",
             GetActivityFeedChimpChatterResponseType::Noop => "",
-            GetActivityFeedChimpChatterResponseType::FallthroughString => "*",
+            GetActivityFeedChimpChatterResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1027,8 +1027,8 @@ pub enum Status {
     Sending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1038,7 +1038,7 @@ impl std::fmt::Display for Status {
             Status::Save => "save",
             Status::Sending => "sending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1066,8 +1066,8 @@ pub enum Match {
     Any,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Match {
@@ -1076,7 +1076,7 @@ impl std::fmt::Display for Match {
             Match::All => "all",
             Match::Any => "any",
             Match::Noop => "",
-            Match::FallthroughString => "*",
+            Match::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1099,8 +1099,8 @@ pub enum ConditionType {
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConditionType {
@@ -1108,7 +1108,7 @@ impl std::fmt::Display for ConditionType {
         match &*self {
             ConditionType::Aim => "Aim",
             ConditionType::Noop => "",
-            ConditionType::FallthroughString => "*",
+            ConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1134,8 +1134,8 @@ pub enum Field {
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Field {
@@ -1143,7 +1143,7 @@ impl std::fmt::Display for Field {
         match &*self {
             Field::Aim => "aim",
             Field::Noop => "",
-            Field::FallthroughString => "*",
+            Field::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1179,8 +1179,8 @@ pub enum Op {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Op {
@@ -1193,7 +1193,7 @@ impl std::fmt::Display for Op {
             Op::Open => "open",
             Op::Sent => "sent",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1245,8 +1245,8 @@ pub enum AutomationSegmentConditionType {
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutomationSegmentConditionType {
@@ -1254,7 +1254,7 @@ impl std::fmt::Display for AutomationSegmentConditionType {
         match &*self {
             AutomationSegmentConditionType::Automation => "Automation",
             AutomationSegmentConditionType::Noop => "",
-            AutomationSegmentConditionType::FallthroughString => "*",
+            AutomationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1280,8 +1280,8 @@ pub enum SegmentField {
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentField {
@@ -1289,7 +1289,7 @@ impl std::fmt::Display for SegmentField {
         match &*self {
             SegmentField::Automation => "automation",
             SegmentField::Noop => "",
-            SegmentField::FallthroughString => "*",
+            SegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1321,8 +1321,8 @@ pub enum SegmentOperator {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentOperator {
@@ -1333,7 +1333,7 @@ impl std::fmt::Display for SegmentOperator {
             SegmentOperator::NotStarted => "not_started",
             SegmentOperator::Started => "started",
             SegmentOperator::Noop => "",
-            SegmentOperator::FallthroughString => "*",
+            SegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1385,8 +1385,8 @@ pub enum PollActivitySegmentConditionType {
     CampaignPoll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentConditionType {
@@ -1394,7 +1394,7 @@ impl std::fmt::Display for PollActivitySegmentConditionType {
         match &*self {
             PollActivitySegmentConditionType::CampaignPoll => "CampaignPoll",
             PollActivitySegmentConditionType::Noop => "",
-            PollActivitySegmentConditionType::FallthroughString => "*",
+            PollActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1420,8 +1420,8 @@ pub enum PollActivitySegmentField {
     Poll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentField {
@@ -1429,7 +1429,7 @@ impl std::fmt::Display for PollActivitySegmentField {
         match &*self {
             PollActivitySegmentField::Poll => "poll",
             PollActivitySegmentField::Noop => "",
-            PollActivitySegmentField::FallthroughString => "*",
+            PollActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1457,8 +1457,8 @@ pub enum PollActivitySegmentOperator {
     Notmember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentOperator {
@@ -1467,7 +1467,7 @@ impl std::fmt::Display for PollActivitySegmentOperator {
             PollActivitySegmentOperator::Member => "member",
             PollActivitySegmentOperator::Notmember => "notmember",
             PollActivitySegmentOperator::Noop => "",
-            PollActivitySegmentOperator::FallthroughString => "*",
+            PollActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1519,8 +1519,8 @@ pub enum ConversationSegmentConditionType {
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentConditionType {
@@ -1528,7 +1528,7 @@ impl std::fmt::Display for ConversationSegmentConditionType {
         match &*self {
             ConversationSegmentConditionType::Conversation => "Conversation",
             ConversationSegmentConditionType::Noop => "",
-            ConversationSegmentConditionType::FallthroughString => "*",
+            ConversationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1554,8 +1554,8 @@ pub enum ConversationSegmentField {
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentField {
@@ -1563,7 +1563,7 @@ impl std::fmt::Display for ConversationSegmentField {
         match &*self {
             ConversationSegmentField::Conversation => "conversation",
             ConversationSegmentField::Noop => "",
-            ConversationSegmentField::FallthroughString => "*",
+            ConversationSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1615,8 +1615,8 @@ pub enum DateSegmentConditionType {
     Date,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentConditionType {
@@ -1624,7 +1624,7 @@ impl std::fmt::Display for DateSegmentConditionType {
         match &*self {
             DateSegmentConditionType::Date => "Date",
             DateSegmentConditionType::Noop => "",
-            DateSegmentConditionType::FallthroughString => "*",
+            DateSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1654,8 +1654,8 @@ pub enum DateSegmentField {
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentField {
@@ -1665,7 +1665,7 @@ impl std::fmt::Display for DateSegmentField {
             DateSegmentField::InfoChanged => "info_changed",
             DateSegmentField::TimestampOpt => "timestamp_opt",
             DateSegmentField::Noop => "",
-            DateSegmentField::FallthroughString => "*",
+            DateSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1705,8 +1705,8 @@ pub enum DateSegmentOperator {
     Within,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentOperator {
@@ -1721,7 +1721,7 @@ impl std::fmt::Display for DateSegmentOperator {
             DateSegmentOperator::Notwithin => "notwithin",
             DateSegmentOperator::Within => "within",
             DateSegmentOperator::Noop => "",
-            DateSegmentOperator::FallthroughString => "*",
+            DateSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1782,8 +1782,8 @@ pub enum EmailClientSegmentConditionType {
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentConditionType {
@@ -1791,7 +1791,7 @@ impl std::fmt::Display for EmailClientSegmentConditionType {
         match &*self {
             EmailClientSegmentConditionType::EmailClient => "EmailClient",
             EmailClientSegmentConditionType::Noop => "",
-            EmailClientSegmentConditionType::FallthroughString => "*",
+            EmailClientSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1817,8 +1817,8 @@ pub enum EmailClientSegmentField {
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentField {
@@ -1826,7 +1826,7 @@ impl std::fmt::Display for EmailClientSegmentField {
         match &*self {
             EmailClientSegmentField::EmailClient => "email_client",
             EmailClientSegmentField::Noop => "",
-            EmailClientSegmentField::FallthroughString => "*",
+            EmailClientSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1854,8 +1854,8 @@ pub enum EmailClientSegmentOperator {
     ClientNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentOperator {
@@ -1864,7 +1864,7 @@ impl std::fmt::Display for EmailClientSegmentOperator {
             EmailClientSegmentOperator::ClientIs => "client_is",
             EmailClientSegmentOperator::ClientNot => "client_not",
             EmailClientSegmentOperator::Noop => "",
-            EmailClientSegmentOperator::FallthroughString => "*",
+            EmailClientSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1916,8 +1916,8 @@ pub enum LanguageSegmentConditionType {
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentConditionType {
@@ -1925,7 +1925,7 @@ impl std::fmt::Display for LanguageSegmentConditionType {
         match &*self {
             LanguageSegmentConditionType::Language => "Language",
             LanguageSegmentConditionType::Noop => "",
-            LanguageSegmentConditionType::FallthroughString => "*",
+            LanguageSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1951,8 +1951,8 @@ pub enum LanguageSegmentField {
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentField {
@@ -1960,7 +1960,7 @@ impl std::fmt::Display for LanguageSegmentField {
         match &*self {
             LanguageSegmentField::Language => "language",
             LanguageSegmentField::Noop => "",
-            LanguageSegmentField::FallthroughString => "*",
+            LanguageSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1988,8 +1988,8 @@ pub enum LanguageSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentOperator {
@@ -1998,7 +1998,7 @@ impl std::fmt::Display for LanguageSegmentOperator {
             LanguageSegmentOperator::Is => "is",
             LanguageSegmentOperator::Not => "not",
             LanguageSegmentOperator::Noop => "",
-            LanguageSegmentOperator::FallthroughString => "*",
+            LanguageSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2050,8 +2050,8 @@ pub enum MemberRatingSegmentConditionType {
     MemberRating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentConditionType {
@@ -2059,7 +2059,7 @@ impl std::fmt::Display for MemberRatingSegmentConditionType {
         match &*self {
             MemberRatingSegmentConditionType::MemberRating => "MemberRating",
             MemberRatingSegmentConditionType::Noop => "",
-            MemberRatingSegmentConditionType::FallthroughString => "*",
+            MemberRatingSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2085,8 +2085,8 @@ pub enum MemberRatingSegmentField {
     Rating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentField {
@@ -2094,7 +2094,7 @@ impl std::fmt::Display for MemberRatingSegmentField {
         match &*self {
             MemberRatingSegmentField::Rating => "rating",
             MemberRatingSegmentField::Noop => "",
-            MemberRatingSegmentField::FallthroughString => "*",
+            MemberRatingSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2126,8 +2126,8 @@ pub enum MemberRatingSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentOperator {
@@ -2138,7 +2138,7 @@ impl std::fmt::Display for MemberRatingSegmentOperator {
             MemberRatingSegmentOperator::Less => "less",
             MemberRatingSegmentOperator::Not => "not",
             MemberRatingSegmentOperator::Noop => "",
-            MemberRatingSegmentOperator::FallthroughString => "*",
+            MemberRatingSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2190,8 +2190,8 @@ pub enum SignupSourceSegmentType {
     SignupSource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentType {
@@ -2199,7 +2199,7 @@ impl std::fmt::Display for SignupSourceSegmentType {
         match &*self {
             SignupSourceSegmentType::SignupSource => "SignupSource",
             SignupSourceSegmentType::Noop => "",
-            SignupSourceSegmentType::FallthroughString => "*",
+            SignupSourceSegmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2222,8 +2222,8 @@ pub enum SignupSourceSegmentField {
     Source,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentField {
@@ -2231,7 +2231,7 @@ impl std::fmt::Display for SignupSourceSegmentField {
         match &*self {
             SignupSourceSegmentField::Source => "source",
             SignupSourceSegmentField::Noop => "",
-            SignupSourceSegmentField::FallthroughString => "*",
+            SignupSourceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2259,8 +2259,8 @@ pub enum SignupSourceSegmentOperator {
     SourceNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentOperator {
@@ -2269,7 +2269,7 @@ impl std::fmt::Display for SignupSourceSegmentOperator {
             SignupSourceSegmentOperator::SourceIs => "source_is",
             SignupSourceSegmentOperator::SourceNot => "source_not",
             SignupSourceSegmentOperator::Noop => "",
-            SignupSourceSegmentOperator::FallthroughString => "*",
+            SignupSourceSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2315,8 +2315,8 @@ pub enum SurveyMonkeySegmentConditionType {
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentConditionType {
@@ -2324,7 +2324,7 @@ impl std::fmt::Display for SurveyMonkeySegmentConditionType {
         match &*self {
             SurveyMonkeySegmentConditionType::SurveyMonkey => "SurveyMonkey",
             SurveyMonkeySegmentConditionType::Noop => "",
-            SurveyMonkeySegmentConditionType::FallthroughString => "*",
+            SurveyMonkeySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2350,8 +2350,8 @@ pub enum SurveyMonkeySegmentField {
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentField {
@@ -2359,7 +2359,7 @@ impl std::fmt::Display for SurveyMonkeySegmentField {
         match &*self {
             SurveyMonkeySegmentField::SurveyMonkey => "survey_monkey",
             SurveyMonkeySegmentField::Noop => "",
-            SurveyMonkeySegmentField::FallthroughString => "*",
+            SurveyMonkeySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2411,8 +2411,8 @@ pub enum VipSegmentConditionType {
     Vip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentConditionType {
@@ -2420,7 +2420,7 @@ impl std::fmt::Display for VipSegmentConditionType {
         match &*self {
             VipSegmentConditionType::Vip => "VIP",
             VipSegmentConditionType::Noop => "",
-            VipSegmentConditionType::FallthroughString => "*",
+            VipSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2446,8 +2446,8 @@ pub enum VipSegmentField {
     Gmonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentField {
@@ -2455,7 +2455,7 @@ impl std::fmt::Display for VipSegmentField {
         match &*self {
             VipSegmentField::Gmonkey => "gmonkey",
             VipSegmentField::Noop => "",
-            VipSegmentField::FallthroughString => "*",
+            VipSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2498,8 +2498,8 @@ pub enum InterestsSegmentConditionType {
     Interests,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentConditionType {
@@ -2507,7 +2507,7 @@ impl std::fmt::Display for InterestsSegmentConditionType {
         match &*self {
             InterestsSegmentConditionType::Interests => "Interests",
             InterestsSegmentConditionType::Noop => "",
-            InterestsSegmentConditionType::FallthroughString => "*",
+            InterestsSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2537,8 +2537,8 @@ pub enum InterestsSegmentOperator {
     Interestnotcontains,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentOperator {
@@ -2548,7 +2548,7 @@ impl std::fmt::Display for InterestsSegmentOperator {
             InterestsSegmentOperator::Interestcontainsall => "interestcontainsall",
             InterestsSegmentOperator::Interestnotcontains => "interestnotcontains",
             InterestsSegmentOperator::Noop => "",
-            InterestsSegmentOperator::FallthroughString => "*",
+            InterestsSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2604,8 +2604,8 @@ pub enum EcommerceCategorySegmentConditionType {
     EcommCategory,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentConditionType {
@@ -2613,7 +2613,7 @@ impl std::fmt::Display for EcommerceCategorySegmentConditionType {
         match &*self {
             EcommerceCategorySegmentConditionType::EcommCategory => "EcommCategory",
             EcommerceCategorySegmentConditionType::Noop => "",
-            EcommerceCategorySegmentConditionType::FallthroughString => "*",
+            EcommerceCategorySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2641,8 +2641,8 @@ pub enum EcommerceCategorySegmentField {
     EcommProd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentField {
@@ -2651,7 +2651,7 @@ impl std::fmt::Display for EcommerceCategorySegmentField {
             EcommerceCategorySegmentField::EcommCat => "ecomm_cat",
             EcommerceCategorySegmentField::EcommProd => "ecomm_prod",
             EcommerceCategorySegmentField::Noop => "",
-            EcommerceCategorySegmentField::FallthroughString => "*",
+            EcommerceCategorySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2687,8 +2687,8 @@ pub enum EcommerceCategorySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentOperator {
@@ -2701,7 +2701,7 @@ impl std::fmt::Display for EcommerceCategorySegmentOperator {
             EcommerceCategorySegmentOperator::Notcontain => "notcontain",
             EcommerceCategorySegmentOperator::Starts => "starts",
             EcommerceCategorySegmentOperator::Noop => "",
-            EcommerceCategorySegmentOperator::FallthroughString => "*",
+            EcommerceCategorySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2753,8 +2753,8 @@ pub enum EcommerceNumberSegmentConditionType {
     EcommNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentConditionType {
@@ -2762,7 +2762,7 @@ impl std::fmt::Display for EcommerceNumberSegmentConditionType {
         match &*self {
             EcommerceNumberSegmentConditionType::EcommNumber => "EcommNumber",
             EcommerceNumberSegmentConditionType::Noop => "",
-            EcommerceNumberSegmentConditionType::FallthroughString => "*",
+            EcommerceNumberSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2794,8 +2794,8 @@ pub enum EcommerceNumberSegmentField {
     EcommSpentAvg,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentField {
@@ -2806,7 +2806,7 @@ impl std::fmt::Display for EcommerceNumberSegmentField {
             EcommerceNumberSegmentField::EcommProdAll => "ecomm_prod_all",
             EcommerceNumberSegmentField::EcommSpentAvg => "ecomm_spent_avg",
             EcommerceNumberSegmentField::Noop => "",
-            EcommerceNumberSegmentField::FallthroughString => "*",
+            EcommerceNumberSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2858,8 +2858,8 @@ pub enum EcommercePurchasedSegmentConditionType {
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
@@ -2867,7 +2867,7 @@ impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
         match &*self {
             EcommercePurchasedSegmentConditionType::EcommPurchased => "EcommPurchased",
             EcommercePurchasedSegmentConditionType::Noop => "",
-            EcommercePurchasedSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2893,8 +2893,8 @@ pub enum EcommercePurchasedSegmentField {
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentField {
@@ -2902,7 +2902,7 @@ impl std::fmt::Display for EcommercePurchasedSegmentField {
         match &*self {
             EcommercePurchasedSegmentField::EcommPurchased => "ecomm_purchased",
             EcommercePurchasedSegmentField::Noop => "",
-            EcommercePurchasedSegmentField::FallthroughString => "*",
+            EcommercePurchasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2945,8 +2945,8 @@ pub enum EcommerceSpentSegmentConditionType {
     EcommSpent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentConditionType {
@@ -2954,7 +2954,7 @@ impl std::fmt::Display for EcommerceSpentSegmentConditionType {
         match &*self {
             EcommerceSpentSegmentConditionType::EcommSpent => "EcommSpent",
             EcommerceSpentSegmentConditionType::Noop => "",
-            EcommerceSpentSegmentConditionType::FallthroughString => "*",
+            EcommerceSpentSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2982,8 +2982,8 @@ pub enum EcommerceSpentSegmentField {
     EcommSpentOne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentField {
@@ -2992,7 +2992,7 @@ impl std::fmt::Display for EcommerceSpentSegmentField {
             EcommerceSpentSegmentField::EcommSpentAll => "ecomm_spent_all",
             EcommerceSpentSegmentField::EcommSpentOne => "ecomm_spent_one",
             EcommerceSpentSegmentField::Noop => "",
-            EcommerceSpentSegmentField::FallthroughString => "*",
+            EcommerceSpentSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3020,8 +3020,8 @@ pub enum EcommerceSpentSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentOperator {
@@ -3030,7 +3030,7 @@ impl std::fmt::Display for EcommerceSpentSegmentOperator {
             EcommerceSpentSegmentOperator::Greater => "greater",
             EcommerceSpentSegmentOperator::Less => "less",
             EcommerceSpentSegmentOperator::Noop => "",
-            EcommerceSpentSegmentOperator::FallthroughString => "*",
+            EcommerceSpentSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3082,8 +3082,8 @@ pub enum EcommercePurchasedStoreSegmentConditionType {
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
@@ -3091,7 +3091,7 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
         match &*self {
             EcommercePurchasedStoreSegmentConditionType::EcommStore => "EcommStore",
             EcommercePurchasedStoreSegmentConditionType::Noop => "",
-            EcommercePurchasedStoreSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3117,8 +3117,8 @@ pub enum EcommercePurchasedStoreSegmentField {
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
@@ -3126,7 +3126,7 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
         match &*self {
             EcommercePurchasedStoreSegmentField::EcommStore => "ecomm_store",
             EcommercePurchasedStoreSegmentField::Noop => "",
-            EcommercePurchasedStoreSegmentField::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3178,8 +3178,8 @@ pub enum GoalActivitySegmentConditionType {
     GoalActivity,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentConditionType {
@@ -3187,7 +3187,7 @@ impl std::fmt::Display for GoalActivitySegmentConditionType {
         match &*self {
             GoalActivitySegmentConditionType::GoalActivity => "GoalActivity",
             GoalActivitySegmentConditionType::Noop => "",
-            GoalActivitySegmentConditionType::FallthroughString => "*",
+            GoalActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3213,8 +3213,8 @@ pub enum GoalActivitySegmentField {
     Goal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentField {
@@ -3222,7 +3222,7 @@ impl std::fmt::Display for GoalActivitySegmentField {
         match &*self {
             GoalActivitySegmentField::Goal => "goal",
             GoalActivitySegmentField::Noop => "",
-            GoalActivitySegmentField::FallthroughString => "*",
+            GoalActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3258,8 +3258,8 @@ pub enum GoalActivitySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentOperator {
@@ -3272,7 +3272,7 @@ impl std::fmt::Display for GoalActivitySegmentOperator {
             GoalActivitySegmentOperator::Is => "is",
             GoalActivitySegmentOperator::Starts => "starts",
             GoalActivitySegmentOperator::Noop => "",
-            GoalActivitySegmentOperator::FallthroughString => "*",
+            GoalActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3324,8 +3324,8 @@ pub enum GoalTimestampSegmentConditionType {
     GoalTimestamp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentConditionType {
@@ -3333,7 +3333,7 @@ impl std::fmt::Display for GoalTimestampSegmentConditionType {
         match &*self {
             GoalTimestampSegmentConditionType::GoalTimestamp => "GoalTimestamp",
             GoalTimestampSegmentConditionType::Noop => "",
-            GoalTimestampSegmentConditionType::FallthroughString => "*",
+            GoalTimestampSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3359,8 +3359,8 @@ pub enum GoalTimestampSegmentField {
     GoalLastVisited,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentField {
@@ -3368,7 +3368,7 @@ impl std::fmt::Display for GoalTimestampSegmentField {
         match &*self {
             GoalTimestampSegmentField::GoalLastVisited => "goal_last_visited",
             GoalTimestampSegmentField::Noop => "",
-            GoalTimestampSegmentField::FallthroughString => "*",
+            GoalTimestampSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3398,8 +3398,8 @@ pub enum GoalTimestampSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentOperator {
@@ -3409,7 +3409,7 @@ impl std::fmt::Display for GoalTimestampSegmentOperator {
             GoalTimestampSegmentOperator::Is => "is",
             GoalTimestampSegmentOperator::Less => "less",
             GoalTimestampSegmentOperator::Noop => "",
-            GoalTimestampSegmentOperator::FallthroughString => "*",
+            GoalTimestampSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3461,8 +3461,8 @@ pub enum SimilarSubscribersSegmentMemberConditionType {
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
@@ -3470,7 +3470,7 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
         match &*self {
             SimilarSubscribersSegmentMemberConditionType::FuzzySegment => "FuzzySegment",
             SimilarSubscribersSegmentMemberConditionType::Noop => "",
-            SimilarSubscribersSegmentMemberConditionType::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3496,8 +3496,8 @@ pub enum SimilarSubscribersSegmentMemberField {
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
@@ -3505,7 +3505,7 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
         match &*self {
             SimilarSubscribersSegmentMemberField::FuzzySegment => "fuzzy_segment",
             SimilarSubscribersSegmentMemberField::Noop => "",
-            SimilarSubscribersSegmentMemberField::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3533,8 +3533,8 @@ pub enum SimilarSubscribersSegmentMemberOperator {
     FuzzyNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
@@ -3543,7 +3543,7 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
             SimilarSubscribersSegmentMemberOperator::FuzzyIs => "fuzzy_is",
             SimilarSubscribersSegmentMemberOperator::FuzzyNot => "fuzzy_not",
             SimilarSubscribersSegmentMemberOperator::Noop => "",
-            SimilarSubscribersSegmentMemberOperator::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3601,8 +3601,8 @@ pub enum StaticSegmentMemberConditionType {
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberConditionType {
@@ -3610,7 +3610,7 @@ impl std::fmt::Display for StaticSegmentMemberConditionType {
         match &*self {
             StaticSegmentMemberConditionType::StaticSegment => "StaticSegment",
             StaticSegmentMemberConditionType::Noop => "",
-            StaticSegmentMemberConditionType::FallthroughString => "*",
+            StaticSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3636,8 +3636,8 @@ pub enum StaticSegmentMemberField {
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberField {
@@ -3645,7 +3645,7 @@ impl std::fmt::Display for StaticSegmentMemberField {
         match &*self {
             StaticSegmentMemberField::StaticSegment => "static_segment",
             StaticSegmentMemberField::Noop => "",
-            StaticSegmentMemberField::FallthroughString => "*",
+            StaticSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3673,8 +3673,8 @@ pub enum StaticSegmentMemberOperator {
     StaticNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberOperator {
@@ -3683,7 +3683,7 @@ impl std::fmt::Display for StaticSegmentMemberOperator {
             StaticSegmentMemberOperator::StaticIs => "static_is",
             StaticSegmentMemberOperator::StaticNot => "static_not",
             StaticSegmentMemberOperator::Noop => "",
-            StaticSegmentMemberOperator::FallthroughString => "*",
+            StaticSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3735,8 +3735,8 @@ pub enum LocationBasedSegmentConditionType {
     IpGeoCountryState,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentConditionType {
@@ -3744,7 +3744,7 @@ impl std::fmt::Display for LocationBasedSegmentConditionType {
         match &*self {
             LocationBasedSegmentConditionType::IpGeoCountryState => "IPGeoCountryState",
             LocationBasedSegmentConditionType::Noop => "",
-            LocationBasedSegmentConditionType::FallthroughString => "*",
+            LocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3770,8 +3770,8 @@ pub enum LocationBasedSegmentField {
     Ipgeo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentField {
@@ -3779,7 +3779,7 @@ impl std::fmt::Display for LocationBasedSegmentField {
         match &*self {
             LocationBasedSegmentField::Ipgeo => "ipgeo",
             LocationBasedSegmentField::Noop => "",
-            LocationBasedSegmentField::FallthroughString => "*",
+            LocationBasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3811,8 +3811,8 @@ pub enum LocationBasedSegmentOperator {
     Ipgeostate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentOperator {
@@ -3823,7 +3823,7 @@ impl std::fmt::Display for LocationBasedSegmentOperator {
             LocationBasedSegmentOperator::Ipgeonotstate => "ipgeonotstate",
             LocationBasedSegmentOperator::Ipgeostate => "ipgeostate",
             LocationBasedSegmentOperator::Noop => "",
-            LocationBasedSegmentOperator::FallthroughString => "*",
+            LocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3875,8 +3875,8 @@ pub enum GeolocationSegmentConditionType {
     IpGeoIn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentConditionType {
@@ -3884,7 +3884,7 @@ impl std::fmt::Display for GeolocationSegmentConditionType {
         match &*self {
             GeolocationSegmentConditionType::IpGeoIn => "IPGeoIn",
             GeolocationSegmentConditionType::Noop => "",
-            GeolocationSegmentConditionType::FallthroughString => "*",
+            GeolocationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3912,8 +3912,8 @@ pub enum GeolocationSegmentOperator {
     Ipgeonotin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentOperator {
@@ -3922,7 +3922,7 @@ impl std::fmt::Display for GeolocationSegmentOperator {
             GeolocationSegmentOperator::Ipgeoin => "ipgeoin",
             GeolocationSegmentOperator::Ipgeonotin => "ipgeonotin",
             GeolocationSegmentOperator::Noop => "",
-            GeolocationSegmentOperator::FallthroughString => "*",
+            GeolocationSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4001,8 +4001,8 @@ pub enum UsZipCodeSegmentConditionType {
     IpGeoInZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentConditionType {
@@ -4010,7 +4010,7 @@ impl std::fmt::Display for UsZipCodeSegmentConditionType {
         match &*self {
             UsZipCodeSegmentConditionType::IpGeoInZip => "IPGeoInZip",
             UsZipCodeSegmentConditionType::Noop => "",
-            UsZipCodeSegmentConditionType::FallthroughString => "*",
+            UsZipCodeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4036,8 +4036,8 @@ pub enum UsZipCodeSegmentOperator {
     Ipgeoinzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentOperator {
@@ -4045,7 +4045,7 @@ impl std::fmt::Display for UsZipCodeSegmentOperator {
         match &*self {
             UsZipCodeSegmentOperator::Ipgeoinzip => "ipgeoinzip",
             UsZipCodeSegmentOperator::Noop => "",
-            UsZipCodeSegmentOperator::FallthroughString => "*",
+            UsZipCodeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4106,8 +4106,8 @@ pub enum UnknownLocationBasedSegmentConditionType {
     IpGeoUnknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
@@ -4115,7 +4115,7 @@ impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
         match &*self {
             UnknownLocationBasedSegmentConditionType::IpGeoUnknown => "IPGeoUnknown",
             UnknownLocationBasedSegmentConditionType::Noop => "",
-            UnknownLocationBasedSegmentConditionType::FallthroughString => "*",
+            UnknownLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4141,8 +4141,8 @@ pub enum UnknownLocationBasedSegmentOperator {
     Ipgeounknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
@@ -4150,7 +4150,7 @@ impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
         match &*self {
             UnknownLocationBasedSegmentOperator::Ipgeounknown => "ipgeounknown",
             UnknownLocationBasedSegmentOperator::Noop => "",
-            UnknownLocationBasedSegmentOperator::FallthroughString => "*",
+            UnknownLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4196,8 +4196,8 @@ pub enum ZipCodeLocationBasedSegmentConditionType {
     IpGeoZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
@@ -4205,7 +4205,7 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
         match &*self {
             ZipCodeLocationBasedSegmentConditionType::IpGeoZip => "IPGeoZip",
             ZipCodeLocationBasedSegmentConditionType::Noop => "",
-            ZipCodeLocationBasedSegmentConditionType::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4233,8 +4233,8 @@ pub enum ZipCodeLocationBasedSegmentOperator {
     Ipgeonotzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
@@ -4243,7 +4243,7 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
             ZipCodeLocationBasedSegmentOperator::Ipgeoiszip => "ipgeoiszip",
             ZipCodeLocationBasedSegmentOperator::Ipgeonotzip => "ipgeonotzip",
             ZipCodeLocationBasedSegmentOperator::Noop => "",
-            ZipCodeLocationBasedSegmentOperator::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4298,8 +4298,8 @@ pub enum SocialProfilesAgeSegmentConditionType {
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
@@ -4307,7 +4307,7 @@ impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
         match &*self {
             SocialProfilesAgeSegmentConditionType::SocialAge => "SocialAge",
             SocialProfilesAgeSegmentConditionType::Noop => "",
-            SocialProfilesAgeSegmentConditionType::FallthroughString => "*",
+            SocialProfilesAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4333,8 +4333,8 @@ pub enum SocialProfilesAgeSegmentField {
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentField {
@@ -4342,7 +4342,7 @@ impl std::fmt::Display for SocialProfilesAgeSegmentField {
         match &*self {
             SocialProfilesAgeSegmentField::SocialAge => "social_age",
             SocialProfilesAgeSegmentField::Noop => "",
-            SocialProfilesAgeSegmentField::FallthroughString => "*",
+            SocialProfilesAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4374,8 +4374,8 @@ pub enum Value {
     FiftyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -4386,7 +4386,7 @@ impl std::fmt::Display for Value {
             Value::ThreeThousandFiveHundredAndFiftyFour => "35-54",
             Value::FiftyFive => "55+",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4437,8 +4437,8 @@ pub enum SocialProfilesGenderSegmentConditionType {
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
@@ -4446,7 +4446,7 @@ impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
         match &*self {
             SocialProfilesGenderSegmentConditionType::SocialGender => "SocialGender",
             SocialProfilesGenderSegmentConditionType::Noop => "",
-            SocialProfilesGenderSegmentConditionType::FallthroughString => "*",
+            SocialProfilesGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4472,8 +4472,8 @@ pub enum SocialProfilesGenderSegmentField {
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentField {
@@ -4481,7 +4481,7 @@ impl std::fmt::Display for SocialProfilesGenderSegmentField {
         match &*self {
             SocialProfilesGenderSegmentField::SocialGender => "social_gender",
             SocialProfilesGenderSegmentField::Noop => "",
-            SocialProfilesGenderSegmentField::FallthroughString => "*",
+            SocialProfilesGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4509,8 +4509,8 @@ pub enum SocialProfilesGenderSegmentOperator {
     Male,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
@@ -4519,7 +4519,7 @@ impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
             SocialProfilesGenderSegmentOperator::Female => "female",
             SocialProfilesGenderSegmentOperator::Male => "male",
             SocialProfilesGenderSegmentOperator::Noop => "",
-            SocialProfilesGenderSegmentOperator::FallthroughString => "*",
+            SocialProfilesGenderSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4573,8 +4573,8 @@ pub enum SocialProfilesInfluenceSegmentConditionType {
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
@@ -4582,7 +4582,7 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
         match &*self {
             SocialProfilesInfluenceSegmentConditionType::SocialInfluence => "SocialInfluence",
             SocialProfilesInfluenceSegmentConditionType::Noop => "",
-            SocialProfilesInfluenceSegmentConditionType::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4608,8 +4608,8 @@ pub enum SocialProfilesInfluenceSegmentField {
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
@@ -4617,7 +4617,7 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
         match &*self {
             SocialProfilesInfluenceSegmentField::SocialInfluence => "social_influence",
             SocialProfilesInfluenceSegmentField::Noop => "",
-            SocialProfilesInfluenceSegmentField::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4672,8 +4672,8 @@ pub enum SocialProfilesNetworkSegmentConditionType {
     SocialNetworkMember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
@@ -4681,7 +4681,7 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
         match &*self {
             SocialProfilesNetworkSegmentConditionType::SocialNetworkMember => "SocialNetworkMember",
             SocialProfilesNetworkSegmentConditionType::Noop => "",
-            SocialProfilesNetworkSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4707,8 +4707,8 @@ pub enum SocialProfilesNetworkSegmentField {
     SocialNetwork,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentField {
@@ -4716,7 +4716,7 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentField {
         match &*self {
             SocialProfilesNetworkSegmentField::SocialNetwork => "social_network",
             SocialProfilesNetworkSegmentField::Noop => "",
-            SocialProfilesNetworkSegmentField::FallthroughString => "*",
+            SocialProfilesNetworkSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4762,8 +4762,8 @@ pub enum SocialProfilesNetworkSegmentOperator {
     Youtube,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
@@ -4781,7 +4781,7 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
             SocialProfilesNetworkSegmentOperator::Yelp => "yelp",
             SocialProfilesNetworkSegmentOperator::Youtube => "youtube",
             SocialProfilesNetworkSegmentOperator::Noop => "",
-            SocialProfilesNetworkSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4835,8 +4835,8 @@ pub enum SocialProfilesNetworkFollowSegmentConditionType {
     SocialNetworkFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
@@ -4846,7 +4846,7 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
                 "SocialNetworkFollow"
             }
             SocialProfilesNetworkFollowSegmentConditionType::Noop => "",
-            SocialProfilesNetworkFollowSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4874,8 +4874,8 @@ pub enum SocialProfilesNetworkFollowSegmentOperator {
     Notfollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
@@ -4884,7 +4884,7 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
             SocialProfilesNetworkFollowSegmentOperator::Follow => "follow",
             SocialProfilesNetworkFollowSegmentOperator::Notfollow => "notfollow",
             SocialProfilesNetworkFollowSegmentOperator::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4910,8 +4910,8 @@ pub enum SocialProfilesNetworkFollowSegmentOperatorData {
     TwitterFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
@@ -4919,7 +4919,7 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
         match &*self {
             SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow => "twitter_follow",
             SocialProfilesNetworkFollowSegmentOperatorData::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperatorData::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4976,8 +4976,8 @@ pub enum AddressMergeFieldSegmentConditionType {
     AddressMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
@@ -4985,7 +4985,7 @@ impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
         match &*self {
             AddressMergeFieldSegmentConditionType::AddressMerge => "AddressMerge",
             AddressMergeFieldSegmentConditionType::Noop => "",
-            AddressMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5017,8 +5017,8 @@ pub enum AddressMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentOperator {
@@ -5029,7 +5029,7 @@ impl std::fmt::Display for AddressMergeFieldSegmentOperator {
             AddressMergeFieldSegmentOperator::Contains => "contains",
             AddressMergeFieldSegmentOperator::Notcontain => "notcontain",
             AddressMergeFieldSegmentOperator::Noop => "",
-            AddressMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5088,8 +5088,8 @@ pub enum AddressZipMergeFieldSegmentConditionType {
     ZipMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
@@ -5097,7 +5097,7 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
         match &*self {
             AddressZipMergeFieldSegmentConditionType::ZipMerge => "ZipMerge",
             AddressZipMergeFieldSegmentConditionType::Noop => "",
-            AddressZipMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressZipMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5123,8 +5123,8 @@ pub enum AddressZipMergeFieldSegmentOperator {
     Geoin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
@@ -5132,7 +5132,7 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
         match &*self {
             AddressZipMergeFieldSegmentOperator::Geoin => "geoin",
             AddressZipMergeFieldSegmentOperator::Noop => "",
-            AddressZipMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressZipMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5200,8 +5200,8 @@ pub enum BirthdayMergeFieldSegmentConditionType {
     BirthdayMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
@@ -5209,7 +5209,7 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
         match &*self {
             BirthdayMergeFieldSegmentConditionType::BirthdayMerge => "BirthdayMerge",
             BirthdayMergeFieldSegmentConditionType::Noop => "",
-            BirthdayMergeFieldSegmentConditionType::FallthroughString => "*",
+            BirthdayMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5241,8 +5241,8 @@ pub enum BirthdayMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
@@ -5253,7 +5253,7 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
             BirthdayMergeFieldSegmentOperator::Is => "is",
             BirthdayMergeFieldSegmentOperator::Not => "not",
             BirthdayMergeFieldSegmentOperator::Noop => "",
-            BirthdayMergeFieldSegmentOperator::FallthroughString => "*",
+            BirthdayMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5312,8 +5312,8 @@ pub enum DateMergeFieldSegmentConditionType {
     DateMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentConditionType {
@@ -5321,7 +5321,7 @@ impl std::fmt::Display for DateMergeFieldSegmentConditionType {
         match &*self {
             DateMergeFieldSegmentConditionType::DateMerge => "DateMerge",
             DateMergeFieldSegmentConditionType::Noop => "",
-            DateMergeFieldSegmentConditionType::FallthroughString => "*",
+            DateMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5357,8 +5357,8 @@ pub enum DateMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentOperator {
@@ -5371,7 +5371,7 @@ impl std::fmt::Display for DateMergeFieldSegmentOperator {
             DateMergeFieldSegmentOperator::Less => "less",
             DateMergeFieldSegmentOperator::Not => "not",
             DateMergeFieldSegmentOperator::Noop => "",
-            DateMergeFieldSegmentOperator::FallthroughString => "*",
+            DateMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5430,8 +5430,8 @@ pub enum DropdownRadioMergeFieldSegmentConditionType {
     SelectMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
@@ -5439,7 +5439,7 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
         match &*self {
             DropdownRadioMergeFieldSegmentConditionType::SelectMerge => "SelectMerge",
             DropdownRadioMergeFieldSegmentConditionType::Noop => "",
-            DropdownRadioMergeFieldSegmentConditionType::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5475,8 +5475,8 @@ pub enum DropdownRadioMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
@@ -5489,7 +5489,7 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
             DropdownRadioMergeFieldSegmentOperator::Not => "not",
             DropdownRadioMergeFieldSegmentOperator::Notcontain => "notcontain",
             DropdownRadioMergeFieldSegmentOperator::Noop => "",
-            DropdownRadioMergeFieldSegmentOperator::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5548,8 +5548,8 @@ pub enum TextNumberMergeFieldSegmentConditionType {
     TextMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
@@ -5557,7 +5557,7 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
         match &*self {
             TextNumberMergeFieldSegmentConditionType::TextMerge => "TextMerge",
             TextNumberMergeFieldSegmentConditionType::Noop => "",
-            TextNumberMergeFieldSegmentConditionType::FallthroughString => "*",
+            TextNumberMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5601,8 +5601,8 @@ pub enum TextNumberMergeFieldSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
@@ -5619,7 +5619,7 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
             TextNumberMergeFieldSegmentOperator::Notcontain => "notcontain",
             TextNumberMergeFieldSegmentOperator::Starts => "starts",
             TextNumberMergeFieldSegmentOperator::Noop => "",
-            TextNumberMergeFieldSegmentOperator::FallthroughString => "*",
+            TextNumberMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5678,8 +5678,8 @@ pub enum EmailSegmentConditionType {
     EmailAddress,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentConditionType {
@@ -5687,7 +5687,7 @@ impl std::fmt::Display for EmailSegmentConditionType {
         match &*self {
             EmailSegmentConditionType::EmailAddress => "EmailAddress",
             EmailSegmentConditionType::Noop => "",
-            EmailSegmentConditionType::FallthroughString => "*",
+            EmailSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5715,8 +5715,8 @@ pub enum EmailSegmentField {
     Merge0,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentField {
@@ -5725,7 +5725,7 @@ impl std::fmt::Display for EmailSegmentField {
             EmailSegmentField::Email => "EMAIL",
             EmailSegmentField::Merge0 => "merge0",
             EmailSegmentField::Noop => "",
-            EmailSegmentField::FallthroughString => "*",
+            EmailSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5765,8 +5765,8 @@ pub enum EmailSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentOperator {
@@ -5781,7 +5781,7 @@ impl std::fmt::Display for EmailSegmentOperator {
             EmailSegmentOperator::Notcontain => "notcontain",
             EmailSegmentOperator::Starts => "starts",
             EmailSegmentOperator::Noop => "",
-            EmailSegmentOperator::FallthroughString => "*",
+            EmailSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5833,8 +5833,8 @@ pub enum PredictedGenderSegmentConditionType {
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentConditionType {
@@ -5842,7 +5842,7 @@ impl std::fmt::Display for PredictedGenderSegmentConditionType {
         match &*self {
             PredictedGenderSegmentConditionType::PredictedGender => "PredictedGender",
             PredictedGenderSegmentConditionType::Noop => "",
-            PredictedGenderSegmentConditionType::FallthroughString => "*",
+            PredictedGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5868,8 +5868,8 @@ pub enum PredictedGenderSegmentField {
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentField {
@@ -5877,7 +5877,7 @@ impl std::fmt::Display for PredictedGenderSegmentField {
         match &*self {
             PredictedGenderSegmentField::PredictedGender => "predicted_gender",
             PredictedGenderSegmentField::Noop => "",
-            PredictedGenderSegmentField::FallthroughString => "*",
+            PredictedGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5928,8 +5928,8 @@ pub enum PredictedAgeSegmentConditionType {
     PredictedAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentConditionType {
@@ -5937,7 +5937,7 @@ impl std::fmt::Display for PredictedAgeSegmentConditionType {
         match &*self {
             PredictedAgeSegmentConditionType::PredictedAge => "PredictedAge",
             PredictedAgeSegmentConditionType::Noop => "",
-            PredictedAgeSegmentConditionType::FallthroughString => "*",
+            PredictedAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5963,8 +5963,8 @@ pub enum PredictedAgeSegmentField {
     PredictedAgeRange,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentField {
@@ -5972,7 +5972,7 @@ impl std::fmt::Display for PredictedAgeSegmentField {
         match &*self {
             PredictedAgeSegmentField::PredictedAgeRange => "predicted_age_range",
             PredictedAgeSegmentField::Noop => "",
-            PredictedAgeSegmentField::FallthroughString => "*",
+            PredictedAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5998,8 +5998,8 @@ pub enum PredictedAgeSegmentOperator {
     Is,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperator {
@@ -6007,7 +6007,7 @@ impl std::fmt::Display for PredictedAgeSegmentOperator {
         match &*self {
             PredictedAgeSegmentOperator::Is => "is",
             PredictedAgeSegmentOperator::Noop => "",
-            PredictedAgeSegmentOperator::FallthroughString => "*",
+            PredictedAgeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6043,8 +6043,8 @@ pub enum PredictedAgeSegmentOperatorData {
     SixtyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperatorData {
@@ -6057,7 +6057,7 @@ impl std::fmt::Display for PredictedAgeSegmentOperatorData {
             PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour => "55-64",
             PredictedAgeSegmentOperatorData::SixtyFive => "65+",
             PredictedAgeSegmentOperatorData::Noop => "",
-            PredictedAgeSegmentOperatorData::FallthroughString => "*",
+            PredictedAgeSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6108,8 +6108,8 @@ pub enum NewSubscribersPrebuiltSegmentConditionType {
     NewSubscribers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
@@ -6117,7 +6117,7 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
         match &*self {
             NewSubscribersPrebuiltSegmentConditionType::NewSubscribers => "NewSubscribers",
             NewSubscribersPrebuiltSegmentConditionType::Noop => "",
-            NewSubscribersPrebuiltSegmentConditionType::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6143,8 +6143,8 @@ pub enum NewSubscribersPrebuiltSegmentField {
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
@@ -6152,7 +6152,7 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
         match &*self {
             NewSubscribersPrebuiltSegmentField::TimestampOpt => "timestamp_opt",
             NewSubscribersPrebuiltSegmentField::Noop => "",
-            NewSubscribersPrebuiltSegmentField::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6178,8 +6178,8 @@ pub enum NewSubscribersPrebuiltSegmentOperator {
     DateWithin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
@@ -6187,7 +6187,7 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
         match &*self {
             NewSubscribersPrebuiltSegmentOperator::DateWithin => "date_within",
             NewSubscribersPrebuiltSegmentOperator::Noop => "",
-            NewSubscribersPrebuiltSegmentOperator::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7034,8 +7034,8 @@ pub enum WorkflowType {
     WelcomeSeries,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowType {
@@ -7059,7 +7059,7 @@ impl std::fmt::Display for WorkflowType {
             WorkflowType::VisitUrl => "visitUrl",
             WorkflowType::WelcomeSeries => "welcomeSeries",
             WorkflowType::Noop => "",
-            WorkflowType::FallthroughString => "*",
+            WorkflowType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7094,8 +7094,8 @@ pub enum Days {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Days {
@@ -7109,7 +7109,7 @@ impl std::fmt::Display for Days {
             Days::Tuesday => "tuesday",
             Days::Wednesday => "wednesday",
             Days::Noop => "",
-            Days::FallthroughString => "*",
+            Days::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7139,8 +7139,8 @@ pub enum EmailSendTimeSettings {
     SendBetween,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSendTimeSettings {
@@ -7150,7 +7150,7 @@ impl std::fmt::Display for EmailSendTimeSettings {
             EmailSendTimeSettings::SendAt => "send_at",
             EmailSendTimeSettings::SendBetween => "send_between",
             EmailSendTimeSettings::Noop => "",
-            EmailSendTimeSettings::FallthroughString => "*",
+            EmailSendTimeSettings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7501,8 +7501,8 @@ pub enum DelayType {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayType {
@@ -7513,7 +7513,7 @@ impl std::fmt::Display for DelayType {
             DelayType::Now => "now",
             DelayType::Week => "week",
             DelayType::Noop => "",
-            DelayType::FallthroughString => "*",
+            DelayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7541,8 +7541,8 @@ pub enum Direction {
     Before,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -7551,7 +7551,7 @@ impl std::fmt::Display for Direction {
             Direction::After => "after",
             Direction::Before => "before",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7641,8 +7641,8 @@ pub enum Action {
     TagAdd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -7682,7 +7682,7 @@ impl std::fmt::Display for Action {
             Action::Signup => "signup",
             Action::TagAdd => "tag_add",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8371,8 +8371,8 @@ pub enum DelayDirection {
     After,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayDirection {
@@ -8380,7 +8380,7 @@ impl std::fmt::Display for DelayDirection {
         match &*self {
             DelayDirection::After => "after",
             DelayDirection::Noop => "",
-            DelayDirection::FallthroughString => "*",
+            DelayDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8410,8 +8410,8 @@ pub enum DelayAction {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayAction {
@@ -8421,7 +8421,7 @@ impl std::fmt::Display for DelayAction {
             DelayAction::EcommAbandonedCart => "ecomm_abandoned_cart",
             DelayAction::Signup => "signup",
             DelayAction::Noop => "",
-            DelayAction::FallthroughString => "*",
+            DelayAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8789,8 +8789,8 @@ pub enum BatchOperationsStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BatchOperationsStatus {
@@ -8802,7 +8802,7 @@ impl std::fmt::Display for BatchOperationsStatus {
             BatchOperationsStatus::Preprocessing => "preprocessing",
             BatchOperationsStatus::Started => "started",
             BatchOperationsStatus::Noop => "",
-            BatchOperationsStatus::FallthroughString => "*",
+            BatchOperationsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8952,8 +8952,8 @@ pub enum HttpMethod {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HttpMethod {
@@ -8965,7 +8965,7 @@ impl std::fmt::Display for HttpMethod {
             HttpMethod::Post => "POST",
             HttpMethod::Put => "PUT",
             HttpMethod::Noop => "",
-            HttpMethod::FallthroughString => "*",
+            HttpMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9018,7 +9018,7 @@ pub struct Operations {
     pub path: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostBatchesRequest {
     /**
     * An array of objects that describes operations to perform.
@@ -9031,6 +9031,29 @@ pub struct PostBatchesRequest {
     pub operations: Vec<Operations>,
 }
 
+impl PostBatchesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostBatchesRequestBuilder {
+        PostBatchesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostBatchesRequest`.
+#[derive(Debug, Clone)]
+pub struct PostBatchesRequestBuilder(PostBatchesRequest);
+
+impl PostBatchesRequestBuilder {
+    pub fn operations(mut self, operations: Vec<Operations>) -> Self {
+        self.0.operations = operations;
+        self
+    }
+
+    pub fn build(self) -> PostBatchesRequest {
+        self.0
+    }
+}
+
 /// A webhook configured for batch status updates.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Webhooks {
@@ -9278,8 +9301,8 @@ pub enum CampaignType {
     Variate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignType {
@@ -9291,7 +9314,7 @@ impl std::fmt::Display for CampaignType {
             CampaignType::Rss => "rss",
             CampaignType::Variate => "variate",
             CampaignType::Noop => "",
-            CampaignType::FallthroughString => "*",
+            CampaignType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9325,8 +9348,8 @@ pub enum GetCampaignsStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCampaignsStatus {
@@ -9338,7 +9361,7 @@ impl std::fmt::Display for GetCampaignsStatus {
             GetCampaignsStatus::Sending => "sending",
             GetCampaignsStatus::Sent => "sent",
             GetCampaignsStatus::Noop => "",
-            GetCampaignsStatus::FallthroughString => "*",
+            GetCampaignsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9366,8 +9389,8 @@ pub enum SortField {
     SendTime,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortField {
@@ -9376,7 +9399,7 @@ impl std::fmt::Display for SortField {
             SortField::CreateTime => "create_time",
             SortField::SendTime => "send_time",
             SortField::Noop => "",
-            SortField::FallthroughString => "*",
+            SortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9404,8 +9427,8 @@ pub enum SortDir {
     Desc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortDir {
@@ -9414,7 +9437,7 @@ impl std::fmt::Display for SortDir {
             SortDir::Asc => "ASC",
             SortDir::Desc => "DESC",
             SortDir::Noop => "",
-            SortDir::FallthroughString => "*",
+            SortDir::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9454,8 +9477,8 @@ pub enum CampaignStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignStatus {
@@ -9470,7 +9493,7 @@ impl std::fmt::Display for CampaignStatus {
             CampaignStatus::Sending => "sending",
             CampaignStatus::Sent => "sent",
             CampaignStatus::Noop => "",
-            CampaignStatus::FallthroughString => "*",
+            CampaignStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9502,8 +9525,8 @@ pub enum ContentType {
     Url,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContentType {
@@ -9514,7 +9537,7 @@ impl std::fmt::Display for ContentType {
             ContentType::Template => "template",
             ContentType::Url => "url",
             ContentType::Noop => "",
-            ContentType::FallthroughString => "*",
+            ContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9696,8 +9719,8 @@ pub enum WinnerCriteria {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -9708,7 +9731,7 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Opens => "opens",
             WinnerCriteria::TotalRevenue => "total_revenue",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9905,8 +9928,8 @@ pub enum Frequency {
     Weekly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -9916,7 +9939,7 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "monthly",
             Frequency::Weekly => "weekly",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10081,8 +10104,8 @@ pub enum SplitTest {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SplitTest {
@@ -10092,7 +10115,7 @@ impl std::fmt::Display for SplitTest {
             SplitTest::Schedule => "schedule",
             SplitTest::Subject => "subject",
             SplitTest::Noop => "",
-            SplitTest::FallthroughString => "*",
+            SplitTest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10122,8 +10145,8 @@ pub enum PickWinner {
     Opens,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PickWinner {
@@ -10133,7 +10156,7 @@ impl std::fmt::Display for PickWinner {
             PickWinner::Manual => "manual",
             PickWinner::Opens => "opens",
             PickWinner::Noop => "",
-            PickWinner::FallthroughString => "*",
+            PickWinner::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10161,8 +10184,8 @@ pub enum WaitTime {
     Hours,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaitTime {
@@ -10171,7 +10194,7 @@ impl std::fmt::Display for WaitTime {
             WaitTime::Days => "days",
             WaitTime::Hours => "hours",
             WaitTime::Noop => "",
-            WaitTime::FallthroughString => "*",
+            WaitTime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10418,8 +10441,8 @@ pub enum CampaignDeliveryStatus {
     Delivering,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignDeliveryStatus {
@@ -10430,7 +10453,7 @@ impl std::fmt::Display for CampaignDeliveryStatus {
             CampaignDeliveryStatus::Delivered => "delivered",
             CampaignDeliveryStatus::Delivering => "delivering",
             CampaignDeliveryStatus::Noop => "",
-            CampaignDeliveryStatus::FallthroughString => "*",
+            CampaignDeliveryStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10975,8 +10998,8 @@ pub enum CreatedCampaignContentType {
     Template,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreatedCampaignContentType {
@@ -10985,7 +11008,7 @@ impl std::fmt::Display for CreatedCampaignContentType {
             CreatedCampaignContentType::Multichannel => "multichannel",
             CreatedCampaignContentType::Template => "template",
             CreatedCampaignContentType::Noop => "",
-            CreatedCampaignContentType::FallthroughString => "*",
+            CreatedCampaignContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11716,7 +11739,7 @@ pub struct BatchDelivery {
     pub batch_delay: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCampaignsActionsScheduleRequest {
     /**
     * Choose whether the campaign should use [Batch Delivery](https://mailchimp.com/help/schedule-batch-delivery/). Cannot be set to `true` for campaigns using [Timewarp](https://mailchimp.com/help/use-timewarp/).
@@ -11739,6 +11762,42 @@ pub struct PostCampaignsActionsScheduleRequest {
     pub timewarp: Option<bool>,
 }
 
+impl PostCampaignsActionsScheduleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCampaignsActionsScheduleRequestBuilder {
+        PostCampaignsActionsScheduleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCampaignsActionsScheduleRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCampaignsActionsScheduleRequestBuilder(PostCampaignsActionsScheduleRequest);
+
+impl PostCampaignsActionsScheduleRequestBuilder {
+    pub fn batch_delivery(mut self, batch_delivery: impl Into<BatchDelivery>) -> Self {
+        self.0.batch_delivery = Some(batch_delivery.into());
+        self
+    }
+
+    pub fn schedule_time(
+        mut self,
+        schedule_time: impl Into<chrono::DateTime<chrono::Utc>>,
+    ) -> Self {
+        self.0.schedule_time = Some(schedule_time.into());
+        self
+    }
+
+    pub fn timewarp(mut self, timewarp: bool) -> Self {
+        self.0.timewarp = Some(timewarp);
+        self
+    }
+
+    pub fn build(self) -> PostCampaignsActionsScheduleRequest {
+        self.0
+    }
+}
+
 /**
 * Choose the type of test email to send.
 */
@@ -11750,8 +11809,8 @@ pub enum SendType {
     Plaintext,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendType {
@@ -11760,7 +11819,7 @@ impl std::fmt::Display for SendType {
             SendType::Html => "html",
             SendType::Plaintext => "plaintext",
             SendType::Noop => "",
-            SendType::FallthroughString => "*",
+            SendType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11777,7 +11836,7 @@ impl SendType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCampaignsActionsTestRequest {
     /**
     * Choose the type of test email to send.
@@ -11795,6 +11854,34 @@ pub struct PostCampaignsActionsTestRequest {
     pub test_emails: Vec<String>,
 }
 
+impl PostCampaignsActionsTestRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCampaignsActionsTestRequestBuilder {
+        PostCampaignsActionsTestRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCampaignsActionsTestRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCampaignsActionsTestRequestBuilder(PostCampaignsActionsTestRequest);
+
+impl PostCampaignsActionsTestRequestBuilder {
+    pub fn send_type(mut self, send_type: impl Into<SendType>) -> Self {
+        self.0.send_type = send_type.into();
+        self
+    }
+
+    pub fn test_emails(mut self, test_emails: Vec<String>) -> Self {
+        self.0.test_emails = test_emails;
+        self
+    }
+
+    pub fn build(self) -> PostCampaignsActionsTestRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VariateContents {
     /**
@@ -11915,8 +12002,8 @@ pub enum ArchiveType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ArchiveType {
@@ -11929,7 +12016,7 @@ impl std::fmt::Display for ArchiveType {
             ArchiveType::Tgz => "tgz",
             ArchiveType::Zip => "zip",
             ArchiveType::Noop => "",
-            ArchiveType::FallthroughString => "*",
+            ArchiveType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12104,8 +12191,8 @@ pub enum Source {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -12118,7 +12205,7 @@ impl std::fmt::Display for Source {
             Source::Sms => "sms",
             Source::Web => "web",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12452,8 +12539,8 @@ pub enum SendChecklistItemsType {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendChecklistItemsType {
@@ -12463,7 +12550,7 @@ impl std::fmt::Display for SendChecklistItemsType {
             SendChecklistItemsType::Success => "success",
             SendChecklistItemsType::Warning => "warning",
             SendChecklistItemsType::Noop => "",
-            SendChecklistItemsType::FallthroughString => "*",
+            SendChecklistItemsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12712,8 +12799,8 @@ pub enum IsRead {
     True,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IsRead {
@@ -12722,7 +12809,7 @@ impl std::fmt::Display for IsRead {
             IsRead::False => "false",
             IsRead::True => "true",
             IsRead::Noop => "",
-            IsRead::FallthroughString => "*",
+            IsRead::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13068,8 +13155,8 @@ pub enum GetFileManagerFilesSortField {
     AddedDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetFileManagerFilesSortField {
@@ -13077,7 +13164,7 @@ impl std::fmt::Display for GetFileManagerFilesSortField {
         match &*self {
             GetFileManagerFilesSortField::AddedDate => "added_date",
             GetFileManagerFilesSortField::Noop => "",
-            GetFileManagerFilesSortField::FallthroughString => "*",
+            GetFileManagerFilesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13105,8 +13192,8 @@ pub enum FileType {
     Image,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -13115,7 +13202,7 @@ impl std::fmt::Display for FileType {
             FileType::File => "file",
             FileType::Image => "image",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13441,8 +13528,8 @@ pub enum GetListsSortField {
     DateCreated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsSortField {
@@ -13450,7 +13537,7 @@ impl std::fmt::Display for GetListsSortField {
         match &*self {
             GetListsSortField::DateCreated => "date_created",
             GetListsSortField::Noop => "",
-            GetListsSortField::FallthroughString => "*",
+            GetListsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13598,8 +13685,8 @@ pub enum Visibility {
     Pub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -13608,7 +13695,7 @@ impl std::fmt::Display for Visibility {
             Visibility::Prv => "prv",
             Visibility::Pub => "pub",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14253,8 +14340,8 @@ pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
@@ -14265,7 +14352,7 @@ impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed => "subscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed => "unsubscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop => "",
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::FallthroughString => "*",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14446,8 +14533,8 @@ pub enum StatusIfNew {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StatusIfNew {
@@ -14459,7 +14546,7 @@ impl std::fmt::Display for StatusIfNew {
             StatusIfNew::Transactional => "transactional",
             StatusIfNew::Unsubscribed => "unsubscribed",
             StatusIfNew::Noop => "",
-            StatusIfNew::FallthroughString => "*",
+            StatusIfNew::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14823,8 +14910,8 @@ pub enum ErrorCode {
     ErrorGeneric,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -14833,7 +14920,7 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::ErrorContactExists => "ERROR_CONTACT_EXISTS",
             ErrorCode::ErrorGeneric => "ERROR_GENERIC",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15453,8 +15540,8 @@ pub enum GetListsGrowthHistorySortField {
     Month,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsGrowthHistorySortField {
@@ -15462,7 +15549,7 @@ impl std::fmt::Display for GetListsGrowthHistorySortField {
         match &*self {
             GetListsGrowthHistorySortField::Month => "month",
             GetListsGrowthHistorySortField::Noop => "",
-            GetListsGrowthHistorySortField::FallthroughString => "*",
+            GetListsGrowthHistorySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15875,8 +15962,8 @@ pub enum CollectionOfSegmentsType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CollectionOfSegmentsType {
@@ -15886,7 +15973,7 @@ impl std::fmt::Display for CollectionOfSegmentsType {
             CollectionOfSegmentsType::Saved => "saved",
             CollectionOfSegmentsType::Static => "static",
             CollectionOfSegmentsType::Noop => "",
-            CollectionOfSegmentsType::FallthroughString => "*",
+            CollectionOfSegmentsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16499,8 +16586,8 @@ pub enum GetListsMembersStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersStatus {
@@ -16513,7 +16600,7 @@ impl std::fmt::Display for GetListsMembersStatus {
             GetListsMembersStatus::Transactional => "transactional",
             GetListsMembersStatus::Unsubscribed => "unsubscribed",
             GetListsMembersStatus::Noop => "",
-            GetListsMembersStatus::FallthroughString => "*",
+            GetListsMembersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16543,8 +16630,8 @@ pub enum InterestMatch {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestMatch {
@@ -16554,7 +16641,7 @@ impl std::fmt::Display for InterestMatch {
             InterestMatch::Any => "any",
             InterestMatch::None => "none",
             InterestMatch::Noop => "",
-            InterestMatch::FallthroughString => "*",
+            InterestMatch::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16584,8 +16671,8 @@ pub enum GetListsMembersSortField {
     TimestampSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersSortField {
@@ -16595,7 +16682,7 @@ impl std::fmt::Display for GetListsMembersSortField {
             GetListsMembersSortField::TimestampOpt => "timestamp_opt",
             GetListsMembersSortField::TimestampSignup => "timestamp_signup",
             GetListsMembersSortField::Noop => "",
-            GetListsMembersSortField::FallthroughString => "*",
+            GetListsMembersSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17470,8 +17557,8 @@ pub enum ActivityType {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ActivityType {
@@ -17479,7 +17566,7 @@ impl std::fmt::Display for ActivityType {
         match &*self {
             ActivityType::Open => "open",
             ActivityType::Noop => "",
-            ActivityType::FallthroughString => "*",
+            ActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17542,8 +17629,8 @@ pub enum EmailClicksActivityType {
     Click,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClicksActivityType {
@@ -17551,7 +17638,7 @@ impl std::fmt::Display for EmailClicksActivityType {
         match &*self {
             EmailClicksActivityType::Click => "click",
             EmailClicksActivityType::Noop => "",
-            EmailClicksActivityType::FallthroughString => "*",
+            EmailClicksActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17623,8 +17710,8 @@ pub enum EmailBouncedActivityType {
     Bounce,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailBouncedActivityType {
@@ -17632,7 +17719,7 @@ impl std::fmt::Display for EmailBouncedActivityType {
         match &*self {
             EmailBouncedActivityType::Bounce => "bounce",
             EmailBouncedActivityType::Noop => "",
-            EmailBouncedActivityType::FallthroughString => "*",
+            EmailBouncedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17660,8 +17747,8 @@ pub enum BounceType {
     Soft,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -17670,7 +17757,7 @@ impl std::fmt::Display for BounceType {
             BounceType::Hard => "hard",
             BounceType::Soft => "soft",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17746,8 +17833,8 @@ pub enum ListUnsubscribedActivityType {
     Unsub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListUnsubscribedActivityType {
@@ -17755,7 +17842,7 @@ impl std::fmt::Display for ListUnsubscribedActivityType {
         match &*self {
             ListUnsubscribedActivityType::Unsub => "unsub",
             ListUnsubscribedActivityType::Noop => "",
-            ListUnsubscribedActivityType::FallthroughString => "*",
+            ListUnsubscribedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17835,8 +17922,8 @@ pub enum EmailSentActivityType {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSentActivityType {
@@ -17844,7 +17931,7 @@ impl std::fmt::Display for EmailSentActivityType {
         match &*self {
             EmailSentActivityType::Sent => "sent",
             EmailSentActivityType::Noop => "",
-            EmailSentActivityType::FallthroughString => "*",
+            EmailSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18005,8 +18092,8 @@ pub enum NoteActivityType {
     Note,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NoteActivityType {
@@ -18014,7 +18101,7 @@ impl std::fmt::Display for NoteActivityType {
         match &*self {
             NoteActivityType::Note => "note",
             NoteActivityType::Noop => "",
-            NoteActivityType::FallthroughString => "*",
+            NoteActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18104,8 +18191,8 @@ pub enum MarketingPermissionActivityType {
     MarketingPermission,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MarketingPermissionActivityType {
@@ -18113,7 +18200,7 @@ impl std::fmt::Display for MarketingPermissionActivityType {
         match &*self {
             MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
             MarketingPermissionActivityType::Noop => "",
-            MarketingPermissionActivityType::FallthroughString => "*",
+            MarketingPermissionActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18184,8 +18271,8 @@ pub enum PostcardSentActivityType {
     PostcardSent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostcardSentActivityType {
@@ -18193,7 +18280,7 @@ impl std::fmt::Display for PostcardSentActivityType {
         match &*self {
             PostcardSentActivityType::PostcardSent => "postcard_sent",
             PostcardSentActivityType::Noop => "",
-            PostcardSentActivityType::FallthroughString => "*",
+            PostcardSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18256,8 +18343,8 @@ pub enum SquatterSignupActivityType {
     SquatterSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SquatterSignupActivityType {
@@ -18265,7 +18352,7 @@ impl std::fmt::Display for SquatterSignupActivityType {
         match &*self {
             SquatterSignupActivityType::SquatterSignup => "squatter_signup",
             SquatterSignupActivityType::Noop => "",
-            SquatterSignupActivityType::FallthroughString => "*",
+            SquatterSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18328,8 +18415,8 @@ pub enum WebsiteSignupActivityType {
     WebsiteSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebsiteSignupActivityType {
@@ -18337,7 +18424,7 @@ impl std::fmt::Display for WebsiteSignupActivityType {
         match &*self {
             WebsiteSignupActivityType::WebsiteSignup => "website_signup",
             WebsiteSignupActivityType::Noop => "",
-            WebsiteSignupActivityType::FallthroughString => "*",
+            WebsiteSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18400,8 +18487,8 @@ pub enum LandingPageSignupActivityType {
     LandingPageSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageSignupActivityType {
@@ -18409,7 +18496,7 @@ impl std::fmt::Display for LandingPageSignupActivityType {
         match &*self {
             LandingPageSignupActivityType::LandingPageSignup => "landing_page_signup",
             LandingPageSignupActivityType::Noop => "",
-            LandingPageSignupActivityType::FallthroughString => "*",
+            LandingPageSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18472,8 +18559,8 @@ pub enum EcommerceSignupActivityType {
     EcommerceSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSignupActivityType {
@@ -18481,7 +18568,7 @@ impl std::fmt::Display for EcommerceSignupActivityType {
         match &*self {
             EcommerceSignupActivityType::EcommerceSignup => "ecommerce_signup",
             EcommerceSignupActivityType::Noop => "",
-            EcommerceSignupActivityType::FallthroughString => "*",
+            EcommerceSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18535,8 +18622,8 @@ pub enum GenericSignupActivityType {
     GenericSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GenericSignupActivityType {
@@ -18544,7 +18631,7 @@ impl std::fmt::Display for GenericSignupActivityType {
         match &*self {
             GenericSignupActivityType::GenericSignup => "generic_signup",
             GenericSignupActivityType::Noop => "",
-            GenericSignupActivityType::FallthroughString => "*",
+            GenericSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18598,8 +18685,8 @@ pub enum EcommerceOrderActivityType {
     Order,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceOrderActivityType {
@@ -18607,7 +18694,7 @@ impl std::fmt::Display for EcommerceOrderActivityType {
         match &*self {
             EcommerceOrderActivityType::Order => "order",
             EcommerceOrderActivityType::Noop => "",
-            EcommerceOrderActivityType::FallthroughString => "*",
+            EcommerceOrderActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18820,8 +18907,8 @@ pub enum ContactActivityEventType {
     Event,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactActivityEventType {
@@ -18829,7 +18916,7 @@ impl std::fmt::Display for ContactActivityEventType {
         match &*self {
             ContactActivityEventType::Event => "event",
             ContactActivityEventType::Noop => "",
-            ContactActivityEventType::FallthroughString => "*",
+            ContactActivityEventType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18892,8 +18979,8 @@ pub enum SurveyResponseActivityType {
     SurveyResponse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyResponseActivityType {
@@ -18901,7 +18988,7 @@ impl std::fmt::Display for SurveyResponseActivityType {
         match &*self {
             SurveyResponseActivityType::SurveyResponse => "survey_response",
             SurveyResponseActivityType::Noop => "",
-            SurveyResponseActivityType::FallthroughString => "*",
+            SurveyResponseActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19257,8 +19344,8 @@ pub enum MemberTagsTagStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberTagsTagStatus {
@@ -19267,7 +19354,7 @@ impl std::fmt::Display for MemberTagsTagStatus {
             MemberTagsTagStatus::Active => "active",
             MemberTagsTagStatus::Inactive => "inactive",
             MemberTagsTagStatus::Noop => "",
-            MemberTagsTagStatus::FallthroughString => "*",
+            MemberTagsTagStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19535,8 +19622,8 @@ pub enum GetListsMembersNotesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersNotesSortField {
@@ -19546,7 +19633,7 @@ impl std::fmt::Display for GetListsMembersNotesSortField {
             GetListsMembersNotesSortField::NoteId => "note_id",
             GetListsMembersNotesSortField::UpdatedAt => "updated_at",
             GetListsMembersNotesSortField::Noop => "",
-            GetListsMembersNotesSortField::FallthroughString => "*",
+            GetListsMembersNotesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19721,8 +19808,8 @@ pub enum MergeFieldType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeFieldType {
@@ -19740,7 +19827,7 @@ impl std::fmt::Display for MergeFieldType {
             MergeFieldType::Url => "url",
             MergeFieldType::Zip => "zip",
             MergeFieldType::Noop => "",
-            MergeFieldType::FallthroughString => "*",
+            MergeFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20336,8 +20423,8 @@ pub enum ImageAlign {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageAlign {
@@ -20348,7 +20435,7 @@ impl std::fmt::Display for ImageAlign {
             ImageAlign::None => "none",
             ImageAlign::Right => "right",
             ImageAlign::Noop => "",
-            ImageAlign::FallthroughString => "*",
+            ImageAlign::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20390,8 +20477,8 @@ pub enum ImageBorderStyle {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageBorderStyle {
@@ -20407,7 +20494,7 @@ impl std::fmt::Display for ImageBorderStyle {
             ImageBorderStyle::Ridge => "ridge",
             ImageBorderStyle::Solid => "solid",
             ImageBorderStyle::Noop => "",
-            ImageBorderStyle::FallthroughString => "*",
+            ImageBorderStyle::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20435,8 +20522,8 @@ pub enum ImageTarget {
     Null,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageTarget {
@@ -20445,7 +20532,7 @@ impl std::fmt::Display for ImageTarget {
             ImageTarget::Blank => "_blank",
             ImageTarget::Null => "null",
             ImageTarget::Noop => "",
-            ImageTarget::FallthroughString => "*",
+            ImageTarget::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20567,8 +20654,8 @@ pub enum Section {
     UnsubMessage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Section {
@@ -20578,7 +20665,7 @@ impl std::fmt::Display for Section {
             Section::SignupThankYouTitle => "signup_thank_you_title",
             Section::UnsubMessage => "unsub_message",
             Section::Noop => "",
-            Section::FallthroughString => "*",
+            Section::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20649,8 +20736,8 @@ pub enum Selector {
     PageOuterWrapper,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Selector {
@@ -20671,7 +20758,7 @@ impl std::fmt::Display for Selector {
             Selector::PageHeader => "page_header",
             Selector::PageOuterWrapper => "page_outer_wrapper",
             Selector::Noop => "",
-            Selector::FallthroughString => "*",
+            Selector::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20937,8 +21024,8 @@ pub enum GetAllLandingPagesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllLandingPagesSortField {
@@ -20947,7 +21034,7 @@ impl std::fmt::Display for GetAllLandingPagesSortField {
             GetAllLandingPagesSortField::CreatedAt => "created_at",
             GetAllLandingPagesSortField::UpdatedAt => "updated_at",
             GetAllLandingPagesSortField::Noop => "",
-            GetAllLandingPagesSortField::FallthroughString => "*",
+            GetAllLandingPagesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20977,8 +21064,8 @@ pub enum LandingPageStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageStatus {
@@ -20988,7 +21075,7 @@ impl std::fmt::Display for LandingPageStatus {
             LandingPageStatus::Published => "published",
             LandingPageStatus::Unpublished => "unpublished",
             LandingPageStatus::Noop => "",
-            LandingPageStatus::FallthroughString => "*",
+            LandingPageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21221,8 +21308,8 @@ pub enum LandingPageTemplateType {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageTemplateType {
@@ -21231,7 +21318,7 @@ impl std::fmt::Display for LandingPageTemplateType {
             LandingPageTemplateType::Product => "product",
             LandingPageTemplateType::Signup => "signup",
             LandingPageTemplateType::Noop => "",
-            LandingPageTemplateType::FallthroughString => "*",
+            LandingPageTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22443,8 +22530,8 @@ pub enum AdviceType {
     Positive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AdviceType {
@@ -22454,7 +22541,7 @@ impl std::fmt::Display for AdviceType {
             AdviceType::Neutral => "neutral",
             AdviceType::Positive => "positive",
             AdviceType::Noop => "",
-            AdviceType::FallthroughString => "*",
+            AdviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24096,8 +24183,8 @@ pub enum GetReportsEcommerceProductActivitySortField {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
@@ -24107,7 +24194,7 @@ impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
             GetReportsEcommerceProductActivitySortField::TotalPurchased => "total_purchased",
             GetReportsEcommerceProductActivitySortField::TotalRevenue => "total_revenue",
             GetReportsEcommerceProductActivitySortField::Noop => "",
-            GetReportsEcommerceProductActivitySortField::FallthroughString => "*",
+            GetReportsEcommerceProductActivitySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24246,8 +24333,8 @@ pub enum GetTemplatesSortField {
     Name,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetTemplatesSortField {
@@ -24257,7 +24344,7 @@ impl std::fmt::Display for GetTemplatesSortField {
             GetTemplatesSortField::DateEdited => "date_edited",
             GetTemplatesSortField::Name => "name",
             GetTemplatesSortField::Noop => "",
-            GetTemplatesSortField::FallthroughString => "*",
+            GetTemplatesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24663,8 +24750,8 @@ pub enum TrackingCode {
     Prec,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrackingCode {
@@ -24672,7 +24759,7 @@ impl std::fmt::Display for TrackingCode {
         match &*self {
             TrackingCode::Prec => "prec",
             TrackingCode::Noop => "",
-            TrackingCode::FallthroughString => "*",
+            TrackingCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24948,8 +25035,8 @@ pub enum OrdersPromosType {
     Percentage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersPromosType {
@@ -24958,7 +25045,7 @@ impl std::fmt::Display for OrdersPromosType {
             OrdersPromosType::Fixed => "fixed",
             OrdersPromosType::Percentage => "percentage",
             OrdersPromosType::Noop => "",
-            OrdersPromosType::FallthroughString => "*",
+            OrdersPromosType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26656,8 +26743,8 @@ pub enum Target {
     Total,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Target {
@@ -26667,7 +26754,7 @@ impl std::fmt::Display for Target {
             Target::Shipping => "shipping",
             Target::Total => "total",
             Target::Noop => "",
-            Target::FallthroughString => "*",
+            Target::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28761,8 +28848,8 @@ pub enum GetAllFacebookAdsSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllFacebookAdsSortField {
@@ -28772,7 +28859,7 @@ impl std::fmt::Display for GetAllFacebookAdsSortField {
             GetAllFacebookAdsSortField::EndTime => "end_time",
             GetAllFacebookAdsSortField::UpdatedAt => "updated_at",
             GetAllFacebookAdsSortField::Noop => "",
-            GetAllFacebookAdsSortField::FallthroughString => "*",
+            GetAllFacebookAdsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28824,8 +28911,8 @@ pub enum OutreachType {
     Website,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachType {
@@ -28846,7 +28933,7 @@ impl std::fmt::Display for OutreachType {
             OutreachType::Variate => "variate",
             OutreachType::Website => "website",
             OutreachType::Noop => "",
-            OutreachType::FallthroughString => "*",
+            OutreachType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28904,8 +28991,8 @@ pub enum OutreachStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachStatus {
@@ -28929,7 +29016,7 @@ impl std::fmt::Display for OutreachStatus {
             OutreachStatus::Somepaused => "somepaused",
             OutreachStatus::Unpublished => "unpublished",
             OutreachStatus::Noop => "",
-            OutreachStatus::FallthroughString => "*",
+            OutreachStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29443,8 +29530,8 @@ pub enum FacebookAdsAudienceType {
     LookalikeAudience,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FacebookAdsAudienceType {
@@ -29454,7 +29541,7 @@ impl std::fmt::Display for FacebookAdsAudienceType {
             FacebookAdsAudienceType::InterestBasedAudience => "Interest-based Audience",
             FacebookAdsAudienceType::LookalikeAudience => "Lookalike Audience",
             FacebookAdsAudienceType::Noop => "",
-            FacebookAdsAudienceType::FallthroughString => "*",
+            FacebookAdsAudienceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29482,8 +29569,8 @@ pub enum SourceType {
     List,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -29492,7 +29579,7 @@ impl std::fmt::Display for SourceType {
             SourceType::Facebook => "facebook",
             SourceType::List => "list",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23,7 +23,8 @@ impl VerifiedDomains {
     *
     * * `domain_name: &str` -- The name of the folder.
     */
-    pub async fn get(&self, domain_name: &str) -> Result<crate::types::VerifiedDomains> {
+    pub async fn get(&self, domain_name: impl AsRef<str>) -> Result<crate::types::VerifiedDomains> {
+        let domain_name = domain_name.as_ref();
         let url = format!(
             "/verified-domains/{}",
             crate::progenitor_support::encode_path(domain_name),
@@ -43,7 +44,8 @@ impl VerifiedDomains {
     *
     * * `domain_name: &str` -- The name of the folder.
     */
-    pub async fn delete(&self, domain_name: &str) -> Result<()> {
+    pub async fn delete(&self, domain_name: impl AsRef<str>) -> Result<()> {
+        let domain_name = domain_name.as_ref();
         let url = format!(
             "/verified-domains/{}",
             crate::progenitor_support::encode_path(domain_name),
@@ -65,9 +67,10 @@ impl VerifiedDomains {
     */
     pub async fn verify_domain(
         &self,
-        domain_name: &str,
+        domain_name: impl AsRef<str>,
         body: &crate::types::VerifyADomainSending,
     ) -> Result<crate::types::VerifiedDomains> {
+        let domain_name = domain_name.as_ref();
         let url = format!(
             "/verified-domains/{}/actions/verify",
             crate::progenitor_support::encode_path(domain_name),
@@ -0,0 +1,161 @@
+//! Provision access automatically, with group rules and the assignments of
+//! applications.
+//!
+//! Okta creates group rules inactive, and only updates inactive ones. The helpers
+//! here take care of the lifecycle of a rule around its creation and update, and
+//! bring the groups assigned to an application in line with a list.
+//!
+//! ```no_run
+//! # async fn run(okta: okta::Client) -> anyhow::Result<()> {
+//! use okta::types::GroupRule;
+//!
+//! let rule = GroupRule::assigning(
+//!     "Engineering",
+//!     "user.department==\"Engineering\"",
+//!     &["00g1emaKYZTWRYYRRTSK"],
+//! );
+//! okta.groups().create_active_rule(&rule).await?;
+//!
+//! let changes = okta
+//!     .applications()
+//!     .set_group_assignments("0oa1gjh63g214q0Hq0g4", &["00g1emaKYZTWRYYRRTSK"])
+//!     .await?;
+//! println!("assigned {:?}, unassigned {:?}", changes.added, changes.removed);
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+
+use crate::{
+    applications::Applications,
+    groups::Groups,
+    types::{
+        ApplicationGroupAssignment, GroupRule, GroupRuleAction, GroupRuleAssignment,
+        GroupRuleConditions, GroupRuleExpression, GroupRuleStatus,
+    },
+};
+
+/// The groups whose assignment to an application was changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupAssignmentChanges {
+    /// The groups which were assigned to the application.
+    pub added: Vec<String>,
+    /// The groups which are not assigned to the application anymore.
+    pub removed: Vec<String>,
+}
+
+impl GroupRule {
+    /// A rule adding the users for whom an Okta expression is true to groups.
+    pub fn assigning(name: &str, expression: &str, group_ids: &[&str]) -> Self {
+        GroupRule {
+            actions: Some(GroupRuleAction {
+                assign_user_to_groups: Some(GroupRuleAssignment {
+                    group_ids: group_ids.iter().map(|id| id.to_string()).collect(),
+                }),
+            }),
+            conditions: Some(GroupRuleConditions {
+                expression: Some(GroupRuleExpression {
+                    type_: "urn:okta:expression:1.0".to_string(),
+                    value: expression.to_string(),
+                }),
+                people: None,
+            }),
+            created: None,
+            id: String::new(),
+            last_updated: None,
+            name: name.to_string(),
+            status: None,
+            type_: "group_rule".to_string(),
+        }
+    }
+}
+
+impl Groups {
+    /**
+     * Create a group rule, then activate it.
+     *
+     * The rule is left inactive when its activation fails.
+     */
+    pub async fn create_active_rule(&self, rule: &GroupRule) -> Result<GroupRule> {
+        let mut rule = self.create_rule(rule).await?;
+        self.activate_rule(&rule.id).await?;
+        rule.status = Some(GroupRuleStatus::Active);
+        Ok(rule)
+    }
+
+    /**
+     * Update a group rule, whatever its status.
+     *
+     * An active rule is deactivated for its update, then activated again, even when
+     * the update fails.
+     *
+     * **Parameters:**
+     *
+     * * `rule_id: &str`
+     */
+    pub async fn replace_rule(&self, rule_id: &str, rule: &GroupRule) -> Result<GroupRule> {
+        let current = self.get_rule(rule_id, "").await?;
+        if current.status != Some(GroupRuleStatus::Active) {
+            return self.update_rule(rule_id, rule).await;
+        }
+
+        self.deactivate_rule(rule_id).await?;
+        let updated = self.update_rule(rule_id, rule).await;
+        self.activate_rule(rule_id).await?;
+        let mut updated = updated?;
+        updated.status = Some(GroupRuleStatus::Active);
+        Ok(updated)
+    }
+}
+
+impl Applications {
+    /**
+     * Assign an application to exactly the given groups, unassigning it from the
+     * others.
+     *
+     * The groups which are assigned already are left as they are, with their
+     * priority and profile.
+     *
+     * **Parameters:**
+     *
+     * * `app_id: &str`
+     * * `group_ids: &[&str]` -- The groups the application should be assigned to.
+     */
+    pub async fn set_group_assignments(
+        &self,
+        app_id: &str,
+        group_ids: &[&str],
+    ) -> Result<GroupAssignmentChanges> {
+        let current = self.list_all_group_assignments(app_id, "", "").await?;
+        let mut changes = GroupAssignmentChanges::default();
+
+        for group_id in group_ids {
+            if current.iter().any(|a| a.id == *group_id)
+                || changes.added.iter().any(|id| id == *group_id)
+            {
+                continue;
+            }
+            let assignment = ApplicationGroupAssignment {
+                embedded: None,
+                links: None,
+                id: String::new(),
+                last_updated: None,
+                priority: 0,
+                profile: None,
+            };
+            self.create_group_assignment(app_id, *group_id, &assignment)
+                .await?;
+            changes.added.push(group_id.to_string());
+        }
+
+        for assignment in current {
+            if group_ids.contains(&assignment.id.as_str()) {
+                continue;
+            }
+            self.delete_group_assignment(app_id, &assignment.id).await?;
+            changes.removed.push(assignment.id);
+        }
+
+        Ok(changes)
+    }
+}
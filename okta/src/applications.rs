@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::access_provisioning::GroupAssignmentChanges;
 use crate::Client;
 
 pub struct Applications {
@@ -37,30 +38,26 @@ impl Applications {
         expand: &str,
         include_non_deleted: bool,
     ) -> Result<Vec<crate::types::Application>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if include_non_deleted {
-            query_args.push((
-                "includeNonDeleted".to_string(),
-                include_non_deleted.to_string(),
-            ));
+            query_args.push(("includeNonDeleted", include_non_deleted.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/apps?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/apps"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -81,24 +78,20 @@ impl Applications {
         expand: &str,
         include_non_deleted: bool,
     ) -> Result<Vec<crate::types::Application>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if include_non_deleted {
-            query_args.push((
-                "includeNonDeleted".to_string(),
-                include_non_deleted.to_string(),
-            ));
+            query_args.push(("includeNonDeleted", include_non_deleted.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/apps?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/apps"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -120,12 +113,11 @@ impl Applications {
         activate: bool,
         body: &crate::types::Application,
     ) -> Result<crate::types::Application> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/apps?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/apps"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -144,16 +136,22 @@ impl Applications {
     * * `app_id: &str`
     * * `expand: &str`
     */
-    pub async fn get(&self, app_id: &str, expand: &str) -> Result<crate::types::Application> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get(
+        &self,
+        app_id: impl AsRef<str>,
+        expand: &str,
+    ) -> Result<crate::types::Application> {
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -172,9 +170,10 @@ impl Applications {
     */
     pub async fn update(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         body: &crate::types::Application,
     ) -> Result<crate::types::Application> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -196,7 +195,8 @@ impl Applications {
     *
     * * `app_id: &str`
     */
-    pub async fn delete(&self, app_id: &str) -> Result<()> {
+    pub async fn delete(&self, app_id: impl AsRef<str>) -> Result<()> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -216,7 +216,8 @@ impl Applications {
     *
     * * `app_id: &str`
     */
-    pub async fn list_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
+    pub async fn list_csrs_fors(&self, app_id: impl AsRef<str>) -> Result<Vec<crate::types::Csr>> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs",
             crate::progenitor_support::encode_path(app_id),
@@ -234,7 +235,11 @@ impl Applications {
     *
     * Enumerates Certificate Signing Requests for an application
     */
-    pub async fn list_all_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
+    pub async fn list_all_csrs_fors(
+        &self,
+        app_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Csr>> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs",
             crate::progenitor_support::encode_path(app_id),
@@ -256,9 +261,10 @@ impl Applications {
     */
     pub async fn generate_csr_for(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         body: &crate::types::CsrMetadata,
     ) -> Result<crate::types::Csr> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs",
             crate::progenitor_support::encode_path(app_id),
@@ -277,7 +283,13 @@ impl Applications {
     * * `app_id: &str`
     * * `csr_id: &str`
     */
-    pub async fn get_csr_for(&self, app_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
+    pub async fn get_csr_for(
+        &self,
+        app_id: impl AsRef<str>,
+        csr_id: impl AsRef<str>,
+    ) -> Result<crate::types::Csr> {
+        let app_id = app_id.as_ref();
+        let csr_id = csr_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -295,7 +307,13 @@ impl Applications {
     * * `app_id: &str`
     * * `csr_id: &str`
     */
-    pub async fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> Result<()> {
+    pub async fn revoke_csr_from(
+        &self,
+        app_id: impl AsRef<str>,
+        csr_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let app_id = app_id.as_ref();
+        let csr_id = csr_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -315,9 +333,11 @@ impl Applications {
     */
     pub async fn post_app_credentials_csr_lifecycle_publish(
         &self,
-        app_id: &str,
-        csr_id: &str,
+        app_id: impl AsRef<str>,
+        csr_id: impl AsRef<str>,
     ) -> Result<crate::types::JsonWebKey> {
+        let app_id = app_id.as_ref();
+        let csr_id = csr_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs/{}/lifecycle/publish",
             crate::progenitor_support::encode_path(app_id),
@@ -338,7 +358,11 @@ impl Applications {
     *
     * * `app_id: &str`
     */
-    pub async fn list_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+    pub async fn list_keys(
+        &self,
+        app_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/keys",
             crate::progenitor_support::encode_path(app_id),
@@ -356,7 +380,11 @@ impl Applications {
     *
     * Enumerates key credentials for an application
     */
-    pub async fn list_all_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+    pub async fn list_all_keys(
+        &self,
+        app_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/keys",
             crate::progenitor_support::encode_path(app_id),
@@ -377,18 +405,20 @@ impl Applications {
     */
     pub async fn generate_key(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         validity_years: i64,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if validity_years > 0 {
-            query_args.push(("validityYears".to_string(), validity_years.to_string()));
+            query_args.push(("validityYears", validity_years.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/credentials/keys/generate?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/credentials/keys/generate",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -406,7 +436,13 @@ impl Applications {
     * * `app_id: &str`
     * * `key_id: &str`
     */
-    pub async fn get_key(&self, app_id: &str, key_id: &str) -> Result<crate::types::JsonWebKey> {
+    pub async fn get_key(
+        &self,
+        app_id: impl AsRef<str>,
+        key_id: impl AsRef<str>,
+    ) -> Result<crate::types::JsonWebKey> {
+        let app_id = app_id.as_ref();
+        let key_id = key_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/credentials/keys/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -431,20 +467,23 @@ impl Applications {
     */
     pub async fn clone_key(
         &self,
-        app_id: &str,
-        key_id: &str,
+        app_id: impl AsRef<str>,
+        key_id: impl AsRef<str>,
         target_aid: &str,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let key_id = key_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !target_aid.is_empty() {
-            query_args.push(("targetAid".to_string(), target_aid.to_string()));
+            query_args.push(("targetAid", target_aid.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/credentials/keys/{}/clone?{}",
-            crate::progenitor_support::encode_path(app_id),
-            crate::progenitor_support::encode_path(key_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/credentials/keys/{}/clone",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(key_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -462,18 +501,20 @@ impl Applications {
     */
     pub async fn list_scope_consent_grants(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/grants?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/grants",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -488,18 +529,20 @@ impl Applications {
     */
     pub async fn list_all_scope_consent_grants(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/grants?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/grants",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -516,9 +559,10 @@ impl Applications {
     */
     pub async fn grant_consent_scope(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         body: &crate::types::OAuth2ScopeConsentGrant,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/grants",
             crate::progenitor_support::encode_path(app_id),
@@ -542,20 +586,23 @@ impl Applications {
     */
     pub async fn get_scope_consent_grant(
         &self,
-        app_id: &str,
-        grant_id: &str,
+        app_id: impl AsRef<str>,
+        grant_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let grant_id = grant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/grants/{}?{}",
-            crate::progenitor_support::encode_path(app_id),
-            crate::progenitor_support::encode_path(grant_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/grants/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(grant_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -571,7 +618,13 @@ impl Applications {
     * * `app_id: &str`
     * * `grant_id: &str`
     */
-    pub async fn revoke_scope_consent_grant(&self, app_id: &str, grant_id: &str) -> Result<()> {
+    pub async fn revoke_scope_consent_grant(
+        &self,
+        app_id: impl AsRef<str>,
+        grant_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let app_id = app_id.as_ref();
+        let grant_id = grant_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/grants/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -598,30 +651,32 @@ impl Applications {
     */
     pub async fn list_group_assignments(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         q: &str,
         after: &str,
         limit: i64,
         expand: &str,
     ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/groups?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/groups",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -638,22 +693,24 @@ impl Applications {
     */
     pub async fn list_all_group_assignments(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         q: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/groups?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/groups",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -674,20 +731,23 @@ impl Applications {
     */
     pub async fn get_group_assignment(
         &self,
-        app_id: &str,
-        group_id: &str,
+        app_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<crate::types::ApplicationGroupAssignment> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/groups/{}?{}",
-            crate::progenitor_support::encode_path(app_id),
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/groups/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -707,10 +767,12 @@ impl Applications {
     */
     pub async fn create_group_assignment(
         &self,
-        app_id: &str,
-        group_id: &str,
+        app_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
         body: &crate::types::ApplicationGroupAssignment,
     ) -> Result<crate::types::ApplicationGroupAssignment> {
+        let app_id = app_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/groups/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -734,7 +796,13 @@ impl Applications {
     * * `app_id: &str`
     * * `group_id: &str`
     */
-    pub async fn delete_group_assignment(&self, app_id: &str, group_id: &str) -> Result<()> {
+    pub async fn delete_group_assignment(
+        &self,
+        app_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let app_id = app_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/groups/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -755,7 +823,8 @@ impl Applications {
     *
     * * `app_id: &str`
     */
-    pub async fn activate(&self, app_id: &str) -> Result<()> {
+    pub async fn activate(&self, app_id: impl AsRef<str>) -> Result<()> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(app_id),
@@ -775,7 +844,8 @@ impl Applications {
     *
     * * `app_id: &str`
     */
-    pub async fn deactivate(&self, app_id: &str) -> Result<()> {
+    pub async fn deactivate(&self, app_id: impl AsRef<str>) -> Result<()> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(app_id),
@@ -798,26 +868,28 @@ impl Applications {
     */
     pub async fn list_o_auth_2_tokens_fors(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         expand: &str,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2Token>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/tokens?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/tokens",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -832,18 +904,20 @@ impl Applications {
     */
     pub async fn list_all_o_auth_2_tokens_fors(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2Token>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/tokens?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/tokens",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -858,7 +932,8 @@ impl Applications {
     *
     * * `app_id: &str`
     */
-    pub async fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> Result<()> {
+    pub async fn revoke_o_auth_2_tokens_for(&self, app_id: impl AsRef<str>) -> Result<()> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/tokens",
             crate::progenitor_support::encode_path(app_id),
@@ -880,20 +955,23 @@ impl Applications {
     */
     pub async fn get_o_auth_2_token_for(
         &self,
-        app_id: &str,
-        token_id: &str,
+        app_id: impl AsRef<str>,
+        token_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<crate::types::OAuth2Token> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let token_id = token_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/tokens/{}?{}",
-            crate::progenitor_support::encode_path(app_id),
-            crate::progenitor_support::encode_path(token_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/tokens/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(token_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -909,7 +987,13 @@ impl Applications {
     * * `app_id: &str`
     * * `token_id: &str`
     */
-    pub async fn revoke_o_auth_2_token_for(&self, app_id: &str, token_id: &str) -> Result<()> {
+    pub async fn revoke_o_auth_2_token_for(
+        &self,
+        app_id: impl AsRef<str>,
+        token_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let app_id = app_id.as_ref();
+        let token_id = token_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/tokens/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -938,7 +1022,7 @@ impl Applications {
     */
     pub async fn list_users(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         q: &str,
         query_scope: &str,
         after: &str,
@@ -946,30 +1030,32 @@ impl Applications {
         filter: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::AppUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !query_scope.is_empty() {
-            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+            query_args.push(("query_scope", query_scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/users",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -986,30 +1072,32 @@ impl Applications {
     */
     pub async fn list_all_users(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         q: &str,
         query_scope: &str,
         filter: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::AppUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !query_scope.is_empty() {
-            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+            query_args.push(("query_scope", query_scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users?{}",
-            crate::progenitor_support::encode_path(app_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/users",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1028,9 +1116,10 @@ impl Applications {
     */
     pub async fn assign_user(
         &self,
-        app_id: &str,
+        app_id: impl AsRef<str>,
         body: &crate::types::AppUser,
     ) -> Result<crate::types::AppUser> {
+        let app_id = app_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/users",
             crate::progenitor_support::encode_path(app_id),
@@ -1056,20 +1145,23 @@ impl Applications {
     */
     pub async fn get_user(
         &self,
-        app_id: &str,
-        user_id: &str,
+        app_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<crate::types::AppUser> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let app_id = app_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users/{}?{}",
-            crate::progenitor_support::encode_path(app_id),
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/users/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1089,10 +1181,12 @@ impl Applications {
     */
     pub async fn update_user(
         &self,
-        app_id: &str,
-        user_id: &str,
+        app_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::AppUser,
     ) -> Result<crate::types::AppUser> {
+        let app_id = app_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/apps/{}/users/{}",
             crate::progenitor_support::encode_path(app_id),
@@ -1117,17 +1211,25 @@ impl Applications {
     * * `user_id: &str`
     * * `send_email: bool`
     */
-    pub async fn delete_user(&self, app_id: &str, user_id: &str, send_email: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn delete_user(
+        &self,
+        app_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        send_email: bool,
+    ) -> Result<()> {
+        let app_id = app_id.as_ref();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users/{}?{}",
-            crate::progenitor_support::encode_path(app_id),
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/apps/{}/users/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
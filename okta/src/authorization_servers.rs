@@ -29,18 +29,20 @@ impl AuthorizationServers {
         limit: &str,
         after: &str,
     ) -> Result<Vec<crate::types::AuthorizationServer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/authorizationServers?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/api/v1/authorizationServers"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -53,12 +55,14 @@ impl AuthorizationServers {
     * Success
     */
     pub async fn list_all(&self, q: &str) -> Result<Vec<crate::types::AuthorizationServer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/authorizationServers?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/api/v1/authorizationServers"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -87,7 +91,11 @@ impl AuthorizationServers {
     *
     * * `auth_server_id: &str`
     */
-    pub async fn get(&self, auth_server_id: &str) -> Result<crate::types::AuthorizationServer> {
+    pub async fn get(
+        &self,
+        auth_server_id: impl AsRef<str>,
+    ) -> Result<crate::types::AuthorizationServer> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -107,9 +115,10 @@ impl AuthorizationServers {
     */
     pub async fn update(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
         body: &crate::types::AuthorizationServer,
     ) -> Result<crate::types::AuthorizationServer> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -129,7 +138,8 @@ impl AuthorizationServers {
     *
     * * `auth_server_id: &str`
     */
-    pub async fn delete(&self, auth_server_id: &str) -> Result<()> {
+    pub async fn delete(&self, auth_server_id: impl AsRef<str>) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -149,8 +159,9 @@ impl AuthorizationServers {
     */
     pub async fn list_o_auth_2_claims(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OAuth2Claim>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/claims",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -168,8 +179,9 @@ impl AuthorizationServers {
     */
     pub async fn list_all_o_auth_2_claims(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OAuth2Claim>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/claims",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -189,9 +201,10 @@ impl AuthorizationServers {
     */
     pub async fn create_o_auth_2_claim(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
         body: &crate::types::OAuth2Claim,
     ) -> Result<crate::types::OAuth2Claim> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/claims",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -214,9 +227,11 @@ impl AuthorizationServers {
     */
     pub async fn get_o_auth_2_claim(
         &self,
-        auth_server_id: &str,
-        claim_id: &str,
+        auth_server_id: impl AsRef<str>,
+        claim_id: impl AsRef<str>,
     ) -> Result<crate::types::OAuth2Claim> {
+        let auth_server_id = auth_server_id.as_ref();
+        let claim_id = claim_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/claims/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -238,10 +253,12 @@ impl AuthorizationServers {
     */
     pub async fn update_o_auth_2_claim(
         &self,
-        auth_server_id: &str,
-        claim_id: &str,
+        auth_server_id: impl AsRef<str>,
+        claim_id: impl AsRef<str>,
         body: &crate::types::OAuth2Claim,
     ) -> Result<crate::types::OAuth2Claim> {
+        let auth_server_id = auth_server_id.as_ref();
+        let claim_id = claim_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/claims/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -263,7 +280,13 @@ impl AuthorizationServers {
     * * `auth_server_id: &str`
     * * `claim_id: &str`
     */
-    pub async fn delete_o_auth_2_claim(&self, auth_server_id: &str, claim_id: &str) -> Result<()> {
+    pub async fn delete_o_auth_2_claim(
+        &self,
+        auth_server_id: impl AsRef<str>,
+        claim_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let claim_id = claim_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/claims/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -284,8 +307,9 @@ impl AuthorizationServers {
     */
     pub async fn list_o_auth_2_clients_fors(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OAuth2Client>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/clients",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -303,8 +327,9 @@ impl AuthorizationServers {
     */
     pub async fn list_all_o_auth_2_clients_fors(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::OAuth2Client>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/clients",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -328,28 +353,31 @@ impl AuthorizationServers {
     */
     pub async fn list_refresh_tokens_for_and_clients(
         &self,
-        auth_server_id: &str,
-        client_id: &str,
+        auth_server_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
         expand: &str,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let auth_server_id = auth_server_id.as_ref();
+        let client_id = client_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(auth_server_id),
-            crate::progenitor_support::encode_path(client_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -364,20 +392,23 @@ impl AuthorizationServers {
     */
     pub async fn list_all_refresh_tokens_for_and_clients(
         &self,
-        auth_server_id: &str,
-        client_id: &str,
+        auth_server_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let auth_server_id = auth_server_id.as_ref();
+        let client_id = client_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(auth_server_id),
-            crate::progenitor_support::encode_path(client_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -395,9 +426,11 @@ impl AuthorizationServers {
     */
     pub async fn revoke_refresh_tokens_for_and_client(
         &self,
-        auth_server_id: &str,
-        client_id: &str,
+        auth_server_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
     ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let client_id = client_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/clients/{}/tokens",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -421,22 +454,26 @@ impl AuthorizationServers {
     */
     pub async fn get_refresh_token_for_and_client(
         &self,
-        auth_server_id: &str,
-        client_id: &str,
-        token_id: &str,
+        auth_server_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+        token_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<crate::types::OAuth2RefreshToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let auth_server_id = auth_server_id.as_ref();
+        let client_id = client_id.as_ref();
+        let token_id = token_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/clients/{}/tokens/{}?{}",
-            crate::progenitor_support::encode_path(auth_server_id),
-            crate::progenitor_support::encode_path(client_id),
-            crate::progenitor_support::encode_path(token_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -455,10 +492,13 @@ impl AuthorizationServers {
     */
     pub async fn revoke_refresh_token_for_and_client(
         &self,
-        auth_server_id: &str,
-        client_id: &str,
-        token_id: &str,
+        auth_server_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+        token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let client_id = client_id.as_ref();
+        let token_id = token_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -478,7 +518,11 @@ impl AuthorizationServers {
     *
     * * `auth_server_id: &str`
     */
-    pub async fn list_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+    pub async fn list_keys(
+        &self,
+        auth_server_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/credentials/keys",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -496,8 +540,9 @@ impl AuthorizationServers {
     */
     pub async fn list_all_keys(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/credentials/keys",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -517,9 +562,10 @@ impl AuthorizationServers {
     */
     pub async fn rotate_keys(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
         body: &crate::types::JwkUse,
     ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/credentials/lifecycle/keyRotate",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -539,7 +585,8 @@ impl AuthorizationServers {
     *
     * * `auth_server_id: &str`
     */
-    pub async fn activate(&self, auth_server_id: &str) -> Result<()> {
+    pub async fn activate(&self, auth_server_id: impl AsRef<str>) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -557,7 +604,8 @@ impl AuthorizationServers {
     *
     * * `auth_server_id: &str`
     */
-    pub async fn deactivate(&self, auth_server_id: &str) -> Result<()> {
+    pub async fn deactivate(&self, auth_server_id: impl AsRef<str>) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -577,8 +625,9 @@ impl AuthorizationServers {
     */
     pub async fn list_policies(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::AuthorizationServerPolicy>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -596,8 +645,9 @@ impl AuthorizationServers {
     */
     pub async fn list_all_policies(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::AuthorizationServerPolicy>> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -617,9 +667,10 @@ impl AuthorizationServers {
     */
     pub async fn create_policy(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
         body: &crate::types::AuthorizationServerPolicy,
     ) -> Result<crate::types::AuthorizationServerPolicy> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -642,9 +693,11 @@ impl AuthorizationServers {
     */
     pub async fn get_policy(
         &self,
-        auth_server_id: &str,
-        policy_id: &str,
+        auth_server_id: impl AsRef<str>,
+        policy_id: impl AsRef<str>,
     ) -> Result<crate::types::AuthorizationServerPolicy> {
+        let auth_server_id = auth_server_id.as_ref();
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -666,10 +719,12 @@ impl AuthorizationServers {
     */
     pub async fn update_policy(
         &self,
-        auth_server_id: &str,
-        policy_id: &str,
+        auth_server_id: impl AsRef<str>,
+        policy_id: impl AsRef<str>,
         body: &crate::types::AuthorizationServerPolicy,
     ) -> Result<crate::types::AuthorizationServerPolicy> {
+        let auth_server_id = auth_server_id.as_ref();
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -691,7 +746,13 @@ impl AuthorizationServers {
     * * `auth_server_id: &str`
     * * `policy_id: &str`
     */
-    pub async fn delete_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+    pub async fn delete_policy(
+        &self,
+        auth_server_id: impl AsRef<str>,
+        policy_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -711,7 +772,13 @@ impl AuthorizationServers {
     * * `auth_server_id: &str`
     * * `policy_id: &str`
     */
-    pub async fn activate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+    pub async fn activate_policy(
+        &self,
+        auth_server_id: impl AsRef<str>,
+        policy_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -731,7 +798,13 @@ impl AuthorizationServers {
     * * `auth_server_id: &str`
     * * `policy_id: &str`
     */
-    pub async fn deactivate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+    pub async fn deactivate_policy(
+        &self,
+        auth_server_id: impl AsRef<str>,
+        policy_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -753,9 +826,11 @@ impl AuthorizationServers {
     */
     pub async fn list_policy_rules(
         &self,
-        policy_id: &str,
-        auth_server_id: &str,
+        policy_id: impl AsRef<str>,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        let policy_id = policy_id.as_ref();
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -774,9 +849,11 @@ impl AuthorizationServers {
     */
     pub async fn list_all_policy_rules(
         &self,
-        policy_id: &str,
-        auth_server_id: &str,
+        policy_id: impl AsRef<str>,
+        auth_server_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        let policy_id = policy_id.as_ref();
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -798,10 +875,12 @@ impl AuthorizationServers {
     */
     pub async fn create_policy_rule(
         &self,
-        policy_id: &str,
-        auth_server_id: &str,
+        policy_id: impl AsRef<str>,
+        auth_server_id: impl AsRef<str>,
         body: &crate::types::AuthorizationServerPolicyRule,
     ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        let policy_id = policy_id.as_ref();
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -826,10 +905,13 @@ impl AuthorizationServers {
     */
     pub async fn get_policy_rule(
         &self,
-        policy_id: &str,
-        auth_server_id: &str,
-        rule_id: &str,
+        policy_id: impl AsRef<str>,
+        auth_server_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
     ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        let policy_id = policy_id.as_ref();
+        let auth_server_id = auth_server_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -853,11 +935,14 @@ impl AuthorizationServers {
     */
     pub async fn update_policy_rule(
         &self,
-        policy_id: &str,
-        auth_server_id: &str,
-        rule_id: &str,
+        policy_id: impl AsRef<str>,
+        auth_server_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
         body: &crate::types::AuthorizationServerPolicyRule,
     ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        let policy_id = policy_id.as_ref();
+        let auth_server_id = auth_server_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -883,10 +968,13 @@ impl AuthorizationServers {
     */
     pub async fn delete_policy_rule(
         &self,
-        policy_id: &str,
-        auth_server_id: &str,
-        rule_id: &str,
+        policy_id: impl AsRef<str>,
+        auth_server_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let policy_id = policy_id.as_ref();
+        let auth_server_id = auth_server_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -910,10 +998,13 @@ impl AuthorizationServers {
     */
     pub async fn activate_policy_rule(
         &self,
-        auth_server_id: &str,
-        policy_id: &str,
-        rule_id: &str,
+        auth_server_id: impl AsRef<str>,
+        policy_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let policy_id = policy_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -937,10 +1028,13 @@ impl AuthorizationServers {
     */
     pub async fn deactivate_policy_rule(
         &self,
-        auth_server_id: &str,
-        policy_id: &str,
-        rule_id: &str,
+        auth_server_id: impl AsRef<str>,
+        policy_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let policy_id = policy_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -966,30 +1060,32 @@ impl AuthorizationServers {
     */
     pub async fn list_o_auth_2_scopes(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
         q: &str,
         filter: &str,
         cursor: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2Scope>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let auth_server_id = auth_server_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/scopes?{}",
-            crate::progenitor_support::encode_path(auth_server_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/authorizationServers/{}/scopes",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1004,26 +1100,28 @@ impl AuthorizationServers {
     */
     pub async fn list_all_o_auth_2_scopes(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
         q: &str,
         filter: &str,
         cursor: &str,
     ) -> Result<Vec<crate::types::OAuth2Scope>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let auth_server_id = auth_server_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/scopes?{}",
-            crate::progenitor_support::encode_path(auth_server_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/authorizationServers/{}/scopes",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1040,9 +1138,10 @@ impl AuthorizationServers {
     */
     pub async fn create_o_auth_2_scope(
         &self,
-        auth_server_id: &str,
+        auth_server_id: impl AsRef<str>,
         body: &crate::types::OAuth2Scope,
     ) -> Result<crate::types::OAuth2Scope> {
+        let auth_server_id = auth_server_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -1065,9 +1164,11 @@ impl AuthorizationServers {
     */
     pub async fn get_o_auth_2_scope(
         &self,
-        auth_server_id: &str,
-        scope_id: &str,
+        auth_server_id: impl AsRef<str>,
+        scope_id: impl AsRef<str>,
     ) -> Result<crate::types::OAuth2Scope> {
+        let auth_server_id = auth_server_id.as_ref();
+        let scope_id = scope_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -1089,10 +1190,12 @@ impl AuthorizationServers {
     */
     pub async fn update_o_auth_2_scope(
         &self,
-        auth_server_id: &str,
-        scope_id: &str,
+        auth_server_id: impl AsRef<str>,
+        scope_id: impl AsRef<str>,
         body: &crate::types::OAuth2Scope,
     ) -> Result<crate::types::OAuth2Scope> {
+        let auth_server_id = auth_server_id.as_ref();
+        let scope_id = scope_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -1114,7 +1217,13 @@ impl AuthorizationServers {
     * * `auth_server_id: &str`
     * * `scope_id: &str`
     */
-    pub async fn delete_o_auth_2_scope(&self, auth_server_id: &str, scope_id: &str) -> Result<()> {
+    pub async fn delete_o_auth_2_scope(
+        &self,
+        auth_server_id: impl AsRef<str>,
+        scope_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let auth_server_id = auth_server_id.as_ref();
+        let scope_id = scope_id.as_ref();
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes/{}",
             crate::progenitor_support::encode_path(auth_server_id),
@@ -49,7 +49,8 @@ impl Domains {
     *
     * * `domain_id: &str`
     */
-    pub async fn get(&self, domain_id: &str) -> Result<crate::types::Domain> {
+    pub async fn get(&self, domain_id: impl AsRef<str>) -> Result<crate::types::Domain> {
+        let domain_id = domain_id.as_ref();
         let url = format!(
             "/api/v1/domains/{}",
             crate::progenitor_support::encode_path(domain_id),
@@ -69,7 +70,8 @@ impl Domains {
     *
     * * `domain_id: &str`
     */
-    pub async fn delete(&self, domain_id: &str) -> Result<()> {
+    pub async fn delete(&self, domain_id: impl AsRef<str>) -> Result<()> {
+        let domain_id = domain_id.as_ref();
         let url = format!(
             "/api/v1/domains/{}",
             crate::progenitor_support::encode_path(domain_id),
@@ -91,9 +93,10 @@ impl Domains {
     */
     pub async fn create_certificate(
         &self,
-        domain_id: &str,
+        domain_id: impl AsRef<str>,
         body: &crate::types::DomainCertificate,
     ) -> Result<()> {
+        let domain_id = domain_id.as_ref();
         let url = format!(
             "/api/v1/domains/{}/certificate",
             crate::progenitor_support::encode_path(domain_id),
@@ -115,7 +118,8 @@ impl Domains {
     *
     * * `domain_id: &str`
     */
-    pub async fn verify(&self, domain_id: &str) -> Result<crate::types::Domain> {
+    pub async fn verify(&self, domain_id: impl AsRef<str>) -> Result<crate::types::Domain> {
+        let domain_id = domain_id.as_ref();
         let url = format!(
             "/api/v1/domains/{}/verify",
             crate::progenitor_support::encode_path(domain_id),
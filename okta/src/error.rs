@@ -0,0 +1,102 @@
+//! When a request fails, Okta describes what went wrong in an error object. The
+//! error of the request is then an `ApiError`, which can be matched on with
+//! `downcast_ref`:
+//!
+//! ```no_run
+//! # async fn run(okta: okta::Client) -> anyhow::Result<()> {
+//! if let Err(err) = okta.users().suspend("00ub0oNGTSWTBKOLGLNR").await {
+//!     match err.downcast_ref::<okta::error::ApiError>() {
+//!         // There is no such user, so there is nothing to suspend.
+//!         Some(e) if e.error_code == "E0000007" => {}
+//!         _ => return Err(err),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+/// An error object returned by Okta.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// The status code of the response.
+    pub status: reqwest::StatusCode,
+    /// The code of the error, for example `E0000007` when the resource was not found.
+    pub error_code: String,
+    /// A description of the error.
+    pub error_summary: String,
+    pub error_link: String,
+    /// The id of the error, to give to Okta support.
+    pub error_id: String,
+    /// Descriptions of the causes of the error, for example the fields that did not
+    /// validate.
+    pub error_causes: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorBody {
+    error_code: String,
+    #[serde(default)]
+    error_summary: String,
+    #[serde(default)]
+    error_link: String,
+    #[serde(default)]
+    error_id: String,
+    #[serde(default)]
+    error_causes: Vec<ErrorCause>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorCause {
+    #[serde(default)]
+    error_summary: String,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "code: {}, {}: {}",
+            self.status, self.error_code, self.error_summary
+        )?;
+        for cause in &self.error_causes {
+            write!(f, "; {}", cause)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Build the error for a response that failed, from the error object in its body
+/// if there is one.
+pub(crate) fn from_response(status: reqwest::StatusCode, body: &[u8]) -> anyhow::Error {
+    if body.is_empty() {
+        return anyhow!("code: {}, empty response", status);
+    }
+
+    match serde_json::from_slice::<ErrorBody>(body) {
+        Ok(e) => anyhow!(ApiError {
+            status,
+            error_code: e.error_code,
+            error_summary: e.error_summary,
+            error_link: e.error_link,
+            error_id: e.error_id,
+            error_causes: e
+                .error_causes
+                .into_iter()
+                .map(|c| c.error_summary)
+                .collect(),
+        }),
+        Err(_) => anyhow!(
+            "code: {}, error: {:?}",
+            status,
+            String::from_utf8_lossy(body),
+        ),
+    }
+}
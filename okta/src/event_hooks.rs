@@ -55,7 +55,8 @@ impl EventHooks {
     *
     * * `event_hook_id: &str`
     */
-    pub async fn get(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+    pub async fn get(&self, event_hook_id: impl AsRef<str>) -> Result<crate::types::EventHook> {
+        let event_hook_id = event_hook_id.as_ref();
         let url = format!(
             "/api/v1/eventHooks/{}",
             crate::progenitor_support::encode_path(event_hook_id),
@@ -75,9 +76,10 @@ impl EventHooks {
     */
     pub async fn update(
         &self,
-        event_hook_id: &str,
+        event_hook_id: impl AsRef<str>,
         body: &crate::types::EventHook,
     ) -> Result<crate::types::EventHook> {
+        let event_hook_id = event_hook_id.as_ref();
         let url = format!(
             "/api/v1/eventHooks/{}",
             crate::progenitor_support::encode_path(event_hook_id),
@@ -97,7 +99,8 @@ impl EventHooks {
     *
     * * `event_hook_id: &str`
     */
-    pub async fn delete(&self, event_hook_id: &str) -> Result<()> {
+    pub async fn delete(&self, event_hook_id: impl AsRef<str>) -> Result<()> {
+        let event_hook_id = event_hook_id.as_ref();
         let url = format!(
             "/api/v1/eventHooks/{}",
             crate::progenitor_support::encode_path(event_hook_id),
@@ -115,7 +118,11 @@ impl EventHooks {
     *
     * * `event_hook_id: &str`
     */
-    pub async fn activate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+    pub async fn activate(
+        &self,
+        event_hook_id: impl AsRef<str>,
+    ) -> Result<crate::types::EventHook> {
+        let event_hook_id = event_hook_id.as_ref();
         let url = format!(
             "/api/v1/eventHooks/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(event_hook_id),
@@ -133,7 +140,11 @@ impl EventHooks {
     *
     * * `event_hook_id: &str`
     */
-    pub async fn deactivate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+    pub async fn deactivate(
+        &self,
+        event_hook_id: impl AsRef<str>,
+    ) -> Result<crate::types::EventHook> {
+        let event_hook_id = event_hook_id.as_ref();
         let url = format!(
             "/api/v1/eventHooks/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(event_hook_id),
@@ -151,7 +162,8 @@ impl EventHooks {
     *
     * * `event_hook_id: &str`
     */
-    pub async fn verify(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+    pub async fn verify(&self, event_hook_id: impl AsRef<str>) -> Result<crate::types::EventHook> {
+        let event_hook_id = event_hook_id.as_ref();
         let url = format!(
             "/api/v1/eventHooks/{}/lifecycle/verify",
             crate::progenitor_support::encode_path(event_hook_id),
@@ -43,7 +43,8 @@ impl Features {
     *
     * * `feature_id: &str`
     */
-    pub async fn get(&self, feature_id: &str) -> Result<crate::types::Feature> {
+    pub async fn get(&self, feature_id: impl AsRef<str>) -> Result<crate::types::Feature> {
+        let feature_id = feature_id.as_ref();
         let url = format!(
             "/api/v1/features/{}",
             crate::progenitor_support::encode_path(feature_id),
@@ -61,7 +62,11 @@ impl Features {
     *
     * * `feature_id: &str`
     */
-    pub async fn list_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+    pub async fn list_dependencies(
+        &self,
+        feature_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Feature>> {
+        let feature_id = feature_id.as_ref();
         let url = format!(
             "/api/v1/features/{}/dependencies",
             crate::progenitor_support::encode_path(feature_id),
@@ -79,8 +84,9 @@ impl Features {
     */
     pub async fn list_all_dependencies(
         &self,
-        feature_id: &str,
+        feature_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Feature>> {
+        let feature_id = feature_id.as_ref();
         let url = format!(
             "/api/v1/features/{}/dependencies",
             crate::progenitor_support::encode_path(feature_id),
@@ -98,7 +104,11 @@ impl Features {
     *
     * * `feature_id: &str`
     */
-    pub async fn list_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+    pub async fn list_dependents(
+        &self,
+        feature_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Feature>> {
+        let feature_id = feature_id.as_ref();
         let url = format!(
             "/api/v1/features/{}/dependents",
             crate::progenitor_support::encode_path(feature_id),
@@ -116,8 +126,9 @@ impl Features {
     */
     pub async fn list_all_dependents(
         &self,
-        feature_id: &str,
+        feature_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Feature>> {
+        let feature_id = feature_id.as_ref();
         let url = format!(
             "/api/v1/features/{}/dependents",
             crate::progenitor_support::encode_path(feature_id),
@@ -139,20 +150,23 @@ impl Features {
     */
     pub async fn update_lifecycle(
         &self,
-        feature_id: &str,
-        lifecycle: &str,
+        feature_id: impl AsRef<str>,
+        lifecycle: impl AsRef<str>,
         mode: &str,
     ) -> Result<crate::types::Feature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let feature_id = feature_id.as_ref();
+        let lifecycle = lifecycle.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !mode.is_empty() {
-            query_args.push(("mode".to_string(), mode.to_string()));
+            query_args.push(("mode", mode.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/features/{}/{}?{}",
-            crate::progenitor_support::encode_path(feature_id),
-            crate::progenitor_support::encode_path(lifecycle),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/features/{}/{}",
+                crate::progenitor_support::encode_path(feature_id),
+                crate::progenitor_support::encode_path(lifecycle),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -35,24 +35,23 @@ impl Groups {
         limit: i64,
         expand: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/groups"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -72,18 +71,17 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/groups"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -123,21 +121,21 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::GroupRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups/rules?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/groups/rules"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -156,15 +154,15 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::GroupRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups/rules?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/groups/rules"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -198,16 +196,22 @@ impl Groups {
     * * `rule_id: &str`
     * * `expand: &str`
     */
-    pub async fn get_rule(&self, rule_id: &str, expand: &str) -> Result<crate::types::GroupRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_rule(
+        &self,
+        rule_id: impl AsRef<str>,
+        expand: &str,
+    ) -> Result<crate::types::GroupRule> {
+        let rule_id = rule_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/rules/{}?{}",
-            crate::progenitor_support::encode_path(rule_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/rules/{}",
+                crate::progenitor_support::encode_path(rule_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -224,9 +228,10 @@ impl Groups {
     */
     pub async fn update_rule(
         &self,
-        rule_id: &str,
+        rule_id: impl AsRef<str>,
         body: &crate::types::GroupRule,
     ) -> Result<crate::types::GroupRule> {
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/groups/rules/{}",
             crate::progenitor_support::encode_path(rule_id),
@@ -249,16 +254,18 @@ impl Groups {
     * * `rule_id: &str`
     * * `remove_users: bool` -- Indicates whether to keep or remove users from groups assigned by this rule.
     */
-    pub async fn delete_rule(&self, rule_id: &str, remove_users: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn delete_rule(&self, rule_id: impl AsRef<str>, remove_users: bool) -> Result<()> {
+        let rule_id = rule_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if remove_users {
-            query_args.push(("removeUsers".to_string(), remove_users.to_string()));
+            query_args.push(("removeUsers", remove_users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/rules/{}?{}",
-            crate::progenitor_support::encode_path(rule_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/rules/{}",
+                crate::progenitor_support::encode_path(rule_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -275,7 +282,8 @@ impl Groups {
     *
     * * `rule_id: &str`
     */
-    pub async fn activate_rule(&self, rule_id: &str) -> Result<()> {
+    pub async fn activate_rule(&self, rule_id: impl AsRef<str>) -> Result<()> {
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/groups/rules/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(rule_id),
@@ -295,7 +303,8 @@ impl Groups {
     *
     * * `rule_id: &str`
     */
-    pub async fn deactivate_rule(&self, rule_id: &str) -> Result<()> {
+    pub async fn deactivate_rule(&self, rule_id: impl AsRef<str>) -> Result<()> {
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/groups/rules/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(rule_id),
@@ -315,7 +324,8 @@ impl Groups {
     *
     * * `group_id: &str`
     */
-    pub async fn get(&self, group_id: &str) -> Result<crate::types::Group> {
+    pub async fn get(&self, group_id: impl AsRef<str>) -> Result<crate::types::Group> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -337,9 +347,10 @@ impl Groups {
     */
     pub async fn update(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::Group,
     ) -> Result<crate::types::Group> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -361,7 +372,8 @@ impl Groups {
     *
     * * `group_id: &str`
     */
-    pub async fn delete(&self, group_id: &str) -> Result<()> {
+    pub async fn delete(&self, group_id: impl AsRef<str>) -> Result<()> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -385,22 +397,24 @@ impl Groups {
     */
     pub async fn list_assigned_applications_fors(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Application>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/apps?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/{}/apps",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -417,8 +431,9 @@ impl Groups {
     */
     pub async fn list_all_assigned_applications_fors(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Application>> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/apps",
             crate::progenitor_support::encode_path(group_id),
@@ -439,18 +454,20 @@ impl Groups {
     */
     pub async fn list_assigned_roles(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/{}/roles",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -465,18 +482,20 @@ impl Groups {
     */
     pub async fn list_all_assigned_roles(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/{}/roles",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -494,22 +513,21 @@ impl Groups {
     */
     pub async fn assign_role(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         disable_notifications: &str,
         body: &crate::types::AssignRoleRequest,
     ) -> Result<crate::types::Role> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !disable_notifications.is_empty() {
-            query_args.push((
-                "disableNotifications".to_string(),
-                disable_notifications.to_string(),
-            ));
+            query_args.push(("disableNotifications", disable_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/{}/roles",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -527,7 +545,13 @@ impl Groups {
     * * `group_id: &str`
     * * `role_id: &str`
     */
-    pub async fn get_role(&self, group_id: &str, role_id: &str) -> Result<crate::types::Role> {
+    pub async fn get_role(
+        &self,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<crate::types::Role> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -547,7 +571,13 @@ impl Groups {
     * * `group_id: &str`
     * * `role_id: &str`
     */
-    pub async fn remove_role_from(&self, group_id: &str, role_id: &str) -> Result<()> {
+    pub async fn remove_role_from(
+        &self,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -571,24 +601,27 @@ impl Groups {
     */
     pub async fn list_application_targets_for_administrator_roles(
         &self,
-        group_id: &str,
-        role_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles/{}/targets/catalog/apps?{}",
-            crate::progenitor_support::encode_path(group_id),
-            crate::progenitor_support::encode_path(role_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -603,9 +636,11 @@ impl Groups {
     */
     pub async fn list_all_application_targets_for_administrator_roles(
         &self,
-        group_id: &str,
-        role_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
             crate::progenitor_support::encode_path(group_id),
@@ -628,10 +663,13 @@ impl Groups {
     */
     pub async fn add_application_target_admin_role_given(
         &self,
-        group_id: &str,
-        role_id: &str,
-        app_name: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -655,10 +693,13 @@ impl Groups {
     */
     pub async fn remove_application_target_from_administrator_role_given(
         &self,
-        group_id: &str,
-        role_id: &str,
-        app_name: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -685,11 +726,15 @@ impl Groups {
     */
     pub async fn add_application_instance_target_app_admin_role_given(
         &self,
-        group_id: &str,
-        role_id: &str,
-        app_name: &str,
-        application_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
+        application_id: impl AsRef<str>,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
+        let application_id = application_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -717,11 +762,15 @@ impl Groups {
     */
     pub async fn remove_application_target_from_administrator_role_given_groups(
         &self,
-        group_id: &str,
-        role_id: &str,
-        app_name: &str,
-        application_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
+        application_id: impl AsRef<str>,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
+        let application_id = application_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -747,24 +796,27 @@ impl Groups {
     */
     pub async fn list_targets_for_roles(
         &self,
-        group_id: &str,
-        role_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles/{}/targets/groups?{}",
-            crate::progenitor_support::encode_path(group_id),
-            crate::progenitor_support::encode_path(role_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -779,9 +831,11 @@ impl Groups {
     */
     pub async fn list_all_targets_for_roles(
         &self,
-        group_id: &str,
-        role_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Group>> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/groups",
             crate::progenitor_support::encode_path(group_id),
@@ -804,10 +858,13 @@ impl Groups {
     */
     pub async fn add_target_administrator_role_for(
         &self,
-        group_id: &str,
-        role_id: &str,
-        target_group_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        target_group_id: impl AsRef<str>,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let target_group_id = target_group_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -831,10 +888,13 @@ impl Groups {
     */
     pub async fn remove_target_from_administrator_role_given(
         &self,
-        group_id: &str,
-        role_id: &str,
-        target_group_id: &str,
+        group_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        target_group_id: impl AsRef<str>,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let role_id = role_id.as_ref();
+        let target_group_id = target_group_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -860,22 +920,24 @@ impl Groups {
     */
     pub async fn list_users(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/users?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/groups/{}/users",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -890,7 +952,11 @@ impl Groups {
     *
     * Enumerates all users that are a member of a group.
     */
-    pub async fn list_all_users(&self, group_id: &str) -> Result<Vec<crate::types::User>> {
+    pub async fn list_all_users(
+        &self,
+        group_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::User>> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/users",
             crate::progenitor_support::encode_path(group_id),
@@ -911,7 +977,13 @@ impl Groups {
     * * `group_id: &str`
     * * `user_id: &str`
     */
-    pub async fn add_user(&self, group_id: &str, user_id: &str) -> Result<()> {
+    pub async fn add_user(
+        &self,
+        group_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/users/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -933,7 +1005,13 @@ impl Groups {
     * * `group_id: &str`
     * * `user_id: &str`
     */
-    pub async fn remove_user_from(&self, group_id: &str, user_id: &str) -> Result<()> {
+    pub async fn remove_user_from(
+        &self,
+        group_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/groups/{}/users/{}",
             crate::progenitor_support::encode_path(group_id),
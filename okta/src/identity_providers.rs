@@ -33,21 +33,20 @@ impl IdentityProviders {
         limit: i64,
         type_: &str,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/idps?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/idps"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -66,15 +65,14 @@ impl IdentityProviders {
         q: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/idps?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/idps"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -113,15 +111,17 @@ impl IdentityProviders {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::JsonWebKey>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/idps/credentials/keys?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/api/v1/idps/credentials/keys"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -168,7 +168,8 @@ impl IdentityProviders {
     *
     * * `key_id: &str`
     */
-    pub async fn get_key(&self, key_id: &str) -> Result<crate::types::JsonWebKey> {
+    pub async fn get_key(&self, key_id: impl AsRef<str>) -> Result<crate::types::JsonWebKey> {
+        let key_id = key_id.as_ref();
         let url = format!(
             "/api/v1/idps/credentials/keys/{}",
             crate::progenitor_support::encode_path(key_id),
@@ -188,7 +189,8 @@ impl IdentityProviders {
     *
     * * `key_id: &str`
     */
-    pub async fn delete_key(&self, key_id: &str) -> Result<()> {
+    pub async fn delete_key(&self, key_id: impl AsRef<str>) -> Result<()> {
+        let key_id = key_id.as_ref();
         let url = format!(
             "/api/v1/idps/credentials/keys/{}",
             crate::progenitor_support::encode_path(key_id),
@@ -208,7 +210,8 @@ impl IdentityProviders {
     *
     * * `idp_id: &str`
     */
-    pub async fn get(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+    pub async fn get(&self, idp_id: impl AsRef<str>) -> Result<crate::types::IdentityProvider> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -230,9 +233,10 @@ impl IdentityProviders {
     */
     pub async fn update(
         &self,
-        idp_id: &str,
+        idp_id: impl AsRef<str>,
         body: &crate::types::IdentityProvider,
     ) -> Result<crate::types::IdentityProvider> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -254,7 +258,8 @@ impl IdentityProviders {
     *
     * * `idp_id: &str`
     */
-    pub async fn delete(&self, idp_id: &str) -> Result<()> {
+    pub async fn delete(&self, idp_id: impl AsRef<str>) -> Result<()> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -274,7 +279,8 @@ impl IdentityProviders {
     *
     * * `idp_id: &str`
     */
-    pub async fn list_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
+    pub async fn list_csrs_fors(&self, idp_id: impl AsRef<str>) -> Result<Vec<crate::types::Csr>> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs",
             crate::progenitor_support::encode_path(idp_id),
@@ -292,7 +298,11 @@ impl IdentityProviders {
     *
     * Enumerates Certificate Signing Requests for an IdP
     */
-    pub async fn list_all_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
+    pub async fn list_all_csrs_fors(
+        &self,
+        idp_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Csr>> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs",
             crate::progenitor_support::encode_path(idp_id),
@@ -314,9 +324,10 @@ impl IdentityProviders {
     */
     pub async fn generate_csr_for(
         &self,
-        idp_id: &str,
+        idp_id: impl AsRef<str>,
         body: &crate::types::CsrMetadata,
     ) -> Result<crate::types::Csr> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs",
             crate::progenitor_support::encode_path(idp_id),
@@ -337,7 +348,13 @@ impl IdentityProviders {
     * * `idp_id: &str`
     * * `csr_id: &str`
     */
-    pub async fn get_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
+    pub async fn get_csr_for(
+        &self,
+        idp_id: impl AsRef<str>,
+        csr_id: impl AsRef<str>,
+    ) -> Result<crate::types::Csr> {
+        let idp_id = idp_id.as_ref();
+        let csr_id = csr_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -357,7 +374,13 @@ impl IdentityProviders {
     * * `idp_id: &str`
     * * `csr_id: &str`
     */
-    pub async fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<()> {
+    pub async fn revoke_csr_for(
+        &self,
+        idp_id: impl AsRef<str>,
+        csr_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let idp_id = idp_id.as_ref();
+        let csr_id = csr_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -379,9 +402,11 @@ impl IdentityProviders {
     */
     pub async fn post_idp_credentials_csr_lifecycle_publish(
         &self,
-        idp_id: &str,
-        csr_id: &str,
+        idp_id: impl AsRef<str>,
+        csr_id: impl AsRef<str>,
     ) -> Result<crate::types::JsonWebKey> {
+        let idp_id = idp_id.as_ref();
+        let csr_id = csr_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs/{}/lifecycle/publish",
             crate::progenitor_support::encode_path(idp_id),
@@ -402,7 +427,11 @@ impl IdentityProviders {
     *
     * * `idp_id: &str`
     */
-    pub async fn list_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+    pub async fn list_signing_keys(
+        &self,
+        idp_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/keys",
             crate::progenitor_support::encode_path(idp_id),
@@ -422,8 +451,9 @@ impl IdentityProviders {
     */
     pub async fn list_all_signing_keys(
         &self,
-        idp_id: &str,
+        idp_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/keys",
             crate::progenitor_support::encode_path(idp_id),
@@ -446,18 +476,20 @@ impl IdentityProviders {
     */
     pub async fn generate_signing_key(
         &self,
-        idp_id: &str,
+        idp_id: impl AsRef<str>,
         validity_years: i64,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let idp_id = idp_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if validity_years > 0 {
-            query_args.push(("validityYears".to_string(), validity_years.to_string()));
+            query_args.push(("validityYears", validity_years.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/idps/{}/credentials/keys/generate?{}",
-            crate::progenitor_support::encode_path(idp_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/idps/{}/credentials/keys/generate",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -477,9 +509,11 @@ impl IdentityProviders {
     */
     pub async fn get_signing_key(
         &self,
-        idp_id: &str,
-        key_id: &str,
+        idp_id: impl AsRef<str>,
+        key_id: impl AsRef<str>,
     ) -> Result<crate::types::JsonWebKey> {
+        let idp_id = idp_id.as_ref();
+        let key_id = key_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/credentials/keys/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -504,20 +538,23 @@ impl IdentityProviders {
     */
     pub async fn clone_key(
         &self,
-        idp_id: &str,
-        key_id: &str,
+        idp_id: impl AsRef<str>,
+        key_id: impl AsRef<str>,
         target_idp_id: &str,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let idp_id = idp_id.as_ref();
+        let key_id = key_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !target_idp_id.is_empty() {
-            query_args.push(("targetIdpId".to_string(), target_idp_id.to_string()));
+            query_args.push(("targetIdpId", target_idp_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/idps/{}/credentials/keys/{}/clone?{}",
-            crate::progenitor_support::encode_path(idp_id),
-            crate::progenitor_support::encode_path(key_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/idps/{}/credentials/keys/{}/clone",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(key_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -534,7 +571,11 @@ impl IdentityProviders {
     *
     * * `idp_id: &str`
     */
-    pub async fn activate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+    pub async fn activate(
+        &self,
+        idp_id: impl AsRef<str>,
+    ) -> Result<crate::types::IdentityProvider> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(idp_id),
@@ -554,7 +595,11 @@ impl IdentityProviders {
     *
     * * `idp_id: &str`
     */
-    pub async fn deactivate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+    pub async fn deactivate(
+        &self,
+        idp_id: impl AsRef<str>,
+    ) -> Result<crate::types::IdentityProvider> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(idp_id),
@@ -576,8 +621,9 @@ impl IdentityProviders {
     */
     pub async fn list_application_users(
         &self,
-        idp_id: &str,
+        idp_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/users",
             crate::progenitor_support::encode_path(idp_id),
@@ -597,8 +643,9 @@ impl IdentityProviders {
     */
     pub async fn list_all_application_users(
         &self,
-        idp_id: &str,
+        idp_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
+        let idp_id = idp_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/users",
             crate::progenitor_support::encode_path(idp_id),
@@ -619,9 +666,11 @@ impl IdentityProviders {
     */
     pub async fn get_application_user(
         &self,
-        idp_id: &str,
-        user_id: &str,
+        idp_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::IdentityProviderApplicationUser> {
+        let idp_id = idp_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/users/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -645,10 +694,12 @@ impl IdentityProviders {
     */
     pub async fn link_user(
         &self,
-        idp_id: &str,
-        user_id: &str,
+        idp_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserIdentityProviderLinkRequest,
     ) -> Result<crate::types::IdentityProviderApplicationUser> {
+        let idp_id = idp_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/users/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -672,7 +723,13 @@ impl IdentityProviders {
     * * `idp_id: &str`
     * * `user_id: &str`
     */
-    pub async fn unlink_user_from(&self, idp_id: &str, user_id: &str) -> Result<()> {
+    pub async fn unlink_user_from(
+        &self,
+        idp_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let idp_id = idp_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/users/{}",
             crate::progenitor_support::encode_path(idp_id),
@@ -696,9 +753,11 @@ impl IdentityProviders {
     */
     pub async fn list_social_auth_tokens(
         &self,
-        idp_id: &str,
-        user_id: &str,
+        idp_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SocialAuthToken>> {
+        let idp_id = idp_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/users/{}/credentials/tokens",
             crate::progenitor_support::encode_path(idp_id),
@@ -719,9 +778,11 @@ impl IdentityProviders {
     */
     pub async fn list_all_social_auth_tokens(
         &self,
-        idp_id: &str,
-        user_id: &str,
+        idp_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SocialAuthToken>> {
+        let idp_id = idp_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/idps/{}/users/{}/credentials/tokens",
             crate::progenitor_support::encode_path(idp_id),
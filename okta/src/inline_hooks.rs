@@ -22,12 +22,12 @@ impl InlineHooks {
     * * `type_: &str`
     */
     pub async fn list(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/inlineHooks?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/inlineHooks"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -40,12 +40,12 @@ impl InlineHooks {
     * Success
     */
     pub async fn list_all(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/inlineHooks?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/inlineHooks"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -74,7 +74,8 @@ impl InlineHooks {
     *
     * * `inline_hook_id: &str`
     */
-    pub async fn get(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+    pub async fn get(&self, inline_hook_id: impl AsRef<str>) -> Result<crate::types::InlineHook> {
+        let inline_hook_id = inline_hook_id.as_ref();
         let url = format!(
             "/api/v1/inlineHooks/{}",
             crate::progenitor_support::encode_path(inline_hook_id),
@@ -94,9 +95,10 @@ impl InlineHooks {
     */
     pub async fn update(
         &self,
-        inline_hook_id: &str,
+        inline_hook_id: impl AsRef<str>,
         body: &crate::types::InlineHook,
     ) -> Result<crate::types::InlineHook> {
+        let inline_hook_id = inline_hook_id.as_ref();
         let url = format!(
             "/api/v1/inlineHooks/{}",
             crate::progenitor_support::encode_path(inline_hook_id),
@@ -116,7 +118,8 @@ impl InlineHooks {
     *
     * * `inline_hook_id: &str`
     */
-    pub async fn delete(&self, inline_hook_id: &str) -> Result<()> {
+    pub async fn delete(&self, inline_hook_id: impl AsRef<str>) -> Result<()> {
+        let inline_hook_id = inline_hook_id.as_ref();
         let url = format!(
             "/api/v1/inlineHooks/{}",
             crate::progenitor_support::encode_path(inline_hook_id),
@@ -136,9 +139,10 @@ impl InlineHooks {
     */
     pub async fn execute(
         &self,
-        inline_hook_id: &str,
+        inline_hook_id: impl AsRef<str>,
         body: &crate::types::Links,
     ) -> Result<crate::types::InlineHookResponse> {
+        let inline_hook_id = inline_hook_id.as_ref();
         let url = format!(
             "/api/v1/inlineHooks/{}/execute",
             crate::progenitor_support::encode_path(inline_hook_id),
@@ -158,7 +162,11 @@ impl InlineHooks {
     *
     * * `inline_hook_id: &str`
     */
-    pub async fn activate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+    pub async fn activate(
+        &self,
+        inline_hook_id: impl AsRef<str>,
+    ) -> Result<crate::types::InlineHook> {
+        let inline_hook_id = inline_hook_id.as_ref();
         let url = format!(
             "/api/v1/inlineHooks/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(inline_hook_id),
@@ -176,7 +184,11 @@ impl InlineHooks {
     *
     * * `inline_hook_id: &str`
     */
-    pub async fn deactivate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+    pub async fn deactivate(
+        &self,
+        inline_hook_id: impl AsRef<str>,
+    ) -> Result<crate::types::InlineHook> {
+        let inline_hook_id = inline_hook_id.as_ref();
         let url = format!(
             "/api/v1/inlineHooks/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(inline_hook_id),
@@ -60,8 +60,9 @@ impl LinkedObjects {
     */
     pub async fn get_definition(
         &self,
-        linked_object_name: &str,
+        linked_object_name: impl AsRef<str>,
     ) -> Result<crate::types::LinkedObject> {
+        let linked_object_name = linked_object_name.as_ref();
         let url = format!(
             "/api/v1/meta/schemas/user/linkedObjects/{}",
             crate::progenitor_support::encode_path(linked_object_name),
@@ -79,7 +80,8 @@ impl LinkedObjects {
     *
     * * `linked_object_name: &str`
     */
-    pub async fn delete_definition(&self, linked_object_name: &str) -> Result<()> {
+    pub async fn delete_definition(&self, linked_object_name: impl AsRef<str>) -> Result<()> {
+        let linked_object_name = linked_object_name.as_ref();
         let url = format!(
             "/api/v1/meta/schemas/user/linkedObjects/{}",
             crate::progenitor_support::encode_path(linked_object_name),
@@ -0,0 +1,321 @@
+//! Stream the events of the System Log as they happen, for example to ingest them
+//! in a SIEM.
+//!
+//! A stream polls `/api/v1/logs` oldest first. Every page links to the next one with
+//! an `after` cursor, which Okta keeps returning once the stream caught up: the
+//! stream then waits for the poll interval and asks for the same page again, until
+//! new events show up. The cursor is saved in a user supplied `CursorStore` after
+//! every page, so that a stream restarted later, even in another process, picks up
+//! where the last one left off.
+//!
+//! The stream also waits for the rate limit to reset when the
+//! `X-Rate-Limit-Remaining` header says there are no requests left.
+//!
+//! ```no_run
+//! # async fn run(okta: okta::Client) -> anyhow::Result<()> {
+//! use futures::TryStreamExt;
+//! use okta::logs::MemoryCursorStore;
+//!
+//! let since = chrono::Utc::now() - chrono::Duration::hours(1);
+//! let store = MemoryCursorStore::default();
+//! let mut events = Box::pin(
+//!     okta.logs()
+//!         .stream(since)
+//!         .filter("eventType eq \"user.session.start\"")
+//!         .cursor_store(store)
+//!         .events(),
+//! );
+//! while let Some(event) = events.try_next().await? {
+//!     println!("{} {}", event.uuid, event.display_message);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::{logs::Logs, types::LogEvent, Client};
+
+/// Number of events requested per page, the most Okta allows.
+const DEFAULT_LIMIT: u32 = 1000;
+
+/// How long to wait before polling again once there are no new events.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+const X_RATE_LIMIT_REMAINING: &str = "x-rate-limit-remaining";
+const X_RATE_LIMIT_RESET: &str = "x-rate-limit-reset";
+
+/// Persists the `after` cursor of a stream between runs, for example in a database
+/// or a file.
+#[async_trait::async_trait]
+pub trait CursorStore: Send + Sync {
+    /// Load the last saved cursor, or `None` to start from the `since` of the stream.
+    async fn load(&self) -> Result<Option<String>>;
+
+    /// Save the cursor of the next page of a stream.
+    async fn save(&self, cursor: &str) -> Result<()>;
+}
+
+/// A `CursorStore` that keeps the cursor in memory. Clones share the same cursor.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCursorStore {
+    cursor: Arc<Mutex<Option<String>>>,
+}
+
+impl MemoryCursorStore {
+    /// The last saved cursor.
+    pub fn cursor(&self) -> Option<String> {
+        self.cursor.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl CursorStore for MemoryCursorStore {
+    async fn load(&self) -> Result<Option<String>> {
+        Ok(self.cursor())
+    }
+
+    async fn save(&self, cursor: &str) -> Result<()> {
+        *self.cursor.lock().unwrap() = Some(cursor.to_string());
+        Ok(())
+    }
+}
+
+/// A stream of the events of the System Log. Created with `Logs::stream`.
+pub struct LogStream<S = MemoryCursorStore> {
+    client: Client,
+    since: DateTime<Utc>,
+    filter: String,
+    q: String,
+    limit: u32,
+    poll_interval: Duration,
+    store: S,
+}
+
+impl Logs {
+    /// Start a stream of the events published from `since` on, oldest first.
+    ///
+    /// The stream never ends by itself: once it caught up, it keeps polling for new
+    /// events.
+    pub fn stream(&self, since: DateTime<Utc>) -> LogStream {
+        LogStream {
+            client: self.client.clone(),
+            since,
+            filter: String::new(),
+            q: String::new(),
+            limit: DEFAULT_LIMIT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            store: MemoryCursorStore::default(),
+        }
+    }
+}
+
+/// The state carried between the pages of a stream.
+struct StreamState<S> {
+    stream: LogStream<S>,
+    /// The URL of the next page, `None` once the last page was fetched.
+    next: Option<String>,
+    /// The `after` cursor of `next`.
+    cursor: Option<String>,
+    /// Whether `cursor` moved since it was last saved.
+    dirty: bool,
+    /// How long to wait before the next request.
+    wait: Option<Duration>,
+    pending: VecDeque<LogEvent>,
+    loaded: bool,
+}
+
+impl<S: CursorStore> LogStream<S> {
+    /// Only stream the events matching a filter expression, like
+    /// `eventType eq "user.session.start"`.
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = filter.to_string();
+        self
+    }
+
+    /// Only stream the events containing all the given keywords.
+    pub fn q(mut self, q: &str) -> Self {
+        self.q = q.to_string();
+        self
+    }
+
+    /// Set the number of events requested per page.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set how long to wait before polling again once there are no new events.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Save the cursor in `store` after every page, and resume from the cursor it
+    /// holds instead of `since`.
+    pub fn cursor_store<T: CursorStore>(self, store: T) -> LogStream<T> {
+        LogStream {
+            client: self.client,
+            since: self.since,
+            filter: self.filter,
+            q: self.q,
+            limit: self.limit,
+            poll_interval: self.poll_interval,
+            store,
+        }
+    }
+
+    /// Returns the events, oldest first, saving the cursor after every page.
+    ///
+    /// The cursor of a page is saved when the stream is polled after the last event
+    /// of that page, so an event is only skipped by a resumed stream once it has
+    /// been handed out and the following one was asked for.
+    pub fn events(self) -> impl futures::Stream<Item = Result<LogEvent>> {
+        let state = StreamState {
+            stream: self,
+            next: None,
+            cursor: None,
+            dirty: false,
+            wait: None,
+            pending: VecDeque::new(),
+            loaded: false,
+        };
+
+        futures::stream::try_unfold(state, |mut state| async move {
+            if !state.loaded {
+                let cursor = state.stream.store.load().await?;
+                state.next = Some(state.stream.first_url(cursor.as_deref()));
+                state.cursor = cursor;
+                state.loaded = true;
+            }
+
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Ok(Some((event, state)));
+                }
+
+                // Everything fetched so far was handed out, so it is safe to save.
+                if state.dirty {
+                    if let Some(cursor) = &state.cursor {
+                        state.stream.store.save(cursor).await?;
+                    }
+                    state.dirty = false;
+                }
+
+                let url = match state.next.take() {
+                    Some(url) => url,
+                    None => return Ok(None),
+                };
+                if let Some(wait) = state.wait.take() {
+                    crate::runtime::sleep(wait).await;
+                }
+
+                let resp = state
+                    .stream
+                    .client
+                    .request_raw(http::Method::GET, &url, None)
+                    .await?;
+                let status = resp.status();
+                let rate_limit_wait = rate_limit_wait(resp.headers());
+                let next = resp
+                    .headers()
+                    .get(http::header::LINK)
+                    .and_then(|l| l.to_str().ok())
+                    .and_then(|l| l.parse::<hyperx::header::Link>().ok())
+                    .as_ref()
+                    .and_then(crate::utils::next_link);
+                let body = resp.bytes().await?;
+
+                if status == http::StatusCode::TOO_MANY_REQUESTS {
+                    // The retries of the client gave up, ask again after the reset.
+                    let wait = rate_limit_wait.unwrap_or(state.stream.poll_interval);
+                    log::debug!("rate limited, waiting {:?} for the reset", wait);
+                    state.wait = Some(wait);
+                    state.next = Some(url);
+                    continue;
+                }
+                if !status.is_success() {
+                    return Err(crate::error::from_response(status, &body));
+                }
+                let events: Vec<LogEvent> = serde_json::from_slice(&body)?;
+
+                let cursor = next.as_deref().and_then(after_cursor);
+                if cursor.is_some() && cursor != state.cursor {
+                    state.cursor = cursor;
+                    state.dirty = true;
+                }
+                // Okta links to the next page even once the stream caught up, and
+                // that page fills up as new events are published.
+                state.wait = if events.is_empty() {
+                    Some(
+                        rate_limit_wait
+                            .unwrap_or_default()
+                            .max(state.stream.poll_interval),
+                    )
+                } else {
+                    rate_limit_wait
+                };
+                state.next = next;
+                state.pending.extend(events);
+            }
+        })
+    }
+
+    /// The URL of the first page, from the saved cursor or else from `since`.
+    fn first_url(&self, cursor: Option<&str>) -> String {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        match cursor {
+            Some(after) => query_args.push(("after".to_string(), after.to_string())),
+            None => query_args.push(("since".to_string(), self.since.to_rfc3339())),
+        }
+        if !self.filter.is_empty() {
+            query_args.push(("filter".to_string(), self.filter.to_string()));
+        }
+        if !self.q.is_empty() {
+            query_args.push(("q".to_string(), self.q.to_string()));
+        }
+        query_args.push(("limit".to_string(), self.limit.to_string()));
+        query_args.push(("sortOrder".to_string(), "ASCENDING".to_string()));
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+
+        format!("/api/v1/logs?{}", query_)
+    }
+}
+
+/// The `after` cursor in the URL of a page.
+fn after_cursor(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+    for (name, value) in url.query_pairs() {
+        if name == "after" {
+            return Some(value.into_owned());
+        }
+    }
+    None
+}
+
+/// How long to wait for the rate limit to reset, when no requests are left.
+fn rate_limit_wait(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let number = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+    };
+
+    if number(X_RATE_LIMIT_REMAINING)? > 0 {
+        return None;
+    }
+    let reset = number(X_RATE_LIMIT_RESET)?;
+    let now = web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    // Wait a bit past the reset, for clock skew.
+    Some(Duration::from_secs(reset.saturating_sub(now) + 1))
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::log_stream::{CursorStore, LogStream, MemoryCursorStore};
 use crate::Client;
 
 pub struct Logs {
@@ -39,30 +40,29 @@ impl Logs {
         sort_order: &str,
         after: &str,
     ) -> Result<Vec<crate::types::LogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+            query_args.push(("until", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/logs?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/logs"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -84,24 +84,23 @@ impl Logs {
         q: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::LogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+            query_args.push(("until", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/logs?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/logs"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
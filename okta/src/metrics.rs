@@ -0,0 +1,286 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: okta::Client) {
+//! use okta::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "Okta";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/api/v1/apps",
+    "/api/v1/apps/{appId}",
+    "/api/v1/apps/{appId}/credentials/csrs",
+    "/api/v1/apps/{appId}/credentials/csrs/{csrId}",
+    "/api/v1/apps/{appId}/credentials/csrs/{csrId}/lifecycle/publish",
+    "/api/v1/apps/{appId}/credentials/keys",
+    "/api/v1/apps/{appId}/credentials/keys/generate",
+    "/api/v1/apps/{appId}/credentials/keys/{keyId}",
+    "/api/v1/apps/{appId}/credentials/keys/{keyId}/clone",
+    "/api/v1/apps/{appId}/grants",
+    "/api/v1/apps/{appId}/grants/{grantId}",
+    "/api/v1/apps/{appId}/groups",
+    "/api/v1/apps/{appId}/groups/{groupId}",
+    "/api/v1/apps/{appId}/lifecycle/activate",
+    "/api/v1/apps/{appId}/lifecycle/deactivate",
+    "/api/v1/apps/{appId}/tokens",
+    "/api/v1/apps/{appId}/tokens/{tokenId}",
+    "/api/v1/apps/{appId}/users",
+    "/api/v1/apps/{appId}/users/{userId}",
+    "/api/v1/authorizationServers",
+    "/api/v1/authorizationServers/{authServerId}",
+    "/api/v1/authorizationServers/{authServerId}/claims",
+    "/api/v1/authorizationServers/{authServerId}/claims/{claimId}",
+    "/api/v1/authorizationServers/{authServerId}/clients",
+    "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens",
+    "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}",
+    "/api/v1/authorizationServers/{authServerId}/credentials/keys",
+    "/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate",
+    "/api/v1/authorizationServers/{authServerId}/lifecycle/activate",
+    "/api/v1/authorizationServers/{authServerId}/lifecycle/deactivate",
+    "/api/v1/authorizationServers/{authServerId}/policies",
+    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}",
+    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/activate",
+    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/deactivate",
+    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules",
+    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}",
+    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/activate",
+    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate",
+    "/api/v1/authorizationServers/{authServerId}/scopes",
+    "/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}",
+    "/api/v1/domains",
+    "/api/v1/domains/{domainId}",
+    "/api/v1/domains/{domainId}/certificate",
+    "/api/v1/domains/{domainId}/verify",
+    "/api/v1/eventHooks",
+    "/api/v1/eventHooks/{eventHookId}",
+    "/api/v1/eventHooks/{eventHookId}/lifecycle/activate",
+    "/api/v1/eventHooks/{eventHookId}/lifecycle/deactivate",
+    "/api/v1/eventHooks/{eventHookId}/lifecycle/verify",
+    "/api/v1/features",
+    "/api/v1/features/{featureId}",
+    "/api/v1/features/{featureId}/dependencies",
+    "/api/v1/features/{featureId}/dependents",
+    "/api/v1/features/{featureId}/{lifecycle}",
+    "/api/v1/groups",
+    "/api/v1/groups/rules",
+    "/api/v1/groups/rules/{ruleId}",
+    "/api/v1/groups/rules/{ruleId}/lifecycle/activate",
+    "/api/v1/groups/rules/{ruleId}/lifecycle/deactivate",
+    "/api/v1/groups/{groupId}",
+    "/api/v1/groups/{groupId}/apps",
+    "/api/v1/groups/{groupId}/roles",
+    "/api/v1/groups/{groupId}/roles/{roleId}",
+    "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps",
+    "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}",
+    "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}",
+    "/api/v1/groups/{groupId}/roles/{roleId}/targets/groups",
+    "/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}",
+    "/api/v1/groups/{groupId}/users",
+    "/api/v1/groups/{groupId}/users/{userId}",
+    "/api/v1/idps",
+    "/api/v1/idps/credentials/keys",
+    "/api/v1/idps/credentials/keys/{keyId}",
+    "/api/v1/idps/{idpId}",
+    "/api/v1/idps/{idpId}/credentials/csrs",
+    "/api/v1/idps/{idpId}/credentials/csrs/{csrId}",
+    "/api/v1/idps/{idpId}/credentials/csrs/{csrId}/lifecycle/publish",
+    "/api/v1/idps/{idpId}/credentials/keys",
+    "/api/v1/idps/{idpId}/credentials/keys/generate",
+    "/api/v1/idps/{idpId}/credentials/keys/{keyId}",
+    "/api/v1/idps/{idpId}/credentials/keys/{keyId}/clone",
+    "/api/v1/idps/{idpId}/lifecycle/activate",
+    "/api/v1/idps/{idpId}/lifecycle/deactivate",
+    "/api/v1/idps/{idpId}/users",
+    "/api/v1/idps/{idpId}/users/{userId}",
+    "/api/v1/idps/{idpId}/users/{userId}/credentials/tokens",
+    "/api/v1/inlineHooks",
+    "/api/v1/inlineHooks/{inlineHookId}",
+    "/api/v1/inlineHooks/{inlineHookId}/execute",
+    "/api/v1/inlineHooks/{inlineHookId}/lifecycle/activate",
+    "/api/v1/inlineHooks/{inlineHookId}/lifecycle/deactivate",
+    "/api/v1/logs",
+    "/api/v1/mappings",
+    "/api/v1/mappings/{mappingId}",
+    "/api/v1/meta/schemas/apps/{appInstanceId}/default",
+    "/api/v1/meta/schemas/user/linkedObjects",
+    "/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}",
+    "/api/v1/meta/schemas/user/{schemaId}",
+    "/api/v1/meta/types/user",
+    "/api/v1/meta/types/user/{typeId}",
+    "/api/v1/policies",
+    "/api/v1/policies/{policyId}",
+    "/api/v1/policies/{policyId}/lifecycle/activate",
+    "/api/v1/policies/{policyId}/lifecycle/deactivate",
+    "/api/v1/policies/{policyId}/rules",
+    "/api/v1/policies/{policyId}/rules/{ruleId}",
+    "/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/activate",
+    "/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate",
+    "/api/v1/sessions",
+    "/api/v1/sessions/{sessionId}",
+    "/api/v1/sessions/{sessionId}/lifecycle/refresh",
+    "/api/v1/templates/sms",
+    "/api/v1/templates/sms/{templateId}",
+    "/api/v1/threats/configuration",
+    "/api/v1/trustedOrigins",
+    "/api/v1/trustedOrigins/{trustedOriginId}",
+    "/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/activate",
+    "/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/deactivate",
+    "/api/v1/users",
+    "/api/v1/users/{associatedUserId}/linkedObjects/{primaryRelationshipName}/{primaryUserId}",
+    "/api/v1/users/{userId}",
+    "/api/v1/users/{userId}/appLinks",
+    "/api/v1/users/{userId}/clients",
+    "/api/v1/users/{userId}/clients/{clientId}/grants",
+    "/api/v1/users/{userId}/clients/{clientId}/tokens",
+    "/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}",
+    "/api/v1/users/{userId}/credentials/change_password",
+    "/api/v1/users/{userId}/credentials/change_recovery_question",
+    "/api/v1/users/{userId}/credentials/forgot_password",
+    "/api/v1/users/{userId}/factors",
+    "/api/v1/users/{userId}/factors/catalog",
+    "/api/v1/users/{userId}/factors/questions",
+    "/api/v1/users/{userId}/factors/{factorId}",
+    "/api/v1/users/{userId}/factors/{factorId}/lifecycle/activate",
+    "/api/v1/users/{userId}/factors/{factorId}/transactions/{transactionId}",
+    "/api/v1/users/{userId}/factors/{factorId}/verify",
+    "/api/v1/users/{userId}/grants",
+    "/api/v1/users/{userId}/grants/{grantId}",
+    "/api/v1/users/{userId}/groups",
+    "/api/v1/users/{userId}/idps",
+    "/api/v1/users/{userId}/lifecycle/activate",
+    "/api/v1/users/{userId}/lifecycle/deactivate",
+    "/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=false",
+    "/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=true",
+    "/api/v1/users/{userId}/lifecycle/reactivate",
+    "/api/v1/users/{userId}/lifecycle/reset_factors",
+    "/api/v1/users/{userId}/lifecycle/reset_password",
+    "/api/v1/users/{userId}/lifecycle/suspend",
+    "/api/v1/users/{userId}/lifecycle/unlock",
+    "/api/v1/users/{userId}/lifecycle/unsuspend",
+    "/api/v1/users/{userId}/linkedObjects/{relationshipName}",
+    "/api/v1/users/{userId}/roles",
+    "/api/v1/users/{userId}/roles/{roleId}",
+    "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps",
+    "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}",
+    "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}",
+    "/api/v1/users/{userId}/roles/{roleId}/targets/groups",
+    "/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}",
+    "/api/v1/users/{userId}/sessions",
+    "/api/v1/zones",
+    "/api/v1/zones/{zoneId}",
+    "/api/v1/zones/{zoneId}/lifecycle/activate",
+    "/api/v1/zones/{zoneId}/lifecycle/deactivate",
+];
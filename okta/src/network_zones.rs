@@ -31,18 +31,17 @@ impl NetworkZones {
         limit: i64,
         filter: &str,
     ) -> Result<Vec<crate::types::NetworkZone>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/zones?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/zones"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -57,12 +56,11 @@ impl NetworkZones {
     * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
     */
     pub async fn list_all(&self, filter: &str) -> Result<Vec<crate::types::NetworkZone>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/zones?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/zones"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -95,7 +93,8 @@ impl NetworkZones {
     *
     * * `zone_id: &str`
     */
-    pub async fn get(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+    pub async fn get(&self, zone_id: impl AsRef<str>) -> Result<crate::types::NetworkZone> {
+        let zone_id = zone_id.as_ref();
         let url = format!(
             "/api/v1/zones/{}",
             crate::progenitor_support::encode_path(zone_id),
@@ -117,9 +116,10 @@ impl NetworkZones {
     */
     pub async fn update(
         &self,
-        zone_id: &str,
+        zone_id: impl AsRef<str>,
         body: &crate::types::NetworkZone,
     ) -> Result<crate::types::NetworkZone> {
+        let zone_id = zone_id.as_ref();
         let url = format!(
             "/api/v1/zones/{}",
             crate::progenitor_support::encode_path(zone_id),
@@ -141,7 +141,8 @@ impl NetworkZones {
     *
     * * `zone_id: &str`
     */
-    pub async fn delete(&self, zone_id: &str) -> Result<()> {
+    pub async fn delete(&self, zone_id: impl AsRef<str>) -> Result<()> {
+        let zone_id = zone_id.as_ref();
         let url = format!(
             "/api/v1/zones/{}",
             crate::progenitor_support::encode_path(zone_id),
@@ -161,7 +162,8 @@ impl NetworkZones {
     *
     * * `zone_id: &str`
     */
-    pub async fn activate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+    pub async fn activate(&self, zone_id: impl AsRef<str>) -> Result<crate::types::NetworkZone> {
+        let zone_id = zone_id.as_ref();
         let url = format!(
             "/api/v1/zones/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(zone_id),
@@ -181,7 +183,8 @@ impl NetworkZones {
     *
     * * `zone_id: &str`
     */
-    pub async fn deactivate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+    pub async fn deactivate(&self, zone_id: impl AsRef<str>) -> Result<crate::types::NetworkZone> {
+        let zone_id = zone_id.as_ref();
         let url = format!(
             "/api/v1/zones/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(zone_id),
@@ -29,18 +29,18 @@ impl Policies {
         status: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Policy>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/policies?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/policies"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -58,18 +58,18 @@ impl Policies {
         status: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Policy>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/policies?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/policies"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -88,12 +88,12 @@ impl Policies {
         activate: bool,
         body: &crate::types::Policy,
     ) -> Result<crate::types::Policy> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/policies?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/policies"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -110,16 +110,22 @@ impl Policies {
     * * `policy_id: &str`
     * * `expand: &str`
     */
-    pub async fn get_policy(&self, policy_id: &str, expand: &str) -> Result<crate::types::Policy> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_policy(
+        &self,
+        policy_id: impl AsRef<str>,
+        expand: &str,
+    ) -> Result<crate::types::Policy> {
+        let policy_id = policy_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/policies/{}?{}",
-            crate::progenitor_support::encode_path(policy_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/policies/{}",
+                crate::progenitor_support::encode_path(policy_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -136,9 +142,10 @@ impl Policies {
     */
     pub async fn update_policy(
         &self,
-        policy_id: &str,
+        policy_id: impl AsRef<str>,
         body: &crate::types::Policy,
     ) -> Result<crate::types::Policy> {
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}",
             crate::progenitor_support::encode_path(policy_id),
@@ -158,7 +165,8 @@ impl Policies {
     *
     * * `policy_id: &str`
     */
-    pub async fn delete_policy(&self, policy_id: &str) -> Result<()> {
+    pub async fn delete_policy(&self, policy_id: impl AsRef<str>) -> Result<()> {
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}",
             crate::progenitor_support::encode_path(policy_id),
@@ -176,7 +184,8 @@ impl Policies {
     *
     * * `policy_id: &str`
     */
-    pub async fn activate_policy(&self, policy_id: &str) -> Result<()> {
+    pub async fn activate_policy(&self, policy_id: impl AsRef<str>) -> Result<()> {
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(policy_id),
@@ -194,7 +203,8 @@ impl Policies {
     *
     * * `policy_id: &str`
     */
-    pub async fn deactivate_policy(&self, policy_id: &str) -> Result<()> {
+    pub async fn deactivate_policy(&self, policy_id: impl AsRef<str>) -> Result<()> {
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(policy_id),
@@ -214,8 +224,9 @@ impl Policies {
     */
     pub async fn list_policy_rules(
         &self,
-        policy_id: &str,
+        policy_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PolicyRule>> {
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules",
             crate::progenitor_support::encode_path(policy_id),
@@ -233,8 +244,9 @@ impl Policies {
     */
     pub async fn list_all_policy_rules(
         &self,
-        policy_id: &str,
+        policy_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::PolicyRule>> {
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules",
             crate::progenitor_support::encode_path(policy_id),
@@ -254,9 +266,10 @@ impl Policies {
     */
     pub async fn create_policy_rule(
         &self,
-        policy_id: &str,
+        policy_id: impl AsRef<str>,
         body: &crate::types::PolicyRule,
     ) -> Result<crate::types::PolicyRule> {
+        let policy_id = policy_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules",
             crate::progenitor_support::encode_path(policy_id),
@@ -279,9 +292,11 @@ impl Policies {
     */
     pub async fn get_policy_rule(
         &self,
-        policy_id: &str,
-        rule_id: &str,
+        policy_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
     ) -> Result<crate::types::PolicyRule> {
+        let policy_id = policy_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules/{}",
             crate::progenitor_support::encode_path(policy_id),
@@ -303,10 +318,12 @@ impl Policies {
     */
     pub async fn update_policy_rule(
         &self,
-        policy_id: &str,
-        rule_id: &str,
+        policy_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
         body: &crate::types::PolicyRule,
     ) -> Result<crate::types::PolicyRule> {
+        let policy_id = policy_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules/{}",
             crate::progenitor_support::encode_path(policy_id),
@@ -328,7 +345,13 @@ impl Policies {
     * * `policy_id: &str`
     * * `rule_id: &str`
     */
-    pub async fn delete_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+    pub async fn delete_policy_rule(
+        &self,
+        policy_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let policy_id = policy_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules/{}",
             crate::progenitor_support::encode_path(policy_id),
@@ -348,7 +371,13 @@ impl Policies {
     * * `policy_id: &str`
     * * `rule_id: &str`
     */
-    pub async fn activate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+    pub async fn activate_policy_rule(
+        &self,
+        policy_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let policy_id = policy_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(policy_id),
@@ -368,7 +397,13 @@ impl Policies {
     * * `policy_id: &str`
     * * `rule_id: &str`
     */
-    pub async fn deactivate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+    pub async fn deactivate_policy_rule(
+        &self,
+        policy_id: impl AsRef<str>,
+        rule_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let policy_id = policy_id.as_ref();
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/api/v1/policies/{}/rules/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(policy_id),
@@ -31,21 +31,21 @@ impl ProfileMappings {
         source_id: &str,
         target_id: &str,
     ) -> Result<Vec<crate::types::ProfileMapping>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !source_id.is_empty() {
-            query_args.push(("sourceId".to_string(), source_id.to_string()));
+            query_args.push(("sourceId", source_id.to_string()));
         }
         if !target_id.is_empty() {
-            query_args.push(("targetId".to_string(), target_id.to_string()));
+            query_args.push(("targetId", target_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/mappings?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/mappings"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -62,15 +62,15 @@ impl ProfileMappings {
         source_id: &str,
         target_id: &str,
     ) -> Result<Vec<crate::types::ProfileMapping>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_id.is_empty() {
-            query_args.push(("sourceId".to_string(), source_id.to_string()));
+            query_args.push(("sourceId", source_id.to_string()));
         }
         if !target_id.is_empty() {
-            query_args.push(("targetId".to_string(), target_id.to_string()));
+            query_args.push(("targetId", target_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/mappings?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/api/v1/mappings"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -86,7 +86,8 @@ impl ProfileMappings {
     *
     * * `mapping_id: &str`
     */
-    pub async fn get(&self, mapping_id: &str) -> Result<crate::types::ProfileMapping> {
+    pub async fn get(&self, mapping_id: impl AsRef<str>) -> Result<crate::types::ProfileMapping> {
+        let mapping_id = mapping_id.as_ref();
         let url = format!(
             "/api/v1/mappings/{}",
             crate::progenitor_support::encode_path(mapping_id),
@@ -108,9 +109,10 @@ impl ProfileMappings {
     */
     pub async fn update(
         &self,
-        mapping_id: &str,
+        mapping_id: impl AsRef<str>,
         body: &crate::types::ProfileMapping,
     ) -> Result<crate::types::ProfileMapping> {
+        let mapping_id = mapping_id.as_ref();
         let url = format!(
             "/api/v1/mappings/{}",
             crate::progenitor_support::encode_path(mapping_id),
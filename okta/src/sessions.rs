@@ -38,7 +38,8 @@ impl Sessions {
     *
     * * `session_id: &str`
     */
-    pub async fn get(&self, session_id: &str) -> Result<crate::types::Session> {
+    pub async fn get(&self, session_id: impl AsRef<str>) -> Result<crate::types::Session> {
+        let session_id = session_id.as_ref();
         let url = format!(
             "/api/v1/sessions/{}",
             crate::progenitor_support::encode_path(session_id),
@@ -58,7 +59,8 @@ impl Sessions {
     *
     * * `session_id: &str`
     */
-    pub async fn end(&self, session_id: &str) -> Result<()> {
+    pub async fn end(&self, session_id: impl AsRef<str>) -> Result<()> {
+        let session_id = session_id.as_ref();
         let url = format!(
             "/api/v1/sessions/{}",
             crate::progenitor_support::encode_path(session_id),
@@ -78,7 +80,8 @@ impl Sessions {
     *
     * * `session_id: &str`
     */
-    pub async fn refresh(&self, session_id: &str) -> Result<crate::types::Session> {
+    pub async fn refresh(&self, session_id: impl AsRef<str>) -> Result<crate::types::Session> {
+        let session_id = session_id.as_ref();
         let url = format!(
             "/api/v1/sessions/{}/lifecycle/refresh",
             crate::progenitor_support::encode_path(session_id),
@@ -24,12 +24,14 @@ impl Templates {
     * * `template_type: &str`
     */
     pub async fn list_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !template_type.is_empty() {
-            query_args.push(("templateType".to_string(), template_type.to_string()));
+            query_args.push(("templateType", template_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/templates/sms?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/api/v1/templates/sms"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -47,12 +49,14 @@ impl Templates {
         &self,
         template_type: &str,
     ) -> Result<Vec<crate::types::SmsTemplate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !template_type.is_empty() {
-            query_args.push(("templateType".to_string(), template_type.to_string()));
+            query_args.push(("templateType", template_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/templates/sms?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/api/v1/templates/sms"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -85,7 +89,8 @@ impl Templates {
     *
     * * `template_id: &str`
     */
-    pub async fn get_sm(&self, template_id: &str) -> Result<crate::types::SmsTemplate> {
+    pub async fn get_sm(&self, template_id: impl AsRef<str>) -> Result<crate::types::SmsTemplate> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/api/v1/templates/sms/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -107,9 +112,10 @@ impl Templates {
     */
     pub async fn update_sms(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         body: &crate::types::SmsTemplate,
     ) -> Result<crate::types::SmsTemplate> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/api/v1/templates/sms/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -133,9 +139,10 @@ impl Templates {
     */
     pub async fn partial_update_sms(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         body: &crate::types::SmsTemplate,
     ) -> Result<crate::types::SmsTemplate> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/api/v1/templates/sms/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -157,7 +164,8 @@ impl Templates {
     *
     * * `template_id: &str`
     */
-    pub async fn delete_sms(&self, template_id: &str) -> Result<()> {
+    pub async fn delete_sms(&self, template_id: impl AsRef<str>) -> Result<()> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/api/v1/templates/sms/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -1 +1,221 @@
+#[tokio::test]
+async fn test_api_error() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::NOT_FOUND,
+        r#"{
+  "errorCode": "E0000007",
+  "errorSummary": "Not found: Resource not found: 00ub0oNGTSWTBKOLGLNR (User)",
+  "errorLink": "E0000007",
+  "errorId": "oaeHfmOAx1iRLa0H10DeMz5fQ",
+  "errorCauses": [{"errorSummary": "the user does not exist"}]
+}"#,
+    );
+    mock.push_response(reqwest::StatusCode::BAD_GATEWAY, "bad gateway");
 
+    let okta = crate::Client::new("token").with_transport(mock.clone());
+    let err = okta
+        .users()
+        .suspend("00ub0oNGTSWTBKOLGLNR")
+        .await
+        .unwrap_err();
+    let api_error = err.downcast_ref::<crate::error::ApiError>().unwrap();
+    assert_eq!(api_error.status, reqwest::StatusCode::NOT_FOUND);
+    assert_eq!(api_error.error_code, "E0000007");
+    assert_eq!(api_error.error_causes, vec!["the user does not exist"]);
+
+    assert_eq!(
+        mock.requests()[0].url.path(),
+        "/api/v1/users/00ub0oNGTSWTBKOLGLNR/lifecycle/suspend"
+    );
+
+    // A body that is not an error object is kept as it was.
+    let err = okta
+        .users()
+        .unsuspend("00ub0oNGTSWTBKOLGLNR")
+        .await
+        .unwrap_err();
+    assert!(err.downcast_ref::<crate::error::ApiError>().is_none());
+    assert!(err.to_string().contains("bad gateway"));
+}
+
+/// Answers with pages of log events, linking to the next page like Okta does.
+#[derive(Clone, Default)]
+struct LogPages {
+    pages: std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<(String, String)>>>,
+    urls: std::sync::Arc<std::sync::Mutex<Vec<reqwest::Url>>>,
+}
+
+impl LogPages {
+    fn push(&self, uuids: &[&str], after: &str) {
+        let events: Vec<serde_json::Value> = uuids
+            .iter()
+            .map(|uuid| serde_json::json!({ "uuid": uuid }))
+            .collect();
+        self.pages.lock().unwrap().push_back((
+            serde_json::to_string(&events).unwrap(),
+            format!(
+                "<https://na4.okta.net/api/v1/logs?after={}&limit=1000&sortOrder=ASCENDING>; rel=\"next\"",
+                after
+            ),
+        ));
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::transport::HttpTransport for LogPages {
+    async fn send(&self, req: reqwest::Request) -> anyhow::Result<reqwest::Response> {
+        self.urls.lock().unwrap().push(req.url().clone());
+        let (body, link) = self.pages.lock().unwrap().pop_front().unwrap();
+        let resp = http::Response::builder()
+            .status(200)
+            .header("link", link)
+            .header("x-rate-limit-remaining", "99")
+            .body(body)?;
+        Ok(reqwest::Response::from(resp))
+    }
+}
+
+#[tokio::test]
+async fn test_log_stream() {
+    use futures::{StreamExt, TryStreamExt};
+
+    let pages = LogPages::default();
+    pages.push(&["a", "b"], "1");
+    // Caught up: Okta links to the same page until new events show up.
+    pages.push(&[], "1");
+    pages.push(&["c"], "2");
+    pages.push(&["d"], "3");
+
+    let okta = crate::Client::new("token").with_transport(pages.clone());
+    let store = crate::logs::MemoryCursorStore::default();
+    let since = chrono::DateTime::parse_from_rfc3339("2021-08-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+
+    let events = Box::pin(
+        okta.logs()
+            .stream(since)
+            .poll_interval(std::time::Duration::from_millis(1))
+            .cursor_store(store.clone())
+            .events(),
+    );
+    let uuids: Vec<String> = events
+        .take(3)
+        .map_ok(|e| e.uuid)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(uuids, vec!["a", "b", "c"]);
+    // The cursor of the last page is only saved once its events were handed out.
+    assert_eq!(store.cursor().as_deref(), Some("1"));
+
+    let urls = pages.urls.lock().unwrap().clone();
+    assert_eq!(urls.len(), 3);
+    assert_eq!(urls[0].path(), "/api/v1/logs");
+    let query: Vec<(String, String)> = urls[0].query_pairs().into_owned().collect();
+    assert!(query.contains(&("since".to_string(), "2021-08-01T00:00:00+00:00".to_string())));
+    assert!(query.contains(&("sortOrder".to_string(), "ASCENDING".to_string())));
+    assert_eq!(
+        urls[1].query(),
+        Some("after=1&limit=1000&sortOrder=ASCENDING")
+    );
+    assert_eq!(urls[2], urls[1]);
+
+    // A new stream resumes from the saved cursor instead of `since`.
+    let events = Box::pin(okta.logs().stream(since).cursor_store(store).events());
+    let uuids: Vec<String> = events
+        .take(1)
+        .map_ok(|e| e.uuid)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(uuids, vec!["d"]);
+    let urls = pages.urls.lock().unwrap().clone();
+    let query: Vec<(String, String)> = urls[3].query_pairs().into_owned().collect();
+    assert!(query.contains(&("after".to_string(), "1".to_string())));
+    assert!(!query.iter().any(|(name, _)| name == "since"));
+}
+
+#[tokio::test]
+async fn test_access_provisioning() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "0pr3f7zMZZHPgUoWO0g4", "status": "ACTIVE"}"#,
+    );
+    mock.push_response(reqwest::StatusCode::NO_CONTENT, "");
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "0pr3f7zMZZHPgUoWO0g4", "status": "INACTIVE"}"#,
+    );
+    mock.push_response(reqwest::StatusCode::NO_CONTENT, "");
+    mock.push_response(reqwest::StatusCode::OK, r#"[{"id": "g2"}, {"id": "g3"}]"#);
+    mock.push_response(reqwest::StatusCode::OK, r#"{"id": "g1"}"#);
+    mock.push_response(reqwest::StatusCode::NO_CONTENT, "");
+
+    let okta = crate::Client::new("token").with_transport(mock.clone());
+    let rule = crate::types::GroupRule::assigning(
+        "Engineering",
+        "user.department==\"Engineering\"",
+        &["g1"],
+    );
+    let updated = okta
+        .groups()
+        .replace_rule("0pr3f7zMZZHPgUoWO0g4", &rule)
+        .await
+        .unwrap();
+    assert_eq!(updated.status, Some(crate::types::GroupRuleStatus::Active));
+
+    let changes = okta
+        .applications()
+        .set_group_assignments("0oa1gjh63g214q0Hq0g4", &["g1", "g2"])
+        .await
+        .unwrap();
+    assert_eq!(changes.added, vec!["g1"]);
+    assert_eq!(changes.removed, vec!["g3"]);
+
+    let requests = mock.requests();
+    let paths: Vec<_> = requests.iter().map(|r| r.url.path()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/api/v1/groups/rules/0pr3f7zMZZHPgUoWO0g4",
+            "/api/v1/groups/rules/0pr3f7zMZZHPgUoWO0g4/lifecycle/deactivate",
+            "/api/v1/groups/rules/0pr3f7zMZZHPgUoWO0g4",
+            "/api/v1/groups/rules/0pr3f7zMZZHPgUoWO0g4/lifecycle/activate",
+            "/api/v1/apps/0oa1gjh63g214q0Hq0g4/groups",
+            "/api/v1/apps/0oa1gjh63g214q0Hq0g4/groups/g1",
+            "/api/v1/apps/0oa1gjh63g214q0Hq0g4/groups/g3",
+        ]
+    );
+    let sent: serde_json::Value = requests[2].json().unwrap();
+    assert_eq!(
+        sent["conditions"]["expression"]["type"],
+        "urn:okta:expression:1.0"
+    );
+}
+
+#[tokio::test]
+async fn test_from_env() {
+    use crate::from_env::FromEnv;
+
+    assert_eq!(crate::Client::env_vars(), &["OKTA_API_KEY", "OKTA_HOST"]);
+
+    std::env::remove_var("OKTA_API_KEY");
+    let err = crate::Client::from_env().unwrap_err();
+    assert_eq!(err.to_string(), "OKTA_API_KEY must be set");
+
+    std::env::set_var("OKTA_API_KEY", "token");
+    std::env::set_var("OKTA_HOST", "https://example.okta.com");
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, "{}");
+    let okta = crate::Client::from_env()
+        .unwrap()
+        .with_transport(mock.clone());
+    okta.users().suspend("00ub0oNGTSWTBKOLGLNR").await.unwrap();
+
+    let request = &mock.requests()[0];
+    assert_eq!(request.url.host_str(), Some("example.okta.com"));
+    assert_eq!(request.headers["authorization"], "SSWS token");
+}
@@ -31,21 +31,23 @@ impl TrustedOrigins {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::TrustedOrigin>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/trustedOrigins?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/api/v1/trustedOrigins"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -62,15 +64,17 @@ impl TrustedOrigins {
         q: &str,
         filter: &str,
     ) -> Result<Vec<crate::types::TrustedOrigin>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/trustedOrigins?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/api/v1/trustedOrigins"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -99,7 +103,11 @@ impl TrustedOrigins {
     *
     * * `trusted_origin_id: &str`
     */
-    pub async fn get_origin(&self, trusted_origin_id: &str) -> Result<crate::types::TrustedOrigin> {
+    pub async fn get_origin(
+        &self,
+        trusted_origin_id: impl AsRef<str>,
+    ) -> Result<crate::types::TrustedOrigin> {
+        let trusted_origin_id = trusted_origin_id.as_ref();
         let url = format!(
             "/api/v1/trustedOrigins/{}",
             crate::progenitor_support::encode_path(trusted_origin_id),
@@ -119,9 +127,10 @@ impl TrustedOrigins {
     */
     pub async fn update_origin(
         &self,
-        trusted_origin_id: &str,
+        trusted_origin_id: impl AsRef<str>,
         body: &crate::types::TrustedOrigin,
     ) -> Result<crate::types::TrustedOrigin> {
+        let trusted_origin_id = trusted_origin_id.as_ref();
         let url = format!(
             "/api/v1/trustedOrigins/{}",
             crate::progenitor_support::encode_path(trusted_origin_id),
@@ -141,7 +150,8 @@ impl TrustedOrigins {
     *
     * * `trusted_origin_id: &str`
     */
-    pub async fn delete_origin(&self, trusted_origin_id: &str) -> Result<()> {
+    pub async fn delete_origin(&self, trusted_origin_id: impl AsRef<str>) -> Result<()> {
+        let trusted_origin_id = trusted_origin_id.as_ref();
         let url = format!(
             "/api/v1/trustedOrigins/{}",
             crate::progenitor_support::encode_path(trusted_origin_id),
@@ -161,8 +171,9 @@ impl TrustedOrigins {
     */
     pub async fn activate_origin(
         &self,
-        trusted_origin_id: &str,
+        trusted_origin_id: impl AsRef<str>,
     ) -> Result<crate::types::TrustedOrigin> {
+        let trusted_origin_id = trusted_origin_id.as_ref();
         let url = format!(
             "/api/v1/trustedOrigins/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(trusted_origin_id),
@@ -182,8 +193,9 @@ impl TrustedOrigins {
     */
     pub async fn deactivate_origin(
         &self,
-        trusted_origin_id: &str,
+        trusted_origin_id: impl AsRef<str>,
     ) -> Result<crate::types::TrustedOrigin> {
+        let trusted_origin_id = trusted_origin_id.as_ref();
         let url = format!(
             "/api/v1/trustedOrigins/{}/lifecycle/deactivate",
             crate::progenitor_support::encode_path(trusted_origin_id),
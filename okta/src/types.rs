@@ -18,7 +18,7 @@ pub struct AcsEndpoint {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivateFactorRequest {
     #[serde(
         default,
@@ -56,6 +56,49 @@ pub struct ActivateFactorRequest {
     pub state_token: String,
 }
 
+impl ActivateFactorRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ActivateFactorRequestBuilder {
+        ActivateFactorRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ActivateFactorRequest`.
+#[derive(Debug, Clone)]
+pub struct ActivateFactorRequestBuilder(ActivateFactorRequest);
+
+impl ActivateFactorRequestBuilder {
+    pub fn attestation(mut self, attestation: impl Into<String>) -> Self {
+        self.0.attestation = attestation.into();
+        self
+    }
+
+    pub fn client_data(mut self, client_data: impl Into<String>) -> Self {
+        self.0.client_data = client_data.into();
+        self
+    }
+
+    pub fn pass_code(mut self, pass_code: impl Into<String>) -> Self {
+        self.0.pass_code = pass_code.into();
+        self
+    }
+
+    pub fn registration_data(mut self, registration_data: impl Into<String>) -> Self {
+        self.0.registration_data = registration_data.into();
+        self
+    }
+
+    pub fn state_token(mut self, state_token: impl Into<String>) -> Self {
+        self.0.state_token = state_token.into();
+        self
+    }
+
+    pub fn build(self) -> ActivateFactorRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "APP")]
@@ -64,8 +107,8 @@ pub enum Type {
     AppType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -74,7 +117,7 @@ impl std::fmt::Display for Type {
             Type::App => "APP",
             Type::AppType => "APP_TYPE",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -324,8 +367,8 @@ pub enum Status {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -335,7 +378,7 @@ impl std::fmt::Display for Status {
             Status::Deleted => "DELETED",
             Status::Inactive => "INACTIVE",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -491,8 +534,8 @@ pub enum ApplicationCredentialsScheme {
     SharedUsernameAndPassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationCredentialsScheme {
@@ -506,7 +549,7 @@ impl std::fmt::Display for ApplicationCredentialsScheme {
                 "SHARED_USERNAME_AND_PASSWORD"
             }
             ApplicationCredentialsScheme::Noop => "",
-            ApplicationCredentialsScheme::FallthroughString => "*",
+            ApplicationCredentialsScheme::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -562,8 +605,8 @@ pub enum Use {
     Sig,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Use {
@@ -571,7 +614,7 @@ impl std::fmt::Display for Use {
         match &*self {
             Use::Sig => "sig",
             Use::Noop => "",
-            Use::FallthroughString => "*",
+            Use::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -741,8 +784,8 @@ pub enum ApplicationSignOnMode {
     WsFederation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationSignOnMode {
@@ -758,7 +801,7 @@ impl std::fmt::Display for ApplicationSignOnMode {
             ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
             ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
             ApplicationSignOnMode::Noop => "",
-            ApplicationSignOnMode::FallthroughString => "*",
+            ApplicationSignOnMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -808,12 +851,35 @@ pub struct ApplicationVisibilityHide {
     pub web: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignRoleRequest {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<RoleType>,
 }
 
+impl AssignRoleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AssignRoleRequestBuilder {
+        AssignRoleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AssignRoleRequest`.
+#[derive(Debug, Clone)]
+pub struct AssignRoleRequestBuilder(AssignRoleRequest);
+
+impl AssignRoleRequestBuilder {
+    pub fn type_(mut self, type_: impl Into<RoleType>) -> Self {
+        self.0.type_ = Some(type_.into());
+        self
+    }
+
+    pub fn build(self) -> AssignRoleRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthenticationProvider {
     #[serde(
@@ -842,8 +908,8 @@ pub enum AuthenticationProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthenticationProviderType {
@@ -856,7 +922,7 @@ impl std::fmt::Display for AuthenticationProviderType {
             AuthenticationProviderType::Okta => "OKTA",
             AuthenticationProviderType::Social => "SOCIAL",
             AuthenticationProviderType::Noop => "",
-            AuthenticationProviderType::FallthroughString => "*",
+            AuthenticationProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -881,8 +947,8 @@ pub enum IssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuerMode {
@@ -891,7 +957,7 @@ impl std::fmt::Display for IssuerMode {
             IssuerMode::CustomUrl => "CUSTOM_URL",
             IssuerMode::OrgUrl => "ORG_URL",
             IssuerMode::Noop => "",
-            IssuerMode::FallthroughString => "*",
+            IssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -916,8 +982,8 @@ pub enum RoleStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleStatus {
@@ -926,7 +992,7 @@ impl std::fmt::Display for RoleStatus {
             RoleStatus::Active => "ACTIVE",
             RoleStatus::Inactive => "INACTIVE",
             RoleStatus::Noop => "",
-            RoleStatus::FallthroughString => "*",
+            RoleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1016,8 +1082,8 @@ pub enum AuthorizationServerCredentialsRotationMode {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
@@ -1026,7 +1092,7 @@ impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
             AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
             AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
             AuthorizationServerCredentialsRotationMode::Noop => "",
-            AuthorizationServerCredentialsRotationMode::FallthroughString => "*",
+            AuthorizationServerCredentialsRotationMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1137,8 +1203,8 @@ pub enum AuthorizationServerPolicyRuleType {
     ResourceAccess,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerPolicyRuleType {
@@ -1146,7 +1212,7 @@ impl std::fmt::Display for AuthorizationServerPolicyRuleType {
         match &*self {
             AuthorizationServerPolicyRuleType::ResourceAccess => "RESOURCE_ACCESS",
             AuthorizationServerPolicyRuleType::Noop => "",
-            AuthorizationServerPolicyRuleType::FallthroughString => "*",
+            AuthorizationServerPolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1442,7 +1508,7 @@ pub struct CatalogApplication {
     pub website: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChangePasswordRequest {
     #[serde(
         default,
@@ -1458,6 +1524,34 @@ pub struct ChangePasswordRequest {
     pub old_password: Option<PasswordCredential>,
 }
 
+impl ChangePasswordRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ChangePasswordRequestBuilder {
+        ChangePasswordRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ChangePasswordRequest`.
+#[derive(Debug, Clone)]
+pub struct ChangePasswordRequestBuilder(ChangePasswordRequest);
+
+impl ChangePasswordRequestBuilder {
+    pub fn new_password(mut self, new_password: impl Into<PasswordCredential>) -> Self {
+        self.0.new_password = Some(new_password.into());
+        self
+    }
+
+    pub fn old_password(mut self, old_password: impl Into<PasswordCredential>) -> Self {
+        self.0.old_password = Some(old_password.into());
+        self
+    }
+
+    pub fn build(self) -> ChangePasswordRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ClientPolicyCondition {
     #[serde(
@@ -1478,7 +1572,7 @@ pub struct ContextPolicyRuleCondition {
     pub expression: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateSessionRequest {
     #[serde(
         default,
@@ -1489,7 +1583,44 @@ pub struct CreateSessionRequest {
     pub session_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl std::convert::From<String> for CreateSessionRequest {
+    fn from(session_token: String) -> Self {
+        CreateSessionRequest { session_token }
+    }
+}
+
+impl std::convert::From<&str> for CreateSessionRequest {
+    fn from(session_token: &str) -> Self {
+        CreateSessionRequest {
+            session_token: session_token.to_string(),
+        }
+    }
+}
+
+impl CreateSessionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateSessionRequestBuilder {
+        CreateSessionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateSessionRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateSessionRequestBuilder(CreateSessionRequest);
+
+impl CreateSessionRequestBuilder {
+    pub fn session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.0.session_token = session_token.into();
+        self
+    }
+
+    pub fn build(self) -> CreateSessionRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateUserRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub credentials: Option<UserCredentials>,
@@ -1506,6 +1637,44 @@ pub struct CreateUserRequest {
     pub type_: Option<UserType>,
 }
 
+impl CreateUserRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateUserRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateUserRequestBuilder(CreateUserRequest);
+
+impl CreateUserRequestBuilder {
+    pub fn credentials(mut self, credentials: impl Into<UserCredentials>) -> Self {
+        self.0.credentials = Some(credentials.into());
+        self
+    }
+
+    pub fn group_ids(mut self, group_ids: Vec<String>) -> Self {
+        self.0.group_ids = group_ids;
+        self
+    }
+
+    pub fn profile(mut self, profile: impl Into<UserProfile>) -> Self {
+        self.0.profile = Some(profile.into());
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<UserType>) -> Self {
+        self.0.type_ = Some(type_.into());
+        self
+    }
+
+    pub fn build(self) -> CreateUserRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Csr {
     #[serde(
@@ -1663,8 +1832,8 @@ pub enum DnsRecordType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DnsRecordType {
@@ -1673,7 +1842,7 @@ impl std::fmt::Display for DnsRecordType {
             DnsRecordType::Cname => "CNAME",
             DnsRecordType::Txt => "TXT",
             DnsRecordType::Noop => "",
-            DnsRecordType::FallthroughString => "*",
+            DnsRecordType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1698,8 +1867,8 @@ pub enum TrustLevel {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrustLevel {
@@ -1708,7 +1877,7 @@ impl std::fmt::Display for TrustLevel {
             TrustLevel::Any => "ANY",
             TrustLevel::Trusted => "TRUSTED",
             TrustLevel::Noop => "",
-            TrustLevel::FallthroughString => "*",
+            TrustLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1757,8 +1926,8 @@ pub enum SupportedMdmFrameworks {
     Safe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SupportedMdmFrameworks {
@@ -1768,7 +1937,7 @@ impl std::fmt::Display for SupportedMdmFrameworks {
             SupportedMdmFrameworks::Native => "NATIVE",
             SupportedMdmFrameworks::Safe => "SAFE",
             SupportedMdmFrameworks::Noop => "",
-            SupportedMdmFrameworks::FallthroughString => "*",
+            SupportedMdmFrameworks::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1797,8 +1966,8 @@ pub enum Types {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Types {
@@ -1809,7 +1978,7 @@ impl std::fmt::Display for Types {
             Types::Osx => "OSX",
             Types::Windows => "WINDOWS",
             Types::Noop => "",
-            Types::FallthroughString => "*",
+            Types::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1938,8 +2107,8 @@ pub enum DomainCertificateSourceType {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateSourceType {
@@ -1947,7 +2116,7 @@ impl std::fmt::Display for DomainCertificateSourceType {
         match &*self {
             DomainCertificateSourceType::Manual => "MANUAL",
             DomainCertificateSourceType::Noop => "",
-            DomainCertificateSourceType::FallthroughString => "*",
+            DomainCertificateSourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1970,8 +2139,8 @@ pub enum DomainCertificateType {
     Pem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateType {
@@ -1979,7 +2148,7 @@ impl std::fmt::Display for DomainCertificateType {
         match &*self {
             DomainCertificateType::Pem => "PEM",
             DomainCertificateType::Noop => "",
-            DomainCertificateType::FallthroughString => "*",
+            DomainCertificateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2018,8 +2187,8 @@ pub enum DomainValidationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainValidationStatus {
@@ -2030,7 +2199,7 @@ impl std::fmt::Display for DomainValidationStatus {
             DomainValidationStatus::NotStarted => "NOT_STARTED",
             DomainValidationStatus::Verified => "VERIFIED",
             DomainValidationStatus::Noop => "",
-            DomainValidationStatus::FallthroughString => "*",
+            DomainValidationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2087,8 +2256,8 @@ pub enum EnabledStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnabledStatus {
@@ -2097,7 +2266,7 @@ impl std::fmt::Display for EnabledStatus {
             EnabledStatus::Disabled => "DISABLED",
             EnabledStatus::Enabled => "ENABLED",
             EnabledStatus::Noop => "",
-            EnabledStatus::FallthroughString => "*",
+            EnabledStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2122,8 +2291,8 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2132,7 +2301,7 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::Unverified => "UNVERIFIED",
             VerificationStatus::Verified => "VERIFIED",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2205,8 +2374,8 @@ pub enum EventHookChannelType {
     Http,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelType {
@@ -2214,7 +2383,7 @@ impl std::fmt::Display for EventHookChannelType {
         match &*self {
             EventHookChannelType::Http => "HTTP",
             EventHookChannelType::Noop => "",
-            EventHookChannelType::FallthroughString => "*",
+            EventHookChannelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2291,8 +2460,8 @@ pub enum EventHookChannelConfigAuthSchemeType {
     Header,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
@@ -2300,7 +2469,7 @@ impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
         match &*self {
             EventHookChannelConfigAuthSchemeType::Header => "HEADER",
             EventHookChannelConfigAuthSchemeType::Noop => "",
-            EventHookChannelConfigAuthSchemeType::FallthroughString => "*",
+            EventHookChannelConfigAuthSchemeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2341,8 +2510,8 @@ pub enum EventSubscriptionsType {
     FlowEvent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventSubscriptionsType {
@@ -2351,7 +2520,7 @@ impl std::fmt::Display for EventSubscriptionsType {
             EventSubscriptionsType::EventType => "EVENT_TYPE",
             EventSubscriptionsType::FlowEvent => "FLOW_EVENT",
             EventSubscriptionsType::Noop => "",
-            EventSubscriptionsType::FallthroughString => "*",
+            EventSubscriptionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2400,8 +2569,8 @@ pub enum FactorProvider {
     Yubico,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorProvider {
@@ -2416,7 +2585,7 @@ impl std::fmt::Display for FactorProvider {
             FactorProvider::Symantec => "SYMANTEC",
             FactorProvider::Yubico => "YUBICO",
             FactorProvider::Noop => "",
-            FactorProvider::FallthroughString => "*",
+            FactorProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2457,8 +2626,8 @@ pub enum FactorResultType {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResultType {
@@ -2475,7 +2644,7 @@ impl std::fmt::Display for FactorResultType {
             FactorResultType::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResultType::Waiting => "WAITING",
             FactorResultType::Noop => "",
-            FactorResultType::FallthroughString => "*",
+            FactorResultType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2510,8 +2679,8 @@ pub enum FactorStatus {
     PendingActivation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorStatus {
@@ -2525,7 +2694,7 @@ impl std::fmt::Display for FactorStatus {
             FactorStatus::NotSetup => "NOT_SETUP",
             FactorStatus::PendingActivation => "PENDING_ACTIVATION",
             FactorStatus::Noop => "",
-            FactorStatus::FallthroughString => "*",
+            FactorStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2572,8 +2741,8 @@ pub enum FactorType {
     Webauthn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorType {
@@ -2593,7 +2762,7 @@ impl std::fmt::Display for FactorType {
             FactorType::Web => "web",
             FactorType::Webauthn => "webauthn",
             FactorType::Noop => "",
-            FactorType::FallthroughString => "*",
+            FactorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2656,8 +2825,8 @@ pub enum FeatureStageState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageState {
@@ -2666,7 +2835,7 @@ impl std::fmt::Display for FeatureStageState {
             FeatureStageState::Closed => "CLOSED",
             FeatureStageState::Open => "OPEN",
             FeatureStageState::Noop => "",
-            FeatureStageState::FallthroughString => "*",
+            FeatureStageState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2691,8 +2860,8 @@ pub enum FeatureStageValue {
     Ea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageValue {
@@ -2701,7 +2870,7 @@ impl std::fmt::Display for FeatureStageValue {
             FeatureStageValue::Beta => "BETA",
             FeatureStageValue::Ea => "EA",
             FeatureStageValue::Noop => "",
-            FeatureStageValue::FallthroughString => "*",
+            FeatureStageValue::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2724,8 +2893,8 @@ pub enum FeatureType {
     SelfService,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureType {
@@ -2733,7 +2902,7 @@ impl std::fmt::Display for FeatureType {
         match &*self {
             FeatureType::SelfService => "self-service",
             FeatureType::Noop => "",
-            FeatureType::FallthroughString => "*",
+            FeatureType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2944,8 +3113,8 @@ pub enum GroupRuleStatus {
     Invalid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupRuleStatus {
@@ -2955,7 +3124,7 @@ impl std::fmt::Display for GroupRuleStatus {
             GroupRuleStatus::Inactive => "INACTIVE",
             GroupRuleStatus::Invalid => "INVALID",
             GroupRuleStatus::Noop => "",
-            GroupRuleStatus::FallthroughString => "*",
+            GroupRuleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2982,8 +3151,8 @@ pub enum GroupType {
     OktaGroup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupType {
@@ -2993,7 +3162,7 @@ impl std::fmt::Display for GroupType {
             GroupType::BuiltIn => "BUILT_IN",
             GroupType::OktaGroup => "OKTA_GROUP",
             GroupType::Noop => "",
-            GroupType::FallthroughString => "*",
+            GroupType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3035,8 +3204,8 @@ pub enum IdentityProviderIssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderIssuerMode {
@@ -3045,7 +3214,7 @@ impl std::fmt::Display for IdentityProviderIssuerMode {
             IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
             IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
             IdentityProviderIssuerMode::Noop => "",
-            IdentityProviderIssuerMode::FallthroughString => "*",
+            IdentityProviderIssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3086,8 +3255,8 @@ pub enum IdentityProviderType {
     X509,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderType {
@@ -3104,7 +3273,7 @@ impl std::fmt::Display for IdentityProviderType {
             IdentityProviderType::Saml2 => "SAML2",
             IdentityProviderType::X509 => "X509",
             IdentityProviderType::Noop => "",
-            IdentityProviderType::FallthroughString => "*",
+            IdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3248,8 +3417,8 @@ pub enum Revocation {
     Ocsp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Revocation {
@@ -3259,7 +3428,7 @@ impl std::fmt::Display for Revocation {
             Revocation::DeltaCrl => "DELTA_CRL",
             Revocation::Ocsp => "OCSP",
             Revocation::Noop => "",
-            Revocation::FallthroughString => "*",
+            Revocation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3338,8 +3507,8 @@ pub enum Provider {
     SpecificIdp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Provider {
@@ -3349,7 +3518,7 @@ impl std::fmt::Display for Provider {
             Provider::Okta => "OKTA",
             Provider::SpecificIdp => "SPECIFIC_IDP",
             Provider::Noop => "",
-            Provider::FallthroughString => "*",
+            Provider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3566,8 +3735,8 @@ pub enum InlineHookType {
     ComOktaUserPreRegistration,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InlineHookType {
@@ -3581,7 +3750,7 @@ impl std::fmt::Display for InlineHookType {
             }
             InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
             InlineHookType::Noop => "",
-            InlineHookType::FallthroughString => "*",
+            InlineHookType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3876,8 +4045,8 @@ pub enum LinkedObjectDetailsType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkedObjectDetailsType {
@@ -3885,7 +4054,7 @@ impl std::fmt::Display for LinkedObjectDetailsType {
         match &*self {
             LinkedObjectDetailsType::User => "USER",
             LinkedObjectDetailsType::Noop => "",
-            LinkedObjectDetailsType::FallthroughString => "*",
+            LinkedObjectDetailsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3995,8 +4164,8 @@ pub enum LogAuthenticationProvider {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogAuthenticationProvider {
@@ -4009,7 +4178,7 @@ impl std::fmt::Display for LogAuthenticationProvider {
             LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
             LogAuthenticationProvider::Social => "SOCIAL",
             LogAuthenticationProvider::Noop => "",
-            LogAuthenticationProvider::FallthroughString => "*",
+            LogAuthenticationProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4081,8 +4250,8 @@ pub enum LogCredentialProvider {
     Yubikey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialProvider {
@@ -4096,7 +4265,7 @@ impl std::fmt::Display for LogCredentialProvider {
             LogCredentialProvider::Symantec => "SYMANTEC",
             LogCredentialProvider::Yubikey => "YUBIKEY",
             LogCredentialProvider::Noop => "",
-            LogCredentialProvider::FallthroughString => "*",
+            LogCredentialProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4133,8 +4302,8 @@ pub enum LogCredentialType {
     Sms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialType {
@@ -4149,7 +4318,7 @@ impl std::fmt::Display for LogCredentialType {
             LogCredentialType::Password => "PASSWORD",
             LogCredentialType::Sms => "SMS",
             LogCredentialType::Noop => "",
-            LogCredentialType::FallthroughString => "*",
+            LogCredentialType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4359,7 +4528,7 @@ pub struct LogOutcome {
     pub result: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LogRequest {
     #[serde(
         default,
@@ -4370,6 +4539,29 @@ pub struct LogRequest {
     pub ip_chain: Vec<LogIpAddress>,
 }
 
+impl LogRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> LogRequestBuilder {
+        LogRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `LogRequest`.
+#[derive(Debug, Clone)]
+pub struct LogRequestBuilder(LogRequest);
+
+impl LogRequestBuilder {
+    pub fn ip_chain(mut self, ip_chain: Vec<LogIpAddress>) -> Self {
+        self.0.ip_chain = ip_chain;
+        self
+    }
+
+    pub fn build(self) -> LogRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LogSecurityContext {
     #[serde(
@@ -4418,8 +4610,8 @@ pub enum LogSeverity {
     Warn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogSeverity {
@@ -4430,7 +4622,7 @@ impl std::fmt::Display for LogSeverity {
             LogSeverity::Info => "INFO",
             LogSeverity::Warn => "WARN",
             LogSeverity::Noop => "",
-            LogSeverity::FallthroughString => "*",
+            LogSeverity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4534,8 +4726,8 @@ pub enum Enrollment {
     Omm,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Enrollment {
@@ -4544,7 +4736,7 @@ impl std::fmt::Display for Enrollment {
             Enrollment::AnyOrNone => "ANY_OR_NONE",
             Enrollment::Omm => "OMM",
             Enrollment::Noop => "",
-            Enrollment::FallthroughString => "*",
+            Enrollment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4666,8 +4858,8 @@ pub enum NetworkZoneAddressType {
     Range,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneAddressType {
@@ -4676,7 +4868,7 @@ impl std::fmt::Display for NetworkZoneAddressType {
             NetworkZoneAddressType::Cidr => "CIDR",
             NetworkZoneAddressType::Range => "RANGE",
             NetworkZoneAddressType::Noop => "",
-            NetworkZoneAddressType::FallthroughString => "*",
+            NetworkZoneAddressType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4717,8 +4909,8 @@ pub enum NetworkZoneType {
     Ip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneType {
@@ -4727,7 +4919,7 @@ impl std::fmt::Display for NetworkZoneType {
             NetworkZoneType::Dynamic => "DYNAMIC",
             NetworkZoneType::Ip => "IP",
             NetworkZoneType::Noop => "",
-            NetworkZoneType::FallthroughString => "*",
+            NetworkZoneType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4752,8 +4944,8 @@ pub enum NetworkZoneUsage {
     Policy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneUsage {
@@ -4762,7 +4954,7 @@ impl std::fmt::Display for NetworkZoneUsage {
             NetworkZoneUsage::Blocklist => "BLOCKLIST",
             NetworkZoneUsage::Policy => "POLICY",
             NetworkZoneUsage::Noop => "",
-            NetworkZoneUsage::FallthroughString => "*",
+            NetworkZoneUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4804,8 +4996,8 @@ pub enum ClaimType {
     Resource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClaimType {
@@ -4814,7 +5006,7 @@ impl std::fmt::Display for ClaimType {
             ClaimType::Identity => "IDENTITY",
             ClaimType::Resource => "RESOURCE",
             ClaimType::Noop => "",
-            ClaimType::FallthroughString => "*",
+            ClaimType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4843,8 +5035,8 @@ pub enum GroupFilterType {
     StartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupFilterType {
@@ -4855,7 +5047,7 @@ impl std::fmt::Display for GroupFilterType {
             GroupFilterType::Regex => "REGEX",
             GroupFilterType::StartsWith => "STARTS_WITH",
             GroupFilterType::Noop => "",
-            GroupFilterType::FallthroughString => "*",
+            GroupFilterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4882,8 +5074,8 @@ pub enum ValueType {
     System,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueType {
@@ -4893,7 +5085,7 @@ impl std::fmt::Display for ValueType {
             ValueType::Groups => "GROUPS",
             ValueType::System => "SYSTEM",
             ValueType::Noop => "",
-            ValueType::FallthroughString => "*",
+            ValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5003,8 +5195,8 @@ pub enum OAuth2RefreshTokenStatus {
     Revoked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2RefreshTokenStatus {
@@ -5013,7 +5205,7 @@ impl std::fmt::Display for OAuth2RefreshTokenStatus {
             OAuth2RefreshTokenStatus::Active => "ACTIVE",
             OAuth2RefreshTokenStatus::Revoked => "REVOKED",
             OAuth2RefreshTokenStatus::Noop => "",
-            OAuth2RefreshTokenStatus::FallthroughString => "*",
+            OAuth2RefreshTokenStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5104,8 +5296,8 @@ pub enum Consent {
     Required,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Consent {
@@ -5115,7 +5307,7 @@ impl std::fmt::Display for Consent {
             Consent::Implicit => "IMPLICIT",
             Consent::Required => "REQUIRED",
             Consent::Noop => "",
-            Consent::FallthroughString => "*",
+            Consent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5140,8 +5332,8 @@ pub enum MetadataPublish {
     NoClients,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MetadataPublish {
@@ -5150,7 +5342,7 @@ impl std::fmt::Display for MetadataPublish {
             MetadataPublish::AllClients => "ALL_CLIENTS",
             MetadataPublish::NoClients => "NO_CLIENTS",
             MetadataPublish::Noop => "",
-            MetadataPublish::FallthroughString => "*",
+            MetadataPublish::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5282,8 +5474,8 @@ pub enum OAuth2ScopeConsentGrantSource {
     EndUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
@@ -5292,7 +5484,7 @@ impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
             OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
             OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
             OAuth2ScopeConsentGrantSource::Noop => "",
-            OAuth2ScopeConsentGrantSource::FallthroughString => "*",
+            OAuth2ScopeConsentGrantSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5405,8 +5597,8 @@ pub enum OAuthEndpointAuthenticationMethod {
     PrivateKeyJwt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
@@ -5418,7 +5610,7 @@ impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
             OAuthEndpointAuthenticationMethod::None => "none",
             OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
             OAuthEndpointAuthenticationMethod::Noop => "",
-            OAuthEndpointAuthenticationMethod::FallthroughString => "*",
+            OAuthEndpointAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5449,8 +5641,8 @@ pub enum OAuthGrantType {
     RefreshToken,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthGrantType {
@@ -5462,7 +5654,7 @@ impl std::fmt::Display for OAuthGrantType {
             OAuthGrantType::Password => "password",
             OAuthGrantType::RefreshToken => "refresh_token",
             OAuthGrantType::Noop => "",
-            OAuthGrantType::FallthroughString => "*",
+            OAuthGrantType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5489,8 +5681,8 @@ pub enum OAuthResponseType {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthResponseType {
@@ -5500,7 +5692,7 @@ impl std::fmt::Display for OAuthResponseType {
             OAuthResponseType::IdToken => "id_token",
             OAuthResponseType::Token => "token",
             OAuthResponseType::Noop => "",
-            OAuthResponseType::FallthroughString => "*",
+            OAuthResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5571,8 +5763,8 @@ pub enum Access {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Access {
@@ -5581,7 +5773,7 @@ impl std::fmt::Display for Access {
             Access::Allow => "ALLOW",
             Access::Deny => "DENY",
             Access::Noop => "",
-            Access::FallthroughString => "*",
+            Access::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5608,8 +5800,8 @@ pub enum FactorPromptMode {
     Session,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorPromptMode {
@@ -5619,7 +5811,7 @@ impl std::fmt::Display for FactorPromptMode {
             FactorPromptMode::Device => "DEVICE",
             FactorPromptMode::Session => "SESSION",
             FactorPromptMode::Noop => "",
-            FactorPromptMode::FallthroughString => "*",
+            FactorPromptMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5711,8 +5903,8 @@ pub enum OpenConnectApplicationConsentMethod {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationConsentMethod {
@@ -5721,7 +5913,7 @@ impl std::fmt::Display for OpenConnectApplicationConsentMethod {
             OpenConnectApplicationConsentMethod::Required => "REQUIRED",
             OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
             OpenConnectApplicationConsentMethod::Noop => "",
-            OpenConnectApplicationConsentMethod::FallthroughString => "*",
+            OpenConnectApplicationConsentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5874,8 +6066,8 @@ pub enum OpenConnectApplicationType {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationType {
@@ -5886,7 +6078,7 @@ impl std::fmt::Display for OpenConnectApplicationType {
             OpenConnectApplicationType::Service => "service",
             OpenConnectApplicationType::Web => "web",
             OpenConnectApplicationType::Noop => "",
-            OpenConnectApplicationType::FallthroughString => "*",
+            OpenConnectApplicationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5911,8 +6103,8 @@ pub enum OpenConnectRefreshTokenRotationType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
@@ -5921,7 +6113,7 @@ impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
             OpenConnectRefreshTokenRotationType::Rotate => "rotate",
             OpenConnectRefreshTokenRotationType::Static => "static",
             OpenConnectRefreshTokenRotationType::Noop => "",
-            OpenConnectRefreshTokenRotationType::FallthroughString => "*",
+            OpenConnectRefreshTokenRotationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5998,8 +6190,8 @@ pub enum PasswordCredentialHashAlgorithm {
     Sha512,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordCredentialHashAlgorithm {
@@ -6011,7 +6203,7 @@ impl std::fmt::Display for PasswordCredentialHashAlgorithm {
             PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
             PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
             PasswordCredentialHashAlgorithm::Noop => "",
-            PasswordCredentialHashAlgorithm::FallthroughString => "*",
+            PasswordCredentialHashAlgorithm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6074,8 +6266,8 @@ pub enum PasswordPolicyAuthenticationProviderCondition {
     Okta,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
@@ -6086,7 +6278,7 @@ impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
             PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
             PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
             PasswordPolicyAuthenticationProviderCondition::Noop => "",
-            PasswordPolicyAuthenticationProviderCondition::FallthroughString => "*",
+            PasswordPolicyAuthenticationProviderCondition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6420,8 +6612,8 @@ pub enum PlatformConditionEvaluatorType {
     Other,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorType {
@@ -6432,7 +6624,7 @@ impl std::fmt::Display for PlatformConditionEvaluatorType {
             PlatformConditionEvaluatorType::Mobile => "MOBILE",
             PlatformConditionEvaluatorType::Other => "OTHER",
             PlatformConditionEvaluatorType::Noop => "",
-            PlatformConditionEvaluatorType::FallthroughString => "*",
+            PlatformConditionEvaluatorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6473,8 +6665,8 @@ pub enum PlatformConditionEvaluatorOperatingSystemType {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
@@ -6487,7 +6679,7 @@ impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
             PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
             PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
             PlatformConditionEvaluatorOperatingSystemType::Noop => "",
-            PlatformConditionEvaluatorOperatingSystemType::FallthroughString => "*",
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6526,8 +6718,8 @@ pub enum MatchType {
     Semver,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -6536,7 +6728,7 @@ impl std::fmt::Display for MatchType {
             MatchType::Expression => "EXPRESSION",
             MatchType::Semver => "SEMVER",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6645,8 +6837,8 @@ pub enum Action {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -6655,7 +6847,7 @@ impl std::fmt::Display for Action {
             Action::Auto => "AUTO",
             Action::Disabled => "DISABLED",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6694,8 +6886,8 @@ pub enum Connection {
     Zone,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Connection {
@@ -6704,7 +6896,7 @@ impl std::fmt::Display for Connection {
             Connection::Anywhere => "ANYWHERE",
             Connection::Zone => "ZONE",
             Connection::Noop => "",
-            Connection::FallthroughString => "*",
+            Connection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6755,8 +6947,8 @@ pub enum PolicyRuleType {
     SignOn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleType {
@@ -6765,7 +6957,7 @@ impl std::fmt::Display for PolicyRuleType {
             PolicyRuleType::Password => "PASSWORD",
             PolicyRuleType::SignOn => "SIGN_ON",
             PolicyRuleType::Noop => "",
-            PolicyRuleType::FallthroughString => "*",
+            PolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6872,8 +7064,8 @@ pub enum PolicyRuleActionsEnrollSelf {
     Never,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
@@ -6883,7 +7075,7 @@ impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
             PolicyRuleActionsEnrollSelf::Login => "LOGIN",
             PolicyRuleActionsEnrollSelf::Never => "NEVER",
             PolicyRuleActionsEnrollSelf::Noop => "",
-            PolicyRuleActionsEnrollSelf::FallthroughString => "*",
+            PolicyRuleActionsEnrollSelf::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6908,8 +7100,8 @@ pub enum AuthType {
     Radius,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthType {
@@ -6918,7 +7110,7 @@ impl std::fmt::Display for AuthType {
             AuthType::Any => "ANY",
             AuthType::Radius => "RADIUS",
             AuthType::Noop => "",
-            AuthType::FallthroughString => "*",
+            AuthType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7062,8 +7254,8 @@ pub enum PolicySubjectMatchType {
     UsernameOrEmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicySubjectMatchType {
@@ -7074,7 +7266,7 @@ impl std::fmt::Display for PolicySubjectMatchType {
             PolicySubjectMatchType::Username => "USERNAME",
             PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
             PolicySubjectMatchType::Noop => "",
-            PolicySubjectMatchType::FallthroughString => "*",
+            PolicySubjectMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7103,8 +7295,8 @@ pub enum PolicyType {
     Password,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyType {
@@ -7115,7 +7307,7 @@ impl std::fmt::Display for PolicyType {
             PolicyType::OktaSignOn => "OKTA_SIGN_ON",
             PolicyType::Password => "PASSWORD",
             PolicyType::Noop => "",
-            PolicyType::FallthroughString => "*",
+            PolicyType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7213,8 +7405,8 @@ pub enum ProtocolType {
     Saml2,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolType {
@@ -7225,7 +7417,7 @@ impl std::fmt::Display for ProtocolType {
             ProtocolType::Oidc => "OIDC",
             ProtocolType::Saml2 => "SAML2",
             ProtocolType::Noop => "",
-            ProtocolType::FallthroughString => "*",
+            ProtocolType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7290,8 +7482,8 @@ pub enum Scope {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Scope {
@@ -7303,7 +7495,7 @@ impl std::fmt::Display for Scope {
             Scope::Response => "RESPONSE",
             Scope::Token => "TOKEN",
             Scope::Noop => "",
-            Scope::FallthroughString => "*",
+            Scope::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7348,8 +7540,8 @@ pub enum Binding {
     HttpRedirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Binding {
@@ -7358,7 +7550,7 @@ impl std::fmt::Display for Binding {
             Binding::HttpPost => "HTTP-POST",
             Binding::HttpRedirect => "HTTP-REDIRECT",
             Binding::Noop => "",
-            Binding::FallthroughString => "*",
+            Binding::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7383,8 +7575,8 @@ pub enum ProtocolEndpointType {
     Org,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolEndpointType {
@@ -7393,7 +7585,7 @@ impl std::fmt::Display for ProtocolEndpointType {
             ProtocolEndpointType::Instance => "INSTANCE",
             ProtocolEndpointType::Org => "ORG",
             ProtocolEndpointType::Noop => "",
-            ProtocolEndpointType::FallthroughString => "*",
+            ProtocolEndpointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7464,8 +7656,8 @@ pub enum ProtocolRelayStateFormat {
     Opaque,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolRelayStateFormat {
@@ -7474,7 +7666,7 @@ impl std::fmt::Display for ProtocolRelayStateFormat {
             ProtocolRelayStateFormat::FromUrl => "FROM_URL",
             ProtocolRelayStateFormat::Opaque => "OPAQUE",
             ProtocolRelayStateFormat::Noop => "",
-            ProtocolRelayStateFormat::FallthroughString => "*",
+            ProtocolRelayStateFormat::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7512,8 +7704,8 @@ pub enum ProvisioningAction {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningAction {
@@ -7523,7 +7715,7 @@ impl std::fmt::Display for ProvisioningAction {
             ProvisioningAction::Callout => "CALLOUT",
             ProvisioningAction::Disabled => "DISABLED",
             ProvisioningAction::Noop => "",
-            ProvisioningAction::FallthroughString => "*",
+            ProvisioningAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7572,8 +7764,8 @@ pub enum ProvisioningDeprovisionedConditionAction {
     Reactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
@@ -7582,7 +7774,7 @@ impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
             ProvisioningDeprovisionedConditionAction::None => "NONE",
             ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
             ProvisioningDeprovisionedConditionAction::Noop => "",
-            ProvisioningDeprovisionedConditionAction::FallthroughString => "*",
+            ProvisioningDeprovisionedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7617,8 +7809,8 @@ pub enum ProvisioningGroupsAction {
     Sync,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningGroupsAction {
@@ -7629,7 +7821,7 @@ impl std::fmt::Display for ProvisioningGroupsAction {
             ProvisioningGroupsAction::None => "NONE",
             ProvisioningGroupsAction::Sync => "SYNC",
             ProvisioningGroupsAction::Noop => "",
-            ProvisioningGroupsAction::FallthroughString => "*",
+            ProvisioningGroupsAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7679,8 +7871,8 @@ pub enum ProvisioningSuspendedConditionAction {
     Unsuspend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningSuspendedConditionAction {
@@ -7689,7 +7881,7 @@ impl std::fmt::Display for ProvisioningSuspendedConditionAction {
             ProvisioningSuspendedConditionAction::None => "NONE",
             ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
             ProvisioningSuspendedConditionAction::Noop => "",
-            ProvisioningSuspendedConditionAction::FallthroughString => "*",
+            ProvisioningSuspendedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7867,8 +8059,8 @@ pub enum RoleAssignmentType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleAssignmentType {
@@ -7877,7 +8069,7 @@ impl std::fmt::Display for RoleAssignmentType {
             RoleAssignmentType::Group => "GROUP",
             RoleAssignmentType::User => "USER",
             RoleAssignmentType::Noop => "",
-            RoleAssignmentType::FallthroughString => "*",
+            RoleAssignmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7916,8 +8108,8 @@ pub enum RoleType {
     UserAdmin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleType {
@@ -7933,7 +8125,7 @@ impl std::fmt::Display for RoleType {
             RoleType::SuperAdmin => "SUPER_ADMIN",
             RoleType::UserAdmin => "USER_ADMIN",
             RoleType::Noop => "",
-            RoleType::FallthroughString => "*",
+            RoleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8197,8 +8389,8 @@ pub enum Value {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -8213,7 +8405,7 @@ impl std::fmt::Display for Value {
             Value::Pending => "PENDING",
             Value::Suspended => "SUSPENDED",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8280,8 +8472,8 @@ pub enum ScopeType {
     Redirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScopeType {
@@ -8290,7 +8482,7 @@ impl std::fmt::Display for ScopeType {
             ScopeType::Cors => "CORS",
             ScopeType::Redirect => "REDIRECT",
             ScopeType::Noop => "",
-            ScopeType::FallthroughString => "*",
+            ScopeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8505,8 +8697,8 @@ pub enum SessionAuthenticationMethod {
     Tel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionAuthenticationMethod {
@@ -8523,7 +8715,7 @@ impl std::fmt::Display for SessionAuthenticationMethod {
             SessionAuthenticationMethod::Swk => "swk",
             SessionAuthenticationMethod::Tel => "tel",
             SessionAuthenticationMethod::Noop => "",
-            SessionAuthenticationMethod::FallthroughString => "*",
+            SessionAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8566,8 +8758,8 @@ pub enum SessionIdentityProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionIdentityProviderType {
@@ -8579,7 +8771,7 @@ impl std::fmt::Display for SessionIdentityProviderType {
             SessionIdentityProviderType::Okta => "OKTA",
             SessionIdentityProviderType::Social => "SOCIAL",
             SessionIdentityProviderType::Noop => "",
-            SessionIdentityProviderType::FallthroughString => "*",
+            SessionIdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8606,8 +8798,8 @@ pub enum SessionStatus {
     MfaRequired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -8617,7 +8809,7 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::MfaEnroll => "MFA_ENROLL",
             SessionStatus::MfaRequired => "MFA_REQUIRED",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8711,8 +8903,8 @@ pub enum SmsTemplateType {
     SmsVerifyCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SmsTemplateType {
@@ -8720,7 +8912,7 @@ impl std::fmt::Display for SmsTemplateType {
         match &*self {
             SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
             SmsTemplateType::Noop => "",
-            SmsTemplateType::FallthroughString => "*",
+            SmsTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8762,8 +8954,8 @@ pub enum TokenType {
     Refresh,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -8772,7 +8964,7 @@ impl std::fmt::Display for TokenType {
             TokenType::Access => "ACCESS",
             TokenType::Refresh => "REFRESH",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9271,8 +9463,8 @@ pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     Suffix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
@@ -9284,7 +9476,7 @@ impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
             UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
             UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
             UserIdentifierConditionEvaluatorPatternMatchType::Noop => "",
-            UserIdentifierConditionEvaluatorPatternMatchType::FallthroughString => "*",
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9321,8 +9513,8 @@ pub enum UserIdentifierPolicyRuleConditionType {
     Identifier,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
@@ -9331,7 +9523,7 @@ impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
             UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
             UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
             UserIdentifierPolicyRuleConditionType::Noop => "",
-            UserIdentifierPolicyRuleConditionType::FallthroughString => "*",
+            UserIdentifierPolicyRuleConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9366,7 +9558,7 @@ pub struct UserIdentifierPolicyRuleCondition {
     pub type_: Option<UserIdentifierPolicyRuleConditionType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserIdentityProviderLinkRequest {
     #[serde(
         default,
@@ -9377,6 +9569,43 @@ pub struct UserIdentityProviderLinkRequest {
     pub external_id: String,
 }
 
+impl std::convert::From<String> for UserIdentityProviderLinkRequest {
+    fn from(external_id: String) -> Self {
+        UserIdentityProviderLinkRequest { external_id }
+    }
+}
+
+impl std::convert::From<&str> for UserIdentityProviderLinkRequest {
+    fn from(external_id: &str) -> Self {
+        UserIdentityProviderLinkRequest {
+            external_id: external_id.to_string(),
+        }
+    }
+}
+
+impl UserIdentityProviderLinkRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UserIdentityProviderLinkRequestBuilder {
+        UserIdentityProviderLinkRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UserIdentityProviderLinkRequest`.
+#[derive(Debug, Clone)]
+pub struct UserIdentityProviderLinkRequestBuilder(UserIdentityProviderLinkRequest);
+
+impl UserIdentityProviderLinkRequestBuilder {
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.0.external_id = external_id.into();
+        self
+    }
+
+    pub fn build(self) -> UserIdentityProviderLinkRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserLifecycleAttributePolicyRuleCondition {
     #[serde(
@@ -9401,8 +9630,8 @@ pub enum UserNextLogin {
     ChangePassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserNextLogin {
@@ -9410,7 +9639,7 @@ impl std::fmt::Display for UserNextLogin {
         match &*self {
             UserNextLogin::ChangePassword => "changePassword",
             UserNextLogin::Noop => "",
-            UserNextLogin::FallthroughString => "*",
+            UserNextLogin::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9989,8 +10218,8 @@ pub enum UserSchemaAttributeMasterType {
     ProfileMaster,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeMasterType {
@@ -10000,7 +10229,7 @@ impl std::fmt::Display for UserSchemaAttributeMasterType {
             UserSchemaAttributeMasterType::Override => "OVERRIDE",
             UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
             UserSchemaAttributeMasterType::Noop => "",
-            UserSchemaAttributeMasterType::FallthroughString => "*",
+            UserSchemaAttributeMasterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10047,8 +10276,8 @@ pub enum UserSchemaAttributeType {
     String,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeType {
@@ -10060,7 +10289,7 @@ impl std::fmt::Display for UserSchemaAttributeType {
             UserSchemaAttributeType::Number => "number",
             UserSchemaAttributeType::String => "string",
             UserSchemaAttributeType::Noop => "",
-            UserSchemaAttributeType::FallthroughString => "*",
+            UserSchemaAttributeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10305,8 +10534,8 @@ pub enum UserStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatus {
@@ -10321,7 +10550,7 @@ impl std::fmt::Display for UserStatus {
             UserStatus::Staged => "STAGED",
             UserStatus::Suspended => "SUSPENDED",
             UserStatus::Noop => "",
-            UserStatus::FallthroughString => "*",
+            UserStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10407,7 +10636,7 @@ pub struct UserType {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VerifyFactorRequest {
     #[serde(
         default,
@@ -10465,6 +10694,64 @@ pub struct VerifyFactorRequest {
     pub state_token: String,
 }
 
+impl VerifyFactorRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> VerifyFactorRequestBuilder {
+        VerifyFactorRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `VerifyFactorRequest`.
+#[derive(Debug, Clone)]
+pub struct VerifyFactorRequestBuilder(VerifyFactorRequest);
+
+impl VerifyFactorRequestBuilder {
+    pub fn activation_token(mut self, activation_token: impl Into<String>) -> Self {
+        self.0.activation_token = activation_token.into();
+        self
+    }
+
+    pub fn answer(mut self, answer: impl Into<String>) -> Self {
+        self.0.answer = answer.into();
+        self
+    }
+
+    pub fn attestation(mut self, attestation: impl Into<String>) -> Self {
+        self.0.attestation = attestation.into();
+        self
+    }
+
+    pub fn client_data(mut self, client_data: impl Into<String>) -> Self {
+        self.0.client_data = client_data.into();
+        self
+    }
+
+    pub fn next_pass_code(mut self, next_pass_code: impl Into<String>) -> Self {
+        self.0.next_pass_code = next_pass_code.into();
+        self
+    }
+
+    pub fn pass_code(mut self, pass_code: impl Into<String>) -> Self {
+        self.0.pass_code = pass_code.into();
+        self
+    }
+
+    pub fn registration_data(mut self, registration_data: impl Into<String>) -> Self {
+        self.0.registration_data = registration_data.into();
+        self
+    }
+
+    pub fn state_token(mut self, state_token: impl Into<String>) -> Self {
+        self.0.state_token = state_token.into();
+        self
+    }
+
+    pub fn build(self) -> VerifyFactorRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum FactorResult {
     #[serde(rename = "CHALLENGE")]
@@ -10489,8 +10776,8 @@ pub enum FactorResult {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResult {
@@ -10507,7 +10794,7 @@ impl std::fmt::Display for FactorResult {
             FactorResult::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResult::Waiting => "WAITING",
             FactorResult::Noop => "",
-            FactorResult::FallthroughString => "*",
+            FactorResult::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21,7 +21,11 @@ impl UserFactors {
     *
     * * `user_id: &str`
     */
-    pub async fn list_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+    pub async fn list_factors(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::UserFactor>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors",
             crate::progenitor_support::encode_path(user_id),
@@ -37,7 +41,11 @@ impl UserFactors {
     *
     * Enumerates all the enrolled factors for the specified user
     */
-    pub async fn list_all_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+    pub async fn list_all_factors(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::UserFactor>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors",
             crate::progenitor_support::encode_path(user_id),
@@ -63,34 +71,33 @@ impl UserFactors {
     */
     pub async fn enroll_factor(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         update_phone: bool,
         template_id: &str,
         token_lifetime_seconds: i64,
         activate: bool,
         body: &crate::types::UserFactor,
     ) -> Result<crate::types::UserFactor> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
         if !template_id.is_empty() {
-            query_args.push(("templateId".to_string(), template_id.to_string()));
+            query_args.push(("templateId", template_id.to_string()));
         }
         if token_lifetime_seconds > 0 {
-            query_args.push((
-                "tokenLifetimeSeconds".to_string(),
-                token_lifetime_seconds.to_string(),
-            ));
+            query_args.push(("tokenLifetimeSeconds", token_lifetime_seconds.to_string()));
         }
         if update_phone {
-            query_args.push(("updatePhone".to_string(), update_phone.to_string()));
+            query_args.push(("updatePhone", update_phone.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/factors?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/factors",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -109,8 +116,9 @@ impl UserFactors {
     */
     pub async fn list_supported_factors(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::UserFactor>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/catalog",
             crate::progenitor_support::encode_path(user_id),
@@ -128,8 +136,9 @@ impl UserFactors {
     */
     pub async fn list_all_supported_factors(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::UserFactor>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/catalog",
             crate::progenitor_support::encode_path(user_id),
@@ -149,8 +158,9 @@ impl UserFactors {
     */
     pub async fn list_supported_security_questions(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SecurityQuestion>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/questions",
             crate::progenitor_support::encode_path(user_id),
@@ -168,8 +178,9 @@ impl UserFactors {
     */
     pub async fn list_all_supported_security_questions(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SecurityQuestion>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/questions",
             crate::progenitor_support::encode_path(user_id),
@@ -190,9 +201,11 @@ impl UserFactors {
     */
     pub async fn get_factor(
         &self,
-        user_id: &str,
-        factor_id: &str,
+        user_id: impl AsRef<str>,
+        factor_id: impl AsRef<str>,
     ) -> Result<crate::types::UserFactor> {
+        let user_id = user_id.as_ref();
+        let factor_id = factor_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -212,7 +225,13 @@ impl UserFactors {
     * * `user_id: &str`
     * * `factor_id: &str`
     */
-    pub async fn delete_factor(&self, user_id: &str, factor_id: &str) -> Result<()> {
+    pub async fn delete_factor(
+        &self,
+        user_id: impl AsRef<str>,
+        factor_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let factor_id = factor_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -236,10 +255,12 @@ impl UserFactors {
     */
     pub async fn activate_factor(
         &self,
-        user_id: &str,
-        factor_id: &str,
+        user_id: impl AsRef<str>,
+        factor_id: impl AsRef<str>,
         body: &crate::types::ActivateFactorRequest,
     ) -> Result<crate::types::UserFactor> {
+        let user_id = user_id.as_ref();
+        let factor_id = factor_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/{}/lifecycle/activate",
             crate::progenitor_support::encode_path(user_id),
@@ -264,10 +285,13 @@ impl UserFactors {
     */
     pub async fn get_factor_transaction_status(
         &self,
-        user_id: &str,
-        factor_id: &str,
-        transaction_id: &str,
+        user_id: impl AsRef<str>,
+        factor_id: impl AsRef<str>,
+        transaction_id: impl AsRef<str>,
     ) -> Result<crate::types::VerifyUserFactorResponse> {
+        let user_id = user_id.as_ref();
+        let factor_id = factor_id.as_ref();
+        let transaction_id = transaction_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/factors/{}/transactions/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -297,28 +321,28 @@ impl UserFactors {
     */
     pub async fn verify_factor(
         &self,
-        user_id: &str,
-        factor_id: &str,
+        user_id: impl AsRef<str>,
+        factor_id: impl AsRef<str>,
         template_id: &str,
         token_lifetime_seconds: i64,
         body: &crate::types::VerifyFactorRequest,
     ) -> Result<crate::types::VerifyUserFactorResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let factor_id = factor_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !template_id.is_empty() {
-            query_args.push(("templateId".to_string(), template_id.to_string()));
+            query_args.push(("templateId", template_id.to_string()));
         }
         if token_lifetime_seconds > 0 {
-            query_args.push((
-                "tokenLifetimeSeconds".to_string(),
-                token_lifetime_seconds.to_string(),
-            ));
+            query_args.push(("tokenLifetimeSeconds", token_lifetime_seconds.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/factors/{}/verify?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(factor_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/factors/{}/verify",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(factor_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -23,7 +23,11 @@ impl UserSchemas {
     *
     * * `app_instance_id: &str`
     */
-    pub async fn get_application(&self, app_instance_id: &str) -> Result<crate::types::UserSchema> {
+    pub async fn get_application(
+        &self,
+        app_instance_id: impl AsRef<str>,
+    ) -> Result<crate::types::UserSchema> {
+        let app_instance_id = app_instance_id.as_ref();
         let url = format!(
             "/api/v1/meta/schemas/apps/{}/default",
             crate::progenitor_support::encode_path(app_instance_id),
@@ -45,9 +49,10 @@ impl UserSchemas {
     */
     pub async fn update_application_user_profile(
         &self,
-        app_instance_id: &str,
+        app_instance_id: impl AsRef<str>,
         body: &crate::types::UserSchema,
     ) -> Result<crate::types::UserSchema> {
+        let app_instance_id = app_instance_id.as_ref();
         let url = format!(
             "/api/v1/meta/schemas/apps/{}/default",
             crate::progenitor_support::encode_path(app_instance_id),
@@ -69,7 +74,8 @@ impl UserSchemas {
     *
     * * `schema_id: &str`
     */
-    pub async fn get(&self, schema_id: &str) -> Result<crate::types::UserSchema> {
+    pub async fn get(&self, schema_id: impl AsRef<str>) -> Result<crate::types::UserSchema> {
+        let schema_id = schema_id.as_ref();
         let url = format!(
             "/api/v1/meta/schemas/user/{}",
             crate::progenitor_support::encode_path(schema_id),
@@ -89,9 +95,10 @@ impl UserSchemas {
     */
     pub async fn update_user_profile(
         &self,
-        schema_id: &str,
+        schema_id: impl AsRef<str>,
         body: &crate::types::UserSchema,
     ) -> Result<crate::types::UserSchema> {
+        let schema_id = schema_id.as_ref();
         let url = format!(
             "/api/v1/meta/schemas/user/{}",
             crate::progenitor_support::encode_path(schema_id),
@@ -55,7 +55,8 @@ impl UserTypes {
     *
     * * `type_id: &str`
     */
-    pub async fn get(&self, type_id: &str) -> Result<crate::types::UserType> {
+    pub async fn get(&self, type_id: impl AsRef<str>) -> Result<crate::types::UserType> {
+        let type_id = type_id.as_ref();
         let url = format!(
             "/api/v1/meta/types/user/{}",
             crate::progenitor_support::encode_path(type_id),
@@ -75,9 +76,10 @@ impl UserTypes {
     */
     pub async fn replace(
         &self,
-        type_id: &str,
+        type_id: impl AsRef<str>,
         body: &crate::types::UserType,
     ) -> Result<crate::types::UserType> {
+        let type_id = type_id.as_ref();
         let url = format!(
             "/api/v1/meta/types/user/{}",
             crate::progenitor_support::encode_path(type_id),
@@ -99,9 +101,10 @@ impl UserTypes {
     */
     pub async fn update(
         &self,
-        type_id: &str,
+        type_id: impl AsRef<str>,
         body: &crate::types::UserType,
     ) -> Result<crate::types::UserType> {
+        let type_id = type_id.as_ref();
         let url = format!(
             "/api/v1/meta/types/user/{}",
             crate::progenitor_support::encode_path(type_id),
@@ -121,7 +124,8 @@ impl UserTypes {
     *
     * * `type_id: &str`
     */
-    pub async fn delete(&self, type_id: &str) -> Result<()> {
+    pub async fn delete(&self, type_id: impl AsRef<str>) -> Result<()> {
+        let type_id = type_id.as_ref();
         let url = format!(
             "/api/v1/meta/types/user/{}",
             crate::progenitor_support::encode_path(type_id),
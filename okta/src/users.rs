@@ -39,30 +39,29 @@ impl Users {
         sort_by: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
         if !sort_by.is_empty() {
-            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+            query_args.push(("sortBy", sort_by.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/users"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -84,24 +83,23 @@ impl Users {
         sort_by: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
         if !sort_by.is_empty() {
-            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+            query_args.push(("sortBy", sort_by.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/users"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -126,18 +124,17 @@ impl Users {
         next_login: &str,
         body: &crate::types::CreateUserRequest,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
         if !next_login.is_empty() {
-            query_args.push(("nextLogin".to_string(), next_login.to_string()));
+            query_args.push(("nextLogin", next_login.to_string()));
         }
         if provider {
-            query_args.push(("provider".to_string(), provider.to_string()));
+            query_args.push(("provider", provider.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api/v1/users"), &query_args);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -155,10 +152,13 @@ impl Users {
     */
     pub async fn set_linked_object_for(
         &self,
-        associated_user_id: &str,
-        primary_relationship_name: &str,
-        primary_user_id: &str,
+        associated_user_id: impl AsRef<str>,
+        primary_relationship_name: impl AsRef<str>,
+        primary_user_id: impl AsRef<str>,
     ) -> Result<()> {
+        let associated_user_id = associated_user_id.as_ref();
+        let primary_relationship_name = primary_relationship_name.as_ref();
+        let primary_user_id = primary_user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/linkedObjects/{}/{}",
             crate::progenitor_support::encode_path(associated_user_id),
@@ -180,7 +180,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn get(&self, user_id: &str) -> Result<crate::types::User> {
+    pub async fn get(&self, user_id: impl AsRef<str>) -> Result<crate::types::User> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -203,19 +204,21 @@ impl Users {
     */
     pub async fn update(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         strict: bool,
         body: &crate::types::User,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if strict {
-            query_args.push(("strict".to_string(), strict.to_string()));
+            query_args.push(("strict", strict.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -235,19 +238,21 @@ impl Users {
     */
     pub async fn partial_update(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         strict: bool,
         body: &crate::types::User,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if strict {
-            query_args.push(("strict".to_string(), strict.to_string()));
+            query_args.push(("strict", strict.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -267,16 +272,22 @@ impl Users {
     * * `user_id: &str`
     * * `send_email: bool`
     */
-    pub async fn deactivate_or_delete(&self, user_id: &str, send_email: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deactivate_or_delete(
+        &self,
+        user_id: impl AsRef<str>,
+        send_email: bool,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -293,7 +304,11 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn list_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
+    pub async fn list_app_links(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::AppLink>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/appLinks",
             crate::progenitor_support::encode_path(user_id),
@@ -311,7 +326,11 @@ impl Users {
     *
     * Fetches appLinks for all direct or indirect (via group membership) assigned applications.
     */
-    pub async fn list_all_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
+    pub async fn list_all_app_links(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::AppLink>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/appLinks",
             crate::progenitor_support::encode_path(user_id),
@@ -329,7 +348,11 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn list_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
+    pub async fn list_clients(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::OAuth2Client>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/clients",
             crate::progenitor_support::encode_path(user_id),
@@ -345,7 +368,11 @@ impl Users {
     *
     * Lists all client resources for which the specified user has grants or tokens.
     */
-    pub async fn list_all_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
+    pub async fn list_all_clients(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::OAuth2Client>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/clients",
             crate::progenitor_support::encode_path(user_id),
@@ -369,28 +396,31 @@ impl Users {
     */
     pub async fn list_grants_for_and_clients(
         &self,
-        user_id: &str,
-        client_id: &str,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
         expand: &str,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/grants?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(client_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/clients/{}/grants",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -405,20 +435,23 @@ impl Users {
     */
     pub async fn list_all_grants_for_and_clients(
         &self,
-        user_id: &str,
-        client_id: &str,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/grants?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(client_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/clients/{}/grants",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -434,7 +467,13 @@ impl Users {
     * * `user_id: &str`
     * * `client_id: &str`
     */
-    pub async fn revoke_grants_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
+    pub async fn revoke_grants_for_and_client(
+        &self,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/clients/{}/grants",
             crate::progenitor_support::encode_path(user_id),
@@ -459,28 +498,31 @@ impl Users {
     */
     pub async fn list_refresh_tokens_for_and_clients(
         &self,
-        user_id: &str,
-        client_id: &str,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
         expand: &str,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(client_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -495,20 +537,23 @@ impl Users {
     */
     pub async fn list_all_refresh_tokens_for_and_clients(
         &self,
-        user_id: &str,
-        client_id: &str,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(client_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -524,7 +569,13 @@ impl Users {
     * * `user_id: &str`
     * * `client_id: &str`
     */
-    pub async fn revoke_tokens_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
+    pub async fn revoke_tokens_for_and_client(
+        &self,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/clients/{}/tokens",
             crate::progenitor_support::encode_path(user_id),
@@ -550,30 +601,34 @@ impl Users {
     */
     pub async fn get_refresh_token_for_and_client(
         &self,
-        user_id: &str,
-        client_id: &str,
-        token_id: &str,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+        token_id: impl AsRef<str>,
         expand: &str,
         limit: i64,
         after: &str,
     ) -> Result<crate::types::OAuth2RefreshToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
+        let token_id = token_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/tokens/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(client_id),
-            crate::progenitor_support::encode_path(token_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/clients/{}/tokens/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -592,10 +647,13 @@ impl Users {
     */
     pub async fn revoke_token_for_and_client(
         &self,
-        user_id: &str,
-        client_id: &str,
-        token_id: &str,
+        user_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+        token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let client_id = client_id.as_ref();
+        let token_id = token_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/clients/{}/tokens/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -620,19 +678,21 @@ impl Users {
     */
     pub async fn change_password(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         strict: bool,
         body: &crate::types::ChangePasswordRequest,
     ) -> Result<crate::types::UserCredentials> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if strict {
-            query_args.push(("strict".to_string(), strict.to_string()));
+            query_args.push(("strict", strict.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/credentials/change_password?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/credentials/change_password",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -653,9 +713,10 @@ impl Users {
     */
     pub async fn change_recovery_question(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserCredentials,
     ) -> Result<crate::types::UserCredentials> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/credentials/change_recovery_question",
             crate::progenitor_support::encode_path(user_id),
@@ -677,8 +738,9 @@ impl Users {
     */
     pub async fn post_credentials_forgot_password(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::ResetPasswordToken> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/credentials/forgot_password",
             crate::progenitor_support::encode_path(user_id),
@@ -702,30 +764,32 @@ impl Users {
     */
     pub async fn list_grants(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         scope_id: &str,
         expand: &str,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !scope_id.is_empty() {
-            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+            query_args.push(("scopeId", scope_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/grants?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/grants",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -740,22 +804,24 @@ impl Users {
     */
     pub async fn list_all_grants(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         scope_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !scope_id.is_empty() {
-            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+            query_args.push(("scopeId", scope_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/grants?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/grants",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -770,7 +836,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn revoke_grants(&self, user_id: &str) -> Result<()> {
+    pub async fn revoke_grants(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/grants",
             crate::progenitor_support::encode_path(user_id),
@@ -792,20 +859,23 @@ impl Users {
     */
     pub async fn get_grant(
         &self,
-        user_id: &str,
-        grant_id: &str,
+        user_id: impl AsRef<str>,
+        grant_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let grant_id = grant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/grants/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(grant_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/grants/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(grant_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -821,7 +891,13 @@ impl Users {
     * * `user_id: &str`
     * * `grant_id: &str`
     */
-    pub async fn revoke_grant(&self, user_id: &str, grant_id: &str) -> Result<()> {
+    pub async fn revoke_grant(
+        &self,
+        user_id: impl AsRef<str>,
+        grant_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let grant_id = grant_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/grants/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -842,7 +918,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn list_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
+    pub async fn list_groups(&self, user_id: impl AsRef<str>) -> Result<Vec<crate::types::Group>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/groups",
             crate::progenitor_support::encode_path(user_id),
@@ -860,7 +937,11 @@ impl Users {
     *
     * Fetches the groups of which the user is a member.
     */
-    pub async fn list_all_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
+    pub async fn list_all_groups(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Group>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/groups",
             crate::progenitor_support::encode_path(user_id),
@@ -882,8 +963,9 @@ impl Users {
     */
     pub async fn list_identity_providers(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/idps",
             crate::progenitor_support::encode_path(user_id),
@@ -903,8 +985,9 @@ impl Users {
     */
     pub async fn list_all_identity_providers(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/idps",
             crate::progenitor_support::encode_path(user_id),
@@ -927,18 +1010,20 @@ impl Users {
     */
     pub async fn activate(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         send_email: bool,
     ) -> Result<crate::types::UserActivationToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/activate?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -956,16 +1041,18 @@ impl Users {
     * * `user_id: &str`
     * * `send_email: bool`
     */
-    pub async fn deactivate(&self, user_id: &str, send_email: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deactivate(&self, user_id: impl AsRef<str>, send_email: bool) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/deactivate?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -982,7 +1069,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn expire_password(&self, user_id: &str) -> Result<crate::types::User> {
+    pub async fn expire_password(&self, user_id: impl AsRef<str>) -> Result<crate::types::User> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/lifecycle/expire_password?tempPassword=false",
             crate::progenitor_support::encode_path(user_id),
@@ -1004,8 +1092,9 @@ impl Users {
     */
     pub async fn expire_password_and_get_temporary(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::TempPassword> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/lifecycle/expire_password?tempPassword=true",
             crate::progenitor_support::encode_path(user_id),
@@ -1028,18 +1117,20 @@ impl Users {
     */
     pub async fn reactivate(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         send_email: bool,
     ) -> Result<crate::types::UserActivationToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/reactivate?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/lifecycle/reactivate",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -1056,7 +1147,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn reset_factors(&self, user_id: &str) -> Result<()> {
+    pub async fn reset_factors(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/lifecycle/reset_factors",
             crate::progenitor_support::encode_path(user_id),
@@ -1079,18 +1171,20 @@ impl Users {
     */
     pub async fn reset_password(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         send_email: bool,
     ) -> Result<crate::types::ResetPasswordToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/reset_password?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/lifecycle/reset_password",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -1107,7 +1201,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn suspend(&self, user_id: &str) -> Result<()> {
+    pub async fn suspend(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/lifecycle/suspend",
             crate::progenitor_support::encode_path(user_id),
@@ -1127,7 +1222,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn unlock(&self, user_id: &str) -> Result<()> {
+    pub async fn unlock(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/lifecycle/unlock",
             crate::progenitor_support::encode_path(user_id),
@@ -1147,7 +1243,8 @@ impl Users {
     *
     * * `user_id: &str`
     */
-    pub async fn unsuspend(&self, user_id: &str) -> Result<()> {
+    pub async fn unsuspend(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/lifecycle/unsuspend",
             crate::progenitor_support::encode_path(user_id),
@@ -1170,24 +1267,27 @@ impl Users {
     */
     pub async fn get_linked_objects_fors(
         &self,
-        user_id: &str,
-        relationship_name: &str,
+        user_id: impl AsRef<str>,
+        relationship_name: impl AsRef<str>,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Links>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let relationship_name = relationship_name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/linkedObjects/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(relationship_name),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/linkedObjects/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(relationship_name),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1202,9 +1302,11 @@ impl Users {
     */
     pub async fn get_all_linked_objects_fors(
         &self,
-        user_id: &str,
-        relationship_name: &str,
+        user_id: impl AsRef<str>,
+        relationship_name: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Links>> {
+        let user_id = user_id.as_ref();
+        let relationship_name = relationship_name.as_ref();
         let url = format!(
             "/api/v1/users/{}/linkedObjects/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1226,9 +1328,11 @@ impl Users {
     */
     pub async fn remove_linked_object_for(
         &self,
-        user_id: &str,
-        relationship_name: &str,
+        user_id: impl AsRef<str>,
+        relationship_name: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let relationship_name = relationship_name.as_ref();
         let url = format!(
             "/api/v1/users/{}/linkedObjects/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1250,18 +1354,20 @@ impl Users {
     */
     pub async fn list_assigned_roles_fors(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/roles",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1276,18 +1382,20 @@ impl Users {
     */
     pub async fn list_all_assigned_roles_fors(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/roles",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1305,22 +1413,21 @@ impl Users {
     */
     pub async fn assign_role(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         disable_notifications: &str,
         body: &crate::types::AssignRoleRequest,
     ) -> Result<crate::types::Role> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !disable_notifications.is_empty() {
-            query_args.push((
-                "disableNotifications".to_string(),
-                disable_notifications.to_string(),
-            ));
+            query_args.push(("disableNotifications", disable_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/roles",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1338,7 +1445,13 @@ impl Users {
     * * `user_id: &str`
     * * `role_id: &str`
     */
-    pub async fn remove_role_from(&self, user_id: &str, role_id: &str) -> Result<()> {
+    pub async fn remove_role_from(
+        &self,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1362,24 +1475,27 @@ impl Users {
     */
     pub async fn list_application_targets_for_administrator_roles(
         &self,
-        user_id: &str,
-        role_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles/{}/targets/catalog/apps?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(role_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1394,9 +1510,11 @@ impl Users {
     */
     pub async fn list_all_application_targets_for_administrator_roles(
         &self,
-        user_id: &str,
-        role_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps",
             crate::progenitor_support::encode_path(user_id),
@@ -1416,7 +1534,13 @@ impl Users {
     * * `user_id: &str`
     * * `role_id: &str`
     */
-    pub async fn add_all_apps_as_target_role(&self, user_id: &str, role_id: &str) -> Result<()> {
+    pub async fn add_all_apps_as_target_role(
+        &self,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps",
             crate::progenitor_support::encode_path(user_id),
@@ -1439,10 +1563,13 @@ impl Users {
     */
     pub async fn add_application_target_admin_role_for(
         &self,
-        user_id: &str,
-        role_id: &str,
-        app_name: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1466,10 +1593,13 @@ impl Users {
     */
     pub async fn remove_application_target_from_administrator_role_for(
         &self,
-        user_id: &str,
-        role_id: &str,
-        app_name: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1496,11 +1626,15 @@ impl Users {
     */
     pub async fn add_application_target_app_admin_role_for(
         &self,
-        user_id: &str,
-        role_id: &str,
-        app_name: &str,
-        application_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
+        application_id: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
+        let application_id = application_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1528,11 +1662,15 @@ impl Users {
     */
     pub async fn remove_application_target_from_administrator_role_for_users(
         &self,
-        user_id: &str,
-        role_id: &str,
-        app_name: &str,
-        application_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        app_name: impl AsRef<str>,
+        application_id: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let app_name = app_name.as_ref();
+        let application_id = application_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1558,24 +1696,27 @@ impl Users {
     */
     pub async fn list_group_targets_for_roles(
         &self,
-        user_id: &str,
-        role_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles/{}/targets/groups?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(role_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1590,9 +1731,11 @@ impl Users {
     */
     pub async fn list_all_group_targets_for_roles(
         &self,
-        user_id: &str,
-        role_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Group>> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/groups",
             crate::progenitor_support::encode_path(user_id),
@@ -1615,10 +1758,13 @@ impl Users {
     */
     pub async fn add_group_target_role(
         &self,
-        user_id: &str,
-        role_id: &str,
-        group_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/groups/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1642,10 +1788,13 @@ impl Users {
     */
     pub async fn remove_group_target_from_role(
         &self,
-        user_id: &str,
-        role_id: &str,
-        group_id: &str,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        group_id: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let role_id = role_id.as_ref();
+        let group_id = group_id.as_ref();
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/groups/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1666,16 +1815,18 @@ impl Users {
     * * `user_id: &str`
     * * `oauth_tokens: bool` -- Revoke issued OpenID Connect and OAuth refresh and access tokens.
     */
-    pub async fn clear_sessions(&self, user_id: &str, oauth_tokens: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn clear_sessions(&self, user_id: impl AsRef<str>, oauth_tokens: bool) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if oauth_tokens {
-            query_args.push(("oauthTokens".to_string(), oauth_tokens.to_string()));
+            query_args.push(("oauthTokens", oauth_tokens.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/sessions?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/api/v1/users/{}/sessions",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
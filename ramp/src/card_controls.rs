@@ -0,0 +1,175 @@
+//! Control how cards can be used: their spending limits and categories, and
+//! whether they are suspended.
+//!
+//! https://docs.ramp.com/developer-api/v1/reference/rest/cards
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client, card_id: &str) -> anyhow::Result<()> {
+//! use std::time::Duration;
+//!
+//! use ramp_api::{cards::SpendingRestrictionsUpdate, types::Interval};
+//!
+//! let cards = ramp.cards();
+//! let restrictions = SpendingRestrictionsUpdate::new(500.0, Interval::Monthly)
+//!     .transaction_amount_limit(100.0)
+//!     .blocked_categories(&[7]);
+//! cards
+//!     .update_spending_restrictions(card_id, &restrictions)
+//!     .await?;
+//!
+//! let task = cards.suspend(card_id).await?;
+//! cards.wait_for_task(&task.id, Duration::from_secs(1)).await?;
+//! # Ok(())
+//! # }
+//! ```
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    cards::Cards,
+    types::{
+        GetResourcesCardsDeferredResponse, Interval, PostResourcesCardsCardSuspensionRequest,
+        Status, TaskResponse,
+    },
+};
+
+/**
+ * The spending restrictions of a card, replacing the ones it has.
+ *
+ * Categories are given by their Ramp category codes.
+ */
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SpendingRestrictionsUpdate {
+    amount: f64,
+    interval: Interval,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    categories: Vec<i64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    blocked_categories: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transaction_amount_limit: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_date: Option<DateTime<Utc>>,
+}
+
+impl SpendingRestrictionsUpdate {
+    /// Allow spending `amount` dollars per `interval`.
+    pub fn new(amount: f64, interval: Interval) -> Self {
+        SpendingRestrictionsUpdate {
+            amount,
+            interval,
+            categories: Vec::new(),
+            blocked_categories: Vec::new(),
+            transaction_amount_limit: None,
+            lock_date: None,
+        }
+    }
+
+    /// Only allow spending in these categories.
+    pub fn categories(mut self, categories: &[i64]) -> Self {
+        self.categories = categories.to_vec();
+        self
+    }
+
+    /// Decline the transactions in these categories.
+    pub fn blocked_categories(mut self, categories: &[i64]) -> Self {
+        self.blocked_categories = categories.to_vec();
+        self
+    }
+
+    /// Decline the transactions of more than `limit` dollars.
+    pub fn transaction_amount_limit(mut self, limit: f64) -> Self {
+        self.transaction_amount_limit = Some(limit);
+        self
+    }
+
+    /// Lock the card from this date on.
+    pub fn lock_date(mut self, lock_date: DateTime<Utc>) -> Self {
+        self.lock_date = Some(lock_date);
+        self
+    }
+}
+
+impl Cards {
+    /**
+     * Set the spending restrictions of a card.
+     *
+     * This function performs a `PATCH` to the `/cards/{id}` endpoint.
+     */
+    pub async fn update_spending_restrictions(
+        &self,
+        id: impl AsRef<str>,
+        restrictions: &SpendingRestrictionsUpdate,
+    ) -> Result<()> {
+        let id = id.as_ref();
+        let url = format!("/cards/{}", crate::progenitor_support::encode_path(id));
+        let body = serde_json::json!({ "spending_restrictions": restrictions });
+
+        self.client
+            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(&body)?)))
+            .await
+    }
+
+    /**
+     * Suspend a card so that it cannot be used until it is unsuspended.
+     *
+     * This function performs a `POST` to the `/cards/{id}/deferred/suspension`
+     * endpoint. The suspension happens in the background, as the returned task.
+     */
+    pub async fn suspend(&self, id: &str) -> Result<TaskResponse> {
+        self.post_resources_suspension(id, &new_task_request())
+            .await
+    }
+
+    /**
+     * Remove the suspension of a card.
+     *
+     * This function performs a `POST` to the `/cards/{id}/deferred/unsuspension`
+     * endpoint. The card can be used again once the returned task succeeded.
+     */
+    pub async fn unsuspend(&self, id: &str) -> Result<TaskResponse> {
+        self.post_resources_unsuspension(id, &new_task_request())
+            .await
+    }
+
+    /**
+     * Terminate a card for good.
+     *
+     * This function performs a `POST` to the `/cards/{id}/deferred/termination`
+     * endpoint.
+     */
+    pub async fn terminate(&self, id: &str) -> Result<TaskResponse> {
+        self.post_resources_termination(id, &new_task_request())
+            .await
+    }
+
+    /// Get the status of a deferred card task every `interval` until it succeeded
+    /// or failed.
+    pub async fn wait_for_task(
+        &self,
+        task_id: &str,
+        interval: Duration,
+    ) -> Result<GetResourcesCardsDeferredResponse> {
+        loop {
+            let task = self.get_resources_deferred(task_id).await?;
+            match task.status {
+                Some(Status::Success) => return Ok(task),
+                Some(Status::Error) => {
+                    let error = task.data.map(|d| d.error).unwrap_or_default();
+                    return Err(anyhow!("card task {} failed: {}", task_id, error));
+                }
+                _ => crate::runtime::sleep(interval).await,
+            }
+        }
+    }
+}
+
+/// A request for a deferred task, with a new idempotency key.
+fn new_task_request() -> PostResourcesCardsCardSuspensionRequest {
+    PostResourcesCardsCardSuspensionRequest {
+        idempotency_key: uuid::Uuid::new_v4().to_string(),
+    }
+}
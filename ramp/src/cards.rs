@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::card_controls::SpendingRestrictionsUpdate;
 use crate::Client;
 
 pub struct Cards {
@@ -34,21 +35,20 @@ impl Cards {
         user_id: &str,
         card_program_id: &str,
     ) -> Result<Vec<crate::types::Card>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card_program_id.is_empty() {
-            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+            query_args.push(("card_program_id", card_program_id.to_string()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/cards?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/cards"), &query_args);
 
         let resp: crate::types::GetCardsResponse = self.client.get(&url, None).await?;
 
@@ -56,6 +56,48 @@ impl Cards {
         Ok(resp.cards)
     }
 
+    /**
+    * List cards.
+    *
+    * This function performs a `GET` to the `/cards` endpoint.
+    *
+    * As opposed to `get_page`, this function also returns the `page` of the response, which holds the cursor for the next page.
+    *
+    * Retrieve all cards.
+    *
+    * **Parameters:**
+    *
+    * * `authorization: &str` -- The OAuth2 token header.
+    * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
+    * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    * * `user_id: &str` -- The OAuth2 token header.
+    * * `card_program_id: &str` -- The OAuth2 token header.
+    */
+    pub async fn get_page_with_cursor(
+        &self,
+        start: &str,
+        page_size: f64,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> Result<crate::types::GetCardsResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !card_program_id.is_empty() {
+            query_args.push(("card_program_id", card_program_id.to_string()));
+        }
+        if !page_size.to_string().is_empty() {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !start.is_empty() {
+            query_args.push(("start", start.to_string()));
+        }
+        if !user_id.is_empty() {
+            query_args.push(("user_id", user_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(format_args!("/cards"), &query_args);
+
+        self.client.get(&url, None).await
+    }
+
     /**
     * List cards.
     *
@@ -70,15 +112,14 @@ impl Cards {
         user_id: &str,
         card_program_id: &str,
     ) -> Result<Vec<crate::types::Card>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card_program_id.is_empty() {
-            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+            query_args.push(("card_program_id", card_program_id.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/cards?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/cards"), &query_args);
 
         let resp: crate::types::GetCardsResponse = self.client.get(&url, None).await?;
 
@@ -90,7 +131,7 @@ impl Cards {
             match self
                 .client
                 .get::<crate::types::GetCardsResponse>(
-                    page.trim_start_matches(crate::DEFAULT_HOST),
+                    page.trim_start_matches(self.client.host.as_str()),
                     None,
                 )
                 .await
@@ -129,11 +170,9 @@ impl Cards {
     *
     * * `authorization: &str` -- The OAuth2 token header.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Card> {
-        let url = format!(
-            "/cards/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::Card> {
+        let id = id.as_ref();
+        let url = format!("/cards/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
@@ -151,13 +190,11 @@ impl Cards {
     */
     pub async fn patch_resources(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PatchResourcesCardsCardRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/cards/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+        let id = id.as_ref();
+        let url = format!("/cards/{}", crate::progenitor_support::encode_path(id),);
 
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -215,9 +252,10 @@ impl Cards {
     */
     pub async fn post_resources_termination(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
     ) -> Result<crate::types::TaskResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/cards/{}/deferred/termination",
             crate::progenitor_support::encode_path(id),
@@ -237,9 +275,10 @@ impl Cards {
     */
     pub async fn post_resources_suspension(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
     ) -> Result<crate::types::TaskResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/cards/{}/deferred/suspension",
             crate::progenitor_support::encode_path(id),
@@ -259,9 +298,10 @@ impl Cards {
     */
     pub async fn post_resources_unsuspension(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
     ) -> Result<crate::types::TaskResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/cards/{}/deferred/unsuspension",
             crate::progenitor_support::encode_path(id),
@@ -285,8 +325,9 @@ impl Cards {
     */
     pub async fn get_resources_deferred(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::GetResourcesCardsDeferredResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/cards/deferred/status/{}",
             crate::progenitor_support::encode_path(id),
@@ -72,9 +72,11 @@ impl CustomIds {
     */
     pub async fn get_entity_type_custom_ramp(
         &self,
-        entity_type: &str,
-        custom_id: &str,
+        entity_type: impl AsRef<str>,
+        custom_id: impl AsRef<str>,
     ) -> Result<crate::types::GetEntityTypeCustomRampResponse> {
+        let entity_type = entity_type.as_ref();
+        let custom_id = custom_id.as_ref();
         let url = format!(
             "/custom-id-provider/{}/{}/ramp-id",
             crate::progenitor_support::encode_path(entity_type),
@@ -95,9 +97,11 @@ impl CustomIds {
     */
     pub async fn get_entity_type_ramp_custom(
         &self,
-        entity_type: &str,
-        ramp_id: &str,
+        entity_type: impl AsRef<str>,
+        ramp_id: impl AsRef<str>,
     ) -> Result<crate::types::GetEntityTypeRampCustomResponse> {
+        let entity_type = entity_type.as_ref();
+        let ramp_id = ramp_id.as_ref();
         let url = format!(
             "/custom-id-provider/{}/{}/custom-id",
             crate::progenitor_support::encode_path(entity_type),
@@ -116,9 +120,10 @@ impl CustomIds {
     */
     pub async fn post_custom_provider_entity_type_link(
         &self,
-        entity_type: &str,
+        entity_type: impl AsRef<str>,
         body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
     ) -> Result<()> {
+        let entity_type = entity_type.as_ref();
         let url = format!(
             "/custom-id-provider/{}/custom-id-link",
             crate::progenitor_support::encode_path(entity_type),
@@ -30,15 +30,14 @@ impl Departments {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::Department>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/departments?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/departments"), &query_args);
 
         let resp: crate::types::GetDepartmentsResponse = self.client.get(&url, None).await?;
 
@@ -46,6 +45,38 @@ impl Departments {
         Ok(resp.data)
     }
 
+    /**
+    * List departments.
+    *
+    * This function performs a `GET` to the `/departments` endpoint.
+    *
+    * As opposed to `get_page`, this function also returns the `page` of the response, which holds the cursor for the next page.
+    *
+    * Retrieve all departments.
+    *
+    * **Parameters:**
+    *
+    * * `authorization: &str` -- The OAuth2 token header.
+    * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
+    * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    */
+    pub async fn get_page_with_cursor(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<crate::types::GetDepartmentsResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !page_size.to_string().is_empty() {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !start.is_empty() {
+            query_args.push(("start", start.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(format_args!("/departments"), &query_args);
+
+        self.client.get(&url, None).await
+    }
+
     /**
     * List departments.
     *
@@ -67,7 +98,7 @@ impl Departments {
             match self
                 .client
                 .get::<crate::types::GetDepartmentsResponse>(
-                    page.trim_start_matches(crate::DEFAULT_HOST),
+                    page.trim_start_matches(self.client.host.as_str()),
                     None,
                 )
                 .await
@@ -123,7 +154,8 @@ impl Departments {
     *
     * * `authorization: &str` -- The OAuth2 token header.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Department> {
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::Department> {
+        let id = id.as_ref();
         let url = format!(
             "/departments/{}",
             crate::progenitor_support::encode_path(id),
@@ -141,9 +173,10 @@ impl Departments {
     */
     pub async fn patch(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PostLocationRequest,
     ) -> Result<crate::types::Department> {
+        let id = id.as_ref();
         let url = format!(
             "/departments/{}",
             crate::progenitor_support::encode_path(id),
@@ -0,0 +1,89 @@
+//! When a request fails, Ramp describes what went wrong in an error object. The
+//! error of the request is then an `ApiError`, which can be matched on with
+//! `downcast_ref`:
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client) -> anyhow::Result<()> {
+//! if let Err(err) = ramp.users().get("9b84d870-f348-43d6-baa4-77181d3cc0f9").await {
+//!     match err.downcast_ref::<ramp_api::error::ApiError>() {
+//!         // There is no such user.
+//!         Some(e) if e.status == reqwest::StatusCode::NOT_FOUND => {}
+//!         _ => return Err(err),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+/// An error object returned by Ramp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// The status code of the response.
+    pub status: reqwest::StatusCode,
+    /// The code of the error, for example `DEVELOPER_7001`.
+    pub error_code: String,
+    /// A description of the error.
+    pub message: String,
+    /// More about the error, such as the fields that did not validate.
+    pub additional_info: serde_json::Value,
+}
+
+/// The error object, which older endpoints return at the top level.
+#[derive(Deserialize)]
+struct ErrorObject {
+    #[serde(default)]
+    error_code: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    additional_info: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error_v2: Option<ErrorObject>,
+    #[serde(flatten)]
+    error: ErrorObject,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "code: {}", self.status)?;
+        if !self.error_code.is_empty() {
+            write!(f, ", {}", self.error_code)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Build the error for a response that failed, from the error object in its body
+/// if there is one.
+pub(crate) fn from_response(status: reqwest::StatusCode, body: &[u8]) -> anyhow::Error {
+    if body.is_empty() {
+        return anyhow!("code: {}, empty response", status);
+    }
+
+    let error = serde_json::from_slice::<ErrorBody>(body)
+        .ok()
+        .map(|b| b.error_v2.unwrap_or(b.error))
+        .filter(|e| !e.error_code.is_empty() || !e.message.is_empty());
+    match error {
+        Some(e) => anyhow!(ApiError {
+            status,
+            error_code: e.error_code,
+            message: e.message,
+            additional_info: e.additional_info,
+        }),
+        None => anyhow!(
+            "code: {}, error: {:?}",
+            status,
+            String::from_utf8_lossy(body),
+        ),
+    }
+}
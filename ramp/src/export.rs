@@ -0,0 +1,145 @@
+//! Turn transactions and their receipts into accounting entries, for exporting them
+//! to a ledger.
+//!
+//! An `AccountingEntry` only keeps what bookkeeping needs out of a transaction, in
+//! a shape that does not depend on Ramp, so that each ledger integration maps
+//! entries rather than the types of the API.
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client) -> anyhow::Result<()> {
+//! use ramp_api::export::export;
+//!
+//! let transactions = ramp
+//!     .transactions()
+//!     .get_all(
+//!         "", "", None, None, "", "", false, true, false, false, "", 0.0, 0.0, false,
+//!     )
+//!     .await?;
+//! let receipts = ramp.receipts().get_all(None, None, None, None).await?;
+//! for entry in export(&transactions, &receipts) {
+//!     println!("{}", serde_json::to_string(&entry)?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Data, Receipt};
+
+/// The category an entry is booked under.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct EntryCategory {
+    pub id: String,
+    pub name: String,
+}
+
+/// A document attached to an entry, like the receipt of the transaction.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct AttachmentRef {
+    pub id: String,
+    /// Where to download the document from. Empty when only its ID is known.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+}
+
+/// A transaction, as an entry of the books.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct AccountingEntry {
+    /// The ID of the transaction, to tell whether it was exported already.
+    pub id: String,
+    /// The day the transaction was made, in UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<NaiveDate>,
+    pub amount: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<EntryCategory>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub memo: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub merchant: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<AttachmentRef>,
+}
+
+impl AccountingEntry {
+    /**
+     * The entry of a transaction, with the given receipts attached.
+     *
+     * The category is the first accounting category of the transaction, synced
+     * from the accounting provider, or else the category Ramp gave it. The receipts
+     * of the transaction which are not given are attached without their URL.
+     */
+    pub fn from_transaction(transaction: &Data, receipts: &[&Receipt]) -> Self {
+        let category = match transaction.accounting_categories.first() {
+            Some(category) => Some(EntryCategory {
+                id: category.category_id.to_string(),
+                name: category.category_name.to_string(),
+            }),
+            None if !transaction.sk_category_name.is_empty() => Some(EntryCategory {
+                id: transaction.sk_category_id.to_string(),
+                name: transaction.sk_category_name.to_string(),
+            }),
+            None => None,
+        };
+
+        let mut attachments: Vec<AttachmentRef> = receipts
+            .iter()
+            .map(|receipt| AttachmentRef {
+                id: receipt.id.to_string(),
+                url: receipt.receipt_url.to_string(),
+            })
+            .collect();
+        for id in &transaction.receipts {
+            if !attachments.iter().any(|a| &a.id == id) {
+                attachments.push(AttachmentRef {
+                    id: id.to_string(),
+                    url: String::new(),
+                });
+            }
+        }
+
+        AccountingEntry {
+            id: transaction.id.to_string(),
+            date: transaction
+                .user_transaction_time
+                .map(|t| t.naive_local().date()),
+            amount: transaction.amount,
+            category,
+            memo: transaction.memo.to_string(),
+            merchant: transaction.merchant_name.to_string(),
+            attachments,
+        }
+    }
+}
+
+impl From<&Data> for AccountingEntry {
+    fn from(transaction: &Data) -> Self {
+        AccountingEntry::from_transaction(transaction, &[])
+    }
+}
+
+/// The entries of transactions, in the same order, with their receipts attached.
+/// Receipts are matched with transactions by their `transaction_id`.
+pub fn export(transactions: &[Data], receipts: &[Receipt]) -> Vec<AccountingEntry> {
+    let mut by_transaction: HashMap<&str, Vec<&Receipt>> = HashMap::new();
+    for receipt in receipts {
+        by_transaction
+            .entry(receipt.transaction_id.as_str())
+            .or_default()
+            .push(receipt);
+    }
+
+    transactions
+        .iter()
+        .map(|transaction| {
+            let receipts = by_transaction
+                .get(transaction.id.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            AccountingEntry::from_transaction(transaction, receipts)
+        })
+        .collect()
+}
@@ -0,0 +1,147 @@
+//! Idempotency keys for requests that create resources.
+//!
+//! Ramp applies a `POST` only once for a given idempotency key, so a request that
+//! timed out can be sent again without creating a second card or user. The default
+//! HTTP client of `Client` sends every `POST` with an `Idempotency-Key` header through
+//! `IdempotencyMiddleware`. The key is kept when the request is retried, which makes
+//! these requests safe to retry.
+//!
+//! To retry a call yourself, for example after a restart, pass the same key to one of
+//! the `_with_idempotency_key` functions.
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client, body: ramp_api::types::PostUsersDeferredRequest) -> anyhow::Result<()> {
+//! let key = uuid::Uuid::new_v4().to_string();
+//! // Calling this again with the same key does not send a second invite.
+//! ramp.users()
+//!     .post_deferred_with_idempotency_key(&body, &key)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use reqwest::{header::HeaderValue, Method, Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+
+use crate::{cards::Cards, users::Users};
+
+/// The header the idempotency key of a request is sent in.
+pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+/// Middleware adding an `Idempotency-Key` header to `POST` requests that do not
+/// have one.
+///
+/// The key is the `idempotency_key` field of the JSON body when it is set, otherwise
+/// a random UUID. It has to be added before the `RetryMiddleware` so that every
+/// attempt uses the same key.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdempotencyMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for IdempotencyMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if req.method() == Method::POST && !req.headers().contains_key(IDEMPOTENCY_KEY) {
+            let key =
+                body_idempotency_key(&req).unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+            if let Ok(value) = HeaderValue::from_str(&key) {
+                req.headers_mut().insert(IDEMPOTENCY_KEY, value);
+            }
+        }
+
+        next.run(req, extensions).await
+    }
+}
+
+/// The `idempotency_key` field of the JSON body of a request, if any.
+fn body_idempotency_key(req: &Request) -> Option<String> {
+    let body = req.body()?.as_bytes()?;
+    let value: serde_json::Value = serde_json::from_slice(body).ok()?;
+    value
+        .get("idempotency_key")?
+        .as_str()
+        .filter(|key| !key.is_empty())
+        .map(|key| key.to_string())
+}
+
+/// Serialize a request body with the given idempotency key.
+fn with_idempotency_key<T: serde::Serialize>(
+    body: &T,
+    idempotency_key: &str,
+) -> Result<reqwest::Body> {
+    let mut body = serde_json::to_value(body)?;
+    body["idempotency_key"] = serde_json::Value::String(idempotency_key.to_string());
+    Ok(reqwest::Body::from(serde_json::to_vec(&body)?))
+}
+
+impl Cards {
+    /**
+     * Create a physical card.
+     *
+     * This function performs a `POST` to the `/cards/deferred/physical` endpoint.
+     *
+     * As opposed to `post_resources_physical`, this function sends the request with the
+     * given idempotency key, so calling it again with the same key creates a single card.
+     */
+    pub async fn post_resources_physical_with_idempotency_key(
+        &self,
+        body: &crate::types::PostResourcesCardPhysicalRequest,
+        idempotency_key: &str,
+    ) -> Result<crate::types::TaskResponse> {
+        self.client
+            .post(
+                "/cards/deferred/physical",
+                Some(with_idempotency_key(body, idempotency_key)?),
+            )
+            .await
+    }
+
+    /**
+     * Create a virtual card.
+     *
+     * This function performs a `POST` to the `/cards/deferred/virtual` endpoint.
+     *
+     * As opposed to `post_resources_virtual`, this function sends the request with the
+     * given idempotency key, so calling it again with the same key creates a single card.
+     */
+    pub async fn post_resources_virtual_with_idempotency_key(
+        &self,
+        body: &crate::types::PostResourcesCardVirtualRequest,
+        idempotency_key: &str,
+    ) -> Result<crate::types::TaskResponse> {
+        self.client
+            .post(
+                "/cards/deferred/virtual",
+                Some(with_idempotency_key(body, idempotency_key)?),
+            )
+            .await
+    }
+}
+
+impl Users {
+    /**
+     * Invite a new user.
+     *
+     * This function performs a `POST` to the `/users/deferred` endpoint.
+     *
+     * As opposed to `post_deferred`, this function sends the request with the given
+     * idempotency key, so calling it again with the same key sends a single invite.
+     */
+    pub async fn post_deferred_with_idempotency_key(
+        &self,
+        body: &crate::types::PostUsersDeferredRequest,
+        idempotency_key: &str,
+    ) -> Result<crate::types::User> {
+        self.client
+            .post(
+                "/users/deferred",
+                Some(with_idempotency_key(body, idempotency_key)?),
+            )
+            .await
+    }
+}
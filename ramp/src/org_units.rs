@@ -0,0 +1,99 @@
+//! Manage the departments and locations of a business by name.
+//!
+//! Users are filtered and assigned by `department_id` and `location_id`; these
+//! helpers find those ids from the names shown in Ramp, and create, rename and
+//! delete departments and locations.
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client) -> anyhow::Result<()> {
+//! let engineering = match ramp.departments().find("Engineering").await? {
+//!     Some(department) => department,
+//!     None => ramp.departments().create("Engineering").await?,
+//! };
+//! let users = ramp.users().get_all(&engineering.id, "").await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+
+use crate::{
+    departments::Departments,
+    locations::Locations,
+    types::{Department, Location, PostLocationRequest},
+};
+
+impl Departments {
+    /// Get the department with the given name, compared case insensitively.
+    pub async fn find(&self, name: &str) -> Result<Option<Department>> {
+        Ok(self
+            .get_all()
+            .await?
+            .into_iter()
+            .find(|department| department.name.eq_ignore_ascii_case(name)))
+    }
+
+    /// Create a department with the given name.
+    pub async fn create(&self, name: &str) -> Result<Department> {
+        self.post(&named(name)).await
+    }
+
+    /// Change the name of a department.
+    pub async fn rename(&self, id: &str, name: &str) -> Result<Department> {
+        self.patch(id, &named(name)).await
+    }
+
+    /**
+     * Delete a department.
+     *
+     * This function performs a `DELETE` to the `/departments/{id}` endpoint.
+     */
+    pub async fn delete(&self, id: impl AsRef<str>) -> Result<()> {
+        let id = id.as_ref();
+        let url = format!(
+            "/departments/{}",
+            crate::progenitor_support::encode_path(id)
+        );
+
+        self.client.delete(&url, None).await
+    }
+}
+
+impl Locations {
+    /// Get the location with the given name, compared case insensitively.
+    pub async fn find(&self, name: &str) -> Result<Option<Location>> {
+        Ok(self
+            .get_all()
+            .await?
+            .into_iter()
+            .find(|location| location.name.eq_ignore_ascii_case(name)))
+    }
+
+    /// Create a location with the given name.
+    pub async fn create(&self, name: &str) -> Result<Location> {
+        self.post(&named(name)).await
+    }
+
+    /// Change the name of a location.
+    pub async fn rename(&self, id: &str, name: &str) -> Result<Location> {
+        self.patch(id, &named(name)).await
+    }
+
+    /**
+     * Delete a location.
+     *
+     * This function performs a `DELETE` to the `/locations/{id}` endpoint.
+     */
+    pub async fn delete(&self, id: impl AsRef<str>) -> Result<()> {
+        let id = id.as_ref();
+        let url = format!("/locations/{}", crate::progenitor_support::encode_path(id));
+
+        self.client.delete(&url, None).await
+    }
+}
+
+/// The body creating or renaming a department or location.
+fn named(name: &str) -> PostLocationRequest {
+    PostLocationRequest {
+        name: name.to_string(),
+    }
+}
@@ -0,0 +1,138 @@
+//! One page of the results of a list endpoint, with the cursor to the next page.
+//!
+//! The APIs paginate in different ways: with a `Link` header, with a cursor or a
+//! `nextPageToken` in the body of the response, or with page numbers. A `Page` holds
+//! the items of a page and where the next one is the same way for all of them.
+
+/// Where the next page of a list endpoint is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageCursor {
+    /// The URL of the next page.
+    Url(String),
+    /// The token or cursor to pass back to the list function to get the next page.
+    Token(String),
+}
+
+impl PageCursor {
+    /// The URL or the token of the next page.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PageCursor::Url(s) | PageCursor::Token(s) => s,
+        }
+    }
+}
+
+/// One page of the results of a list endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Where the next page is, or `None` if this is the last page.
+    pub next: Option<PageCursor>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, next: Option<PageCursor>) -> Self {
+        Page { items, next }
+    }
+
+    /// Whether there are no more pages after this one.
+    pub fn is_last(&self) -> bool {
+        self.next.is_none()
+    }
+
+    /// Convert the items of the page, keeping the cursor.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next: self.next,
+        }
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// A response that holds one page of items, along with the cursor to the next page.
+pub trait Paginated {
+    type Item;
+
+    fn into_page(self) -> Page<Self::Item>;
+}
+
+impl Paginated for crate::types::GetCardProgramsResponse {
+    type Item = crate::types::CardProgram;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.card_programs, next)
+    }
+}
+
+impl Paginated for crate::types::GetCardsResponse {
+    type Item = crate::types::Card;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.cards, next)
+    }
+}
+
+impl Paginated for crate::types::GetDepartmentsResponse {
+    type Item = crate::types::Department;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.data, next)
+    }
+}
+
+impl Paginated for crate::types::GetLocationResponse {
+    type Item = crate::types::Location;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.data, next)
+    }
+}
+
+impl Paginated for crate::types::GetReceiptsResponse {
+    type Item = crate::types::Receipt;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.data, next)
+    }
+}
+
+impl Paginated for crate::types::GetReimbursementsResponse {
+    type Item = crate::types::Reimbursement;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.data, next)
+    }
+}
+
+impl Paginated for crate::types::GetTransactionResponse {
+    type Item = crate::types::Data;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.data, next)
+    }
+}
+
+impl Paginated for crate::types::GetUsersResponse {
+    type Item = crate::types::User;
+
+    fn into_page(self) -> Page<Self::Item> {
+        let next = self.page.next_start().map(PageCursor::Token);
+        Page::new(self.data, next)
+    }
+}
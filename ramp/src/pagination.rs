@@ -0,0 +1,37 @@
+//! Helpers for paginating through list endpoints one page at a time.
+//!
+//! The `get_all` functions load every page into memory at once. To control how many
+//! requests are made and how much is held in memory, use the `_with_cursor` variant of
+//! a list function and pass the cursor of each page to the next call.
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client) -> anyhow::Result<()> {
+//! let mut start = String::new();
+//! loop {
+//!     let resp = ramp.users().get_page_with_cursor(&start, 100.0, "", "").await?;
+//!     for user in resp.data {
+//!         println!("{}", user.email);
+//!     }
+//!
+//!     match resp.page.next_start() {
+//!         Some(next) => start = next,
+//!         None => break,
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+impl crate::types::Page {
+    /// Returns the `start` cursor to pass to the next call, or `None` if this is the
+    /// last page.
+    ///
+    /// The API returns the next page as a full URL, the cursor is read from its
+    /// `start` query parameter.
+    pub fn next_start(&self) -> Option<String> {
+        let url = url::Url::parse(&self.next).ok()?;
+        url.query_pairs()
+            .find(|(key, _)| key == "start")
+            .map(|(_, value)| value.to_string())
+            .filter(|value| !value.is_empty())
+    }
+}
@@ -0,0 +1,41 @@
+//! Upload receipts and attach them to transactions.
+//!
+//! https://docs.ramp.com/developer-api/v1/reference/rest/receipts
+use anyhow::Result;
+
+use crate::receipts::Receipts;
+
+impl Receipts {
+    /**
+     * Upload a receipt.
+     *
+     * This function performs a `POST` to the `/receipts` endpoint.
+     *
+     * Uploads a receipt as a `multipart/form-data` request and attaches it to the
+     * transaction. A random idempotency key is sent with the upload.
+     *
+     * **Parameters:**
+     *
+     * * `transaction_id: &str` -- The ID of the transaction the receipt is for.
+     * * `filename: &str` -- The name of the file, for example `receipt.pdf`.
+     * * `bytes: Vec<u8>` -- The contents of the file.
+     * * `content_type: &str` -- The media type of the file, for example `application/pdf` or `image/png`.
+     */
+    pub async fn upload(
+        &self,
+        transaction_id: &str,
+        filename: &str,
+        bytes: Vec<u8>,
+        content_type: &str,
+    ) -> Result<crate::types::Receipt> {
+        let receipt = reqwest::multipart::Part::bytes(bytes)
+            .file_name(filename.to_string())
+            .mime_str(content_type)?;
+        let form = reqwest::multipart::Form::new()
+            .text("transaction_id", transaction_id.to_string())
+            .text("idempotency_key", uuid::Uuid::new_v4().to_string())
+            .part("receipt", receipt);
+
+        self.client.post_multipart("/receipts", form).await
+    }
+}
@@ -37,27 +37,26 @@ impl Receipts {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::Receipt>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = created_after {
-            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+            query_args.push(("created_after", date.to_rfc3339()));
         }
         if let Some(date) = created_before {
-            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+            query_args.push(("created_before", date.to_rfc3339()));
         }
         if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+            query_args.push(("from_date", date.to_rfc3339()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
         if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+            query_args.push(("to_date", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/receipts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/receipts"), &query_args);
 
         let resp: crate::types::GetReceiptsResponse = self.client.get(&url, None).await?;
 
@@ -65,6 +64,57 @@ impl Receipts {
         Ok(resp.data)
     }
 
+    /**
+    * List receipts.
+    *
+    * This function performs a `GET` to the `/receipts` endpoint.
+    *
+    * As opposed to `get_page`, this function also returns the `page` of the response, which holds the cursor for the next page.
+    *
+    * Returns description of all receipts of a business.
+    *
+    * **Parameters:**
+    *
+    * * `from_date: chrono::DateTime<chrono::Utc>` -- Filter for receipts related to transactions which occurred after the specified date.
+    * * `to_date: chrono::DateTime<chrono::Utc>` -- Filter for receipts related to transactions which occurred before the specified date.
+    * * `created_after: chrono::DateTime<chrono::Utc>` -- Filter for receipts that were created after the specified date.
+    * * `created_before: chrono::DateTime<chrono::Utc>` -- Filter for receipts that were created before the specified date.
+    * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
+    * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    */
+    pub async fn get_page_with_cursor(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        start: &str,
+        page_size: f64,
+    ) -> Result<crate::types::GetReceiptsResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(date) = created_after {
+            query_args.push(("created_after", date.to_rfc3339()));
+        }
+        if let Some(date) = created_before {
+            query_args.push(("created_before", date.to_rfc3339()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date", date.to_rfc3339()));
+        }
+        if !page_size.to_string().is_empty() {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !start.is_empty() {
+            query_args.push(("start", start.to_string()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date", date.to_rfc3339()));
+        }
+        let url = crate::progenitor_support::build_url(format_args!("/receipts"), &query_args);
+
+        self.client.get(&url, None).await
+    }
+
     /**
     * List receipts.
     *
@@ -81,21 +131,20 @@ impl Receipts {
         created_after: Option<chrono::DateTime<chrono::Utc>>,
         created_before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Receipt>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = created_after {
-            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+            query_args.push(("created_after", date.to_rfc3339()));
         }
         if let Some(date) = created_before {
-            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+            query_args.push(("created_before", date.to_rfc3339()));
         }
         if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+            query_args.push(("from_date", date.to_rfc3339()));
         }
         if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+            query_args.push(("to_date", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/receipts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/receipts"), &query_args);
 
         let resp: crate::types::GetReceiptsResponse = self.client.get(&url, None).await?;
 
@@ -107,7 +156,7 @@ impl Receipts {
             match self
                 .client
                 .get::<crate::types::GetReceiptsResponse>(
-                    page.trim_start_matches(crate::DEFAULT_HOST),
+                    page.trim_start_matches(self.client.host.as_str()),
                     None,
                 )
                 .await
@@ -142,11 +191,9 @@ impl Receipts {
     *
     *
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Receipt> {
-        let url = format!(
-            "/receipts/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::Receipt> {
+        let id = id.as_ref();
+        let url = format!("/receipts/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
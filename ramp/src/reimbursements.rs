@@ -27,15 +27,15 @@ impl Reimbursements {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::Reimbursement>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reimbursements?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/reimbursements"), &query_args);
 
         let resp: crate::types::GetReimbursementsResponse = self.client.get(&url, None).await?;
 
@@ -43,6 +43,36 @@ impl Reimbursements {
         Ok(resp.data)
     }
 
+    /**
+    * List Reimbursements.
+    *
+    * This function performs a `GET` to the `/reimbursements` endpoint.
+    *
+    * As opposed to `get_page`, this function also returns the `page` of the response, which holds the cursor for the next page.
+    *
+    * **Parameters:**
+    *
+    * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
+    * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    */
+    pub async fn get_page_with_cursor(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<crate::types::GetReimbursementsResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !page_size.to_string().is_empty() {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !start.is_empty() {
+            query_args.push(("start", start.to_string()));
+        }
+        let url =
+            crate::progenitor_support::build_url(format_args!("/reimbursements"), &query_args);
+
+        self.client.get(&url, None).await
+    }
+
     /**
     * List Reimbursements.
     *
@@ -62,7 +92,7 @@ impl Reimbursements {
             match self
                 .client
                 .get::<crate::types::GetReimbursementsResponse>(
-                    page.trim_start_matches(crate::DEFAULT_HOST),
+                    page.trim_start_matches(self.client.host.as_str()),
                     None,
                 )
                 .await
@@ -95,7 +125,8 @@ impl Reimbursements {
     *
     * This function performs a `GET` to the `/reimbursements/{id}` endpoint.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Reimbursement> {
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::Reimbursement> {
+        let id = id.as_ref();
         let url = format!(
             "/reimbursements/{}",
             crate::progenitor_support::encode_path(id),
@@ -0,0 +1,261 @@
+//! The statements of the business and the transactions on them, for monthly
+//! reconciliation.
+//!
+//! The business and its balance are available from `Client::businesses`, as
+//! `get` and `get_balance`.
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client) -> anyhow::Result<()> {
+//! let business = ramp.businesses().get().await?;
+//! for statement in ramp.statements().get_all("", "").await? {
+//!     let transactions = ramp.statements().get_all_transactions(&statement.id).await?;
+//!     let total: f64 = transactions.iter().map(|t| t.amount).sum();
+//!     println!(
+//!         "{} {}: {} transactions, {}",
+//!         business.business_name_legal,
+//!         statement.id,
+//!         transactions.len(),
+//!         total
+//!     );
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    businesses::Businesses,
+    types::{Business, BusinessCurrentStatus, Data, GetTransactionResponse, Page},
+    Client,
+};
+
+/// An amount of money.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct Amount {
+    #[serde(default)]
+    pub amount: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub currency_code: String,
+}
+
+/// A statement of the business.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Statement {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub opening_balance: Amount,
+    #[serde(default)]
+    pub ending_balance: Amount,
+    /// The total of the transactions over the period of the statement.
+    #[serde(default)]
+    pub charges: Amount,
+    #[serde(default)]
+    pub credits: Amount,
+    #[serde(default)]
+    pub payments: Amount,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetStatementsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Statement>,
+    #[serde(default)]
+    pub page: Page,
+}
+
+pub struct Statements {
+    pub client: Client,
+}
+
+impl Client {
+    /// Return a reference to an interface that provides access to the statements
+    /// operations.
+    pub fn statements(&self) -> Statements {
+        Statements::new(self.clone())
+    }
+}
+
+impl Statements {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Statements { client }
+    }
+
+    /**
+     * List statements.
+     *
+     * This function performs a `GET` to the `/statements` endpoint.
+     *
+     * **Parameters:**
+     *
+     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
+     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * * `from_date: &str` -- Only return the statements that end after this date, in ISO 8601 format.
+     * * `to_date: &str` -- Only return the statements that start before this date, in ISO 8601 format.
+     */
+    pub async fn get_page_with_cursor(
+        &self,
+        start: &str,
+        page_size: f64,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<GetStatementsResponse> {
+        let mut query_args = dates_query(from_date, to_date);
+        query_args.extend(page_query(start, page_size));
+        let url = crate::progenitor_support::build_url(format_args!("/statements"), &query_args);
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List statements.
+     *
+     * This function performs a `GET` to the `/statements` endpoint.
+     *
+     * As opposed to `get_page_with_cursor`, this function returns all the pages of
+     * the request at once.
+     */
+    pub async fn get_all(&self, from_date: &str, to_date: &str) -> Result<Vec<Statement>> {
+        let mut data = Vec::new();
+        let mut start = String::new();
+        loop {
+            let mut resp = self
+                .get_page_with_cursor(&start, 0.0, from_date, to_date)
+                .await?;
+            data.append(&mut resp.data);
+
+            match resp.page.next_start() {
+                Some(next) if next != start => start = next,
+                _ => return Ok(data),
+            }
+        }
+    }
+
+    /**
+     * GET a statement.
+     *
+     * This function performs a `GET` to the `/statements/{id}` endpoint.
+     */
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<Statement> {
+        let id = id.as_ref();
+        let url = format!("/statements/{}", crate::progenitor_support::encode_path(id));
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List the transactions on a statement.
+     *
+     * This function performs a `GET` to the `/statements/{id}/transactions` endpoint.
+     *
+     * **Parameters:**
+     *
+     * * `id: &str`
+     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
+     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     */
+    pub async fn get_transactions_page_with_cursor(
+        &self,
+        id: impl AsRef<str>,
+        start: &str,
+        page_size: f64,
+    ) -> Result<GetTransactionResponse> {
+        let id = id.as_ref();
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/statements/{}/transactions",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &page_query(start, page_size),
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List the transactions on a statement.
+     *
+     * This function performs a `GET` to the `/statements/{id}/transactions` endpoint.
+     *
+     * As opposed to `get_transactions_page_with_cursor`, this function returns all
+     * the pages of the request at once.
+     */
+    pub async fn get_all_transactions(&self, id: &str) -> Result<Vec<Data>> {
+        let mut data = Vec::new();
+        let mut start = String::new();
+        loop {
+            let mut resp = self
+                .get_transactions_page_with_cursor(id, &start, 0.0)
+                .await?;
+            data.append(&mut resp.data);
+
+            match resp.page.next_start() {
+                Some(next) if next != start => start = next,
+                _ => return Ok(data),
+            }
+        }
+    }
+}
+
+impl Businesses {
+    /**
+     * GET the business.
+     *
+     * This function performs a `GET` to the `/business` endpoint.
+     */
+    pub async fn get(&self) -> Result<Business> {
+        self.get_resources_busine().await
+    }
+
+    /**
+     * GET the current balance and limits of the business.
+     *
+     * This function performs a `GET` to the `/business/balance` endpoint.
+     */
+    pub async fn get_balance(&self) -> Result<BusinessCurrentStatus> {
+        self.get_resources_business_current().await
+    }
+}
+
+/// The query of a page, leaving out the default page size.
+fn page_query(start: &str, page_size: f64) -> Vec<(&'static str, String)> {
+    let mut query_args: Vec<(&str, String)> = Default::default();
+    if page_size > 0.0 {
+        query_args.push(("page_size", page_size.to_string()));
+    }
+    if !start.is_empty() {
+        query_args.push(("start", start.to_string()));
+    }
+    query_args
+}
+
+fn dates_query(from_date: &str, to_date: &str) -> Vec<(&'static str, String)> {
+    let mut query_args: Vec<(&str, String)> = Default::default();
+    if !from_date.is_empty() {
+        query_args.push(("from_date", from_date.to_string()));
+    }
+    if !to_date.is_empty() {
+        query_args.push(("to_date", to_date.to_string()));
+    }
+    query_args
+}
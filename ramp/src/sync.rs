@@ -0,0 +1,242 @@
+//! Incrementally pull the transactions that are new since the last sync.
+//!
+//! A sync pages through `/transactions` from the time of the most recent transaction
+//! it has seen, oldest first, and emits every transaction it has not seen before. The
+//! point it got to is saved in a user supplied `CheckpointStore` after every page, so
+//! that the next sync, even from another process, picks up where it left off.
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client) -> anyhow::Result<()> {
+//! use futures::TryStreamExt;
+//! use ramp_api::sync::MemoryCheckpointStore;
+//!
+//! let store = MemoryCheckpointStore::default();
+//! let mut transactions = Box::pin(ramp.transactions().sync(store).stream());
+//! while let Some(transaction) = transactions.try_next().await? {
+//!     println!("{} {}", transaction.id, transaction.amount);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The checkpoint of a page is saved when the stream is polled after the last
+//! transaction of that page, so a transaction is only skipped by the next sync
+//! once it has been handed out and the following one was asked for.
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    transaction_query::{TransactionOrder, TransactionQuery},
+    transactions::Transactions,
+    types::Data,
+    Client,
+};
+
+/// Number of transactions requested per page.
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// The point a sync of transactions got to.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct Checkpoint {
+    /// The time of the most recent transaction seen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_time: Option<DateTime<Utc>>,
+    /// The IDs of the transactions seen at `last_transaction_time`. The next sync
+    /// starts at that time again, so these are skipped rather than emitted twice.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub last_transaction_ids: Vec<String>,
+}
+
+impl Checkpoint {
+    /// Returns whether the transaction was already emitted by a previous sync.
+    fn contains(&self, transaction: &Data) -> bool {
+        let time = transaction
+            .user_transaction_time
+            .map(|t| t.with_timezone(&Utc));
+        match (time, self.last_transaction_time) {
+            (Some(time), Some(last)) => {
+                time < last || (time == last && self.last_transaction_ids.contains(&transaction.id))
+            }
+            _ => false,
+        }
+    }
+
+    /// Move the checkpoint past the given transaction.
+    fn advance(&mut self, transaction: &Data) {
+        let time = match transaction.user_transaction_time {
+            Some(time) => time.with_timezone(&Utc),
+            None => return,
+        };
+
+        if Some(time) > self.last_transaction_time {
+            self.last_transaction_time = Some(time);
+            self.last_transaction_ids.clear();
+        }
+        if Some(time) == self.last_transaction_time {
+            self.last_transaction_ids.push(transaction.id.to_string());
+        }
+    }
+}
+
+/// Persists the checkpoint of a sync between runs, for example in a database or a file.
+#[async_trait::async_trait]
+pub trait CheckpointStore: Send + Sync {
+    /// Load the last saved checkpoint, or `None` to sync all the transactions.
+    async fn load(&self) -> Result<Option<Checkpoint>>;
+
+    /// Save the checkpoint of a sync.
+    async fn save(&self, checkpoint: &Checkpoint) -> Result<()>;
+}
+
+/// A `CheckpointStore` that keeps the checkpoint in memory. Clones share the same
+/// checkpoint.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoint: Arc<Mutex<Option<Checkpoint>>>,
+}
+
+impl MemoryCheckpointStore {
+    /// The last saved checkpoint.
+    pub fn checkpoint(&self) -> Option<Checkpoint> {
+        self.checkpoint.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckpointStore for MemoryCheckpointStore {
+    async fn load(&self) -> Result<Option<Checkpoint>> {
+        Ok(self.checkpoint())
+    }
+
+    async fn save(&self, checkpoint: &Checkpoint) -> Result<()> {
+        *self.checkpoint.lock().unwrap() = Some(checkpoint.clone());
+        Ok(())
+    }
+}
+
+/// An incremental sync of transactions. Created with `Transactions::sync`.
+pub struct TransactionSync<S> {
+    client: Client,
+    store: S,
+    page_size: u32,
+}
+
+impl Transactions {
+    /// Start a sync of the transactions that are new since the checkpoint in `store`.
+    pub fn sync<S: CheckpointStore>(&self, store: S) -> TransactionSync<S> {
+        TransactionSync {
+            client: self.client.clone(),
+            store,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+/// The state carried between the pages of a sync.
+struct SyncState<S> {
+    sync: TransactionSync<S>,
+    /// The checkpoint the sync started from.
+    from: Checkpoint,
+    /// The checkpoint of the transactions handed out so far.
+    checkpoint: Checkpoint,
+    /// Whether `checkpoint` moved since it was last saved.
+    dirty: bool,
+    /// The `start` cursor of the next page, `None` once the last page was fetched.
+    next: Option<String>,
+    pending: VecDeque<Data>,
+    seen: HashSet<String>,
+    loaded: bool,
+}
+
+impl<S: CheckpointStore> TransactionSync<S> {
+    /// Set the number of transactions requested per page.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Returns the new transactions, oldest first, saving the checkpoint after
+    /// every page.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<Data>> {
+        let state = SyncState {
+            sync: self,
+            from: Checkpoint::default(),
+            checkpoint: Checkpoint::default(),
+            dirty: false,
+            next: Some(String::new()),
+            pending: VecDeque::new(),
+            seen: HashSet::new(),
+            loaded: false,
+        };
+
+        futures::stream::try_unfold(state, |mut state| async move {
+            if !state.loaded {
+                if let Some(checkpoint) = state.sync.store.load().await? {
+                    state.from = checkpoint.clone();
+                    state.checkpoint = checkpoint;
+                }
+                state.loaded = true;
+            }
+
+            loop {
+                if let Some(transaction) = state.pending.pop_front() {
+                    state.checkpoint.advance(&transaction);
+                    state.dirty = true;
+                    return Ok(Some((transaction, state)));
+                }
+
+                // Everything fetched so far was handed out, so it is safe to save.
+                if state.dirty {
+                    state.sync.store.save(&state.checkpoint).await?;
+                    state.dirty = false;
+                }
+
+                let start = match state.next.take() {
+                    Some(start) => start,
+                    None => return Ok(None),
+                };
+                let resp = state
+                    .sync
+                    .page(state.from.last_transaction_time, &start)
+                    .await?;
+                state.next = resp.page.next_start();
+
+                for transaction in resp.data {
+                    if state.from.contains(&transaction)
+                        || !state.seen.insert(transaction.id.to_string())
+                    {
+                        continue;
+                    }
+                    state.pending.push_back(transaction);
+                }
+            }
+        })
+    }
+
+    /// Get a page of transactions from `from_date` on, oldest first.
+    ///
+    /// This builds the request rather than using `Transactions::get_page_with_cursor`,
+    /// which always sends the amount filters.
+    async fn page(
+        &self,
+        from_date: Option<DateTime<Utc>>,
+        start: &str,
+    ) -> Result<crate::types::GetTransactionResponse> {
+        let mut query = TransactionQuery::new()
+            .order_by(TransactionOrder::DateAsc)
+            .page_size(self.page_size)
+            .start(start);
+        if let Some(date) = from_date {
+            query = query.from_date(date);
+        }
+
+        self.client.transactions().query_page(&query).await
+    }
+}
@@ -47,3 +47,1109 @@ fn test_deserialize() {
     println!("string_user_request = {}", s);
     assert!(true, "{}", s.contains("BUSINESS_USER"));
 }
+
+#[tokio::test]
+async fn test_mock_transport() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let user = ramp
+        .users()
+        .get("9b84d870-f348-43d6-baa4-77181d3cc0f9")
+        .await
+        .unwrap();
+    assert_eq!(user.email, "thing@example.com");
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, reqwest::Method::GET);
+    assert_eq!(
+        requests[0].url.path(),
+        "/developer/v1/users/9b84d870-f348-43d6-baa4-77181d3cc0f9"
+    );
+    assert_eq!(
+        requests[0].headers[http::header::AUTHORIZATION],
+        "Bearer token"
+    );
+
+    // Nothing else is queued, so the next request fails.
+    assert!(ramp.users().get("other").await.is_err());
+}
+
+#[tokio::test]
+async fn test_request_options() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+
+    let mut options = crate::request_options::RequestOptions::default();
+    let request_id = reqwest::header::HeaderValue::from_static("1234");
+    options.headers.insert("x-request-id", request_id);
+    ramp.with_request_options(options)
+        .users()
+        .get("9b84d870-f348-43d6-baa4-77181d3cc0f9")
+        .await
+        .unwrap();
+    assert_eq!(mock.requests()[0].headers["x-request-id"], "1234");
+
+    // A cancelled token aborts the request before it is sent.
+    let token = crate::request_options::CancellationToken::new();
+    token.cancel();
+    let cancelled = ramp.with_request_options(crate::request_options::RequestOptions {
+        cancel_token: Some(token),
+        ..Default::default()
+    });
+    assert!(cancelled.users().get("other").await.is_err());
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_client() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+
+    let ramp = crate::blocking::Client::new(
+        crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+            .with_transport(mock.clone()),
+    )
+    .unwrap();
+    let user = ramp
+        .block_on(ramp.users().get("9b84d870-f348-43d6-baa4-77181d3cc0f9"))
+        .unwrap();
+    assert_eq!(user.email, "thing@example.com");
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn test_page_next_start() {
+    let resp: crate::types::GetUsersResponse = serde_json::from_str(&format!(
+        r#"{{"data": [{}], "page": {{"next": "https://api.ramp.com/developer/v1/users?page_size=1&start=9b84d870-f348-43d6-baa4-77181d3cc0f9"}}}}"#,
+        USER
+    ))
+    .unwrap();
+    assert_eq!(resp.data.len(), 1);
+    assert_eq!(
+        resp.page.next_start().as_deref(),
+        Some("9b84d870-f348-43d6-baa4-77181d3cc0f9")
+    );
+
+    let last: crate::types::GetUsersResponse =
+        serde_json::from_str(r#"{"data": [], "page": {"next": null}}"#).unwrap();
+    assert_eq!(last.page.next_start(), None);
+}
+
+#[test]
+fn test_paginated() {
+    use crate::page::{PageCursor, Paginated};
+
+    let resp: crate::types::GetUsersResponse = serde_json::from_str(
+        r#"{"data": [{"id": "u-1"}], "page": {"next": "https://api.ramp.com/developer/v1/users?page_size=2&start=u-1"}}"#,
+    )
+    .unwrap();
+    let page = resp.into_page();
+    assert_eq!(page.items[0].id, "u-1");
+    assert_eq!(page.next, Some(PageCursor::Token("u-1".to_string())));
+
+    let resp: crate::types::GetCardsResponse =
+        serde_json::from_str(r#"{"cards": [], "page": {"next": null}}"#).unwrap();
+    assert!(resp.into_page().is_last());
+}
+
+#[test]
+fn test_append_query() {
+    use crate::progenitor_support::append_query;
+
+    assert_eq!(
+        append_query("/users", &[("start", "a&b=c d")]),
+        "/users?start=a%26b%3Dc+d"
+    );
+    assert_eq!(
+        append_query("/users?page_size=1", &[("start", "9b84")]),
+        "/users?page_size=1&start=9b84"
+    );
+    assert_eq!(
+        append_query("/users?", &[("start", "9b84")]),
+        "/users?start=9b84"
+    );
+}
+
+#[tokio::test]
+async fn test_transaction_sync() {
+    use futures::TryStreamExt;
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [
+            {"id": "t1", "amount": 1.5, "card_holder": {}, "user_transaction_time": "2021-08-05T10:00:00+00:00"},
+            {"id": "t2", "amount": 2.5, "card_holder": {}, "user_transaction_time": "2021-08-06T10:00:00+00:00"}
+        ], "page": {"next": "https://api.ramp.com/developer/v1/transactions?start=t2"}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [
+            {"id": "t2", "amount": 2.5, "card_holder": {}, "user_transaction_time": "2021-08-06T10:00:00+00:00"},
+            {"id": "t3", "amount": 3.5, "card_holder": {}, "user_transaction_time": "2021-08-06T10:00:00+00:00"}
+        ], "page": {"next": null}}"#,
+    );
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let store = crate::sync::MemoryCheckpointStore::default();
+    let transactions: Vec<_> = ramp
+        .transactions()
+        .sync(store.clone())
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<_> = transactions.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["t1", "t2", "t3"]);
+
+    let checkpoint = store.checkpoint().unwrap();
+    assert_eq!(
+        checkpoint.last_transaction_time.unwrap().to_rfc3339(),
+        "2021-08-06T10:00:00+00:00"
+    );
+    assert_eq!(checkpoint.last_transaction_ids, vec!["t2", "t3"]);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1]
+            .url
+            .query_pairs()
+            .find(|(k, _)| k == "start")
+            .unwrap()
+            .1,
+        "t2"
+    );
+
+    // The next sync starts from the checkpoint and skips what was already emitted.
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [
+            {"id": "t3", "amount": 3.5, "card_holder": {}, "user_transaction_time": "2021-08-06T10:00:00+00:00"},
+            {"id": "t4", "amount": 4.5, "card_holder": {}, "user_transaction_time": "2021-08-07T10:00:00+00:00"}
+        ], "page": {"next": null}}"#,
+    );
+    let transactions: Vec<_> = ramp
+        .transactions()
+        .sync(store.clone())
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].id, "t4");
+    assert!(mock.requests()[2]
+        .url
+        .query_pairs()
+        .any(|(k, v)| k == "from_date" && v == "2021-08-06T10:00:00+00:00"));
+}
+
+#[tokio::test]
+async fn test_transaction_query() {
+    use crate::transactions::{TransactionOrder, TransactionQuery};
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"id": "t1", "amount": 1.5, "card_holder": {}}],
+            "page": {"next": "https://api.ramp.com/developer/v1/transactions?start=t1"}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"id": "t2", "amount": 2.5, "card_holder": {}}], "page": {"next": null}}"#,
+    );
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let department_id = "d-1".to_string();
+    let query = TransactionQuery::new()
+        .department(&department_id)
+        .order_by(TransactionOrder::AmountDesc)
+        .requires_memo();
+    let transactions = ramp.transactions().query_all(&query).await.unwrap();
+    let ids: Vec<_> = transactions.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["t1", "t2"]);
+
+    // Only the filters that are set are sent, and the next page keeps them.
+    let requests = mock.requests();
+    assert_eq!(
+        requests[0].url.query(),
+        Some("department_id=d-1&order_by_amount_desc=true&requires_memo=true")
+    );
+    assert_eq!(
+        requests[1].url.query(),
+        Some("department_id=d-1&order_by_amount_desc=true&requires_memo=true&start=t1")
+    );
+}
+
+#[tokio::test]
+async fn test_receipt_upload() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "2fae6f28-8872-4f9b-bae8-7f588125d72a", "transaction_id": "t1"}"#,
+    );
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let receipt = ramp
+        .receipts()
+        .upload("t1", "receipt.pdf", b"%PDF-1.4".to_vec(), "application/pdf")
+        .await
+        .unwrap();
+    assert_eq!(receipt.transaction_id, "t1");
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].method, reqwest::Method::POST);
+    assert_eq!(requests[0].url.path(), "/developer/v1/receipts");
+    assert!(requests[0].headers[http::header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .starts_with("multipart/form-data; boundary="));
+}
+
+#[tokio::test]
+async fn test_idempotency_key() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+    mock.push_response(reqwest::StatusCode::OK, USER);
+
+    let http = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+        .with(crate::idempotency::IdempotencyMiddleware)
+        .with(crate::transport::TransportMiddleware::new(mock.clone()))
+        .build();
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_http_client(http);
+    let body = crate::types::PostUsersDeferredRequest {
+        department_id: "".to_string(),
+        direct_manager_id: "".to_string(),
+        email: "thing@example.com".to_string(),
+        first_name: "Kevin".to_string(),
+        last_name: "Thing".to_string(),
+        location_id: "".to_string(),
+        phone: "".to_string(),
+        role: crate::types::Role::BusinessUser,
+    };
+
+    // A key is generated when none is given.
+    ramp.users().post_deferred(&body).await.unwrap();
+    // The key given for the request is sent in the body and in the header.
+    ramp.users()
+        .post_deferred_with_idempotency_key(&body, "invite-kevin")
+        .await
+        .unwrap();
+
+    let requests = mock.requests();
+    let generated = requests[0].headers[crate::idempotency::IDEMPOTENCY_KEY]
+        .to_str()
+        .unwrap();
+    assert!(uuid::Uuid::parse_str(generated).is_ok());
+    assert_eq!(
+        requests[1].headers[crate::idempotency::IDEMPOTENCY_KEY],
+        "invite-kevin"
+    );
+    let sent: serde_json::Value = requests[1].json().unwrap();
+    assert_eq!(sent["idempotency_key"], "invite-kevin");
+}
+
+#[tokio::test]
+async fn test_card_controls() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, "");
+    mock.push_response(reqwest::StatusCode::OK, r#"{"id": "task-1"}"#);
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "task-1", "status": "IN_PROGRESS"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "task-1", "status": "SUCCESS"}"#,
+    );
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let cards = ramp.cards();
+    let restrictions =
+        crate::cards::SpendingRestrictionsUpdate::new(500.0, crate::types::Interval::Monthly)
+            .blocked_categories(&[7]);
+    cards
+        .update_spending_restrictions("card-1", &restrictions)
+        .await
+        .unwrap();
+    let task = cards.suspend("card-1").await.unwrap();
+    let done = cards
+        .wait_for_task(&task.id, std::time::Duration::from_millis(1))
+        .await
+        .unwrap();
+    assert_eq!(done.status, Some(crate::types::Status::Success));
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].method, reqwest::Method::PATCH);
+    assert_eq!(requests[0].url.path(), "/developer/v1/cards/card-1");
+    // Only the restrictions that are set are sent, so the card is not unsuspended.
+    let sent: serde_json::Value = requests[0].json().unwrap();
+    assert_eq!(
+        sent,
+        serde_json::json!({
+            "spending_restrictions": {
+                "amount": 500.0,
+                "interval": "MONTHLY",
+                "blocked_categories": [7],
+            }
+        })
+    );
+    assert_eq!(
+        requests[1].url.path(),
+        "/developer/v1/cards/card-1/deferred/suspension"
+    );
+    let sent: serde_json::Value = requests[1].json().unwrap();
+    assert!(uuid::Uuid::parse_str(sent["idempotency_key"].as_str().unwrap()).is_ok());
+    assert_eq!(requests.len(), 4);
+}
+
+#[tokio::test]
+async fn test_org_units() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"id": "d-1", "name": "Sales"}, {"id": "d-2", "name": "Engineering"}], "page": {}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "d-2", "name": "Platform"}"#,
+    );
+    mock.push_response(reqwest::StatusCode::OK, "");
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let departments = ramp.departments();
+    let engineering = departments.find("engineering").await.unwrap().unwrap();
+    assert_eq!(engineering.id, "d-2");
+    let renamed = departments
+        .rename(&engineering.id, "Platform")
+        .await
+        .unwrap();
+    assert_eq!(renamed.name, "Platform");
+    departments.delete(&engineering.id).await.unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests[1].method, reqwest::Method::PATCH);
+    assert_eq!(requests[1].url.path(), "/developer/v1/departments/d-2");
+    let sent: serde_json::Value = requests[1].json().unwrap();
+    assert_eq!(sent, serde_json::json!({ "name": "Platform" }));
+    assert_eq!(requests[2].method, reqwest::Method::DELETE);
+    assert_eq!(requests[2].url.path(), "/developer/v1/departments/d-2");
+}
+
+#[tokio::test]
+async fn test_statements() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"id": "s-1", "ending_balance": {"amount": 1250.5, "currency_code": "USD"}}], "page": {"next": "https://api.ramp.com/developer/v1/statements?start=s-1"}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"id": "s-2"}], "page": {"next": null}}"#,
+    );
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let statements = ramp
+        .statements()
+        .get_all("2022-01-01T00:00:00Z", "")
+        .await
+        .unwrap();
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[0].ending_balance.amount, 1250.5);
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].url.path(), "/developer/v1/statements");
+    assert_eq!(
+        requests[1].url.query(),
+        Some("from_date=2022-01-01T00%3A00%3A00Z&start=s-1")
+    );
+}
+
+#[tokio::test]
+async fn test_token_store() {
+    use crate::token_store::{MemoryTokenStore, StoredToken, TokenStore};
+
+    let store = std::sync::Arc::new(MemoryTokenStore::new());
+    store
+        .put(&StoredToken {
+            access_token: "stored-token".to_string(),
+            refresh_token: "stored-refresh-token".to_string(),
+            expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+        })
+        .await
+        .unwrap();
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "", "")
+        .with_transport(mock.clone())
+        .with_token_store(store.clone());
+
+    // The client starts from the stored token.
+    ramp.users()
+        .get("9b84d870-f348-43d6-baa4-77181d3cc0f9")
+        .await
+        .unwrap();
+    assert_eq!(
+        mock.requests()[0].headers[http::header::AUTHORIZATION],
+        "Bearer stored-token"
+    );
+
+    // Another client refreshed the token, which is used instead of refreshing it again.
+    store
+        .put(&StoredToken {
+            access_token: "refreshed-token".to_string(),
+            refresh_token: "refreshed-refresh-token".to_string(),
+            expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+        })
+        .await
+        .unwrap();
+    let token = ramp.refresh_access_token().await.unwrap();
+    assert_eq!(token.access_token, "refreshed-token");
+    assert_eq!(token.refresh_token, "refreshed-refresh-token");
+    assert!(ramp.expires_in().await.unwrap() > std::time::Duration::from_secs(50 * 60));
+
+    // The refresh lock was released.
+    store.lock_refresh().await.unwrap();
+    store.unlock_refresh().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_token_refresh_on_unauthorized() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::UNAUTHORIZED, "{}");
+    mock.push_json(
+        reqwest::StatusCode::OK,
+        &serde_json::json!({
+            "access_token": "new-token",
+            "refresh_token": "new-refresh-token",
+            "expires_in": 3600,
+        }),
+    )
+    .unwrap();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+
+    let refreshes = Arc::new(AtomicUsize::new(0));
+    let mut ramp = crate::Client::new(
+        "client-id",
+        "client-secret",
+        "redirect-uri",
+        "token",
+        "refresh-token",
+    )
+    .with_transport(mock.clone());
+    let counter = refreshes.clone();
+    ramp.set_auto_access_token_refresh(true)
+        .set_token_refresh_callback(move |t| {
+            assert_eq!(t.access_token, "new-token");
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+    // The rejected request is sent again, once, with the refreshed token.
+    ramp.users()
+        .get("9b84d870-f348-43d6-baa4-77181d3cc0f9")
+        .await
+        .unwrap();
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(
+        requests[0].headers[http::header::AUTHORIZATION],
+        "Bearer token"
+    );
+    assert_eq!(requests[1].url.as_str(), crate::TOKEN_ENDPOINT);
+    let form = String::from_utf8(requests[1].body.clone()).unwrap();
+    assert!(form.contains("grant_type=refresh_token"));
+    assert!(form.contains("refresh_token=refresh-token"));
+    assert_eq!(requests[2].url, requests[0].url);
+    assert_eq!(
+        requests[2].headers[http::header::AUTHORIZATION],
+        "Bearer new-token"
+    );
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    assert!(ramp.expires_in().await.unwrap() > std::time::Duration::from_secs(50 * 60));
+}
+
+#[tokio::test]
+async fn test_token_refresh_refused() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::BAD_REQUEST,
+        r#"{"error":"invalid_grant"}"#,
+    );
+
+    let mut ramp = crate::Client::new(
+        "client-id",
+        "client-secret",
+        "redirect-uri",
+        "token",
+        "refresh-token",
+    )
+    .with_transport(mock.clone());
+    ramp.set_token_refresh_callback(|_| panic!("the token was not refreshed"));
+
+    // The refusal is returned as an error, rather than as a token failing to parse.
+    let err = ramp.refresh_access_token().await.unwrap_err().to_string();
+    assert!(err.contains("400"), "{}", err);
+    assert!(err.contains("invalid_grant"), "{}", err);
+    assert_eq!(mock.requests().len(), 1);
+    assert!(ramp.expires_in().await.is_none());
+}
+
+// Answer a single request with the user, returning the head of the request.
+async fn serve_user(listener: tokio::net::TcpListener) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let (mut socket, _) = listener.accept().await.unwrap();
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.ends_with(b"\r\n\r\n") {
+        let n = socket.read(&mut buf).await.unwrap();
+        head.extend_from_slice(&buf[..n]);
+    }
+    let resp = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        USER.len(),
+        USER
+    );
+    socket.write_all(resp.as_bytes()).await.unwrap();
+    String::from_utf8(head).unwrap().to_lowercase()
+}
+
+#[tokio::test]
+async fn test_compression() {
+    use crate::client_config::ClientConfig;
+
+    for (config, accept_encoding) in [
+        (ClientConfig::new(), true),
+        (ClientConfig::new().compression(false), false),
+    ] {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(serve_user(listener));
+
+        let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+            .with_host(host)
+            .with_config(&config)
+            .unwrap();
+        let user = ramp
+            .users()
+            .get("9b84d870-f348-43d6-baa4-77181d3cc0f9")
+            .await
+            .unwrap();
+        assert_eq!(user.email, "thing@example.com");
+
+        let head = server.await.unwrap();
+        let header = head.lines().find(|l| l.starts_with("accept-encoding:"));
+        if accept_encoding {
+            let header = header.unwrap();
+            assert!(["gzip", "deflate", "br"]
+                .iter()
+                .all(|encoding| header.contains(encoding)));
+        } else {
+            assert_eq!(header, None);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_proxy() {
+    use crate::client_config::ClientConfig;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_user(listener));
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_host("http://api.ramp.example.com")
+        .with_config(&ClientConfig::new().proxy(&proxy))
+        .unwrap();
+    ramp.users()
+        .get("9b84d870-f348-43d6-baa4-77181d3cc0f9")
+        .await
+        .unwrap();
+
+    // The proxy is asked for the whole URL.
+    let head = server.await.unwrap();
+    assert!(head.starts_with(
+        "get http://api.ramp.example.com/developer/v1/users/9b84d870-f348-43d6-baa4-77181d3cc0f9 http/1.1\r\n"
+    ));
+}
+
+#[tokio::test]
+async fn test_response_cache() {
+    use std::time::Duration;
+
+    use crate::cache::ResponseCache;
+
+    let mock = crate::transport::MockTransport::new();
+    for _ in 0..4 {
+        mock.push_response(reqwest::StatusCode::OK, USER);
+    }
+
+    let cache = ResponseCache::new(Duration::from_secs(60)).ttl_for("/users", Duration::ZERO);
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone())
+        .with_cache(cache.clone());
+
+    // The second request is answered from the cache.
+    let user = ramp.users().get("1234").await.unwrap();
+    assert_eq!(ramp.users().get("1234").await.unwrap(), user);
+    assert_eq!(mock.requests().len(), 1);
+    assert_eq!(cache.len(), 1);
+
+    // Other credentials do not share the responses.
+    let other = crate::Client::new("client-id", "client-secret", "redirect-uri", "other", "")
+        .with_transport(mock.clone())
+        .with_cache(cache.clone());
+    other.users().get("1234").await.unwrap();
+    assert_eq!(mock.requests().len(), 2);
+
+    // The endpoints with a time to live of zero are not cached.
+    assert_eq!(
+        cache.ttl("/developer/v1/users/1234"),
+        Duration::from_secs(60)
+    );
+    assert_eq!(cache.ttl("/developer/v1/users"), Duration::ZERO);
+    assert_eq!(
+        cache.ttl("/developer/v1/departments"),
+        Duration::from_secs(60)
+    );
+
+    cache.clear();
+    ramp.users().get("1234").await.unwrap();
+    assert_eq!(mock.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_response_cache_skips() {
+    use std::time::Duration;
+
+    use futures::TryStreamExt;
+
+    use crate::cache::ResponseCache;
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+    mock.push_response(reqwest::StatusCode::OK, USER);
+    mock.push_response(reqwest::StatusCode::OK, r#"[{"id": "1234"}]"#);
+    mock.push_response(reqwest::StatusCode::OK, USER);
+    mock.push_response(reqwest::StatusCode::OK, USER);
+
+    let cache = ResponseCache::new(Duration::from_secs(60));
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone())
+        .with_cache(cache.clone());
+    ramp.users().get("1234").await.unwrap();
+
+    // The responses for another `Accept` are cached apart.
+    let csv = ramp.with_default_header("accept", "text/csv").unwrap();
+    csv.users().get("1234").await.unwrap();
+    csv.users().get("1234").await.unwrap();
+    assert_eq!(mock.requests().len(), 2);
+    assert_eq!(cache.len(), 2);
+
+    // Streamed responses are not read in memory, nor cached.
+    let _: Vec<serde_json::Value> = ramp
+        .get_stream_items("/users")
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(mock.requests().len(), 3);
+    assert_eq!(cache.len(), 2);
+
+    // Neither are the responses larger than the limit.
+    let cache = ResponseCache::new(Duration::from_secs(60)).max_body_size(16);
+    let ramp = ramp.with_cache(cache.clone());
+    ramp.users().get("1234").await.unwrap();
+    ramp.users().get("1234").await.unwrap();
+    assert_eq!(mock.requests().len(), 5);
+    assert!(cache.is_empty());
+}
+
+/// A transport taking a while to answer, so that requests overlap.
+struct SlowTransport(crate::transport::MockTransport);
+
+#[async_trait::async_trait]
+impl crate::transport::HttpTransport for SlowTransport {
+    async fn send(&self, req: reqwest::Request) -> anyhow::Result<reqwest::Response> {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        crate::transport::HttpTransport::send(&self.0, req).await
+    }
+}
+
+#[tokio::test]
+async fn test_single_flight() {
+    let mock = crate::transport::MockTransport::new();
+    for _ in 0..4 {
+        mock.push_response(reqwest::StatusCode::OK, USER);
+    }
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(SlowTransport(mock.clone()))
+        .with_single_flight(true);
+    let users = ramp.users();
+    let (a, b, c) = futures::join!(users.get("1234"), users.get("1234"), users.get("5678"));
+    assert_eq!(a.unwrap(), b.unwrap());
+    c.unwrap();
+    assert_eq!(mock.requests().len(), 2);
+
+    // Without it, every request is sent.
+    let ramp = ramp.with_single_flight(false);
+    let users = ramp.users();
+    let (a, b) = futures::join!(users.get("1234"), users.get("1234"));
+    a.unwrap();
+    b.unwrap();
+    assert_eq!(mock.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn test_single_flight_accept() {
+    let mock = crate::transport::MockTransport::new();
+    for _ in 0..2 {
+        mock.push_response(reqwest::StatusCode::OK, USER);
+    }
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(SlowTransport(mock.clone()))
+        .with_single_flight(true);
+    let csv = ramp.with_default_header("accept", "text/csv").unwrap();
+
+    // The requests differ only in `Accept`, so they are both sent.
+    let (users, csv_users) = (ramp.users(), csv.users());
+    let (a, b) = futures::join!(users.get("1234"), csv_users.get("1234"));
+    a.unwrap();
+    b.unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_ne!(requests[0].headers["accept"], requests[1].headers["accept"]);
+}
+
+#[test]
+fn test_retry_after() {
+    use std::time::Duration;
+
+    use crate::retry::RetryPolicy;
+
+    let response = |status: u16, retry_after: Option<&str>| -> reqwest_middleware::Result<_> {
+        let mut resp = http::Response::builder().status(status);
+        if let Some(retry_after) = retry_after {
+            resp = resp.header("retry-after", retry_after);
+        }
+        Ok(reqwest::Response::from(resp.body(Vec::new()).unwrap()))
+    };
+    let policy = RetryPolicy {
+        backoff_base: Duration::from_secs(1),
+        max_backoff: Duration::from_secs(30),
+        jitter: false,
+        ..Default::default()
+    };
+
+    // The wait the server asks for replaces the backoff, up to `max_backoff`.
+    assert_eq!(
+        policy.wait(2, &response(429, Some("7"))),
+        Duration::from_secs(7)
+    );
+    assert_eq!(
+        policy.wait(0, &response(503, Some("7"))),
+        Duration::from_secs(7)
+    );
+    assert_eq!(
+        policy.wait(0, &response(429, Some("3600"))),
+        Duration::from_secs(30)
+    );
+
+    // A date in the past is no wait, one in the future about the time until then.
+    let past = "Wed, 21 Oct 2015 07:28:00 GMT";
+    assert_eq!(policy.wait(1, &response(429, Some(past))), Duration::ZERO);
+    let future = (chrono::Utc::now() + chrono::Duration::seconds(20)).to_rfc2822();
+    let wait = policy.wait(0, &response(429, Some(&future)));
+    assert!(wait > Duration::from_secs(15) && wait <= Duration::from_secs(20));
+
+    // Without a usable header, or for other statuses, the backoff applies.
+    assert_eq!(policy.wait(2, &response(429, None)), Duration::from_secs(4));
+    assert_eq!(
+        policy.wait(2, &response(429, Some("soon"))),
+        Duration::from_secs(4)
+    );
+    assert_eq!(
+        policy.wait(2, &response(502, Some("7"))),
+        Duration::from_secs(4)
+    );
+}
+
+/// A transport answering every user with its id, the later ones sooner, and counting
+/// the requests in flight.
+#[derive(Clone, Default)]
+struct CountingTransport {
+    mock: crate::transport::MockTransport,
+    in_flight: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    max_in_flight: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl crate::transport::HttpTransport for CountingTransport {
+    async fn send(&self, req: reqwest::Request) -> anyhow::Result<reqwest::Response> {
+        use std::sync::atomic::Ordering;
+
+        let n = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(n, Ordering::SeqCst);
+
+        let id = req
+            .url()
+            .path_segments()
+            .unwrap()
+            .last()
+            .unwrap()
+            .to_string();
+        let delay = 50 - id.parse::<u64>().unwrap() * 4;
+        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        // The test runtime has a single thread, so the response queued here is the
+        // one the mock answers this request with.
+        self.mock.push_response(
+            reqwest::StatusCode::OK,
+            USER.replace("9b84d870-f348-43d6-baa4-77181d3cc0f9", &id),
+        );
+        crate::transport::HttpTransport::send(&self.mock, req).await
+    }
+}
+
+#[tokio::test]
+async fn test_batch() {
+    let transport = CountingTransport::default();
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(transport.clone());
+    let users = ramp.users();
+
+    let ids: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+    let results = crate::batch::batch(ids.iter().map(|id| users.get(id)), 3).await;
+
+    // The results are in the order of the requests, not the order they finished in.
+    let got: Vec<String> = results.into_iter().map(|user| user.unwrap().id).collect();
+    assert_eq!(got, ids);
+    assert_eq!(transport.mock.requests().len(), 10);
+    assert_eq!(
+        transport
+            .max_in_flight
+            .load(std::sync::atomic::Ordering::SeqCst),
+        3
+    );
+}
+
+#[tokio::test]
+async fn test_metrics() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::metrics::RequestMetrics;
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+    mock.push_response(reqwest::StatusCode::NOT_FOUND, "");
+
+    let recorded: Arc<Mutex<Vec<RequestMetrics>>> = Default::default();
+    let sink = recorded.clone();
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone())
+        .with_metrics(move |m: &RequestMetrics| sink.lock().unwrap().push(m.clone()));
+
+    ramp.users().get("1234").await.unwrap();
+    assert!(ramp.transactions().get_resource("5678").await.is_err());
+
+    let recorded = recorded.lock().unwrap();
+    assert_eq!(recorded.len(), 2);
+    assert_eq!(recorded[0].service, "Ramp");
+    assert_eq!(recorded[0].method, reqwest::Method::GET);
+    assert_eq!(recorded[0].endpoint, Some("/users/{id}"));
+    assert!(!recorded[0].is_error());
+    assert_eq!(recorded[1].endpoint, Some("/transactions/{id}"));
+    assert_eq!(recorded[1].status, Some(reqwest::StatusCode::NOT_FOUND));
+    assert!(recorded[1].is_error());
+
+    // The endpoints without parameters win over the ones with.
+    assert_eq!(
+        crate::metrics::endpoint("/developer/v1/users/deferred"),
+        Some("/users/deferred")
+    );
+    assert_eq!(crate::metrics::endpoint("/developer/v1/unknown"), None);
+}
+
+#[tokio::test]
+async fn test_fixtures() {
+    use crate::fixtures::{FixtureMode, FixtureTransport};
+
+    let path = std::env::temp_dir().join(format!("ramp-fixture-{}.json", uuid::Uuid::new_v4()));
+
+    // Record a request to a server answering it once.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_user(listener));
+
+    let recorder = FixtureTransport::record(&path);
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_host(&host)
+        .with_transport(recorder.clone());
+    let user = ramp.users().get("1234").await.unwrap();
+    server.await.unwrap();
+    assert_eq!(recorder.fixture().interactions.len(), 1);
+
+    // The same request is then answered from the fixture, without the server.
+    let replayer = FixtureTransport::replay(&path).unwrap();
+    assert_eq!(replayer.mode(), FixtureMode::Replay);
+    let ramp = ramp.with_transport(replayer);
+    assert_eq!(ramp.users().get("1234").await.unwrap(), user);
+    assert!(ramp.users().get("1234").await.is_err());
+    assert!(ramp.users().get("5678").await.is_err());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_default_headers() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::OK, USER);
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone())
+        .with_user_agent("billing-sync/1.4")
+        .unwrap()
+        .with_default_header("accept", "application/vnd.ramp+json")
+        .unwrap();
+    ramp.users().get("1234").await.unwrap();
+
+    // The headers replace the ones set by the client.
+    let headers = &mock.requests()[0].headers;
+    assert_eq!(headers["user-agent"], "billing-sync/1.4");
+    assert_eq!(headers["accept"], "application/vnd.ramp+json");
+    assert_eq!(headers.get_all("accept").iter().count(), 1);
+
+    assert!(ramp.with_default_header("bad header", "value").is_err());
+}
+
+#[tokio::test]
+async fn test_get_stream_items() {
+    use futures::TryStreamExt;
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"page": {"next": null},
+            "data": [{"id": "t1", "amount": 1.5, "card_holder": {}},
+                     {"id": "t2", "amount": 2.5, "card_holder": {}}]}"#,
+    );
+    mock.push_response(reqwest::StatusCode::NOT_FOUND, "");
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_transport(mock.clone());
+    let transactions: Vec<crate::types::Data> = ramp
+        .get_stream_items("/transactions")
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<_> = transactions.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["t1", "t2"]);
+
+    assert!(ramp
+        .get_stream_items::<crate::types::Data>("/transactions")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_sandbox() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"id": "d-1", "name": "Sales"}],
+            "page": {"next": "https://demo-api.ramp.com/developer/v1/departments?start=d-1"}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"id": "d-2", "name": "Support"}], "page": {"next": null}}"#,
+    );
+
+    let ramp = crate::Client::new("client-id", "client-secret", "redirect-uri", "token", "")
+        .with_environment(crate::Environment::Sandbox)
+        .with_transport(mock.clone());
+    let departments = ramp.departments().get_all().await.unwrap();
+    assert_eq!(departments.len(), 2);
+
+    // Every page is requested from the sandbox, and users consent on it as well.
+    let requests = mock.requests();
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://demo-api.ramp.com/developer/v1/departments"
+    );
+    assert_eq!(
+        requests[1].url.as_str(),
+        "https://demo-api.ramp.com/developer/v1/departments?start=d-1"
+    );
+    assert!(ramp
+        .user_consent_url(&[])
+        .starts_with("https://demo.ramp.com/v1/authorize?"));
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_export() {
+    let transactions: Vec<crate::types::Data> = serde_json::from_str(
+        r#"[
+            {"id": "t1", "amount": 42.5, "card_holder": {}, "memo": "Team lunch",
+             "merchant_name": "Tartine", "user_transaction_time": "2021-08-05T23:30:00-04:00",
+             "accounting_categories": [{"category_id": "6000", "category_name": "Meals"}],
+             "receipts": ["r1", "r2"]},
+            {"id": "t2", "amount": 12.0, "card_holder": {}, "sk_category_id": 7,
+             "sk_category_name": "Software"}
+        ]"#,
+    )
+    .unwrap();
+    let receipts: Vec<crate::types::Receipt> = serde_json::from_str(
+        r#"[{"id": "r1", "transaction_id": "t1", "receipt_url": "https://receipts.ramp.com/r1"}]"#,
+    )
+    .unwrap();
+
+    let entries = crate::export::export(&transactions, &receipts);
+    assert_eq!(
+        serde_json::to_value(&entries[0]).unwrap(),
+        serde_json::json!({
+            "id": "t1",
+            "date": "2021-08-05",
+            "amount": 42.5,
+            "category": {"id": "6000", "name": "Meals"},
+            "memo": "Team lunch",
+            "merchant": "Tartine",
+            "attachments": [{"id": "r1", "url": "https://receipts.ramp.com/r1"}, {"id": "r2"}]
+        })
+    );
+    let category = entries[1].category.as_ref().unwrap();
+    assert_eq!(
+        (category.id.as_str(), category.name.as_str()),
+        ("7", "Software")
+    );
+    assert!(entries[1].attachments.is_empty());
+}
@@ -0,0 +1,206 @@
+//! Filter transactions with a query that borrows its values.
+//!
+//! The generated `Transactions::get_page_with_cursor` and `Transactions::get_all`
+//! take every filter in order and copy each of them into the request. A
+//! `TransactionQuery` is built once, borrowing the IDs it filters on, and the pages
+//! of the listing only replace its cursor.
+//!
+//! ```no_run
+//! # async fn run(ramp: ramp_api::Client, department_ids: Vec<String>) -> anyhow::Result<()> {
+//! use ramp_api::transactions::{TransactionOrder, TransactionQuery};
+//!
+//! for department_id in &department_ids {
+//!     let query = TransactionQuery::new()
+//!         .department(department_id)
+//!         .order_by(TransactionOrder::DateDesc)
+//!         .requires_memo();
+//!     let transactions = ramp.transactions().query_all(&query).await?;
+//!     println!("{}: {} without a memo", department_id, transactions.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::borrow::Cow;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+
+use crate::{
+    transactions::Transactions,
+    types::{Data, GetTransactionResponse},
+};
+
+/// The order of the transactions. The API supports a single one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionOrder {
+    DateAsc,
+    DateDesc,
+    AmountAsc,
+    AmountDesc,
+}
+
+/// The filters of a listing of transactions.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct TransactionQuery<'a> {
+    #[serde(skip_serializing_if = "str::is_empty")]
+    department_id: Cow<'a, str>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_date"
+    )]
+    from_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    location_id: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_amount: Option<f64>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    merchant_id: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_amount: Option<f64>,
+    #[serde(skip_serializing_if = "is_false")]
+    order_by_amount_asc: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    order_by_amount_desc: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    order_by_date_asc: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    order_by_date_desc: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_size: Option<u32>,
+    #[serde(skip_serializing_if = "is_false")]
+    requires_memo: bool,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    sk_category_id: Cow<'a, str>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    start: Cow<'a, str>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    state: Cow<'a, str>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_date"
+    )]
+    to_date: Option<DateTime<Utc>>,
+}
+
+impl<'a> TransactionQuery<'a> {
+    /// All the transactions of the business.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn department(mut self, department_id: impl Into<Cow<'a, str>>) -> Self {
+        self.department_id = department_id.into();
+        self
+    }
+
+    pub fn location(mut self, location_id: impl Into<Cow<'a, str>>) -> Self {
+        self.location_id = location_id.into();
+        self
+    }
+
+    pub fn merchant(mut self, merchant_id: impl Into<Cow<'a, str>>) -> Self {
+        self.merchant_id = merchant_id.into();
+        self
+    }
+
+    /// Only the transactions in a category, by its Ramp category code.
+    pub fn sk_category(mut self, sk_category_id: impl Into<Cow<'a, str>>) -> Self {
+        self.sk_category_id = sk_category_id.into();
+        self
+    }
+
+    /// Only the transactions in a state, like `CLEARED`.
+    pub fn state(mut self, state: impl Into<Cow<'a, str>>) -> Self {
+        self.state = state.into();
+        self
+    }
+
+    /// Only the transactions made from `from_date` on.
+    pub fn from_date(mut self, from_date: DateTime<Utc>) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Only the transactions made before `to_date`.
+    pub fn to_date(mut self, to_date: DateTime<Utc>) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    pub fn min_amount(mut self, min_amount: f64) -> Self {
+        self.min_amount = Some(min_amount);
+        self
+    }
+
+    pub fn max_amount(mut self, max_amount: f64) -> Self {
+        self.max_amount = Some(max_amount);
+        self
+    }
+
+    /// Only the transactions which require a memo, but do not have one.
+    pub fn requires_memo(mut self) -> Self {
+        self.requires_memo = true;
+        self
+    }
+
+    pub fn order_by(mut self, order: TransactionOrder) -> Self {
+        self.order_by_date_asc = order == TransactionOrder::DateAsc;
+        self.order_by_date_desc = order == TransactionOrder::DateDesc;
+        self.order_by_amount_asc = order == TransactionOrder::AmountAsc;
+        self.order_by_amount_desc = order == TransactionOrder::AmountDesc;
+        self
+    }
+
+    /// Set the number of transactions requested per page, between 2 and 10,000.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Start at the page after the transaction with the given ID.
+    pub fn start(mut self, start: impl Into<Cow<'a, str>>) -> Self {
+        self.start = start.into();
+        self
+    }
+
+    fn url(&self) -> Result<String> {
+        Ok(format!(
+            "/transactions?{}",
+            serde_urlencoded::to_string(self)?
+        ))
+    }
+}
+
+fn is_false(b: &bool) -> bool {
+    !b
+}
+
+/// The dates are sent as RFC 3339, like the generated functions do.
+fn serialize_date<S: Serializer>(date: &Option<DateTime<Utc>>, s: S) -> Result<S::Ok, S::Error> {
+    match date {
+        Some(date) => s.serialize_str(&date.to_rfc3339()),
+        None => s.serialize_none(),
+    }
+}
+
+impl Transactions {
+    /// Get a page of the transactions matching the query.
+    pub async fn query_page(&self, query: &TransactionQuery<'_>) -> Result<GetTransactionResponse> {
+        self.client.get(&query.url()?, None).await
+    }
+
+    /// Get all the transactions matching the query, following the pages from its
+    /// `start` on.
+    pub async fn query_all(&self, query: &TransactionQuery<'_>) -> Result<Vec<Data>> {
+        let mut resp = self.query_page(query).await?;
+        let mut transactions = resp.data;
+        while let Some(start) = resp.page.next_start() {
+            // Only the cursor changes, the values the query borrows are not copied.
+            resp = self.query_page(&query.clone().start(start)).await?;
+            transactions.append(&mut resp.data);
+        }
+
+        Ok(transactions)
+    }
+}
@@ -55,8 +55,8 @@ pub enum Role {
     BusinessUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -67,7 +67,7 @@ impl std::fmt::Display for Role {
             Role::BusinessOwner => "BUSINESS_OWNER",
             Role::BusinessUser => "BUSINESS_USER",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -181,7 +181,7 @@ pub struct User {
     pub role: Role,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchUsersRequest {
     /**
     * The OAuth2 token header
@@ -214,6 +214,44 @@ pub struct PatchUsersRequest {
     pub role: Option<Role>,
 }
 
+impl PatchUsersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchUsersRequestBuilder {
+        PatchUsersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchUsersRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchUsersRequestBuilder(PatchUsersRequest);
+
+impl PatchUsersRequestBuilder {
+    pub fn department_id(mut self, department_id: impl Into<String>) -> Self {
+        self.0.department_id = department_id.into();
+        self
+    }
+
+    pub fn direct_manager_id(mut self, direct_manager_id: impl Into<String>) -> Self {
+        self.0.direct_manager_id = direct_manager_id.into();
+        self
+    }
+
+    pub fn location_id(mut self, location_id: impl Into<String>) -> Self {
+        self.0.location_id = location_id.into();
+        self
+    }
+
+    pub fn role(mut self, role: impl Into<Role>) -> Self {
+        self.0.role = Some(role.into());
+        self
+    }
+
+    pub fn build(self) -> PatchUsersRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Page {
     /**
@@ -315,8 +353,8 @@ pub enum Type {
     PolicyViolationFromUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -325,7 +363,7 @@ impl std::fmt::Display for Type {
             Type::PolicyViolationFromAdmin => "POLICY_VIOLATION_FROM_ADMIN",
             Type::PolicyViolationFromUser => "POLICY_VIOLATION_FROM_USER",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -384,8 +422,8 @@ pub enum GetTransactionResponseDataDisputesType {
     UnrecognizedCharge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetTransactionResponseDataDisputesType {
@@ -396,7 +434,7 @@ impl std::fmt::Display for GetTransactionResponseDataDisputesType {
             GetTransactionResponseDataDisputesType::Unknown => "UNKNOWN",
             GetTransactionResponseDataDisputesType::UnrecognizedCharge => "UNRECOGNIZED_CHARGE",
             GetTransactionResponseDataDisputesType::Noop => "",
-            GetTransactionResponseDataDisputesType::FallthroughString => "*",
+            GetTransactionResponseDataDisputesType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -565,12 +603,8 @@ pub struct Data {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub user_transaction_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_transaction_time: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -621,7 +655,7 @@ pub struct GetLocationResponse {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostLocationRequest {
     /**
     * The OAuth2 token header
@@ -634,6 +668,43 @@ pub struct PostLocationRequest {
     pub name: String,
 }
 
+impl std::convert::From<String> for PostLocationRequest {
+    fn from(name: String) -> Self {
+        PostLocationRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PostLocationRequest {
+    fn from(name: &str) -> Self {
+        PostLocationRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PostLocationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostLocationRequestBuilder {
+        PostLocationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostLocationRequest`.
+#[derive(Debug, Clone)]
+pub struct PostLocationRequestBuilder(PostLocationRequest);
+
+impl PostLocationRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PostLocationRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUsersResponse {
@@ -781,8 +852,8 @@ pub enum Interval {
     Yearly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Interval {
@@ -793,7 +864,7 @@ impl std::fmt::Display for Interval {
             Interval::Total => "TOTAL",
             Interval::Yearly => "YEARLY",
             Interval::Noop => "",
-            Interval::FallthroughString => "*",
+            Interval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -811,7 +882,7 @@ impl Interval {
 }
 
 /// Specifies the spend restrictions on a Ramp card.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SpendingRestrictions {
     /**
     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
@@ -962,7 +1033,7 @@ pub struct GetCardsResponse {
     pub page: Page,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchResourcesCardsCardRequest {
     /**
     * The OAuth2 token header
@@ -991,6 +1062,47 @@ pub struct PatchResourcesCardsCardRequest {
     pub spending_restrictions: Option<SpendingRestrictions>,
 }
 
+impl PatchResourcesCardsCardRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchResourcesCardsCardRequestBuilder {
+        PatchResourcesCardsCardRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchResourcesCardsCardRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchResourcesCardsCardRequestBuilder(PatchResourcesCardsCardRequest);
+
+impl PatchResourcesCardsCardRequestBuilder {
+    pub fn card_program_id(mut self, card_program_id: impl Into<String>) -> Self {
+        self.0.card_program_id = card_program_id.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn has_notifications_enabled(mut self, has_notifications_enabled: bool) -> Self {
+        self.0.has_notifications_enabled = Some(has_notifications_enabled);
+        self
+    }
+
+    pub fn spending_restrictions(
+        mut self,
+        spending_restrictions: impl Into<SpendingRestrictions>,
+    ) -> Self {
+        self.0.spending_restrictions = Some(spending_restrictions.into());
+        self
+    }
+
+    pub fn build(self) -> PatchResourcesCardsCardRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCustomProviderResponse {
     /**
@@ -1180,7 +1292,7 @@ pub struct Business {
     pub website: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardPhysicalRequest {
     /**
     * The OAuth2 token header
@@ -1230,7 +1342,58 @@ pub struct PostResourcesCardPhysicalRequest {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostResourcesCardPhysicalRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostResourcesCardPhysicalRequestBuilder {
+        PostResourcesCardPhysicalRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostResourcesCardPhysicalRequest`.
+#[derive(Debug, Clone)]
+pub struct PostResourcesCardPhysicalRequestBuilder(PostResourcesCardPhysicalRequest);
+
+impl PostResourcesCardPhysicalRequestBuilder {
+    pub fn card_program_id(mut self, card_program_id: impl Into<String>) -> Self {
+        self.0.card_program_id = card_program_id.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn fulfillment(mut self, fulfillment: impl Into<Fulfillment>) -> Self {
+        self.0.fulfillment = Some(fulfillment.into());
+        self
+    }
+
+    pub fn idempotency_key(mut self, idempotency_key: impl Into<String>) -> Self {
+        self.0.idempotency_key = idempotency_key.into();
+        self
+    }
+
+    pub fn spending_restrictions(
+        mut self,
+        spending_restrictions: impl Into<SpendingRestrictions>,
+    ) -> Self {
+        self.0.spending_restrictions = Some(spending_restrictions.into());
+        self
+    }
+
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.0.user_id = user_id.into();
+        self
+    }
+
+    pub fn build(self) -> PostResourcesCardPhysicalRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardVirtualRequest {
     /**
     * The OAuth2 token header
@@ -1275,14 +1438,60 @@ pub struct PostResourcesCardVirtualRequest {
     pub user_id: String,
 }
 
+impl PostResourcesCardVirtualRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostResourcesCardVirtualRequestBuilder {
+        PostResourcesCardVirtualRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostResourcesCardVirtualRequest`.
+#[derive(Debug, Clone)]
+pub struct PostResourcesCardVirtualRequestBuilder(PostResourcesCardVirtualRequest);
+
+impl PostResourcesCardVirtualRequestBuilder {
+    pub fn card_program_id(mut self, card_program_id: impl Into<String>) -> Self {
+        self.0.card_program_id = card_program_id.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn idempotency_key(mut self, idempotency_key: impl Into<String>) -> Self {
+        self.0.idempotency_key = idempotency_key.into();
+        self
+    }
+
+    pub fn spending_restrictions(
+        mut self,
+        spending_restrictions: impl Into<SpendingRestrictions>,
+    ) -> Self {
+        self.0.spending_restrictions = Some(spending_restrictions.into());
+        self
+    }
+
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.0.user_id = user_id.into();
+        self
+    }
+
+    pub fn build(self) -> PostResourcesCardVirtualRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum TokenType {
     #[serde(rename = "Bearer")]
     Bearer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -1290,7 +1499,7 @@ impl std::fmt::Display for TokenType {
         match &*self {
             TokenType::Bearer => "Bearer",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1409,7 +1618,7 @@ pub struct BusinessCurrentStatus {
     pub statement_balance: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardsCardSuspensionRequest {
     /**
     * The OAuth2 token header
@@ -1422,6 +1631,43 @@ pub struct PostResourcesCardsCardSuspensionRequest {
     pub idempotency_key: String,
 }
 
+impl std::convert::From<String> for PostResourcesCardsCardSuspensionRequest {
+    fn from(idempotency_key: String) -> Self {
+        PostResourcesCardsCardSuspensionRequest { idempotency_key }
+    }
+}
+
+impl std::convert::From<&str> for PostResourcesCardsCardSuspensionRequest {
+    fn from(idempotency_key: &str) -> Self {
+        PostResourcesCardsCardSuspensionRequest {
+            idempotency_key: idempotency_key.to_string(),
+        }
+    }
+}
+
+impl PostResourcesCardsCardSuspensionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostResourcesCardsCardSuspensionRequestBuilder {
+        PostResourcesCardsCardSuspensionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostResourcesCardsCardSuspensionRequest`.
+#[derive(Debug, Clone)]
+pub struct PostResourcesCardsCardSuspensionRequestBuilder(PostResourcesCardsCardSuspensionRequest);
+
+impl PostResourcesCardsCardSuspensionRequestBuilder {
+    pub fn idempotency_key(mut self, idempotency_key: impl Into<String>) -> Self {
+        self.0.idempotency_key = idempotency_key.into();
+        self
+    }
+
+    pub fn build(self) -> PostResourcesCardsCardSuspensionRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetEntityTypeCustomRampResponse {
     /**
@@ -1500,8 +1746,8 @@ pub enum Status {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1512,7 +1758,7 @@ impl std::fmt::Display for Status {
             Status::Started => "STARTED",
             Status::Success => "SUCCESS",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1570,8 +1816,8 @@ pub enum Icon {
     WellnessIcon,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Icon {
@@ -1588,7 +1834,7 @@ impl std::fmt::Display for Icon {
             Icon::TravelExpensesIcon => "TravelExpensesIcon",
             Icon::WellnessIcon => "WellnessIcon",
             Icon::Noop => "",
-            Icon::FallthroughString => "*",
+            Icon::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1676,7 +1922,7 @@ pub struct GetCardProgramsResponse {
     pub page: Page,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardProgramRequest {
     /**
     * The OAuth2 token header
@@ -1709,7 +1955,58 @@ pub struct PostResourcesCardProgramRequest {
     pub spending_restrictions: SpendingRestrictions,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostResourcesCardProgramRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostResourcesCardProgramRequestBuilder {
+        PostResourcesCardProgramRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostResourcesCardProgramRequest`.
+#[derive(Debug, Clone)]
+pub struct PostResourcesCardProgramRequestBuilder(PostResourcesCardProgramRequest);
+
+impl PostResourcesCardProgramRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.0.icon = icon.into();
+        self
+    }
+
+    pub fn is_default(mut self, is_default: bool) -> Self {
+        self.0.is_default = Some(is_default);
+        self
+    }
+
+    pub fn is_physical(mut self, is_physical: bool) -> Self {
+        self.0.is_physical = Some(is_physical);
+        self
+    }
+
+    pub fn spending_restrictions(
+        mut self,
+        spending_restrictions: impl Into<SpendingRestrictions>,
+    ) -> Self {
+        self.0.spending_restrictions = spending_restrictions.into();
+        self
+    }
+
+    pub fn build(self) -> PostResourcesCardProgramRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostUsersDeferredRequest {
     /**
     * The OAuth2 token header
@@ -1778,6 +2075,64 @@ pub struct PostUsersDeferredRequest {
     pub role: Role,
 }
 
+impl PostUsersDeferredRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostUsersDeferredRequestBuilder {
+        PostUsersDeferredRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostUsersDeferredRequest`.
+#[derive(Debug, Clone)]
+pub struct PostUsersDeferredRequestBuilder(PostUsersDeferredRequest);
+
+impl PostUsersDeferredRequestBuilder {
+    pub fn department_id(mut self, department_id: impl Into<String>) -> Self {
+        self.0.department_id = department_id.into();
+        self
+    }
+
+    pub fn direct_manager_id(mut self, direct_manager_id: impl Into<String>) -> Self {
+        self.0.direct_manager_id = direct_manager_id.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn location_id(mut self, location_id: impl Into<String>) -> Self {
+        self.0.location_id = location_id.into();
+        self
+    }
+
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.0.phone = phone.into();
+        self
+    }
+
+    pub fn role(mut self, role: impl Into<Role>) -> Self {
+        self.0.role = role.into();
+        self
+    }
+
+    pub fn build(self) -> PostUsersDeferredRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUsersDeferredStatusResponseData {
     /**
@@ -1966,7 +2321,7 @@ pub struct GetReimbursementsResponse {
     pub page: Page,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCustomProviderEntityTypeLinkRequest {
     /**
     * The OAuth2 token header
@@ -1987,3 +2342,31 @@ pub struct PostCustomProviderEntityTypeLinkRequest {
     )]
     pub ramp_id: String,
 }
+
+impl PostCustomProviderEntityTypeLinkRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostCustomProviderEntityTypeLinkRequestBuilder {
+        PostCustomProviderEntityTypeLinkRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostCustomProviderEntityTypeLinkRequest`.
+#[derive(Debug, Clone)]
+pub struct PostCustomProviderEntityTypeLinkRequestBuilder(PostCustomProviderEntityTypeLinkRequest);
+
+impl PostCustomProviderEntityTypeLinkRequestBuilder {
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.0.custom_id = custom_id.into();
+        self
+    }
+
+    pub fn ramp_id(mut self, ramp_id: impl Into<String>) -> Self {
+        self.0.ramp_id = ramp_id.into();
+        self
+    }
+
+    pub fn build(self) -> PostCustomProviderEntityTypeLinkRequest {
+        self.0
+    }
+}
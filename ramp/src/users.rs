@@ -23,11 +23,9 @@ impl Users {
     *
     * * `authorization: &str` -- The OAuth2 token header.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::User> {
-        let url = format!(
-            "/users/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::User> {
+        let id = id.as_ref();
+        let url = format!("/users/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
@@ -39,11 +37,9 @@ impl Users {
     *
     * Suspends a user. Does not delete the user's cards. Currently this action is not reversible.
     */
-    pub async fn delete(&self, id: &str) -> Result<()> {
-        let url = format!(
-            "/users/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn delete(&self, id: impl AsRef<str>) -> Result<()> {
+        let id = id.as_ref();
+        let url = format!("/users/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.delete(&url, None).await
     }
@@ -55,11 +51,13 @@ impl Users {
     *
     * Modify information about a user.
     */
-    pub async fn patch(&self, id: &str, body: &crate::types::PatchUsersRequest) -> Result<()> {
-        let url = format!(
-            "/users/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn patch(
+        &self,
+        id: impl AsRef<str>,
+        body: &crate::types::PatchUsersRequest,
+    ) -> Result<()> {
+        let id = id.as_ref();
+        let url = format!("/users/{}", crate::progenitor_support::encode_path(id),);
 
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -88,21 +86,20 @@ impl Users {
         department_id: &str,
         location_id: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !department_id.is_empty() {
-            query_args.push(("department_id".to_string(), department_id.to_string()));
+            query_args.push(("department_id", department_id.to_string()));
         }
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users"), &query_args);
 
         let resp: crate::types::GetUsersResponse = self.client.get(&url, None).await?;
 
@@ -110,6 +107,48 @@ impl Users {
         Ok(resp.data)
     }
 
+    /**
+    * List users.
+    *
+    * This function performs a `GET` to the `/users` endpoint.
+    *
+    * As opposed to `get_page`, this function also returns the `page` of the response, which holds the cursor for the next page.
+    *
+    * Retrieve all users of the business.
+    *
+    * **Parameters:**
+    *
+    * * `authorization: &str` -- The OAuth2 token header.
+    * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
+    * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    * * `department_id: &str` -- The OAuth2 token header.
+    * * `location_id: &str` -- The OAuth2 token header.
+    */
+    pub async fn get_page_with_cursor(
+        &self,
+        start: &str,
+        page_size: f64,
+        department_id: &str,
+        location_id: &str,
+    ) -> Result<crate::types::GetUsersResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !department_id.is_empty() {
+            query_args.push(("department_id", department_id.to_string()));
+        }
+        if !location_id.is_empty() {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !page_size.to_string().is_empty() {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !start.is_empty() {
+            query_args.push(("start", start.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(format_args!("/users"), &query_args);
+
+        self.client.get(&url, None).await
+    }
+
     /**
     * List users.
     *
@@ -124,15 +163,14 @@ impl Users {
         department_id: &str,
         location_id: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !department_id.is_empty() {
-            query_args.push(("department_id".to_string(), department_id.to_string()));
+            query_args.push(("department_id", department_id.to_string()));
         }
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users"), &query_args);
 
         let resp: crate::types::GetUsersResponse = self.client.get(&url, None).await?;
 
@@ -144,7 +182,7 @@ impl Users {
             match self
                 .client
                 .get::<crate::types::GetUsersResponse>(
-                    page.trim_start_matches(crate::DEFAULT_HOST),
+                    page.trim_start_matches(self.client.host.as_str()),
                     None,
                 )
                 .await
@@ -198,8 +236,9 @@ impl Users {
     */
     pub async fn get_deferred_status(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::GetUsersDeferredStatusResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/users/deferred/status/{}",
             crate::progenitor_support::encode_path(id),
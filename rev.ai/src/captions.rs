@@ -30,19 +30,21 @@ impl Captions {
     */
     pub async fn get(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         accept: crate::types::Accept,
         speaker_channel: i64,
     ) -> Result<String> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if speaker_channel > 0 {
-            query_args.push(("speaker_channel".to_string(), speaker_channel.to_string()));
+            query_args.push(("speaker_channel", speaker_channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/jobs/{}/captions?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/jobs/{}/captions",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client
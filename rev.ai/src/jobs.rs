@@ -29,15 +29,14 @@ impl Jobs {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::JobAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/jobs?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/jobs"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -55,12 +54,11 @@ impl Jobs {
         &self,
         starting_after: &str,
     ) -> Result<Vec<crate::types::JobAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/jobs?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/jobs"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -89,11 +87,9 @@ impl Jobs {
     *
     * Returns information about a transcription job
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::JobAllOf> {
-        let url = format!(
-            "/jobs/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::JobAllOf> {
+        let id = id.as_ref();
+        let url = format!("/jobs/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
@@ -105,11 +101,9 @@ impl Jobs {
     *
     * Deletes a transcription job. All data related to the job, such as input media and transcript, will be permanently deleted. A job can only be deleted once it's completed (either with success or failure).
     */
-    pub async fn delete(&self, id: &str) -> Result<()> {
-        let url = format!(
-            "/jobs/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn delete(&self, id: impl AsRef<str>) -> Result<()> {
+        let id = id.as_ref();
+        let url = format!("/jobs/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.delete(&url, None).await
     }
@@ -26,7 +26,12 @@ impl Transcript {
     *
     * * `accept: crate::types::AcceptTranscript` -- MIME type specifying the transcription output format.
     */
-    pub async fn get(&self, id: &str, accept: crate::types::AcceptTranscript) -> Result<String> {
+    pub async fn get(
+        &self,
+        id: impl AsRef<str>,
+        accept: crate::types::AcceptTranscript,
+    ) -> Result<String> {
+        let id = id.as_ref();
         let url = format!(
             "/jobs/{}/transcript",
             crate::progenitor_support::encode_path(id),
@@ -115,8 +115,8 @@ pub enum Language {
     Sv,
     #[serde(rename = "tr")]
     Tr,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Language {
@@ -153,7 +153,7 @@ impl std::fmt::Display for Language {
             Language::Sl => "sl",
             Language::Sv => "sv",
             Language::Tr => "tr",
-            Language::FallthroughString => "*",
+            Language::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -268,8 +268,8 @@ pub enum Failure {
     Transcription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Failure {
@@ -285,7 +285,7 @@ impl std::fmt::Display for Failure {
             Failure::InvoicingLimitExceeded => "invoicing_limit_exceeded",
             Failure::Transcription => "transcription",
             Failure::Noop => "",
-            Failure::FallthroughString => "*",
+            Failure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -315,8 +315,8 @@ pub enum Status {
     Transcribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -326,7 +326,7 @@ impl std::fmt::Display for Status {
             Status::InProgress => "in_progress",
             Status::Transcribed => "transcribed",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -352,8 +352,8 @@ pub enum Type {
     Async,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -361,7 +361,7 @@ impl std::fmt::Display for Type {
         match &*self {
             Type::Async => "async",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -719,8 +719,8 @@ pub enum TranscriptMonologuesElementsType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TranscriptMonologuesElementsType {
@@ -730,7 +730,7 @@ impl std::fmt::Display for TranscriptMonologuesElementsType {
             TranscriptMonologuesElementsType::Text => "text",
             TranscriptMonologuesElementsType::Unknown => "unknown",
             TranscriptMonologuesElementsType::Noop => "",
-            TranscriptMonologuesElementsType::FallthroughString => "*",
+            TranscriptMonologuesElementsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -849,8 +849,8 @@ pub enum Accept {
     TextVtt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Accept {
@@ -859,7 +859,7 @@ impl std::fmt::Display for Accept {
             Accept::ApplicationXSubrip => "application/x-subrip",
             Accept::TextVtt => "text/vtt",
             Accept::Noop => "",
-            Accept::FallthroughString => "*",
+            Accept::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -887,8 +887,8 @@ pub enum AcceptTranscript {
     TextPlain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AcceptTranscript {
@@ -899,7 +899,7 @@ impl std::fmt::Display for AcceptTranscript {
             }
             AcceptTranscript::TextPlain => "text/plain",
             AcceptTranscript::Noop => "",
-            AcceptTranscript::FallthroughString => "*",
+            AcceptTranscript::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31,12 +31,11 @@ impl ApiKeys {
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
     pub async fn get(&self, limit: i64) -> Result<crate::types::GetApiKeysResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api_keys?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api_keys"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -89,7 +88,11 @@ impl ApiKeys {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn get_key(&self, api_key_id: &str) -> Result<crate::types::GetApiKeysKeyResponse> {
+    pub async fn get_key(
+        &self,
+        api_key_id: impl AsRef<str>,
+    ) -> Result<crate::types::GetApiKeysKeyResponse> {
+        let api_key_id = api_key_id.as_ref();
         let url = format!(
             "/api_keys/{}",
             crate::progenitor_support::encode_path(api_key_id),
@@ -117,9 +120,10 @@ impl ApiKeys {
     */
     pub async fn put_key(
         &self,
-        api_key_id: &str,
+        api_key_id: impl AsRef<str>,
         body: &crate::types::PutApiKeysKeyRequest,
     ) -> Result<crate::types::ApiKeyNameScopesAllOf> {
+        let api_key_id = api_key_id.as_ref();
         let url = format!(
             "/api_keys/{}",
             crate::progenitor_support::encode_path(api_key_id),
@@ -143,7 +147,8 @@ impl ApiKeys {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_key(&self, api_key_id: &str) -> Result<()> {
+    pub async fn delete_key(&self, api_key_id: impl AsRef<str>) -> Result<()> {
+        let api_key_id = api_key_id.as_ref();
         let url = format!(
             "/api_keys/{}",
             crate::progenitor_support::encode_path(api_key_id),
@@ -167,9 +172,10 @@ impl ApiKeys {
     */
     pub async fn patch_key(
         &self,
-        api_key_id: &str,
+        api_key_id: impl AsRef<str>,
         body: &crate::types::IpPool,
     ) -> Result<crate::types::ApiKeyNameId> {
+        let api_key_id = api_key_id.as_ref();
         let url = format!(
             "/api_keys/{}",
             crate::progenitor_support::encode_path(api_key_id),
@@ -31,15 +31,15 @@ impl BouncesApi {
         start_time: i64,
         end_time: i64,
     ) -> Result<Vec<crate::types::BounceResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/bounces?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/suppression/bounces"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -58,15 +58,15 @@ impl BouncesApi {
         start_time: i64,
         end_time: i64,
     ) -> Result<Vec<crate::types::BounceResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/bounces?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/suppression/bounces"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -110,8 +110,9 @@ impl BouncesApi {
     */
     pub async fn get_suppression_bounces_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<Vec<crate::types::BounceResponse>> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/bounces/{}",
             crate::progenitor_support::encode_path(email),
@@ -131,8 +132,9 @@ impl BouncesApi {
     */
     pub async fn get_all_suppression_bounces_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<Vec<crate::types::BounceResponse>> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/bounces/{}",
             crate::progenitor_support::encode_path(email),
@@ -155,19 +157,21 @@ impl BouncesApi {
     */
     pub async fn delete_suppression_bounces_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
         email_address: &str,
         body: &serde_json::Value,
     ) -> Result<crate::types::Help> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let email = email.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email_address.is_empty() {
-            query_args.push(("email_address".to_string(), email_address.to_string()));
+            query_args.push(("email_address", email_address.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/suppression/bounces/{}?{}",
-            crate::progenitor_support::encode_path(email),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/suppression/bounces/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            &query_args,
         );
 
         self.client
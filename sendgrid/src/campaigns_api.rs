@@ -34,15 +34,14 @@ impl CampaignsApi {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::GetCampaignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/campaigns?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/campaigns"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -87,7 +86,7 @@ impl CampaignsApi {
     ) -> Result<crate::types::GetCampaignsCampaignResponse> {
         let url = format!(
             "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client.get(&url, None).await
@@ -107,7 +106,7 @@ impl CampaignsApi {
     pub async fn delete_campaigns_campaign(&self, campaign_id: i64) -> Result<()> {
         let url = format!(
             "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client.delete(&url, None).await
@@ -133,7 +132,7 @@ impl CampaignsApi {
     ) -> Result<crate::types::CampaignResponseAllOf> {
         let url = format!(
             "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client
@@ -160,7 +159,7 @@ impl CampaignsApi {
     ) -> Result<crate::types::SendACampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules/now",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client.post(&url, None).await
@@ -183,7 +182,7 @@ impl CampaignsApi {
     ) -> Result<crate::types::ScheduleACampaignRequest> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client.get(&url, None).await
@@ -209,7 +208,7 @@ impl CampaignsApi {
     ) -> Result<crate::types::ScheduleACampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client
@@ -234,7 +233,7 @@ impl CampaignsApi {
     pub async fn delete_campaigns_campaign_schedules(&self, campaign_id: i64) -> Result<()> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client.delete(&url, None).await
@@ -258,7 +257,7 @@ impl CampaignsApi {
     ) -> Result<crate::types::UpdateAScheduledCampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client
@@ -286,7 +285,7 @@ impl CampaignsApi {
     ) -> Result<crate::types::SendATestCampaignRequest> {
         let url = format!(
             "/campaigns/{}/schedules/test",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            crate::progenitor_support::encode_path(&campaign_id),
         );
 
         self.client
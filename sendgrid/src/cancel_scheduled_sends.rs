@@ -114,7 +114,11 @@ impl CancelScheduledSends {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn get_mail_batch(&self, batch_id: &str) -> Result<crate::types::MailBatchId> {
+    pub async fn get_mail_batch(
+        &self,
+        batch_id: impl AsRef<str>,
+    ) -> Result<crate::types::MailBatchId> {
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/mail/batch/{}",
             crate::progenitor_support::encode_path(batch_id),
@@ -136,8 +140,9 @@ impl CancelScheduledSends {
     */
     pub async fn get_user_scheduled_sends_batch(
         &self,
-        batch_id: &str,
+        batch_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/user/scheduled_sends/{}",
             crate::progenitor_support::encode_path(batch_id),
@@ -157,8 +162,9 @@ impl CancelScheduledSends {
     */
     pub async fn get_all_user_scheduled_sends_batch(
         &self,
-        batch_id: &str,
+        batch_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/user/scheduled_sends/{}",
             crate::progenitor_support::encode_path(batch_id),
@@ -180,7 +186,8 @@ impl CancelScheduledSends {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_user_scheduled_sends_batch(&self, batch_id: &str) -> Result<()> {
+    pub async fn delete_user_scheduled_sends_batch(&self, batch_id: impl AsRef<str>) -> Result<()> {
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/user/scheduled_sends/{}",
             crate::progenitor_support::encode_path(batch_id),
@@ -204,9 +211,10 @@ impl CancelScheduledSends {
     */
     pub async fn patch_user_scheduled_sends_batch(
         &self,
-        batch_id: &str,
+        batch_id: impl AsRef<str>,
         body: &crate::types::UserScheduledSendStatus,
     ) -> Result<()> {
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/user/scheduled_sends/{}",
             crate::progenitor_support::encode_path(batch_id),
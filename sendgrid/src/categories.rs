@@ -32,18 +32,17 @@ impl Categories {
         category: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !category.is_empty() {
-            query_args.push(("category".to_string(), category.to_string()));
+            query_args.push(("category", category.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/categories"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -62,15 +61,14 @@ impl Categories {
         category: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !category.is_empty() {
-            query_args.push(("category".to_string(), category.to_string()));
+            query_args.push(("category", category.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/categories"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -105,33 +103,32 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<crate::types::CategoryStats> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories/stats/sums?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/categories/stats/sums"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -164,27 +161,27 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::CategoryStats>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !categories.is_empty() {
-            query_args.push(("categories".to_string(), categories.to_string()));
+            query_args.push(("categories", categories.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories/stats?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/categories/stats"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -208,24 +205,24 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::CategoryStats>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !categories.is_empty() {
-            query_args.push(("categories".to_string(), categories.to_string()));
+            query_args.push(("categories", categories.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories/stats?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/categories/stats"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
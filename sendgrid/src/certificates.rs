@@ -40,8 +40,9 @@ impl Certificates {
     */
     pub async fn get_sso_integrations_integration(
         &self,
-        integration_id: &str,
+        integration_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SsoCertificateBody>> {
+        let integration_id = integration_id.as_ref();
         let url = format!(
             "/sso/integrations/{}/certificates",
             crate::progenitor_support::encode_path(integration_id),
@@ -63,8 +64,9 @@ impl Certificates {
     */
     pub async fn get_all_sso_integrations_integration(
         &self,
-        integration_id: &str,
+        integration_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SsoCertificateBody>> {
+        let integration_id = integration_id.as_ref();
         let url = format!(
             "/sso/integrations/{}/certificates",
             crate::progenitor_support::encode_path(integration_id),
@@ -80,7 +82,11 @@ impl Certificates {
     *
     * **This endpoint allows you to retrieve an individual SSO certificate.**
     */
-    pub async fn get_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
+    pub async fn get_sso_cert(
+        &self,
+        cert_id: impl AsRef<str>,
+    ) -> Result<crate::types::SsoCertificateBody> {
+        let cert_id = cert_id.as_ref();
         let url = format!(
             "/sso/certificates/{}",
             crate::progenitor_support::encode_path(cert_id),
@@ -98,7 +104,11 @@ impl Certificates {
     *
     * You can retrieve a certificate's ID from the response provided by the "Get All SSO Integrations" endpoint.
     */
-    pub async fn delete_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
+    pub async fn delete_sso_cert(
+        &self,
+        cert_id: impl AsRef<str>,
+    ) -> Result<crate::types::SsoCertificateBody> {
+        let cert_id = cert_id.as_ref();
         let url = format!(
             "/sso/certificates/{}",
             crate::progenitor_support::encode_path(cert_id),
@@ -118,9 +128,10 @@ impl Certificates {
     */
     pub async fn patch_sso_cert(
         &self,
-        cert_id: &str,
+        cert_id: impl AsRef<str>,
         body: &crate::types::PatchSsoCertificatesCertRequest,
     ) -> Result<Vec<crate::types::SsoErrorResponse>> {
+        let cert_id = cert_id.as_ref();
         let url = format!(
             "/sso/certificates/{}",
             crate::progenitor_support::encode_path(cert_id),
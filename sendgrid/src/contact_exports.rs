@@ -0,0 +1,318 @@
+//! Export contacts in bulk, and stream the exported records as they download.
+//!
+//! An export runs in the background: SendGrid gets the contacts of the lists and
+//! segments ready in one or more files, which are downloaded once the export is
+//! `ready`. The files are decompressed and parsed while they download, one record
+//! at a time, so that exports of millions of contacts are never held in memory.
+//!
+//! ```no_run
+//! # async fn run(sendgrid: sendgrid_api::Client) -> anyhow::Result<()> {
+//! use futures::TryStreamExt;
+//! use sendgrid_api::{
+//!     contacts::ExportedContact,
+//!     types::{FileType, PostMcContactsExportsRequest},
+//! };
+//!
+//! let request = PostMcContactsExportsRequest {
+//!     file_type: Some(FileType::Json),
+//!     list_ids: vec!["ca7a3796-e8a8-4029-9ccb-df8937940562".to_string()],
+//!     max_file_size: 0,
+//!     notifications: None,
+//!     segment_ids: vec![],
+//! };
+//! let mut contacts = Box::pin(
+//!     sendgrid
+//!         .contacts()
+//!         .export_records::<ExportedContact>(&request)
+//!         .await?,
+//! );
+//! while let Some(contact) = contacts.try_next().await? {
+//!     println!("{}", contact.email);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::{collections::VecDeque, io::Write, time::Duration};
+
+use anyhow::{anyhow, Result};
+use futures::{Stream, TryStreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    contacts::Contacts,
+    types::{ContactExport, ContactExportStatus, FileType, PostMcContactsExportsRequest},
+    Client,
+};
+
+/// How long to wait between two checks of the status of an export.
+const EXPORT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A contact as exported by SendGrid. The CSV files name the columns in upper case,
+/// which are read into the same fields.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct ExportedContact {
+    #[serde(default, alias = "CONTACT_ID")]
+    pub contact_id: String,
+    #[serde(default, alias = "EMAIL")]
+    pub email: String,
+    #[serde(default, alias = "FIRST_NAME")]
+    pub first_name: String,
+    #[serde(default, alias = "LAST_NAME")]
+    pub last_name: String,
+    /// The other fields, including the custom fields.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Contacts {
+    /// Start an export of contacts, and wait until its files are ready to download.
+    pub async fn export_and_wait(
+        &self,
+        body: &PostMcContactsExportsRequest,
+    ) -> Result<ContactExport> {
+        let started = self.post_mc_export(body).await?;
+
+        loop {
+            let export = self.get_mc_export(&started.id).await?;
+            match &export.status {
+                ContactExportStatus::Ready => return Ok(export),
+                ContactExportStatus::Failure => {
+                    return Err(anyhow!("export {} failed: {}", export.id, export.message))
+                }
+                _ => crate::runtime::sleep(EXPORT_POLL_INTERVAL).await,
+            }
+        }
+    }
+
+    /// Export contacts, wait for the export to be ready, and stream its records.
+    pub async fn export_records<T>(
+        &self,
+        body: &PostMcContactsExportsRequest,
+    ) -> Result<impl Stream<Item = Result<T>>>
+    where
+        T: DeserializeOwned,
+    {
+        let export = self.export_and_wait(body).await?;
+        let file_type = body.file_type.clone().unwrap_or_default();
+
+        Ok(self.stream_export(&export, file_type))
+    }
+
+    /// Download the files of a ready export one after the other, and parse their
+    /// records as they arrive. `file_type` is the one the export was requested with.
+    pub fn stream_export<T>(
+        &self,
+        export: &ContactExport,
+        file_type: FileType,
+    ) -> impl Stream<Item = Result<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let state = ExportState {
+            client: self.client.clone(),
+            urls: export.urls.iter().cloned().collect(),
+            file_type,
+            body: None,
+            records: None,
+            pending: VecDeque::new(),
+        };
+
+        futures::stream::try_unfold(state, |mut state| async move {
+            loop {
+                if let Some(record) = state.pending.pop_front() {
+                    return Ok(Some((record, state)));
+                }
+
+                if state.body.is_none() {
+                    let url = match state.urls.pop_front() {
+                        Some(url) => url,
+                        None => return Ok(None),
+                    };
+                    state.body = Some(Box::pin(download(&state.client, &url).await?));
+                    state.records = Some(Records::new(state.file_type.clone()));
+                }
+                let body = state.body.as_mut().unwrap();
+                let records = state.records.as_mut().unwrap();
+
+                match body.try_next().await? {
+                    Some(chunk) => records.push(&chunk)?,
+                    None => {
+                        records.finish()?;
+                        state.body = None;
+                    }
+                }
+                for record in records.take() {
+                    state.pending.push_back(serde_json::from_value(record)?);
+                }
+            }
+        })
+    }
+}
+
+/// The state carried between the chunks of an export.
+struct ExportState<T> {
+    client: Client,
+    /// The URLs of the files left to download.
+    urls: VecDeque<String>,
+    file_type: FileType,
+    /// The body of the file being downloaded.
+    body: Option<std::pin::Pin<Box<dyn Stream<Item = Result<bytes::Bytes>> + Send>>>,
+    records: Option<Records>,
+    pending: VecDeque<T>,
+}
+
+/// Download a file of an export. The URLs are signed, so the request is sent
+/// without the API key.
+async fn download(client: &Client, url: &str) -> Result<impl Stream<Item = Result<bytes::Bytes>>> {
+    let req = client.client.get(url).build()?;
+    let resp = client.streaming().execute(req).await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.bytes().await?;
+        return Err(anyhow!(
+            "code: {}, downloading export failed: {:?}",
+            status,
+            String::from_utf8_lossy(&body),
+        ));
+    }
+
+    Ok(resp.bytes_stream().map_err(anyhow::Error::from))
+}
+
+/// Splits the chunks of a file into records, decompressing them first when the
+/// file is gzipped.
+struct Records {
+    file_type: FileType,
+    decoder: Option<Decoder>,
+    /// Decompressed bytes that do not make up a whole record yet.
+    buf: Vec<u8>,
+    /// The names of the columns of a CSV file, from its first line.
+    header: Option<Vec<String>>,
+    records: Vec<serde_json::Value>,
+}
+
+enum Decoder {
+    Plain,
+    Gzip(flate2::write::GzDecoder<Vec<u8>>),
+}
+
+impl Records {
+    fn new(file_type: FileType) -> Self {
+        Records {
+            file_type,
+            decoder: None,
+            buf: Vec::new(),
+            header: None,
+            records: Vec::new(),
+        }
+    }
+
+    /// Add a chunk of the file.
+    fn push(&mut self, chunk: &[u8]) -> Result<()> {
+        let decoder = self.decoder.get_or_insert_with(|| {
+            if chunk.starts_with(&[0x1f, 0x8b]) {
+                Decoder::Gzip(flate2::write::GzDecoder::new(Vec::new()))
+            } else {
+                Decoder::Plain
+            }
+        });
+        match decoder {
+            Decoder::Plain => self.buf.extend_from_slice(chunk),
+            Decoder::Gzip(gz) => {
+                gz.write_all(chunk)?;
+                self.buf.append(gz.get_mut());
+            }
+        }
+
+        self.split(false)
+    }
+
+    /// Parse what is left once the whole file was added.
+    fn finish(&mut self) -> Result<()> {
+        if let Some(Decoder::Gzip(gz)) = self.decoder.take() {
+            self.buf.append(&mut gz.finish()?);
+        }
+
+        self.split(true)
+    }
+
+    /// The records parsed so far.
+    fn take(&mut self) -> Vec<serde_json::Value> {
+        std::mem::take(&mut self.records)
+    }
+
+    /// Parse the complete lines of the buffer, and the last one as well at the end
+    /// of the file.
+    fn split(&mut self, eof: bool) -> Result<()> {
+        let mut start = 0;
+        let mut quoted = false;
+        for i in 0..self.buf.len() {
+            match self.buf[i] {
+                // Line breaks in the quoted values of a CSV file do not end a record.
+                b'"' if self.file_type == FileType::Csv => quoted = !quoted,
+                b'\n' if !quoted => {
+                    let line = self.buf[start..i].to_vec();
+                    self.parse_line(&line)?;
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        self.buf.drain(..start);
+
+        if eof && !self.buf.is_empty() {
+            let line = std::mem::take(&mut self.buf);
+            self.parse_line(&line)?;
+        }
+        Ok(())
+    }
+
+    fn parse_line(&mut self, line: &[u8]) -> Result<()> {
+        let line = std::str::from_utf8(line)?.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        if self.file_type != FileType::Csv {
+            self.records.push(serde_json::from_str(line)?);
+            return Ok(());
+        }
+
+        let fields = csv_fields(line);
+        if self.header.is_none() {
+            self.header = Some(fields);
+            return Ok(());
+        }
+        let record = self
+            .header
+            .iter()
+            .flatten()
+            .cloned()
+            .zip(fields.into_iter().map(serde_json::Value::String))
+            .collect();
+        self.records.push(serde_json::Value::Object(record));
+        Ok(())
+    }
+}
+
+/// Split a CSV record into its values, unquoting them.
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
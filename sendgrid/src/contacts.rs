@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::contact_exports::ExportedContact;
 use crate::Client;
 
 pub struct Contacts {
@@ -82,18 +83,15 @@ impl Contacts {
         delete_all_contacts: &str,
         ids: &str,
     ) -> Result<crate::types::DeleteMcContactsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !delete_all_contacts.is_empty() {
-            query_args.push((
-                "delete_all_contacts".to_string(),
-                delete_all_contacts.to_string(),
-            ));
+            query_args.push(("delete_all_contacts", delete_all_contacts.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/contacts?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/marketing/contacts"), &query_args);
 
         self.client.delete(&url, None).await
     }
@@ -166,7 +164,8 @@ impl Contacts {
     *
     * The "Get Contacts by Emails" endpoint can be used to get the ID of a contact.
     */
-    pub async fn get_mc(&self, id: &str) -> Result<crate::types::ContactDetails3> {
+    pub async fn get_mc(&self, id: impl AsRef<str>) -> Result<crate::types::ContactDetails3> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/contacts/{}",
             crate::progenitor_support::encode_path(id),
@@ -252,7 +251,11 @@ impl Contacts {
     *
     * The `results` object will have fields depending on the job type.
     */
-    pub async fn get_marketing_import(&self, id: &str) -> Result<crate::types::ContactImport> {
+    pub async fn get_marketing_import(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::ContactImport> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/contacts/imports/{}",
             crate::progenitor_support::encode_path(id),
@@ -272,7 +275,8 @@ impl Contacts {
     *
     * If you would like to download a list, take the `id` that is returned from the "Export Contacts" endpoint and make an API request here to get the `urls`. Once you have the list of URLs, make a `GET` request on each URL to download your CSV file(s).
     */
-    pub async fn get_mc_export(&self, id: &str) -> Result<crate::types::ContactExport> {
+    pub async fn get_mc_export(&self, id: impl AsRef<str>) -> Result<crate::types::ContactExport> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/contacts/exports/{}",
             crate::progenitor_support::encode_path(id),
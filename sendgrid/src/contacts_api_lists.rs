@@ -81,8 +81,9 @@ impl ContactsApiLists {
     */
     pub async fn get_contactdb_lists_list(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
     ) -> Result<crate::types::ContactdbList> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/contactdb/lists/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -105,19 +106,21 @@ impl ContactsApiLists {
     */
     pub async fn delete_contactdb_lists_list(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         delete_contacts: bool,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let list_id = list_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delete_contacts {
-            query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/lists/{}?{}",
-            crate::progenitor_support::encode_path(list_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/contactdb/lists/{}",
+                crate::progenitor_support::encode_path(list_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -139,9 +142,10 @@ impl ContactsApiLists {
     */
     pub async fn patch_contactdb_lists_list(
         &self,
-        list_id: &str,
+        list_id: impl AsRef<str>,
         body: &crate::types::IpPool,
     ) -> Result<crate::types::PatchContactdbListsListResponse> {
+        let list_id = list_id.as_ref();
         let url = format!(
             "/contactdb/lists/{}",
             crate::progenitor_support::encode_path(list_id),
@@ -172,18 +176,19 @@ impl ContactsApiLists {
         page: i64,
         page_size: i64,
     ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/lists/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/contactdb/lists/{}/recipients",
+                crate::progenitor_support::encode_path(&list_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -209,7 +214,7 @@ impl ContactsApiLists {
     ) -> Result<()> {
         let url = format!(
             "/contactdb/lists/{}/recipients",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
+            crate::progenitor_support::encode_path(&list_id),
         );
 
         self.client
@@ -231,11 +236,12 @@ impl ContactsApiLists {
     pub async fn post_contactdb_lists_list_recipients_recipient(
         &self,
         list_id: i64,
-        recipient_id: &str,
+        recipient_id: impl AsRef<str>,
     ) -> Result<()> {
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/contactdb/lists/{}/recipients/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
+            crate::progenitor_support::encode_path(&list_id),
             crate::progenitor_support::encode_path(recipient_id),
         );
 
@@ -258,12 +264,13 @@ impl ContactsApiLists {
     pub async fn delete_contactdb_lists_list_recipients_recipient(
         &self,
         list_id: i64,
-        recipient_id: &str,
+        recipient_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/contactdb/lists/{}/recipients/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
+            crate::progenitor_support::encode_path(&list_id),
             crate::progenitor_support::encode_path(recipient_id),
         );
 
@@ -33,15 +33,17 @@ impl ContactsApiRecipients {
         page: i64,
         page_size: i64,
     ) -> Result<crate::types::ListRecipientsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contactdb/recipients?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/contactdb/recipients"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -147,8 +149,9 @@ impl ContactsApiRecipients {
     */
     pub async fn get_contactdb_recipients_recipient(
         &self,
-        recipient_id: &str,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::ContactdbRecipient> {
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/contactdb/recipients/{}",
             crate::progenitor_support::encode_path(recipient_id),
@@ -172,8 +175,9 @@ impl ContactsApiRecipients {
     */
     pub async fn delete_contactdb_recipients_recipient(
         &self,
-        recipient_id: &str,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/contactdb/recipients/{}",
             crate::progenitor_support::encode_path(recipient_id),
@@ -197,8 +201,9 @@ impl ContactsApiRecipients {
     */
     pub async fn get_contactdb_recipients_recipient_lists(
         &self,
-        recipient_id: &str,
+        recipient_id: impl AsRef<str>,
     ) -> Result<crate::types::GetContactdbRecipientsRecipientListsResponse> {
+        let recipient_id = recipient_id.as_ref();
         let url = format!(
             "/contactdb/recipients/{}/lists",
             crate::progenitor_support::encode_path(recipient_id),
@@ -270,12 +275,14 @@ impl ContactsApiRecipients {
         &self,
         field_name: &str,
     ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !field_name.is_empty() {
-            query_args.push(("{field_name}".to_string(), field_name.to_string()));
+            query_args.push(("{field_name}", field_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contactdb/recipients/search?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/contactdb/recipients/search"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -102,8 +102,9 @@ impl ContactsApiSegments {
     */
     pub async fn get_contactdb_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
     ) -> Result<crate::types::ContactdbSegments> {
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/contactdb/segments/{}",
             crate::progenitor_support::encode_path(segment_id),
@@ -128,19 +129,21 @@ impl ContactsApiSegments {
     */
     pub async fn delete_contactdb_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
         delete_contacts: bool,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let segment_id = segment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delete_contacts {
-            query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/segments/{}?{}",
-            crate::progenitor_support::encode_path(segment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/contactdb/segments/{}",
+                crate::progenitor_support::encode_path(segment_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -162,9 +165,10 @@ impl ContactsApiSegments {
     */
     pub async fn patch_contactdb_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
         body: &crate::types::PatchContactdbSegmentsSegmentRequest,
     ) -> Result<crate::types::ContactdbSegments> {
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/contactdb/segments/{}",
             crate::progenitor_support::encode_path(segment_id),
@@ -194,18 +198,19 @@ impl ContactsApiSegments {
         page: i64,
         page_size: i64,
     ) -> Result<crate::types::ListRecipientsOnASegmentResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/segments/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/contactdb/segments/{}/recipients",
+                crate::progenitor_support::encode_path(&segment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -34,12 +34,12 @@ impl CsvUiOnly {
         &self,
         query: &str,
     ) -> Result<crate::types::PostMessagesDownloadResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/messages/download?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/messages/download"), &query_args);
 
         self.client.post(&url, None).await
     }
@@ -57,8 +57,9 @@ impl CsvUiOnly {
     */
     pub async fn get_messages_download(
         &self,
-        download_uuid: &str,
+        download_uuid: impl AsRef<str>,
     ) -> Result<crate::types::GetMessagesDownloadResponse> {
+        let download_uuid = download_uuid.as_ref();
         let url = format!(
             "/messages/download/{}",
             crate::progenitor_support::encode_path(download_uuid),
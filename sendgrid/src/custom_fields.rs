@@ -58,7 +58,11 @@ impl CustomFields {
     *
     * You cand delete only Custom Fields; Reserved Fields cannot be deleted.
     */
-    pub async fn delete_mc_field_definitions_custom(&self, custom_field_id: &str) -> Result<()> {
+    pub async fn delete_mc_field_definitions_custom(
+        &self,
+        custom_field_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let custom_field_id = custom_field_id.as_ref();
         let url = format!(
             "/marketing/field_definitions/{}",
             crate::progenitor_support::encode_path(custom_field_id),
@@ -78,9 +82,10 @@ impl CustomFields {
     */
     pub async fn patch_mc_field_definitions_custom(
         &self,
-        custom_field_id: &str,
+        custom_field_id: impl AsRef<str>,
         body: &crate::types::IpPool,
     ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        let custom_field_id = custom_field_id.as_ref();
         let url = format!(
             "/marketing/field_definitions/{}",
             crate::progenitor_support::encode_path(custom_field_id),
@@ -23,11 +23,9 @@ impl DesignsApi {
     *
     * This endpoint is valuable when retrieving information stored in a field that you wish to update using a PATCH request.
     */
-    pub async fn get_design(&self, id: &str) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get_design(&self, id: impl AsRef<str>) -> Result<crate::types::DesignOutputAllOf> {
+        let id = id.as_ref();
+        let url = format!("/designs/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
@@ -48,13 +46,11 @@ impl DesignsApi {
     */
     pub async fn post_duplicate_design(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::DesignDuplicateInput,
     ) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+        let id = id.as_ref();
+        let url = format!("/designs/{}", crate::progenitor_support::encode_path(id),);
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -70,11 +66,9 @@ impl DesignsApi {
     *
     * Be sure to check the ID of the design you intend to delete before making this request; deleting a design is a permanent action.
     */
-    pub async fn delete_design(&self, id: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn delete_design(&self, id: impl AsRef<str>) -> Result<crate::types::Help> {
+        let id = id.as_ref();
+        let url = format!("/designs/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.delete(&url, None).await
     }
@@ -98,13 +92,11 @@ impl DesignsApi {
     */
     pub async fn put_design(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PutDesignRequest,
     ) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+        let id = id.as_ref();
+        let url = format!("/designs/{}", crate::progenitor_support::encode_path(id),);
 
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -134,18 +126,17 @@ impl DesignsApi {
         page_token: &str,
         summary: bool,
     ) -> Result<crate::types::ListDesignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if summary {
-            query_args.push(("summary".to_string(), summary.to_string()));
+            query_args.push(("summary", summary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/designs?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/designs"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -186,8 +177,9 @@ impl DesignsApi {
     */
     pub async fn get_sendgrid_pre_built_design(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DesignOutputAllOf> {
+        let id = id.as_ref();
         let url = format!(
             "/designs/pre-builts/{}",
             crate::progenitor_support::encode_path(id),
@@ -210,9 +202,10 @@ impl DesignsApi {
     */
     pub async fn post_sendgrid_pre_built_design(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::DesignDuplicateInput,
     ) -> Result<crate::types::DesignOutputAllOf> {
+        let id = id.as_ref();
         let url = format!(
             "/designs/pre-builts/{}",
             crate::progenitor_support::encode_path(id),
@@ -248,18 +241,18 @@ impl DesignsApi {
         page_token: &str,
         summary: bool,
     ) -> Result<crate::types::ListDesignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if summary {
-            query_args.push(("summary".to_string(), summary.to_string()));
+            query_args.push(("summary", summary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/designs/pre-builts?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/designs/pre-builts"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -36,24 +36,24 @@ impl DomainAuthentication {
         username: &str,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if exclude_subusers {
-            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+            query_args.push(("exclude_subusers", exclude_subusers.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/whitelabel/domains"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -74,21 +74,21 @@ impl DomainAuthentication {
         username: &str,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if exclude_subusers {
-            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+            query_args.push(("exclude_subusers", exclude_subusers.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/whitelabel/domains"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -131,8 +131,9 @@ impl DomainAuthentication {
     */
     pub async fn get_whitelabel_domains_domain(
         &self,
-        domain_id: &str,
+        domain_id: impl AsRef<str>,
     ) -> Result<crate::types::AuthenticationDomain> {
+        let domain_id = domain_id.as_ref();
         let url = format!(
             "/whitelabel/domains/{}",
             crate::progenitor_support::encode_path(domain_id),
@@ -154,8 +155,9 @@ impl DomainAuthentication {
     */
     pub async fn delete_whitelabel_domains_domain(
         &self,
-        domain_id: &str,
+        domain_id: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let domain_id = domain_id.as_ref();
         let url = format!(
             "/whitelabel/domains/{}",
             crate::progenitor_support::encode_path(domain_id),
@@ -177,9 +179,10 @@ impl DomainAuthentication {
     */
     pub async fn patch_whitelabel_domains_domain(
         &self,
-        domain_id: &str,
+        domain_id: impl AsRef<str>,
         body: &crate::types::PatchWhitelabelDomainsDomainRequest,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        let domain_id = domain_id.as_ref();
         let url = format!(
             "/whitelabel/domains/{}",
             crate::progenitor_support::encode_path(domain_id),
@@ -210,12 +213,14 @@ impl DomainAuthentication {
         &self,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/default?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/whitelabel/domains/default"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -237,12 +242,14 @@ impl DomainAuthentication {
         &self,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/default?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/whitelabel/domains/default"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -265,7 +272,7 @@ impl DomainAuthentication {
     ) -> Result<crate::types::DomainAuthentication> {
         let url = format!(
             "/whitelabel/domains/{}/ips",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client
@@ -287,11 +294,12 @@ impl DomainAuthentication {
     pub async fn delete_whitelabel_domains_ips_ip(
         &self,
         id: i64,
-        ip: &str,
+        ip: impl AsRef<str>,
     ) -> Result<crate::types::DomainAuthentication> {
+        let ip = ip.as_ref();
         let url = format!(
             "/whitelabel/domains/{}/ips/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
             crate::progenitor_support::encode_path(ip),
         );
 
@@ -315,7 +323,7 @@ impl DomainAuthentication {
     ) -> Result<crate::types::PostWhitelabelDomainsValidateResponse> {
         let url = format!(
             "/whitelabel/domains/{}/validate",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client.post(&url, None).await
@@ -338,12 +346,14 @@ impl DomainAuthentication {
         &self,
         username: &str,
     ) -> Result<crate::types::DomainAuthentication> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/subuser?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/whitelabel/domains/subuser"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -365,12 +375,14 @@ impl DomainAuthentication {
         &self,
         username: &str,
     ) -> Result<crate::types::Help> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/subuser?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/whitelabel/domains/subuser"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -391,7 +403,7 @@ impl DomainAuthentication {
     ) -> Result<crate::types::DomainAuthentication> {
         let url = format!(
             "/whitelabel/domains/{}/subuser",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
+            crate::progenitor_support::encode_path(&domain_id),
         );
 
         self.client
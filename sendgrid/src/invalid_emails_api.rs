@@ -34,21 +34,23 @@ impl InvalidEmailsApi {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/invalid_emails?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/suppression/invalid_emails"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -68,18 +70,20 @@ impl InvalidEmailsApi {
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/invalid_emails?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/suppression/invalid_emails"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -123,8 +127,9 @@ impl InvalidEmailsApi {
     */
     pub async fn get_suppression_invalid_emails_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/invalid_emails/{}",
             crate::progenitor_support::encode_path(email),
@@ -144,8 +149,9 @@ impl InvalidEmailsApi {
     */
     pub async fn get_all_suppression_invalid_emails_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/invalid_emails/{}",
             crate::progenitor_support::encode_path(email),
@@ -167,8 +173,9 @@ impl InvalidEmailsApi {
     */
     pub async fn delete_suppression_invalid_emails_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/invalid_emails/{}",
             crate::progenitor_support::encode_path(email),
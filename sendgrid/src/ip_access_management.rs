@@ -94,12 +94,14 @@ impl IpAccessManagement {
         &self,
         limit: i64,
     ) -> Result<crate::types::GetAccessSettingsActivityResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/access_settings/activity?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/access_settings/activity"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -119,8 +121,9 @@ impl IpAccessManagement {
     */
     pub async fn get_access_settings_whitelist_rule(
         &self,
-        rule_id: &str,
+        rule_id: impl AsRef<str>,
     ) -> Result<crate::types::IpAccessResponse> {
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/access_settings/whitelist/{}",
             crate::progenitor_support::encode_path(rule_id),
@@ -144,8 +147,9 @@ impl IpAccessManagement {
     */
     pub async fn delete_access_settings_whitelist_rule(
         &self,
-        rule_id: &str,
+        rule_id: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let rule_id = rule_id.as_ref();
         let url = format!(
             "/access_settings/whitelist/{}",
             crate::progenitor_support::encode_path(rule_id),
@@ -41,33 +41,26 @@ impl IpAddresses {
         subuser: &str,
         sort_by_direction: crate::types::SortByDirection,
     ) -> Result<Vec<crate::types::GetIpsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if exclude_whitelabels {
-            query_args.push((
-                "exclude_whitelabels".to_string(),
-                exclude_whitelabels.to_string(),
-            ));
+            query_args.push(("exclude_whitelabels", exclude_whitelabels.to_string()));
         }
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !subuser.is_empty() {
-            query_args.push(("subuser".to_string(), subuser.to_string()));
+            query_args.push(("subuser", subuser.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ips?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/ips"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -93,30 +86,23 @@ impl IpAddresses {
         subuser: &str,
         sort_by_direction: crate::types::SortByDirection,
     ) -> Result<Vec<crate::types::GetIpsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if exclude_whitelabels {
-            query_args.push((
-                "exclude_whitelabels".to_string(),
-                exclude_whitelabels.to_string(),
-            ));
+            query_args.push(("exclude_whitelabels", exclude_whitelabels.to_string()));
         }
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !subuser.is_empty() {
-            query_args.push(("subuser".to_string(), subuser.to_string()));
+            query_args.push(("subuser", subuser.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ips?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/ips"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -193,8 +179,9 @@ impl IpAddresses {
     */
     pub async fn get_ips_ip_address(
         &self,
-        ip_address: &str,
+        ip_address: impl AsRef<str>,
     ) -> Result<crate::types::GetIpsIpAddressResponse> {
+        let ip_address = ip_address.as_ref();
         let url = format!(
             "/ips/{}",
             crate::progenitor_support::encode_path(ip_address),
@@ -84,9 +84,10 @@ impl IpPools {
     */
     pub async fn post_ips_pools_pool_name_ip(
         &self,
-        pool_name: &str,
+        pool_name: impl AsRef<str>,
         body: &crate::types::PostIpsWarmupRequest,
     ) -> Result<crate::types::GetIpsAssignedResponse> {
+        let pool_name = pool_name.as_ref();
         let url = format!(
             "/ips/pools/{}/ips",
             crate::progenitor_support::encode_path(pool_name),
@@ -106,8 +107,9 @@ impl IpPools {
     */
     pub async fn get_ips_pools_pool_name(
         &self,
-        pool_name: &str,
+        pool_name: impl AsRef<str>,
     ) -> Result<crate::types::GetIpsPoolsPoolNameResponse> {
+        let pool_name = pool_name.as_ref();
         let url = format!(
             "/ips/pools/{}",
             crate::progenitor_support::encode_path(pool_name),
@@ -125,9 +127,10 @@ impl IpPools {
     */
     pub async fn put_ips_pools_pool_name(
         &self,
-        pool_name: &str,
+        pool_name: impl AsRef<str>,
         body: &crate::types::PutIpsPoolsPoolNameRequest,
     ) -> Result<crate::types::IpPoolsPoolResp> {
+        let pool_name = pool_name.as_ref();
         let url = format!(
             "/ips/pools/{}",
             crate::progenitor_support::encode_path(pool_name),
@@ -145,7 +148,11 @@ impl IpPools {
     *
     * **This endpoint allows you to delete an IP pool.**
     */
-    pub async fn delete_ips_pools_pool_name(&self, pool_name: &str) -> Result<crate::types::Help> {
+    pub async fn delete_ips_pools_pool_name(
+        &self,
+        pool_name: impl AsRef<str>,
+    ) -> Result<crate::types::Help> {
+        let pool_name = pool_name.as_ref();
         let url = format!(
             "/ips/pools/{}",
             crate::progenitor_support::encode_path(pool_name),
@@ -163,9 +170,11 @@ impl IpPools {
     */
     pub async fn delete_ips_pools_pool_name_ip(
         &self,
-        pool_name: &str,
-        ip: &str,
+        pool_name: impl AsRef<str>,
+        ip: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let pool_name = pool_name.as_ref();
+        let ip = ip.as_ref();
         let url = format!(
             "/ips/pools/{}/ips/{}",
             crate::progenitor_support::encode_path(pool_name),
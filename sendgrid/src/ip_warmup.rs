@@ -66,8 +66,9 @@ impl IpWarmup {
     */
     pub async fn get_ips_warmup_ip_address(
         &self,
-        ip_address: &str,
+        ip_address: impl AsRef<str>,
     ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        let ip_address = ip_address.as_ref();
         let url = format!(
             "/ips/warmup/{}",
             crate::progenitor_support::encode_path(ip_address),
@@ -89,8 +90,9 @@ impl IpWarmup {
     */
     pub async fn get_all_ips_warmup_ip_address(
         &self,
-        ip_address: &str,
+        ip_address: impl AsRef<str>,
     ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        let ip_address = ip_address.as_ref();
         let url = format!(
             "/ips/warmup/{}",
             crate::progenitor_support::encode_path(ip_address),
@@ -110,8 +112,9 @@ impl IpWarmup {
     */
     pub async fn delete_ips_warmup_ip_address(
         &self,
-        ip_address: &str,
+        ip_address: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let ip_address = ip_address.as_ref();
         let url = format!(
             "/ips/warmup/{}",
             crate::progenitor_support::encode_path(ip_address),
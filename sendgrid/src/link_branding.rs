@@ -30,12 +30,12 @@ impl LinkBranding {
         &self,
         limit: i64,
     ) -> Result<Vec<crate::types::LinkBranding200Response>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/whitelabel/links"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -102,7 +102,7 @@ impl LinkBranding {
     ) -> Result<crate::types::PostWhitelabelLinksValidateResponse> {
         let url = format!(
             "/whitelabel/links/{}/validate",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client.post(&url, None).await
@@ -124,7 +124,7 @@ impl LinkBranding {
     ) -> Result<crate::types::LinkBranding200Response> {
         let url = format!(
             "/whitelabel/links/{}/subuser",
-            crate::progenitor_support::encode_path(&link_id.to_string()),
+            crate::progenitor_support::encode_path(&link_id),
         );
 
         self.client
@@ -151,7 +151,7 @@ impl LinkBranding {
     ) -> Result<crate::types::LinkBranding200Response> {
         let url = format!(
             "/whitelabel/links/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client.get(&url, None).await
@@ -175,7 +175,7 @@ impl LinkBranding {
     pub async fn delete_whitelabel_links(&self, id: i64) -> Result<crate::types::Help> {
         let url = format!(
             "/whitelabel/links/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client.delete(&url, None).await
@@ -201,7 +201,7 @@ impl LinkBranding {
     ) -> Result<crate::types::LinkBranding200Response> {
         let url = format!(
             "/whitelabel/links/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client
@@ -233,12 +233,14 @@ impl LinkBranding {
         &self,
         domain: &str,
     ) -> Result<crate::types::LinkBranding200Response> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links/default?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/whitelabel/links/default"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -260,12 +262,14 @@ impl LinkBranding {
         &self,
         username: &str,
     ) -> Result<crate::types::LinkBranding200Response> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links/subuser?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/whitelabel/links/subuser"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -289,12 +293,14 @@ impl LinkBranding {
         &self,
         username: &str,
     ) -> Result<crate::types::Help> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links/subuser?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/whitelabel/links/subuser"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
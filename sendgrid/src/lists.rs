@@ -29,15 +29,15 @@ impl Lists {
         page_size: f64,
         page_token: &str,
     ) -> Result<crate::types::GetMcListsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/lists?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/marketing/lists"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -69,8 +69,9 @@ impl Lists {
     */
     pub async fn get_mc_contacts_count(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::GetMcListsContactsCountResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/lists/{}/contacts/count",
             crate::progenitor_support::encode_path(id),
@@ -96,18 +97,20 @@ impl Lists {
     */
     pub async fn get_mc_lists(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         contact_sample: bool,
     ) -> Result<crate::types::GetMcListsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if contact_sample {
-            query_args.push(("contact_sample".to_string(), contact_sample.to_string()));
+            query_args.push(("contact_sample", contact_sample.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/lists/{}?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/lists/{}",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -128,18 +131,20 @@ impl Lists {
     */
     pub async fn delete(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         delete_contacts: bool,
     ) -> Result<crate::types::DeleteListsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delete_contacts {
-            query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/lists/{}?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/lists/{}",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -154,9 +159,10 @@ impl Lists {
     */
     pub async fn patch_mc(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PatchMcListsRequest,
     ) -> Result<crate::types::List> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/lists/{}",
             crate::progenitor_support::encode_path(id),
@@ -182,18 +188,20 @@ impl Lists {
     */
     pub async fn delete_mc_contacts(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         contact_ids: &str,
     ) -> Result<crate::types::DeleteMcListsContactsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !contact_ids.is_empty() {
-            query_args.push(("contact_ids".to_string(), contact_ids.to_string()));
+            query_args.push(("contact_ids", contact_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/lists/{}/contacts?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/lists/{}/contacts",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
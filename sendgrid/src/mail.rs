@@ -0,0 +1,357 @@
+//! Build mail with personalizations, templates and attachments, and send it with
+//! `MailSend::send`.
+//!
+//! When SendGrid rejects the mail, the error is an `ApiError` listing what was
+//! wrong, which can be matched on with `downcast_ref`.
+//!
+//! ```no_run
+//! # async fn run(sendgrid: sendgrid_api::Client) -> anyhow::Result<()> {
+//! use sendgrid_api::mail::{Address, MailBuilder, Personalization};
+//!
+//! let invoice = tokio::fs::File::open("invoice.pdf").await?;
+//! let mail = MailBuilder::new(Address::new("billing@example.com").with_name("Example"))
+//!     .template_id("d-13b8f94fbcae4ec6b75270d6cb59f932")
+//!     .personalization(
+//!         Personalization::to(Address::new("jane@example.com"))
+//!             .with_cc(Address::new("accounting@example.com"))
+//!             .with_template_data(serde_json::json!({"name": "Jane", "total": "$42.00"})),
+//!     )
+//!     .category("invoices")
+//!     .attach("invoice.pdf", "application/pdf", invoice)
+//!     .await?;
+//!
+//! sendgrid.mail_send().send(&mail).await?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    mail_send::MailSend,
+    types::{Attachments, Content},
+};
+
+/// An email address, with the name to show along with it.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Address {
+    pub email: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+}
+
+/// The recipients of the mail, with what is specific to them.
+#[derive(Serialize, Default, Debug, Clone, PartialEq)]
+pub struct Personalization {
+    pub to: Vec<Address>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cc: Vec<Address>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<Address>,
+    /// Replaces the subject of the mail for these recipients.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// The data filled into the dynamic template of the mail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dynamic_template_data: Option<serde_json::Value>,
+}
+
+/// Mail to send, built up from its sender.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MailBuilder {
+    personalizations: Vec<Personalization>,
+    from: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to: Option<Address>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    subject: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    content: Vec<Content>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<Attachments>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    template_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    categories: Vec<String>,
+}
+
+/// The errors SendGrid returned for a mail it did not accept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub status: reqwest::StatusCode,
+    pub errors: Vec<ErrorItem>,
+}
+
+/// Something that was wrong with the mail.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ErrorItem {
+    #[serde(default)]
+    pub message: String,
+    /// The field of the mail the error is about, like `personalizations.0.to`.
+    #[serde(default)]
+    pub field: Option<String>,
+    /// A link to the documentation about the error.
+    #[serde(default)]
+    pub help: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    errors: Vec<ErrorItem>,
+}
+
+impl Address {
+    pub fn new(email: &str) -> Self {
+        Address {
+            email: email.to_string(),
+            name: String::new(),
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+impl From<&str> for Address {
+    fn from(email: &str) -> Self {
+        Address::new(email)
+    }
+}
+
+impl Personalization {
+    /// A personalization for the given recipient, to add more to.
+    pub fn to(address: Address) -> Self {
+        Personalization {
+            to: vec![address],
+            ..Default::default()
+        }
+    }
+
+    pub fn with_to(mut self, address: Address) -> Self {
+        self.to.push(address);
+        self
+    }
+
+    pub fn with_cc(mut self, address: Address) -> Self {
+        self.cc.push(address);
+        self
+    }
+
+    pub fn with_bcc(mut self, address: Address) -> Self {
+        self.bcc.push(address);
+        self
+    }
+
+    pub fn with_subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.to_string());
+        self
+    }
+
+    /// The data to fill the dynamic template with, usually a JSON object.
+    pub fn with_template_data(mut self, data: serde_json::Value) -> Self {
+        self.dynamic_template_data = Some(data);
+        self
+    }
+}
+
+impl MailBuilder {
+    pub fn new(from: Address) -> Self {
+        MailBuilder {
+            personalizations: Vec::new(),
+            from,
+            reply_to: None,
+            subject: String::new(),
+            content: Vec::new(),
+            attachments: Vec::new(),
+            template_id: String::new(),
+            categories: Vec::new(),
+        }
+    }
+
+    // The personalization `to`, `cc` and `bcc` add to.
+    fn first_personalization(&mut self) -> &mut Personalization {
+        if self.personalizations.is_empty() {
+            self.personalizations.push(Default::default());
+        }
+        &mut self.personalizations[0]
+    }
+
+    /// Send the mail to this recipient, along with the ones of the first
+    /// personalization.
+    pub fn to(mut self, address: Address) -> Self {
+        self.first_personalization().to.push(address);
+        self
+    }
+
+    pub fn cc(mut self, address: Address) -> Self {
+        self.first_personalization().cc.push(address);
+        self
+    }
+
+    pub fn bcc(mut self, address: Address) -> Self {
+        self.first_personalization().bcc.push(address);
+        self
+    }
+
+    /// Send the mail to other recipients as well, each personalization gets its own
+    /// copy of the mail.
+    pub fn personalization(mut self, personalization: Personalization) -> Self {
+        self.personalizations.push(personalization);
+        self
+    }
+
+    pub fn reply_to(mut self, address: Address) -> Self {
+        self.reply_to = Some(address);
+        self
+    }
+
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.subject = subject.to_string();
+        self
+    }
+
+    /// The plain text body of the mail.
+    pub fn text(mut self, text: &str) -> Self {
+        self.content.retain(|c| c.type_ != "text/plain");
+        // The plain text has to come before the HTML.
+        self.content.insert(
+            0,
+            Content {
+                type_: "text/plain".to_string(),
+                value: text.to_string(),
+            },
+        );
+        self
+    }
+
+    /// The HTML body of the mail.
+    pub fn html(mut self, html: &str) -> Self {
+        self.content.retain(|c| c.type_ != "text/html");
+        self.content.push(Content {
+            type_: "text/html".to_string(),
+            value: html.to_string(),
+        });
+        self
+    }
+
+    /// Send the mail with this dynamic template, which the template data of each
+    /// personalization is filled into.
+    pub fn template_id(mut self, template_id: &str) -> Self {
+        self.template_id = template_id.to_string();
+        self
+    }
+
+    /// Set the template data of the first personalization.
+    pub fn template_data(mut self, data: serde_json::Value) -> Self {
+        self.first_personalization().dynamic_template_data = Some(data);
+        self
+    }
+
+    /// Add a category to track the mail by, at most 10 of them are allowed.
+    pub fn category(mut self, category: &str) -> Self {
+        self.categories.push(category.to_string());
+        self
+    }
+
+    /// Attach a file to the mail.
+    pub fn attach_bytes(mut self, filename: &str, content_type: &str, contents: &[u8]) -> Self {
+        self.attachments.push(Attachments {
+            content: base64::encode(contents),
+            content_id: String::new(),
+            disposition: Some(crate::types::Disposition::Attachment),
+            filename: filename.to_string(),
+            type_: content_type.to_string(),
+        });
+        self
+    }
+
+    /// Attach a file read from `reader` to the mail, encoding it as it is read.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn attach<R>(
+        mut self,
+        filename: &str,
+        content_type: &str,
+        mut reader: R,
+    ) -> Result<Self>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        let mut content = String::new();
+        // A multiple of 3 bytes, so the pieces encode to the encoding of the whole.
+        let mut buf = vec![0u8; 3 * 16 * 1024];
+        let mut filled = 0;
+        loop {
+            let n = reader.read(&mut buf[filled..]).await?;
+            filled += n;
+            if n == 0 || filled == buf.len() {
+                base64::encode_config_buf(&buf[..filled], base64::STANDARD, &mut content);
+                filled = 0;
+            }
+            if n == 0 {
+                break;
+            }
+        }
+
+        self.attachments.push(Attachments {
+            content,
+            content_id: String::new(),
+            disposition: Some(crate::types::Disposition::Attachment),
+            filename: filename.to_string(),
+            type_: content_type.to_string(),
+        });
+        Ok(self)
+    }
+}
+
+impl MailSend {
+    /// Send the mail, SendGrid queues it to be delivered.
+    pub async fn send(&self, mail: &MailBuilder) -> Result<()> {
+        let resp = self
+            .client
+            .request_raw(
+                reqwest::Method::POST,
+                "/mail/send",
+                Some(reqwest::Body::from(serde_json::to_vec(mail)?)),
+            )
+            .await?;
+
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = resp.bytes().await?;
+        match serde_json::from_slice::<ErrorBody>(&body) {
+            Ok(e) => Err(anyhow!(ApiError {
+                status,
+                errors: e.errors,
+            })),
+            Err(_) => Err(anyhow!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&body),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "code: {}", self.status)?;
+        for (i, e) in self.errors.iter().enumerate() {
+            write!(f, "{} {}", if i == 0 { "," } else { ";" }, e.message)?;
+            if let Some(field) = &e.field {
+                write!(f, " ({})", field)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
@@ -37,18 +37,20 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::AutomationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !automation_ids.is_empty() {
-            query_args.push(("automation_ids".to_string(), automation_ids.join(" ")));
+            query_args.push(("automation_ids", automation_ids.join(" ")));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/automations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/stats/automations"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -77,7 +79,7 @@ impl MarketingCampaignsStats {
     */
     pub async fn get_automation_stat(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         group_by: &[String],
         step_ids: &[String],
         aggregated_by: crate::types::AggregatedBy,
@@ -87,36 +89,38 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::AutomationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.to_string().is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(" ")));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !start_date.to_string().is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
         if !step_ids.is_empty() {
-            query_args.push(("step_ids".to_string(), step_ids.join(" ")));
+            query_args.push(("step_ids", step_ids.join(" ")));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/automations/{}?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+            query_args.push(("timezone", timezone.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/stats/automations/{}",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -147,18 +151,20 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::SinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !singlesend_ids.is_empty() {
-            query_args.push(("singlesend_ids".to_string(), singlesend_ids.join(" ")));
+            query_args.push(("singlesend_ids", singlesend_ids.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/singlesends?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/stats/singlesends"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -186,7 +192,7 @@ impl MarketingCampaignsStats {
     */
     pub async fn get_singlesend_stat(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         aggregated_by: crate::types::AggregatedBy,
         start_date: chrono::NaiveDate,
         end_date: chrono::NaiveDate,
@@ -195,33 +201,35 @@ impl MarketingCampaignsStats {
         page_token: &str,
         group_by: &[String],
     ) -> Result<crate::types::SinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.to_string().is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(" ")));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !start_date.to_string().is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/singlesends/{}?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+            query_args.push(("timezone", timezone.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/stats/singlesends/{}",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -249,30 +257,32 @@ impl MarketingCampaignsStats {
     */
     pub async fn get_automation_link_stat(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         group_by: &[String],
         step_ids: &[String],
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::AutomationsLinkStatsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(" ")));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !step_ids.is_empty() {
-            query_args.push(("step_ids".to_string(), step_ids.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/automations/{}/links?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+            query_args.push(("step_ids", step_ids.join(" ")));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/stats/automations/{}/links",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -301,34 +311,36 @@ impl MarketingCampaignsStats {
     */
     pub async fn get_singlesend_link_stat(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         page_size: i64,
         page_token: &str,
         group_by: &[String],
         ab_variation_id: &str,
         ab_phase_id: crate::types::AbPhaseId,
     ) -> Result<crate::types::SinglesendsLinkStatsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ab_phase_id.to_string().is_empty() {
-            query_args.push(("ab_phase_id".to_string(), ab_phase_id.to_string()));
+            query_args.push(("ab_phase_id", ab_phase_id.to_string()));
         }
         if !ab_variation_id.is_empty() {
-            query_args.push(("ab_variation_id".to_string(), ab_variation_id.to_string()));
+            query_args.push(("ab_variation_id", ab_variation_id.to_string()));
         }
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(" ")));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/singlesends/{}/links?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+            query_args.push(("page_token", page_token.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/stats/singlesends/{}/links",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -355,15 +367,17 @@ impl MarketingCampaignsStats {
         ids: &[String],
         timezone: &str,
     ) -> Result<String> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.join(" ")));
+            query_args.push(("ids", ids.join(" ")));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
+            query_args.push(("timezone", timezone.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/singlesends/export?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/stats/singlesends/export"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -389,15 +403,17 @@ impl MarketingCampaignsStats {
         ids: &[String],
         timezone: &str,
     ) -> Result<String> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.join(" ")));
+            query_args.push(("ids", ids.join(" ")));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
+            query_args.push(("timezone", timezone.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/automations/export?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/stats/automations/export"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -0,0 +1,324 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: sendgrid_api::Client) {
+//! use sendgrid_api::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "SendGrid";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/access_settings/activity",
+    "/access_settings/whitelist",
+    "/access_settings/whitelist/{rule_id}",
+    "/alerts",
+    "/alerts/{alert_id}",
+    "/api_keys",
+    "/api_keys/{api_key_id}",
+    "/asm/groups",
+    "/asm/groups/{group_id}",
+    "/asm/groups/{group_id}/suppressions",
+    "/asm/groups/{group_id}/suppressions/search",
+    "/asm/groups/{group_id}/suppressions/{email}",
+    "/asm/suppressions",
+    "/asm/suppressions/global",
+    "/asm/suppressions/global/{email}",
+    "/asm/suppressions/{email}",
+    "/browsers/stats",
+    "/campaigns",
+    "/campaigns/{campaign_id}",
+    "/campaigns/{campaign_id}/schedules",
+    "/campaigns/{campaign_id}/schedules/now",
+    "/campaigns/{campaign_id}/schedules/test",
+    "/categories",
+    "/categories/stats",
+    "/categories/stats/sums",
+    "/clients/stats",
+    "/clients/{client_type}/stats",
+    "/contactdb/custom_fields",
+    "/contactdb/custom_fields/{custom_field_id}",
+    "/contactdb/lists",
+    "/contactdb/lists/{list_id}",
+    "/contactdb/lists/{list_id}/recipients",
+    "/contactdb/lists/{list_id}/recipients/{recipient_id}",
+    "/contactdb/recipients",
+    "/contactdb/recipients/billable_count",
+    "/contactdb/recipients/count",
+    "/contactdb/recipients/search",
+    "/contactdb/recipients/{recipient_id}",
+    "/contactdb/recipients/{recipient_id}/lists",
+    "/contactdb/reserved_fields",
+    "/contactdb/segments",
+    "/contactdb/segments/{segment_id}",
+    "/contactdb/segments/{segment_id}/recipients",
+    "/contactdb/status",
+    "/designs",
+    "/designs/pre-builts",
+    "/designs/pre-builts/{id}",
+    "/designs/{id}",
+    "/devices/stats",
+    "/geo/stats",
+    "/ips",
+    "/ips/assigned",
+    "/ips/pools",
+    "/ips/pools/{pool_name}",
+    "/ips/pools/{pool_name}/ips",
+    "/ips/pools/{pool_name}/ips/{ip}",
+    "/ips/remaining",
+    "/ips/warmup",
+    "/ips/warmup/{ip_address}",
+    "/ips/{ip_address}",
+    "/mail/batch",
+    "/mail/batch/{batch_id}",
+    "/mail/send",
+    "/mail_settings",
+    "/mail_settings/address_whitelist",
+    "/mail_settings/bounce_purge",
+    "/mail_settings/footer",
+    "/mail_settings/forward_bounce",
+    "/mail_settings/forward_spam",
+    "/mail_settings/template",
+    "/mailbox_providers/stats",
+    "/marketing/contacts",
+    "/marketing/contacts/batch",
+    "/marketing/contacts/count",
+    "/marketing/contacts/exports",
+    "/marketing/contacts/exports/{id}",
+    "/marketing/contacts/imports",
+    "/marketing/contacts/imports/{id}",
+    "/marketing/contacts/search",
+    "/marketing/contacts/search/emails",
+    "/marketing/contacts/{id}",
+    "/marketing/field_definitions",
+    "/marketing/field_definitions/{custom_field_id}",
+    "/marketing/lists",
+    "/marketing/lists/{id}",
+    "/marketing/lists/{id}/contacts",
+    "/marketing/lists/{id}/contacts/count",
+    "/marketing/segments",
+    "/marketing/segments/2.0",
+    "/marketing/segments/2.0/{segment_id}",
+    "/marketing/segments/delete",
+    "/marketing/segments/{segment_id}",
+    "/marketing/senders",
+    "/marketing/singlesends",
+    "/marketing/singlesends/categories",
+    "/marketing/singlesends/search",
+    "/marketing/singlesends/{id}",
+    "/marketing/singlesends/{id}/schedule",
+    "/marketing/stats/automations",
+    "/marketing/stats/automations/export",
+    "/marketing/stats/automations/{id}",
+    "/marketing/stats/automations/{id}/links",
+    "/marketing/stats/singlesends",
+    "/marketing/stats/singlesends/export",
+    "/marketing/stats/singlesends/{id}",
+    "/marketing/stats/singlesends/{id}/links",
+    "/marketing/test/send_email",
+    "/messages",
+    "/messages/download",
+    "/messages/download/{download_uuid}",
+    "/messages/{msg_id}",
+    "/partner_settings",
+    "/partner_settings/new_relic",
+    "/scopes",
+    "/scopes/requests",
+    "/scopes/requests/{request_id}",
+    "/scopes/requests/{request_id}/approve",
+    "/senders",
+    "/senders/{sender_id}",
+    "/senders/{sender_id}/resend_verification",
+    "/sso/certificates",
+    "/sso/certificates/{cert_id}",
+    "/sso/integrations",
+    "/sso/integrations/{id}",
+    "/sso/integrations/{integration_id}/certificates",
+    "/sso/teammates",
+    "/sso/teammates/{username}",
+    "/stats",
+    "/subusers",
+    "/subusers/reputations",
+    "/subusers/stats",
+    "/subusers/stats/monthly",
+    "/subusers/stats/sums",
+    "/subusers/{subuser_name}",
+    "/subusers/{subuser_name}/ips",
+    "/subusers/{subuser_name}/monitor",
+    "/subusers/{subuser_name}/stats/monthly",
+    "/suppression/blocks",
+    "/suppression/blocks/{email}",
+    "/suppression/bounces",
+    "/suppression/bounces/{email}",
+    "/suppression/invalid_emails",
+    "/suppression/invalid_emails/{email}",
+    "/suppression/spam_reports",
+    "/suppression/spam_reports/{email}",
+    "/suppression/unsubscribes",
+    "/teammates",
+    "/teammates/pending",
+    "/teammates/pending/{token}",
+    "/teammates/pending/{token}/resend",
+    "/teammates/{username}",
+    "/templates",
+    "/templates/{template_id}",
+    "/templates/{template_id}/versions",
+    "/templates/{template_id}/versions/{version_id}",
+    "/templates/{template_id}/versions/{version_id}/activate",
+    "/tracking_settings",
+    "/tracking_settings/click",
+    "/tracking_settings/google_analytics",
+    "/tracking_settings/open",
+    "/tracking_settings/subscription",
+    "/user/account",
+    "/user/credits",
+    "/user/email",
+    "/user/password",
+    "/user/profile",
+    "/user/scheduled_sends",
+    "/user/scheduled_sends/{batch_id}",
+    "/user/settings/enforced_tls",
+    "/user/username",
+    "/user/webhooks/event/settings",
+    "/user/webhooks/event/settings/signed",
+    "/user/webhooks/event/test",
+    "/user/webhooks/parse/settings",
+    "/user/webhooks/parse/settings/{hostname}",
+    "/user/webhooks/parse/stats",
+    "/validations/email",
+    "/verified_senders",
+    "/verified_senders/domains",
+    "/verified_senders/resend/{id}",
+    "/verified_senders/steps_completed",
+    "/verified_senders/verify/{token}",
+    "/verified_senders/{id}",
+    "/whitelabel/dns/email",
+    "/whitelabel/domains",
+    "/whitelabel/domains/default",
+    "/whitelabel/domains/subuser",
+    "/whitelabel/domains/{domain_id}",
+    "/whitelabel/domains/{domain_id}/subuser",
+    "/whitelabel/domains/{id}/ips",
+    "/whitelabel/domains/{id}/ips/{ip}",
+    "/whitelabel/domains/{id}/validate",
+    "/whitelabel/ips",
+    "/whitelabel/ips/{id}",
+    "/whitelabel/ips/{id}/validate",
+    "/whitelabel/links",
+    "/whitelabel/links/default",
+    "/whitelabel/links/subuser",
+    "/whitelabel/links/{id}",
+    "/whitelabel/links/{id}/validate",
+    "/whitelabel/links/{link_id}/subuser",
+];
@@ -71,15 +71,14 @@ impl Query {
         query: &str,
         limit: f64,
     ) -> Result<crate::types::GetMessagesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.to_string().is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/messages?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/messages"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -97,7 +96,8 @@ impl Query {
     *
     * * `authorization: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn get_messages_msg(&self, msg_id: &str) -> Result<crate::types::Message> {
+    pub async fn get_messages_msg(&self, msg_id: impl AsRef<str>) -> Result<crate::types::Message> {
+        let msg_id = msg_id.as_ref();
         let url = format!(
             "/messages/{}",
             crate::progenitor_support::encode_path(msg_id),
@@ -38,18 +38,18 @@ impl ReverseDns {
         offset: i64,
         ip: &str,
     ) -> Result<Vec<crate::types::ReverseDns>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/ips?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/whitelabel/ips"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -74,15 +74,15 @@ impl ReverseDns {
         offset: i64,
         ip: &str,
     ) -> Result<Vec<crate::types::ReverseDns>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/ips?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/whitelabel/ips"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -127,8 +127,9 @@ impl ReverseDns {
     */
     pub async fn post_whitelabel_ips_validate(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::PostWhitelabelIpsValidateResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/whitelabel/ips/{}/validate",
             crate::progenitor_support::encode_path(id),
@@ -150,7 +151,8 @@ impl ReverseDns {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn get_whitelabel_ip(&self, id: &str) -> Result<crate::types::ReverseDns> {
+    pub async fn get_whitelabel_ip(&self, id: impl AsRef<str>) -> Result<crate::types::ReverseDns> {
+        let id = id.as_ref();
         let url = format!(
             "/whitelabel/ips/{}",
             crate::progenitor_support::encode_path(id),
@@ -174,7 +176,8 @@ impl ReverseDns {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_whitelabel_ips(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_whitelabel_ips(&self, id: impl AsRef<str>) -> Result<crate::types::Help> {
+        let id = id.as_ref();
         let url = format!(
             "/whitelabel/ips/{}",
             crate::progenitor_support::encode_path(id),
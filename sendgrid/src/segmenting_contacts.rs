@@ -38,18 +38,15 @@ impl SegmentingContacts {
         parent_list_ids: &str,
         no_parent_list_id: bool,
     ) -> Result<crate::types::GetMarketingSegmentsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if no_parent_list_id {
-            query_args.push((
-                "no_parent_list_id".to_string(),
-                no_parent_list_id.to_string(),
-            ));
+            query_args.push(("no_parent_list_id", no_parent_list_id.to_string()));
         }
         if !parent_list_ids.is_empty() {
-            query_args.push(("parent_list_ids".to_string(), parent_list_ids.to_string()));
+            query_args.push(("parent_list_ids", parent_list_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/segments?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/marketing/segments"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -84,18 +81,20 @@ impl SegmentingContacts {
     */
     pub async fn get_marketing_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
         query_json: bool,
     ) -> Result<crate::types::FullSegmentAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let segment_id = segment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if query_json {
-            query_args.push(("query_json".to_string(), query_json.to_string()));
+            query_args.push(("query_json", query_json.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/segments/{}?{}",
-            crate::progenitor_support::encode_path(segment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/segments/{}",
+                crate::progenitor_support::encode_path(segment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -112,8 +111,9 @@ impl SegmentingContacts {
     */
     pub async fn delete_marketing_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/marketing/segments/{}",
             crate::progenitor_support::encode_path(segment_id),
@@ -133,9 +133,10 @@ impl SegmentingContacts {
     */
     pub async fn patch_marketing_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
         body: &crate::types::SegmentWriteV2,
     ) -> Result<crate::types::FullSegmentAllOf> {
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/marketing/segments/{}",
             crate::progenitor_support::encode_path(segment_id),
@@ -38,18 +38,17 @@ impl SegmentingContactsBeta {
         parent_list_ids: &str,
         no_parent_list_id: bool,
     ) -> Result<crate::types::AllSegmentsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if no_parent_list_id {
-            query_args.push((
-                "no_parent_list_id".to_string(),
-                no_parent_list_id.to_string(),
-            ));
+            query_args.push(("no_parent_list_id", no_parent_list_id.to_string()));
         }
         if !parent_list_ids.is_empty() {
-            query_args.push(("parent_list_ids".to_string(), parent_list_ids.to_string()));
+            query_args.push(("parent_list_ids", parent_list_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/segments/2.0?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/segments/2.0"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -86,18 +85,20 @@ impl SegmentingContactsBeta {
     */
     pub async fn get_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
         contacts_sample: bool,
     ) -> Result<crate::types::SegmentResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let segment_id = segment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if contacts_sample {
-            query_args.push(("contacts_sample".to_string(), contacts_sample.to_string()));
+            query_args.push(("contacts_sample", contacts_sample.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/segments/2.0/{}?{}",
-            crate::progenitor_support::encode_path(segment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/marketing/segments/2.0/{}",
+                crate::progenitor_support::encode_path(segment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -110,7 +111,8 @@ impl SegmentingContactsBeta {
     *
     * **The Segmentation V2 API is currently in private beta. If you'd like to be added to the beta, please fill out this [form](https://docs.google.com/forms/d/e/1FAIpQLSd5zwC9dRk8lAp1oTWjdGc-aSY69flW_7wnutvKBhpUluSnfQ/viewform)**
     */
-    pub async fn delete_segments_segment(&self, segment_id: &str) -> Result<()> {
+    pub async fn delete_segments_segment(&self, segment_id: impl AsRef<str>) -> Result<()> {
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/marketing/segments/2.0/{}",
             crate::progenitor_support::encode_path(segment_id),
@@ -130,9 +132,10 @@ impl SegmentingContactsBeta {
     */
     pub async fn patch_segments_segment(
         &self,
-        segment_id: &str,
+        segment_id: impl AsRef<str>,
         body: &crate::types::SegmentUpdate,
     ) -> Result<crate::types::SegmentResponse> {
+        let segment_id = segment_id.as_ref();
         let url = format!(
             "/marketing/segments/2.0/{}",
             crate::progenitor_support::encode_path(segment_id),
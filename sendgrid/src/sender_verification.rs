@@ -75,18 +75,18 @@ impl SenderVerification {
         last_seen_id: f64,
         id: i64,
     ) -> Result<crate::types::GetVerifiedSendersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if id > 0 {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
         if !last_seen_id.to_string().is_empty() {
-            query_args.push(("lastSeenID".to_string(), last_seen_id.to_string()));
+            query_args.push(("lastSeenID", last_seen_id.to_string()));
         }
         if !limit.to_string().is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/verified_senders?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/verified_senders"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -123,7 +123,8 @@ impl SenderVerification {
     *
     * The token is generated by SendGrid and included in a verification email delivered to the address that's pending verification.
     */
-    pub async fn get_verified_senders_verify_token(&self, token: &str) -> Result<()> {
+    pub async fn get_verified_senders_verify_token(&self, token: impl AsRef<str>) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/verified_senders/verify/{}",
             crate::progenitor_support::encode_path(token),
@@ -143,7 +144,8 @@ impl SenderVerification {
     *
     * You can retrieve the IDs associated with Sender Identities using the "Get All Verified Senders" endpoint.
     */
-    pub async fn delete_verified_senders(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_verified_senders(&self, id: impl AsRef<str>) -> Result<crate::types::Help> {
+        let id = id.as_ref();
         let url = format!(
             "/verified_senders/{}",
             crate::progenitor_support::encode_path(id),
@@ -167,9 +169,10 @@ impl SenderVerification {
     */
     pub async fn patch_verified_senders(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::VerifiedSenderRequestSchema,
     ) -> Result<crate::types::VerifiedSenderResponseSchema> {
+        let id = id.as_ref();
         let url = format!(
             "/verified_senders/{}",
             crate::progenitor_support::encode_path(id),
@@ -191,7 +194,11 @@ impl SenderVerification {
     *
     * You can retrieve the IDs associated with Sender Identities by passing a "Get All Verified Senders" endpoint.
     */
-    pub async fn post_verified_senders_resend(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn post_verified_senders_resend(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::Help> {
+        let id = id.as_ref();
         let url = format!(
             "/verified_senders/resend/{}",
             crate::progenitor_support::encode_path(id),
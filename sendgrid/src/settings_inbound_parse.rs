@@ -56,8 +56,9 @@ impl SettingsInboundParse {
     */
     pub async fn get_user_webhooks_parse_settings_hostname(
         &self,
-        hostname: &str,
+        hostname: impl AsRef<str>,
     ) -> Result<crate::types::ParseSetting> {
+        let hostname = hostname.as_ref();
         let url = format!(
             "/user/webhooks/parse/settings/{}",
             crate::progenitor_support::encode_path(hostname),
@@ -81,8 +82,9 @@ impl SettingsInboundParse {
     */
     pub async fn delete_user_webhooks_parse_settings_hostname(
         &self,
-        hostname: &str,
+        hostname: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let hostname = hostname.as_ref();
         let url = format!(
             "/user/webhooks/parse/settings/{}",
             crate::progenitor_support::encode_path(hostname),
@@ -106,9 +108,10 @@ impl SettingsInboundParse {
     */
     pub async fn patch_user_webhooks_parse_settings_hostname(
         &self,
-        hostname: &str,
+        hostname: impl AsRef<str>,
         body: &crate::types::ParseSetting,
     ) -> Result<crate::types::ParseSetting> {
+        let hostname = hostname.as_ref();
         let url = format!(
             "/user/webhooks/parse/settings/{}",
             crate::progenitor_support::encode_path(hostname),
@@ -79,15 +79,15 @@ impl SettingsPartner {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::GetPartnerSettingsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/partner_settings?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/partner_settings"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -31,15 +31,17 @@ impl SingleSends {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/singlesends?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/singlesends"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -79,12 +81,14 @@ impl SingleSends {
     * * `ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
     */
     pub async fn delete_marketing_singlesends(&self, ids: &[String]) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.join(" ")));
+            query_args.push(("ids", ids.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/singlesends?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/singlesends"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -100,8 +104,9 @@ impl SingleSends {
     */
     pub async fn get_marketing_singlesend(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::SinglesendResponseAllOf> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/singlesends/{}",
             crate::progenitor_support::encode_path(id),
@@ -123,9 +128,10 @@ impl SingleSends {
     */
     pub async fn post_marketing_singlesend_single_sends(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PostMarketingSinglesendsRequest,
     ) -> Result<crate::types::SinglesendResponseAllOf> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/singlesends/{}",
             crate::progenitor_support::encode_path(id),
@@ -147,7 +153,11 @@ impl SingleSends {
     *
     * Please note that a `DELETE` request is permanent, and your Single Send will not be recoverable after deletion.
     */
-    pub async fn delete_marketing_singlesends_single_sends(&self, id: &str) -> Result<()> {
+    pub async fn delete_marketing_singlesends_single_sends(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<()> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/singlesends/{}",
             crate::progenitor_support::encode_path(id),
@@ -167,9 +177,10 @@ impl SingleSends {
     */
     pub async fn patch_marketing_singlesends(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::SinglesendRequest,
     ) -> Result<crate::types::SinglesendResponseAllOf> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/singlesends/{}",
             crate::progenitor_support::encode_path(id),
@@ -214,15 +225,17 @@ impl SingleSends {
         page_token: &str,
         body: &crate::types::SinglesendSearch,
     ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/singlesends/search?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/marketing/singlesends/search"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -240,9 +253,10 @@ impl SingleSends {
     */
     pub async fn put_marketing_singlesends_schedule(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         body: &crate::types::PutMarketingSinglesendsScheduleRequest,
     ) -> Result<crate::types::PutMarketingSinglesendsScheduleResponse> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/singlesends/{}/schedule",
             crate::progenitor_support::encode_path(id),
@@ -264,8 +278,9 @@ impl SingleSends {
     */
     pub async fn delete_marketing_singlesends_schedule(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::SinglesendSchedule> {
+        let id = id.as_ref();
         let url = format!(
             "/marketing/singlesends/{}/schedule",
             crate::progenitor_support::encode_path(id),
@@ -29,12 +29,12 @@ impl SingleSignOnSettings {
         &self,
         si: bool,
     ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sso/integrations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/sso/integrations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -54,12 +54,12 @@ impl SingleSignOnSettings {
         &self,
         si: bool,
     ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sso/integrations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/sso/integrations"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -96,18 +96,20 @@ impl SingleSignOnSettings {
     */
     pub async fn get_sso_integration(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         si: bool,
     ) -> Result<crate::types::SsoIntegrationAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/sso/integrations/{}?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/sso/integrations/{}",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -122,7 +124,8 @@ impl SingleSignOnSettings {
     *
     * You can retrieve the IDs for your configurations from the response provided by the "Get All SSO Integrations" endpoint.
     */
-    pub async fn delete_sso_integrations(&self, id: &str) -> Result<()> {
+    pub async fn delete_sso_integrations(&self, id: impl AsRef<str>) -> Result<()> {
+        let id = id.as_ref();
         let url = format!(
             "/sso/integrations/{}",
             crate::progenitor_support::encode_path(id),
@@ -146,19 +149,21 @@ impl SingleSignOnSettings {
     */
     pub async fn patch_sso_integrations(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
         si: bool,
         body: &crate::types::CreateIntegrationRequest,
     ) -> Result<crate::types::SsoIntegrationAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/sso/integrations/{}?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/sso/integrations/{}",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -44,9 +44,10 @@ impl SingleSignOnTeammates {
     */
     pub async fn patch_sso_teammates_username(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         body: &crate::types::PatchSsoTeammatesUsernameRequest,
     ) -> Result<crate::types::SsoTeammatesPatchResponseAllOf> {
+        let username = username.as_ref();
         let url = format!(
             "/sso/teammates/{}",
             crate::progenitor_support::encode_path(username),
@@ -34,21 +34,23 @@ impl SpamReportsApi {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/spam_reports?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/suppression/spam_reports"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -68,18 +70,20 @@ impl SpamReportsApi {
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/spam_reports?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/suppression/spam_reports"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -125,8 +129,9 @@ impl SpamReportsApi {
     */
     pub async fn get_suppression_spam_reports_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/spam_reports/{}",
             crate::progenitor_support::encode_path(email),
@@ -146,8 +151,9 @@ impl SpamReportsApi {
     */
     pub async fn get_all_suppression_spam_reports_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/spam_reports/{}",
             crate::progenitor_support::encode_path(email),
@@ -171,8 +177,9 @@ impl SpamReportsApi {
     */
     pub async fn delete_suppression_spam_reports_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let email = email.as_ref();
         let url = format!(
             "/suppression/spam_reports/{}",
             crate::progenitor_support::encode_path(email),
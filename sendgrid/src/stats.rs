@@ -37,21 +37,20 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/stats"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -74,21 +73,20 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/stats"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -122,24 +120,23 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !country.to_string().is_empty() {
-            query_args.push(("country".to_string(), country.to_string()));
+            query_args.push(("country", country.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/geo/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/geo/stats"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -165,24 +162,23 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !country.to_string().is_empty() {
-            query_args.push(("country".to_string(), country.to_string()));
+            query_args.push(("country", country.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/geo/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/geo/stats"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -223,21 +219,20 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/devices/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/devices/stats"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -271,21 +266,20 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/devices/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/devices/stats"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -314,18 +308,17 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/clients/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/clients/stats"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -349,18 +342,17 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/clients/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/clients/stats"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -396,21 +388,22 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/clients/{}/stats?{}",
-            crate::progenitor_support::encode_path(&client_type.to_string()),
-            query_
+            query_args.push(("start_date", start_date.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/clients/{}/stats",
+                crate::progenitor_support::encode_path(&client_type),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -442,21 +435,22 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/clients/{}/stats?{}",
-            crate::progenitor_support::encode_path(&client_type.to_string()),
-            query_
+            query_args.push(("start_date", start_date.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/clients/{}/stats",
+                crate::progenitor_support::encode_path(&client_type),
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -491,27 +485,26 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !mailbox_providers.is_empty() {
-            query_args.push((
-                "mailbox_providers".to_string(),
-                mailbox_providers.to_string(),
-            ));
+            query_args.push(("mailbox_providers", mailbox_providers.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/mailbox_providers/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/mailbox_providers/stats"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -537,27 +530,26 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !mailbox_providers.is_empty() {
-            query_args.push((
-                "mailbox_providers".to_string(),
-                mailbox_providers.to_string(),
-            ));
+            query_args.push(("mailbox_providers", mailbox_providers.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/mailbox_providers/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/mailbox_providers/stats"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -591,24 +583,24 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !browsers.is_empty() {
-            query_args.push(("browsers".to_string(), browsers.to_string()));
+            query_args.push(("browsers", browsers.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/browsers/stats?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/browsers/stats"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -634,24 +626,24 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !browsers.is_empty() {
-            query_args.push(("browsers".to_string(), browsers.to_string()));
+            query_args.push(("browsers", browsers.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/browsers/stats?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/browsers/stats"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
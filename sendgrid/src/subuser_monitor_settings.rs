@@ -19,8 +19,9 @@ impl SubuserMonitorSettings {
     */
     pub async fn get_subusers_subuser_name_monitor(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
     ) -> Result<crate::types::Monitor> {
+        let subuser_name = subuser_name.as_ref();
         let url = format!(
             "/subusers/{}/monitor",
             crate::progenitor_support::encode_path(subuser_name),
@@ -36,9 +37,10 @@ impl SubuserMonitorSettings {
     */
     pub async fn put_subusers_subuser_name_monitor(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
         body: &crate::types::Monitor,
     ) -> Result<crate::types::Monitor> {
+        let subuser_name = subuser_name.as_ref();
         let url = format!(
             "/subusers/{}/monitor",
             crate::progenitor_support::encode_path(subuser_name),
@@ -56,9 +58,10 @@ impl SubuserMonitorSettings {
     */
     pub async fn post_subusers_subuser_name_monitor(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
         body: &crate::types::Monitor,
     ) -> Result<crate::types::Monitor> {
+        let subuser_name = subuser_name.as_ref();
         let url = format!(
             "/subusers/{}/monitor",
             crate::progenitor_support::encode_path(subuser_name),
@@ -76,8 +79,9 @@ impl SubuserMonitorSettings {
     */
     pub async fn delete_subusers_subuser_name_monitor(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let subuser_name = subuser_name.as_ref();
         let url = format!(
             "/subusers/{}/monitor",
             crate::progenitor_support::encode_path(subuser_name),
@@ -32,37 +32,36 @@ impl SubuserStatistics {
     */
     pub async fn get_subusers_subuser_name_stats_monthly(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
         date: &str,
         sort_by_metric: &str,
         sort_by_direction: crate::types::SortByDirection,
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::SubuserStatsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let subuser_name = subuser_name.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/subusers/{}/stats/monthly?{}",
-            crate::progenitor_support::encode_path(subuser_name),
-            query_
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/subusers/{}/stats/monthly",
+                crate::progenitor_support::encode_path(subuser_name),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -96,30 +95,29 @@ impl SubuserStatistics {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::SubuserStatsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.to_string().is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
         }
         if !subuser.is_empty() {
-            query_args.push(("subuser".to_string(), subuser.to_string()));
+            query_args.push(("subuser", subuser.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/stats/monthly?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/subusers/stats/monthly"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -151,33 +149,30 @@ impl SubuserStatistics {
         aggregated_by: &str,
         sort_by_metric: &str,
     ) -> Result<crate::types::CategoryStats> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/stats/sums?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/subusers/stats/sums"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -209,27 +204,27 @@ impl SubuserStatistics {
         start_date: &str,
         end_date: &str,
     ) -> Result<crate::types::CategoryStats> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
         if !subusers.is_empty() {
-            query_args.push(("subusers".to_string(), subusers.to_string()));
+            query_args.push(("subusers", subusers.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/stats?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/subusers/stats"), &query_args);
 
         self.client.get(&url, None).await
     }
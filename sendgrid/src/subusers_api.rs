@@ -33,18 +33,17 @@ impl SubusersApi {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::Subuser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/subusers"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -65,15 +64,14 @@ impl SubusersApi {
         username: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::Subuser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/subusers"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -106,8 +104,9 @@ impl SubusersApi {
     */
     pub async fn delete_subusers_subuser_name(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
     ) -> Result<crate::types::Help> {
+        let subuser_name = subuser_name.as_ref();
         let url = format!(
             "/subusers/{}",
             crate::progenitor_support::encode_path(subuser_name),
@@ -125,9 +124,10 @@ impl SubusersApi {
     */
     pub async fn patch_subusers_subuser_name(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
         body: &crate::types::PatchSubusersSubuserNameRequest,
     ) -> Result<crate::types::Help> {
+        let subuser_name = subuser_name.as_ref();
         let url = format!(
             "/subusers/{}",
             crate::progenitor_support::encode_path(subuser_name),
@@ -155,12 +155,14 @@ impl SubusersApi {
         &self,
         usernames: &str,
     ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !usernames.is_empty() {
-            query_args.push(("usernames".to_string(), usernames.to_string()));
+            query_args.push(("usernames", usernames.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/reputations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/subusers/reputations"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -180,12 +182,14 @@ impl SubusersApi {
         &self,
         usernames: &str,
     ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !usernames.is_empty() {
-            query_args.push(("usernames".to_string(), usernames.to_string()));
+            query_args.push(("usernames", usernames.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/reputations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/subusers/reputations"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -206,9 +210,10 @@ impl SubusersApi {
     */
     pub async fn put_subusers_subuser_name_ips(
         &self,
-        subuser_name: &str,
+        subuser_name: impl AsRef<str>,
         body: &[std::net::Ipv4Addr],
     ) -> Result<crate::types::PutSubusersSubuserNameIpsResponse> {
+        let subuser_name = subuser_name.as_ref();
         let url = format!(
             "/subusers/{}/ips",
             crate::progenitor_support::encode_path(subuser_name),
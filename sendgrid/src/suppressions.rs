@@ -23,7 +23,8 @@ impl Suppressions {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn get_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
+    pub async fn get_asm_groups_group(&self, group_id: impl AsRef<str>) -> Result<Vec<String>> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/asm/groups/{}/suppressions",
             crate::progenitor_support::encode_path(group_id),
@@ -41,7 +42,8 @@ impl Suppressions {
     *
     * **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
     */
-    pub async fn get_all_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
+    pub async fn get_all_asm_groups_group(&self, group_id: impl AsRef<str>) -> Result<Vec<String>> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/asm/groups/{}/suppressions",
             crate::progenitor_support::encode_path(group_id),
@@ -65,9 +67,10 @@ impl Suppressions {
     */
     pub async fn post_asm_groups_group(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::SuppressionsRequestBody,
     ) -> Result<crate::types::PostAsmGroupsGroupSuppressionsResponse> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/asm/groups/{}/suppressions",
             crate::progenitor_support::encode_path(group_id),
@@ -93,9 +96,10 @@ impl Suppressions {
     */
     pub async fn post_asm_groups_group_search(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::SuppressionsRequestBody,
     ) -> Result<Vec<String>> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/asm/groups/{}/suppressions/search",
             crate::progenitor_support::encode_path(group_id),
@@ -149,8 +153,9 @@ impl Suppressions {
     */
     pub async fn get_asm_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<crate::types::GetAsmSuppressionsEmailResponse> {
+        let email = email.as_ref();
         let url = format!(
             "/asm/suppressions/{}",
             crate::progenitor_support::encode_path(email),
@@ -172,7 +177,13 @@ impl Suppressions {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_asm_groups_group_email(&self, group_id: &str, email: &str) -> Result<()> {
+    pub async fn delete_asm_groups_group_email(
+        &self,
+        group_id: impl AsRef<str>,
+        email: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let email = email.as_ref();
         let url = format!(
             "/asm/groups/{}/suppressions/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -55,21 +55,23 @@ impl SuppressionsGlobal {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/unsubscribes?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/suppression/unsubscribes"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -89,18 +91,20 @@ impl SuppressionsGlobal {
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/unsubscribes?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/suppression/unsubscribes"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
@@ -120,8 +124,9 @@ impl SuppressionsGlobal {
     */
     pub async fn get_asm_email(
         &self,
-        email: &str,
+        email: impl AsRef<str>,
     ) -> Result<crate::types::RetrieveAGlobalSuppressionResponse> {
+        let email = email.as_ref();
         let url = format!(
             "/asm/suppressions/global/{}",
             crate::progenitor_support::encode_path(email),
@@ -143,7 +148,8 @@ impl SuppressionsGlobal {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_asm_email(&self, email: &str) -> Result<crate::types::Help> {
+    pub async fn delete_asm_email(&self, email: impl AsRef<str>) -> Result<crate::types::Help> {
+        let email = email.as_ref();
         let url = format!(
             "/asm/suppressions/global/{}",
             crate::progenitor_support::encode_path(email),
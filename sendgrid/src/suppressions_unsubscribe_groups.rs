@@ -27,12 +27,11 @@ impl SuppressionsUnsubscribeGroups {
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
     pub async fn get_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if id > 0 {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/asm/groups?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/asm/groups"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -49,12 +48,11 @@ impl SuppressionsUnsubscribeGroups {
     * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
     */
     pub async fn get_all_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if id > 0 {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/asm/groups?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/asm/groups"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -95,8 +93,9 @@ impl SuppressionsUnsubscribeGroups {
     */
     pub async fn get_asm_groups_group(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
     ) -> Result<crate::types::GetAsmGroupsGroupResponseAllOf> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/asm/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -120,7 +119,11 @@ impl SuppressionsUnsubscribeGroups {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_asm_groups_group(&self, group_id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_asm_groups_group(
+        &self,
+        group_id: impl AsRef<str>,
+    ) -> Result<crate::types::Help> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/asm/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -142,9 +145,10 @@ impl SuppressionsUnsubscribeGroups {
     */
     pub async fn patch_asm_groups_group(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::SuppressionGroupRequestBase,
     ) -> Result<crate::types::SuppressionGroup> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/asm/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -28,15 +28,14 @@ impl Teammates {
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
     pub async fn get(&self, limit: u64, offset: u64) -> Result<crate::types::GetTeammatesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.to_string().is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !offset.to_string().is_empty() {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teammates?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/teammates"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -81,8 +80,9 @@ impl Teammates {
     */
     pub async fn post_pending_token_resend(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<crate::types::PostTeammatesResponse> {
+        let token = token.as_ref();
         let url = format!(
             "/teammates/pending/{}/resend",
             crate::progenitor_support::encode_path(token),
@@ -110,15 +110,15 @@ impl Teammates {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/scopes/requests?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/scopes/requests"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -138,12 +138,12 @@ impl Teammates {
         &self,
         offset: i64,
     ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/scopes/requests?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/scopes/requests"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -181,8 +181,9 @@ impl Teammates {
     */
     pub async fn get_username(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::GetTeammatesUsernameResponse> {
+        let username = username.as_ref();
         let url = format!(
             "/teammates/{}",
             crate::progenitor_support::encode_path(username),
@@ -206,8 +207,9 @@ impl Teammates {
     */
     pub async fn delete_username(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
     ) -> Result<crate::types::PostSendersResponse> {
+        let username = username.as_ref();
         let url = format!(
             "/teammates/{}",
             crate::progenitor_support::encode_path(username),
@@ -235,9 +237,10 @@ impl Teammates {
     */
     pub async fn patch_username(
         &self,
-        username: &str,
+        username: impl AsRef<str>,
         body: &crate::types::PatchTeammatesUsernameRequest,
     ) -> Result<crate::types::GetTeammatesUsernameResponse> {
+        let username = username.as_ref();
         let url = format!(
             "/teammates/{}",
             crate::progenitor_support::encode_path(username),
@@ -259,8 +262,9 @@ impl Teammates {
     */
     pub async fn patch_scopes_requests_approve(
         &self,
-        request_id: &str,
+        request_id: impl AsRef<str>,
     ) -> Result<crate::types::PatchScopesRequestsApproveResponse> {
+        let request_id = request_id.as_ref();
         let url = format!(
             "/scopes/requests/{}/approve",
             crate::progenitor_support::encode_path(request_id),
@@ -278,7 +282,8 @@ impl Teammates {
     *
     * **Note:** Only teammate admins may delete a teammate's access request.
     */
-    pub async fn delete_scopes_requests_request(&self, request_id: &str) -> Result<()> {
+    pub async fn delete_scopes_requests_request(&self, request_id: impl AsRef<str>) -> Result<()> {
+        let request_id = request_id.as_ref();
         let url = format!(
             "/scopes/requests/{}",
             crate::progenitor_support::encode_path(request_id),
@@ -298,7 +303,8 @@ impl Teammates {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_pending_token(&self, token: &str) -> Result<()> {
+    pub async fn delete_pending_token(&self, token: impl AsRef<str>) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/teammates/pending/{}",
             crate::progenitor_support::encode_path(token),
@@ -1 +1,143 @@
+use crate::mail::{Address, MailBuilder, Personalization};
 
+#[tokio::test]
+async fn test_mail_builder() {
+    // More than one read buffer, and not a multiple of 3 bytes.
+    let invoice: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    let mail = MailBuilder::new(Address::new("billing@example.com").with_name("Example"))
+        .to(Address::new("jane@example.com"))
+        .bcc(Address::new("archive@example.com"))
+        .template_id("d-13b8f94fbcae4ec6b75270d6cb59f932")
+        .template_data(serde_json::json!({"name": "Jane"}))
+        .personalization(
+            Personalization::to(Address::new("john@example.com")).with_subject("Hi John"),
+        )
+        .category("invoices")
+        .html("<p>Your invoice</p>")
+        .text("Your invoice")
+        .attach("invoice.bin", "application/octet-stream", &invoice[..])
+        .await
+        .unwrap();
+
+    let body = serde_json::to_value(&mail).unwrap();
+    assert_eq!(
+        body["personalizations"],
+        serde_json::json!([
+            {
+                "to": [{"email": "jane@example.com"}],
+                "bcc": [{"email": "archive@example.com"}],
+                "dynamic_template_data": {"name": "Jane"}
+            },
+            {"to": [{"email": "john@example.com"}], "subject": "Hi John"}
+        ])
+    );
+    assert_eq!(
+        body["from"],
+        serde_json::json!({"email": "billing@example.com", "name": "Example"})
+    );
+    assert_eq!(body["content"][0]["type"], "text/plain");
+    assert_eq!(body["content"][1]["type"], "text/html");
+    assert_eq!(body["categories"], serde_json::json!(["invoices"]));
+    assert_eq!(body["attachments"][0]["disposition"], "attachment");
+    assert_eq!(body["attachments"][0]["content"], base64::encode(&invoice));
+}
+
+#[tokio::test]
+async fn test_mail_send_errors() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::ACCEPTED, "");
+    mock.push_response(
+        reqwest::StatusCode::BAD_REQUEST,
+        r#"{"errors": [{"message": "Does not contain a valid address.", "field": "personalizations.0.to.0.email", "help": null}]}"#,
+    );
+
+    let sendgrid = crate::Client::new("token").with_transport(mock.clone());
+    let mail = MailBuilder::new("billing@example.com".into())
+        .to("jane@example.com".into())
+        .subject("Invoice")
+        .text("Your invoice");
+    sendgrid.mail_send().send(&mail).await.unwrap();
+    assert_eq!(mock.requests()[0].url.path(), "/v3/mail/send");
+
+    let err = sendgrid.mail_send().send(&mail).await.unwrap_err();
+    let api_error = err.downcast_ref::<crate::mail::ApiError>().unwrap();
+    assert_eq!(api_error.status, reqwest::StatusCode::BAD_REQUEST);
+    assert_eq!(
+        api_error.errors[0].field.as_deref(),
+        Some("personalizations.0.to.0.email")
+    );
+    assert_eq!(
+        err.to_string(),
+        "code: 400 Bad Request, Does not contain a valid address. (personalizations.0.to.0.email)"
+    );
+}
+
+#[tokio::test]
+async fn test_contact_export() {
+    use std::io::Write;
+
+    use futures::TryStreamExt;
+
+    use crate::{
+        contacts::ExportedContact,
+        types::{FileType, PostMcContactsExportsRequest},
+    };
+
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    gz.write_all(
+        b"CONTACT_ID,EMAIL,FIRST_NAME,ADDRESS_LINE_1\r\n\
+          1,jane@example.com,Jane,\"1 Main St\nApt \"\"2\"\"\"\r\n\
+          2,john@example.com,John,\r\n",
+    )
+    .unwrap();
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::ACCEPTED,
+        r#"{"_metadata": {}, "id": "e1"}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"id": "e1", "status": "ready", "urls": ["https://exports.example.com/1.csv.gzip", "https://exports.example.com/2.csv"]}"#,
+    );
+    mock.push_response(reqwest::StatusCode::OK, gz.finish().unwrap());
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        "CONTACT_ID,EMAIL,FIRST_NAME,ADDRESS_LINE_1\n3,max@example.com,Max,",
+    );
+
+    let sendgrid = crate::Client::new("token").with_transport(mock.clone());
+    let request = PostMcContactsExportsRequest {
+        file_type: Some(FileType::Csv),
+        list_ids: vec!["l1".to_string()],
+        max_file_size: 0,
+        notifications: None,
+        segment_ids: vec![],
+    };
+    let contacts: Vec<ExportedContact> = sendgrid
+        .contacts()
+        .export_records(&request)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let emails: Vec<&str> = contacts.iter().map(|c| c.email.as_str()).collect();
+    assert_eq!(
+        emails,
+        vec!["jane@example.com", "john@example.com", "max@example.com"]
+    );
+    assert_eq!(contacts[0].contact_id, "1");
+    assert_eq!(contacts[0].first_name, "Jane");
+    assert_eq!(contacts[0].other["ADDRESS_LINE_1"], "1 Main St\nApt \"2\"");
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].url.path(), "/v3/marketing/contacts/exports");
+    assert_eq!(requests[1].url.path(), "/v3/marketing/contacts/exports/e1");
+    // The signed URLs of the files are downloaded without the API key.
+    assert_eq!(
+        requests[2].url.as_str(),
+        "https://exports.example.com/1.csv.gzip"
+    );
+    assert!(!requests[2].headers.contains_key("authorization"));
+}
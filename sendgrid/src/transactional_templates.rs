@@ -32,18 +32,17 @@ impl TransactionalTemplates {
         page_size: f64,
         page_token: &str,
     ) -> Result<crate::types::GetTemplatesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !generations.to_string().is_empty() {
-            query_args.push(("generations".to_string(), generations.to_string()));
+            query_args.push(("generations", generations.to_string()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/templates?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/templates"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -82,8 +81,9 @@ impl TransactionalTemplates {
     */
     pub async fn get_templates_template(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
     ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -105,9 +105,10 @@ impl TransactionalTemplates {
     */
     pub async fn post_templates_template(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         body: &crate::types::PostTemplatesTemplateRequest,
     ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -129,7 +130,11 @@ impl TransactionalTemplates {
     *
     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
     */
-    pub async fn delete_templates_template(&self, template_id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_templates_template(
+        &self,
+        template_id: impl AsRef<str>,
+    ) -> Result<crate::types::Help> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -153,9 +158,10 @@ impl TransactionalTemplates {
     */
     pub async fn patch_templates_template(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         body: &crate::types::PatchTemplatesTemplateRequest,
     ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(template_id),
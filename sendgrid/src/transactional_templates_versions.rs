@@ -25,9 +25,10 @@ impl TransactionalTemplatesVersions {
     */
     pub async fn post_templates_template_version(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         body: &crate::types::TransactionalTemplateVersionCreate,
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/templates/{}/versions",
             crate::progenitor_support::encode_path(template_id),
@@ -51,9 +52,11 @@ impl TransactionalTemplatesVersions {
     */
     pub async fn post_templates_template_versions_version_activate(
         &self,
-        template_id: &str,
-        version_id: &str,
+        template_id: impl AsRef<str>,
+        version_id: impl AsRef<str>,
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        let template_id = template_id.as_ref();
+        let version_id = version_id.as_ref();
         let url = format!(
             "/templates/{}/versions/{}/activate",
             crate::progenitor_support::encode_path(template_id),
@@ -76,9 +79,11 @@ impl TransactionalTemplatesVersions {
     */
     pub async fn get_templates_template_versions_version(
         &self,
-        template_id: &str,
-        version_id: &str,
+        template_id: impl AsRef<str>,
+        version_id: impl AsRef<str>,
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        let template_id = template_id.as_ref();
+        let version_id = version_id.as_ref();
         let url = format!(
             "/templates/{}/versions/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -101,9 +106,11 @@ impl TransactionalTemplatesVersions {
     */
     pub async fn delete_templates_template_versions_version(
         &self,
-        template_id: &str,
-        version_id: &str,
+        template_id: impl AsRef<str>,
+        version_id: impl AsRef<str>,
     ) -> Result<()> {
+        let template_id = template_id.as_ref();
+        let version_id = version_id.as_ref();
         let url = format!(
             "/templates/{}/versions/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -126,10 +133,12 @@ impl TransactionalTemplatesVersions {
     */
     pub async fn patch_templates_template_versions_version(
         &self,
-        template_id: &str,
-        version_id: &str,
+        template_id: impl AsRef<str>,
+        version_id: impl AsRef<str>,
         body: &crate::types::TransactionalTemplateVersionCreate,
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        let template_id = template_id.as_ref();
+        let version_id = version_id.as_ref();
         let url = format!(
             "/templates/{}/versions/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -187,8 +187,8 @@ pub enum Operator {
     Ne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Operator {
@@ -200,7 +200,7 @@ impl std::fmt::Display for Operator {
             Operator::Lt => "lt",
             Operator::Ne => "ne",
             Operator::Noop => "",
-            Operator::FallthroughString => "*",
+            Operator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -225,8 +225,8 @@ pub enum AndOr {
     Or,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AndOr {
@@ -235,7 +235,7 @@ impl std::fmt::Display for AndOr {
             AndOr::And => "and",
             AndOr::Or => "or",
             AndOr::Noop => "",
-            AndOr::FallthroughString => "*",
+            AndOr::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -585,8 +585,8 @@ pub enum Type {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -596,7 +596,7 @@ impl std::fmt::Display for Type {
             Type::Number => "number",
             Type::Text => "text",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -692,8 +692,8 @@ pub enum LinkBranding200ResponseDnsDomainCnameType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
@@ -703,7 +703,7 @@ impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
             LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
             LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
             LinkBranding200ResponseDnsDomainCnameType::Noop => "",
-            LinkBranding200ResponseDnsDomainCnameType::FallthroughString => "*",
+            LinkBranding200ResponseDnsDomainCnameType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1855,8 +1855,8 @@ pub enum Editor {
     Design,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Editor {
@@ -1865,7 +1865,7 @@ impl std::fmt::Display for Editor {
             Editor::Code => "code",
             Editor::Design => "design",
             Editor::Noop => "",
-            Editor::FallthroughString => "*",
+            Editor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1882,7 +1882,7 @@ impl Editor {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CampaignsRequest {
     #[serde(
         default,
@@ -1963,6 +1963,84 @@ pub struct CampaignsRequest {
     pub title: String,
 }
 
+impl CampaignsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CampaignsRequestBuilder {
+        CampaignsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CampaignsRequest`.
+#[derive(Debug, Clone)]
+pub struct CampaignsRequestBuilder(CampaignsRequest);
+
+impl CampaignsRequestBuilder {
+    pub fn categories(mut self, categories: Vec<String>) -> Self {
+        self.0.categories = categories;
+        self
+    }
+
+    pub fn custom_unsubscribe_url(mut self, custom_unsubscribe_url: impl Into<String>) -> Self {
+        self.0.custom_unsubscribe_url = custom_unsubscribe_url.into();
+        self
+    }
+
+    pub fn editor(mut self, editor: impl Into<Editor>) -> Self {
+        self.0.editor = Some(editor.into());
+        self
+    }
+
+    pub fn html_content(mut self, html_content: impl Into<String>) -> Self {
+        self.0.html_content = html_content.into();
+        self
+    }
+
+    pub fn ip_pool(mut self, ip_pool: impl Into<String>) -> Self {
+        self.0.ip_pool = ip_pool.into();
+        self
+    }
+
+    pub fn list_ids(mut self, list_ids: Vec<i64>) -> Self {
+        self.0.list_ids = list_ids;
+        self
+    }
+
+    pub fn plain_content(mut self, plain_content: impl Into<String>) -> Self {
+        self.0.plain_content = plain_content.into();
+        self
+    }
+
+    pub fn segment_ids(mut self, segment_ids: Vec<i64>) -> Self {
+        self.0.segment_ids = segment_ids;
+        self
+    }
+
+    pub fn sender_id(mut self, sender_id: i64) -> Self {
+        self.0.sender_id = sender_id;
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.0.subject = subject.into();
+        self
+    }
+
+    pub fn suppression_group_id(mut self, suppression_group_id: i64) -> Self {
+        self.0.suppression_group_id = suppression_group_id;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn build(self) -> CampaignsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubuserStatsMetrics {
     #[serde(
@@ -2133,8 +2211,8 @@ pub enum Status {
     Pause,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2143,7 +2221,7 @@ impl std::fmt::Display for Status {
             Status::Cancel => "cancel",
             Status::Pause => "pause",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2616,8 +2694,8 @@ pub enum Generation {
     Legacy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generation {
@@ -2626,7 +2704,7 @@ impl std::fmt::Display for Generation {
             Generation::Dynamic => "dynamic",
             Generation::Legacy => "legacy",
             Generation::Noop => "",
-            Generation::FallthroughString => "*",
+            Generation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3005,7 +3083,7 @@ pub struct Contact {
     pub state_province_region: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SingleContactRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contact: Option<Contact>,
@@ -3017,6 +3095,34 @@ pub struct SingleContactRequest {
     pub list_ids: Vec<String>,
 }
 
+impl SingleContactRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SingleContactRequestBuilder {
+        SingleContactRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SingleContactRequest`.
+#[derive(Debug, Clone)]
+pub struct SingleContactRequestBuilder(SingleContactRequest);
+
+impl SingleContactRequestBuilder {
+    pub fn contact(mut self, contact: impl Into<Contact>) -> Self {
+        self.0.contact = Some(contact.into());
+        self
+    }
+
+    pub fn list_ids(mut self, list_ids: Vec<String>) -> Self {
+        self.0.list_ids = list_ids;
+        self
+    }
+
+    pub fn build(self) -> SingleContactRequest {
+        self.0
+    }
+}
+
 /**
 * The export job's status. Allowed values: `pending`, `ready`, or `failure`.
 */
@@ -3030,8 +3136,8 @@ pub enum ContactExportStatus {
     Ready,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactExportStatus {
@@ -3041,7 +3147,7 @@ impl std::fmt::Display for ContactExportStatus {
             ContactExportStatus::Pending => "pending",
             ContactExportStatus::Ready => "ready",
             ContactExportStatus::Noop => "",
-            ContactExportStatus::FallthroughString => "*",
+            ContactExportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3189,7 +3295,7 @@ pub struct ContactSummary {
     pub updated_at: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactRequest {
     #[serde(
         default,
@@ -3258,6 +3364,79 @@ pub struct ContactRequest {
     pub state_province_region: String,
 }
 
+impl ContactRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ContactRequestBuilder {
+        ContactRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ContactRequest`.
+#[derive(Debug, Clone)]
+pub struct ContactRequestBuilder(ContactRequest);
+
+impl ContactRequestBuilder {
+    pub fn address_line_1(mut self, address_line_1: impl Into<String>) -> Self {
+        self.0.address_line_1 = address_line_1.into();
+        self
+    }
+
+    pub fn address_line_2(mut self, address_line_2: impl Into<String>) -> Self {
+        self.0.address_line_2 = address_line_2.into();
+        self
+    }
+
+    pub fn alternate_emails(mut self, alternate_emails: Vec<String>) -> Self {
+        self.0.alternate_emails = alternate_emails;
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.0.city = city.into();
+        self
+    }
+
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.0.country = country.into();
+        self
+    }
+
+    pub fn custom_fields(mut self, custom_fields: impl Into<Help>) -> Self {
+        self.0.custom_fields = Some(custom_fields.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn postal_code(mut self, postal_code: impl Into<String>) -> Self {
+        self.0.postal_code = postal_code.into();
+        self
+    }
+
+    pub fn state_province_region(mut self, state_province_region: impl Into<String>) -> Self {
+        self.0.state_province_region = state_province_region.into();
+        self
+    }
+
+    pub fn build(self) -> ContactRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactDetails2 {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
@@ -3535,8 +3714,8 @@ pub enum FieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldType {
@@ -3546,7 +3725,7 @@ impl std::fmt::Display for FieldType {
             FieldType::Number => "Number",
             FieldType::Text => "Text",
             FieldType::Noop => "",
-            FieldType::FallthroughString => "*",
+            FieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4458,8 +4637,8 @@ pub enum AbPhase {
     Send,
     #[serde(rename = "test")]
     Test,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhase {
@@ -4468,7 +4647,7 @@ impl std::fmt::Display for AbPhase {
             AbPhase::All => "all",
             AbPhase::Send => "send",
             AbPhase::Test => "test",
-            AbPhase::FallthroughString => "*",
+            AbPhase::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4661,8 +4840,8 @@ pub enum SinglesendSearchStatus {
     Triggered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SinglesendSearchStatus {
@@ -4672,7 +4851,7 @@ impl std::fmt::Display for SinglesendSearchStatus {
             SinglesendSearchStatus::Scheduled => "scheduled",
             SinglesendSearchStatus::Triggered => "triggered",
             SinglesendSearchStatus::Noop => "",
-            SinglesendSearchStatus::FallthroughString => "*",
+            SinglesendSearchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4824,7 +5003,7 @@ pub struct EmailConfig {
     pub suppression_group_id: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendRequest {
     #[serde(
         default,
@@ -4853,6 +5032,49 @@ pub struct SinglesendRequest {
     pub send_to: Option<SendTo>,
 }
 
+impl SinglesendRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SinglesendRequestBuilder {
+        SinglesendRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SinglesendRequest`.
+#[derive(Debug, Clone)]
+pub struct SinglesendRequestBuilder(SinglesendRequest);
+
+impl SinglesendRequestBuilder {
+    pub fn categories(mut self, categories: Vec<String>) -> Self {
+        self.0.categories = categories;
+        self
+    }
+
+    pub fn email_config(mut self, email_config: impl Into<EmailConfig>) -> Self {
+        self.0.email_config = Some(email_config.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn send_at(mut self, send_at: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.0.send_at = Some(send_at.into());
+        self
+    }
+
+    pub fn send_to(mut self, send_to: impl Into<SendTo>) -> Self {
+        self.0.send_to = Some(send_to.into());
+        self
+    }
+
+    pub fn build(self) -> SinglesendRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendSchedule {
     #[serde(
@@ -4928,7 +5150,7 @@ pub struct ReplyTo {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebhooksEventWebhookUpdateWithOAuthRequest {
     /**
     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
@@ -5019,7 +5241,107 @@ pub struct WebhooksEventWebhookUpdateWithOAuthRequest {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl WebhooksEventWebhookUpdateWithOAuthRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> WebhooksEventWebhookUpdateWithOAuthRequestBuilder {
+        WebhooksEventWebhookUpdateWithOAuthRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `WebhooksEventWebhookUpdateWithOAuthRequest`.
+#[derive(Debug, Clone)]
+pub struct WebhooksEventWebhookUpdateWithOAuthRequestBuilder(
+    WebhooksEventWebhookUpdateWithOAuthRequest,
+);
+
+impl WebhooksEventWebhookUpdateWithOAuthRequestBuilder {
+    pub fn bounce(mut self, bounce: bool) -> Self {
+        self.0.bounce = Some(bounce);
+        self
+    }
+
+    pub fn click(mut self, click: bool) -> Self {
+        self.0.click = Some(click);
+        self
+    }
+
+    pub fn deferred(mut self, deferred: bool) -> Self {
+        self.0.deferred = Some(deferred);
+        self
+    }
+
+    pub fn delivered(mut self, delivered: bool) -> Self {
+        self.0.delivered = Some(delivered);
+        self
+    }
+
+    pub fn dropped(mut self, dropped: bool) -> Self {
+        self.0.dropped = Some(dropped);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn group_resubscribe(mut self, group_resubscribe: bool) -> Self {
+        self.0.group_resubscribe = Some(group_resubscribe);
+        self
+    }
+
+    pub fn group_unsubscribe(mut self, group_unsubscribe: bool) -> Self {
+        self.0.group_unsubscribe = Some(group_unsubscribe);
+        self
+    }
+
+    pub fn oauth_client_id(mut self, oauth_client_id: impl Into<String>) -> Self {
+        self.0.oauth_client_id = oauth_client_id.into();
+        self
+    }
+
+    pub fn oauth_client_secret(mut self, oauth_client_secret: impl Into<String>) -> Self {
+        self.0.oauth_client_secret = oauth_client_secret.into();
+        self
+    }
+
+    pub fn oauth_token_url(mut self, oauth_token_url: impl Into<String>) -> Self {
+        self.0.oauth_token_url = oauth_token_url.into();
+        self
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.0.open = Some(open);
+        self
+    }
+
+    pub fn processed(mut self, processed: bool) -> Self {
+        self.0.processed = Some(processed);
+        self
+    }
+
+    pub fn spam_report(mut self, spam_report: bool) -> Self {
+        self.0.spam_report = Some(spam_report);
+        self
+    }
+
+    pub fn unsubscribe(mut self, unsubscribe: bool) -> Self {
+        self.0.unsubscribe = Some(unsubscribe);
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = url.into();
+        self
+    }
+
+    pub fn build(self) -> WebhooksEventWebhookUpdateWithOAuthRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebhooksEventWebhookRequest {
     /**
     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
@@ -5104,6 +5426,99 @@ pub struct WebhooksEventWebhookRequest {
     pub url: String,
 }
 
+impl WebhooksEventWebhookRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> WebhooksEventWebhookRequestBuilder {
+        WebhooksEventWebhookRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `WebhooksEventWebhookRequest`.
+#[derive(Debug, Clone)]
+pub struct WebhooksEventWebhookRequestBuilder(WebhooksEventWebhookRequest);
+
+impl WebhooksEventWebhookRequestBuilder {
+    pub fn bounce(mut self, bounce: bool) -> Self {
+        self.0.bounce = Some(bounce);
+        self
+    }
+
+    pub fn click(mut self, click: bool) -> Self {
+        self.0.click = Some(click);
+        self
+    }
+
+    pub fn deferred(mut self, deferred: bool) -> Self {
+        self.0.deferred = Some(deferred);
+        self
+    }
+
+    pub fn delivered(mut self, delivered: bool) -> Self {
+        self.0.delivered = Some(delivered);
+        self
+    }
+
+    pub fn dropped(mut self, dropped: bool) -> Self {
+        self.0.dropped = Some(dropped);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn group_resubscribe(mut self, group_resubscribe: bool) -> Self {
+        self.0.group_resubscribe = Some(group_resubscribe);
+        self
+    }
+
+    pub fn group_unsubscribe(mut self, group_unsubscribe: bool) -> Self {
+        self.0.group_unsubscribe = Some(group_unsubscribe);
+        self
+    }
+
+    pub fn oauth_client_id(mut self, oauth_client_id: impl Into<String>) -> Self {
+        self.0.oauth_client_id = oauth_client_id.into();
+        self
+    }
+
+    pub fn oauth_token_url(mut self, oauth_token_url: impl Into<String>) -> Self {
+        self.0.oauth_token_url = oauth_token_url.into();
+        self
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.0.open = Some(open);
+        self
+    }
+
+    pub fn processed(mut self, processed: bool) -> Self {
+        self.0.processed = Some(processed);
+        self
+    }
+
+    pub fn spam_report(mut self, spam_report: bool) -> Self {
+        self.0.spam_report = Some(spam_report);
+        self
+    }
+
+    pub fn unsubscribe(mut self, unsubscribe: bool) -> Self {
+        self.0.unsubscribe = Some(unsubscribe);
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = url.into();
+        self
+    }
+
+    pub fn build(self) -> WebhooksEventWebhookRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReplyEmailObject {
     /**
@@ -5326,8 +5741,8 @@ pub enum AbTestSummaryType {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbTestSummaryType {
@@ -5336,7 +5751,7 @@ impl std::fmt::Display for AbTestSummaryType {
             AbTestSummaryType::Content => "content",
             AbTestSummaryType::Subject => "subject",
             AbTestSummaryType::Noop => "",
-            AbTestSummaryType::FallthroughString => "*",
+            AbTestSummaryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5366,8 +5781,8 @@ pub enum WinnerCriteria {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -5377,7 +5792,7 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Manual => "manual",
             WinnerCriteria::Open => "open",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5893,7 +6308,7 @@ pub struct FullSegmentAllOf {
     pub segment_write_v2: SegmentWriteV2,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct From {
     /**
     * The license key provided with your New Relic account.
@@ -6115,8 +6530,8 @@ pub enum EmailActivityResponseCommonFieldsStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
@@ -6126,7 +6541,7 @@ impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
             EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
             EmailActivityResponseCommonFieldsStatus::Processed => "processed",
             EmailActivityResponseCommonFieldsStatus::Noop => "",
-            EmailActivityResponseCommonFieldsStatus::FallthroughString => "*",
+            EmailActivityResponseCommonFieldsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6294,7 +6709,7 @@ pub struct SsoIntegrationAllOf {
     pub sso_integration: SsoIntegration,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateIntegrationRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub completed_integration: Option<bool>,
@@ -6341,6 +6756,54 @@ pub struct CreateIntegrationRequest {
     pub signout_url: String,
 }
 
+impl CreateIntegrationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateIntegrationRequestBuilder {
+        CreateIntegrationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateIntegrationRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateIntegrationRequestBuilder(CreateIntegrationRequest);
+
+impl CreateIntegrationRequestBuilder {
+    pub fn completed_integration(mut self, completed_integration: bool) -> Self {
+        self.0.completed_integration = Some(completed_integration);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn entity_id(mut self, entity_id: impl Into<String>) -> Self {
+        self.0.entity_id = entity_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn signin_url(mut self, signin_url: impl Into<String>) -> Self {
+        self.0.signin_url = signin_url.into();
+        self
+    }
+
+    pub fn signout_url(mut self, signout_url: impl Into<String>) -> Self {
+        self.0.signout_url = signout_url.into();
+        self
+    }
+
+    pub fn build(self) -> CreateIntegrationRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SsoTeammateResponse {
     /**
@@ -6414,8 +6877,8 @@ pub enum UserType {
     Teammate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserType {
@@ -6425,7 +6888,7 @@ impl std::fmt::Display for UserType {
             UserType::Owner => "owner",
             UserType::Teammate => "teammate",
             UserType::Noop => "",
-            UserType::FallthroughString => "*",
+            UserType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6753,7 +7216,7 @@ pub struct SenderRequestFrom {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SenderRequest {
     #[serde(
         default,
@@ -6803,6 +7266,69 @@ pub struct SenderRequest {
     pub zip: String,
 }
 
+impl SenderRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SenderRequestBuilder {
+        SenderRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SenderRequest`.
+#[derive(Debug, Clone)]
+pub struct SenderRequestBuilder(SenderRequest);
+
+impl SenderRequestBuilder {
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.0.address = address.into();
+        self
+    }
+
+    pub fn address_2(mut self, address_2: impl Into<String>) -> Self {
+        self.0.address_2 = address_2.into();
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.0.city = city.into();
+        self
+    }
+
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.0.country = country.into();
+        self
+    }
+
+    pub fn from(mut self, from: impl Into<SenderRequestFrom>) -> Self {
+        self.0.from = Some(from.into());
+        self
+    }
+
+    pub fn nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.0.nickname = nickname.into();
+        self
+    }
+
+    pub fn reply_to(mut self, reply_to: impl Into<SenderRequestFrom>) -> Self {
+        self.0.reply_to = Some(reply_to.into());
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn zip(mut self, zip: impl Into<String>) -> Self {
+        self.0.zip = zip.into();
+        self
+    }
+
+    pub fn build(self) -> SenderRequest {
+        self.0
+    }
+}
+
 /// Segment status indicates whether the segment's contacts will be updated periodically
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentStatusResponse {
@@ -7118,8 +7644,8 @@ pub enum AbbvMessageStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbbvMessageStatus {
@@ -7129,7 +7655,7 @@ impl std::fmt::Display for AbbvMessageStatus {
             AbbvMessageStatus::NotDelivered => "not_delivered",
             AbbvMessageStatus::Processed => "processed",
             AbbvMessageStatus::Noop => "",
-            AbbvMessageStatus::FallthroughString => "*",
+            AbbvMessageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7238,8 +7764,8 @@ pub enum EventName {
     Unsubscribe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventName {
@@ -7257,7 +7783,7 @@ impl std::fmt::Display for EventName {
             EventName::SpamReport => "spam_report",
             EventName::Unsubscribe => "unsubscribe",
             EventName::Noop => "",
-            EventName::FallthroughString => "*",
+            EventName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7287,8 +7813,8 @@ pub enum BounceType {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -7298,7 +7824,7 @@ impl std::fmt::Display for BounceType {
             BounceType::Bounced => "bounced",
             BounceType::Expired => "expired",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7388,8 +7914,8 @@ pub enum OutboundIpType {
     Shared,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutboundIpType {
@@ -7398,7 +7924,7 @@ impl std::fmt::Display for OutboundIpType {
             OutboundIpType::Dedicated => "dedicated",
             OutboundIpType::Shared => "shared",
             OutboundIpType::Noop => "",
-            OutboundIpType::FallthroughString => "*",
+            OutboundIpType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7789,8 +8315,8 @@ pub enum AggregatedBy {
     Day,
     #[serde(rename = "total")]
     Total,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregatedBy {
@@ -7798,7 +8324,7 @@ impl std::fmt::Display for AggregatedBy {
         match &*self {
             AggregatedBy::Day => "day",
             AggregatedBy::Total => "total",
-            AggregatedBy::FallthroughString => "*",
+            AggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7818,8 +8344,8 @@ pub enum GroupBy {
     AbVariation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupBy {
@@ -7828,7 +8354,7 @@ impl std::fmt::Display for GroupBy {
             GroupBy::AbPhase => "ab_phase",
             GroupBy::AbVariation => "ab_variation",
             GroupBy::Noop => "",
-            GroupBy::FallthroughString => "*",
+            GroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7851,8 +8377,8 @@ pub enum TraitAutomationQueryParamsGroupBy {
     StepId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
@@ -7860,7 +8386,7 @@ impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
         match &*self {
             TraitAutomationQueryParamsGroupBy::StepId => "step_id",
             TraitAutomationQueryParamsGroupBy::Noop => "",
-            TraitAutomationQueryParamsGroupBy::FallthroughString => "*",
+            TraitAutomationQueryParamsGroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7885,8 +8411,8 @@ pub enum AbPhaseId {
     Test,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhaseId {
@@ -7895,7 +8421,7 @@ impl std::fmt::Display for AbPhaseId {
             AbPhaseId::Send => "send",
             AbPhaseId::Test => "test",
             AbPhaseId::Noop => "",
-            AbPhaseId::FallthroughString => "*",
+            AbPhaseId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7925,8 +8451,8 @@ pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
@@ -7936,7 +8462,7 @@ impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop => "",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::FallthroughString => "*",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8175,8 +8701,8 @@ pub enum Disposition {
     Attachment,
     #[serde(rename = "inline")]
     Inline,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Disposition {
@@ -8184,7 +8710,7 @@ impl std::fmt::Display for Disposition {
         match &*self {
             Disposition::Attachment => "attachment",
             Disposition::Inline => "inline",
-            Disposition::FallthroughString => "*",
+            Disposition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8644,38 +9170,164 @@ pub struct PostMailSendRequest {
     pub tracking_settings: Option<TrackingSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CancelPauseAScheduledSendRequest {
-    /**
-    * The license key provided with your New Relic account.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub batch_id: String,
-    /**
-    * The status of the scheduled send.
-    */
-    #[serde(default, skip_serializing_if = "Status::is_noop")]
-    pub status: Status,
+impl PostMailSendRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMailSendRequestBuilder {
+        PostMailSendRequestBuilder(Default::default())
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetApiKeysResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<ApiKeyNameId>,
-}
+/// The builder of a `PostMailSendRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMailSendRequestBuilder(PostMailSendRequest);
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreateApiKeysRequest {
-    /**
-    * The license key provided with your New Relic account.
+impl PostMailSendRequestBuilder {
+    pub fn asm(mut self, asm: impl Into<Asm>) -> Self {
+        self.0.asm = Some(asm.into());
+        self
+    }
+
+    pub fn attachments(mut self, attachments: Vec<Attachments>) -> Self {
+        self.0.attachments = attachments;
+        self
+    }
+
+    pub fn batch_id(mut self, batch_id: impl Into<String>) -> Self {
+        self.0.batch_id = batch_id.into();
+        self
+    }
+
+    pub fn categories(mut self, categories: Vec<String>) -> Self {
+        self.0.categories = categories;
+        self
+    }
+
+    pub fn content(mut self, content: Vec<Content>) -> Self {
+        self.0.content = content;
+        self
+    }
+
+    pub fn custom_args(mut self, custom_args: impl Into<String>) -> Self {
+        self.0.custom_args = custom_args.into();
+        self
+    }
+
+    pub fn from(mut self, from: impl Into<FromEmailObject>) -> Self {
+        self.0.from = from.into();
+        self
+    }
+
+    pub fn headers(mut self, headers: impl Into<Help>) -> Self {
+        self.0.headers = Some(headers.into());
+        self
+    }
+
+    pub fn ip_pool_name(mut self, ip_pool_name: impl Into<String>) -> Self {
+        self.0.ip_pool_name = ip_pool_name.into();
+        self
+    }
+
+    pub fn mail_settings(mut self, mail_settings: impl Into<MailSettings>) -> Self {
+        self.0.mail_settings = Some(mail_settings.into());
+        self
+    }
+
+    pub fn personalizations(mut self, personalizations: Vec<Personalizations>) -> Self {
+        self.0.personalizations = personalizations;
+        self
+    }
+
+    pub fn reply_to(mut self, reply_to: impl Into<ReplyEmailObject>) -> Self {
+        self.0.reply_to = Some(reply_to.into());
+        self
+    }
+
+    pub fn send_at(mut self, send_at: i64) -> Self {
+        self.0.send_at = send_at;
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.0.subject = subject.into();
+        self
+    }
+
+    pub fn template_id(mut self, template_id: impl Into<String>) -> Self {
+        self.0.template_id = template_id.into();
+        self
+    }
+
+    pub fn tracking_settings(mut self, tracking_settings: impl Into<TrackingSettings>) -> Self {
+        self.0.tracking_settings = Some(tracking_settings.into());
+        self
+    }
+
+    pub fn build(self) -> PostMailSendRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CancelPauseAScheduledSendRequest {
+    /**
+    * The license key provided with your New Relic account.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub batch_id: String,
+    /**
+    * The status of the scheduled send.
+    */
+    #[serde(default, skip_serializing_if = "Status::is_noop")]
+    pub status: Status,
+}
+
+impl CancelPauseAScheduledSendRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CancelPauseAScheduledSendRequestBuilder {
+        CancelPauseAScheduledSendRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CancelPauseAScheduledSendRequest`.
+#[derive(Debug, Clone)]
+pub struct CancelPauseAScheduledSendRequestBuilder(CancelPauseAScheduledSendRequest);
+
+impl CancelPauseAScheduledSendRequestBuilder {
+    pub fn batch_id(mut self, batch_id: impl Into<String>) -> Self {
+        self.0.batch_id = batch_id.into();
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<Status>) -> Self {
+        self.0.status = status.into();
+        self
+    }
+
+    pub fn build(self) -> CancelPauseAScheduledSendRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetApiKeysResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub result: Vec<ApiKeyNameId>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CreateApiKeysRequest {
+    /**
+    * The license key provided with your New Relic account.
     */
     #[serde(
         default,
@@ -8691,6 +9343,34 @@ pub struct CreateApiKeysRequest {
     pub scopes: Vec<String>,
 }
 
+impl CreateApiKeysRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateApiKeysRequestBuilder {
+        CreateApiKeysRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateApiKeysRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateApiKeysRequestBuilder(CreateApiKeysRequest);
+
+impl CreateApiKeysRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> CreateApiKeysRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateApiKeysResponse {
     /**
@@ -8741,7 +9421,7 @@ pub struct GetApiKeysKeyResponse {
     pub result: Vec<ApiKeyNameScopesAllOf>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutApiKeysKeyRequest {
     /**
     * The license key provided with your New Relic account.
@@ -8760,6 +9440,34 @@ pub struct PutApiKeysKeyRequest {
     pub scopes: Vec<String>,
 }
 
+impl PutApiKeysKeyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutApiKeysKeyRequestBuilder {
+        PutApiKeysKeyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutApiKeysKeyRequest`.
+#[derive(Debug, Clone)]
+pub struct PutApiKeysKeyRequestBuilder(PutApiKeysKeyRequest);
+
+impl PutApiKeysKeyRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> PutApiKeysKeyRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetScopesResponseErrors {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -8801,7 +9509,7 @@ pub struct Ips {
     pub ip: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAccessSettingsWhitelistRequest {
     /**
     * An array containing the IP(s) you want to allow.
@@ -8814,7 +9522,30 @@ pub struct PostAccessSettingsWhitelistRequest {
     pub ips: Vec<Ips>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostAccessSettingsWhitelistRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostAccessSettingsWhitelistRequestBuilder {
+        PostAccessSettingsWhitelistRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostAccessSettingsWhitelistRequest`.
+#[derive(Debug, Clone)]
+pub struct PostAccessSettingsWhitelistRequestBuilder(PostAccessSettingsWhitelistRequest);
+
+impl PostAccessSettingsWhitelistRequestBuilder {
+    pub fn ips(mut self, ips: Vec<Ips>) -> Self {
+        self.0.ips = ips;
+        self
+    }
+
+    pub fn build(self) -> PostAccessSettingsWhitelistRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteAccessSettingsWhitelistRequest {
     /**
     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
@@ -8827,6 +9558,29 @@ pub struct DeleteAccessSettingsWhitelistRequest {
     pub ids: Vec<i64>,
 }
 
+impl DeleteAccessSettingsWhitelistRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteAccessSettingsWhitelistRequestBuilder {
+        DeleteAccessSettingsWhitelistRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteAccessSettingsWhitelistRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteAccessSettingsWhitelistRequestBuilder(DeleteAccessSettingsWhitelistRequest);
+
+impl DeleteAccessSettingsWhitelistRequestBuilder {
+    pub fn ids(mut self, ids: Vec<i64>) -> Self {
+        self.0.ids = ids;
+        self
+    }
+
+    pub fn build(self) -> DeleteAccessSettingsWhitelistRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetAccessSettingsActivityResponseResult {
     /**
@@ -8892,7 +9646,7 @@ pub struct GetAccessSettingsActivityResponse {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSsoCertificatesRequest {
     /**
     *
@@ -8919,7 +9673,40 @@ pub struct PostSsoCertificatesRequest {
     pub public_certificate: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostSsoCertificatesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostSsoCertificatesRequestBuilder {
+        PostSsoCertificatesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostSsoCertificatesRequest`.
+#[derive(Debug, Clone)]
+pub struct PostSsoCertificatesRequestBuilder(PostSsoCertificatesRequest);
+
+impl PostSsoCertificatesRequestBuilder {
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn integration_id(mut self, integration_id: impl Into<String>) -> Self {
+        self.0.integration_id = integration_id.into();
+        self
+    }
+
+    pub fn public_certificate(mut self, public_certificate: impl Into<String>) -> Self {
+        self.0.public_certificate = public_certificate.into();
+        self
+    }
+
+    pub fn build(self) -> PostSsoCertificatesRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchSsoCertificatesCertRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
@@ -8937,7 +9724,40 @@ pub struct PatchSsoCertificatesCertRequest {
     pub public_certificate: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PatchSsoCertificatesCertRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchSsoCertificatesCertRequestBuilder {
+        PatchSsoCertificatesCertRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchSsoCertificatesCertRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchSsoCertificatesCertRequestBuilder(PatchSsoCertificatesCertRequest);
+
+impl PatchSsoCertificatesCertRequestBuilder {
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn integration_id(mut self, integration_id: impl Into<String>) -> Self {
+        self.0.integration_id = integration_id.into();
+        self
+    }
+
+    pub fn public_certificate(mut self, public_certificate: impl Into<String>) -> Self {
+        self.0.public_certificate = public_certificate.into();
+        self
+    }
+
+    pub fn build(self) -> PatchSsoCertificatesCertRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchSsoTeammatesUsernameRequest {
     #[serde(
         default,
@@ -8961,6 +9781,44 @@ pub struct PatchSsoTeammatesUsernameRequest {
     pub scopes: Vec<String>,
 }
 
+impl PatchSsoTeammatesUsernameRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchSsoTeammatesUsernameRequestBuilder {
+        PatchSsoTeammatesUsernameRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchSsoTeammatesUsernameRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchSsoTeammatesUsernameRequestBuilder(PatchSsoTeammatesUsernameRequest);
+
+impl PatchSsoTeammatesUsernameRequestBuilder {
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn is_admin(mut self, is_admin: bool) -> Self {
+        self.0.is_admin = Some(is_admin);
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> PatchSsoTeammatesUsernameRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMailSettingsResponseResult {
     /**
@@ -9013,7 +9871,7 @@ pub struct GetMailSettingsResponse {
     pub result: Vec<GetMailSettingsResponseResult>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMailSettingsAddressWhitelistRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
@@ -9025,7 +9883,37 @@ pub struct PatchMailSettingsAddressWhitelistRequest {
     pub list: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PatchMailSettingsAddressWhitelistRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchMailSettingsAddressWhitelistRequestBuilder {
+        PatchMailSettingsAddressWhitelistRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchMailSettingsAddressWhitelistRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchMailSettingsAddressWhitelistRequestBuilder(
+    PatchMailSettingsAddressWhitelistRequest,
+);
+
+impl PatchMailSettingsAddressWhitelistRequestBuilder {
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn list(mut self, list: Vec<String>) -> Self {
+        self.0.list = list;
+        self
+    }
+
+    pub fn build(self) -> PatchMailSettingsAddressWhitelistRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMailSettingsTemplateRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
@@ -9037,6 +9925,34 @@ pub struct PatchMailSettingsTemplateRequest {
     pub html_content: String,
 }
 
+impl PatchMailSettingsTemplateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchMailSettingsTemplateRequestBuilder {
+        PatchMailSettingsTemplateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchMailSettingsTemplateRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchMailSettingsTemplateRequestBuilder(PatchMailSettingsTemplateRequest);
+
+impl PatchMailSettingsTemplateRequestBuilder {
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn html_content(mut self, html_content: impl Into<String>) -> Self {
+        self.0.html_content = html_content.into();
+        self
+    }
+
+    pub fn build(self) -> PatchMailSettingsTemplateRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMailSettingsTemplateResponse {
     /**
@@ -9058,7 +9974,7 @@ pub struct PatchMailSettingsTemplateResponse {
     pub html_content: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchPartnerSettingsNewRelicRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_subuser_statistics: Option<bool>,
@@ -9072,6 +9988,39 @@ pub struct PatchPartnerSettingsNewRelicRequest {
     pub license_key: String,
 }
 
+impl PatchPartnerSettingsNewRelicRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchPartnerSettingsNewRelicRequestBuilder {
+        PatchPartnerSettingsNewRelicRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchPartnerSettingsNewRelicRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchPartnerSettingsNewRelicRequestBuilder(PatchPartnerSettingsNewRelicRequest);
+
+impl PatchPartnerSettingsNewRelicRequestBuilder {
+    pub fn enable_subuser_statistics(mut self, enable_subuser_statistics: bool) -> Self {
+        self.0.enable_subuser_statistics = Some(enable_subuser_statistics);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn license_key(mut self, license_key: impl Into<String>) -> Self {
+        self.0.license_key = license_key.into();
+        self
+    }
+
+    pub fn build(self) -> PatchPartnerSettingsNewRelicRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetPartnerSettingsResponse {
     /**
@@ -9221,7 +10170,7 @@ pub struct GetTeammatesResponse {
     pub result: Vec<GetTeammatesResponseResult>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTeammatesRequest {
     /**
     * The license key provided with your New Relic account.
@@ -9248,6 +10197,39 @@ pub struct PostTeammatesRequest {
     pub scopes: Vec<String>,
 }
 
+impl PostTeammatesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostTeammatesRequestBuilder {
+        PostTeammatesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostTeammatesRequest`.
+#[derive(Debug, Clone)]
+pub struct PostTeammatesRequestBuilder(PostTeammatesRequest);
+
+impl PostTeammatesRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn is_admin(mut self, is_admin: bool) -> Self {
+        self.0.is_admin = Some(is_admin);
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> PostTeammatesRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTeammatesResponse {
     /**
@@ -9564,7 +10546,7 @@ pub struct GetTeammatesUsernameResponse {
     pub zip: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTeammatesUsernameRequest {
     /**
     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
@@ -9582,6 +10564,34 @@ pub struct PatchTeammatesUsernameRequest {
     pub scopes: Vec<String>,
 }
 
+impl PatchTeammatesUsernameRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchTeammatesUsernameRequestBuilder {
+        PatchTeammatesUsernameRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchTeammatesUsernameRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchTeammatesUsernameRequestBuilder(PatchTeammatesUsernameRequest);
+
+impl PatchTeammatesUsernameRequestBuilder {
+    pub fn is_admin(mut self, is_admin: bool) -> Self {
+        self.0.is_admin = Some(is_admin);
+        self
+    }
+
+    pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+        self.0.scopes = scopes;
+        self
+    }
+
+    pub fn build(self) -> PatchTeammatesUsernameRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchScopesRequestsApproveResponse {
     /**
@@ -9606,8 +10616,8 @@ pub enum GetAlertsResponseType {
     UsageLimit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsResponseType {
@@ -9616,7 +10626,7 @@ impl std::fmt::Display for GetAlertsResponseType {
             GetAlertsResponseType::StatsNotification => "stats_notification",
             GetAlertsResponseType::UsageLimit => "usage_limit",
             GetAlertsResponseType::Noop => "",
-            GetAlertsResponseType::FallthroughString => "*",
+            GetAlertsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9688,7 +10698,7 @@ pub struct GetAlertsResponse {
     pub updated_at: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAlertsRequest {
     /**
     * The license key provided with your New Relic account.
@@ -9722,6 +10732,44 @@ pub struct PostAlertsRequest {
     pub type_: GetAlertsResponseType,
 }
 
+impl PostAlertsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostAlertsRequestBuilder {
+        PostAlertsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostAlertsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostAlertsRequestBuilder(PostAlertsRequest);
+
+impl PostAlertsRequestBuilder {
+    pub fn email_to(mut self, email_to: impl Into<String>) -> Self {
+        self.0.email_to = email_to.into();
+        self
+    }
+
+    pub fn frequency(mut self, frequency: impl Into<String>) -> Self {
+        self.0.frequency = frequency.into();
+        self
+    }
+
+    pub fn percentage(mut self, percentage: i64) -> Self {
+        self.0.percentage = percentage;
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<GetAlertsResponseType>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> PostAlertsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAlertsResponse {
     #[serde(
@@ -9789,8 +10837,8 @@ pub enum GetAlertsAlertResponseType {
     UsageAlert,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsAlertResponseType {
@@ -9799,7 +10847,7 @@ impl std::fmt::Display for GetAlertsAlertResponseType {
             GetAlertsAlertResponseType::StatsNotification => "stats_notification",
             GetAlertsAlertResponseType::UsageAlert => "usage_alert",
             GetAlertsAlertResponseType::Noop => "",
-            GetAlertsAlertResponseType::FallthroughString => "*",
+            GetAlertsAlertResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9871,7 +10919,7 @@ pub struct GetAlertsAlertResponse {
     pub updated_at: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchAlertsAlertRequest {
     #[serde(
         default,
@@ -9893,6 +10941,39 @@ pub struct PatchAlertsAlertRequest {
     pub percentage: i64,
 }
 
+impl PatchAlertsAlertRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchAlertsAlertRequestBuilder {
+        PatchAlertsAlertRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchAlertsAlertRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchAlertsAlertRequestBuilder(PatchAlertsAlertRequest);
+
+impl PatchAlertsAlertRequestBuilder {
+    pub fn email_to(mut self, email_to: impl Into<String>) -> Self {
+        self.0.email_to = email_to.into();
+        self
+    }
+
+    pub fn frequency(mut self, frequency: impl Into<String>) -> Self {
+        self.0.frequency = frequency.into();
+        self
+    }
+
+    pub fn percentage(mut self, percentage: i64) -> Self {
+        self.0.percentage = percentage;
+        self
+    }
+
+    pub fn build(self) -> PatchAlertsAlertRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserProfileResponse {
     /**
@@ -10005,8 +11086,8 @@ pub enum GetUserAccountResponseType {
     Paid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetUserAccountResponseType {
@@ -10015,7 +11096,7 @@ impl std::fmt::Display for GetUserAccountResponseType {
             GetUserAccountResponseType::Free => "free",
             GetUserAccountResponseType::Paid => "paid",
             GetUserAccountResponseType::Noop => "",
-            GetUserAccountResponseType::FallthroughString => "*",
+            GetUserAccountResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10067,7 +11148,7 @@ pub struct GetUserEmailResponse {
     pub email: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserEmailRequest {
     #[serde(
         default,
@@ -10077,7 +11158,44 @@ pub struct PutUserEmailRequest {
     pub email: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl std::convert::From<String> for PutUserEmailRequest {
+    fn from(email: String) -> Self {
+        PutUserEmailRequest { email }
+    }
+}
+
+impl std::convert::From<&str> for PutUserEmailRequest {
+    fn from(email: &str) -> Self {
+        PutUserEmailRequest {
+            email: email.to_string(),
+        }
+    }
+}
+
+impl PutUserEmailRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutUserEmailRequestBuilder {
+        PutUserEmailRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutUserEmailRequest`.
+#[derive(Debug, Clone)]
+pub struct PutUserEmailRequestBuilder(PutUserEmailRequest);
+
+impl PutUserEmailRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn build(self) -> PutUserEmailRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserUsernameRequest {
     #[serde(
         default,
@@ -10087,6 +11205,43 @@ pub struct PutUserUsernameRequest {
     pub username: String,
 }
 
+impl std::convert::From<String> for PutUserUsernameRequest {
+    fn from(username: String) -> Self {
+        PutUserUsernameRequest { username }
+    }
+}
+
+impl std::convert::From<&str> for PutUserUsernameRequest {
+    fn from(username: &str) -> Self {
+        PutUserUsernameRequest {
+            username: username.to_string(),
+        }
+    }
+}
+
+impl PutUserUsernameRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutUserUsernameRequestBuilder {
+        PutUserUsernameRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutUserUsernameRequest`.
+#[derive(Debug, Clone)]
+pub struct PutUserUsernameRequestBuilder(PutUserUsernameRequest);
+
+impl PutUserUsernameRequestBuilder {
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.0.username = username.into();
+        self
+    }
+
+    pub fn build(self) -> PutUserUsernameRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserUsernameResponse {
     /**
@@ -10155,7 +11310,7 @@ pub struct GetUserCreditsResponse {
     pub used: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserPasswordRequest {
     /**
     * The license key provided with your New Relic account.
@@ -10177,7 +11332,35 @@ pub struct PutUserPasswordRequest {
     pub old_password: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PutUserPasswordRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutUserPasswordRequestBuilder {
+        PutUserPasswordRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutUserPasswordRequest`.
+#[derive(Debug, Clone)]
+pub struct PutUserPasswordRequestBuilder(PutUserPasswordRequest);
+
+impl PutUserPasswordRequestBuilder {
+    pub fn new_password(mut self, new_password: impl Into<String>) -> Self {
+        self.0.new_password = new_password.into();
+        self
+    }
+
+    pub fn old_password(mut self, old_password: impl Into<String>) -> Self {
+        self.0.old_password = old_password.into();
+        self
+    }
+
+    pub fn build(self) -> PutUserPasswordRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSubusersRequest {
     /**
     * The license key provided with your New Relic account.
@@ -10217,12 +11400,73 @@ pub struct PostSubusersRequest {
     pub username: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostSubusersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostSubusersRequestBuilder {
+        PostSubusersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostSubusersRequest`.
+#[derive(Debug, Clone)]
+pub struct PostSubusersRequestBuilder(PostSubusersRequest);
+
+impl PostSubusersRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn ips(mut self, ips: Vec<std::net::Ipv4Addr>) -> Self {
+        self.0.ips = ips;
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.0.password = password.into();
+        self
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.0.username = username.into();
+        self
+    }
+
+    pub fn build(self) -> PostSubusersRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchSubusersSubuserNameRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub disabled: Option<bool>,
 }
 
+impl PatchSubusersSubuserNameRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchSubusersSubuserNameRequestBuilder {
+        PatchSubusersSubuserNameRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchSubusersSubuserNameRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchSubusersSubuserNameRequestBuilder(PatchSubusersSubuserNameRequest);
+
+impl PatchSubusersSubuserNameRequestBuilder {
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = Some(disabled);
+        self
+    }
+
+    pub fn build(self) -> PatchSubusersSubuserNameRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSubusersReputationsResponse {
     /**
@@ -10267,8 +11511,8 @@ pub enum SortByDirection {
     Asc,
     #[serde(rename = "desc")]
     Desc,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByDirection {
@@ -10276,7 +11520,7 @@ impl std::fmt::Display for SortByDirection {
         match &*self {
             SortByDirection::Asc => "asc",
             SortByDirection::Desc => "desc",
-            SortByDirection::FallthroughString => "*",
+            SortByDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10311,8 +11555,8 @@ pub enum SortByMetric {
     UniqueOpens,
     #[serde(rename = "unsubscribes")]
     Unsubscribes,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByMetric {
@@ -10327,7 +11571,7 @@ impl std::fmt::Display for SortByMetric {
             SortByMetric::UniqueClicks => "unique_clicks",
             SortByMetric::UniqueOpens => "unique_opens",
             SortByMetric::Unsubscribes => "unsubscribes",
-            SortByMetric::FallthroughString => "*",
+            SortByMetric::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10339,7 +11583,7 @@ impl Default for SortByMetric {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
@@ -10360,6 +11604,39 @@ pub struct PostWhitelabelLinksRequest {
     pub subdomain: String,
 }
 
+impl PostWhitelabelLinksRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostWhitelabelLinksRequestBuilder {
+        PostWhitelabelLinksRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostWhitelabelLinksRequest`.
+#[derive(Debug, Clone)]
+pub struct PostWhitelabelLinksRequestBuilder(PostWhitelabelLinksRequest);
+
+impl PostWhitelabelLinksRequestBuilder {
+    pub fn default(mut self, default: bool) -> Self {
+        self.0.default = Some(default);
+        self
+    }
+
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.0.domain = domain.into();
+        self
+    }
+
+    pub fn subdomain(mut self, subdomain: impl Into<String>) -> Self {
+        self.0.subdomain = subdomain.into();
+        self
+    }
+
+    pub fn build(self) -> PostWhitelabelLinksRequest {
+        self.0
+    }
+}
+
 /// The DNS record generated for the sending domain used for this branded link.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksValidateResponseValidationResultsDomainCname {
@@ -10446,7 +11723,7 @@ pub struct PostMarketingSegmentsResponse {
     pub errors: Vec<PostWhitelabelLinksValidateResponseErrors>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksLinkSubuserRequest {
     #[serde(
         default,
@@ -10456,13 +11733,73 @@ pub struct PostWhitelabelLinksLinkSubuserRequest {
     pub username: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl std::convert::From<String> for PostWhitelabelLinksLinkSubuserRequest {
+    fn from(username: String) -> Self {
+        PostWhitelabelLinksLinkSubuserRequest { username }
+    }
+}
+
+impl std::convert::From<&str> for PostWhitelabelLinksLinkSubuserRequest {
+    fn from(username: &str) -> Self {
+        PostWhitelabelLinksLinkSubuserRequest {
+            username: username.to_string(),
+        }
+    }
+}
+
+impl PostWhitelabelLinksLinkSubuserRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostWhitelabelLinksLinkSubuserRequestBuilder {
+        PostWhitelabelLinksLinkSubuserRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostWhitelabelLinksLinkSubuserRequest`.
+#[derive(Debug, Clone)]
+pub struct PostWhitelabelLinksLinkSubuserRequestBuilder(PostWhitelabelLinksLinkSubuserRequest);
+
+impl PostWhitelabelLinksLinkSubuserRequestBuilder {
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.0.username = username.into();
+        self
+    }
+
+    pub fn build(self) -> PostWhitelabelLinksLinkSubuserRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchWhitelabelLinksRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PatchWhitelabelLinksRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchWhitelabelLinksRequestBuilder {
+        PatchWhitelabelLinksRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchWhitelabelLinksRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchWhitelabelLinksRequestBuilder(PatchWhitelabelLinksRequest);
+
+impl PatchWhitelabelLinksRequestBuilder {
+    pub fn default(mut self, default: bool) -> Self {
+        self.0.default = Some(default);
+        self
+    }
+
+    pub fn build(self) -> PatchWhitelabelLinksRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsWarmupRequest {
     #[serde(
         default,
@@ -10472,6 +11809,41 @@ pub struct PostIpsWarmupRequest {
     pub ip: String,
 }
 
+impl std::convert::From<String> for PostIpsWarmupRequest {
+    fn from(ip: String) -> Self {
+        PostIpsWarmupRequest { ip }
+    }
+}
+
+impl std::convert::From<&str> for PostIpsWarmupRequest {
+    fn from(ip: &str) -> Self {
+        PostIpsWarmupRequest { ip: ip.to_string() }
+    }
+}
+
+impl PostIpsWarmupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostIpsWarmupRequestBuilder {
+        PostIpsWarmupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostIpsWarmupRequest`.
+#[derive(Debug, Clone)]
+pub struct PostIpsWarmupRequestBuilder(PostIpsWarmupRequest);
+
+impl PostIpsWarmupRequestBuilder {
+    pub fn ip(mut self, ip: impl Into<String>) -> Self {
+        self.0.ip = ip.into();
+        self
+    }
+
+    pub fn build(self) -> PostIpsWarmupRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsWarmupResponseErrors {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -10500,7 +11872,7 @@ pub struct PostIpsWarmupResponse {
     pub errors: Vec<PostIpsWarmupResponseErrors>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelIpsRequest {
     /**
     * The license key provided with your New Relic account.
@@ -10528,6 +11900,39 @@ pub struct PostWhitelabelIpsRequest {
     pub subdomain: String,
 }
 
+impl PostWhitelabelIpsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostWhitelabelIpsRequestBuilder {
+        PostWhitelabelIpsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostWhitelabelIpsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostWhitelabelIpsRequestBuilder(PostWhitelabelIpsRequest);
+
+impl PostWhitelabelIpsRequestBuilder {
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.0.domain = domain.into();
+        self
+    }
+
+    pub fn ip(mut self, ip: impl Into<String>) -> Self {
+        self.0.ip = ip.into();
+        self
+    }
+
+    pub fn subdomain(mut self, subdomain: impl Into<String>) -> Self {
+        self.0.subdomain = subdomain.into();
+        self
+    }
+
+    pub fn build(self) -> PostWhitelabelIpsRequest {
+        self.0
+    }
+}
+
 /// The specific results of the validation.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelIpsValidateResponseValidationResults {
@@ -10561,7 +11966,7 @@ pub struct PostWhitelabelIpsValidateResponse {
     pub validation_results: PostWhitelabelIpsValidateResponseValidationResults,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostValidationsEmailRequest {
     /**
     * The license key provided with your New Relic account.
@@ -10580,6 +11985,34 @@ pub struct PostValidationsEmailRequest {
     pub source: String,
 }
 
+impl PostValidationsEmailRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostValidationsEmailRequestBuilder {
+        PostValidationsEmailRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostValidationsEmailRequest`.
+#[derive(Debug, Clone)]
+pub struct PostValidationsEmailRequestBuilder(PostValidationsEmailRequest);
+
+impl PostValidationsEmailRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.0.source = source.into();
+        self
+    }
+
+    pub fn build(self) -> PostValidationsEmailRequest {
+        self.0
+    }
+}
+
 /**
 * A generic classification of whether or not the email address is valid.
 */
@@ -10593,8 +12026,8 @@ pub enum Verdict {
     Valid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Verdict {
@@ -10604,7 +12037,7 @@ impl std::fmt::Display for Verdict {
             Verdict::Risky => "Risky",
             Verdict::Valid => "Valid",
             Verdict::Noop => "",
-            Verdict::FallthroughString => "*",
+            Verdict::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10787,7 +12220,7 @@ pub struct PostValidationsEmailResponse {
     pub result: PostValidationsEmailResponseResult,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDnsEmailRequest {
     #[serde(
         default,
@@ -10818,6 +12251,44 @@ pub struct PostWhitelabelDnsEmailRequest {
     pub message: String,
 }
 
+impl PostWhitelabelDnsEmailRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostWhitelabelDnsEmailRequestBuilder {
+        PostWhitelabelDnsEmailRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostWhitelabelDnsEmailRequest`.
+#[derive(Debug, Clone)]
+pub struct PostWhitelabelDnsEmailRequestBuilder(PostWhitelabelDnsEmailRequest);
+
+impl PostWhitelabelDnsEmailRequestBuilder {
+    pub fn domain_id(mut self, domain_id: i64) -> Self {
+        self.0.domain_id = domain_id;
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn link_id(mut self, link_id: i64) -> Self {
+        self.0.link_id = link_id;
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn build(self) -> PostWhitelabelDnsEmailRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDnsEmailResponseErrors {
     /**
@@ -10904,7 +12375,7 @@ pub struct GetIpsPoolsPoolNameResponse {
     pub pool_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutIpsPoolsPoolNameRequest {
     #[serde(
         default,
@@ -10914,6 +12385,43 @@ pub struct PutIpsPoolsPoolNameRequest {
     pub name: String,
 }
 
+impl std::convert::From<String> for PutIpsPoolsPoolNameRequest {
+    fn from(name: String) -> Self {
+        PutIpsPoolsPoolNameRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PutIpsPoolsPoolNameRequest {
+    fn from(name: &str) -> Self {
+        PutIpsPoolsPoolNameRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PutIpsPoolsPoolNameRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutIpsPoolsPoolNameRequestBuilder {
+        PutIpsPoolsPoolNameRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutIpsPoolsPoolNameRequest`.
+#[derive(Debug, Clone)]
+pub struct PutIpsPoolsPoolNameRequestBuilder(PutIpsPoolsPoolNameRequest);
+
+impl PutIpsPoolsPoolNameRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PutIpsPoolsPoolNameRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteIpsPoolsPoolNameResponse {
     /**
@@ -10998,7 +12506,7 @@ pub struct GetIpsResponse {
     pub whitelabeled: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsRequest {
     #[serde(
         default,
@@ -11016,6 +12524,39 @@ pub struct PostIpsRequest {
     pub warmup: Option<bool>,
 }
 
+impl PostIpsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostIpsRequestBuilder {
+        PostIpsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostIpsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostIpsRequestBuilder(PostIpsRequest);
+
+impl PostIpsRequestBuilder {
+    pub fn count(mut self, count: i64) -> Self {
+        self.0.count = count;
+        self
+    }
+
+    pub fn subusers(mut self, subusers: Vec<String>) -> Self {
+        self.0.subusers = subusers;
+        self
+    }
+
+    pub fn warmup(mut self, warmup: bool) -> Self {
+        self.0.warmup = Some(warmup);
+        self
+    }
+
+    pub fn build(self) -> PostIpsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsResponse {
     /**
@@ -11165,7 +12706,7 @@ pub struct GetIpsIpAddressResponse {
     pub whitelabeled: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDomainsRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub automatic_security: Option<bool>,
@@ -11208,7 +12749,65 @@ pub struct PostWhitelabelDomainsRequest {
     pub username: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostWhitelabelDomainsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostWhitelabelDomainsRequestBuilder {
+        PostWhitelabelDomainsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostWhitelabelDomainsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostWhitelabelDomainsRequestBuilder(PostWhitelabelDomainsRequest);
+
+impl PostWhitelabelDomainsRequestBuilder {
+    pub fn automatic_security(mut self, automatic_security: bool) -> Self {
+        self.0.automatic_security = Some(automatic_security);
+        self
+    }
+
+    pub fn custom_dkim_selector(mut self, custom_dkim_selector: impl Into<String>) -> Self {
+        self.0.custom_dkim_selector = custom_dkim_selector.into();
+        self
+    }
+
+    pub fn custom_spf(mut self, custom_spf: bool) -> Self {
+        self.0.custom_spf = Some(custom_spf);
+        self
+    }
+
+    pub fn default(mut self, default: bool) -> Self {
+        self.0.default = Some(default);
+        self
+    }
+
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.0.domain = domain.into();
+        self
+    }
+
+    pub fn ips(mut self, ips: Vec<String>) -> Self {
+        self.0.ips = ips;
+        self
+    }
+
+    pub fn subdomain(mut self, subdomain: impl Into<String>) -> Self {
+        self.0.subdomain = subdomain.into();
+        self
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.0.username = username.into();
+        self
+    }
+
+    pub fn build(self) -> PostWhitelabelDomainsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchWhitelabelDomainsDomainRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_spf: Option<bool>,
@@ -11216,6 +12815,34 @@ pub struct PatchWhitelabelDomainsDomainRequest {
     pub default: Option<bool>,
 }
 
+impl PatchWhitelabelDomainsDomainRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchWhitelabelDomainsDomainRequestBuilder {
+        PatchWhitelabelDomainsDomainRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchWhitelabelDomainsDomainRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchWhitelabelDomainsDomainRequestBuilder(PatchWhitelabelDomainsDomainRequest);
+
+impl PatchWhitelabelDomainsDomainRequestBuilder {
+    pub fn custom_spf(mut self, custom_spf: bool) -> Self {
+        self.0.custom_spf = Some(custom_spf);
+        self
+    }
+
+    pub fn default(mut self, default: bool) -> Self {
+        self.0.default = Some(default);
+        self
+    }
+
+    pub fn build(self) -> PatchWhitelabelDomainsDomainRequest {
+        self.0
+    }
+}
+
 /// The CNAME record for the authenticated domain.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailCname {
@@ -11490,7 +13117,7 @@ pub struct DeleteVerifiedSendersResponse {
     pub errors: Vec<GetVerifiedSendersVerifyTokenResponseErrors>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutDesignRequest {
     #[serde(
         default,
@@ -11526,6 +13153,54 @@ pub struct PutDesignRequest {
     pub subject: String,
 }
 
+impl PutDesignRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutDesignRequestBuilder {
+        PutDesignRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutDesignRequest`.
+#[derive(Debug, Clone)]
+pub struct PutDesignRequestBuilder(PutDesignRequest);
+
+impl PutDesignRequestBuilder {
+    pub fn categories(mut self, categories: Vec<String>) -> Self {
+        self.0.categories = categories;
+        self
+    }
+
+    pub fn generate_plain_content(mut self, generate_plain_content: bool) -> Self {
+        self.0.generate_plain_content = Some(generate_plain_content);
+        self
+    }
+
+    pub fn html_content(mut self, html_content: impl Into<String>) -> Self {
+        self.0.html_content = html_content.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn plain_content(mut self, plain_content: impl Into<String>) -> Self {
+        self.0.plain_content = plain_content.into();
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.0.subject = subject.into();
+        self
+    }
+
+    pub fn build(self) -> PutDesignRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListDesignsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
@@ -11566,7 +13241,7 @@ pub struct PostMcListsResponse {
     pub errors: Vec<Error>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutMcContactsRequest {
     /**
     * One or more contacts objects that you intend to upsert. The available fields for a contact, including the required `email` field are described below.
@@ -11588,6 +13263,34 @@ pub struct PutMcContactsRequest {
     pub list_ids: Vec<String>,
 }
 
+impl PutMcContactsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutMcContactsRequestBuilder {
+        PutMcContactsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutMcContactsRequest`.
+#[derive(Debug, Clone)]
+pub struct PutMcContactsRequestBuilder(PutMcContactsRequest);
+
+impl PutMcContactsRequestBuilder {
+    pub fn contacts(mut self, contacts: Vec<ContactRequest>) -> Self {
+        self.0.contacts = contacts;
+        self
+    }
+
+    pub fn list_ids(mut self, list_ids: Vec<String>) -> Self {
+        self.0.list_ids = list_ids;
+        self
+    }
+
+    pub fn build(self) -> PutMcContactsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutMcContactsResponse {
     /**
@@ -11872,8 +13575,8 @@ pub enum FileType {
     Csv,
     #[serde(rename = "json")]
     Json,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -11881,7 +13584,7 @@ impl std::fmt::Display for FileType {
         match &*self {
             FileType::Csv => "csv",
             FileType::Json => "json",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11893,7 +13596,7 @@ impl Default for FileType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcContactsExportsRequest {
     /**
     * File type for export file. Choose from `json` or `csv`.
@@ -11928,6 +13631,49 @@ pub struct PostMcContactsExportsRequest {
     pub segment_ids: Vec<String>,
 }
 
+impl PostMcContactsExportsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMcContactsExportsRequestBuilder {
+        PostMcContactsExportsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMcContactsExportsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMcContactsExportsRequestBuilder(PostMcContactsExportsRequest);
+
+impl PostMcContactsExportsRequestBuilder {
+    pub fn file_type(mut self, file_type: impl Into<FileType>) -> Self {
+        self.0.file_type = Some(file_type.into());
+        self
+    }
+
+    pub fn list_ids(mut self, list_ids: Vec<String>) -> Self {
+        self.0.list_ids = list_ids;
+        self
+    }
+
+    pub fn max_file_size(mut self, max_file_size: i64) -> Self {
+        self.0.max_file_size = max_file_size;
+        self
+    }
+
+    pub fn notifications(mut self, notifications: impl Into<Notifications>) -> Self {
+        self.0.notifications = Some(notifications.into());
+        self
+    }
+
+    pub fn segment_ids(mut self, segment_ids: Vec<String>) -> Self {
+        self.0.segment_ids = segment_ids;
+        self
+    }
+
+    pub fn build(self) -> PostMcContactsExportsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcContactsExportsResponse {
     #[serde(rename = "_metadata")]
@@ -11943,7 +13689,7 @@ pub struct PostMcContactsExportsResponse {
     pub id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcContactsSearchRequest {
     /**
     * The license key provided with your New Relic account.
@@ -11956,6 +13702,43 @@ pub struct PostMcContactsSearchRequest {
     pub query: String,
 }
 
+impl std::convert::From<String> for PostMcContactsSearchRequest {
+    fn from(query: String) -> Self {
+        PostMcContactsSearchRequest { query }
+    }
+}
+
+impl std::convert::From<&str> for PostMcContactsSearchRequest {
+    fn from(query: &str) -> Self {
+        PostMcContactsSearchRequest {
+            query: query.to_string(),
+        }
+    }
+}
+
+impl PostMcContactsSearchRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMcContactsSearchRequestBuilder {
+        PostMcContactsSearchRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMcContactsSearchRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMcContactsSearchRequestBuilder(PostMcContactsSearchRequest);
+
+impl PostMcContactsSearchRequestBuilder {
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.0.query = query.into();
+        self
+    }
+
+    pub fn build(self) -> PostMcContactsSearchRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcContactsSearchResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
@@ -11986,8 +13769,8 @@ pub enum PutMcContactsImportsRequestFileType {
     Csv,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMcContactsImportsRequestFileType {
@@ -11995,7 +13778,7 @@ impl std::fmt::Display for PutMcContactsImportsRequestFileType {
         match &*self {
             PutMcContactsImportsRequestFileType::Csv => "csv",
             PutMcContactsImportsRequestFileType::Noop => "",
-            PutMcContactsImportsRequestFileType::FallthroughString => "*",
+            PutMcContactsImportsRequestFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12028,7 +13811,7 @@ pub struct FieldMappingsAnyOf {
     pub value: serde_json::Value,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutMcContactsImportsRequest {
     /**
     * Import file header to reserved/custom field mapping.
@@ -12058,6 +13841,39 @@ pub struct PutMcContactsImportsRequest {
     pub list_ids: Vec<String>,
 }
 
+impl PutMcContactsImportsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutMcContactsImportsRequestBuilder {
+        PutMcContactsImportsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutMcContactsImportsRequest`.
+#[derive(Debug, Clone)]
+pub struct PutMcContactsImportsRequestBuilder(PutMcContactsImportsRequest);
+
+impl PutMcContactsImportsRequestBuilder {
+    pub fn field_mappings(mut self, field_mappings: Vec<FieldMappingsAnyOf>) -> Self {
+        self.0.field_mappings = field_mappings;
+        self
+    }
+
+    pub fn file_type(mut self, file_type: impl Into<PutMcContactsImportsRequestFileType>) -> Self {
+        self.0.file_type = file_type.into();
+        self
+    }
+
+    pub fn list_ids(mut self, list_ids: Vec<String>) -> Self {
+        self.0.list_ids = list_ids;
+        self
+    }
+
+    pub fn build(self) -> PutMcContactsImportsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UploadHeaders {
     /**
@@ -12128,7 +13944,7 @@ pub struct GetMarketingContactsImportsResponse {
 }
 
 /// Array of IDs
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingContactsBatchRequest {
     /**
     * The recipient IDs of the recipients that already existed from this request.
@@ -12141,6 +13957,29 @@ pub struct PostMarketingContactsBatchRequest {
     pub ids: Vec<String>,
 }
 
+impl PostMarketingContactsBatchRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMarketingContactsBatchRequestBuilder {
+        PostMarketingContactsBatchRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMarketingContactsBatchRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMarketingContactsBatchRequestBuilder(PostMarketingContactsBatchRequest);
+
+impl PostMarketingContactsBatchRequestBuilder {
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.0.ids = ids;
+        self
+    }
+
+    pub fn build(self) -> PostMarketingContactsBatchRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingContactsBatchResponse {
     #[serde(
@@ -12152,7 +13991,7 @@ pub struct PostMarketingContactsBatchResponse {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingContactsSearchEmailsRequest {
     /**
     * The recipient IDs of the recipients that already existed from this request.
@@ -12165,6 +14004,31 @@ pub struct PostMarketingContactsSearchEmailsRequest {
     pub emails: Vec<String>,
 }
 
+impl PostMarketingContactsSearchEmailsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMarketingContactsSearchEmailsRequestBuilder {
+        PostMarketingContactsSearchEmailsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMarketingContactsSearchEmailsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMarketingContactsSearchEmailsRequestBuilder(
+    PostMarketingContactsSearchEmailsRequest,
+);
+
+impl PostMarketingContactsSearchEmailsRequestBuilder {
+    pub fn emails(mut self, emails: Vec<String>) -> Self {
+        self.0.emails = emails;
+        self
+    }
+
+    pub fn build(self) -> PostMarketingContactsSearchEmailsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingContactsSearchEmailsResponse {
     /**
@@ -12174,7 +14038,7 @@ pub struct PostMarketingContactsSearchEmailsResponse {
     pub result: Option<Help>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSendersRequest {
     /**
     * The license key provided with your New Relic account.
@@ -12236,6 +14100,69 @@ pub struct PostMarketingSendersRequest {
     pub zip: String,
 }
 
+impl PostMarketingSendersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMarketingSendersRequestBuilder {
+        PostMarketingSendersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMarketingSendersRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMarketingSendersRequestBuilder(PostMarketingSendersRequest);
+
+impl PostMarketingSendersRequestBuilder {
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.0.address = address.into();
+        self
+    }
+
+    pub fn address_2(mut self, address_2: impl Into<String>) -> Self {
+        self.0.address_2 = address_2.into();
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.0.city = city.into();
+        self
+    }
+
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.0.country = country.into();
+        self
+    }
+
+    pub fn from(mut self, from: impl Into<From>) -> Self {
+        self.0.from = from.into();
+        self
+    }
+
+    pub fn nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.0.nickname = nickname.into();
+        self
+    }
+
+    pub fn reply_to(mut self, reply_to: impl Into<ReplyTo>) -> Self {
+        self.0.reply_to = Some(reply_to.into());
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = state.into();
+        self
+    }
+
+    pub fn zip(mut self, zip: impl Into<String>) -> Self {
+        self.0.zip = zip.into();
+        self
+    }
+
+    pub fn build(self) -> PostMarketingSendersRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMcListsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
@@ -12297,7 +14224,7 @@ pub struct DeleteListsResponse {
     pub job_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMcListsRequest {
     #[serde(
         default,
@@ -12307,6 +14234,43 @@ pub struct PatchMcListsRequest {
     pub name: String,
 }
 
+impl std::convert::From<String> for PatchMcListsRequest {
+    fn from(name: String) -> Self {
+        PatchMcListsRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PatchMcListsRequest {
+    fn from(name: &str) -> Self {
+        PatchMcListsRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PatchMcListsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchMcListsRequestBuilder {
+        PatchMcListsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchMcListsRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchMcListsRequestBuilder(PatchMcListsRequest);
+
+impl PatchMcListsRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PatchMcListsRequest {
+        self.0
+    }
+}
+
 /// The removal is accepted and processing.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteMcListsContactsResponse {
@@ -12339,7 +14303,7 @@ pub struct GetMcFieldDefinitionsResponse {
     pub reserved_fields: Vec<ReservedFieldDefinitionsResponse>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcFieldDefinitionsRequest {
     #[serde(default, skip_serializing_if = "FieldType::is_noop")]
     pub field_type: FieldType,
@@ -12354,6 +14318,34 @@ pub struct PostMcFieldDefinitionsRequest {
     pub name: String,
 }
 
+impl PostMcFieldDefinitionsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMcFieldDefinitionsRequestBuilder {
+        PostMcFieldDefinitionsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMcFieldDefinitionsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMcFieldDefinitionsRequestBuilder(PostMcFieldDefinitionsRequest);
+
+impl PostMcFieldDefinitionsRequestBuilder {
+    pub fn field_type(mut self, field_type: impl Into<FieldType>) -> Self {
+        self.0.field_type = field_type.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PostMcFieldDefinitionsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcFieldDefinitionsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
@@ -12383,7 +14375,7 @@ pub struct GetMarketingSegmentsResponse {
     pub results: Vec<SegmentSummary>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSegmentsRequest {
     /**
     * The license key provided with your New Relic account.
@@ -12396,6 +14388,43 @@ pub struct PostMarketingSegmentsRequest {
     pub parent_list_id: String,
 }
 
+impl std::convert::From<String> for PostMarketingSegmentsRequest {
+    fn from(parent_list_id: String) -> Self {
+        PostMarketingSegmentsRequest { parent_list_id }
+    }
+}
+
+impl std::convert::From<&str> for PostMarketingSegmentsRequest {
+    fn from(parent_list_id: &str) -> Self {
+        PostMarketingSegmentsRequest {
+            parent_list_id: parent_list_id.to_string(),
+        }
+    }
+}
+
+impl PostMarketingSegmentsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMarketingSegmentsRequestBuilder {
+        PostMarketingSegmentsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMarketingSegmentsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMarketingSegmentsRequestBuilder(PostMarketingSegmentsRequest);
+
+impl PostMarketingSegmentsRequestBuilder {
+    pub fn parent_list_id(mut self, parent_list_id: impl Into<String>) -> Self {
+        self.0.parent_list_id = parent_list_id.into();
+        self
+    }
+
+    pub fn build(self) -> PostMarketingSegmentsRequest {
+        self.0
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `SegmentWriteV2`
@@ -12409,7 +14438,7 @@ pub struct PostMarketingSegmentsRequestAllOf {
     pub post_marketing_segments_request: PostMarketingSegmentsRequest,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSegmentsDeleteRequest {
     #[serde(
         default,
@@ -12419,6 +14448,29 @@ pub struct PostMarketingSegmentsDeleteRequest {
     pub ids: Vec<String>,
 }
 
+impl PostMarketingSegmentsDeleteRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMarketingSegmentsDeleteRequestBuilder {
+        PostMarketingSegmentsDeleteRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMarketingSegmentsDeleteRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMarketingSegmentsDeleteRequestBuilder(PostMarketingSegmentsDeleteRequest);
+
+impl PostMarketingSegmentsDeleteRequestBuilder {
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.0.ids = ids;
+        self
+    }
+
+    pub fn build(self) -> PostMarketingSegmentsDeleteRequest {
+        self.0
+    }
+}
+
 /// resources in which segment is being used
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Resources {
@@ -12502,7 +14554,7 @@ pub struct PostMarketingSinglesendsResponse {
     pub errors: Vec<SsoErrorResponse>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSinglesendsRequest {
     /**
     * The license key provided with your New Relic account.
@@ -12515,6 +14567,43 @@ pub struct PostMarketingSinglesendsRequest {
     pub name: String,
 }
 
+impl std::convert::From<String> for PostMarketingSinglesendsRequest {
+    fn from(name: String) -> Self {
+        PostMarketingSinglesendsRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PostMarketingSinglesendsRequest {
+    fn from(name: &str) -> Self {
+        PostMarketingSinglesendsRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PostMarketingSinglesendsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMarketingSinglesendsRequestBuilder {
+        PostMarketingSinglesendsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMarketingSinglesendsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMarketingSinglesendsRequestBuilder(PostMarketingSinglesendsRequest);
+
+impl PostMarketingSinglesendsRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PostMarketingSinglesendsRequest {
+        self.0
+    }
+}
+
 /**
 * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
 */
@@ -12524,8 +14613,8 @@ pub enum SendAt {
     Now,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendAt {
@@ -12533,7 +14622,7 @@ impl std::fmt::Display for SendAt {
         match &*self {
             SendAt::Now => "now",
             SendAt::Noop => "",
-            SendAt::FallthroughString => "*",
+            SendAt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12550,7 +14639,7 @@ impl SendAt {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutMarketingSinglesendsScheduleRequest {
     /**
     * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
@@ -12559,14 +14648,37 @@ pub struct PutMarketingSinglesendsScheduleRequest {
     pub send_at: SendAt,
 }
 
+impl PutMarketingSinglesendsScheduleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PutMarketingSinglesendsScheduleRequestBuilder {
+        PutMarketingSinglesendsScheduleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PutMarketingSinglesendsScheduleRequest`.
+#[derive(Debug, Clone)]
+pub struct PutMarketingSinglesendsScheduleRequestBuilder(PutMarketingSinglesendsScheduleRequest);
+
+impl PutMarketingSinglesendsScheduleRequestBuilder {
+    pub fn send_at(mut self, send_at: impl Into<SendAt>) -> Self {
+        self.0.send_at = send_at.into();
+        self
+    }
+
+    pub fn build(self) -> PutMarketingSinglesendsScheduleRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum PutMarketingSinglesendsScheduleResponseStatus {
     #[serde(rename = "scheduled")]
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
@@ -12574,7 +14686,7 @@ impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
         match &*self {
             PutMarketingSinglesendsScheduleResponseStatus::Scheduled => "scheduled",
             PutMarketingSinglesendsScheduleResponseStatus::Noop => "",
-            PutMarketingSinglesendsScheduleResponseStatus::FallthroughString => "*",
+            PutMarketingSinglesendsScheduleResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12616,7 +14728,7 @@ pub struct GetMarketingSinglesendsCategoriesResponse {
     pub categories: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingTestSendEmailRequest {
     #[serde(
         default,
@@ -12668,6 +14780,59 @@ pub struct PostMarketingTestSendEmailRequest {
     pub version_id_override: String,
 }
 
+impl PostMarketingTestSendEmailRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostMarketingTestSendEmailRequestBuilder {
+        PostMarketingTestSendEmailRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostMarketingTestSendEmailRequest`.
+#[derive(Debug, Clone)]
+pub struct PostMarketingTestSendEmailRequestBuilder(PostMarketingTestSendEmailRequest);
+
+impl PostMarketingTestSendEmailRequestBuilder {
+    pub fn custom_unsubscribe_url(mut self, custom_unsubscribe_url: impl Into<String>) -> Self {
+        self.0.custom_unsubscribe_url = custom_unsubscribe_url.into();
+        self
+    }
+
+    pub fn emails(mut self, emails: Vec<String>) -> Self {
+        self.0.emails = emails;
+        self
+    }
+
+    pub fn from_address(mut self, from_address: impl Into<String>) -> Self {
+        self.0.from_address = from_address.into();
+        self
+    }
+
+    pub fn sender_id(mut self, sender_id: i64) -> Self {
+        self.0.sender_id = sender_id;
+        self
+    }
+
+    pub fn suppression_group_id(mut self, suppression_group_id: i64) -> Self {
+        self.0.suppression_group_id = suppression_group_id;
+        self
+    }
+
+    pub fn template_id(mut self, template_id: impl Into<String>) -> Self {
+        self.0.template_id = template_id.into();
+        self
+    }
+
+    pub fn version_id_override(mut self, version_id_override: impl Into<String>) -> Self {
+        self.0.version_id_override = version_id_override.into();
+        self
+    }
+
+    pub fn build(self) -> PostMarketingTestSendEmailRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSendersResponse {
     #[serde(
@@ -12678,7 +14843,7 @@ pub struct GetSendersResponse {
     pub result: Vec<SenderAllOf>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSendersRequest {
     /**
     * helper text or docs for troubleshooting
@@ -12692,6 +14857,34 @@ pub struct PostSendersRequest {
     pub reply_to: Option<Help>,
 }
 
+impl PostSendersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostSendersRequestBuilder {
+        PostSendersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostSendersRequest`.
+#[derive(Debug, Clone)]
+pub struct PostSendersRequestBuilder(PostSendersRequest);
+
+impl PostSendersRequestBuilder {
+    pub fn from(mut self, from: impl Into<Help>) -> Self {
+        self.0.from = Some(from.into());
+        self
+    }
+
+    pub fn reply_to(mut self, reply_to: impl Into<Help>) -> Self {
+        self.0.reply_to = Some(reply_to.into());
+        self
+    }
+
+    pub fn build(self) -> PostSendersRequest {
+        self.0
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `SenderRequest`
@@ -12760,7 +14953,7 @@ pub struct ListRecipientsResponse {
     pub recipients: Vec<Help>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostContactdbRecipientsRequest {
     #[serde(
         default,
@@ -12797,7 +14990,45 @@ pub struct PostContactdbRecipientsRequest {
     pub last_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostContactdbRecipientsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostContactdbRecipientsRequestBuilder {
+        PostContactdbRecipientsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostContactdbRecipientsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostContactdbRecipientsRequestBuilder(PostContactdbRecipientsRequest);
+
+impl PostContactdbRecipientsRequestBuilder {
+    pub fn age(mut self, age: i64) -> Self {
+        self.0.age = age;
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn build(self) -> PostContactdbRecipientsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchContactdbRecipientsRequest {
     /**
     * The license key provided with your New Relic account.
@@ -12828,6 +15059,39 @@ pub struct PatchContactdbRecipientsRequest {
     pub last_name: String,
 }
 
+impl PatchContactdbRecipientsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchContactdbRecipientsRequestBuilder {
+        PatchContactdbRecipientsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchContactdbRecipientsRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchContactdbRecipientsRequestBuilder(PatchContactdbRecipientsRequest);
+
+impl PatchContactdbRecipientsRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn build(self) -> PatchContactdbRecipientsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetContactdbStatusResponse {
     /**
@@ -12870,7 +15134,7 @@ pub struct GetContactdbRecipientsRecipientListsResponse {
     pub lists: Vec<ContactdbList>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostContactdbRecipientsSearchRequest {
     /**
     * The recipient IDs of the recipients that already existed from this request.
@@ -12889,6 +15153,34 @@ pub struct PostContactdbRecipientsSearchRequest {
     pub list_id: i64,
 }
 
+impl PostContactdbRecipientsSearchRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostContactdbRecipientsSearchRequestBuilder {
+        PostContactdbRecipientsSearchRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostContactdbRecipientsSearchRequest`.
+#[derive(Debug, Clone)]
+pub struct PostContactdbRecipientsSearchRequestBuilder(PostContactdbRecipientsSearchRequest);
+
+impl PostContactdbRecipientsSearchRequestBuilder {
+    pub fn conditions(mut self, conditions: Vec<String>) -> Self {
+        self.0.conditions = conditions;
+        self
+    }
+
+    pub fn list_id(mut self, list_id: i64) -> Self {
+        self.0.list_id = list_id;
+        self
+    }
+
+    pub fn build(self) -> PostContactdbRecipientsSearchRequest {
+        self.0
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `String`
@@ -13029,7 +15321,7 @@ pub struct ListAllCustomFieldsResponse {
     pub custom_fields: Vec<ContactdbCustomFieldWithAllOf>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostContactdbCustomFieldsRequest {
     #[serde(
         default,
@@ -13046,6 +15338,34 @@ pub struct PostContactdbCustomFieldsRequest {
     pub type_: String,
 }
 
+impl PostContactdbCustomFieldsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostContactdbCustomFieldsRequestBuilder {
+        PostContactdbCustomFieldsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostContactdbCustomFieldsRequest`.
+#[derive(Debug, Clone)]
+pub struct PostContactdbCustomFieldsRequestBuilder(PostContactdbCustomFieldsRequest);
+
+impl PostContactdbCustomFieldsRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<String>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> PostContactdbCustomFieldsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReservedFields {
     /**
@@ -13089,7 +15409,7 @@ pub struct ListAllSegmentsResponse {
     pub segments: Vec<ContactdbSegments>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchContactdbSegmentsSegmentRequest {
     #[serde(
         default,
@@ -13114,6 +15434,39 @@ pub struct PatchContactdbSegmentsSegmentRequest {
     pub name: String,
 }
 
+impl PatchContactdbSegmentsSegmentRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchContactdbSegmentsSegmentRequestBuilder {
+        PatchContactdbSegmentsSegmentRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchContactdbSegmentsSegmentRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchContactdbSegmentsSegmentRequestBuilder(PatchContactdbSegmentsSegmentRequest);
+
+impl PatchContactdbSegmentsSegmentRequestBuilder {
+    pub fn conditions(mut self, conditions: Vec<ContactdbSegmentsConditions>) -> Self {
+        self.0.conditions = conditions;
+        self
+    }
+
+    pub fn list_id(mut self, list_id: f64) -> Self {
+        self.0.list_id = list_id;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PatchContactdbSegmentsSegmentRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListRecipientsOnASegmentResponse {
     #[serde(
@@ -13269,7 +15622,7 @@ pub struct GetCampaignsCampaignResponse {
     pub title: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateACampaignRequest {
     /**
     * The recipient IDs of the recipients that already existed from this request.
@@ -13318,6 +15671,49 @@ pub struct UpdateACampaignRequest {
     pub title: String,
 }
 
+impl UpdateACampaignRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateACampaignRequestBuilder {
+        UpdateACampaignRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateACampaignRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateACampaignRequestBuilder(UpdateACampaignRequest);
+
+impl UpdateACampaignRequestBuilder {
+    pub fn categories(mut self, categories: Vec<String>) -> Self {
+        self.0.categories = categories;
+        self
+    }
+
+    pub fn html_content(mut self, html_content: impl Into<String>) -> Self {
+        self.0.html_content = html_content.into();
+        self
+    }
+
+    pub fn plain_content(mut self, plain_content: impl Into<String>) -> Self {
+        self.0.plain_content = plain_content.into();
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.0.subject = subject.into();
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateACampaignRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendACampaignResponse {
     #[serde(
@@ -13337,7 +15733,7 @@ pub struct SendACampaignResponse {
     pub status: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ScheduleACampaignRequest {
     #[serde(
         default,
@@ -13347,6 +15743,29 @@ pub struct ScheduleACampaignRequest {
     pub send_at: i64,
 }
 
+impl ScheduleACampaignRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ScheduleACampaignRequestBuilder {
+        ScheduleACampaignRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ScheduleACampaignRequest`.
+#[derive(Debug, Clone)]
+pub struct ScheduleACampaignRequestBuilder(ScheduleACampaignRequest);
+
+impl ScheduleACampaignRequestBuilder {
+    pub fn send_at(mut self, send_at: i64) -> Self {
+        self.0.send_at = send_at;
+        self
+    }
+
+    pub fn build(self) -> ScheduleACampaignRequest {
+        self.0
+    }
+}
+
 /**
 * The status of your campaign.
 */
@@ -13356,8 +15775,8 @@ pub enum ScheduleACampaignResponseStatus {
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScheduleACampaignResponseStatus {
@@ -13365,7 +15784,7 @@ impl std::fmt::Display for ScheduleACampaignResponseStatus {
         match &*self {
             ScheduleACampaignResponseStatus::Scheduled => "Scheduled",
             ScheduleACampaignResponseStatus::Noop => "",
-            ScheduleACampaignResponseStatus::FallthroughString => "*",
+            ScheduleACampaignResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13431,7 +15850,7 @@ pub struct UpdateAScheduledCampaignResponse {
     pub status: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendATestCampaignRequest {
     /**
     * The license key provided with your New Relic account.
@@ -13444,6 +15863,41 @@ pub struct SendATestCampaignRequest {
     pub to: String,
 }
 
+impl std::convert::From<String> for SendATestCampaignRequest {
+    fn from(to: String) -> Self {
+        SendATestCampaignRequest { to }
+    }
+}
+
+impl std::convert::From<&str> for SendATestCampaignRequest {
+    fn from(to: &str) -> Self {
+        SendATestCampaignRequest { to: to.to_string() }
+    }
+}
+
+impl SendATestCampaignRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SendATestCampaignRequestBuilder {
+        SendATestCampaignRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SendATestCampaignRequest`.
+#[derive(Debug, Clone)]
+pub struct SendATestCampaignRequestBuilder(SendATestCampaignRequest);
+
+impl SendATestCampaignRequestBuilder {
+    pub fn to(mut self, to: impl Into<String>) -> Self {
+        self.0.to = to.into();
+        self
+    }
+
+    pub fn build(self) -> SendATestCampaignRequest {
+        self.0
+    }
+}
+
 /**
 * Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
 */
@@ -13455,8 +15909,8 @@ pub enum Generations {
     Legacy,
     #[serde(rename = "legacy,dynamic")]
     LegacyDynamic,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generations {
@@ -13465,7 +15919,7 @@ impl std::fmt::Display for Generations {
             Generations::Dynamic => "dynamic",
             Generations::Legacy => "legacy",
             Generations::LegacyDynamic => "legacy,dynamic",
-            Generations::FallthroughString => "*",
+            Generations::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13524,7 +15978,7 @@ pub struct GetTemplatesResponseData {
     pub errors: Vec<GetTemplatesResponseErrors>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTemplatesRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub generation: Option<Generation>,
@@ -13539,7 +15993,35 @@ pub struct PostTemplatesRequest {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostTemplatesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostTemplatesRequestBuilder {
+        PostTemplatesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostTemplatesRequest`.
+#[derive(Debug, Clone)]
+pub struct PostTemplatesRequestBuilder(PostTemplatesRequest);
+
+impl PostTemplatesRequestBuilder {
+    pub fn generation(mut self, generation: impl Into<Generation>) -> Self {
+        self.0.generation = Some(generation.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PostTemplatesRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTemplatesTemplateRequest {
     #[serde(
         default,
@@ -13549,7 +16031,44 @@ pub struct PostTemplatesTemplateRequest {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl std::convert::From<String> for PostTemplatesTemplateRequest {
+    fn from(name: String) -> Self {
+        PostTemplatesTemplateRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PostTemplatesTemplateRequest {
+    fn from(name: &str) -> Self {
+        PostTemplatesTemplateRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PostTemplatesTemplateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostTemplatesTemplateRequestBuilder {
+        PostTemplatesTemplateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostTemplatesTemplateRequest`.
+#[derive(Debug, Clone)]
+pub struct PostTemplatesTemplateRequestBuilder(PostTemplatesTemplateRequest);
+
+impl PostTemplatesTemplateRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PostTemplatesTemplateRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTemplatesTemplateRequest {
     #[serde(
         default,
@@ -13559,6 +16078,43 @@ pub struct PatchTemplatesTemplateRequest {
     pub name: String,
 }
 
+impl std::convert::From<String> for PatchTemplatesTemplateRequest {
+    fn from(name: String) -> Self {
+        PatchTemplatesTemplateRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for PatchTemplatesTemplateRequest {
+    fn from(name: &str) -> Self {
+        PatchTemplatesTemplateRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl PatchTemplatesTemplateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchTemplatesTemplateRequestBuilder {
+        PatchTemplatesTemplateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchTemplatesTemplateRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchTemplatesTemplateRequestBuilder(PatchTemplatesTemplateRequest);
+
+impl PatchTemplatesTemplateRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> PatchTemplatesTemplateRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserWebhooksParseSettingsResponse {
     /**
@@ -13670,7 +16226,7 @@ pub struct PatchUserWebhooksEventSettingsSignedResponse {
     pub errors: Vec<PatchUserWebhooksEventSettingsSignedResponseErrors>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostUserWebhooksEventTestRequest {
     #[serde(
         default,
@@ -13698,6 +16254,44 @@ pub struct PostUserWebhooksEventTestRequest {
     pub url: String,
 }
 
+impl PostUserWebhooksEventTestRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostUserWebhooksEventTestRequestBuilder {
+        PostUserWebhooksEventTestRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostUserWebhooksEventTestRequest`.
+#[derive(Debug, Clone)]
+pub struct PostUserWebhooksEventTestRequestBuilder(PostUserWebhooksEventTestRequest);
+
+impl PostUserWebhooksEventTestRequestBuilder {
+    pub fn oauth_client_id(mut self, oauth_client_id: impl Into<String>) -> Self {
+        self.0.oauth_client_id = oauth_client_id.into();
+        self
+    }
+
+    pub fn oauth_client_secret(mut self, oauth_client_secret: impl Into<String>) -> Self {
+        self.0.oauth_client_secret = oauth_client_secret.into();
+        self
+    }
+
+    pub fn oauth_token_url(mut self, oauth_token_url: impl Into<String>) -> Self {
+        self.0.oauth_token_url = oauth_token_url.into();
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = url.into();
+        self
+    }
+
+    pub fn build(self) -> PostUserWebhooksEventTestRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMessagesResponse {
     #[serde(
@@ -13714,8 +16308,8 @@ pub enum PostMessagesDownloadResponseStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostMessagesDownloadResponseStatus {
@@ -13723,7 +16317,7 @@ impl std::fmt::Display for PostMessagesDownloadResponseStatus {
         match &*self {
             PostMessagesDownloadResponseStatus::Pending => "pending",
             PostMessagesDownloadResponseStatus::Noop => "",
-            PostMessagesDownloadResponseStatus::FallthroughString => "*",
+            PostMessagesDownloadResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13829,12 +16423,35 @@ pub struct GetTrackingSettingsResponse {
     pub result: Vec<GetTrackingSettingsResponseResult>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTrackingSettingsOpenRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
 }
 
+impl PatchTrackingSettingsOpenRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PatchTrackingSettingsOpenRequestBuilder {
+        PatchTrackingSettingsOpenRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PatchTrackingSettingsOpenRequest`.
+#[derive(Debug, Clone)]
+pub struct PatchTrackingSettingsOpenRequestBuilder(PatchTrackingSettingsOpenRequest);
+
+impl PatchTrackingSettingsOpenRequestBuilder {
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.enabled = Some(enabled);
+        self
+    }
+
+    pub fn build(self) -> PatchTrackingSettingsOpenRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -13874,8 +16491,8 @@ pub enum Country {
     Us,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Country {
@@ -13884,7 +16501,7 @@ impl std::fmt::Display for Country {
             Country::Ca => "CA",
             Country::Us => "US",
             Country::Noop => "",
-            Country::FallthroughString => "*",
+            Country::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14013,8 +16630,8 @@ pub enum ClientType {
     Webmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClientType {
@@ -14025,7 +16642,7 @@ impl std::fmt::Display for ClientType {
             ClientType::Tablet => "tablet",
             ClientType::Webmail => "webmail",
             ClientType::Noop => "",
-            ClientType::FallthroughString => "*",
+            ClientType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14139,7 +16756,7 @@ pub struct GetBrowsersStatsResponseData {
     pub stats: Vec<GetBrowsersStatsResponse>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteSuppressionBouncesRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_all: Option<bool>,
@@ -14151,7 +16768,35 @@ pub struct DeleteSuppressionBouncesRequest {
     pub emails: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl DeleteSuppressionBouncesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteSuppressionBouncesRequestBuilder {
+        DeleteSuppressionBouncesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteSuppressionBouncesRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteSuppressionBouncesRequestBuilder(DeleteSuppressionBouncesRequest);
+
+impl DeleteSuppressionBouncesRequestBuilder {
+    pub fn delete_all(mut self, delete_all: bool) -> Self {
+        self.0.delete_all = Some(delete_all);
+        self
+    }
+
+    pub fn emails(mut self, emails: Vec<String>) -> Self {
+        self.0.emails = emails;
+        self
+    }
+
+    pub fn build(self) -> DeleteSuppressionBouncesRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteSuppressionBlocksRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_all: Option<bool>,
@@ -14163,6 +16808,34 @@ pub struct DeleteSuppressionBlocksRequest {
     pub emails: Vec<String>,
 }
 
+impl DeleteSuppressionBlocksRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteSuppressionBlocksRequestBuilder {
+        DeleteSuppressionBlocksRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteSuppressionBlocksRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteSuppressionBlocksRequestBuilder(DeleteSuppressionBlocksRequest);
+
+impl DeleteSuppressionBlocksRequestBuilder {
+    pub fn delete_all(mut self, delete_all: bool) -> Self {
+        self.0.delete_all = Some(delete_all);
+        self
+    }
+
+    pub fn emails(mut self, emails: Vec<String>) -> Self {
+        self.0.emails = emails;
+        self
+    }
+
+    pub fn build(self) -> DeleteSuppressionBlocksRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSuppressionUnsubscribesResponse {
     #[serde(
@@ -109,24 +109,26 @@ impl Webhooks {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !offset.is_empty() {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/webhooks/parse/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/user/webhooks/parse/stats"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -151,21 +153,23 @@ impl Webhooks {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !offset.is_empty() {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/webhooks/parse/stats?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/user/webhooks/parse/stats"),
+            &query_args,
+        );
 
         self.client.get_all_pages(&url, None).await
     }
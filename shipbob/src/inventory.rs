@@ -24,7 +24,7 @@ impl Inventory {
     pub async fn get(&self, inventory_id: i64) -> Result<crate::types::Inventory> {
         let url = format!(
             "/inventory/{}",
-            crate::progenitor_support::encode_path(&inventory_id.to_string()),
+            crate::progenitor_support::encode_path(&inventory_id),
         );
 
         self.client.get(&url, None).await
@@ -61,30 +61,29 @@ impl Inventory {
         sort: &str,
         search: &str,
     ) -> Result<Vec<crate::types::Inventory>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if is_active {
-            query_args.push(("IsActive".to_string(), is_active.to_string()));
+            query_args.push(("IsActive", is_active.to_string()));
         }
         if is_digital {
-            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+            query_args.push(("IsDigital", is_digital.to_string()));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
         if !sort.is_empty() {
-            query_args.push(("Sort".to_string(), sort.to_string()));
+            query_args.push(("Sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/inventory?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/inventory"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -104,24 +103,23 @@ impl Inventory {
         sort: &str,
         search: &str,
     ) -> Result<Vec<crate::types::Inventory>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if is_active {
-            query_args.push(("IsActive".to_string(), is_active.to_string()));
+            query_args.push(("IsActive", is_active.to_string()));
         }
         if is_digital {
-            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+            query_args.push(("IsDigital", is_digital.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
         if !sort.is_empty() {
-            query_args.push(("Sort".to_string(), sort.to_string()));
+            query_args.push(("Sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/inventory?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/inventory"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -139,7 +137,7 @@ impl Inventory {
     pub async fn get_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
         let url = format!(
             "/product/{}/inventory",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            crate::progenitor_support::encode_path(&product_id),
         );
 
         self.client.get(&url, None).await
@@ -155,7 +153,7 @@ impl Inventory {
     pub async fn get_all_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
         let url = format!(
             "/product/{}/inventory",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            crate::progenitor_support::encode_path(&product_id),
         );
 
         self.client.get_all_pages(&url, None).await
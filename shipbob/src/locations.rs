@@ -29,21 +29,17 @@ impl Locations {
         receiving_enabled: bool,
         access_granted: bool,
     ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if access_granted {
-            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+            query_args.push(("AccessGranted", access_granted.to_string()));
         }
         if include_inactive {
-            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+            query_args.push(("IncludeInactive", include_inactive.to_string()));
         }
         if receiving_enabled {
-            query_args.push((
-                "ReceivingEnabled".to_string(),
-                receiving_enabled.to_string(),
-            ));
+            query_args.push(("ReceivingEnabled", receiving_enabled.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/location?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/location"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -61,21 +57,17 @@ impl Locations {
         receiving_enabled: bool,
         access_granted: bool,
     ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if access_granted {
-            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+            query_args.push(("AccessGranted", access_granted.to_string()));
         }
         if include_inactive {
-            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+            query_args.push(("IncludeInactive", include_inactive.to_string()));
         }
         if receiving_enabled {
-            query_args.push((
-                "ReceivingEnabled".to_string(),
-                receiving_enabled.to_string(),
-            ));
+            query_args.push(("ReceivingEnabled", receiving_enabled.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/location?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/location"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
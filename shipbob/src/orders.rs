@@ -49,7 +49,7 @@ impl Orders {
     pub async fn get(&self, order_id: i64) -> Result<crate::types::Order> {
         let url = format!(
             "/order/{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
         );
 
         self.client.get(&url, None).await
@@ -91,45 +91,41 @@ impl Orders {
         last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
         is_tracking_uploaded: bool,
     ) -> Result<Vec<crate::types::Order>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
         if has_tracking {
-            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+            query_args.push(("HasTracking", has_tracking.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if is_tracking_uploaded {
-            query_args.push((
-                "IsTrackingUploaded".to_string(),
-                is_tracking_uploaded.to_string(),
-            ));
+            query_args.push(("IsTrackingUploaded", is_tracking_uploaded.to_string()));
         }
         if let Some(date) = last_update_end_date {
-            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateEndDate", date.to_rfc3339()));
         }
         if let Some(date) = last_update_start_date {
-            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateStartDate", date.to_rfc3339()));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
         if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+            query_args.push(("ReferenceIds", reference_ids.join(" ")));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/order?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/order"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -155,39 +151,35 @@ impl Orders {
         last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
         is_tracking_uploaded: bool,
     ) -> Result<Vec<crate::types::Order>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
         if has_tracking {
-            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+            query_args.push(("HasTracking", has_tracking.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if is_tracking_uploaded {
-            query_args.push((
-                "IsTrackingUploaded".to_string(),
-                is_tracking_uploaded.to_string(),
-            ));
+            query_args.push(("IsTrackingUploaded", is_tracking_uploaded.to_string()));
         }
         if let Some(date) = last_update_end_date {
-            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateEndDate", date.to_rfc3339()));
         }
         if let Some(date) = last_update_start_date {
-            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateStartDate", date.to_rfc3339()));
         }
         if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+            query_args.push(("ReferenceIds", reference_ids.join(" ")));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/order?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/order"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -224,7 +216,7 @@ impl Orders {
     pub async fn post_cancel(&self, order_id: i64) -> Result<crate::types::OrdersCanceledOrder> {
         let url = format!(
             "/order/{}/cancel",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
         );
 
         self.client.post(&url, None).await
@@ -242,7 +234,7 @@ impl Orders {
     pub async fn get_store_json(&self, order_id: i64) -> Result<String> {
         let url = format!(
             "/order/{}/storeOrderJson",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
         );
 
         self.client.get(&url, None).await
@@ -264,7 +256,7 @@ impl Orders {
     ) -> Result<String> {
         let url = format!(
             "/order/{}/storeOrderJson",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
         );
 
         self.client
@@ -290,8 +282,8 @@ impl Orders {
     ) -> Result<crate::types::OrdersShipment> {
         let url = format!(
             "/order/{}/shipment/{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get(&url, None).await
@@ -311,12 +303,13 @@ impl Orders {
     pub async fn post_shipment_cancel(
         &self,
         shipment_id: i64,
-        order_id: &str,
+        order_id: impl AsRef<str>,
     ) -> Result<crate::types::OrdersShipment> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/order/{}/shipment/{}/cancel",
             crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.post(&url, None).await
@@ -340,8 +333,8 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get(&url, None).await
@@ -361,8 +354,8 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -381,7 +374,7 @@ impl Orders {
     pub async fn get_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>> {
         let url = format!(
             "/order/{}/shipment",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
         );
 
         self.client.get(&url, None).await
@@ -400,7 +393,7 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipment>> {
         let url = format!(
             "/order/{}/shipment",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -424,8 +417,8 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get(&url, None).await
@@ -445,8 +438,8 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&order_id),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -468,7 +461,7 @@ impl Orders {
     ) -> Result<crate::types::OrdersShipment> {
         let url = format!(
             "/shipment/{}",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get(&url, None).await
@@ -490,7 +483,7 @@ impl Orders {
     ) -> Result<crate::types::OrdersShipment> {
         let url = format!(
             "/shipment/{}/cancel",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.post(&url, None).await
@@ -531,7 +524,7 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get(&url, None).await
@@ -550,7 +543,7 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -572,7 +565,7 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get(&url, None).await
@@ -591,7 +584,7 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id),
         );
 
         self.client.get_all_pages(&url, None).await
@@ -614,15 +607,15 @@ impl Orders {
         page: i64,
         limit: i64,
     ) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/shippingmethod?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/shippingmethod"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -47,30 +47,29 @@ impl Products {
         active_status: crate::types::ProductActiveStatus,
         bundle_status: crate::types::ProductBundleStatus,
     ) -> Result<Vec<crate::types::Product>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !active_status.to_string().is_empty() {
-            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+            query_args.push(("ActiveStatus", active_status.to_string()));
         }
         if !bundle_status.to_string().is_empty() {
-            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+            query_args.push(("BundleStatus", bundle_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
         if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+            query_args.push(("ReferenceIds", reference_ids.join(" ")));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/product?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/product"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -90,24 +89,23 @@ impl Products {
         active_status: crate::types::ProductActiveStatus,
         bundle_status: crate::types::ProductBundleStatus,
     ) -> Result<Vec<crate::types::Product>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !active_status.to_string().is_empty() {
-            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+            query_args.push(("ActiveStatus", active_status.to_string()));
         }
         if !bundle_status.to_string().is_empty() {
-            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+            query_args.push(("BundleStatus", bundle_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+            query_args.push(("ReferenceIds", reference_ids.join(" ")));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/product?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/product"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -144,7 +142,7 @@ impl Products {
     pub async fn get(&self, product_id: i64) -> Result<crate::types::Product> {
         let url = format!(
             "/product/{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            crate::progenitor_support::encode_path(&product_id),
         );
 
         self.client.get(&url, None).await
@@ -167,7 +165,7 @@ impl Products {
     ) -> Result<Vec<crate::types::Product>> {
         let url = format!(
             "/product/{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            crate::progenitor_support::encode_path(&product_id),
         );
 
         self.client
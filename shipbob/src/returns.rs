@@ -23,10 +23,7 @@ impl Returns {
     * * `channel_id: i64` -- Unique id of the channel.
     */
     pub async fn get(&self, id: i64) -> Result<crate::types::ReturnOrder> {
-        let url = format!(
-            "/return/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/return/{}", crate::progenitor_support::encode_path(&id),);
 
         self.client.get(&url, None).await
     }
@@ -46,10 +43,7 @@ impl Returns {
         id: i64,
         body: &crate::types::ReturnsCreateReturn,
     ) -> Result<crate::types::ReturnOrder> {
-        let url = format!(
-            "/return/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/return/{}", crate::progenitor_support::encode_path(&id),);
 
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -92,51 +86,44 @@ impl Returns {
         original_shipment_ids: &[String],
         inventory_ids: &[String],
     ) -> Result<Vec<crate::types::ReturnOrder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
         if !fulfillment_center_ids.is_empty() {
-            query_args.push((
-                "FulfillmentCenterIds".to_string(),
-                fulfillment_center_ids.join(" "),
-            ));
+            query_args.push(("FulfillmentCenterIds", fulfillment_center_ids.join(" ")));
         }
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if !inventory_ids.is_empty() {
-            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+            query_args.push(("InventoryIds", inventory_ids.join(" ")));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if !original_shipment_ids.is_empty() {
-            query_args.push((
-                "OriginalShipmentIds".to_string(),
-                original_shipment_ids.join(" "),
-            ));
+            query_args.push(("OriginalShipmentIds", original_shipment_ids.join(" ")));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
         if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+            query_args.push(("ReferenceIds", reference_ids.join(" ")));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
         if !status.is_empty() {
-            query_args.push(("Status".to_string(), status.join(" ")));
+            query_args.push(("Status", status.join(" ")));
         }
         if !tracking_numbers.is_empty() {
-            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+            query_args.push(("TrackingNumbers", tracking_numbers.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/return?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/return"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -161,45 +148,38 @@ impl Returns {
         original_shipment_ids: &[String],
         inventory_ids: &[String],
     ) -> Result<Vec<crate::types::ReturnOrder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
         if !fulfillment_center_ids.is_empty() {
-            query_args.push((
-                "FulfillmentCenterIds".to_string(),
-                fulfillment_center_ids.join(" "),
-            ));
+            query_args.push(("FulfillmentCenterIds", fulfillment_center_ids.join(" ")));
         }
         if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+            query_args.push(("IDs", ids.join(" ")));
         }
         if !inventory_ids.is_empty() {
-            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+            query_args.push(("InventoryIds", inventory_ids.join(" ")));
         }
         if !original_shipment_ids.is_empty() {
-            query_args.push((
-                "OriginalShipmentIds".to_string(),
-                original_shipment_ids.join(" "),
-            ));
+            query_args.push(("OriginalShipmentIds", original_shipment_ids.join(" ")));
         }
         if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+            query_args.push(("ReferenceIds", reference_ids.join(" ")));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
         if !status.is_empty() {
-            query_args.push(("Status".to_string(), status.join(" ")));
+            query_args.push(("Status", status.join(" ")));
         }
         if !tracking_numbers.is_empty() {
-            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+            query_args.push(("TrackingNumbers", tracking_numbers.join(" ")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/return?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/return"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -236,7 +216,7 @@ impl Returns {
     pub async fn post_cancel(&self, id: i64) -> Result<crate::types::ReturnOrder> {
         let url = format!(
             "/return/{}/cancel",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client.post(&url, None).await
@@ -258,7 +238,7 @@ impl Returns {
     ) -> Result<crate::types::ReturnOrderStatusHistory> {
         let url = format!(
             "/return/{}/statushistory",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            crate::progenitor_support::encode_path(&id),
         );
 
         self.client.get(&url, None).await
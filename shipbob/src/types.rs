@@ -253,8 +253,8 @@ pub enum PackagingAttribute {
     Stackable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackagingAttribute {
@@ -270,7 +270,7 @@ impl std::fmt::Display for PackagingAttribute {
             PackagingAttribute::Poster => "Poster",
             PackagingAttribute::Stackable => "Stackable",
             PackagingAttribute::Noop => "",
-            PackagingAttribute::FallthroughString => "*",
+            PackagingAttribute::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -665,8 +665,8 @@ pub enum Type {
     ShipFrom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -675,7 +675,7 @@ impl std::fmt::Display for Type {
             Type::MarkFor => "MarkFor",
             Type::ShipFrom => "ShipFrom",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1189,8 +1189,8 @@ pub enum Status {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1207,7 +1207,7 @@ impl std::fmt::Display for Status {
             Status::Pending => "Pending",
             Status::Processing => "Processing",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1251,8 +1251,8 @@ pub enum PackageMaterialType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PackageMaterialType {
@@ -1269,7 +1269,7 @@ impl std::fmt::Display for PackageMaterialType {
             PackageMaterialType::Undefined => "Undefined",
             PackageMaterialType::Unknown => "Unknown",
             PackageMaterialType::Noop => "",
-            PackageMaterialType::FallthroughString => "*",
+            PackageMaterialType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1307,8 +1307,8 @@ pub enum EstimatedFulfillmentDateStatus {
     Unavailable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EstimatedFulfillmentDateStatus {
@@ -1324,7 +1324,7 @@ impl std::fmt::Display for EstimatedFulfillmentDateStatus {
             EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
             EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
             EstimatedFulfillmentDateStatus::Noop => "",
-            EstimatedFulfillmentDateStatus::FallthroughString => "*",
+            EstimatedFulfillmentDateStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1529,8 +1529,8 @@ pub enum CarrierType {
     Parcel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CarrierType {
@@ -1539,7 +1539,7 @@ impl std::fmt::Display for CarrierType {
             CarrierType::Freight => "Freight",
             CarrierType::Parcel => "Parcel",
             CarrierType::Noop => "",
-            CarrierType::FallthroughString => "*",
+            CarrierType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1575,8 +1575,8 @@ pub enum PaymentTerm {
     ThirdParty,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentTerm {
@@ -1586,7 +1586,7 @@ impl std::fmt::Display for PaymentTerm {
             PaymentTerm::Prepaid => "Prepaid",
             PaymentTerm::ThirdParty => "ThirdParty",
             PaymentTerm::Noop => "",
-            PaymentTerm::FallthroughString => "*",
+            PaymentTerm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1697,8 +1697,8 @@ pub enum OrderStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderStatus {
@@ -1711,7 +1711,7 @@ impl std::fmt::Display for OrderStatus {
             OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
             OrderStatus::Processing => "Processing",
             OrderStatus::Noop => "",
-            OrderStatus::FallthroughString => "*",
+            OrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1741,8 +1741,8 @@ pub enum OrderType {
     DropShip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderType {
@@ -1752,7 +1752,7 @@ impl std::fmt::Display for OrderType {
             OrderType::Dtc => "DTC",
             OrderType::DropShip => "DropShip",
             OrderType::Noop => "",
-            OrderType::FallthroughString => "*",
+            OrderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2080,8 +2080,8 @@ pub enum Action {
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -2089,7 +2089,7 @@ impl std::fmt::Display for Action {
         match &*self {
             Action::Cancel => "Cancel",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2155,8 +2155,8 @@ pub enum OrdersCanceledOrderStatus {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersCanceledOrderStatus {
@@ -2166,7 +2166,7 @@ impl std::fmt::Display for OrdersCanceledOrderStatus {
             OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
             OrdersCanceledOrderStatus::Success => "Success",
             OrdersCanceledOrderStatus::Noop => "",
-            OrdersCanceledOrderStatus::FallthroughString => "*",
+            OrdersCanceledOrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2379,8 +2379,8 @@ pub enum ProductActiveStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductActiveStatus {
@@ -2390,7 +2390,7 @@ impl std::fmt::Display for ProductActiveStatus {
             ProductActiveStatus::Any => "Any",
             ProductActiveStatus::Inactive => "Inactive",
             ProductActiveStatus::Noop => "",
-            ProductActiveStatus::FallthroughString => "*",
+            ProductActiveStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2417,8 +2417,8 @@ pub enum ProductBundleStatus {
     NotBundle,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductBundleStatus {
@@ -2428,7 +2428,7 @@ impl std::fmt::Display for ProductBundleStatus {
             ProductBundleStatus::Bundle => "Bundle",
             ProductBundleStatus::NotBundle => "NotBundle",
             ProductBundleStatus::Noop => "",
-            ProductBundleStatus::FallthroughString => "*",
+            ProductBundleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2938,8 +2938,8 @@ pub enum ReceivingStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingStatus {
@@ -2953,7 +2953,7 @@ impl std::fmt::Display for ReceivingStatus {
             ReceivingStatus::PartiallyArrived => "PartiallyArrived",
             ReceivingStatus::Processing => "Processing",
             ReceivingStatus::Noop => "",
-            ReceivingStatus::FallthroughString => "*",
+            ReceivingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2980,8 +2980,8 @@ pub enum ReceivingPackageType {
     Pallet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackageType {
@@ -2991,7 +2991,7 @@ impl std::fmt::Display for ReceivingPackageType {
             ReceivingPackageType::Package => "Package",
             ReceivingPackageType::Pallet => "Pallet",
             ReceivingPackageType::Noop => "",
-            ReceivingPackageType::FallthroughString => "*",
+            ReceivingPackageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3018,8 +3018,8 @@ pub enum ReceivingPackingType {
     OneSkuPerBox,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackingType {
@@ -3029,7 +3029,7 @@ impl std::fmt::Display for ReceivingPackingType {
             ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
             ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
             ReceivingPackingType::Noop => "",
-            ReceivingPackingType::FallthroughString => "*",
+            ReceivingPackingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3062,8 +3062,8 @@ pub enum ReceivingBoxStatus {
     Stowing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingBoxStatus {
@@ -3076,7 +3076,7 @@ impl std::fmt::Display for ReceivingBoxStatus {
             ReceivingBoxStatus::Counting => "Counting",
             ReceivingBoxStatus::Stowing => "Stowing",
             ReceivingBoxStatus::Noop => "",
-            ReceivingBoxStatus::FallthroughString => "*",
+            ReceivingBoxStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3471,8 +3471,8 @@ pub enum ReturnStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -3484,7 +3484,7 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::Completed => "Completed",
             ReturnStatus::Processing => "Processing",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3511,8 +3511,8 @@ pub enum ReturnsTransactionLogSource {
     ReturnToSenderFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnsTransactionLogSource {
@@ -3522,7 +3522,7 @@ impl std::fmt::Display for ReturnsTransactionLogSource {
             ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
             ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
             ReturnsTransactionLogSource::Noop => "",
-            ReturnsTransactionLogSource::FallthroughString => "*",
+            ReturnsTransactionLogSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3589,8 +3589,8 @@ pub enum ReturnAction {
     Restock,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnAction {
@@ -3601,7 +3601,7 @@ impl std::fmt::Display for ReturnAction {
             ReturnAction::Quarantine => "Quarantine",
             ReturnAction::Restock => "Restock",
             ReturnAction::Noop => "",
-            ReturnAction::FallthroughString => "*",
+            ReturnAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3626,8 +3626,8 @@ pub enum ReturnActionSource {
     Override,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnActionSource {
@@ -3636,7 +3636,7 @@ impl std::fmt::Display for ReturnActionSource {
             ReturnActionSource::InventoryDefault => "InventoryDefault",
             ReturnActionSource::Override => "Override",
             ReturnActionSource::Noop => "",
-            ReturnActionSource::FallthroughString => "*",
+            ReturnActionSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3744,8 +3744,8 @@ pub enum ReturnType {
     ReturnToSender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnType {
@@ -3754,7 +3754,7 @@ impl std::fmt::Display for ReturnType {
             ReturnType::Regular => "Regular",
             ReturnType::ReturnToSender => "ReturnToSender",
             ReturnType::Noop => "",
-            ReturnType::FallthroughString => "*",
+            ReturnType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3941,8 +3941,8 @@ pub enum SortOrder {
     Oldest,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -3951,7 +3951,7 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Newest => "Newest",
             SortOrder::Oldest => "Oldest",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3995,8 +3995,8 @@ pub enum WebhooksTopics {
     ShipmentOnhold,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebhooksTopics {
@@ -4007,7 +4007,7 @@ impl std::fmt::Display for WebhooksTopics {
             WebhooksTopics::ShipmentException => "shipment_exception",
             WebhooksTopics::ShipmentOnhold => "shipment_onhold",
             WebhooksTopics::Noop => "",
-            WebhooksTopics::FallthroughString => "*",
+            WebhooksTopics::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4139,8 +4139,8 @@ pub enum IntegrationsLocationServiceTypeEnum {
     Returns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
@@ -4149,7 +4149,7 @@ impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
             IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
             IntegrationsLocationServiceTypeEnum::Returns => "Returns",
             IntegrationsLocationServiceTypeEnum::Noop => "",
-            IntegrationsLocationServiceTypeEnum::FallthroughString => "*",
+            IntegrationsLocationServiceTypeEnum::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
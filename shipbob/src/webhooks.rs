@@ -31,18 +31,17 @@ impl Webhooks {
         page: u64,
         limit: i64,
     ) -> Result<Vec<crate::types::Webhook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if !page.to_string().is_empty() {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
         if !topic.to_string().is_empty() {
-            query_args.push(("Topic".to_string(), topic.to_string()));
+            query_args.push(("Topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/webhook?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/webhook"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -60,12 +59,11 @@ impl Webhooks {
         &self,
         topic: crate::types::WebhooksTopics,
     ) -> Result<Vec<crate::types::Webhook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !topic.to_string().is_empty() {
-            query_args.push(("Topic".to_string(), topic.to_string()));
+            query_args.push(("Topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/webhook?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/webhook"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -99,10 +97,7 @@ impl Webhooks {
     * * `id: i64` -- Unique id of the channel.
     */
     pub async fn delete(&self, id: i64) -> Result<()> {
-        let url = format!(
-            "/webhook/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/webhook/{}", crate::progenitor_support::encode_path(&id),);
 
         self.client.delete(&url, None).await
     }
@@ -66,8 +66,9 @@ impl Access {
     */
     pub async fn deprecated_202001_delete_storefront_tokens_param_token(
         &self,
-        storefront_access_token_id: &str,
+        storefront_access_token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let storefront_access_token_id = storefront_access_token_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/storefront_access_tokens/{}/json",
             crate::progenitor_support::encode_path(storefront_access_token_id),
@@ -118,8 +119,9 @@ impl Access {
     */
     pub async fn deprecated_202004_delete_storefront_tokens_param_token(
         &self,
-        storefront_access_token_id: &str,
+        storefront_access_token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let storefront_access_token_id = storefront_access_token_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/storefront_access_tokens/{}/json",
             crate::progenitor_support::encode_path(storefront_access_token_id),
@@ -170,8 +172,9 @@ impl Access {
     */
     pub async fn deprecated_202007_delete_storefront_tokens_param_token(
         &self,
-        storefront_access_token_id: &str,
+        storefront_access_token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let storefront_access_token_id = storefront_access_token_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/storefront_access_tokens/{}/json",
             crate::progenitor_support::encode_path(storefront_access_token_id),
@@ -219,8 +222,9 @@ impl Access {
     */
     pub async fn delete_storefront_tokens_param_token(
         &self,
-        storefront_access_token_id: &str,
+        storefront_access_token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let storefront_access_token_id = storefront_access_token_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/storefront_access_tokens/{}/json",
             crate::progenitor_support::encode_path(storefront_access_token_id),
@@ -271,8 +275,9 @@ impl Access {
     */
     pub async fn deprecated_202101_delete_storefront_tokens_param_token(
         &self,
-        storefront_access_token_id: &str,
+        storefront_access_token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let storefront_access_token_id = storefront_access_token_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/storefront_access_tokens/{}/json",
             crate::progenitor_support::encode_path(storefront_access_token_id),
@@ -323,8 +328,9 @@ impl Access {
     */
     pub async fn deprecated_unstable_delete_storefront_tokens_param_token(
         &self,
-        storefront_access_token_id: &str,
+        storefront_access_token_id: impl AsRef<str>,
     ) -> Result<()> {
+        let storefront_access_token_id = storefront_access_token_id.as_ref();
         let url = format!(
             "/admin/api/unstable/storefront_access_tokens/{}/json",
             crate::progenitor_support::encode_path(storefront_access_token_id),
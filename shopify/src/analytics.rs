@@ -38,27 +38,29 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/reports.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -81,15 +83,17 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/reports.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -110,18 +114,20 @@ impl Analytics {
     */
     pub async fn deprecated_202001_get_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(report_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/reports/{}/json",
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -140,9 +146,10 @@ impl Analytics {
     */
     pub async fn deprecated_202001_update_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -166,8 +173,9 @@ impl Analytics {
     */
     pub async fn deprecated_202001_delete_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -202,27 +210,29 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/reports.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -245,15 +255,17 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/reports.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -274,18 +286,20 @@ impl Analytics {
     */
     pub async fn deprecated_202004_get_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(report_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/reports/{}/json",
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -304,9 +318,10 @@ impl Analytics {
     */
     pub async fn deprecated_202004_update_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -330,8 +345,9 @@ impl Analytics {
     */
     pub async fn deprecated_202004_delete_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -366,27 +382,29 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/reports.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -409,15 +427,17 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/reports.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -438,18 +458,20 @@ impl Analytics {
     */
     pub async fn deprecated_202007_get_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(report_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/reports/{}/json",
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -468,9 +490,10 @@ impl Analytics {
     */
     pub async fn deprecated_202007_update_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -494,8 +517,9 @@ impl Analytics {
     */
     pub async fn deprecated_202007_delete_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -530,27 +554,29 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/reports.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -573,15 +599,17 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/reports.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -600,16 +628,22 @@ impl Analytics {
     * * `report_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
-    pub async fn get_reports_param_report(&self, report_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_reports_param_report(
+        &self,
+        report_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(report_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/reports/{}/json",
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -628,9 +662,10 @@ impl Analytics {
     */
     pub async fn update_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -652,7 +687,8 @@ impl Analytics {
     *
     * * `report_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+    pub async fn delete_reports_param_report(&self, report_id: impl AsRef<str>) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -687,27 +723,29 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/reports.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -730,15 +768,17 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/reports.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -759,18 +799,20 @@ impl Analytics {
     */
     pub async fn deprecated_202101_get_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(report_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/reports/{}/json",
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -789,9 +831,10 @@ impl Analytics {
     */
     pub async fn deprecated_202101_update_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -815,8 +858,9 @@ impl Analytics {
     */
     pub async fn deprecated_202101_delete_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -851,27 +895,29 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/reports.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -894,15 +940,17 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/reports.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/reports.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -923,18 +971,20 @@ impl Analytics {
     */
     pub async fn deprecated_unstable_get_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let report_id = report_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(report_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/reports/{}/json",
+                crate::progenitor_support::encode_path(report_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -953,9 +1003,10 @@ impl Analytics {
     */
     pub async fn deprecated_unstable_update_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/unstable/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -979,8 +1030,9 @@ impl Analytics {
     */
     pub async fn deprecated_unstable_delete_reports_param_report(
         &self,
-        report_id: &str,
+        report_id: impl AsRef<str>,
     ) -> Result<()> {
+        let report_id = report_id.as_ref();
         let url = format!(
             "/admin/api/unstable/reports/{}/json",
             crate::progenitor_support::encode_path(report_id),
@@ -29,15 +29,17 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/application_charges.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/application_charges.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -73,18 +75,20 @@ impl Billing {
     */
     pub async fn deprecated_202001_get_application_charges_param_charge(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_charge_id = application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/application_charges/{}/json",
+                crate::progenitor_support::encode_path(application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -108,9 +112,10 @@ impl Billing {
     */
     pub async fn deprecated_202001_create_application_charges_param_charge_activate(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let application_charge_id = application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(application_charge_id),
@@ -138,15 +143,17 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/application_charges.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/application_charges.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -182,18 +189,20 @@ impl Billing {
     */
     pub async fn deprecated_202004_get_application_charges_param_charge(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_charge_id = application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/application_charges/{}/json",
+                crate::progenitor_support::encode_path(application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -217,9 +226,10 @@ impl Billing {
     */
     pub async fn deprecated_202004_create_application_charges_param_charge_activate(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let application_charge_id = application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(application_charge_id),
@@ -247,15 +257,17 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/application_charges.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/application_charges.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -291,18 +303,20 @@ impl Billing {
     */
     pub async fn deprecated_202007_get_application_charges_param_charge(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_charge_id = application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/application_charges/{}/json",
+                crate::progenitor_support::encode_path(application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -326,9 +340,10 @@ impl Billing {
     */
     pub async fn deprecated_202007_create_application_charges_param_charge_activate(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let application_charge_id = application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(application_charge_id),
@@ -352,15 +367,17 @@ impl Billing {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn get_application_charge(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/application_charges.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/application_charges.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -393,18 +410,20 @@ impl Billing {
     */
     pub async fn get_application_charges_param_charge(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_charge_id = application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/application_charges/{}/json",
+                crate::progenitor_support::encode_path(application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -428,9 +447,10 @@ impl Billing {
     */
     pub async fn create_application_charges_param_charge_activate(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let application_charge_id = application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(application_charge_id),
@@ -458,15 +478,17 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/application_charges.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/application_charges.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -502,18 +524,20 @@ impl Billing {
     */
     pub async fn deprecated_202101_get_application_charges_param_charge(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_charge_id = application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/application_charges/{}/json",
+                crate::progenitor_support::encode_path(application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -536,15 +560,17 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/application_charges.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/application_charges.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -580,18 +606,20 @@ impl Billing {
     */
     pub async fn deprecated_unstable_get_application_charges_param_charge(
         &self,
-        application_charge_id: &str,
+        application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_charge_id = application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/application_charges/{}/json",
+                crate::progenitor_support::encode_path(application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -609,12 +637,14 @@ impl Billing {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202001_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/application_credits.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/application_credits.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -650,18 +680,20 @@ impl Billing {
     */
     pub async fn deprecated_202001_get_application_credits_param_credit(
         &self,
-        application_credit_id: &str,
+        application_credit_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_credit_id = application_credit_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(application_credit_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/application_credits/{}/json",
+                crate::progenitor_support::encode_path(application_credit_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -679,12 +711,14 @@ impl Billing {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202004_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/application_credits.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/application_credits.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -720,18 +754,20 @@ impl Billing {
     */
     pub async fn deprecated_202004_get_application_credits_param_credit(
         &self,
-        application_credit_id: &str,
+        application_credit_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_credit_id = application_credit_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(application_credit_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/application_credits/{}/json",
+                crate::progenitor_support::encode_path(application_credit_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -749,12 +785,14 @@ impl Billing {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202007_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/application_credits.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/application_credits.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -790,18 +828,20 @@ impl Billing {
     */
     pub async fn deprecated_202007_get_application_credits_param_credit(
         &self,
-        application_credit_id: &str,
+        application_credit_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_credit_id = application_credit_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(application_credit_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/application_credits/{}/json",
+                crate::progenitor_support::encode_path(application_credit_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -819,12 +859,14 @@ impl Billing {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/application_credits.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/application_credits.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -857,18 +899,20 @@ impl Billing {
     */
     pub async fn get_application_credits_param_credit(
         &self,
-        application_credit_id: &str,
+        application_credit_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_credit_id = application_credit_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(application_credit_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/application_credits/{}/json",
+                crate::progenitor_support::encode_path(application_credit_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -886,12 +930,14 @@ impl Billing {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202101_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/application_credits.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/application_credits.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -927,18 +973,20 @@ impl Billing {
     */
     pub async fn deprecated_202101_get_application_credits_param_credit(
         &self,
-        application_credit_id: &str,
+        application_credit_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_credit_id = application_credit_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(application_credit_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/application_credits/{}/json",
+                crate::progenitor_support::encode_path(application_credit_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -956,12 +1004,14 @@ impl Billing {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_unstable_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/application_credits.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/application_credits.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -997,18 +1047,20 @@ impl Billing {
     */
     pub async fn deprecated_unstable_get_application_credits_param_credit(
         &self,
-        application_credit_id: &str,
+        application_credit_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let application_credit_id = application_credit_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(application_credit_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/application_credits/{}/json",
+                crate::progenitor_support::encode_path(application_credit_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1031,17 +1083,16 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/recurring_application_charges.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1078,18 +1129,20 @@ impl Billing {
     */
     pub async fn deprecated_202001_get_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/recurring_application_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1108,8 +1161,9 @@ impl Billing {
     */
     pub async fn deprecated_202001_delete_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/recurring_application_charges/{}/json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1136,9 +1190,10 @@ impl Billing {
     */
     pub async fn deprecated_202001_create_recurring_application_charges_param_charge_activate(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/recurring_application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1163,21 +1218,23 @@ impl Billing {
     */
     pub async fn deprecated_202001_update_recurring_application_charges_param_charge_customize(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/recurring_application_charges/{}/customize.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -1200,17 +1257,16 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/recurring_application_charges.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1247,18 +1303,20 @@ impl Billing {
     */
     pub async fn deprecated_202004_get_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/recurring_application_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1277,8 +1335,9 @@ impl Billing {
     */
     pub async fn deprecated_202004_delete_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/recurring_application_charges/{}/json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1305,9 +1364,10 @@ impl Billing {
     */
     pub async fn deprecated_202004_create_recurring_application_charges_param_charge_activate(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/recurring_application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1332,21 +1392,23 @@ impl Billing {
     */
     pub async fn deprecated_202004_update_recurring_application_charges_param_charge_customize(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/recurring_application_charges/{}/customize.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -1369,17 +1431,16 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/recurring_application_charges.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1416,18 +1477,20 @@ impl Billing {
     */
     pub async fn deprecated_202007_get_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/recurring_application_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1446,8 +1509,9 @@ impl Billing {
     */
     pub async fn deprecated_202007_delete_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/recurring_application_charges/{}/json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1474,9 +1538,10 @@ impl Billing {
     */
     pub async fn deprecated_202007_create_recurring_application_charges_param_charge_activate(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/recurring_application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1501,21 +1566,23 @@ impl Billing {
     */
     pub async fn deprecated_202007_update_recurring_application_charges_param_charge_customize(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/recurring_application_charges/{}/customize.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -1538,17 +1605,16 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/recurring_application_charges.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1585,18 +1651,20 @@ impl Billing {
     */
     pub async fn get_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/recurring_application_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1615,8 +1683,9 @@ impl Billing {
     */
     pub async fn delete_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/recurring_application_charges/{}/json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1643,9 +1712,10 @@ impl Billing {
     */
     pub async fn create_recurring_application_charges_param_charge_activate(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/recurring_application_charges/{}/activate.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1670,21 +1740,23 @@ impl Billing {
     */
     pub async fn update_recurring_application_charges_param_charge_customize(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/recurring_application_charges/{}/customize.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -1707,17 +1779,16 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/recurring_application_charges.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1754,18 +1825,20 @@ impl Billing {
     */
     pub async fn deprecated_202101_get_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/recurring_application_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1784,8 +1857,9 @@ impl Billing {
     */
     pub async fn deprecated_202101_delete_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/recurring_application_charges/{}/json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1808,21 +1882,23 @@ impl Billing {
     */
     pub async fn deprecated_202101_update_recurring_application_charges_param_charge_customize(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/recurring_application_charges/{}/customize.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -1845,17 +1921,16 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/recurring_application_charges.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1892,18 +1967,20 @@ impl Billing {
     */
     pub async fn deprecated_unstable_get_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/recurring_application_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1922,8 +1999,9 @@ impl Billing {
     */
     pub async fn deprecated_unstable_delete_recurring_application_charges_param_charge(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/unstable/recurring_application_charges/{}/json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -1946,21 +2024,23 @@ impl Billing {
     */
     pub async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/recurring_application_charges/{}/customize.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -1980,18 +2060,20 @@ impl Billing {
     */
     pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2010,9 +2092,10 @@ impl Billing {
     */
     pub async fn deprecated_202001_create_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -2038,20 +2121,23 @@ impl Billing {
     */
     pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
+        usage_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let usage_charge_id = usage_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            crate::progenitor_support::encode_path(usage_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2071,18 +2157,20 @@ impl Billing {
     */
     pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2101,9 +2189,10 @@ impl Billing {
     */
     pub async fn deprecated_202004_create_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -2129,20 +2218,23 @@ impl Billing {
     */
     pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
+        usage_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let usage_charge_id = usage_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            crate::progenitor_support::encode_path(usage_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2162,18 +2254,20 @@ impl Billing {
     */
     pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2192,9 +2286,10 @@ impl Billing {
     */
     pub async fn deprecated_202007_create_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -2220,20 +2315,23 @@ impl Billing {
     */
     pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
+        usage_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let usage_charge_id = usage_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            crate::progenitor_support::encode_path(usage_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2253,18 +2351,20 @@ impl Billing {
     */
     pub async fn get_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2283,9 +2383,10 @@ impl Billing {
     */
     pub async fn create_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -2311,20 +2412,23 @@ impl Billing {
     */
     pub async fn get_recurring_application_charges_param_charge_usage_billing(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
+        usage_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let usage_charge_id = usage_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            crate::progenitor_support::encode_path(usage_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2344,18 +2448,20 @@ impl Billing {
     */
     pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2374,9 +2480,10 @@ impl Billing {
     */
     pub async fn deprecated_202101_create_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -2402,20 +2509,23 @@ impl Billing {
     */
     pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
+        usage_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let usage_charge_id = usage_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            crate::progenitor_support::encode_path(usage_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2435,18 +2545,20 @@ impl Billing {
     */
     pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2465,9 +2577,10 @@ impl Billing {
     */
     pub async fn deprecated_unstable_create_recurring_application_charges_param_charge_usage(
         &self,
-        recurring_application_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
         let url = format!(
             "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json",
             crate::progenitor_support::encode_path(recurring_application_charge_id),
@@ -2493,20 +2606,23 @@ impl Billing {
     */
     pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
+        recurring_application_charge_id: impl AsRef<str>,
+        usage_charge_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let recurring_application_charge_id = recurring_application_charge_id.as_ref();
+        let usage_charge_id = usage_charge_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(recurring_application_charge_id),
-            crate::progenitor_support::encode_path(usage_charge_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/recurring_application_charges/{}/usage_charges/{}/json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
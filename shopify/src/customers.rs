@@ -42,33 +42,35 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/customers.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/customers.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -110,21 +112,23 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/customers/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/customers/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -141,16 +145,22 @@ impl Customers {
     * * `customer_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202001_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202001_get_param(
+        &self,
+        customer_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/customers/{}/json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -169,9 +179,10 @@ impl Customers {
     */
     pub async fn deprecated_202001_update_param(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -193,7 +204,8 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_delete_param(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_delete_param(&self, customer_id: impl AsRef<str>) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -216,9 +228,10 @@ impl Customers {
     */
     pub async fn deprecated_202001_create_param_account_activation_url(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/account_activation_url.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -242,9 +255,10 @@ impl Customers {
     */
     pub async fn deprecated_202001_create_param_send_invite(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/send_invite.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -278,7 +292,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_get_param_order(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_param_order(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/orders.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -317,33 +335,35 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/customers.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/customers.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -385,21 +405,23 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/customers/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/customers/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -416,16 +438,22 @@ impl Customers {
     * * `customer_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202004_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202004_get_param(
+        &self,
+        customer_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/customers/{}/json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -444,9 +472,10 @@ impl Customers {
     */
     pub async fn deprecated_202004_update_param(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -468,7 +497,8 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_delete_param(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_delete_param(&self, customer_id: impl AsRef<str>) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -491,9 +521,10 @@ impl Customers {
     */
     pub async fn deprecated_202004_create_param_account_activation_url(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/account_activation_url.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -517,9 +548,10 @@ impl Customers {
     */
     pub async fn deprecated_202004_create_param_send_invite(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/send_invite.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -553,7 +585,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_get_param_order(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_param_order(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/orders.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -592,33 +628,35 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/customers.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/customers.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -660,21 +698,23 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/customers/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/customers/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -691,16 +731,22 @@ impl Customers {
     * * `customer_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202007_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202007_get_param(
+        &self,
+        customer_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/customers/{}/json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -719,9 +765,10 @@ impl Customers {
     */
     pub async fn deprecated_202007_update_param(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -743,7 +790,8 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_delete_param(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_delete_param(&self, customer_id: impl AsRef<str>) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -766,9 +814,10 @@ impl Customers {
     */
     pub async fn deprecated_202007_create_param_account_activation_url(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/account_activation_url.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -792,9 +841,10 @@ impl Customers {
     */
     pub async fn deprecated_202007_create_param_send_invite(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/send_invite.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -828,7 +878,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_get_param_order(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_param_order(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/orders.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -867,33 +921,35 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/customers.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/customers.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -935,21 +991,23 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/customers/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/customers/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -966,16 +1024,18 @@ impl Customers {
     * * `customer_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_param(&self, customer_id: impl AsRef<str>, fields: &str) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/customers/{}/json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -992,7 +1052,12 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn update_param(&self, customer_id: &str, body: &serde_json::Value) -> Result<()> {
+    pub async fn update_param(
+        &self,
+        customer_id: impl AsRef<str>,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1014,7 +1079,8 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_param(&self, customer_id: &str) -> Result<()> {
+    pub async fn delete_param(&self, customer_id: impl AsRef<str>) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1037,9 +1103,10 @@ impl Customers {
     */
     pub async fn create_param_account_activation_url(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/account_activation_url.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1063,9 +1130,10 @@ impl Customers {
     */
     pub async fn create_param_send_invite(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/send_invite.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1099,7 +1167,8 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_param_order(&self, customer_id: &str) -> Result<()> {
+    pub async fn get_param_order(&self, customer_id: impl AsRef<str>) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/orders.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1138,33 +1207,35 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/customers.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/customers.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1206,21 +1277,23 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/customers/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/customers/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1237,16 +1310,22 @@ impl Customers {
     * * `customer_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202101_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202101_get_param(
+        &self,
+        customer_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/customers/{}/json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1265,9 +1344,10 @@ impl Customers {
     */
     pub async fn deprecated_202101_update_param(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1289,7 +1369,8 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_delete_param(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_delete_param(&self, customer_id: impl AsRef<str>) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1312,9 +1393,10 @@ impl Customers {
     */
     pub async fn deprecated_202101_create_param_account_activation_url(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/account_activation_url.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1338,9 +1420,10 @@ impl Customers {
     */
     pub async fn deprecated_202101_create_param_send_invite(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/send_invite.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1374,7 +1457,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_get_param_order(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_param_order(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/orders.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1413,33 +1500,35 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/customers.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/customers.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1481,21 +1570,23 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/customers/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/customers/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1514,18 +1605,20 @@ impl Customers {
     */
     pub async fn deprecated_unstable_get_param(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/customers/{}/json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1544,9 +1637,10 @@ impl Customers {
     */
     pub async fn deprecated_unstable_update_param(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1568,7 +1662,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_delete_param(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_delete_param(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1591,9 +1689,10 @@ impl Customers {
     */
     pub async fn deprecated_unstable_create_param_account_activation_url(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/account_activation_url.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1617,9 +1716,10 @@ impl Customers {
     */
     pub async fn deprecated_unstable_create_param_send_invite(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/send_invite.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1653,7 +1753,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_get_param_order(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_param_order(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/orders.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1673,7 +1777,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_param_addresse(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1695,9 +1803,10 @@ impl Customers {
     */
     pub async fn deprecated_202001_create_param_addresses(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1722,9 +1831,11 @@ impl Customers {
     */
     pub async fn deprecated_202001_get_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1748,10 +1859,12 @@ impl Customers {
     */
     pub async fn deprecated_202001_update_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1777,9 +1890,11 @@ impl Customers {
     */
     pub async fn deprecated_202001_delete_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1804,22 +1919,24 @@ impl Customers {
     */
     pub async fn deprecated_202001_update_param_addresses_set(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/customers/{}/addresses/set.json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -1839,9 +1956,11 @@ impl Customers {
     */
     pub async fn deprecated_202001_update_param_addresses_address_default(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/default.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1862,7 +1981,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_param_addresse(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1884,9 +2007,10 @@ impl Customers {
     */
     pub async fn deprecated_202004_create_param_addresses(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1911,9 +2035,11 @@ impl Customers {
     */
     pub async fn deprecated_202004_get_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1937,10 +2063,12 @@ impl Customers {
     */
     pub async fn deprecated_202004_update_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1966,9 +2094,11 @@ impl Customers {
     */
     pub async fn deprecated_202004_delete_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -1993,22 +2123,24 @@ impl Customers {
     */
     pub async fn deprecated_202004_update_param_addresses_set(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/customers/{}/addresses/set.json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -2028,9 +2160,11 @@ impl Customers {
     */
     pub async fn deprecated_202004_update_param_addresses_address_default(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/default.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2051,7 +2185,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_param_addresse(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2073,9 +2211,10 @@ impl Customers {
     */
     pub async fn deprecated_202007_create_param_addresses(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2100,9 +2239,11 @@ impl Customers {
     */
     pub async fn deprecated_202007_get_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2126,10 +2267,12 @@ impl Customers {
     */
     pub async fn deprecated_202007_update_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2155,9 +2298,11 @@ impl Customers {
     */
     pub async fn deprecated_202007_delete_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2182,22 +2327,24 @@ impl Customers {
     */
     pub async fn deprecated_202007_update_param_addresses_set(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/customers/{}/addresses/set.json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -2217,9 +2364,11 @@ impl Customers {
     */
     pub async fn deprecated_202007_update_param_addresses_address_default(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/default.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2240,7 +2389,8 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_param_addresse(&self, customer_id: &str) -> Result<()> {
+    pub async fn get_param_addresse(&self, customer_id: impl AsRef<str>) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2262,9 +2412,10 @@ impl Customers {
     */
     pub async fn create_param_addresses(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2289,9 +2440,11 @@ impl Customers {
     */
     pub async fn get_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2315,10 +2468,12 @@ impl Customers {
     */
     pub async fn update_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2344,9 +2499,11 @@ impl Customers {
     */
     pub async fn delete_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2371,22 +2528,24 @@ impl Customers {
     */
     pub async fn update_param_addresses_set(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/customers/{}/addresses/set.json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -2406,9 +2565,11 @@ impl Customers {
     */
     pub async fn update_param_addresses_address_default(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/default.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2429,7 +2590,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_param_addresse(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2451,9 +2616,10 @@ impl Customers {
     */
     pub async fn deprecated_202101_create_param_addresses(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2478,9 +2644,11 @@ impl Customers {
     */
     pub async fn deprecated_202101_get_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2504,10 +2672,12 @@ impl Customers {
     */
     pub async fn deprecated_202101_update_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2533,9 +2703,11 @@ impl Customers {
     */
     pub async fn deprecated_202101_delete_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2560,22 +2732,24 @@ impl Customers {
     */
     pub async fn deprecated_202101_update_param_addresses_set(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/customers/{}/addresses/set.json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -2595,9 +2769,11 @@ impl Customers {
     */
     pub async fn deprecated_202101_update_param_addresses_address_default(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/default.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2618,7 +2794,11 @@ impl Customers {
     *
     * * `customer_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_param_addresse(
+        &self,
+        customer_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2640,9 +2820,10 @@ impl Customers {
     */
     pub async fn deprecated_unstable_create_param_addresses(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2667,9 +2848,11 @@ impl Customers {
     */
     pub async fn deprecated_unstable_get_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2693,10 +2876,12 @@ impl Customers {
     */
     pub async fn deprecated_unstable_update_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2722,9 +2907,11 @@ impl Customers {
     */
     pub async fn deprecated_unstable_delete_param_addresses_address(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2749,22 +2936,24 @@ impl Customers {
     */
     pub async fn deprecated_unstable_update_param_addresses_set(
         &self,
-        customer_id: &str,
+        customer_id: impl AsRef<str>,
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_id = customer_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(customer_id),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/customers/{}/addresses/set.json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            &query_args,
         );
 
         self.client.put(&url, None).await
@@ -2784,9 +2973,11 @@ impl Customers {
     */
     pub async fn deprecated_unstable_update_param_addresses_address_default(
         &self,
-        customer_id: &str,
-        address_id: &str,
+        customer_id: impl AsRef<str>,
+        address_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_id = customer_id.as_ref();
+        let address_id = address_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/default.json",
             crate::progenitor_support::encode_path(customer_id),
@@ -2816,18 +3007,20 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/customer_saved_searches.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/customer_saved_searches.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2861,14 +3054,13 @@ impl Customers {
     * * `since_id: &str` -- Restrict results to after the specified ID.
     */
     pub async fn deprecated_202001_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/customer_saved_searches/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2888,18 +3080,20 @@ impl Customers {
     */
     pub async fn deprecated_202001_get_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/customer_saved_searches/{}/json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2918,9 +3112,10 @@ impl Customers {
     */
     pub async fn deprecated_202001_update_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -2944,8 +3139,9 @@ impl Customers {
     */
     pub async fn deprecated_202001_delete_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -2972,26 +3168,28 @@ impl Customers {
     */
     pub async fn deprecated_202001_get_saved_searches_param_search_customers(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         order: &str,
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/customer_saved_searches/{}/customers.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3017,18 +3215,20 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/customer_saved_searches.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/customer_saved_searches.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3062,14 +3262,13 @@ impl Customers {
     * * `since_id: &str` -- Restrict results to after the specified ID.
     */
     pub async fn deprecated_202004_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/customer_saved_searches/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3089,18 +3288,20 @@ impl Customers {
     */
     pub async fn deprecated_202004_get_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/customer_saved_searches/{}/json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3119,9 +3320,10 @@ impl Customers {
     */
     pub async fn deprecated_202004_update_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3145,8 +3347,9 @@ impl Customers {
     */
     pub async fn deprecated_202004_delete_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3173,26 +3376,28 @@ impl Customers {
     */
     pub async fn deprecated_202004_get_saved_searches_param_search_customers(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         order: &str,
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/customer_saved_searches/{}/customers.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3218,18 +3423,20 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/customer_saved_searches.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/customer_saved_searches.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3263,14 +3470,13 @@ impl Customers {
     * * `since_id: &str` -- Restrict results to after the specified ID.
     */
     pub async fn deprecated_202007_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/customer_saved_searches/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3290,18 +3496,20 @@ impl Customers {
     */
     pub async fn deprecated_202007_get_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/customer_saved_searches/{}/json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3320,9 +3528,10 @@ impl Customers {
     */
     pub async fn deprecated_202007_update_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3346,8 +3555,9 @@ impl Customers {
     */
     pub async fn deprecated_202007_delete_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3374,26 +3584,28 @@ impl Customers {
     */
     pub async fn deprecated_202007_get_saved_searches_param_search_customers(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         order: &str,
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/customer_saved_searches/{}/customers.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3414,18 +3626,20 @@ impl Customers {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn get_saved_searche(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/customer_saved_searches.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/customer_saved_searches.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3456,14 +3670,13 @@ impl Customers {
     * * `since_id: &str` -- Restrict results to after the specified ID.
     */
     pub async fn get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/customer_saved_searches/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3483,18 +3696,20 @@ impl Customers {
     */
     pub async fn get_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/customer_saved_searches/{}/json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3513,9 +3728,10 @@ impl Customers {
     */
     pub async fn update_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3539,8 +3755,9 @@ impl Customers {
     */
     pub async fn delete_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3567,26 +3784,28 @@ impl Customers {
     */
     pub async fn get_saved_searches_param_search_customers(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         order: &str,
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/customer_saved_searches/{}/customers.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3612,18 +3831,20 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/customer_saved_searches.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/customer_saved_searches.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3657,14 +3878,13 @@ impl Customers {
     * * `since_id: &str` -- Restrict results to after the specified ID.
     */
     pub async fn deprecated_202101_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/customer_saved_searches/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3684,18 +3904,20 @@ impl Customers {
     */
     pub async fn deprecated_202101_get_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/customer_saved_searches/{}/json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3714,9 +3936,10 @@ impl Customers {
     */
     pub async fn deprecated_202101_update_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3740,8 +3963,9 @@ impl Customers {
     */
     pub async fn deprecated_202101_delete_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3768,26 +3992,28 @@ impl Customers {
     */
     pub async fn deprecated_202101_get_saved_searches_param_search_customers(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         order: &str,
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/customer_saved_searches/{}/customers.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3813,20 +4039,19 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/customer_saved_searches.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3861,14 +4086,13 @@ impl Customers {
     * * `since_id: &str` -- Restrict results to after the specified ID.
     */
     pub async fn deprecated_unstable_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/customer_saved_searches/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3888,18 +4112,20 @@ impl Customers {
     */
     pub async fn deprecated_unstable_get_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/customer_saved_searches/{}/json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3918,9 +4144,10 @@ impl Customers {
     */
     pub async fn deprecated_unstable_update_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3944,8 +4171,9 @@ impl Customers {
     */
     pub async fn deprecated_unstable_delete_saved_searches_param_search(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
     ) -> Result<()> {
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
         let url = format!(
             "/admin/api/unstable/customer_saved_searches/{}/json",
             crate::progenitor_support::encode_path(customer_saved_search_id),
@@ -3972,26 +4200,28 @@ impl Customers {
     */
     pub async fn deprecated_unstable_get_saved_searches_param_search_customers(
         &self,
-        customer_saved_search_id: &str,
+        customer_saved_search_id: impl AsRef<str>,
         order: &str,
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer_saved_search_id = customer_saved_search_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(customer_saved_search_id),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/customer_saved_searches/{}/customers.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -25,8 +25,9 @@ impl Discounts {
     */
     pub async fn deprecated_202001_get_price_rules_param_rule_code(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -48,9 +49,10 @@ impl Discounts {
     */
     pub async fn deprecated_202001_create_price_rules_param_rule_codes(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -75,9 +77,11 @@ impl Discounts {
     */
     pub async fn deprecated_202001_get_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -101,10 +105,12 @@ impl Discounts {
     */
     pub async fn deprecated_202001_update_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -130,9 +136,11 @@ impl Discounts {
     */
     pub async fn deprecated_202001_delete_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -156,12 +164,14 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202001_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/discount_codes/lookup.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/discount_codes/lookup.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -196,9 +206,10 @@ impl Discounts {
     */
     pub async fn deprecated_202001_create_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/batch.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -223,9 +234,11 @@ impl Discounts {
     */
     pub async fn deprecated_202001_get_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/batch/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -251,9 +264,11 @@ impl Discounts {
     */
     pub async fn deprecated_202001_get_price_rules_param_rule_batch_code(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/batch/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -276,8 +291,9 @@ impl Discounts {
     */
     pub async fn deprecated_202004_get_price_rules_param_rule_code(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -299,9 +315,10 @@ impl Discounts {
     */
     pub async fn deprecated_202004_create_price_rules_param_rule_codes(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -326,9 +343,11 @@ impl Discounts {
     */
     pub async fn deprecated_202004_get_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -352,10 +371,12 @@ impl Discounts {
     */
     pub async fn deprecated_202004_update_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -381,9 +402,11 @@ impl Discounts {
     */
     pub async fn deprecated_202004_delete_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -407,12 +430,14 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202004_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/discount_codes/lookup.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/discount_codes/lookup.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -447,9 +472,10 @@ impl Discounts {
     */
     pub async fn deprecated_202004_create_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/batch.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -474,9 +500,11 @@ impl Discounts {
     */
     pub async fn deprecated_202004_get_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/batch/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -502,9 +530,11 @@ impl Discounts {
     */
     pub async fn deprecated_202004_get_price_rules_param_rule_batch_code(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/batch/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -527,8 +557,9 @@ impl Discounts {
     */
     pub async fn deprecated_202007_get_price_rules_param_rule_code(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -550,9 +581,10 @@ impl Discounts {
     */
     pub async fn deprecated_202007_create_price_rules_param_rule_codes(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -577,9 +609,11 @@ impl Discounts {
     */
     pub async fn deprecated_202007_get_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -603,10 +637,12 @@ impl Discounts {
     */
     pub async fn deprecated_202007_update_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -632,9 +668,11 @@ impl Discounts {
     */
     pub async fn deprecated_202007_delete_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -658,12 +696,14 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202007_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/discount_codes/lookup.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/discount_codes/lookup.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -698,9 +738,10 @@ impl Discounts {
     */
     pub async fn deprecated_202007_create_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/batch.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -725,9 +766,11 @@ impl Discounts {
     */
     pub async fn deprecated_202007_get_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/batch/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -753,9 +796,11 @@ impl Discounts {
     */
     pub async fn deprecated_202007_get_price_rules_param_rule_batch_code(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/batch/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -776,7 +821,11 @@ impl Discounts {
     *
     * * `price_rule_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_price_rules_param_rule_code(&self, price_rule_id: &str) -> Result<()> {
+    pub async fn get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -798,9 +847,10 @@ impl Discounts {
     */
     pub async fn create_price_rules_param_rule_codes(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -825,9 +875,11 @@ impl Discounts {
     */
     pub async fn get_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -851,10 +903,12 @@ impl Discounts {
     */
     pub async fn update_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -880,9 +934,11 @@ impl Discounts {
     */
     pub async fn delete_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -906,12 +962,14 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/discount_codes/lookup.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/discount_codes/lookup.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -946,9 +1004,10 @@ impl Discounts {
     */
     pub async fn create_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/batch.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -973,9 +1032,11 @@ impl Discounts {
     */
     pub async fn get_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/batch/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1001,9 +1062,11 @@ impl Discounts {
     */
     pub async fn get_price_rules_param_rule_batch_code(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/batch/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1026,8 +1089,9 @@ impl Discounts {
     */
     pub async fn deprecated_202101_get_price_rules_param_rule_code(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1049,9 +1113,10 @@ impl Discounts {
     */
     pub async fn deprecated_202101_create_price_rules_param_rule_codes(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1076,9 +1141,11 @@ impl Discounts {
     */
     pub async fn deprecated_202101_get_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1102,10 +1169,12 @@ impl Discounts {
     */
     pub async fn deprecated_202101_update_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1131,9 +1200,11 @@ impl Discounts {
     */
     pub async fn deprecated_202101_delete_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1157,12 +1228,14 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202101_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/discount_codes/lookup.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/discount_codes/lookup.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1197,9 +1270,10 @@ impl Discounts {
     */
     pub async fn deprecated_202101_create_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/batch.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1224,9 +1298,11 @@ impl Discounts {
     */
     pub async fn deprecated_202101_get_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/batch/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1252,9 +1328,11 @@ impl Discounts {
     */
     pub async fn deprecated_202101_get_price_rules_param_rule_batch_code(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/batch/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1277,8 +1355,9 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_get_price_rules_param_rule_code(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1300,9 +1379,10 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_create_price_rules_param_rule_codes(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1327,9 +1407,11 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_get_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1353,10 +1435,12 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_update_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1382,9 +1466,11 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_delete_price_rules_param_rule_codes_code(
         &self,
-        price_rule_id: &str,
-        discount_code_id: &str,
+        price_rule_id: impl AsRef<str>,
+        discount_code_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let discount_code_id = discount_code_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1408,12 +1494,14 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_unstable_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/discount_codes/lookup.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/discount_codes/lookup.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1448,9 +1536,10 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_create_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
+        price_rule_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/batch.json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1475,9 +1564,11 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_get_price_rules_param_rule_batch(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/batch/{}/json",
             crate::progenitor_support::encode_path(price_rule_id),
@@ -1503,9 +1594,11 @@ impl Discounts {
     */
     pub async fn deprecated_unstable_get_price_rules_param_rule_batch_code(
         &self,
-        price_rule_id: &str,
-        batch_id: &str,
+        price_rule_id: impl AsRef<str>,
+        batch_id: impl AsRef<str>,
     ) -> Result<()> {
+        let price_rule_id = price_rule_id.as_ref();
+        let batch_id = batch_id.as_ref();
         let url = format!(
             "/admin/api/unstable/price_rules/{}/batch/{}/discount_codes.json",
             crate::progenitor_support::encode_path(price_rule_id),
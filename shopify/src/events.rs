@@ -40,30 +40,32 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/events.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/events.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -80,16 +82,22 @@ impl Events {
     * * `event_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202001_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202001_get_param(
+        &self,
+        event_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/events/{}/json?{}",
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/events/{}/json",
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -112,15 +120,17 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/events/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/events/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -153,30 +163,32 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/events.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/events.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -193,16 +205,22 @@ impl Events {
     * * `event_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202004_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202004_get_param(
+        &self,
+        event_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/events/{}/json?{}",
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/events/{}/json",
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -225,15 +243,17 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/events/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/events/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -266,30 +286,32 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/events.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/events.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -306,16 +328,22 @@ impl Events {
     * * `event_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202007_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202007_get_param(
+        &self,
+        event_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/events/{}/json?{}",
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/events/{}/json",
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -338,15 +366,17 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/events/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/events/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -379,30 +409,32 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/events.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/events.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -419,16 +451,18 @@ impl Events {
     * * `event_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_param(&self, event_id: impl AsRef<str>, fields: &str) -> Result<()> {
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/events/{}/json?{}",
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/events/{}/json",
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -447,15 +481,17 @@ impl Events {
     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
     */
     pub async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/events/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/events/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -488,30 +524,32 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/events.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/events.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -528,16 +566,22 @@ impl Events {
     * * `event_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_202101_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202101_get_param(
+        &self,
+        event_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/events/{}/json?{}",
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/events/{}/json",
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -560,15 +604,17 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/events/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/events/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -601,30 +647,32 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/events.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/events.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -641,16 +689,22 @@ impl Events {
     * * `event_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn deprecated_unstable_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_unstable_get_param(
+        &self,
+        event_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let event_id = event_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/events/{}/json?{}",
-            crate::progenitor_support::encode_path(event_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/events/{}/json",
+                crate::progenitor_support::encode_path(event_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -673,15 +727,17 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/events/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/events/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -719,36 +775,38 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/webhooks.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -770,12 +828,14 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/webhooks.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -800,15 +860,17 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/webhooks/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/webhooks/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -827,18 +889,20 @@ impl Events {
     */
     pub async fn deprecated_202001_get_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webhook_id = webhook_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(webhook_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/webhooks/{}/json",
+                crate::progenitor_support::encode_path(webhook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -857,9 +921,10 @@ impl Events {
     */
     pub async fn deprecated_202001_update_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -883,8 +948,9 @@ impl Events {
     */
     pub async fn deprecated_202001_delete_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -926,36 +992,38 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/webhooks.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -977,12 +1045,14 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/webhooks.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1007,15 +1077,17 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/webhooks/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/webhooks/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1034,18 +1106,20 @@ impl Events {
     */
     pub async fn deprecated_202004_get_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webhook_id = webhook_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(webhook_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/webhooks/{}/json",
+                crate::progenitor_support::encode_path(webhook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1064,9 +1138,10 @@ impl Events {
     */
     pub async fn deprecated_202004_update_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1090,8 +1165,9 @@ impl Events {
     */
     pub async fn deprecated_202004_delete_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1133,36 +1209,38 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/webhooks.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1184,12 +1262,14 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/webhooks.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1214,15 +1294,17 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/webhooks/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/webhooks/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1241,18 +1323,20 @@ impl Events {
     */
     pub async fn deprecated_202007_get_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webhook_id = webhook_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(webhook_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/webhooks/{}/json",
+                crate::progenitor_support::encode_path(webhook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1271,9 +1355,10 @@ impl Events {
     */
     pub async fn deprecated_202007_update_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1297,8 +1382,9 @@ impl Events {
     */
     pub async fn deprecated_202007_delete_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1340,36 +1426,38 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/webhooks.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1387,12 +1475,14 @@ impl Events {
     *                     (default: json).
     */
     pub async fn create_webhooks(&self, format: &str, body: &serde_json::Value) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/webhooks.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1413,15 +1503,17 @@ impl Events {
     *   For a list of valid values, refer to the topic property.>.
     */
     pub async fn get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/webhooks/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/webhooks/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1438,16 +1530,22 @@ impl Events {
     * * `webhook_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
     */
-    pub async fn get_webhooks_param_webhook(&self, webhook_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_webhooks_param_webhook(
+        &self,
+        webhook_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(webhook_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/webhooks/{}/json",
+                crate::progenitor_support::encode_path(webhook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1466,9 +1564,10 @@ impl Events {
     */
     pub async fn update_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1490,7 +1589,8 @@ impl Events {
     *
     * * `webhook_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> Result<()> {
+    pub async fn delete_webhooks_param_webhook(&self, webhook_id: impl AsRef<str>) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1532,36 +1632,38 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/webhooks.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1583,12 +1685,14 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/webhooks.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1613,15 +1717,17 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/webhooks/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/webhooks/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1640,18 +1746,20 @@ impl Events {
     */
     pub async fn deprecated_202101_get_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webhook_id = webhook_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(webhook_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/webhooks/{}/json",
+                crate::progenitor_support::encode_path(webhook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1670,9 +1778,10 @@ impl Events {
     */
     pub async fn deprecated_202101_update_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1696,8 +1805,9 @@ impl Events {
     */
     pub async fn deprecated_202101_delete_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1739,36 +1849,38 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/webhooks.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1790,12 +1902,14 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/webhooks.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/webhooks.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1820,15 +1934,17 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/webhooks/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/webhooks/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1847,18 +1963,20 @@ impl Events {
     */
     pub async fn deprecated_unstable_get_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webhook_id = webhook_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(webhook_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/webhooks/{}/json",
+                crate::progenitor_support::encode_path(webhook_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1877,9 +1995,10 @@ impl Events {
     */
     pub async fn deprecated_unstable_update_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/unstable/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -1903,8 +2022,9 @@ impl Events {
     */
     pub async fn deprecated_unstable_delete_webhooks_param_webhook(
         &self,
-        webhook_id: &str,
+        webhook_id: impl AsRef<str>,
     ) -> Result<()> {
+        let webhook_id = webhook_id.as_ref();
         let url = format!(
             "/admin/api/unstable/webhooks/{}/json",
             crate::progenitor_support::encode_path(webhook_id),
@@ -33,23 +33,20 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
+            query_args.push(("ids required".to_string(), ids_required.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_items.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/inventory_items.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -67,8 +64,9 @@ impl Inventory {
     */
     pub async fn deprecated_202001_get_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -90,9 +88,10 @@ impl Inventory {
     */
     pub async fn deprecated_202001_update_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -124,23 +123,20 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
+            query_args.push(("ids required".to_string(), ids_required.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_items.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/inventory_items.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -158,8 +154,9 @@ impl Inventory {
     */
     pub async fn deprecated_202004_get_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -181,9 +178,10 @@ impl Inventory {
     */
     pub async fn deprecated_202004_update_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -215,23 +213,20 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
+            query_args.push(("ids required".to_string(), ids_required.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_items.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/inventory_items.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -249,8 +244,9 @@ impl Inventory {
     */
     pub async fn deprecated_202007_get_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -272,9 +268,10 @@ impl Inventory {
     */
     pub async fn deprecated_202007_update_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -301,23 +298,20 @@ impl Inventory {
     * * `ids: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn get_item(&self, ids_required: &str, limit: &str, ids: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
+            query_args.push(("ids required".to_string(), ids_required.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_items.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/inventory_items.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -333,7 +327,8 @@ impl Inventory {
     *
     * * `inventory_item_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+    pub async fn get_items_param_item(&self, inventory_item_id: impl AsRef<str>) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -355,9 +350,10 @@ impl Inventory {
     */
     pub async fn update_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -389,23 +385,20 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
+            query_args.push(("ids required".to_string(), ids_required.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_items.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/inventory_items.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -423,8 +416,9 @@ impl Inventory {
     */
     pub async fn deprecated_202101_get_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -446,9 +440,10 @@ impl Inventory {
     */
     pub async fn deprecated_202101_update_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -480,23 +475,20 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
+            query_args.push(("ids required".to_string(), ids_required.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_items.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/inventory_items.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -514,8 +506,9 @@ impl Inventory {
     */
     pub async fn deprecated_unstable_get_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/unstable/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -537,9 +530,10 @@ impl Inventory {
     */
     pub async fn deprecated_unstable_update_items_param_item(
         &self,
-        inventory_item_id: &str,
+        inventory_item_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let inventory_item_id = inventory_item_id.as_ref();
         let url = format!(
             "/admin/api/unstable/inventory_items/{}/json",
             crate::progenitor_support::encode_path(inventory_item_id),
@@ -576,24 +570,23 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -622,34 +615,29 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -674,33 +662,29 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
+                "available_adjustment required".to_string(),
                 available_adjustment_required.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels/adjust.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/inventory_levels/adjust.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -730,33 +714,25 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/inventory_levels/connect.json"),
+            &query_args,
         );
 
         self.client
@@ -790,39 +766,35 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
-                "available
-                  required"
-                    .to_string(),
+                "available required".to_string(),
                 available_required.to_string(),
             ));
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels/set.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/inventory_levels/set.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -855,24 +827,23 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -901,34 +872,29 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -953,33 +919,29 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
+                "available_adjustment required".to_string(),
                 available_adjustment_required.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels/adjust.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/inventory_levels/adjust.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1009,33 +971,25 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/inventory_levels/connect.json"),
+            &query_args,
         );
 
         self.client
@@ -1069,39 +1023,35 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
-                "available
-                  required"
-                    .to_string(),
+                "available required".to_string(),
                 available_required.to_string(),
             ));
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels/set.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/inventory_levels/set.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1134,24 +1084,23 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1180,34 +1129,29 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -1232,33 +1176,29 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
+                "available_adjustment required".to_string(),
                 available_adjustment_required.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels/adjust.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/inventory_levels/adjust.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1288,33 +1228,25 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/inventory_levels/connect.json"),
+            &query_args,
         );
 
         self.client
@@ -1348,39 +1280,35 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
-                "available
-                  required"
-                    .to_string(),
+                "available required".to_string(),
                 available_required.to_string(),
             ));
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels/set.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/inventory_levels/set.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1413,24 +1341,23 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1459,34 +1386,29 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -1511,33 +1433,29 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
+                "available_adjustment required".to_string(),
                 available_adjustment_required.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels/adjust.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/inventory_levels/adjust.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1567,33 +1485,25 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/inventory_levels/connect.json"),
+            &query_args,
         );
 
         self.client
@@ -1627,39 +1537,35 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
-                "available
-                  required"
-                    .to_string(),
+                "available required".to_string(),
                 available_required.to_string(),
             ));
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels/set.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/inventory_levels/set.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1692,24 +1598,23 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1738,34 +1643,29 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -1790,33 +1690,29 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
+                "available_adjustment required".to_string(),
                 available_adjustment_required.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels/adjust.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/inventory_levels/adjust.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1846,33 +1742,25 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/inventory_levels/connect.json"),
+            &query_args,
         );
 
         self.client
@@ -1906,39 +1794,35 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
-                "available
-                  required"
-                    .to_string(),
+                "available required".to_string(),
                 available_required.to_string(),
             ));
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels/set.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/inventory_levels/set.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -1971,24 +1855,23 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2017,34 +1900,29 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_levels.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/inventory_levels.json"),
+            &query_args,
+        );
 
         self.client.delete(&url, None).await
     }
@@ -2069,35 +1947,28 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
+                "available_adjustment required".to_string(),
                 available_adjustment_required.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/inventory_levels/adjust.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/inventory_levels/adjust.json"),
+            &query_args,
         );
 
         self.client
@@ -2128,33 +1999,25 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/inventory_levels/connect.json"),
+            &query_args,
         );
 
         self.client
@@ -2188,39 +2051,35 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
-                "available
-                  required"
-                    .to_string(),
+                "available required".to_string(),
                 available_required.to_string(),
             ));
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
+                "inventory_item_id required".to_string(),
                 inventory_item_id_required.to_string(),
             ));
         }
         if !location_id_required.is_empty() {
             query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
+                "location_id required".to_string(),
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_levels/set.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/inventory_levels/set.json"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -2252,8 +2111,9 @@ impl Inventory {
     */
     pub async fn deprecated_202001_get_locations_param_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/locations/{}/json",
             crate::progenitor_support::encode_path(location_id),
@@ -2287,8 +2147,9 @@ impl Inventory {
     */
     pub async fn deprecated_202001_get_locations_param_location_level(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/locations/{}/inventory_levels.json",
             crate::progenitor_support::encode_path(location_id),
@@ -2322,8 +2183,9 @@ impl Inventory {
     */
     pub async fn deprecated_202004_get_locations_param_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/locations/{}/json",
             crate::progenitor_support::encode_path(location_id),
@@ -2357,8 +2219,9 @@ impl Inventory {
     */
     pub async fn deprecated_202004_get_locations_param_location_level(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/locations/{}/inventory_levels.json",
             crate::progenitor_support::encode_path(location_id),
@@ -2392,8 +2255,9 @@ impl Inventory {
     */
     pub async fn deprecated_202007_get_locations_param_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/locations/{}/json",
             crate::progenitor_support::encode_path(location_id),
@@ -2427,8 +2291,9 @@ impl Inventory {
     */
     pub async fn deprecated_202007_get_locations_param_location_level(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/locations/{}/inventory_levels.json",
             crate::progenitor_support::encode_path(location_id),
@@ -2460,7 +2325,8 @@ impl Inventory {
     *
     * * `location_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_locations_param_location(&self, location_id: &str) -> Result<()> {
+    pub async fn get_locations_param_location(&self, location_id: impl AsRef<str>) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/locations/{}/json",
             crate::progenitor_support::encode_path(location_id),
@@ -2492,7 +2358,11 @@ impl Inventory {
     *
     * * `location_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_locations_param_location_level(&self, location_id: &str) -> Result<()> {
+    pub async fn get_locations_param_location_level(
+        &self,
+        location_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/locations/{}/inventory_levels.json",
             crate::progenitor_support::encode_path(location_id),
@@ -2526,8 +2396,9 @@ impl Inventory {
     */
     pub async fn deprecated_202101_get_locations_param_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/locations/{}/json",
             crate::progenitor_support::encode_path(location_id),
@@ -2561,8 +2432,9 @@ impl Inventory {
     */
     pub async fn deprecated_202101_get_locations_param_location_level(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/locations/{}/inventory_levels.json",
             crate::progenitor_support::encode_path(location_id),
@@ -2596,8 +2468,9 @@ impl Inventory {
     */
     pub async fn deprecated_unstable_get_locations_param_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/unstable/locations/{}/json",
             crate::progenitor_support::encode_path(location_id),
@@ -2631,8 +2504,9 @@ impl Inventory {
     */
     pub async fn deprecated_unstable_get_locations_param_location_level(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/admin/api/unstable/locations/{}/inventory_levels.json",
             crate::progenitor_support::encode_path(location_id),
@@ -29,21 +29,20 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/metafields.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/metafields.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -88,18 +87,20 @@ impl Metafield {
     */
     pub async fn deprecated_202001_get_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let metafield_id = metafield_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(metafield_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/metafields/{}/json",
+                crate::progenitor_support::encode_path(metafield_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -118,9 +119,10 @@ impl Metafield {
     */
     pub async fn deprecated_202001_update_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -142,7 +144,11 @@ impl Metafield {
     *
     * * `metafield_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_delete_param(&self, metafield_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_delete_param(
+        &self,
+        metafield_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -168,21 +174,20 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/metafields.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/metafields.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -227,18 +232,20 @@ impl Metafield {
     */
     pub async fn deprecated_202004_get_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let metafield_id = metafield_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(metafield_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/metafields/{}/json",
+                crate::progenitor_support::encode_path(metafield_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -257,9 +264,10 @@ impl Metafield {
     */
     pub async fn deprecated_202004_update_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -281,7 +289,11 @@ impl Metafield {
     *
     * * `metafield_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_delete_param(&self, metafield_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_delete_param(
+        &self,
+        metafield_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -307,21 +319,20 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/metafields.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/metafields.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -366,18 +377,20 @@ impl Metafield {
     */
     pub async fn deprecated_202007_get_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let metafield_id = metafield_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(metafield_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/metafields/{}/json",
+                crate::progenitor_support::encode_path(metafield_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -396,9 +409,10 @@ impl Metafield {
     */
     pub async fn deprecated_202007_update_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -420,7 +434,11 @@ impl Metafield {
     *
     * * `metafield_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_delete_param(&self, metafield_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_delete_param(
+        &self,
+        metafield_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -442,21 +460,20 @@ impl Metafield {
     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
     */
     pub async fn get(&self, metafield_owner_id: i64, metafield_owner_resource: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/metafields.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/metafields.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -499,16 +516,18 @@ impl Metafield {
     * * `metafield_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_param(&self, metafield_id: impl AsRef<str>, fields: &str) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(metafield_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/metafields/{}/json",
+                crate::progenitor_support::encode_path(metafield_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -525,7 +544,12 @@ impl Metafield {
     *
     * * `metafield_id: &str` -- storefront_access_token_id.
     */
-    pub async fn update_param(&self, metafield_id: &str, body: &serde_json::Value) -> Result<()> {
+    pub async fn update_param(
+        &self,
+        metafield_id: impl AsRef<str>,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -547,7 +571,8 @@ impl Metafield {
     *
     * * `metafield_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_param(&self, metafield_id: &str) -> Result<()> {
+    pub async fn delete_param(&self, metafield_id: impl AsRef<str>) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -573,21 +598,20 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/metafields.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/metafields.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -632,18 +656,20 @@ impl Metafield {
     */
     pub async fn deprecated_202101_get_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let metafield_id = metafield_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(metafield_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/metafields/{}/json",
+                crate::progenitor_support::encode_path(metafield_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -662,9 +688,10 @@ impl Metafield {
     */
     pub async fn deprecated_202101_update_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -686,7 +713,11 @@ impl Metafield {
     *
     * * `metafield_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_delete_param(&self, metafield_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_delete_param(
+        &self,
+        metafield_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -712,21 +743,20 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/metafields.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/metafields.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -771,18 +801,20 @@ impl Metafield {
     */
     pub async fn deprecated_unstable_get_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let metafield_id = metafield_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(metafield_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/metafields/{}/json",
+                crate::progenitor_support::encode_path(metafield_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -801,9 +833,10 @@ impl Metafield {
     */
     pub async fn deprecated_unstable_update_param(
         &self,
-        metafield_id: &str,
+        metafield_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/unstable/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -825,7 +858,11 @@ impl Metafield {
     *
     * * `metafield_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_delete_param(&self, metafield_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_delete_param(
+        &self,
+        metafield_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let metafield_id = metafield_id.as_ref();
         let url = format!(
             "/admin/api/unstable/metafields/{}/json",
             crate::progenitor_support::encode_path(metafield_id),
@@ -0,0 +1,1093 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: shopify::Client) {
+//! use shopify::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "Shopify";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/admin/api/2020-01/application_charges.json",
+    "/admin/api/2020-01/application_charges/{application_charge_id}.json",
+    "/admin/api/2020-01/application_charges/{application_charge_id}/activate.json",
+    "/admin/api/2020-01/application_credits.json",
+    "/admin/api/2020-01/application_credits/{application_credit_id}.json",
+    "/admin/api/2020-01/articles/authors.json",
+    "/admin/api/2020-01/articles/tags.json",
+    "/admin/api/2020-01/assigned_fulfillment_orders.json",
+    "/admin/api/2020-01/blogs.json",
+    "/admin/api/2020-01/blogs/count.json",
+    "/admin/api/2020-01/blogs/{blog_id}.json",
+    "/admin/api/2020-01/blogs/{blog_id}/articles.json",
+    "/admin/api/2020-01/blogs/{blog_id}/articles/count.json",
+    "/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json",
+    "/admin/api/2020-01/carrier_services.json",
+    "/admin/api/2020-01/carrier_services/{carrier_service_id}.json",
+    "/admin/api/2020-01/checkouts.json",
+    "/admin/api/2020-01/checkouts/count.json",
+    "/admin/api/2020-01/checkouts/{token}.json",
+    "/admin/api/2020-01/checkouts/{token}/complete.json",
+    "/admin/api/2020-01/checkouts/{token}/payments.json",
+    "/admin/api/2020-01/checkouts/{token}/payments/count.json",
+    "/admin/api/2020-01/checkouts/{token}/payments/{payment_id}.json",
+    "/admin/api/2020-01/checkouts/{token}/shipping_rates.json",
+    "/admin/api/2020-01/collection_listings.json",
+    "/admin/api/2020-01/collection_listings/{collection_listing_id}.json",
+    "/admin/api/2020-01/collection_listings/{collection_listing_id}/product_ids.json",
+    "/admin/api/2020-01/collections/{collection_id}.json",
+    "/admin/api/2020-01/collections/{collection_id}/products.json",
+    "/admin/api/2020-01/collects.json",
+    "/admin/api/2020-01/collects/count.json",
+    "/admin/api/2020-01/collects/{collect_id}.json",
+    "/admin/api/2020-01/comments.json",
+    "/admin/api/2020-01/comments/count.json",
+    "/admin/api/2020-01/comments/{comment_id}.json",
+    "/admin/api/2020-01/comments/{comment_id}/approve.json",
+    "/admin/api/2020-01/comments/{comment_id}/not_spam.json",
+    "/admin/api/2020-01/comments/{comment_id}/remove.json",
+    "/admin/api/2020-01/comments/{comment_id}/restore.json",
+    "/admin/api/2020-01/comments/{comment_id}/spam.json",
+    "/admin/api/2020-01/countries.json",
+    "/admin/api/2020-01/countries/count.json",
+    "/admin/api/2020-01/countries/{country_id}.json",
+    "/admin/api/2020-01/countries/{country_id}/provinces.json",
+    "/admin/api/2020-01/countries/{country_id}/provinces/count.json",
+    "/admin/api/2020-01/countries/{country_id}/provinces/{province_id}.json",
+    "/admin/api/2020-01/currencies.json",
+    "/admin/api/2020-01/custom_collections.json",
+    "/admin/api/2020-01/custom_collections/count.json",
+    "/admin/api/2020-01/custom_collections/{custom_collection_id}.json",
+    "/admin/api/2020-01/customer_saved_searches.json",
+    "/admin/api/2020-01/customer_saved_searches/count.json",
+    "/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json",
+    "/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}/customers.json",
+    "/admin/api/2020-01/customers.json",
+    "/admin/api/2020-01/customers/count.json",
+    "/admin/api/2020-01/customers/search.json",
+    "/admin/api/2020-01/customers/{customer_id}.json",
+    "/admin/api/2020-01/customers/{customer_id}/account_activation_url.json",
+    "/admin/api/2020-01/customers/{customer_id}/addresses.json",
+    "/admin/api/2020-01/customers/{customer_id}/addresses/set.json",
+    "/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json",
+    "/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}/default.json",
+    "/admin/api/2020-01/customers/{customer_id}/orders.json",
+    "/admin/api/2020-01/customers/{customer_id}/send_invite.json",
+    "/admin/api/2020-01/discount_codes/lookup.json",
+    "/admin/api/2020-01/events.json",
+    "/admin/api/2020-01/events/count.json",
+    "/admin/api/2020-01/events/{event_id}.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/close.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json",
+    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/move.json",
+    "/admin/api/2020-01/fulfillment_services.json",
+    "/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json",
+    "/admin/api/2020-01/fulfillments.json",
+    "/admin/api/2020-01/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-01/fulfillments/{fulfillment_id}/update_tracking.json",
+    "/admin/api/2020-01/gift_cards.json",
+    "/admin/api/2020-01/gift_cards/count.json",
+    "/admin/api/2020-01/gift_cards/search.json",
+    "/admin/api/2020-01/gift_cards/{gift_card_id}.json",
+    "/admin/api/2020-01/gift_cards/{gift_card_id}/disable.json",
+    "/admin/api/2020-01/inventory_items.json",
+    "/admin/api/2020-01/inventory_items/{inventory_item_id}.json",
+    "/admin/api/2020-01/inventory_levels.json",
+    "/admin/api/2020-01/inventory_levels/adjust.json",
+    "/admin/api/2020-01/inventory_levels/connect.json",
+    "/admin/api/2020-01/inventory_levels/set.json",
+    "/admin/api/2020-01/locations.json",
+    "/admin/api/2020-01/locations/count.json",
+    "/admin/api/2020-01/locations/{location_id}.json",
+    "/admin/api/2020-01/locations/{location_id}/inventory_levels.json",
+    "/admin/api/2020-01/metafields.json",
+    "/admin/api/2020-01/metafields/count.json",
+    "/admin/api/2020-01/metafields/{metafield_id}.json",
+    "/admin/api/2020-01/orders.json",
+    "/admin/api/2020-01/orders/count.json",
+    "/admin/api/2020-01/orders/{order_id}.json",
+    "/admin/api/2020-01/orders/{order_id}/cancel.json",
+    "/admin/api/2020-01/orders/{order_id}/close.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillment_orders.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments/count.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json",
+    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+    "/admin/api/2020-01/orders/{order_id}/open.json",
+    "/admin/api/2020-01/orders/{order_id}/refunds.json",
+    "/admin/api/2020-01/orders/{order_id}/refunds/calculate.json",
+    "/admin/api/2020-01/orders/{order_id}/refunds/{refund_id}.json",
+    "/admin/api/2020-01/orders/{order_id}/risks.json",
+    "/admin/api/2020-01/orders/{order_id}/risks/{risk_id}.json",
+    "/admin/api/2020-01/policies.json",
+    "/admin/api/2020-01/price_rules/{price_rule_id}/batch.json",
+    "/admin/api/2020-01/price_rules/{price_rule_id}/batch/{batch_id}.json",
+    "/admin/api/2020-01/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json",
+    "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes.json",
+    "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json",
+    "/admin/api/2020-01/product_listings.json",
+    "/admin/api/2020-01/product_listings/count.json",
+    "/admin/api/2020-01/product_listings/product_ids.json",
+    "/admin/api/2020-01/product_listings/{product_listing_id}.json",
+    "/admin/api/2020-01/products.json",
+    "/admin/api/2020-01/products/count.json",
+    "/admin/api/2020-01/products/{product_id}.json",
+    "/admin/api/2020-01/products/{product_id}/images.json",
+    "/admin/api/2020-01/products/{product_id}/images/count.json",
+    "/admin/api/2020-01/products/{product_id}/images/{image_id}.json",
+    "/admin/api/2020-01/recurring_application_charges.json",
+    "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json",
+    "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/activate.json",
+    "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/customize.json",
+    "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json",
+    "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json",
+    "/admin/api/2020-01/redirects.json",
+    "/admin/api/2020-01/redirects/count.json",
+    "/admin/api/2020-01/redirects/{redirect_id}.json",
+    "/admin/api/2020-01/reports.json",
+    "/admin/api/2020-01/reports/{report_id}.json",
+    "/admin/api/2020-01/script_tags.json",
+    "/admin/api/2020-01/script_tags/count.json",
+    "/admin/api/2020-01/script_tags/{script_tag_id}.json",
+    "/admin/api/2020-01/shipping_zones.json",
+    "/admin/api/2020-01/shop.json",
+    "/admin/api/2020-01/shopify_payments/balance.json",
+    "/admin/api/2020-01/shopify_payments/balance/transactions.json",
+    "/admin/api/2020-01/shopify_payments/disputes.json",
+    "/admin/api/2020-01/shopify_payments/disputes/{dispute_id}.json",
+    "/admin/api/2020-01/shopify_payments/payouts.json",
+    "/admin/api/2020-01/shopify_payments/payouts/{payout_id}.json",
+    "/admin/api/2020-01/smart_collections.json",
+    "/admin/api/2020-01/smart_collections/count.json",
+    "/admin/api/2020-01/smart_collections/{smart_collection_id}.json",
+    "/admin/api/2020-01/smart_collections/{smart_collection_id}/order.json",
+    "/admin/api/2020-01/storefront_access_tokens.json",
+    "/admin/api/2020-01/storefront_access_tokens/{storefront_access_token_id}.json",
+    "/admin/api/2020-01/tender_transactions.json",
+    "/admin/api/2020-01/themes.json",
+    "/admin/api/2020-01/themes/{theme_id}.json",
+    "/admin/api/2020-01/themes/{theme_id}/assets.json",
+    "/admin/api/2020-01/users.json",
+    "/admin/api/2020-01/users/current.json",
+    "/admin/api/2020-01/users/{user_id}.json",
+    "/admin/api/2020-01/webhooks.json",
+    "/admin/api/2020-01/webhooks/count.json",
+    "/admin/api/2020-01/webhooks/{webhook_id}.json",
+    "/admin/api/2020-04/application_charges.json",
+    "/admin/api/2020-04/application_charges/{application_charge_id}.json",
+    "/admin/api/2020-04/application_charges/{application_charge_id}/activate.json",
+    "/admin/api/2020-04/application_credits.json",
+    "/admin/api/2020-04/application_credits/{application_credit_id}.json",
+    "/admin/api/2020-04/articles/authors.json",
+    "/admin/api/2020-04/articles/tags.json",
+    "/admin/api/2020-04/assigned_fulfillment_orders.json",
+    "/admin/api/2020-04/blogs/{blog_id}/articles.json",
+    "/admin/api/2020-04/blogs/{blog_id}/articles/count.json",
+    "/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json",
+    "/admin/api/2020-04/carrier_services.json",
+    "/admin/api/2020-04/carrier_services/{carrier_service_id}.json",
+    "/admin/api/2020-04/checkouts.json",
+    "/admin/api/2020-04/checkouts/count.json",
+    "/admin/api/2020-04/checkouts/{token}.json",
+    "/admin/api/2020-04/checkouts/{token}/complete.json",
+    "/admin/api/2020-04/checkouts/{token}/payments.json",
+    "/admin/api/2020-04/checkouts/{token}/payments/count.json",
+    "/admin/api/2020-04/checkouts/{token}/payments/{payment_id}.json",
+    "/admin/api/2020-04/checkouts/{token}/shipping_rates.json",
+    "/admin/api/2020-04/collection_listings.json",
+    "/admin/api/2020-04/collection_listings/{collection_listing_id}.json",
+    "/admin/api/2020-04/collection_listings/{collection_listing_id}/product_ids.json",
+    "/admin/api/2020-04/collections/{collection_id}.json",
+    "/admin/api/2020-04/collections/{collection_id}/products.json",
+    "/admin/api/2020-04/collects.json",
+    "/admin/api/2020-04/collects/count.json",
+    "/admin/api/2020-04/collects/{collect_id}.json",
+    "/admin/api/2020-04/countries.json",
+    "/admin/api/2020-04/countries/count.json",
+    "/admin/api/2020-04/countries/{country_id}.json",
+    "/admin/api/2020-04/countries/{country_id}/provinces.json",
+    "/admin/api/2020-04/countries/{country_id}/provinces/count.json",
+    "/admin/api/2020-04/countries/{country_id}/provinces/{province_id}.json",
+    "/admin/api/2020-04/currencies.json",
+    "/admin/api/2020-04/custom_collections.json",
+    "/admin/api/2020-04/custom_collections/count.json",
+    "/admin/api/2020-04/custom_collections/{custom_collection_id}.json",
+    "/admin/api/2020-04/customer_saved_searches.json",
+    "/admin/api/2020-04/customer_saved_searches/count.json",
+    "/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json",
+    "/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}/customers.json",
+    "/admin/api/2020-04/customers.json",
+    "/admin/api/2020-04/customers/count.json",
+    "/admin/api/2020-04/customers/search.json",
+    "/admin/api/2020-04/customers/{customer_id}.json",
+    "/admin/api/2020-04/customers/{customer_id}/account_activation_url.json",
+    "/admin/api/2020-04/customers/{customer_id}/addresses.json",
+    "/admin/api/2020-04/customers/{customer_id}/addresses/set.json",
+    "/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json",
+    "/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}/default.json",
+    "/admin/api/2020-04/customers/{customer_id}/orders.json",
+    "/admin/api/2020-04/customers/{customer_id}/send_invite.json",
+    "/admin/api/2020-04/discount_codes/lookup.json",
+    "/admin/api/2020-04/events.json",
+    "/admin/api/2020-04/events/count.json",
+    "/admin/api/2020-04/events/{event_id}.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/close.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json",
+    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/move.json",
+    "/admin/api/2020-04/fulfillment_services.json",
+    "/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json",
+    "/admin/api/2020-04/fulfillments.json",
+    "/admin/api/2020-04/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-04/fulfillments/{fulfillment_id}/update_tracking.json",
+    "/admin/api/2020-04/gift_cards.json",
+    "/admin/api/2020-04/gift_cards/count.json",
+    "/admin/api/2020-04/gift_cards/search.json",
+    "/admin/api/2020-04/gift_cards/{gift_card_id}.json",
+    "/admin/api/2020-04/gift_cards/{gift_card_id}/disable.json",
+    "/admin/api/2020-04/inventory_items.json",
+    "/admin/api/2020-04/inventory_items/{inventory_item_id}.json",
+    "/admin/api/2020-04/inventory_levels.json",
+    "/admin/api/2020-04/inventory_levels/adjust.json",
+    "/admin/api/2020-04/inventory_levels/connect.json",
+    "/admin/api/2020-04/inventory_levels/set.json",
+    "/admin/api/2020-04/locations.json",
+    "/admin/api/2020-04/locations/count.json",
+    "/admin/api/2020-04/locations/{location_id}.json",
+    "/admin/api/2020-04/locations/{location_id}/inventory_levels.json",
+    "/admin/api/2020-04/metafields.json",
+    "/admin/api/2020-04/metafields/count.json",
+    "/admin/api/2020-04/metafields/{metafield_id}.json",
+    "/admin/api/2020-04/orders.json",
+    "/admin/api/2020-04/orders/count.json",
+    "/admin/api/2020-04/orders/{order_id}.json",
+    "/admin/api/2020-04/orders/{order_id}/cancel.json",
+    "/admin/api/2020-04/orders/{order_id}/close.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillment_orders.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments/count.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json",
+    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+    "/admin/api/2020-04/orders/{order_id}/open.json",
+    "/admin/api/2020-04/orders/{order_id}/refunds.json",
+    "/admin/api/2020-04/orders/{order_id}/refunds/calculate.json",
+    "/admin/api/2020-04/orders/{order_id}/refunds/{refund_id}.json",
+    "/admin/api/2020-04/orders/{order_id}/risks.json",
+    "/admin/api/2020-04/orders/{order_id}/risks/{risk_id}.json",
+    "/admin/api/2020-04/policies.json",
+    "/admin/api/2020-04/price_rules/{price_rule_id}/batch.json",
+    "/admin/api/2020-04/price_rules/{price_rule_id}/batch/{batch_id}.json",
+    "/admin/api/2020-04/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json",
+    "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes.json",
+    "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json",
+    "/admin/api/2020-04/product_listings.json",
+    "/admin/api/2020-04/product_listings/count.json",
+    "/admin/api/2020-04/product_listings/product_ids.json",
+    "/admin/api/2020-04/product_listings/{product_listing_id}.json",
+    "/admin/api/2020-04/products.json",
+    "/admin/api/2020-04/products/count.json",
+    "/admin/api/2020-04/products/{product_id}.json",
+    "/admin/api/2020-04/products/{product_id}/images.json",
+    "/admin/api/2020-04/products/{product_id}/images/count.json",
+    "/admin/api/2020-04/products/{product_id}/images/{image_id}.json",
+    "/admin/api/2020-04/recurring_application_charges.json",
+    "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json",
+    "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/activate.json",
+    "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/customize.json",
+    "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json",
+    "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json",
+    "/admin/api/2020-04/redirects.json",
+    "/admin/api/2020-04/redirects/count.json",
+    "/admin/api/2020-04/redirects/{redirect_id}.json",
+    "/admin/api/2020-04/reports.json",
+    "/admin/api/2020-04/reports/{report_id}.json",
+    "/admin/api/2020-04/script_tags.json",
+    "/admin/api/2020-04/script_tags/count.json",
+    "/admin/api/2020-04/script_tags/{script_tag_id}.json",
+    "/admin/api/2020-04/shipping_zones.json",
+    "/admin/api/2020-04/shop.json",
+    "/admin/api/2020-04/shopify_payments/balance.json",
+    "/admin/api/2020-04/shopify_payments/disputes.json",
+    "/admin/api/2020-04/shopify_payments/payouts.json",
+    "/admin/api/2020-04/shopify_payments/payouts/{payout_id}.json",
+    "/admin/api/2020-04/smart_collections.json",
+    "/admin/api/2020-04/smart_collections/count.json",
+    "/admin/api/2020-04/smart_collections/{smart_collection_id}.json",
+    "/admin/api/2020-04/smart_collections/{smart_collection_id}/order.json",
+    "/admin/api/2020-04/storefront_access_tokens.json",
+    "/admin/api/2020-04/storefront_access_tokens/{storefront_access_token_id}.json",
+    "/admin/api/2020-04/tender_transactions.json",
+    "/admin/api/2020-04/themes.json",
+    "/admin/api/2020-04/themes/{theme_id}.json",
+    "/admin/api/2020-04/themes/{theme_id}/assets.json",
+    "/admin/api/2020-04/users.json",
+    "/admin/api/2020-04/users/current.json",
+    "/admin/api/2020-04/users/{user_id}.json",
+    "/admin/api/2020-04/webhooks.json",
+    "/admin/api/2020-04/webhooks/count.json",
+    "/admin/api/2020-04/webhooks/{webhook_id}.json",
+    "/admin/api/2020-07/application_charges.json",
+    "/admin/api/2020-07/application_charges/{application_charge_id}.json",
+    "/admin/api/2020-07/application_charges/{application_charge_id}/activate.json",
+    "/admin/api/2020-07/application_credits.json",
+    "/admin/api/2020-07/application_credits/{application_credit_id}.json",
+    "/admin/api/2020-07/articles/authors.json",
+    "/admin/api/2020-07/articles/tags.json",
+    "/admin/api/2020-07/assigned_fulfillment_orders.json",
+    "/admin/api/2020-07/blogs/{blog_id}/articles.json",
+    "/admin/api/2020-07/blogs/{blog_id}/articles/count.json",
+    "/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json",
+    "/admin/api/2020-07/carrier_services.json",
+    "/admin/api/2020-07/carrier_services/{carrier_service_id}.json",
+    "/admin/api/2020-07/checkouts.json",
+    "/admin/api/2020-07/checkouts/count.json",
+    "/admin/api/2020-07/checkouts/{token}.json",
+    "/admin/api/2020-07/checkouts/{token}/complete.json",
+    "/admin/api/2020-07/checkouts/{token}/payments.json",
+    "/admin/api/2020-07/checkouts/{token}/payments/count.json",
+    "/admin/api/2020-07/checkouts/{token}/payments/{payment_id}.json",
+    "/admin/api/2020-07/checkouts/{token}/shipping_rates.json",
+    "/admin/api/2020-07/collection_listings.json",
+    "/admin/api/2020-07/collection_listings/{collection_listing_id}.json",
+    "/admin/api/2020-07/collection_listings/{collection_listing_id}/product_ids.json",
+    "/admin/api/2020-07/collections/{collection_id}.json",
+    "/admin/api/2020-07/collections/{collection_id}/products.json",
+    "/admin/api/2020-07/collects.json",
+    "/admin/api/2020-07/collects/count.json",
+    "/admin/api/2020-07/collects/{collect_id}.json",
+    "/admin/api/2020-07/countries.json",
+    "/admin/api/2020-07/countries/count.json",
+    "/admin/api/2020-07/countries/{country_id}.json",
+    "/admin/api/2020-07/countries/{country_id}/provinces.json",
+    "/admin/api/2020-07/countries/{country_id}/provinces/count.json",
+    "/admin/api/2020-07/countries/{country_id}/provinces/{province_id}.json",
+    "/admin/api/2020-07/currencies.json",
+    "/admin/api/2020-07/custom_collections.json",
+    "/admin/api/2020-07/custom_collections/count.json",
+    "/admin/api/2020-07/custom_collections/{custom_collection_id}.json",
+    "/admin/api/2020-07/customer_saved_searches.json",
+    "/admin/api/2020-07/customer_saved_searches/count.json",
+    "/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json",
+    "/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}/customers.json",
+    "/admin/api/2020-07/customers.json",
+    "/admin/api/2020-07/customers/count.json",
+    "/admin/api/2020-07/customers/search.json",
+    "/admin/api/2020-07/customers/{customer_id}.json",
+    "/admin/api/2020-07/customers/{customer_id}/account_activation_url.json",
+    "/admin/api/2020-07/customers/{customer_id}/addresses.json",
+    "/admin/api/2020-07/customers/{customer_id}/addresses/set.json",
+    "/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json",
+    "/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}/default.json",
+    "/admin/api/2020-07/customers/{customer_id}/orders.json",
+    "/admin/api/2020-07/customers/{customer_id}/send_invite.json",
+    "/admin/api/2020-07/discount_codes/lookup.json",
+    "/admin/api/2020-07/events.json",
+    "/admin/api/2020-07/events/count.json",
+    "/admin/api/2020-07/events/{event_id}.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/close.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json",
+    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/move.json",
+    "/admin/api/2020-07/fulfillment_services.json",
+    "/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json",
+    "/admin/api/2020-07/fulfillments.json",
+    "/admin/api/2020-07/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-07/fulfillments/{fulfillment_id}/update_tracking.json",
+    "/admin/api/2020-07/gift_cards.json",
+    "/admin/api/2020-07/gift_cards/count.json",
+    "/admin/api/2020-07/gift_cards/search.json",
+    "/admin/api/2020-07/gift_cards/{gift_card_id}.json",
+    "/admin/api/2020-07/gift_cards/{gift_card_id}/disable.json",
+    "/admin/api/2020-07/inventory_items.json",
+    "/admin/api/2020-07/inventory_items/{inventory_item_id}.json",
+    "/admin/api/2020-07/inventory_levels.json",
+    "/admin/api/2020-07/inventory_levels/adjust.json",
+    "/admin/api/2020-07/inventory_levels/connect.json",
+    "/admin/api/2020-07/inventory_levels/set.json",
+    "/admin/api/2020-07/locations.json",
+    "/admin/api/2020-07/locations/count.json",
+    "/admin/api/2020-07/locations/{location_id}.json",
+    "/admin/api/2020-07/locations/{location_id}/inventory_levels.json",
+    "/admin/api/2020-07/metafields.json",
+    "/admin/api/2020-07/metafields/count.json",
+    "/admin/api/2020-07/metafields/{metafield_id}.json",
+    "/admin/api/2020-07/orders.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillment_orders.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments/count.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json",
+    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+    "/admin/api/2020-07/orders/{order_id}/refunds.json",
+    "/admin/api/2020-07/orders/{order_id}/refunds/calculate.json",
+    "/admin/api/2020-07/orders/{order_id}/refunds/{refund_id}.json",
+    "/admin/api/2020-07/orders/{order_id}/risks.json",
+    "/admin/api/2020-07/orders/{order_id}/risks/{risk_id}.json",
+    "/admin/api/2020-07/policies.json",
+    "/admin/api/2020-07/price_rules/{price_rule_id}/batch.json",
+    "/admin/api/2020-07/price_rules/{price_rule_id}/batch/{batch_id}.json",
+    "/admin/api/2020-07/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json",
+    "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes.json",
+    "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json",
+    "/admin/api/2020-07/product_listings.json",
+    "/admin/api/2020-07/product_listings/count.json",
+    "/admin/api/2020-07/product_listings/product_ids.json",
+    "/admin/api/2020-07/product_listings/{product_listing_id}.json",
+    "/admin/api/2020-07/products.json",
+    "/admin/api/2020-07/products/count.json",
+    "/admin/api/2020-07/products/{product_id}.json",
+    "/admin/api/2020-07/products/{product_id}/images.json",
+    "/admin/api/2020-07/products/{product_id}/images/count.json",
+    "/admin/api/2020-07/products/{product_id}/images/{image_id}.json",
+    "/admin/api/2020-07/recurring_application_charges.json",
+    "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json",
+    "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/activate.json",
+    "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/customize.json",
+    "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json",
+    "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json",
+    "/admin/api/2020-07/redirects.json",
+    "/admin/api/2020-07/redirects/count.json",
+    "/admin/api/2020-07/redirects/{redirect_id}.json",
+    "/admin/api/2020-07/reports.json",
+    "/admin/api/2020-07/reports/{report_id}.json",
+    "/admin/api/2020-07/script_tags.json",
+    "/admin/api/2020-07/script_tags/count.json",
+    "/admin/api/2020-07/script_tags/{script_tag_id}.json",
+    "/admin/api/2020-07/shipping_zones.json",
+    "/admin/api/2020-07/shop.json",
+    "/admin/api/2020-07/shopify_payments/balance.json",
+    "/admin/api/2020-07/shopify_payments/payouts.json",
+    "/admin/api/2020-07/shopify_payments/payouts/{payout_id}.json",
+    "/admin/api/2020-07/smart_collections.json",
+    "/admin/api/2020-07/smart_collections/count.json",
+    "/admin/api/2020-07/smart_collections/{smart_collection_id}.json",
+    "/admin/api/2020-07/smart_collections/{smart_collection_id}/order.json",
+    "/admin/api/2020-07/storefront_access_tokens.json",
+    "/admin/api/2020-07/storefront_access_tokens/{storefront_access_token_id}.json",
+    "/admin/api/2020-07/tender_transactions.json",
+    "/admin/api/2020-07/themes.json",
+    "/admin/api/2020-07/themes/{theme_id}.json",
+    "/admin/api/2020-07/themes/{theme_id}/assets.json",
+    "/admin/api/2020-07/users.json",
+    "/admin/api/2020-07/users/current.json",
+    "/admin/api/2020-07/users/{user_id}.json",
+    "/admin/api/2020-07/webhooks.json",
+    "/admin/api/2020-07/webhooks/count.json",
+    "/admin/api/2020-07/webhooks/{webhook_id}.json",
+    "/admin/api/2020-10/application_charges.json",
+    "/admin/api/2020-10/application_charges/{application_charge_id}.json",
+    "/admin/api/2020-10/application_charges/{application_charge_id}/activate.json",
+    "/admin/api/2020-10/application_credits.json",
+    "/admin/api/2020-10/application_credits/{application_credit_id}.json",
+    "/admin/api/2020-10/articles/authors.json",
+    "/admin/api/2020-10/articles/tags.json",
+    "/admin/api/2020-10/assigned_fulfillment_orders.json",
+    "/admin/api/2020-10/blogs/{blog_id}/articles.json",
+    "/admin/api/2020-10/blogs/{blog_id}/articles/count.json",
+    "/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json",
+    "/admin/api/2020-10/carrier_services.json",
+    "/admin/api/2020-10/carrier_services/{carrier_service_id}.json",
+    "/admin/api/2020-10/checkouts.json",
+    "/admin/api/2020-10/checkouts/count.json",
+    "/admin/api/2020-10/checkouts/{token}.json",
+    "/admin/api/2020-10/checkouts/{token}/complete.json",
+    "/admin/api/2020-10/checkouts/{token}/payments.json",
+    "/admin/api/2020-10/checkouts/{token}/payments/count.json",
+    "/admin/api/2020-10/checkouts/{token}/payments/{payment_id}.json",
+    "/admin/api/2020-10/checkouts/{token}/shipping_rates.json",
+    "/admin/api/2020-10/collection_listings.json",
+    "/admin/api/2020-10/collection_listings/{collection_listing_id}.json",
+    "/admin/api/2020-10/collection_listings/{collection_listing_id}/product_ids.json",
+    "/admin/api/2020-10/collections/{collection_id}.json",
+    "/admin/api/2020-10/collections/{collection_id}/products.json",
+    "/admin/api/2020-10/collects.json",
+    "/admin/api/2020-10/collects/count.json",
+    "/admin/api/2020-10/collects/{collect_id}.json",
+    "/admin/api/2020-10/countries.json",
+    "/admin/api/2020-10/countries/count.json",
+    "/admin/api/2020-10/countries/{country_id}.json",
+    "/admin/api/2020-10/countries/{country_id}/provinces.json",
+    "/admin/api/2020-10/countries/{country_id}/provinces/count.json",
+    "/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json",
+    "/admin/api/2020-10/currencies.json",
+    "/admin/api/2020-10/custom_collections.json",
+    "/admin/api/2020-10/custom_collections/count.json",
+    "/admin/api/2020-10/custom_collections/{custom_collection_id}.json",
+    "/admin/api/2020-10/customer_saved_searches.json",
+    "/admin/api/2020-10/customer_saved_searches/count.json",
+    "/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json",
+    "/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}/customers.json",
+    "/admin/api/2020-10/customers.json",
+    "/admin/api/2020-10/customers/count.json",
+    "/admin/api/2020-10/customers/search.json",
+    "/admin/api/2020-10/customers/{customer_id}.json",
+    "/admin/api/2020-10/customers/{customer_id}/account_activation_url.json",
+    "/admin/api/2020-10/customers/{customer_id}/addresses.json",
+    "/admin/api/2020-10/customers/{customer_id}/addresses/set.json",
+    "/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json",
+    "/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}/default.json",
+    "/admin/api/2020-10/customers/{customer_id}/orders.json",
+    "/admin/api/2020-10/customers/{customer_id}/send_invite.json",
+    "/admin/api/2020-10/discount_codes/lookup.json",
+    "/admin/api/2020-10/events.json",
+    "/admin/api/2020-10/events/count.json",
+    "/admin/api/2020-10/events/{event_id}.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/close.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json",
+    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/move.json",
+    "/admin/api/2020-10/fulfillment_services.json",
+    "/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json",
+    "/admin/api/2020-10/fulfillments.json",
+    "/admin/api/2020-10/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-10/fulfillments/{fulfillment_id}/update_tracking.json",
+    "/admin/api/2020-10/gift_cards.json",
+    "/admin/api/2020-10/gift_cards/count.json",
+    "/admin/api/2020-10/gift_cards/search.json",
+    "/admin/api/2020-10/gift_cards/{gift_card_id}.json",
+    "/admin/api/2020-10/gift_cards/{gift_card_id}/disable.json",
+    "/admin/api/2020-10/inventory_items.json",
+    "/admin/api/2020-10/inventory_items/{inventory_item_id}.json",
+    "/admin/api/2020-10/inventory_levels.json",
+    "/admin/api/2020-10/inventory_levels/adjust.json",
+    "/admin/api/2020-10/inventory_levels/connect.json",
+    "/admin/api/2020-10/inventory_levels/set.json",
+    "/admin/api/2020-10/locations.json",
+    "/admin/api/2020-10/locations/count.json",
+    "/admin/api/2020-10/locations/{location_id}.json",
+    "/admin/api/2020-10/locations/{location_id}/inventory_levels.json",
+    "/admin/api/2020-10/metafields.json",
+    "/admin/api/2020-10/metafields/count.json",
+    "/admin/api/2020-10/metafields/{metafield_id}.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillment_orders.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments/count.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json",
+    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+    "/admin/api/2020-10/orders/{order_id}/refunds.json",
+    "/admin/api/2020-10/orders/{order_id}/refunds/calculate.json",
+    "/admin/api/2020-10/orders/{order_id}/refunds/{refund_id}.json",
+    "/admin/api/2020-10/orders/{order_id}/risks.json",
+    "/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json",
+    "/admin/api/2020-10/policies.json",
+    "/admin/api/2020-10/price_rules/{price_rule_id}/batch.json",
+    "/admin/api/2020-10/price_rules/{price_rule_id}/batch/{batch_id}.json",
+    "/admin/api/2020-10/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json",
+    "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes.json",
+    "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json",
+    "/admin/api/2020-10/products.json",
+    "/admin/api/2020-10/products/count.json",
+    "/admin/api/2020-10/products/{product_id}.json",
+    "/admin/api/2020-10/products/{product_id}/images.json",
+    "/admin/api/2020-10/products/{product_id}/images/count.json",
+    "/admin/api/2020-10/products/{product_id}/images/{image_id}.json",
+    "/admin/api/2020-10/recurring_application_charges.json",
+    "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json",
+    "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/activate.json",
+    "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/customize.json",
+    "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json",
+    "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json",
+    "/admin/api/2020-10/redirects.json",
+    "/admin/api/2020-10/redirects/count.json",
+    "/admin/api/2020-10/redirects/{redirect_id}.json",
+    "/admin/api/2020-10/reports.json",
+    "/admin/api/2020-10/reports/{report_id}.json",
+    "/admin/api/2020-10/script_tags.json",
+    "/admin/api/2020-10/script_tags/count.json",
+    "/admin/api/2020-10/script_tags/{script_tag_id}.json",
+    "/admin/api/2020-10/shipping_zones.json",
+    "/admin/api/2020-10/shop.json",
+    "/admin/api/2020-10/shopify_payments/balance.json",
+    "/admin/api/2020-10/shopify_payments/payouts.json",
+    "/admin/api/2020-10/shopify_payments/payouts/{payout_id}.json",
+    "/admin/api/2020-10/smart_collections.json",
+    "/admin/api/2020-10/smart_collections/count.json",
+    "/admin/api/2020-10/smart_collections/{smart_collection_id}.json",
+    "/admin/api/2020-10/smart_collections/{smart_collection_id}/order.json",
+    "/admin/api/2020-10/storefront_access_tokens.json",
+    "/admin/api/2020-10/storefront_access_tokens/{storefront_access_token_id}.json",
+    "/admin/api/2020-10/tender_transactions.json",
+    "/admin/api/2020-10/themes.json",
+    "/admin/api/2020-10/themes/{theme_id}.json",
+    "/admin/api/2020-10/themes/{theme_id}/assets.json",
+    "/admin/api/2020-10/users.json",
+    "/admin/api/2020-10/users/current.json",
+    "/admin/api/2020-10/users/{user_id}.json",
+    "/admin/api/2020-10/webhooks.json",
+    "/admin/api/2020-10/webhooks/count.json",
+    "/admin/api/2020-10/webhooks/{webhook_id}.json",
+    "/admin/api/2021-01/application_charges.json",
+    "/admin/api/2021-01/application_charges/{application_charge_id}.json",
+    "/admin/api/2021-01/application_credits.json",
+    "/admin/api/2021-01/application_credits/{application_credit_id}.json",
+    "/admin/api/2021-01/articles/authors.json",
+    "/admin/api/2021-01/articles/tags.json",
+    "/admin/api/2021-01/assigned_fulfillment_orders.json",
+    "/admin/api/2021-01/blogs/{blog_id}/articles.json",
+    "/admin/api/2021-01/blogs/{blog_id}/articles/count.json",
+    "/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json",
+    "/admin/api/2021-01/carrier_services.json",
+    "/admin/api/2021-01/carrier_services/{carrier_service_id}.json",
+    "/admin/api/2021-01/checkouts.json",
+    "/admin/api/2021-01/checkouts/count.json",
+    "/admin/api/2021-01/checkouts/{token}.json",
+    "/admin/api/2021-01/checkouts/{token}/complete.json",
+    "/admin/api/2021-01/checkouts/{token}/payments.json",
+    "/admin/api/2021-01/checkouts/{token}/payments/count.json",
+    "/admin/api/2021-01/checkouts/{token}/payments/{payment_id}.json",
+    "/admin/api/2021-01/checkouts/{token}/shipping_rates.json",
+    "/admin/api/2021-01/collection_listings.json",
+    "/admin/api/2021-01/collection_listings/{collection_listing_id}.json",
+    "/admin/api/2021-01/collection_listings/{collection_listing_id}/product_ids.json",
+    "/admin/api/2021-01/collections/{collection_id}.json",
+    "/admin/api/2021-01/collections/{collection_id}/products.json",
+    "/admin/api/2021-01/collects.json",
+    "/admin/api/2021-01/collects/count.json",
+    "/admin/api/2021-01/collects/{collect_id}.json",
+    "/admin/api/2021-01/countries.json",
+    "/admin/api/2021-01/countries/count.json",
+    "/admin/api/2021-01/countries/{country_id}.json",
+    "/admin/api/2021-01/countries/{country_id}/provinces.json",
+    "/admin/api/2021-01/countries/{country_id}/provinces/count.json",
+    "/admin/api/2021-01/countries/{country_id}/provinces/{province_id}.json",
+    "/admin/api/2021-01/currencies.json",
+    "/admin/api/2021-01/custom_collections.json",
+    "/admin/api/2021-01/custom_collections/count.json",
+    "/admin/api/2021-01/custom_collections/{custom_collection_id}.json",
+    "/admin/api/2021-01/customer_saved_searches.json",
+    "/admin/api/2021-01/customer_saved_searches/count.json",
+    "/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json",
+    "/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}/customers.json",
+    "/admin/api/2021-01/customers.json",
+    "/admin/api/2021-01/customers/count.json",
+    "/admin/api/2021-01/customers/search.json",
+    "/admin/api/2021-01/customers/{customer_id}.json",
+    "/admin/api/2021-01/customers/{customer_id}/account_activation_url.json",
+    "/admin/api/2021-01/customers/{customer_id}/addresses.json",
+    "/admin/api/2021-01/customers/{customer_id}/addresses/set.json",
+    "/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json",
+    "/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}/default.json",
+    "/admin/api/2021-01/customers/{customer_id}/orders.json",
+    "/admin/api/2021-01/customers/{customer_id}/send_invite.json",
+    "/admin/api/2021-01/deprecated_api_calls.json",
+    "/admin/api/2021-01/discount_codes/lookup.json",
+    "/admin/api/2021-01/events.json",
+    "/admin/api/2021-01/events/count.json",
+    "/admin/api/2021-01/events/{event_id}.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/close.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/move.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/open.json",
+    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/reschedule.json",
+    "/admin/api/2021-01/fulfillment_services.json",
+    "/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json",
+    "/admin/api/2021-01/fulfillments.json",
+    "/admin/api/2021-01/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2021-01/fulfillments/{fulfillment_id}/update_tracking.json",
+    "/admin/api/2021-01/gift_cards.json",
+    "/admin/api/2021-01/gift_cards/count.json",
+    "/admin/api/2021-01/gift_cards/search.json",
+    "/admin/api/2021-01/gift_cards/{gift_card_id}.json",
+    "/admin/api/2021-01/gift_cards/{gift_card_id}/disable.json",
+    "/admin/api/2021-01/inventory_items.json",
+    "/admin/api/2021-01/inventory_items/{inventory_item_id}.json",
+    "/admin/api/2021-01/inventory_levels.json",
+    "/admin/api/2021-01/inventory_levels/adjust.json",
+    "/admin/api/2021-01/inventory_levels/connect.json",
+    "/admin/api/2021-01/inventory_levels/set.json",
+    "/admin/api/2021-01/locations.json",
+    "/admin/api/2021-01/locations/count.json",
+    "/admin/api/2021-01/locations/{location_id}.json",
+    "/admin/api/2021-01/locations/{location_id}/inventory_levels.json",
+    "/admin/api/2021-01/metafields.json",
+    "/admin/api/2021-01/metafields/count.json",
+    "/admin/api/2021-01/metafields/{metafield_id}.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillment_orders.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments/count.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json",
+    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+    "/admin/api/2021-01/orders/{order_id}/refunds.json",
+    "/admin/api/2021-01/orders/{order_id}/refunds/calculate.json",
+    "/admin/api/2021-01/orders/{order_id}/refunds/{refund_id}.json",
+    "/admin/api/2021-01/orders/{order_id}/risks.json",
+    "/admin/api/2021-01/orders/{order_id}/risks/{risk_id}.json",
+    "/admin/api/2021-01/policies.json",
+    "/admin/api/2021-01/price_rules/{price_rule_id}/batch.json",
+    "/admin/api/2021-01/price_rules/{price_rule_id}/batch/{batch_id}.json",
+    "/admin/api/2021-01/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json",
+    "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes.json",
+    "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json",
+    "/admin/api/2021-01/products.json",
+    "/admin/api/2021-01/products/count.json",
+    "/admin/api/2021-01/products/{product_id}.json",
+    "/admin/api/2021-01/products/{product_id}/images.json",
+    "/admin/api/2021-01/products/{product_id}/images/count.json",
+    "/admin/api/2021-01/products/{product_id}/images/{image_id}.json",
+    "/admin/api/2021-01/recurring_application_charges.json",
+    "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json",
+    "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/customize.json",
+    "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json",
+    "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json",
+    "/admin/api/2021-01/redirects.json",
+    "/admin/api/2021-01/redirects/count.json",
+    "/admin/api/2021-01/redirects/{redirect_id}.json",
+    "/admin/api/2021-01/reports.json",
+    "/admin/api/2021-01/reports/{report_id}.json",
+    "/admin/api/2021-01/script_tags.json",
+    "/admin/api/2021-01/script_tags/count.json",
+    "/admin/api/2021-01/script_tags/{script_tag_id}.json",
+    "/admin/api/2021-01/shipping_zones.json",
+    "/admin/api/2021-01/shop.json",
+    "/admin/api/2021-01/shopify_payments/balance.json",
+    "/admin/api/2021-01/shopify_payments/payouts.json",
+    "/admin/api/2021-01/shopify_payments/payouts/{payout_id}.json",
+    "/admin/api/2021-01/smart_collections.json",
+    "/admin/api/2021-01/smart_collections/count.json",
+    "/admin/api/2021-01/smart_collections/{smart_collection_id}.json",
+    "/admin/api/2021-01/smart_collections/{smart_collection_id}/order.json",
+    "/admin/api/2021-01/storefront_access_tokens.json",
+    "/admin/api/2021-01/storefront_access_tokens/{storefront_access_token_id}.json",
+    "/admin/api/2021-01/tender_transactions.json",
+    "/admin/api/2021-01/themes.json",
+    "/admin/api/2021-01/themes/{theme_id}.json",
+    "/admin/api/2021-01/themes/{theme_id}/assets.json",
+    "/admin/api/2021-01/users.json",
+    "/admin/api/2021-01/users/current.json",
+    "/admin/api/2021-01/users/{user_id}.json",
+    "/admin/api/2021-01/webhooks.json",
+    "/admin/api/2021-01/webhooks/count.json",
+    "/admin/api/2021-01/webhooks/{webhook_id}.json",
+    "/admin/api/unstable/application_charges.json",
+    "/admin/api/unstable/application_charges/{application_charge_id}.json",
+    "/admin/api/unstable/application_credits.json",
+    "/admin/api/unstable/application_credits/{application_credit_id}.json",
+    "/admin/api/unstable/articles/authors.json",
+    "/admin/api/unstable/articles/tags.json",
+    "/admin/api/unstable/assigned_fulfillment_orders.json",
+    "/admin/api/unstable/blogs/{blog_id}/articles.json",
+    "/admin/api/unstable/blogs/{blog_id}/articles/count.json",
+    "/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json",
+    "/admin/api/unstable/carrier_services.json",
+    "/admin/api/unstable/carrier_services/{carrier_service_id}.json",
+    "/admin/api/unstable/checkouts.json",
+    "/admin/api/unstable/checkouts/count.json",
+    "/admin/api/unstable/checkouts/{token}.json",
+    "/admin/api/unstable/checkouts/{token}/complete.json",
+    "/admin/api/unstable/checkouts/{token}/payments.json",
+    "/admin/api/unstable/checkouts/{token}/payments/count.json",
+    "/admin/api/unstable/checkouts/{token}/payments/{payment_id}.json",
+    "/admin/api/unstable/checkouts/{token}/shipping_rates.json",
+    "/admin/api/unstable/collection_listings.json",
+    "/admin/api/unstable/collection_listings/{collection_listing_id}.json",
+    "/admin/api/unstable/collection_listings/{collection_listing_id}/product_ids.json",
+    "/admin/api/unstable/collections/{collection_id}.json",
+    "/admin/api/unstable/collections/{collection_id}/products.json",
+    "/admin/api/unstable/collects.json",
+    "/admin/api/unstable/collects/count.json",
+    "/admin/api/unstable/collects/{collect_id}.json",
+    "/admin/api/unstable/countries.json",
+    "/admin/api/unstable/countries/count.json",
+    "/admin/api/unstable/countries/{country_id}.json",
+    "/admin/api/unstable/countries/{country_id}/provinces.json",
+    "/admin/api/unstable/countries/{country_id}/provinces/count.json",
+    "/admin/api/unstable/countries/{country_id}/provinces/{province_id}.json",
+    "/admin/api/unstable/currencies.json",
+    "/admin/api/unstable/custom_collections.json",
+    "/admin/api/unstable/custom_collections/count.json",
+    "/admin/api/unstable/custom_collections/{custom_collection_id}.json",
+    "/admin/api/unstable/customer_saved_searches.json",
+    "/admin/api/unstable/customer_saved_searches/count.json",
+    "/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json",
+    "/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}/customers.json",
+    "/admin/api/unstable/customers.json",
+    "/admin/api/unstable/customers/count.json",
+    "/admin/api/unstable/customers/search.json",
+    "/admin/api/unstable/customers/{customer_id}.json",
+    "/admin/api/unstable/customers/{customer_id}/account_activation_url.json",
+    "/admin/api/unstable/customers/{customer_id}/addresses.json",
+    "/admin/api/unstable/customers/{customer_id}/addresses/set.json",
+    "/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json",
+    "/admin/api/unstable/customers/{customer_id}/addresses/{address_id}/default.json",
+    "/admin/api/unstable/customers/{customer_id}/orders.json",
+    "/admin/api/unstable/customers/{customer_id}/send_invite.json",
+    "/admin/api/unstable/deprecated_api_calls.json",
+    "/admin/api/unstable/discount_codes/lookup.json",
+    "/admin/api/unstable/events.json",
+    "/admin/api/unstable/events/count.json",
+    "/admin/api/unstable/events/{event_id}.json",
+    "/admin/api/unstable/fulfillment_orders/release_hold.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/close.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/move.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/open.json",
+    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/reschedule.json",
+    "/admin/api/unstable/fulfillment_services.json",
+    "/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json",
+    "/admin/api/unstable/fulfillments.json",
+    "/admin/api/unstable/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/unstable/fulfillments/{fulfillment_id}/update_tracking.json",
+    "/admin/api/unstable/gift_cards.json",
+    "/admin/api/unstable/gift_cards/count.json",
+    "/admin/api/unstable/gift_cards/search.json",
+    "/admin/api/unstable/gift_cards/{gift_card_id}.json",
+    "/admin/api/unstable/gift_cards/{gift_card_id}/disable.json",
+    "/admin/api/unstable/inventory_items.json",
+    "/admin/api/unstable/inventory_items/{inventory_item_id}.json",
+    "/admin/api/unstable/inventory_levels.json",
+    "/admin/api/unstable/inventory_levels/adjust.json",
+    "/admin/api/unstable/inventory_levels/connect.json",
+    "/admin/api/unstable/inventory_levels/set.json",
+    "/admin/api/unstable/locations.json",
+    "/admin/api/unstable/locations/count.json",
+    "/admin/api/unstable/locations/{location_id}.json",
+    "/admin/api/unstable/locations/{location_id}/inventory_levels.json",
+    "/admin/api/unstable/metafields.json",
+    "/admin/api/unstable/metafields/count.json",
+    "/admin/api/unstable/metafields/{metafield_id}.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillment_orders.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments/count.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json",
+    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+    "/admin/api/unstable/orders/{order_id}/refunds.json",
+    "/admin/api/unstable/orders/{order_id}/refunds/calculate.json",
+    "/admin/api/unstable/orders/{order_id}/refunds/{refund_id}.json",
+    "/admin/api/unstable/orders/{order_id}/risks.json",
+    "/admin/api/unstable/orders/{order_id}/risks/{risk_id}.json",
+    "/admin/api/unstable/policies.json",
+    "/admin/api/unstable/price_rules/{price_rule_id}/batch.json",
+    "/admin/api/unstable/price_rules/{price_rule_id}/batch/{batch_id}.json",
+    "/admin/api/unstable/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json",
+    "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes.json",
+    "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json",
+    "/admin/api/unstable/products.json",
+    "/admin/api/unstable/products/count.json",
+    "/admin/api/unstable/products/{product_id}.json",
+    "/admin/api/unstable/products/{product_id}/images.json",
+    "/admin/api/unstable/products/{product_id}/images/count.json",
+    "/admin/api/unstable/products/{product_id}/images/{image_id}.json",
+    "/admin/api/unstable/recurring_application_charges.json",
+    "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json",
+    "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/customize.json",
+    "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json",
+    "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json",
+    "/admin/api/unstable/redirects.json",
+    "/admin/api/unstable/redirects/count.json",
+    "/admin/api/unstable/redirects/{redirect_id}.json",
+    "/admin/api/unstable/reports.json",
+    "/admin/api/unstable/reports/{report_id}.json",
+    "/admin/api/unstable/script_tags.json",
+    "/admin/api/unstable/script_tags/count.json",
+    "/admin/api/unstable/script_tags/{script_tag_id}.json",
+    "/admin/api/unstable/shipping_zones.json",
+    "/admin/api/unstable/shop.json",
+    "/admin/api/unstable/shopify_payments/balance.json",
+    "/admin/api/unstable/shopify_payments/payouts.json",
+    "/admin/api/unstable/shopify_payments/payouts/{payout_id}.json",
+    "/admin/api/unstable/smart_collections.json",
+    "/admin/api/unstable/smart_collections/count.json",
+    "/admin/api/unstable/smart_collections/{smart_collection_id}.json",
+    "/admin/api/unstable/smart_collections/{smart_collection_id}/order.json",
+    "/admin/api/unstable/storefront_access_tokens.json",
+    "/admin/api/unstable/storefront_access_tokens/{storefront_access_token_id}.json",
+    "/admin/api/unstable/tender_transactions.json",
+    "/admin/api/unstable/themes.json",
+    "/admin/api/unstable/themes/{theme_id}.json",
+    "/admin/api/unstable/themes/{theme_id}/assets.json",
+    "/admin/api/unstable/users.json",
+    "/admin/api/unstable/users/current.json",
+    "/admin/api/unstable/users/{user_id}.json",
+    "/admin/api/unstable/webhooks.json",
+    "/admin/api/unstable/webhooks/count.json",
+    "/admin/api/unstable/webhooks/{webhook_id}.json",
+    "/admin/oauth/access_scopes.json",
+    "/fetch_stock",
+    "/fetch_tracking_numbers",
+];
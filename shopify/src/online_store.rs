@@ -44,7 +44,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_blogs_param_blog_article(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         limit: &str,
         since_id: &str,
         created_at_min: &str,
@@ -59,51 +59,53 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -122,9 +124,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_create_blogs_param_blog_articles(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/blogs/{}/articles.json",
             crate::progenitor_support::encode_path(blog_id),
@@ -160,7 +163,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_blogs_param_blog_articles_count(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
@@ -169,33 +172,35 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/blogs/{}/articles/count.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -216,20 +221,23 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            crate::progenitor_support::encode_path(article_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/blogs/{}/articles/{}/json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -249,10 +257,12 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_update_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -278,9 +288,11 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_delete_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -319,15 +331,17 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/articles/tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/articles/tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -364,7 +378,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_get_blogs_param_blog_article(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         limit: &str,
         since_id: &str,
         created_at_min: &str,
@@ -379,51 +393,53 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -442,9 +458,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_create_blogs_param_blog_articles(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/blogs/{}/articles.json",
             crate::progenitor_support::encode_path(blog_id),
@@ -480,7 +497,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_get_blogs_param_blog_articles_count(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
@@ -489,33 +506,35 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/blogs/{}/articles/count.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -536,20 +555,23 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_get_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            crate::progenitor_support::encode_path(article_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/blogs/{}/articles/{}/json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -569,10 +591,12 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_update_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -598,9 +622,11 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_delete_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -639,15 +665,17 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/articles/tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/articles/tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -684,7 +712,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_get_blogs_param_blog_article(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         limit: &str,
         since_id: &str,
         created_at_min: &str,
@@ -699,51 +727,53 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -762,9 +792,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_create_blogs_param_blog_articles(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/blogs/{}/articles.json",
             crate::progenitor_support::encode_path(blog_id),
@@ -800,7 +831,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_get_blogs_param_blog_articles_count(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
@@ -809,33 +840,35 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/blogs/{}/articles/count.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -856,20 +889,23 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_get_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            crate::progenitor_support::encode_path(article_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/blogs/{}/articles/{}/json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -889,10 +925,12 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_update_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -918,9 +956,11 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_delete_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -959,15 +999,17 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/articles/tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/articles/tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1004,7 +1046,7 @@ impl OnlineStore {
     */
     pub async fn get_blogs_param_blog_article(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         limit: &str,
         since_id: &str,
         created_at_min: &str,
@@ -1019,51 +1061,53 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1082,9 +1126,10 @@ impl OnlineStore {
     */
     pub async fn create_blogs_param_blog_articles(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/blogs/{}/articles.json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1120,7 +1165,7 @@ impl OnlineStore {
     */
     pub async fn get_blogs_param_blog_articles_count(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
@@ -1129,33 +1174,35 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/blogs/{}/articles/count.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1176,20 +1223,23 @@ impl OnlineStore {
     */
     pub async fn get_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            crate::progenitor_support::encode_path(article_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/blogs/{}/articles/{}/json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1209,10 +1259,12 @@ impl OnlineStore {
     */
     pub async fn update_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1238,9 +1290,11 @@ impl OnlineStore {
     */
     pub async fn delete_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1275,15 +1329,17 @@ impl OnlineStore {
     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
     */
     pub async fn get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/articles/tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/articles/tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1320,7 +1376,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_get_blogs_param_blog_article(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         limit: &str,
         since_id: &str,
         created_at_min: &str,
@@ -1335,51 +1391,53 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1398,9 +1456,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_create_blogs_param_blog_articles(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/blogs/{}/articles.json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1436,7 +1495,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_get_blogs_param_blog_articles_count(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
@@ -1445,33 +1504,35 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/blogs/{}/articles/count.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1492,20 +1553,23 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_get_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            crate::progenitor_support::encode_path(article_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/blogs/{}/articles/{}/json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1525,10 +1589,12 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_update_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1554,9 +1620,11 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_delete_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1595,15 +1663,17 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/articles/tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/articles/tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1640,7 +1710,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_get_blogs_param_blog_article(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         limit: &str,
         since_id: &str,
         created_at_min: &str,
@@ -1655,51 +1725,53 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1718,9 +1790,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_create_blogs_param_blog_articles(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/unstable/blogs/{}/articles.json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1756,7 +1829,7 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_get_blogs_param_blog_articles_count(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
@@ -1765,33 +1838,35 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/blogs/{}/articles/count.json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1812,20 +1887,23 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_get_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            crate::progenitor_support::encode_path(article_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/blogs/{}/articles/{}/json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1845,10 +1923,12 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_update_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/unstable/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1874,9 +1954,11 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_delete_blogs_param_blog_articles_article(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        blog_id: impl AsRef<str>,
+        article_id: impl AsRef<str>,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
+        let article_id = article_id.as_ref();
         let url = format!(
             "/admin/api/unstable/blogs/{}/articles/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -1915,15 +1997,17 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/articles/tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/articles/tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1945,22 +2029,24 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_themes_param_theme_asset(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1982,23 +2068,25 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_update_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         src: &str,
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2020,18 +2108,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_delete_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -2054,22 +2144,24 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_get_themes_param_theme_asset(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2091,23 +2183,25 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_update_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         src: &str,
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2129,18 +2223,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_delete_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -2163,22 +2259,24 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_get_themes_param_theme_asset(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2200,23 +2298,25 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_update_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         src: &str,
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2238,18 +2338,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_delete_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -2272,22 +2374,24 @@ impl OnlineStore {
     */
     pub async fn get_themes_param_theme_asset(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2309,23 +2413,25 @@ impl OnlineStore {
     */
     pub async fn update_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         src: &str,
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2347,18 +2453,20 @@ impl OnlineStore {
     */
     pub async fn delete_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -2381,22 +2489,24 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_get_themes_param_theme_asset(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2418,23 +2528,25 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_update_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         src: &str,
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2456,18 +2568,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_delete_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -2490,22 +2604,24 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_get_themes_param_theme_asset(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2527,23 +2643,25 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_update_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         src: &str,
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2565,18 +2683,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_delete_themes_param_theme_assets(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -2604,21 +2724,23 @@ impl OnlineStore {
         handle: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/blogs.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/blogs.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2663,18 +2785,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_blogs_param_blog(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let blog_id = blog_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/json?{}",
-            crate::progenitor_support::encode_path(blog_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/blogs/{}/json",
+                crate::progenitor_support::encode_path(blog_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2693,9 +2817,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_update_blogs_param_blog(
         &self,
-        blog_id: &str,
+        blog_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/blogs/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -2717,7 +2842,11 @@ impl OnlineStore {
     *
     * * `blog_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_delete_blogs_param_blog(&self, blog_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_delete_blogs_param_blog(
+        &self,
+        blog_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let blog_id = blog_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/blogs/{}/json",
             crate::progenitor_support::encode_path(blog_id),
@@ -2775,48 +2904,50 @@ impl OnlineStore {
         article_id: i64,
         blog_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if article_id > 0 {
-            query_args.push(("article_id".to_string(), article_id.to_string()));
+            query_args.push(("article_id", article_id.to_string()));
         }
         if blog_id > 0 {
-            query_args.push(("blog_id".to_string(), blog_id.to_string()));
+            query_args.push(("blog_id", blog_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/comments.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/comments.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2877,39 +3008,41 @@ impl OnlineStore {
         article_id: i64,
         blog_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if article_id > 0 {
-            query_args.push(("article_id".to_string(), article_id.to_string()));
+            query_args.push(("article_id", article_id.to_string()));
         }
         if blog_id > 0 {
-            query_args.push(("blog_id".to_string(), blog_id.to_string()));
+            query_args.push(("blog_id", blog_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/comments/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/comments/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2928,18 +3061,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_comments_param_comment(
         &self,
-        comment_id: &str,
+        comment_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let comment_id = comment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/comments/{}/json?{}",
-            crate::progenitor_support::encode_path(comment_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/comments/{}/json",
+                crate::progenitor_support::encode_path(comment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2958,9 +3093,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_update_comments_param_comment(
         &self,
-        comment_id: &str,
+        comment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/comments/{}/json",
             crate::progenitor_support::encode_path(comment_id),
@@ -2984,9 +3120,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_create_comments_param_comment_spam(
         &self,
-        comment_id: &str,
+        comment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/comments/{}/spam.json",
             crate::progenitor_support::encode_path(comment_id),
@@ -3010,9 +3147,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_create_comments_param_comment_not_spam(
         &self,
-        comment_id: &str,
+        comment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/comments/{}/not_spam.json",
             crate::progenitor_support::encode_path(comment_id),
@@ -3036,9 +3174,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_create_comments_param_comment_approve(
         &self,
-        comment_id: &str,
+        comment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/comments/{}/approve.json",
             crate::progenitor_support::encode_path(comment_id),
@@ -3062,9 +3201,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_create_comments_param_comment_remove(
         &self,
-        comment_id: &str,
+        comment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/comments/{}/remove.json",
             crate::progenitor_support::encode_path(comment_id),
@@ -3088,9 +3228,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_create_comments_param_comment_restore(
         &self,
-        comment_id: &str,
+        comment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let comment_id = comment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/comments/{}/restore.json",
             crate::progenitor_support::encode_path(comment_id),
@@ -3125,24 +3266,26 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/redirects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/redirects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3179,15 +3322,17 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/redirects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/redirects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3206,18 +3351,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let redirect_id = redirect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(redirect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/redirects/{}/json",
+                crate::progenitor_support::encode_path(redirect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3236,9 +3383,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_update_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3262,8 +3410,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_delete_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3296,24 +3445,26 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/redirects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/redirects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3350,15 +3501,17 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/redirects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/redirects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3377,18 +3530,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_get_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let redirect_id = redirect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(redirect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/redirects/{}/json",
+                crate::progenitor_support::encode_path(redirect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3407,9 +3562,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_update_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3433,8 +3589,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_delete_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3467,24 +3624,26 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/redirects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/redirects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3521,15 +3680,17 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/redirects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/redirects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3548,18 +3709,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_get_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let redirect_id = redirect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(redirect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/redirects/{}/json",
+                crate::progenitor_support::encode_path(redirect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3578,9 +3741,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_update_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3604,8 +3768,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_delete_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3638,24 +3803,26 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/redirects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/redirects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3688,15 +3855,17 @@ impl OnlineStore {
     * * `target: &str` -- Count redirects with given target.
     */
     pub async fn get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/redirects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/redirects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3715,18 +3884,20 @@ impl OnlineStore {
     */
     pub async fn get_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let redirect_id = redirect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(redirect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/redirects/{}/json",
+                crate::progenitor_support::encode_path(redirect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3745,9 +3916,10 @@ impl OnlineStore {
     */
     pub async fn update_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3769,7 +3941,11 @@ impl OnlineStore {
     *
     * * `redirect_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> Result<()> {
+    pub async fn delete_redirects_param_redirect(
+        &self,
+        redirect_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3802,24 +3978,26 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/redirects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/redirects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3856,15 +4034,17 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/redirects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/redirects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3883,18 +4063,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_get_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let redirect_id = redirect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(redirect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/redirects/{}/json",
+                crate::progenitor_support::encode_path(redirect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3913,9 +4095,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_update_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3939,8 +4122,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_delete_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -3973,24 +4157,26 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/redirects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/redirects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4030,15 +4216,17 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/redirects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/redirects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4057,18 +4245,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_get_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let redirect_id = redirect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(redirect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/redirects/{}/json",
+                crate::progenitor_support::encode_path(redirect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4087,9 +4277,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_update_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/unstable/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -4113,8 +4304,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_delete_redirects_param_redirect(
         &self,
-        redirect_id: &str,
+        redirect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let redirect_id = redirect_id.as_ref();
         let url = format!(
             "/admin/api/unstable/redirects/{}/json",
             crate::progenitor_support::encode_path(redirect_id),
@@ -4153,33 +4345,35 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/script_tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/script_tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4213,12 +4407,14 @@ impl OnlineStore {
     * * `src: &str` -- Count only script tags with a given URL.
     */
     pub async fn deprecated_202001_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/script_tags/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/script_tags/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4237,18 +4433,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let script_tag_id = script_tag_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(script_tag_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/script_tags/{}/json",
+                crate::progenitor_support::encode_path(script_tag_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4267,9 +4465,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_update_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4293,8 +4492,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_delete_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4333,33 +4533,35 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/script_tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/script_tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4393,12 +4595,14 @@ impl OnlineStore {
     * * `src: &str` -- Count only script tags with a given URL.
     */
     pub async fn deprecated_202004_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/script_tags/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/script_tags/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4417,18 +4621,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_get_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let script_tag_id = script_tag_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(script_tag_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/script_tags/{}/json",
+                crate::progenitor_support::encode_path(script_tag_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4447,9 +4653,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_update_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4473,8 +4680,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_delete_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4513,33 +4721,35 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/script_tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/script_tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4573,12 +4783,14 @@ impl OnlineStore {
     * * `src: &str` -- Count only script tags with a given URL.
     */
     pub async fn deprecated_202007_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/script_tags/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/script_tags/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4597,18 +4809,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_get_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let script_tag_id = script_tag_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(script_tag_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/script_tags/{}/json",
+                crate::progenitor_support::encode_path(script_tag_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4627,9 +4841,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_update_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4653,8 +4868,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_delete_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4693,33 +4909,35 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/script_tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/script_tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4750,12 +4968,14 @@ impl OnlineStore {
     * * `src: &str` -- Count only script tags with a given URL.
     */
     pub async fn get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/script_tags/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/script_tags/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4772,16 +4992,22 @@ impl OnlineStore {
     * * `script_tag_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
-    pub async fn get_script_tags_param_tag(&self, script_tag_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_script_tags_param_tag(
+        &self,
+        script_tag_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(script_tag_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/script_tags/{}/json",
+                crate::progenitor_support::encode_path(script_tag_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4800,9 +5026,10 @@ impl OnlineStore {
     */
     pub async fn update_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4824,7 +5051,8 @@ impl OnlineStore {
     *
     * * `script_tag_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> Result<()> {
+    pub async fn delete_script_tags_param_tag(&self, script_tag_id: impl AsRef<str>) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -4863,33 +5091,35 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/script_tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/script_tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4923,12 +5153,14 @@ impl OnlineStore {
     * * `src: &str` -- Count only script tags with a given URL.
     */
     pub async fn deprecated_202101_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/script_tags/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/script_tags/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4947,18 +5179,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_get_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let script_tag_id = script_tag_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(script_tag_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/script_tags/{}/json",
+                crate::progenitor_support::encode_path(script_tag_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4977,9 +5211,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_update_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -5003,8 +5238,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_delete_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -5043,33 +5279,35 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/script_tags.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/script_tags.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5103,12 +5341,14 @@ impl OnlineStore {
     * * `src: &str` -- Count only script tags with a given URL.
     */
     pub async fn deprecated_unstable_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/script_tags/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/script_tags/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5127,18 +5367,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_get_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let script_tag_id = script_tag_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(script_tag_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/script_tags/{}/json",
+                crate::progenitor_support::encode_path(script_tag_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5157,9 +5399,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_update_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/unstable/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -5183,8 +5426,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_delete_script_tags_param_tag(
         &self,
-        script_tag_id: &str,
+        script_tag_id: impl AsRef<str>,
     ) -> Result<()> {
+        let script_tag_id = script_tag_id.as_ref();
         let url = format!(
             "/admin/api/unstable/script_tags/{}/json",
             crate::progenitor_support::encode_path(script_tag_id),
@@ -5205,12 +5449,14 @@ impl OnlineStore {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202001_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/themes.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/themes.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5246,18 +5492,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_get_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/themes/{}/json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5276,9 +5524,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202001_update_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5300,7 +5549,11 @@ impl OnlineStore {
     *
     * * `theme_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_delete_themes_param_theme(
+        &self,
+        theme_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5321,12 +5574,14 @@ impl OnlineStore {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202004_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/themes.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/themes.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5362,18 +5617,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_get_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/themes/{}/json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5392,9 +5649,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202004_update_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5416,7 +5674,11 @@ impl OnlineStore {
     *
     * * `theme_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_delete_themes_param_theme(
+        &self,
+        theme_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5437,12 +5699,14 @@ impl OnlineStore {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202007_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/themes.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/themes.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5478,18 +5742,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_get_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/themes/{}/json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5508,9 +5774,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202007_update_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5532,7 +5799,11 @@ impl OnlineStore {
     *
     * * `theme_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_delete_themes_param_theme(
+        &self,
+        theme_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5553,12 +5824,14 @@ impl OnlineStore {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/themes.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/themes.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5592,16 +5865,22 @@ impl OnlineStore {
     * * `theme_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn get_themes_param_theme(&self, theme_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_themes_param_theme(
+        &self,
+        theme_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/themes/{}/json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5620,9 +5899,10 @@ impl OnlineStore {
     */
     pub async fn update_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5644,7 +5924,8 @@ impl OnlineStore {
     *
     * * `theme_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+    pub async fn delete_themes_param_theme(&self, theme_id: impl AsRef<str>) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5665,12 +5946,14 @@ impl OnlineStore {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202101_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/themes.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/themes.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5706,18 +5989,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_get_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/themes/{}/json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5736,9 +6021,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_202101_update_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5760,7 +6046,11 @@ impl OnlineStore {
     *
     * * `theme_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_delete_themes_param_theme(
+        &self,
+        theme_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5781,12 +6071,14 @@ impl OnlineStore {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_unstable_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/themes.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/themes.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5822,18 +6114,20 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_get_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let theme_id = theme_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(theme_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/themes/{}/json",
+                crate::progenitor_support::encode_path(theme_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5852,9 +6146,10 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_update_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/unstable/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -5878,8 +6173,9 @@ impl OnlineStore {
     */
     pub async fn deprecated_unstable_delete_themes_param_theme(
         &self,
-        theme_id: &str,
+        theme_id: impl AsRef<str>,
     ) -> Result<()> {
+        let theme_id = theme_id.as_ref();
         let url = format!(
             "/admin/api/unstable/themes/{}/json",
             crate::progenitor_support::encode_path(theme_id),
@@ -41,27 +41,29 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/checkouts/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/checkouts/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -95,27 +97,29 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/checkouts/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/checkouts/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -149,27 +153,29 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/checkouts/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/checkouts/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -203,27 +209,29 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/checkouts/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/checkouts/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -257,27 +265,29 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/checkouts/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/checkouts/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -311,27 +321,29 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/checkouts/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/checkouts/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -402,57 +414,53 @@ impl Orders {
         fulfillment_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !attribution_app_id.is_empty() {
-            query_args.push((
-                "attribution_app_id".to_string(),
-                attribution_app_id.to_string(),
-            ));
+            query_args.push(("attribution_app_id", attribution_app_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/orders.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/orders.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -506,16 +514,22 @@ impl Orders {
     * * `order_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
     */
-    pub async fn deprecated_202001_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202001_get_param(
+        &self,
+        order_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -534,9 +548,10 @@ impl Orders {
     */
     pub async fn deprecated_202001_update_param(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -558,7 +573,8 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_delete_param(&self, order_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_delete_param(&self, order_id: impl AsRef<str>) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -614,33 +630,32 @@ impl Orders {
         financial_status: &str,
         fulfillment_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/orders/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/orders/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -658,9 +673,10 @@ impl Orders {
     */
     pub async fn deprecated_202001_create_param_close(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/close.json",
             crate::progenitor_support::encode_path(order_id),
@@ -684,9 +700,10 @@ impl Orders {
     */
     pub async fn deprecated_202001_create_param_open(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -722,7 +739,7 @@ impl Orders {
     */
     pub async fn deprecated_202001_create_param_cancel(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         amount: &str,
         currency: &str,
         restock_deprecated: &str,
@@ -731,35 +748,35 @@ impl Orders {
         refund: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount.is_empty() {
-            query_args.push(("amount".to_string(), amount.to_string()));
+            query_args.push(("amount", amount.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !reason.is_empty() {
-            query_args.push(("reason".to_string(), reason.to_string()));
+            query_args.push(("reason", reason.to_string()));
         }
         if !refund.is_empty() {
-            query_args.push(("refund".to_string(), refund.to_string()));
+            query_args.push(("refund", refund.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/cancel.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/cancel.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -833,57 +850,53 @@ impl Orders {
         fulfillment_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !attribution_app_id.is_empty() {
-            query_args.push((
-                "attribution_app_id".to_string(),
-                attribution_app_id.to_string(),
-            ));
+            query_args.push(("attribution_app_id", attribution_app_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/orders.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/orders.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -937,16 +950,22 @@ impl Orders {
     * * `order_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
     */
-    pub async fn deprecated_202004_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202004_get_param(
+        &self,
+        order_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -965,9 +984,10 @@ impl Orders {
     */
     pub async fn deprecated_202004_update_param(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -989,7 +1009,8 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_delete_param(&self, order_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_delete_param(&self, order_id: impl AsRef<str>) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1045,33 +1066,32 @@ impl Orders {
         financial_status: &str,
         fulfillment_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/orders/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/orders/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1089,9 +1109,10 @@ impl Orders {
     */
     pub async fn deprecated_202004_create_param_close(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/close.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1115,9 +1136,10 @@ impl Orders {
     */
     pub async fn deprecated_202004_create_param_open(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1153,7 +1175,7 @@ impl Orders {
     */
     pub async fn deprecated_202004_create_param_cancel(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         amount: &str,
         currency: &str,
         restock_deprecated: &str,
@@ -1162,35 +1184,35 @@ impl Orders {
         refund: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount.is_empty() {
-            query_args.push(("amount".to_string(), amount.to_string()));
+            query_args.push(("amount", amount.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !reason.is_empty() {
-            query_args.push(("reason".to_string(), reason.to_string()));
+            query_args.push(("reason", reason.to_string()));
         }
         if !refund.is_empty() {
-            query_args.push(("refund".to_string(), refund.to_string()));
+            query_args.push(("refund", refund.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/cancel.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/cancel.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1264,57 +1286,53 @@ impl Orders {
         fulfillment_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !attribution_app_id.is_empty() {
-            query_args.push((
-                "attribution_app_id".to_string(),
-                attribution_app_id.to_string(),
-            ));
+            query_args.push(("attribution_app_id", attribution_app_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/orders.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/orders.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1330,7 +1348,8 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_get_param_risk(&self, order_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_param_risk(&self, order_id: impl AsRef<str>) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1352,9 +1371,10 @@ impl Orders {
     */
     pub async fn deprecated_202001_create_param_risks(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1379,9 +1399,11 @@ impl Orders {
     */
     pub async fn deprecated_202001_get_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1409,10 +1431,12 @@ impl Orders {
     */
     pub async fn deprecated_202001_update_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1442,9 +1466,11 @@ impl Orders {
     */
     pub async fn deprecated_202001_delete_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1465,7 +1491,8 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_get_param_risk(&self, order_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_param_risk(&self, order_id: impl AsRef<str>) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1487,9 +1514,10 @@ impl Orders {
     */
     pub async fn deprecated_202004_create_param_risks(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1514,9 +1542,11 @@ impl Orders {
     */
     pub async fn deprecated_202004_get_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1544,10 +1574,12 @@ impl Orders {
     */
     pub async fn deprecated_202004_update_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1577,9 +1609,11 @@ impl Orders {
     */
     pub async fn deprecated_202004_delete_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1600,7 +1634,8 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_get_param_risk(&self, order_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_param_risk(&self, order_id: impl AsRef<str>) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1622,9 +1657,10 @@ impl Orders {
     */
     pub async fn deprecated_202007_create_param_risks(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1649,9 +1685,11 @@ impl Orders {
     */
     pub async fn deprecated_202007_get_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1679,10 +1717,12 @@ impl Orders {
     */
     pub async fn deprecated_202007_update_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1712,9 +1752,11 @@ impl Orders {
     */
     pub async fn deprecated_202007_delete_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1735,7 +1777,8 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_param_risk(&self, order_id: &str) -> Result<()> {
+    pub async fn get_param_risk(&self, order_id: impl AsRef<str>) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1755,7 +1798,12 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn create_param_risks(&self, order_id: &str, body: &serde_json::Value) -> Result<()> {
+    pub async fn create_param_risks(
+        &self,
+        order_id: impl AsRef<str>,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1778,7 +1826,13 @@ impl Orders {
     * * `order_id: &str` -- storefront_access_token_id.
     * * `risk_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
+    pub async fn get_param_risks_risk(
+        &self,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1806,10 +1860,12 @@ impl Orders {
     */
     pub async fn update_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1837,7 +1893,13 @@ impl Orders {
     * * `order_id: &str` -- storefront_access_token_id.
     * * `risk_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
+    pub async fn delete_param_risks_risk(
+        &self,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1858,7 +1920,8 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_get_param_risk(&self, order_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_param_risk(&self, order_id: impl AsRef<str>) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1880,9 +1943,10 @@ impl Orders {
     */
     pub async fn deprecated_202101_create_param_risks(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1907,9 +1971,11 @@ impl Orders {
     */
     pub async fn deprecated_202101_get_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1937,10 +2003,12 @@ impl Orders {
     */
     pub async fn deprecated_202101_update_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1970,9 +2038,11 @@ impl Orders {
     */
     pub async fn deprecated_202101_delete_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1993,7 +2063,11 @@ impl Orders {
     *
     * * `order_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_get_param_risk(&self, order_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_param_risk(
+        &self,
+        order_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2015,9 +2089,10 @@ impl Orders {
     */
     pub async fn deprecated_unstable_create_param_risks(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/risks.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2042,9 +2117,11 @@ impl Orders {
     */
     pub async fn deprecated_unstable_get_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -2072,10 +2149,12 @@ impl Orders {
     */
     pub async fn deprecated_unstable_update_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -2105,9 +2184,11 @@ impl Orders {
     */
     pub async fn deprecated_unstable_delete_param_risks_risk(
         &self,
-        order_id: &str,
-        risk_id: &str,
+        order_id: impl AsRef<str>,
+        risk_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let risk_id = risk_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/risks/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -2135,26 +2216,28 @@ impl Orders {
     */
     pub async fn deprecated_202001_get_param_refund(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         limit: &str,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2212,7 +2295,7 @@ impl Orders {
     */
     pub async fn deprecated_202001_create_param_refunds(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         restock_deprecated: &str,
         notify: &str,
         note: &str,
@@ -2223,47 +2306,41 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
+            query_args.push(("transactions", transactions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2288,24 +2365,27 @@ impl Orders {
     */
     pub async fn deprecated_202001_get_param_refunds_refund(
         &self,
-        order_id: &str,
-        refund_id: &str,
+        order_id: impl AsRef<str>,
+        refund_id: impl AsRef<str>,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let refund_id = refund_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(refund_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/refunds/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2361,30 +2441,29 @@ impl Orders {
     */
     pub async fn deprecated_202001_create_param_refunds_calculate(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         shipping: &str,
         refund_line_items: &str,
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/refunds/calculate.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2410,26 +2489,28 @@ impl Orders {
     */
     pub async fn deprecated_202004_get_param_refund(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         limit: &str,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2487,7 +2568,7 @@ impl Orders {
     */
     pub async fn deprecated_202004_create_param_refunds(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         restock_deprecated: &str,
         notify: &str,
         note: &str,
@@ -2498,47 +2579,41 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
+            query_args.push(("transactions", transactions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2563,24 +2638,27 @@ impl Orders {
     */
     pub async fn deprecated_202004_get_param_refunds_refund(
         &self,
-        order_id: &str,
-        refund_id: &str,
+        order_id: impl AsRef<str>,
+        refund_id: impl AsRef<str>,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let refund_id = refund_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(refund_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/refunds/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2636,30 +2714,29 @@ impl Orders {
     */
     pub async fn deprecated_202004_create_param_refunds_calculate(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         shipping: &str,
         refund_line_items: &str,
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/refunds/calculate.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2685,26 +2762,28 @@ impl Orders {
     */
     pub async fn deprecated_202007_get_param_refund(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         limit: &str,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2762,7 +2841,7 @@ impl Orders {
     */
     pub async fn deprecated_202007_create_param_refunds(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         restock_deprecated: &str,
         notify: &str,
         note: &str,
@@ -2773,47 +2852,41 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
+            query_args.push(("transactions", transactions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2838,24 +2911,27 @@ impl Orders {
     */
     pub async fn deprecated_202007_get_param_refunds_refund(
         &self,
-        order_id: &str,
-        refund_id: &str,
+        order_id: impl AsRef<str>,
+        refund_id: impl AsRef<str>,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let refund_id = refund_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(refund_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/orders/{}/refunds/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2911,30 +2987,29 @@ impl Orders {
     */
     pub async fn deprecated_202007_create_param_refunds_calculate(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         shipping: &str,
         refund_line_items: &str,
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/orders/{}/refunds/calculate.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2960,26 +3035,28 @@ impl Orders {
     */
     pub async fn get_param_refund(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         limit: &str,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3037,7 +3114,7 @@ impl Orders {
     */
     pub async fn create_param_refunds(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         restock_deprecated: &str,
         notify: &str,
         note: &str,
@@ -3048,47 +3125,41 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
+            query_args.push(("transactions", transactions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -3113,24 +3184,27 @@ impl Orders {
     */
     pub async fn get_param_refunds_refund(
         &self,
-        order_id: &str,
-        refund_id: &str,
+        order_id: impl AsRef<str>,
+        refund_id: impl AsRef<str>,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let refund_id = refund_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(refund_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/orders/{}/refunds/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3186,30 +3260,29 @@ impl Orders {
     */
     pub async fn create_param_refunds_calculate(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         shipping: &str,
         refund_line_items: &str,
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/orders/{}/refunds/calculate.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -3235,26 +3308,28 @@ impl Orders {
     */
     pub async fn deprecated_202101_get_param_refund(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         limit: &str,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3312,7 +3387,7 @@ impl Orders {
     */
     pub async fn deprecated_202101_create_param_refunds(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         restock_deprecated: &str,
         notify: &str,
         note: &str,
@@ -3323,47 +3398,41 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
+            query_args.push(("transactions", transactions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -3388,24 +3457,27 @@ impl Orders {
     */
     pub async fn deprecated_202101_get_param_refunds_refund(
         &self,
-        order_id: &str,
-        refund_id: &str,
+        order_id: impl AsRef<str>,
+        refund_id: impl AsRef<str>,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let refund_id = refund_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(refund_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/orders/{}/refunds/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3461,30 +3533,29 @@ impl Orders {
     */
     pub async fn deprecated_202101_create_param_refunds_calculate(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         shipping: &str,
         refund_line_items: &str,
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/orders/{}/refunds/calculate.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -3510,26 +3581,28 @@ impl Orders {
     */
     pub async fn deprecated_unstable_get_param_refund(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         limit: &str,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3587,7 +3660,7 @@ impl Orders {
     */
     pub async fn deprecated_unstable_create_param_refunds(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         restock_deprecated: &str,
         notify: &str,
         note: &str,
@@ -3598,47 +3671,41 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
-                "restock
-                  deprecated"
-                    .to_string(),
+                "restock deprecated".to_string(),
                 restock_deprecated.to_string(),
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
+            query_args.push(("transactions", transactions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -3663,24 +3730,27 @@ impl Orders {
     */
     pub async fn deprecated_unstable_get_param_refunds_refund(
         &self,
-        order_id: &str,
-        refund_id: &str,
+        order_id: impl AsRef<str>,
+        refund_id: impl AsRef<str>,
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let refund_id = refund_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(refund_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/orders/{}/refunds/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3736,30 +3806,29 @@ impl Orders {
     */
     pub async fn deprecated_unstable_create_param_refunds_calculate(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         shipping: &str,
         refund_line_items: &str,
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+            query_args.push(("shipping", shipping.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/orders/{}/refunds/calculate.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -37,21 +37,23 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/gift_cards.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/gift_cards.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -86,8 +88,9 @@ impl Plus {
     */
     pub async fn deprecated_202001_get_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -110,9 +113,10 @@ impl Plus {
     */
     pub async fn deprecated_202001_update_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -138,12 +142,14 @@ impl Plus {
     *                           disabled: Count only disabled gift cards.
     */
     pub async fn deprecated_202001_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/gift_cards/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/gift_cards/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -161,9 +167,10 @@ impl Plus {
     */
     pub async fn deprecated_202001_create_gift_cards_param_card_disable(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/gift_cards/{}/disable.json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -208,21 +215,23 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/gift_cards/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/gift_cards/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -252,21 +261,23 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/gift_cards.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/gift_cards.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -301,8 +312,9 @@ impl Plus {
     */
     pub async fn deprecated_202004_get_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -325,9 +337,10 @@ impl Plus {
     */
     pub async fn deprecated_202004_update_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -353,12 +366,14 @@ impl Plus {
     *                           disabled: Count only disabled gift cards.
     */
     pub async fn deprecated_202004_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/gift_cards/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/gift_cards/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -376,9 +391,10 @@ impl Plus {
     */
     pub async fn deprecated_202004_create_gift_cards_param_card_disable(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/gift_cards/{}/disable.json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -423,21 +439,23 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/gift_cards/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/gift_cards/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -467,21 +485,23 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/gift_cards.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/gift_cards.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -516,8 +536,9 @@ impl Plus {
     */
     pub async fn deprecated_202007_get_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -540,9 +561,10 @@ impl Plus {
     */
     pub async fn deprecated_202007_update_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -568,12 +590,14 @@ impl Plus {
     *                           disabled: Count only disabled gift cards.
     */
     pub async fn deprecated_202007_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/gift_cards/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/gift_cards/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -591,9 +615,10 @@ impl Plus {
     */
     pub async fn deprecated_202007_create_gift_cards_param_card_disable(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/gift_cards/{}/disable.json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -638,21 +663,23 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/gift_cards/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/gift_cards/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -682,21 +709,23 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/gift_cards.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/gift_cards.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -726,7 +755,8 @@ impl Plus {
     *
     * * `gift_card_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+    pub async fn get_gift_cards_param_card(&self, gift_card_id: impl AsRef<str>) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -749,9 +779,10 @@ impl Plus {
     */
     pub async fn update_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -777,12 +808,14 @@ impl Plus {
     *                           disabled: Count only disabled gift cards.
     */
     pub async fn get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/gift_cards/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/gift_cards/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -800,9 +833,10 @@ impl Plus {
     */
     pub async fn create_gift_cards_param_card_disable(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/gift_cards/{}/disable.json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -847,21 +881,23 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/gift_cards/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/gift_cards/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -891,21 +927,23 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/gift_cards.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/gift_cards.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -940,8 +978,9 @@ impl Plus {
     */
     pub async fn deprecated_202101_get_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -964,9 +1003,10 @@ impl Plus {
     */
     pub async fn deprecated_202101_update_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -992,12 +1032,14 @@ impl Plus {
     *                           disabled: Count only disabled gift cards.
     */
     pub async fn deprecated_202101_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/gift_cards/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/gift_cards/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1015,9 +1057,10 @@ impl Plus {
     */
     pub async fn deprecated_202101_create_gift_cards_param_card_disable(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/gift_cards/{}/disable.json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -1062,21 +1105,23 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/gift_cards/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/gift_cards/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1106,21 +1151,23 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/gift_cards.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/gift_cards.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1155,8 +1202,9 @@ impl Plus {
     */
     pub async fn deprecated_unstable_get_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/unstable/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -1179,9 +1227,10 @@ impl Plus {
     */
     pub async fn deprecated_unstable_update_gift_cards_param_card(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/unstable/gift_cards/{}/json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -1207,12 +1256,14 @@ impl Plus {
     *                           disabled: Count only disabled gift cards.
     */
     pub async fn deprecated_unstable_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/gift_cards/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/gift_cards/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1230,9 +1281,10 @@ impl Plus {
     */
     pub async fn deprecated_unstable_create_gift_cards_param_card_disable(
         &self,
-        gift_card_id: &str,
+        gift_card_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let gift_card_id = gift_card_id.as_ref();
         let url = format!(
             "/admin/api/unstable/gift_cards/{}/disable.json",
             crate::progenitor_support::encode_path(gift_card_id),
@@ -1277,21 +1329,23 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/gift_cards/search.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/gift_cards/search.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1310,15 +1364,17 @@ impl Plus {
     * * `page_info: &str` -- A unique ID used to access a certain page of results.
     */
     pub async fn deprecated_202001_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/users.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/users.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1334,7 +1390,11 @@ impl Plus {
     *
     * * `user_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_get_users_param_user(&self, user_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_users_param_user(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/users/{}/json",
             crate::progenitor_support::encode_path(user_id),
@@ -1369,15 +1429,17 @@ impl Plus {
     * * `page_info: &str` -- A unique ID used to access a certain page of results.
     */
     pub async fn deprecated_202004_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/users.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/users.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1393,7 +1455,11 @@ impl Plus {
     *
     * * `user_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_get_users_param_user(&self, user_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_users_param_user(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/users/{}/json",
             crate::progenitor_support::encode_path(user_id),
@@ -1428,15 +1494,17 @@ impl Plus {
     * * `page_info: &str` -- A unique ID used to access a certain page of results.
     */
     pub async fn deprecated_202007_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/users.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/users.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1452,7 +1520,11 @@ impl Plus {
     *
     * * `user_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_get_users_param_user(&self, user_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_users_param_user(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/users/{}/json",
             crate::progenitor_support::encode_path(user_id),
@@ -1487,15 +1559,17 @@ impl Plus {
     * * `page_info: &str` -- A unique ID used to access a certain page of results.
     */
     pub async fn get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/users.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/users.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1511,7 +1585,8 @@ impl Plus {
     *
     * * `user_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_users_param_user(&self, user_id: &str) -> Result<()> {
+    pub async fn get_users_param_user(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/users/{}/json",
             crate::progenitor_support::encode_path(user_id),
@@ -1546,15 +1621,17 @@ impl Plus {
     * * `page_info: &str` -- A unique ID used to access a certain page of results.
     */
     pub async fn deprecated_202101_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/users.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/users.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1570,7 +1647,11 @@ impl Plus {
     *
     * * `user_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_get_users_param_user(&self, user_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_users_param_user(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/users/{}/json",
             crate::progenitor_support::encode_path(user_id),
@@ -1605,15 +1686,17 @@ impl Plus {
     * * `page_info: &str` -- A unique ID used to access a certain page of results.
     */
     pub async fn deprecated_unstable_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/users.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/users.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1629,7 +1712,11 @@ impl Plus {
     *
     * * `user_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_get_users_param_user(&self, user_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_users_param_user(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/admin/api/unstable/users/{}/json",
             crate::progenitor_support::encode_path(user_id),
@@ -32,18 +32,20 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/collects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/collects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -76,18 +78,20 @@ impl Products {
     */
     pub async fn deprecated_202001_get_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collect_id = collect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(collect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/collects/{}/json",
+                crate::progenitor_support::encode_path(collect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -106,8 +110,9 @@ impl Products {
     */
     pub async fn deprecated_202001_delete_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collect_id = collect_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/collects/{}/json",
             crate::progenitor_support::encode_path(collect_id),
@@ -128,12 +133,14 @@ impl Products {
     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202001_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/collects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/collects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -158,18 +165,20 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/collects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/collects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -202,18 +211,20 @@ impl Products {
     */
     pub async fn deprecated_202004_get_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collect_id = collect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(collect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/collects/{}/json",
+                crate::progenitor_support::encode_path(collect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -232,8 +243,9 @@ impl Products {
     */
     pub async fn deprecated_202004_delete_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collect_id = collect_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/collects/{}/json",
             crate::progenitor_support::encode_path(collect_id),
@@ -254,12 +266,14 @@ impl Products {
     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202004_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/collects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/collects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -284,18 +298,20 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/collects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/collects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -328,18 +344,20 @@ impl Products {
     */
     pub async fn deprecated_202007_get_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collect_id = collect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(collect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/collects/{}/json",
+                crate::progenitor_support::encode_path(collect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -358,8 +376,9 @@ impl Products {
     */
     pub async fn deprecated_202007_delete_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collect_id = collect_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/collects/{}/json",
             crate::progenitor_support::encode_path(collect_id),
@@ -380,12 +399,14 @@ impl Products {
     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202007_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/collects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/collects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -405,18 +426,20 @@ impl Products {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn get_collect(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/collects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/collects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -447,16 +470,22 @@ impl Products {
     * * `collect_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn get_collects_param_collect(&self, collect_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_collects_param_collect(
+        &self,
+        collect_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let collect_id = collect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(collect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/collects/{}/json",
+                crate::progenitor_support::encode_path(collect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -473,7 +502,8 @@ impl Products {
     *
     * * `collect_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_collects_param_collect(&self, collect_id: &str) -> Result<()> {
+    pub async fn delete_collects_param_collect(&self, collect_id: impl AsRef<str>) -> Result<()> {
+        let collect_id = collect_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/collects/{}/json",
             crate::progenitor_support::encode_path(collect_id),
@@ -494,12 +524,14 @@ impl Products {
     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/collects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/collects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -524,18 +556,20 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/collects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/collects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -568,18 +602,20 @@ impl Products {
     */
     pub async fn deprecated_202101_get_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collect_id = collect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(collect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/collects/{}/json",
+                crate::progenitor_support::encode_path(collect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -598,8 +634,9 @@ impl Products {
     */
     pub async fn deprecated_202101_delete_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collect_id = collect_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/collects/{}/json",
             crate::progenitor_support::encode_path(collect_id),
@@ -620,12 +657,14 @@ impl Products {
     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202101_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/collects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/collects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -650,18 +689,20 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/collects.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/collects.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -697,18 +738,20 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collect_id = collect_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(collect_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/collects/{}/json",
+                crate::progenitor_support::encode_path(collect_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -727,8 +770,9 @@ impl Products {
     */
     pub async fn deprecated_unstable_delete_collects_param_collect(
         &self,
-        collect_id: &str,
+        collect_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collect_id = collect_id.as_ref();
         let url = format!(
             "/admin/api/unstable/collects/{}/json",
             crate::progenitor_support::encode_path(collect_id),
@@ -749,12 +793,14 @@ impl Products {
     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_unstable_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/collects/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/collects/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -773,18 +819,20 @@ impl Products {
     */
     pub async fn deprecated_202001_get_collections_param_collection(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/collections/{}/json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -805,18 +853,20 @@ impl Products {
     */
     pub async fn deprecated_202001_get_collections_param_collection_products(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -836,18 +886,20 @@ impl Products {
     */
     pub async fn deprecated_202004_get_collections_param_collection(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/collections/{}/json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -868,18 +920,20 @@ impl Products {
     */
     pub async fn deprecated_202004_get_collections_param_collection_products(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -899,18 +953,20 @@ impl Products {
     */
     pub async fn deprecated_202007_get_collections_param_collection(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/collections/{}/json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -931,18 +987,20 @@ impl Products {
     */
     pub async fn deprecated_202007_get_collections_param_collection_products(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -962,18 +1020,20 @@ impl Products {
     */
     pub async fn get_collections_param_collection(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/collections/{}/json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -994,18 +1054,20 @@ impl Products {
     */
     pub async fn get_collections_param_collection_products(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1025,18 +1087,20 @@ impl Products {
     */
     pub async fn deprecated_202101_get_collections_param_collection(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/collections/{}/json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1057,18 +1121,20 @@ impl Products {
     */
     pub async fn deprecated_202101_get_collections_param_collection_products(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1088,18 +1154,20 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_collections_param_collection(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/collections/{}/json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1120,18 +1188,20 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_collections_param_collection_products(
         &self,
-        collection_id: &str,
+        collection_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_id = collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(collection_id),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1180,45 +1250,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/custom_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/custom_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1272,32 +1344,31 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/custom_collections/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/custom_collections/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1317,18 +1388,20 @@ impl Products {
     */
     pub async fn deprecated_202001_get_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let custom_collection_id = custom_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(custom_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/custom_collections/{}/json",
+                crate::progenitor_support::encode_path(custom_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1347,9 +1420,10 @@ impl Products {
     */
     pub async fn deprecated_202001_update_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -1373,8 +1447,9 @@ impl Products {
     */
     pub async fn deprecated_202001_delete_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -1426,45 +1501,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/custom_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/custom_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1518,32 +1595,31 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/custom_collections/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/custom_collections/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1563,18 +1639,20 @@ impl Products {
     */
     pub async fn deprecated_202004_get_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let custom_collection_id = custom_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(custom_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/custom_collections/{}/json",
+                crate::progenitor_support::encode_path(custom_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1593,9 +1671,10 @@ impl Products {
     */
     pub async fn deprecated_202004_update_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -1619,8 +1698,9 @@ impl Products {
     */
     pub async fn deprecated_202004_delete_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -1672,45 +1752,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/custom_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/custom_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1764,32 +1846,31 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/custom_collections/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/custom_collections/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1809,18 +1890,20 @@ impl Products {
     */
     pub async fn deprecated_202007_get_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let custom_collection_id = custom_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(custom_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/custom_collections/{}/json",
+                crate::progenitor_support::encode_path(custom_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1839,9 +1922,10 @@ impl Products {
     */
     pub async fn deprecated_202007_update_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -1865,8 +1949,9 @@ impl Products {
     */
     pub async fn deprecated_202007_delete_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -1918,45 +2003,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/custom_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/custom_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2007,32 +2094,31 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/custom_collections/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/custom_collections/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2052,18 +2138,20 @@ impl Products {
     */
     pub async fn get_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let custom_collection_id = custom_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(custom_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/custom_collections/{}/json",
+                crate::progenitor_support::encode_path(custom_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2082,9 +2170,10 @@ impl Products {
     */
     pub async fn update_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -2108,8 +2197,9 @@ impl Products {
     */
     pub async fn delete_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -2161,45 +2251,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/custom_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/custom_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2253,32 +2345,31 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/custom_collections/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/custom_collections/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2298,18 +2389,20 @@ impl Products {
     */
     pub async fn deprecated_202101_get_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let custom_collection_id = custom_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(custom_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/custom_collections/{}/json",
+                crate::progenitor_support::encode_path(custom_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2328,9 +2421,10 @@ impl Products {
     */
     pub async fn deprecated_202101_update_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -2354,8 +2448,9 @@ impl Products {
     */
     pub async fn deprecated_202101_delete_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -2407,45 +2502,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/custom_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/custom_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2499,32 +2596,31 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/custom_collections/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/custom_collections/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2544,18 +2640,20 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let custom_collection_id = custom_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(custom_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/custom_collections/{}/json",
+                crate::progenitor_support::encode_path(custom_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2574,9 +2672,10 @@ impl Products {
     */
     pub async fn deprecated_unstable_update_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/unstable/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -2600,8 +2699,9 @@ impl Products {
     */
     pub async fn deprecated_unstable_delete_custom_collections_param_collection(
         &self,
-        custom_collection_id: &str,
+        custom_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let custom_collection_id = custom_collection_id.as_ref();
         let url = format!(
             "/admin/api/unstable/custom_collections/{}/json",
             crate::progenitor_support::encode_path(custom_collection_id),
@@ -2670,66 +2770,65 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/products.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/products.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2790,39 +2889,41 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/products/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/products/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2839,16 +2940,22 @@ impl Products {
     * * `product_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
-    pub async fn deprecated_202001_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202001_get_param(
+        &self,
+        product_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/products/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2871,9 +2978,10 @@ impl Products {
     */
     pub async fn deprecated_202001_update_param(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -2895,7 +3003,8 @@ impl Products {
     *
     * * `product_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_delete_param(&self, product_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_delete_param(&self, product_id: impl AsRef<str>) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -2964,66 +3073,65 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/products.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/products.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3084,39 +3192,41 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/products/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/products/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3133,16 +3243,22 @@ impl Products {
     * * `product_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
-    pub async fn deprecated_202004_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202004_get_param(
+        &self,
+        product_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/products/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3165,9 +3281,10 @@ impl Products {
     */
     pub async fn deprecated_202004_update_param(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -3189,7 +3306,8 @@ impl Products {
     *
     * * `product_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_delete_param(&self, product_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_delete_param(&self, product_id: impl AsRef<str>) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -3258,66 +3376,65 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/products.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/products.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3378,39 +3495,41 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/products/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/products/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3427,16 +3546,22 @@ impl Products {
     * * `product_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
-    pub async fn deprecated_202007_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202007_get_param(
+        &self,
+        product_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/products/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3459,9 +3584,10 @@ impl Products {
     */
     pub async fn deprecated_202007_update_param(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -3483,7 +3609,8 @@ impl Products {
     *
     * * `product_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_delete_param(&self, product_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_delete_param(&self, product_id: impl AsRef<str>) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -3552,66 +3679,65 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/products.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/products.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3672,39 +3798,41 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/products/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/products/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3721,16 +3849,18 @@ impl Products {
     * * `product_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
-    pub async fn get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_param(&self, product_id: impl AsRef<str>, fields: &str) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/products/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3751,7 +3881,12 @@ impl Products {
     *
     * * `product_id: &str` -- storefront_access_token_id.
     */
-    pub async fn update_param(&self, product_id: &str, body: &serde_json::Value) -> Result<()> {
+    pub async fn update_param(
+        &self,
+        product_id: impl AsRef<str>,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -3773,7 +3908,8 @@ impl Products {
     *
     * * `product_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_param(&self, product_id: &str) -> Result<()> {
+    pub async fn delete_param(&self, product_id: impl AsRef<str>) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -3842,66 +3978,65 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/products.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/products.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -3962,39 +4097,41 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/products/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/products/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4011,16 +4148,22 @@ impl Products {
     * * `product_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
-    pub async fn deprecated_202101_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn deprecated_202101_get_param(
+        &self,
+        product_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/products/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4043,9 +4186,10 @@ impl Products {
     */
     pub async fn deprecated_202101_update_param(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4067,7 +4211,8 @@ impl Products {
     *
     * * `product_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_delete_param(&self, product_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_delete_param(&self, product_id: impl AsRef<str>) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4136,66 +4281,65 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/products.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/products.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4256,39 +4400,41 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/products/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/products/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -4307,18 +4453,20 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_param(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/products/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4341,9 +4489,10 @@ impl Products {
     */
     pub async fn deprecated_unstable_update_param(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/unstable/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4365,7 +4514,11 @@ impl Products {
     *
     * * `product_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_delete_param(&self, product_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_delete_param(
+        &self,
+        product_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/unstable/products/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4389,22 +4542,24 @@ impl Products {
     */
     pub async fn deprecated_202001_get_param_image(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4423,9 +4578,10 @@ impl Products {
     */
     pub async fn deprecated_202001_create_param_images(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/products/{}/images.json",
             crate::progenitor_support::encode_path(product_id),
@@ -4450,18 +4606,20 @@ impl Products {
     */
     pub async fn deprecated_202001_get_param_images_count(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/products/{}/images/count.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4482,20 +4640,23 @@ impl Products {
     */
     pub async fn deprecated_202001_get_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(image_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/products/{}/images/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4515,10 +4676,12 @@ impl Products {
     */
     pub async fn deprecated_202001_update_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4544,9 +4707,11 @@ impl Products {
     */
     pub async fn deprecated_202001_delete_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4571,22 +4736,24 @@ impl Products {
     */
     pub async fn deprecated_202004_get_param_image(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4605,9 +4772,10 @@ impl Products {
     */
     pub async fn deprecated_202004_create_param_images(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/products/{}/images.json",
             crate::progenitor_support::encode_path(product_id),
@@ -4632,18 +4800,20 @@ impl Products {
     */
     pub async fn deprecated_202004_get_param_images_count(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/products/{}/images/count.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4664,20 +4834,23 @@ impl Products {
     */
     pub async fn deprecated_202004_get_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(image_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/products/{}/images/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4697,10 +4870,12 @@ impl Products {
     */
     pub async fn deprecated_202004_update_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4726,9 +4901,11 @@ impl Products {
     */
     pub async fn deprecated_202004_delete_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4753,22 +4930,24 @@ impl Products {
     */
     pub async fn deprecated_202007_get_param_image(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4787,9 +4966,10 @@ impl Products {
     */
     pub async fn deprecated_202007_create_param_images(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/products/{}/images.json",
             crate::progenitor_support::encode_path(product_id),
@@ -4814,18 +4994,20 @@ impl Products {
     */
     pub async fn deprecated_202007_get_param_images_count(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/products/{}/images/count.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4846,20 +5028,23 @@ impl Products {
     */
     pub async fn deprecated_202007_get_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(image_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/products/{}/images/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4879,10 +5064,12 @@ impl Products {
     */
     pub async fn deprecated_202007_update_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4908,9 +5095,11 @@ impl Products {
     */
     pub async fn deprecated_202007_delete_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -4935,22 +5124,24 @@ impl Products {
     */
     pub async fn get_param_image(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -4969,9 +5160,10 @@ impl Products {
     */
     pub async fn create_param_images(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/products/{}/images.json",
             crate::progenitor_support::encode_path(product_id),
@@ -4994,16 +5186,22 @@ impl Products {
     * * `product_id: &str` -- storefront_access_token_id.
     * * `since_id: &str` -- Restrict results to after the specified ID.
     */
-    pub async fn get_param_images_count(&self, product_id: &str, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_param_images_count(
+        &self,
+        product_id: impl AsRef<str>,
+        since_id: &str,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/products/{}/images/count.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5024,20 +5222,23 @@ impl Products {
     */
     pub async fn get_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(image_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/products/{}/images/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5057,10 +5258,12 @@ impl Products {
     */
     pub async fn update_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -5084,7 +5287,13 @@ impl Products {
     * * `product_id: &str` -- storefront_access_token_id.
     * * `image_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_param_images_image(&self, product_id: &str, image_id: &str) -> Result<()> {
+    pub async fn delete_param_images_image(
+        &self,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -5109,22 +5318,24 @@ impl Products {
     */
     pub async fn deprecated_202101_get_param_image(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5143,9 +5354,10 @@ impl Products {
     */
     pub async fn deprecated_202101_create_param_images(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/products/{}/images.json",
             crate::progenitor_support::encode_path(product_id),
@@ -5170,18 +5382,20 @@ impl Products {
     */
     pub async fn deprecated_202101_get_param_images_count(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/products/{}/images/count.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5202,20 +5416,23 @@ impl Products {
     */
     pub async fn deprecated_202101_get_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(image_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/products/{}/images/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5235,10 +5452,12 @@ impl Products {
     */
     pub async fn deprecated_202101_update_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -5264,9 +5483,11 @@ impl Products {
     */
     pub async fn deprecated_202101_delete_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -5291,22 +5512,24 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_param_image(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5325,9 +5548,10 @@ impl Products {
     */
     pub async fn deprecated_unstable_create_param_images(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
         let url = format!(
             "/admin/api/unstable/products/{}/images.json",
             crate::progenitor_support::encode_path(product_id),
@@ -5352,18 +5576,20 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_param_images_count(
         &self,
-        product_id: &str,
+        product_id: impl AsRef<str>,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/products/{}/images/count.json",
+                crate::progenitor_support::encode_path(product_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5384,20 +5610,23 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(product_id),
-            crate::progenitor_support::encode_path(image_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/products/{}/images/{}/json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5417,10 +5646,12 @@ impl Products {
     */
     pub async fn deprecated_unstable_update_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/unstable/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -5446,9 +5677,11 @@ impl Products {
     */
     pub async fn deprecated_unstable_delete_param_images_image(
         &self,
-        product_id: &str,
-        image_id: &str,
+        product_id: impl AsRef<str>,
+        image_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_id = product_id.as_ref();
+        let image_id = image_id.as_ref();
         let url = format!(
             "/admin/api/unstable/products/{}/images/{}/json",
             crate::progenitor_support::encode_path(product_id),
@@ -5501,45 +5734,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/smart_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/smart_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5593,30 +5828,32 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/smart_collections/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/smart_collections/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5635,18 +5872,20 @@ impl Products {
     */
     pub async fn deprecated_202001_get_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/smart_collections/{}/json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5665,9 +5904,10 @@ impl Products {
     */
     pub async fn deprecated_202001_update_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -5691,8 +5931,9 @@ impl Products {
     */
     pub async fn deprecated_202001_delete_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -5718,23 +5959,25 @@ impl Products {
     */
     pub async fn deprecated_202001_update_smart_collections_param_collection_order(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         products: &str,
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/smart_collections/{}/order.json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5785,45 +6028,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/smart_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/smart_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5877,30 +6122,32 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/smart_collections/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/smart_collections/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -5919,18 +6166,20 @@ impl Products {
     */
     pub async fn deprecated_202004_get_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/smart_collections/{}/json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -5949,9 +6198,10 @@ impl Products {
     */
     pub async fn deprecated_202004_update_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -5975,8 +6225,9 @@ impl Products {
     */
     pub async fn deprecated_202004_delete_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -6002,23 +6253,25 @@ impl Products {
     */
     pub async fn deprecated_202004_update_smart_collections_param_collection_order(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         products: &str,
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/smart_collections/{}/order.json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6069,45 +6322,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/smart_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/smart_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6161,30 +6416,32 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/smart_collections/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/smart_collections/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6203,18 +6460,20 @@ impl Products {
     */
     pub async fn deprecated_202007_get_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/smart_collections/{}/json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6233,9 +6492,10 @@ impl Products {
     */
     pub async fn deprecated_202007_update_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -6259,8 +6519,9 @@ impl Products {
     */
     pub async fn deprecated_202007_delete_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -6286,23 +6547,25 @@ impl Products {
     */
     pub async fn deprecated_202007_update_smart_collections_param_collection_order(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         products: &str,
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/smart_collections/{}/order.json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6353,45 +6616,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/smart_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/smart_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6442,30 +6707,32 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/smart_collections/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/smart_collections/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6484,18 +6751,20 @@ impl Products {
     */
     pub async fn get_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/smart_collections/{}/json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6514,9 +6783,10 @@ impl Products {
     */
     pub async fn update_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -6540,8 +6810,9 @@ impl Products {
     */
     pub async fn delete_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -6567,23 +6838,25 @@ impl Products {
     */
     pub async fn update_smart_collections_param_collection_order(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         products: &str,
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/smart_collections/{}/order.json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6634,45 +6907,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/smart_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/smart_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6726,30 +7001,32 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/smart_collections/count.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/smart_collections/count.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6768,18 +7045,20 @@ impl Products {
     */
     pub async fn deprecated_202101_get_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/smart_collections/{}/json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -6798,9 +7077,10 @@ impl Products {
     */
     pub async fn deprecated_202101_update_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -6824,8 +7104,9 @@ impl Products {
     */
     pub async fn deprecated_202101_delete_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -6851,23 +7132,25 @@ impl Products {
     */
     pub async fn deprecated_202101_update_smart_collections_param_collection_order(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         products: &str,
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/smart_collections/{}/order.json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6918,45 +7201,47 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/smart_collections.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/smart_collections.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -7010,32 +7295,31 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/smart_collections/count.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/smart_collections/count.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -7055,18 +7339,20 @@ impl Products {
     */
     pub async fn deprecated_unstable_get_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/smart_collections/{}/json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -7085,9 +7371,10 @@ impl Products {
     */
     pub async fn deprecated_unstable_update_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/unstable/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -7111,8 +7398,9 @@ impl Products {
     */
     pub async fn deprecated_unstable_delete_smart_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
     ) -> Result<()> {
+        let smart_collection_id = smart_collection_id.as_ref();
         let url = format!(
             "/admin/api/unstable/smart_collections/{}/json",
             crate::progenitor_support::encode_path(smart_collection_id),
@@ -7138,23 +7426,25 @@ impl Products {
     */
     pub async fn deprecated_unstable_update_smart_collections_param_collection_order(
         &self,
-        smart_collection_id: &str,
+        smart_collection_id: impl AsRef<str>,
         products: &str,
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let smart_collection_id = smart_collection_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(smart_collection_id),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/smart_collections/{}/order.json",
+                crate::progenitor_support::encode_path(smart_collection_id),
+            ),
+            &query_args,
         );
 
         self.client
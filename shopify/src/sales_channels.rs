@@ -112,9 +112,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_create_checkouts_param_token_complete(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/complete.json",
             crate::progenitor_support::encode_path(token),
@@ -136,7 +137,11 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_checkouts_param_token(
+        &self,
+        token: impl AsRef<str>,
+    ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -158,9 +163,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_update_checkouts_param_token(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -186,8 +192,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_get_checkouts_param_token_shipping_rate(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/shipping_rates.json",
             crate::progenitor_support::encode_path(token),
@@ -209,9 +216,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_create_checkouts_param_token_complete(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/complete.json",
             crate::progenitor_support::encode_path(token),
@@ -233,7 +241,11 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_checkouts_param_token(
+        &self,
+        token: impl AsRef<str>,
+    ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -255,9 +267,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_update_checkouts_param_token(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -283,8 +296,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_get_checkouts_param_token_shipping_rate(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/shipping_rates.json",
             crate::progenitor_support::encode_path(token),
@@ -306,9 +320,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_create_checkouts_param_token_complete(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/complete.json",
             crate::progenitor_support::encode_path(token),
@@ -330,7 +345,11 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_checkouts_param_token(
+        &self,
+        token: impl AsRef<str>,
+    ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -352,9 +371,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_update_checkouts_param_token(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -380,8 +400,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_get_checkouts_param_token_shipping_rate(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/shipping_rates.json",
             crate::progenitor_support::encode_path(token),
@@ -403,9 +424,10 @@ impl SalesChannels {
     */
     pub async fn create_checkouts_param_token_complete(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/complete.json",
             crate::progenitor_support::encode_path(token),
@@ -427,7 +449,8 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn get_checkouts_param_token(&self, token: &str) -> Result<()> {
+    pub async fn get_checkouts_param_token(&self, token: impl AsRef<str>) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -449,9 +472,10 @@ impl SalesChannels {
     */
     pub async fn update_checkouts_param_token(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -475,7 +499,11 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> Result<()> {
+    pub async fn get_checkouts_param_token_shipping_rate(
+        &self,
+        token: impl AsRef<str>,
+    ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/shipping_rates.json",
             crate::progenitor_support::encode_path(token),
@@ -497,9 +525,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_create_checkouts_param_token_complete(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/complete.json",
             crate::progenitor_support::encode_path(token),
@@ -521,7 +550,11 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_checkouts_param_token(
+        &self,
+        token: impl AsRef<str>,
+    ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -543,9 +576,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_update_checkouts_param_token(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -571,8 +605,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_get_checkouts_param_token_shipping_rate(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/shipping_rates.json",
             crate::progenitor_support::encode_path(token),
@@ -594,9 +629,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_create_checkouts_param_token_complete(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/unstable/checkouts/{}/complete.json",
             crate::progenitor_support::encode_path(token),
@@ -618,7 +654,11 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_unstable_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_checkouts_param_token(
+        &self,
+        token: impl AsRef<str>,
+    ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/unstable/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -640,9 +680,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_update_checkouts_param_token(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/unstable/checkouts/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -668,8 +709,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_get_checkouts_param_token_shipping_rate(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/unstable/checkouts/{}/shipping_rates.json",
             crate::progenitor_support::encode_path(token),
@@ -691,12 +733,14 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_202001_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/collection_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/collection_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -716,18 +760,20 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_get_collection_listings_param_listing_product_id(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_listing_id = collection_listing_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(collection_listing_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/collection_listings/{}/product_ids.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -746,8 +792,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_get_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -769,9 +816,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_update_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -795,8 +843,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_delete_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -818,12 +867,14 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_202004_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/collection_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/collection_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -843,18 +894,20 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_get_collection_listings_param_listing_product_id(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_listing_id = collection_listing_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(collection_listing_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/collection_listings/{}/product_ids.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -873,8 +926,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_get_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -896,9 +950,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_update_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -922,8 +977,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_delete_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -945,12 +1001,14 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_202007_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/collection_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/collection_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -970,18 +1028,20 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_get_collection_listings_param_listing_product_id(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_listing_id = collection_listing_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(collection_listing_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/collection_listings/{}/product_ids.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1000,8 +1060,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_get_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1023,9 +1084,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_update_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1049,8 +1111,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_delete_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1072,12 +1135,14 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/collection_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/collection_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1097,18 +1162,20 @@ impl SalesChannels {
     */
     pub async fn get_collection_listings_param_listing_product_id(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_listing_id = collection_listing_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(collection_listing_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/collection_listings/{}/product_ids.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1127,8 +1194,9 @@ impl SalesChannels {
     */
     pub async fn get_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1150,9 +1218,10 @@ impl SalesChannels {
     */
     pub async fn update_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1176,8 +1245,9 @@ impl SalesChannels {
     */
     pub async fn delete_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1199,12 +1269,14 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_202101_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/collection_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/collection_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1224,18 +1296,20 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_get_collection_listings_param_listing_product_id(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_listing_id = collection_listing_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(collection_listing_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/collection_listings/{}/product_ids.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1254,8 +1328,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_get_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1277,9 +1352,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_update_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1303,8 +1379,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_delete_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1326,12 +1403,14 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_unstable_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/collection_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/collection_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1351,18 +1430,20 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_get_collection_listings_param_listing_product_id(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let collection_listing_id = collection_listing_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(collection_listing_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/collection_listings/{}/product_ids.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1381,8 +1462,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_get_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/unstable/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1404,9 +1486,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_update_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/unstable/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1430,8 +1513,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_delete_collection_listings_param_listing(
         &self,
-        collection_listing_id: &str,
+        collection_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let collection_listing_id = collection_listing_id.as_ref();
         let url = format!(
             "/admin/api/unstable/collection_listings/{}/json",
             crate::progenitor_support::encode_path(collection_listing_id),
@@ -1453,8 +1537,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_get_checkouts_param_token_payment(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/payments.json",
             crate::progenitor_support::encode_path(token),
@@ -1485,51 +1570,42 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_create_checkouts_param_token_payments(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         amount_required: &str,
         request_details_required: &str,
         session_id_required: &str,
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let token = token.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
+            query_args.push(("amount required".to_string(), amount_required.to_string()));
         }
         if !request_details_required.is_empty() {
             query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
+                "request_details required".to_string(),
                 request_details_required.to_string(),
             ));
         }
         if !session_id_required.is_empty() {
             query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
+                "session_id required".to_string(),
                 session_id_required.to_string(),
             ));
         }
         if !unique_token_required.is_empty() {
             query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
+                "unique_token required".to_string(),
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(token),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1551,9 +1627,11 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_get_checkouts_param_token_payments_payment(
         &self,
-        token: &str,
-        payment_id: &str,
+        token: impl AsRef<str>,
+        payment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
+        let payment_id = payment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/payments/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -1576,8 +1654,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_get_checkouts_param_token_payments_count(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/payments/count.json",
             crate::progenitor_support::encode_path(token),
@@ -1599,8 +1678,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_get_checkouts_param_token_payment(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/payments.json",
             crate::progenitor_support::encode_path(token),
@@ -1631,51 +1711,42 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_create_checkouts_param_token_payments(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         amount_required: &str,
         request_details_required: &str,
         session_id_required: &str,
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let token = token.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
+            query_args.push(("amount required".to_string(), amount_required.to_string()));
         }
         if !request_details_required.is_empty() {
             query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
+                "request_details required".to_string(),
                 request_details_required.to_string(),
             ));
         }
         if !session_id_required.is_empty() {
             query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
+                "session_id required".to_string(),
                 session_id_required.to_string(),
             ));
         }
         if !unique_token_required.is_empty() {
             query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
+                "unique_token required".to_string(),
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(token),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1697,9 +1768,11 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_get_checkouts_param_token_payments_payment(
         &self,
-        token: &str,
-        payment_id: &str,
+        token: impl AsRef<str>,
+        payment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
+        let payment_id = payment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/payments/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -1722,8 +1795,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_get_checkouts_param_token_payments_count(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/payments/count.json",
             crate::progenitor_support::encode_path(token),
@@ -1745,8 +1819,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_get_checkouts_param_token_payment(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/payments.json",
             crate::progenitor_support::encode_path(token),
@@ -1777,51 +1852,42 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_create_checkouts_param_token_payments(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         amount_required: &str,
         request_details_required: &str,
         session_id_required: &str,
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let token = token.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
+            query_args.push(("amount required".to_string(), amount_required.to_string()));
         }
         if !request_details_required.is_empty() {
             query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
+                "request_details required".to_string(),
                 request_details_required.to_string(),
             ));
         }
         if !session_id_required.is_empty() {
             query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
+                "session_id required".to_string(),
                 session_id_required.to_string(),
             ));
         }
         if !unique_token_required.is_empty() {
             query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
+                "unique_token required".to_string(),
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(token),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1843,9 +1909,11 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_get_checkouts_param_token_payments_payment(
         &self,
-        token: &str,
-        payment_id: &str,
+        token: impl AsRef<str>,
+        payment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
+        let payment_id = payment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/payments/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -1868,8 +1936,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_get_checkouts_param_token_payments_count(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/payments/count.json",
             crate::progenitor_support::encode_path(token),
@@ -1889,7 +1958,8 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+    pub async fn get_checkouts_param_token_payment(&self, token: impl AsRef<str>) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/payments.json",
             crate::progenitor_support::encode_path(token),
@@ -1920,51 +1990,42 @@ impl SalesChannels {
     */
     pub async fn create_checkouts_param_token_payments(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         amount_required: &str,
         request_details_required: &str,
         session_id_required: &str,
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let token = token.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
+            query_args.push(("amount required".to_string(), amount_required.to_string()));
         }
         if !request_details_required.is_empty() {
             query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
+                "request_details required".to_string(),
                 request_details_required.to_string(),
             ));
         }
         if !session_id_required.is_empty() {
             query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
+                "session_id required".to_string(),
                 session_id_required.to_string(),
             ));
         }
         if !unique_token_required.is_empty() {
             query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
+                "unique_token required".to_string(),
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(token),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token),
+            ),
+            &query_args,
         );
 
         self.client
@@ -1986,9 +2047,11 @@ impl SalesChannels {
     */
     pub async fn get_checkouts_param_token_payments_payment(
         &self,
-        token: &str,
-        payment_id: &str,
+        token: impl AsRef<str>,
+        payment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
+        let payment_id = payment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/payments/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -2009,7 +2072,11 @@ impl SalesChannels {
     *
     * * `token: &str` -- storefront_access_token_id.
     */
-    pub async fn get_checkouts_param_token_payments_count(&self, token: &str) -> Result<()> {
+    pub async fn get_checkouts_param_token_payments_count(
+        &self,
+        token: impl AsRef<str>,
+    ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/payments/count.json",
             crate::progenitor_support::encode_path(token),
@@ -2031,8 +2098,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_get_checkouts_param_token_payment(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/payments.json",
             crate::progenitor_support::encode_path(token),
@@ -2063,51 +2131,42 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_create_checkouts_param_token_payments(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         amount_required: &str,
         request_details_required: &str,
         session_id_required: &str,
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let token = token.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
+            query_args.push(("amount required".to_string(), amount_required.to_string()));
         }
         if !request_details_required.is_empty() {
             query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
+                "request_details required".to_string(),
                 request_details_required.to_string(),
             ));
         }
         if !session_id_required.is_empty() {
             query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
+                "session_id required".to_string(),
                 session_id_required.to_string(),
             ));
         }
         if !unique_token_required.is_empty() {
             query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
+                "unique_token required".to_string(),
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(token),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2129,9 +2188,11 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_get_checkouts_param_token_payments_payment(
         &self,
-        token: &str,
-        payment_id: &str,
+        token: impl AsRef<str>,
+        payment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
+        let payment_id = payment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/payments/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -2154,8 +2215,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202101_get_checkouts_param_token_payments_count(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/payments/count.json",
             crate::progenitor_support::encode_path(token),
@@ -2177,8 +2239,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_get_checkouts_param_token_payment(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/unstable/checkouts/{}/payments.json",
             crate::progenitor_support::encode_path(token),
@@ -2209,51 +2272,42 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_create_checkouts_param_token_payments(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
         amount_required: &str,
         request_details_required: &str,
         session_id_required: &str,
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let token = token.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
+            query_args.push(("amount required".to_string(), amount_required.to_string()));
         }
         if !request_details_required.is_empty() {
             query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
+                "request_details required".to_string(),
                 request_details_required.to_string(),
             ));
         }
         if !session_id_required.is_empty() {
             query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
+                "session_id required".to_string(),
                 session_id_required.to_string(),
             ));
         }
         if !unique_token_required.is_empty() {
             query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
+                "unique_token required".to_string(),
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(token),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token),
+            ),
+            &query_args,
         );
 
         self.client
@@ -2275,9 +2329,11 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_get_checkouts_param_token_payments_payment(
         &self,
-        token: &str,
-        payment_id: &str,
+        token: impl AsRef<str>,
+        payment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
+        let payment_id = payment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/checkouts/{}/payments/{}/json",
             crate::progenitor_support::encode_path(token),
@@ -2300,8 +2356,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_unstable_get_checkouts_param_token_payments_count(
         &self,
-        token: &str,
+        token: impl AsRef<str>,
     ) -> Result<()> {
+        let token = token.as_ref();
         let url = format!(
             "/admin/api/unstable/checkouts/{}/payments/count.json",
             crate::progenitor_support::encode_path(token),
@@ -2334,24 +2391,26 @@ impl SalesChannels {
         updated_at_min: &str,
         handle: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
+            query_args.push(("product_ids", product_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/product_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/product_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2369,14 +2428,13 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_202001_get_product_listings_id(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/product_listings/product_ids.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/product_listings/product_ids.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2407,8 +2465,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_get_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2430,9 +2489,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_update_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2456,8 +2516,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202001_delete_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2490,24 +2551,26 @@ impl SalesChannels {
         updated_at_min: &str,
         handle: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
+            query_args.push(("product_ids", product_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/product_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/product_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2525,14 +2588,13 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_202004_get_product_listings_id(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/product_listings/product_ids.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/product_listings/product_ids.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2563,8 +2625,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_get_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2586,9 +2649,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_update_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2612,8 +2676,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202004_delete_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2646,24 +2711,26 @@ impl SalesChannels {
         updated_at_min: &str,
         handle: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
+            query_args.push(("product_ids", product_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/product_listings.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/product_listings.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -2681,14 +2748,13 @@ impl SalesChannels {
     *                     (default: 50, maximum: 1000).
     */
     pub async fn deprecated_202007_get_product_listings_id(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/product_listings/product_ids.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/product_listings/product_ids.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2719,8 +2785,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_get_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2742,9 +2809,10 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_update_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
@@ -2768,8 +2836,9 @@ impl SalesChannels {
     */
     pub async fn deprecated_202007_delete_product_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        product_listing_id: impl AsRef<str>,
     ) -> Result<()> {
+        let product_listing_id = product_listing_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/product_listings/{}/json",
             crate::progenitor_support::encode_path(product_listing_id),
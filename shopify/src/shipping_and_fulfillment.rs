@@ -34,20 +34,16 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/assigned_fulfillment_orders.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -75,20 +71,16 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/assigned_fulfillment_orders.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -116,20 +108,16 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/assigned_fulfillment_orders.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -157,20 +145,16 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/assigned_fulfillment_orders.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -198,20 +182,16 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/assigned_fulfillment_orders.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -239,20 +219,16 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/assigned_fulfillment_orders.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -272,19 +248,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -306,19 +284,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -340,19 +320,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -374,19 +356,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -408,19 +392,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -442,19 +428,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -476,19 +464,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -510,19 +500,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -544,19 +536,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -578,19 +572,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_cancellation_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -612,19 +608,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_cancellation_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -646,19 +644,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -680,19 +680,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -714,19 +716,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -748,19 +752,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -782,19 +788,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -816,19 +824,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -850,19 +860,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -912,8 +924,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -935,9 +948,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_update_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -961,8 +975,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_delete_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1013,8 +1028,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1036,9 +1052,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_update_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1062,8 +1079,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_delete_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1114,8 +1132,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1137,9 +1156,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_update_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1163,8 +1183,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_delete_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1210,7 +1231,11 @@ impl ShippingAndFulfillment {
     *
     * * `carrier_service_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()> {
+    pub async fn get_carrier_services_param_service(
+        &self,
+        carrier_service_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1232,9 +1257,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn update_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1258,8 +1284,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn delete_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1310,8 +1337,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1333,9 +1361,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_update_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1359,8 +1388,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_delete_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1411,8 +1441,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/unstable/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1434,9 +1465,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_update_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/unstable/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1460,8 +1492,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_delete_carrier_services_param_service(
         &self,
-        carrier_service_id: &str,
+        carrier_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let carrier_service_id = carrier_service_id.as_ref();
         let url = format!(
             "/admin/api/unstable/carrier_services/{}/json",
             crate::progenitor_support::encode_path(carrier_service_id),
@@ -1491,7 +1524,7 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_orders_param_order_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_max: &str,
         created_at_min: &str,
         fields: &str,
@@ -1500,33 +1533,35 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1567,9 +1602,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_orders_param_order_fulfillments(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1594,8 +1630,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -1621,30 +1658,32 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_orders_param_order_fulfillments_count(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/fulfillments/count.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1665,20 +1704,23 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(fulfillment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/orders/{}/fulfillments/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1698,10 +1740,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -1743,9 +1787,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillments/{}/update_tracking.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -1770,10 +1815,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/complete.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1799,10 +1846,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1828,10 +1877,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1856,9 +1907,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillments_param_fulfillment_cancel(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -1890,7 +1942,7 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_orders_param_order_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_max: &str,
         created_at_min: &str,
         fields: &str,
@@ -1899,33 +1951,35 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1966,9 +2020,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_orders_param_order_fulfillments(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(order_id),
@@ -1993,8 +2048,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -2020,30 +2076,32 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_orders_param_order_fulfillments_count(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/fulfillments/count.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2064,20 +2122,23 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(fulfillment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/orders/{}/fulfillments/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2097,10 +2158,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -2142,9 +2205,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillments/{}/update_tracking.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -2169,10 +2233,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/complete.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2198,10 +2264,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2227,10 +2295,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2255,9 +2325,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillments_param_fulfillment_cancel(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -2289,7 +2360,7 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_orders_param_order_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_max: &str,
         created_at_min: &str,
         fields: &str,
@@ -2298,33 +2369,35 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2365,9 +2438,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_orders_param_order_fulfillments(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2392,8 +2466,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -2419,30 +2494,32 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_orders_param_order_fulfillments_count(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/orders/{}/fulfillments/count.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2463,20 +2540,23 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(fulfillment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/orders/{}/fulfillments/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2496,10 +2576,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -2541,9 +2623,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillments/{}/update_tracking.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -2568,10 +2651,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/complete.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2597,10 +2682,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2626,10 +2713,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2654,9 +2743,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillments_param_fulfillment_cancel(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -2688,7 +2778,7 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_orders_param_order_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_max: &str,
         created_at_min: &str,
         fields: &str,
@@ -2697,33 +2787,35 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2764,9 +2856,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_orders_param_order_fulfillments(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2791,8 +2884,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_fulfillment_orders_param_order_fulfillment(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -2818,30 +2912,32 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_orders_param_order_fulfillments_count(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/orders/{}/fulfillments/count.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2862,20 +2958,23 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(fulfillment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2895,10 +2994,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn update_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -2937,9 +3038,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillments_param_fulfillment_update_tracking(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillments/{}/update_tracking.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -2964,10 +3066,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_orders_param_order_fulfillments_fulfillment_complete(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/complete.json",
             crate::progenitor_support::encode_path(order_id),
@@ -2993,10 +3097,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_orders_param_order_fulfillments_fulfillment_open(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3022,10 +3128,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_orders_param_order_fulfillments_fulfillment_cancel(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3050,9 +3158,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillments_param_fulfillment_cancel(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -3084,7 +3193,7 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_orders_param_order_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_max: &str,
         created_at_min: &str,
         fields: &str,
@@ -3093,33 +3202,35 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3160,9 +3271,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_orders_param_order_fulfillments(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3187,8 +3299,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -3214,30 +3327,32 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_orders_param_order_fulfillments_count(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/orders/{}/fulfillments/count.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3258,20 +3373,23 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(fulfillment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/orders/{}/fulfillments/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3291,10 +3409,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -3336,9 +3456,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillments/{}/update_tracking.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -3363,10 +3484,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/complete.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3392,10 +3515,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3421,10 +3546,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3449,9 +3576,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillments_param_fulfillment_cancel(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -3483,7 +3611,7 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_orders_param_order_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_max: &str,
         created_at_min: &str,
         fields: &str,
@@ -3492,33 +3620,35 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3559,9 +3689,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_orders_param_order_fulfillments(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3586,8 +3717,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/fulfillments.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -3613,30 +3745,32 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_orders_param_order_fulfillments_count(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/orders/{}/fulfillments/count.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3657,20 +3791,23 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(order_id),
-            crate::progenitor_support::encode_path(fulfillment_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/orders/{}/fulfillments/{}/json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -3690,10 +3827,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -3735,9 +3874,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillments/{}/update_tracking.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -3762,10 +3902,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/complete.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3791,10 +3933,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/open.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3820,10 +3964,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3848,9 +3994,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
         &self,
-        fulfillment_id: &str,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillments/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_id),
@@ -3877,9 +4024,11 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3903,10 +4052,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -3934,10 +4085,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -3963,10 +4117,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -3993,9 +4150,11 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4019,10 +4178,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4050,10 +4211,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4079,10 +4243,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4109,9 +4276,11 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4135,10 +4304,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4166,10 +4337,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4195,10 +4369,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4225,9 +4402,11 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_orders_param_order_fulfillments_fulfillment_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4251,10 +4430,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_orders_param_order_fulfillments_fulfillment_events(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4282,10 +4463,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4311,10 +4495,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn delete_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4341,9 +4528,11 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4367,10 +4556,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4398,10 +4589,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4427,10 +4621,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4457,9 +4654,11 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4483,10 +4682,12 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4514,10 +4715,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4543,10 +4747,13 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
+        order_id: impl AsRef<str>,
+        fulfillment_id: impl AsRef<str>,
+        event_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
+        let fulfillment_id = fulfillment_id.as_ref();
+        let event_id = event_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events/{}/json",
             crate::progenitor_support::encode_path(order_id),
@@ -4571,8 +4778,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillment_orders.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4594,8 +4802,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_fulfillment_orders_param_order(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -4617,9 +4826,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancel(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -4645,19 +4855,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_close(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -4679,19 +4891,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -4713,8 +4927,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillment_orders.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4736,8 +4951,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_fulfillment_orders_param_order(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -4759,9 +4975,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancel(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -4787,19 +5004,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_close(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -4821,19 +5040,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -4855,8 +5076,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillment_orders.json",
             crate::progenitor_support::encode_path(order_id),
@@ -4878,8 +5100,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_fulfillment_orders_param_order(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -4901,9 +5124,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancel(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -4929,19 +5153,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_close(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -4963,19 +5189,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -4997,8 +5225,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillment_orders.json",
             crate::progenitor_support::encode_path(order_id),
@@ -5020,8 +5249,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_fulfillment_orders_param_order(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5043,9 +5273,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_cancel(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5071,19 +5302,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_close(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5105,19 +5338,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5139,8 +5374,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillment_orders.json",
             crate::progenitor_support::encode_path(order_id),
@@ -5162,8 +5398,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_fulfillment_orders_param_order(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5185,9 +5422,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancel(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5213,19 +5451,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_close(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5247,19 +5487,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5281,9 +5523,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_open(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/open.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5308,9 +5551,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_reschedule(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/reschedule.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5335,8 +5579,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
         &self,
-        order_id: &str,
+        order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let order_id = order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillment_orders.json",
             crate::progenitor_support::encode_path(order_id),
@@ -5358,8 +5603,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_fulfillment_orders_param_order(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5381,9 +5627,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancel(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/cancel.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5411,14 +5658,13 @@ impl ShippingAndFulfillment {
         order_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_id.is_empty() {
-            query_args.push(("order_id".to_string(), order_id.to_string()));
+            query_args.push(("order_id", order_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/release_hold.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/fulfillment_orders/release_hold.json"),
+            &query_args,
         );
 
         self.client
@@ -5441,19 +5687,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_close(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5475,19 +5723,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5509,9 +5759,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_open(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/open.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5536,9 +5787,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/reschedule.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -5564,26 +5816,28 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5605,19 +5859,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5639,19 +5895,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5674,26 +5932,28 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5715,19 +5975,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5749,19 +6011,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5784,26 +6048,28 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5825,19 +6091,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5859,19 +6127,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5894,26 +6164,28 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5935,19 +6207,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -5969,19 +6243,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn create_fulfillment_orders_param_order_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6004,26 +6280,28 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6045,19 +6323,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6079,19 +6359,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6114,26 +6396,28 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6155,19 +6439,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6189,19 +6475,21 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(fulfillment_order_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -6226,15 +6514,17 @@ impl ShippingAndFulfillment {
         order_names: &str,
         shop: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_names.is_empty() {
-            query_args.push(("order_names".to_string(), order_names.to_string()));
+            query_args.push(("order_names", order_names.to_string()));
         }
         if !shop.is_empty() {
-            query_args.push(("shop".to_string(), shop.to_string()));
+            query_args.push(("shop", shop.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/fetch_tracking_numbers?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/fetch_tracking_numbers"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6260,21 +6550,20 @@ impl ShippingAndFulfillment {
         sku: &str,
         timestamp: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !max_retries.is_empty() {
-            query_args.push(("max_retries".to_string(), max_retries.to_string()));
+            query_args.push(("max_retries", max_retries.to_string()));
         }
         if !shop.is_empty() {
-            query_args.push(("shop".to_string(), shop.to_string()));
+            query_args.push(("shop", shop.to_string()));
         }
         if !sku.is_empty() {
-            query_args.push(("sku".to_string(), sku.to_string()));
+            query_args.push(("sku", sku.to_string()));
         }
         if !timestamp.is_empty() {
-            query_args.push(("timestamp".to_string(), timestamp.to_string()));
+            query_args.push(("timestamp", timestamp.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/fetch_stock?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/fetch_stock"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -6292,12 +6581,14 @@ impl ShippingAndFulfillment {
     *                           all: Returns all the fulfillment providers.
     */
     pub async fn deprecated_202001_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/fulfillment_services.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/fulfillment_services.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6335,8 +6626,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6358,9 +6650,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_update_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6384,8 +6677,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_delete_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6407,12 +6701,14 @@ impl ShippingAndFulfillment {
     *                           all: Returns all the fulfillment providers.
     */
     pub async fn deprecated_202004_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/fulfillment_services.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/fulfillment_services.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6450,8 +6746,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6473,9 +6770,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_update_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6499,8 +6797,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_delete_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6522,12 +6821,14 @@ impl ShippingAndFulfillment {
     *                           all: Returns all the fulfillment providers.
     */
     pub async fn deprecated_202007_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/fulfillment_services.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/fulfillment_services.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6565,8 +6866,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6588,9 +6890,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_update_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6614,8 +6917,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_delete_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6637,12 +6941,14 @@ impl ShippingAndFulfillment {
     *                           all: Returns all the fulfillment providers.
     */
     pub async fn get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/fulfillment_services.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/fulfillment_services.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6677,8 +6983,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6700,9 +7007,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn update_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6726,8 +7034,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn delete_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6749,12 +7058,14 @@ impl ShippingAndFulfillment {
     *                           all: Returns all the fulfillment providers.
     */
     pub async fn deprecated_202101_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/fulfillment_services.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/fulfillment_services.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6792,8 +7103,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6815,9 +7127,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_update_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6841,8 +7154,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_delete_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6864,12 +7178,14 @@ impl ShippingAndFulfillment {
     *                           all: Returns all the fulfillment providers.
     */
     pub async fn deprecated_unstable_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/fulfillment_services.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/fulfillment_services.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -6907,8 +7223,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6930,9 +7247,10 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_update_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6956,8 +7274,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_delete_fulfillment_services_param_service(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_service_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_service_id = fulfillment_service_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_services/{}/json",
             crate::progenitor_support::encode_path(fulfillment_service_id),
@@ -6981,8 +7300,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/locations_for_move.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -7006,8 +7326,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/locations_for_move.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -7031,8 +7352,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/locations_for_move.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -7056,8 +7378,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn get_fulfillment_orders_param_order_locations_for_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/locations_for_move.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -7081,8 +7404,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/locations_for_move.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
@@ -7106,8 +7430,9 @@ impl ShippingAndFulfillment {
     */
     pub async fn deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
         &self,
-        fulfillment_order_id: &str,
+        fulfillment_order_id: impl AsRef<str>,
     ) -> Result<()> {
+        let fulfillment_order_id = fulfillment_order_id.as_ref();
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/locations_for_move.json",
             crate::progenitor_support::encode_path(fulfillment_order_id),
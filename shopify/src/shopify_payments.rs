@@ -106,23 +106,22 @@ impl ShopifyPayments {
         status: &str,
         initiated_at: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !initiated_at.is_empty() {
-            query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
+            query_args.push(("initiated_at", initiated_at.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/shopify_payments/disputes.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/shopify_payments/disputes.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -141,8 +140,9 @@ impl ShopifyPayments {
     */
     pub async fn deprecated_202001_get_disputes_param_dispute(
         &self,
-        dispute_id: &str,
+        dispute_id: impl AsRef<str>,
     ) -> Result<()> {
+        let dispute_id = dispute_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/shopify_payments/disputes/{}/json",
             crate::progenitor_support::encode_path(dispute_id),
@@ -173,23 +173,22 @@ impl ShopifyPayments {
         status: &str,
         initiated_at: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !initiated_at.is_empty() {
-            query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
+            query_args.push(("initiated_at", initiated_at.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/shopify_payments/disputes.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/shopify_payments/disputes.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -221,29 +220,28 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/shopify_payments/payouts.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -260,7 +258,11 @@ impl ShopifyPayments {
     *
     * * `payout_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202001_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_payouts_param_payout(
+        &self,
+        payout_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let payout_id = payout_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/shopify_payments/payouts/{}/json",
             crate::progenitor_support::encode_path(payout_id),
@@ -295,29 +297,28 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/shopify_payments/payouts.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -334,7 +335,11 @@ impl ShopifyPayments {
     *
     * * `payout_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202004_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_payouts_param_payout(
+        &self,
+        payout_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let payout_id = payout_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/shopify_payments/payouts/{}/json",
             crate::progenitor_support::encode_path(payout_id),
@@ -369,29 +374,28 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/shopify_payments/payouts.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -408,7 +412,11 @@ impl ShopifyPayments {
     *
     * * `payout_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202007_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_payouts_param_payout(
+        &self,
+        payout_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let payout_id = payout_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/shopify_payments/payouts/{}/json",
             crate::progenitor_support::encode_path(payout_id),
@@ -443,29 +451,28 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/shopify_payments/payouts.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -482,7 +489,8 @@ impl ShopifyPayments {
     *
     * * `payout_id: &str` -- storefront_access_token_id.
     */
-    pub async fn get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+    pub async fn get_payouts_param_payout(&self, payout_id: impl AsRef<str>) -> Result<()> {
+        let payout_id = payout_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/shopify_payments/payouts/{}/json",
             crate::progenitor_support::encode_path(payout_id),
@@ -517,29 +525,28 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/shopify_payments/payouts.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -556,7 +563,11 @@ impl ShopifyPayments {
     *
     * * `payout_id: &str` -- storefront_access_token_id.
     */
-    pub async fn deprecated_202101_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_payouts_param_payout(
+        &self,
+        payout_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let payout_id = payout_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/shopify_payments/payouts/{}/json",
             crate::progenitor_support::encode_path(payout_id),
@@ -591,29 +602,28 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/shopify_payments/payouts.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -632,8 +642,9 @@ impl ShopifyPayments {
     */
     pub async fn deprecated_unstable_get_payouts_param_payout(
         &self,
-        payout_id: &str,
+        payout_id: impl AsRef<str>,
     ) -> Result<()> {
+        let payout_id = payout_id.as_ref();
         let url = format!(
             "/admin/api/unstable/shopify_payments/payouts/{}/json",
             crate::progenitor_support::encode_path(payout_id),
@@ -667,26 +678,25 @@ impl ShopifyPayments {
         payout_id: &str,
         payout_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !payout_id.is_empty() {
-            query_args.push(("payout_id".to_string(), payout_id.to_string()));
+            query_args.push(("payout_id", payout_id.to_string()));
         }
         if !payout_status.is_empty() {
-            query_args.push(("payout_status".to_string(), payout_status.to_string()));
+            query_args.push(("payout_status", payout_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !test.is_empty() {
-            query_args.push(("test".to_string(), test.to_string()));
+            query_args.push(("test", test.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/shopify_payments/balance/transactions.json?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/shopify_payments/balance/transactions.json"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -25,15 +25,17 @@ impl StoreProperties {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202001_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/countries.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/countries.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -81,18 +83,20 @@ impl StoreProperties {
     */
     pub async fn deprecated_202001_get_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/countries/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -114,9 +118,10 @@ impl StoreProperties {
     */
     pub async fn deprecated_202001_update_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -140,8 +145,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202001_delete_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -163,15 +169,17 @@ impl StoreProperties {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202004_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/countries.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/countries.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -219,18 +227,20 @@ impl StoreProperties {
     */
     pub async fn deprecated_202004_get_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/countries/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -252,9 +262,10 @@ impl StoreProperties {
     */
     pub async fn deprecated_202004_update_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -278,8 +289,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202004_delete_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -301,15 +313,17 @@ impl StoreProperties {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202007_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/countries.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/countries.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -357,18 +371,20 @@ impl StoreProperties {
     */
     pub async fn deprecated_202007_get_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/countries/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -390,9 +406,10 @@ impl StoreProperties {
     */
     pub async fn deprecated_202007_update_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -416,8 +433,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202007_delete_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -439,15 +457,17 @@ impl StoreProperties {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/countries.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/countries.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -493,16 +513,22 @@ impl StoreProperties {
     * * `country_id: &str` -- storefront_access_token_id.
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
-    pub async fn get_countries_param_country(&self, country_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get_countries_param_country(
+        &self,
+        country_id: impl AsRef<str>,
+        fields: &str,
+    ) -> Result<()> {
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/countries/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -524,9 +550,10 @@ impl StoreProperties {
     */
     pub async fn update_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -548,7 +575,8 @@ impl StoreProperties {
     *
     * * `country_id: &str` -- storefront_access_token_id.
     */
-    pub async fn delete_countries_param_country(&self, country_id: &str) -> Result<()> {
+    pub async fn delete_countries_param_country(&self, country_id: impl AsRef<str>) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -570,15 +598,17 @@ impl StoreProperties {
     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
     */
     pub async fn deprecated_202101_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/countries.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/countries.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -626,18 +656,20 @@ impl StoreProperties {
     */
     pub async fn deprecated_202101_get_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/countries/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -659,9 +691,10 @@ impl StoreProperties {
     */
     pub async fn deprecated_202101_update_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -685,8 +718,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202101_delete_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -712,15 +746,17 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/countries.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/countries.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -771,18 +807,20 @@ impl StoreProperties {
     */
     pub async fn deprecated_unstable_get_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/countries/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -804,9 +842,10 @@ impl StoreProperties {
     */
     pub async fn deprecated_unstable_update_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/unstable/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -830,8 +869,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_unstable_delete_countries_param_country(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/unstable/countries/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -999,22 +1039,24 @@ impl StoreProperties {
     */
     pub async fn deprecated_202001_get_countries_param_country_province(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/countries/{}/provinces.json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1033,8 +1075,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202001_get_countries_param_country_provinces_count(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/countries/{}/provinces/count.json",
             crate::progenitor_support::encode_path(country_id),
@@ -1058,20 +1101,23 @@ impl StoreProperties {
     */
     pub async fn deprecated_202001_get_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            crate::progenitor_support::encode_path(province_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-01/countries/{}/provinces/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1094,10 +1140,12 @@ impl StoreProperties {
     */
     pub async fn deprecated_202001_update_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
         let url = format!(
             "/admin/api/2020-01/countries/{}/provinces/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -1124,22 +1172,24 @@ impl StoreProperties {
     */
     pub async fn deprecated_202004_get_countries_param_country_province(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/countries/{}/provinces.json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1158,8 +1208,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202004_get_countries_param_country_provinces_count(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/countries/{}/provinces/count.json",
             crate::progenitor_support::encode_path(country_id),
@@ -1183,20 +1234,23 @@ impl StoreProperties {
     */
     pub async fn deprecated_202004_get_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            crate::progenitor_support::encode_path(province_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-04/countries/{}/provinces/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1219,10 +1273,12 @@ impl StoreProperties {
     */
     pub async fn deprecated_202004_update_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
         let url = format!(
             "/admin/api/2020-04/countries/{}/provinces/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -1249,22 +1305,24 @@ impl StoreProperties {
     */
     pub async fn deprecated_202007_get_countries_param_country_province(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/countries/{}/provinces.json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1283,8 +1341,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202007_get_countries_param_country_provinces_count(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/countries/{}/provinces/count.json",
             crate::progenitor_support::encode_path(country_id),
@@ -1308,20 +1367,23 @@ impl StoreProperties {
     */
     pub async fn deprecated_202007_get_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            crate::progenitor_support::encode_path(province_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-07/countries/{}/provinces/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1344,10 +1406,12 @@ impl StoreProperties {
     */
     pub async fn deprecated_202007_update_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
         let url = format!(
             "/admin/api/2020-07/countries/{}/provinces/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -1374,22 +1438,24 @@ impl StoreProperties {
     */
     pub async fn get_countries_param_country_province(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/countries/{}/provinces.json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1408,8 +1474,9 @@ impl StoreProperties {
     */
     pub async fn get_countries_param_country_provinces_count(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/countries/{}/provinces/count.json",
             crate::progenitor_support::encode_path(country_id),
@@ -1433,20 +1500,23 @@ impl StoreProperties {
     */
     pub async fn get_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            crate::progenitor_support::encode_path(province_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2020-10/countries/{}/provinces/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1469,10 +1539,12 @@ impl StoreProperties {
     */
     pub async fn update_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
         let url = format!(
             "/admin/api/2020-10/countries/{}/provinces/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -1499,22 +1571,24 @@ impl StoreProperties {
     */
     pub async fn deprecated_202101_get_countries_param_country_province(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/countries/{}/provinces.json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1533,8 +1607,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_202101_get_countries_param_country_provinces_count(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/countries/{}/provinces/count.json",
             crate::progenitor_support::encode_path(country_id),
@@ -1558,20 +1633,23 @@ impl StoreProperties {
     */
     pub async fn deprecated_202101_get_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            crate::progenitor_support::encode_path(province_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/2021-01/countries/{}/provinces/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1594,10 +1672,12 @@ impl StoreProperties {
     */
     pub async fn deprecated_202101_update_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
         let url = format!(
             "/admin/api/2021-01/countries/{}/provinces/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -1624,22 +1704,24 @@ impl StoreProperties {
     */
     pub async fn deprecated_unstable_get_countries_param_country_province(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/countries/{}/provinces.json",
+                crate::progenitor_support::encode_path(country_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1658,8 +1740,9 @@ impl StoreProperties {
     */
     pub async fn deprecated_unstable_get_countries_param_country_provinces_count(
         &self,
-        country_id: &str,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
         let url = format!(
             "/admin/api/unstable/countries/{}/provinces/count.json",
             crate::progenitor_support::encode_path(country_id),
@@ -1683,20 +1766,23 @@ impl StoreProperties {
     */
     pub async fn deprecated_unstable_get_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(country_id),
-            crate::progenitor_support::encode_path(province_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/admin/api/unstable/countries/{}/provinces/{}/json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1719,10 +1805,12 @@ impl StoreProperties {
     */
     pub async fn deprecated_unstable_update_countries_param_country_provinces_province(
         &self,
-        country_id: &str,
-        province_id: &str,
+        country_id: impl AsRef<str>,
+        province_id: impl AsRef<str>,
         body: &serde_json::Value,
     ) -> Result<()> {
+        let country_id = country_id.as_ref();
+        let province_id = province_id.as_ref();
         let url = format!(
             "/admin/api/unstable/countries/{}/provinces/{}/json",
             crate::progenitor_support::encode_path(country_id),
@@ -1746,12 +1834,14 @@ impl StoreProperties {
     * * `fields: &str` -- comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202001_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/shipping_zones.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/shipping_zones.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1768,12 +1858,14 @@ impl StoreProperties {
     * * `fields: &str` -- comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202004_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/shipping_zones.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/shipping_zones.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1790,12 +1882,14 @@ impl StoreProperties {
     * * `fields: &str` -- comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202007_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/shipping_zones.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/shipping_zones.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1812,12 +1906,14 @@ impl StoreProperties {
     * * `fields: &str` -- comma-separated list of fields to include in the response.
     */
     pub async fn get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/shipping_zones.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/shipping_zones.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1834,12 +1930,14 @@ impl StoreProperties {
     * * `fields: &str` -- comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202101_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/shipping_zones.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/shipping_zones.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1856,12 +1954,14 @@ impl StoreProperties {
     * * `fields: &str` -- comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_unstable_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/shipping_zones.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/shipping_zones.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1878,12 +1978,14 @@ impl StoreProperties {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202001_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/shop.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/shop.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1900,12 +2002,14 @@ impl StoreProperties {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202004_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/shop.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/shop.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1922,12 +2026,14 @@ impl StoreProperties {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202007_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/shop.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/shop.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1944,12 +2050,14 @@ impl StoreProperties {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/shop.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/shop.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1966,12 +2074,14 @@ impl StoreProperties {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_202101_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/shop.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/shop.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1988,12 +2098,14 @@ impl StoreProperties {
     * * `fields: &str` -- A comma-separated list of fields to include in the response.
     */
     pub async fn deprecated_unstable_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/shop.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/shop.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -38,27 +38,29 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/tender_transactions.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-01/tender_transactions.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -89,27 +91,29 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/tender_transactions.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-04/tender_transactions.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -140,27 +144,29 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/tender_transactions.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-07/tender_transactions.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -191,27 +197,29 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/tender_transactions.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2020-10/tender_transactions.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -242,27 +250,29 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/tender_transactions.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/2021-01/tender_transactions.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -293,27 +303,29 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/tender_transactions.json?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin/api/unstable/tender_transactions.json"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
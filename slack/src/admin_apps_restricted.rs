@@ -34,21 +34,23 @@ impl AdminAppsRestricted {
         team_id: &str,
         enterprise_id: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !enterprise_id.is_empty() {
-            query_args.push(("enterprise_id".to_string(), enterprise_id.to_string()));
+            query_args.push(("enterprise_id", enterprise_id.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.apps.restricted.list?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.apps.restricted.list"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -108,12 +108,14 @@ impl AdminConversations {
         &self,
         channel_id: &str,
     ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_id.is_empty() {
-            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+            query_args.push(("channel_id", channel_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.getConversationPrefs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.conversations.getConversationPrefs"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -138,18 +140,20 @@ impl AdminConversations {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::AdminConversationsGetTeamsSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_id.is_empty() {
-            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+            query_args.push(("channel_id", channel_id.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.getTeams?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.conversations.getTeams"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -214,33 +218,32 @@ impl AdminConversations {
         sort: &str,
         sort_dir: &str,
     ) -> Result<crate::types::AdminConversationsSearchSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !search_channel_types.is_empty() {
-            query_args.push((
-                "search_channel_types".to_string(),
-                search_channel_types.to_string(),
-            ));
+            query_args.push(("search_channel_types", search_channel_types.to_string()));
         }
         if !sort.is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !sort_dir.is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !team_ids.is_empty() {
-            query_args.push(("team_ids".to_string(), team_ids.to_string()));
+            query_args.push(("team_ids", team_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.search?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.conversations.search"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -34,23 +34,22 @@ impl AdminConversationsEkm {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_ids.is_empty() {
-            query_args.push(("channel_ids".to_string(), channel_ids.to_string()));
+            query_args.push(("channel_ids", channel_ids.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !team_ids.is_empty() {
-            query_args.push(("team_ids".to_string(), team_ids.to_string()));
+            query_args.push(("team_ids", team_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin.conversations.ekm.listOriginalConnectedChannelInfo?{}",
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.conversations.ekm.listOriginalConnectedChannelInfo"),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -42,15 +42,17 @@ impl AdminConversationsRestrictAccess {
         channel_id: &str,
         team_id: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_id.is_empty() {
-            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+            query_args.push(("channel_id", channel_id.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.restrictAccess.listGroups?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.conversations.restrictAccess.listGroups"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
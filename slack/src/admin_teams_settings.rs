@@ -25,12 +25,14 @@ impl AdminTeamsSettings {
     * * `team_id: &str`
     */
     pub async fn info(&self, team_id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.teams.settings.info?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.teams.settings.info"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
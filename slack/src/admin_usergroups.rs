@@ -64,21 +64,20 @@ impl AdminUsergroups {
         team_id: &str,
         include_num_members: bool,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_num_members {
-            query_args.push((
-                "include_num_members".to_string(),
-                include_num_members.to_string(),
-            ));
+            query_args.push(("include_num_members", include_num_members.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
         if !usergroup_id.is_empty() {
-            query_args.push(("usergroup_id".to_string(), usergroup_id.to_string()));
+            query_args.push(("usergroup_id", usergroup_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.usergroups.listChannels?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/admin.usergroups.listChannels"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -25,15 +25,14 @@ impl Api {
     * * `foo_: &str` -- example property to return.
     */
     pub async fn test(&self, error: &str, foo_: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !error.is_empty() {
-            query_args.push(("error".to_string(), error.to_string()));
+            query_args.push(("error", error.to_string()));
         }
         if !foo_.is_empty() {
-            query_args.push(("foo".to_string(), foo_.to_string()));
+            query_args.push(("foo", foo_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api.test?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/api.test"), &query_args);
 
         self.client.get(&url, None).await
     }
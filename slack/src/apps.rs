@@ -30,15 +30,15 @@ impl Apps {
         client_id: &str,
         client_secret: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.uninstall?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/apps.uninstall"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -46,15 +46,17 @@ impl AppsPermissions {
         scopes: &str,
         trigger_id: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scopes.is_empty() {
-            query_args.push(("scopes".to_string(), scopes.to_string()));
+            query_args.push(("scopes", scopes.to_string()));
         }
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.permissions.request?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/apps.permissions.request"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -26,15 +26,17 @@ impl AppsPermissionsUsers {
     * * `limit: i64` -- The maximum number of items to return.
     */
     pub async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.permissions.users.list?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/apps.permissions.users.list"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -59,18 +61,20 @@ impl AppsPermissionsUsers {
         trigger_id: &str,
         user: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scopes.is_empty() {
-            query_args.push(("scopes".to_string(), scopes.to_string()));
+            query_args.push(("scopes", scopes.to_string()));
         }
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.permissions.users.request?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/apps.permissions.users.request"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -25,12 +25,11 @@ impl Auth {
     * * `test: bool` -- Setting this parameter to `1` triggers a _testing mode_ where the specified token will not actually be revoked.
     */
     pub async fn revoke(&self, test: bool) -> Result<crate::types::AuthRevokeSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if test {
-            query_args.push(("test".to_string(), test.to_string()));
+            query_args.push(("test", test.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/auth.revoke?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/auth.revoke"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -0,0 +1,301 @@
+//! Build messages out of [Block Kit](https://api.slack.com/block-kit) blocks, and
+//! post them to a channel or to an incoming webhook.
+//!
+//! ```no_run
+//! # async fn run(slack: slack_chat_api::Client) -> anyhow::Result<()> {
+//! use slack_chat_api::blocks::{Block, Button, Message, Section, Text};
+//!
+//! let message = Message::new("Deploy #42 finished")
+//!     .in_channel("C1234567890")
+//!     .with_block(Block::header("Deploy #42"))
+//!     .with_block(
+//!         Section::new(Text::markdown("*api* was deployed to production."))
+//!             .with_field(Text::markdown("*Commit*\n`a1b2c3d`"))
+//!             .with_field(Text::markdown("*Took*\n4m 12s")),
+//!     )
+//!     .with_block(Block::actions(vec![
+//!         Button::new("View logs", "view_logs").with_url("https://example.com/deploys/42"),
+//!         Button::new("Roll back", "roll_back").danger(),
+//!     ]))
+//!     .with_block(Block::context(vec![Text::markdown("Deployed by <@U0123>")]));
+//!
+//! slack.chat().post_blocks(&message).await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{chat::Chat, types::ChatPostMessageSuccessSchema};
+
+/// A text object, shown as is or formatted with Slack's markdown.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    PlainText {
+        text: String,
+        /// Whether to show emojis written as `:smile:`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+    },
+}
+
+/// The style of a button, the default style is used when there is none.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+/// A button, either opening a link or sending an interaction to the app.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename = "button")]
+pub struct Button {
+    pub text: Text,
+    /// Identifies the button in the interaction payloads.
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Sent along in the interaction payloads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+/// A section block: some text, fields shown in two columns, and a button next to
+/// them.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Section {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Text>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<Button>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+/// A block of the layout of a message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Section(Section),
+    Actions {
+        elements: Vec<Button>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        block_id: Option<String>,
+    },
+    Context {
+        elements: Vec<Text>,
+    },
+    Divider,
+    Header {
+        text: Text,
+    },
+}
+
+/**
+ * A message to post, made of blocks.
+ *
+ * The text is shown in notifications, and in place of the blocks where they
+ * cannot be shown.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Message {
+    /// The channel to post to, which incoming webhooks ignore.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel: String,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+    /// The timestamp of the message to reply to in a thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+}
+
+impl Text {
+    /// Plain text, showing emojis.
+    pub fn plain(text: &str) -> Self {
+        Text::PlainText {
+            text: text.to_string(),
+            emoji: Some(true),
+        }
+    }
+
+    /// Text formatted with Slack's markdown.
+    pub fn markdown(text: &str) -> Self {
+        Text::Mrkdwn {
+            text: text.to_string(),
+        }
+    }
+}
+
+impl Button {
+    pub fn new(text: &str, action_id: &str) -> Self {
+        Button {
+            text: Text::plain(text),
+            action_id: action_id.to_string(),
+            url: None,
+            value: None,
+            style: None,
+        }
+    }
+
+    /// Open this link when the button is clicked.
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    pub fn with_value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Show the button in green, for the action to take.
+    pub fn primary(mut self) -> Self {
+        self.style = Some(ButtonStyle::Primary);
+        self
+    }
+
+    /// Show the button in red, for actions that destroy something.
+    pub fn danger(mut self) -> Self {
+        self.style = Some(ButtonStyle::Danger);
+        self
+    }
+}
+
+impl Section {
+    pub fn new(text: Text) -> Self {
+        Section {
+            text: Some(text),
+            fields: Vec::new(),
+            accessory: None,
+            block_id: None,
+        }
+    }
+
+    /// Add a field, at most 10 of them are shown.
+    pub fn with_field(mut self, field: Text) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Show the button next to the text.
+    pub fn with_accessory(mut self, button: Button) -> Self {
+        self.accessory = Some(button);
+        self
+    }
+}
+
+impl Block {
+    /// A section with only some markdown text.
+    pub fn section(text: &str) -> Self {
+        Block::Section(Section::new(Text::markdown(text)))
+    }
+
+    /// A row of buttons.
+    pub fn actions(elements: Vec<Button>) -> Self {
+        Block::Actions {
+            elements,
+            block_id: None,
+        }
+    }
+
+    /// Small text, shown under the other blocks.
+    pub fn context(elements: Vec<Text>) -> Self {
+        Block::Context { elements }
+    }
+
+    pub fn divider() -> Self {
+        Block::Divider
+    }
+
+    /// A title, in large plain text.
+    pub fn header(text: &str) -> Self {
+        Block::Header {
+            text: Text::plain(text),
+        }
+    }
+}
+
+impl From<Section> for Block {
+    fn from(section: Section) -> Self {
+        Block::Section(section)
+    }
+}
+
+impl Message {
+    /// A message with the given text, to add blocks to.
+    pub fn new(text: &str) -> Self {
+        Message {
+            channel: String::new(),
+            text: text.to_string(),
+            blocks: Vec::new(),
+            thread_ts: None,
+        }
+    }
+
+    pub fn in_channel(mut self, channel: &str) -> Self {
+        self.channel = channel.to_string();
+        self
+    }
+
+    pub fn with_block<B: Into<Block>>(mut self, block: B) -> Self {
+        self.blocks.push(block.into());
+        self
+    }
+
+    /// Reply in the thread of the message with this timestamp.
+    pub fn in_thread(mut self, thread_ts: &str) -> Self {
+        self.thread_ts = Some(thread_ts.to_string());
+        self
+    }
+}
+
+impl Chat {
+    /// Post a message made of blocks to its channel.
+    pub async fn post_blocks(&self, message: &Message) -> Result<ChatPostMessageSuccessSchema> {
+        if message.channel.is_empty() {
+            bail!("the message has no channel to be posted to");
+        }
+
+        let body = reqwest::Body::from(serde_json::to_vec(message)?);
+        let resp: serde_json::Value = self.client.post("/chat.postMessage", Some(body)).await?;
+        // Slack answers with `ok: false` and a 200 when the message was not posted.
+        if resp["ok"] != serde_json::Value::Bool(true) {
+            bail!(
+                "chat.postMessage failed: {}",
+                resp["error"].as_str().unwrap_or("unknown error")
+            );
+        }
+
+        Ok(serde_json::from_value(resp)?)
+    }
+
+    /// Post a message made of blocks to an incoming webhook, whose URL is the only
+    /// credential it needs.
+    pub async fn post_webhook(&self, webhook_url: &str, message: &Message) -> Result<()> {
+        let req = self
+            .client
+            .client
+            .post(webhook_url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(message)?)
+            .build()?;
+
+        let resp = self.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_success() {
+            // The body is the reason, like `invalid_blocks` or `channel_not_found`.
+            bail!("code: {}, error: {:?}", status, resp.text().await?);
+        }
+        Ok(())
+    }
+}
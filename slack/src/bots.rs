@@ -25,12 +25,11 @@ impl Bots {
     * * `bot: &str` -- Bot user to get info on.
     */
     pub async fn info(&self, bot: &str) -> Result<crate::types::BotsInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !bot.is_empty() {
-            query_args.push(("bot".to_string(), bot.to_string()));
+            query_args.push(("bot", bot.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/bots.info?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/bots.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -57,12 +57,11 @@ impl Calls {
     * * `id: &str` -- `id` of the Call returned by the [`calls.add`](/methods/calls.add) method.
     */
     pub async fn info(&self, id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !id.is_empty() {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/calls.info?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/calls.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -62,15 +62,15 @@ impl Chat {
         channel: &str,
         message_ts: &str,
     ) -> Result<crate::types::ChatGetPermalinkSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !message_ts.is_empty() {
-            query_args.push(("message_ts".to_string(), message_ts.to_string()));
+            query_args.push(("message_ts", message_ts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat.getPermalink?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/chat.getPermalink"), &query_args);
 
         self.client.get(&url, None).await
     }
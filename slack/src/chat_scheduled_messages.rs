@@ -36,24 +36,26 @@ impl ChatScheduledMessages {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ChatScheduledMessagesListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !latest.to_string().is_empty() {
-            query_args.push(("latest".to_string(), latest.to_string()));
+            query_args.push(("latest", latest.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !oldest.to_string().is_empty() {
-            query_args.push(("oldest".to_string(), oldest.to_string()));
+            query_args.push(("oldest", oldest.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat.scheduledMessages.list?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/chat.scheduledMessages.list"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
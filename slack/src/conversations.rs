@@ -86,27 +86,29 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsHistorySuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if inclusive {
-            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+            query_args.push(("inclusive", inclusive.to_string()));
         }
         if !latest.to_string().is_empty() {
-            query_args.push(("latest".to_string(), latest.to_string()));
+            query_args.push(("latest", latest.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !oldest.to_string().is_empty() {
-            query_args.push(("oldest".to_string(), oldest.to_string()));
+            query_args.push(("oldest", oldest.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.history?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/conversations.history"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -131,21 +133,18 @@ impl Conversations {
         include_locale: bool,
         include_num_members: bool,
     ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if include_locale {
-            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+            query_args.push(("include_locale", include_locale.to_string()));
         }
         if include_num_members {
-            query_args.push((
-                "include_num_members".to_string(),
-                include_num_members.to_string(),
-            ));
+            query_args.push(("include_num_members", include_num_members.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.info?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/conversations.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -236,21 +235,21 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsListSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if exclude_archived {
-            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+            query_args.push(("exclude_archived", exclude_archived.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !types.is_empty() {
-            query_args.push(("types".to_string(), types.to_string()));
+            query_args.push(("types", types.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.list?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/conversations.list"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -291,18 +290,20 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsMembersSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.members?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/conversations.members"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -367,30 +368,32 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsRepliesSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if inclusive {
-            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+            query_args.push(("inclusive", inclusive.to_string()));
         }
         if !latest.to_string().is_empty() {
-            query_args.push(("latest".to_string(), latest.to_string()));
+            query_args.push(("latest", latest.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !oldest.to_string().is_empty() {
-            query_args.push(("oldest".to_string(), oldest.to_string()));
+            query_args.push(("oldest", oldest.to_string()));
         }
         if !ts.to_string().is_empty() {
-            query_args.push(("ts".to_string(), ts.to_string()));
+            query_args.push(("ts", ts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.replies?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/conversations.replies"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -26,15 +26,14 @@ impl Dialog {
     * * `trigger_id: &str` -- Exchange a trigger to post to the user.
     */
     pub async fn open(&self, dialog: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !dialog.is_empty() {
-            query_args.push(("dialog".to_string(), dialog.to_string()));
+            query_args.push(("dialog", dialog.to_string()));
         }
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/dialog.open?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/dialog.open"), &query_args);
 
         self.client.get(&url, None).await
     }
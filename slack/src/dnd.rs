@@ -57,12 +57,11 @@ impl Dnd {
     * * `user: &str` -- User to fetch status for (defaults to current user).
     */
     pub async fn info(&self, user: &str) -> Result<crate::types::DndInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/dnd.info?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/dnd.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -92,12 +91,11 @@ impl Dnd {
     * * `users: &str` -- Comma-separated list of users to fetch Do Not Disturb status for.
     */
     pub async fn team_info(&self, users: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !users.is_empty() {
-            query_args.push(("users".to_string(), users.to_string()));
+            query_args.push(("users", users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/dnd.teamInfo?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/dnd.teamInfo"), &query_args);
 
         self.client.get(&url, None).await
     }
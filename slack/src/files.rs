@@ -52,24 +52,23 @@ impl Files {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::FilesInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.info?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/files.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -104,36 +103,35 @@ impl Files {
         page: &str,
         show_files_hidden_by_limit: bool,
     ) -> Result<crate::types::FilesListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if show_files_hidden_by_limit {
             query_args.push((
-                "show_files_hidden_by_limit".to_string(),
+                "show_files_hidden_by_limit",
                 show_files_hidden_by_limit.to_string(),
             ));
         }
         if !ts_from.to_string().is_empty() {
-            query_args.push(("ts_from".to_string(), ts_from.to_string()));
+            query_args.push(("ts_from", ts_from.to_string()));
         }
         if !ts_to.to_string().is_empty() {
-            query_args.push(("ts_to".to_string(), ts_to.to_string()));
+            query_args.push(("ts_to", ts_to.to_string()));
         }
         if !types.is_empty() {
-            query_args.push(("types".to_string(), types.to_string()));
+            query_args.push(("types", types.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.list?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/files.list"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -38,15 +38,15 @@ impl FilesRemote {
     * * `external_id: &str` -- Creator defined GUID for the file.
     */
     pub async fn info(&self, file: &str, external_id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !external_id.is_empty() {
-            query_args.push(("external_id".to_string(), external_id.to_string()));
+            query_args.push(("external_id", external_id.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.remote.info?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/files.remote.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -75,24 +75,24 @@ impl FilesRemote {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !ts_from.to_string().is_empty() {
-            query_args.push(("ts_from".to_string(), ts_from.to_string()));
+            query_args.push(("ts_from", ts_from.to_string()));
         }
         if !ts_to.to_string().is_empty() {
-            query_args.push(("ts_to".to_string(), ts_to.to_string()));
+            query_args.push(("ts_to", ts_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.remote.list?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/files.remote.list"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -129,18 +129,18 @@ impl FilesRemote {
         external_id: &str,
         channels: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channels.is_empty() {
-            query_args.push(("channels".to_string(), channels.to_string()));
+            query_args.push(("channels", channels.to_string()));
         }
         if !external_id.is_empty() {
-            query_args.push(("external_id".to_string(), external_id.to_string()));
+            query_args.push(("external_id", external_id.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.remote.share?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/files.remote.share"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -0,0 +1,186 @@
+//! Parse the items of a JSON array as the body of a response downloads.
+//!
+//! The body is read a chunk at a time, and every item is deserialized as soon as
+//! it is whole, so that responses of many megabytes are never held in memory at
+//! once.
+use anyhow::{anyhow, Result};
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+/// Where the parser is in the body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Start,
+    /// In the object around the array, looking for the field holding it.
+    Object {
+        first: bool,
+    },
+    Array {
+        first: bool,
+    },
+    Done,
+}
+
+enum Step<T> {
+    Item(T),
+    /// The next value is not whole yet.
+    NeedMore,
+    Done,
+}
+
+struct Parser {
+    response: reqwest::Response,
+    buf: Vec<u8>,
+    /// How far into `buf` was parsed.
+    pos: usize,
+    eof: bool,
+    state: State,
+}
+
+/// Stream the items of the JSON array in the body of a response. The array is
+/// either the body itself, or the first field of the body holding an array, like
+/// the `data` of `{"data": [...], "page": {...}}`.
+pub(crate) fn items<T>(response: reqwest::Response) -> impl futures::Stream<Item = Result<T>>
+where
+    T: DeserializeOwned,
+{
+    let parser = Parser {
+        response,
+        buf: Vec::new(),
+        pos: 0,
+        eof: false,
+        state: State::Start,
+    };
+
+    futures::stream::try_unfold(parser, |mut parser| async move {
+        loop {
+            match parser.next::<T>()? {
+                Step::Item(item) => return Ok(Some((item, parser))),
+                Step::Done => return Ok(None),
+                Step::NeedMore if parser.eof => {
+                    return Err(anyhow!("the JSON body ended before the end of the array"))
+                }
+                Step::NeedMore => {
+                    // Drop what was parsed already before reading more.
+                    parser.buf.drain(..parser.pos);
+                    parser.pos = 0;
+                    match parser.response.chunk().await? {
+                        Some(chunk) => parser.buf.extend_from_slice(&chunk),
+                        None => parser.eof = true,
+                    }
+                }
+            }
+        }
+    })
+}
+
+impl Parser {
+    fn next<T>(&mut self) -> Result<Step<T>>
+    where
+        T: DeserializeOwned,
+    {
+        loop {
+            let start = self.skip_whitespace(0);
+            let byte = match self.buf.get(self.pos + start) {
+                Some(byte) => *byte,
+                None if self.state == State::Done => return Ok(Step::Done),
+                None => return Ok(Step::NeedMore),
+            };
+
+            match self.state {
+                State::Start => {
+                    self.state = match byte {
+                        b'[' => State::Array { first: true },
+                        b'{' => State::Object { first: true },
+                        _ => return Err(anyhow!("the JSON body is not an array or an object")),
+                    };
+                    self.pos += start + 1;
+                }
+                State::Array { .. } if byte == b']' => {
+                    self.pos += start + 1;
+                    self.state = State::Done;
+                }
+                State::Array { first } => {
+                    let start = self.separator(first, start, byte)?;
+                    match self.value::<T>(start)? {
+                        Some((item, end)) => {
+                            self.pos += end;
+                            self.state = State::Array { first: false };
+                            return Ok(Step::Item(item));
+                        }
+                        None => return Ok(Step::NeedMore),
+                    }
+                }
+                State::Object { .. } if byte == b'}' => {
+                    // None of the fields holds an array.
+                    self.pos += start + 1;
+                    self.state = State::Done;
+                }
+                State::Object { first } => {
+                    let start = self.separator(first, start, byte)?;
+                    let key_end = match self.value::<IgnoredAny>(start)? {
+                        Some((_, end)) => end,
+                        None => return Ok(Step::NeedMore),
+                    };
+                    let colon = self.skip_whitespace(key_end);
+                    let value = match self.buf.get(self.pos + colon) {
+                        Some(b':') => self.skip_whitespace(colon + 1),
+                        Some(_) => {
+                            return Err(anyhow!("expected `:` after a key of the JSON body"))
+                        }
+                        None => return Ok(Step::NeedMore),
+                    };
+                    match self.buf.get(self.pos + value) {
+                        Some(b'[') => {
+                            self.pos += value + 1;
+                            self.state = State::Array { first: true };
+                        }
+                        Some(_) => match self.value::<IgnoredAny>(value)? {
+                            Some((_, end)) => {
+                                self.pos += end;
+                                self.state = State::Object { first: false };
+                            }
+                            None => return Ok(Step::NeedMore),
+                        },
+                        None => return Ok(Step::NeedMore),
+                    }
+                }
+                State::Done => return Ok(Step::Done),
+            }
+        }
+    }
+
+    /// The offset, from `pos`, of the first byte at or after `offset` that is not
+    /// whitespace.
+    fn skip_whitespace(&self, offset: usize) -> usize {
+        let rest = self.buf.get(self.pos + offset..).unwrap_or_default();
+        offset + rest.iter().take_while(|b| b.is_ascii_whitespace()).count()
+    }
+
+    /// Skip the comma between two values, given the first `byte` of the value at
+    /// `offset`, returning the offset of the value.
+    fn separator(&self, first: bool, offset: usize, byte: u8) -> Result<usize> {
+        match (first, byte) {
+            (true, _) => Ok(offset),
+            (false, b',') => Ok(self.skip_whitespace(offset + 1)),
+            (false, _) => Err(anyhow!("expected `,` between the values of the JSON body")),
+        }
+    }
+
+    /// Parse the value at `offset` from `pos`, returning it with the offset of its
+    /// end, or `None` when it is not whole yet.
+    fn value<V>(&self, offset: usize) -> Result<Option<(V, usize)>>
+    where
+        V: DeserializeOwned,
+    {
+        let rest = &self.buf[self.pos + offset..];
+        let mut values = serde_json::Deserializer::from_slice(rest).into_iter::<V>();
+        match values.next() {
+            // A number at the end of the buffer may go on in the next chunk.
+            Some(Ok(_)) if values.byte_offset() == rest.len() && !self.eof => Ok(None),
+            Some(Ok(value)) => Ok(Some((value, offset + values.byte_offset()))),
+            Some(Err(e)) if e.is_eof() => Ok(None),
+            Some(Err(e)) => Err(e.into()),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: slack_chat_api::Client) {
+//! use slack_chat_api::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "Slack";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/admin.apps.approve",
+    "/admin.apps.approved.list",
+    "/admin.apps.requests.list",
+    "/admin.apps.restrict",
+    "/admin.apps.restricted.list",
+    "/admin.conversations.archive",
+    "/admin.conversations.convertToPrivate",
+    "/admin.conversations.create",
+    "/admin.conversations.delete",
+    "/admin.conversations.disconnectShared",
+    "/admin.conversations.ekm.listOriginalConnectedChannelInfo",
+    "/admin.conversations.getConversationPrefs",
+    "/admin.conversations.getTeams",
+    "/admin.conversations.invite",
+    "/admin.conversations.rename",
+    "/admin.conversations.restrictAccess.addGroup",
+    "/admin.conversations.restrictAccess.listGroups",
+    "/admin.conversations.restrictAccess.removeGroup",
+    "/admin.conversations.search",
+    "/admin.conversations.setConversationPrefs",
+    "/admin.conversations.setTeams",
+    "/admin.conversations.unarchive",
+    "/admin.emoji.add",
+    "/admin.emoji.addAlias",
+    "/admin.emoji.list",
+    "/admin.emoji.remove",
+    "/admin.emoji.rename",
+    "/admin.inviteRequests.approve",
+    "/admin.inviteRequests.approved.list",
+    "/admin.inviteRequests.denied.list",
+    "/admin.inviteRequests.deny",
+    "/admin.inviteRequests.list",
+    "/admin.teams.admins.list",
+    "/admin.teams.create",
+    "/admin.teams.list",
+    "/admin.teams.owners.list",
+    "/admin.teams.settings.info",
+    "/admin.teams.settings.setDefaultChannels",
+    "/admin.teams.settings.setDescription",
+    "/admin.teams.settings.setDiscoverability",
+    "/admin.teams.settings.setIcon",
+    "/admin.teams.settings.setName",
+    "/admin.usergroups.addChannels",
+    "/admin.usergroups.addTeams",
+    "/admin.usergroups.listChannels",
+    "/admin.usergroups.removeChannels",
+    "/admin.users.assign",
+    "/admin.users.invite",
+    "/admin.users.list",
+    "/admin.users.remove",
+    "/admin.users.session.invalidate",
+    "/admin.users.session.reset",
+    "/admin.users.setAdmin",
+    "/admin.users.setExpiration",
+    "/admin.users.setOwner",
+    "/admin.users.setRegular",
+    "/api.test",
+    "/apps.event.authorizations.list",
+    "/apps.permissions.info",
+    "/apps.permissions.request",
+    "/apps.permissions.resources.list",
+    "/apps.permissions.scopes.list",
+    "/apps.permissions.users.list",
+    "/apps.permissions.users.request",
+    "/apps.uninstall",
+    "/auth.revoke",
+    "/auth.test",
+    "/bots.info",
+    "/calls.add",
+    "/calls.end",
+    "/calls.info",
+    "/calls.participants.add",
+    "/calls.participants.remove",
+    "/calls.update",
+    "/chat.delete",
+    "/chat.deleteScheduledMessage",
+    "/chat.getPermalink",
+    "/chat.meMessage",
+    "/chat.postEphemeral",
+    "/chat.postMessage",
+    "/chat.scheduleMessage",
+    "/chat.scheduledMessages.list",
+    "/chat.unfurl",
+    "/chat.update",
+    "/conversations.archive",
+    "/conversations.close",
+    "/conversations.create",
+    "/conversations.history",
+    "/conversations.info",
+    "/conversations.invite",
+    "/conversations.join",
+    "/conversations.kick",
+    "/conversations.leave",
+    "/conversations.list",
+    "/conversations.mark",
+    "/conversations.members",
+    "/conversations.open",
+    "/conversations.rename",
+    "/conversations.replies",
+    "/conversations.setPurpose",
+    "/conversations.setTopic",
+    "/conversations.unarchive",
+    "/dialog.open",
+    "/dnd.endDnd",
+    "/dnd.endSnooze",
+    "/dnd.info",
+    "/dnd.setSnooze",
+    "/dnd.teamInfo",
+    "/emoji.list",
+    "/files.comments.delete",
+    "/files.delete",
+    "/files.info",
+    "/files.list",
+    "/files.remote.add",
+    "/files.remote.info",
+    "/files.remote.list",
+    "/files.remote.remove",
+    "/files.remote.share",
+    "/files.remote.update",
+    "/files.revokePublicURL",
+    "/files.sharedPublicURL",
+    "/files.upload",
+    "/migration.exchange",
+    "/oauth.access",
+    "/oauth.token",
+    "/oauth.v2.access",
+    "/pins.add",
+    "/pins.list",
+    "/pins.remove",
+    "/reactions.add",
+    "/reactions.get",
+    "/reactions.list",
+    "/reactions.remove",
+    "/reminders.add",
+    "/reminders.complete",
+    "/reminders.delete",
+    "/reminders.info",
+    "/reminders.list",
+    "/rtm.connect",
+    "/search.messages",
+    "/stars.add",
+    "/stars.list",
+    "/stars.remove",
+    "/team.accessLogs",
+    "/team.billableInfo",
+    "/team.info",
+    "/team.integrationLogs",
+    "/team.profile.get",
+    "/usergroups.create",
+    "/usergroups.disable",
+    "/usergroups.enable",
+    "/usergroups.list",
+    "/usergroups.update",
+    "/usergroups.users.list",
+    "/usergroups.users.update",
+    "/users.conversations",
+    "/users.deletePhoto",
+    "/users.getPresence",
+    "/users.identity",
+    "/users.info",
+    "/users.list",
+    "/users.lookupByEmail",
+    "/users.profile.get",
+    "/users.profile.set",
+    "/users.setActive",
+    "/users.setPhoto",
+    "/users.setPresence",
+    "/views.open",
+    "/views.publish",
+    "/views.push",
+    "/views.update",
+    "/workflows.stepCompleted",
+    "/workflows.stepFailed",
+    "/workflows.updateStep",
+];
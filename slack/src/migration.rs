@@ -32,18 +32,18 @@ impl Migration {
         team_id: &str,
         to_old: bool,
     ) -> Result<crate::types::MigrationExchangeSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
         if to_old {
-            query_args.push(("to_old".to_string(), to_old.to_string()));
+            query_args.push(("to_old", to_old.to_string()));
         }
         if !users.is_empty() {
-            query_args.push(("users".to_string(), users.to_string()));
+            query_args.push(("users", users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/migration.exchange?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/migration.exchange"), &query_args);
 
         self.client.get(&url, None).await
     }
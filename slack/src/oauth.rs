@@ -35,24 +35,23 @@ impl Oauth {
         redirect_uri: &str,
         single_channel: bool,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !redirect_uri.is_empty() {
-            query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
+            query_args.push(("redirect_uri", redirect_uri.to_string()));
         }
         if single_channel {
-            query_args.push(("single_channel".to_string(), single_channel.to_string()));
+            query_args.push(("single_channel", single_channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/oauth.access?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/oauth.access"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -80,24 +79,23 @@ impl Oauth {
         redirect_uri: &str,
         single_channel: bool,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !redirect_uri.is_empty() {
-            query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
+            query_args.push(("redirect_uri", redirect_uri.to_string()));
         }
         if single_channel {
-            query_args.push(("single_channel".to_string(), single_channel.to_string()));
+            query_args.push(("single_channel", single_channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/oauth.token?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/oauth.token"), &query_args);
 
         self.client.get(&url, None).await
     }
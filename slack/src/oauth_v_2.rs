@@ -33,21 +33,21 @@ impl OauthV2 {
         code: &str,
         redirect_uri: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !redirect_uri.is_empty() {
-            query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
+            query_args.push(("redirect_uri", redirect_uri.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/oauth.v2.access?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/oauth.v2.access"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -41,12 +41,11 @@ impl Pins {
     * * `channel: &str` -- Channel to get pinned items for.
     */
     pub async fn list(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/pins.list?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/pins.list"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -64,12 +63,11 @@ impl Pins {
         &self,
         channel: &str,
     ) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/pins.list?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/pins.list"), &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -52,24 +52,23 @@ impl Reactions {
         full: bool,
         timestamp: &str,
     ) -> Result<crate::types::Fields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
         if !file_comment.is_empty() {
-            query_args.push(("file_comment".to_string(), file_comment.to_string()));
+            query_args.push(("file_comment", file_comment.to_string()));
         }
         if full {
-            query_args.push(("full".to_string(), full.to_string()));
+            query_args.push(("full", full.to_string()));
         }
         if !timestamp.is_empty() {
-            query_args.push(("timestamp".to_string(), timestamp.to_string()));
+            query_args.push(("timestamp", timestamp.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reactions.get?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/reactions.get"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -100,27 +99,27 @@ impl Reactions {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::ReactionsListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if full {
-            query_args.push(("full".to_string(), full.to_string()));
+            query_args.push(("full", full.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reactions.list?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/reactions.list"), &query_args);
 
         self.client.get(&url, None).await
     }
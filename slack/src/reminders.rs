@@ -73,12 +73,12 @@ impl Reminders {
     * * `reminder: &str` -- The ID of the reminder.
     */
     pub async fn info(&self, reminder: &str) -> Result<crate::types::RemindersAddSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !reminder.is_empty() {
-            query_args.push(("reminder".to_string(), reminder.to_string()));
+            query_args.push(("reminder", reminder.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reminders.info?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/reminders.info"), &query_args);
 
         self.client.get(&url, None).await
     }
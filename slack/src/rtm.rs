@@ -30,18 +30,14 @@ impl Rtm {
         batch_presence_aware: bool,
         presence_sub: bool,
     ) -> Result<crate::types::RtmConnectSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if batch_presence_aware {
-            query_args.push((
-                "batch_presence_aware".to_string(),
-                batch_presence_aware.to_string(),
-            ));
+            query_args.push(("batch_presence_aware", batch_presence_aware.to_string()));
         }
         if presence_sub {
-            query_args.push(("presence_sub".to_string(), presence_sub.to_string()));
+            query_args.push(("presence_sub", presence_sub.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rtm.connect?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/rtm.connect"), &query_args);
 
         self.client.get(&url, None).await
     }
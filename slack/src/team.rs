@@ -32,18 +32,18 @@ impl Team {
         count: &str,
         page: &str,
     ) -> Result<crate::types::TeamAccessLogsSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.accessLogs?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/team.accessLogs"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -61,12 +61,12 @@ impl Team {
     * * `user: &str` -- A user to retrieve the billable information for. Defaults to all users.
     */
     pub async fn billable_info(&self, user: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.billableInfo?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/team.billableInfo"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -84,12 +84,11 @@ impl Team {
     * * `team: &str` -- Team to get info on, if omitted, will return information about the current team. Will only return team that the authenticated token is allowed to see through external shared channels.
     */
     pub async fn info(&self, team: &str) -> Result<crate::types::TeamInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !team.is_empty() {
-            query_args.push(("team".to_string(), team.to_string()));
+            query_args.push(("team", team.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.info?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/team.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -120,27 +119,29 @@ impl Team {
         service_id: &str,
         user: &str,
     ) -> Result<crate::types::TeamIntegrationLogsSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !app_id.is_empty() {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+            query_args.push(("app_id", app_id.to_string()));
         }
         if !change_type.is_empty() {
-            query_args.push(("change_type".to_string(), change_type.to_string()));
+            query_args.push(("change_type", change_type.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !service_id.is_empty() {
-            query_args.push(("service_id".to_string(), service_id.to_string()));
+            query_args.push(("service_id", service_id.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.integrationLogs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/team.integrationLogs"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -25,12 +25,12 @@ impl TeamProfile {
     * * `visibility: &str` -- Filter by visibility.
     */
     pub async fn get(&self, visibility: &str) -> Result<crate::types::TeamProfileGetSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !visibility.is_empty() {
-            query_args.push(("visibility".to_string(), visibility.to_string()));
+            query_args.push(("visibility", visibility.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.profile.get?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/team.profile.get"), &query_args);
 
         self.client.get(&url, None).await
     }
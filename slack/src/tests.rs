@@ -1 +1,92 @@
+use crate::blocks::{Block, Button, Message, Section, Text};
 
+#[test]
+fn test_blocks_serialization() {
+    let message = Message::new("Deploy #42 finished")
+        .in_channel("C1234567890")
+        .with_block(Block::header("Deploy #42"))
+        .with_block(
+            Section::new(Text::markdown("*api* was deployed."))
+                .with_field(Text::markdown("*Commit*"))
+                .with_accessory(Button::new("Logs", "view_logs").with_url("https://example.com")),
+        )
+        .with_block(Block::divider())
+        .with_block(Block::actions(vec![Button::new("Roll back", "roll_back")
+            .with_value("42")
+            .danger()]))
+        .with_block(Block::context(vec![Text::markdown("by <@U0123>")]));
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        serde_json::json!({
+            "channel": "C1234567890",
+            "text": "Deploy #42 finished",
+            "blocks": [
+                {"type": "header", "text": {"type": "plain_text", "text": "Deploy #42", "emoji": true}},
+                {
+                    "type": "section",
+                    "text": {"type": "mrkdwn", "text": "*api* was deployed."},
+                    "fields": [{"type": "mrkdwn", "text": "*Commit*"}],
+                    "accessory": {
+                        "type": "button",
+                        "text": {"type": "plain_text", "text": "Logs", "emoji": true},
+                        "action_id": "view_logs",
+                        "url": "https://example.com"
+                    }
+                },
+                {"type": "divider"},
+                {
+                    "type": "actions",
+                    "elements": [{
+                        "type": "button",
+                        "text": {"type": "plain_text", "text": "Roll back", "emoji": true},
+                        "action_id": "roll_back",
+                        "value": "42",
+                        "style": "danger"
+                    }]
+                },
+                {"type": "context", "elements": [{"type": "mrkdwn", "text": "by <@U0123>"}]}
+            ]
+        })
+    );
+
+    let parsed: Message = serde_json::from_value(serde_json::to_value(&message).unwrap()).unwrap();
+    assert_eq!(parsed, message);
+}
+
+#[tokio::test]
+async fn test_post_blocks() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"ok": true, "channel": "C1234567890", "ts": "1503435956.000247", "message": {"text": "hi"}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"ok": false, "error": "channel_not_found"}"#,
+    );
+    mock.push_response(reqwest::StatusCode::NOT_FOUND, "no_service");
+
+    let slack = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let message = Message::new("hi")
+        .in_channel("C1234567890")
+        .with_block(Block::section("hi"));
+
+    let resp = slack.chat().post_blocks(&message).await.unwrap();
+    assert_eq!(resp.ts, "1503435956.000247");
+    assert_eq!(mock.requests()[0].json::<Message>().unwrap(), message);
+
+    let err = slack.chat().post_blocks(&message).await.unwrap_err();
+    assert!(err.to_string().contains("channel_not_found"));
+
+    let err = slack
+        .chat()
+        .post_webhook("https://hooks.slack.com/services/T000/B000/XXXX", &message)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no_service"));
+    assert!(mock.requests()[2]
+        .headers
+        .get(reqwest::header::AUTHORIZATION)
+        .is_none());
+}
@@ -2135,8 +2135,8 @@ pub enum Warnings {
     MethodDeprecated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Warnings {
@@ -2144,7 +2144,7 @@ impl std::fmt::Display for Warnings {
         match &*self {
             Warnings::MethodDeprecated => "method_deprecated",
             Warnings::Noop => "",
-            Warnings::FallthroughString => "*",
+            Warnings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2223,8 +2223,8 @@ pub enum AutoType {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoType {
@@ -2233,7 +2233,7 @@ impl std::fmt::Display for AutoType {
             AutoType::Admin => "admin",
             AutoType::Owner => "owner",
             AutoType::Noop => "",
-            AutoType::FallthroughString => "*",
+            AutoType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2439,8 +2439,8 @@ pub enum Plan {
     Std,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Plan {
@@ -2451,7 +2451,7 @@ impl std::fmt::Display for Plan {
             Plan::Plus => "plus",
             Plan::Std => "std",
             Plan::Noop => "",
-            Plan::FallthroughString => "*",
+            Plan::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2671,8 +2671,8 @@ pub enum Type {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2685,7 +2685,7 @@ impl std::fmt::Display for Type {
             Type::Text => "text",
             Type::User => "user",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3615,7 +3615,7 @@ pub struct ObjsUserProfileShort {
     pub team: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdminInviteRequestsApproveRequest {
     #[serde(
         default,
@@ -3631,6 +3631,34 @@ pub struct AdminInviteRequestsApproveRequest {
     pub team_id: String,
 }
 
+impl AdminInviteRequestsApproveRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AdminInviteRequestsApproveRequestBuilder {
+        AdminInviteRequestsApproveRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AdminInviteRequestsApproveRequest`.
+#[derive(Debug, Clone)]
+pub struct AdminInviteRequestsApproveRequestBuilder(AdminInviteRequestsApproveRequest);
+
+impl AdminInviteRequestsApproveRequestBuilder {
+    pub fn invite_request_id(mut self, invite_request_id: impl Into<String>) -> Self {
+        self.0.invite_request_id = invite_request_id.into();
+        self
+    }
+
+    pub fn team_id(mut self, team_id: impl Into<String>) -> Self {
+        self.0.team_id = team_id.into();
+        self
+    }
+
+    pub fn build(self) -> AdminInviteRequestsApproveRequest {
+        self.0
+    }
+}
+
 /// Schema for successful response from dnd.endDnd method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DndEndSchema {
@@ -4835,8 +4863,8 @@ pub enum ItemsType {
     File,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ItemsType {
@@ -4844,7 +4872,7 @@ impl std::fmt::Display for ItemsType {
         match &*self {
             ItemsType::File => "file",
             ItemsType::Noop => "",
-            ItemsType::FallthroughString => "*",
+            ItemsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4887,8 +4915,8 @@ pub enum MessagePinType {
     Message,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MessagePinType {
@@ -4896,7 +4924,7 @@ impl std::fmt::Display for MessagePinType {
         match &*self {
             MessagePinType::Message => "message",
             MessagePinType::Noop => "",
-            MessagePinType::FallthroughString => "*",
+            MessagePinType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
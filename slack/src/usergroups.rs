@@ -80,18 +80,18 @@ impl Usergroups {
         include_count: bool,
         include_disabled: bool,
     ) -> Result<crate::types::UsergroupsListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_count {
-            query_args.push(("include_count".to_string(), include_count.to_string()));
+            query_args.push(("include_count", include_count.to_string()));
         }
         if include_disabled {
-            query_args.push(("include_disabled".to_string(), include_disabled.to_string()));
+            query_args.push(("include_disabled", include_disabled.to_string()));
         }
         if include_users {
-            query_args.push(("include_users".to_string(), include_users.to_string()));
+            query_args.push(("include_users", include_users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/usergroups.list?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/usergroups.list"), &query_args);
 
         self.client.get(&url, None).await
     }
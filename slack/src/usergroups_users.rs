@@ -30,15 +30,17 @@ impl UsergroupsUsers {
         include_disabled: bool,
         usergroup: &str,
     ) -> Result<crate::types::UsergroupsUsersListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_disabled {
-            query_args.push(("include_disabled".to_string(), include_disabled.to_string()));
+            query_args.push(("include_disabled", include_disabled.to_string()));
         }
         if !usergroup.is_empty() {
-            query_args.push(("usergroup".to_string(), usergroup.to_string()));
+            query_args.push(("usergroup", usergroup.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/usergroups.users.list?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/usergroups.users.list"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
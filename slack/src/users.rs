@@ -36,24 +36,24 @@ impl Users {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::UsersConversationsSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if exclude_archived {
-            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+            query_args.push(("exclude_archived", exclude_archived.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !types.is_empty() {
-            query_args.push(("types".to_string(), types.to_string()));
+            query_args.push(("types", types.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.conversations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/users.conversations"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -86,12 +86,12 @@ impl Users {
         &self,
         user: &str,
     ) -> Result<crate::types::ApiMethodUsersGetPresence> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.getPresence?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/users.getPresence"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -144,15 +144,14 @@ impl Users {
         include_locale: bool,
         user: &str,
     ) -> Result<crate::types::UsersInfoSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_locale {
-            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+            query_args.push(("include_locale", include_locale.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.info?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users.info"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -177,18 +176,17 @@ impl Users {
         cursor: &str,
         include_locale: bool,
     ) -> Result<crate::types::UsersListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if include_locale {
-            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+            query_args.push(("include_locale", include_locale.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.list?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users.list"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -206,12 +204,12 @@ impl Users {
     * * `email: &str` -- An email address belonging to a user in the workspace.
     */
     pub async fn lookup_email(&self, email: &str) -> Result<crate::types::UsersInfoSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.lookupByEmail?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/users.lookupByEmail"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -30,15 +30,15 @@ impl UsersProfile {
         include_labels: bool,
         user: &str,
     ) -> Result<crate::types::UsersProfileGetSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_labels {
-            query_args.push(("include_labels".to_string(), include_labels.to_string()));
+            query_args.push(("include_labels", include_labels.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.profile.get?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/users.profile.get"), &query_args);
 
         self.client.get(&url, None).await
     }
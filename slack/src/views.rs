@@ -26,15 +26,14 @@ impl Views {
     * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
     */
     pub async fn open(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.open?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/views.open"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -59,18 +58,17 @@ impl Views {
         view: &str,
         hash: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !hash.is_empty() {
-            query_args.push(("hash".to_string(), hash.to_string()));
+            query_args.push(("hash", hash.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.publish?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/views.publish"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -89,15 +87,14 @@ impl Views {
     * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
     */
     pub async fn push(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.push?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/views.push"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -124,21 +121,20 @@ impl Views {
         view: &str,
         hash: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !external_id.is_empty() {
-            query_args.push(("external_id".to_string(), external_id.to_string()));
+            query_args.push(("external_id", external_id.to_string()));
         }
         if !hash.is_empty() {
-            query_args.push(("hash".to_string(), hash.to_string()));
+            query_args.push(("hash", hash.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
         if !view_id.is_empty() {
-            query_args.push(("view_id".to_string(), view_id.to_string()));
+            query_args.push(("view_id", view_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.update?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/views.update"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -30,18 +30,20 @@ impl Workflows {
         workflow_step_execute_id: &str,
         outputs: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !outputs.is_empty() {
-            query_args.push(("outputs".to_string(), outputs.to_string()));
+            query_args.push(("outputs", outputs.to_string()));
         }
         if !workflow_step_execute_id.is_empty() {
             query_args.push((
-                "workflow_step_execute_id".to_string(),
+                "workflow_step_execute_id",
                 workflow_step_execute_id.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/workflows.stepCompleted?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/workflows.stepCompleted"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -64,18 +66,20 @@ impl Workflows {
         workflow_step_execute_id: &str,
         error: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !error.is_empty() {
-            query_args.push(("error".to_string(), error.to_string()));
+            query_args.push(("error", error.to_string()));
         }
         if !workflow_step_execute_id.is_empty() {
             query_args.push((
-                "workflow_step_execute_id".to_string(),
+                "workflow_step_execute_id",
                 workflow_step_execute_id.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/workflows.stepFailed?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/workflows.stepFailed"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -104,27 +108,26 @@ impl Workflows {
         step_name: &str,
         step_image_url: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inputs.is_empty() {
-            query_args.push(("inputs".to_string(), inputs.to_string()));
+            query_args.push(("inputs", inputs.to_string()));
         }
         if !outputs.is_empty() {
-            query_args.push(("outputs".to_string(), outputs.to_string()));
+            query_args.push(("outputs", outputs.to_string()));
         }
         if !step_image_url.is_empty() {
-            query_args.push(("step_image_url".to_string(), step_image_url.to_string()));
+            query_args.push(("step_image_url", step_image_url.to_string()));
         }
         if !step_name.is_empty() {
-            query_args.push(("step_name".to_string(), step_name.to_string()));
+            query_args.push(("step_name", step_name.to_string()));
         }
         if !workflow_step_edit_id.is_empty() {
-            query_args.push((
-                "workflow_step_edit_id".to_string(),
-                workflow_step_edit_id.to_string(),
-            ));
+            query_args.push(("workflow_step_edit_id", workflow_step_edit_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/workflows.updateStep?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/workflows.updateStep"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -72,7 +72,8 @@ impl Account {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get_bank(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn get_bank(&self, id: impl AsRef<str>) -> Result<crate::types::DataAnyOf> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/account/bank_accounts/{}",
             crate::progenitor_support::encode_path(id),
@@ -92,7 +93,8 @@ impl Account {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_bank_account(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank_account(&self, id: impl AsRef<str>) -> Result<crate::types::DataAnyOf> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/account/bank_accounts/{}",
             crate::progenitor_support::encode_path(id),
@@ -110,7 +112,11 @@ impl Account {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn delete_bank(&self, id: &str) -> Result<crate::types::DeletedExternalAccountAnyOf> {
+    pub async fn delete_bank(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::DeletedExternalAccountAnyOf> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/account/bank_accounts/{}",
             crate::progenitor_support::encode_path(id),
@@ -163,17 +169,13 @@ impl Account {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -196,8 +198,9 @@ impl Account {
     */
     pub async fn get_capabilities_capability(
         &self,
-        capability: &str,
+        capability: impl AsRef<str>,
     ) -> Result<crate::types::Capability> {
+        let capability = capability.as_ref();
         let url = format!(
             "/v1/account/capabilities/{}",
             crate::progenitor_support::encode_path(capability),
@@ -217,8 +220,9 @@ impl Account {
     */
     pub async fn post_capabilities_capability(
         &self,
-        capability: &str,
+        capability: impl AsRef<str>,
     ) -> Result<crate::types::Capability> {
+        let capability = capability.as_ref();
         let url = format!(
             "/v1/account/capabilities/{}",
             crate::progenitor_support::encode_path(capability),
@@ -245,18 +249,20 @@ impl Account {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::DataAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/account/external_accounts?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/account/external_accounts"),
+            &query_args,
+        );
 
         let resp: crate::types::ExternalAccounts = self.client.get(&url, None).await?;
 
@@ -291,17 +297,13 @@ impl Account {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -332,7 +334,11 @@ impl Account {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get_external_account(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn get_external_account(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::DataAnyOf> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/account/external_accounts/{}",
             crate::progenitor_support::encode_path(id),
@@ -352,7 +358,11 @@ impl Account {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_external_account(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external_account(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::DataAnyOf> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/account/external_accounts/{}",
             crate::progenitor_support::encode_path(id),
@@ -372,8 +382,9 @@ impl Account {
     */
     pub async fn delete_external(
         &self,
-        id: &str,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DeletedExternalAccountAnyOf> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/account/external_accounts/{}",
             crate::progenitor_support::encode_path(id),
@@ -414,18 +425,18 @@ impl Account {
         _relationship: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Person>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/account/people?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/account/people"), &query_args);
 
         let resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
 
@@ -460,17 +471,13 @@ impl Account {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -501,7 +508,8 @@ impl Account {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `person: &str` -- The account's country.
     */
-    pub async fn get_people_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn get_people_person(&self, person: impl AsRef<str>) -> Result<crate::types::Person> {
+        let person = person.as_ref();
         let url = format!(
             "/v1/account/people/{}",
             crate::progenitor_support::encode_path(person),
@@ -519,7 +527,11 @@ impl Account {
     *
     * * `person: &str` -- The account's country.
     */
-    pub async fn post_people_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn post_people_person(
+        &self,
+        person: impl AsRef<str>,
+    ) -> Result<crate::types::Person> {
+        let person = person.as_ref();
         let url = format!(
             "/v1/account/people/{}",
             crate::progenitor_support::encode_path(person),
@@ -537,7 +549,11 @@ impl Account {
     *
     * * `person: &str` -- The account's country.
     */
-    pub async fn delete_people_person(&self, person: &str) -> Result<crate::types::DeletedPerson> {
+    pub async fn delete_people_person(
+        &self,
+        person: impl AsRef<str>,
+    ) -> Result<crate::types::DeletedPerson> {
+        let person = person.as_ref();
         let url = format!(
             "/v1/account/people/{}",
             crate::progenitor_support::encode_path(person),
@@ -566,18 +582,18 @@ impl Account {
         _relationship: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Person>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/account/persons?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/account/persons"), &query_args);
 
         let resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
 
@@ -612,17 +628,13 @@ impl Account {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -653,7 +665,11 @@ impl Account {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `person: &str` -- The account's country.
     */
-    pub async fn get_persons_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn get_persons_person(
+        &self,
+        person: impl AsRef<str>,
+    ) -> Result<crate::types::Person> {
+        let person = person.as_ref();
         let url = format!(
             "/v1/account/persons/{}",
             crate::progenitor_support::encode_path(person),
@@ -671,7 +687,11 @@ impl Account {
     *
     * * `person: &str` -- The account's country.
     */
-    pub async fn post_persons_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn post_persons_person(
+        &self,
+        person: impl AsRef<str>,
+    ) -> Result<crate::types::Person> {
+        let person = person.as_ref();
         let url = format!(
             "/v1/account/persons/{}",
             crate::progenitor_support::encode_path(person),
@@ -689,7 +709,11 @@ impl Account {
     *
     * * `person: &str` -- The account's country.
     */
-    pub async fn delete_persons_person(&self, person: &str) -> Result<crate::types::DeletedPerson> {
+    pub async fn delete_persons_person(
+        &self,
+        person: impl AsRef<str>,
+    ) -> Result<crate::types::DeletedPerson> {
+        let person = person.as_ref();
         let url = format!(
             "/v1/account/persons/{}",
             crate::progenitor_support::encode_path(person),
@@ -32,18 +32,17 @@ impl Accounts {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Account>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/accounts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/accounts"), &query_args);
 
         let resp: crate::types::GetAccountsResponse = self.client.get(&url, None).await?;
 
@@ -78,17 +77,13 @@ impl Accounts {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -120,7 +115,8 @@ impl Accounts {
     * * `account: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get(&self, account: &str) -> Result<crate::types::Account> {
+    pub async fn get(&self, account: impl AsRef<str>) -> Result<crate::types::Account> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -140,7 +136,8 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn post_accounts(&self, account: &str) -> Result<crate::types::Account> {
+    pub async fn post_accounts(&self, account: impl AsRef<str>) -> Result<crate::types::Account> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -162,7 +159,8 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn delete(&self, account: &str) -> Result<crate::types::DeletedAccount> {
+    pub async fn delete(&self, account: impl AsRef<str>) -> Result<crate::types::DeletedAccount> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -180,7 +178,8 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn post_bank(&self, account: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank(&self, account: impl AsRef<str>) -> Result<crate::types::DataAnyOf> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/bank_accounts",
             crate::progenitor_support::encode_path(account),
@@ -200,7 +199,13 @@ impl Accounts {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get_bank(&self, account: &str, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn get_bank(
+        &self,
+        account: impl AsRef<str>,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::DataAnyOf> {
+        let account = account.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/accounts/{}/bank_accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -224,9 +229,11 @@ impl Accounts {
     */
     pub async fn post_bank_accounts(
         &self,
-        account: &str,
-        id: &str,
+        account: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DataAnyOf> {
+        let account = account.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/accounts/{}/bank_accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -248,9 +255,11 @@ impl Accounts {
     */
     pub async fn delete_bank(
         &self,
-        account: &str,
-        id: &str,
+        account: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DeletedExternalAccountAnyOf> {
+        let account = account.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/accounts/{}/bank_accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -270,7 +279,11 @@ impl Accounts {
     * * `account: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get_capabilities(&self, account: &str) -> Result<Vec<crate::types::Capability>> {
+    pub async fn get_capabilities(
+        &self,
+        account: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Capability>> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/capabilities",
             crate::progenitor_support::encode_path(account),
@@ -291,8 +304,9 @@ impl Accounts {
     */
     pub async fn get_all_capabilities(
         &self,
-        account: &str,
+        account: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Capability>> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/capabilities",
             crate::progenitor_support::encode_path(account),
@@ -316,17 +330,13 @@ impl Accounts {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -350,9 +360,11 @@ impl Accounts {
     */
     pub async fn get_capabilities_capability(
         &self,
-        account: &str,
-        capability: &str,
+        account: impl AsRef<str>,
+        capability: impl AsRef<str>,
     ) -> Result<crate::types::Capability> {
+        let account = account.as_ref();
+        let capability = capability.as_ref();
         let url = format!(
             "/v1/accounts/{}/capabilities/{}",
             crate::progenitor_support::encode_path(account),
@@ -374,9 +386,11 @@ impl Accounts {
     */
     pub async fn post_capabilities_capability(
         &self,
-        account: &str,
-        capability: &str,
+        account: impl AsRef<str>,
+        capability: impl AsRef<str>,
     ) -> Result<crate::types::Capability> {
+        let account = account.as_ref();
+        let capability = capability.as_ref();
         let url = format!(
             "/v1/accounts/{}/capabilities/{}",
             crate::progenitor_support::encode_path(account),
@@ -401,26 +415,28 @@ impl Accounts {
     */
     pub async fn get_external(
         &self,
-        account: &str,
+        account: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::DataAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account = account.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/accounts/{}/external_accounts?{}",
-            crate::progenitor_support::encode_path(account),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/accounts/{}/external_accounts",
+                crate::progenitor_support::encode_path(account),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ExternalAccounts = self.client.get(&url, None).await?;
@@ -436,7 +452,11 @@ impl Accounts {
     *
     * <p>List external accounts for an account.</p>
     */
-    pub async fn get_all_external(&self, account: &str) -> Result<Vec<crate::types::DataAnyOf>> {
+    pub async fn get_all_external(
+        &self,
+        account: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::DataAnyOf>> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/external_accounts",
             crate::progenitor_support::encode_path(account),
@@ -460,17 +480,13 @@ impl Accounts {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -490,7 +506,8 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn post_external(&self, account: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external(&self, account: impl AsRef<str>) -> Result<crate::types::DataAnyOf> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/external_accounts",
             crate::progenitor_support::encode_path(account),
@@ -512,9 +529,11 @@ impl Accounts {
     */
     pub async fn get_external_accounts(
         &self,
-        account: &str,
-        id: &str,
+        account: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DataAnyOf> {
+        let account = account.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/accounts/{}/external_accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -538,9 +557,11 @@ impl Accounts {
     */
     pub async fn post_external_accounts(
         &self,
-        account: &str,
-        id: &str,
+        account: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DataAnyOf> {
+        let account = account.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/accounts/{}/external_accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -562,9 +583,11 @@ impl Accounts {
     */
     pub async fn delete_external(
         &self,
-        account: &str,
-        id: &str,
+        account: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DeletedExternalAccountAnyOf> {
+        let account = account.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/accounts/{}/external_accounts/{}",
             crate::progenitor_support::encode_path(account),
@@ -585,7 +608,11 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn post_login_link(&self, account: &str) -> Result<crate::types::LoginLink> {
+    pub async fn post_login_link(
+        &self,
+        account: impl AsRef<str>,
+    ) -> Result<crate::types::LoginLink> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/login_links",
             crate::progenitor_support::encode_path(account),
@@ -610,27 +637,29 @@ impl Accounts {
     */
     pub async fn get_people(
         &self,
-        account: &str,
+        account: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         _relationship: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Person>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account = account.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/accounts/{}/people?{}",
-            crate::progenitor_support::encode_path(account),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/accounts/{}/people",
+                crate::progenitor_support::encode_path(account),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
@@ -648,9 +677,10 @@ impl Accounts {
     */
     pub async fn get_all_people(
         &self,
-        account: &str,
+        account: impl AsRef<str>,
         _relationship: &str,
     ) -> Result<Vec<crate::types::Person>> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/people",
             crate::progenitor_support::encode_path(account),
@@ -674,17 +704,13 @@ impl Accounts {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -704,7 +730,8 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn post_people(&self, account: &str) -> Result<crate::types::Person> {
+    pub async fn post_people(&self, account: impl AsRef<str>) -> Result<crate::types::Person> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/people",
             crate::progenitor_support::encode_path(account),
@@ -726,9 +753,11 @@ impl Accounts {
     */
     pub async fn get_people_person(
         &self,
-        account: &str,
-        person: &str,
+        account: impl AsRef<str>,
+        person: impl AsRef<str>,
     ) -> Result<crate::types::Person> {
+        let account = account.as_ref();
+        let person = person.as_ref();
         let url = format!(
             "/v1/accounts/{}/people/{}",
             crate::progenitor_support::encode_path(account),
@@ -750,9 +779,11 @@ impl Accounts {
     */
     pub async fn post_people_person(
         &self,
-        account: &str,
-        person: &str,
+        account: impl AsRef<str>,
+        person: impl AsRef<str>,
     ) -> Result<crate::types::Person> {
+        let account = account.as_ref();
+        let person = person.as_ref();
         let url = format!(
             "/v1/accounts/{}/people/{}",
             crate::progenitor_support::encode_path(account),
@@ -774,9 +805,11 @@ impl Accounts {
     */
     pub async fn delete_people_person(
         &self,
-        account: &str,
-        person: &str,
+        account: impl AsRef<str>,
+        person: impl AsRef<str>,
     ) -> Result<crate::types::DeletedPerson> {
+        let account = account.as_ref();
+        let person = person.as_ref();
         let url = format!(
             "/v1/accounts/{}/people/{}",
             crate::progenitor_support::encode_path(account),
@@ -802,27 +835,29 @@ impl Accounts {
     */
     pub async fn get_persons(
         &self,
-        account: &str,
+        account: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         _relationship: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Person>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account = account.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/accounts/{}/persons?{}",
-            crate::progenitor_support::encode_path(account),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/accounts/{}/persons",
+                crate::progenitor_support::encode_path(account),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
@@ -840,9 +875,10 @@ impl Accounts {
     */
     pub async fn get_all_persons(
         &self,
-        account: &str,
+        account: impl AsRef<str>,
         _relationship: &str,
     ) -> Result<Vec<crate::types::Person>> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/persons",
             crate::progenitor_support::encode_path(account),
@@ -866,17 +902,13 @@ impl Accounts {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -896,7 +928,8 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn post_person(&self, account: &str) -> Result<crate::types::Person> {
+    pub async fn post_person(&self, account: impl AsRef<str>) -> Result<crate::types::Person> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/persons",
             crate::progenitor_support::encode_path(account),
@@ -918,9 +951,11 @@ impl Accounts {
     */
     pub async fn get_persons_person(
         &self,
-        account: &str,
-        person: &str,
+        account: impl AsRef<str>,
+        person: impl AsRef<str>,
     ) -> Result<crate::types::Person> {
+        let account = account.as_ref();
+        let person = person.as_ref();
         let url = format!(
             "/v1/accounts/{}/persons/{}",
             crate::progenitor_support::encode_path(account),
@@ -942,9 +977,11 @@ impl Accounts {
     */
     pub async fn post_persons_person(
         &self,
-        account: &str,
-        person: &str,
+        account: impl AsRef<str>,
+        person: impl AsRef<str>,
     ) -> Result<crate::types::Person> {
+        let account = account.as_ref();
+        let person = person.as_ref();
         let url = format!(
             "/v1/accounts/{}/persons/{}",
             crate::progenitor_support::encode_path(account),
@@ -966,9 +1003,11 @@ impl Accounts {
     */
     pub async fn delete_persons_person(
         &self,
-        account: &str,
-        person: &str,
+        account: impl AsRef<str>,
+        person: impl AsRef<str>,
     ) -> Result<crate::types::DeletedPerson> {
+        let account = account.as_ref();
+        let person = person.as_ref();
         let url = format!(
             "/v1/accounts/{}/persons/{}",
             crate::progenitor_support::encode_path(account),
@@ -989,7 +1028,8 @@ impl Accounts {
     *
     * * `account: &str` -- The account's country.
     */
-    pub async fn post_reject(&self, account: &str) -> Result<crate::types::Account> {
+    pub async fn post_reject(&self, account: impl AsRef<str>) -> Result<crate::types::Account> {
+        let account = account.as_ref();
         let url = format!(
             "/v1/accounts/{}/reject",
             crate::progenitor_support::encode_path(account),
@@ -32,21 +32,23 @@ impl ApplePay {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::ApplePayDomain>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain_name.is_empty() {
-            query_args.push(("domain_name".to_string(), domain_name.to_string()));
+            query_args.push(("domain_name", domain_name.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/apple_pay/domains?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/apple_pay/domains"),
+            &query_args,
+        );
 
         let resp: crate::types::ApplePayDomainList = self.client.get(&url, None).await?;
 
@@ -65,12 +67,14 @@ impl ApplePay {
         &self,
         domain_name: &str,
     ) -> Result<Vec<crate::types::ApplePayDomain>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain_name.is_empty() {
-            query_args.push(("domain_name".to_string(), domain_name.to_string()));
+            query_args.push(("domain_name", domain_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/apple_pay/domains?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/apple_pay/domains"),
+            &query_args,
+        );
 
         let mut resp: crate::types::ApplePayDomainList = self.client.get(&url, None).await?;
 
@@ -90,17 +94,13 @@ impl ApplePay {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -131,7 +131,11 @@ impl ApplePay {
     * * `domain: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get_domains_domain(&self, domain: &str) -> Result<crate::types::ApplePayDomain> {
+    pub async fn get_domains_domain(
+        &self,
+        domain: impl AsRef<str>,
+    ) -> Result<crate::types::ApplePayDomain> {
+        let domain = domain.as_ref();
         let url = format!(
             "/v1/apple_pay/domains/{}",
             crate::progenitor_support::encode_path(domain),
@@ -151,8 +155,9 @@ impl ApplePay {
     */
     pub async fn delete_domains_domain(
         &self,
-        domain: &str,
+        domain: impl AsRef<str>,
     ) -> Result<crate::types::DeletedApplePayDomain> {
+        let domain = domain.as_ref();
         let url = format!(
             "/v1/apple_pay/domains/{}",
             crate::progenitor_support::encode_path(domain),
@@ -34,21 +34,21 @@ impl ApplicationFees {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::PlatformFee>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/application_fees?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/application_fees"), &query_args);
 
         let resp: crate::types::GetApplicationFeesResponse = self.client.get(&url, None).await?;
 
@@ -68,12 +68,12 @@ impl ApplicationFees {
         charge: &str,
         _created: &str,
     ) -> Result<Vec<crate::types::PlatformFee>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/application_fees?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/application_fees"), &query_args);
 
         let mut resp: crate::types::GetApplicationFeesResponse =
             self.client.get(&url, None).await?;
@@ -94,17 +94,13 @@ impl ApplicationFees {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -126,7 +122,13 @@ impl ApplicationFees {
     * * `fee: &str` -- The account's country.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get_fee_refund(&self, fee: &str, id: &str) -> Result<crate::types::FeeRefund> {
+    pub async fn get_fee_refund(
+        &self,
+        fee: impl AsRef<str>,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::FeeRefund> {
+        let fee = fee.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/application_fees/{}/refunds/{}",
             crate::progenitor_support::encode_path(fee),
@@ -148,7 +150,13 @@ impl ApplicationFees {
     * * `fee: &str` -- The account's country.
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_fee_refund(&self, fee: &str, id: &str) -> Result<crate::types::FeeRefund> {
+    pub async fn post_fee_refund(
+        &self,
+        fee: impl AsRef<str>,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::FeeRefund> {
+        let fee = fee.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/application_fees/{}/refunds/{}",
             crate::progenitor_support::encode_path(fee),
@@ -168,7 +176,8 @@ impl ApplicationFees {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::PlatformFee> {
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::PlatformFee> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/application_fees/{}",
             crate::progenitor_support::encode_path(id),
@@ -186,7 +195,8 @@ impl ApplicationFees {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_refund(&self, id: &str) -> Result<crate::types::PlatformFee> {
+    pub async fn post_refund(&self, id: impl AsRef<str>) -> Result<crate::types::PlatformFee> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/application_fees/{}/refund",
             crate::progenitor_support::encode_path(id),
@@ -211,25 +221,27 @@ impl ApplicationFees {
     pub async fn get_refunds(
         &self,
         ending_before: &str,
-        id: &str,
+        id: impl AsRef<str>,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::FeeRefund>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/application_fees/{}/refunds?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/application_fees/{}/refunds",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Refunds = self.client.get(&url, None).await?;
@@ -245,7 +257,11 @@ impl ApplicationFees {
     *
     * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
     */
-    pub async fn get_all_refunds(&self, id: &str) -> Result<Vec<crate::types::FeeRefund>> {
+    pub async fn get_all_refunds(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::FeeRefund>> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/application_fees/{}/refunds",
             crate::progenitor_support::encode_path(id),
@@ -269,17 +285,13 @@ impl ApplicationFees {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -307,7 +319,11 @@ impl ApplicationFees {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_refund_application_fees(&self, id: &str) -> Result<crate::types::FeeRefund> {
+    pub async fn post_refund_application_fees(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::FeeRefund> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/application_fees/{}/refunds",
             crate::progenitor_support::encode_path(id),
@@ -42,30 +42,32 @@ impl BalanceTransactions {
         starting_after: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::BalanceTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !payout.is_empty() {
-            query_args.push(("payout".to_string(), payout.to_string()));
+            query_args.push(("payout", payout.to_string()));
         }
         if !source.is_empty() {
-            query_args.push(("source".to_string(), source.to_string()));
+            query_args.push(("source", source.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/balance_transactions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/balance_transactions"),
+            &query_args,
+        );
 
         let resp: crate::types::BalanceTransactionsList = self.client.get(&url, None).await?;
 
@@ -90,21 +92,23 @@ impl BalanceTransactions {
         source: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::BalanceTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !payout.is_empty() {
-            query_args.push(("payout".to_string(), payout.to_string()));
+            query_args.push(("payout", payout.to_string()));
         }
         if !source.is_empty() {
-            query_args.push(("source".to_string(), source.to_string()));
+            query_args.push(("source", source.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/balance_transactions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/balance_transactions"),
+            &query_args,
+        );
 
         let mut resp: crate::types::BalanceTransactionsList = self.client.get(&url, None).await?;
 
@@ -124,17 +128,13 @@ impl BalanceTransactions {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -157,7 +157,8 @@ impl BalanceTransactions {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::BalanceTransaction> {
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::BalanceTransaction> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/balance_transactions/{}",
             crate::progenitor_support::encode_path(id),
@@ -34,24 +34,26 @@ impl BillingPortal {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::PortalConfiguration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if is_default {
-            query_args.push(("is_default".to_string(), is_default.to_string()));
+            query_args.push(("is_default", is_default.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/billing_portal/configurations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/billing_portal/configurations"),
+            &query_args,
+        );
 
         let resp: crate::types::GetBillingPortalConfigurationsResponse =
             self.client.get(&url, None).await?;
@@ -72,15 +74,17 @@ impl BillingPortal {
         active: bool,
         is_default: bool,
     ) -> Result<Vec<crate::types::PortalConfiguration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if is_default {
-            query_args.push(("is_default".to_string(), is_default.to_string()));
+            query_args.push(("is_default", is_default.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/billing_portal/configurations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/billing_portal/configurations"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetBillingPortalConfigurationsResponse =
             self.client.get(&url, None).await?;
@@ -101,17 +105,13 @@ impl BillingPortal {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -144,8 +144,9 @@ impl BillingPortal {
     */
     pub async fn get_configurations_configuration(
         &self,
-        configuration: &str,
+        configuration: impl AsRef<str>,
     ) -> Result<crate::types::PortalConfiguration> {
+        let configuration = configuration.as_ref();
         let url = format!(
             "/v1/billing_portal/configurations/{}",
             crate::progenitor_support::encode_path(configuration),
@@ -165,8 +166,9 @@ impl BillingPortal {
     */
     pub async fn post_configurations_configuration(
         &self,
-        configuration: &str,
+        configuration: impl AsRef<str>,
     ) -> Result<crate::types::PortalConfiguration> {
+        let configuration = configuration.as_ref();
         let url = format!(
             "/v1/billing_portal/configurations/{}",
             crate::progenitor_support::encode_path(configuration),
@@ -36,27 +36,29 @@ impl Bitcoin {
         starting_after: &str,
         uncaptured_funds: bool,
     ) -> Result<Vec<crate::types::BitcoinReceiver>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if filled {
-            query_args.push(("filled".to_string(), filled.to_string()));
+            query_args.push(("filled", filled.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if uncaptured_funds {
-            query_args.push(("uncaptured_funds".to_string(), uncaptured_funds.to_string()));
+            query_args.push(("uncaptured_funds", uncaptured_funds.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bitcoin/receivers?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/bitcoin/receivers"),
+            &query_args,
+        );
 
         let resp: crate::types::GetBitcoinReceiversResponse = self.client.get(&url, None).await?;
 
@@ -77,18 +79,20 @@ impl Bitcoin {
         filled: bool,
         uncaptured_funds: bool,
     ) -> Result<Vec<crate::types::BitcoinReceiver>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if filled {
-            query_args.push(("filled".to_string(), filled.to_string()));
+            query_args.push(("filled", filled.to_string()));
         }
         if uncaptured_funds {
-            query_args.push(("uncaptured_funds".to_string(), uncaptured_funds.to_string()));
+            query_args.push(("uncaptured_funds", uncaptured_funds.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bitcoin/receivers?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/bitcoin/receivers"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetBitcoinReceiversResponse =
             self.client.get(&url, None).await?;
@@ -109,17 +113,13 @@ impl Bitcoin {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -140,7 +140,8 @@ impl Bitcoin {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get_receiver(&self, id: &str) -> Result<crate::types::BitcoinReceiver> {
+    pub async fn get_receiver(&self, id: impl AsRef<str>) -> Result<crate::types::BitcoinReceiver> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/bitcoin/receivers/{}",
             crate::progenitor_support::encode_path(id),
@@ -168,27 +169,29 @@ impl Bitcoin {
         customer: &str,
         ending_before: &str,
         limit: i64,
-        receiver: &str,
+        receiver: impl AsRef<str>,
         starting_after: &str,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let receiver = receiver.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/bitcoin/receivers/{}/transactions?{}",
-            crate::progenitor_support::encode_path(receiver),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/bitcoin/receivers/{}/transactions",
+                crate::progenitor_support::encode_path(receiver),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Transactions = self.client.get(&url, None).await?;
@@ -207,17 +210,19 @@ impl Bitcoin {
     pub async fn get_all_receivers_receiver_transactions(
         &self,
         customer: &str,
-        receiver: &str,
+        receiver: impl AsRef<str>,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let receiver = receiver.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/bitcoin/receivers/{}/transactions?{}",
-            crate::progenitor_support::encode_path(receiver),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/bitcoin/receivers/{}/transactions",
+                crate::progenitor_support::encode_path(receiver),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::Transactions = self.client.get(&url, None).await?;
@@ -238,17 +243,13 @@ impl Bitcoin {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -281,24 +282,26 @@ impl Bitcoin {
         receiver: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !receiver.is_empty() {
-            query_args.push(("receiver".to_string(), receiver.to_string()));
+            query_args.push(("receiver", receiver.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bitcoin/transactions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/bitcoin/transactions"),
+            &query_args,
+        );
 
         let resp: crate::types::Transactions = self.client.get(&url, None).await?;
 
@@ -318,15 +321,17 @@ impl Bitcoin {
         customer: &str,
         receiver: &str,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !receiver.is_empty() {
-            query_args.push(("receiver".to_string(), receiver.to_string()));
+            query_args.push(("receiver", receiver.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bitcoin/transactions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/bitcoin/transactions"),
+            &query_args,
+        );
 
         let mut resp: crate::types::Transactions = self.client.get(&url, None).await?;
 
@@ -346,17 +351,13 @@ impl Bitcoin {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -38,27 +38,26 @@ impl Charges {
         starting_after: &str,
         transfer_group: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !transfer_group.is_empty() {
-            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+            query_args.push(("transfer_group", transfer_group.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/charges?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/charges"), &query_args);
 
         let resp: crate::types::Charges = self.client.get(&url, None).await?;
 
@@ -80,18 +79,17 @@ impl Charges {
         payment_intent: &str,
         transfer_group: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
         if !transfer_group.is_empty() {
-            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+            query_args.push(("transfer_group", transfer_group.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/charges?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/charges"), &query_args);
 
         let mut resp: crate::types::Charges = self.client.get(&url, None).await?;
 
@@ -111,17 +109,13 @@ impl Charges {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -163,18 +157,18 @@ impl Charges {
         page: &str,
         query: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/charges/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/charges/search"), &query_args);
 
         let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -193,12 +187,12 @@ impl Charges {
     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
     */
     pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/charges/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/charges/search"), &query_args);
 
         let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -218,17 +212,13 @@ impl Charges {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -249,7 +239,8 @@ impl Charges {
     * * `charge: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn get(&self, charge: impl AsRef<str>) -> Result<crate::types::Charge> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}",
             crate::progenitor_support::encode_path(charge),
@@ -267,7 +258,8 @@ impl Charges {
     *
     * * `charge: &str` -- The account's country.
     */
-    pub async fn post_charges(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_charges(&self, charge: impl AsRef<str>) -> Result<crate::types::Charge> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}",
             crate::progenitor_support::encode_path(charge),
@@ -287,7 +279,8 @@ impl Charges {
     *
     * * `charge: &str` -- The account's country.
     */
-    pub async fn post_capture(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_capture(&self, charge: impl AsRef<str>) -> Result<crate::types::Charge> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}/capture",
             crate::progenitor_support::encode_path(charge),
@@ -306,7 +299,8 @@ impl Charges {
     * * `charge: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get_dispute(&self, charge: &str) -> Result<crate::types::Dispute> {
+    pub async fn get_dispute(&self, charge: impl AsRef<str>) -> Result<crate::types::Dispute> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}/dispute",
             crate::progenitor_support::encode_path(charge),
@@ -324,7 +318,8 @@ impl Charges {
     *
     * * `charge: &str` -- The account's country.
     */
-    pub async fn post_dispute(&self, charge: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_dispute(&self, charge: impl AsRef<str>) -> Result<crate::types::Dispute> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}/dispute",
             crate::progenitor_support::encode_path(charge),
@@ -342,7 +337,11 @@ impl Charges {
     *
     * * `charge: &str` -- The account's country.
     */
-    pub async fn post_dispute_close(&self, charge: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_dispute_close(
+        &self,
+        charge: impl AsRef<str>,
+    ) -> Result<crate::types::Dispute> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}/dispute/close",
             crate::progenitor_support::encode_path(charge),
@@ -370,7 +369,8 @@ impl Charges {
     *
     * * `charge: &str` -- The account's country.
     */
-    pub async fn post_refund(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_refund(&self, charge: impl AsRef<str>) -> Result<crate::types::Charge> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}/refund",
             crate::progenitor_support::encode_path(charge),
@@ -394,26 +394,28 @@ impl Charges {
     */
     pub async fn get_refunds(
         &self,
-        charge: &str,
+        charge: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Refund>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let charge = charge.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/charges/{}/refunds?{}",
-            crate::progenitor_support::encode_path(charge),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/charges/{}/refunds",
+                crate::progenitor_support::encode_path(charge),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::RefundList = self.client.get(&url, None).await?;
@@ -429,7 +431,11 @@ impl Charges {
     *
     * <p>You can see a list of the refunds belonging to a specific charge. Note that the 10 most recent refunds are always available by default on the charge object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
     */
-    pub async fn get_all_refunds(&self, charge: &str) -> Result<Vec<crate::types::Refund>> {
+    pub async fn get_all_refunds(
+        &self,
+        charge: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Refund>> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}/refunds",
             crate::progenitor_support::encode_path(charge),
@@ -453,17 +459,13 @@ impl Charges {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -483,7 +485,11 @@ impl Charges {
     *
     * * `charge: &str` -- The account's country.
     */
-    pub async fn post_refund_charges(&self, charge: &str) -> Result<crate::types::Refund> {
+    pub async fn post_refund_charges(
+        &self,
+        charge: impl AsRef<str>,
+    ) -> Result<crate::types::Refund> {
+        let charge = charge.as_ref();
         let url = format!(
             "/v1/charges/{}/refunds",
             crate::progenitor_support::encode_path(charge),
@@ -505,9 +511,11 @@ impl Charges {
     */
     pub async fn get_refunds_refund(
         &self,
-        charge: &str,
-        refund: &str,
+        charge: impl AsRef<str>,
+        refund: impl AsRef<str>,
     ) -> Result<crate::types::Refund> {
+        let charge = charge.as_ref();
+        let refund = refund.as_ref();
         let url = format!(
             "/v1/charges/{}/refunds/{}",
             crate::progenitor_support::encode_path(charge),
@@ -529,9 +537,11 @@ impl Charges {
     */
     pub async fn post_refunds_refund(
         &self,
-        charge: &str,
-        refund: &str,
+        charge: impl AsRef<str>,
+        refund: impl AsRef<str>,
     ) -> Result<crate::types::Refund> {
+        let charge = charge.as_ref();
+        let refund = refund.as_ref();
         let url = format!(
             "/v1/charges/{}/refunds/{}",
             crate::progenitor_support::encode_path(charge),
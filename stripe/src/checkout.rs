@@ -34,24 +34,26 @@ impl Checkout {
         starting_after: &str,
         subscription: &str,
     ) -> Result<Vec<crate::types::Session>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/checkout/sessions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/checkout/sessions"),
+            &query_args,
+        );
 
         let resp: crate::types::PaymentPagesCheckoutSessionList =
             self.client.get(&url, None).await?;
@@ -72,15 +74,17 @@ impl Checkout {
         payment_intent: &str,
         subscription: &str,
     ) -> Result<Vec<crate::types::Session>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/checkout/sessions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/checkout/sessions"),
+            &query_args,
+        );
 
         let mut resp: crate::types::PaymentPagesCheckoutSessionList =
             self.client.get(&url, None).await?;
@@ -101,17 +105,13 @@ impl Checkout {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -142,7 +142,11 @@ impl Checkout {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `session: &str` -- The account's country.
     */
-    pub async fn get_sessions_session(&self, session: &str) -> Result<crate::types::Session> {
+    pub async fn get_sessions_session(
+        &self,
+        session: impl AsRef<str>,
+    ) -> Result<crate::types::Session> {
+        let session = session.as_ref();
         let url = format!(
             "/v1/checkout/sessions/{}",
             crate::progenitor_support::encode_path(session),
@@ -164,8 +168,9 @@ impl Checkout {
     */
     pub async fn post_sessions_session_expire(
         &self,
-        session: &str,
+        session: impl AsRef<str>,
     ) -> Result<crate::types::Session> {
+        let session = session.as_ref();
         let url = format!(
             "/v1/checkout/sessions/{}/expire",
             crate::progenitor_support::encode_path(session),
@@ -191,24 +196,26 @@ impl Checkout {
         &self,
         ending_before: &str,
         limit: i64,
-        session: &str,
+        session: impl AsRef<str>,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Item>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let session = session.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/checkout/sessions/{}/line_items?{}",
-            crate::progenitor_support::encode_path(session),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/checkout/sessions/{}/line_items",
+                crate::progenitor_support::encode_path(session),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::LineItems = self.client.get(&url, None).await?;
@@ -226,8 +233,9 @@ impl Checkout {
     */
     pub async fn get_all_sessions_session_line_items(
         &self,
-        session: &str,
+        session: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Item>> {
+        let session = session.as_ref();
         let url = format!(
             "/v1/checkout/sessions/{}/line_items",
             crate::progenitor_support::encode_path(session),
@@ -251,17 +259,13 @@ impl Checkout {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -32,18 +32,17 @@ impl Coupons {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Coupon>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/coupons?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/coupons"), &query_args);
 
         let resp: crate::types::GetCouponsResponse = self.client.get(&url, None).await?;
 
@@ -78,17 +77,13 @@ impl Coupons {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -121,7 +116,8 @@ impl Coupons {
     * * `coupon: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get(&self, coupon: &str) -> Result<crate::types::Coupon> {
+    pub async fn get(&self, coupon: impl AsRef<str>) -> Result<crate::types::Coupon> {
+        let coupon = coupon.as_ref();
         let url = format!(
             "/v1/coupons/{}",
             crate::progenitor_support::encode_path(coupon),
@@ -139,7 +135,8 @@ impl Coupons {
     *
     * * `coupon: &str` -- The account's country.
     */
-    pub async fn post_coupons(&self, coupon: &str) -> Result<crate::types::Coupon> {
+    pub async fn post_coupons(&self, coupon: impl AsRef<str>) -> Result<crate::types::Coupon> {
+        let coupon = coupon.as_ref();
         let url = format!(
             "/v1/coupons/{}",
             crate::progenitor_support::encode_path(coupon),
@@ -157,7 +154,8 @@ impl Coupons {
     *
     * * `coupon: &str` -- The account's country.
     */
-    pub async fn delete(&self, coupon: &str) -> Result<crate::types::DeletedCoupon> {
+    pub async fn delete(&self, coupon: impl AsRef<str>) -> Result<crate::types::DeletedCoupon> {
+        let coupon = coupon.as_ref();
         let url = format!(
             "/v1/coupons/{}",
             crate::progenitor_support::encode_path(coupon),
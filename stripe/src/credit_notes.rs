@@ -34,24 +34,24 @@ impl CreditNotes {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::CreditNote>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if !invoice.is_empty() {
-            query_args.push(("invoice".to_string(), invoice.to_string()));
+            query_args.push(("invoice", invoice.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/credit_notes?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/credit_notes"), &query_args);
 
         let resp: crate::types::CreditNotesList = self.client.get(&url, None).await?;
 
@@ -71,15 +71,15 @@ impl CreditNotes {
         customer: &str,
         invoice: &str,
     ) -> Result<Vec<crate::types::CreditNote>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !invoice.is_empty() {
-            query_args.push(("invoice".to_string(), invoice.to_string()));
+            query_args.push(("invoice", invoice.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/credit_notes?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/credit_notes"), &query_args);
 
         let mut resp: crate::types::CreditNotesList = self.client.get(&url, None).await?;
 
@@ -99,17 +99,13 @@ impl CreditNotes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -175,36 +171,35 @@ impl CreditNotes {
         refund: &str,
         refund_amount: i64,
     ) -> Result<crate::types::CreditNote> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if amount > 0 {
-            query_args.push(("amount".to_string(), amount.to_string()));
+            query_args.push(("amount", amount.to_string()));
         }
         if credit_amount > 0 {
-            query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
+            query_args.push(("credit_amount", credit_amount.to_string()));
         }
         if !invoice.is_empty() {
-            query_args.push(("invoice".to_string(), invoice.to_string()));
+            query_args.push(("invoice", invoice.to_string()));
         }
         if !memo.is_empty() {
-            query_args.push(("memo".to_string(), memo.to_string()));
+            query_args.push(("memo", memo.to_string()));
         }
         if out_of_band_amount > 0 {
-            query_args.push((
-                "out_of_band_amount".to_string(),
-                out_of_band_amount.to_string(),
-            ));
+            query_args.push(("out_of_band_amount", out_of_band_amount.to_string()));
         }
         if !reason.to_string().is_empty() {
-            query_args.push(("reason".to_string(), reason.to_string()));
+            query_args.push(("reason", reason.to_string()));
         }
         if !refund.is_empty() {
-            query_args.push(("refund".to_string(), refund.to_string()));
+            query_args.push(("refund", refund.to_string()));
         }
         if refund_amount > 0 {
-            query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
+            query_args.push(("refund_amount", refund_amount.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/credit_notes/preview?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/credit_notes/preview"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -247,45 +242,44 @@ impl CreditNotes {
         refund_amount: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if amount > 0 {
-            query_args.push(("amount".to_string(), amount.to_string()));
+            query_args.push(("amount", amount.to_string()));
         }
         if credit_amount > 0 {
-            query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
+            query_args.push(("credit_amount", credit_amount.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if !invoice.is_empty() {
-            query_args.push(("invoice".to_string(), invoice.to_string()));
+            query_args.push(("invoice", invoice.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !memo.is_empty() {
-            query_args.push(("memo".to_string(), memo.to_string()));
+            query_args.push(("memo", memo.to_string()));
         }
         if out_of_band_amount > 0 {
-            query_args.push((
-                "out_of_band_amount".to_string(),
-                out_of_band_amount.to_string(),
-            ));
+            query_args.push(("out_of_band_amount", out_of_band_amount.to_string()));
         }
         if !reason.to_string().is_empty() {
-            query_args.push(("reason".to_string(), reason.to_string()));
+            query_args.push(("reason", reason.to_string()));
         }
         if !refund.is_empty() {
-            query_args.push(("refund".to_string(), refund.to_string()));
+            query_args.push(("refund", refund.to_string()));
         }
         if refund_amount > 0 {
-            query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
+            query_args.push(("refund_amount", refund_amount.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/credit_notes/preview/lines?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/credit_notes/preview/lines"),
+            &query_args,
+        );
 
         let resp: crate::types::Lines = self.client.get(&url, None).await?;
 
@@ -313,36 +307,35 @@ impl CreditNotes {
         refund: &str,
         refund_amount: i64,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if amount > 0 {
-            query_args.push(("amount".to_string(), amount.to_string()));
+            query_args.push(("amount", amount.to_string()));
         }
         if credit_amount > 0 {
-            query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
+            query_args.push(("credit_amount", credit_amount.to_string()));
         }
         if !invoice.is_empty() {
-            query_args.push(("invoice".to_string(), invoice.to_string()));
+            query_args.push(("invoice", invoice.to_string()));
         }
         if !memo.is_empty() {
-            query_args.push(("memo".to_string(), memo.to_string()));
+            query_args.push(("memo", memo.to_string()));
         }
         if out_of_band_amount > 0 {
-            query_args.push((
-                "out_of_band_amount".to_string(),
-                out_of_band_amount.to_string(),
-            ));
+            query_args.push(("out_of_band_amount", out_of_band_amount.to_string()));
         }
         if !reason.to_string().is_empty() {
-            query_args.push(("reason".to_string(), reason.to_string()));
+            query_args.push(("reason", reason.to_string()));
         }
         if !refund.is_empty() {
-            query_args.push(("refund".to_string(), refund.to_string()));
+            query_args.push(("refund", refund.to_string()));
         }
         if refund_amount > 0 {
-            query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
+            query_args.push(("refund_amount", refund_amount.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/credit_notes/preview/lines?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/credit_notes/preview/lines"),
+            &query_args,
+        );
 
         let mut resp: crate::types::Lines = self.client.get(&url, None).await?;
 
@@ -362,17 +355,13 @@ impl CreditNotes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -398,26 +387,28 @@ impl CreditNotes {
     */
     pub async fn get_note_lines(
         &self,
-        credit_note: &str,
+        credit_note: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let credit_note = credit_note.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/credit_notes/{}/lines?{}",
-            crate::progenitor_support::encode_path(credit_note),
-            query_
+            query_args.push(("starting_after", starting_after.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/credit_notes/{}/lines",
+                crate::progenitor_support::encode_path(credit_note),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Lines = self.client.get(&url, None).await?;
@@ -435,8 +426,9 @@ impl CreditNotes {
     */
     pub async fn get_all_note_lines(
         &self,
-        credit_note: &str,
+        credit_note: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
+        let credit_note = credit_note.as_ref();
         let url = format!(
             "/v1/credit_notes/{}/lines",
             crate::progenitor_support::encode_path(credit_note),
@@ -460,17 +452,13 @@ impl CreditNotes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -491,7 +479,8 @@ impl CreditNotes {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::CreditNote> {
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::CreditNote> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/credit_notes/{}",
             crate::progenitor_support::encode_path(id),
@@ -509,7 +498,8 @@ impl CreditNotes {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_credit_notes(&self, id: &str) -> Result<crate::types::CreditNote> {
+    pub async fn post_credit_notes(&self, id: impl AsRef<str>) -> Result<crate::types::CreditNote> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/credit_notes/{}",
             crate::progenitor_support::encode_path(id),
@@ -527,7 +517,8 @@ impl CreditNotes {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_void(&self, id: &str) -> Result<crate::types::CreditNote> {
+    pub async fn post_void(&self, id: impl AsRef<str>) -> Result<crate::types::CreditNote> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/credit_notes/{}/void",
             crate::progenitor_support::encode_path(id),
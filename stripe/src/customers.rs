@@ -36,24 +36,23 @@ impl Customers {
         starting_after: &str,
         test_clock: &str,
     ) -> Result<Vec<crate::types::Customer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !test_clock.is_empty() {
-            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+            query_args.push(("test_clock", test_clock.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/customers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/customers"), &query_args);
 
         let resp: crate::types::GetCustomersResponse = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl Customers {
         email: &str,
         test_clock: &str,
     ) -> Result<Vec<crate::types::Customer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !test_clock.is_empty() {
-            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+            query_args.push(("test_clock", test_clock.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/customers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/customers"), &query_args);
 
         let mut resp: crate::types::GetCustomersResponse = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -154,18 +148,18 @@ impl Customers {
         page: &str,
         query: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/customers/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/customers/search"), &query_args);
 
         let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -184,12 +178,12 @@ impl Customers {
     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
     */
     pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/customers/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/customers/search"), &query_args);
 
         let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -209,17 +203,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -242,8 +232,9 @@ impl Customers {
     */
     pub async fn get(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
     ) -> Result<crate::types::GetCustomersCustomerResponseAnyOf> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}",
             crate::progenitor_support::encode_path(customer),
@@ -263,7 +254,11 @@ impl Customers {
     *
     * * `customer: &str` -- The account's country.
     */
-    pub async fn post_customers(&self, customer: &str) -> Result<crate::types::Customer> {
+    pub async fn post_customers(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<crate::types::Customer> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}",
             crate::progenitor_support::encode_path(customer),
@@ -281,7 +276,8 @@ impl Customers {
     *
     * * `customer: &str` -- The account's country.
     */
-    pub async fn delete(&self, customer: &str) -> Result<crate::types::DeletedCustomer> {
+    pub async fn delete(&self, customer: impl AsRef<str>) -> Result<crate::types::DeletedCustomer> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}",
             crate::progenitor_support::encode_path(customer),
@@ -305,26 +301,28 @@ impl Customers {
     */
     pub async fn get_balance_transactions(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::CustomerBalanceTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/balance_transactions?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/balance_transactions",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::CustomerBalanceTransactionList =
@@ -343,8 +341,9 @@ impl Customers {
     */
     pub async fn get_all_balance_transactions(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
     ) -> Result<Vec<crate::types::CustomerBalanceTransaction>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/balance_transactions",
             crate::progenitor_support::encode_path(customer),
@@ -369,17 +368,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -401,8 +396,9 @@ impl Customers {
     */
     pub async fn post_balance_transaction(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
     ) -> Result<crate::types::CustomerBalanceTransaction> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/balance_transactions",
             crate::progenitor_support::encode_path(customer),
@@ -424,9 +420,11 @@ impl Customers {
     */
     pub async fn get_balance_transactions_transaction(
         &self,
-        customer: &str,
-        transaction: &str,
+        customer: impl AsRef<str>,
+        transaction: impl AsRef<str>,
     ) -> Result<crate::types::CustomerBalanceTransaction> {
+        let customer = customer.as_ref();
+        let transaction = transaction.as_ref();
         let url = format!(
             "/v1/customers/{}/balance_transactions/{}",
             crate::progenitor_support::encode_path(customer),
@@ -448,9 +446,11 @@ impl Customers {
     */
     pub async fn post_balance_transactions_transaction(
         &self,
-        customer: &str,
-        transaction: &str,
+        customer: impl AsRef<str>,
+        transaction: impl AsRef<str>,
     ) -> Result<crate::types::CustomerBalanceTransaction> {
+        let customer = customer.as_ref();
+        let transaction = transaction.as_ref();
         let url = format!(
             "/v1/customers/{}/balance_transactions/{}",
             crate::progenitor_support::encode_path(customer),
@@ -475,26 +475,28 @@ impl Customers {
     */
     pub async fn get_bank_accounts(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::BankAccount>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/bank_accounts?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/bank_accounts",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::BankAccountList = self.client.get(&url, None).await?;
@@ -512,8 +514,9 @@ impl Customers {
     */
     pub async fn get_all_bank_accounts(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
     ) -> Result<Vec<crate::types::BankAccount>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/bank_accounts",
             crate::progenitor_support::encode_path(customer),
@@ -537,17 +540,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -573,8 +572,9 @@ impl Customers {
     */
     pub async fn post_bank_account(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
     ) -> Result<crate::types::PaymentSourceAnyOf> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/bank_accounts",
             crate::progenitor_support::encode_path(customer),
@@ -596,9 +596,11 @@ impl Customers {
     */
     pub async fn get_bank_account(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::BankAccount> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/bank_accounts/{}",
             crate::progenitor_support::encode_path(customer),
@@ -620,9 +622,11 @@ impl Customers {
     */
     pub async fn post_bank_account_customers(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::SourceAnyOf> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/bank_accounts/{}",
             crate::progenitor_support::encode_path(customer),
@@ -644,9 +648,11 @@ impl Customers {
     */
     pub async fn delete_bank_accounts(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DeleteCustomersCustomerCardsResponseAnyOf> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/bank_accounts/{}",
             crate::progenitor_support::encode_path(customer),
@@ -668,9 +674,11 @@ impl Customers {
     */
     pub async fn post_bank_accounts_verify(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::BankAccount> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/bank_accounts/{}/verify",
             crate::progenitor_support::encode_path(customer),
@@ -697,26 +705,28 @@ impl Customers {
     */
     pub async fn get_cards(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Card>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/cards?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/cards",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Cards = self.client.get(&url, None).await?;
@@ -734,7 +744,11 @@ impl Customers {
     * Note that the 10 most recent sources are always available on the <code>Customer</code> object.
     * If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional cards.</p>
     */
-    pub async fn get_all_cards(&self, customer: &str) -> Result<Vec<crate::types::Card>> {
+    pub async fn get_all_cards(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Card>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/cards",
             crate::progenitor_support::encode_path(customer),
@@ -758,17 +772,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -792,7 +802,11 @@ impl Customers {
     *
     * * `customer: &str` -- The account's country.
     */
-    pub async fn post_card(&self, customer: &str) -> Result<crate::types::PaymentSourceAnyOf> {
+    pub async fn post_card(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentSourceAnyOf> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/cards",
             crate::progenitor_support::encode_path(customer),
@@ -812,7 +826,13 @@ impl Customers {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get_card(&self, customer: &str, id: &str) -> Result<crate::types::Card> {
+    pub async fn get_card(
+        &self,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::Card> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/cards/{}",
             crate::progenitor_support::encode_path(customer),
@@ -834,9 +854,11 @@ impl Customers {
     */
     pub async fn post_card_customers(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::SourceAnyOf> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/cards/{}",
             crate::progenitor_support::encode_path(customer),
@@ -858,9 +880,11 @@ impl Customers {
     */
     pub async fn delete_cards(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DeleteCustomersCustomerCardsResponseAnyOf> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/cards/{}",
             crate::progenitor_support::encode_path(customer),
@@ -880,7 +904,11 @@ impl Customers {
     * * `customer: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get_discount(&self, customer: &str) -> Result<crate::types::DiscountData> {
+    pub async fn get_discount(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<crate::types::DiscountData> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/discount",
             crate::progenitor_support::encode_path(customer),
@@ -898,7 +926,11 @@ impl Customers {
     *
     * * `customer: &str` -- The account's country.
     */
-    pub async fn delete_discount(&self, customer: &str) -> Result<crate::types::DeletedDiscount> {
+    pub async fn delete_discount(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<crate::types::DeletedDiscount> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/discount",
             crate::progenitor_support::encode_path(customer),
@@ -923,30 +955,32 @@ impl Customers {
     */
     pub async fn get_payment_methods(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> Result<Vec<crate::types::PaymentMethod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/payment_methods?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/payment_methods",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::PaymentFlowsMethodList = self.client.get(&url, None).await?;
@@ -964,18 +998,20 @@ impl Customers {
     */
     pub async fn get_all_payment_methods(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> Result<Vec<crate::types::PaymentMethod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/payment_methods?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/payment_methods",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::PaymentFlowsMethodList = self.client.get(&url, None).await?;
@@ -996,17 +1032,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -1033,30 +1065,32 @@ impl Customers {
     */
     pub async fn get_sources(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         object: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::CustomerSourcesDataAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !object.is_empty() {
-            query_args.push(("object".to_string(), object.to_string()));
+            query_args.push(("object", object.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/sources?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/sources",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Sources = self.client.get(&url, None).await?;
@@ -1074,18 +1108,20 @@ impl Customers {
     */
     pub async fn get_all_sources(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         object: &str,
     ) -> Result<Vec<crate::types::CustomerSourcesDataAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !object.is_empty() {
-            query_args.push(("object".to_string(), object.to_string()));
+            query_args.push(("object", object.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/sources?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/sources",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::Sources = self.client.get(&url, None).await?;
@@ -1106,17 +1142,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -1140,7 +1172,11 @@ impl Customers {
     *
     * * `customer: &str` -- The account's country.
     */
-    pub async fn post_source(&self, customer: &str) -> Result<crate::types::PaymentSourceAnyOf> {
+    pub async fn post_source(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentSourceAnyOf> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/sources",
             crate::progenitor_support::encode_path(customer),
@@ -1162,9 +1198,11 @@ impl Customers {
     */
     pub async fn get_source(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::PaymentSourceAnyOf> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/sources/{}",
             crate::progenitor_support::encode_path(customer),
@@ -1186,9 +1224,11 @@ impl Customers {
     */
     pub async fn post_source_customers(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::SourceAnyOf> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/sources/{}",
             crate::progenitor_support::encode_path(customer),
@@ -1210,9 +1250,11 @@ impl Customers {
     */
     pub async fn delete_sources(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DeleteCustomersCustomerCardsResponseAnyOf> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/sources/{}",
             crate::progenitor_support::encode_path(customer),
@@ -1234,9 +1276,11 @@ impl Customers {
     */
     pub async fn post_sources_verify(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::BankAccount> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/sources/{}/verify",
             crate::progenitor_support::encode_path(customer),
@@ -1261,26 +1305,28 @@ impl Customers {
     */
     pub async fn get_subscriptions(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Subscription>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/subscriptions?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/subscriptions",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Subscriptions = self.client.get(&url, None).await?;
@@ -1298,8 +1344,9 @@ impl Customers {
     */
     pub async fn get_all_subscriptions(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Subscription>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/subscriptions",
             crate::progenitor_support::encode_path(customer),
@@ -1323,17 +1370,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -1353,7 +1396,11 @@ impl Customers {
     *
     * * `customer: &str` -- The account's country.
     */
-    pub async fn post_subscription(&self, customer: &str) -> Result<crate::types::Subscription> {
+    pub async fn post_subscription(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<crate::types::Subscription> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/subscriptions",
             crate::progenitor_support::encode_path(customer),
@@ -1375,9 +1422,11 @@ impl Customers {
     */
     pub async fn get_subscriptions_subscription_exposed(
         &self,
-        customer: &str,
-        subscription_exposed_id: &str,
+        customer: impl AsRef<str>,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::Subscription> {
+        let customer = customer.as_ref();
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/customers/{}/subscriptions/{}",
             crate::progenitor_support::encode_path(customer),
@@ -1399,9 +1448,11 @@ impl Customers {
     */
     pub async fn post_subscriptions_subscription_exposed(
         &self,
-        customer: &str,
-        subscription_exposed_id: &str,
+        customer: impl AsRef<str>,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::Subscription> {
+        let customer = customer.as_ref();
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/customers/{}/subscriptions/{}",
             crate::progenitor_support::encode_path(customer),
@@ -1427,9 +1478,11 @@ impl Customers {
     */
     pub async fn delete_subscriptions_subscription_exposed(
         &self,
-        customer: &str,
-        subscription_exposed_id: &str,
+        customer: impl AsRef<str>,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::Subscription> {
+        let customer = customer.as_ref();
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/customers/{}/subscriptions/{}",
             crate::progenitor_support::encode_path(customer),
@@ -1452,9 +1505,11 @@ impl Customers {
     */
     pub async fn get_subscriptions_subscription_exposed_discount(
         &self,
-        customer: &str,
-        subscription_exposed_id: &str,
+        customer: impl AsRef<str>,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::DiscountData> {
+        let customer = customer.as_ref();
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/customers/{}/subscriptions/{}/discount",
             crate::progenitor_support::encode_path(customer),
@@ -1476,9 +1531,11 @@ impl Customers {
     */
     pub async fn delete_subscriptions_subscription_exposed_discount(
         &self,
-        customer: &str,
-        subscription_exposed_id: &str,
+        customer: impl AsRef<str>,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::DeletedDiscount> {
+        let customer = customer.as_ref();
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/customers/{}/subscriptions/{}/discount",
             crate::progenitor_support::encode_path(customer),
@@ -1503,26 +1560,28 @@ impl Customers {
     */
     pub async fn get_tax_ids(
         &self,
-        customer: &str,
+        customer: impl AsRef<str>,
         ending_before: &str,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::TaxId>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let customer = customer.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/customers/{}/tax_ids?{}",
-            crate::progenitor_support::encode_path(customer),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/customers/{}/tax_ids",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::TaxIds = self.client.get(&url, None).await?;
@@ -1538,7 +1597,11 @@ impl Customers {
     *
     * <p>Returns a list of tax IDs for a customer.</p>
     */
-    pub async fn get_all_tax_ids(&self, customer: &str) -> Result<Vec<crate::types::TaxId>> {
+    pub async fn get_all_tax_ids(
+        &self,
+        customer: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::TaxId>> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/tax_ids",
             crate::progenitor_support::encode_path(customer),
@@ -1562,17 +1625,13 @@ impl Customers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -1592,7 +1651,8 @@ impl Customers {
     *
     * * `customer: &str` -- The account's country.
     */
-    pub async fn post_tax_id(&self, customer: &str) -> Result<crate::types::TaxId> {
+    pub async fn post_tax_id(&self, customer: impl AsRef<str>) -> Result<crate::types::TaxId> {
+        let customer = customer.as_ref();
         let url = format!(
             "/v1/customers/{}/tax_ids",
             crate::progenitor_support::encode_path(customer),
@@ -1612,7 +1672,13 @@ impl Customers {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get_tax_id(&self, customer: &str, id: &str) -> Result<crate::types::TaxId> {
+    pub async fn get_tax_id(
+        &self,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::TaxId> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/tax_ids/{}",
             crate::progenitor_support::encode_path(customer),
@@ -1634,9 +1700,11 @@ impl Customers {
     */
     pub async fn delete_tax_ids(
         &self,
-        customer: &str,
-        id: &str,
+        customer: impl AsRef<str>,
+        id: impl AsRef<str>,
     ) -> Result<crate::types::DeletedTaxId> {
+        let customer = customer.as_ref();
+        let id = id.as_ref();
         let url = format!(
             "/v1/customers/{}/tax_ids/{}",
             crate::progenitor_support::encode_path(customer),
@@ -36,24 +36,23 @@ impl Disputes {
         payment_intent: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Dispute>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/disputes?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/disputes"), &query_args);
 
         let resp: crate::types::GetDisputesResponse = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl Disputes {
         _created: &str,
         payment_intent: &str,
     ) -> Result<Vec<crate::types::Dispute>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/disputes?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/disputes"), &query_args);
 
         let mut resp: crate::types::GetDisputesResponse = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl Disputes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -133,7 +127,8 @@ impl Disputes {
     * * `dispute: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get(&self, dispute: &str) -> Result<crate::types::Dispute> {
+    pub async fn get(&self, dispute: impl AsRef<str>) -> Result<crate::types::Dispute> {
+        let dispute = dispute.as_ref();
         let url = format!(
             "/v1/disputes/{}",
             crate::progenitor_support::encode_path(dispute),
@@ -153,7 +148,8 @@ impl Disputes {
     *
     * * `dispute: &str` -- The account's country.
     */
-    pub async fn post(&self, dispute: &str) -> Result<crate::types::Dispute> {
+    pub async fn post(&self, dispute: impl AsRef<str>) -> Result<crate::types::Dispute> {
+        let dispute = dispute.as_ref();
         let url = format!(
             "/v1/disputes/{}",
             crate::progenitor_support::encode_path(dispute),
@@ -173,7 +169,8 @@ impl Disputes {
     *
     * * `dispute: &str` -- The account's country.
     */
-    pub async fn post_close(&self, dispute: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_close(&self, dispute: impl AsRef<str>) -> Result<crate::types::Dispute> {
+        let dispute = dispute.as_ref();
         let url = format!(
             "/v1/disputes/{}/close",
             crate::progenitor_support::encode_path(dispute),
@@ -31,7 +31,8 @@ impl EphemeralKeys {
     *
     * * `key: &str` -- The account's country.
     */
-    pub async fn delete_key(&self, key: &str) -> Result<crate::types::EphemeralKey> {
+    pub async fn delete_key(&self, key: impl AsRef<str>) -> Result<crate::types::EphemeralKey> {
+        let key = key.as_ref();
         let url = format!(
             "/v1/ephemeral_keys/{}",
             crate::progenitor_support::encode_path(key),
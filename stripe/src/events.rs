@@ -38,24 +38,23 @@ impl Events {
         type_: &str,
         _types: &[String],
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delivery_success {
-            query_args.push(("delivery_success".to_string(), delivery_success.to_string()));
+            query_args.push(("delivery_success", delivery_success.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/events?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/events"), &query_args);
 
         let resp: crate::types::NotificationEventList = self.client.get(&url, None).await?;
 
@@ -77,15 +76,14 @@ impl Events {
         type_: &str,
         _types: &[String],
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delivery_success {
-            query_args.push(("delivery_success".to_string(), delivery_success.to_string()));
+            query_args.push(("delivery_success", delivery_success.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/events?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/events"), &query_args);
 
         let mut resp: crate::types::NotificationEventList = self.client.get(&url, None).await?;
 
@@ -105,17 +103,13 @@ impl Events {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -136,11 +130,9 @@ impl Events {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Event> {
-        let url = format!(
-            "/v1/events/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::Event> {
+        let id = id.as_ref();
+        let url = format!("/v1/events/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
@@ -30,18 +30,18 @@ impl ExchangeRates {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::ExchangeRate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/exchange_rates?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/exchange_rates"), &query_args);
 
         let resp: crate::types::GetExchangeRatesResponse = self.client.get(&url, None).await?;
 
@@ -76,17 +76,13 @@ impl ExchangeRates {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -107,7 +103,8 @@ impl ExchangeRates {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `rate_id: &str` -- The account's country.
     */
-    pub async fn get_rate(&self, rate_id: &str) -> Result<crate::types::ExchangeRate> {
+    pub async fn get_rate(&self, rate_id: impl AsRef<str>) -> Result<crate::types::ExchangeRate> {
+        let rate_id = rate_id.as_ref();
         let url = format!(
             "/v1/exchange_rates/{}",
             crate::progenitor_support::encode_path(rate_id),
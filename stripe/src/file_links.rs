@@ -36,24 +36,23 @@ impl FileLinks {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::FileLink>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if expired {
-            query_args.push(("expired".to_string(), expired.to_string()));
+            query_args.push(("expired", expired.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/file_links?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/file_links"), &query_args);
 
         let resp: crate::types::Links = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl FileLinks {
         expired: bool,
         file: &str,
     ) -> Result<Vec<crate::types::FileLink>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if expired {
-            query_args.push(("expired".to_string(), expired.to_string()));
+            query_args.push(("expired", expired.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/file_links?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/file_links"), &query_args);
 
         let mut resp: crate::types::Links = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl FileLinks {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -143,7 +137,8 @@ impl FileLinks {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `link: &str` -- The account's country.
     */
-    pub async fn get_link(&self, link: &str) -> Result<crate::types::FileLink> {
+    pub async fn get_link(&self, link: impl AsRef<str>) -> Result<crate::types::FileLink> {
+        let link = link.as_ref();
         let url = format!(
             "/v1/file_links/{}",
             crate::progenitor_support::encode_path(link),
@@ -161,7 +156,8 @@ impl FileLinks {
     *
     * * `link: &str` -- The account's country.
     */
-    pub async fn post_link(&self, link: &str) -> Result<crate::types::FileLink> {
+    pub async fn post_link(&self, link: impl AsRef<str>) -> Result<crate::types::FileLink> {
+        let link = link.as_ref();
         let url = format!(
             "/v1/file_links/{}",
             crate::progenitor_support::encode_path(link),
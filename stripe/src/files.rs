@@ -34,21 +34,20 @@ impl Files {
         purpose: crate::types::Purpose,
         starting_after: &str,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !purpose.to_string().is_empty() {
-            query_args.push(("purpose".to_string(), purpose.to_string()));
+            query_args.push(("purpose", purpose.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/files?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/files"), &query_args);
 
         let resp: crate::types::GetFilesResponse = self.client.get(&url, None).await?;
 
@@ -68,12 +67,11 @@ impl Files {
         _created: &str,
         purpose: crate::types::Purpose,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !purpose.to_string().is_empty() {
-            query_args.push(("purpose".to_string(), purpose.to_string()));
+            query_args.push(("purpose", purpose.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/files?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/files"), &query_args);
 
         let mut resp: crate::types::GetFilesResponse = self.client.get(&url, None).await?;
 
@@ -93,17 +91,13 @@ impl Files {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -136,11 +130,9 @@ impl Files {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `file: &str` -- The account's country.
     */
-    pub async fn get(&self, file: &str) -> Result<crate::types::File> {
-        let url = format!(
-            "/v1/files/{}",
-            crate::progenitor_support::encode_path(file),
-        );
+    pub async fn get(&self, file: impl AsRef<str>) -> Result<crate::types::File> {
+        let file = file.as_ref();
+        let url = format!("/v1/files/{}", crate::progenitor_support::encode_path(file),);
 
         self.client.get(&url, None).await
     }
@@ -36,27 +36,26 @@ impl Identity {
         type_: crate::types::GelatoVerificationReportType,
         verification_session: &str,
     ) -> Result<Vec<crate::types::GelatoVerificationReport>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if !verification_session.is_empty() {
-            query_args.push((
-                "verification_session".to_string(),
-                verification_session.to_string(),
-            ));
+            query_args.push(("verification_session", verification_session.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/identity/verification_reports?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/identity/verification_reports"),
+            &query_args,
+        );
 
         let resp: crate::types::GetIdentityVerificationReportsResponse =
             self.client.get(&url, None).await?;
@@ -78,18 +77,17 @@ impl Identity {
         type_: crate::types::GelatoVerificationReportType,
         verification_session: &str,
     ) -> Result<Vec<crate::types::GelatoVerificationReport>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if !verification_session.is_empty() {
-            query_args.push((
-                "verification_session".to_string(),
-                verification_session.to_string(),
-            ));
+            query_args.push(("verification_session", verification_session.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/identity/verification_reports?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/identity/verification_reports"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetIdentityVerificationReportsResponse =
             self.client.get(&url, None).await?;
@@ -110,17 +108,13 @@ impl Identity {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -143,8 +137,9 @@ impl Identity {
     */
     pub async fn get_verification_reports_report(
         &self,
-        report: &str,
+        report: impl AsRef<str>,
     ) -> Result<crate::types::GelatoVerificationReport> {
+        let report = report.as_ref();
         let url = format!(
             "/v1/identity/verification_reports/{}",
             crate::progenitor_support::encode_path(report),
@@ -175,21 +170,23 @@ impl Identity {
         starting_after: &str,
         status: crate::types::GelatoVerificationSessionStatus,
     ) -> Result<Vec<crate::types::GelatoVerificationSession>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/identity/verification_sessions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/identity/verification_sessions"),
+            &query_args,
+        );
 
         let resp: crate::types::GetIdentityVerificationSessionsResponse =
             self.client.get(&url, None).await?;
@@ -210,12 +207,14 @@ impl Identity {
         _created: &str,
         status: crate::types::GelatoVerificationSessionStatus,
     ) -> Result<Vec<crate::types::GelatoVerificationSession>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/identity/verification_sessions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/identity/verification_sessions"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetIdentityVerificationSessionsResponse =
             self.client.get(&url, None).await?;
@@ -236,17 +235,13 @@ impl Identity {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -290,8 +285,9 @@ impl Identity {
     */
     pub async fn get_verification_sessions_session(
         &self,
-        session: &str,
+        session: impl AsRef<str>,
     ) -> Result<crate::types::GelatoVerificationSession> {
+        let session = session.as_ref();
         let url = format!(
             "/v1/identity/verification_sessions/{}",
             crate::progenitor_support::encode_path(session),
@@ -314,8 +310,9 @@ impl Identity {
     */
     pub async fn post_verification_sessions_session(
         &self,
-        session: &str,
+        session: impl AsRef<str>,
     ) -> Result<crate::types::GelatoVerificationSession> {
+        let session = session.as_ref();
         let url = format!(
             "/v1/identity/verification_sessions/{}",
             crate::progenitor_support::encode_path(session),
@@ -337,8 +334,9 @@ impl Identity {
     */
     pub async fn post_verification_sessions_session_cancel(
         &self,
-        session: &str,
+        session: impl AsRef<str>,
     ) -> Result<crate::types::GelatoVerificationSession> {
+        let session = session.as_ref();
         let url = format!(
             "/v1/identity/verification_sessions/{}/cancel",
             crate::progenitor_support::encode_path(session),
@@ -376,8 +374,9 @@ impl Identity {
     */
     pub async fn post_verification_sessions_session_redact(
         &self,
-        session: &str,
+        session: impl AsRef<str>,
     ) -> Result<crate::types::GelatoVerificationSession> {
+        let session = session.as_ref();
         let url = format!(
             "/v1/identity/verification_sessions/{}/redact",
             crate::progenitor_support::encode_path(session),
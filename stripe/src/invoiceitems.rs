@@ -38,27 +38,27 @@ impl Invoiceitems {
         pending: bool,
         starting_after: &str,
     ) -> Result<Vec<crate::types::InvoiceItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if !invoice.is_empty() {
-            query_args.push(("invoice".to_string(), invoice.to_string()));
+            query_args.push(("invoice", invoice.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if pending {
-            query_args.push(("pending".to_string(), pending.to_string()));
+            query_args.push(("pending", pending.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoiceitems?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/invoiceitems"), &query_args);
 
         let resp: crate::types::GetInvoiceitemsResponse = self.client.get(&url, None).await?;
 
@@ -80,18 +80,18 @@ impl Invoiceitems {
         invoice: &str,
         pending: bool,
     ) -> Result<Vec<crate::types::InvoiceItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !invoice.is_empty() {
-            query_args.push(("invoice".to_string(), invoice.to_string()));
+            query_args.push(("invoice", invoice.to_string()));
         }
         if pending {
-            query_args.push(("pending".to_string(), pending.to_string()));
+            query_args.push(("pending", pending.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoiceitems?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/invoiceitems"), &query_args);
 
         let mut resp: crate::types::GetInvoiceitemsResponse = self.client.get(&url, None).await?;
 
@@ -111,17 +111,13 @@ impl Invoiceitems {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -152,7 +148,8 @@ impl Invoiceitems {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `invoiceitem: &str` -- The account's country.
     */
-    pub async fn get(&self, invoiceitem: &str) -> Result<crate::types::InvoiceItem> {
+    pub async fn get(&self, invoiceitem: impl AsRef<str>) -> Result<crate::types::InvoiceItem> {
+        let invoiceitem = invoiceitem.as_ref();
         let url = format!(
             "/v1/invoiceitems/{}",
             crate::progenitor_support::encode_path(invoiceitem),
@@ -170,7 +167,11 @@ impl Invoiceitems {
     *
     * * `invoiceitem: &str` -- The account's country.
     */
-    pub async fn post_invoiceitems(&self, invoiceitem: &str) -> Result<crate::types::InvoiceItem> {
+    pub async fn post_invoiceitems(
+        &self,
+        invoiceitem: impl AsRef<str>,
+    ) -> Result<crate::types::InvoiceItem> {
+        let invoiceitem = invoiceitem.as_ref();
         let url = format!(
             "/v1/invoiceitems/{}",
             crate::progenitor_support::encode_path(invoiceitem),
@@ -188,7 +189,11 @@ impl Invoiceitems {
     *
     * * `invoiceitem: &str` -- The account's country.
     */
-    pub async fn delete(&self, invoiceitem: &str) -> Result<crate::types::DeletedInvoiceItem> {
+    pub async fn delete(
+        &self,
+        invoiceitem: impl AsRef<str>,
+    ) -> Result<crate::types::DeletedInvoiceItem> {
+        let invoiceitem = invoiceitem.as_ref();
         let url = format!(
             "/v1/invoiceitems/{}",
             crate::progenitor_support::encode_path(invoiceitem),
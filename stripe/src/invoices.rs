@@ -42,33 +42,29 @@ impl Invoices {
         status: crate::types::GetInvoicesStatus,
         subscription: &str,
     ) -> Result<Vec<crate::types::Invoice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
-            query_args.push((
-                "collection_method".to_string(),
-                collection_method.to_string(),
-            ));
+            query_args.push(("collection_method", collection_method.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoices?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/invoices"), &query_args);
 
         let resp: crate::types::InvoicesList = self.client.get(&url, None).await?;
 
@@ -92,24 +88,20 @@ impl Invoices {
         status: crate::types::GetInvoicesStatus,
         subscription: &str,
     ) -> Result<Vec<crate::types::Invoice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
-            query_args.push((
-                "collection_method".to_string(),
-                collection_method.to_string(),
-            ));
+            query_args.push(("collection_method", collection_method.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoices?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/invoices"), &query_args);
 
         let mut resp: crate::types::InvoicesList = self.client.get(&url, None).await?;
 
@@ -129,17 +121,13 @@ impl Invoices {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -181,18 +169,18 @@ impl Invoices {
         page: &str,
         query: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoices/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/invoices/search"), &query_args);
 
         let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -211,12 +199,12 @@ impl Invoices {
     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
     */
     pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoices/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/invoices/search"), &query_args);
 
         let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -236,17 +224,13 @@ impl Invoices {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -315,57 +299,59 @@ impl Invoices {
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
     ) -> Result<crate::types::Invoice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coupon.is_empty() {
-            query_args.push(("coupon".to_string(), coupon.to_string()));
+            query_args.push(("coupon", coupon.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !schedule.is_empty() {
-            query_args.push(("schedule".to_string(), schedule.to_string()));
+            query_args.push(("schedule", schedule.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
         if subscription_cancel_at_period_end {
             query_args.push((
-                "subscription_cancel_at_period_end".to_string(),
+                "subscription_cancel_at_period_end",
                 subscription_cancel_at_period_end.to_string(),
             ));
         }
         if subscription_cancel_now {
             query_args.push((
-                "subscription_cancel_now".to_string(),
+                "subscription_cancel_now",
                 subscription_cancel_now.to_string(),
             ));
         }
         if !subscription_proration_behavior.to_string().is_empty() {
             query_args.push((
-                "subscription_proration_behavior".to_string(),
+                "subscription_proration_behavior",
                 subscription_proration_behavior.to_string(),
             ));
         }
         if subscription_proration_date > 0 {
             query_args.push((
-                "subscription_proration_date".to_string(),
+                "subscription_proration_date",
                 subscription_proration_date.to_string(),
             ));
         }
         if subscription_start_date > 0 {
             query_args.push((
-                "subscription_start_date".to_string(),
+                "subscription_start_date",
                 subscription_start_date.to_string(),
             ));
         }
         if subscription_trial_from_plan {
             query_args.push((
-                "subscription_trial_from_plan".to_string(),
+                "subscription_trial_from_plan",
                 subscription_trial_from_plan.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoices/upcoming?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/invoices/upcoming"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -430,66 +416,68 @@ impl Invoices {
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
     ) -> Result<Vec<crate::types::LineItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coupon.is_empty() {
-            query_args.push(("coupon".to_string(), coupon.to_string()));
+            query_args.push(("coupon", coupon.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !schedule.is_empty() {
-            query_args.push(("schedule".to_string(), schedule.to_string()));
+            query_args.push(("schedule", schedule.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
         if subscription_cancel_at_period_end {
             query_args.push((
-                "subscription_cancel_at_period_end".to_string(),
+                "subscription_cancel_at_period_end",
                 subscription_cancel_at_period_end.to_string(),
             ));
         }
         if subscription_cancel_now {
             query_args.push((
-                "subscription_cancel_now".to_string(),
+                "subscription_cancel_now",
                 subscription_cancel_now.to_string(),
             ));
         }
         if !subscription_proration_behavior.to_string().is_empty() {
             query_args.push((
-                "subscription_proration_behavior".to_string(),
+                "subscription_proration_behavior",
                 subscription_proration_behavior.to_string(),
             ));
         }
         if subscription_proration_date > 0 {
             query_args.push((
-                "subscription_proration_date".to_string(),
+                "subscription_proration_date",
                 subscription_proration_date.to_string(),
             ));
         }
         if subscription_start_date > 0 {
             query_args.push((
-                "subscription_start_date".to_string(),
+                "subscription_start_date",
                 subscription_start_date.to_string(),
             ));
         }
         if subscription_trial_from_plan {
             query_args.push((
-                "subscription_trial_from_plan".to_string(),
+                "subscription_trial_from_plan",
                 subscription_trial_from_plan.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoices/upcoming/lines?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/invoices/upcoming/lines"),
+            &query_args,
+        );
 
         let resp: crate::types::InvoiceLinesList = self.client.get(&url, None).await?;
 
@@ -526,57 +514,59 @@ impl Invoices {
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
     ) -> Result<Vec<crate::types::LineItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coupon.is_empty() {
-            query_args.push(("coupon".to_string(), coupon.to_string()));
+            query_args.push(("coupon", coupon.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !schedule.is_empty() {
-            query_args.push(("schedule".to_string(), schedule.to_string()));
+            query_args.push(("schedule", schedule.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
         if subscription_cancel_at_period_end {
             query_args.push((
-                "subscription_cancel_at_period_end".to_string(),
+                "subscription_cancel_at_period_end",
                 subscription_cancel_at_period_end.to_string(),
             ));
         }
         if subscription_cancel_now {
             query_args.push((
-                "subscription_cancel_now".to_string(),
+                "subscription_cancel_now",
                 subscription_cancel_now.to_string(),
             ));
         }
         if !subscription_proration_behavior.to_string().is_empty() {
             query_args.push((
-                "subscription_proration_behavior".to_string(),
+                "subscription_proration_behavior",
                 subscription_proration_behavior.to_string(),
             ));
         }
         if subscription_proration_date > 0 {
             query_args.push((
-                "subscription_proration_date".to_string(),
+                "subscription_proration_date",
                 subscription_proration_date.to_string(),
             ));
         }
         if subscription_start_date > 0 {
             query_args.push((
-                "subscription_start_date".to_string(),
+                "subscription_start_date",
                 subscription_start_date.to_string(),
             ));
         }
         if subscription_trial_from_plan {
             query_args.push((
-                "subscription_trial_from_plan".to_string(),
+                "subscription_trial_from_plan",
                 subscription_trial_from_plan.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/invoices/upcoming/lines?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/invoices/upcoming/lines"),
+            &query_args,
+        );
 
         let mut resp: crate::types::InvoiceLinesList = self.client.get(&url, None).await?;
 
@@ -596,17 +586,13 @@ impl Invoices {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -627,7 +613,8 @@ impl Invoices {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn get(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn get(&self, invoice: impl AsRef<str>) -> Result<crate::types::Invoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}",
             crate::progenitor_support::encode_path(invoice),
@@ -650,7 +637,8 @@ impl Invoices {
     *
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn post_invoices(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_invoices(&self, invoice: impl AsRef<str>) -> Result<crate::types::Invoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}",
             crate::progenitor_support::encode_path(invoice),
@@ -668,7 +656,8 @@ impl Invoices {
     *
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn delete(&self, invoice: &str) -> Result<crate::types::DeletedInvoice> {
+    pub async fn delete(&self, invoice: impl AsRef<str>) -> Result<crate::types::DeletedInvoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}",
             crate::progenitor_support::encode_path(invoice),
@@ -686,7 +675,8 @@ impl Invoices {
     *
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn post_finalize(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_finalize(&self, invoice: impl AsRef<str>) -> Result<crate::types::Invoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}/finalize",
             crate::progenitor_support::encode_path(invoice),
@@ -711,25 +701,27 @@ impl Invoices {
     pub async fn get_lines(
         &self,
         ending_before: &str,
-        invoice: &str,
+        invoice: impl AsRef<str>,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::LineItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let invoice = invoice.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/invoices/{}/lines?{}",
-            crate::progenitor_support::encode_path(invoice),
-            query_
+            query_args.push(("starting_after", starting_after.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/invoices/{}/lines",
+                crate::progenitor_support::encode_path(invoice),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::InvoiceLinesList = self.client.get(&url, None).await?;
@@ -745,7 +737,11 @@ impl Invoices {
     *
     * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
     */
-    pub async fn get_all_lines(&self, invoice: &str) -> Result<Vec<crate::types::LineItem>> {
+    pub async fn get_all_lines(
+        &self,
+        invoice: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::LineItem>> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}/lines",
             crate::progenitor_support::encode_path(invoice),
@@ -769,17 +765,13 @@ impl Invoices {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -799,7 +791,11 @@ impl Invoices {
     *
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn post_mark_uncollectible(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_mark_uncollectible(
+        &self,
+        invoice: impl AsRef<str>,
+    ) -> Result<crate::types::Invoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}/mark_uncollectible",
             crate::progenitor_support::encode_path(invoice),
@@ -817,7 +813,8 @@ impl Invoices {
     *
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn post_pay(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_pay(&self, invoice: impl AsRef<str>) -> Result<crate::types::Invoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}/pay",
             crate::progenitor_support::encode_path(invoice),
@@ -837,7 +834,8 @@ impl Invoices {
     *
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn post_send(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_send(&self, invoice: impl AsRef<str>) -> Result<crate::types::Invoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}/send",
             crate::progenitor_support::encode_path(invoice),
@@ -855,7 +853,8 @@ impl Invoices {
     *
     * * `invoice: &str` -- The account's country.
     */
-    pub async fn post_void(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_void(&self, invoice: impl AsRef<str>) -> Result<crate::types::Invoice> {
+        let invoice = invoice.as_ref();
         let url = format!(
             "/v1/invoices/{}/void",
             crate::progenitor_support::encode_path(invoice),
@@ -32,21 +32,23 @@ impl IssuerFraudRecords {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::IssuerFraudRecord>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuer_fraud_records?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuer_fraud_records"),
+            &query_args,
+        );
 
         let resp: crate::types::RadarIssuerFraudRecordList = self.client.get(&url, None).await?;
 
@@ -62,12 +64,14 @@ impl IssuerFraudRecords {
     * <p>Returns a list of issuer fraud records.</p>
     */
     pub async fn get_all(&self, charge: &str) -> Result<Vec<crate::types::IssuerFraudRecord>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuer_fraud_records?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuer_fraud_records"),
+            &query_args,
+        );
 
         let mut resp: crate::types::RadarIssuerFraudRecordList =
             self.client.get(&url, None).await?;
@@ -88,17 +92,13 @@ impl IssuerFraudRecords {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -123,8 +123,9 @@ impl IssuerFraudRecords {
     */
     pub async fn get_record(
         &self,
-        issuer_fraud_record: &str,
+        issuer_fraud_record: impl AsRef<str>,
     ) -> Result<crate::types::IssuerFraudRecord> {
+        let issuer_fraud_record = issuer_fraud_record.as_ref();
         let url = format!(
             "/v1/issuer_fraud_records/{}",
             crate::progenitor_support::encode_path(issuer_fraud_record),
@@ -38,27 +38,29 @@ impl Issuing {
         starting_after: &str,
         status: crate::types::IssuingAuthorizationStatus,
     ) -> Result<Vec<crate::types::IssuingAuthorization>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card.is_empty() {
-            query_args.push(("card".to_string(), card.to_string()));
+            query_args.push(("card", card.to_string()));
         }
         if !cardholder.is_empty() {
-            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+            query_args.push(("cardholder", cardholder.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/authorizations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuing/authorizations"),
+            &query_args,
+        );
 
         let resp: crate::types::GetIssuingAuthorizationsResponse =
             self.client.get(&url, None).await?;
@@ -81,18 +83,20 @@ impl Issuing {
         _created: &str,
         status: crate::types::IssuingAuthorizationStatus,
     ) -> Result<Vec<crate::types::IssuingAuthorization>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card.is_empty() {
-            query_args.push(("card".to_string(), card.to_string()));
+            query_args.push(("card", card.to_string()));
         }
         if !cardholder.is_empty() {
-            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+            query_args.push(("cardholder", cardholder.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/authorizations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuing/authorizations"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetIssuingAuthorizationsResponse =
             self.client.get(&url, None).await?;
@@ -113,17 +117,13 @@ impl Issuing {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -146,8 +146,9 @@ impl Issuing {
     */
     pub async fn get_authorizations_authorization(
         &self,
-        authorization: &str,
+        authorization: impl AsRef<str>,
     ) -> Result<crate::types::IssuingAuthorization> {
+        let authorization = authorization.as_ref();
         let url = format!(
             "/v1/issuing/authorizations/{}",
             crate::progenitor_support::encode_path(authorization),
@@ -167,8 +168,9 @@ impl Issuing {
     */
     pub async fn post_authorizations_authorization(
         &self,
-        authorization: &str,
+        authorization: impl AsRef<str>,
     ) -> Result<crate::types::IssuingAuthorization> {
+        let authorization = authorization.as_ref();
         let url = format!(
             "/v1/issuing/authorizations/{}",
             crate::progenitor_support::encode_path(authorization),
@@ -188,8 +190,9 @@ impl Issuing {
     */
     pub async fn post_authorizations_authorization_approve(
         &self,
-        authorization: &str,
+        authorization: impl AsRef<str>,
     ) -> Result<crate::types::IssuingAuthorization> {
+        let authorization = authorization.as_ref();
         let url = format!(
             "/v1/issuing/authorizations/{}/approve",
             crate::progenitor_support::encode_path(authorization),
@@ -209,8 +212,9 @@ impl Issuing {
     */
     pub async fn post_authorizations_authorization_decline(
         &self,
-        authorization: &str,
+        authorization: impl AsRef<str>,
     ) -> Result<crate::types::IssuingAuthorization> {
+        let authorization = authorization.as_ref();
         let url = format!(
             "/v1/issuing/authorizations/{}/decline",
             crate::progenitor_support::encode_path(authorization),
@@ -247,30 +251,32 @@ impl Issuing {
         status: crate::types::IssuingCardholderStatus,
         type_: crate::types::AccountHolderType,
     ) -> Result<Vec<crate::types::IssuingCardholder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !phone_number.is_empty() {
-            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+            query_args.push(("phone_number", phone_number.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/cardholders?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuing/cardholders"),
+            &query_args,
+        );
 
         let resp: crate::types::GetIssuingCardholdersResponse = self.client.get(&url, None).await?;
 
@@ -293,21 +299,23 @@ impl Issuing {
         status: crate::types::IssuingCardholderStatus,
         type_: crate::types::AccountHolderType,
     ) -> Result<Vec<crate::types::IssuingCardholder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !phone_number.is_empty() {
-            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+            query_args.push(("phone_number", phone_number.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/cardholders?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuing/cardholders"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetIssuingCardholdersResponse =
             self.client.get(&url, None).await?;
@@ -328,17 +336,13 @@ impl Issuing {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -371,8 +375,9 @@ impl Issuing {
     */
     pub async fn get_cardholders_cardholder(
         &self,
-        cardholder: &str,
+        cardholder: impl AsRef<str>,
     ) -> Result<crate::types::IssuingCardholder> {
+        let cardholder = cardholder.as_ref();
         let url = format!(
             "/v1/issuing/cardholders/{}",
             crate::progenitor_support::encode_path(cardholder),
@@ -392,8 +397,9 @@ impl Issuing {
     */
     pub async fn post_cardholders_cardholder(
         &self,
-        cardholder: &str,
+        cardholder: impl AsRef<str>,
     ) -> Result<crate::types::IssuingCardholder> {
+        let cardholder = cardholder.as_ref();
         let url = format!(
             "/v1/issuing/cardholders/{}",
             crate::progenitor_support::encode_path(cardholder),
@@ -434,36 +440,36 @@ impl Issuing {
         status: crate::types::IssuingCardStatus,
         type_: crate::types::IssuingCardType,
     ) -> Result<Vec<crate::types::IssuingCard>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cardholder.is_empty() {
-            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+            query_args.push(("cardholder", cardholder.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if exp_month > 0 {
-            query_args.push(("exp_month".to_string(), exp_month.to_string()));
+            query_args.push(("exp_month", exp_month.to_string()));
         }
         if exp_year > 0 {
-            query_args.push(("exp_year".to_string(), exp_year.to_string()));
+            query_args.push(("exp_year", exp_year.to_string()));
         }
         if !last_4.is_empty() {
-            query_args.push(("last4".to_string(), last_4.to_string()));
+            query_args.push(("last4", last_4.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/cards?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/issuing/cards"), &query_args);
 
         let resp: crate::types::GetIssuingCardsResponse = self.client.get(&url, None).await?;
 
@@ -488,27 +494,27 @@ impl Issuing {
         status: crate::types::IssuingCardStatus,
         type_: crate::types::IssuingCardType,
     ) -> Result<Vec<crate::types::IssuingCard>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cardholder.is_empty() {
-            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+            query_args.push(("cardholder", cardholder.to_string()));
         }
         if exp_month > 0 {
-            query_args.push(("exp_month".to_string(), exp_month.to_string()));
+            query_args.push(("exp_month", exp_month.to_string()));
         }
         if exp_year > 0 {
-            query_args.push(("exp_year".to_string(), exp_year.to_string()));
+            query_args.push(("exp_year", exp_year.to_string()));
         }
         if !last_4.is_empty() {
-            query_args.push(("last4".to_string(), last_4.to_string()));
+            query_args.push(("last4", last_4.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/cards?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/issuing/cards"), &query_args);
 
         let mut resp: crate::types::GetIssuingCardsResponse = self.client.get(&url, None).await?;
 
@@ -528,17 +534,13 @@ impl Issuing {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -569,7 +571,8 @@ impl Issuing {
     * * `card: &str` -- The account's country.
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     */
-    pub async fn get_cards_card(&self, card: &str) -> Result<crate::types::IssuingCard> {
+    pub async fn get_cards_card(&self, card: impl AsRef<str>) -> Result<crate::types::IssuingCard> {
+        let card = card.as_ref();
         let url = format!(
             "/v1/issuing/cards/{}",
             crate::progenitor_support::encode_path(card),
@@ -587,7 +590,11 @@ impl Issuing {
     *
     * * `card: &str` -- The account's country.
     */
-    pub async fn post_cards_card(&self, card: &str) -> Result<crate::types::IssuingCard> {
+    pub async fn post_cards_card(
+        &self,
+        card: impl AsRef<str>,
+    ) -> Result<crate::types::IssuingCard> {
+        let card = card.as_ref();
         let url = format!(
             "/v1/issuing/cards/{}",
             crate::progenitor_support::encode_path(card),
@@ -620,24 +627,24 @@ impl Issuing {
         status: crate::types::IssuingDisputeStatus,
         transaction: &str,
     ) -> Result<Vec<crate::types::IssuingDispute>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !transaction.is_empty() {
-            query_args.push(("transaction".to_string(), transaction.to_string()));
+            query_args.push(("transaction", transaction.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/disputes?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/issuing/disputes"), &query_args);
 
         let resp: crate::types::IssuingDisputeList = self.client.get(&url, None).await?;
 
@@ -658,15 +665,15 @@ impl Issuing {
         status: crate::types::IssuingDisputeStatus,
         transaction: &str,
     ) -> Result<Vec<crate::types::IssuingDispute>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !transaction.is_empty() {
-            query_args.push(("transaction".to_string(), transaction.to_string()));
+            query_args.push(("transaction", transaction.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/disputes?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/issuing/disputes"), &query_args);
 
         let mut resp: crate::types::IssuingDisputeList = self.client.get(&url, None).await?;
 
@@ -686,17 +693,13 @@ impl Issuing {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -729,8 +732,9 @@ impl Issuing {
     */
     pub async fn get_disputes_dispute(
         &self,
-        dispute: &str,
+        dispute: impl AsRef<str>,
     ) -> Result<crate::types::IssuingDispute> {
+        let dispute = dispute.as_ref();
         let url = format!(
             "/v1/issuing/disputes/{}",
             crate::progenitor_support::encode_path(dispute),
@@ -750,8 +754,9 @@ impl Issuing {
     */
     pub async fn post_disputes_dispute(
         &self,
-        dispute: &str,
+        dispute: impl AsRef<str>,
     ) -> Result<crate::types::IssuingDispute> {
+        let dispute = dispute.as_ref();
         let url = format!(
             "/v1/issuing/disputes/{}",
             crate::progenitor_support::encode_path(dispute),
@@ -771,8 +776,9 @@ impl Issuing {
     */
     pub async fn post_disputes_dispute_submit(
         &self,
-        dispute: &str,
+        dispute: impl AsRef<str>,
     ) -> Result<crate::types::IssuingDispute> {
+        let dispute = dispute.as_ref();
         let url = format!(
             "/v1/issuing/disputes/{}/submit",
             crate::progenitor_support::encode_path(dispute),
@@ -801,18 +807,20 @@ impl Issuing {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::IssuingSettlement>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/settlements?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuing/settlements"),
+            &query_args,
+        );
 
         let resp: crate::types::GetIssuingSettlementsResponse = self.client.get(&url, None).await?;
 
@@ -851,17 +859,13 @@ impl Issuing {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -884,8 +888,9 @@ impl Issuing {
     */
     pub async fn get_settlements_settlement(
         &self,
-        settlement: &str,
+        settlement: impl AsRef<str>,
     ) -> Result<crate::types::IssuingSettlement> {
+        let settlement = settlement.as_ref();
         let url = format!(
             "/v1/issuing/settlements/{}",
             crate::progenitor_support::encode_path(settlement),
@@ -905,8 +910,9 @@ impl Issuing {
     */
     pub async fn post_settlements_settlement(
         &self,
-        settlement: &str,
+        settlement: impl AsRef<str>,
     ) -> Result<crate::types::IssuingSettlement> {
+        let settlement = settlement.as_ref();
         let url = format!(
             "/v1/issuing/settlements/{}",
             crate::progenitor_support::encode_path(settlement),
@@ -941,27 +947,29 @@ impl Issuing {
         starting_after: &str,
         type_: crate::types::IssuingTransactionType,
     ) -> Result<Vec<crate::types::IssuingTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card.is_empty() {
-            query_args.push(("card".to_string(), card.to_string()));
+            query_args.push(("card", card.to_string()));
         }
         if !cardholder.is_empty() {
-            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+            query_args.push(("cardholder", cardholder.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/transactions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuing/transactions"),
+            &query_args,
+        );
 
         let resp: crate::types::GetIssuingTransactionsResponse =
             self.client.get(&url, None).await?;
@@ -984,18 +992,20 @@ impl Issuing {
         _created: &str,
         type_: crate::types::IssuingTransactionType,
     ) -> Result<Vec<crate::types::IssuingTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card.is_empty() {
-            query_args.push(("card".to_string(), card.to_string()));
+            query_args.push(("card", card.to_string()));
         }
         if !cardholder.is_empty() {
-            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+            query_args.push(("cardholder", cardholder.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/issuing/transactions?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/issuing/transactions"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetIssuingTransactionsResponse =
             self.client.get(&url, None).await?;
@@ -1016,17 +1026,13 @@ impl Issuing {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -1049,8 +1055,9 @@ impl Issuing {
     */
     pub async fn get_transactions_transaction(
         &self,
-        transaction: &str,
+        transaction: impl AsRef<str>,
     ) -> Result<crate::types::IssuingTransaction> {
+        let transaction = transaction.as_ref();
         let url = format!(
             "/v1/issuing/transactions/{}",
             crate::progenitor_support::encode_path(transaction),
@@ -1070,8 +1077,9 @@ impl Issuing {
     */
     pub async fn post_transactions_transaction(
         &self,
-        transaction: &str,
+        transaction: impl AsRef<str>,
     ) -> Result<crate::types::IssuingTransaction> {
+        let transaction = transaction.as_ref();
         let url = format!(
             "/v1/issuing/transactions/{}",
             crate::progenitor_support::encode_path(transaction),
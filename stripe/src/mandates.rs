@@ -22,7 +22,8 @@ impl Mandates {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `mandate: &str` -- The account's country.
     */
-    pub async fn get(&self, mandate: &str) -> Result<crate::types::Mandate> {
+    pub async fn get(&self, mandate: impl AsRef<str>) -> Result<crate::types::Mandate> {
+        let mandate = mandate.as_ref();
         let url = format!(
             "/v1/mandates/{}",
             crate::progenitor_support::encode_path(mandate),
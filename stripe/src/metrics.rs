@@ -0,0 +1,382 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: dolladollabills::Client) {
+//! use dolladollabills::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "Stripe";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/v1/3d_secure",
+    "/v1/3d_secure/{three_d_secure}",
+    "/v1/account",
+    "/v1/account/bank_accounts",
+    "/v1/account/bank_accounts/{id}",
+    "/v1/account/capabilities",
+    "/v1/account/capabilities/{capability}",
+    "/v1/account/external_accounts",
+    "/v1/account/external_accounts/{id}",
+    "/v1/account/login_links",
+    "/v1/account/people",
+    "/v1/account/people/{person}",
+    "/v1/account/persons",
+    "/v1/account/persons/{person}",
+    "/v1/account_links",
+    "/v1/accounts",
+    "/v1/accounts/{account}",
+    "/v1/accounts/{account}/bank_accounts",
+    "/v1/accounts/{account}/bank_accounts/{id}",
+    "/v1/accounts/{account}/capabilities",
+    "/v1/accounts/{account}/capabilities/{capability}",
+    "/v1/accounts/{account}/external_accounts",
+    "/v1/accounts/{account}/external_accounts/{id}",
+    "/v1/accounts/{account}/login_links",
+    "/v1/accounts/{account}/people",
+    "/v1/accounts/{account}/people/{person}",
+    "/v1/accounts/{account}/persons",
+    "/v1/accounts/{account}/persons/{person}",
+    "/v1/accounts/{account}/reject",
+    "/v1/apple_pay/domains",
+    "/v1/apple_pay/domains/{domain}",
+    "/v1/application_fees",
+    "/v1/application_fees/{fee}/refunds/{id}",
+    "/v1/application_fees/{id}",
+    "/v1/application_fees/{id}/refund",
+    "/v1/application_fees/{id}/refunds",
+    "/v1/balance",
+    "/v1/balance/history",
+    "/v1/balance/history/{id}",
+    "/v1/balance_transactions",
+    "/v1/balance_transactions/{id}",
+    "/v1/billing_portal/configurations",
+    "/v1/billing_portal/configurations/{configuration}",
+    "/v1/billing_portal/sessions",
+    "/v1/bitcoin/receivers",
+    "/v1/bitcoin/receivers/{id}",
+    "/v1/bitcoin/receivers/{receiver}/transactions",
+    "/v1/bitcoin/transactions",
+    "/v1/charges",
+    "/v1/charges/search",
+    "/v1/charges/{charge}",
+    "/v1/charges/{charge}/capture",
+    "/v1/charges/{charge}/dispute",
+    "/v1/charges/{charge}/dispute/close",
+    "/v1/charges/{charge}/refund",
+    "/v1/charges/{charge}/refunds",
+    "/v1/charges/{charge}/refunds/{refund}",
+    "/v1/checkout/sessions",
+    "/v1/checkout/sessions/{session}",
+    "/v1/checkout/sessions/{session}/expire",
+    "/v1/checkout/sessions/{session}/line_items",
+    "/v1/country_specs",
+    "/v1/country_specs/{country}",
+    "/v1/coupons",
+    "/v1/coupons/{coupon}",
+    "/v1/credit_notes",
+    "/v1/credit_notes/preview",
+    "/v1/credit_notes/preview/lines",
+    "/v1/credit_notes/{credit_note}/lines",
+    "/v1/credit_notes/{id}",
+    "/v1/credit_notes/{id}/void",
+    "/v1/customers",
+    "/v1/customers/search",
+    "/v1/customers/{customer}",
+    "/v1/customers/{customer}/balance_transactions",
+    "/v1/customers/{customer}/balance_transactions/{transaction}",
+    "/v1/customers/{customer}/bank_accounts",
+    "/v1/customers/{customer}/bank_accounts/{id}",
+    "/v1/customers/{customer}/bank_accounts/{id}/verify",
+    "/v1/customers/{customer}/cards",
+    "/v1/customers/{customer}/cards/{id}",
+    "/v1/customers/{customer}/discount",
+    "/v1/customers/{customer}/payment_methods",
+    "/v1/customers/{customer}/sources",
+    "/v1/customers/{customer}/sources/{id}",
+    "/v1/customers/{customer}/sources/{id}/verify",
+    "/v1/customers/{customer}/subscriptions",
+    "/v1/customers/{customer}/subscriptions/{subscription_exposed_id}",
+    "/v1/customers/{customer}/subscriptions/{subscription_exposed_id}/discount",
+    "/v1/customers/{customer}/tax_ids",
+    "/v1/customers/{customer}/tax_ids/{id}",
+    "/v1/disputes",
+    "/v1/disputes/{dispute}",
+    "/v1/disputes/{dispute}/close",
+    "/v1/ephemeral_keys",
+    "/v1/ephemeral_keys/{key}",
+    "/v1/events",
+    "/v1/events/{id}",
+    "/v1/exchange_rates",
+    "/v1/exchange_rates/{rate_id}",
+    "/v1/file_links",
+    "/v1/file_links/{link}",
+    "/v1/files",
+    "/v1/files/{file}",
+    "/v1/identity/verification_reports",
+    "/v1/identity/verification_reports/{report}",
+    "/v1/identity/verification_sessions",
+    "/v1/identity/verification_sessions/{session}",
+    "/v1/identity/verification_sessions/{session}/cancel",
+    "/v1/identity/verification_sessions/{session}/redact",
+    "/v1/invoiceitems",
+    "/v1/invoiceitems/{invoiceitem}",
+    "/v1/invoices",
+    "/v1/invoices/search",
+    "/v1/invoices/upcoming",
+    "/v1/invoices/upcoming/lines",
+    "/v1/invoices/{invoice}",
+    "/v1/invoices/{invoice}/finalize",
+    "/v1/invoices/{invoice}/lines",
+    "/v1/invoices/{invoice}/mark_uncollectible",
+    "/v1/invoices/{invoice}/pay",
+    "/v1/invoices/{invoice}/send",
+    "/v1/invoices/{invoice}/void",
+    "/v1/issuer_fraud_records",
+    "/v1/issuer_fraud_records/{issuer_fraud_record}",
+    "/v1/issuing/authorizations",
+    "/v1/issuing/authorizations/{authorization}",
+    "/v1/issuing/authorizations/{authorization}/approve",
+    "/v1/issuing/authorizations/{authorization}/decline",
+    "/v1/issuing/cardholders",
+    "/v1/issuing/cardholders/{cardholder}",
+    "/v1/issuing/cards",
+    "/v1/issuing/cards/{card}",
+    "/v1/issuing/disputes",
+    "/v1/issuing/disputes/{dispute}",
+    "/v1/issuing/disputes/{dispute}/submit",
+    "/v1/issuing/settlements",
+    "/v1/issuing/settlements/{settlement}",
+    "/v1/issuing/transactions",
+    "/v1/issuing/transactions/{transaction}",
+    "/v1/mandates/{mandate}",
+    "/v1/order_returns",
+    "/v1/order_returns/{id}",
+    "/v1/orders",
+    "/v1/orders/{id}",
+    "/v1/orders/{id}/pay",
+    "/v1/orders/{id}/returns",
+    "/v1/payment_intents",
+    "/v1/payment_intents/search",
+    "/v1/payment_intents/{intent}",
+    "/v1/payment_intents/{intent}/cancel",
+    "/v1/payment_intents/{intent}/capture",
+    "/v1/payment_intents/{intent}/confirm",
+    "/v1/payment_intents/{intent}/verify_microdeposits",
+    "/v1/payment_links",
+    "/v1/payment_links/{payment_link}",
+    "/v1/payment_links/{payment_link}/line_items",
+    "/v1/payment_methods",
+    "/v1/payment_methods/{payment_method}",
+    "/v1/payment_methods/{payment_method}/attach",
+    "/v1/payment_methods/{payment_method}/detach",
+    "/v1/payouts",
+    "/v1/payouts/{payout}",
+    "/v1/payouts/{payout}/cancel",
+    "/v1/payouts/{payout}/reverse",
+    "/v1/plans",
+    "/v1/plans/{plan}",
+    "/v1/prices",
+    "/v1/prices/search",
+    "/v1/prices/{price}",
+    "/v1/products",
+    "/v1/products/search",
+    "/v1/products/{id}",
+    "/v1/promotion_codes",
+    "/v1/promotion_codes/{promotion_code}",
+    "/v1/quotes",
+    "/v1/quotes/{quote}",
+    "/v1/quotes/{quote}/accept",
+    "/v1/quotes/{quote}/cancel",
+    "/v1/quotes/{quote}/computed_upfront_line_items",
+    "/v1/quotes/{quote}/finalize",
+    "/v1/quotes/{quote}/line_items",
+    "/v1/quotes/{quote}/pdf",
+    "/v1/radar/early_fraud_warnings",
+    "/v1/radar/early_fraud_warnings/{early_fraud_warning}",
+    "/v1/radar/value_list_items",
+    "/v1/radar/value_list_items/{item}",
+    "/v1/radar/value_lists",
+    "/v1/radar/value_lists/{value_list}",
+    "/v1/recipients",
+    "/v1/recipients/{id}",
+    "/v1/refunds",
+    "/v1/refunds/{refund}",
+    "/v1/refunds/{refund}/cancel",
+    "/v1/reporting/report_runs",
+    "/v1/reporting/report_runs/{report_run}",
+    "/v1/reporting/report_types",
+    "/v1/reporting/report_types/{report_type}",
+    "/v1/reviews",
+    "/v1/reviews/{review}",
+    "/v1/reviews/{review}/approve",
+    "/v1/setup_attempts",
+    "/v1/setup_intents",
+    "/v1/setup_intents/{intent}",
+    "/v1/setup_intents/{intent}/cancel",
+    "/v1/setup_intents/{intent}/confirm",
+    "/v1/setup_intents/{intent}/verify_microdeposits",
+    "/v1/shipping_rates",
+    "/v1/shipping_rates/{shipping_rate_token}",
+    "/v1/sigma/scheduled_query_runs",
+    "/v1/sigma/scheduled_query_runs/{scheduled_query_run}",
+    "/v1/skus",
+    "/v1/skus/{id}",
+    "/v1/sources",
+    "/v1/sources/{source}",
+    "/v1/sources/{source}/mandate_notifications/{mandate_notification}",
+    "/v1/sources/{source}/source_transactions",
+    "/v1/sources/{source}/source_transactions/{source_transaction}",
+    "/v1/sources/{source}/verify",
+    "/v1/subscription_items",
+    "/v1/subscription_items/{item}",
+    "/v1/subscription_items/{subscription_item}/usage_record_summaries",
+    "/v1/subscription_items/{subscription_item}/usage_records",
+    "/v1/subscription_schedules",
+    "/v1/subscription_schedules/{schedule}",
+    "/v1/subscription_schedules/{schedule}/cancel",
+    "/v1/subscription_schedules/{schedule}/release",
+    "/v1/subscriptions",
+    "/v1/subscriptions/search",
+    "/v1/subscriptions/{subscription_exposed_id}",
+    "/v1/subscriptions/{subscription_exposed_id}/discount",
+    "/v1/tax_codes",
+    "/v1/tax_codes/{id}",
+    "/v1/tax_rates",
+    "/v1/tax_rates/{tax_rate}",
+    "/v1/terminal/connection_tokens",
+    "/v1/terminal/locations",
+    "/v1/terminal/locations/{location}",
+    "/v1/terminal/readers",
+    "/v1/terminal/readers/{reader}",
+    "/v1/terminal/readers/{reader}/cancel_action",
+    "/v1/terminal/readers/{reader}/process_payment_intent",
+    "/v1/terminal/readers/{reader}/process_setup_intent",
+    "/v1/terminal/readers/{reader}/set_reader_display",
+    "/v1/test_helpers/terminal/readers/{reader}/present_payment_method",
+    "/v1/test_helpers/test_clocks",
+    "/v1/test_helpers/test_clocks/{test_clock}",
+    "/v1/test_helpers/test_clocks/{test_clock}/advance",
+    "/v1/tokens",
+    "/v1/tokens/{token}",
+    "/v1/topups",
+    "/v1/topups/{topup}",
+    "/v1/topups/{topup}/cancel",
+    "/v1/transfers",
+    "/v1/transfers/{id}/reversals",
+    "/v1/transfers/{transfer}",
+    "/v1/transfers/{transfer}/reversals/{id}",
+    "/v1/webhook_endpoints",
+    "/v1/webhook_endpoints/{webhook_endpoint}",
+];
@@ -34,21 +34,21 @@ impl OrderReturns {
         order: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::OrderReturn>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/order_returns?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/order_returns"), &query_args);
 
         let resp: crate::types::Returns = self.client.get(&url, None).await?;
 
@@ -68,12 +68,12 @@ impl OrderReturns {
         _created: &str,
         order: &str,
     ) -> Result<Vec<crate::types::OrderReturn>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/order_returns?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/order_returns"), &query_args);
 
         let mut resp: crate::types::Returns = self.client.get(&url, None).await?;
 
@@ -93,17 +93,13 @@ impl OrderReturns {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -124,7 +120,8 @@ impl OrderReturns {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::OrderReturn> {
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::OrderReturn> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/order_returns/{}",
             crate::progenitor_support::encode_path(id),
@@ -42,24 +42,23 @@ impl Orders {
         _status_transitions: &str,
         _upstream_ids: &[String],
     ) -> Result<Vec<crate::types::Order>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/orders?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/orders"), &query_args);
 
         let resp: crate::types::OrdersLegacyResourceOrderList = self.client.get(&url, None).await?;
 
@@ -83,15 +82,14 @@ impl Orders {
         _status_transitions: &str,
         _upstream_ids: &[String],
     ) -> Result<Vec<crate::types::Order>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/orders?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/orders"), &query_args);
 
         let mut resp: crate::types::OrdersLegacyResourceOrderList =
             self.client.get(&url, None).await?;
@@ -112,17 +110,13 @@ impl Orders {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -153,11 +147,9 @@ impl Orders {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Order> {
-        let url = format!(
-            "/v1/orders/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::Order> {
+        let id = id.as_ref();
+        let url = format!("/v1/orders/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
@@ -171,11 +163,9 @@ impl Orders {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_orders(&self, id: &str) -> Result<crate::types::Order> {
-        let url = format!(
-            "/v1/orders/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn post_orders(&self, id: impl AsRef<str>) -> Result<crate::types::Order> {
+        let id = id.as_ref();
+        let url = format!("/v1/orders/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.post(&url, None).await
     }
@@ -189,7 +179,8 @@ impl Orders {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_pay(&self, id: &str) -> Result<crate::types::Order> {
+    pub async fn post_pay(&self, id: impl AsRef<str>) -> Result<crate::types::Order> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/orders/{}/pay",
             crate::progenitor_support::encode_path(id),
@@ -207,7 +198,8 @@ impl Orders {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_return(&self, id: &str) -> Result<crate::types::OrderReturn> {
+    pub async fn post_return(&self, id: impl AsRef<str>) -> Result<crate::types::OrderReturn> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/orders/{}/returns",
             crate::progenitor_support::encode_path(id),
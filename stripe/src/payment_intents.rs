@@ -34,21 +34,21 @@ impl PaymentIntents {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::PaymentIntent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_intents?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/payment_intents"), &query_args);
 
         let resp: crate::types::PaymentFlowsIntentList = self.client.get(&url, None).await?;
 
@@ -68,12 +68,12 @@ impl PaymentIntents {
         _created: &str,
         customer: &str,
     ) -> Result<Vec<crate::types::PaymentIntent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_intents?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/payment_intents"), &query_args);
 
         let mut resp: crate::types::PaymentFlowsIntentList = self.client.get(&url, None).await?;
 
@@ -93,17 +93,13 @@ impl PaymentIntents {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -154,18 +150,20 @@ impl PaymentIntents {
         page: &str,
         query: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_intents/search?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/payment_intents/search"),
+            &query_args,
+        );
 
         let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -184,12 +182,14 @@ impl PaymentIntents {
     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
     */
     pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_intents/search?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/payment_intents/search"),
+            &query_args,
+        );
 
         let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -209,17 +209,13 @@ impl PaymentIntents {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -248,17 +244,19 @@ impl PaymentIntents {
     pub async fn get_intent(
         &self,
         client_secret: &str,
-        intent: &str,
+        intent: impl AsRef<str>,
     ) -> Result<crate::types::PaymentIntent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let intent = intent.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/payment_intents/{}?{}",
-            crate::progenitor_support::encode_path(intent),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/payment_intents/{}",
+                crate::progenitor_support::encode_path(intent),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -279,7 +277,11 @@ impl PaymentIntents {
     *
     * * `intent: &str` -- The account's country.
     */
-    pub async fn post_intent(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent(
+        &self,
+        intent: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/payment_intents/{}",
             crate::progenitor_support::encode_path(intent),
@@ -301,7 +303,11 @@ impl PaymentIntents {
     *
     * * `intent: &str` -- The account's country.
     */
-    pub async fn post_intent_cancel(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_cancel(
+        &self,
+        intent: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/payment_intents/{}/cancel",
             crate::progenitor_support::encode_path(intent),
@@ -323,7 +329,11 @@ impl PaymentIntents {
     *
     * * `intent: &str` -- The account's country.
     */
-    pub async fn post_intent_capture(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_capture(
+        &self,
+        intent: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/payment_intents/{}/capture",
             crate::progenitor_support::encode_path(intent),
@@ -365,7 +375,11 @@ impl PaymentIntents {
     *
     * * `intent: &str` -- The account's country.
     */
-    pub async fn post_intent_confirm(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_confirm(
+        &self,
+        intent: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/payment_intents/{}/confirm",
             crate::progenitor_support::encode_path(intent),
@@ -385,8 +399,9 @@ impl PaymentIntents {
     */
     pub async fn post_intent_verify_microdeposit(
         &self,
-        intent: &str,
+        intent: impl AsRef<str>,
     ) -> Result<crate::types::PaymentIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/payment_intents/{}/verify_microdeposits",
             crate::progenitor_support::encode_path(intent),
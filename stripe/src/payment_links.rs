@@ -32,21 +32,21 @@ impl PaymentLinks {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::PaymentLink>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_links?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/payment_links"), &query_args);
 
         let resp: crate::types::GetPaymentLinksResponse = self.client.get(&url, None).await?;
 
@@ -62,12 +62,12 @@ impl PaymentLinks {
     * <p>Returns a list of your payment links.</p>
     */
     pub async fn get_all(&self, active: bool) -> Result<Vec<crate::types::PaymentLink>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_links?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/payment_links"), &query_args);
 
         let mut resp: crate::types::GetPaymentLinksResponse = self.client.get(&url, None).await?;
 
@@ -87,17 +87,13 @@ impl PaymentLinks {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -128,7 +124,11 @@ impl PaymentLinks {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `payment_link: &str` -- The account's country.
     */
-    pub async fn get_link(&self, payment_link: &str) -> Result<crate::types::PaymentLink> {
+    pub async fn get_link(
+        &self,
+        payment_link: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentLink> {
+        let payment_link = payment_link.as_ref();
         let url = format!(
             "/v1/payment_links/{}",
             crate::progenitor_support::encode_path(payment_link),
@@ -146,7 +146,11 @@ impl PaymentLinks {
     *
     * * `payment_link: &str` -- The account's country.
     */
-    pub async fn post_link(&self, payment_link: &str) -> Result<crate::types::PaymentLink> {
+    pub async fn post_link(
+        &self,
+        payment_link: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentLink> {
+        let payment_link = payment_link.as_ref();
         let url = format!(
             "/v1/payment_links/{}",
             crate::progenitor_support::encode_path(payment_link),
@@ -172,24 +176,26 @@ impl PaymentLinks {
         &self,
         ending_before: &str,
         limit: i64,
-        payment_link: &str,
+        payment_link: impl AsRef<str>,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Item>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let payment_link = payment_link.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/payment_links/{}/line_items?{}",
-            crate::progenitor_support::encode_path(payment_link),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/payment_links/{}/line_items",
+                crate::progenitor_support::encode_path(payment_link),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::LineItems = self.client.get(&url, None).await?;
@@ -207,8 +213,9 @@ impl PaymentLinks {
     */
     pub async fn get_all_link_line_items(
         &self,
-        payment_link: &str,
+        payment_link: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Item>> {
+        let payment_link = payment_link.as_ref();
         let url = format!(
             "/v1/payment_links/{}/line_items",
             crate::progenitor_support::encode_path(payment_link),
@@ -232,17 +239,13 @@ impl PaymentLinks {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
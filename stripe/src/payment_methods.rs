@@ -34,24 +34,24 @@ impl PaymentMethods {
         starting_after: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> Result<Vec<crate::types::PaymentMethod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_methods?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/payment_methods"), &query_args);
 
         let resp: crate::types::PaymentFlowsMethodList = self.client.get(&url, None).await?;
 
@@ -71,15 +71,15 @@ impl PaymentMethods {
         customer: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> Result<Vec<crate::types::PaymentMethod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payment_methods?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/payment_methods"), &query_args);
 
         let mut resp: crate::types::PaymentFlowsMethodList = self.client.get(&url, None).await?;
 
@@ -99,17 +99,13 @@ impl PaymentMethods {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -142,7 +138,11 @@ impl PaymentMethods {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `payment_method: &str` -- The account's country.
     */
-    pub async fn get_method(&self, payment_method: &str) -> Result<crate::types::PaymentMethod> {
+    pub async fn get_method(
+        &self,
+        payment_method: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentMethod> {
+        let payment_method = payment_method.as_ref();
         let url = format!(
             "/v1/payment_methods/{}",
             crate::progenitor_support::encode_path(payment_method),
@@ -160,7 +160,11 @@ impl PaymentMethods {
     *
     * * `payment_method: &str` -- The account's country.
     */
-    pub async fn post_method(&self, payment_method: &str) -> Result<crate::types::PaymentMethod> {
+    pub async fn post_method(
+        &self,
+        payment_method: impl AsRef<str>,
+    ) -> Result<crate::types::PaymentMethod> {
+        let payment_method = payment_method.as_ref();
         let url = format!(
             "/v1/payment_methods/{}",
             crate::progenitor_support::encode_path(payment_method),
@@ -190,8 +194,9 @@ impl PaymentMethods {
     */
     pub async fn post_method_attach(
         &self,
-        payment_method: &str,
+        payment_method: impl AsRef<str>,
     ) -> Result<crate::types::PaymentMethod> {
+        let payment_method = payment_method.as_ref();
         let url = format!(
             "/v1/payment_methods/{}/attach",
             crate::progenitor_support::encode_path(payment_method),
@@ -211,8 +216,9 @@ impl PaymentMethods {
     */
     pub async fn post_method_detach(
         &self,
-        payment_method: &str,
+        payment_method: impl AsRef<str>,
     ) -> Result<crate::types::PaymentMethod> {
+        let payment_method = payment_method.as_ref();
         let url = format!(
             "/v1/payment_methods/{}/detach",
             crate::progenitor_support::encode_path(payment_method),
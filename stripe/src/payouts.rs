@@ -38,24 +38,23 @@ impl Payouts {
         starting_after: &str,
         status: &str,
     ) -> Result<Vec<crate::types::Payout>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !destination.is_empty() {
-            query_args.push(("destination".to_string(), destination.to_string()));
+            query_args.push(("destination", destination.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payouts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/payouts"), &query_args);
 
         let resp: crate::types::PayoutList = self.client.get(&url, None).await?;
 
@@ -77,15 +76,14 @@ impl Payouts {
         destination: &str,
         status: &str,
     ) -> Result<Vec<crate::types::Payout>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !destination.is_empty() {
-            query_args.push(("destination".to_string(), destination.to_string()));
+            query_args.push(("destination", destination.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/payouts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/payouts"), &query_args);
 
         let mut resp: crate::types::PayoutList = self.client.get(&url, None).await?;
 
@@ -105,17 +103,13 @@ impl Payouts {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -150,7 +144,8 @@ impl Payouts {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `payout: &str` -- The account's country.
     */
-    pub async fn get(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn get(&self, payout: impl AsRef<str>) -> Result<crate::types::Payout> {
+        let payout = payout.as_ref();
         let url = format!(
             "/v1/payouts/{}",
             crate::progenitor_support::encode_path(payout),
@@ -168,7 +163,8 @@ impl Payouts {
     *
     * * `payout: &str` -- The account's country.
     */
-    pub async fn post_payouts(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_payouts(&self, payout: impl AsRef<str>) -> Result<crate::types::Payout> {
+        let payout = payout.as_ref();
         let url = format!(
             "/v1/payouts/{}",
             crate::progenitor_support::encode_path(payout),
@@ -186,7 +182,8 @@ impl Payouts {
     *
     * * `payout: &str` -- The account's country.
     */
-    pub async fn post_cancel(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_cancel(&self, payout: impl AsRef<str>) -> Result<crate::types::Payout> {
+        let payout = payout.as_ref();
         let url = format!(
             "/v1/payouts/{}/cancel",
             crate::progenitor_support::encode_path(payout),
@@ -206,7 +203,8 @@ impl Payouts {
     *
     * * `payout: &str` -- The account's country.
     */
-    pub async fn post_reverse(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_reverse(&self, payout: impl AsRef<str>) -> Result<crate::types::Payout> {
+        let payout = payout.as_ref();
         let url = format!(
             "/v1/payouts/{}/reverse",
             crate::progenitor_support::encode_path(payout),
@@ -36,24 +36,23 @@ impl Plans {
         product: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::PlanData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product.is_empty() {
-            query_args.push(("product".to_string(), product.to_string()));
+            query_args.push(("product", product.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/plans?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/plans"), &query_args);
 
         let resp: crate::types::PlanList = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl Plans {
         _created: &str,
         product: &str,
     ) -> Result<Vec<crate::types::PlanData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !product.is_empty() {
-            query_args.push(("product".to_string(), product.to_string()));
+            query_args.push(("product", product.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/plans?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/plans"), &query_args);
 
         let mut resp: crate::types::PlanList = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl Plans {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -143,11 +137,9 @@ impl Plans {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `plan: &str` -- The account's country.
     */
-    pub async fn get(&self, plan: &str) -> Result<crate::types::PlanData> {
-        let url = format!(
-            "/v1/plans/{}",
-            crate::progenitor_support::encode_path(plan),
-        );
+    pub async fn get(&self, plan: impl AsRef<str>) -> Result<crate::types::PlanData> {
+        let plan = plan.as_ref();
+        let url = format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan),);
 
         self.client.get(&url, None).await
     }
@@ -161,11 +153,9 @@ impl Plans {
     *
     * * `plan: &str` -- The account's country.
     */
-    pub async fn post_plans(&self, plan: &str) -> Result<crate::types::PlanData> {
-        let url = format!(
-            "/v1/plans/{}",
-            crate::progenitor_support::encode_path(plan),
-        );
+    pub async fn post_plans(&self, plan: impl AsRef<str>) -> Result<crate::types::PlanData> {
+        let plan = plan.as_ref();
+        let url = format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan),);
 
         self.client.post(&url, None).await
     }
@@ -179,11 +169,9 @@ impl Plans {
     *
     * * `plan: &str` -- The account's country.
     */
-    pub async fn delete(&self, plan: &str) -> Result<crate::types::DeletedPlan> {
-        let url = format!(
-            "/v1/plans/{}",
-            crate::progenitor_support::encode_path(plan),
-        );
+    pub async fn delete(&self, plan: impl AsRef<str>) -> Result<crate::types::DeletedPlan> {
+        let plan = plan.as_ref();
+        let url = format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan),);
 
         self.client.delete(&url, None).await
     }
@@ -44,30 +44,29 @@ impl Prices {
         starting_after: &str,
         type_: crate::types::PriceType,
     ) -> Result<Vec<crate::types::PriceData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product.is_empty() {
-            query_args.push(("product".to_string(), product.to_string()));
+            query_args.push(("product", product.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/prices?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/prices"), &query_args);
 
         let resp: crate::types::PriceList = self.client.get(&url, None).await?;
 
@@ -92,21 +91,20 @@ impl Prices {
         _recurring: &str,
         type_: crate::types::PriceType,
     ) -> Result<Vec<crate::types::PriceData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !product.is_empty() {
-            query_args.push(("product".to_string(), product.to_string()));
+            query_args.push(("product", product.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/prices?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/prices"), &query_args);
 
         let mut resp: crate::types::PriceList = self.client.get(&url, None).await?;
 
@@ -126,17 +124,13 @@ impl Prices {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -178,18 +172,18 @@ impl Prices {
         page: &str,
         query: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/prices/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/prices/search"), &query_args);
 
         let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -208,12 +202,12 @@ impl Prices {
     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
     */
     pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/prices/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/prices/search"), &query_args);
 
         let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -233,17 +227,13 @@ impl Prices {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -264,7 +254,8 @@ impl Prices {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `price: &str` -- The account's country.
     */
-    pub async fn get(&self, price: &str) -> Result<crate::types::PriceData> {
+    pub async fn get(&self, price: impl AsRef<str>) -> Result<crate::types::PriceData> {
+        let price = price.as_ref();
         let url = format!(
             "/v1/prices/{}",
             crate::progenitor_support::encode_path(price),
@@ -282,7 +273,8 @@ impl Prices {
     *
     * * `price: &str` -- The account's country.
     */
-    pub async fn post_prices(&self, price: &str) -> Result<crate::types::PriceData> {
+    pub async fn post_prices(&self, price: impl AsRef<str>) -> Result<crate::types::PriceData> {
+        let price = price.as_ref();
         let url = format!(
             "/v1/prices/{}",
             crate::progenitor_support::encode_path(price),
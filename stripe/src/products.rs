@@ -40,27 +40,26 @@ impl Products {
         starting_after: &str,
         url: &str,
     ) -> Result<Vec<crate::types::Product>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if shippable {
-            query_args.push(("shippable".to_string(), shippable.to_string()));
+            query_args.push(("shippable", shippable.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !url.is_empty() {
-            query_args.push(("url".to_string(), url.to_string()));
+            query_args.push(("url", url.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/products?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/products"), &query_args);
 
         let resp: crate::types::ProductList = self.client.get(&url, None).await?;
 
@@ -83,18 +82,17 @@ impl Products {
         shippable: bool,
         url: &str,
     ) -> Result<Vec<crate::types::Product>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if shippable {
-            query_args.push(("shippable".to_string(), shippable.to_string()));
+            query_args.push(("shippable", shippable.to_string()));
         }
         if !url.is_empty() {
-            query_args.push(("url".to_string(), url.to_string()));
+            query_args.push(("url", url.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/products?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/products"), &query_args);
 
         let mut resp: crate::types::ProductList = self.client.get(&url, None).await?;
 
@@ -114,17 +112,13 @@ impl Products {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -166,18 +160,18 @@ impl Products {
         page: &str,
         query: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/products/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/products/search"), &query_args);
 
         let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -196,12 +190,12 @@ impl Products {
     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
     */
     pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/products/search?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/products/search"), &query_args);
 
         let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -221,17 +215,13 @@ impl Products {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -252,7 +242,8 @@ impl Products {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Product> {
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::Product> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/products/{}",
             crate::progenitor_support::encode_path(id),
@@ -270,7 +261,8 @@ impl Products {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_products(&self, id: &str) -> Result<crate::types::Product> {
+    pub async fn post_products(&self, id: impl AsRef<str>) -> Result<crate::types::Product> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/products/{}",
             crate::progenitor_support::encode_path(id),
@@ -288,7 +280,8 @@ impl Products {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn delete(&self, id: &str) -> Result<crate::types::DeletedProduct> {
+    pub async fn delete(&self, id: impl AsRef<str>) -> Result<crate::types::DeletedProduct> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/products/{}",
             crate::progenitor_support::encode_path(id),
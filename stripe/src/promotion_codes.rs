@@ -40,30 +40,30 @@ impl PromotionCodes {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::PromotionCode>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !coupon.is_empty() {
-            query_args.push(("coupon".to_string(), coupon.to_string()));
+            query_args.push(("coupon", coupon.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/promotion_codes?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/promotion_codes"), &query_args);
 
         let resp: crate::types::GetPromotionCodesResponse = self.client.get(&url, None).await?;
 
@@ -86,21 +86,21 @@ impl PromotionCodes {
         _created: &str,
         customer: &str,
     ) -> Result<Vec<crate::types::PromotionCode>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !coupon.is_empty() {
-            query_args.push(("coupon".to_string(), coupon.to_string()));
+            query_args.push(("coupon", coupon.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/promotion_codes?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/promotion_codes"), &query_args);
 
         let mut resp: crate::types::GetPromotionCodesResponse = self.client.get(&url, None).await?;
 
@@ -120,17 +120,13 @@ impl PromotionCodes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -161,7 +157,11 @@ impl PromotionCodes {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `promotion_code: &str` -- The account's country.
     */
-    pub async fn get_code(&self, promotion_code: &str) -> Result<crate::types::PromotionCode> {
+    pub async fn get_code(
+        &self,
+        promotion_code: impl AsRef<str>,
+    ) -> Result<crate::types::PromotionCode> {
+        let promotion_code = promotion_code.as_ref();
         let url = format!(
             "/v1/promotion_codes/{}",
             crate::progenitor_support::encode_path(promotion_code),
@@ -179,7 +179,11 @@ impl PromotionCodes {
     *
     * * `promotion_code: &str` -- The account's country.
     */
-    pub async fn post_code(&self, promotion_code: &str) -> Result<crate::types::PromotionCode> {
+    pub async fn post_code(
+        &self,
+        promotion_code: impl AsRef<str>,
+    ) -> Result<crate::types::PromotionCode> {
+        let promotion_code = promotion_code.as_ref();
         let url = format!(
             "/v1/promotion_codes/{}",
             crate::progenitor_support::encode_path(promotion_code),
@@ -36,27 +36,26 @@ impl Quotes {
         status: crate::types::QuoteStatus,
         test_clock: &str,
     ) -> Result<Vec<crate::types::Quote>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !test_clock.is_empty() {
-            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+            query_args.push(("test_clock", test_clock.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/quotes?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/quotes"), &query_args);
 
         let resp: crate::types::GetQuotesResponse = self.client.get(&url, None).await?;
 
@@ -77,18 +76,17 @@ impl Quotes {
         status: crate::types::QuoteStatus,
         test_clock: &str,
     ) -> Result<Vec<crate::types::Quote>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !test_clock.is_empty() {
-            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+            query_args.push(("test_clock", test_clock.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/quotes?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/quotes"), &query_args);
 
         let mut resp: crate::types::GetQuotesResponse = self.client.get(&url, None).await?;
 
@@ -108,17 +106,13 @@ impl Quotes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -149,7 +143,8 @@ impl Quotes {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `quote: &str` -- The account's country.
     */
-    pub async fn get(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn get(&self, quote: impl AsRef<str>) -> Result<crate::types::Quote> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}",
             crate::progenitor_support::encode_path(quote),
@@ -167,7 +162,8 @@ impl Quotes {
     *
     * * `quote: &str` -- The account's country.
     */
-    pub async fn post_quotes(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_quotes(&self, quote: impl AsRef<str>) -> Result<crate::types::Quote> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}",
             crate::progenitor_support::encode_path(quote),
@@ -185,7 +181,8 @@ impl Quotes {
     *
     * * `quote: &str` -- The account's country.
     */
-    pub async fn post_accept(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_accept(&self, quote: impl AsRef<str>) -> Result<crate::types::Quote> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}/accept",
             crate::progenitor_support::encode_path(quote),
@@ -203,7 +200,8 @@ impl Quotes {
     *
     * * `quote: &str` -- The account's country.
     */
-    pub async fn post_cancel(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_cancel(&self, quote: impl AsRef<str>) -> Result<crate::types::Quote> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}/cancel",
             crate::progenitor_support::encode_path(quote),
@@ -229,24 +227,26 @@ impl Quotes {
         &self,
         ending_before: &str,
         limit: i64,
-        quote: &str,
+        quote: impl AsRef<str>,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Item>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let quote = quote.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/quotes/{}/computed_upfront_line_items?{}",
-            crate::progenitor_support::encode_path(quote),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/quotes/{}/computed_upfront_line_items",
+                crate::progenitor_support::encode_path(quote),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::LineItems = self.client.get(&url, None).await?;
@@ -264,8 +264,9 @@ impl Quotes {
     */
     pub async fn get_all_computed_upfront_line_items(
         &self,
-        quote: &str,
+        quote: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Item>> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}/computed_upfront_line_items",
             crate::progenitor_support::encode_path(quote),
@@ -289,17 +290,13 @@ impl Quotes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -319,7 +316,8 @@ impl Quotes {
     *
     * * `quote: &str` -- The account's country.
     */
-    pub async fn post_finalize(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_finalize(&self, quote: impl AsRef<str>) -> Result<crate::types::Quote> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}/finalize",
             crate::progenitor_support::encode_path(quote),
@@ -345,24 +343,26 @@ impl Quotes {
         &self,
         ending_before: &str,
         limit: i64,
-        quote: &str,
+        quote: impl AsRef<str>,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Item>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let quote = quote.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/quotes/{}/line_items?{}",
-            crate::progenitor_support::encode_path(quote),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/quotes/{}/line_items",
+                crate::progenitor_support::encode_path(quote),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::LineItems = self.client.get(&url, None).await?;
@@ -378,7 +378,11 @@ impl Quotes {
     *
     * <p>When retrieving a quote, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
     */
-    pub async fn get_all_line_items(&self, quote: &str) -> Result<Vec<crate::types::Item>> {
+    pub async fn get_all_line_items(
+        &self,
+        quote: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Item>> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}/line_items",
             crate::progenitor_support::encode_path(quote),
@@ -402,17 +406,13 @@ impl Quotes {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -433,7 +433,8 @@ impl Quotes {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `quote: &str` -- The account's country.
     */
-    pub async fn get_pdf(&self, quote: &str) -> Result<()> {
+    pub async fn get_pdf(&self, quote: impl AsRef<str>) -> Result<()> {
+        let quote = quote.as_ref();
         let url = format!(
             "/v1/quotes/{}/pdf",
             crate::progenitor_support::encode_path(quote),
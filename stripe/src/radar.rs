@@ -34,24 +34,26 @@ impl Radar {
         payment_intent: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::RadarEarlyFraudWarning>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/radar/early_fraud_warnings?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/radar/early_fraud_warnings"),
+            &query_args,
+        );
 
         let resp: crate::types::RadarEarlyFraudWarningList = self.client.get(&url, None).await?;
 
@@ -71,15 +73,17 @@ impl Radar {
         charge: &str,
         payment_intent: &str,
     ) -> Result<Vec<crate::types::RadarEarlyFraudWarning>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/radar/early_fraud_warnings?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/radar/early_fraud_warnings"),
+            &query_args,
+        );
 
         let mut resp: crate::types::RadarEarlyFraudWarningList =
             self.client.get(&url, None).await?;
@@ -100,17 +104,13 @@ impl Radar {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -135,8 +135,9 @@ impl Radar {
     */
     pub async fn get_early_fraud_warnings_warning(
         &self,
-        early_fraud_warning: &str,
+        early_fraud_warning: impl AsRef<str>,
     ) -> Result<crate::types::RadarEarlyFraudWarning> {
+        let early_fraud_warning = early_fraud_warning.as_ref();
         let url = format!(
             "/v1/radar/early_fraud_warnings/{}",
             crate::progenitor_support::encode_path(early_fraud_warning),
@@ -169,24 +170,26 @@ impl Radar {
         value: &str,
         value_list: &str,
     ) -> Result<Vec<crate::types::RadarListItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !value.is_empty() {
-            query_args.push(("value".to_string(), value.to_string()));
+            query_args.push(("value", value.to_string()));
         }
         if !value_list.is_empty() {
-            query_args.push(("value_list".to_string(), value_list.to_string()));
+            query_args.push(("value_list", value_list.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/radar/value_list_items?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/radar/value_list_items"),
+            &query_args,
+        );
 
         let resp: crate::types::ListItems = self.client.get(&url, None).await?;
 
@@ -207,15 +210,17 @@ impl Radar {
         value: &str,
         value_list: &str,
     ) -> Result<Vec<crate::types::RadarListItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !value.is_empty() {
-            query_args.push(("value".to_string(), value.to_string()));
+            query_args.push(("value", value.to_string()));
         }
         if !value_list.is_empty() {
-            query_args.push(("value_list".to_string(), value_list.to_string()));
+            query_args.push(("value_list", value_list.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/radar/value_list_items?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/radar/value_list_items"),
+            &query_args,
+        );
 
         let mut resp: crate::types::ListItems = self.client.get(&url, None).await?;
 
@@ -235,17 +240,13 @@ impl Radar {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -278,8 +279,9 @@ impl Radar {
     */
     pub async fn get_value_list_items_item(
         &self,
-        item: &str,
+        item: impl AsRef<str>,
     ) -> Result<crate::types::RadarListItem> {
+        let item = item.as_ref();
         let url = format!(
             "/v1/radar/value_list_items/{}",
             crate::progenitor_support::encode_path(item),
@@ -299,8 +301,9 @@ impl Radar {
     */
     pub async fn delete_value_list_items_item(
         &self,
-        item: &str,
+        item: impl AsRef<str>,
     ) -> Result<crate::types::RadarListDeletedItem> {
+        let item = item.as_ref();
         let url = format!(
             "/v1/radar/value_list_items/{}",
             crate::progenitor_support::encode_path(item),
@@ -333,24 +336,26 @@ impl Radar {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::RadarList>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alias.is_empty() {
-            query_args.push(("alias".to_string(), alias.to_string()));
+            query_args.push(("alias", alias.to_string()));
         }
         if !contains.is_empty() {
-            query_args.push(("contains".to_string(), contains.to_string()));
+            query_args.push(("contains", contains.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/radar/value_lists?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/radar/value_lists"),
+            &query_args,
+        );
 
         let resp: crate::types::GetRadarValueListsResponse = self.client.get(&url, None).await?;
 
@@ -371,15 +376,17 @@ impl Radar {
         contains: &str,
         _created: &str,
     ) -> Result<Vec<crate::types::RadarList>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alias.is_empty() {
-            query_args.push(("alias".to_string(), alias.to_string()));
+            query_args.push(("alias", alias.to_string()));
         }
         if !contains.is_empty() {
-            query_args.push(("contains".to_string(), contains.to_string()));
+            query_args.push(("contains", contains.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/radar/value_lists?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/radar/value_lists"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetRadarValueListsResponse =
             self.client.get(&url, None).await?;
@@ -400,17 +407,13 @@ impl Radar {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -441,7 +444,11 @@ impl Radar {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `value_list: &str` -- The account's country.
     */
-    pub async fn get_value_lists_list(&self, value_list: &str) -> Result<crate::types::RadarList> {
+    pub async fn get_value_lists_list(
+        &self,
+        value_list: impl AsRef<str>,
+    ) -> Result<crate::types::RadarList> {
+        let value_list = value_list.as_ref();
         let url = format!(
             "/v1/radar/value_lists/{}",
             crate::progenitor_support::encode_path(value_list),
@@ -459,7 +466,11 @@ impl Radar {
     *
     * * `value_list: &str` -- The account's country.
     */
-    pub async fn post_value_lists_list(&self, value_list: &str) -> Result<crate::types::RadarList> {
+    pub async fn post_value_lists_list(
+        &self,
+        value_list: impl AsRef<str>,
+    ) -> Result<crate::types::RadarList> {
+        let value_list = value_list.as_ref();
         let url = format!(
             "/v1/radar/value_lists/{}",
             crate::progenitor_support::encode_path(value_list),
@@ -479,8 +490,9 @@ impl Radar {
     */
     pub async fn delete_value_lists_list(
         &self,
-        value_list: &str,
+        value_list: impl AsRef<str>,
     ) -> Result<crate::types::RadarListDeleted> {
+        let value_list = value_list.as_ref();
         let url = format!(
             "/v1/radar/value_lists/{}",
             crate::progenitor_support::encode_path(value_list),
@@ -36,24 +36,23 @@ impl Recipients {
         type_: crate::types::GetRecipientsType,
         verified: bool,
     ) -> Result<Vec<crate::types::Recipient>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if verified {
-            query_args.push(("verified".to_string(), verified.to_string()));
+            query_args.push(("verified", verified.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/recipients?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/recipients"), &query_args);
 
         let resp: crate::types::GetRecipientsResponse = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl Recipients {
         type_: crate::types::GetRecipientsType,
         verified: bool,
     ) -> Result<Vec<crate::types::Recipient>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if verified {
-            query_args.push(("verified".to_string(), verified.to_string()));
+            query_args.push(("verified", verified.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/recipients?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/recipients"), &query_args);
 
         let mut resp: crate::types::GetRecipientsResponse = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl Recipients {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -144,7 +138,11 @@ impl Recipients {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::GetRecipientsResponseAnyOf> {
+    pub async fn get(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::GetRecipientsResponseAnyOf> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/recipients/{}",
             crate::progenitor_support::encode_path(id),
@@ -166,7 +164,8 @@ impl Recipients {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_recipients(&self, id: &str) -> Result<crate::types::Recipient> {
+    pub async fn post_recipients(&self, id: impl AsRef<str>) -> Result<crate::types::Recipient> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/recipients/{}",
             crate::progenitor_support::encode_path(id),
@@ -184,7 +183,8 @@ impl Recipients {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn delete(&self, id: &str) -> Result<crate::types::DeletedRecipient> {
+    pub async fn delete(&self, id: impl AsRef<str>) -> Result<crate::types::DeletedRecipient> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/recipients/{}",
             crate::progenitor_support::encode_path(id),
@@ -36,24 +36,23 @@ impl Refunds {
         payment_intent: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Refund>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/refunds?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/refunds"), &query_args);
 
         let resp: crate::types::RefundList = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl Refunds {
         _created: &str,
         payment_intent: &str,
     ) -> Result<Vec<crate::types::Refund>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !charge.is_empty() {
-            query_args.push(("charge".to_string(), charge.to_string()));
+            query_args.push(("charge", charge.to_string()));
         }
         if !payment_intent.is_empty() {
-            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+            query_args.push(("payment_intent", payment_intent.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/refunds?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/refunds"), &query_args);
 
         let mut resp: crate::types::RefundList = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl Refunds {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -143,7 +137,8 @@ impl Refunds {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `refund: &str` -- The account's country.
     */
-    pub async fn get(&self, refund: &str) -> Result<crate::types::Refund> {
+    pub async fn get(&self, refund: impl AsRef<str>) -> Result<crate::types::Refund> {
+        let refund = refund.as_ref();
         let url = format!(
             "/v1/refunds/{}",
             crate::progenitor_support::encode_path(refund),
@@ -163,7 +158,8 @@ impl Refunds {
     *
     * * `refund: &str` -- The account's country.
     */
-    pub async fn post_refunds(&self, refund: &str) -> Result<crate::types::Refund> {
+    pub async fn post_refunds(&self, refund: impl AsRef<str>) -> Result<crate::types::Refund> {
+        let refund = refund.as_ref();
         let url = format!(
             "/v1/refunds/{}",
             crate::progenitor_support::encode_path(refund),
@@ -183,7 +179,8 @@ impl Refunds {
     *
     * * `refund: &str` -- The account's country.
     */
-    pub async fn post_cancel(&self, refund: &str) -> Result<crate::types::Refund> {
+    pub async fn post_cancel(&self, refund: impl AsRef<str>) -> Result<crate::types::Refund> {
+        let refund = refund.as_ref();
         let url = format!(
             "/v1/refunds/{}/cancel",
             crate::progenitor_support::encode_path(refund),
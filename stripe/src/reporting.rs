@@ -32,18 +32,20 @@ impl Reporting {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::ReportingReportRun>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/reporting/report_runs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/reporting/report_runs"),
+            &query_args,
+        );
 
         let resp: crate::types::GetReportingReportRunsResponse =
             self.client.get(&url, None).await?;
@@ -83,17 +85,13 @@ impl Reporting {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -126,8 +124,9 @@ impl Reporting {
     */
     pub async fn get_report_runs_run(
         &self,
-        report_run: &str,
+        report_run: impl AsRef<str>,
     ) -> Result<crate::types::ReportingReportRun> {
+        let report_run = report_run.as_ref();
         let url = format!(
             "/v1/reporting/report_runs/{}",
             crate::progenitor_support::encode_path(report_run),
@@ -182,17 +181,13 @@ impl Reporting {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -215,8 +210,9 @@ impl Reporting {
     */
     pub async fn get_report_types_type(
         &self,
-        report_type: &str,
+        report_type: impl AsRef<str>,
     ) -> Result<crate::types::ReportingReportType> {
+        let report_type = report_type.as_ref();
         let url = format!(
             "/v1/reporting/report_types/{}",
             crate::progenitor_support::encode_path(report_type),
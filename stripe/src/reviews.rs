@@ -32,18 +32,17 @@ impl Reviews {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Review>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/reviews?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/reviews"), &query_args);
 
         let resp: crate::types::GetReviewsResponse = self.client.get(&url, None).await?;
 
@@ -78,17 +77,13 @@ impl Reviews {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -109,7 +104,8 @@ impl Reviews {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `review: &str` -- The account's country.
     */
-    pub async fn get(&self, review: &str) -> Result<crate::types::Review> {
+    pub async fn get(&self, review: impl AsRef<str>) -> Result<crate::types::Review> {
+        let review = review.as_ref();
         let url = format!(
             "/v1/reviews/{}",
             crate::progenitor_support::encode_path(review),
@@ -127,7 +123,8 @@ impl Reviews {
     *
     * * `review: &str` -- The account's country.
     */
-    pub async fn post_approve(&self, review: &str) -> Result<crate::types::Review> {
+    pub async fn post_approve(&self, review: impl AsRef<str>) -> Result<crate::types::Review> {
+        let review = review.as_ref();
         let url = format!(
             "/v1/reviews/{}/approve",
             crate::progenitor_support::encode_path(review),
@@ -37,21 +37,21 @@ impl SetupAttempts {
         setup_intent: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::SetupAttempt>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !setup_intent.is_empty() {
-            query_args.push(("setup_intent".to_string(), setup_intent.to_string()));
+            query_args.push(("setup_intent", setup_intent.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/setup_attempts?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/setup_attempts"), &query_args);
 
         let resp: crate::types::PaymentFlowsSetupIntentAttemptList =
             self.client.get(&url, None).await?;
@@ -72,12 +72,12 @@ impl SetupAttempts {
         _created: &str,
         setup_intent: &str,
     ) -> Result<Vec<crate::types::SetupAttempt>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setup_intent.is_empty() {
-            query_args.push(("setup_intent".to_string(), setup_intent.to_string()));
+            query_args.push(("setup_intent", setup_intent.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/setup_attempts?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/setup_attempts"), &query_args);
 
         let mut resp: crate::types::PaymentFlowsSetupIntentAttemptList =
             self.client.get(&url, None).await?;
@@ -98,17 +98,13 @@ impl SetupAttempts {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -36,24 +36,24 @@ impl SetupIntents {
         payment_method: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::SetupIntent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !payment_method.is_empty() {
-            query_args.push(("payment_method".to_string(), payment_method.to_string()));
+            query_args.push(("payment_method", payment_method.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/setup_intents?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/setup_intents"), &query_args);
 
         let resp: crate::types::PaymentFlowsSetupIntentList = self.client.get(&url, None).await?;
 
@@ -74,15 +74,15 @@ impl SetupIntents {
         customer: &str,
         payment_method: &str,
     ) -> Result<Vec<crate::types::SetupIntent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !payment_method.is_empty() {
-            query_args.push(("payment_method".to_string(), payment_method.to_string()));
+            query_args.push(("payment_method", payment_method.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/setup_intents?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/setup_intents"), &query_args);
 
         let mut resp: crate::types::PaymentFlowsSetupIntentList =
             self.client.get(&url, None).await?;
@@ -103,17 +103,13 @@ impl SetupIntents {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -155,17 +151,19 @@ impl SetupIntents {
     pub async fn get_intent(
         &self,
         client_secret: &str,
-        intent: &str,
+        intent: impl AsRef<str>,
     ) -> Result<crate::types::SetupIntent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let intent = intent.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/setup_intents/{}?{}",
-            crate::progenitor_support::encode_path(intent),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/setup_intents/{}",
+                crate::progenitor_support::encode_path(intent),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -180,7 +178,8 @@ impl SetupIntents {
     *
     * * `intent: &str` -- The account's country.
     */
-    pub async fn post_intent(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent(&self, intent: impl AsRef<str>) -> Result<crate::types::SetupIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/setup_intents/{}",
             crate::progenitor_support::encode_path(intent),
@@ -200,7 +199,11 @@ impl SetupIntents {
     *
     * * `intent: &str` -- The account's country.
     */
-    pub async fn post_intent_cancel(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent_cancel(
+        &self,
+        intent: impl AsRef<str>,
+    ) -> Result<crate::types::SetupIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/setup_intents/{}/cancel",
             crate::progenitor_support::encode_path(intent),
@@ -230,7 +233,11 @@ impl SetupIntents {
     *
     * * `intent: &str` -- The account's country.
     */
-    pub async fn post_intent_confirm(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent_confirm(
+        &self,
+        intent: impl AsRef<str>,
+    ) -> Result<crate::types::SetupIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/setup_intents/{}/confirm",
             crate::progenitor_support::encode_path(intent),
@@ -250,8 +257,9 @@ impl SetupIntents {
     */
     pub async fn post_intent_verify_microdeposit(
         &self,
-        intent: &str,
+        intent: impl AsRef<str>,
     ) -> Result<crate::types::SetupIntent> {
+        let intent = intent.as_ref();
         let url = format!(
             "/v1/setup_intents/{}/verify_microdeposits",
             crate::progenitor_support::encode_path(intent),
@@ -36,24 +36,24 @@ impl ShippingRates {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::ShippingRate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/shipping_rates?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/shipping_rates"), &query_args);
 
         let resp: crate::types::ShippingResourcesRateList = self.client.get(&url, None).await?;
 
@@ -74,15 +74,15 @@ impl ShippingRates {
         _created: &str,
         currency: &str,
     ) -> Result<Vec<crate::types::ShippingRate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/shipping_rates?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/shipping_rates"), &query_args);
 
         let mut resp: crate::types::ShippingResourcesRateList = self.client.get(&url, None).await?;
 
@@ -102,17 +102,13 @@ impl ShippingRates {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -145,8 +141,9 @@ impl ShippingRates {
     */
     pub async fn get_rate_token(
         &self,
-        shipping_rate_token: &str,
+        shipping_rate_token: impl AsRef<str>,
     ) -> Result<crate::types::ShippingRate> {
+        let shipping_rate_token = shipping_rate_token.as_ref();
         let url = format!(
             "/v1/shipping_rates/{}",
             crate::progenitor_support::encode_path(shipping_rate_token),
@@ -166,8 +163,9 @@ impl ShippingRates {
     */
     pub async fn post_rate_token(
         &self,
-        shipping_rate_token: &str,
+        shipping_rate_token: impl AsRef<str>,
     ) -> Result<crate::types::ShippingRate> {
+        let shipping_rate_token = shipping_rate_token.as_ref();
         let url = format!(
             "/v1/shipping_rates/{}",
             crate::progenitor_support::encode_path(shipping_rate_token),
@@ -30,18 +30,20 @@ impl Sigma {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::ScheduledQueryRun>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/sigma/scheduled_query_runs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/sigma/scheduled_query_runs"),
+            &query_args,
+        );
 
         let resp: crate::types::GetSigmaScheduledQueryRunsResponse =
             self.client.get(&url, None).await?;
@@ -80,17 +82,13 @@ impl Sigma {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -113,8 +111,9 @@ impl Sigma {
     */
     pub async fn get_scheduled_query_runs_run(
         &self,
-        scheduled_query_run: &str,
+        scheduled_query_run: impl AsRef<str>,
     ) -> Result<crate::types::ScheduledQueryRun> {
+        let scheduled_query_run = scheduled_query_run.as_ref();
         let url = format!(
             "/v1/sigma/scheduled_query_runs/{}",
             crate::progenitor_support::encode_path(scheduled_query_run),
@@ -40,27 +40,26 @@ impl Skus {
         product: &str,
         starting_after: &str,
     ) -> Result<Vec<crate::types::Sku>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if in_stock {
-            query_args.push(("in_stock".to_string(), in_stock.to_string()));
+            query_args.push(("in_stock", in_stock.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product.is_empty() {
-            query_args.push(("product".to_string(), product.to_string()));
+            query_args.push(("product", product.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/skus?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/skus"), &query_args);
 
         let resp: crate::types::GetSkusResponse = self.client.get(&url, None).await?;
 
@@ -83,18 +82,17 @@ impl Skus {
         in_stock: bool,
         product: &str,
     ) -> Result<Vec<crate::types::Sku>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if in_stock {
-            query_args.push(("in_stock".to_string(), in_stock.to_string()));
+            query_args.push(("in_stock", in_stock.to_string()));
         }
         if !product.is_empty() {
-            query_args.push(("product".to_string(), product.to_string()));
+            query_args.push(("product", product.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/skus?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/skus"), &query_args);
 
         let mut resp: crate::types::GetSkusResponse = self.client.get(&url, None).await?;
 
@@ -114,17 +112,13 @@ impl Skus {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -155,11 +149,9 @@ impl Skus {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `id: &str` -- The account's country.
     */
-    pub async fn get(&self, id: &str) -> Result<crate::types::GetSkusResponseAnyOf> {
-        let url = format!(
-            "/v1/skus/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<crate::types::GetSkusResponseAnyOf> {
+        let id = id.as_ref();
+        let url = format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
     }
@@ -175,11 +167,9 @@ impl Skus {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_skus(&self, id: &str) -> Result<crate::types::Sku> {
-        let url = format!(
-            "/v1/skus/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn post_skus(&self, id: impl AsRef<str>) -> Result<crate::types::Sku> {
+        let id = id.as_ref();
+        let url = format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.post(&url, None).await
     }
@@ -193,11 +183,9 @@ impl Skus {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn delete(&self, id: &str) -> Result<crate::types::DeletedSku> {
-        let url = format!(
-            "/v1/skus/{}",
-            crate::progenitor_support::encode_path(id),
-        );
+    pub async fn delete(&self, id: impl AsRef<str>) -> Result<crate::types::DeletedSku> {
+        let id = id.as_ref();
+        let url = format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.delete(&url, None).await
     }
@@ -33,16 +33,22 @@ impl Sources {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `source: &str` -- The account's country.
     */
-    pub async fn get(&self, client_secret: &str, source: &str) -> Result<crate::types::SourceData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn get(
+        &self,
+        client_secret: &str,
+        source: impl AsRef<str>,
+    ) -> Result<crate::types::SourceData> {
+        let source = source.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/sources/{}?{}",
-            crate::progenitor_support::encode_path(source),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/sources/{}",
+                crate::progenitor_support::encode_path(source),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -59,7 +65,8 @@ impl Sources {
     *
     * * `source: &str` -- The account's country.
     */
-    pub async fn post_sources(&self, source: &str) -> Result<crate::types::SourceData> {
+    pub async fn post_sources(&self, source: impl AsRef<str>) -> Result<crate::types::SourceData> {
+        let source = source.as_ref();
         let url = format!(
             "/v1/sources/{}",
             crate::progenitor_support::encode_path(source),
@@ -81,9 +88,11 @@ impl Sources {
     */
     pub async fn get_mandate_notifications_notification(
         &self,
-        mandate_notification: &str,
-        source: &str,
+        mandate_notification: impl AsRef<str>,
+        source: impl AsRef<str>,
     ) -> Result<crate::types::SourceMandateNotification> {
+        let mandate_notification = mandate_notification.as_ref();
+        let source = source.as_ref();
         let url = format!(
             "/v1/sources/{}/mandate_notifications/{}",
             crate::progenitor_support::encode_path(source),
@@ -110,24 +119,26 @@ impl Sources {
         &self,
         ending_before: &str,
         limit: i64,
-        source: &str,
+        source: impl AsRef<str>,
         starting_after: &str,
     ) -> Result<Vec<crate::types::SourceTransaction>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let source = source.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/sources/{}/source_transactions?{}",
-            crate::progenitor_support::encode_path(source),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/sources/{}/source_transactions",
+                crate::progenitor_support::encode_path(source),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ApmsSourcesSourceTransactionList =
@@ -146,8 +157,9 @@ impl Sources {
     */
     pub async fn get_all_transactions(
         &self,
-        source: &str,
+        source: impl AsRef<str>,
     ) -> Result<Vec<crate::types::SourceTransaction>> {
+        let source = source.as_ref();
         let url = format!(
             "/v1/sources/{}/source_transactions",
             crate::progenitor_support::encode_path(source),
@@ -172,17 +184,13 @@ impl Sources {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -206,9 +214,11 @@ impl Sources {
     */
     pub async fn get_transactions_transaction(
         &self,
-        source: &str,
-        source_transaction: &str,
+        source: impl AsRef<str>,
+        source_transaction: impl AsRef<str>,
     ) -> Result<crate::types::SourceTransaction> {
+        let source = source.as_ref();
+        let source_transaction = source_transaction.as_ref();
         let url = format!(
             "/v1/sources/{}/source_transactions/{}",
             crate::progenitor_support::encode_path(source),
@@ -227,7 +237,8 @@ impl Sources {
     *
     * * `source: &str` -- The account's country.
     */
-    pub async fn post_verify(&self, source: &str) -> Result<crate::types::SourceData> {
+    pub async fn post_verify(&self, source: impl AsRef<str>) -> Result<crate::types::SourceData> {
+        let source = source.as_ref();
         let url = format!(
             "/v1/sources/{}/verify",
             crate::progenitor_support::encode_path(source),
@@ -32,21 +32,23 @@ impl SubscriptionItems {
         starting_after: &str,
         subscription: &str,
     ) -> Result<Vec<crate::types::SubscriptionItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscription_items?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/subscription_items"),
+            &query_args,
+        );
 
         let resp: crate::types::Items = self.client.get(&url, None).await?;
 
@@ -62,12 +64,14 @@ impl SubscriptionItems {
     * <p>Returns a list of your subscription items for a given subscription.</p>
     */
     pub async fn get_all(&self, subscription: &str) -> Result<Vec<crate::types::SubscriptionItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !subscription.is_empty() {
-            query_args.push(("subscription".to_string(), subscription.to_string()));
+            query_args.push(("subscription", subscription.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscription_items?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/subscription_items"),
+            &query_args,
+        );
 
         let mut resp: crate::types::Items = self.client.get(&url, None).await?;
 
@@ -87,17 +91,13 @@ impl SubscriptionItems {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -128,7 +128,8 @@ impl SubscriptionItems {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `item: &str` -- The account's country.
     */
-    pub async fn get_item(&self, item: &str) -> Result<crate::types::SubscriptionItem> {
+    pub async fn get_item(&self, item: impl AsRef<str>) -> Result<crate::types::SubscriptionItem> {
+        let item = item.as_ref();
         let url = format!(
             "/v1/subscription_items/{}",
             crate::progenitor_support::encode_path(item),
@@ -146,7 +147,8 @@ impl SubscriptionItems {
     *
     * * `item: &str` -- The account's country.
     */
-    pub async fn post_item(&self, item: &str) -> Result<crate::types::SubscriptionItem> {
+    pub async fn post_item(&self, item: impl AsRef<str>) -> Result<crate::types::SubscriptionItem> {
+        let item = item.as_ref();
         let url = format!(
             "/v1/subscription_items/{}",
             crate::progenitor_support::encode_path(item),
@@ -164,7 +166,11 @@ impl SubscriptionItems {
     *
     * * `item: &str` -- The account's country.
     */
-    pub async fn delete_item(&self, item: &str) -> Result<crate::types::DeletedSubscriptionItem> {
+    pub async fn delete_item(
+        &self,
+        item: impl AsRef<str>,
+    ) -> Result<crate::types::DeletedSubscriptionItem> {
+        let item = item.as_ref();
         let url = format!(
             "/v1/subscription_items/{}",
             crate::progenitor_support::encode_path(item),
@@ -193,23 +199,25 @@ impl SubscriptionItems {
         ending_before: &str,
         limit: i64,
         starting_after: &str,
-        subscription_item: &str,
+        subscription_item: impl AsRef<str>,
     ) -> Result<Vec<crate::types::UsageRecordSummary>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let subscription_item = subscription_item.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/subscription_items/{}/usage_record_summaries?{}",
-            crate::progenitor_support::encode_path(subscription_item),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/subscription_items/{}/usage_record_summaries",
+                crate::progenitor_support::encode_path(subscription_item),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse =
@@ -230,8 +238,9 @@ impl SubscriptionItems {
     */
     pub async fn get_all_item_usage_record_summaries(
         &self,
-        subscription_item: &str,
+        subscription_item: impl AsRef<str>,
     ) -> Result<Vec<crate::types::UsageRecordSummary>> {
+        let subscription_item = subscription_item.as_ref();
         let url = format!(
             "/v1/subscription_items/{}/usage_record_summaries",
             crate::progenitor_support::encode_path(subscription_item),
@@ -256,17 +265,13 @@ impl SubscriptionItems {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -294,8 +299,9 @@ impl SubscriptionItems {
     */
     pub async fn post_item_usage_record(
         &self,
-        subscription_item: &str,
+        subscription_item: impl AsRef<str>,
     ) -> Result<crate::types::UsageRecord> {
+        let subscription_item = subscription_item.as_ref();
         let url = format!(
             "/v1/subscription_items/{}/usage_records",
             crate::progenitor_support::encode_path(subscription_item),
@@ -42,24 +42,26 @@ impl SubscriptionSchedules {
         scheduled: bool,
         starting_after: &str,
     ) -> Result<Vec<crate::types::SubscriptionSchedule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if scheduled {
-            query_args.push(("scheduled".to_string(), scheduled.to_string()));
+            query_args.push(("scheduled", scheduled.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscription_schedules?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/subscription_schedules"),
+            &query_args,
+        );
 
         let resp: crate::types::GetSubscriptionSchedulesResponse =
             self.client.get(&url, None).await?;
@@ -84,15 +86,17 @@ impl SubscriptionSchedules {
         _released_at: &str,
         scheduled: bool,
     ) -> Result<Vec<crate::types::SubscriptionSchedule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if scheduled {
-            query_args.push(("scheduled".to_string(), scheduled.to_string()));
+            query_args.push(("scheduled", scheduled.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscription_schedules?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/subscription_schedules"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetSubscriptionSchedulesResponse =
             self.client.get(&url, None).await?;
@@ -113,17 +117,13 @@ impl SubscriptionSchedules {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -154,7 +154,11 @@ impl SubscriptionSchedules {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `schedule: &str` -- The account's country.
     */
-    pub async fn get_schedule(&self, schedule: &str) -> Result<crate::types::SubscriptionSchedule> {
+    pub async fn get_schedule(
+        &self,
+        schedule: impl AsRef<str>,
+    ) -> Result<crate::types::SubscriptionSchedule> {
+        let schedule = schedule.as_ref();
         let url = format!(
             "/v1/subscription_schedules/{}",
             crate::progenitor_support::encode_path(schedule),
@@ -174,8 +178,9 @@ impl SubscriptionSchedules {
     */
     pub async fn post_schedule(
         &self,
-        schedule: &str,
+        schedule: impl AsRef<str>,
     ) -> Result<crate::types::SubscriptionSchedule> {
+        let schedule = schedule.as_ref();
         let url = format!(
             "/v1/subscription_schedules/{}",
             crate::progenitor_support::encode_path(schedule),
@@ -195,8 +200,9 @@ impl SubscriptionSchedules {
     */
     pub async fn post_schedule_cancel(
         &self,
-        schedule: &str,
+        schedule: impl AsRef<str>,
     ) -> Result<crate::types::SubscriptionSchedule> {
+        let schedule = schedule.as_ref();
         let url = format!(
             "/v1/subscription_schedules/{}/cancel",
             crate::progenitor_support::encode_path(schedule),
@@ -216,8 +222,9 @@ impl SubscriptionSchedules {
     */
     pub async fn post_schedule_release(
         &self,
-        schedule: &str,
+        schedule: impl AsRef<str>,
     ) -> Result<crate::types::SubscriptionSchedule> {
+        let schedule = schedule.as_ref();
         let url = format!(
             "/v1/subscription_schedules/{}/release",
             crate::progenitor_support::encode_path(schedule),
@@ -46,36 +46,33 @@ impl Subscriptions {
         status: crate::types::GetSubscriptionsStatus,
         test_clock: &str,
     ) -> Result<Vec<crate::types::Subscription>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
-            query_args.push((
-                "collection_method".to_string(),
-                collection_method.to_string(),
-            ));
+            query_args.push(("collection_method", collection_method.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !price.is_empty() {
-            query_args.push(("price".to_string(), price.to_string()));
+            query_args.push(("price", price.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !test_clock.is_empty() {
-            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+            query_args.push(("test_clock", test_clock.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscriptions?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/subscriptions"), &query_args);
 
         let resp: crate::types::Subscriptions = self.client.get(&url, None).await?;
 
@@ -101,27 +98,24 @@ impl Subscriptions {
         status: crate::types::GetSubscriptionsStatus,
         test_clock: &str,
     ) -> Result<Vec<crate::types::Subscription>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
-            query_args.push((
-                "collection_method".to_string(),
-                collection_method.to_string(),
-            ));
+            query_args.push(("collection_method", collection_method.to_string()));
         }
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !price.is_empty() {
-            query_args.push(("price".to_string(), price.to_string()));
+            query_args.push(("price", price.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !test_clock.is_empty() {
-            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+            query_args.push(("test_clock", test_clock.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscriptions?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/subscriptions"), &query_args);
 
         let mut resp: crate::types::Subscriptions = self.client.get(&url, None).await?;
 
@@ -141,17 +135,13 @@ impl Subscriptions {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -199,18 +189,20 @@ impl Subscriptions {
         page: &str,
         query: &str,
     ) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscriptions/search?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/subscriptions/search"),
+            &query_args,
+        );
 
         let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -229,12 +221,14 @@ impl Subscriptions {
     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
     */
     pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/subscriptions/search?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/subscriptions/search"),
+            &query_args,
+        );
 
         let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
 
@@ -254,17 +248,13 @@ impl Subscriptions {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -287,8 +277,9 @@ impl Subscriptions {
     */
     pub async fn get_exposed(
         &self,
-        subscription_exposed_id: &str,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::Subscription> {
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/subscriptions/{}",
             crate::progenitor_support::encode_path(subscription_exposed_id),
@@ -308,8 +299,9 @@ impl Subscriptions {
     */
     pub async fn post_exposed(
         &self,
-        subscription_exposed_id: &str,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::Subscription> {
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/subscriptions/{}",
             crate::progenitor_support::encode_path(subscription_exposed_id),
@@ -333,8 +325,9 @@ impl Subscriptions {
     */
     pub async fn delete_exposed(
         &self,
-        subscription_exposed_id: &str,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::Subscription> {
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/subscriptions/{}",
             crate::progenitor_support::encode_path(subscription_exposed_id),
@@ -354,8 +347,9 @@ impl Subscriptions {
     */
     pub async fn delete_exposed_discount(
         &self,
-        subscription_exposed_id: &str,
+        subscription_exposed_id: impl AsRef<str>,
     ) -> Result<crate::types::DeletedDiscount> {
+        let subscription_exposed_id = subscription_exposed_id.as_ref();
         let url = format!(
             "/v1/subscriptions/{}/discount",
             crate::progenitor_support::encode_path(subscription_exposed_id),
@@ -36,24 +36,23 @@ impl TaxRates {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::TaxRate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if inclusive {
-            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+            query_args.push(("inclusive", inclusive.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/tax_rates?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/tax_rates"), &query_args);
 
         let resp: crate::types::GetTaxRatesResponse = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl TaxRates {
         _created: &str,
         inclusive: bool,
     ) -> Result<Vec<crate::types::TaxRate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if active {
-            query_args.push(("active".to_string(), active.to_string()));
+            query_args.push(("active", active.to_string()));
         }
         if inclusive {
-            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+            query_args.push(("inclusive", inclusive.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/tax_rates?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/tax_rates"), &query_args);
 
         let mut resp: crate::types::GetTaxRatesResponse = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl TaxRates {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -143,7 +137,8 @@ impl TaxRates {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `tax_rate: &str` -- The account's country.
     */
-    pub async fn get_rate(&self, tax_rate: &str) -> Result<crate::types::TaxRate> {
+    pub async fn get_rate(&self, tax_rate: impl AsRef<str>) -> Result<crate::types::TaxRate> {
+        let tax_rate = tax_rate.as_ref();
         let url = format!(
             "/v1/tax_rates/{}",
             crate::progenitor_support::encode_path(tax_rate),
@@ -161,7 +156,8 @@ impl TaxRates {
     *
     * * `tax_rate: &str` -- The account's country.
     */
-    pub async fn post_rate(&self, tax_rate: &str) -> Result<crate::types::TaxRate> {
+    pub async fn post_rate(&self, tax_rate: impl AsRef<str>) -> Result<crate::types::TaxRate> {
+        let tax_rate = tax_rate.as_ref();
         let url = format!(
             "/v1/tax_rates/{}",
             crate::progenitor_support::encode_path(tax_rate),
@@ -40,18 +40,20 @@ impl Terminal {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::TerminalLocation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/terminal/locations?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/terminal/locations"),
+            &query_args,
+        );
 
         let resp: crate::types::TerminalLocationList = self.client.get(&url, None).await?;
 
@@ -86,17 +88,13 @@ impl Terminal {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -130,8 +128,9 @@ impl Terminal {
     */
     pub async fn get_locations_location(
         &self,
-        location: &str,
+        location: impl AsRef<str>,
     ) -> Result<crate::types::GetTerminalLocationResponseAnyOf> {
+        let location = location.as_ref();
         let url = format!(
             "/v1/terminal/locations/{}",
             crate::progenitor_support::encode_path(location),
@@ -151,8 +150,9 @@ impl Terminal {
     */
     pub async fn post_locations_location(
         &self,
-        location: &str,
+        location: impl AsRef<str>,
     ) -> Result<crate::types::GetTerminalLocationResponseAnyOf> {
+        let location = location.as_ref();
         let url = format!(
             "/v1/terminal/locations/{}",
             crate::progenitor_support::encode_path(location),
@@ -172,8 +172,9 @@ impl Terminal {
     */
     pub async fn delete_locations_location(
         &self,
-        location: &str,
+        location: impl AsRef<str>,
     ) -> Result<crate::types::DeletedTerminalLocation> {
+        let location = location.as_ref();
         let url = format!(
             "/v1/terminal/locations/{}",
             crate::progenitor_support::encode_path(location),
@@ -206,27 +207,27 @@ impl Terminal {
         starting_after: &str,
         status: crate::types::CustomerAcceptanceType,
     ) -> Result<Vec<crate::types::TerminalReader>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !device_type.to_string().is_empty() {
-            query_args.push(("device_type".to_string(), device_type.to_string()));
+            query_args.push(("device_type", device_type.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location.is_empty() {
-            query_args.push(("location".to_string(), location.to_string()));
+            query_args.push(("location", location.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/terminal/readers?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/terminal/readers"), &query_args);
 
         let resp: crate::types::TerminalReaderRetrieve = self.client.get(&url, None).await?;
 
@@ -247,18 +248,18 @@ impl Terminal {
         location: &str,
         status: crate::types::CustomerAcceptanceType,
     ) -> Result<Vec<crate::types::TerminalReader>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !device_type.to_string().is_empty() {
-            query_args.push(("device_type".to_string(), device_type.to_string()));
+            query_args.push(("device_type", device_type.to_string()));
         }
         if !location.is_empty() {
-            query_args.push(("location".to_string(), location.to_string()));
+            query_args.push(("location", location.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/terminal/readers?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/v1/terminal/readers"), &query_args);
 
         let mut resp: crate::types::TerminalReaderRetrieve = self.client.get(&url, None).await?;
 
@@ -278,17 +279,13 @@ impl Terminal {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -321,8 +318,9 @@ impl Terminal {
     */
     pub async fn get_readers_reader(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::GetTerminalReadersReaderResponseAnyOf> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/terminal/readers/{}",
             crate::progenitor_support::encode_path(reader),
@@ -342,8 +340,9 @@ impl Terminal {
     */
     pub async fn post_readers_reader(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::GetTerminalReadersReaderResponseAnyOf> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/terminal/readers/{}",
             crate::progenitor_support::encode_path(reader),
@@ -363,8 +362,9 @@ impl Terminal {
     */
     pub async fn delete_readers_reader(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::DeletedTerminalReader> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/terminal/readers/{}",
             crate::progenitor_support::encode_path(reader),
@@ -384,8 +384,9 @@ impl Terminal {
     */
     pub async fn post_readers_reader_cancel_action(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::TerminalReader> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/terminal/readers/{}/cancel_action",
             crate::progenitor_support::encode_path(reader),
@@ -405,8 +406,9 @@ impl Terminal {
     */
     pub async fn post_readers_reader_process_payment_intent(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::TerminalReader> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/terminal/readers/{}/process_payment_intent",
             crate::progenitor_support::encode_path(reader),
@@ -426,8 +428,9 @@ impl Terminal {
     */
     pub async fn post_readers_reader_process_setup_intent(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::TerminalReader> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/terminal/readers/{}/process_setup_intent",
             crate::progenitor_support::encode_path(reader),
@@ -447,8 +450,9 @@ impl Terminal {
     */
     pub async fn post_readers_reader_set_display(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::TerminalReader> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/terminal/readers/{}/set_reader_display",
             crate::progenitor_support::encode_path(reader),
@@ -23,8 +23,9 @@ impl TestHelpers {
     */
     pub async fn post_terminal_readers_reader_present_payment_method(
         &self,
-        reader: &str,
+        reader: impl AsRef<str>,
     ) -> Result<crate::types::TerminalReader> {
+        let reader = reader.as_ref();
         let url = format!(
             "/v1/test_helpers/terminal/readers/{}/present_payment_method",
             crate::progenitor_support::encode_path(reader),
@@ -51,18 +52,20 @@ impl TestHelpers {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::TestClock>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/test_helpers/test_clocks?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/test_helpers/test_clocks"),
+            &query_args,
+        );
 
         let resp: crate::types::GetTestHelpersClocksResponse = self.client.get(&url, None).await?;
 
@@ -98,17 +101,13 @@ impl TestHelpers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -139,7 +138,11 @@ impl TestHelpers {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `test_clock: &str` -- The account's country.
     */
-    pub async fn get_clocks_clock(&self, test_clock: &str) -> Result<crate::types::TestClock> {
+    pub async fn get_clocks_clock(
+        &self,
+        test_clock: impl AsRef<str>,
+    ) -> Result<crate::types::TestClock> {
+        let test_clock = test_clock.as_ref();
         let url = format!(
             "/v1/test_helpers/test_clocks/{}",
             crate::progenitor_support::encode_path(test_clock),
@@ -159,8 +162,9 @@ impl TestHelpers {
     */
     pub async fn delete_clocks_clock(
         &self,
-        test_clock: &str,
+        test_clock: impl AsRef<str>,
     ) -> Result<crate::types::DeletedTestClock> {
+        let test_clock = test_clock.as_ref();
         let url = format!(
             "/v1/test_helpers/test_clocks/{}",
             crate::progenitor_support::encode_path(test_clock),
@@ -180,8 +184,9 @@ impl TestHelpers {
     */
     pub async fn post_clocks_clock_advance(
         &self,
-        test_clock: &str,
+        test_clock: impl AsRef<str>,
     ) -> Result<crate::types::TestClock> {
+        let test_clock = test_clock.as_ref();
         let url = format!(
             "/v1/test_helpers/test_clocks/{}/advance",
             crate::progenitor_support::encode_path(test_clock),
@@ -1 +1,69 @@
+use hmac::{Hmac, Mac};
+use reqwest::header::{HeaderMap, HeaderValue};
+use sha2::Sha256;
 
+use crate::webhook_verify::{verify_webhook, Scheme, WebhookVerifier};
+
+fn sign(secret: &[u8], payload: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+    mac.update(payload.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+#[test]
+fn test_verify_webhook() {
+    let secret = b"whsec_test";
+    let body = r#"{"id": "evt_1", "type": "charge.succeeded"}"#;
+
+    let t = now();
+    let mut headers = HeaderMap::new();
+    let header = format!(
+        "t={},v1={},v1={}",
+        t,
+        sign(b"whsec_old", &format!("{}.{}", t, body)),
+        sign(secret, &format!("{}.{}", t, body))
+    );
+    headers.insert("Stripe-Signature", HeaderValue::from_str(&header).unwrap());
+    verify_webhook(secret, &headers, body.as_bytes()).unwrap();
+    assert!(verify_webhook(b"whsec_other", &headers, body.as_bytes()).is_err());
+    assert!(verify_webhook(secret, &headers, b"{}").is_err());
+
+    // Replayed deliveries are rejected once they are too old.
+    let t = now() - 10 * 60;
+    let header = format!("t={},v1={}", t, sign(secret, &format!("{}.{}", t, body)));
+    headers.insert("Stripe-Signature", HeaderValue::from_str(&header).unwrap());
+    let err = verify_webhook(secret, &headers, body.as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("tolerance"));
+    WebhookVerifier::new(secret)
+        .tolerance(std::time::Duration::from_secs(15 * 60))
+        .verify(&headers, body.as_bytes())
+        .unwrap();
+
+    let t = now().to_string();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "X-Slack-Request-Timestamp",
+        HeaderValue::from_str(&t).unwrap(),
+    );
+    let signature = format!("v0={}", sign(secret, &format!("v0:{}:{}", t, body)));
+    headers.insert(
+        "X-Slack-Signature",
+        HeaderValue::from_str(&signature).unwrap(),
+    );
+    let slack = WebhookVerifier::with_scheme(Scheme::Slack, secret);
+    slack.verify(&headers, body.as_bytes()).unwrap();
+    assert!(slack.verify(&headers, b"{}").is_err());
+
+    let mut headers = HeaderMap::new();
+    headers.insert("Authorization", HeaderValue::from_static("hook-secret"));
+    let okta = WebhookVerifier::with_scheme(Scheme::SharedSecret("Authorization"), b"hook-secret");
+    okta.verify(&headers, body.as_bytes()).unwrap();
+    assert!(okta.verify(&HeaderMap::new(), body.as_bytes()).is_err());
+}
@@ -34,8 +34,9 @@ impl ThreeDSecure {
     */
     pub async fn get_3d_secure_three_d(
         &self,
-        three_d_secure: &str,
+        three_d_secure: impl AsRef<str>,
     ) -> Result<crate::types::ThreeDSecure> {
+        let three_d_secure = three_d_secure.as_ref();
         let url = format!(
             "/v1/3d_secure/{}",
             crate::progenitor_support::encode_path(three_d_secure),
@@ -33,7 +33,8 @@ impl Tokens {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `token: &str` -- The account's country.
     */
-    pub async fn get(&self, token: &str) -> Result<crate::types::Token> {
+    pub async fn get(&self, token: impl AsRef<str>) -> Result<crate::types::Token> {
+        let token = token.as_ref();
         let url = format!(
             "/v1/tokens/{}",
             crate::progenitor_support::encode_path(token),
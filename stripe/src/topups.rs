@@ -36,21 +36,20 @@ impl Topups {
         starting_after: &str,
         status: crate::types::GetTopupsStatus,
     ) -> Result<Vec<crate::types::Topup>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/topups?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/topups"), &query_args);
 
         let resp: crate::types::TopupList = self.client.get(&url, None).await?;
 
@@ -71,12 +70,11 @@ impl Topups {
         _created: &str,
         status: crate::types::GetTopupsStatus,
     ) -> Result<Vec<crate::types::Topup>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/topups?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/topups"), &query_args);
 
         let mut resp: crate::types::TopupList = self.client.get(&url, None).await?;
 
@@ -96,17 +94,13 @@ impl Topups {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -137,7 +131,8 @@ impl Topups {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `topup: &str` -- The account's country.
     */
-    pub async fn get(&self, topup: &str) -> Result<crate::types::Topup> {
+    pub async fn get(&self, topup: impl AsRef<str>) -> Result<crate::types::Topup> {
+        let topup = topup.as_ref();
         let url = format!(
             "/v1/topups/{}",
             crate::progenitor_support::encode_path(topup),
@@ -155,7 +150,8 @@ impl Topups {
     *
     * * `topup: &str` -- The account's country.
     */
-    pub async fn post_topups(&self, topup: &str) -> Result<crate::types::Topup> {
+    pub async fn post_topups(&self, topup: impl AsRef<str>) -> Result<crate::types::Topup> {
+        let topup = topup.as_ref();
         let url = format!(
             "/v1/topups/{}",
             crate::progenitor_support::encode_path(topup),
@@ -173,7 +169,8 @@ impl Topups {
     *
     * * `topup: &str` -- The account's country.
     */
-    pub async fn post_cancel(&self, topup: &str) -> Result<crate::types::Topup> {
+    pub async fn post_cancel(&self, topup: impl AsRef<str>) -> Result<crate::types::Topup> {
+        let topup = topup.as_ref();
         let url = format!(
             "/v1/topups/{}/cancel",
             crate::progenitor_support::encode_path(topup),
@@ -36,24 +36,23 @@ impl Transfers {
         starting_after: &str,
         transfer_group: &str,
     ) -> Result<Vec<crate::types::Transfer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !destination.is_empty() {
-            query_args.push(("destination".to_string(), destination.to_string()));
+            query_args.push(("destination", destination.to_string()));
         }
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
         if !transfer_group.is_empty() {
-            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+            query_args.push(("transfer_group", transfer_group.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/transfers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/transfers"), &query_args);
 
         let resp: crate::types::TransferList = self.client.get(&url, None).await?;
 
@@ -74,15 +73,14 @@ impl Transfers {
         destination: &str,
         transfer_group: &str,
     ) -> Result<Vec<crate::types::Transfer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !destination.is_empty() {
-            query_args.push(("destination".to_string(), destination.to_string()));
+            query_args.push(("destination", destination.to_string()));
         }
         if !transfer_group.is_empty() {
-            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+            query_args.push(("transfer_group", transfer_group.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/transfers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/transfers"), &query_args);
 
         let mut resp: crate::types::TransferList = self.client.get(&url, None).await?;
 
@@ -102,17 +100,13 @@ impl Transfers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -149,25 +143,27 @@ impl Transfers {
     pub async fn get_reversals(
         &self,
         ending_before: &str,
-        id: &str,
+        id: impl AsRef<str>,
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::TransferReversal>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let id = id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/transfers/{}/reversals?{}",
-            crate::progenitor_support::encode_path(id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/v1/transfers/{}/reversals",
+                crate::progenitor_support::encode_path(id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::Reversals = self.client.get(&url, None).await?;
@@ -183,7 +179,11 @@ impl Transfers {
     *
     * <p>You can see a list of the reversals belonging to a specific transfer. Note that the 10 most recent reversals are always available by default on the transfer object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional reversals.</p>
     */
-    pub async fn get_all_reversals(&self, id: &str) -> Result<Vec<crate::types::TransferReversal>> {
+    pub async fn get_all_reversals(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::TransferReversal>> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/transfers/{}/reversals",
             crate::progenitor_support::encode_path(id),
@@ -207,17 +207,13 @@ impl Transfers {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -241,7 +237,11 @@ impl Transfers {
     *
     * * `id: &str` -- The account's country.
     */
-    pub async fn post_reversal(&self, id: &str) -> Result<crate::types::TransferReversal> {
+    pub async fn post_reversal(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<crate::types::TransferReversal> {
+        let id = id.as_ref();
         let url = format!(
             "/v1/transfers/{}/reversals",
             crate::progenitor_support::encode_path(id),
@@ -260,7 +260,8 @@ impl Transfers {
     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
     * * `transfer: &str` -- The account's country.
     */
-    pub async fn get(&self, transfer: &str) -> Result<crate::types::Transfer> {
+    pub async fn get(&self, transfer: impl AsRef<str>) -> Result<crate::types::Transfer> {
+        let transfer = transfer.as_ref();
         let url = format!(
             "/v1/transfers/{}",
             crate::progenitor_support::encode_path(transfer),
@@ -280,7 +281,11 @@ impl Transfers {
     *
     * * `transfer: &str` -- The account's country.
     */
-    pub async fn post_transfers(&self, transfer: &str) -> Result<crate::types::Transfer> {
+    pub async fn post_transfers(
+        &self,
+        transfer: impl AsRef<str>,
+    ) -> Result<crate::types::Transfer> {
+        let transfer = transfer.as_ref();
         let url = format!(
             "/v1/transfers/{}",
             crate::progenitor_support::encode_path(transfer),
@@ -302,9 +307,11 @@ impl Transfers {
     */
     pub async fn get_reversal(
         &self,
-        id: &str,
-        transfer: &str,
+        id: impl AsRef<str>,
+        transfer: impl AsRef<str>,
     ) -> Result<crate::types::TransferReversal> {
+        let id = id.as_ref();
+        let transfer = transfer.as_ref();
         let url = format!(
             "/v1/transfers/{}/reversals/{}",
             crate::progenitor_support::encode_path(transfer),
@@ -328,9 +335,11 @@ impl Transfers {
     */
     pub async fn post_reversal_transfers(
         &self,
-        id: &str,
-        transfer: &str,
+        id: impl AsRef<str>,
+        transfer: impl AsRef<str>,
     ) -> Result<crate::types::TransferReversal> {
+        let id = id.as_ref();
+        let transfer = transfer.as_ref();
         let url = format!(
             "/v1/transfers/{}/reversals/{}",
             crate::progenitor_support::encode_path(transfer),
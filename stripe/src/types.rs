@@ -17,8 +17,8 @@ pub enum BusinessType {
     NonProfit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BusinessType {
@@ -29,7 +29,7 @@ impl std::fmt::Display for BusinessType {
             BusinessType::Individual => "individual",
             BusinessType::NonProfit => "non_profit",
             BusinessType::Noop => "",
-            BusinessType::FallthroughString => "*",
+            BusinessType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -101,8 +101,8 @@ pub enum Object {
     List,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Object {
@@ -110,7 +110,7 @@ impl std::fmt::Display for Object {
         match &*self {
             Object::List => "list",
             Object::Noop => "",
-            Object::FallthroughString => "*",
+            Object::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -168,8 +168,8 @@ pub enum AccountObject {
     Account,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountObject {
@@ -177,7 +177,7 @@ impl std::fmt::Display for AccountObject {
         match &*self {
             AccountObject::Account => "account",
             AccountObject::Noop => "",
-            AccountObject::FallthroughString => "*",
+            AccountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -207,8 +207,8 @@ pub enum Type {
     Standard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -218,7 +218,7 @@ impl std::fmt::Display for Type {
             Type::Express => "express",
             Type::Standard => "standard",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -660,8 +660,8 @@ pub enum Transfers {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Transfers {
@@ -671,7 +671,7 @@ impl std::fmt::Display for Transfers {
             Transfers::Inactive => "inactive",
             Transfers::Pending => "pending",
             Transfers::Noop => "",
-            Transfers::FallthroughString => "*",
+            Transfers::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1150,8 +1150,8 @@ pub enum AccountLinkObject {
     AccountLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountLinkObject {
@@ -1159,7 +1159,7 @@ impl std::fmt::Display for AccountLinkObject {
         match &*self {
             AccountLinkObject::AccountLink => "account_link",
             AccountLinkObject::Noop => "",
-            AccountLinkObject::FallthroughString => "*",
+            AccountLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1472,8 +1472,8 @@ pub enum Code {
     VerificationRequiresAdditionalMemorandumOfAssociations,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Code {
@@ -1535,7 +1535,7 @@ impl std::fmt::Display for Code {
                 "verification_requires_additional_memorandum_of_associations"
             }
             Code::Noop => "",
-            Code::FallthroughString => "*",
+            Code::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1691,8 +1691,8 @@ pub enum AccountUnificationControllerType {
     Application,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountUnificationControllerType {
@@ -1701,7 +1701,7 @@ impl std::fmt::Display for AccountUnificationControllerType {
             AccountUnificationControllerType::Account => "account",
             AccountUnificationControllerType::Application => "application",
             AccountUnificationControllerType::Noop => "",
-            AccountUnificationControllerType::FallthroughString => "*",
+            AccountUnificationControllerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1872,8 +1872,8 @@ pub enum AlipayAccountObject {
     AlipayAccount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AlipayAccountObject {
@@ -1881,7 +1881,7 @@ impl std::fmt::Display for AlipayAccountObject {
         match &*self {
             AlipayAccountObject::AlipayAccount => "alipay_account",
             AlipayAccountObject::Noop => "",
-            AlipayAccountObject::FallthroughString => "*",
+            AlipayAccountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2078,8 +2078,8 @@ pub enum ApiErrorsType {
     InvalidRequestError,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApiErrorsType {
@@ -2090,7 +2090,7 @@ impl std::fmt::Display for ApiErrorsType {
             ApiErrorsType::IdempotencyError => "idempotency_error",
             ApiErrorsType::InvalidRequestError => "invalid_request_error",
             ApiErrorsType::Noop => "",
-            ApiErrorsType::FallthroughString => "*",
+            ApiErrorsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2213,8 +2213,8 @@ pub enum ApplePayDomainObject {
     ApplePayDomain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplePayDomainObject {
@@ -2222,7 +2222,7 @@ impl std::fmt::Display for ApplePayDomainObject {
         match &*self {
             ApplePayDomainObject::ApplePayDomain => "apple_pay_domain",
             ApplePayDomainObject::Noop => "",
-            ApplePayDomainObject::FallthroughString => "*",
+            ApplePayDomainObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2293,8 +2293,8 @@ pub enum ApplicationObject {
     Application,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationObject {
@@ -2302,7 +2302,7 @@ impl std::fmt::Display for ApplicationObject {
         match &*self {
             ApplicationObject::Application => "application",
             ApplicationObject::Noop => "",
-            ApplicationObject::FallthroughString => "*",
+            ApplicationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2560,8 +2560,8 @@ pub enum PlatformFeeObject {
     ApplicationFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformFeeObject {
@@ -2569,7 +2569,7 @@ impl std::fmt::Display for PlatformFeeObject {
         match &*self {
             PlatformFeeObject::ApplicationFee => "application_fee",
             PlatformFeeObject::Noop => "",
-            PlatformFeeObject::FallthroughString => "*",
+            PlatformFeeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2727,8 +2727,8 @@ pub enum Status {
     RequiresLocationInputs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2738,7 +2738,7 @@ impl std::fmt::Display for Status {
             Status::Failed => "failed",
             Status::RequiresLocationInputs => "requires_location_inputs",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2782,8 +2782,8 @@ pub enum BalanceObject {
     Balance,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BalanceObject {
@@ -2791,7 +2791,7 @@ impl std::fmt::Display for BalanceObject {
         match &*self {
             BalanceObject::Balance => "balance",
             BalanceObject::Noop => "",
-            BalanceObject::FallthroughString => "*",
+            BalanceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2990,8 +2990,8 @@ pub enum BalanceTransactionObject {
     BalanceTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BalanceTransactionObject {
@@ -2999,7 +2999,7 @@ impl std::fmt::Display for BalanceTransactionObject {
         match &*self {
             BalanceTransactionObject::BalanceTransaction => "balance_transaction",
             BalanceTransactionObject::Noop => "",
-            BalanceTransactionObject::FallthroughString => "*",
+            BalanceTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3375,8 +3375,8 @@ pub enum BalanceTransactionType {
     TransferRefund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BalanceTransactionType {
@@ -3415,7 +3415,7 @@ impl std::fmt::Display for BalanceTransactionType {
             BalanceTransactionType::TransferFailure => "transfer_failure",
             BalanceTransactionType::TransferRefund => "transfer_refund",
             BalanceTransactionType::Noop => "",
-            BalanceTransactionType::FallthroughString => "*",
+            BalanceTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3581,8 +3581,8 @@ pub enum AvailablePayoutMethods {
     Standard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AvailablePayoutMethods {
@@ -3591,7 +3591,7 @@ impl std::fmt::Display for AvailablePayoutMethods {
             AvailablePayoutMethods::Instant => "instant",
             AvailablePayoutMethods::Standard => "standard",
             AvailablePayoutMethods::Noop => "",
-            AvailablePayoutMethods::FallthroughString => "*",
+            AvailablePayoutMethods::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3617,8 +3617,8 @@ pub enum BankAccountObject {
     BankAccount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BankAccountObject {
@@ -3626,7 +3626,7 @@ impl std::fmt::Display for BankAccountObject {
         match &*self {
             BankAccountObject::BankAccount => "bank_account",
             BankAccountObject::Noop => "",
-            BankAccountObject::FallthroughString => "*",
+            BankAccountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3901,8 +3901,8 @@ pub enum PortalConfigurationObject {
     BillingPortalConfiguration,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PortalConfigurationObject {
@@ -3910,7 +3910,7 @@ impl std::fmt::Display for PortalConfigurationObject {
         match &*self {
             PortalConfigurationObject::BillingPortalConfiguration => "billing_portal.configuration",
             PortalConfigurationObject::Noop => "",
-            PortalConfigurationObject::FallthroughString => "*",
+            PortalConfigurationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4174,8 +4174,8 @@ pub enum Locale {
     ZhTw,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Locale {
@@ -4229,7 +4229,7 @@ impl std::fmt::Display for Locale {
             Locale::ZhHk => "zh-HK",
             Locale::ZhTw => "zh-TW",
             Locale::Noop => "",
-            Locale::FallthroughString => "*",
+            Locale::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4255,8 +4255,8 @@ pub enum PortalSessionObject {
     BillingPortalSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PortalSessionObject {
@@ -4264,7 +4264,7 @@ impl std::fmt::Display for PortalSessionObject {
         match &*self {
             PortalSessionObject::BillingPortalSession => "billing_portal.session",
             PortalSessionObject::Noop => "",
-            PortalSessionObject::FallthroughString => "*",
+            PortalSessionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4395,8 +4395,8 @@ pub enum BitcoinReceiverObject {
     BitcoinReceiver,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BitcoinReceiverObject {
@@ -4404,7 +4404,7 @@ impl std::fmt::Display for BitcoinReceiverObject {
         match &*self {
             BitcoinReceiverObject::BitcoinReceiver => "bitcoin_receiver",
             BitcoinReceiverObject::Noop => "",
-            BitcoinReceiverObject::FallthroughString => "*",
+            BitcoinReceiverObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4656,8 +4656,8 @@ pub enum BitcoinTransactionObject {
     BitcoinTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BitcoinTransactionObject {
@@ -4665,7 +4665,7 @@ impl std::fmt::Display for BitcoinTransactionObject {
         match &*self {
             BitcoinTransactionObject::BitcoinTransaction => "bitcoin_transaction",
             BitcoinTransactionObject::Noop => "",
-            BitcoinTransactionObject::FallthroughString => "*",
+            BitcoinTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4755,8 +4755,8 @@ pub enum CapabilityObject {
     Capability,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CapabilityObject {
@@ -4764,7 +4764,7 @@ impl std::fmt::Display for CapabilityObject {
         match &*self {
             CapabilityObject::Capability => "capability",
             CapabilityObject::Noop => "",
-            CapabilityObject::FallthroughString => "*",
+            CapabilityObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4798,8 +4798,8 @@ pub enum CapabilityStatus {
     Unrequested,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CapabilityStatus {
@@ -4811,7 +4811,7 @@ impl std::fmt::Display for CapabilityStatus {
             CapabilityStatus::Pending => "pending",
             CapabilityStatus::Unrequested => "unrequested",
             CapabilityStatus::Noop => "",
-            CapabilityStatus::FallthroughString => "*",
+            CapabilityStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4898,8 +4898,8 @@ pub enum CardObject {
     Card,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CardObject {
@@ -4907,7 +4907,7 @@ impl std::fmt::Display for CardObject {
         match &*self {
             CardObject::Card => "card",
             CardObject::Noop => "",
-            CardObject::FallthroughString => "*",
+            CardObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5515,8 +5515,8 @@ pub enum ChargeObject {
     Charge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChargeObject {
@@ -5524,7 +5524,7 @@ impl std::fmt::Display for ChargeObject {
         match &*self {
             ChargeObject::Charge => "charge",
             ChargeObject::Noop => "",
-            ChargeObject::FallthroughString => "*",
+            ChargeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5808,8 +5808,8 @@ pub enum ChargeStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChargeStatus {
@@ -5819,7 +5819,7 @@ impl std::fmt::Display for ChargeStatus {
             ChargeStatus::Pending => "pending",
             ChargeStatus::Succeeded => "succeeded",
             ChargeStatus::Noop => "",
-            ChargeStatus::FallthroughString => "*",
+            ChargeStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6420,8 +6420,8 @@ pub enum BillingAddressCollection {
     Required,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingAddressCollection {
@@ -6430,7 +6430,7 @@ impl std::fmt::Display for BillingAddressCollection {
             BillingAddressCollection::Auto => "auto",
             BillingAddressCollection::Required => "required",
             BillingAddressCollection::Noop => "",
-            BillingAddressCollection::FallthroughString => "*",
+            BillingAddressCollection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6458,8 +6458,8 @@ pub enum CustomerCreation {
     IfRequired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerCreation {
@@ -6468,7 +6468,7 @@ impl std::fmt::Display for CustomerCreation {
             CustomerCreation::Always => "always",
             CustomerCreation::IfRequired => "if_required",
             CustomerCreation::Noop => "",
-            CustomerCreation::FallthroughString => "*",
+            CustomerCreation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6610,8 +6610,8 @@ pub enum SessionLocale {
     ZhTw,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionLocale {
@@ -6659,7 +6659,7 @@ impl std::fmt::Display for SessionLocale {
             SessionLocale::ZhHk => "zh-HK",
             SessionLocale::ZhTw => "zh-TW",
             SessionLocale::Noop => "",
-            SessionLocale::FallthroughString => "*",
+            SessionLocale::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6689,8 +6689,8 @@ pub enum Mode {
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -6700,7 +6700,7 @@ impl std::fmt::Display for Mode {
             Mode::Setup => "setup",
             Mode::Subscription => "subscription",
             Mode::Noop => "",
-            Mode::FallthroughString => "*",
+            Mode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6726,8 +6726,8 @@ pub enum SessionObject {
     CheckoutSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionObject {
@@ -6735,7 +6735,7 @@ impl std::fmt::Display for SessionObject {
         match &*self {
             SessionObject::CheckoutSession => "checkout.session",
             SessionObject::Noop => "",
-            SessionObject::FallthroughString => "*",
+            SessionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6818,8 +6818,8 @@ pub enum PaymentStatus {
     Unpaid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentStatus {
@@ -6829,7 +6829,7 @@ impl std::fmt::Display for PaymentStatus {
             PaymentStatus::Paid => "paid",
             PaymentStatus::Unpaid => "unpaid",
             PaymentStatus::Noop => "",
-            PaymentStatus::FallthroughString => "*",
+            PaymentStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6977,8 +6977,8 @@ pub enum SessionStatus {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -6988,7 +6988,7 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::Expired => "expired",
             SessionStatus::Open => "open",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7023,8 +7023,8 @@ pub enum SubmitType {
     Pay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubmitType {
@@ -7035,7 +7035,7 @@ impl std::fmt::Display for SubmitType {
             SubmitType::Donate => "donate",
             SubmitType::Pay => "pay",
             SubmitType::Noop => "",
-            SubmitType::FallthroughString => "*",
+            SubmitType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7638,8 +7638,8 @@ pub enum DefaultFor {
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DefaultFor {
@@ -7648,7 +7648,7 @@ impl std::fmt::Display for DefaultFor {
             DefaultFor::Invoice => "invoice",
             DefaultFor::Subscription => "subscription",
             DefaultFor::Noop => "",
-            DefaultFor::FallthroughString => "*",
+            DefaultFor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7678,8 +7678,8 @@ pub enum PaymentSchedule {
     Sporadic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -7689,7 +7689,7 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Interval => "interval",
             PaymentSchedule::Sporadic => "sporadic",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7717,8 +7717,8 @@ pub enum TransactionType {
     Personal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransactionType {
@@ -7727,7 +7727,7 @@ impl std::fmt::Display for TransactionType {
             TransactionType::Business => "business",
             TransactionType::Personal => "personal",
             TransactionType::Noop => "",
-            TransactionType::FallthroughString => "*",
+            TransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7797,8 +7797,8 @@ pub enum Currency {
     Usd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Currency {
@@ -7807,7 +7807,7 @@ impl std::fmt::Display for Currency {
             Currency::Cad => "cad",
             Currency::Usd => "usd",
             Currency::Noop => "",
-            Currency::FallthroughString => "*",
+            Currency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7837,8 +7837,8 @@ pub enum VerificationMethod {
     Microdeposits,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationMethod {
@@ -7848,7 +7848,7 @@ impl std::fmt::Display for VerificationMethod {
             VerificationMethod::Instant => "instant",
             VerificationMethod::Microdeposits => "microdeposits",
             VerificationMethod::Noop => "",
-            VerificationMethod::FallthroughString => "*",
+            VerificationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7954,8 +7954,8 @@ pub enum CheckoutUsBankAccountPaymentMethodOptionsVerification {
     Instant,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CheckoutUsBankAccountPaymentMethodOptionsVerification {
@@ -7964,7 +7964,7 @@ impl std::fmt::Display for CheckoutUsBankAccountPaymentMethodOptionsVerification
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Automatic => "automatic",
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Instant => "instant",
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Noop => "",
-            CheckoutUsBankAccountPaymentMethodOptionsVerification::FallthroughString => "*",
+            CheckoutUsBankAccountPaymentMethodOptionsVerification::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8003,8 +8003,8 @@ pub enum ConnectCollectionTransferObject {
     ConnectCollectionTransfer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConnectCollectionTransferObject {
@@ -8014,7 +8014,7 @@ impl std::fmt::Display for ConnectCollectionTransferObject {
                 "connect_collection_transfer"
             }
             ConnectCollectionTransferObject::Noop => "",
-            ConnectCollectionTransferObject::FallthroughString => "*",
+            ConnectCollectionTransferObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8090,8 +8090,8 @@ pub enum CountrySpecObject {
     CountrySpec,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CountrySpecObject {
@@ -8099,7 +8099,7 @@ impl std::fmt::Display for CountrySpecObject {
         match &*self {
             CountrySpecObject::CountrySpec => "country_spec",
             CountrySpecObject::Noop => "",
-            CountrySpecObject::FallthroughString => "*",
+            CountrySpecObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8241,8 +8241,8 @@ pub enum Duration {
     Repeating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Duration {
@@ -8252,7 +8252,7 @@ impl std::fmt::Display for Duration {
             Duration::Once => "once",
             Duration::Repeating => "repeating",
             Duration::Noop => "",
-            Duration::FallthroughString => "*",
+            Duration::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8278,8 +8278,8 @@ pub enum CouponObject {
     Coupon,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CouponObject {
@@ -8287,7 +8287,7 @@ impl std::fmt::Display for CouponObject {
         match &*self {
             CouponObject::Coupon => "coupon",
             CouponObject::Noop => "",
-            CouponObject::FallthroughString => "*",
+            CouponObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8569,8 +8569,8 @@ pub enum CreditNoteObject {
     CreditNote,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteObject {
@@ -8578,7 +8578,7 @@ impl std::fmt::Display for CreditNoteObject {
         match &*self {
             CreditNoteObject::CreditNote => "credit_note",
             CreditNoteObject::Noop => "",
-            CreditNoteObject::FallthroughString => "*",
+            CreditNoteObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8610,8 +8610,8 @@ pub enum Reason {
     ProductUnsatisfactory,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Reason {
@@ -8622,7 +8622,7 @@ impl std::fmt::Display for Reason {
             Reason::OrderChange => "order_change",
             Reason::ProductUnsatisfactory => "product_unsatisfactory",
             Reason::Noop => "",
-            Reason::FallthroughString => "*",
+            Reason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8702,8 +8702,8 @@ pub enum CreditNoteStatus {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteStatus {
@@ -8712,7 +8712,7 @@ impl std::fmt::Display for CreditNoteStatus {
             CreditNoteStatus::Issued => "issued",
             CreditNoteStatus::Void => "void",
             CreditNoteStatus::Noop => "",
-            CreditNoteStatus::FallthroughString => "*",
+            CreditNoteStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8740,8 +8740,8 @@ pub enum CreditNoteType {
     PrePayment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteType {
@@ -8750,7 +8750,7 @@ impl std::fmt::Display for CreditNoteType {
             CreditNoteType::PostPayment => "post_payment",
             CreditNoteType::PrePayment => "pre_payment",
             CreditNoteType::Noop => "",
-            CreditNoteType::FallthroughString => "*",
+            CreditNoteType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8981,8 +8981,8 @@ pub enum CreditNoteLineItemObject {
     CreditNoteLineItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteLineItemObject {
@@ -8990,7 +8990,7 @@ impl std::fmt::Display for CreditNoteLineItemObject {
         match &*self {
             CreditNoteLineItemObject::CreditNoteLineItem => "credit_note_line_item",
             CreditNoteLineItemObject::Noop => "",
-            CreditNoteLineItemObject::FallthroughString => "*",
+            CreditNoteLineItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9018,8 +9018,8 @@ pub enum CreditNoteLineItemType {
     InvoiceLineItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteLineItemType {
@@ -9028,7 +9028,7 @@ impl std::fmt::Display for CreditNoteLineItemType {
             CreditNoteLineItemType::CustomLineItem => "custom_line_item",
             CreditNoteLineItemType::InvoiceLineItem => "invoice_line_item",
             CreditNoteLineItemType::Noop => "",
-            CreditNoteLineItemType::FallthroughString => "*",
+            CreditNoteLineItemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9365,8 +9365,8 @@ pub enum CustomerObject {
     Customer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerObject {
@@ -9374,7 +9374,7 @@ impl std::fmt::Display for CustomerObject {
         match &*self {
             CustomerObject::Customer => "customer",
             CustomerObject::Noop => "",
-            CustomerObject::FallthroughString => "*",
+            CustomerObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9559,8 +9559,8 @@ pub enum TaxExempt {
     Reverse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxExempt {
@@ -9570,7 +9570,7 @@ impl std::fmt::Display for TaxExempt {
             TaxExempt::None => "none",
             TaxExempt::Reverse => "reverse",
             TaxExempt::Noop => "",
-            TaxExempt::FallthroughString => "*",
+            TaxExempt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9911,8 +9911,8 @@ pub enum CustomerAcceptanceType {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerAcceptanceType {
@@ -9921,7 +9921,7 @@ impl std::fmt::Display for CustomerAcceptanceType {
             CustomerAcceptanceType::Offline => "offline",
             CustomerAcceptanceType::Online => "online",
             CustomerAcceptanceType::Noop => "",
-            CustomerAcceptanceType::FallthroughString => "*",
+            CustomerAcceptanceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10080,8 +10080,8 @@ pub enum CustomerBalanceTransactionObject {
     CustomerBalanceTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerBalanceTransactionObject {
@@ -10091,7 +10091,7 @@ impl std::fmt::Display for CustomerBalanceTransactionObject {
                 "customer_balance_transaction"
             }
             CustomerBalanceTransactionObject::Noop => "",
-            CustomerBalanceTransactionObject::FallthroughString => "*",
+            CustomerBalanceTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10133,8 +10133,8 @@ pub enum CustomerBalanceTransactionType {
     UnspentReceiverCredit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerBalanceTransactionType {
@@ -10150,7 +10150,7 @@ impl std::fmt::Display for CustomerBalanceTransactionType {
             CustomerBalanceTransactionType::UnappliedFromInvoice => "unapplied_from_invoice",
             CustomerBalanceTransactionType::UnspentReceiverCredit => "unspent_receiver_credit",
             CustomerBalanceTransactionType::Noop => "",
-            CustomerBalanceTransactionType::FallthroughString => "*",
+            CustomerBalanceTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10312,8 +10312,8 @@ pub enum CustomerTaxAutomatic {
     UnrecognizedLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerTaxAutomatic {
@@ -10324,7 +10324,7 @@ impl std::fmt::Display for CustomerTaxAutomatic {
             CustomerTaxAutomatic::Supported => "supported",
             CustomerTaxAutomatic::UnrecognizedLocation => "unrecognized_location",
             CustomerTaxAutomatic::Noop => "",
-            CustomerTaxAutomatic::FallthroughString => "*",
+            CustomerTaxAutomatic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10380,8 +10380,8 @@ pub enum Source {
     ShippingDestination,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -10392,7 +10392,7 @@ impl std::fmt::Display for Source {
             Source::PaymentMethod => "payment_method",
             Source::ShippingDestination => "shipping_destination",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10680,8 +10680,8 @@ pub enum DeletedDiscountObject {
     Discount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedDiscountObject {
@@ -10689,7 +10689,7 @@ impl std::fmt::Display for DeletedDiscountObject {
         match &*self {
             DeletedDiscountObject::Discount => "discount",
             DeletedDiscountObject::Noop => "",
-            DeletedDiscountObject::FallthroughString => "*",
+            DeletedDiscountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10889,8 +10889,8 @@ pub enum DeletedInvoiceObject {
     Invoice,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedInvoiceObject {
@@ -10898,7 +10898,7 @@ impl std::fmt::Display for DeletedInvoiceObject {
         match &*self {
             DeletedInvoiceObject::Invoice => "invoice",
             DeletedInvoiceObject::Noop => "",
-            DeletedInvoiceObject::FallthroughString => "*",
+            DeletedInvoiceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10951,8 +10951,8 @@ pub enum DeletedInvoiceItemObject {
     Invoiceitem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedInvoiceItemObject {
@@ -10960,7 +10960,7 @@ impl std::fmt::Display for DeletedInvoiceItemObject {
         match &*self {
             DeletedInvoiceItemObject::Invoiceitem => "invoiceitem",
             DeletedInvoiceItemObject::Noop => "",
-            DeletedInvoiceItemObject::FallthroughString => "*",
+            DeletedInvoiceItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11073,8 +11073,8 @@ pub enum DeletedPersonObject {
     Person,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedPersonObject {
@@ -11082,7 +11082,7 @@ impl std::fmt::Display for DeletedPersonObject {
         match &*self {
             DeletedPersonObject::Person => "person",
             DeletedPersonObject::Noop => "",
-            DeletedPersonObject::FallthroughString => "*",
+            DeletedPersonObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11135,8 +11135,8 @@ pub enum DeletedPlanObject {
     Plan,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedPlanObject {
@@ -11144,7 +11144,7 @@ impl std::fmt::Display for DeletedPlanObject {
         match &*self {
             DeletedPlanObject::Plan => "plan",
             DeletedPlanObject::Noop => "",
-            DeletedPlanObject::FallthroughString => "*",
+            DeletedPlanObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11197,8 +11197,8 @@ pub enum DeletedPriceObject {
     Price,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedPriceObject {
@@ -11206,7 +11206,7 @@ impl std::fmt::Display for DeletedPriceObject {
         match &*self {
             DeletedPriceObject::Price => "price",
             DeletedPriceObject::Noop => "",
-            DeletedPriceObject::FallthroughString => "*",
+            DeletedPriceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11259,8 +11259,8 @@ pub enum DeletedProductObject {
     Product,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedProductObject {
@@ -11268,7 +11268,7 @@ impl std::fmt::Display for DeletedProductObject {
         match &*self {
             DeletedProductObject::Product => "product",
             DeletedProductObject::Noop => "",
-            DeletedProductObject::FallthroughString => "*",
+            DeletedProductObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11321,8 +11321,8 @@ pub enum RadarListDeletedObject {
     RadarValueList,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RadarListDeletedObject {
@@ -11330,7 +11330,7 @@ impl std::fmt::Display for RadarListDeletedObject {
         match &*self {
             RadarListDeletedObject::RadarValueList => "radar.value_list",
             RadarListDeletedObject::Noop => "",
-            RadarListDeletedObject::FallthroughString => "*",
+            RadarListDeletedObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11383,8 +11383,8 @@ pub enum RadarListDeletedItemObject {
     RadarValueListItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RadarListDeletedItemObject {
@@ -11392,7 +11392,7 @@ impl std::fmt::Display for RadarListDeletedItemObject {
         match &*self {
             RadarListDeletedItemObject::RadarValueListItem => "radar.value_list_item",
             RadarListDeletedItemObject::Noop => "",
-            RadarListDeletedItemObject::FallthroughString => "*",
+            RadarListDeletedItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11445,8 +11445,8 @@ pub enum DeletedRecipientObject {
     Recipient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedRecipientObject {
@@ -11454,7 +11454,7 @@ impl std::fmt::Display for DeletedRecipientObject {
         match &*self {
             DeletedRecipientObject::Recipient => "recipient",
             DeletedRecipientObject::Noop => "",
-            DeletedRecipientObject::FallthroughString => "*",
+            DeletedRecipientObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11507,8 +11507,8 @@ pub enum DeletedSkuObject {
     Sku,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedSkuObject {
@@ -11516,7 +11516,7 @@ impl std::fmt::Display for DeletedSkuObject {
         match &*self {
             DeletedSkuObject::Sku => "sku",
             DeletedSkuObject::Noop => "",
-            DeletedSkuObject::FallthroughString => "*",
+            DeletedSkuObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11569,8 +11569,8 @@ pub enum DeletedSubscriptionItemObject {
     SubscriptionItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedSubscriptionItemObject {
@@ -11578,7 +11578,7 @@ impl std::fmt::Display for DeletedSubscriptionItemObject {
         match &*self {
             DeletedSubscriptionItemObject::SubscriptionItem => "subscription_item",
             DeletedSubscriptionItemObject::Noop => "",
-            DeletedSubscriptionItemObject::FallthroughString => "*",
+            DeletedSubscriptionItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11634,8 +11634,8 @@ pub enum DeletedTaxObject {
     TaxId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTaxObject {
@@ -11643,7 +11643,7 @@ impl std::fmt::Display for DeletedTaxObject {
         match &*self {
             DeletedTaxObject::TaxId => "tax_id",
             DeletedTaxObject::Noop => "",
-            DeletedTaxObject::FallthroughString => "*",
+            DeletedTaxObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11696,8 +11696,8 @@ pub enum DeletedTerminalLocationObject {
     TerminalLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTerminalLocationObject {
@@ -11705,7 +11705,7 @@ impl std::fmt::Display for DeletedTerminalLocationObject {
         match &*self {
             DeletedTerminalLocationObject::TerminalLocation => "terminal.location",
             DeletedTerminalLocationObject::Noop => "",
-            DeletedTerminalLocationObject::FallthroughString => "*",
+            DeletedTerminalLocationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11761,8 +11761,8 @@ pub enum DeletedTerminalReaderObject {
     TerminalReader,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTerminalReaderObject {
@@ -11770,7 +11770,7 @@ impl std::fmt::Display for DeletedTerminalReaderObject {
         match &*self {
             DeletedTerminalReaderObject::TerminalReader => "terminal.reader",
             DeletedTerminalReaderObject::Noop => "",
-            DeletedTerminalReaderObject::FallthroughString => "*",
+            DeletedTerminalReaderObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11823,8 +11823,8 @@ pub enum DeletedTestClockObject {
     TestHelpersClock,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTestClockObject {
@@ -11832,7 +11832,7 @@ impl std::fmt::Display for DeletedTestClockObject {
         match &*self {
             DeletedTestClockObject::TestHelpersClock => "test_helpers.test_clock",
             DeletedTestClockObject::Noop => "",
-            DeletedTestClockObject::FallthroughString => "*",
+            DeletedTestClockObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11885,8 +11885,8 @@ pub enum DeletedWebhookEndpointObject {
     WebhookEndpoint,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedWebhookEndpointObject {
@@ -11894,7 +11894,7 @@ impl std::fmt::Display for DeletedWebhookEndpointObject {
         match &*self {
             DeletedWebhookEndpointObject::WebhookEndpoint => "webhook_endpoint",
             DeletedWebhookEndpointObject::Noop => "",
-            DeletedWebhookEndpointObject::FallthroughString => "*",
+            DeletedWebhookEndpointObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12191,8 +12191,8 @@ pub enum DisputeObject {
     Dispute,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DisputeObject {
@@ -12200,7 +12200,7 @@ impl std::fmt::Display for DisputeObject {
         match &*self {
             DisputeObject::Dispute => "dispute",
             DisputeObject::Noop => "",
-            DisputeObject::FallthroughString => "*",
+            DisputeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12240,8 +12240,8 @@ pub enum DisputeStatus {
     Won,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DisputeStatus {
@@ -12256,7 +12256,7 @@ impl std::fmt::Display for DisputeStatus {
             DisputeStatus::WarningUnderReview => "warning_under_review",
             DisputeStatus::Won => "won",
             DisputeStatus::Noop => "",
-            DisputeStatus::FallthroughString => "*",
+            DisputeStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12679,8 +12679,8 @@ pub enum EphemeralKeyObject {
     EphemeralKey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EphemeralKeyObject {
@@ -12688,7 +12688,7 @@ impl std::fmt::Display for EphemeralKeyObject {
         match &*self {
             EphemeralKeyObject::EphemeralKey => "ephemeral_key",
             EphemeralKeyObject::Noop => "",
-            EphemeralKeyObject::FallthroughString => "*",
+            EphemeralKeyObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12778,8 +12778,8 @@ pub enum EventObject {
     Event,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventObject {
@@ -12787,7 +12787,7 @@ impl std::fmt::Display for EventObject {
         match &*self {
             EventObject::Event => "event",
             EventObject::Noop => "",
-            EventObject::FallthroughString => "*",
+            EventObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12980,8 +12980,8 @@ pub enum ExchangeRateObject {
     ExchangeRate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ExchangeRateObject {
@@ -12989,7 +12989,7 @@ impl std::fmt::Display for ExchangeRateObject {
         match &*self {
             ExchangeRateObject::ExchangeRate => "exchange_rate",
             ExchangeRateObject::Noop => "",
-            ExchangeRateObject::FallthroughString => "*",
+            ExchangeRateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13104,8 +13104,8 @@ pub enum FeeRefundObject {
     FeeRefund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeeRefundObject {
@@ -13113,7 +13113,7 @@ impl std::fmt::Display for FeeRefundObject {
         match &*self {
             FeeRefundObject::FeeRefund => "fee_refund",
             FeeRefundObject::Noop => "",
-            FeeRefundObject::FallthroughString => "*",
+            FeeRefundObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13249,8 +13249,8 @@ pub enum FileObject {
     File,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileObject {
@@ -13258,7 +13258,7 @@ impl std::fmt::Display for FileObject {
         match &*self {
             FileObject::File => "file",
             FileObject::Noop => "",
-            FileObject::FallthroughString => "*",
+            FileObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13310,8 +13310,8 @@ pub enum Purpose {
     TaxDocumentUserUpload,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Purpose {
@@ -13332,7 +13332,7 @@ impl std::fmt::Display for Purpose {
             Purpose::SigmaScheduledQuery => "sigma_scheduled_query",
             Purpose::TaxDocumentUserUpload => "tax_document_user_upload",
             Purpose::Noop => "",
-            Purpose::FallthroughString => "*",
+            Purpose::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13487,8 +13487,8 @@ pub enum FileLinkObject {
     FileLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileLinkObject {
@@ -13496,7 +13496,7 @@ impl std::fmt::Display for FileLinkObject {
         match &*self {
             FileLinkObject::FileLink => "file_link",
             FileLinkObject::Noop => "",
-            FileLinkObject::FallthroughString => "*",
+            FileLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13840,8 +13840,8 @@ pub enum GelatoDocumentReportStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoDocumentReportStatus {
@@ -13850,7 +13850,7 @@ impl std::fmt::Display for GelatoDocumentReportStatus {
             GelatoDocumentReportStatus::Unverified => "unverified",
             GelatoDocumentReportStatus::Verified => "verified",
             GelatoDocumentReportStatus::Noop => "",
-            GelatoDocumentReportStatus::FallthroughString => "*",
+            GelatoDocumentReportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13877,8 +13877,8 @@ pub enum AllowedTypes {
     Passport,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedTypes {
@@ -13888,7 +13888,7 @@ impl std::fmt::Display for AllowedTypes {
             AllowedTypes::IdCard => "id_card",
             AllowedTypes::Passport => "passport",
             AllowedTypes::Noop => "",
-            AllowedTypes::FallthroughString => "*",
+            AllowedTypes::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14003,8 +14003,8 @@ pub enum GelatoDocumentReportErrorCode {
     DocumentUnverifiedOther,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoDocumentReportErrorCode {
@@ -14016,7 +14016,7 @@ impl std::fmt::Display for GelatoDocumentReportErrorCode {
             }
             GelatoDocumentReportErrorCode::DocumentUnverifiedOther => "document_unverified_other",
             GelatoDocumentReportErrorCode::Noop => "",
-            GelatoDocumentReportErrorCode::FallthroughString => "*",
+            GelatoDocumentReportErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14065,8 +14065,8 @@ pub enum IdNumberType {
     UsSsn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdNumberType {
@@ -14076,7 +14076,7 @@ impl std::fmt::Display for IdNumberType {
             IdNumberType::SgNric => "sg_nric",
             IdNumberType::UsSsn => "us_ssn",
             IdNumberType::Noop => "",
-            IdNumberType::FallthroughString => "*",
+            IdNumberType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14158,8 +14158,8 @@ pub enum GelatoNumberReportErrorCode {
     IdNumberUnverifiedOther,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoNumberReportErrorCode {
@@ -14171,7 +14171,7 @@ impl std::fmt::Display for GelatoNumberReportErrorCode {
             GelatoNumberReportErrorCode::IdNumberMismatch => "id_number_mismatch",
             GelatoNumberReportErrorCode::IdNumberUnverifiedOther => "id_number_unverified_other",
             GelatoNumberReportErrorCode::Noop => "",
-            GelatoNumberReportErrorCode::FallthroughString => "*",
+            GelatoNumberReportErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14293,8 +14293,8 @@ pub enum GelatoSelfieReportErrorCode {
     SelfieUnverifiedOther,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoSelfieReportErrorCode {
@@ -14307,7 +14307,7 @@ impl std::fmt::Display for GelatoSelfieReportErrorCode {
             GelatoSelfieReportErrorCode::SelfieManipulated => "selfie_manipulated",
             GelatoSelfieReportErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
             GelatoSelfieReportErrorCode::Noop => "",
-            GelatoSelfieReportErrorCode::FallthroughString => "*",
+            GelatoSelfieReportErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14418,8 +14418,8 @@ pub enum GelatoSessionLastErrorCode {
     UnderSupportedAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoSessionLastErrorCode {
@@ -14445,7 +14445,7 @@ impl std::fmt::Display for GelatoSessionLastErrorCode {
             GelatoSessionLastErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
             GelatoSessionLastErrorCode::UnderSupportedAge => "under_supported_age",
             GelatoSessionLastErrorCode::Noop => "",
-            GelatoSessionLastErrorCode::FallthroughString => "*",
+            GelatoSessionLastErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14567,8 +14567,8 @@ pub enum GelatoVerificationReportObject {
     IdentityVerificationReport,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationReportObject {
@@ -14578,7 +14578,7 @@ impl std::fmt::Display for GelatoVerificationReportObject {
                 "identity.verification_report"
             }
             GelatoVerificationReportObject::Noop => "",
-            GelatoVerificationReportObject::FallthroughString => "*",
+            GelatoVerificationReportObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14606,8 +14606,8 @@ pub enum GelatoVerificationReportType {
     IdNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationReportType {
@@ -14616,7 +14616,7 @@ impl std::fmt::Display for GelatoVerificationReportType {
             GelatoVerificationReportType::Document => "document",
             GelatoVerificationReportType::IdNumber => "id_number",
             GelatoVerificationReportType::Noop => "",
-            GelatoVerificationReportType::FallthroughString => "*",
+            GelatoVerificationReportType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14827,8 +14827,8 @@ pub enum GelatoVerificationSessionObject {
     IdentityVerificationSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationSessionObject {
@@ -14838,7 +14838,7 @@ impl std::fmt::Display for GelatoVerificationSessionObject {
                 "identity.verification_session"
             }
             GelatoVerificationSessionObject::Noop => "",
-            GelatoVerificationSessionObject::FallthroughString => "*",
+            GelatoVerificationSessionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14870,8 +14870,8 @@ pub enum GelatoVerificationSessionStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationSessionStatus {
@@ -14882,7 +14882,7 @@ impl std::fmt::Display for GelatoVerificationSessionStatus {
             GelatoVerificationSessionStatus::RequiresInput => "requires_input",
             GelatoVerificationSessionStatus::Verified => "verified",
             GelatoVerificationSessionStatus::Noop => "",
-            GelatoVerificationSessionStatus::FallthroughString => "*",
+            GelatoVerificationSessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15135,8 +15135,8 @@ pub enum BillingReason {
     Upcoming,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingReason {
@@ -15152,7 +15152,7 @@ impl std::fmt::Display for BillingReason {
             BillingReason::SubscriptionUpdate => "subscription_update",
             BillingReason::Upcoming => "upcoming",
             BillingReason::Noop => "",
-            BillingReason::FallthroughString => "*",
+            BillingReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15180,8 +15180,8 @@ pub enum CollectionMethod {
     SendInvoice,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CollectionMethod {
@@ -15190,7 +15190,7 @@ impl std::fmt::Display for CollectionMethod {
             CollectionMethod::ChargeAutomatically => "charge_automatically",
             CollectionMethod::SendInvoice => "send_invoice",
             CollectionMethod::Noop => "",
-            CollectionMethod::FallthroughString => "*",
+            CollectionMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15363,8 +15363,8 @@ pub enum InvoiceStatus {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InvoiceStatus {
@@ -15377,7 +15377,7 @@ impl std::fmt::Display for InvoiceStatus {
             InvoiceStatus::Uncollectible => "uncollectible",
             InvoiceStatus::Void => "void",
             InvoiceStatus::Noop => "",
-            InvoiceStatus::FallthroughString => "*",
+            InvoiceStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17031,8 +17031,8 @@ pub enum AmountType {
     Maximum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AmountType {
@@ -17041,7 +17041,7 @@ impl std::fmt::Display for AmountType {
             AmountType::Fixed => "fixed",
             AmountType::Maximum => "maximum",
             AmountType::Noop => "",
-            AmountType::FallthroughString => "*",
+            AmountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17126,8 +17126,8 @@ pub enum PreferredLanguage {
     Nl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PreferredLanguage {
@@ -17138,7 +17138,7 @@ impl std::fmt::Display for PreferredLanguage {
             PreferredLanguage::Fr => "fr",
             PreferredLanguage::Nl => "nl",
             PreferredLanguage::Noop => "",
-            PreferredLanguage::FallthroughString => "*",
+            PreferredLanguage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17176,8 +17176,8 @@ pub enum RequestThreeDSecure {
     Automatic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequestThreeDSecure {
@@ -17186,7 +17186,7 @@ impl std::fmt::Display for RequestThreeDSecure {
             RequestThreeDSecure::Any => "any",
             RequestThreeDSecure::Automatic => "automatic",
             RequestThreeDSecure::Noop => "",
-            RequestThreeDSecure::FallthroughString => "*",
+            RequestThreeDSecure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17222,8 +17222,8 @@ pub enum FundingType {
     BankTransfer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FundingType {
@@ -17231,7 +17231,7 @@ impl std::fmt::Display for FundingType {
         match &*self {
             FundingType::BankTransfer => "bank_transfer",
             FundingType::Noop => "",
-            FundingType::FallthroughString => "*",
+            FundingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17787,8 +17787,8 @@ pub enum PaymentMethodTypes {
     WechatPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodTypes {
@@ -17814,7 +17814,7 @@ impl std::fmt::Display for PaymentMethodTypes {
             PaymentMethodTypes::UsBankAccount => "us_bank_account",
             PaymentMethodTypes::WechatPay => "wechat_pay",
             PaymentMethodTypes::Noop => "",
-            PaymentMethodTypes::FallthroughString => "*",
+            PaymentMethodTypes::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17949,8 +17949,8 @@ pub enum InvoicesResourceInvoiceTaxType {
     ZaVat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InvoicesResourceInvoiceTaxType {
@@ -18003,7 +18003,7 @@ impl std::fmt::Display for InvoicesResourceInvoiceTaxType {
             InvoicesResourceInvoiceTaxType::UsEin => "us_ein",
             InvoicesResourceInvoiceTaxType::ZaVat => "za_vat",
             InvoicesResourceInvoiceTaxType::Noop => "",
-            InvoicesResourceInvoiceTaxType::FallthroughString => "*",
+            InvoicesResourceInvoiceTaxType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18093,8 +18093,8 @@ pub enum IssuerFraudRecordObject {
     IssuerFraudRecord,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuerFraudRecordObject {
@@ -18102,7 +18102,7 @@ impl std::fmt::Display for IssuerFraudRecordObject {
         match &*self {
             IssuerFraudRecordObject::IssuerFraudRecord => "issuer_fraud_record",
             IssuerFraudRecordObject::Noop => "",
-            IssuerFraudRecordObject::FallthroughString => "*",
+            IssuerFraudRecordObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18210,8 +18210,8 @@ pub enum AuthorizationMethod {
     Swipe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationMethod {
@@ -18223,7 +18223,7 @@ impl std::fmt::Display for AuthorizationMethod {
             AuthorizationMethod::Online => "online",
             AuthorizationMethod::Swipe => "swipe",
             AuthorizationMethod::Noop => "",
-            AuthorizationMethod::FallthroughString => "*",
+            AuthorizationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18299,8 +18299,8 @@ pub enum IssuingAuthorizationObject {
     IssuingAuthorization,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationObject {
@@ -18308,7 +18308,7 @@ impl std::fmt::Display for IssuingAuthorizationObject {
         match &*self {
             IssuingAuthorizationObject::IssuingAuthorization => "issuing.authorization",
             IssuingAuthorizationObject::Noop => "",
-            IssuingAuthorizationObject::FallthroughString => "*",
+            IssuingAuthorizationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18338,8 +18338,8 @@ pub enum IssuingAuthorizationStatus {
     Reversed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationStatus {
@@ -18349,7 +18349,7 @@ impl std::fmt::Display for IssuingAuthorizationStatus {
             IssuingAuthorizationStatus::Pending => "pending",
             IssuingAuthorizationStatus::Reversed => "reversed",
             IssuingAuthorizationStatus::Noop => "",
-            IssuingAuthorizationStatus::FallthroughString => "*",
+            IssuingAuthorizationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18554,8 +18554,8 @@ pub enum CancellationReason {
     Stolen,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CancellationReason {
@@ -18564,7 +18564,7 @@ impl std::fmt::Display for CancellationReason {
             CancellationReason::Lost => "lost",
             CancellationReason::Stolen => "stolen",
             CancellationReason::Noop => "",
-            CancellationReason::FallthroughString => "*",
+            CancellationReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18590,8 +18590,8 @@ pub enum IssuingCardObject {
     IssuingCard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardObject {
@@ -18599,7 +18599,7 @@ impl std::fmt::Display for IssuingCardObject {
         match &*self {
             IssuingCardObject::IssuingCard => "issuing.card",
             IssuingCardObject::Noop => "",
-            IssuingCardObject::FallthroughString => "*",
+            IssuingCardObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18679,8 +18679,8 @@ pub enum ReplacementReason {
     Stolen,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReplacementReason {
@@ -18691,7 +18691,7 @@ impl std::fmt::Display for ReplacementReason {
             ReplacementReason::Lost => "lost",
             ReplacementReason::Stolen => "stolen",
             ReplacementReason::Noop => "",
-            ReplacementReason::FallthroughString => "*",
+            ReplacementReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18721,8 +18721,8 @@ pub enum IssuingCardStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardStatus {
@@ -18732,7 +18732,7 @@ impl std::fmt::Display for IssuingCardStatus {
             IssuingCardStatus::Canceled => "canceled",
             IssuingCardStatus::Inactive => "inactive",
             IssuingCardStatus::Noop => "",
-            IssuingCardStatus::FallthroughString => "*",
+            IssuingCardStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18760,8 +18760,8 @@ pub enum IssuingCardType {
     Virtual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardType {
@@ -18770,7 +18770,7 @@ impl std::fmt::Display for IssuingCardType {
             IssuingCardType::Physical => "physical",
             IssuingCardType::Virtual => "virtual",
             IssuingCardType::Noop => "",
-            IssuingCardType::FallthroughString => "*",
+            IssuingCardType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18961,8 +18961,8 @@ pub enum IssuingCardholderObject {
     IssuingCardholder,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardholderObject {
@@ -18970,7 +18970,7 @@ impl std::fmt::Display for IssuingCardholderObject {
         match &*self {
             IssuingCardholderObject::IssuingCardholder => "issuing.cardholder",
             IssuingCardholderObject::Noop => "",
-            IssuingCardholderObject::FallthroughString => "*",
+            IssuingCardholderObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19000,8 +19000,8 @@ pub enum IssuingCardholderStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardholderStatus {
@@ -19011,7 +19011,7 @@ impl std::fmt::Display for IssuingCardholderStatus {
             IssuingCardholderStatus::Blocked => "blocked",
             IssuingCardholderStatus::Inactive => "inactive",
             IssuingCardholderStatus::Noop => "",
-            IssuingCardholderStatus::FallthroughString => "*",
+            IssuingCardholderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19039,8 +19039,8 @@ pub enum AccountHolderType {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountHolderType {
@@ -19049,7 +19049,7 @@ impl std::fmt::Display for AccountHolderType {
             AccountHolderType::Company => "company",
             AccountHolderType::Individual => "individual",
             AccountHolderType::Noop => "",
-            AccountHolderType::FallthroughString => "*",
+            AccountHolderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19192,8 +19192,8 @@ pub enum IssuingDisputeObject {
     IssuingDispute,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingDisputeObject {
@@ -19201,7 +19201,7 @@ impl std::fmt::Display for IssuingDisputeObject {
         match &*self {
             IssuingDisputeObject::IssuingDispute => "issuing.dispute",
             IssuingDisputeObject::Noop => "",
-            IssuingDisputeObject::FallthroughString => "*",
+            IssuingDisputeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19235,8 +19235,8 @@ pub enum IssuingDisputeStatus {
     Won,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingDisputeStatus {
@@ -19248,7 +19248,7 @@ impl std::fmt::Display for IssuingDisputeStatus {
             IssuingDisputeStatus::Unsubmitted => "unsubmitted",
             IssuingDisputeStatus::Won => "won",
             IssuingDisputeStatus::Noop => "",
-            IssuingDisputeStatus::FallthroughString => "*",
+            IssuingDisputeStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19414,8 +19414,8 @@ pub enum Network {
     Visa,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Network {
@@ -19423,7 +19423,7 @@ impl std::fmt::Display for Network {
         match &*self {
             Network::Visa => "visa",
             Network::Noop => "",
-            Network::FallthroughString => "*",
+            Network::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19449,8 +19449,8 @@ pub enum IssuingSettlementObject {
     IssuingSettlement,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingSettlementObject {
@@ -19458,7 +19458,7 @@ impl std::fmt::Display for IssuingSettlementObject {
         match &*self {
             IssuingSettlementObject::IssuingSettlement => "issuing.settlement",
             IssuingSettlementObject::Noop => "",
-            IssuingSettlementObject::FallthroughString => "*",
+            IssuingSettlementObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19726,8 +19726,8 @@ pub enum IssuingTransactionObject {
     IssuingTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingTransactionObject {
@@ -19735,7 +19735,7 @@ impl std::fmt::Display for IssuingTransactionObject {
         match &*self {
             IssuingTransactionObject::IssuingTransaction => "issuing.transaction",
             IssuingTransactionObject::Noop => "",
-            IssuingTransactionObject::FallthroughString => "*",
+            IssuingTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19763,8 +19763,8 @@ pub enum IssuingTransactionType {
     Refund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingTransactionType {
@@ -19773,7 +19773,7 @@ impl std::fmt::Display for IssuingTransactionType {
             IssuingTransactionType::Capture => "capture",
             IssuingTransactionType::Refund => "refund",
             IssuingTransactionType::Noop => "",
-            IssuingTransactionType::FallthroughString => "*",
+            IssuingTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19803,8 +19803,8 @@ pub enum Wallet {
     SamsungPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Wallet {
@@ -19814,7 +19814,7 @@ impl std::fmt::Display for Wallet {
             Wallet::GooglePay => "google_pay",
             Wallet::SamsungPay => "samsung_pay",
             Wallet::Noop => "",
-            Wallet::FallthroughString => "*",
+            Wallet::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20075,7 +20075,7 @@ pub struct IssuingAuthorizationMerchantData {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuingAuthorizationPendingRequest {
     /**
     * Time at which the account was connected. Measured in seconds since the Unix epoch.
@@ -20125,6 +20125,57 @@ pub struct IssuingAuthorizationPendingRequest {
     pub merchant_currency: String,
 }
 
+impl IssuingAuthorizationPendingRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuingAuthorizationPendingRequestBuilder {
+        IssuingAuthorizationPendingRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuingAuthorizationPendingRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuingAuthorizationPendingRequestBuilder(IssuingAuthorizationPendingRequest);
+
+impl IssuingAuthorizationPendingRequestBuilder {
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.0.amount = amount;
+        self
+    }
+
+    pub fn amount_details(
+        mut self,
+        amount_details: impl Into<IssuingAuthorizationAmountDetails>,
+    ) -> Self {
+        self.0.amount_details = Some(amount_details.into());
+        self
+    }
+
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.0.currency = currency.into();
+        self
+    }
+
+    pub fn is_amount_controllable(mut self, is_amount_controllable: bool) -> Self {
+        self.0.is_amount_controllable = Some(is_amount_controllable);
+        self
+    }
+
+    pub fn merchant_amount(mut self, merchant_amount: i64) -> Self {
+        self.0.merchant_amount = merchant_amount;
+        self
+    }
+
+    pub fn merchant_currency(mut self, merchant_currency: impl Into<String>) -> Self {
+        self.0.merchant_currency = merchant_currency.into();
+        self
+    }
+
+    pub fn build(self) -> IssuingAuthorizationPendingRequest {
+        self.0
+    }
+}
+
 /**
 * The reason for the approval or decline.
 */
@@ -20158,8 +20209,8 @@ pub enum IssuingAuthorizationRequestReason {
     WebhookTimeout,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationRequestReason {
@@ -20181,7 +20232,7 @@ impl std::fmt::Display for IssuingAuthorizationRequestReason {
             IssuingAuthorizationRequestReason::WebhookDeclined => "webhook_declined",
             IssuingAuthorizationRequestReason::WebhookTimeout => "webhook_timeout",
             IssuingAuthorizationRequestReason::Noop => "",
-            IssuingAuthorizationRequestReason::FallthroughString => "*",
+            IssuingAuthorizationRequestReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20199,7 +20250,7 @@ impl IssuingAuthorizationRequestReason {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuingAuthorizationRequest {
     /**
     * Time at which the account was connected. Measured in seconds since the Unix epoch.
@@ -20266,6 +20317,67 @@ pub struct IssuingAuthorizationRequest {
     pub reason: IssuingAuthorizationRequestReason,
 }
 
+impl IssuingAuthorizationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> IssuingAuthorizationRequestBuilder {
+        IssuingAuthorizationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `IssuingAuthorizationRequest`.
+#[derive(Debug, Clone)]
+pub struct IssuingAuthorizationRequestBuilder(IssuingAuthorizationRequest);
+
+impl IssuingAuthorizationRequestBuilder {
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.0.amount = amount;
+        self
+    }
+
+    pub fn amount_details(
+        mut self,
+        amount_details: impl Into<IssuingAuthorizationAmountDetails>,
+    ) -> Self {
+        self.0.amount_details = Some(amount_details.into());
+        self
+    }
+
+    pub fn approved(mut self, approved: bool) -> Self {
+        self.0.approved = Some(approved);
+        self
+    }
+
+    pub fn created(mut self, created: i64) -> Self {
+        self.0.created = created;
+        self
+    }
+
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.0.currency = currency.into();
+        self
+    }
+
+    pub fn merchant_amount(mut self, merchant_amount: i64) -> Self {
+        self.0.merchant_amount = merchant_amount;
+        self
+    }
+
+    pub fn merchant_currency(mut self, merchant_currency: impl Into<String>) -> Self {
+        self.0.merchant_currency = merchant_currency.into();
+        self
+    }
+
+    pub fn reason(mut self, reason: impl Into<IssuingAuthorizationRequestReason>) -> Self {
+        self.0.reason = reason.into();
+        self
+    }
+
+    pub fn build(self) -> IssuingAuthorizationRequest {
+        self.0
+    }
+}
+
 /**
 * Whether the cardholder provided a CVC and if it matched Stripe’s record.
 */
@@ -20279,8 +20391,8 @@ pub enum CvcCheck {
     NotProvided,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CvcCheck {
@@ -20290,7 +20402,7 @@ impl std::fmt::Display for CvcCheck {
             CvcCheck::Mismatch => "mismatch",
             CvcCheck::NotProvided => "not_provided",
             CvcCheck::Noop => "",
-            CvcCheck::FallthroughString => "*",
+            CvcCheck::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20349,8 +20461,8 @@ pub enum IneligibleReason {
     UnsupportedRegion,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IneligibleReason {
@@ -20360,7 +20472,7 @@ impl std::fmt::Display for IneligibleReason {
             IneligibleReason::MissingCardholderContact => "missing_cardholder_contact",
             IneligibleReason::UnsupportedRegion => "unsupported_region",
             IneligibleReason::Noop => "",
-            IneligibleReason::FallthroughString => "*",
+            IneligibleReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20975,8 +21087,8 @@ pub enum Categories {
     WreckingAndSalvageYards,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Categories {
@@ -21339,7 +21451,7 @@ impl std::fmt::Display for Categories {
             Categories::WomensReadyToWearStores => "womens_ready_to_wear_stores",
             Categories::WreckingAndSalvageYards => "wrecking_and_salvage_yards",
             Categories::Noop => "",
-            Categories::FallthroughString => "*",
+            Categories::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21412,8 +21524,8 @@ pub enum Carrier {
     Usps,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Carrier {
@@ -21424,7 +21536,7 @@ impl std::fmt::Display for Carrier {
             Carrier::RoyalMail => "royal_mail",
             Carrier::Usps => "usps",
             Carrier::Noop => "",
-            Carrier::FallthroughString => "*",
+            Carrier::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21454,8 +21566,8 @@ pub enum Service {
     Standard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Service {
@@ -21465,7 +21577,7 @@ impl std::fmt::Display for Service {
             Service::Priority => "priority",
             Service::Standard => "standard",
             Service::Noop => "",
-            Service::FallthroughString => "*",
+            Service::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21501,8 +21613,8 @@ pub enum IssuingCardShippingStatus {
     Shipped,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardShippingStatus {
@@ -21515,7 +21627,7 @@ impl std::fmt::Display for IssuingCardShippingStatus {
             IssuingCardShippingStatus::Returned => "returned",
             IssuingCardShippingStatus::Shipped => "shipped",
             IssuingCardShippingStatus::Noop => "",
-            IssuingCardShippingStatus::FallthroughString => "*",
+            IssuingCardShippingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21543,8 +21655,8 @@ pub enum IssuingCardShippingType {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardShippingType {
@@ -21553,7 +21665,7 @@ impl std::fmt::Display for IssuingCardShippingType {
             IssuingCardShippingType::Bulk => "bulk",
             IssuingCardShippingType::Individual => "individual",
             IssuingCardShippingType::Noop => "",
-            IssuingCardShippingType::FallthroughString => "*",
+            IssuingCardShippingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21659,8 +21771,8 @@ pub enum Interval {
     Yearly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Interval {
@@ -21673,7 +21785,7 @@ impl std::fmt::Display for Interval {
             Interval::Weekly => "weekly",
             Interval::Yearly => "yearly",
             Interval::Noop => "",
-            Interval::FallthroughString => "*",
+            Interval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21899,8 +22011,8 @@ pub enum DisabledReason {
     UnderReview,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DisabledReason {
@@ -21910,7 +22022,7 @@ impl std::fmt::Display for DisabledReason {
             DisabledReason::RejectedListed => "rejected.listed",
             DisabledReason::UnderReview => "under_review",
             DisabledReason::Noop => "",
-            DisabledReason::FallthroughString => "*",
+            DisabledReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21945,8 +22057,8 @@ pub enum PastDue {
     IndividualVerificationDocument,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PastDue {
@@ -21960,7 +22072,7 @@ impl std::fmt::Display for PastDue {
             PastDue::IndividualLastName => "individual.last_name",
             PastDue::IndividualVerificationDocument => "individual.verification.document",
             PastDue::Noop => "",
-            PastDue::FallthroughString => "*",
+            PastDue::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22017,8 +22129,8 @@ pub enum ProductType {
     Service,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductType {
@@ -22027,7 +22139,7 @@ impl std::fmt::Display for ProductType {
             ProductType::Merchandise => "merchandise",
             ProductType::Service => "service",
             ProductType::Noop => "",
-            ProductType::FallthroughString => "*",
+            ProductType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22055,8 +22167,8 @@ pub enum ReturnStatus {
     Successful,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -22065,7 +22177,7 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::MerchantRejected => "merchant_rejected",
             ReturnStatus::Successful => "successful",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22228,8 +22340,8 @@ pub enum IssuingDisputeEvidenceReason {
     ServiceNotAsDescribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingDisputeEvidenceReason {
@@ -22245,7 +22357,7 @@ impl std::fmt::Display for IssuingDisputeEvidenceReason {
             IssuingDisputeEvidenceReason::Other => "other",
             IssuingDisputeEvidenceReason::ServiceNotAsDescribed => "service_not_as_described",
             IssuingDisputeEvidenceReason::Noop => "",
-            IssuingDisputeEvidenceReason::FallthroughString => "*",
+            IssuingDisputeEvidenceReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22772,8 +22884,8 @@ pub enum ItemObject {
     Item,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ItemObject {
@@ -22781,7 +22893,7 @@ impl std::fmt::Display for ItemObject {
         match &*self {
             ItemObject::Item => "item",
             ItemObject::Noop => "",
-            ItemObject::FallthroughString => "*",
+            ItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22932,8 +23044,8 @@ pub enum Structure {
     UnincorporatedNonProfit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Structure {
@@ -22962,7 +23074,7 @@ impl std::fmt::Display for Structure {
             Structure::UnincorporatedAssociation => "unincorporated_association",
             Structure::UnincorporatedNonProfit => "unincorporated_non_profit",
             Structure::Noop => "",
-            Structure::FallthroughString => "*",
+            Structure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23360,8 +23472,8 @@ pub enum LineItemObject {
     LineItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineItemObject {
@@ -23369,7 +23481,7 @@ impl std::fmt::Display for LineItemObject {
         match &*self {
             LineItemObject::LineItem => "line_item",
             LineItemObject::Noop => "",
-            LineItemObject::FallthroughString => "*",
+            LineItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23397,8 +23509,8 @@ pub enum LineItemType {
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineItemType {
@@ -23407,7 +23519,7 @@ impl std::fmt::Display for LineItemType {
             LineItemType::Invoiceitem => "invoiceitem",
             LineItemType::Subscription => "subscription",
             LineItemType::Noop => "",
-            LineItemType::FallthroughString => "*",
+            LineItemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23648,8 +23760,8 @@ pub enum LoginLinkObject {
     LoginLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LoginLinkObject {
@@ -23657,7 +23769,7 @@ impl std::fmt::Display for LoginLinkObject {
         match &*self {
             LoginLinkObject::LoginLink => "login_link",
             LoginLinkObject::Noop => "",
-            LoginLinkObject::FallthroughString => "*",
+            LoginLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23711,8 +23823,8 @@ pub enum MandateObject {
     Mandate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MandateObject {
@@ -23720,7 +23832,7 @@ impl std::fmt::Display for MandateObject {
         match &*self {
             MandateObject::Mandate => "mandate",
             MandateObject::Noop => "",
-            MandateObject::FallthroughString => "*",
+            MandateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23748,8 +23860,8 @@ pub enum MandateType {
     SingleUse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MandateType {
@@ -23758,7 +23870,7 @@ impl std::fmt::Display for MandateType {
             MandateType::MultiUse => "multi_use",
             MandateType::SingleUse => "single_use",
             MandateType::Noop => "",
-            MandateType::FallthroughString => "*",
+            MandateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23896,8 +24008,8 @@ pub enum NetworkStatus {
     Revoked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkStatus {
@@ -23908,7 +24020,7 @@ impl std::fmt::Display for NetworkStatus {
             NetworkStatus::Refused => "refused",
             NetworkStatus::Revoked => "revoked",
             NetworkStatus::Noop => "",
-            NetworkStatus::FallthroughString => "*",
+            NetworkStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24083,7 +24195,7 @@ pub struct NotificationEventData {
 }
 
 ///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NotificationEventRequest {
     /**
     *
@@ -24105,6 +24217,34 @@ pub struct NotificationEventRequest {
     pub idempotency_key: String,
 }
 
+impl NotificationEventRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> NotificationEventRequestBuilder {
+        NotificationEventRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `NotificationEventRequest`.
+#[derive(Debug, Clone)]
+pub struct NotificationEventRequestBuilder(NotificationEventRequest);
+
+impl NotificationEventRequestBuilder {
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.0.id = id.into();
+        self
+    }
+
+    pub fn idempotency_key(mut self, idempotency_key: impl Into<String>) -> Self {
+        self.0.idempotency_key = idempotency_key.into();
+        self
+    }
+
+    pub fn build(self) -> NotificationEventRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OnlineAcceptance {
@@ -24137,8 +24277,8 @@ pub enum OrderObject {
     Order,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderObject {
@@ -24146,7 +24286,7 @@ impl std::fmt::Display for OrderObject {
         match &*self {
             OrderObject::Order => "order",
             OrderObject::Noop => "",
-            OrderObject::FallthroughString => "*",
+            OrderObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24443,8 +24583,8 @@ pub enum OrderItemObject {
     OrderItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderItemObject {
@@ -24452,7 +24592,7 @@ impl std::fmt::Display for OrderItemObject {
         match &*self {
             OrderItemObject::OrderItem => "order_item",
             OrderItemObject::Noop => "",
-            OrderItemObject::FallthroughString => "*",
+            OrderItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24601,8 +24741,8 @@ pub enum OrderReturnObject {
     OrderReturn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderReturnObject {
@@ -24610,7 +24750,7 @@ impl std::fmt::Display for OrderReturnObject {
         match &*self {
             OrderReturnObject::OrderReturn => "order_return",
             OrderReturnObject::Noop => "",
-            OrderReturnObject::FallthroughString => "*",
+            OrderReturnObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24848,8 +24988,8 @@ pub enum PaymentIntentCancellationReason {
     VoidInvoice,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentCancellationReason {
@@ -24863,7 +25003,7 @@ impl std::fmt::Display for PaymentIntentCancellationReason {
             PaymentIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
             PaymentIntentCancellationReason::VoidInvoice => "void_invoice",
             PaymentIntentCancellationReason::Noop => "",
-            PaymentIntentCancellationReason::FallthroughString => "*",
+            PaymentIntentCancellationReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24891,8 +25031,8 @@ pub enum CaptureMethod {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CaptureMethod {
@@ -24901,7 +25041,7 @@ impl std::fmt::Display for CaptureMethod {
             CaptureMethod::Automatic => "automatic",
             CaptureMethod::Manual => "manual",
             CaptureMethod::Noop => "",
-            CaptureMethod::FallthroughString => "*",
+            CaptureMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24963,8 +25103,8 @@ pub enum PaymentIntentObject {
     PaymentIntent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentObject {
@@ -24972,7 +25112,7 @@ impl std::fmt::Display for PaymentIntentObject {
         match &*self {
             PaymentIntentObject::PaymentIntent => "payment_intent",
             PaymentIntentObject::Noop => "",
-            PaymentIntentObject::FallthroughString => "*",
+            PaymentIntentObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25004,8 +25144,8 @@ pub enum SetupFutureUsage {
     OnSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupFutureUsage {
@@ -25014,7 +25154,7 @@ impl std::fmt::Display for SetupFutureUsage {
             SetupFutureUsage::OffSession => "off_session",
             SetupFutureUsage::OnSession => "on_session",
             SetupFutureUsage::Noop => "",
-            SetupFutureUsage::FallthroughString => "*",
+            SetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25052,8 +25192,8 @@ pub enum PaymentIntentStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentStatus {
@@ -25067,7 +25207,7 @@ impl std::fmt::Display for PaymentIntentStatus {
             PaymentIntentStatus::RequiresPaymentMethod => "requires_payment_method",
             PaymentIntentStatus::Succeeded => "succeeded",
             PaymentIntentStatus::Noop => "",
-            PaymentIntentStatus::FallthroughString => "*",
+            PaymentIntentStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25968,8 +26108,8 @@ pub enum MicrodepositType {
     DescriptorCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MicrodepositType {
@@ -25978,7 +26118,7 @@ impl std::fmt::Display for MicrodepositType {
             MicrodepositType::Amounts => "amounts",
             MicrodepositType::DescriptorCode => "descriptor_code",
             MicrodepositType::Noop => "",
-            MicrodepositType::FallthroughString => "*",
+            MicrodepositType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27213,8 +27353,8 @@ pub enum PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
     OnSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
@@ -27224,7 +27364,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OffSession => "off_session",
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OnSession => "on_session",
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::Noop => "",
-            PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::FallthroughString => "*",
+            PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27283,8 +27423,8 @@ pub enum PaymentIntentMethodOptionsCardCapture {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardCapture {
@@ -27292,7 +27432,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardCapture {
         match &*self {
             PaymentIntentMethodOptionsCardCapture::Manual => "manual",
             PaymentIntentMethodOptionsCardCapture::Noop => "",
-            PaymentIntentMethodOptionsCardCapture::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardCapture::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27336,8 +27476,8 @@ pub enum PaymentIntentMethodOptionsCardNetwork {
     Visa,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardNetwork {
@@ -27354,7 +27494,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardNetwork {
             PaymentIntentMethodOptionsCardNetwork::Unknown => "unknown",
             PaymentIntentMethodOptionsCardNetwork::Visa => "visa",
             PaymentIntentMethodOptionsCardNetwork::Noop => "",
-            PaymentIntentMethodOptionsCardNetwork::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardNetwork::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27384,8 +27524,8 @@ pub enum PaymentIntentMethodOptionsCardRequestThreeDSecure {
     ChallengeOnly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardRequestThreeDSecure {
@@ -27395,7 +27535,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardRequestThreeDSecure {
             PaymentIntentMethodOptionsCardRequestThreeDSecure::Automatic => "automatic",
             PaymentIntentMethodOptionsCardRequestThreeDSecure::ChallengeOnly => "challenge_only",
             PaymentIntentMethodOptionsCardRequestThreeDSecure::Noop => "",
-            PaymentIntentMethodOptionsCardRequestThreeDSecure::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardRequestThreeDSecure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27465,8 +27605,8 @@ pub enum PaymentIntentMethodOptionsEpsSetupFutureUsage {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsEpsSetupFutureUsage {
@@ -27474,7 +27614,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsEpsSetupFutureUsage {
         match &*self {
             PaymentIntentMethodOptionsEpsSetupFutureUsage::None => "none",
             PaymentIntentMethodOptionsEpsSetupFutureUsage::Noop => "",
-            PaymentIntentMethodOptionsEpsSetupFutureUsage::FallthroughString => "*",
+            PaymentIntentMethodOptionsEpsSetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27630,8 +27770,8 @@ pub enum PaymentLinkObject {
     PaymentLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentLinkObject {
@@ -27639,7 +27779,7 @@ impl std::fmt::Display for PaymentLinkObject {
         match &*self {
             PaymentLinkObject::PaymentLink => "payment_link",
             PaymentLinkObject::Noop => "",
-            PaymentLinkObject::FallthroughString => "*",
+            PaymentLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27816,8 +27956,8 @@ pub enum PaymentLinksResourceAfterCompletionType {
     Redirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentLinksResourceAfterCompletionType {
@@ -27826,7 +27966,7 @@ impl std::fmt::Display for PaymentLinksResourceAfterCompletionType {
             PaymentLinksResourceAfterCompletionType::HostedConfirmation => "hosted_confirmation",
             PaymentLinksResourceAfterCompletionType::Redirect => "redirect",
             PaymentLinksResourceAfterCompletionType::Noop => "",
-            PaymentLinksResourceAfterCompletionType::FallthroughString => "*",
+            PaymentLinksResourceAfterCompletionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28359,8 +28499,8 @@ pub enum AllowedCountries {
     Zz,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedCountries {
@@ -28604,7 +28744,7 @@ impl std::fmt::Display for AllowedCountries {
             AllowedCountries::Zw => "ZW",
             AllowedCountries::Zz => "ZZ",
             AllowedCountries::Noop => "",
-            AllowedCountries::FallthroughString => "*",
+            AllowedCountries::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28674,8 +28814,8 @@ pub enum PaymentMethodObject {
     PaymentMethod,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodObject {
@@ -28683,7 +28823,7 @@ impl std::fmt::Display for PaymentMethodObject {
         match &*self {
             PaymentMethodObject::PaymentMethod => "payment_method",
             PaymentMethodObject::Noop => "",
-            PaymentMethodObject::FallthroughString => "*",
+            PaymentMethodObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28755,8 +28895,8 @@ pub enum PaymentMethodType {
     WechatPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodType {
@@ -28787,7 +28927,7 @@ impl std::fmt::Display for PaymentMethodType {
             PaymentMethodType::UsBankAccount => "us_bank_account",
             PaymentMethodType::WechatPay => "wechat_pay",
             PaymentMethodType::Noop => "",
-            PaymentMethodType::FallthroughString => "*",
+            PaymentMethodType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29448,8 +29588,8 @@ pub enum PaymentMethodCardWalletType {
     VisaCheckout,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodCardWalletType {
@@ -29462,7 +29602,7 @@ impl std::fmt::Display for PaymentMethodCardWalletType {
             PaymentMethodCardWalletType::SamsungPay => "samsung_pay",
             PaymentMethodCardWalletType::VisaCheckout => "visa_checkout",
             PaymentMethodCardWalletType::Noop => "",
-            PaymentMethodCardWalletType::FallthroughString => "*",
+            PaymentMethodCardWalletType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30290,8 +30430,8 @@ pub enum PaymentMethodDetailsCardInstallmentsPlanInterval {
     Month,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanInterval {
@@ -30299,7 +30439,7 @@ impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanInterval {
         match &*self {
             PaymentMethodDetailsCardInstallmentsPlanInterval::Month => "month",
             PaymentMethodDetailsCardInstallmentsPlanInterval::Noop => "",
-            PaymentMethodDetailsCardInstallmentsPlanInterval::FallthroughString => "*",
+            PaymentMethodDetailsCardInstallmentsPlanInterval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30325,8 +30465,8 @@ pub enum PaymentMethodDetailsCardInstallmentsPlanType {
     FixedCount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanType {
@@ -30334,7 +30474,7 @@ impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanType {
         match &*self {
             PaymentMethodDetailsCardInstallmentsPlanType::FixedCount => "fixed_count",
             PaymentMethodDetailsCardInstallmentsPlanType::Noop => "",
-            PaymentMethodDetailsCardInstallmentsPlanType::FallthroughString => "*",
+            PaymentMethodDetailsCardInstallmentsPlanType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30397,8 +30537,8 @@ pub enum ReadMethod {
     MagneticStripeTrack2,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReadMethod {
@@ -30410,7 +30550,7 @@ impl std::fmt::Display for ReadMethod {
             ReadMethod::MagneticStripeFallback => "magnetic_stripe_fallback",
             ReadMethod::MagneticStripeTrack2 => "magnetic_stripe_track2",
             ReadMethod::Noop => "",
-            ReadMethod::FallthroughString => "*",
+            ReadMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30583,8 +30723,8 @@ pub enum AccountType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -30595,7 +30735,7 @@ impl std::fmt::Display for AccountType {
             AccountType::Prepaid => "prepaid",
             AccountType::Unknown => "unknown",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30875,8 +31015,8 @@ pub enum Bank {
     VrBankBraunau,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Bank {
@@ -30910,7 +31050,7 @@ impl std::fmt::Display for Bank {
             Bank::VolkskreditbankAg => "volkskreditbank_ag",
             Bank::VrBankBraunau => "vr_bank_braunau",
             Bank::Noop => "",
-            Bank::FallthroughString => "*",
+            Bank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30995,8 +31135,8 @@ pub enum PaymentMethodDetailsFpxBank {
     Uob,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsFpxBank {
@@ -31024,7 +31164,7 @@ impl std::fmt::Display for PaymentMethodDetailsFpxBank {
             PaymentMethodDetailsFpxBank::StandardChartered => "standard_chartered",
             PaymentMethodDetailsFpxBank::Uob => "uob",
             PaymentMethodDetailsFpxBank::Noop => "",
-            PaymentMethodDetailsFpxBank::FallthroughString => "*",
+            PaymentMethodDetailsFpxBank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31148,8 +31288,8 @@ pub enum PaymentMethodDetailsIdealBank {
     VanLanschot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsIdealBank {
@@ -31169,7 +31309,7 @@ impl std::fmt::Display for PaymentMethodDetailsIdealBank {
             PaymentMethodDetailsIdealBank::TriodosBank => "triodos_bank",
             PaymentMethodDetailsIdealBank::VanLanschot => "van_lanschot",
             PaymentMethodDetailsIdealBank::Noop => "",
-            PaymentMethodDetailsIdealBank::FallthroughString => "*",
+            PaymentMethodDetailsIdealBank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31219,8 +31359,8 @@ pub enum Bic {
     Trionl2U,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Bic {
@@ -31240,7 +31380,7 @@ impl std::fmt::Display for Bic {
             Bic::Snsbnl2A => "SNSBNL2A",
             Bic::Trionl2U => "TRIONL2U",
             Bic::Noop => "",
-            Bic::FallthroughString => "*",
+            Bic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31438,8 +31578,8 @@ pub enum PaymentMethodDetailsInteracPresentReceiptAccountType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsInteracPresentReceiptAccountType {
@@ -31449,7 +31589,7 @@ impl std::fmt::Display for PaymentMethodDetailsInteracPresentReceiptAccountType
             PaymentMethodDetailsInteracPresentReceiptAccountType::Savings => "savings",
             PaymentMethodDetailsInteracPresentReceiptAccountType::Unknown => "unknown",
             PaymentMethodDetailsInteracPresentReceiptAccountType::Noop => "",
-            PaymentMethodDetailsInteracPresentReceiptAccountType::FallthroughString => "*",
+            PaymentMethodDetailsInteracPresentReceiptAccountType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31599,8 +31739,8 @@ pub enum Chain {
     Seicomart,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Chain {
@@ -31611,7 +31751,7 @@ impl std::fmt::Display for Chain {
             Chain::Ministop => "ministop",
             Chain::Seicomart => "seicomart",
             Chain::Noop => "",
-            Chain::FallthroughString => "*",
+            Chain::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31732,8 +31872,8 @@ pub enum PaymentMethodDetailsP24Bank {
     VolkswagenBank,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsP24Bank {
@@ -31765,7 +31905,7 @@ impl std::fmt::Display for PaymentMethodDetailsP24Bank {
             PaymentMethodDetailsP24Bank::ToyotaBank => "toyota_bank",
             PaymentMethodDetailsP24Bank::VolkswagenBank => "volkswagen_bank",
             PaymentMethodDetailsP24Bank::Noop => "",
-            PaymentMethodDetailsP24Bank::FallthroughString => "*",
+            PaymentMethodDetailsP24Bank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31906,8 +32046,8 @@ pub enum PaymentMethodDetailsSofortPreferredLanguage {
     Pl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsSofortPreferredLanguage {
@@ -31921,7 +32061,7 @@ impl std::fmt::Display for PaymentMethodDetailsSofortPreferredLanguage {
             PaymentMethodDetailsSofortPreferredLanguage::Nl => "nl",
             PaymentMethodDetailsSofortPreferredLanguage::Pl => "pl",
             PaymentMethodDetailsSofortPreferredLanguage::Noop => "",
-            PaymentMethodDetailsSofortPreferredLanguage::FallthroughString => "*",
+            PaymentMethodDetailsSofortPreferredLanguage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32025,8 +32165,8 @@ pub enum PaymentMethodDetailsUsBankAccountType {
     Savings,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsUsBankAccountType {
@@ -32035,7 +32175,7 @@ impl std::fmt::Display for PaymentMethodDetailsUsBankAccountType {
             PaymentMethodDetailsUsBankAccountType::Checking => "checking",
             PaymentMethodDetailsUsBankAccountType::Savings => "savings",
             PaymentMethodDetailsUsBankAccountType::Noop => "",
-            PaymentMethodDetailsUsBankAccountType::FallthroughString => "*",
+            PaymentMethodDetailsUsBankAccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32211,8 +32351,8 @@ pub enum PaymentMethodOptionsAlipaySetupFutureUsage {
     OffSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodOptionsAlipaySetupFutureUsage {
@@ -32221,7 +32361,7 @@ impl std::fmt::Display for PaymentMethodOptionsAlipaySetupFutureUsage {
             PaymentMethodOptionsAlipaySetupFutureUsage::None => "none",
             PaymentMethodOptionsAlipaySetupFutureUsage::OffSession => "off_session",
             PaymentMethodOptionsAlipaySetupFutureUsage::Noop => "",
-            PaymentMethodOptionsAlipaySetupFutureUsage::FallthroughString => "*",
+            PaymentMethodOptionsAlipaySetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32336,8 +32476,8 @@ pub enum PaymentMethodOptionsCardMandateInterval {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodOptionsCardMandateInterval {
@@ -32349,7 +32489,7 @@ impl std::fmt::Display for PaymentMethodOptionsCardMandateInterval {
             PaymentMethodOptionsCardMandateInterval::Week => "week",
             PaymentMethodOptionsCardMandateInterval::Year => "year",
             PaymentMethodOptionsCardMandateInterval::Noop => "",
-            PaymentMethodOptionsCardMandateInterval::FallthroughString => "*",
+            PaymentMethodOptionsCardMandateInterval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32372,8 +32512,8 @@ pub enum SupportedTypes {
     India,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SupportedTypes {
@@ -32381,7 +32521,7 @@ impl std::fmt::Display for SupportedTypes {
         match &*self {
             SupportedTypes::India => "india",
             SupportedTypes::Noop => "",
-            SupportedTypes::FallthroughString => "*",
+            SupportedTypes::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32670,8 +32810,8 @@ pub enum Client {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Client {
@@ -32681,7 +32821,7 @@ impl std::fmt::Display for Client {
             Client::Ios => "ios",
             Client::Web => "web",
             Client::Noop => "",
-            Client::FallthroughString => "*",
+            Client::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32915,8 +33055,8 @@ pub enum Promotions {
     OptOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Promotions {
@@ -32925,7 +33065,7 @@ impl std::fmt::Display for Promotions {
             Promotions::OptIn => "opt_in",
             Promotions::OptOut => "opt_out",
             Promotions::Noop => "",
-            Promotions::FallthroughString => "*",
+            Promotions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32964,8 +33104,8 @@ pub enum PaymentPagesCheckoutSessionConsentCollectionPromotions {
     Auto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentPagesCheckoutSessionConsentCollectionPromotions {
@@ -32973,7 +33113,7 @@ impl std::fmt::Display for PaymentPagesCheckoutSessionConsentCollectionPromotion
         match &*self {
             PaymentPagesCheckoutSessionConsentCollectionPromotions::Auto => "auto",
             PaymentPagesCheckoutSessionConsentCollectionPromotions::Noop => "",
-            PaymentPagesCheckoutSessionConsentCollectionPromotions::FallthroughString => "*",
+            PaymentPagesCheckoutSessionConsentCollectionPromotions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33362,8 +33502,8 @@ pub enum PayoutObject {
     Payout,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayoutObject {
@@ -33371,7 +33511,7 @@ impl std::fmt::Display for PayoutObject {
         match &*self {
             PayoutObject::Payout => "payout",
             PayoutObject::Noop => "",
-            PayoutObject::FallthroughString => "*",
+            PayoutObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33454,8 +33594,8 @@ pub enum PayoutType {
     Card,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayoutType {
@@ -33464,7 +33604,7 @@ impl std::fmt::Display for PayoutType {
             PayoutType::BankAccount => "bank_account",
             PayoutType::Card => "card",
             PayoutType::Noop => "",
-            PayoutType::FallthroughString => "*",
+            PayoutType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33765,8 +33905,8 @@ pub enum PoliticalExposure {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PoliticalExposure {
@@ -33775,7 +33915,7 @@ impl std::fmt::Display for PoliticalExposure {
             PoliticalExposure::Existing => "existing",
             PoliticalExposure::None => "none",
             PoliticalExposure::Noop => "",
-            PoliticalExposure::FallthroughString => "*",
+            PoliticalExposure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34235,8 +34375,8 @@ pub enum AggregateUsage {
     Sum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregateUsage {
@@ -34247,7 +34387,7 @@ impl std::fmt::Display for AggregateUsage {
             AggregateUsage::Max => "max",
             AggregateUsage::Sum => "sum",
             AggregateUsage::Noop => "",
-            AggregateUsage::FallthroughString => "*",
+            AggregateUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34275,8 +34415,8 @@ pub enum BillingScheme {
     Tiered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingScheme {
@@ -34285,7 +34425,7 @@ impl std::fmt::Display for BillingScheme {
             BillingScheme::PerUnit => "per_unit",
             BillingScheme::Tiered => "tiered",
             BillingScheme::Noop => "",
-            BillingScheme::FallthroughString => "*",
+            BillingScheme::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34317,8 +34457,8 @@ pub enum PlanInterval {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlanInterval {
@@ -34329,7 +34469,7 @@ impl std::fmt::Display for PlanInterval {
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
             PlanInterval::Noop => "",
-            PlanInterval::FallthroughString => "*",
+            PlanInterval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34424,8 +34564,8 @@ pub enum TiersMode {
     Volume,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TiersMode {
@@ -34434,7 +34574,7 @@ impl std::fmt::Display for TiersMode {
             TiersMode::Graduated => "graduated",
             TiersMode::Volume => "volume",
             TiersMode::Noop => "",
-            TiersMode::FallthroughString => "*",
+            TiersMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34462,8 +34602,8 @@ pub enum UsageType {
     Metered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsageType {
@@ -34472,7 +34612,7 @@ impl std::fmt::Display for UsageType {
             UsageType::Licensed => "licensed",
             UsageType::Metered => "metered",
             UsageType::Noop => "",
-            UsageType::FallthroughString => "*",
+            UsageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34748,8 +34888,8 @@ pub enum PlatformTaxObject {
     PlatformTaxFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformTaxObject {
@@ -34757,7 +34897,7 @@ impl std::fmt::Display for PlatformTaxObject {
         match &*self {
             PlatformTaxObject::PlatformTaxFee => "platform_tax_fee",
             PlatformTaxObject::Noop => "",
-            PlatformTaxObject::FallthroughString => "*",
+            PlatformTaxObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34867,8 +35007,8 @@ pub enum AllowedUpdates {
     TaxId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedUpdates {
@@ -34880,7 +35020,7 @@ impl std::fmt::Display for AllowedUpdates {
             AllowedUpdates::Shipping => "shipping",
             AllowedUpdates::TaxId => "tax_id",
             AllowedUpdates::Noop => "",
-            AllowedUpdates::FallthroughString => "*",
+            AllowedUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34965,8 +35105,8 @@ pub enum PortalSubscriptionCancelMode {
     Immediately,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PortalSubscriptionCancelMode {
@@ -34975,7 +35115,7 @@ impl std::fmt::Display for PortalSubscriptionCancelMode {
             PortalSubscriptionCancelMode::AtPeriodEnd => "at_period_end",
             PortalSubscriptionCancelMode::Immediately => "immediately",
             PortalSubscriptionCancelMode::Noop => "",
-            PortalSubscriptionCancelMode::FallthroughString => "*",
+            PortalSubscriptionCancelMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35005,8 +35145,8 @@ pub enum ProrationBehavior {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProrationBehavior {
@@ -35016,7 +35156,7 @@ impl std::fmt::Display for ProrationBehavior {
             ProrationBehavior::CreateProrations => "create_prorations",
             ProrationBehavior::None => "none",
             ProrationBehavior::Noop => "",
-            ProrationBehavior::FallthroughString => "*",
+            ProrationBehavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35081,8 +35221,8 @@ pub enum Options {
     Unused,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Options {
@@ -35097,7 +35237,7 @@ impl std::fmt::Display for Options {
             Options::TooExpensive => "too_expensive",
             Options::Unused => "unused",
             Options::Noop => "",
-            Options::FallthroughString => "*",
+            Options::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35146,8 +35286,8 @@ pub enum DefaultAllowedUpdates {
     Quantity,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DefaultAllowedUpdates {
@@ -35157,7 +35297,7 @@ impl std::fmt::Display for DefaultAllowedUpdates {
             DefaultAllowedUpdates::PromotionCode => "promotion_code",
             DefaultAllowedUpdates::Quantity => "quantity",
             DefaultAllowedUpdates::Noop => "",
-            DefaultAllowedUpdates::FallthroughString => "*",
+            DefaultAllowedUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35246,8 +35386,8 @@ pub enum TaxBehavior {
     Unspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxBehavior {
@@ -35257,7 +35397,7 @@ impl std::fmt::Display for TaxBehavior {
             TaxBehavior::Inclusive => "inclusive",
             TaxBehavior::Unspecified => "unspecified",
             TaxBehavior::Noop => "",
-            TaxBehavior::FallthroughString => "*",
+            TaxBehavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35285,8 +35425,8 @@ pub enum PriceType {
     Recurring,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PriceType {
@@ -35295,7 +35435,7 @@ impl std::fmt::Display for PriceType {
             PriceType::OneTime => "one_time",
             PriceType::Recurring => "recurring",
             PriceType::Noop => "",
-            PriceType::FallthroughString => "*",
+            PriceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35766,8 +35906,8 @@ pub enum PromotionCodeObject {
     PromotionCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PromotionCodeObject {
@@ -35775,7 +35915,7 @@ impl std::fmt::Display for PromotionCodeObject {
         match &*self {
             PromotionCodeObject::PromotionCode => "promotion_code",
             PromotionCodeObject::Noop => "",
-            PromotionCodeObject::FallthroughString => "*",
+            PromotionCodeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36034,8 +36174,8 @@ pub enum QuoteObject {
     Quote,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QuoteObject {
@@ -36043,7 +36183,7 @@ impl std::fmt::Display for QuoteObject {
         match &*self {
             QuoteObject::Quote => "quote",
             QuoteObject::Noop => "",
-            QuoteObject::FallthroughString => "*",
+            QuoteObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36075,8 +36215,8 @@ pub enum QuoteStatus {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QuoteStatus {
@@ -36087,7 +36227,7 @@ impl std::fmt::Display for QuoteStatus {
             QuoteStatus::Draft => "draft",
             QuoteStatus::Open => "open",
             QuoteStatus::Noop => "",
-            QuoteStatus::FallthroughString => "*",
+            QuoteStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36630,8 +36770,8 @@ pub enum RadarEarlyFraudWarningObject {
     RadarEarlyFraudWarning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RadarEarlyFraudWarningObject {
@@ -36639,7 +36779,7 @@ impl std::fmt::Display for RadarEarlyFraudWarningObject {
         match &*self {
             RadarEarlyFraudWarningObject::RadarEarlyFraudWarning => "radar.early_fraud_warning",
             RadarEarlyFraudWarningObject::Noop => "",
-            RadarEarlyFraudWarningObject::FallthroughString => "*",
+            RadarEarlyFraudWarningObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36745,8 +36885,8 @@ pub enum ItemType {
     String,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ItemType {
@@ -36761,7 +36901,7 @@ impl std::fmt::Display for ItemType {
             ItemType::IpAddress => "ip_address",
             ItemType::String => "string",
             ItemType::Noop => "",
-            ItemType::FallthroughString => "*",
+            ItemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37347,8 +37487,8 @@ pub enum RefundObject {
     Refund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefundObject {
@@ -37356,7 +37496,7 @@ impl std::fmt::Display for RefundObject {
         match &*self {
             RefundObject::Refund => "refund",
             RefundObject::Noop => "",
-            RefundObject::FallthroughString => "*",
+            RefundObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37388,8 +37528,8 @@ pub enum RefundReason {
     RequestedByCustomer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefundReason {
@@ -37400,7 +37540,7 @@ impl std::fmt::Display for RefundReason {
             RefundReason::Fraudulent => "fraudulent",
             RefundReason::RequestedByCustomer => "requested_by_customer",
             RefundReason::Noop => "",
-            RefundReason::FallthroughString => "*",
+            RefundReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37708,8 +37848,8 @@ pub enum ReportingReportRunObject {
     ReportingReportRun,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportingReportRunObject {
@@ -37717,7 +37857,7 @@ impl std::fmt::Display for ReportingReportRunObject {
         match &*self {
             ReportingReportRunObject::ReportingReportRun => "reporting.report_run",
             ReportingReportRunObject::Noop => "",
-            ReportingReportRunObject::FallthroughString => "*",
+            ReportingReportRunObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37847,8 +37987,8 @@ pub enum ReportingReportTypeObject {
     ReportingReportType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportingReportTypeObject {
@@ -37856,7 +37996,7 @@ impl std::fmt::Display for ReportingReportTypeObject {
         match &*self {
             ReportingReportTypeObject::ReportingReportType => "reporting.report_type",
             ReportingReportTypeObject::Noop => "",
-            ReportingReportTypeObject::FallthroughString => "*",
+            ReportingReportTypeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37977,8 +38117,8 @@ pub enum ReserveTransactionObject {
     ReserveTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReserveTransactionObject {
@@ -37986,7 +38126,7 @@ impl std::fmt::Display for ReserveTransactionObject {
         match &*self {
             ReserveTransactionObject::ReserveTransaction => "reserve_transaction",
             ReserveTransactionObject::Noop => "",
-            ReserveTransactionObject::FallthroughString => "*",
+            ReserveTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38066,8 +38206,8 @@ pub enum ClosedReason {
     RefundedAsFraud,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClosedReason {
@@ -38079,7 +38219,7 @@ impl std::fmt::Display for ClosedReason {
             ClosedReason::Refunded => "refunded",
             ClosedReason::RefundedAsFraud => "refunded_as_fraud",
             ClosedReason::Noop => "",
-            ClosedReason::FallthroughString => "*",
+            ClosedReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38105,8 +38245,8 @@ pub enum ReviewObject {
     Review,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReviewObject {
@@ -38114,7 +38254,7 @@ impl std::fmt::Display for ReviewObject {
         match &*self {
             ReviewObject::Review => "review",
             ReviewObject::Noop => "",
-            ReviewObject::FallthroughString => "*",
+            ReviewObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38142,8 +38282,8 @@ pub enum OpenedReason {
     Rule,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenedReason {
@@ -38152,7 +38292,7 @@ impl std::fmt::Display for OpenedReason {
             OpenedReason::Manual => "manual",
             OpenedReason::Rule => "rule",
             OpenedReason::Noop => "",
-            OpenedReason::FallthroughString => "*",
+            OpenedReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38326,8 +38466,8 @@ pub enum ScheduledQueryRunObject {
     ScheduledQueryRun,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScheduledQueryRunObject {
@@ -38335,7 +38475,7 @@ impl std::fmt::Display for ScheduledQueryRunObject {
         match &*self {
             ScheduledQueryRunObject::ScheduledQueryRun => "scheduled_query_run",
             ScheduledQueryRunObject::Noop => "",
-            ScheduledQueryRunObject::FallthroughString => "*",
+            ScheduledQueryRunObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38474,8 +38614,8 @@ pub enum SetupAttemptObject {
     SetupAttempt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupAttemptObject {
@@ -38483,7 +38623,7 @@ impl std::fmt::Display for SetupAttemptObject {
         match &*self {
             SetupAttemptObject::SetupAttempt => "setup_attempt",
             SetupAttemptObject::Noop => "",
-            SetupAttemptObject::FallthroughString => "*",
+            SetupAttemptObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38874,8 +39014,8 @@ pub enum SetupIntentCancellationReason {
     RequestedByCustomer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupIntentCancellationReason {
@@ -38885,7 +39025,7 @@ impl std::fmt::Display for SetupIntentCancellationReason {
             SetupIntentCancellationReason::Duplicate => "duplicate",
             SetupIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
             SetupIntentCancellationReason::Noop => "",
-            SetupIntentCancellationReason::FallthroughString => "*",
+            SetupIntentCancellationReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38911,8 +39051,8 @@ pub enum SetupIntentObject {
     SetupIntent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupIntentObject {
@@ -38920,7 +39060,7 @@ impl std::fmt::Display for SetupIntentObject {
         match &*self {
             SetupIntentObject::SetupIntent => "setup_intent",
             SetupIntentObject::Noop => "",
-            SetupIntentObject::FallthroughString => "*",
+            SetupIntentObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38956,8 +39096,8 @@ pub enum SetupIntentStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupIntentStatus {
@@ -38970,7 +39110,7 @@ impl std::fmt::Display for SetupIntentStatus {
             SetupIntentStatus::RequiresPaymentMethod => "requires_payment_method",
             SetupIntentStatus::Succeeded => "succeeded",
             SetupIntentStatus::Noop => "",
-            SetupIntentStatus::FallthroughString => "*",
+            SetupIntentStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -39861,8 +40001,8 @@ pub enum ShippingRateObject {
     ShippingRate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShippingRateObject {
@@ -39870,7 +40010,7 @@ impl std::fmt::Display for ShippingRateObject {
         match &*self {
             ShippingRateObject::ShippingRate => "shipping_rate",
             ShippingRateObject::Noop => "",
-            ShippingRateObject::FallthroughString => "*",
+            ShippingRateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -39896,8 +40036,8 @@ pub enum ShippingRateType {
     FixedAmount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShippingRateType {
@@ -39905,7 +40045,7 @@ impl std::fmt::Display for ShippingRateType {
         match &*self {
             ShippingRateType::FixedAmount => "fixed_amount",
             ShippingRateType::Noop => "",
-            ShippingRateType::FallthroughString => "*",
+            ShippingRateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -40049,8 +40189,8 @@ pub enum Unit {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Unit {
@@ -40062,7 +40202,7 @@ impl std::fmt::Display for Unit {
             Unit::Month => "month",
             Unit::Week => "week",
             Unit::Noop => "",
-            Unit::FallthroughString => "*",
+            Unit::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -40329,8 +40469,8 @@ pub enum SourceObject {
     Source,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceObject {
@@ -40338,7 +40478,7 @@ impl std::fmt::Display for SourceObject {
         match &*self {
             SourceObject::Source => "source",
             SourceObject::Noop => "",
-            SourceObject::FallthroughString => "*",
+            SourceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -40398,8 +40538,8 @@ pub enum SourceType {
     Wechat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -40424,7 +40564,7 @@ impl std::fmt::Display for SourceType {
             SourceType::ThreeDSecure => "three_d_secure",
             SourceType::Wechat => "wechat",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -40855,8 +40995,8 @@ pub enum SourceMandateNotificationObject {
     SourceMandateNotification,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceMandateNotificationObject {
@@ -40866,7 +41006,7 @@ impl std::fmt::Display for SourceMandateNotificationObject {
                 "source_mandate_notification"
             }
             SourceMandateNotificationObject::Noop => "",
-            SourceMandateNotificationObject::FallthroughString => "*",
+            SourceMandateNotificationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -41340,8 +41480,8 @@ pub enum SourceTransactionObject {
     SourceTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceTransactionObject {
@@ -41349,7 +41489,7 @@ impl std::fmt::Display for SourceTransactionObject {
         match &*self {
             SourceTransactionObject::SourceTransaction => "source_transaction",
             SourceTransactionObject::Noop => "",
-            SourceTransactionObject::FallthroughString => "*",
+            SourceTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -41405,8 +41545,8 @@ pub enum SourceTransactionType {
     Wechat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceTransactionType {
@@ -41429,7 +41569,7 @@ impl std::fmt::Display for SourceTransactionType {
             SourceTransactionType::ThreeDSecure => "three_d_secure",
             SourceTransactionType::Wechat => "wechat",
             SourceTransactionType::Noop => "",
-            SourceTransactionType::FallthroughString => "*",
+            SourceTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43347,8 +43487,8 @@ pub enum SubscriptionObject {
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionObject {
@@ -43356,7 +43496,7 @@ impl std::fmt::Display for SubscriptionObject {
         match &*self {
             SubscriptionObject::Subscription => "subscription",
             SubscriptionObject::Noop => "",
-            SubscriptionObject::FallthroughString => "*",
+            SubscriptionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43402,8 +43542,8 @@ pub enum SubscriptionStatus {
     Unpaid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionStatus {
@@ -43417,7 +43557,7 @@ impl std::fmt::Display for SubscriptionStatus {
             SubscriptionStatus::Trialing => "trialing",
             SubscriptionStatus::Unpaid => "unpaid",
             SubscriptionStatus::Noop => "",
-            SubscriptionStatus::FallthroughString => "*",
+            SubscriptionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43899,8 +44039,8 @@ pub enum EndBehavior {
     Renew,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EndBehavior {
@@ -43911,7 +44051,7 @@ impl std::fmt::Display for EndBehavior {
             EndBehavior::Release => "release",
             EndBehavior::Renew => "renew",
             EndBehavior::Noop => "",
-            EndBehavior::FallthroughString => "*",
+            EndBehavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43937,8 +44077,8 @@ pub enum SubscriptionScheduleObject {
     SubscriptionSchedule,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionScheduleObject {
@@ -43946,7 +44086,7 @@ impl std::fmt::Display for SubscriptionScheduleObject {
         match &*self {
             SubscriptionScheduleObject::SubscriptionSchedule => "subscription_schedule",
             SubscriptionScheduleObject::Noop => "",
-            SubscriptionScheduleObject::FallthroughString => "*",
+            SubscriptionScheduleObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43980,8 +44120,8 @@ pub enum SubscriptionScheduleStatus {
     Released,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionScheduleStatus {
@@ -43993,7 +44133,7 @@ impl std::fmt::Display for SubscriptionScheduleStatus {
             SubscriptionScheduleStatus::NotStarted => "not_started",
             SubscriptionScheduleStatus::Released => "released",
             SubscriptionScheduleStatus::Noop => "",
-            SubscriptionScheduleStatus::FallthroughString => "*",
+            SubscriptionScheduleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44302,8 +44442,8 @@ pub enum BillingCycleAnchor {
     PhaseStart,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingCycleAnchor {
@@ -44312,7 +44452,7 @@ impl std::fmt::Display for BillingCycleAnchor {
             BillingCycleAnchor::Automatic => "automatic",
             BillingCycleAnchor::PhaseStart => "phase_start",
             BillingCycleAnchor::Noop => "",
-            BillingCycleAnchor::FallthroughString => "*",
+            BillingCycleAnchor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44582,8 +44722,8 @@ pub enum Behavior {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Behavior {
@@ -44593,7 +44733,7 @@ impl std::fmt::Display for Behavior {
             Behavior::MarkUncollectible => "mark_uncollectible",
             Behavior::Void => "void",
             Behavior::Noop => "",
-            Behavior::FallthroughString => "*",
+            Behavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44748,8 +44888,8 @@ pub enum TaxCodeObject {
     TaxCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxCodeObject {
@@ -44757,7 +44897,7 @@ impl std::fmt::Display for TaxCodeObject {
         match &*self {
             TaxCodeObject::TaxCode => "tax_code",
             TaxCodeObject::Noop => "",
-            TaxCodeObject::FallthroughString => "*",
+            TaxCodeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44820,8 +44960,8 @@ pub enum TaxDeductedAtSourceObject {
     TaxDeductedAtSource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxDeductedAtSourceObject {
@@ -44829,7 +44969,7 @@ impl std::fmt::Display for TaxDeductedAtSourceObject {
         match &*self {
             TaxDeductedAtSourceObject::TaxDeductedAtSource => "tax_deducted_at_source",
             TaxDeductedAtSourceObject::Noop => "",
-            TaxDeductedAtSourceObject::FallthroughString => "*",
+            TaxDeductedAtSourceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44989,8 +45129,8 @@ pub enum TaxVerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxVerificationStatus {
@@ -45001,7 +45141,7 @@ impl std::fmt::Display for TaxVerificationStatus {
             TaxVerificationStatus::Unverified => "unverified",
             TaxVerificationStatus::Verified => "verified",
             TaxVerificationStatus::Noop => "",
-            TaxVerificationStatus::FallthroughString => "*",
+            TaxVerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45055,8 +45195,8 @@ pub enum TaxRateObject {
     TaxRate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxRateObject {
@@ -45064,7 +45204,7 @@ impl std::fmt::Display for TaxRateObject {
         match &*self {
             TaxRateObject::TaxRate => "tax_rate",
             TaxRateObject::Noop => "",
-            TaxRateObject::FallthroughString => "*",
+            TaxRateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45104,8 +45244,8 @@ pub enum TaxType {
     Vat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxType {
@@ -45120,7 +45260,7 @@ impl std::fmt::Display for TaxType {
             TaxType::SalesTax => "sales_tax",
             TaxType::Vat => "vat",
             TaxType::Noop => "",
-            TaxType::FallthroughString => "*",
+            TaxType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45278,8 +45418,8 @@ pub enum TerminalConnectionTokenObject {
     TerminalConnectionToken,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalConnectionTokenObject {
@@ -45287,7 +45427,7 @@ impl std::fmt::Display for TerminalConnectionTokenObject {
         match &*self {
             TerminalConnectionTokenObject::TerminalConnectionToken => "terminal.connection_token",
             TerminalConnectionTokenObject::Noop => "",
-            TerminalConnectionTokenObject::FallthroughString => "*",
+            TerminalConnectionTokenObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45411,8 +45551,8 @@ pub enum DeviceType {
     VerifoneP400,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -45424,7 +45564,7 @@ impl std::fmt::Display for DeviceType {
             DeviceType::StripeM2 => "stripe_m2",
             DeviceType::VerifoneP400 => "verifone_P400",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45706,8 +45846,8 @@ pub enum TerminalReaderResourceActionStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceActionStatus {
@@ -45717,7 +45857,7 @@ impl std::fmt::Display for TerminalReaderResourceActionStatus {
             TerminalReaderResourceActionStatus::InProgress => "in_progress",
             TerminalReaderResourceActionStatus::Succeeded => "succeeded",
             TerminalReaderResourceActionStatus::Noop => "",
-            TerminalReaderResourceActionStatus::FallthroughString => "*",
+            TerminalReaderResourceActionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45747,8 +45887,8 @@ pub enum TerminalReaderResourceActionType {
     SetReaderDisplay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceActionType {
@@ -45758,7 +45898,7 @@ impl std::fmt::Display for TerminalReaderResourceActionType {
             TerminalReaderResourceActionType::ProcessSetupIntent => "process_setup_intent",
             TerminalReaderResourceActionType::SetReaderDisplay => "set_reader_display",
             TerminalReaderResourceActionType::Noop => "",
-            TerminalReaderResourceActionType::FallthroughString => "*",
+            TerminalReaderResourceActionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45839,8 +45979,8 @@ pub enum TerminalReaderResourceSetDisplayActionType {
     Cart,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceSetDisplayActionType {
@@ -45848,7 +45988,7 @@ impl std::fmt::Display for TerminalReaderResourceSetDisplayActionType {
         match &*self {
             TerminalReaderResourceSetDisplayActionType::Cart => "cart",
             TerminalReaderResourceSetDisplayActionType::Noop => "",
-            TerminalReaderResourceSetDisplayActionType::FallthroughString => "*",
+            TerminalReaderResourceSetDisplayActionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45897,8 +46037,8 @@ pub enum TestClockStatus {
     Ready,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TestClockStatus {
@@ -45908,7 +46048,7 @@ impl std::fmt::Display for TestClockStatus {
             TestClockStatus::InternalFailure => "internal_failure",
             TestClockStatus::Ready => "ready",
             TestClockStatus::Noop => "",
-            TestClockStatus::FallthroughString => "*",
+            TestClockStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46006,8 +46146,8 @@ pub enum ThreeDSecureObject {
     ThreeDSecure,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ThreeDSecureObject {
@@ -46015,7 +46155,7 @@ impl std::fmt::Display for ThreeDSecureObject {
         match &*self {
             ThreeDSecureObject::ThreeDSecure => "three_d_secure",
             ThreeDSecureObject::Noop => "",
-            ThreeDSecureObject::FallthroughString => "*",
+            ThreeDSecureObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46137,8 +46277,8 @@ pub enum AuthenticationFlow {
     Frictionless,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthenticationFlow {
@@ -46147,7 +46287,7 @@ impl std::fmt::Display for AuthenticationFlow {
             AuthenticationFlow::Challenge => "challenge",
             AuthenticationFlow::Frictionless => "frictionless",
             AuthenticationFlow::Noop => "",
-            AuthenticationFlow::FallthroughString => "*",
+            AuthenticationFlow::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46181,8 +46321,8 @@ pub enum ThreeDSecureDetailsResult {
     ProcessingError,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ThreeDSecureDetailsResult {
@@ -46194,7 +46334,7 @@ impl std::fmt::Display for ThreeDSecureDetailsResult {
             ThreeDSecureDetailsResult::NotSupported => "not_supported",
             ThreeDSecureDetailsResult::ProcessingError => "processing_error",
             ThreeDSecureDetailsResult::Noop => "",
-            ThreeDSecureDetailsResult::FallthroughString => "*",
+            ThreeDSecureDetailsResult::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46233,8 +46373,8 @@ pub enum ResultReason {
     Rejected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ResultReason {
@@ -46248,7 +46388,7 @@ impl std::fmt::Display for ResultReason {
             ResultReason::ProtocolError => "protocol_error",
             ResultReason::Rejected => "rejected",
             ResultReason::Noop => "",
-            ResultReason::FallthroughString => "*",
+            ResultReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46278,8 +46418,8 @@ pub enum Version {
     Twenty,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Version {
@@ -46289,7 +46429,7 @@ impl std::fmt::Display for Version {
             Version::TwoHundredAndTen => "2.1.0",
             Version::Twenty => "2.2.0",
             Version::Noop => "",
-            Version::FallthroughString => "*",
+            Version::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46355,8 +46495,8 @@ pub enum TokenObject {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenObject {
@@ -46364,7 +46504,7 @@ impl std::fmt::Display for TokenObject {
         match &*self {
             TokenObject::Token => "token",
             TokenObject::Noop => "",
-            TokenObject::FallthroughString => "*",
+            TokenObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46547,8 +46687,8 @@ pub enum TopupObject {
     Topup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TopupObject {
@@ -46556,7 +46696,7 @@ impl std::fmt::Display for TopupObject {
         match &*self {
             TopupObject::Topup => "topup",
             TopupObject::Noop => "",
-            TopupObject::FallthroughString => "*",
+            TopupObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46590,8 +46730,8 @@ pub enum TopupStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TopupStatus {
@@ -46603,7 +46743,7 @@ impl std::fmt::Display for TopupStatus {
             TopupStatus::Reversed => "reversed",
             TopupStatus::Succeeded => "succeeded",
             TopupStatus::Noop => "",
-            TopupStatus::FallthroughString => "*",
+            TopupStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46798,8 +46938,8 @@ pub enum TransferObject {
     Transfer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransferObject {
@@ -46807,7 +46947,7 @@ impl std::fmt::Display for TransferObject {
         match &*self {
             TransferObject::Transfer => "transfer",
             TransferObject::Noop => "",
-            TransferObject::FallthroughString => "*",
+            TransferObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47089,8 +47229,8 @@ pub enum TransferReversalObject {
     TransferReversal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransferReversalObject {
@@ -47098,7 +47238,7 @@ impl std::fmt::Display for TransferReversalObject {
         match &*self {
             TransferReversalObject::TransferReversal => "transfer_reversal",
             TransferReversalObject::Noop => "",
-            TransferReversalObject::FallthroughString => "*",
+            TransferReversalObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47299,8 +47439,8 @@ pub enum Round {
     Up,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Round {
@@ -47309,7 +47449,7 @@ impl std::fmt::Display for Round {
             Round::Down => "down",
             Round::Up => "up",
             Round::Noop => "",
-            Round::FallthroughString => "*",
+            Round::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47354,8 +47494,8 @@ pub enum UsageRecordObject {
     UsageRecord,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsageRecordObject {
@@ -47363,7 +47503,7 @@ impl std::fmt::Display for UsageRecordObject {
         match &*self {
             UsageRecordObject::UsageRecord => "usage_record",
             UsageRecordObject::Noop => "",
-            UsageRecordObject::FallthroughString => "*",
+            UsageRecordObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47446,8 +47586,8 @@ pub enum UsageRecordSummaryObject {
     UsageRecordSummary,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsageRecordSummaryObject {
@@ -47455,7 +47595,7 @@ impl std::fmt::Display for UsageRecordSummaryObject {
         match &*self {
             UsageRecordSummaryObject::UsageRecordSummary => "usage_record_summary",
             UsageRecordSummaryObject::Noop => "",
-            UsageRecordSummaryObject::FallthroughString => "*",
+            UsageRecordSummaryObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47542,8 +47682,8 @@ pub enum VerificationSessionRedactionStatus {
     Redacted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationSessionRedactionStatus {
@@ -47552,7 +47692,7 @@ impl std::fmt::Display for VerificationSessionRedactionStatus {
             VerificationSessionRedactionStatus::Processing => "processing",
             VerificationSessionRedactionStatus::Redacted => "redacted",
             VerificationSessionRedactionStatus::Noop => "",
-            VerificationSessionRedactionStatus::FallthroughString => "*",
+            VerificationSessionRedactionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48106,8 +48246,8 @@ pub enum SearchResultObject {
     SearchResult,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchResultObject {
@@ -48115,7 +48255,7 @@ impl std::fmt::Display for SearchResultObject {
         match &*self {
             SearchResultObject::SearchResult => "search_result",
             SearchResultObject::Noop => "",
-            SearchResultObject::FallthroughString => "*",
+            SearchResultObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48322,15 +48462,15 @@ pub struct CreditNotesList {
 pub enum Shipping {
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Shipping {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &*self {
             Shipping::Noop => "",
-            Shipping::FallthroughString => "*",
+            Shipping::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48670,8 +48810,8 @@ pub enum GetCustomersCustomerPaymentMethodsType {
     WechatPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCustomersCustomerPaymentMethodsType {
@@ -48700,7 +48840,7 @@ impl std::fmt::Display for GetCustomersCustomerPaymentMethodsType {
             GetCustomersCustomerPaymentMethodsType::UsBankAccount => "us_bank_account",
             GetCustomersCustomerPaymentMethodsType::WechatPay => "wechat_pay",
             GetCustomersCustomerPaymentMethodsType::Noop => "",
-            GetCustomersCustomerPaymentMethodsType::FallthroughString => "*",
+            GetCustomersCustomerPaymentMethodsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48998,8 +49138,8 @@ pub enum GetInvoicesStatus {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetInvoicesStatus {
@@ -49011,7 +49151,7 @@ impl std::fmt::Display for GetInvoicesStatus {
             GetInvoicesStatus::Uncollectible => "uncollectible",
             GetInvoicesStatus::Void => "void",
             GetInvoicesStatus::Noop => "",
-            GetInvoicesStatus::FallthroughString => "*",
+            GetInvoicesStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -49245,8 +49385,8 @@ pub enum GetInvoicesUpcomingCustomerDetailsTaxExempt {
     Reverse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsTaxExempt {
@@ -49256,7 +49396,7 @@ impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsTaxExempt {
             GetInvoicesUpcomingCustomerDetailsTaxExempt::None => "none",
             GetInvoicesUpcomingCustomerDetailsTaxExempt::Reverse => "reverse",
             GetInvoicesUpcomingCustomerDetailsTaxExempt::Noop => "",
-            GetInvoicesUpcomingCustomerDetailsTaxExempt::FallthroughString => "*",
+            GetInvoicesUpcomingCustomerDetailsTaxExempt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -49367,8 +49507,8 @@ pub enum GetInvoicesUpcomingCustomerDetailsDataParamsType {
     ZaVat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsDataParamsType {
@@ -49420,7 +49560,7 @@ impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsDataParamsType {
             GetInvoicesUpcomingCustomerDetailsDataParamsType::UsEin => "us_ein",
             GetInvoicesUpcomingCustomerDetailsDataParamsType::ZaVat => "za_vat",
             GetInvoicesUpcomingCustomerDetailsDataParamsType::Noop => "",
-            GetInvoicesUpcomingCustomerDetailsDataParamsType::FallthroughString => "*",
+            GetInvoicesUpcomingCustomerDetailsDataParamsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -49719,8 +49859,8 @@ pub enum SubscriptionBillingCycleAnchor {
     Unchanged,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionBillingCycleAnchor {
@@ -49729,7 +49869,7 @@ impl std::fmt::Display for SubscriptionBillingCycleAnchor {
             SubscriptionBillingCycleAnchor::Now => "now",
             SubscriptionBillingCycleAnchor::Unchanged => "unchanged",
             SubscriptionBillingCycleAnchor::Noop => "",
-            SubscriptionBillingCycleAnchor::FallthroughString => "*",
+            SubscriptionBillingCycleAnchor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -49990,8 +50130,8 @@ pub enum SubscriptionTrialEnd {
     Now,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionTrialEnd {
@@ -49999,7 +50139,7 @@ impl std::fmt::Display for SubscriptionTrialEnd {
         match &*self {
             SubscriptionTrialEnd::Now => "now",
             SubscriptionTrialEnd::Noop => "",
-            SubscriptionTrialEnd::FallthroughString => "*",
+            SubscriptionTrialEnd::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -50718,8 +50858,8 @@ pub enum GetRecipientsType {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetRecipientsType {
@@ -50728,7 +50868,7 @@ impl std::fmt::Display for GetRecipientsType {
             GetRecipientsType::Corporation => "corporation",
             GetRecipientsType::Individual => "individual",
             GetRecipientsType::Noop => "",
-            GetRecipientsType::FallthroughString => "*",
+            GetRecipientsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -51252,8 +51392,8 @@ pub enum GetSubscriptionsStatus {
     Unpaid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetSubscriptionsStatus {
@@ -51269,7 +51409,7 @@ impl std::fmt::Display for GetSubscriptionsStatus {
             GetSubscriptionsStatus::Trialing => "trialing",
             GetSubscriptionsStatus::Unpaid => "unpaid",
             GetSubscriptionsStatus::Noop => "",
-            GetSubscriptionsStatus::FallthroughString => "*",
+            GetSubscriptionsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -51548,8 +51688,8 @@ pub enum GetTopupsStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetTopupsStatus {
@@ -51560,7 +51700,7 @@ impl std::fmt::Display for GetTopupsStatus {
             GetTopupsStatus::Pending => "pending",
             GetTopupsStatus::Succeeded => "succeeded",
             GetTopupsStatus::Noop => "",
-            GetTopupsStatus::FallthroughString => "*",
+            GetTopupsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30,18 +30,20 @@ impl WebhookEndpoints {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::WebhookEndpoint>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ending_before.is_empty() {
-            query_args.push(("ending_before".to_string(), ending_before.to_string()));
+            query_args.push(("ending_before", ending_before.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/webhook_endpoints?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/v1/webhook_endpoints"),
+            &query_args,
+        );
 
         let resp: crate::types::GetWebhookEndpointsResponse = self.client.get(&url, None).await?;
 
@@ -77,17 +79,13 @@ impl WebhookEndpoints {
                 }
             }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("starting_after", &page)]),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
@@ -120,8 +118,9 @@ impl WebhookEndpoints {
     */
     pub async fn get_endpoint(
         &self,
-        webhook_endpoint: &str,
+        webhook_endpoint: impl AsRef<str>,
     ) -> Result<crate::types::WebhookEndpoint> {
+        let webhook_endpoint = webhook_endpoint.as_ref();
         let url = format!(
             "/v1/webhook_endpoints/{}",
             crate::progenitor_support::encode_path(webhook_endpoint),
@@ -141,8 +140,9 @@ impl WebhookEndpoints {
     */
     pub async fn post_endpoint(
         &self,
-        webhook_endpoint: &str,
+        webhook_endpoint: impl AsRef<str>,
     ) -> Result<crate::types::WebhookEndpoint> {
+        let webhook_endpoint = webhook_endpoint.as_ref();
         let url = format!(
             "/v1/webhook_endpoints/{}",
             crate::progenitor_support::encode_path(webhook_endpoint),
@@ -162,8 +162,9 @@ impl WebhookEndpoints {
     */
     pub async fn delete_endpoint(
         &self,
-        webhook_endpoint: &str,
+        webhook_endpoint: impl AsRef<str>,
     ) -> Result<crate::types::DeletedWebhookEndpoint> {
+        let webhook_endpoint = webhook_endpoint.as_ref();
         let url = format!(
             "/v1/webhook_endpoints/{}",
             crate::progenitor_support::encode_path(webhook_endpoint),
@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::booking_reports::{BookingReportQuery, DateRange};
 use crate::Client;
 
 pub struct BookingData {
@@ -41,33 +42,32 @@ impl BookingData {
         size: i64,
         booking_type: crate::types::BookingType,
     ) -> Result<Vec<crate::types::BookingReport>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !booking_status.to_string().is_empty() {
-            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+            query_args.push(("bookingStatus", booking_status.to_string()));
         }
         if !booking_type.to_string().is_empty() {
-            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+            query_args.push(("bookingType", booking_type.to_string()));
         }
         if !created_from.is_empty() {
-            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+            query_args.push(("createdFrom", created_from.to_string()));
         }
         if !created_to.is_empty() {
-            query_args.push(("createdTo".to_string(), created_to.to_string()));
+            query_args.push(("createdTo", created_to.to_string()));
         }
         if !page.to_string().is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if size > 0 {
-            query_args.push(("size".to_string(), size.to_string()));
+            query_args.push(("size", size.to_string()));
         }
         if !start_date_from.is_empty() {
-            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+            query_args.push(("startDateFrom", start_date_from.to_string()));
         }
         if !start_date_to.is_empty() {
-            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+            query_args.push(("startDateTo", start_date_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bookings?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/bookings"), &query_args);
 
         let resp: crate::types::BookingReportResponse = self.client.get(&url, None).await?;
 
@@ -93,54 +93,50 @@ impl BookingData {
         booking_status: crate::types::BookingStatus,
         booking_type: crate::types::BookingType,
     ) -> Result<Vec<crate::types::BookingReport>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !booking_status.to_string().is_empty() {
-            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+            query_args.push(("bookingStatus", booking_status.to_string()));
         }
         if !booking_type.to_string().is_empty() {
-            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+            query_args.push(("bookingType", booking_type.to_string()));
         }
         if !created_from.is_empty() {
-            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+            query_args.push(("createdFrom", created_from.to_string()));
         }
         if !created_to.is_empty() {
-            query_args.push(("createdTo".to_string(), created_to.to_string()));
+            query_args.push(("createdTo", created_to.to_string()));
         }
         if !start_date_from.is_empty() {
-            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+            query_args.push(("startDateFrom", start_date_from.to_string()));
         }
         if !start_date_to.is_empty() {
-            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+            query_args.push(("startDateTo", start_date_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bookings?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/v1/bookings"), &query_args);
 
-        let mut resp: crate::types::BookingReportResponse = if !url.contains('?') {
-            self.client
-                .get(&format!("{}?page=0&size=100", url), None)
-                .await?
-        } else {
-            self.client
-                .get(&format!("{}&page=0&size=100", url), None)
-                .await?
-        };
+        let mut resp: crate::types::BookingReportResponse = self
+            .client
+            .get(
+                &crate::progenitor_support::append_query(&url, &[("page", "0"), ("size", "100")]),
+                None,
+            )
+            .await?;
 
         let mut data = resp.data;
         let mut page = resp.page.current_page + 1;
 
         // Paginate if we should.
         while page <= (resp.page.total_pages - 1) {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?page={}&size=100", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&page={}&size=100", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(
+                        &url,
+                        &[("page", &page.to_string()), ("size", "100")],
+                    ),
+                    None,
+                )
+                .await?;
 
             data.append(&mut resp.data);
 
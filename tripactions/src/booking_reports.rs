@@ -0,0 +1,246 @@
+//! Pull the booking report for a range of dates, as records or as CSV.
+//!
+//! The booking report is paginated: a `BookingReportQuery` walks through its pages
+//! one at a time, so that the report of a busy month never has to be held in memory
+//! at once.
+//!
+//! ```no_run
+//! # async fn run(tripactions: tripactions::Client) -> anyhow::Result<()> {
+//! use chrono::NaiveDate;
+//! use futures::TryStreamExt;
+//! use tokio::io::AsyncWriteExt;
+//! use tripactions::{
+//!     booking_data::{BookingReportQuery, DateRange},
+//!     types::BookingStatus,
+//! };
+//!
+//! // Yesterday's bookings, for the nightly export to the finance team.
+//! let yesterday = NaiveDate::from_ymd_opt(2021, 8, 31).unwrap();
+//! let query = BookingReportQuery::created(DateRange::days(yesterday, yesterday))
+//!     .status(BookingStatus::Confirmed);
+//!
+//! let mut file = tokio::fs::File::create("bookings.csv").await?;
+//! let mut csv = Box::pin(tripactions.booking_data().report_csv(&query));
+//! while let Some(chunk) = csv.try_next().await? {
+//!     file.write_all(&chunk).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use futures::{Stream, StreamExt, TryStreamExt};
+
+use crate::{
+    booking_data::BookingData,
+    types::{BookingReport, BookingReportResponse, BookingStatus, BookingType},
+    Client,
+};
+
+/// The most bookings a page of the report can hold.
+const MAX_PAGE_SIZE: i64 = 100;
+
+/// A range of time, from `from` to `to` included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+impl DateRange {
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Self> {
+        if from > to {
+            return Err(anyhow!("the range from {} to {} is empty", from, to));
+        }
+
+        Ok(DateRange { from, to })
+    }
+
+    /// The whole days from `from` to `to` included, in UTC.
+    pub fn days(from: NaiveDate, to: NaiveDate) -> Self {
+        DateRange {
+            from: Utc.from_utc_datetime(&from.and_hms_opt(0, 0, 0).unwrap()),
+            to: Utc.from_utc_datetime(&to.and_hms_opt(23, 59, 59).unwrap()),
+        }
+    }
+}
+
+/// The filters of the booking report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookingReportQuery {
+    created: DateRange,
+    start_date: Option<DateRange>,
+    status: BookingStatus,
+    booking_type: BookingType,
+    page: i64,
+    page_size: i64,
+}
+
+impl BookingReportQuery {
+    /// The bookings created within the range.
+    pub fn created(range: DateRange) -> Self {
+        BookingReportQuery {
+            created: range,
+            start_date: None,
+            status: BookingStatus::Noop,
+            booking_type: BookingType::Noop,
+            page: 0,
+            page_size: MAX_PAGE_SIZE,
+        }
+    }
+
+    /// Only the bookings of trips starting within the range.
+    pub fn start_date(mut self, range: DateRange) -> Self {
+        self.start_date = Some(range);
+        self
+    }
+
+    pub fn status(mut self, status: BookingStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn booking_type(mut self, booking_type: BookingType) -> Self {
+        self.booking_type = booking_type;
+        self
+    }
+
+    /// Start at the given page, to resume a report that was interrupted.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page as i64;
+        self
+    }
+
+    /// Set the number of bookings requested per page, at most 100.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = i64::from(page_size).min(MAX_PAGE_SIZE);
+        self
+    }
+
+    fn url(&self, page: i64) -> String {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !self.status.to_string().is_empty() {
+            query_args.push(("bookingStatus".to_string(), self.status.to_string()));
+        }
+        if !self.booking_type.to_string().is_empty() {
+            query_args.push(("bookingType".to_string(), self.booking_type.to_string()));
+        }
+        query_args.push((
+            "createdFrom".to_string(),
+            self.created.from.timestamp().to_string(),
+        ));
+        query_args.push((
+            "createdTo".to_string(),
+            self.created.to.timestamp().to_string(),
+        ));
+        query_args.push(("page".to_string(), page.to_string()));
+        query_args.push(("size".to_string(), self.page_size.to_string()));
+        if let Some(range) = &self.start_date {
+            query_args.push((
+                "startDateFrom".to_string(),
+                range.from.timestamp().to_string(),
+            ));
+            query_args.push(("startDateTo".to_string(), range.to.timestamp().to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+
+        format!("/v1/bookings?{}", query_)
+    }
+}
+
+/// The state carried between the pages of a report.
+struct ReportState {
+    client: Client,
+    query: BookingReportQuery,
+    /// The next page, `None` once the last page was fetched.
+    page: Option<i64>,
+    pending: VecDeque<BookingReport>,
+}
+
+impl BookingData {
+    /// Returns the bookings matching the query, one page at a time.
+    pub fn report(&self, query: &BookingReportQuery) -> impl Stream<Item = Result<BookingReport>> {
+        let state = ReportState {
+            client: self.client.clone(),
+            query: query.clone(),
+            page: Some(query.page),
+            pending: VecDeque::new(),
+        };
+
+        futures::stream::try_unfold(state, |mut state| async move {
+            loop {
+                if let Some(booking) = state.pending.pop_front() {
+                    return Ok(Some((booking, state)));
+                }
+
+                let page = match state.page.take() {
+                    Some(page) => page,
+                    None => return Ok(None),
+                };
+                let resp: BookingReportResponse =
+                    state.client.get(&state.query.url(page), None).await?;
+
+                let next = resp.page.current_page + 1;
+                if !resp.data.is_empty() && next < resp.page.total_pages {
+                    state.page = Some(next);
+                }
+                state.pending.extend(resp.data);
+            }
+        })
+    }
+
+    /// Returns the bookings matching the query as CSV, a header line first and then
+    /// a line per booking. There is a column for every field of `BookingReport`,
+    /// with the nested objects written as JSON.
+    pub fn report_csv(
+        &self,
+        query: &BookingReportQuery,
+    ) -> impl Stream<Item = Result<bytes::Bytes>> {
+        let columns = csv_columns();
+        let header = csv_line(columns.iter().map(|c| c.to_string()));
+
+        futures::stream::once(async move { Ok(bytes::Bytes::from(header)) }).chain(
+            self.report(query).and_then(move |booking| {
+                let line = serde_json::to_value(&booking).map(|value| {
+                    csv_line(columns.iter().map(|c| match value.get(c) {
+                        None | Some(serde_json::Value::Null) => String::new(),
+                        Some(serde_json::Value::String(s)) => s.to_string(),
+                        Some(v) => v.to_string(),
+                    }))
+                });
+                futures::future::ready(line.map(bytes::Bytes::from).map_err(anyhow::Error::from))
+            }),
+        )
+    }
+}
+
+/// The names of the fields of `BookingReport`, in alphabetical order.
+fn csv_columns() -> Vec<String> {
+    let schema = schemars::schema_for!(BookingReport);
+    schema
+        .schema
+        .object
+        .map(|o| o.properties.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// A line of CSV, quoting the values that need it.
+fn csv_line<I>(values: I) -> String
+where
+    I: Iterator<Item = String>,
+{
+    let mut line = values
+        .map(|v| {
+            if v.contains(&[',', '"', '\n', '\r'][..]) {
+                format!("\"{}\"", v.replace('"', "\"\""))
+            } else {
+                v
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
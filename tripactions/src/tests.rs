@@ -1 +1,51 @@
+#[tokio::test]
+async fn test_booking_report() {
+    use futures::TryStreamExt;
 
+    use crate::booking_data::{BookingReportQuery, DateRange};
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"bookingId": "b1", "approverReason": "Over \"budget\", approved"}], "page": {"currentPage": 0, "pageSize": 1, "totalElements": 2, "totalPages": 2}}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"data": [{"bookingId": "b2", "grandTotal": 120.5}], "page": {"currentPage": 1, "pageSize": 1, "totalElements": 2, "totalPages": 2}}"#,
+    );
+
+    let tripactions = crate::Client::new("", "", "token").with_transport(mock.clone());
+    let day = chrono::NaiveDate::from_ymd_opt(2021, 8, 31).unwrap();
+    let query = BookingReportQuery::created(DateRange::days(day, day)).page_size(1);
+    let csv: Vec<bytes::Bytes> = tripactions
+        .booking_data()
+        .report_csv(&query)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(csv.len(), 3);
+    let header = std::str::from_utf8(&csv[0]).unwrap();
+    assert!(header.starts_with("airlineCreditCardSurcharge,approvalChangedAt,"));
+    assert!(header.ends_with("\r\n"));
+    let first = std::str::from_utf8(&csv[1]).unwrap();
+    assert!(first.contains(",b1,"));
+    assert!(first.contains(r#","Over ""budget"", approved","#));
+    assert!(std::str::from_utf8(&csv[2]).unwrap().contains(",120.5,"));
+
+    let requests = mock.requests();
+    let query: Vec<(String, String)> = requests[0].url.query_pairs().into_owned().collect();
+    assert!(query.contains(&("createdFrom".to_string(), "1630368000".to_string())));
+    assert!(query.contains(&("createdTo".to_string(), "1630454399".to_string())));
+    assert!(query.contains(&("page".to_string(), "0".to_string())));
+    assert!(query.contains(&("size".to_string(), "1".to_string())));
+    assert_eq!(
+        requests[1]
+            .url
+            .query_pairs()
+            .find(|(k, _)| k == "page")
+            .unwrap()
+            .1,
+        "1"
+    );
+}
@@ -10,8 +10,8 @@ pub enum TravelerType {
     Passenger,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TravelerType {
@@ -20,7 +20,7 @@ impl std::fmt::Display for TravelerType {
             TravelerType::Guest => "GUEST",
             TravelerType::Passenger => "PASSENGER",
             TravelerType::Noop => "",
-            TravelerType::FallthroughString => "*",
+            TravelerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -51,8 +51,8 @@ pub enum BookingStatus {
     Voided,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingStatus {
@@ -64,7 +64,7 @@ impl std::fmt::Display for BookingStatus {
             BookingStatus::Ticketed => "TICKETED",
             BookingStatus::Voided => "VOIDED",
             BookingStatus::Noop => "",
-            BookingStatus::FallthroughString => "*",
+            BookingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -97,8 +97,8 @@ pub enum BookingType {
     Transportation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingType {
@@ -111,7 +111,7 @@ impl std::fmt::Display for BookingType {
             BookingType::Rail => "RAIL",
             BookingType::Transportation => "TRANSPORTATION",
             BookingType::Noop => "",
-            BookingType::FallthroughString => "*",
+            BookingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -140,8 +140,8 @@ pub enum PaymentSchedule {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -152,7 +152,7 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Partial => "PARTIAL",
             PaymentSchedule::Unknown => "UNKNOWN",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -177,8 +177,8 @@ pub enum PassengerStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PassengerStatus {
@@ -187,7 +187,7 @@ impl std::fmt::Display for PassengerStatus {
             PassengerStatus::Disabled => "DISABLED",
             PassengerStatus::Enabled => "ENABLED",
             PassengerStatus::Noop => "",
-            PassengerStatus::FallthroughString => "*",
+            PassengerStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
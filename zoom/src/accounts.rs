@@ -42,18 +42,17 @@ impl Accounts {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/accounts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/accounts"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -106,7 +105,11 @@ impl Accounts {
     *
     * * `account_id: &str` -- User's first name.
     */
-    pub async fn account(&self, account_id: &str) -> Result<crate::types::AccountResponse> {
+    pub async fn account(
+        &self,
+        account_id: impl AsRef<str>,
+    ) -> Result<crate::types::AccountResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -137,7 +140,8 @@ impl Accounts {
     *
     * * `account_id: &str` -- User's first name.
     */
-    pub async fn disassociate(&self, account_id: &str) -> Result<()> {
+    pub async fn disassociate(&self, account_id: impl AsRef<str>) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -169,9 +173,10 @@ impl Accounts {
     */
     pub async fn options_update(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::Options,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/options",
             crate::progenitor_support::encode_path(account_id),
@@ -212,25 +217,24 @@ impl Accounts {
     */
     pub async fn settings_domains(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -266,25 +270,24 @@ impl Accounts {
     */
     pub async fn settings_security(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::Security> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -320,25 +323,24 @@ impl Accounts {
     */
     pub async fn settings_account(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::AccountSettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -374,25 +376,24 @@ impl Accounts {
     */
     pub async fn settings_meeting_security(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -428,25 +429,24 @@ impl Accounts {
     */
     pub async fn setting(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::AccountSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -471,19 +471,21 @@ impl Accounts {
     */
     pub async fn settings_update(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         option: crate::types::AccountSettingsUpdateOption,
         body: &crate::types::AccountSettingsUpdateRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -514,7 +516,11 @@ impl Accounts {
     *
     * * `account_id: &str` -- Unique Identifier of the account. To retrieve locked settings of the master account or a regular account, provide "me" as the value of this field. <br> To retrieve locked settings of a sub account, provide the Account ID of the sub account in this field.
     */
-    pub async fn managed_domain(&self, account_id: &str) -> Result<crate::types::DomainsList> {
+    pub async fn managed_domain(
+        &self,
+        account_id: impl AsRef<str>,
+    ) -> Result<crate::types::DomainsList> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/managed_domains",
             crate::progenitor_support::encode_path(account_id),
@@ -541,8 +547,9 @@ impl Accounts {
     */
     pub async fn trusted_domain(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountTrustedDomainResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/trusted_domains",
             crate::progenitor_support::encode_path(account_id),
@@ -586,25 +593,24 @@ impl Accounts {
     */
     pub async fn get_lock_setting(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         option: &str,
         custom_query_fields: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/lock_settings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -631,7 +637,8 @@ impl Accounts {
     *
     * * `account_id: &str` -- Unique Identifier of the account. To retrieve locked settings of the master account or a regular account, provide "me" as the value of this field. <br> To retrieve locked settings of a sub account, provide the Account ID of the sub account in this field.
     */
-    pub async fn update_lock_settings(&self, account_id: &str) -> Result<()> {
+    pub async fn update_lock_settings(&self, account_id: impl AsRef<str>) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/lock_settings",
             crate::progenitor_support::encode_path(account_id),
@@ -659,7 +666,12 @@ impl Accounts {
     *
     * * `account_id: &str` -- The account's account ID.
     */
-    pub async fn update_owner(&self, account_id: &str, body: &crate::types::Members) -> Result<()> {
+    pub async fn update_owner(
+        &self,
+        account_id: impl AsRef<str>,
+        body: &crate::types::Members,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/owner",
             crate::progenitor_support::encode_path(account_id),
@@ -691,9 +703,10 @@ impl Accounts {
     */
     pub async fn upload_vb(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::UploadVbRequest,
     ) -> Result<crate::types::Files> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/settings/virtual_backgrounds",
             crate::progenitor_support::encode_path(account_id),
@@ -722,16 +735,18 @@ impl Accounts {
     *
     * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
     */
-    pub async fn del_vb(&self, account_id: &str, file_ids: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn del_vb(&self, account_id: impl AsRef<str>, file_ids: &str) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -42,24 +42,23 @@ impl Archiving {
         to: &str,
         query_date_type: crate::types::ListArchivedFilesQueryDateType,
     ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/archive_files?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/archive_files"), &query_args);
 
         let resp: crate::types::ListArchivedFilesResponse = self.client.get(&url, None).await?;
 
@@ -89,18 +88,17 @@ impl Archiving {
         to: &str,
         query_date_type: crate::types::ListArchivedFilesQueryDateType,
     ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/archive_files?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/archive_files"), &query_args);
 
         let mut resp: crate::types::ListArchivedFilesResponse = self.client.get(&url, None).await?;
 
@@ -109,18 +107,13 @@ impl Archiving {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             meetings.append(&mut resp.meetings);
 
@@ -156,8 +149,9 @@ impl Archiving {
     */
     pub async fn testget_record_archived_file(
         &self,
-        meeting_uuid: &str,
+        meeting_uuid: impl AsRef<str>,
     ) -> Result<crate::types::CloudArchivedFiles> {
+        let meeting_uuid = meeting_uuid.as_ref();
         let url = format!(
             "/past_meetings/{}/archive_files",
             crate::progenitor_support::encode_path(meeting_uuid),
@@ -31,7 +31,8 @@ impl Billing {
     *
     * * `account_id: &str` -- User's first name.
     */
-    pub async fn account(&self, account_id: &str) -> Result<crate::types::Contact> {
+    pub async fn account(&self, account_id: impl AsRef<str>) -> Result<crate::types::Contact> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/billing",
             crate::progenitor_support::encode_path(account_id),
@@ -62,9 +63,10 @@ impl Billing {
     */
     pub async fn account_update(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::BillingContact,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/billing",
             crate::progenitor_support::encode_path(account_id),
@@ -93,8 +95,9 @@ impl Billing {
     */
     pub async fn account_plans(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::AccountPlansResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans",
             crate::progenitor_support::encode_path(account_id),
@@ -118,9 +121,10 @@ impl Billing {
     */
     pub async fn account_plan_create(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AccountPlanCreateRequestAllOf,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans",
             crate::progenitor_support::encode_path(account_id),
@@ -151,9 +155,10 @@ impl Billing {
     */
     pub async fn account_plan_base_update(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::PlanBase,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans/base",
             crate::progenitor_support::encode_path(account_id),
@@ -188,9 +193,10 @@ impl Billing {
     */
     pub async fn account_plan_addon_update(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::PlanBase,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans/addons",
             crate::progenitor_support::encode_path(account_id),
@@ -220,9 +226,10 @@ impl Billing {
     */
     pub async fn account_plan_addon_create(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AccountPlanAddonCreateRequestOneOf,
     ) -> Result<crate::types::AccountPlans> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans/addons",
             crate::progenitor_support::encode_path(account_id),
@@ -254,9 +261,10 @@ impl Billing {
     */
     pub async fn account_plan_base_delete(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AccountPlanBaseDeleteRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans/base/status",
             crate::progenitor_support::encode_path(account_id),
@@ -286,9 +294,10 @@ impl Billing {
     */
     pub async fn account_plan_addon_cancel(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AccountPlanAddonCancelRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans/addons/status",
             crate::progenitor_support::encode_path(account_id),
@@ -313,8 +322,9 @@ impl Billing {
     */
     pub async fn get_plan_usage(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::GetPlanUsageResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/plans/usage",
             crate::progenitor_support::encode_path(account_id),
@@ -346,22 +356,24 @@ impl Billing {
     */
     pub async fn account_invoice(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::AccountBillingInvoicesResponseData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/billing/invoices?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/billing/invoices",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -383,9 +395,11 @@ impl Billing {
     */
     pub async fn get_account_invoice(
         &self,
-        account_id: &str,
-        invoice_id: &str,
+        account_id: impl AsRef<str>,
+        invoice_id: impl AsRef<str>,
     ) -> Result<crate::types::GetAccountBillingInvoiceResponse> {
+        let account_id = account_id.as_ref();
+        let invoice_id = invoice_id.as_ref();
         let url = format!(
             "/accounts/{}/billing/invoices/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -406,7 +420,8 @@ impl Billing {
     * * You can make **one** request to this API every **30 minutes** until the daily limit is reached.
     * * This API has a daily limit of **100 requests per account**.
     */
-    pub async fn download_invoice_pdf(&self, invoice_id: &str) -> Result<()> {
+    pub async fn download_invoice_pdf(&self, invoice_id: impl AsRef<str>) -> Result<()> {
+        let invoice_id = invoice_id.as_ref();
         let url = format!(
             "/api/download/billing/invoices/{}",
             crate::progenitor_support::encode_path(invoice_id),
@@ -33,22 +33,24 @@ impl ChatChannels {
     */
     pub async fn get_channels(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Channels>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/channels?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/chat/users/{}/channels",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetChannelsResponse = self.client.get(&url, None).await?;
@@ -72,7 +74,11 @@ impl ChatChannels {
     *
     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> This API supports both user-managed apps and account-level apps. However, in an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, to list channels of another user in the same Zoom account, the user calling this API must have a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for the <b>Chat channels</b> feature.</p>
     */
-    pub async fn get_all_channels(&self, user_id: &str) -> Result<Vec<crate::types::Channels>> {
+    pub async fn get_all_channels(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Vec<crate::types::Channels>> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels",
             crate::progenitor_support::encode_path(user_id),
@@ -85,18 +91,13 @@ impl ChatChannels {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             channels.append(&mut resp.channels);
 
@@ -130,9 +131,10 @@ impl ChatChannels {
     */
     pub async fn create_channel(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::CreateChannelRequest,
     ) -> Result<crate::types::CreateChannelResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels",
             crate::progenitor_support::encode_path(user_id),
@@ -162,7 +164,11 @@ impl ChatChannels {
     *
     * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
     */
-    pub async fn get_user_level_channel(&self, channel_id: &str) -> Result<crate::types::Channel> {
+    pub async fn get_user_level_channel(
+        &self,
+        channel_id: impl AsRef<str>,
+    ) -> Result<crate::types::Channel> {
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/channels/{}",
             crate::progenitor_support::encode_path(channel_id),
@@ -190,7 +196,8 @@ impl ChatChannels {
     *
     * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
     */
-    pub async fn delete_user_level_channel(&self, channel_id: &str) -> Result<()> {
+    pub async fn delete_user_level_channel(&self, channel_id: impl AsRef<str>) -> Result<()> {
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/channels/{}",
             crate::progenitor_support::encode_path(channel_id),
@@ -219,9 +226,10 @@ impl ChatChannels {
     */
     pub async fn update_user_level_channel(
         &self,
-        channel_id: &str,
+        channel_id: impl AsRef<str>,
         body: &crate::types::Attendees,
     ) -> Result<()> {
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/channels/{}",
             crate::progenitor_support::encode_path(channel_id),
@@ -253,9 +261,11 @@ impl ChatChannels {
     */
     pub async fn remove_user_level_channel_member(
         &self,
-        channel_id: &str,
-        member_id: &str,
+        channel_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
     ) -> Result<()> {
+        let channel_id = channel_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/chat/channels/{}/members/{}",
             crate::progenitor_support::encode_path(channel_id),
@@ -283,8 +293,9 @@ impl ChatChannels {
     */
     pub async fn join_channel(
         &self,
-        channel_id: &str,
+        channel_id: impl AsRef<str>,
     ) -> Result<crate::types::JoinChannelResponse> {
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/channels/{}/members/me",
             crate::progenitor_support::encode_path(channel_id),
@@ -309,7 +320,8 @@ impl ChatChannels {
     *
     * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
     */
-    pub async fn leave_channel(&self, channel_id: &str) -> Result<()> {
+    pub async fn leave_channel(&self, channel_id: impl AsRef<str>) -> Result<()> {
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/channels/{}/members/me",
             crate::progenitor_support::encode_path(channel_id),
@@ -32,9 +32,11 @@ impl ChatChannelsAccountLevel {
     */
     pub async fn get_channel(
         &self,
-        user_id: &str,
-        channel_id: &str,
+        user_id: impl AsRef<str>,
+        channel_id: impl AsRef<str>,
     ) -> Result<crate::types::Channel> {
+        let user_id = user_id.as_ref();
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -61,7 +63,13 @@ impl ChatChannelsAccountLevel {
     *
     * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
     */
-    pub async fn delete_channel(&self, user_id: &str, channel_id: &str) -> Result<()> {
+    pub async fn delete_channel(
+        &self,
+        user_id: impl AsRef<str>,
+        channel_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -91,10 +99,12 @@ impl ChatChannelsAccountLevel {
     */
     pub async fn update_channel(
         &self,
-        user_id: &str,
-        channel_id: &str,
+        user_id: impl AsRef<str>,
+        channel_id: impl AsRef<str>,
         body: &crate::types::Attendees,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -126,24 +136,27 @@ impl ChatChannelsAccountLevel {
     */
     pub async fn list_channel_members(
         &self,
-        user_id: &str,
-        channel_id: &str,
+        user_id: impl AsRef<str>,
+        channel_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let channel_id = channel_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/channels/{}/members?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(channel_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/chat/users/{}/channels/{}/members",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListChannelMembersResponseData =
@@ -168,9 +181,11 @@ impl ChatChannelsAccountLevel {
     */
     pub async fn list_all_channel_members(
         &self,
-        user_id: &str,
-        channel_id: &str,
+        user_id: impl AsRef<str>,
+        channel_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
+        let user_id = user_id.as_ref();
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels/{}/members",
             crate::progenitor_support::encode_path(user_id),
@@ -185,18 +200,13 @@ impl ChatChannelsAccountLevel {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             members.append(&mut resp.members);
 
@@ -229,10 +239,12 @@ impl ChatChannelsAccountLevel {
     */
     pub async fn invite_channel_members(
         &self,
-        user_id: &str,
-        channel_id: &str,
+        user_id: impl AsRef<str>,
+        channel_id: impl AsRef<str>,
         body: &crate::types::InviteChannelMembersRequest,
     ) -> Result<crate::types::InviteChannelMembersResponse> {
+        let user_id = user_id.as_ref();
+        let channel_id = channel_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels/{}/members",
             crate::progenitor_support::encode_path(user_id),
@@ -265,10 +277,13 @@ impl ChatChannelsAccountLevel {
     */
     pub async fn remove_channel_member(
         &self,
-        user_id: &str,
-        channel_id: &str,
-        member_id: &str,
+        user_id: impl AsRef<str>,
+        channel_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let channel_id = channel_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/chat/users/{}/channels/{}/members/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -44,7 +44,7 @@ impl ChatMessages {
     */
     pub async fn get_page(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         to_contact: &str,
         to_channel: &str,
         date: chrono::NaiveDate,
@@ -52,33 +52,35 @@ impl ChatMessages {
         next_page_token: &str,
         include_deleted_and_edited_message: &str,
     ) -> Result<Vec<crate::types::Messages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.to_string().is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !include_deleted_and_edited_message.is_empty() {
             query_args.push((
-                "include_deleted_and_edited_message".to_string(),
+                "include_deleted_and_edited_message",
                 include_deleted_and_edited_message.to_string(),
             ));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/chat/users/{}/messages",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetChatMessagesResponse = self.client.get(&url, None).await?;
@@ -107,33 +109,35 @@ impl ChatMessages {
     */
     pub async fn get_all(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         to_contact: &str,
         to_channel: &str,
         date: chrono::NaiveDate,
         include_deleted_and_edited_message: &str,
     ) -> Result<Vec<crate::types::Messages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.to_string().is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !include_deleted_and_edited_message.is_empty() {
             query_args.push((
-                "include_deleted_and_edited_message".to_string(),
+                "include_deleted_and_edited_message",
                 include_deleted_and_edited_message.to_string(),
             ));
         }
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/chat/users/{}/messages",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::GetChatMessagesResponse = self.client.get(&url, None).await?;
@@ -143,18 +147,13 @@ impl ChatMessages {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             messages.append(&mut resp.messages);
 
@@ -184,9 +183,10 @@ impl ChatMessages {
     */
     pub async fn senda(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::SendaChatMessageRequest,
     ) -> Result<crate::types::Groups> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/chat/users/{}/messages",
             crate::progenitor_support::encode_path(user_id),
@@ -215,10 +215,12 @@ impl ChatMessages {
     */
     pub async fn mark_message(
         &self,
-        user_id: &str,
-        message_id: &str,
+        user_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
         body: &crate::types::MarkMessageRequest,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let message_id = message_id.as_ref();
         let url = format!(
             "/chat/users/{}/messages/{}/status",
             crate::progenitor_support::encode_path(user_id),
@@ -248,10 +250,12 @@ impl ChatMessages {
     */
     pub async fn react_message(
         &self,
-        user_id: &str,
-        message_id: &str,
+        user_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
         body: &crate::types::ReactMessageRequest,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let message_id = message_id.as_ref();
         let url = format!(
             "/chat/users/{}/messages/{}/emoji_reactions",
             crate::progenitor_support::encode_path(user_id),
@@ -286,24 +290,27 @@ impl ChatMessages {
     */
     pub async fn get(
         &self,
-        user_id: &str,
-        message_id: &str,
+        user_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
         to_contact: &str,
         to_channel: &str,
     ) -> Result<crate::types::GetChatMessageResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let message_id = message_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(message_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/chat/users/{}/messages/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -329,10 +336,12 @@ impl ChatMessages {
     */
     pub async fn edit_message(
         &self,
-        user_id: &str,
-        message_id: &str,
+        user_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
         body: &crate::types::EditMessageRequest,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let message_id = message_id.as_ref();
         let url = format!(
             "/chat/users/{}/messages/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -374,24 +383,27 @@ impl ChatMessages {
     */
     pub async fn delete(
         &self,
-        user_id: &str,
-        message_id: &str,
+        user_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
         to_contact: &str,
         to_channel: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let message_id = message_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(message_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/chat/users/{}/messages/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -52,9 +52,10 @@ impl ChatbotMessages {
     */
     pub async fn edit(
         &self,
-        message_id: &str,
+        message_id: impl AsRef<str>,
         body: &crate::types::EditChatbotMessageRequest,
     ) -> Result<crate::types::EditChatbotMessageResponse> {
+        let message_id = message_id.as_ref();
         let url = format!(
             "/im/chat/messages/{}",
             crate::progenitor_support::encode_path(message_id),
@@ -76,9 +77,10 @@ impl ChatbotMessages {
     */
     pub async fn delete(
         &self,
-        message_id: &str,
+        message_id: impl AsRef<str>,
         body: &crate::types::DeleteChatbotMessageRequest,
     ) -> Result<crate::types::DeleteChatbotMessageResponse> {
+        let message_id = message_id.as_ref();
         let url = format!(
             "/im/chat/messages/{}",
             crate::progenitor_support::encode_path(message_id),
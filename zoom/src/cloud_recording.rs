@@ -46,7 +46,7 @@ impl CloudRecording {
     */
     pub async fn recordings_list(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
         mc: &str,
@@ -55,33 +55,35 @@ impl CloudRecording {
         to: chrono::NaiveDate,
         trash_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !mc.is_empty() {
-            query_args.push(("mc".to_string(), mc.to_string()));
+            query_args.push(("mc", mc.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if trash {
-            query_args.push(("trash".to_string(), trash.to_string()));
+            query_args.push(("trash", trash.to_string()));
         }
         if !trash_type.is_empty() {
-            query_args.push(("trash_type".to_string(), trash_type.to_string()));
+            query_args.push(("trash_type", trash_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/recordings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/recordings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -110,22 +112,24 @@ impl CloudRecording {
     */
     pub async fn recording_get(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         include_fields: &str,
         ttl: u64,
     ) -> Result<crate::types::RecordingGetResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !ttl.to_string().is_empty() {
-            query_args.push(("ttl".to_string(), ttl.to_string()));
+            query_args.push(("ttl", ttl.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/recordings",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -155,18 +159,20 @@ impl CloudRecording {
     */
     pub async fn recording_delete(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         action: crate::types::RecordingDeleteAction,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.to_string().is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/recordings",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -193,20 +199,23 @@ impl CloudRecording {
     */
     pub async fn recording_delete_one(
         &self,
-        meeting_id: &str,
-        recording_id: &str,
+        meeting_id: impl AsRef<str>,
+        recording_id: impl AsRef<str>,
         action: crate::types::RecordingDeleteAction,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let recording_id = recording_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.to_string().is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings/{}?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            crate::progenitor_support::encode_path(recording_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/recordings/{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                crate::progenitor_support::encode_path(recording_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -234,9 +243,10 @@ impl CloudRecording {
     */
     pub async fn recording_status_update(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         body: &crate::types::RecordingStatusUpdateBodyRequest,
     ) -> Result<()> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/recordings/status",
             crate::progenitor_support::encode_path(meeting_id),
@@ -269,10 +279,12 @@ impl CloudRecording {
     */
     pub async fn recording_status_update_one(
         &self,
-        meeting_id: &str,
-        recording_id: &str,
+        meeting_id: impl AsRef<str>,
+        recording_id: impl AsRef<str>,
         body: &crate::types::RecordingStatusUpdateBodyRequest,
     ) -> Result<()> {
+        let meeting_id = meeting_id.as_ref();
+        let recording_id = recording_id.as_ref();
         let url = format!(
             "/meetings/{}/recordings/{}/status",
             crate::progenitor_support::encode_path(meeting_id),
@@ -304,8 +316,9 @@ impl CloudRecording {
     */
     pub async fn recording_setting_update(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
     ) -> Result<crate::types::RecordingSettings> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/recordings/settings",
             crate::progenitor_support::encode_path(meeting_id),
@@ -334,9 +347,10 @@ impl CloudRecording {
     */
     pub async fn recording_settings_update(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         body: &crate::types::RecordingSettings,
     ) -> Result<()> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/recordings/settings",
             crate::progenitor_support::encode_path(meeting_id),
@@ -381,24 +395,25 @@ impl CloudRecording {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings/registrants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/recordings/registrants",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -430,7 +445,7 @@ impl CloudRecording {
     ) -> Result<crate::types::MeetingRecordingRegistrantCreateResponse> {
         let url = format!(
             "/meetings/{}/recordings/registrants",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client.post(&url, None).await
@@ -462,7 +477,7 @@ impl CloudRecording {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/recordings/registrants/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client
@@ -493,8 +508,9 @@ impl CloudRecording {
     */
     pub async fn recording_registrants_questions_get(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
     ) -> Result<crate::types::RecordingRegistrantQuestionsData> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/recordings/registrants/questions",
             crate::progenitor_support::encode_path(meeting_id),
@@ -525,9 +541,10 @@ impl CloudRecording {
     */
     pub async fn recording_registrant_question_update(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         body: &crate::types::RecordingRegistrantQuestionsData,
     ) -> Result<()> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/recordings/registrants/questions",
             crate::progenitor_support::encode_path(meeting_id),
@@ -566,30 +583,32 @@ impl CloudRecording {
     */
     pub async fn get_account(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
         from: Option<chrono::DateTime<chrono::Utc>>,
         to: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = from {
-            query_args.push(("from".to_string(), date.to_rfc3339()));
+            query_args.push(("from", date.to_rfc3339()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if let Some(date) = to {
-            query_args.push(("to".to_string(), date.to_rfc3339()));
+            query_args.push(("to", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/recordings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/recordings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetAccountCloudRecordingResponse =
@@ -621,22 +640,24 @@ impl CloudRecording {
     */
     pub async fn get_all_account(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         from: Option<chrono::DateTime<chrono::Utc>>,
         to: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = from {
-            query_args.push(("from".to_string(), date.to_rfc3339()));
+            query_args.push(("from", date.to_rfc3339()));
         }
         if let Some(date) = to {
-            query_args.push(("to".to_string(), date.to_rfc3339()));
+            query_args.push(("to", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/recordings?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/recordings",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::GetAccountCloudRecordingResponse =
@@ -647,18 +668,13 @@ impl CloudRecording {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             meetings.append(&mut resp.meetings);
 
@@ -39,15 +39,17 @@ impl CommonAreaPhones {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::CommonAreaPhones>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/common_area_phones?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/common_area_phones"),
+            &query_args,
+        );
 
         let resp: crate::types::ListCommonAreaPhonesResponse = self.client.get(&url, None).await?;
 
@@ -84,18 +86,13 @@ impl CommonAreaPhones {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             common_area_phones.append(&mut resp.common_area_phones);
 
@@ -159,8 +156,9 @@ impl CommonAreaPhones {
     */
     pub async fn get(
         &self,
-        common_area_phone_id: &str,
+        common_area_phone_id: impl AsRef<str>,
     ) -> Result<crate::types::GetCommonAreaPhoneResponse> {
+        let common_area_phone_id = common_area_phone_id.as_ref();
         let url = format!(
             "/phone/common_area_phones/{}",
             crate::progenitor_support::encode_path(common_area_phone_id),
@@ -187,7 +185,8 @@ impl CommonAreaPhones {
     *
     * * `common_area_phone_id: &str` -- Unique Identifier of the common area phone.
     */
-    pub async fn delete(&self, common_area_phone_id: &str) -> Result<()> {
+    pub async fn delete(&self, common_area_phone_id: impl AsRef<str>) -> Result<()> {
+        let common_area_phone_id = common_area_phone_id.as_ref();
         let url = format!(
             "/phone/common_area_phones/{}",
             crate::progenitor_support::encode_path(common_area_phone_id),
@@ -215,9 +214,10 @@ impl CommonAreaPhones {
     */
     pub async fn update(
         &self,
-        common_area_phone_id: &str,
+        common_area_phone_id: impl AsRef<str>,
         body: &crate::types::UpdateCommonAreaPhoneRequest,
     ) -> Result<()> {
+        let common_area_phone_id = common_area_phone_id.as_ref();
         let url = format!(
             "/phone/common_area_phones/{}",
             crate::progenitor_support::encode_path(common_area_phone_id),
@@ -244,9 +244,10 @@ impl CommonAreaPhones {
     */
     pub async fn assign_phone_numbers_common_area(
         &self,
-        common_area_phone_id: &str,
+        common_area_phone_id: impl AsRef<str>,
         body: &crate::types::AssignPhoneNumbersCommonAreaRequest,
     ) -> Result<crate::types::AssignPhoneNumbersCommonAreaResponseData> {
+        let common_area_phone_id = common_area_phone_id.as_ref();
         let url = format!(
             "/phone/common_area_phones/{}/phone_numbers",
             crate::progenitor_support::encode_path(common_area_phone_id),
@@ -277,9 +278,11 @@ impl CommonAreaPhones {
     */
     pub async fn unassign_phone_numbers_from_common_area(
         &self,
-        common_area_phone_id: &str,
-        phone_number_id: &str,
+        common_area_phone_id: impl AsRef<str>,
+        phone_number_id: impl AsRef<str>,
     ) -> Result<()> {
+        let common_area_phone_id = common_area_phone_id.as_ref();
+        let phone_number_id = phone_number_id.as_ref();
         let url = format!(
             "/phone/common_area_phones/{}/phone_numbers/{}",
             crate::progenitor_support::encode_path(common_area_phone_id),
@@ -305,9 +308,10 @@ impl CommonAreaPhones {
     */
     pub async fn assign_calling_plans(
         &self,
-        common_area_phone_id: &str,
+        common_area_phone_id: impl AsRef<str>,
         body: &crate::types::AssignCallingPlansCommonAreaPhoneRequestData,
     ) -> Result<crate::types::AssignCallingPlansCommonAreaPhoneResponseData> {
+        let common_area_phone_id = common_area_phone_id.as_ref();
         let url = format!(
             "/phone/common_area_phones/{}/calling_plans",
             crate::progenitor_support::encode_path(common_area_phone_id),
@@ -338,9 +342,11 @@ impl CommonAreaPhones {
     */
     pub async fn unassign_calling_plans_from(
         &self,
-        common_area_phone_id: &str,
-        type_: &str,
+        common_area_phone_id: impl AsRef<str>,
+        type_: impl AsRef<str>,
     ) -> Result<()> {
+        let common_area_phone_id = common_area_phone_id.as_ref();
+        let type_ = type_.as_ref();
         let url = format!(
             "/phone/common_area_phones/{}/calling_plans/{}",
             crate::progenitor_support::encode_path(common_area_phone_id),
@@ -37,24 +37,20 @@ impl Contacts {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Contacts>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !query_presence_status.is_empty() {
-            query_args.push((
-                "query_presence_status".to_string(),
-                query_presence_status.to_string(),
-            ));
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
         }
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contacts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/contacts"), &query_args);
 
         let resp: crate::types::SearchCompanyContactsResponse = self.client.get(&url, None).await?;
 
@@ -80,18 +76,14 @@ impl Contacts {
         search_key: &str,
         query_presence_status: &str,
     ) -> Result<Vec<crate::types::Contacts>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query_presence_status.is_empty() {
-            query_args.push((
-                "query_presence_status".to_string(),
-                query_presence_status.to_string(),
-            ));
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
         }
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contacts?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/contacts"), &query_args);
 
         let mut resp: crate::types::SearchCompanyContactsResponse =
             self.client.get(&url, None).await?;
@@ -101,18 +93,13 @@ impl Contacts {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             contacts.append(&mut resp.contacts);
 
@@ -154,18 +141,20 @@ impl Contacts {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat/users/me/contacts?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/chat/users/me/contacts"),
+            &query_args,
+        );
 
         let resp: crate::types::GetUserContactsResponseData = self.client.get(&url, None).await?;
 
@@ -192,12 +181,14 @@ impl Contacts {
         &self,
         type_: &str,
     ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat/users/me/contacts?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/chat/users/me/contacts"),
+            &query_args,
+        );
 
         let mut resp: crate::types::GetUserContactsResponseData =
             self.client.get(&url, None).await?;
@@ -207,18 +198,13 @@ impl Contacts {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             contacts.append(&mut resp.contacts);
 
@@ -253,21 +239,20 @@ impl Contacts {
     */
     pub async fn get_user_contacts(
         &self,
-        contact_id: &str,
+        contact_id: impl AsRef<str>,
         query_presence_status: bool,
     ) -> Result<crate::types::GetUserContactResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let contact_id = contact_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if query_presence_status {
-            query_args.push((
-                "query_presence_status".to_string(),
-                query_presence_status.to_string(),
-            ));
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/me/contacts/{}?{}",
-            crate::progenitor_support::encode_path(contact_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/chat/users/me/contacts/{}",
+                crate::progenitor_support::encode_path(contact_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::quality_metrics::{MeetingQualityScores, QualityScore, QualityScores};
 use crate::Client;
 
 pub struct Dashboards {
@@ -43,27 +44,27 @@ impl Dashboards {
         next_page_token: &str,
         include_fields: crate::types::IncludeFields,
     ) -> Result<crate::types::DashboardMeetingsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/meetings?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/metrics/meetings"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -90,18 +91,20 @@ impl Dashboards {
     */
     pub async fn meeting_detail(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         type_: crate::types::DashboardMeetingsType,
     ) -> Result<crate::types::MeetingMetric> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/meetings/{}",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -133,30 +136,32 @@ impl Dashboards {
     */
     pub async fn meeting_participant(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         type_: crate::types::DashboardMeetingsType,
         page_size: i64,
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> Result<crate::types::DashboardMeetingParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/meetings/{}/participants",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -186,20 +191,23 @@ impl Dashboards {
     */
     pub async fn meeting_participant_qo(
         &self,
-        meeting_id: &str,
-        participant_id: &str,
+        meeting_id: impl AsRef<str>,
+        participant_id: impl AsRef<str>,
         type_: crate::types::DashboardMeetingsType,
     ) -> Result<crate::types::ParticipantQos> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let participant_id = participant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/{}/qos?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            crate::progenitor_support::encode_path(participant_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/meetings/{}/participants/{}/qos",
+                crate::progenitor_support::encode_path(meeting_id),
+                crate::progenitor_support::encode_path(participant_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -229,26 +237,28 @@ impl Dashboards {
     */
     pub async fn meeting_participants_qo(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         type_: crate::types::DashboardMeetingsType,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/qos?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/meetings/{}/participants/qos",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -278,26 +288,28 @@ impl Dashboards {
     */
     pub async fn meeting_participant_share(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         type_: crate::types::DashboardMeetingsType,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/sharing?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/meetings/{}/participants/sharing",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -333,24 +345,24 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardWebinarsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/webinars?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/metrics/webinars"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -377,18 +389,20 @@ impl Dashboards {
     */
     pub async fn webinar_detail(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         type_: crate::types::DashboardWebinarsType,
     ) -> Result<crate::types::Webinars> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/webinars/{}",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -419,30 +433,32 @@ impl Dashboards {
     */
     pub async fn webinar_participant(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         type_: crate::types::DashboardWebinarsType,
         page_size: i64,
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> Result<crate::types::DashboardWebinarParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -470,20 +486,23 @@ impl Dashboards {
     */
     pub async fn webinar_participant_qo(
         &self,
-        webinar_id: &str,
-        participant_id: &str,
+        webinar_id: impl AsRef<str>,
+        participant_id: impl AsRef<str>,
         type_: crate::types::DashboardWebinarsType,
     ) -> Result<crate::types::ParticipantQos> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let participant_id = participant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/{}/qos?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            crate::progenitor_support::encode_path(participant_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/webinars/{}/participants/{}/qos",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(participant_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -513,26 +532,28 @@ impl Dashboards {
     */
     pub async fn webinar_participants_qo(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         type_: crate::types::DashboardWebinarsType,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/qos?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/webinars/{}/participants/qos",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -562,26 +583,28 @@ impl Dashboards {
     */
     pub async fn webinar_participant_share(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         type_: crate::types::DashboardWebinarsType,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/sharing?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/webinars/{}/participants/sharing",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -614,18 +637,18 @@ impl Dashboards {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/zoomrooms?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/metrics/zoomrooms"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -654,30 +677,32 @@ impl Dashboards {
     */
     pub async fn zoom_room_dashboards(
         &self,
-        zoomroom_id: &str,
+        zoomroom_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let zoomroom_id = zoomroom_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/zoomrooms/{}?{}",
-            crate::progenitor_support::encode_path(zoomroom_id),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/zoomrooms/{}",
+                crate::progenitor_support::encode_path(zoomroom_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -707,15 +732,14 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/crc?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/metrics/crc"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -749,21 +773,20 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardImResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/im?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/metrics/im"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -799,21 +822,20 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardChatResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/chat?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/metrics/chat"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -843,15 +865,17 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::DashboardClientFeedbackResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/client/feedback?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/metrics/client/feedback"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -880,15 +904,17 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/zoomrooms/issues?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/metrics/zoomrooms/issues"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -915,15 +941,17 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::DashboardIssueZoomRoomResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/issues/zoomrooms?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/metrics/issues/zoomrooms"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -951,30 +979,32 @@ impl Dashboards {
     */
     pub async fn issue_detail_zoom_room(
         &self,
-        zoomroom_id: &str,
+        zoomroom_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardIssueDetailZoomRoomResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let zoomroom_id = zoomroom_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/issues/zoomrooms/{}?{}",
-            crate::progenitor_support::encode_path(zoomroom_id),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/issues/zoomrooms/{}",
+                crate::progenitor_support::encode_path(zoomroom_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1006,30 +1036,32 @@ impl Dashboards {
     */
     pub async fn client_feedback_detail(
         &self,
-        feedback_id: &str,
+        feedback_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardClientFeedbackDetailResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let feedback_id = feedback_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/client/feedback/{}?{}",
-            crate::progenitor_support::encode_path(feedback_id),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/client/feedback/{}",
+                crate::progenitor_support::encode_path(feedback_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1056,15 +1088,17 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::ListMeetingSatisfactionResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/client/satisfaction?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/metrics/client/satisfaction"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -1110,27 +1144,29 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !quality_type.is_empty() {
-            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+            query_args.push(("quality_type", quality_type.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/metrics/call_logs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/metrics/call_logs"),
+            &query_args,
+        );
 
         let resp: crate::types::ListCallLogsMetricsResponseData =
             self.client.get(&url, None).await?;
@@ -1164,21 +1200,23 @@ impl Dashboards {
         site_id: &str,
         quality_type: &str,
     ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !quality_type.is_empty() {
-            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+            query_args.push(("quality_type", quality_type.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/metrics/call_logs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/metrics/call_logs"),
+            &query_args,
+        );
 
         let mut resp: crate::types::ListCallLogsMetricsResponseData =
             self.client.get(&url, None).await?;
@@ -1188,18 +1226,13 @@ impl Dashboards {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             call_logs.append(&mut resp.call_logs);
 
@@ -1237,8 +1270,9 @@ impl Dashboards {
     */
     pub async fn get_call_log_metrics_details(
         &self,
-        call_id: &str,
+        call_id: impl AsRef<str>,
     ) -> Result<crate::types::ListCallLogsMetricsResponse> {
+        let call_id = call_id.as_ref();
         let url = format!(
             "/phone/metrics/call_logs/{}",
             crate::progenitor_support::encode_path(call_id),
@@ -1264,7 +1298,11 @@ impl Dashboards {
     *
     * * `call_id: &str` -- Unique identifier of the call.
     */
-    pub async fn get_call_qo(&self, call_id: &str) -> Result<crate::types::GetCallQoSResponse> {
+    pub async fn get_call_qo(
+        &self,
+        call_id: impl AsRef<str>,
+    ) -> Result<crate::types::GetCallQoSResponse> {
+        let call_id = call_id.as_ref();
         let url = format!(
             "/phone/metrics/call_logs/{}/qos",
             crate::progenitor_support::encode_path(call_id),
@@ -1301,26 +1339,28 @@ impl Dashboards {
     */
     pub async fn participant_feedback(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         type_: crate::types::DashboardMeetingsType,
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ParticipantFeedbackResponse = self.client.get(&url, None).await?;
@@ -1348,18 +1388,20 @@ impl Dashboards {
     */
     pub async fn get_all_participant_feedback(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         type_: crate::types::DashboardMeetingsType,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ParticipantFeedbackResponse =
@@ -1370,18 +1412,13 @@ impl Dashboards {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             participants.append(&mut resp.participants);
 
@@ -1428,23 +1465,25 @@ impl Dashboards {
         type_: crate::types::DashboardMeetingsType,
         page_size: i64,
         next_page_token: &str,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ParticipantFeedbackResponse = self.client.get(&url, None).await?;
@@ -1474,17 +1513,19 @@ impl Dashboards {
     pub async fn get_all_participant_webinar_feedback(
         &self,
         type_: crate::types::DashboardMeetingsType,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ParticipantFeedbackResponse =
@@ -1495,18 +1536,13 @@ impl Dashboards {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             participants.append(&mut resp.participants);
 
@@ -33,8 +33,9 @@ impl DeprecatedApiEndpoints {
     */
     pub async fn list_past_meeting_files(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
     ) -> Result<crate::types::ListPastMeetingFilesResponse> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/past_meetings/{}/files",
             crate::progenitor_support::encode_path(meeting_id),
@@ -64,8 +65,9 @@ impl DeprecatedApiEndpoints {
     */
     pub async fn list_past_webinar_files(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::ListPastMeetingFilesResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/past_webinars/{}/files",
             crate::progenitor_support::encode_path(webinar_id),
@@ -37,18 +37,17 @@ impl Devices {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/h323/devices?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/h323/devices"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -84,7 +83,8 @@ impl Devices {
     *
     * * `device_id: &str` -- User's first name.
     */
-    pub async fn delete(&self, device_id: &str) -> Result<()> {
+    pub async fn delete(&self, device_id: impl AsRef<str>) -> Result<()> {
+        let device_id = device_id.as_ref();
         let url = format!(
             "/h323/devices/{}",
             crate::progenitor_support::encode_path(device_id),
@@ -107,7 +107,12 @@ impl Devices {
     *
     * * `device_id: &str` -- User's first name.
     */
-    pub async fn update(&self, device_id: &str, body: &crate::types::Device) -> Result<()> {
+    pub async fn update(
+        &self,
+        device_id: impl AsRef<str>,
+        body: &crate::types::Device,
+    ) -> Result<()> {
+        let device_id = device_id.as_ref();
         let url = format!(
             "/h323/devices/{}",
             crate::progenitor_support::encode_path(device_id),
@@ -67,7 +67,8 @@ impl Groups {
     * * `group_id: &str` -- The group ID.<br>
     *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
     */
-    pub async fn group(&self, group_id: &str) -> Result<crate::types::GroupResponse> {
+    pub async fn group(&self, group_id: impl AsRef<str>) -> Result<crate::types::GroupResponse> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -93,7 +94,8 @@ impl Groups {
     * * `group_id: &str` -- The group ID.<br>
     *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
     */
-    pub async fn delete(&self, group_id: &str) -> Result<()> {
+    pub async fn delete(&self, group_id: impl AsRef<str>) -> Result<()> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -121,9 +123,10 @@ impl Groups {
     */
     pub async fn update(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::GroupCreateRequest,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -159,26 +162,28 @@ impl Groups {
     */
     pub async fn members(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         page_size: i64,
         page_number: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::UserCreateResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GroupMembersResponseData = self.client.get(&url, None).await?;
@@ -203,8 +208,9 @@ impl Groups {
     */
     pub async fn get_all_members(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::UserCreateResponse>> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/groups/{}/members",
             crate::progenitor_support::encode_path(group_id),
@@ -217,18 +223,13 @@ impl Groups {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             members.append(&mut resp.members);
 
@@ -262,9 +263,10 @@ impl Groups {
     */
     pub async fn members_create(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::AddRoleMembersRequest,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/groups/{}/members",
             crate::progenitor_support::encode_path(group_id),
@@ -293,7 +295,13 @@ impl Groups {
     *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
     * * `member_id: &str` -- User's first name.
     */
-    pub async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()> {
+    pub async fn members_delete(
+        &self,
+        group_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/groups/{}/members/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -328,10 +336,12 @@ impl Groups {
     */
     pub async fn update_member(
         &self,
-        group_id: &str,
-        member_id: &str,
+        group_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
         body: &crate::types::UpdateGroupMemberRequest,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/groups/{}/members/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -364,25 +374,24 @@ impl Groups {
     */
     pub async fn get_settings_domains(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: crate::types::OptionData,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -409,25 +418,24 @@ impl Groups {
     */
     pub async fn get_settings_meeting_security(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: crate::types::OptionData,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -454,25 +462,24 @@ impl Groups {
     */
     pub async fn get_settings_group_response(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: crate::types::OptionData,
     ) -> Result<crate::types::GetGroupSettingsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -499,25 +506,24 @@ impl Groups {
     */
     pub async fn get_setting(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: crate::types::OptionData,
     ) -> Result<crate::types::GetGroupSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -541,26 +547,25 @@ impl Groups {
     */
     pub async fn update_settings(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: crate::types::UpdateGroupSettingsOption,
         body: &crate::types::UpdateGroupSettingsRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -587,25 +592,24 @@ impl Groups {
     */
     pub async fn get_lock_settings_meeting_security(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: &str,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -630,25 +634,24 @@ impl Groups {
     */
     pub async fn get_lock_settings_group_response(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: &str,
     ) -> Result<crate::types::GetGroupLockSettingsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -673,25 +676,24 @@ impl Groups {
     */
     pub async fn get_lock_setting(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: &str,
     ) -> Result<crate::types::GetGroupLockSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -716,26 +718,25 @@ impl Groups {
     */
     pub async fn locked_settings(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         custom_query_fields: &str,
         option: &str,
         body: &crate::types::GroupLockedSettingsRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -766,18 +767,20 @@ impl Groups {
     pub async fn upload_vb(
         &self,
         file_ids: &str,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::UploadVbRequest,
     ) -> Result<crate::types::Files> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -804,16 +807,18 @@ impl Groups {
     * * `group_id: &str` -- Unique identifier of the group. Retrieve the value for this field by calling the [List groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups) API.
     * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
     */
-    pub async fn del_vb(&self, file_ids: &str, group_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn del_vb(&self, file_ids: &str, group_id: impl AsRef<str>) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/groups/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -40,21 +40,21 @@ impl ImChat {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::ImChatSessionsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/im/chat/sessions?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/im/chat/sessions"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -87,30 +87,32 @@ impl ImChat {
     */
     pub async fn message(
         &self,
-        session_id: &str,
+        session_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::ImChatMessagesResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let session_id = session_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/chat/sessions/{}?{}",
-            crate::progenitor_support::encode_path(session_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/im/chat/sessions/{}",
+                crate::progenitor_support::encode_path(session_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -139,34 +141,36 @@ impl ImChat {
     */
     pub async fn list_im_messages(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         chat_user: &str,
         channel: &str,
         date: &str,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !chat_user.is_empty() {
-            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+            query_args.push(("chat_user", chat_user.to_string()));
         }
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/users/{}/chat/messages?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/im/users/{}/chat/messages",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListimmessagesResponse = self.client.get(&url, None).await?;
@@ -191,26 +195,28 @@ impl ImChat {
     */
     pub async fn list_all_im_messages(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         chat_user: &str,
         channel: &str,
         date: &str,
     ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !chat_user.is_empty() {
-            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+            query_args.push(("chat_user", chat_user.to_string()));
         }
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/users/{}/chat/messages?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/im/users/{}/chat/messages",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ListimmessagesResponse = self.client.get(&url, None).await?;
@@ -220,18 +226,13 @@ impl ImChat {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             messages.append(&mut resp.messages);
 
@@ -264,12 +265,14 @@ impl ImChat {
         chat_user: &str,
         body: &crate::types::SendimmessagesRequest,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !chat_user.is_empty() {
-            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+            query_args.push(("chat_user", chat_user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/im/users/me/chat/messages?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/im/users/me/chat/messages"),
+            &query_args,
+        );
 
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
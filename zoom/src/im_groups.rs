@@ -59,7 +59,11 @@ impl ImGroups {
     * * `group_id: &str` -- The group ID.<br>
     *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
     */
-    pub async fn im_group(&self, group_id: &str) -> Result<crate::types::ImGroupResponseAllOf> {
+    pub async fn im_group(
+        &self,
+        group_id: impl AsRef<str>,
+    ) -> Result<crate::types::ImGroupResponseAllOf> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/im/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -83,7 +87,8 @@ impl ImGroups {
     * * `group_id: &str` -- The group ID.<br>
     *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
     */
-    pub async fn delete(&self, group_id: &str) -> Result<()> {
+    pub async fn delete(&self, group_id: impl AsRef<str>) -> Result<()> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/im/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -109,9 +114,10 @@ impl ImGroups {
     */
     pub async fn update(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::ImGroupCreateRequest,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/im/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -145,26 +151,28 @@ impl ImGroups {
     */
     pub async fn member(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         page_size: i64,
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let group_id = group_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(group_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/im/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -187,9 +195,10 @@ impl ImGroups {
     */
     pub async fn members_create(
         &self,
-        group_id: &str,
+        group_id: impl AsRef<str>,
         body: &crate::types::AddRoleMembersRequest,
     ) -> Result<()> {
+        let group_id = group_id.as_ref();
         let url = format!(
             "/im/groups/{}/members",
             crate::progenitor_support::encode_path(group_id),
@@ -216,7 +225,13 @@ impl ImGroups {
     *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
     * * `member_id: &str` -- User's first name.
     */
-    pub async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()> {
+    pub async fn members_delete(
+        &self,
+        group_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let group_id = group_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/im/groups/{}/members/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::registrants::MeetingRegistrant;
 use crate::Client;
 
 pub struct Meetings {
@@ -33,30 +34,32 @@ impl Meetings {
     */
     pub async fn get(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         type_: crate::types::MeetingsType,
         page_size: i64,
         next_page_token: &str,
         page_number: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !page_number.is_empty() {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/meetings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/meetings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -86,9 +89,10 @@ impl Meetings {
     */
     pub async fn create(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::MeetingCreate,
     ) -> Result<crate::types::MeetingCreateResponseAllOf> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/meetings",
             crate::progenitor_support::encode_path(user_id),
@@ -125,21 +129,22 @@ impl Meetings {
         occurrence_id: &str,
         show_previous_occurrences: bool,
     ) -> Result<crate::types::MeetingResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if show_previous_occurrences {
             query_args.push((
-                "show_previous_occurrences".to_string(),
+                "show_previous_occurrences",
                 show_previous_occurrences.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -177,27 +182,25 @@ impl Meetings {
         schedule_for_reminder: bool,
         cancel_meeting_reminder: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cancel_meeting_reminder.is_empty() {
             query_args.push((
-                "cancel_meeting_reminder".to_string(),
+                "cancel_meeting_reminder",
                 cancel_meeting_reminder.to_string(),
             ));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if schedule_for_reminder {
-            query_args.push((
-                "schedule_for_reminder".to_string(),
-                schedule_for_reminder.to_string(),
-            ));
+            query_args.push(("schedule_for_reminder", schedule_for_reminder.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -225,15 +228,16 @@ impl Meetings {
         occurrence_id: &str,
         body: &crate::types::MeetingUpdateRequestAllOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -263,7 +267,7 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client
@@ -304,27 +308,28 @@ impl Meetings {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/registrants",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -355,15 +360,16 @@ impl Meetings {
         meeting_id: i64,
         occurrence_ids: &str,
     ) -> Result<crate::types::MeetingRegistrantCreateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_ids.is_empty() {
-            query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
+            query_args.push(("occurrence_ids", occurrence_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/registrants",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -389,18 +395,20 @@ impl Meetings {
         &self,
         occurrence_id: &str,
         meeting_id: i64,
-        registrant_id: &str,
+        registrant_id: impl AsRef<str>,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let registrant_id = registrant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(registrant_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/registrants/{}",
+                crate::progenitor_support::encode_path(&meeting_id),
+                crate::progenitor_support::encode_path(registrant_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -428,15 +436,16 @@ impl Meetings {
         occurrence_id: &str,
         body: &crate::types::RegistrantStatus,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants/status?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/meetings/{}/registrants/status",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -463,8 +472,9 @@ impl Meetings {
     */
     pub async fn past_details(
         &self,
-        meeting_uuid: &str,
+        meeting_uuid: impl AsRef<str>,
     ) -> Result<crate::types::PastMeetingDetailsResponse> {
+        let meeting_uuid = meeting_uuid.as_ref();
         let url = format!(
             "/past_meetings/{}",
             crate::progenitor_support::encode_path(meeting_uuid),
@@ -498,22 +508,24 @@ impl Meetings {
     */
     pub async fn past_participant(
         &self,
-        meeting_uuid: &str,
+        meeting_uuid: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::PastMeetingParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_uuid = meeting_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/past_meetings/{}/participants?{}",
-            crate::progenitor_support::encode_path(meeting_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/past_meetings/{}/participants",
+                crate::progenitor_support::encode_path(meeting_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -538,7 +550,7 @@ impl Meetings {
     pub async fn past(&self, meeting_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/past_meetings/{}/instances",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client.get(&url, None).await
@@ -566,7 +578,7 @@ impl Meetings {
     pub async fn poll(&self, meeting_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/meetings/{}/polls",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client.get(&url, None).await
@@ -599,7 +611,7 @@ impl Meetings {
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
         let url = format!(
             "/meetings/{}/polls",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client
@@ -630,11 +642,12 @@ impl Meetings {
     pub async fn poll_get(
         &self,
         meeting_id: i64,
-        poll_id: &str,
+        poll_id: impl AsRef<str>,
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
+        let poll_id = poll_id.as_ref();
         let url = format!(
             "/meetings/{}/polls/{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
             crate::progenitor_support::encode_path(poll_id),
         );
 
@@ -663,12 +676,13 @@ impl Meetings {
     pub async fn poll_update(
         &self,
         meeting_id: i64,
-        poll_id: &str,
+        poll_id: impl AsRef<str>,
         body: &crate::types::Poll,
     ) -> Result<()> {
+        let poll_id = poll_id.as_ref();
         let url = format!(
             "/meetings/{}/polls/{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
             crate::progenitor_support::encode_path(poll_id),
         );
 
@@ -697,10 +711,11 @@ impl Meetings {
     *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
     * * `poll_id: &str` -- User's first name.
     */
-    pub async fn poll_delete(&self, meeting_id: i64, poll_id: &str) -> Result<()> {
+    pub async fn poll_delete(&self, meeting_id: i64, poll_id: impl AsRef<str>) -> Result<()> {
+        let poll_id = poll_id.as_ref();
         let url = format!(
             "/meetings/{}/polls/{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
             crate::progenitor_support::encode_path(poll_id),
         );
 
@@ -732,7 +747,7 @@ impl Meetings {
     ) -> Result<crate::types::MeetingRegistrantQuestionsData> {
         let url = format!(
             "/meetings/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client.get(&url, None).await
@@ -763,7 +778,7 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client
@@ -792,7 +807,7 @@ impl Meetings {
     pub async fn invitation(&self, meeting_id: i64) -> Result<crate::types::MeetingInvitation> {
         let url = format!(
             "/meetings/{}/invitation",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client.get(&url, None).await
@@ -820,8 +835,9 @@ impl Meetings {
     */
     pub async fn get_live_stream_details(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
     ) -> Result<crate::types::GetLiveStreamDetailsResponse> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/livestream",
             crate::progenitor_support::encode_path(meeting_id),
@@ -850,9 +866,10 @@ impl Meetings {
     */
     pub async fn live_stream_update(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         body: &crate::types::MeetingLiveStream,
     ) -> Result<()> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/livestream",
             crate::progenitor_support::encode_path(meeting_id),
@@ -889,7 +906,7 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/livestream/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client
@@ -918,8 +935,9 @@ impl Meetings {
     */
     pub async fn list_past_polls(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/past_meetings/{}/polls",
             crate::progenitor_support::encode_path(meeting_id),
@@ -955,9 +973,10 @@ impl Meetings {
     */
     pub async fn add_batch_registrants(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         body: &crate::types::AddBatchRegistrantsRequest,
     ) -> Result<crate::types::AddBatchRegistrantsResponse> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/batch_registrants",
             crate::progenitor_support::encode_path(meeting_id),
@@ -989,9 +1008,10 @@ impl Meetings {
     */
     pub async fn recording_control(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         body: &crate::types::InMeetingRecordingControlRequest,
     ) -> Result<()> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/live_meetings/{}/events",
             crate::progenitor_support::encode_path(meeting_id),
@@ -1034,9 +1054,10 @@ impl Meetings {
     */
     pub async fn create_batch_polls(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         body: &crate::types::CreateBatchPollsRequest,
     ) -> Result<crate::types::CreateBatchPollsResponse> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/meetings/{}/batch_polls",
             crate::progenitor_support::encode_path(meeting_id),
@@ -1063,8 +1084,9 @@ impl Meetings {
     */
     pub async fn list_template(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::ListMeetingTemplatesResponseData> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/meeting_templates",
             crate::progenitor_support::encode_path(user_id),
@@ -1095,7 +1117,7 @@ impl Meetings {
     ) -> Result<crate::types::InviteLinks> {
         let url = format!(
             "/meetings/{}/invite_links",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            crate::progenitor_support::encode_path(&meeting_id),
         );
 
         self.client
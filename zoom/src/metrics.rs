@@ -0,0 +1,399 @@
+//! Metrics of the requests made by a client.
+//!
+//! A `MetricsSink` given to `Client::with_metrics` is called once every request
+//! completes, with the endpoint it was made to, the status of the response and how
+//! long it took. This makes it possible to feed request counts, latencies and error
+//! rates to Prometheus, StatsD or any other metrics system.
+//!
+//! ```no_run
+//! # fn run(client: zoom_api::Client) {
+//! use zoom_api::metrics::RequestMetrics;
+//!
+//! let client = client.with_metrics(|m: &RequestMetrics| {
+//!     println!(
+//!         "{} {} {} {:?} in {:?}",
+//!         m.service,
+//!         m.method,
+//!         m.endpoint.unwrap_or("other"),
+//!         m.status,
+//!         m.duration
+//!     );
+//! });
+//! # }
+//! ```
+use std::{fmt, time::Duration};
+
+use reqwest::{Method, StatusCode};
+
+/// The name of the service the client talks to.
+pub const SERVICE: &str = "Zoom";
+
+/// A request that completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, the same for all the requests of a client.
+    pub service: &'static str,
+    pub method: Method,
+    /// The endpoint the request was made to, as in the documentation of the
+    /// functions, for example `/users/{id}`. This is `None` for URLs which are not
+    /// endpoints of the API.
+    pub endpoint: Option<&'static str>,
+    /// The status of the response, or `None` when there was no response because the
+    /// request failed, timed out or was cancelled.
+    pub status: Option<StatusCode>,
+    /// How long the request took, retries included.
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request failed, with an error status or without a response.
+    pub fn is_error(&self) -> bool {
+        !self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+}
+
+/// Receives the metrics of every request a client makes.
+///
+/// This is called on the task that made the request, so it should not block.
+pub trait MetricsSink: Send + Sync + 'static {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync + 'static,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// The endpoint a URL path is for. When more than one endpoint matches, the one
+/// with the most segments wins, then the one with the fewest parameters.
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .copied()
+        .filter(|e| crate::cache::matches_endpoint(e, path))
+        .max_by_key(|e| {
+            let segments: Vec<&str> = crate::cache::segments(e).collect();
+            let literals = segments.iter().filter(|s| !s.contains('{')).count();
+            (segments.len(), literals)
+        })
+}
+
+/// Report a request that completed to the sink.
+pub(crate) fn record(
+    sink: &dyn MetricsSink,
+    method: Method,
+    path: &str,
+    result: &anyhow::Result<reqwest::Response>,
+    duration: Duration,
+) {
+    sink.record(&RequestMetrics {
+        service: SERVICE,
+        method,
+        endpoint: endpoint(path),
+        status: result.as_ref().ok().map(|r| r.status()),
+        duration,
+    });
+}
+
+impl crate::Client {
+    /// Report the metrics of every request made with the returned client to the
+    /// given sink.
+    pub fn with_metrics<S>(&self, sink: S) -> Self
+    where
+        S: MetricsSink,
+    {
+        let mut c = self.clone();
+        c.request_options.metrics = Some(std::sync::Arc::new(sink));
+        c
+    }
+}
+
+/// The endpoints of the API.
+const ENDPOINTS: &[&str] = &[
+    "/accounts",
+    "/accounts/{accountId}",
+    "/accounts/{accountId}/billing",
+    "/accounts/{accountId}/billing/invoices",
+    "/accounts/{accountId}/billing/invoices/{invoiceId}",
+    "/accounts/{accountId}/lock_settings",
+    "/accounts/{accountId}/managed_domains",
+    "/accounts/{accountId}/options",
+    "/accounts/{accountId}/owner",
+    "/accounts/{accountId}/phone/setup",
+    "/accounts/{accountId}/phone/sip_trunk/trunks",
+    "/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}",
+    "/accounts/{accountId}/plans",
+    "/accounts/{accountId}/plans/addons",
+    "/accounts/{accountId}/plans/addons/status",
+    "/accounts/{accountId}/plans/base",
+    "/accounts/{accountId}/plans/base/status",
+    "/accounts/{accountId}/plans/usage",
+    "/accounts/{accountId}/recordings",
+    "/accounts/{accountId}/settings",
+    "/accounts/{accountId}/settings/virtual_backgrounds",
+    "/accounts/{accountId}/sip_trunk/callout_countries",
+    "/accounts/{accountId}/sip_trunk/callout_countries/{countryId}",
+    "/accounts/{accountId}/sip_trunk/internal_numbers",
+    "/accounts/{accountId}/sip_trunk/internal_numbers/{numberId}",
+    "/accounts/{accountId}/sip_trunk/numbers",
+    "/accounts/{accountId}/sip_trunk/settings",
+    "/accounts/{accountId}/sip_trunk/trunks",
+    "/accounts/{accountId}/sip_trunk/trunks/{trunkId}",
+    "/accounts/{accountId}/trusted_domains",
+    "/accounts/{accountId}/users/{userId}/account",
+    "/api/download/billing/invoices/{invoiceId}",
+    "/archive_files",
+    "/chat/channels/{channelId}",
+    "/chat/channels/{channelId}/members/me",
+    "/chat/channels/{channelId}/members/{memberId}",
+    "/chat/users/me/contacts",
+    "/chat/users/me/contacts/{contactId}",
+    "/chat/users/{userId}/channels",
+    "/chat/users/{userId}/channels/{channelId}",
+    "/chat/users/{userId}/channels/{channelId}/members",
+    "/chat/users/{userId}/channels/{channelId}/members/{memberId}",
+    "/chat/users/{userId}/messages",
+    "/chat/users/{userId}/messages/{messageId}",
+    "/chat/users/{userId}/messages/{messageId}/emoji_reactions",
+    "/chat/users/{userId}/messages/{messageId}/status",
+    "/contacts",
+    "/groups",
+    "/groups/{groupId}",
+    "/groups/{groupId}/lock_settings",
+    "/groups/{groupId}/members",
+    "/groups/{groupId}/members/{memberId}",
+    "/groups/{groupId}/settings",
+    "/groups/{groupId}/settings/virtual_backgrounds",
+    "/h323/devices",
+    "/h323/devices/{deviceId}",
+    "/im/chat/messages",
+    "/im/chat/messages/{message_id}",
+    "/im/chat/sessions",
+    "/im/chat/sessions/{sessionId}",
+    "/im/groups",
+    "/im/groups/{groupId}",
+    "/im/groups/{groupId}/members",
+    "/im/groups/{groupId}/members/{memberId}",
+    "/im/users/me/chat/messages",
+    "/im/users/{userId}/chat/messages",
+    "/live_meetings/{meetingId}/events",
+    "/meetings/{meetingId}",
+    "/meetings/{meetingId}/batch_polls",
+    "/meetings/{meetingId}/batch_registrants",
+    "/meetings/{meetingId}/invitation",
+    "/meetings/{meetingId}/invite_links",
+    "/meetings/{meetingId}/livestream",
+    "/meetings/{meetingId}/livestream/status",
+    "/meetings/{meetingId}/polls",
+    "/meetings/{meetingId}/polls/{pollId}",
+    "/meetings/{meetingId}/recordings",
+    "/meetings/{meetingId}/recordings/registrants",
+    "/meetings/{meetingId}/recordings/registrants/questions",
+    "/meetings/{meetingId}/recordings/registrants/status",
+    "/meetings/{meetingId}/recordings/settings",
+    "/meetings/{meetingId}/recordings/status",
+    "/meetings/{meetingId}/recordings/{recordingId}",
+    "/meetings/{meetingId}/recordings/{recordingId}/status",
+    "/meetings/{meetingId}/registrants",
+    "/meetings/{meetingId}/registrants/questions",
+    "/meetings/{meetingId}/registrants/status",
+    "/meetings/{meetingId}/registrants/{registrantId}",
+    "/meetings/{meetingId}/status",
+    "/metrics/chat",
+    "/metrics/client/feedback",
+    "/metrics/client/feedback/{feedbackId}",
+    "/metrics/client/satisfaction",
+    "/metrics/crc",
+    "/metrics/im",
+    "/metrics/issues/zoomrooms",
+    "/metrics/issues/zoomrooms/{zoomroomId}",
+    "/metrics/meetings",
+    "/metrics/meetings/{meetingId}",
+    "/metrics/meetings/{meetingId}/participants",
+    "/metrics/meetings/{meetingId}/participants/qos",
+    "/metrics/meetings/{meetingId}/participants/satisfaction",
+    "/metrics/meetings/{meetingId}/participants/sharing",
+    "/metrics/meetings/{meetingId}/participants/{participantId}/qos",
+    "/metrics/quality",
+    "/metrics/webinars",
+    "/metrics/webinars/{webinarId}",
+    "/metrics/webinars/{webinarId}/participants",
+    "/metrics/webinars/{webinarId}/participants/qos",
+    "/metrics/webinars/{webinarId}/participants/satisfaction",
+    "/metrics/webinars/{webinarId}/participants/sharing",
+    "/metrics/webinars/{webinarId}/participants/{participantId}/qos",
+    "/metrics/zoomrooms",
+    "/metrics/zoomrooms/issues",
+    "/metrics/zoomrooms/{zoomroomId}",
+    "/past_meetings/{meetingId}/files",
+    "/past_meetings/{meetingId}/instances",
+    "/past_meetings/{meetingId}/polls",
+    "/past_meetings/{meetingUUID}",
+    "/past_meetings/{meetingUUID}/archive_files",
+    "/past_meetings/{meetingUUID}/participants",
+    "/past_webinars/{WebinarUUID}/absentees",
+    "/past_webinars/{webinarId}/files",
+    "/past_webinars/{webinarId}/instances",
+    "/past_webinars/{webinarId}/participants",
+    "/past_webinars/{webinarId}/polls",
+    "/past_webinars/{webinarId}/qa",
+    "/phone/auto_receptionists",
+    "/phone/auto_receptionists/{autoReceptionistId}",
+    "/phone/auto_receptionists/{autoReceptionistId}/phone_numbers",
+    "/phone/auto_receptionists/{autoReceptionistId}/phone_numbers/{phoneNumberId}",
+    "/phone/batch_locations",
+    "/phone/blocked_list",
+    "/phone/blocked_list/{blockedListId}",
+    "/phone/byoc_numbers",
+    "/phone/call_logs",
+    "/phone/call_logs/{callLogId}",
+    "/phone/call_queues",
+    "/phone/call_queues/{callQueueId}",
+    "/phone/call_queues/{callQueueId}/manager",
+    "/phone/call_queues/{callQueueId}/members",
+    "/phone/call_queues/{callQueueId}/members/{memberId}",
+    "/phone/call_queues/{callQueueId}/phone_numbers",
+    "/phone/call_queues/{callQueueId}/phone_numbers/{phoneNumberId}",
+    "/phone/call_queues/{callQueueId}/recordings",
+    "/phone/calling_plans",
+    "/phone/common_area_phones",
+    "/phone/common_area_phones/{commonAreaPhoneId}",
+    "/phone/common_area_phones/{commonAreaPhoneId}/calling_plans",
+    "/phone/common_area_phones/{commonAreaPhoneId}/calling_plans/{type}",
+    "/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers",
+    "/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers/{phoneNumberId}",
+    "/phone/company_number",
+    "/phone/devices",
+    "/phone/devices/{deviceId}",
+    "/phone/external_contacts",
+    "/phone/external_contacts/{externalContactId}",
+    "/phone/locations",
+    "/phone/locations/{locationId}",
+    "/phone/metrics/call_logs",
+    "/phone/metrics/call_logs/{callId}/qos",
+    "/phone/metrics/call_logs/{call_id}",
+    "/phone/numbers",
+    "/phone/numbers/{numberId}",
+    "/phone/recordings",
+    "/phone/reports/operationlogs",
+    "/phone/setting_templates",
+    "/phone/setting_templates/{templateId}",
+    "/phone/settings",
+    "/phone/shared_line_groups",
+    "/phone/shared_line_groups/{sharedLineGroupId}",
+    "/phone/shared_line_groups/{sharedLineGroupId}/members",
+    "/phone/shared_line_groups/{sharedLineGroupId}/members/{memberId}",
+    "/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers",
+    "/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers/{phoneNumberId}",
+    "/phone/sip_groups",
+    "/phone/sip_trunk/trunks",
+    "/phone/sites",
+    "/phone/sites/{siteId}",
+    "/phone/users",
+    "/phone/users/{userId}",
+    "/phone/users/{userId}/call_logs",
+    "/phone/users/{userId}/call_logs/{callLogId}",
+    "/phone/users/{userId}/calling_plans",
+    "/phone/users/{userId}/calling_plans/{type}",
+    "/phone/users/{userId}/phone_numbers",
+    "/phone/users/{userId}/phone_numbers/{phoneNumberId}",
+    "/phone/users/{userId}/recordings",
+    "/phone/users/{userId}/settings",
+    "/phone/users/{userId}/settings/{settingType}",
+    "/phone/users/{userId}/voice_mails",
+    "/phone/voice_mails/{voicemailId}",
+    "/report/activities",
+    "/report/billing",
+    "/report/billing/invoices",
+    "/report/cloud_recording",
+    "/report/daily",
+    "/report/meetings/{meetingId}",
+    "/report/meetings/{meetingId}/participants",
+    "/report/meetings/{meetingId}/polls",
+    "/report/operationlogs",
+    "/report/telephone",
+    "/report/users",
+    "/report/users/{userId}/meetings",
+    "/report/webinars/{webinarId}",
+    "/report/webinars/{webinarId}/participants",
+    "/report/webinars/{webinarId}/polls",
+    "/report/webinars/{webinarId}/qa",
+    "/roles",
+    "/roles/{roleId}",
+    "/roles/{roleId}/members",
+    "/roles/{roleId}/members/{memberId}",
+    "/rooms",
+    "/rooms/account_profile",
+    "/rooms/account_settings",
+    "/rooms/digital_signage",
+    "/rooms/events",
+    "/rooms/locations",
+    "/rooms/locations/structure",
+    "/rooms/locations/{locationId}",
+    "/rooms/locations/{locationId}/location",
+    "/rooms/locations/{locationId}/settings",
+    "/rooms/{id}/events",
+    "/rooms/{roomId}",
+    "/rooms/{roomId}/devices",
+    "/rooms/{roomId}/devices/{deviceId}/app_version",
+    "/rooms/{roomId}/location",
+    "/rooms/{roomId}/settings",
+    "/sip_phones",
+    "/sip_phones/{phoneId}",
+    "/sip_trunk/numbers",
+    "/tracking_fields",
+    "/tracking_fields/{fieldId}",
+    "/tsp",
+    "/users",
+    "/users/email",
+    "/users/me/zak",
+    "/users/vanity_name",
+    "/users/{userId}",
+    "/users/{userId}/assistants",
+    "/users/{userId}/assistants/{assistantId}",
+    "/users/{userId}/email",
+    "/users/{userId}/meeting_templates",
+    "/users/{userId}/meetings",
+    "/users/{userId}/pac",
+    "/users/{userId}/password",
+    "/users/{userId}/permissions",
+    "/users/{userId}/picture",
+    "/users/{userId}/presence_status",
+    "/users/{userId}/recordings",
+    "/users/{userId}/schedulers",
+    "/users/{userId}/schedulers/{schedulerId}",
+    "/users/{userId}/settings",
+    "/users/{userId}/settings/virtual_backgrounds",
+    "/users/{userId}/status",
+    "/users/{userId}/token",
+    "/users/{userId}/tsp",
+    "/users/{userId}/tsp/settings",
+    "/users/{userId}/tsp/{tspId}",
+    "/users/{userId}/webinar_templates",
+    "/users/{userId}/webinars",
+    "/webinars/{webinarId}",
+    "/webinars/{webinarId}/batch_registrants",
+    "/webinars/{webinarId}/invite_links",
+    "/webinars/{webinarId}/livestream",
+    "/webinars/{webinarId}/livestream/status",
+    "/webinars/{webinarId}/panelists",
+    "/webinars/{webinarId}/panelists/{panelistId}",
+    "/webinars/{webinarId}/polls",
+    "/webinars/{webinarId}/polls/{pollId}",
+    "/webinars/{webinarId}/registrants",
+    "/webinars/{webinarId}/registrants/questions",
+    "/webinars/{webinarId}/registrants/status",
+    "/webinars/{webinarId}/registrants/{registrantId}",
+    "/webinars/{webinarId}/status",
+    "/webinars/{webinarId}/tracking_sources",
+];
@@ -31,7 +31,11 @@ impl Pac {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn user_pa_cs(&self, user_id: &str) -> Result<crate::types::UserPaCsResponse> {
+    pub async fn user_pa_cs(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<crate::types::UserPaCsResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/pac",
             crate::progenitor_support::encode_path(user_id),
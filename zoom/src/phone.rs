@@ -32,9 +32,10 @@ impl Phone {
     */
     pub async fn set_up_account(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::SetUpAccountRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/phone/setup",
             crate::progenitor_support::encode_path(account_id),
@@ -84,30 +85,29 @@ impl Phone {
         pending_numbers: bool,
         site_id: &str,
     ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !extension_type.to_string().is_empty() {
-            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+            query_args.push(("extension_type", extension_type.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !number_type.to_string().is_empty() {
-            query_args.push(("number_type".to_string(), number_type.to_string()));
+            query_args.push(("number_type", number_type.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if pending_numbers {
-            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+            query_args.push(("pending_numbers", pending_numbers.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/numbers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/phone/numbers"), &query_args);
 
         let resp: crate::types::ListAccountPhoneNumbersResponseData =
             self.client.get(&url, None).await?;
@@ -139,24 +139,23 @@ impl Phone {
         pending_numbers: bool,
         site_id: &str,
     ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !extension_type.to_string().is_empty() {
-            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+            query_args.push(("extension_type", extension_type.to_string()));
         }
         if !number_type.to_string().is_empty() {
-            query_args.push(("number_type".to_string(), number_type.to_string()));
+            query_args.push(("number_type", number_type.to_string()));
         }
         if pending_numbers {
-            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+            query_args.push(("pending_numbers", pending_numbers.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/numbers?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/phone/numbers"), &query_args);
 
         let mut resp: crate::types::ListAccountPhoneNumbersResponseData =
             self.client.get(&url, None).await?;
@@ -166,18 +165,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             phone_numbers.append(&mut resp.phone_numbers);
 
@@ -209,7 +203,8 @@ impl Phone {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn user(&self, user_id: &str) -> Result<crate::types::PhoneUserResponse> {
+    pub async fn user(&self, user_id: impl AsRef<str>) -> Result<crate::types::PhoneUserResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/phone/users/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -233,9 +228,10 @@ impl Phone {
     */
     pub async fn update_user_profile(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UpdateUserProfileRequest,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/phone/users/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -310,8 +306,9 @@ impl Phone {
     */
     pub async fn user_settings(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::PhoneUserSettingsResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/phone/users/{}/settings",
             crate::progenitor_support::encode_path(user_id),
@@ -345,18 +342,20 @@ impl Phone {
         next_page_token: &str,
         site_id: &str,
     ) -> Result<Vec<crate::types::Templates>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/setting_templates?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/setting_templates"),
+            &query_args,
+        );
 
         let resp: crate::types::ListSettingTemplatesResponse = self.client.get(&url, None).await?;
 
@@ -383,12 +382,14 @@ impl Phone {
         &self,
         site_id: &str,
     ) -> Result<Vec<crate::types::Templates>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/setting_templates?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/setting_templates"),
+            &query_args,
+        );
 
         let mut resp: crate::types::ListSettingTemplatesResponse =
             self.client.get(&url, None).await?;
@@ -398,18 +399,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             templates.append(&mut resp.templates);
 
@@ -487,15 +483,15 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ListLocationsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/locations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/locations"), &query_args);
 
         let resp: crate::types::ListLocationsResponseData = self.client.get(&url, None).await?;
 
@@ -527,18 +523,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             locations.append(&mut resp.locations);
 
@@ -595,8 +586,9 @@ impl Phone {
     */
     pub async fn get_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<crate::types::GetLocationResponse> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/phone/locations/{}",
             crate::progenitor_support::encode_path(location_id),
@@ -622,7 +614,8 @@ impl Phone {
     *
     * * `location_id: &str` -- The emergency service location's ID.
     */
-    pub async fn delete_location(&self, location_id: &str) -> Result<()> {
+    pub async fn delete_location(&self, location_id: impl AsRef<str>) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/phone/locations/{}",
             crate::progenitor_support::encode_path(location_id),
@@ -646,9 +639,10 @@ impl Phone {
     */
     pub async fn update_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
         body: &crate::types::UpdateLocationRequest,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/phone/locations/{}",
             crate::progenitor_support::encode_path(location_id),
@@ -682,15 +676,15 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::SipGroups>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/sip_groups?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/sip_groups"), &query_args);
 
         let resp: crate::types::ListSipGroupsResponse = self.client.get(&url, None).await?;
 
@@ -722,18 +716,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             sip_groups.append(&mut resp.sip_groups);
 
@@ -768,21 +757,20 @@ impl Phone {
     */
     pub async fn get_setting_template(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         custom_query_fields: &str,
     ) -> Result<crate::types::GetSettingTemplateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let template_id = template_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/setting_templates/{}?{}",
-            crate::progenitor_support::encode_path(template_id),
-            query_
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/setting_templates/{}",
+                crate::progenitor_support::encode_path(template_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -807,9 +795,10 @@ impl Phone {
     */
     pub async fn update_setting_template(
         &self,
-        template_id: &str,
+        template_id: impl AsRef<str>,
         body: &crate::types::UpdateSettingTemplateRequest,
     ) -> Result<()> {
+        let template_id = template_id.as_ref();
         let url = format!(
             "/phone/setting_templates/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -846,7 +835,7 @@ impl Phone {
     */
     pub async fn user_call_logs(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         page_size: i64,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
@@ -855,33 +844,35 @@ impl Phone {
         phone_number: &str,
         time_type: crate::types::TimeType,
     ) -> Result<Vec<crate::types::CallLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !phone_number.is_empty() {
-            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+            query_args.push(("phone_number", phone_number.to_string()));
         }
         if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/call_logs?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/users/{}/call_logs",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::PhoneUserCallLogsResponse = self.client.get(&url, None).await?;
@@ -907,34 +898,36 @@ impl Phone {
     */
     pub async fn get_all_user_call_logs(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
         type_: crate::types::PhoneUserCallLogsType,
         phone_number: &str,
         time_type: crate::types::TimeType,
     ) -> Result<Vec<crate::types::CallLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !phone_number.is_empty() {
-            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+            query_args.push(("phone_number", phone_number.to_string()));
         }
         if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/call_logs?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/users/{}/call_logs",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::PhoneUserCallLogsResponse = self.client.get(&url, None).await?;
@@ -944,18 +937,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             call_logs.append(&mut resp.call_logs);
 
@@ -993,30 +981,32 @@ impl Phone {
     */
     pub async fn user_recordings(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::Recordings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/recordings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/users/{}/recordings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::PhoneUserRecordingsResponse = self.client.get(&url, None).await?;
@@ -1042,22 +1032,24 @@ impl Phone {
     */
     pub async fn get_all_user_recordings(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::Recordings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/recordings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/users/{}/recordings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::PhoneUserRecordingsResponse =
@@ -1068,18 +1060,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             recordings.append(&mut resp.recordings);
 
@@ -1118,34 +1105,36 @@ impl Phone {
     */
     pub async fn user_voice_mails(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         page_size: i64,
         status: crate::types::PhoneUserVoiceMailsStatus,
         next_page_token: &str,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::VoiceMails>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/voice_mails?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/users/{}/voice_mails",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::PhoneUserVoiceMailsResponse = self.client.get(&url, None).await?;
@@ -1171,26 +1160,28 @@ impl Phone {
     */
     pub async fn get_all_user_voice_mails(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         status: crate::types::PhoneUserVoiceMailsStatus,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::VoiceMails>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/voice_mails?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/users/{}/voice_mails",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::PhoneUserVoiceMailsResponse =
@@ -1201,18 +1192,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             voice_mails.append(&mut resp.voice_mails);
 
@@ -1250,10 +1236,12 @@ impl Phone {
     */
     pub async fn add_user_setting(
         &self,
-        user_id: &str,
-        setting_type: &str,
+        user_id: impl AsRef<str>,
+        setting_type: impl AsRef<str>,
         body: &crate::types::AddUserSettingRequest,
     ) -> Result<crate::types::AddUserSettingResponse> {
+        let user_id = user_id.as_ref();
+        let setting_type = setting_type.as_ref();
         let url = format!(
             "/phone/users/{}/settings/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1287,20 +1275,23 @@ impl Phone {
     */
     pub async fn delete_user_setting(
         &self,
-        user_id: &str,
-        setting_type: &str,
+        user_id: impl AsRef<str>,
+        setting_type: impl AsRef<str>,
         shared_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let setting_type = setting_type.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !shared_id.is_empty() {
-            query_args.push(("shared_id".to_string(), shared_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/settings/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            crate::progenitor_support::encode_path(setting_type),
-            query_
+            query_args.push(("shared_id", shared_id.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/users/{}/settings/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(setting_type),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -1329,10 +1320,12 @@ impl Phone {
     */
     pub async fn update_user_setting(
         &self,
-        setting_type: &str,
-        user_id: &str,
+        setting_type: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::UpdateUserSettingRequest,
     ) -> Result<()> {
+        let setting_type = setting_type.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/phone/users/{}/settings/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1384,33 +1377,33 @@ impl Phone {
         time_type: crate::types::TimeType,
         site_id: &str,
     ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/call_logs?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/call_logs"), &query_args);
 
         let resp: crate::types::AccountCallLogsResponseData = self.client.get(&url, None).await?;
 
@@ -1443,27 +1436,27 @@ impl Phone {
         time_type: crate::types::TimeType,
         site_id: &str,
     ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/call_logs?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/call_logs"), &query_args);
 
         let mut resp: crate::types::AccountCallLogsResponseData =
             self.client.get(&url, None).await?;
@@ -1473,18 +1466,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             call_logs.append(&mut resp.call_logs);
 
@@ -1514,9 +1502,10 @@ impl Phone {
     */
     pub async fn assign_number(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::AddByocNumberResponse,
     ) -> Result<crate::types::AddByocNumberResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/phone/users/{}/phone_numbers",
             crate::progenitor_support::encode_path(user_id),
@@ -1548,7 +1537,13 @@ impl Phone {
     * * `user_id: &str` -- Provide either userId or email address of the user.
     * * `phone_number_id: &str` -- Provide either phone number or phoneNumberId of the user. .
     */
-    pub async fn unassign_number(&self, user_id: &str, phone_number_id: &str) -> Result<()> {
+    pub async fn unassign_number(
+        &self,
+        user_id: impl AsRef<str>,
+        phone_number_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let phone_number_id = phone_number_id.as_ref();
         let url = format!(
             "/phone/users/{}/phone_numbers/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1573,9 +1568,10 @@ impl Phone {
     */
     pub async fn assign_calling_plan(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::AssignCallingPlanRequest,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/phone/users/{}/calling_plans",
             crate::progenitor_support::encode_path(user_id),
@@ -1604,7 +1600,13 @@ impl Phone {
     * * `type_: &str` -- The [type](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans) of the calling plan that was assigned to user. (e.g: The value of type would be "200" for Unlimited US/Canada calling plan.)
     *   .
     */
-    pub async fn unassign_calling_plan(&self, user_id: &str, type_: &str) -> Result<()> {
+    pub async fn unassign_calling_plan(
+        &self,
+        user_id: impl AsRef<str>,
+        type_: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let type_ = type_.as_ref();
         let url = format!(
             "/phone/users/{}/calling_plans/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1655,33 +1657,33 @@ impl Phone {
         site_id: &str,
         query_date_type: crate::types::QueryDateType,
     ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !owner_type.is_empty() {
-            query_args.push(("owner_type".to_string(), owner_type.to_string()));
+            query_args.push(("owner_type", owner_type.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !recording_type.is_empty() {
-            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+            query_args.push(("recording_type", recording_type.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/recordings?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/recordings"), &query_args);
 
         let resp: crate::types::GetPhoneRecordingsResponseData =
             self.client.get(&url, None).await?;
@@ -1715,27 +1717,27 @@ impl Phone {
         site_id: &str,
         query_date_type: crate::types::QueryDateType,
     ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !owner_type.is_empty() {
-            query_args.push(("owner_type".to_string(), owner_type.to_string()));
+            query_args.push(("owner_type", owner_type.to_string()));
         }
         if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !recording_type.is_empty() {
-            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+            query_args.push(("recording_type", recording_type.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/recordings?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/recordings"), &query_args);
 
         let mut resp: crate::types::GetPhoneRecordingsResponseData =
             self.client.get(&url, None).await?;
@@ -1745,18 +1747,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             recordings.append(&mut resp.recordings);
 
@@ -1793,15 +1790,17 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ByocSipTrunk>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/sip_trunk/trunks?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/sip_trunk/trunks"),
+            &query_args,
+        );
 
         let resp: crate::types::ListByocsipTrunkResponse = self.client.get(&url, None).await?;
 
@@ -1832,18 +1831,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             byoc_sip_trunk.append(&mut resp.byoc_sip_trunk);
 
@@ -1876,9 +1870,10 @@ impl Phone {
     */
     pub async fn post_sip_trunk(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::PostPhoneSipTrunkRequest,
     ) -> Result<crate::types::PostPhoneSipTrunkRequest> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/phone/sip_trunk/trunks",
             crate::progenitor_support::encode_path(account_id),
@@ -1908,10 +1903,12 @@ impl Phone {
     */
     pub async fn update_sip_trunk(
         &self,
-        sip_trunk_id: &str,
-        account_id: &str,
+        sip_trunk_id: impl AsRef<str>,
+        account_id: impl AsRef<str>,
         body: &crate::types::UpdatePhoneSipTrunkRequest,
     ) -> Result<()> {
+        let sip_trunk_id = sip_trunk_id.as_ref();
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/phone/sip_trunk/trunks/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -1946,15 +1943,17 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ExternalContacts>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/external_contacts?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/external_contacts"),
+            &query_args,
+        );
 
         let resp: crate::types::ListExternalContactsResponse = self.client.get(&url, None).await?;
 
@@ -1987,18 +1986,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             external_contacts.append(&mut resp.external_contacts);
 
@@ -2055,8 +2049,9 @@ impl Phone {
     */
     pub async fn get_external_contact(
         &self,
-        external_contact_id: &str,
+        external_contact_id: impl AsRef<str>,
     ) -> Result<crate::types::ExternalContacts> {
+        let external_contact_id = external_contact_id.as_ref();
         let url = format!(
             "/phone/external_contacts/{}",
             crate::progenitor_support::encode_path(external_contact_id),
@@ -2082,7 +2077,11 @@ impl Phone {
     *
     * * `external_contact_id: &str` -- The external contact's ID.
     */
-    pub async fn delete_external_contact(&self, external_contact_id: &str) -> Result<()> {
+    pub async fn delete_external_contact(
+        &self,
+        external_contact_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let external_contact_id = external_contact_id.as_ref();
         let url = format!(
             "/phone/external_contacts/{}",
             crate::progenitor_support::encode_path(external_contact_id),
@@ -2110,9 +2109,10 @@ impl Phone {
     */
     pub async fn update_external_contact(
         &self,
-        external_contact_id: &str,
+        external_contact_id: impl AsRef<str>,
         body: &crate::types::UpdateExternalContactRequest,
     ) -> Result<()> {
+        let external_contact_id = external_contact_id.as_ref();
         let url = format!(
             "/phone/external_contacts/{}",
             crate::progenitor_support::encode_path(external_contact_id),
@@ -2142,8 +2142,9 @@ impl Phone {
     */
     pub async fn get_number_details(
         &self,
-        number_id: &str,
+        number_id: impl AsRef<str>,
     ) -> Result<crate::types::GetPhoneNumberDetailsResponse> {
+        let number_id = number_id.as_ref();
         let url = format!(
             "/phone/numbers/{}",
             crate::progenitor_support::encode_path(number_id),
@@ -2170,9 +2171,10 @@ impl Phone {
     */
     pub async fn update_number_details(
         &self,
-        number_id: &str,
+        number_id: impl AsRef<str>,
         body: &crate::types::UpdatePhoneNumberDetailsRequest,
     ) -> Result<()> {
+        let number_id = number_id.as_ref();
         let url = format!(
             "/phone/numbers/{}",
             crate::progenitor_support::encode_path(number_id),
@@ -2251,18 +2253,17 @@ impl Phone {
         next_page_token: &str,
         site_id: &str,
     ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/phone/users"), &query_args);
 
         let resp: crate::types::ListPhoneUsersResponseData = self.client.get(&url, None).await?;
 
@@ -2289,12 +2290,11 @@ impl Phone {
         &self,
         site_id: &str,
     ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/phone/users"), &query_args);
 
         let mut resp: crate::types::ListPhoneUsersResponseData =
             self.client.get(&url, None).await?;
@@ -2304,18 +2304,13 @@ impl Phone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             users.append(&mut resp.users);
 
@@ -2349,8 +2344,9 @@ impl Phone {
     */
     pub async fn get_call_log_details(
         &self,
-        call_log_id: &str,
+        call_log_id: impl AsRef<str>,
     ) -> Result<crate::types::GetCallLogDetailsResponse> {
+        let call_log_id = call_log_id.as_ref();
         let url = format!(
             "/phone/call_logs/{}",
             crate::progenitor_support::encode_path(call_log_id),
@@ -2377,7 +2373,13 @@ impl Phone {
     * * `user_id: &str` -- The user ID or email address of the user.
     * * `call_log_id: &str` -- Unique identifier of the call log. The value for this field can be retrieved from [account's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/accountcalllogs) or [user's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneusercalllogs).
     */
-    pub async fn delete_call_log(&self, user_id: &str, call_log_id: &str) -> Result<()> {
+    pub async fn delete_call_log(
+        &self,
+        user_id: impl AsRef<str>,
+        call_log_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let call_log_id = call_log_id.as_ref();
         let url = format!(
             "/phone/users/{}/call_logs/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -2426,7 +2428,8 @@ impl Phone {
     *
     * * `voicemail_id: &str` -- Unique identifier of the voicemail. Retrieve the value for this field by calling the [Get voicemails](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneuservoicemails) API.
     */
-    pub async fn delete_voicemail(&self, voicemail_id: &str) -> Result<()> {
+    pub async fn delete_voicemail(&self, voicemail_id: impl AsRef<str>) -> Result<()> {
+        let voicemail_id = voicemail_id.as_ref();
         let url = format!(
             "/phone/voice_mails/{}",
             crate::progenitor_support::encode_path(voicemail_id),
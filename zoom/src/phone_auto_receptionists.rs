@@ -32,9 +32,10 @@ impl PhoneAutoReceptionists {
     */
     pub async fn update_auto_receptionist(
         &self,
-        auto_receptionist_id: &str,
+        auto_receptionist_id: impl AsRef<str>,
         body: &crate::types::UpdateAutoReceptionistRequest,
     ) -> Result<()> {
+        let auto_receptionist_id = auto_receptionist_id.as_ref();
         let url = format!(
             "/phone/auto_receptionists/{}",
             crate::progenitor_support::encode_path(auto_receptionist_id),
@@ -66,9 +67,10 @@ impl PhoneAutoReceptionists {
     */
     pub async fn assign_phone_numbers_auto_receptionist(
         &self,
-        auto_receptionist_id: &str,
+        auto_receptionist_id: impl AsRef<str>,
         body: &crate::types::AddByocNumberResponse,
     ) -> Result<()> {
+        let auto_receptionist_id = auto_receptionist_id.as_ref();
         let url = format!(
             "/phone/auto_receptionists/{}/phone_numbers",
             crate::progenitor_support::encode_path(auto_receptionist_id),
@@ -96,8 +98,9 @@ impl PhoneAutoReceptionists {
     */
     pub async fn unassign_all_phone_nums_auto_receptionist(
         &self,
-        auto_receptionist_id: &str,
+        auto_receptionist_id: impl AsRef<str>,
     ) -> Result<()> {
+        let auto_receptionist_id = auto_receptionist_id.as_ref();
         let url = format!(
             "/phone/auto_receptionists/{}/phone_numbers",
             crate::progenitor_support::encode_path(auto_receptionist_id),
@@ -128,9 +131,11 @@ impl PhoneAutoReceptionists {
     */
     pub async fn unassign_phone_num_auto_receptionist(
         &self,
-        auto_receptionist_id: &str,
-        phone_number_id: &str,
+        auto_receptionist_id: impl AsRef<str>,
+        phone_number_id: impl AsRef<str>,
     ) -> Result<()> {
+        let auto_receptionist_id = auto_receptionist_id.as_ref();
+        let phone_number_id = phone_number_id.as_ref();
         let url = format!(
             "/phone/auto_receptionists/{}/phone_numbers/{}",
             crate::progenitor_support::encode_path(auto_receptionist_id),
@@ -35,15 +35,15 @@ impl PhoneBlockedList {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::BlockedList>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/blocked_list?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/blocked_list"), &query_args);
 
         let resp: crate::types::ListBlockedResponse = self.client.get(&url, None).await?;
 
@@ -75,18 +75,13 @@ impl PhoneBlockedList {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             blocked_list.append(&mut resp.blocked_list);
 
@@ -142,8 +137,9 @@ impl PhoneBlockedList {
     */
     pub async fn get_blocked_list(
         &self,
-        blocked_list_id: &str,
+        blocked_list_id: impl AsRef<str>,
     ) -> Result<crate::types::BlockedList> {
+        let blocked_list_id = blocked_list_id.as_ref();
         let url = format!(
             "/phone/blocked_list/{}",
             crate::progenitor_support::encode_path(blocked_list_id),
@@ -170,7 +166,8 @@ impl PhoneBlockedList {
     *
     * * `blocked_list_id: &str` -- Unique Identifier of the blocked list. This can be retrieved from the List Blocked List API.
     */
-    pub async fn delete_blocked_list(&self, blocked_list_id: &str) -> Result<()> {
+    pub async fn delete_blocked_list(&self, blocked_list_id: impl AsRef<str>) -> Result<()> {
+        let blocked_list_id = blocked_list_id.as_ref();
         let url = format!(
             "/phone/blocked_list/{}",
             crate::progenitor_support::encode_path(blocked_list_id),
@@ -198,9 +195,10 @@ impl PhoneBlockedList {
     */
     pub async fn update_blocked_list(
         &self,
-        blocked_list_id: &str,
+        blocked_list_id: impl AsRef<str>,
         body: &crate::types::UpdateBlockedListRequest,
     ) -> Result<()> {
+        let blocked_list_id = blocked_list_id.as_ref();
         let url = format!(
             "/phone/blocked_list/{}",
             crate::progenitor_support::encode_path(blocked_list_id),
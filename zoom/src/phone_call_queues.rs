@@ -38,15 +38,15 @@ impl PhoneCallQueues {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::CallQueues>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/call_queues?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/phone/call_queues"), &query_args);
 
         let resp: crate::types::ListCallQueuesResponse = self.client.get(&url, None).await?;
 
@@ -81,18 +81,13 @@ impl PhoneCallQueues {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             call_queues.append(&mut resp.call_queues);
 
@@ -154,8 +149,9 @@ impl PhoneCallQueues {
     */
     pub async fn get_call_queue(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
     ) -> Result<crate::types::GetCallQueueResponse> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -185,7 +181,8 @@ impl PhoneCallQueues {
     *
     * * `call_queue_id: &str` -- Unique Identifier of the call queue.
     */
-    pub async fn delete_call_queue(&self, call_queue_id: &str) -> Result<()> {
+    pub async fn delete_call_queue(&self, call_queue_id: impl AsRef<str>) -> Result<()> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -215,9 +212,10 @@ impl PhoneCallQueues {
     */
     pub async fn update_call_queue(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
         body: &crate::types::UpdateCallQueueRequest,
     ) -> Result<()> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -249,9 +247,10 @@ impl PhoneCallQueues {
     */
     pub async fn assign(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
         body: &crate::types::AddByocNumberResponse,
     ) -> Result<crate::types::Domains> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}/phone_numbers",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -282,8 +281,9 @@ impl PhoneCallQueues {
     */
     pub async fn unassign_phone_num_call_queue(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
     ) -> Result<crate::types::Domains> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}/phone_numbers",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -313,9 +313,11 @@ impl PhoneCallQueues {
     */
     pub async fn un_assign_phone_num_call_queue(
         &self,
-        call_queue_id: &str,
-        phone_number_id: &str,
+        call_queue_id: impl AsRef<str>,
+        phone_number_id: impl AsRef<str>,
     ) -> Result<crate::types::Domains> {
+        let call_queue_id = call_queue_id.as_ref();
+        let phone_number_id = phone_number_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}/phone_numbers/{}",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -345,9 +347,10 @@ impl PhoneCallQueues {
     */
     pub async fn add_members_call_queue(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
         body: &crate::types::AddMembersCallQueueRequestData,
     ) -> Result<crate::types::Domains> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}/members",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -376,7 +379,8 @@ impl PhoneCallQueues {
     *
     * * `call_queue_id: &str` -- User's first name.
     */
-    pub async fn unassign_all_members(&self, call_queue_id: &str) -> Result<()> {
+    pub async fn unassign_all_members(&self, call_queue_id: impl AsRef<str>) -> Result<()> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}/members",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -406,9 +410,11 @@ impl PhoneCallQueues {
     */
     pub async fn unassign_member_from_call_queue(
         &self,
-        call_queue_id: &str,
-        member_id: &str,
+        call_queue_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
     ) -> Result<()> {
+        let call_queue_id = call_queue_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}/members/{}",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -440,9 +446,10 @@ impl PhoneCallQueues {
     */
     pub async fn change_call_queue_manager(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
         body: &crate::types::ChangeCallQueueManagerRequest,
     ) -> Result<()> {
+        let call_queue_id = call_queue_id.as_ref();
         let url = format!(
             "/phone/call_queues/{}/manager",
             crate::progenitor_support::encode_path(call_queue_id),
@@ -477,30 +484,32 @@ impl PhoneCallQueues {
     */
     pub async fn get_call_queue_recordings(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let call_queue_id = call_queue_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/call_queues/{}/recordings?{}",
-            crate::progenitor_support::encode_path(call_queue_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/call_queues/{}/recordings",
+                crate::progenitor_support::encode_path(call_queue_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetCallQueueRecordingsResponseData =
@@ -528,22 +537,24 @@ impl PhoneCallQueues {
     */
     pub async fn get_all_call_queue_recordings(
         &self,
-        call_queue_id: &str,
+        call_queue_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let call_queue_id = call_queue_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/call_queues/{}/recordings?{}",
-            crate::progenitor_support::encode_path(call_queue_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/call_queues/{}/recordings",
+                crate::progenitor_support::encode_path(call_queue_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::GetCallQueueRecordingsResponseData =
@@ -554,18 +565,13 @@ impl PhoneCallQueues {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             recordings.append(&mut resp.recordings);
 
@@ -37,18 +37,17 @@ impl PhoneDevices {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/devices?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/phone/devices"), &query_args);
 
         let resp: crate::types::ListPhoneDevicesResponseData = self.client.get(&url, None).await?;
 
@@ -75,12 +74,11 @@ impl PhoneDevices {
         &self,
         type_: crate::types::ListPhoneDevicesType,
     ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/devices?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/phone/devices"), &query_args);
 
         let mut resp: crate::types::ListPhoneDevicesResponseData =
             self.client.get(&url, None).await?;
@@ -90,18 +88,13 @@ impl PhoneDevices {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             devices.append(&mut resp.devices);
 
@@ -156,7 +149,11 @@ impl PhoneDevices {
     *
     * * `device_id: &str` -- Unique Identifier of the device.
     */
-    pub async fn get_device(&self, device_id: &str) -> Result<crate::types::GetDeviceResponse> {
+    pub async fn get_device(
+        &self,
+        device_id: impl AsRef<str>,
+    ) -> Result<crate::types::GetDeviceResponse> {
+        let device_id = device_id.as_ref();
         let url = format!(
             "/phone/devices/{}",
             crate::progenitor_support::encode_path(device_id),
@@ -184,7 +181,8 @@ impl PhoneDevices {
     *
     * * `device_id: &str` -- Unique Identifier of the device.
     */
-    pub async fn delete_device(&self, device_id: &str) -> Result<()> {
+    pub async fn delete_device(&self, device_id: impl AsRef<str>) -> Result<()> {
+        let device_id = device_id.as_ref();
         let url = format!(
             "/phone/devices/{}",
             crate::progenitor_support::encode_path(device_id),
@@ -213,9 +211,10 @@ impl PhoneDevices {
     */
     pub async fn update_device(
         &self,
-        device_id: &str,
+        device_id: impl AsRef<str>,
         body: &crate::types::UpdateDeviceRequest,
     ) -> Result<()> {
+        let device_id = device_id.as_ref();
         let url = format!(
             "/phone/devices/{}",
             crate::progenitor_support::encode_path(device_id),
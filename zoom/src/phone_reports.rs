@@ -42,24 +42,26 @@ impl PhoneReports {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !category_type.is_empty() {
-            query_args.push(("category_type".to_string(), category_type.to_string()));
+            query_args.push(("category_type", category_type.to_string()));
         }
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/reports/operationlogs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/reports/operationlogs"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
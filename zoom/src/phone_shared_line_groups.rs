@@ -38,15 +38,17 @@ impl PhoneSharedLineGroups {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::SharedLineGroups>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/shared_line_groups?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/phone/shared_line_groups"),
+            &query_args,
+        );
 
         let resp: crate::types::ListSharedLineGroupsResponse = self.client.get(&url, None).await?;
 
@@ -82,18 +84,13 @@ impl PhoneSharedLineGroups {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             shared_line_groups.append(&mut resp.shared_line_groups);
 
@@ -155,8 +152,9 @@ impl PhoneSharedLineGroups {
     */
     pub async fn get_shared_line_group(
         &self,
-        shared_line_group_id: &str,
+        shared_line_group_id: impl AsRef<str>,
     ) -> Result<crate::types::GetSharedLineGroupResponse> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -184,7 +182,11 @@ impl PhoneSharedLineGroups {
     *
     * * `shared_line_group_id: &str` -- Unique Identifier of the shared line group that you would like to delete.
     */
-    pub async fn delete_shared_line_group(&self, shared_line_group_id: &str) -> Result<()> {
+    pub async fn delete_shared_line_group(
+        &self,
+        shared_line_group_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -213,9 +215,10 @@ impl PhoneSharedLineGroups {
     */
     pub async fn update_shared_line_group(
         &self,
-        shared_line_group_id: &str,
+        shared_line_group_id: impl AsRef<str>,
         body: &crate::types::UpdateSharedLineGroupRequest,
     ) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -249,9 +252,10 @@ impl PhoneSharedLineGroups {
     */
     pub async fn add_members_shared_line_group(
         &self,
-        shared_line_group_id: &str,
+        shared_line_group_id: impl AsRef<str>,
         body: &crate::types::AddMembersSharedLineGroupRequestData,
     ) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}/members",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -282,7 +286,8 @@ impl PhoneSharedLineGroups {
     *
     * * `shared_line_group_id: &str` -- Unique identifier of the Shared Line Group that you would like to delete.
     */
-    pub async fn delete_members_of_slg(&self, shared_line_group_id: &str) -> Result<()> {
+    pub async fn delete_members_of_slg(&self, shared_line_group_id: impl AsRef<str>) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}/members",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -314,9 +319,11 @@ impl PhoneSharedLineGroups {
     */
     pub async fn delete_member_slg(
         &self,
-        shared_line_group_id: &str,
-        member_id: &str,
+        shared_line_group_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
     ) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}/members/{}",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -346,9 +353,10 @@ impl PhoneSharedLineGroups {
     */
     pub async fn assign_phone_numbers_slg(
         &self,
-        shared_line_group_id: &str,
+        shared_line_group_id: impl AsRef<str>,
         body: &crate::types::AddByocNumberResponse,
     ) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}/phone_numbers",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -377,7 +385,11 @@ impl PhoneSharedLineGroups {
     *
     * * `shared_line_group_id: &str` -- Unique Identifier of the Shared Line Group.
     */
-    pub async fn delete_phone_numbers_slg(&self, shared_line_group_id: &str) -> Result<()> {
+    pub async fn delete_phone_numbers_slg(
+        &self,
+        shared_line_group_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}/phone_numbers",
             crate::progenitor_support::encode_path(shared_line_group_id),
@@ -407,9 +419,11 @@ impl PhoneSharedLineGroups {
     */
     pub async fn delete_phone_number_slg(
         &self,
-        shared_line_group_id: &str,
-        phone_number_id: &str,
+        shared_line_group_id: impl AsRef<str>,
+        phone_number_id: impl AsRef<str>,
     ) -> Result<()> {
+        let shared_line_group_id = shared_line_group_id.as_ref();
+        let phone_number_id = phone_number_id.as_ref();
         let url = format!(
             "/phone/shared_line_groups/{}/phone_numbers/{}",
             crate::progenitor_support::encode_path(shared_line_group_id),
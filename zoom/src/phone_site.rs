@@ -35,15 +35,14 @@ impl PhoneSite {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Sites>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/sites?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/phone/sites"), &query_args);
 
         let resp: crate::types::ListPhoneSitesResponse = self.client.get(&url, None).await?;
 
@@ -75,18 +74,13 @@ impl PhoneSite {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             sites.append(&mut resp.sites);
 
@@ -146,7 +140,11 @@ impl PhoneSite {
     *
     * * `site_id: &str` -- Unique Identifier of the Site.
     */
-    pub async fn get_site(&self, site_id: &str) -> Result<crate::types::GetSiteResponse> {
+    pub async fn get_site(
+        &self,
+        site_id: impl AsRef<str>,
+    ) -> Result<crate::types::GetSiteResponse> {
+        let site_id = site_id.as_ref();
         let url = format!(
             "/phone/sites/{}",
             crate::progenitor_support::encode_path(site_id),
@@ -176,16 +174,18 @@ impl PhoneSite {
     * * `site_id: &str` -- Unique Identifier of the Site.
     * * `transfer_site_id: &str` -- The Site ID of another site where the assets of the current site (users, numbers and phones) can be transferred to.
     */
-    pub async fn delete(&self, site_id: &str, transfer_site_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn delete(&self, site_id: impl AsRef<str>, transfer_site_id: &str) -> Result<()> {
+        let site_id = site_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !transfer_site_id.is_empty() {
-            query_args.push(("transfer_site_id".to_string(), transfer_site_id.to_string()));
+            query_args.push(("transfer_site_id", transfer_site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/sites/{}?{}",
-            crate::progenitor_support::encode_path(site_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/phone/sites/{}",
+                crate::progenitor_support::encode_path(site_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -212,9 +212,10 @@ impl PhoneSite {
     */
     pub async fn update_site_details(
         &self,
-        site_id: &str,
+        site_id: impl AsRef<str>,
         body: &crate::types::UpdateSiteDetailsRequest,
     ) -> Result<()> {
+        let site_id = site_id.as_ref();
         let url = format!(
             "/phone/sites/{}",
             crate::progenitor_support::encode_path(site_id),
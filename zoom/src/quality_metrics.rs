@@ -0,0 +1,154 @@
+//! Monitor the quality of meetings and webinars from the dashboard metrics: the
+//! quality scores of the account, and the quality of service every participant
+//! received.
+//!
+//! ```no_run
+//! # async fn run(zoom: zoom_api::Client) -> anyhow::Result<()> {
+//! use chrono::NaiveDate;
+//! use zoom_api::types::DashboardMeetingsType;
+//!
+//! let dashboards = zoom.dashboards();
+//! let scores = dashboards
+//!     .meeting_quality_scores(
+//!         NaiveDate::from_ymd(2021, 8, 1),
+//!         NaiveDate::from_ymd(2021, 8, 31),
+//!     )
+//!     .await?;
+//! println!("{} meetings with bad audio", scores.quality.audio.bad);
+//!
+//! let participants = dashboards
+//!     .meeting_participants_qos_list_all("85746065432", DashboardMeetingsType::Past)
+//!     .await?;
+//! for participant in participants {
+//!     println!("{}: {} samples", participant.user_name, participant.user_qos.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dashboards::Dashboards,
+    types::{DashboardMeetingsType, DashboardWebinarsType, ParticipantQos},
+};
+
+/// The quality scores of the meetings of the account between two dates.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct MeetingQualityScores {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<chrono::NaiveDate>,
+    #[serde(default)]
+    pub quality: QualityScores,
+}
+
+/// The quality scores of audio, video and screen sharing.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct QualityScores {
+    #[serde(default)]
+    pub audio: QualityScore,
+    #[serde(default)]
+    pub video: QualityScore,
+    #[serde(default)]
+    pub screen_share: QualityScore,
+}
+
+/// How many meetings had a quality rated as good, normal, bad or poor.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct QualityScore {
+    #[serde(default)]
+    pub good: i64,
+    #[serde(default)]
+    pub normal: i64,
+    #[serde(default)]
+    pub bad: i64,
+    #[serde(default)]
+    pub poor: i64,
+}
+
+#[derive(Deserialize)]
+struct ParticipantsQosPage {
+    #[serde(default)]
+    participants: Vec<ParticipantQos>,
+    #[serde(default)]
+    next_page_token: String,
+}
+
+impl Dashboards {
+    /// Get the quality scores of the meetings of the account between two dates.
+    pub async fn meeting_quality_scores(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<MeetingQualityScores> {
+        let url = crate::progenitor_support::append_query(
+            "/metrics/quality",
+            &[("from", &from.to_string()), ("to", &to.to_string())],
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /// List the quality of service of all the participants of a live or past
+    /// meeting, requesting every page.
+    pub async fn meeting_participants_qos_list_all(
+        &self,
+        meeting_id: &str,
+        type_: DashboardMeetingsType,
+    ) -> Result<Vec<ParticipantQos>> {
+        let url = format!(
+            "/metrics/meetings/{}/participants/qos",
+            crate::progenitor_support::encode_path(meeting_id),
+        );
+
+        self.participants_qos_list_all(&url, &type_.to_string())
+            .await
+    }
+
+    /// List the quality of service of all the participants of a live or past
+    /// webinar, requesting every page.
+    pub async fn webinar_participants_qos_list_all(
+        &self,
+        webinar_id: &str,
+        type_: DashboardWebinarsType,
+    ) -> Result<Vec<ParticipantQos>> {
+        let url = format!(
+            "/metrics/webinars/{}/participants/qos",
+            crate::progenitor_support::encode_path(webinar_id),
+        );
+
+        self.participants_qos_list_all(&url, &type_.to_string())
+            .await
+    }
+
+    async fn participants_qos_list_all(
+        &self,
+        url: &str,
+        type_: &str,
+    ) -> Result<Vec<ParticipantQos>> {
+        // The pages of quality of service have at most ten participants.
+        let url =
+            crate::progenitor_support::append_query(url, &[("type", type_), ("page_size", "10")]);
+
+        let mut resp: ParticipantsQosPage = self.client.get(&url, None).await?;
+        let mut participants = resp.participants;
+        while !resp.next_page_token.is_empty() {
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(
+                        &url,
+                        &[("next_page_token", &resp.next_page_token)],
+                    ),
+                    None,
+                )
+                .await?;
+            participants.append(&mut resp.participants);
+        }
+
+        Ok(participants)
+    }
+}
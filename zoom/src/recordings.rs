@@ -0,0 +1,96 @@
+//! List the cloud recordings of a user and download their files.
+//!
+//! ```no_run
+//! # async fn run(zoom: zoom_api::Client) -> anyhow::Result<()> {
+//! use chrono::NaiveDate;
+//! use futures::TryStreamExt;
+//! use tokio::io::AsyncWriteExt;
+//!
+//! let recordings = zoom
+//!     .cloud_recording()
+//!     .recordings_list_all(
+//!         "me",
+//!         NaiveDate::from_ymd(2021, 8, 1),
+//!         NaiveDate::from_ymd(2021, 8, 31),
+//!     )
+//!     .await?;
+//! for file in recordings.iter().flat_map(|m| &m.recording_files) {
+//!     let mut out = tokio::fs::File::create(&file.id).await?;
+//!     let mut download = Box::pin(zoom.cloud_recording().download(&file.download_url).await?);
+//!     while let Some(chunk) = download.try_next().await? {
+//!         out.write_all(&chunk).await?;
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, Result};
+use futures::TryStreamExt;
+
+use crate::{
+    cloud_recording::CloudRecording,
+    types::{GetAccountCloudRecordingResponse, GetAccountCloudRecordingResponseMeetings},
+};
+
+impl CloudRecording {
+    /// List all the meetings of a user that were recorded between two dates, with
+    /// their recording files, requesting every page.
+    pub async fn recordings_list_all(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<GetAccountCloudRecordingResponseMeetings>> {
+        let url = crate::progenitor_support::append_query(
+            &format!(
+                "/users/{}/recordings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &[
+                ("from", &from.to_string()),
+                ("to", &to.to_string()),
+                ("page_size", "300"),
+            ],
+        );
+
+        // The response has the same shape as the one listing the recordings of the
+        // account.
+        let mut resp: GetAccountCloudRecordingResponse = self.client.get(&url, None).await?;
+        let mut meetings = resp.meetings;
+        while !resp.next_page_token.is_empty() {
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(
+                        &url,
+                        &[("next_page_token", &resp.next_page_token)],
+                    ),
+                    None,
+                )
+                .await?;
+            meetings.append(&mut resp.meetings);
+        }
+
+        Ok(meetings)
+    }
+
+    /// Download a recording file from its `download_url`, which takes the same
+    /// access token as the API. The contents are returned as they come in.
+    pub async fn download(
+        &self,
+        download_url: &str,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let resp = self
+            .client
+            .streaming()
+            .request_raw(reqwest::Method::GET, download_url, None)
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(anyhow!("code: {}, error: {:?}", status, resp.text().await?));
+        }
+
+        Ok(resp.bytes_stream().map_err(anyhow::Error::from))
+    }
+}
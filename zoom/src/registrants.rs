@@ -0,0 +1,153 @@
+//! Manage the registrants of meetings that require registration: register people,
+//! list them with all their details, and approve or deny them.
+//!
+//! ```no_run
+//! # async fn run(zoom: zoom_api::Client, registrant: zoom_api::types::Registrant) -> anyhow::Result<()> {
+//! use zoom_api::types::MeetingRegistrantsStatus;
+//!
+//! let meetings = zoom.meetings();
+//! meetings.registrant_add(85746065432, &registrant, &[]).await?;
+//!
+//! let pending = meetings
+//!     .registrants_list_all(85746065432, MeetingRegistrantsStatus::Pending)
+//!     .await?;
+//! let ids: Vec<&str> = pending.iter().map(|r| r.id.as_str()).collect();
+//! meetings.registrants_approve(85746065432, &ids).await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    meetings::Meetings,
+    types::{
+        Assistants, MeetingRegistrantCreateResponse, MeetingRegistrantsStatus, Registrant,
+        RegistrantStatus, RegistrantStatusAction,
+    },
+};
+
+/// A registrant of a meeting, as listed by `Meetings::registrants_list_all`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MeetingRegistrant {
+    #[serde(default)]
+    pub id: String,
+    #[serde(flatten)]
+    pub registrant: Registrant,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<MeetingRegistrantsStatus>,
+    /// The link the registrant joins the meeting with.
+    #[serde(default)]
+    pub join_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Deserialize)]
+struct RegistrantsPage {
+    #[serde(default)]
+    registrants: Vec<MeetingRegistrant>,
+    #[serde(default)]
+    next_page_token: String,
+}
+
+impl Meetings {
+    /// Register someone for a meeting, for the given occurrences of a recurring
+    /// meeting or for all of them when there are none.
+    pub async fn registrant_add(
+        &self,
+        meeting_id: i64,
+        registrant: &Registrant,
+        occurrence_ids: &[&str],
+    ) -> Result<MeetingRegistrantCreateResponse> {
+        let mut url = format!(
+            "/meetings/{}/registrants",
+            crate::progenitor_support::encode_path(&meeting_id),
+        );
+        if !occurrence_ids.is_empty() {
+            url = crate::progenitor_support::append_query(
+                &url,
+                &[("occurrence_ids", &occurrence_ids.join(","))],
+            );
+        }
+
+        self.client
+            .post(
+                &url,
+                Some(reqwest::Body::from(serde_json::to_vec(registrant)?)),
+            )
+            .await
+    }
+
+    /// List all the registrants of a meeting with the given status, requesting
+    /// every page.
+    pub async fn registrants_list_all(
+        &self,
+        meeting_id: i64,
+        status: MeetingRegistrantsStatus,
+    ) -> Result<Vec<MeetingRegistrant>> {
+        let url = crate::progenitor_support::append_query(
+            &format!(
+                "/meetings/{}/registrants",
+                crate::progenitor_support::encode_path(&meeting_id),
+            ),
+            &[("status", &status.to_string()), ("page_size", "300")],
+        );
+
+        let mut resp: RegistrantsPage = self.client.get(&url, None).await?;
+        let mut registrants = resp.registrants;
+        while !resp.next_page_token.is_empty() {
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(
+                        &url,
+                        &[("next_page_token", &resp.next_page_token)],
+                    ),
+                    None,
+                )
+                .await?;
+            registrants.append(&mut resp.registrants);
+        }
+
+        Ok(registrants)
+    }
+
+    /// Approve the registrants with the given ids, who are then sent the link to
+    /// join the meeting.
+    pub async fn registrants_approve(
+        &self,
+        meeting_id: i64,
+        registrant_ids: &[&str],
+    ) -> Result<()> {
+        self.registrants_set_status(meeting_id, RegistrantStatusAction::Approve, registrant_ids)
+            .await
+    }
+
+    /// Deny the registrants with the given ids.
+    pub async fn registrants_deny(&self, meeting_id: i64, registrant_ids: &[&str]) -> Result<()> {
+        self.registrants_set_status(meeting_id, RegistrantStatusAction::Deny, registrant_ids)
+            .await
+    }
+
+    async fn registrants_set_status(
+        &self,
+        meeting_id: i64,
+        action: RegistrantStatusAction,
+        registrant_ids: &[&str],
+    ) -> Result<()> {
+        let body = RegistrantStatus {
+            action,
+            registrants: registrant_ids
+                .iter()
+                .map(|id| Assistants {
+                    email: String::new(),
+                    id: id.to_string(),
+                })
+                .collect(),
+        };
+
+        self.registrant_status(meeting_id, "", &body).await
+    }
+}
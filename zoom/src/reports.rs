@@ -30,15 +30,14 @@ impl Reports {
     * * `month: i64` -- Month for this report.
     */
     pub async fn daily(&self, year: i64, month: i64) -> Result<crate::types::ReportDailyResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if month > 0 {
-            query_args.push(("month".to_string(), month.to_string()));
+            query_args.push(("month", month.to_string()));
         }
         if year > 0 {
-            query_args.push(("year".to_string(), year.to_string()));
+            query_args.push(("year", year.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/daily?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/report/daily"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -74,27 +73,26 @@ impl Reports {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/report/users"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -124,34 +122,36 @@ impl Reports {
     */
     pub async fn meeting(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
         page_size: i64,
         next_page_token: &str,
         type_: crate::types::ReportMeetingsType,
     ) -> Result<crate::types::ReportMeetingsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/report/users/{}/meetings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/report/users/{}/meetings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -178,8 +178,9 @@ impl Reports {
     */
     pub async fn meeting_details(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportMeetingDetailsResponse> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/report/meetings/{}",
             crate::progenitor_support::encode_path(meeting_id),
@@ -213,26 +214,28 @@ impl Reports {
     */
     pub async fn meeting_participant(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> Result<crate::types::ReportMeetingParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let meeting_id = meeting_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/report/meetings/{}/participants?{}",
-            crate::progenitor_support::encode_path(meeting_id),
-            query_
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/report/meetings/{}/participants",
+                crate::progenitor_support::encode_path(meeting_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -259,8 +262,9 @@ impl Reports {
     */
     pub async fn meeting_polls(
         &self,
-        meeting_id: &str,
+        meeting_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        let meeting_id = meeting_id.as_ref();
         let url = format!(
             "/report/meetings/{}/polls",
             crate::progenitor_support::encode_path(meeting_id),
@@ -289,8 +293,9 @@ impl Reports {
     */
     pub async fn webinar_details(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportMeetingDetailsResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/report/webinars/{}",
             crate::progenitor_support::encode_path(webinar_id),
@@ -322,26 +327,28 @@ impl Reports {
     */
     pub async fn webinar_participant(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> Result<crate::types::ReportWebinarParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/report/webinars/{}/participants?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/report/webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -367,8 +374,9 @@ impl Reports {
     */
     pub async fn webinar_polls(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/report/webinars/{}/polls",
             crate::progenitor_support::encode_path(webinar_id),
@@ -399,8 +407,9 @@ impl Reports {
     */
     pub async fn webinar_qa(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportWebinarQaResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/report/webinars/{}/qa",
             crate::progenitor_support::encode_path(webinar_id),
@@ -445,30 +454,30 @@ impl Reports {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/telephone?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/report/telephone"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -495,15 +504,17 @@ impl Reports {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::ReportCloudRecordingResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/cloud_recording?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/report/cloud_recording"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -539,24 +550,26 @@ impl Reports {
         next_page_token: &str,
         category_type: crate::types::CategoryType,
     ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !category_type.to_string().is_empty() {
-            query_args.push(("category_type".to_string(), category_type.to_string()));
+            query_args.push(("category_type", category_type.to_string()));
         }
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/operationlogs?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/report/operationlogs"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -587,21 +600,21 @@ impl Reports {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ActivityLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/activities?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/report/activities"), &query_args);
 
         let resp: crate::types::ReportSignInOutActivitiesResponse =
             self.client.get(&url, None).await?;
@@ -629,15 +642,15 @@ impl Reports {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::ActivityLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/activities?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/report/activities"), &query_args);
 
         let mut resp: crate::types::ReportSignInOutActivitiesResponse =
             self.client.get(&url, None).await?;
@@ -647,18 +660,13 @@ impl Reports {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             activity_logs.append(&mut resp.activity_logs);
 
@@ -717,12 +725,14 @@ impl Reports {
         &self,
         billing_id: &str,
     ) -> Result<crate::types::GetBillingInvoicesReportsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !billing_id.is_empty() {
-            query_args.push(("billing_id".to_string(), billing_id.to_string()));
+            query_args.push(("billing_id", billing_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/billing/invoices?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/report/billing/invoices"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -79,30 +79,32 @@ impl Roles {
     */
     pub async fn members(
         &self,
-        role_id: &str,
+        role_id: impl AsRef<str>,
         page_count: &str,
         page_number: i64,
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::Domains>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let role_id = role_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !page_count.is_empty() {
-            query_args.push(("page_count".to_string(), page_count.to_string()));
+            query_args.push(("page_count", page_count.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/roles/{}/members?{}",
-            crate::progenitor_support::encode_path(role_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/roles/{}/members",
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::RoleMembersList = self.client.get(&url, None).await?;
@@ -127,18 +129,20 @@ impl Roles {
     */
     pub async fn get_all_members(
         &self,
-        role_id: &str,
+        role_id: impl AsRef<str>,
         page_count: &str,
     ) -> Result<Vec<crate::types::Domains>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let role_id = role_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_count.is_empty() {
-            query_args.push(("page_count".to_string(), page_count.to_string()));
+            query_args.push(("page_count", page_count.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/roles/{}/members?{}",
-            crate::progenitor_support::encode_path(role_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/roles/{}/members",
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::RoleMembersList = self.client.get(&url, None).await?;
@@ -148,18 +152,13 @@ impl Roles {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             members.append(&mut resp.members);
 
@@ -193,9 +192,10 @@ impl Roles {
     */
     pub async fn add_members(
         &self,
-        role_id: &str,
+        role_id: impl AsRef<str>,
         body: &crate::types::AddRoleMembersRequest,
     ) -> Result<crate::types::AddRoleMembersResponse> {
+        let role_id = role_id.as_ref();
         let url = format!(
             "/roles/{}/members",
             crate::progenitor_support::encode_path(role_id),
@@ -224,7 +224,13 @@ impl Roles {
     * * `role_id: &str` -- User's first name.
     * * `member_id: &str` -- User's first name.
     */
-    pub async fn member_delete(&self, role_id: &str, member_id: &str) -> Result<()> {
+    pub async fn member_delete(
+        &self,
+        role_id: impl AsRef<str>,
+        member_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let role_id = role_id.as_ref();
+        let member_id = member_id.as_ref();
         let url = format!(
             "/roles/{}/members/{}",
             crate::progenitor_support::encode_path(role_id),
@@ -256,12 +262,10 @@ impl Roles {
     */
     pub async fn get_information(
         &self,
-        role_id: &str,
+        role_id: impl AsRef<str>,
     ) -> Result<crate::types::GetRoleInformationResponse> {
-        let url = format!(
-            "/roles/{}",
-            crate::progenitor_support::encode_path(role_id),
-        );
+        let role_id = role_id.as_ref();
+        let url = format!("/roles/{}", crate::progenitor_support::encode_path(role_id),);
 
         self.client.get(&url, None).await
     }
@@ -286,11 +290,9 @@ impl Roles {
     *
     * * `role_id: &str` -- User's first name.
     */
-    pub async fn delete(&self, role_id: &str) -> Result<()> {
-        let url = format!(
-            "/roles/{}",
-            crate::progenitor_support::encode_path(role_id),
-        );
+    pub async fn delete(&self, role_id: impl AsRef<str>) -> Result<()> {
+        let role_id = role_id.as_ref();
+        let url = format!("/roles/{}", crate::progenitor_support::encode_path(role_id),);
 
         self.client.delete(&url, None).await
     }
@@ -315,13 +317,11 @@ impl Roles {
     */
     pub async fn update(
         &self,
-        role_id: &str,
+        role_id: impl AsRef<str>,
         body: &crate::types::UpdateRoleRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/roles/{}",
-            crate::progenitor_support::encode_path(role_id),
-        );
+        let role_id = role_id.as_ref();
+        let url = format!("/roles/{}", crate::progenitor_support::encode_path(role_id),);
 
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -41,27 +41,26 @@ impl Rooms {
         next_page_token: &str,
         location_id: &str,
     ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if unassigned_rooms {
-            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+            query_args.push(("unassigned_rooms", unassigned_rooms.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/rooms"), &query_args);
 
         let resp: crate::types::ListZoomRoomsResponseData = self.client.get(&url, None).await?;
 
@@ -89,21 +88,20 @@ impl Rooms {
         unassigned_rooms: bool,
         location_id: &str,
     ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
         if unassigned_rooms {
-            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+            query_args.push(("unassigned_rooms", unassigned_rooms.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/rooms"), &query_args);
 
         let mut resp: crate::types::ListZoomRoomsResponseData = self.client.get(&url, None).await?;
 
@@ -112,18 +110,13 @@ impl Rooms {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             rooms.append(&mut resp.rooms);
 
@@ -178,12 +171,10 @@ impl Rooms {
     */
     pub async fn get_zr_profile(
         &self,
-        room_id: &str,
+        room_id: impl AsRef<str>,
     ) -> Result<crate::types::GetZrProfileResponse> {
-        let url = format!(
-            "/rooms/{}",
-            crate::progenitor_support::encode_path(room_id),
-        );
+        let room_id = room_id.as_ref();
+        let url = format!("/rooms/{}", crate::progenitor_support::encode_path(room_id),);
 
         self.client.get(&url, None).await
     }
@@ -203,11 +194,9 @@ impl Rooms {
     *
     * * `room_id: &str` -- Unique Identifier of a Zoom Room.
     */
-    pub async fn delete_zoom(&self, room_id: &str) -> Result<crate::types::Domains> {
-        let url = format!(
-            "/rooms/{}",
-            crate::progenitor_support::encode_path(room_id),
-        );
+    pub async fn delete_zoom(&self, room_id: impl AsRef<str>) -> Result<crate::types::Domains> {
+        let room_id = room_id.as_ref();
+        let url = format!("/rooms/{}", crate::progenitor_support::encode_path(room_id),);
 
         self.client.delete(&url, None).await
     }
@@ -230,13 +219,11 @@ impl Rooms {
     */
     pub async fn update_profile(
         &self,
-        room_id: &str,
+        room_id: impl AsRef<str>,
         body: &crate::types::UpdateRoomProfileRequest,
     ) -> Result<crate::types::Domains> {
-        let url = format!(
-            "/rooms/{}",
-            crate::progenitor_support::encode_path(room_id),
-        );
+        let room_id = room_id.as_ref();
+        let url = format!("/rooms/{}", crate::progenitor_support::encode_path(room_id),);
 
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -264,18 +251,20 @@ impl Rooms {
     */
     pub async fn get_zr_setting(
         &self,
-        room_id: &str,
+        room_id: impl AsRef<str>,
         setting_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let room_id = room_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/{}/settings?{}",
-            crate::progenitor_support::encode_path(room_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/rooms/{}/settings",
+                crate::progenitor_support::encode_path(room_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -300,16 +289,22 @@ impl Rooms {
     *   `meeting`: Meeting settings of the Zoom Room.<br>
     *   `signage`: Digital signage settings applied on the Zoom Room.
     */
-    pub async fn update_zr_settings(&self, room_id: &str, setting_type: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn update_zr_settings(
+        &self,
+        room_id: impl AsRef<str>,
+        setting_type: &str,
+    ) -> Result<()> {
+        let room_id = room_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/{}/settings?{}",
-            crate::progenitor_support::encode_path(room_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/rooms/{}/settings",
+                crate::progenitor_support::encode_path(room_id),
+            ),
+            &query_args,
         );
 
         self.client.patch(&url, None).await
@@ -334,8 +329,9 @@ impl Rooms {
     */
     pub async fn list_zr_devices(
         &self,
-        room_id: &str,
+        room_id: impl AsRef<str>,
     ) -> Result<crate::types::ListZrDevicesResponse> {
+        let room_id = room_id.as_ref();
         let url = format!(
             "/rooms/{}/devices",
             crate::progenitor_support::encode_path(room_id),
@@ -362,9 +358,10 @@ impl Rooms {
     */
     pub async fn change_zr_location(
         &self,
-        room_id: &str,
+        room_id: impl AsRef<str>,
         body: &crate::types::ChangeZrLocationRequest,
     ) -> Result<()> {
+        let room_id = room_id.as_ref();
         let url = format!(
             "/rooms/{}/location",
             crate::progenitor_support::encode_path(room_id),
@@ -392,7 +389,12 @@ impl Rooms {
     *
     * * `id: &str` -- User's first name.
     */
-    pub async fn check(&self, id: &str, body: &crate::types::CheckInRoomsRequest) -> Result<()> {
+    pub async fn check(
+        &self,
+        id: impl AsRef<str>,
+        body: &crate::types::CheckInRoomsRequest,
+    ) -> Result<()> {
+        let id = id.as_ref();
         let url = format!(
             "/rooms/{}/events",
             crate::progenitor_support::encode_path(id),
@@ -433,21 +435,23 @@ impl Rooms {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Site>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/digital_signage?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/rooms/digital_signage"),
+            &query_args,
+        );
 
         let resp: crate::types::ListDigitalSignageContentResponse =
             self.client.get(&url, None).await?;
@@ -477,15 +481,17 @@ impl Rooms {
         type_: &str,
         folder_id: &str,
     ) -> Result<Vec<crate::types::Site>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/digital_signage?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/rooms/digital_signage"),
+            &query_args,
+        );
 
         let mut resp: crate::types::ListDigitalSignageContentResponse =
             self.client.get(&url, None).await?;
@@ -495,18 +501,13 @@ impl Rooms {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             contents.append(&mut resp.contents);
 
@@ -76,12 +76,14 @@ impl RoomsAccount {
         &self,
         setting_type: crate::types::SettingType,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.to_string().is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/account_settings?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/rooms/account_settings"),
+            &query_args,
+        );
 
         self.client.get(&url, None).await
     }
@@ -109,12 +111,14 @@ impl RoomsAccount {
         &self,
         setting_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/account_settings?{}", query_);
+        let url = crate::progenitor_support::build_url(
+            format_args!("/rooms/account_settings"),
+            &query_args,
+        );
 
         self.client.patch(&url, None).await
     }
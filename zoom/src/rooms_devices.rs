@@ -30,10 +30,12 @@ impl RoomsDevices {
     */
     pub async fn change_zoom_rooms_app_version(
         &self,
-        room_id: &str,
-        device_id: &str,
+        room_id: impl AsRef<str>,
+        device_id: impl AsRef<str>,
         body: &crate::types::ChangeZoomRoomsAppVersionRequest,
     ) -> Result<()> {
+        let room_id = room_id.as_ref();
+        let device_id = device_id.as_ref();
         let url = format!(
             "/rooms/{}/devices/{}/app_version",
             crate::progenitor_support::encode_path(room_id),
@@ -40,24 +40,21 @@ impl RoomsLocation {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !parent_location_id.is_empty() {
-            query_args.push((
-                "parent_location_id".to_string(),
-                parent_location_id.to_string(),
-            ));
+            query_args.push(("parent_location_id", parent_location_id.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/locations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/rooms/locations"), &query_args);
 
         let resp: crate::types::ListZrLocationsResponseData = self.client.get(&url, None).await?;
 
@@ -85,18 +82,15 @@ impl RoomsLocation {
         parent_location_id: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent_location_id.is_empty() {
-            query_args.push((
-                "parent_location_id".to_string(),
-                parent_location_id.to_string(),
-            ));
+            query_args.push(("parent_location_id", parent_location_id.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/locations?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/rooms/locations"), &query_args);
 
         let mut resp: crate::types::ListZrLocationsResponseData =
             self.client.get(&url, None).await?;
@@ -106,18 +100,13 @@ impl RoomsLocation {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             locations.append(&mut resp.locations);
 
@@ -177,8 +166,9 @@ impl RoomsLocation {
     */
     pub async fn get_zr_location_profile(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
     ) -> Result<crate::types::GetZrLocationProfileResponse> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/rooms/locations/{}",
             crate::progenitor_support::encode_path(location_id),
@@ -208,9 +198,10 @@ impl RoomsLocation {
     */
     pub async fn update_zr_location_profile(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
         body: &crate::types::GetZrLocationProfileResponse,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/rooms/locations/{}",
             crate::progenitor_support::encode_path(location_id),
@@ -242,18 +233,20 @@ impl RoomsLocation {
     */
     pub async fn get_zr_location_setting(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
         setting_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let location_id = location_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/locations/{}/settings?{}",
-            crate::progenitor_support::encode_path(location_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/rooms/locations/{}/settings",
+                crate::progenitor_support::encode_path(location_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -280,18 +273,20 @@ impl RoomsLocation {
     */
     pub async fn update_zr_location_settings(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
         setting_type: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let location_id = location_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/locations/{}/settings?{}",
-            crate::progenitor_support::encode_path(location_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/rooms/locations/{}/settings",
+                crate::progenitor_support::encode_path(location_id),
+            ),
+            &query_args,
         );
 
         self.client.patch(&url, None).await
@@ -356,9 +351,10 @@ impl RoomsLocation {
     */
     pub async fn change_parent_location(
         &self,
-        location_id: &str,
+        location_id: impl AsRef<str>,
         body: &crate::types::ChangeParentLocationRequest,
     ) -> Result<()> {
+        let location_id = location_id.as_ref();
         let url = format!(
             "/rooms/locations/{}/location",
             crate::progenitor_support::encode_path(location_id),
@@ -50,9 +50,10 @@ impl SipConnectedAudio {
     */
     pub async fn assign_sip_config(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AssignSipConfigRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/settings",
             crate::progenitor_support::encode_path(account_id),
@@ -83,9 +84,10 @@ impl SipConnectedAudio {
     */
     pub async fn assign_sip_trunk_numbers(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AssignSipTrunkNumbersRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/numbers",
             crate::progenitor_support::encode_path(account_id),
@@ -113,7 +115,8 @@ impl SipConnectedAudio {
     *
     * * `account_id: &str` -- Account ID of the sub account from which the numbers are to be deleted. This can be retrieved from [List sub accounts](https://marketplace.zoom.us/docs/api-reference/zoom-api/accounts/account) API.
     */
-    pub async fn delete_all_sip_numbers(&self, account_id: &str) -> Result<()> {
+    pub async fn delete_all_sip_numbers(&self, account_id: impl AsRef<str>) -> Result<()> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/numbers",
             crate::progenitor_support::encode_path(account_id),
@@ -138,8 +141,9 @@ impl SipConnectedAudio {
     */
     pub async fn list_sip_trunk(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::ListSipTrunksResponseData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/trunks",
             crate::progenitor_support::encode_path(account_id),
@@ -163,9 +167,10 @@ impl SipConnectedAudio {
     */
     pub async fn assign_sip_trunks(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AssignSipTrunksRequestData,
     ) -> Result<crate::types::AssignSipTrunksResponseData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/trunks",
             crate::progenitor_support::encode_path(account_id),
@@ -193,9 +198,11 @@ impl SipConnectedAudio {
     */
     pub async fn delete_sip_trunk(
         &self,
-        account_id: &str,
-        trunk_id: &str,
+        account_id: impl AsRef<str>,
+        trunk_id: impl AsRef<str>,
     ) -> Result<crate::types::Domains> {
+        let account_id = account_id.as_ref();
+        let trunk_id = trunk_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/trunks/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -221,8 +228,9 @@ impl SipConnectedAudio {
     */
     pub async fn list_internal_callout_countries(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<crate::types::ListInternalCalloutCountriesResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/callout_countries",
             crate::progenitor_support::encode_path(account_id),
@@ -247,9 +255,10 @@ impl SipConnectedAudio {
     */
     pub async fn add_callout_countries(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AddCalloutCountriesRequestData,
     ) -> Result<crate::types::AddCalloutCountriesResponse> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/callout_countries",
             crate::progenitor_support::encode_path(account_id),
@@ -278,9 +287,11 @@ impl SipConnectedAudio {
     */
     pub async fn delete_internal_call_out_country(
         &self,
-        account_id: &str,
-        country_id: &str,
+        account_id: impl AsRef<str>,
+        country_id: impl AsRef<str>,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let country_id = country_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/callout_countries/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -311,22 +322,24 @@ impl SipConnectedAudio {
     */
     pub async fn list_internal_numbers(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::InternalNumbers>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let account_id = account_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/sip_trunk/internal_numbers?{}",
-            crate::progenitor_support::encode_path(account_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListInternalNumbersResponse = self.client.get(&url, None).await?;
@@ -352,8 +365,9 @@ impl SipConnectedAudio {
     */
     pub async fn list_all_internal_numbers(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::InternalNumbers>> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/internal_numbers",
             crate::progenitor_support::encode_path(account_id),
@@ -367,18 +381,13 @@ impl SipConnectedAudio {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             internal_numbers.append(&mut resp.internal_numbers);
 
@@ -411,9 +420,10 @@ impl SipConnectedAudio {
     */
     pub async fn add_internal_numbers(
         &self,
-        account_id: &str,
+        account_id: impl AsRef<str>,
         body: &crate::types::AddInternalNumbersRequest,
     ) -> Result<crate::types::AddInternalNumbersResponseData> {
+        let account_id = account_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/internal_numbers",
             crate::progenitor_support::encode_path(account_id),
@@ -442,7 +452,13 @@ impl SipConnectedAudio {
     * * `account_id: &str` -- Unique Identifier of the account. To delete an internal number from a sub account, provide the account ID of the sub account in the `accountId` path parameter. To delete an internal number from a master account, provide `me` as the value of the `accountId` path parameter.
     * * `number_id: &str` -- Unique identifier of the phone number. This value can be retrieved by calling the List Internal Numbers API.
     */
-    pub async fn delete_internal_number(&self, account_id: &str, number_id: &str) -> Result<()> {
+    pub async fn delete_internal_number(
+        &self,
+        account_id: impl AsRef<str>,
+        number_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let number_id = number_id.as_ref();
         let url = format!(
             "/accounts/{}/sip_trunk/internal_numbers/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -41,21 +41,20 @@ impl SipPhone {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Phones>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sip_phones?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/sip_phones"), &query_args);
 
         let resp: crate::types::ListSipPhonesResponse = self.client.get(&url, None).await?;
 
@@ -78,12 +77,11 @@ impl SipPhone {
     *
     */
     pub async fn list_all(&self, search_key: &str) -> Result<Vec<crate::types::Phones>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sip_phones?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/sip_phones"), &query_args);
 
         let mut resp: crate::types::ListSipPhonesResponse = self.client.get(&url, None).await?;
 
@@ -92,18 +90,13 @@ impl SipPhone {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             phones.append(&mut resp.phones);
 
@@ -155,7 +148,8 @@ impl SipPhone {
     *
     * * `phone_id: &str` -- Unique Identifier of the SIP Phone. It can be retrieved from the List SIP Phones API.
     */
-    pub async fn delete(&self, phone_id: &str) -> Result<()> {
+    pub async fn delete(&self, phone_id: impl AsRef<str>) -> Result<()> {
+        let phone_id = phone_id.as_ref();
         let url = format!(
             "/sip_phones/{}",
             crate::progenitor_support::encode_path(phone_id),
@@ -182,9 +176,10 @@ impl SipPhone {
     */
     pub async fn update(
         &self,
-        phone_id: &str,
+        phone_id: impl AsRef<str>,
         body: &crate::types::UpdateSipPhoneRequest,
     ) -> Result<()> {
+        let phone_id = phone_id.as_ref();
         let url = format!(
             "/sip_phones/{}",
             crate::progenitor_support::encode_path(phone_id),
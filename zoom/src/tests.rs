@@ -83,3 +83,126 @@ fn test_deserialize_recorded_meetings() {
         deserialized.from.unwrap()
     );
 }
+
+#[tokio::test]
+async fn test_registrants_and_download() {
+    use futures::TryStreamExt;
+
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"next_page_token": "abc", "registrants": [{"id": "9tboDiHUQAeOnbmudzWa5g", "email": "jane@example.com", "first_name": "Jane", "status": "pending"}]}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"next_page_token": "", "registrants": [{"id": "Dp5bHPLbT3i6plrQcq0r8Q", "email": "john@example.com", "status": "pending"}]}"#,
+    );
+    mock.push_response(reqwest::StatusCode::NO_CONTENT, "");
+    mock.push_response(reqwest::StatusCode::OK, "recording bytes");
+
+    let zoom = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let meetings = zoom.meetings();
+    let pending = meetings
+        .registrants_list_all(85746065432, crate::types::MeetingRegistrantsStatus::Pending)
+        .await
+        .unwrap();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].registrant.first_name, "Jane");
+    assert_eq!(
+        pending[0].status,
+        Some(crate::types::MeetingRegistrantsStatus::Pending)
+    );
+
+    let ids: Vec<&str> = pending.iter().map(|r| r.id.as_str()).collect();
+    meetings
+        .registrants_approve(85746065432, &ids)
+        .await
+        .unwrap();
+
+    let download = zoom
+        .cloud_recording()
+        .download("https://zoom.us/rec/download/exImJm")
+        .await
+        .unwrap();
+    let chunks: Vec<bytes::Bytes> = download.try_collect().await.unwrap();
+    assert_eq!(chunks.concat(), b"recording bytes");
+
+    let requests = mock.requests();
+    assert_eq!(
+        requests[1].url.query(),
+        Some("status=pending&page_size=300&next_page_token=abc")
+    );
+    assert_eq!(
+        requests[2].json::<serde_json::Value>().unwrap(),
+        serde_json::json!({
+            "action": "approve",
+            "registrants": [{"id": "9tboDiHUQAeOnbmudzWa5g"}, {"id": "Dp5bHPLbT3i6plrQcq0r8Q"}]
+        })
+    );
+    assert_eq!(
+        requests[3].headers[reqwest::header::AUTHORIZATION],
+        "Bearer token"
+    );
+}
+
+#[tokio::test]
+async fn test_webinars_and_quality_metrics() {
+    let mock = crate::transport::MockTransport::new();
+    mock.push_response(reqwest::StatusCode::NO_CONTENT, "");
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"next_page_token": "abc", "participants": [{"user_id": "16778240", "user_name": "Jane", "user_qos": [{"date_time": "2021-08-02T10:15:00Z", "audio_input": {"bitrate": "27.6 kbps", "latency": "12 ms", "jitter": "2 ms", "avg_loss": "0.1%", "max_loss": "0.9%"}}]}]}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"next_page_token": "", "participants": [{"user_id": "16779264", "user_name": "John", "user_qos": []}]}"#,
+    );
+    mock.push_response(
+        reqwest::StatusCode::OK,
+        r#"{"from": "2021-08-01", "to": "2021-08-31", "quality": {"audio": {"good": 12, "normal": 3, "bad": 1, "poor": 0}}}"#,
+    );
+
+    let zoom = crate::Client::new("", "", "", "token", "").with_transport(mock.clone());
+    let webinar: crate::types::WebinarInfo =
+        serde_json::from_value(serde_json::json!({"topic": "Quarterly review", "duration": 90}))
+            .unwrap();
+    zoom.webinars()
+        .webinar_update(93398114182, "", &webinar)
+        .await
+        .unwrap();
+
+    let dashboards = zoom.dashboards();
+    let participants = dashboards
+        .meeting_participants_qos_list_all("85746065432", crate::types::DashboardMeetingsType::Past)
+        .await
+        .unwrap();
+    assert_eq!(participants.len(), 2);
+    let audio = participants[0].user_qos[0].audio_input.as_ref().unwrap();
+    assert_eq!(audio.latency, "12 ms");
+
+    let scores = dashboards
+        .meeting_quality_scores(
+            chrono::NaiveDate::from_ymd(2021, 8, 1),
+            chrono::NaiveDate::from_ymd(2021, 8, 31),
+        )
+        .await
+        .unwrap();
+    assert_eq!(scores.quality.audio.good, 12);
+    assert_eq!(scores.quality.video, Default::default());
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].method, reqwest::Method::PATCH);
+    assert_eq!(requests[0].url.path(), "/v2/webinars/93398114182");
+    assert_eq!(
+        requests[0].json::<serde_json::Value>().unwrap(),
+        serde_json::json!({"topic": "Quarterly review", "duration": 90})
+    );
+    assert_eq!(
+        requests[2].url.query(),
+        Some("type=past&page_size=10&next_page_token=abc")
+    );
+    assert_eq!(
+        requests[3].url.query(),
+        Some("from=2021-08-01&to=2021-08-31")
+    );
+}
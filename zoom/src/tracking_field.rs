@@ -70,8 +70,9 @@ impl TrackingField {
     */
     pub async fn trackingfield_get(
         &self,
-        field_id: &str,
+        field_id: impl AsRef<str>,
     ) -> Result<crate::types::TrackingfieldGetResponseAllOf> {
+        let field_id = field_id.as_ref();
         let url = format!(
             "/tracking_fields/{}",
             crate::progenitor_support::encode_path(field_id),
@@ -96,7 +97,8 @@ impl TrackingField {
     *
     * * `field_id: &str` -- The Tracking Field ID.
     */
-    pub async fn trackingfield_delete(&self, field_id: &str) -> Result<()> {
+    pub async fn trackingfield_delete(&self, field_id: impl AsRef<str>) -> Result<()> {
+        let field_id = field_id.as_ref();
         let url = format!(
             "/tracking_fields/{}",
             crate::progenitor_support::encode_path(field_id),
@@ -123,9 +125,10 @@ impl TrackingField {
     */
     pub async fn trackingfield_update(
         &self,
-        field_id: &str,
+        field_id: impl AsRef<str>,
         body: &crate::types::TrackingField,
     ) -> Result<()> {
+        let field_id = field_id.as_ref();
         let url = format!(
             "/tracking_fields/{}",
             crate::progenitor_support::encode_path(field_id),
@@ -64,7 +64,11 @@ impl Tsp {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn user_ts_ps(&self, user_id: &str) -> Result<crate::types::UserTsPsResponse> {
+    pub async fn user_ts_ps(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<crate::types::UserTsPsResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/tsp",
             crate::progenitor_support::encode_path(user_id),
@@ -90,9 +94,10 @@ impl Tsp {
     */
     pub async fn user_create(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::TspAccountsList,
     ) -> Result<crate::types::TspAccountsList> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/tsp",
             crate::progenitor_support::encode_path(user_id),
@@ -120,7 +125,13 @@ impl Tsp {
     * * `tsp_id: &str` -- Audio types:<br>`1` - Toll-free Call-in & Call-out.<br>`2` - Toll <br>
     *  `3` - SIP Connected Audio.
     */
-    pub async fn user(&self, user_id: &str, tsp_id: &str) -> Result<crate::types::TspAccount> {
+    pub async fn user(
+        &self,
+        user_id: impl AsRef<str>,
+        tsp_id: impl AsRef<str>,
+    ) -> Result<crate::types::TspAccount> {
+        let user_id = user_id.as_ref();
+        let tsp_id = tsp_id.as_ref();
         let url = format!(
             "/users/{}/tsp/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -147,7 +158,13 @@ impl Tsp {
     * * `tsp_id: &str` -- Audio types:<br>`1` - Toll-free Call-in & Call-out.<br>`2` - Toll <br>
     *  `3` - SIP Connected Audio.
     */
-    pub async fn user_delete(&self, user_id: &str, tsp_id: &str) -> Result<()> {
+    pub async fn user_delete(
+        &self,
+        user_id: impl AsRef<str>,
+        tsp_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let tsp_id = tsp_id.as_ref();
         let url = format!(
             "/users/{}/tsp/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -176,10 +193,12 @@ impl Tsp {
     */
     pub async fn user_update(
         &self,
-        user_id: &str,
-        tsp_id: &str,
+        user_id: impl AsRef<str>,
+        tsp_id: impl AsRef<str>,
         body: &crate::types::TspAccountData,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let tsp_id = tsp_id.as_ref();
         let url = format!(
             "/users/{}/tsp/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -208,9 +227,10 @@ impl Tsp {
     */
     pub async fn url_update(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::TspGlobalDialIn,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/tsp/settings",
             crate::progenitor_support::encode_path(user_id),
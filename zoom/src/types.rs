@@ -11,8 +11,8 @@ pub enum PayMode {
     Master,
     #[serde(rename = "sub")]
     Sub,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayMode {
@@ -20,7 +20,7 @@ impl std::fmt::Display for PayMode {
         match &*self {
             PayMode::Master => "master",
             PayMode::Sub => "sub",
-            PayMode::FallthroughString => "*",
+            PayMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -238,7 +238,7 @@ pub struct AccountListItem {
     pub accounts: Vec<Accounts>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Domains {}
 
 /// Account plan object.
@@ -388,7 +388,7 @@ pub struct PlanNumber {
 }
 
 /// Phone Plan Object
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PhonePlan {
     /**
     * Phone Plan Object
@@ -485,8 +485,8 @@ pub enum AudioType {
     ThirdParty,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioType {
@@ -496,7 +496,7 @@ impl std::fmt::Display for AudioType {
             AudioType::Telephony => "telephony",
             AudioType::ThirdParty => "thirdParty",
             AudioType::Voip => "voip",
-            AudioType::FallthroughString => "*",
+            AudioType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -522,8 +522,8 @@ pub enum RequirePasswordPmiMeetings {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequirePasswordPmiMeetings {
@@ -533,7 +533,7 @@ impl std::fmt::Display for RequirePasswordPmiMeetings {
             RequirePasswordPmiMeetings::JbhOnly => "jbh_only",
             RequirePasswordPmiMeetings::None => "none",
             RequirePasswordPmiMeetings::Noop => "",
-            RequirePasswordPmiMeetings::FallthroughString => "*",
+            RequirePasswordPmiMeetings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -776,8 +776,8 @@ pub enum EntryExitChime {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntryExitChime {
@@ -787,7 +787,7 @@ impl std::fmt::Display for EntryExitChime {
             EntryExitChime::Host => "host",
             EntryExitChime::None => "none",
             EntryExitChime::Noop => "",
-            EntryExitChime::FallthroughString => "*",
+            EntryExitChime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -905,8 +905,8 @@ pub enum WhoCanShareScreen {
     Host,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WhoCanShareScreen {
@@ -915,7 +915,7 @@ impl std::fmt::Display for WhoCanShareScreen {
             WhoCanShareScreen::All => "all",
             WhoCanShareScreen::Host => "host",
             WhoCanShareScreen::Noop => "",
-            WhoCanShareScreen::FallthroughString => "*",
+            WhoCanShareScreen::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1555,8 +1555,8 @@ pub enum SignInWithTwoFactorAuth {
     Role,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignInWithTwoFactorAuth {
@@ -1567,7 +1567,7 @@ impl std::fmt::Display for SignInWithTwoFactorAuth {
             SignInWithTwoFactorAuth::None => "none",
             SignInWithTwoFactorAuth::Role => "role",
             SignInWithTwoFactorAuth::Noop => "",
-            SignInWithTwoFactorAuth::FallthroughString => "*",
+            SignInWithTwoFactorAuth::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1672,8 +1672,8 @@ pub enum AutoRecording {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecording {
@@ -1683,7 +1683,7 @@ impl std::fmt::Display for AutoRecording {
             AutoRecording::Local => "local",
             AutoRecording::None => "none",
             AutoRecording::Noop => "",
-            AutoRecording::FallthroughString => "*",
+            AutoRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2571,8 +2571,8 @@ pub enum Protocol {
     Sip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Protocol {
@@ -2581,7 +2581,7 @@ impl std::fmt::Display for Protocol {
             Protocol::H323 => "H.323",
             Protocol::Sip => "SIP",
             Protocol::Noop => "",
-            Protocol::FallthroughString => "*",
+            Protocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2611,8 +2611,8 @@ pub enum Encryption {
     Yes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Encryption {
@@ -2622,7 +2622,7 @@ impl std::fmt::Display for Encryption {
             Encryption::No => "no",
             Encryption::Yes => "yes",
             Encryption::Noop => "",
-            Encryption::FallthroughString => "*",
+            Encryption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2880,8 +2880,8 @@ pub enum Audio {
     Telephony,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Audio {
@@ -2890,7 +2890,7 @@ impl std::fmt::Display for Audio {
             Audio::Both => "both",
             Audio::Telephony => "telephony",
             Audio::Voip => "voip",
-            Audio::FallthroughString => "*",
+            Audio::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2913,8 +2913,8 @@ pub enum Type {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2923,7 +2923,7 @@ impl std::fmt::Display for Type {
             Type::Toll => "toll",
             Type::Tollfree => "tollfree",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2999,8 +2999,8 @@ pub enum EncryptionType {
     EnhancedEncryption,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EncryptionType {
@@ -3009,7 +3009,7 @@ impl std::fmt::Display for EncryptionType {
             EncryptionType::E2Ee => "e2ee",
             EncryptionType::EnhancedEncryption => "enhanced_encryption",
             EncryptionType::Noop => "",
-            EncryptionType::FallthroughString => "*",
+            EncryptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3039,8 +3039,8 @@ pub enum Method {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -3049,7 +3049,7 @@ impl std::fmt::Display for Method {
             Method::Approve => "approve",
             Method::Deny => "deny",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3559,8 +3559,8 @@ pub enum WeeklyDays {
     Six,
     #[serde(rename = "7")]
     Seven,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WeeklyDays {
@@ -3573,7 +3573,7 @@ impl std::fmt::Display for WeeklyDays {
             WeeklyDays::Five => "5",
             WeeklyDays::Six => "6",
             WeeklyDays::Seven => "7",
-            WeeklyDays::FallthroughString => "*",
+            WeeklyDays::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3821,8 +3821,8 @@ pub enum Status {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -3831,7 +3831,7 @@ impl std::fmt::Display for Status {
             Status::Started => "started",
             Status::Waiting => "waiting",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4252,8 +4252,8 @@ pub enum Action {
     Stop,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -4262,7 +4262,7 @@ impl std::fmt::Display for Action {
             Action::Start => "start",
             Action::Stop => "stop",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4604,8 +4604,8 @@ pub enum RecordingStatus {
     Completed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatus {
@@ -4613,7 +4613,7 @@ impl std::fmt::Display for RecordingStatus {
         match &*self {
             RecordingStatus::Completed => "completed",
             RecordingStatus::Noop => "",
-            RecordingStatus::FallthroughString => "*",
+            RecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4825,8 +4825,8 @@ pub enum ShareRecording {
     Publicly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShareRecording {
@@ -4836,7 +4836,7 @@ impl std::fmt::Display for ShareRecording {
             ShareRecording::None => "none",
             ShareRecording::Publicly => "publicly",
             ShareRecording::Noop => "",
-            ShareRecording::FallthroughString => "*",
+            ShareRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5106,8 +5106,8 @@ pub enum RegistrantStatusAction {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RegistrantStatusAction {
@@ -5117,7 +5117,7 @@ impl std::fmt::Display for RegistrantStatusAction {
             RegistrantStatusAction::Cancel => "cancel",
             RegistrantStatusAction::Deny => "deny",
             RegistrantStatusAction::Noop => "",
-            RegistrantStatusAction::FallthroughString => "*",
+            RegistrantStatusAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5583,8 +5583,8 @@ pub enum AnswerQuestions {
     Only,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AnswerQuestions {
@@ -5593,7 +5593,7 @@ impl std::fmt::Display for AnswerQuestions {
             AnswerQuestions::All => "all",
             AnswerQuestions::Only => "only",
             AnswerQuestions::Noop => "",
-            AnswerQuestions::FallthroughString => "*",
+            AnswerQuestions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6200,8 +6200,8 @@ pub enum TspAccountsListDialInNumbersType {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspAccountsListDialInNumbersType {
@@ -6211,7 +6211,7 @@ impl std::fmt::Display for TspAccountsListDialInNumbersType {
             TspAccountsListDialInNumbersType::Toll => "toll",
             TspAccountsListDialInNumbersType::Tollfree => "tollfree",
             TspAccountsListDialInNumbersType::Noop => "",
-            TspAccountsListDialInNumbersType::FallthroughString => "*",
+            TspAccountsListDialInNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6276,8 +6276,8 @@ pub enum TspBridge {
     UsTspTb,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspBridge {
@@ -6286,7 +6286,7 @@ impl std::fmt::Display for TspBridge {
             TspBridge::EuTspTb => "EU_TSP_TB",
             TspBridge::UsTspTb => "US_TSP_TB",
             TspBridge::Noop => "",
-            TspBridge::FallthroughString => "*",
+            TspBridge::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6577,8 +6577,8 @@ pub enum Label {
     Office,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Label {
@@ -6589,7 +6589,7 @@ impl std::fmt::Display for Label {
             Label::Mobile => "Mobile",
             Label::Office => "Office",
             Label::Noop => "",
-            Label::FallthroughString => "*",
+            Label::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8343,8 +8343,8 @@ pub enum PollQuestionsType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollQuestionsType {
@@ -8353,7 +8353,7 @@ impl std::fmt::Display for PollQuestionsType {
             PollQuestionsType::Multiple => "multiple",
             PollQuestionsType::Single => "single",
             PollQuestionsType::Noop => "",
-            PollQuestionsType::FallthroughString => "*",
+            PollQuestionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8456,8 +8456,8 @@ pub enum FieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldName {
@@ -8477,7 +8477,7 @@ impl std::fmt::Display for FieldName {
             FieldName::State => "state",
             FieldName::Zip => "zip",
             FieldName::Noop => "",
-            FieldName::FallthroughString => "*",
+            FieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8522,8 +8522,8 @@ pub enum MeetingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
@@ -8532,7 +8532,7 @@ impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
             MeetingRegistrantQuestionsCustomType::Short => "short",
             MeetingRegistrantQuestionsCustomType::Single => "single",
             MeetingRegistrantQuestionsCustomType::Noop => "",
-            MeetingRegistrantQuestionsCustomType::FallthroughString => "*",
+            MeetingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8668,8 +8668,8 @@ pub enum RecordingRegistrantQuestionsFieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
@@ -8692,7 +8692,7 @@ impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
             RecordingRegistrantQuestionsFieldName::State => "state",
             RecordingRegistrantQuestionsFieldName::Zip => "zip",
             RecordingRegistrantQuestionsFieldName::Noop => "",
-            RecordingRegistrantQuestionsFieldName::FallthroughString => "*",
+            RecordingRegistrantQuestionsFieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8739,8 +8739,8 @@ pub enum RecordingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
@@ -8750,7 +8750,7 @@ impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
             RecordingRegistrantQuestionsCustomType::Short => "short",
             RecordingRegistrantQuestionsCustomType::Single => "single",
             RecordingRegistrantQuestionsCustomType::Noop => "",
-            RecordingRegistrantQuestionsCustomType::FallthroughString => "*",
+            RecordingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10257,8 +10257,8 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     Internally,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionType {
@@ -10270,7 +10270,7 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionTyp
             }
             AccountSettingsRecordingAuthenticationUpdateOptionType::Internally => "internally",
             AccountSettingsRecordingAuthenticationUpdateOptionType::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionType::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10300,8 +10300,8 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAction {
@@ -10311,7 +10311,7 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAct
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete => "delete",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Update => "update",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10899,8 +10899,8 @@ pub enum FileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -10913,7 +10913,7 @@ impl std::fmt::Display for FileType {
             FileType::Mp4 => "MP4",
             FileType::Transcript => "TRANSCRIPT",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10972,8 +10972,8 @@ pub enum RecordingType {
     SpeakerView,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingType {
@@ -10991,7 +10991,7 @@ impl std::fmt::Display for RecordingType {
             RecordingType::SharedScreenWithSpeakerViewCc => "shared_screen_with_speaker_view(CC)",
             RecordingType::SpeakerView => "speaker_view",
             RecordingType::Noop => "",
-            RecordingType::FallthroughString => "*",
+            RecordingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11270,8 +11270,8 @@ pub enum RecordingStatusUpdateBodyRequestAction {
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
@@ -11279,7 +11279,7 @@ impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
         match &*self {
             RecordingStatusUpdateBodyRequestAction::Recover => "recover",
             RecordingStatusUpdateBodyRequestAction::Noop => "",
-            RecordingStatusUpdateBodyRequestAction::FallthroughString => "*",
+            RecordingStatusUpdateBodyRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11296,13 +11296,36 @@ impl RecordingStatusUpdateBodyRequestAction {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecordingStatusUpdateBodyRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<RecordingStatusUpdateBodyRequestAction>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl RecordingStatusUpdateBodyRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> RecordingStatusUpdateBodyRequestBuilder {
+        RecordingStatusUpdateBodyRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `RecordingStatusUpdateBodyRequest`.
+#[derive(Debug, Clone)]
+pub struct RecordingStatusUpdateBodyRequestBuilder(RecordingStatusUpdateBodyRequest);
+
+impl RecordingStatusUpdateBodyRequestBuilder {
+    pub fn action(mut self, action: impl Into<RecordingStatusUpdateBodyRequestAction>) -> Self {
+        self.0.action = Some(action.into());
+        self
+    }
+
+    pub fn build(self) -> RecordingStatusUpdateBodyRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UploadVbRequest {
     /**
     * User's first name.
@@ -11315,8 +11338,45 @@ pub struct UploadVbRequest {
     pub file: String,
 }
 
+impl std::convert::From<String> for UploadVbRequest {
+    fn from(file: String) -> Self {
+        UploadVbRequest { file }
+    }
+}
+
+impl std::convert::From<&str> for UploadVbRequest {
+    fn from(file: &str) -> Self {
+        UploadVbRequest {
+            file: file.to_string(),
+        }
+    }
+}
+
+impl UploadVbRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UploadVbRequestBuilder {
+        UploadVbRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UploadVbRequest`.
+#[derive(Debug, Clone)]
+pub struct UploadVbRequestBuilder(UploadVbRequest);
+
+impl UploadVbRequestBuilder {
+    pub fn file(mut self, file: impl Into<String>) -> Self {
+        self.0.file = file.into();
+        self
+    }
+
+    pub fn build(self) -> UploadVbRequest {
+        self.0
+    }
+}
+
 /// Specify emergency address for the account.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmergencyAddress {
     /**
     * User's first name.
@@ -11376,7 +11436,7 @@ pub struct EmergencyAddress {
     pub zip: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetUpAccountRequest {
     /**
     * Specify emergency address for the account.
@@ -11394,6 +11454,34 @@ pub struct SetUpAccountRequest {
     pub extension_number: String,
 }
 
+impl SetUpAccountRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SetUpAccountRequestBuilder {
+        SetUpAccountRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SetUpAccountRequest`.
+#[derive(Debug, Clone)]
+pub struct SetUpAccountRequestBuilder(SetUpAccountRequest);
+
+impl SetUpAccountRequestBuilder {
+    pub fn emergency_address(mut self, emergency_address: impl Into<EmergencyAddress>) -> Self {
+        self.0.emergency_address = emergency_address.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: impl Into<String>) -> Self {
+        self.0.extension_number = extension_number.into();
+        self
+    }
+
+    pub fn build(self) -> SetUpAccountRequest {
+        self.0
+    }
+}
+
 /**
 * Query response by number assignment. The value can be one of the following:
 *   <br>
@@ -11411,8 +11499,8 @@ pub enum ListAccountPhoneNumbersType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersType {
@@ -11422,7 +11510,7 @@ impl std::fmt::Display for ListAccountPhoneNumbersType {
             ListAccountPhoneNumbersType::Assigned => "assigned",
             ListAccountPhoneNumbersType::Unassigned => "unassigned",
             ListAccountPhoneNumbersType::Noop => "",
-            ListAccountPhoneNumbersType::FallthroughString => "*",
+            ListAccountPhoneNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11456,8 +11544,8 @@ pub enum ExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ExtensionType {
@@ -11468,7 +11556,7 @@ impl std::fmt::Display for ExtensionType {
             ExtensionType::CommonAreaPhone => "commonAreaPhone",
             ExtensionType::User => "user",
             ExtensionType::Noop => "",
-            ExtensionType::FallthroughString => "*",
+            ExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11496,8 +11584,8 @@ pub enum Source {
     Internal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -11506,7 +11594,7 @@ impl std::fmt::Display for Source {
             Source::External => "external",
             Source::Internal => "internal",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11534,8 +11622,8 @@ pub enum ListAccountPhoneNumbersResponseStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
@@ -11544,7 +11632,7 @@ impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
             ListAccountPhoneNumbersResponseStatus::Available => "available",
             ListAccountPhoneNumbersResponseStatus::Pending => "pending",
             ListAccountPhoneNumbersResponseStatus::Noop => "",
-            ListAccountPhoneNumbersResponseStatus::FallthroughString => "*",
+            ListAccountPhoneNumbersResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11800,8 +11888,8 @@ pub enum TransportProtocol {
     Udp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransportProtocol {
@@ -11812,7 +11900,7 @@ impl std::fmt::Display for TransportProtocol {
             TransportProtocol::Tls => "TLS",
             TransportProtocol::Udp => "UDP",
             TransportProtocol::Noop => "",
-            TransportProtocol::FallthroughString => "*",
+            TransportProtocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12044,7 +12132,7 @@ pub struct ListSipPhonesResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateSipPhoneRequest {
     /**
     * User's first name.
@@ -12192,7 +12280,111 @@ pub struct CreateSipPhoneRequest {
     pub voice_mail: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl CreateSipPhoneRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateSipPhoneRequestBuilder {
+        CreateSipPhoneRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateSipPhoneRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateSipPhoneRequestBuilder(CreateSipPhoneRequest);
+
+impl CreateSipPhoneRequestBuilder {
+    pub fn authorization_name(mut self, authorization_name: impl Into<String>) -> Self {
+        self.0.authorization_name = authorization_name.into();
+        self
+    }
+
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.0.domain = domain.into();
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.0.password = password.into();
+        self
+    }
+
+    pub fn proxy_server(mut self, proxy_server: impl Into<String>) -> Self {
+        self.0.proxy_server = proxy_server.into();
+        self
+    }
+
+    pub fn proxy_server_2(mut self, proxy_server_2: impl Into<String>) -> Self {
+        self.0.proxy_server_2 = proxy_server_2.into();
+        self
+    }
+
+    pub fn proxy_server_3(mut self, proxy_server_3: impl Into<String>) -> Self {
+        self.0.proxy_server_3 = proxy_server_3.into();
+        self
+    }
+
+    pub fn register_server(mut self, register_server: impl Into<String>) -> Self {
+        self.0.register_server = register_server.into();
+        self
+    }
+
+    pub fn register_server_2(mut self, register_server_2: impl Into<String>) -> Self {
+        self.0.register_server_2 = register_server_2.into();
+        self
+    }
+
+    pub fn register_server_3(mut self, register_server_3: impl Into<String>) -> Self {
+        self.0.register_server_3 = register_server_3.into();
+        self
+    }
+
+    pub fn registration_expire_time(mut self, registration_expire_time: i64) -> Self {
+        self.0.registration_expire_time = registration_expire_time;
+        self
+    }
+
+    pub fn transport_protocol(mut self, transport_protocol: impl Into<TransportProtocol>) -> Self {
+        self.0.transport_protocol = Some(transport_protocol.into());
+        self
+    }
+
+    pub fn transport_protocol_2(
+        mut self,
+        transport_protocol_2: impl Into<TransportProtocol>,
+    ) -> Self {
+        self.0.transport_protocol_2 = Some(transport_protocol_2.into());
+        self
+    }
+
+    pub fn transport_protocol_3(
+        mut self,
+        transport_protocol_3: impl Into<TransportProtocol>,
+    ) -> Self {
+        self.0.transport_protocol_3 = Some(transport_protocol_3.into());
+        self
+    }
+
+    pub fn user_email(mut self, user_email: impl Into<String>) -> Self {
+        self.0.user_email = user_email.into();
+        self
+    }
+
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.0.user_name = user_name.into();
+        self
+    }
+
+    pub fn voice_mail(mut self, voice_mail: impl Into<String>) -> Self {
+        self.0.voice_mail = voice_mail.into();
+        self
+    }
+
+    pub fn build(self) -> CreateSipPhoneRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSipPhoneRequest {
     /**
     * User's first name.
@@ -12331,6 +12523,105 @@ pub struct UpdateSipPhoneRequest {
     pub voice_mail: String,
 }
 
+impl UpdateSipPhoneRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateSipPhoneRequestBuilder {
+        UpdateSipPhoneRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateSipPhoneRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateSipPhoneRequestBuilder(UpdateSipPhoneRequest);
+
+impl UpdateSipPhoneRequestBuilder {
+    pub fn authorization_name(mut self, authorization_name: impl Into<String>) -> Self {
+        self.0.authorization_name = authorization_name.into();
+        self
+    }
+
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.0.domain = domain.into();
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.0.password = password.into();
+        self
+    }
+
+    pub fn proxy_server(mut self, proxy_server: impl Into<String>) -> Self {
+        self.0.proxy_server = proxy_server.into();
+        self
+    }
+
+    pub fn proxy_server_2(mut self, proxy_server_2: impl Into<String>) -> Self {
+        self.0.proxy_server_2 = proxy_server_2.into();
+        self
+    }
+
+    pub fn proxy_server_3(mut self, proxy_server_3: impl Into<String>) -> Self {
+        self.0.proxy_server_3 = proxy_server_3.into();
+        self
+    }
+
+    pub fn register_server(mut self, register_server: impl Into<String>) -> Self {
+        self.0.register_server = register_server.into();
+        self
+    }
+
+    pub fn register_server_2(mut self, register_server_2: impl Into<String>) -> Self {
+        self.0.register_server_2 = register_server_2.into();
+        self
+    }
+
+    pub fn register_server_3(mut self, register_server_3: impl Into<String>) -> Self {
+        self.0.register_server_3 = register_server_3.into();
+        self
+    }
+
+    pub fn registration_expire_time(mut self, registration_expire_time: i64) -> Self {
+        self.0.registration_expire_time = registration_expire_time;
+        self
+    }
+
+    pub fn transport_protocol(mut self, transport_protocol: impl Into<TransportProtocol>) -> Self {
+        self.0.transport_protocol = Some(transport_protocol.into());
+        self
+    }
+
+    pub fn transport_protocol_2(
+        mut self,
+        transport_protocol_2: impl Into<TransportProtocol>,
+    ) -> Self {
+        self.0.transport_protocol_2 = Some(transport_protocol_2.into());
+        self
+    }
+
+    pub fn transport_protocol_3(
+        mut self,
+        transport_protocol_3: impl Into<TransportProtocol>,
+    ) -> Self {
+        self.0.transport_protocol_3 = Some(transport_protocol_3.into());
+        self
+    }
+
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.0.user_name = user_name.into();
+        self
+    }
+
+    pub fn voice_mail(mut self, voice_mail: impl Into<String>) -> Self {
+        self.0.voice_mail = voice_mail.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateSipPhoneRequest {
+        self.0
+    }
+}
+
 /**
 * The status of the Zoom Room.
 */
@@ -12346,8 +12637,8 @@ pub enum ListZoomRoomsStatus {
     UnderConstruction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsStatus {
@@ -12358,7 +12649,7 @@ impl std::fmt::Display for ListZoomRoomsStatus {
             ListZoomRoomsStatus::Offline => "Offline",
             ListZoomRoomsStatus::UnderConstruction => "UnderConstruction",
             ListZoomRoomsStatus::Noop => "",
-            ListZoomRoomsStatus::FallthroughString => "*",
+            ListZoomRoomsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12388,8 +12679,8 @@ pub enum ListZoomRoomsType {
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsType {
@@ -12399,7 +12690,7 @@ impl std::fmt::Display for ListZoomRoomsType {
             ListZoomRoomsType::SchedulingDisplayOnly => "SchedulingDisplayOnly",
             ListZoomRoomsType::ZoomRoom => "ZoomRoom",
             ListZoomRoomsType::Noop => "",
-            ListZoomRoomsType::FallthroughString => "*",
+            ListZoomRoomsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12501,7 +12792,7 @@ pub struct ListZoomRoomsResponseData {
     pub rooms: Vec<ListZoomRoomsResponse>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddRoomRequest {
     /**
     * User's first name.
@@ -12532,6 +12823,39 @@ pub struct AddRoomRequest {
     pub type_: ListZoomRoomsType,
 }
 
+impl AddRoomRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddRoomRequestBuilder {
+        AddRoomRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddRoomRequest`.
+#[derive(Debug, Clone)]
+pub struct AddRoomRequestBuilder(AddRoomRequest);
+
+impl AddRoomRequestBuilder {
+    pub fn location_id(mut self, location_id: impl Into<String>) -> Self {
+        self.0.location_id = location_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<ListZoomRoomsType>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> AddRoomRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddRoomResponse {
     /**
@@ -12796,7 +13120,7 @@ pub struct ShortExtension {
     pub length: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreatePhoneSiteRequest {
     /**
     * User's first name.
@@ -12834,6 +13158,52 @@ pub struct CreatePhoneSiteRequest {
     pub site_code: i64,
 }
 
+impl CreatePhoneSiteRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreatePhoneSiteRequestBuilder {
+        CreatePhoneSiteRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreatePhoneSiteRequest`.
+#[derive(Debug, Clone)]
+pub struct CreatePhoneSiteRequestBuilder(CreatePhoneSiteRequest);
+
+impl CreatePhoneSiteRequestBuilder {
+    pub fn auto_receptionist_name(mut self, auto_receptionist_name: impl Into<String>) -> Self {
+        self.0.auto_receptionist_name = auto_receptionist_name.into();
+        self
+    }
+
+    pub fn default_emergency_address(
+        mut self,
+        default_emergency_address: impl Into<DefaultEmergencyAddress>,
+    ) -> Self {
+        self.0.default_emergency_address = Some(default_emergency_address.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn short_extension(mut self, short_extension: impl Into<ShortExtension>) -> Self {
+        self.0.short_extension = Some(short_extension.into());
+        self
+    }
+
+    pub fn site_code(mut self, site_code: i64) -> Self {
+        self.0.site_code = site_code;
+        self
+    }
+
+    pub fn build(self) -> CreatePhoneSiteRequest {
+        self.0
+    }
+}
+
 /// [Main Auto Receptionist](https://support.zoom.us/hc/en-us/articles/360021121312#h_bc7ff1d5-0e6c-40cd-b889-62010cb98c57) for each site.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSiteResponseMainAutoReceptionist {
@@ -12921,7 +13291,7 @@ pub struct GetSiteResponse {
     pub site_code: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSiteDetailsRequest {
     /**
     * User's first name.
@@ -12943,6 +13313,34 @@ pub struct UpdateSiteDetailsRequest {
     pub site_code: i64,
 }
 
+impl UpdateSiteDetailsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateSiteDetailsRequestBuilder {
+        UpdateSiteDetailsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateSiteDetailsRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateSiteDetailsRequestBuilder(UpdateSiteDetailsRequest);
+
+impl UpdateSiteDetailsRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn site_code(mut self, site_code: i64) -> Self {
+        self.0.site_code = site_code;
+        self
+    }
+
+    pub fn build(self) -> UpdateSiteDetailsRequest {
+        self.0
+    }
+}
+
 /**
 * Presence status of the contact in Zoom Client. The value of this field can be one of the following:
 *   `Do_Not_Disturb`<br> `away`<br> `Available`<br> `Offline`
@@ -12959,8 +13357,8 @@ pub enum PresenceStatus {
     Offline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PresenceStatus {
@@ -12971,7 +13369,7 @@ impl std::fmt::Display for PresenceStatus {
             PresenceStatus::DoNotDisturb => "Do_Not_Disturb",
             PresenceStatus::Offline => "Offline",
             PresenceStatus::Noop => "",
-            PresenceStatus::FallthroughString => "*",
+            PresenceStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13161,8 +13559,8 @@ pub enum GetChatMessagesResponseStatus {
     Normal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetChatMessagesResponseStatus {
@@ -13172,7 +13570,7 @@ impl std::fmt::Display for GetChatMessagesResponseStatus {
             GetChatMessagesResponseStatus::Edited => "Edited",
             GetChatMessagesResponseStatus::Normal => "Normal",
             GetChatMessagesResponseStatus::Noop => "",
-            GetChatMessagesResponseStatus::FallthroughString => "*",
+            GetChatMessagesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13382,7 +13780,7 @@ pub struct AtItems {
     pub start_position: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendaChatMessageRequest {
     /**
     * [Chat mentions](https://support.zoom.us/hc/en-us/articles/360037567431-Using-chat-mentions-and-slash-commands) object. Use this object to include mentions in the message that will be sent to  a channel.
@@ -13422,7 +13820,45 @@ pub struct SendaChatMessageRequest {
     pub to_contact: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl SendaChatMessageRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SendaChatMessageRequestBuilder {
+        SendaChatMessageRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SendaChatMessageRequest`.
+#[derive(Debug, Clone)]
+pub struct SendaChatMessageRequestBuilder(SendaChatMessageRequest);
+
+impl SendaChatMessageRequestBuilder {
+    pub fn at_items(mut self, at_items: Vec<AtItems>) -> Self {
+        self.0.at_items = at_items;
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn to_channel(mut self, to_channel: impl Into<String>) -> Self {
+        self.0.to_channel = to_channel.into();
+        self
+    }
+
+    pub fn to_contact(mut self, to_contact: impl Into<String>) -> Self {
+        self.0.to_contact = to_contact.into();
+        self
+    }
+
+    pub fn build(self) -> SendaChatMessageRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MarkMessageRequest {
     /**
     * User's first name.
@@ -13462,6 +13898,44 @@ pub struct MarkMessageRequest {
     pub to_contact: String,
 }
 
+impl MarkMessageRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MarkMessageRequestBuilder {
+        MarkMessageRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MarkMessageRequest`.
+#[derive(Debug, Clone)]
+pub struct MarkMessageRequestBuilder(MarkMessageRequest);
+
+impl MarkMessageRequestBuilder {
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.0.action = action.into();
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.0.timestamp = timestamp;
+        self
+    }
+
+    pub fn to_channel(mut self, to_channel: impl Into<String>) -> Self {
+        self.0.to_channel = to_channel.into();
+        self
+    }
+
+    pub fn to_contact(mut self, to_contact: impl Into<String>) -> Self {
+        self.0.to_contact = to_contact.into();
+        self
+    }
+
+    pub fn build(self) -> MarkMessageRequest {
+        self.0
+    }
+}
+
 /**
 * The action to perform with the message:
 *   * `add` — Add an emoji reaction.
@@ -13475,8 +13949,8 @@ pub enum ReactMessageRequestAction {
     Remove,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReactMessageRequestAction {
@@ -13485,7 +13959,7 @@ impl std::fmt::Display for ReactMessageRequestAction {
             ReactMessageRequestAction::Add => "add",
             ReactMessageRequestAction::Remove => "remove",
             ReactMessageRequestAction::Noop => "",
-            ReactMessageRequestAction::FallthroughString => "*",
+            ReactMessageRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13502,7 +13976,7 @@ impl ReactMessageRequestAction {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReactMessageRequest {
     /**
     * The action to perform with the message:
@@ -13540,6 +14014,44 @@ pub struct ReactMessageRequest {
     pub to_contact: String,
 }
 
+impl ReactMessageRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ReactMessageRequestBuilder {
+        ReactMessageRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ReactMessageRequest`.
+#[derive(Debug, Clone)]
+pub struct ReactMessageRequestBuilder(ReactMessageRequest);
+
+impl ReactMessageRequestBuilder {
+    pub fn action(mut self, action: impl Into<ReactMessageRequestAction>) -> Self {
+        self.0.action = Some(action.into());
+        self
+    }
+
+    pub fn emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.0.emoji = emoji.into();
+        self
+    }
+
+    pub fn to_channel(mut self, to_channel: impl Into<String>) -> Self {
+        self.0.to_channel = to_channel.into();
+        self
+    }
+
+    pub fn to_contact(mut self, to_contact: impl Into<String>) -> Self {
+        self.0.to_contact = to_contact.into();
+        self
+    }
+
+    pub fn build(self) -> ReactMessageRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Reactions {
     /**
@@ -13680,7 +14192,7 @@ pub struct GetChatMessageResponse {
     pub timestamp: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EditMessageRequest {
     /**
     * User's first name.
@@ -13711,6 +14223,39 @@ pub struct EditMessageRequest {
     pub to_contact: String,
 }
 
+impl EditMessageRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EditMessageRequestBuilder {
+        EditMessageRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EditMessageRequest`.
+#[derive(Debug, Clone)]
+pub struct EditMessageRequestBuilder(EditMessageRequest);
+
+impl EditMessageRequestBuilder {
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn to_channel(mut self, to_channel: impl Into<String>) -> Self {
+        self.0.to_channel = to_channel.into();
+        self
+    }
+
+    pub fn to_contact(mut self, to_contact: impl Into<String>) -> Self {
+        self.0.to_contact = to_contact.into();
+        self
+    }
+
+    pub fn build(self) -> EditMessageRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Channels {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -13798,7 +14343,7 @@ pub struct Members {
     pub email: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateChannelRequest {
     /**
     * Member(s) to include in the channel. A max of 5 members can be added to the channel at once with this API.
@@ -13830,6 +14375,39 @@ pub struct CreateChannelRequest {
     pub type_: i64,
 }
 
+impl CreateChannelRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateChannelRequestBuilder {
+        CreateChannelRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateChannelRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateChannelRequestBuilder(CreateChannelRequest);
+
+impl CreateChannelRequestBuilder {
+    pub fn members(mut self, members: Vec<Members>) -> Self {
+        self.0.members = members;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: i64) -> Self {
+        self.0.type_ = type_;
+        self
+    }
+
+    pub fn build(self) -> CreateChannelRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateChannelResponse {
     /**
@@ -14031,8 +14609,8 @@ pub enum Role {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -14042,7 +14620,7 @@ impl std::fmt::Display for Role {
             Role::Member => "member",
             Role::Owner => "owner",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14153,7 +14731,7 @@ pub struct ListChannelMembersResponseData {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InviteChannelMembersRequest {
     /**
     * Member(s) to include in the channel. A max of 5 members can be added to the channel at once with this API.
@@ -14166,6 +14744,29 @@ pub struct InviteChannelMembersRequest {
     pub members: Vec<Members>,
 }
 
+impl InviteChannelMembersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> InviteChannelMembersRequestBuilder {
+        InviteChannelMembersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `InviteChannelMembersRequest`.
+#[derive(Debug, Clone)]
+pub struct InviteChannelMembersRequestBuilder(InviteChannelMembersRequest);
+
+impl InviteChannelMembersRequestBuilder {
+    pub fn members(mut self, members: Vec<Members>) -> Self {
+        self.0.members = members;
+        self
+    }
+
+    pub fn build(self) -> InviteChannelMembersRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InviteChannelMembersResponse {
     /**
@@ -14214,7 +14815,7 @@ pub struct JoinChannelResponse {
 }
 
 /// The account object represents an account on Zoom. The account owner is the user who created the account or had an account created for them. You can read more about the Zoom account structure <a href='https://medium.com/zoom-developer-blog/a-brief-look-at-zoom-account-structures-1d19c745bf8a' target='_blank'>here</a>.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AccountCreateRequest {
     /**
     * The account object represents an account on Zoom. The account owner is the user who created the account or had an account created for them. You can read more about the Zoom account structure <a href='https://medium.com/zoom-developer-blog/a-brief-look-at-zoom-account-structures-1d19c745bf8a' target='_blank'>here</a>.
@@ -14268,6 +14869,54 @@ pub struct AccountCreateRequest {
     pub password: String,
 }
 
+impl AccountCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AccountCreateRequestBuilder {
+        AccountCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AccountCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct AccountCreateRequestBuilder(AccountCreateRequest);
+
+impl AccountCreateRequestBuilder {
+    pub fn account_name(mut self, account_name: impl Into<String>) -> Self {
+        self.0.account_name = account_name.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn options(mut self, options: impl Into<Options>) -> Self {
+        self.0.options = Some(options.into());
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.0.password = password.into();
+        self
+    }
+
+    pub fn build(self) -> AccountCreateRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AccountCreateResponse {
     /**
@@ -14377,8 +15026,8 @@ pub enum OptionData {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OptionData {
@@ -14387,7 +15036,7 @@ impl std::fmt::Display for OptionData {
             OptionData::MeetingAuthentication => "meeting_authentication",
             OptionData::RecordingAuthentication => "recording_authentication",
             OptionData::Noop => "",
-            OptionData::FallthroughString => "*",
+            OptionData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14506,8 +15155,8 @@ pub enum AccountSettingsUpdateOption {
     Security,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsUpdateOption {
@@ -14518,7 +15167,7 @@ impl std::fmt::Display for AccountSettingsUpdateOption {
             AccountSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             AccountSettingsUpdateOption::Security => "security",
             AccountSettingsUpdateOption::Noop => "",
-            AccountSettingsUpdateOption::FallthroughString => "*",
+            AccountSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14564,8 +15213,8 @@ pub enum AccountPlansResponsePlanBaseStatus {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
@@ -14575,7 +15224,7 @@ impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
             AccountPlansResponsePlanBaseStatus::Cancel => "cancel",
             AccountPlansResponsePlanBaseStatus::Expired => "expired",
             AccountPlansResponsePlanBaseStatus::Noop => "",
-            AccountPlansResponsePlanBaseStatus::FallthroughString => "*",
+            AccountPlansResponsePlanBaseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14696,8 +15345,8 @@ pub enum PlanRecordingStatus {
     Cancelled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlanRecordingStatus {
@@ -14706,7 +15355,7 @@ impl std::fmt::Display for PlanRecordingStatus {
             PlanRecordingStatus::Active => "Active",
             PlanRecordingStatus::Cancelled => "Cancelled",
             PlanRecordingStatus::Noop => "",
-            PlanRecordingStatus::FallthroughString => "*",
+            PlanRecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14936,7 +15585,7 @@ pub struct AccountPlansResponse {
     pub plan_zoom_rooms: Option<PlanZoomRooms>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AccountPlanCreateRequest {
     /**
     * Billing Contact object.
@@ -14945,6 +15594,29 @@ pub struct AccountPlanCreateRequest {
     pub contact: Option<Contact>,
 }
 
+impl AccountPlanCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AccountPlanCreateRequestBuilder {
+        AccountPlanCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AccountPlanCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct AccountPlanCreateRequestBuilder(AccountPlanCreateRequest);
+
+impl AccountPlanCreateRequestBuilder {
+    pub fn contact(mut self, contact: impl Into<Contact>) -> Self {
+        self.0.contact = Some(contact.into());
+        self
+    }
+
+    pub fn build(self) -> AccountPlanCreateRequest {
+        self.0
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `AccountPlans`
@@ -14962,7 +15634,7 @@ pub struct AccountPlanCreateRequestAllOf {
 }
 
 /// Zoom Phone Plan Object
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AccountPlanAddonCreateRequest {
     /**
     * Phone Plan Object
@@ -14981,6 +15653,34 @@ pub struct AccountPlanAddonCreateRequest {
     pub type_: String,
 }
 
+impl AccountPlanAddonCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AccountPlanAddonCreateRequestBuilder {
+        AccountPlanAddonCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AccountPlanAddonCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct AccountPlanAddonCreateRequestBuilder(AccountPlanAddonCreateRequest);
+
+impl AccountPlanAddonCreateRequestBuilder {
+    pub fn plan_details(mut self, plan_details: impl Into<PhonePlan>) -> Self {
+        self.0.plan_details = plan_details.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<String>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> AccountPlanAddonCreateRequest {
+        self.0
+    }
+}
+
 /// All of the following types:
 ///
 /// - `PlanBase`
@@ -15017,7 +15717,7 @@ impl AccountPlanAddonCreateRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GroupCreateRequest {
     /**
     * User's first name.
@@ -15030,6 +15730,43 @@ pub struct GroupCreateRequest {
     pub name: String,
 }
 
+impl std::convert::From<String> for GroupCreateRequest {
+    fn from(name: String) -> Self {
+        GroupCreateRequest { name }
+    }
+}
+
+impl std::convert::From<&str> for GroupCreateRequest {
+    fn from(name: &str) -> Self {
+        GroupCreateRequest {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl GroupCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GroupCreateRequestBuilder {
+        GroupCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GroupCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct GroupCreateRequestBuilder(GroupCreateRequest);
+
+impl GroupCreateRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> GroupCreateRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GroupResponse {
     /**
@@ -15166,7 +15903,7 @@ pub struct GroupMembersResponseData {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddRoleMembersRequest {
     /**
     * List of registrants.
@@ -15179,6 +15916,29 @@ pub struct AddRoleMembersRequest {
     pub members: Vec<Assistants>,
 }
 
+impl AddRoleMembersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddRoleMembersRequestBuilder {
+        AddRoleMembersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddRoleMembersRequest`.
+#[derive(Debug, Clone)]
+pub struct AddRoleMembersRequestBuilder(AddRoleMembersRequest);
+
+impl AddRoleMembersRequestBuilder {
+    pub fn members(mut self, members: Vec<Assistants>) -> Self {
+        self.0.members = members;
+        self
+    }
+
+    pub fn build(self) -> AddRoleMembersRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GroupMembersCreateResponse {
     /**
@@ -15214,8 +15974,8 @@ pub enum UpdateGroupMemberRequestAction {
     SetPrimary,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupMemberRequestAction {
@@ -15224,7 +15984,7 @@ impl std::fmt::Display for UpdateGroupMemberRequestAction {
             UpdateGroupMemberRequestAction::Move => "move",
             UpdateGroupMemberRequestAction::SetPrimary => "set_primary",
             UpdateGroupMemberRequestAction::Noop => "",
-            UpdateGroupMemberRequestAction::FallthroughString => "*",
+            UpdateGroupMemberRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15241,7 +16001,7 @@ impl UpdateGroupMemberRequestAction {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateGroupMemberRequest {
     /**
     * The action to perform:
@@ -15264,6 +16024,34 @@ pub struct UpdateGroupMemberRequest {
     pub target_group_id: String,
 }
 
+impl UpdateGroupMemberRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateGroupMemberRequestBuilder {
+        UpdateGroupMemberRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateGroupMemberRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateGroupMemberRequestBuilder(UpdateGroupMemberRequest);
+
+impl UpdateGroupMemberRequestBuilder {
+    pub fn action(mut self, action: impl Into<UpdateGroupMemberRequestAction>) -> Self {
+        self.0.action = action.into();
+        self
+    }
+
+    pub fn target_group_id(mut self, target_group_id: impl Into<String>) -> Self {
+        self.0.target_group_id = target_group_id.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateGroupMemberRequest {
+        self.0
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `Device`
@@ -15307,8 +16095,8 @@ pub enum ImGroupCreateRequestType {
     Restricted,
     #[serde(rename = "shared")]
     Shared,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImGroupCreateRequestType {
@@ -15317,7 +16105,7 @@ impl std::fmt::Display for ImGroupCreateRequestType {
             ImGroupCreateRequestType::Normal => "normal",
             ImGroupCreateRequestType::Restricted => "restricted",
             ImGroupCreateRequestType::Shared => "shared",
-            ImGroupCreateRequestType::FallthroughString => "*",
+            ImGroupCreateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15329,7 +16117,7 @@ impl Default for ImGroupCreateRequestType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ImGroupCreateRequest {
     /**
     * User's first name.
@@ -15362,6 +16150,49 @@ pub struct ImGroupCreateRequest {
     pub type_: Option<ImGroupCreateRequestType>,
 }
 
+impl ImGroupCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ImGroupCreateRequestBuilder {
+        ImGroupCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ImGroupCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct ImGroupCreateRequestBuilder(ImGroupCreateRequest);
+
+impl ImGroupCreateRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn search_by_account(mut self, search_by_account: bool) -> Self {
+        self.0.search_by_account = Some(search_by_account);
+        self
+    }
+
+    pub fn search_by_domain(mut self, search_by_domain: bool) -> Self {
+        self.0.search_by_domain = Some(search_by_domain);
+        self
+    }
+
+    pub fn search_by_ma_account(mut self, search_by_ma_account: bool) -> Self {
+        self.0.search_by_ma_account = Some(search_by_ma_account);
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<ImGroupCreateRequestType>) -> Self {
+        self.0.type_ = Some(type_.into());
+        self
+    }
+
+    pub fn build(self) -> ImGroupCreateRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ImGroupCreateResponse {
     /**
@@ -15463,8 +16294,8 @@ pub enum ImChatSessionsResponseType {
     Group,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImChatSessionsResponseType {
@@ -15473,7 +16304,7 @@ impl std::fmt::Display for ImChatSessionsResponseType {
             ImChatSessionsResponseType::One => "1:1",
             ImChatSessionsResponseType::Group => "Group",
             ImChatSessionsResponseType::Noop => "",
-            ImChatSessionsResponseType::FallthroughString => "*",
+            ImChatSessionsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15681,8 +16512,8 @@ pub enum MeetingsType {
     Scheduled,
     #[serde(rename = "upcoming")]
     Upcoming,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingsType {
@@ -15691,7 +16522,7 @@ impl std::fmt::Display for MeetingsType {
             MeetingsType::Live => "live",
             MeetingsType::Scheduled => "scheduled",
             MeetingsType::Upcoming => "upcoming",
-            MeetingsType::FallthroughString => "*",
+            MeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15824,7 +16655,7 @@ pub struct MeetingResponseAllOf {
     pub meeting_response: MeetingResponse,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingUpdateRequest {
     /**
     * User's first name.
@@ -15837,6 +16668,43 @@ pub struct MeetingUpdateRequest {
     pub schedule_for: String,
 }
 
+impl std::convert::From<String> for MeetingUpdateRequest {
+    fn from(schedule_for: String) -> Self {
+        MeetingUpdateRequest { schedule_for }
+    }
+}
+
+impl std::convert::From<&str> for MeetingUpdateRequest {
+    fn from(schedule_for: &str) -> Self {
+        MeetingUpdateRequest {
+            schedule_for: schedule_for.to_string(),
+        }
+    }
+}
+
+impl MeetingUpdateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MeetingUpdateRequestBuilder {
+        MeetingUpdateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MeetingUpdateRequest`.
+#[derive(Debug, Clone)]
+pub struct MeetingUpdateRequestBuilder(MeetingUpdateRequest);
+
+impl MeetingUpdateRequestBuilder {
+    pub fn schedule_for(mut self, schedule_for: impl Into<String>) -> Self {
+        self.0.schedule_for = schedule_for.into();
+        self
+    }
+
+    pub fn build(self) -> MeetingUpdateRequest {
+        self.0
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `Domains`
@@ -15863,8 +16731,8 @@ pub enum MeetingStatusRequestAction {
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingStatusRequestAction {
@@ -15873,7 +16741,7 @@ impl std::fmt::Display for MeetingStatusRequestAction {
             MeetingStatusRequestAction::End => "end",
             MeetingStatusRequestAction::Recover => "recover",
             MeetingStatusRequestAction::Noop => "",
-            MeetingStatusRequestAction::FallthroughString => "*",
+            MeetingStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15890,7 +16758,7 @@ impl MeetingStatusRequestAction {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingStatusRequest {
     /**
     * `end` - End a meeting.<br>
@@ -15901,6 +16769,29 @@ pub struct MeetingStatusRequest {
     pub action: Option<MeetingStatusRequestAction>,
 }
 
+impl MeetingStatusRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> MeetingStatusRequestBuilder {
+        MeetingStatusRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `MeetingStatusRequest`.
+#[derive(Debug, Clone)]
+pub struct MeetingStatusRequestBuilder(MeetingStatusRequest);
+
+impl MeetingStatusRequestBuilder {
+    pub fn action(mut self, action: impl Into<MeetingStatusRequestAction>) -> Self {
+        self.0.action = Some(action.into());
+        self
+    }
+
+    pub fn build(self) -> MeetingStatusRequest {
+        self.0
+    }
+}
+
 /**
 * The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
 */
@@ -15912,8 +16803,8 @@ pub enum MeetingRegistrantsStatus {
     Denied,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantsStatus {
@@ -15922,7 +16813,7 @@ impl std::fmt::Display for MeetingRegistrantsStatus {
             MeetingRegistrantsStatus::Approved => "approved",
             MeetingRegistrantsStatus::Denied => "denied",
             MeetingRegistrantsStatus::Pending => "pending",
-            MeetingRegistrantsStatus::FallthroughString => "*",
+            MeetingRegistrantsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16171,8 +17062,8 @@ pub enum MeetingPollCreateResponseStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingPollCreateResponseStatus {
@@ -16183,7 +17074,7 @@ impl std::fmt::Display for MeetingPollCreateResponseStatus {
             MeetingPollCreateResponseStatus::Sharing => "sharing",
             MeetingPollCreateResponseStatus::Started => "started",
             MeetingPollCreateResponseStatus::Noop => "",
-            MeetingPollCreateResponseStatus::FallthroughString => "*",
+            MeetingPollCreateResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16278,8 +17169,8 @@ pub enum RecordingDeleteAction {
     Delete,
     #[serde(rename = "trash")]
     Trash,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingDeleteAction {
@@ -16287,7 +17178,7 @@ impl std::fmt::Display for RecordingDeleteAction {
         match &*self {
             RecordingDeleteAction::Delete => "delete",
             RecordingDeleteAction::Trash => "trash",
-            RecordingDeleteAction::FallthroughString => "*",
+            RecordingDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16352,8 +17243,8 @@ pub enum DashboardMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingsType {
@@ -16362,7 +17253,7 @@ impl std::fmt::Display for DashboardMeetingsType {
             DashboardMeetingsType::Live => "live",
             DashboardMeetingsType::Past => "past",
             DashboardMeetingsType::PastOne => "pastOne",
-            DashboardMeetingsType::FallthroughString => "*",
+            DashboardMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16383,8 +17274,8 @@ pub enum IncludeFields {
     TrackingFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IncludeFields {
@@ -16392,7 +17283,7 @@ impl std::fmt::Display for IncludeFields {
         match &*self {
             IncludeFields::TrackingFields => "tracking_fields",
             IncludeFields::Noop => "",
-            IncludeFields::FallthroughString => "*",
+            IncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16450,8 +17341,8 @@ pub enum DashboardMeetingParticipantsIncludeFields {
     RegistrantId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
@@ -16459,7 +17350,7 @@ impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
         match &*self {
             DashboardMeetingParticipantsIncludeFields::RegistrantId => "registrant_id",
             DashboardMeetingParticipantsIncludeFields::Noop => "",
-            DashboardMeetingParticipantsIncludeFields::FallthroughString => "*",
+            DashboardMeetingParticipantsIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16503,8 +17394,8 @@ pub enum DashboardMeetingParticipantsResponseDevice {
     IOs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
@@ -16517,7 +17408,7 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
             DashboardMeetingParticipantsResponseDevice::Windows => "Windows",
             DashboardMeetingParticipantsResponseDevice::IOs => "iOS",
             DashboardMeetingParticipantsResponseDevice::Noop => "",
-            DashboardMeetingParticipantsResponseDevice::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseDevice::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16557,8 +17448,8 @@ pub enum NetworkType {
     Wired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkType {
@@ -16570,7 +17461,7 @@ impl std::fmt::Display for NetworkType {
             NetworkType::Wifi => "Wifi",
             NetworkType::Wired => "Wired",
             NetworkType::Noop => "",
-            NetworkType::FallthroughString => "*",
+            NetworkType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16602,8 +17493,8 @@ pub enum AudioQuality {
     Poor,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioQuality {
@@ -16614,7 +17505,7 @@ impl std::fmt::Display for AudioQuality {
             AudioQuality::Good => "good",
             AudioQuality::Poor => "poor",
             AudioQuality::Noop => "",
-            AudioQuality::FallthroughString => "*",
+            AudioQuality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16644,8 +17535,8 @@ pub enum DashboardMeetingParticipantsResponseStatus {
     InWaitingRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
@@ -16654,7 +17545,7 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
             DashboardMeetingParticipantsResponseStatus::InMeeting => "in_meeting",
             DashboardMeetingParticipantsResponseStatus::InWaitingRoom => "in_waiting_room",
             DashboardMeetingParticipantsResponseStatus::Noop => "",
-            DashboardMeetingParticipantsResponseStatus::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17091,8 +17982,8 @@ pub enum DashboardWebinarsType {
     Live,
     #[serde(rename = "past")]
     Past,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardWebinarsType {
@@ -17100,7 +17991,7 @@ impl std::fmt::Display for DashboardWebinarsType {
         match &*self {
             DashboardWebinarsType::Live => "live",
             DashboardWebinarsType::Past => "past",
-            DashboardWebinarsType::FallthroughString => "*",
+            DashboardWebinarsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18087,8 +18978,8 @@ pub enum ReportUsersType {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportUsersType {
@@ -18097,7 +18988,7 @@ impl std::fmt::Display for ReportUsersType {
             ReportUsersType::Active => "active",
             ReportUsersType::Inactive => "inactive",
             ReportUsersType::Noop => "",
-            ReportUsersType::FallthroughString => "*",
+            ReportUsersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18123,8 +19014,8 @@ pub enum ReportMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportMeetingsType {
@@ -18132,7 +19023,7 @@ impl std::fmt::Display for ReportMeetingsType {
         match &*self {
             ReportMeetingsType::Past => "past",
             ReportMeetingsType::PastOne => "pastOne",
-            ReportMeetingsType::FallthroughString => "*",
+            ReportMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18905,8 +19796,8 @@ pub enum QueryDateType {
     EndTime,
     #[serde(rename = "start_time")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QueryDateType {
@@ -18914,7 +19805,7 @@ impl std::fmt::Display for QueryDateType {
         match &*self {
             QueryDateType::EndTime => "end_time",
             QueryDateType::StartTime => "start_time",
-            QueryDateType::FallthroughString => "*",
+            QueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18988,8 +19879,8 @@ pub enum CategoryType {
     ZoomRooms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CategoryType {
@@ -19008,7 +19899,7 @@ impl std::fmt::Display for CategoryType {
             CategoryType::Webinar => "webinar",
             CategoryType::ZoomRooms => "zoom_rooms",
             CategoryType::Noop => "",
-            CategoryType::FallthroughString => "*",
+            CategoryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19103,7 +19994,7 @@ pub struct ReportOperationLogsResponseAllOf {
     pub report_operation_logs_response: ReportOperationLogsResponse,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateRoleRequest {
     /**
     * User's first name.
@@ -19137,6 +20028,39 @@ pub struct CreateRoleRequest {
     pub privileges: Vec<String>,
 }
 
+impl CreateRoleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateRoleRequestBuilder {
+        CreateRoleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateRoleRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateRoleRequestBuilder(CreateRoleRequest);
+
+impl CreateRoleRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn privileges(mut self, privileges: Vec<String>) -> Self {
+        self.0.privileges = privileges;
+        self
+    }
+
+    pub fn build(self) -> CreateRoleRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateRoleResponse {
     /**
@@ -19307,7 +20231,7 @@ pub struct TspResponse {
     pub tsp_provider: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TspUpdateRequest {
     /**
     * Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
@@ -19350,6 +20274,62 @@ pub struct TspUpdateRequest {
     pub tsp_provider: String,
 }
 
+impl TspUpdateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> TspUpdateRequestBuilder {
+        TspUpdateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `TspUpdateRequest`.
+#[derive(Debug, Clone)]
+pub struct TspUpdateRequestBuilder(TspUpdateRequest);
+
+impl TspUpdateRequestBuilder {
+    pub fn dial_in_number_unrestricted(mut self, dial_in_number_unrestricted: bool) -> Self {
+        self.0.dial_in_number_unrestricted = Some(dial_in_number_unrestricted);
+        self
+    }
+
+    pub fn enable(mut self, enable: bool) -> Self {
+        self.0.enable = Some(enable);
+        self
+    }
+
+    pub fn master_account_setting_extended(
+        mut self,
+        master_account_setting_extended: bool,
+    ) -> Self {
+        self.0.master_account_setting_extended = Some(master_account_setting_extended);
+        self
+    }
+
+    pub fn modify_credential_forbidden(mut self, modify_credential_forbidden: bool) -> Self {
+        self.0.modify_credential_forbidden = Some(modify_credential_forbidden);
+        self
+    }
+
+    pub fn tsp_bridge(mut self, tsp_bridge: impl Into<TspBridge>) -> Self {
+        self.0.tsp_bridge = Some(tsp_bridge.into());
+        self
+    }
+
+    pub fn tsp_enabled(mut self, tsp_enabled: bool) -> Self {
+        self.0.tsp_enabled = Some(tsp_enabled);
+        self
+    }
+
+    pub fn tsp_provider(mut self, tsp_provider: impl Into<String>) -> Self {
+        self.0.tsp_provider = tsp_provider.into();
+        self
+    }
+
+    pub fn build(self) -> TspUpdateRequest {
+        self.0
+    }
+}
+
 /**
 * The user's status:
 *   * `active` — An active user.
@@ -19366,8 +20346,8 @@ pub enum UsersStatus {
     Inactive,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersStatus {
@@ -19376,7 +20356,7 @@ impl std::fmt::Display for UsersStatus {
             UsersStatus::Active => "active",
             UsersStatus::Inactive => "inactive",
             UsersStatus::Pending => "pending",
-            UsersStatus::FallthroughString => "*",
+            UsersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19401,8 +20381,8 @@ pub enum UsersIncludeFields {
     HostKey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersIncludeFields {
@@ -19411,7 +20391,7 @@ impl std::fmt::Display for UsersIncludeFields {
             UsersIncludeFields::CustomAttributes => "custom_attributes",
             UsersIncludeFields::HostKey => "host_key",
             UsersIncludeFields::Noop => "",
-            UsersIncludeFields::FallthroughString => "*",
+            UsersIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19717,8 +20697,8 @@ pub enum UserCreateRequestAction {
     SsoCreate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserCreateRequestAction {
@@ -19729,7 +20709,7 @@ impl std::fmt::Display for UserCreateRequestAction {
             UserCreateRequestAction::CustCreate => "custCreate",
             UserCreateRequestAction::SsoCreate => "ssoCreate",
             UserCreateRequestAction::Noop => "",
-            UserCreateRequestAction::FallthroughString => "*",
+            UserCreateRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19796,7 +20776,7 @@ pub struct UserInfo {
     pub type_: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserCreateRequest {
     /**
     * Specify how to create the new user: <br>`create` - User will get an email sent from Zoom. There is a confirmation link in this email. The user will then need to use the link to activate their Zoom account. The user can then set or change their password.<br>`autoCreate` - This action is provided for the enterprise customer who has a managed domain. This feature is disabled by default because of the security risk involved in creating a user who does not belong to your domain.<br>`custCreate` - Users created via this option do not have passwords and will not have the ability to log into the Zoom Web Portal or the Zoom Client. These users can still host and join meetings using the `start_url` and `join_url` respectively. To use this option, you must contact the ISV Platform Sales team at isv@zoom.us.<br>`ssoCreate` - This action is provided for the enabled “Pre-provisioning SSO User” option. A user created in this way has no password. If not a basic user, a personal vanity URL using the user name (no domain) of the provisioning email will be generated. If the user name or PMI is invalid or occupied, it will use a random number or random personal vanity URL.
@@ -19807,6 +20787,34 @@ pub struct UserCreateRequest {
     pub user_info: Option<UserInfo>,
 }
 
+impl UserCreateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UserCreateRequestBuilder {
+        UserCreateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UserCreateRequest`.
+#[derive(Debug, Clone)]
+pub struct UserCreateRequestBuilder(UserCreateRequest);
+
+impl UserCreateRequestBuilder {
+    pub fn action(mut self, action: impl Into<UserCreateRequestAction>) -> Self {
+        self.0.action = action.into();
+        self
+    }
+
+    pub fn user_info(mut self, user_info: impl Into<UserInfo>) -> Self {
+        self.0.user_info = Some(user_info.into());
+        self
+    }
+
+    pub fn build(self) -> UserCreateRequest {
+        self.0
+    }
+}
+
 /**
 * The user's login method:
 *   
@@ -19844,8 +20852,8 @@ pub enum LoginType {
     NinetyNine,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LoginType {
@@ -19864,7 +20872,7 @@ impl std::fmt::Display for LoginType {
             LoginType::NinetyEight => "98",
             LoginType::NinetyNine => "99",
             LoginType::Noop => "",
-            LoginType::FallthroughString => "*",
+            LoginType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20214,8 +21222,8 @@ pub enum UserDeleteAction {
     Delete,
     #[serde(rename = "disassociate")]
     Disassociate,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserDeleteAction {
@@ -20223,7 +21231,7 @@ impl std::fmt::Display for UserDeleteAction {
         match &*self {
             UserDeleteAction::Delete => "delete",
             UserDeleteAction::Disassociate => "disassociate",
-            UserDeleteAction::FallthroughString => "*",
+            UserDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20336,8 +21344,8 @@ pub enum UserSettingsUpdateOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSettingsUpdateOption {
@@ -20347,7 +21355,7 @@ impl std::fmt::Display for UserSettingsUpdateOption {
             UserSettingsUpdateOption::MeetingSecuirty => "meeting_secuirty",
             UserSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             UserSettingsUpdateOption::Noop => "",
-            UserSettingsUpdateOption::FallthroughString => "*",
+            UserSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20375,8 +21383,8 @@ pub enum UserStatusRequestAction {
     Deactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatusRequestAction {
@@ -20385,7 +21393,7 @@ impl std::fmt::Display for UserStatusRequestAction {
             UserStatusRequestAction::Activate => "activate",
             UserStatusRequestAction::Deactivate => "deactivate",
             UserStatusRequestAction::Noop => "",
-            UserStatusRequestAction::FallthroughString => "*",
+            UserStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20403,7 +21411,7 @@ impl UserStatusRequestAction {
 }
 
 /// The action.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserStatusRequest {
     /**
     * The action types:<br>`activate` - Activate a deactivated user.<br>`deactivate` - Deactivate a user.
@@ -20412,7 +21420,30 @@ pub struct UserStatusRequest {
     pub action: UserStatusRequestAction,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl UserStatusRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UserStatusRequestBuilder {
+        UserStatusRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UserStatusRequest`.
+#[derive(Debug, Clone)]
+pub struct UserStatusRequestBuilder(UserStatusRequest);
+
+impl UserStatusRequestBuilder {
+    pub fn action(mut self, action: impl Into<UserStatusRequestAction>) -> Self {
+        self.0.action = action.into();
+        self
+    }
+
+    pub fn build(self) -> UserStatusRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserPasswordRequest {
     /**
     * User's first name.
@@ -20425,6 +21456,43 @@ pub struct UserPasswordRequest {
     pub password: String,
 }
 
+impl std::convert::From<String> for UserPasswordRequest {
+    fn from(password: String) -> Self {
+        UserPasswordRequest { password }
+    }
+}
+
+impl std::convert::From<&str> for UserPasswordRequest {
+    fn from(password: &str) -> Self {
+        UserPasswordRequest {
+            password: password.to_string(),
+        }
+    }
+}
+
+impl UserPasswordRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UserPasswordRequestBuilder {
+        UserPasswordRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UserPasswordRequest`.
+#[derive(Debug, Clone)]
+pub struct UserPasswordRequestBuilder(UserPasswordRequest);
+
+impl UserPasswordRequestBuilder {
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.0.password = password.into();
+        self
+    }
+
+    pub fn build(self) -> UserPasswordRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PacAccounts {
     /**
@@ -20634,8 +21702,8 @@ pub enum UserTokenType {
     Zak,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserTokenType {
@@ -20644,7 +21712,7 @@ impl std::fmt::Display for UserTokenType {
             UserTokenType::Token => "token",
             UserTokenType::Zak => "zak",
             UserTokenType::Noop => "",
-            UserTokenType::FallthroughString => "*",
+            UserTokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20869,8 +21937,8 @@ pub enum WebinarStatusRequestAction {
     End,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebinarStatusRequestAction {
@@ -20878,7 +21946,7 @@ impl std::fmt::Display for WebinarStatusRequestAction {
         match &*self {
             WebinarStatusRequestAction::End => "end",
             WebinarStatusRequestAction::Noop => "",
-            WebinarStatusRequestAction::FallthroughString => "*",
+            WebinarStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20895,12 +21963,35 @@ impl WebinarStatusRequestAction {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebinarStatusRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<WebinarStatusRequestAction>,
 }
 
+impl WebinarStatusRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> WebinarStatusRequestBuilder {
+        WebinarStatusRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `WebinarStatusRequest`.
+#[derive(Debug, Clone)]
+pub struct WebinarStatusRequestBuilder(WebinarStatusRequest);
+
+impl WebinarStatusRequestBuilder {
+    pub fn action(mut self, action: impl Into<WebinarStatusRequestAction>) -> Self {
+        self.0.action = Some(action.into());
+        self
+    }
+
+    pub fn build(self) -> WebinarStatusRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebinarPanelistCreateResponse {
     /**
@@ -20972,7 +22063,7 @@ pub struct WebinarRegistrantCreateResponse {
     pub topic: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBatchWebinarRegistrantsRequest {
     /**
     * User's first name.
@@ -21003,7 +22094,40 @@ pub struct AddBatchWebinarRegistrantsRequest {
     pub last_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl AddBatchWebinarRegistrantsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddBatchWebinarRegistrantsRequestBuilder {
+        AddBatchWebinarRegistrantsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddBatchWebinarRegistrantsRequest`.
+#[derive(Debug, Clone)]
+pub struct AddBatchWebinarRegistrantsRequestBuilder(AddBatchWebinarRegistrantsRequest);
+
+impl AddBatchWebinarRegistrantsRequestBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.0.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.0.last_name = last_name.into();
+        self
+    }
+
+    pub fn build(self) -> AddBatchWebinarRegistrantsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBatchRegistrantsRequest {
     /**
     * Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
@@ -21018,6 +22142,34 @@ pub struct AddBatchRegistrantsRequest {
     pub registrants: Vec<AddBatchWebinarRegistrantsRequest>,
 }
 
+impl AddBatchRegistrantsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddBatchRegistrantsRequestBuilder {
+        AddBatchRegistrantsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddBatchRegistrantsRequest`.
+#[derive(Debug, Clone)]
+pub struct AddBatchRegistrantsRequestBuilder(AddBatchRegistrantsRequest);
+
+impl AddBatchRegistrantsRequestBuilder {
+    pub fn auto_approve(mut self, auto_approve: bool) -> Self {
+        self.0.auto_approve = Some(auto_approve);
+        self
+    }
+
+    pub fn registrants(mut self, registrants: Vec<AddBatchWebinarRegistrantsRequest>) -> Self {
+        self.0.registrants = registrants;
+        self
+    }
+
+    pub fn build(self) -> AddBatchRegistrantsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBatchWebinarRegistrantsResponse {
     /**
@@ -21059,7 +22211,7 @@ pub struct AddBatchRegistrantsResponse {
     pub registrants: Vec<AddBatchWebinarRegistrantsResponse>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendchatbotRequest {
     /**
     * User's first name.
@@ -21115,7 +22267,60 @@ pub struct SendchatbotRequest {
     pub visible_to_user: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl SendchatbotRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SendchatbotRequestBuilder {
+        SendchatbotRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SendchatbotRequest`.
+#[derive(Debug, Clone)]
+pub struct SendchatbotRequestBuilder(SendchatbotRequest);
+
+impl SendchatbotRequestBuilder {
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.0.account_id = account_id.into();
+        self
+    }
+
+    pub fn content(mut self, content: impl Into<Domains>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+
+    pub fn is_markdown_support(mut self, is_markdown_support: bool) -> Self {
+        self.0.is_markdown_support = Some(is_markdown_support);
+        self
+    }
+
+    pub fn robot_jid(mut self, robot_jid: impl Into<String>) -> Self {
+        self.0.robot_jid = robot_jid.into();
+        self
+    }
+
+    pub fn to_jid(mut self, to_jid: impl Into<String>) -> Self {
+        self.0.to_jid = to_jid.into();
+        self
+    }
+
+    pub fn user_jid(mut self, user_jid: impl Into<String>) -> Self {
+        self.0.user_jid = user_jid.into();
+        self
+    }
+
+    pub fn visible_to_user(mut self, visible_to_user: impl Into<String>) -> Self {
+        self.0.visible_to_user = visible_to_user.into();
+        self
+    }
+
+    pub fn build(self) -> SendchatbotRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EditChatbotMessageRequest {
     /**
     * User's first name.
@@ -21153,6 +22358,49 @@ pub struct EditChatbotMessageRequest {
     pub user_jid: String,
 }
 
+impl EditChatbotMessageRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> EditChatbotMessageRequestBuilder {
+        EditChatbotMessageRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `EditChatbotMessageRequest`.
+#[derive(Debug, Clone)]
+pub struct EditChatbotMessageRequestBuilder(EditChatbotMessageRequest);
+
+impl EditChatbotMessageRequestBuilder {
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.0.account_id = account_id.into();
+        self
+    }
+
+    pub fn content(mut self, content: impl Into<Domains>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+
+    pub fn is_markdown_support(mut self, is_markdown_support: bool) -> Self {
+        self.0.is_markdown_support = Some(is_markdown_support);
+        self
+    }
+
+    pub fn robot_jid(mut self, robot_jid: impl Into<String>) -> Self {
+        self.0.robot_jid = robot_jid.into();
+        self
+    }
+
+    pub fn user_jid(mut self, user_jid: impl Into<String>) -> Self {
+        self.0.user_jid = user_jid.into();
+        self
+    }
+
+    pub fn build(self) -> EditChatbotMessageRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EditChatbotMessageResponse {
     /**
@@ -21202,7 +22450,7 @@ pub struct EditChatbotMessageResponse {
     pub user_jid: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteChatbotMessageRequest {
     /**
     * User's first name.
@@ -21233,6 +22481,39 @@ pub struct DeleteChatbotMessageRequest {
     pub user_jid: String,
 }
 
+impl DeleteChatbotMessageRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> DeleteChatbotMessageRequestBuilder {
+        DeleteChatbotMessageRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `DeleteChatbotMessageRequest`.
+#[derive(Debug, Clone)]
+pub struct DeleteChatbotMessageRequestBuilder(DeleteChatbotMessageRequest);
+
+impl DeleteChatbotMessageRequestBuilder {
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.0.account_id = account_id.into();
+        self
+    }
+
+    pub fn robot_jid(mut self, robot_jid: impl Into<String>) -> Self {
+        self.0.robot_jid = robot_jid.into();
+        self
+    }
+
+    pub fn user_jid(mut self, user_jid: impl Into<String>) -> Self {
+        self.0.user_jid = user_jid.into();
+        self
+    }
+
+    pub fn build(self) -> DeleteChatbotMessageRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteChatbotMessageResponse {
     /**
@@ -21471,7 +22752,7 @@ pub struct ListimmessagesResponse {
     pub page_size: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendimmessagesRequest {
     /**
     * User's first name.
@@ -21484,6 +22765,43 @@ pub struct SendimmessagesRequest {
     pub message: String,
 }
 
+impl std::convert::From<String> for SendimmessagesRequest {
+    fn from(message: String) -> Self {
+        SendimmessagesRequest { message }
+    }
+}
+
+impl std::convert::From<&str> for SendimmessagesRequest {
+    fn from(message: &str) -> Self {
+        SendimmessagesRequest {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl SendimmessagesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SendimmessagesRequestBuilder {
+        SendimmessagesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SendimmessagesRequest`.
+#[derive(Debug, Clone)]
+pub struct SendimmessagesRequestBuilder(SendimmessagesRequest);
+
+impl SendimmessagesRequestBuilder {
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.0.message = message.into();
+        self
+    }
+
+    pub fn build(self) -> SendimmessagesRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CallingPlan {
     /**
@@ -21632,7 +22950,7 @@ pub struct UpdateUserProfileRequestPolicy {
     pub international_calling: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateUserProfileRequest {
     /**
     * User's first name.
@@ -21659,6 +22977,39 @@ pub struct UpdateUserProfileRequest {
     pub site_id: String,
 }
 
+impl UpdateUserProfileRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateUserProfileRequestBuilder {
+        UpdateUserProfileRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateUserProfileRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateUserProfileRequestBuilder(UpdateUserProfileRequest);
+
+impl UpdateUserProfileRequestBuilder {
+    pub fn extension_number(mut self, extension_number: impl Into<String>) -> Self {
+        self.0.extension_number = extension_number.into();
+        self
+    }
+
+    pub fn policy(mut self, policy: impl Into<UpdateUserProfileRequestPolicy>) -> Self {
+        self.0.policy = Some(policy.into());
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateUserProfileRequest {
+        self.0
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PhoneSettingResponseCountry {
@@ -21779,7 +23130,7 @@ pub struct UpdatePhoneSettingsRequestByoc {
     pub enable: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdatePhoneSettingsRequest {
     /**
     * Only [master account owners](https://marketplace.zoom.us/docs/api-reference/master-account-apis) can use this MA API to enable BYOC(Bring your own carrier) option for a sub account.<br>
@@ -21796,6 +23147,37 @@ pub struct UpdatePhoneSettingsRequest {
     pub show_device_ip_for_call_log: Option<ShowDeviceIpCallLog>,
 }
 
+impl UpdatePhoneSettingsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdatePhoneSettingsRequestBuilder {
+        UpdatePhoneSettingsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdatePhoneSettingsRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdatePhoneSettingsRequestBuilder(UpdatePhoneSettingsRequest);
+
+impl UpdatePhoneSettingsRequestBuilder {
+    pub fn byoc(mut self, byoc: impl Into<UpdatePhoneSettingsRequestByoc>) -> Self {
+        self.0.byoc = Some(byoc.into());
+        self
+    }
+
+    pub fn show_device_ip_for_call_log(
+        mut self,
+        show_device_ip_for_call_log: impl Into<ShowDeviceIpCallLog>,
+    ) -> Self {
+        self.0.show_device_ip_for_call_log = Some(show_device_ip_for_call_log.into());
+        self
+    }
+
+    pub fn build(self) -> UpdatePhoneSettingsRequest {
+        self.0
+    }
+}
+
 /// Outbound Caller Info
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OutboundCaller {
@@ -22017,8 +23399,8 @@ pub enum ListSettingTemplatesResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListSettingTemplatesResponseType {
@@ -22031,7 +23413,7 @@ impl std::fmt::Display for ListSettingTemplatesResponseType {
             ListSettingTemplatesResponseType::User => "user",
             ListSettingTemplatesResponseType::Zr => "zr",
             ListSettingTemplatesResponseType::Noop => "",
-            ListSettingTemplatesResponseType::FallthroughString => "*",
+            ListSettingTemplatesResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22145,8 +23527,8 @@ pub enum AddSettingTemplateRequestType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddSettingTemplateRequestType {
@@ -22158,7 +23540,7 @@ impl std::fmt::Display for AddSettingTemplateRequestType {
             AddSettingTemplateRequestType::Interop => "interop",
             AddSettingTemplateRequestType::User => "user",
             AddSettingTemplateRequestType::Noop => "",
-            AddSettingTemplateRequestType::FallthroughString => "*",
+            AddSettingTemplateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22175,7 +23557,7 @@ impl AddSettingTemplateRequestType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSettingTemplateRequest {
     /**
     * User's first name.
@@ -22215,6 +23597,44 @@ pub struct AddSettingTemplateRequest {
     pub type_: AddSettingTemplateRequestType,
 }
 
+impl AddSettingTemplateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddSettingTemplateRequestBuilder {
+        AddSettingTemplateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddSettingTemplateRequest`.
+#[derive(Debug, Clone)]
+pub struct AddSettingTemplateRequestBuilder(AddSettingTemplateRequest);
+
+impl AddSettingTemplateRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<AddSettingTemplateRequestType>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> AddSettingTemplateRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSettingTemplateResponse {
     /**
@@ -22458,7 +23878,7 @@ pub struct Locations {
     pub sip_group_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchAddLocationsRequest {
     #[serde(
         default,
@@ -22477,6 +23897,34 @@ pub struct BatchAddLocationsRequest {
     pub site_id: String,
 }
 
+impl BatchAddLocationsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> BatchAddLocationsRequestBuilder {
+        BatchAddLocationsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `BatchAddLocationsRequest`.
+#[derive(Debug, Clone)]
+pub struct BatchAddLocationsRequestBuilder(BatchAddLocationsRequest);
+
+impl BatchAddLocationsRequestBuilder {
+    pub fn locations(mut self, locations: Vec<Locations>) -> Self {
+        self.0.locations = locations;
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> BatchAddLocationsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchAddLocationsResponse {
     /**
@@ -22687,7 +24135,7 @@ pub struct ListLocationsResponseData {
     pub page_size: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddLocationRequest {
     /**
     * User's first name.
@@ -22772,6 +24220,69 @@ pub struct AddLocationRequest {
     pub site_id: String,
 }
 
+impl AddLocationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddLocationRequestBuilder {
+        AddLocationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddLocationRequest`.
+#[derive(Debug, Clone)]
+pub struct AddLocationRequestBuilder(AddLocationRequest);
+
+impl AddLocationRequestBuilder {
+    pub fn bssid(mut self, bssid: impl Into<String>) -> Self {
+        self.0.bssid = bssid.into();
+        self
+    }
+
+    pub fn elin_phone_number_id(mut self, elin_phone_number_id: impl Into<String>) -> Self {
+        self.0.elin_phone_number_id = elin_phone_number_id.into();
+        self
+    }
+
+    pub fn emergency_address_id(mut self, emergency_address_id: impl Into<String>) -> Self {
+        self.0.emergency_address_id = emergency_address_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn parent_location_id(mut self, parent_location_id: impl Into<String>) -> Self {
+        self.0.parent_location_id = parent_location_id.into();
+        self
+    }
+
+    pub fn private_ip(mut self, private_ip: impl Into<String>) -> Self {
+        self.0.private_ip = private_ip.into();
+        self
+    }
+
+    pub fn public_ip(mut self, public_ip: impl Into<String>) -> Self {
+        self.0.public_ip = public_ip.into();
+        self
+    }
+
+    pub fn sip_group_id(mut self, sip_group_id: impl Into<String>) -> Self {
+        self.0.sip_group_id = sip_group_id.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> AddLocationRequest {
+        self.0
+    }
+}
+
 /// The ELIN (Emergency Location Identification Number).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetLocationResponseElin {
@@ -22995,7 +24506,7 @@ pub struct GetLocationResponse {
     pub site: Option<GetLocationResponseSite>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateLocationRequest {
     /**
     * User's first name.
@@ -23068,6 +24579,64 @@ pub struct UpdateLocationRequest {
     pub sip_group_id: String,
 }
 
+impl UpdateLocationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateLocationRequestBuilder {
+        UpdateLocationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateLocationRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateLocationRequestBuilder(UpdateLocationRequest);
+
+impl UpdateLocationRequestBuilder {
+    pub fn bssid(mut self, bssid: impl Into<String>) -> Self {
+        self.0.bssid = bssid.into();
+        self
+    }
+
+    pub fn elin_phone_number_id(mut self, elin_phone_number_id: impl Into<String>) -> Self {
+        self.0.elin_phone_number_id = elin_phone_number_id.into();
+        self
+    }
+
+    pub fn emergency_address_id(mut self, emergency_address_id: impl Into<String>) -> Self {
+        self.0.emergency_address_id = emergency_address_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn network_switches(mut self, network_switches: Vec<NetworkSwitches>) -> Self {
+        self.0.network_switches = network_switches;
+        self
+    }
+
+    pub fn private_ip(mut self, private_ip: impl Into<String>) -> Self {
+        self.0.private_ip = private_ip.into();
+        self
+    }
+
+    pub fn public_ip(mut self, public_ip: impl Into<String>) -> Self {
+        self.0.public_ip = public_ip.into();
+        self
+    }
+
+    pub fn sip_group_id(mut self, sip_group_id: impl Into<String>) -> Self {
+        self.0.sip_group_id = sip_group_id.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateLocationRequest {
+        self.0
+    }
+}
+
 /// The SIP trunk group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SipTrunk {
@@ -23185,8 +24754,8 @@ pub enum GetSettingTemplateResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetSettingTemplateResponseType {
@@ -23199,7 +24768,7 @@ impl std::fmt::Display for GetSettingTemplateResponseType {
             GetSettingTemplateResponseType::User => "user",
             GetSettingTemplateResponseType::Zr => "zr",
             GetSettingTemplateResponseType::Noop => "",
-            GetSettingTemplateResponseType::FallthroughString => "*",
+            GetSettingTemplateResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23415,8 +24984,8 @@ pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectO
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display
@@ -23431,7 +25000,7 @@ GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorTy
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop => "",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::FallthroughString => "*",
+GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s) => s.as_str(),
 }
 .fmt(f)
     }
@@ -23585,8 +25154,8 @@ pub enum HoldMusic {
     Disable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HoldMusic {
@@ -23595,7 +25164,7 @@ impl std::fmt::Display for HoldMusic {
             HoldMusic::Default => "default",
             HoldMusic::Disable => "disable",
             HoldMusic::Noop => "",
-            HoldMusic::FallthroughString => "*",
+            HoldMusic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23701,8 +25270,8 @@ pub enum RecordingCalls {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingCalls {
@@ -23712,7 +25281,7 @@ impl std::fmt::Display for RecordingCalls {
             RecordingCalls::Inbound => "inbound",
             RecordingCalls::Outbound => "outbound",
             RecordingCalls::Noop => "",
-            RecordingCalls::FallthroughString => "*",
+            RecordingCalls::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23774,7 +25343,7 @@ pub struct UpdateSettingTemplateRequestPolicy {
     pub voicemail: Option<Voicemail>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSettingTemplateRequest {
     /**
     * User's first name.
@@ -23802,6 +25371,52 @@ pub struct UpdateSettingTemplateRequest {
     pub user_settings: Option<GetSettingTemplateResponseUserSettings>,
 }
 
+impl UpdateSettingTemplateRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateSettingTemplateRequestBuilder {
+        UpdateSettingTemplateRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateSettingTemplateRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateSettingTemplateRequestBuilder(UpdateSettingTemplateRequest);
+
+impl UpdateSettingTemplateRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn policy(mut self, policy: impl Into<UpdateSettingTemplateRequestPolicy>) -> Self {
+        self.0.policy = Some(policy.into());
+        self
+    }
+
+    pub fn profile(mut self, profile: impl Into<GetSettingTemplateResponseProfile>) -> Self {
+        self.0.profile = Some(profile.into());
+        self
+    }
+
+    pub fn user_settings(
+        mut self,
+        user_settings: impl Into<GetSettingTemplateResponseUserSettings>,
+    ) -> Self {
+        self.0.user_settings = Some(user_settings.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateSettingTemplateRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum PhoneUserCallLogsType {
     #[serde(rename = "all")]
@@ -23810,8 +25425,8 @@ pub enum PhoneUserCallLogsType {
     Missed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsType {
@@ -23820,7 +25435,7 @@ impl std::fmt::Display for PhoneUserCallLogsType {
             PhoneUserCallLogsType::All => "all",
             PhoneUserCallLogsType::Missed => "missed",
             PhoneUserCallLogsType::Noop => "",
-            PhoneUserCallLogsType::FallthroughString => "*",
+            PhoneUserCallLogsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23846,8 +25461,8 @@ pub enum TimeType {
     EndTime,
     #[serde(rename = "startTime")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TimeType {
@@ -23855,7 +25470,7 @@ impl std::fmt::Display for TimeType {
         match &*self {
             TimeType::EndTime => "endTime",
             TimeType::StartTime => "startTime",
-            TimeType::FallthroughString => "*",
+            TimeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23931,8 +25546,8 @@ pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
@@ -23946,7 +25561,7 @@ impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
             PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup => "sharedLineGroup",
             PhoneUserCallLogsResponseForwardedByExtensionType::User => "user",
             PhoneUserCallLogsResponseForwardedByExtensionType::Noop => "",
-            PhoneUserCallLogsResponseForwardedByExtensionType::FallthroughString => "*",
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24613,8 +26228,8 @@ pub enum PhoneUserVoiceMailsStatus {
     Read,
     #[serde(rename = "unread")]
     Unread,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsStatus {
@@ -24623,7 +26238,7 @@ impl std::fmt::Display for PhoneUserVoiceMailsStatus {
             PhoneUserVoiceMailsStatus::All => "all",
             PhoneUserVoiceMailsStatus::Read => "read",
             PhoneUserVoiceMailsStatus::Unread => "unread",
-            PhoneUserVoiceMailsStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24646,8 +26261,8 @@ pub enum PhoneUserVoiceMailsResponseStatus {
     Unread,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
@@ -24656,7 +26271,7 @@ impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
             PhoneUserVoiceMailsResponseStatus::Read => "read",
             PhoneUserVoiceMailsResponseStatus::Unread => "unread",
             PhoneUserVoiceMailsResponseStatus::Noop => "",
-            PhoneUserVoiceMailsResponseStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24870,7 +26485,7 @@ pub struct AddUserSettingRequestVoiceMail {
     pub download: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddUserSettingRequest {
     /**
     * Update the voicemail setting.
@@ -24879,6 +26494,29 @@ pub struct AddUserSettingRequest {
     pub voice_mail: Option<AddUserSettingRequestVoiceMail>,
 }
 
+impl AddUserSettingRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddUserSettingRequestBuilder {
+        AddUserSettingRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddUserSettingRequest`.
+#[derive(Debug, Clone)]
+pub struct AddUserSettingRequestBuilder(AddUserSettingRequest);
+
+impl AddUserSettingRequestBuilder {
+    pub fn voice_mail(mut self, voice_mail: impl Into<AddUserSettingRequestVoiceMail>) -> Self {
+        self.0.voice_mail = Some(voice_mail.into());
+        self
+    }
+
+    pub fn build(self) -> AddUserSettingRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddUserSettingResponseVoiceMail {
     /**
@@ -24962,7 +26600,7 @@ pub struct UpdateUserSettingRequestVoiceMail {
     pub shared_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateUserSettingRequest {
     /**
     * Update the voicemail setting.
@@ -24971,6 +26609,29 @@ pub struct UpdateUserSettingRequest {
     pub voice_mail: Option<UpdateUserSettingRequestVoiceMail>,
 }
 
+impl UpdateUserSettingRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateUserSettingRequestBuilder {
+        UpdateUserSettingRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateUserSettingRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateUserSettingRequestBuilder(UpdateUserSettingRequest);
+
+impl UpdateUserSettingRequestBuilder {
+    pub fn voice_mail(mut self, voice_mail: impl Into<UpdateUserSettingRequestVoiceMail>) -> Self {
+        self.0.voice_mail = Some(voice_mail.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateUserSettingRequest {
+        self.0
+    }
+}
+
 /**
 * The type of call:
 *   *`voip` (Voice over IP)
@@ -24993,8 +26654,8 @@ pub enum CallType {
     Voip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CallType {
@@ -25006,7 +26667,7 @@ impl std::fmt::Display for CallType {
             CallType::Tollfree => "tollfree",
             CallType::Voip => "voip",
             CallType::Noop => "",
-            CallType::FallthroughString => "*",
+            CallType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25367,7 +27028,7 @@ pub struct CallingPlans {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignCallingPlanRequest {
     #[serde(
         default,
@@ -25377,6 +27038,29 @@ pub struct AssignCallingPlanRequest {
     pub calling_plans: Vec<CallingPlans>,
 }
 
+impl AssignCallingPlanRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AssignCallingPlanRequestBuilder {
+        AssignCallingPlanRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AssignCallingPlanRequest`.
+#[derive(Debug, Clone)]
+pub struct AssignCallingPlanRequestBuilder(AssignCallingPlanRequest);
+
+impl AssignCallingPlanRequestBuilder {
+    pub fn calling_plans(mut self, calling_plans: Vec<CallingPlans>) -> Self {
+        self.0.calling_plans = calling_plans;
+        self
+    }
+
+    pub fn build(self) -> AssignCallingPlanRequest {
+        self.0
+    }
+}
+
 /**
 * The owner type: `user` or `call queue`.
 */
@@ -25388,8 +27072,8 @@ pub enum GetPhoneRecordingsResponseOwnerType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
@@ -25398,7 +27082,7 @@ impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
             GetPhoneRecordingsResponseOwnerType::CallQueue => "call queue",
             GetPhoneRecordingsResponseOwnerType::User => "user",
             GetPhoneRecordingsResponseOwnerType::Noop => "",
-            GetPhoneRecordingsResponseOwnerType::FallthroughString => "*",
+            GetPhoneRecordingsResponseOwnerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25463,8 +27147,8 @@ pub enum Direction {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -25473,7 +27157,7 @@ impl std::fmt::Display for Direction {
             Direction::Inbound => "inbound",
             Direction::Outbound => "outbound",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25780,7 +27464,7 @@ pub struct SipTrunks {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostPhoneSipTrunkRequest {
     /**
     * SIP trunk configurations.
@@ -25793,7 +27477,30 @@ pub struct PostPhoneSipTrunkRequest {
     pub sip_trunks: Vec<SipTrunks>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl PostPhoneSipTrunkRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> PostPhoneSipTrunkRequestBuilder {
+        PostPhoneSipTrunkRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `PostPhoneSipTrunkRequest`.
+#[derive(Debug, Clone)]
+pub struct PostPhoneSipTrunkRequestBuilder(PostPhoneSipTrunkRequest);
+
+impl PostPhoneSipTrunkRequestBuilder {
+    pub fn sip_trunks(mut self, sip_trunks: Vec<SipTrunks>) -> Self {
+        self.0.sip_trunks = sip_trunks;
+        self
+    }
+
+    pub fn build(self) -> PostPhoneSipTrunkRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdatePhoneSipTrunkRequest {
     /**
     * User's first name.
@@ -25815,7 +27522,35 @@ pub struct UpdatePhoneSipTrunkRequest {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl UpdatePhoneSipTrunkRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdatePhoneSipTrunkRequestBuilder {
+        UpdatePhoneSipTrunkRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdatePhoneSipTrunkRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdatePhoneSipTrunkRequestBuilder(UpdatePhoneSipTrunkRequest);
+
+impl UpdatePhoneSipTrunkRequestBuilder {
+    pub fn carrier_account(mut self, carrier_account: impl Into<String>) -> Self {
+        self.0.carrier_account = carrier_account.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> UpdatePhoneSipTrunkRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AccountPlanBaseDeleteRequest {
     /**
     * User's first name.
@@ -25846,6 +27581,39 @@ pub struct AccountPlanBaseDeleteRequest {
     pub reason: i64,
 }
 
+impl AccountPlanBaseDeleteRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AccountPlanBaseDeleteRequestBuilder {
+        AccountPlanBaseDeleteRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AccountPlanBaseDeleteRequest`.
+#[derive(Debug, Clone)]
+pub struct AccountPlanBaseDeleteRequestBuilder(AccountPlanBaseDeleteRequest);
+
+impl AccountPlanBaseDeleteRequestBuilder {
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.0.action = action.into();
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.0.comment = comment.into();
+        self
+    }
+
+    pub fn reason(mut self, reason: i64) -> Self {
+        self.0.reason = reason;
+        self
+    }
+
+    pub fn build(self) -> AccountPlanBaseDeleteRequest {
+        self.0
+    }
+}
+
 /**
 * The action that needs to be taken for this sub account. Value must be set to "cancel".
 */
@@ -25855,8 +27623,8 @@ pub enum AccountPlanAddonCancelRequestAction {
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
@@ -25864,7 +27632,7 @@ impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
         match &*self {
             AccountPlanAddonCancelRequestAction::Cancel => "cancel",
             AccountPlanAddonCancelRequestAction::Noop => "",
-            AccountPlanAddonCancelRequestAction::FallthroughString => "*",
+            AccountPlanAddonCancelRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25882,7 +27650,7 @@ impl AccountPlanAddonCancelRequestAction {
 }
 
 /// Account plan object
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AccountPlanAddonCancelRequest {
     /**
     * Account plan object
@@ -25919,6 +27687,44 @@ pub struct AccountPlanAddonCancelRequest {
     pub type_: String,
 }
 
+impl AccountPlanAddonCancelRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AccountPlanAddonCancelRequestBuilder {
+        AccountPlanAddonCancelRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AccountPlanAddonCancelRequest`.
+#[derive(Debug, Clone)]
+pub struct AccountPlanAddonCancelRequestBuilder(AccountPlanAddonCancelRequest);
+
+impl AccountPlanAddonCancelRequestBuilder {
+    pub fn action(mut self, action: impl Into<AccountPlanAddonCancelRequestAction>) -> Self {
+        self.0.action = Some(action.into());
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.0.comment = comment.into();
+        self
+    }
+
+    pub fn reason(mut self, reason: i64) -> Self {
+        self.0.reason = reason;
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<String>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> AccountPlanAddonCancelRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivityLogs {
     /**
@@ -27297,7 +29103,7 @@ pub struct UpdateGroupSettingsRequestTelephony {
     pub third_party_audio: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateGroupSettingsRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub email_notification: Option<GetGroupSettingsResponseEmailNotification>,
@@ -27313,6 +29119,63 @@ pub struct UpdateGroupSettingsRequest {
     pub telephony: Option<UpdateGroupSettingsRequestTelephony>,
 }
 
+impl UpdateGroupSettingsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateGroupSettingsRequestBuilder {
+        UpdateGroupSettingsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateGroupSettingsRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateGroupSettingsRequestBuilder(UpdateGroupSettingsRequest);
+
+impl UpdateGroupSettingsRequestBuilder {
+    pub fn email_notification(
+        mut self,
+        email_notification: impl Into<GetGroupSettingsResponseEmailNotification>,
+    ) -> Self {
+        self.0.email_notification = Some(email_notification.into());
+        self
+    }
+
+    pub fn in_meeting(
+        mut self,
+        in_meeting: impl Into<UpdateGroupSettingsRequestInMeeting>,
+    ) -> Self {
+        self.0.in_meeting = Some(in_meeting.into());
+        self
+    }
+
+    pub fn profile(mut self, profile: impl Into<Profile>) -> Self {
+        self.0.profile = Some(profile.into());
+        self
+    }
+
+    pub fn recording(mut self, recording: impl Into<GetGroupSettingsResponseRecording>) -> Self {
+        self.0.recording = Some(recording.into());
+        self
+    }
+
+    pub fn schedule_meeting(
+        mut self,
+        schedule_meeting: impl Into<UpdateGroupSettingsRequestScheduleMeeting>,
+    ) -> Self {
+        self.0.schedule_meeting = Some(schedule_meeting.into());
+        self
+    }
+
+    pub fn telephony(mut self, telephony: impl Into<UpdateGroupSettingsRequestTelephony>) -> Self {
+        self.0.telephony = Some(telephony.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateGroupSettingsRequest {
+        self.0
+    }
+}
+
 /// All of the following types:
 ///
 /// - `Domains`
@@ -27362,8 +29225,8 @@ pub enum UpdateGroupSettingsOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupSettingsOption {
@@ -27373,7 +29236,7 @@ impl std::fmt::Display for UpdateGroupSettingsOption {
             UpdateGroupSettingsOption::MeetingSecurity => "meeting_security",
             UpdateGroupSettingsOption::RecordingAuthentication => "recording_authentication",
             UpdateGroupSettingsOption::Noop => "",
-            UpdateGroupSettingsOption::FallthroughString => "*",
+            UpdateGroupSettingsOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28123,7 +29986,7 @@ pub struct GroupLockedSettingsRequestRecording {
     pub recording_authentication: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GroupLockedSettingsRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub email_notification: Option<GetGroupSettingsResponseEmailNotification>,
@@ -28137,6 +30000,61 @@ pub struct GroupLockedSettingsRequest {
     pub telephony: Option<GetGroupLockSettingsResponseTelephony>,
 }
 
+impl GroupLockedSettingsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> GroupLockedSettingsRequestBuilder {
+        GroupLockedSettingsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `GroupLockedSettingsRequest`.
+#[derive(Debug, Clone)]
+pub struct GroupLockedSettingsRequestBuilder(GroupLockedSettingsRequest);
+
+impl GroupLockedSettingsRequestBuilder {
+    pub fn email_notification(
+        mut self,
+        email_notification: impl Into<GetGroupSettingsResponseEmailNotification>,
+    ) -> Self {
+        self.0.email_notification = Some(email_notification.into());
+        self
+    }
+
+    pub fn in_meeting(
+        mut self,
+        in_meeting: impl Into<GetGroupLockSettingsResponseInMeeting>,
+    ) -> Self {
+        self.0.in_meeting = Some(in_meeting.into());
+        self
+    }
+
+    pub fn recording(mut self, recording: impl Into<GroupLockedSettingsRequestRecording>) -> Self {
+        self.0.recording = Some(recording.into());
+        self
+    }
+
+    pub fn schedule_meeting(
+        mut self,
+        schedule_meeting: impl Into<GroupLockedSettingsRequestScheduleMeeting>,
+    ) -> Self {
+        self.0.schedule_meeting = Some(schedule_meeting.into());
+        self
+    }
+
+    pub fn telephony(
+        mut self,
+        telephony: impl Into<GetGroupLockSettingsResponseTelephony>,
+    ) -> Self {
+        self.0.telephony = Some(telephony.into());
+        self
+    }
+
+    pub fn build(self) -> GroupLockedSettingsRequest {
+        self.0
+    }
+}
+
 /// All of the following types:
 ///
 /// - `MeetingSecuritySettings`
@@ -28252,7 +30170,7 @@ pub struct UpdateRoleRequestSubAccountPrivileges {
     pub second_level: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateRoleRequest {
     /**
     * User's first name.
@@ -28309,6 +30227,57 @@ pub struct UpdateRoleRequest {
     pub total_members: i64,
 }
 
+impl UpdateRoleRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateRoleRequestBuilder {
+        UpdateRoleRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateRoleRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateRoleRequestBuilder(UpdateRoleRequest);
+
+impl UpdateRoleRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.0.id = id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn privileges(mut self, privileges: Vec<String>) -> Self {
+        self.0.privileges = privileges;
+        self
+    }
+
+    pub fn sub_account_privileges(
+        mut self,
+        sub_account_privileges: impl Into<UpdateRoleRequestSubAccountPrivileges>,
+    ) -> Self {
+        self.0.sub_account_privileges = Some(sub_account_privileges.into());
+        self
+    }
+
+    pub fn total_members(mut self, total_members: i64) -> Self {
+        self.0.total_members = total_members;
+        self
+    }
+
+    pub fn build(self) -> UpdateRoleRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetPlanUsageResponseBase {
     /**
@@ -28493,7 +30462,7 @@ pub struct GetPlanUsageResponse {
     pub plan_zoom_rooms: Vec<GetPlanUsageResponseBase>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SwitchUserAccountRequest {
     /**
     * User's first name.
@@ -28506,6 +30475,43 @@ pub struct SwitchUserAccountRequest {
     pub account_id: String,
 }
 
+impl std::convert::From<String> for SwitchUserAccountRequest {
+    fn from(account_id: String) -> Self {
+        SwitchUserAccountRequest { account_id }
+    }
+}
+
+impl std::convert::From<&str> for SwitchUserAccountRequest {
+    fn from(account_id: &str) -> Self {
+        SwitchUserAccountRequest {
+            account_id: account_id.to_string(),
+        }
+    }
+}
+
+impl SwitchUserAccountRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> SwitchUserAccountRequestBuilder {
+        SwitchUserAccountRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `SwitchUserAccountRequest`.
+#[derive(Debug, Clone)]
+pub struct SwitchUserAccountRequestBuilder(SwitchUserAccountRequest);
+
+impl SwitchUserAccountRequestBuilder {
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.0.account_id = account_id.into();
+        self
+    }
+
+    pub fn build(self) -> SwitchUserAccountRequest {
+        self.0
+    }
+}
+
 /**
 * The recording file type. The value of this field could be one of the following:<br>
 *   * `MP4`: Video file of the recording.<br>
@@ -28537,8 +30543,8 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType {
@@ -28552,7 +30558,7 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb => "TB",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript => "TRANSCRIPT",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28583,8 +30589,8 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
@@ -28593,7 +30599,7 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed => "completed",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing => "processing",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28663,8 +30669,8 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     Timeline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
@@ -28693,7 +30699,7 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
             GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail => "thumbnail",
             GetAccountCloudRecordingResponseMeetingsFilesType::Timeline => "timeline",
             GetAccountCloudRecordingResponseMeetingsFilesType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29073,12 +31079,35 @@ pub struct Basic {
     pub support_phone: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateZrAccProfileRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic: Option<Basic>,
 }
 
+impl UpdateZrAccProfileRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateZrAccProfileRequestBuilder {
+        UpdateZrAccProfileRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateZrAccProfileRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateZrAccProfileRequestBuilder(UpdateZrAccProfileRequest);
+
+impl UpdateZrAccProfileRequestBuilder {
+    pub fn basic(mut self, basic: impl Into<Basic>) -> Self {
+        self.0.basic = Some(basic.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateZrAccProfileRequest {
+        self.0
+    }
+}
+
 /**
 * The type of setting that you would like to retrieve.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
 *   `meeting`: Meeting settings of the Zoom Rooms Account. <br>
@@ -29090,8 +31119,8 @@ pub enum SettingType {
     Alert,
     #[serde(rename = "meeting")]
     Meeting,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SettingType {
@@ -29099,7 +31128,7 @@ impl std::fmt::Display for SettingType {
         match &*self {
             SettingType::Alert => "alert",
             SettingType::Meeting => "meeting",
-            SettingType::FallthroughString => "*",
+            SettingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29131,8 +31160,8 @@ pub enum ListZrLocationsResponseType {
     States,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrLocationsResponseType {
@@ -29145,7 +31174,7 @@ impl std::fmt::Display for ListZrLocationsResponseType {
             ListZrLocationsResponseType::Floor => "floor",
             ListZrLocationsResponseType::States => "states",
             ListZrLocationsResponseType::Noop => "",
-            ListZrLocationsResponseType::FallthroughString => "*",
+            ListZrLocationsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29227,7 +31256,7 @@ pub struct ListZrLocationsResponseData {
     pub page_size: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddAzrLocationRequest {
     /**
     * User's first name.
@@ -29249,6 +31278,34 @@ pub struct AddAzrLocationRequest {
     pub parent_location_id: String,
 }
 
+impl AddAzrLocationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddAzrLocationRequestBuilder {
+        AddAzrLocationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddAzrLocationRequest`.
+#[derive(Debug, Clone)]
+pub struct AddAzrLocationRequestBuilder(AddAzrLocationRequest);
+
+impl AddAzrLocationRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn parent_location_id(mut self, parent_location_id: impl Into<String>) -> Self {
+        self.0.parent_location_id = parent_location_id.into();
+        self
+    }
+
+    pub fn build(self) -> AddAzrLocationRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetZrLocationProfileResponseBasic {
     /**
@@ -29474,12 +31531,35 @@ pub struct UpdateRoomProfileRequestBasic {
     pub support_phone: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateRoomProfileRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic: Option<UpdateRoomProfileRequestBasic>,
 }
 
+impl UpdateRoomProfileRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateRoomProfileRequestBuilder {
+        UpdateRoomProfileRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateRoomProfileRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateRoomProfileRequestBuilder(UpdateRoomProfileRequest);
+
+impl UpdateRoomProfileRequestBuilder {
+    pub fn basic(mut self, basic: impl Into<UpdateRoomProfileRequestBasic>) -> Self {
+        self.0.basic = Some(basic.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateRoomProfileRequest {
+        self.0
+    }
+}
+
 /**
 * Type of the device. The value of this field can be one of the following:<br>`Zoom Rooms Computer`,<br> `Controller`, `Scheduling Display`, `Zoom Rooms Control System`, `Companion Whiteboard`
 */
@@ -29497,8 +31577,8 @@ pub enum DeviceType {
     ZoomRoomsControlSystem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -29510,7 +31590,7 @@ impl std::fmt::Display for DeviceType {
             DeviceType::ZoomRoomsComputer => "Zoom Rooms Computer",
             DeviceType::ZoomRoomsControlSystem => "Zoom Rooms Control System",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29538,8 +31618,8 @@ pub enum ListZrDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrDevicesResponseStatus {
@@ -29548,7 +31628,7 @@ impl std::fmt::Display for ListZrDevicesResponseStatus {
             ListZrDevicesResponseStatus::Offline => "Offline",
             ListZrDevicesResponseStatus::Online => "Online",
             ListZrDevicesResponseStatus::Noop => "",
-            ListZrDevicesResponseStatus::FallthroughString => "*",
+            ListZrDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29647,7 +31727,7 @@ pub struct ListSipTrunkNumbersResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignSipConfigRequest {
     /**
     * Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
@@ -29679,7 +31759,48 @@ pub struct AssignSipConfigRequest {
     pub show_zoom_provided_numbers: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl AssignSipConfigRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AssignSipConfigRequestBuilder {
+        AssignSipConfigRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AssignSipConfigRequest`.
+#[derive(Debug, Clone)]
+pub struct AssignSipConfigRequestBuilder(AssignSipConfigRequest);
+
+impl AssignSipConfigRequestBuilder {
+    pub fn enable(mut self, enable: bool) -> Self {
+        self.0.enable = Some(enable);
+        self
+    }
+
+    pub fn show_callout_internal_number(mut self, show_callout_internal_number: bool) -> Self {
+        self.0.show_callout_internal_number = Some(show_callout_internal_number);
+        self
+    }
+
+    pub fn show_zoom_provided_callout_countries(
+        mut self,
+        show_zoom_provided_callout_countries: i64,
+    ) -> Self {
+        self.0.show_zoom_provided_callout_countries = show_zoom_provided_callout_countries;
+        self
+    }
+
+    pub fn show_zoom_provided_numbers(mut self, show_zoom_provided_numbers: i64) -> Self {
+        self.0.show_zoom_provided_numbers = show_zoom_provided_numbers;
+        self
+    }
+
+    pub fn build(self) -> AssignSipConfigRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignSipTrunkNumbersRequest {
     /**
     * Specify the IP addresses of the Room Connectors that you would like to share with the sub account. Multiple values can be separated by comma. If no value is provided in this field, all the Room Connectors of a master account will be shared with the sub account.
@@ -29695,6 +31816,29 @@ pub struct AssignSipTrunkNumbersRequest {
     pub phone_numbers: Vec<String>,
 }
 
+impl AssignSipTrunkNumbersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AssignSipTrunkNumbersRequestBuilder {
+        AssignSipTrunkNumbersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AssignSipTrunkNumbersRequest`.
+#[derive(Debug, Clone)]
+pub struct AssignSipTrunkNumbersRequestBuilder(AssignSipTrunkNumbersRequest);
+
+impl AssignSipTrunkNumbersRequestBuilder {
+    pub fn phone_numbers(mut self, phone_numbers: Vec<String>) -> Self {
+        self.0.phone_numbers = phone_numbers;
+        self
+    }
+
+    pub fn build(self) -> AssignSipTrunkNumbersRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InMeetingFiles {
     /**
@@ -29842,8 +31986,8 @@ pub enum ListPhoneDevicesType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesType {
@@ -29852,7 +31996,7 @@ impl std::fmt::Display for ListPhoneDevicesType {
             ListPhoneDevicesType::Assigned => "assigned",
             ListPhoneDevicesType::Unassigned => "unassigned",
             ListPhoneDevicesType::Noop => "",
-            ListPhoneDevicesType::FallthroughString => "*",
+            ListPhoneDevicesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29911,8 +32055,8 @@ pub enum ListPhoneDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesResponseStatus {
@@ -29921,7 +32065,7 @@ impl std::fmt::Display for ListPhoneDevicesResponseStatus {
             ListPhoneDevicesResponseStatus::Offline => "offline",
             ListPhoneDevicesResponseStatus::Online => "online",
             ListPhoneDevicesResponseStatus::Noop => "",
-            ListPhoneDevicesResponseStatus::FallthroughString => "*",
+            ListPhoneDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30024,7 +32168,7 @@ pub struct ListPhoneDevicesResponseData {
     pub total_records: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddPhoneDeviceRequest {
     /**
     * User's first name.
@@ -30074,6 +32218,49 @@ pub struct AddPhoneDeviceRequest {
     pub type_: String,
 }
 
+impl AddPhoneDeviceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddPhoneDeviceRequestBuilder {
+        AddPhoneDeviceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddPhoneDeviceRequest`.
+#[derive(Debug, Clone)]
+pub struct AddPhoneDeviceRequestBuilder(AddPhoneDeviceRequest);
+
+impl AddPhoneDeviceRequestBuilder {
+    pub fn assigned_to(mut self, assigned_to: impl Into<String>) -> Self {
+        self.0.assigned_to = assigned_to.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn mac_address(mut self, mac_address: impl Into<String>) -> Self {
+        self.0.mac_address = mac_address.into();
+        self
+    }
+
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.0.model = model.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<String>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> AddPhoneDeviceRequest {
+        self.0
+    }
+}
+
 /// User to whom the device has been assigned.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetDeviceResponseAssignee {
@@ -30125,8 +32312,8 @@ pub enum GetDeviceResponseProvisionType {
     Ztp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetDeviceResponseProvisionType {
@@ -30136,7 +32323,7 @@ impl std::fmt::Display for GetDeviceResponseProvisionType {
             GetDeviceResponseProvisionType::Manual => "manual",
             GetDeviceResponseProvisionType::Ztp => "ztp",
             GetDeviceResponseProvisionType::Noop => "",
-            GetDeviceResponseProvisionType::FallthroughString => "*",
+            GetDeviceResponseProvisionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30361,7 +32548,7 @@ pub struct GetDeviceResponse {
     pub status: Option<ListPhoneDevicesResponseStatus>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDeviceRequest {
     /**
     * User's first name.
@@ -30392,6 +32579,39 @@ pub struct UpdateDeviceRequest {
     pub mac_address: String,
 }
 
+impl UpdateDeviceRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateDeviceRequestBuilder {
+        UpdateDeviceRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateDeviceRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateDeviceRequestBuilder(UpdateDeviceRequest);
+
+impl UpdateDeviceRequestBuilder {
+    pub fn assigned_to(mut self, assigned_to: impl Into<String>) -> Self {
+        self.0.assigned_to = assigned_to.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn mac_address(mut self, mac_address: impl Into<String>) -> Self {
+        self.0.mac_address = mac_address.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateDeviceRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ExternalContacts {
     /**
@@ -30493,7 +32713,7 @@ pub struct ListExternalContactsResponse {
     pub page_size: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddExternalContactRequest {
     /**
     * User's first name.
@@ -30563,7 +32783,60 @@ pub struct AddExternalContactRequest {
     pub routing_path: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl AddExternalContactRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddExternalContactRequestBuilder {
+        AddExternalContactRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddExternalContactRequest`.
+#[derive(Debug, Clone)]
+pub struct AddExternalContactRequestBuilder(AddExternalContactRequest);
+
+impl AddExternalContactRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: impl Into<String>) -> Self {
+        self.0.extension_number = extension_number.into();
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.0.id = id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn phone_numbers(mut self, phone_numbers: Vec<String>) -> Self {
+        self.0.phone_numbers = phone_numbers;
+        self
+    }
+
+    pub fn routing_path(mut self, routing_path: impl Into<String>) -> Self {
+        self.0.routing_path = routing_path.into();
+        self
+    }
+
+    pub fn build(self) -> AddExternalContactRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateExternalContactRequest {
     /**
     * User's first name.
@@ -30633,7 +32906,60 @@ pub struct UpdateExternalContactRequest {
     pub routing_path: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl UpdateExternalContactRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateExternalContactRequestBuilder {
+        UpdateExternalContactRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateExternalContactRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateExternalContactRequestBuilder(UpdateExternalContactRequest);
+
+impl UpdateExternalContactRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.0.email = email.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: impl Into<String>) -> Self {
+        self.0.extension_number = extension_number.into();
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.0.id = id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn phone_numbers(mut self, phone_numbers: Vec<String>) -> Self {
+        self.0.phone_numbers = phone_numbers;
+        self
+    }
+
+    pub fn routing_path(mut self, routing_path: impl Into<String>) -> Self {
+        self.0.routing_path = routing_path.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateExternalContactRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChangeZrLocationRequest {
     /**
     * User's first name.
@@ -30646,7 +32972,44 @@ pub struct ChangeZrLocationRequest {
     pub location_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl std::convert::From<String> for ChangeZrLocationRequest {
+    fn from(location_id: String) -> Self {
+        ChangeZrLocationRequest { location_id }
+    }
+}
+
+impl std::convert::From<&str> for ChangeZrLocationRequest {
+    fn from(location_id: &str) -> Self {
+        ChangeZrLocationRequest {
+            location_id: location_id.to_string(),
+        }
+    }
+}
+
+impl ChangeZrLocationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ChangeZrLocationRequestBuilder {
+        ChangeZrLocationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ChangeZrLocationRequest`.
+#[derive(Debug, Clone)]
+pub struct ChangeZrLocationRequestBuilder(ChangeZrLocationRequest);
+
+impl ChangeZrLocationRequestBuilder {
+    pub fn location_id(mut self, location_id: impl Into<String>) -> Self {
+        self.0.location_id = location_id.into();
+        self
+    }
+
+    pub fn build(self) -> ChangeZrLocationRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChangeParentLocationRequest {
     /**
     * User's first name.
@@ -30659,6 +33022,43 @@ pub struct ChangeParentLocationRequest {
     pub parent_location_id: String,
 }
 
+impl std::convert::From<String> for ChangeParentLocationRequest {
+    fn from(parent_location_id: String) -> Self {
+        ChangeParentLocationRequest { parent_location_id }
+    }
+}
+
+impl std::convert::From<&str> for ChangeParentLocationRequest {
+    fn from(parent_location_id: &str) -> Self {
+        ChangeParentLocationRequest {
+            parent_location_id: parent_location_id.to_string(),
+        }
+    }
+}
+
+impl ChangeParentLocationRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ChangeParentLocationRequestBuilder {
+        ChangeParentLocationRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ChangeParentLocationRequest`.
+#[derive(Debug, Clone)]
+pub struct ChangeParentLocationRequestBuilder(ChangeParentLocationRequest);
+
+impl ChangeParentLocationRequestBuilder {
+    pub fn parent_location_id(mut self, parent_location_id: impl Into<String>) -> Self {
+        self.0.parent_location_id = parent_location_id.into();
+        self
+    }
+
+    pub fn build(self) -> ChangeParentLocationRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListCallQueuesResponsePhoneNumbers {
     /**
@@ -30793,7 +33193,7 @@ pub struct CreateCallQueueRequestMembers {
     pub users: Vec<Assistants>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateCallQueueRequest {
     /**
     * User's first name.
@@ -30838,6 +33238,49 @@ pub struct CreateCallQueueRequest {
     pub site_id: String,
 }
 
+impl CreateCallQueueRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateCallQueueRequestBuilder {
+        CreateCallQueueRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateCallQueueRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateCallQueueRequestBuilder(CreateCallQueueRequest);
+
+impl CreateCallQueueRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: i64) -> Self {
+        self.0.extension_number = extension_number;
+        self
+    }
+
+    pub fn members(mut self, members: impl Into<CreateCallQueueRequestMembers>) -> Self {
+        self.0.members = Some(members.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> CreateCallQueueRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateCallQueueResponse {
     /**
@@ -30891,8 +33334,8 @@ pub enum Level {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Level {
@@ -30901,7 +33344,7 @@ impl std::fmt::Display for Level {
             Level::Manager => "manager",
             Level::User => "user",
             Level::Noop => "",
-            Level::FallthroughString => "*",
+            Level::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31020,7 +33463,7 @@ pub struct GetCallQueueResponse {
     pub status: Option<ReportUsersType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateCallQueueRequest {
     /**
     * User's first name.
@@ -31074,8 +33517,56 @@ pub struct UpdateCallQueueRequest {
     pub timezone: String,
 }
 
+impl UpdateCallQueueRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateCallQueueRequestBuilder {
+        UpdateCallQueueRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateCallQueueRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateCallQueueRequestBuilder(UpdateCallQueueRequest);
+
+impl UpdateCallQueueRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: i64) -> Self {
+        self.0.extension_number = extension_number;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<ReportUsersType>) -> Self {
+        self.0.status = Some(status.into());
+        self
+    }
+
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.0.timezone = timezone.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateCallQueueRequest {
+        self.0
+    }
+}
+
 /// A maximum of 10 members can be added at a time.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddMembersCallQueueRequest {
     /**
     * A maximum of 10 members can be added at a time.
@@ -31097,6 +33588,34 @@ pub struct AddMembersCallQueueRequest {
     pub users: Vec<Assistants>,
 }
 
+impl AddMembersCallQueueRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddMembersCallQueueRequestBuilder {
+        AddMembersCallQueueRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddMembersCallQueueRequest`.
+#[derive(Debug, Clone)]
+pub struct AddMembersCallQueueRequestBuilder(AddMembersCallQueueRequest);
+
+impl AddMembersCallQueueRequestBuilder {
+    pub fn common_area_phone_ids(mut self, common_area_phone_ids: Vec<String>) -> Self {
+        self.0.common_area_phone_ids = common_area_phone_ids;
+        self
+    }
+
+    pub fn users(mut self, users: Vec<Assistants>) -> Self {
+        self.0.users = users;
+        self
+    }
+
+    pub fn build(self) -> AddMembersCallQueueRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddMembersCallQueueRequestData {
     /**
@@ -31106,7 +33625,7 @@ pub struct AddMembersCallQueueRequestData {
     pub members: Option<AddMembersCallQueueRequest>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChangeCallQueueManagerRequest {
     /**
     * User's first name.
@@ -31119,6 +33638,43 @@ pub struct ChangeCallQueueManagerRequest {
     pub member_id: String,
 }
 
+impl std::convert::From<String> for ChangeCallQueueManagerRequest {
+    fn from(member_id: String) -> Self {
+        ChangeCallQueueManagerRequest { member_id }
+    }
+}
+
+impl std::convert::From<&str> for ChangeCallQueueManagerRequest {
+    fn from(member_id: &str) -> Self {
+        ChangeCallQueueManagerRequest {
+            member_id: member_id.to_string(),
+        }
+    }
+}
+
+impl ChangeCallQueueManagerRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ChangeCallQueueManagerRequestBuilder {
+        ChangeCallQueueManagerRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ChangeCallQueueManagerRequest`.
+#[derive(Debug, Clone)]
+pub struct ChangeCallQueueManagerRequestBuilder(ChangeCallQueueManagerRequest);
+
+impl ChangeCallQueueManagerRequestBuilder {
+    pub fn member_id(mut self, member_id: impl Into<String>) -> Self {
+        self.0.member_id = member_id.into();
+        self
+    }
+
+    pub fn build(self) -> ChangeCallQueueManagerRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetPhoneNumberDetailsResponseCarrier {
     /**
@@ -31238,7 +33794,7 @@ pub struct GetPhoneNumberDetailsResponse {
     pub status: Option<ListAccountPhoneNumbersResponseStatus>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdatePhoneNumberDetailsRequest {
     /**
     * Specify the IP addresses of the Room Connectors that you would like to share with the sub account. Multiple values can be separated by comma. If no value is provided in this field, all the Room Connectors of a master account will be shared with the sub account.
@@ -31272,7 +33828,40 @@ pub struct UpdatePhoneNumberDetailsRequest {
     pub sip_group_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl UpdatePhoneNumberDetailsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdatePhoneNumberDetailsRequestBuilder {
+        UpdatePhoneNumberDetailsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdatePhoneNumberDetailsRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdatePhoneNumberDetailsRequestBuilder(UpdatePhoneNumberDetailsRequest);
+
+impl UpdatePhoneNumberDetailsRequestBuilder {
+    pub fn capability(mut self, capability: Vec<String>) -> Self {
+        self.0.capability = capability;
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn sip_group_id(mut self, sip_group_id: impl Into<String>) -> Self {
+        self.0.sip_group_id = sip_group_id.into();
+        self
+    }
+
+    pub fn build(self) -> UpdatePhoneNumberDetailsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChangeMainCompanyNumberRequest {
     /**
     * User's first name.
@@ -31285,6 +33874,43 @@ pub struct ChangeMainCompanyNumberRequest {
     pub phone_number: String,
 }
 
+impl std::convert::From<String> for ChangeMainCompanyNumberRequest {
+    fn from(phone_number: String) -> Self {
+        ChangeMainCompanyNumberRequest { phone_number }
+    }
+}
+
+impl std::convert::From<&str> for ChangeMainCompanyNumberRequest {
+    fn from(phone_number: &str) -> Self {
+        ChangeMainCompanyNumberRequest {
+            phone_number: phone_number.to_string(),
+        }
+    }
+}
+
+impl ChangeMainCompanyNumberRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ChangeMainCompanyNumberRequestBuilder {
+        ChangeMainCompanyNumberRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ChangeMainCompanyNumberRequest`.
+#[derive(Debug, Clone)]
+pub struct ChangeMainCompanyNumberRequestBuilder(ChangeMainCompanyNumberRequest);
+
+impl ChangeMainCompanyNumberRequestBuilder {
+    pub fn phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.0.phone_number = phone_number.into();
+        self
+    }
+
+    pub fn build(self) -> ChangeMainCompanyNumberRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListCallingPlansResponse {
     /**
@@ -31471,7 +34097,7 @@ pub struct ListPhoneUsersResponseData {
     pub users: Vec<ListPhoneUsersResponse>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateAutoReceptionistRequest {
     /**
     * Account seats.
@@ -31493,6 +34119,34 @@ pub struct UpdateAutoReceptionistRequest {
     pub name: String,
 }
 
+impl UpdateAutoReceptionistRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateAutoReceptionistRequestBuilder {
+        UpdateAutoReceptionistRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateAutoReceptionistRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateAutoReceptionistRequestBuilder(UpdateAutoReceptionistRequest);
+
+impl UpdateAutoReceptionistRequestBuilder {
+    pub fn extension_number(mut self, extension_number: i64) -> Self {
+        self.0.extension_number = extension_number;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateAutoReceptionistRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CommonAreaPhones {
     #[serde(
@@ -31596,7 +34250,7 @@ pub struct ListCommonAreaPhonesResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddCommonAreaPhoneRequest {
     /**
     * User's first name.
@@ -31682,6 +34336,69 @@ pub struct AddCommonAreaPhoneRequest {
     pub type_: String,
 }
 
+impl AddCommonAreaPhoneRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddCommonAreaPhoneRequestBuilder {
+        AddCommonAreaPhoneRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddCommonAreaPhoneRequest`.
+#[derive(Debug, Clone)]
+pub struct AddCommonAreaPhoneRequestBuilder(AddCommonAreaPhoneRequest);
+
+impl AddCommonAreaPhoneRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: i64) -> Self {
+        self.0.extension_number = extension_number;
+        self
+    }
+
+    pub fn mac_address(mut self, mac_address: impl Into<String>) -> Self {
+        self.0.mac_address = mac_address.into();
+        self
+    }
+
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.0.model = model.into();
+        self
+    }
+
+    pub fn provision_template_id(mut self, provision_template_id: impl Into<String>) -> Self {
+        self.0.provision_template_id = provision_template_id.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+        self.0.time_zone = time_zone.into();
+        self
+    }
+
+    pub fn type_(mut self, type_: impl Into<String>) -> Self {
+        self.0.type_ = type_.into();
+        self
+    }
+
+    pub fn build(self) -> AddCommonAreaPhoneRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCommonAreaPhoneResponseProvisionSipAccounts {
     /**
@@ -31860,8 +34577,8 @@ pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     Unsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
@@ -31871,7 +34588,7 @@ impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On => "on",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported => "unsupported",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop => "",
-            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::FallthroughString => "*",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32002,7 +34719,7 @@ pub struct UpdateCommonAreaPhoneRequestPolicy {
     pub select_outbound_caller_id: Option<ShowDeviceIpCallLog>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateCommonAreaPhoneRequest {
     /**
     * User's first name.
@@ -32053,7 +34770,55 @@ pub struct UpdateCommonAreaPhoneRequest {
     pub site_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl UpdateCommonAreaPhoneRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateCommonAreaPhoneRequestBuilder {
+        UpdateCommonAreaPhoneRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateCommonAreaPhoneRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateCommonAreaPhoneRequestBuilder(UpdateCommonAreaPhoneRequest);
+
+impl UpdateCommonAreaPhoneRequestBuilder {
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: i64) -> Self {
+        self.0.extension_number = extension_number;
+        self
+    }
+
+    pub fn mac_address(mut self, mac_address: impl Into<String>) -> Self {
+        self.0.mac_address = mac_address.into();
+        self
+    }
+
+    pub fn policy(mut self, policy: impl Into<UpdateCommonAreaPhoneRequestPolicy>) -> Self {
+        self.0.policy = Some(policy.into());
+        self
+    }
+
+    pub fn provision_template_id(mut self, provision_template_id: impl Into<String>) -> Self {
+        self.0.provision_template_id = provision_template_id.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateCommonAreaPhoneRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignPhoneNumbersCommonAreaRequest {
     #[serde(
         default,
@@ -32063,6 +34828,29 @@ pub struct AssignPhoneNumbersCommonAreaRequest {
     pub phone_numbers: Vec<PhoneUserResponseNumbers>,
 }
 
+impl AssignPhoneNumbersCommonAreaRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AssignPhoneNumbersCommonAreaRequestBuilder {
+        AssignPhoneNumbersCommonAreaRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AssignPhoneNumbersCommonAreaRequest`.
+#[derive(Debug, Clone)]
+pub struct AssignPhoneNumbersCommonAreaRequestBuilder(AssignPhoneNumbersCommonAreaRequest);
+
+impl AssignPhoneNumbersCommonAreaRequestBuilder {
+    pub fn phone_numbers(mut self, phone_numbers: Vec<PhoneUserResponseNumbers>) -> Self {
+        self.0.phone_numbers = phone_numbers;
+        self
+    }
+
+    pub fn build(self) -> AssignPhoneNumbersCommonAreaRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignPhoneNumbersCommonAreaResponse {
     /**
@@ -32095,7 +34883,7 @@ pub struct AssignPhoneNumbersCommonAreaResponseData {
     pub phone_numbers: Vec<AssignPhoneNumbersCommonAreaResponse>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignCallingPlansCommonAreaPhoneRequest {
     /**
     * Account seats.
@@ -32109,6 +34897,31 @@ pub struct AssignCallingPlansCommonAreaPhoneRequest {
     pub type_: i64,
 }
 
+impl AssignCallingPlansCommonAreaPhoneRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AssignCallingPlansCommonAreaPhoneRequestBuilder {
+        AssignCallingPlansCommonAreaPhoneRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AssignCallingPlansCommonAreaPhoneRequest`.
+#[derive(Debug, Clone)]
+pub struct AssignCallingPlansCommonAreaPhoneRequestBuilder(
+    AssignCallingPlansCommonAreaPhoneRequest,
+);
+
+impl AssignCallingPlansCommonAreaPhoneRequestBuilder {
+    pub fn type_(mut self, type_: i64) -> Self {
+        self.0.type_ = type_;
+        self
+    }
+
+    pub fn build(self) -> AssignCallingPlansCommonAreaPhoneRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignCallingPlansCommonAreaPhoneRequestData {
     #[serde(
@@ -32165,8 +34978,8 @@ pub enum MatchType {
     Prefix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -32175,7 +34988,7 @@ impl std::fmt::Display for MatchType {
             MatchType::PhoneNumber => "phoneNumber",
             MatchType::Prefix => "prefix",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32240,80 +35053,123 @@ pub struct BlockedList {
     pub status: Option<ReportUsersType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListBlockedResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub blocked_list: Vec<BlockedList>,
-    /**
-    * User's first name.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next_page_token: String,
-    /**
-    * Account seats.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub page_size: i64,
-    /**
-    * Account seats.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_records: i64,
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ListBlockedResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub blocked_list: Vec<BlockedList>,
+    /**
+    * User's first name.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page_token: String,
+    /**
+    * Account seats.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub page_size: i64,
+    /**
+    * Account seats.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_records: i64,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UpdateBlockedListRequest {
+    /**
+    * The direction of the call. Values: `inbound` or `outbound`.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_type: Option<Direction>,
+    /**
+    * User's first name.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub comment: String,
+    /**
+    * Indicates the match type for the blocked list. The values can be one of the following:<br>
+    *  `phoneNumber`: Indicates that only a specific phone number that is shown in the `phone_number` field is blocked.<br><br>
+    *  `prefix`: Indicates that all numbers starting with prefix that is shown in the `phone_number` field are blocked.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_type: Option<MatchType>,
+    /**
+    * User's first name.
+    */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub phone_number: String,
+    /**
+    * Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ReportUsersType>,
+}
+
+impl UpdateBlockedListRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateBlockedListRequestBuilder {
+        UpdateBlockedListRequestBuilder(Default::default())
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UpdateBlockedListRequest {
-    /**
-    * The direction of the call. Values: `inbound` or `outbound`.
-    */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub block_type: Option<Direction>,
-    /**
-    * User's first name.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub comment: String,
-    /**
-    * Indicates the match type for the blocked list. The values can be one of the following:<br>
-    *  `phoneNumber`: Indicates that only a specific phone number that is shown in the `phone_number` field is blocked.<br><br>
-    *  `prefix`: Indicates that all numbers starting with prefix that is shown in the `phone_number` field are blocked.
-    */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub match_type: Option<MatchType>,
-    /**
-    * User's first name.
-    */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone_number: String,
-    /**
-    * Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
-    */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<ReportUsersType>,
+/// The builder of a `UpdateBlockedListRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateBlockedListRequestBuilder(UpdateBlockedListRequest);
+
+impl UpdateBlockedListRequestBuilder {
+    pub fn block_type(mut self, block_type: impl Into<Direction>) -> Self {
+        self.0.block_type = Some(block_type.into());
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.0.comment = comment.into();
+        self
+    }
+
+    pub fn match_type(mut self, match_type: impl Into<MatchType>) -> Self {
+        self.0.match_type = Some(match_type.into());
+        self
+    }
+
+    pub fn phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.0.phone_number = phone_number.into();
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<ReportUsersType>) -> Self {
+        self.0.status = Some(status.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateBlockedListRequest {
+        self.0
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32591,7 +35447,7 @@ pub struct ListSharedLineGroupsResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateSharedLineGroupRequest {
     /**
     * User's first name.
@@ -32631,6 +35487,44 @@ pub struct CreateSharedLineGroupRequest {
     pub site_id: String,
 }
 
+impl CreateSharedLineGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateSharedLineGroupRequestBuilder {
+        CreateSharedLineGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateSharedLineGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateSharedLineGroupRequestBuilder(CreateSharedLineGroupRequest);
+
+impl CreateSharedLineGroupRequestBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = description.into();
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: i64) -> Self {
+        self.0.extension_number = extension_number;
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> CreateSharedLineGroupRequest {
+        self.0
+    }
+}
+
 /// Site assigned to the Shared Line Group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSharedLineGroupResponseSite {
@@ -32770,7 +35664,7 @@ pub struct PrimaryNumber {
     pub number: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSharedLineGroupRequest {
     /**
     * User's first name.
@@ -32811,8 +35705,51 @@ pub struct UpdateSharedLineGroupRequest {
     pub timezone: String,
 }
 
+impl UpdateSharedLineGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> UpdateSharedLineGroupRequestBuilder {
+        UpdateSharedLineGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `UpdateSharedLineGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct UpdateSharedLineGroupRequestBuilder(UpdateSharedLineGroupRequest);
+
+impl UpdateSharedLineGroupRequestBuilder {
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.0.display_name = display_name.into();
+        self
+    }
+
+    pub fn extension_number(mut self, extension_number: i64) -> Self {
+        self.0.extension_number = extension_number;
+        self
+    }
+
+    pub fn primary_number(mut self, primary_number: impl Into<PrimaryNumber>) -> Self {
+        self.0.primary_number = Some(primary_number.into());
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<ReportUsersType>) -> Self {
+        self.0.status = Some(status.into());
+        self
+    }
+
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.0.timezone = timezone.into();
+        self
+    }
+
+    pub fn build(self) -> UpdateSharedLineGroupRequest {
+        self.0
+    }
+}
+
 /// Members can comprise of users on the account as well as common area phones. You can add a maximum of 10 members at once.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddMembersSharedLineGroupRequest {
     /**
     * Members can comprise of users on the account as well as common area phones. You can add a maximum of 10 members at once.
@@ -32834,6 +35771,34 @@ pub struct AddMembersSharedLineGroupRequest {
     pub users: Vec<Assistants>,
 }
 
+impl AddMembersSharedLineGroupRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddMembersSharedLineGroupRequestBuilder {
+        AddMembersSharedLineGroupRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddMembersSharedLineGroupRequest`.
+#[derive(Debug, Clone)]
+pub struct AddMembersSharedLineGroupRequestBuilder(AddMembersSharedLineGroupRequest);
+
+impl AddMembersSharedLineGroupRequestBuilder {
+    pub fn common_area_phone_ids(mut self, common_area_phone_ids: Vec<String>) -> Self {
+        self.0.common_area_phone_ids = common_area_phone_ids;
+        self
+    }
+
+    pub fn users(mut self, users: Vec<Assistants>) -> Self {
+        self.0.users = users;
+        self
+    }
+
+    pub fn build(self) -> AddMembersSharedLineGroupRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddMembersSharedLineGroupRequestData {
     /**
@@ -32843,7 +35808,7 @@ pub struct AddMembersSharedLineGroupRequestData {
     pub members: Option<AddMembersSharedLineGroupRequest>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddAutoReceptionistRequest {
     /**
     * User's first name.
@@ -32865,6 +35830,34 @@ pub struct AddAutoReceptionistRequest {
     pub site_id: String,
 }
 
+impl AddAutoReceptionistRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddAutoReceptionistRequestBuilder {
+        AddAutoReceptionistRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddAutoReceptionistRequest`.
+#[derive(Debug, Clone)]
+pub struct AddAutoReceptionistRequestBuilder(AddAutoReceptionistRequest);
+
+impl AddAutoReceptionistRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> AddAutoReceptionistRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCallQueueRecordingsResponse {
     /**
@@ -33063,7 +36056,7 @@ pub struct Params {
     pub resource_email: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CheckInRoomsRequest {
     /**
     * User's first name.
@@ -33078,7 +36071,35 @@ pub struct CheckInRoomsRequest {
     pub params: Option<Params>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl CheckInRoomsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CheckInRoomsRequestBuilder {
+        CheckInRoomsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CheckInRoomsRequest`.
+#[derive(Debug, Clone)]
+pub struct CheckInRoomsRequestBuilder(CheckInRoomsRequest);
+
+impl CheckInRoomsRequestBuilder {
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.0.method = method.into();
+        self
+    }
+
+    pub fn params(mut self, params: impl Into<Params>) -> Self {
+        self.0.params = Some(params.into());
+        self
+    }
+
+    pub fn build(self) -> CheckInRoomsRequest {
+        self.0
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InMeetingRecordingControlRequest {
     /**
     * User's first name.
@@ -33091,6 +36112,43 @@ pub struct InMeetingRecordingControlRequest {
     pub method: String,
 }
 
+impl std::convert::From<String> for InMeetingRecordingControlRequest {
+    fn from(method: String) -> Self {
+        InMeetingRecordingControlRequest { method }
+    }
+}
+
+impl std::convert::From<&str> for InMeetingRecordingControlRequest {
+    fn from(method: &str) -> Self {
+        InMeetingRecordingControlRequest {
+            method: method.to_string(),
+        }
+    }
+}
+
+impl InMeetingRecordingControlRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> InMeetingRecordingControlRequestBuilder {
+        InMeetingRecordingControlRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `InMeetingRecordingControlRequest`.
+#[derive(Debug, Clone)]
+pub struct InMeetingRecordingControlRequestBuilder(InMeetingRecordingControlRequest);
+
+impl InMeetingRecordingControlRequestBuilder {
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.0.method = method.into();
+        self
+    }
+
+    pub fn build(self) -> InMeetingRecordingControlRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListSipTrunksResponse {
     /**
@@ -33168,7 +36226,7 @@ pub struct ListSipTrunksResponseData {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignSipTrunksRequest {
     /**
     * User's first name.
@@ -33199,6 +36257,39 @@ pub struct AssignSipTrunksRequest {
     pub outbound_caller_id: String,
 }
 
+impl AssignSipTrunksRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AssignSipTrunksRequestBuilder {
+        AssignSipTrunksRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AssignSipTrunksRequest`.
+#[derive(Debug, Clone)]
+pub struct AssignSipTrunksRequestBuilder(AssignSipTrunksRequest);
+
+impl AssignSipTrunksRequestBuilder {
+    pub fn dnis(mut self, dnis: impl Into<String>) -> Self {
+        self.0.dnis = dnis.into();
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.0.id = id.into();
+        self
+    }
+
+    pub fn outbound_caller_id(mut self, outbound_caller_id: impl Into<String>) -> Self {
+        self.0.outbound_caller_id = outbound_caller_id.into();
+        self
+    }
+
+    pub fn build(self) -> AssignSipTrunksRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignSipTrunksRequestData {
     /**
@@ -33321,7 +36412,7 @@ pub struct ListInternalCalloutCountriesResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddCalloutCountriesRequest {
     /**
     * User's first name.
@@ -33334,6 +36425,41 @@ pub struct AddCalloutCountriesRequest {
     pub id: String,
 }
 
+impl std::convert::From<String> for AddCalloutCountriesRequest {
+    fn from(id: String) -> Self {
+        AddCalloutCountriesRequest { id }
+    }
+}
+
+impl std::convert::From<&str> for AddCalloutCountriesRequest {
+    fn from(id: &str) -> Self {
+        AddCalloutCountriesRequest { id: id.to_string() }
+    }
+}
+
+impl AddCalloutCountriesRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddCalloutCountriesRequestBuilder {
+        AddCalloutCountriesRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddCalloutCountriesRequest`.
+#[derive(Debug, Clone)]
+pub struct AddCalloutCountriesRequestBuilder(AddCalloutCountriesRequest);
+
+impl AddCalloutCountriesRequestBuilder {
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.0.id = id.into();
+        self
+    }
+
+    pub fn build(self) -> AddCalloutCountriesRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddCalloutCountriesRequestData {
     /**
@@ -33370,8 +36496,8 @@ pub enum Languages {
     EnUs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Languages {
@@ -33381,7 +36507,7 @@ impl std::fmt::Display for Languages {
             Languages::EnGb => "en-GB",
             Languages::EnUs => "en-US",
             Languages::Noop => "",
-            Languages::FallthroughString => "*",
+            Languages::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33514,7 +36640,7 @@ pub struct ListInternalNumbersResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddInternalNumbersRequest {
     #[serde(
         default,
@@ -33524,6 +36650,29 @@ pub struct AddInternalNumbersRequest {
     pub internal_numbers: Vec<InternalNumbers>,
 }
 
+impl AddInternalNumbersRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddInternalNumbersRequestBuilder {
+        AddInternalNumbersRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddInternalNumbersRequest`.
+#[derive(Debug, Clone)]
+pub struct AddInternalNumbersRequestBuilder(AddInternalNumbersRequest);
+
+impl AddInternalNumbersRequestBuilder {
+    pub fn internal_numbers(mut self, internal_numbers: Vec<InternalNumbers>) -> Self {
+        self.0.internal_numbers = internal_numbers;
+        self
+    }
+
+    pub fn build(self) -> AddInternalNumbersRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddInternalNumbersResponse {
     /**
@@ -33639,8 +36788,8 @@ pub enum ChangeZoomRoomsAppVersionRequestAction {
     Upgrade,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
@@ -33650,7 +36799,7 @@ impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
             ChangeZoomRoomsAppVersionRequestAction::Downgrade => "downgrade",
             ChangeZoomRoomsAppVersionRequestAction::Upgrade => "upgrade",
             ChangeZoomRoomsAppVersionRequestAction::Noop => "",
-            ChangeZoomRoomsAppVersionRequestAction::FallthroughString => "*",
+            ChangeZoomRoomsAppVersionRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33667,7 +36816,7 @@ impl ChangeZoomRoomsAppVersionRequestAction {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChangeZoomRoomsAppVersionRequest {
     /**
     * Specify one of the following values for this field:
@@ -33680,6 +36829,29 @@ pub struct ChangeZoomRoomsAppVersionRequest {
     pub action: Option<ChangeZoomRoomsAppVersionRequestAction>,
 }
 
+impl ChangeZoomRoomsAppVersionRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> ChangeZoomRoomsAppVersionRequestBuilder {
+        ChangeZoomRoomsAppVersionRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `ChangeZoomRoomsAppVersionRequest`.
+#[derive(Debug, Clone)]
+pub struct ChangeZoomRoomsAppVersionRequestBuilder(ChangeZoomRoomsAppVersionRequest);
+
+impl ChangeZoomRoomsAppVersionRequestBuilder {
+    pub fn action(mut self, action: impl Into<ChangeZoomRoomsAppVersionRequestAction>) -> Self {
+        self.0.action = Some(action.into());
+        self
+    }
+
+    pub fn build(self) -> ChangeZoomRoomsAppVersionRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListDigitalSignageContentResponse {
     #[serde(
@@ -33765,8 +36937,8 @@ pub enum UpdatePresenceStatusRequest {
     Presenting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdatePresenceStatusRequest {
@@ -33780,7 +36952,7 @@ impl std::fmt::Display for UpdatePresenceStatusRequest {
             UpdatePresenceStatusRequest::OnACall => "On_A_Call",
             UpdatePresenceStatusRequest::Presenting => "Presenting",
             UpdatePresenceStatusRequest::Noop => "",
-            UpdatePresenceStatusRequest::FallthroughString => "*",
+            UpdatePresenceStatusRequest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34313,7 +37485,7 @@ pub struct GetCallQoSResponse {
     pub caller_qos: Option<CallerQos>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateBatchPollsRequest {
     /**
     * Array of Polls
@@ -34335,6 +37507,34 @@ pub struct CreateBatchPollsRequest {
     pub title: String,
 }
 
+impl CreateBatchPollsRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> CreateBatchPollsRequestBuilder {
+        CreateBatchPollsRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `CreateBatchPollsRequest`.
+#[derive(Debug, Clone)]
+pub struct CreateBatchPollsRequestBuilder(CreateBatchPollsRequest);
+
+impl CreateBatchPollsRequestBuilder {
+    pub fn questions(mut self, questions: Vec<Questions>) -> Self {
+        self.0.questions = questions;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    pub fn build(self) -> CreateBatchPollsRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Polls {
     /**
@@ -34688,8 +37888,8 @@ pub enum Quality {
     NotGood,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Quality {
@@ -34698,7 +37898,7 @@ impl std::fmt::Display for Quality {
             Quality::Good => "GOOD",
             Quality::NotGood => "NOT GOOD",
             Quality::Noop => "",
-            Quality::FallthroughString => "*",
+            Quality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34795,8 +37995,8 @@ pub enum Result {
     Recorded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -34806,7 +38006,7 @@ impl std::fmt::Display for Result {
             Result::NoAnswer => "no_answer",
             Result::Recorded => "recorded",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35094,7 +38294,7 @@ pub struct GetCallLogDetailsResponse {
     pub result: Option<Result>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddByocNumberRequest {
     /**
     * User's first name.
@@ -35137,6 +38337,44 @@ pub struct AddByocNumberRequest {
     pub site_id: String,
 }
 
+impl AddByocNumberRequest {
+    /// Build the request from the defaults of its fields, setting only the fields it
+    /// needs.
+    pub fn builder() -> AddByocNumberRequestBuilder {
+        AddByocNumberRequestBuilder(Default::default())
+    }
+}
+
+/// The builder of a `AddByocNumberRequest`.
+#[derive(Debug, Clone)]
+pub struct AddByocNumberRequestBuilder(AddByocNumberRequest);
+
+impl AddByocNumberRequestBuilder {
+    pub fn carrier(mut self, carrier: impl Into<String>) -> Self {
+        self.0.carrier = carrier.into();
+        self
+    }
+
+    pub fn phone_numbers(mut self, phone_numbers: Vec<String>) -> Self {
+        self.0.phone_numbers = phone_numbers;
+        self
+    }
+
+    pub fn sip_group_id(mut self, sip_group_id: impl Into<String>) -> Self {
+        self.0.sip_group_id = sip_group_id.into();
+        self
+    }
+
+    pub fn site_id(mut self, site_id: impl Into<String>) -> Self {
+        self.0.site_id = site_id.into();
+        self
+    }
+
+    pub fn build(self) -> AddByocNumberRequest {
+        self.0
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListMeetingTemplatesResponse {
     /**
@@ -35197,8 +38435,8 @@ pub enum ListArchivedFilesQueryDateType {
     ArchiveCompleteTime,
     #[serde(rename = "meeting_start_time")]
     MeetingStartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListArchivedFilesQueryDateType {
@@ -35206,7 +38444,7 @@ impl std::fmt::Display for ListArchivedFilesQueryDateType {
         match &*self {
             ListArchivedFilesQueryDateType::ArchiveCompleteTime => "archive_complete_time",
             ListArchivedFilesQueryDateType::MeetingStartTime => "meeting_start_time",
-            ListArchivedFilesQueryDateType::FallthroughString => "*",
+            ListArchivedFilesQueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
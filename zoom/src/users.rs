@@ -46,27 +46,26 @@ impl Users {
         include_fields: crate::types::UsersIncludeFields,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::UsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !page_number.is_empty() {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !role_id.is_empty() {
-            query_args.push(("role_id".to_string(), role_id.to_string()));
+            query_args.push(("role_id", role_id.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users"), &query_args);
 
         let resp: crate::types::UsersResponseData = self.client.get(&url, None).await?;
 
@@ -91,18 +90,17 @@ impl Users {
         role_id: &str,
         include_fields: crate::types::UsersIncludeFields,
     ) -> Result<Vec<crate::types::UsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !role_id.is_empty() {
-            query_args.push(("role_id".to_string(), role_id.to_string()));
+            query_args.push(("role_id", role_id.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users"), &query_args);
 
         let mut resp: crate::types::UsersResponseData = self.client.get(&url, None).await?;
 
@@ -111,18 +109,13 @@ impl Users {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             users.append(&mut resp.users);
 
@@ -184,22 +177,21 @@ impl Users {
     */
     pub async fn user(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         login_type: crate::types::LoginType,
         encrypted_email: bool,
     ) -> Result<crate::types::UserResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if encrypted_email {
-            query_args.push(("encrypted_email".to_string(), encrypted_email.to_string()));
+            query_args.push(("encrypted_email", encrypted_email.to_string()));
         }
         if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+            query_args.push(("login_type", login_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/{}", crate::progenitor_support::encode_path(user_id),),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -229,37 +221,33 @@ impl Users {
     */
     pub async fn delete(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         action: crate::types::UserDeleteAction,
         transfer_email: &str,
         transfer_meeting: bool,
         transfer_webinar: bool,
         transfer_recording: bool,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.to_string().is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
         if !transfer_email.is_empty() {
-            query_args.push(("transfer_email".to_string(), transfer_email.to_string()));
+            query_args.push(("transfer_email", transfer_email.to_string()));
         }
         if transfer_meeting {
-            query_args.push(("transfer_meeting".to_string(), transfer_meeting.to_string()));
+            query_args.push(("transfer_meeting", transfer_meeting.to_string()));
         }
         if transfer_recording {
-            query_args.push((
-                "transfer_recording".to_string(),
-                transfer_recording.to_string(),
-            ));
+            query_args.push(("transfer_recording", transfer_recording.to_string()));
         }
         if transfer_webinar {
-            query_args.push(("transfer_webinar".to_string(), transfer_webinar.to_string()));
+            query_args.push(("transfer_webinar", transfer_webinar.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/{}", crate::progenitor_support::encode_path(user_id),),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -287,19 +275,18 @@ impl Users {
     */
     pub async fn update(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         login_type: crate::types::LoginType,
         body: &crate::types::UserUpdate,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+            query_args.push(("login_type", login_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!("/users/{}", crate::progenitor_support::encode_path(user_id),),
+            &query_args,
         );
 
         self.client
@@ -343,7 +330,11 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn assistant(&self, user_id: &str) -> Result<crate::types::UserAssistantsList> {
+    pub async fn assistant(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<crate::types::UserAssistantsList> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/assistants",
             crate::progenitor_support::encode_path(user_id),
@@ -373,9 +364,10 @@ impl Users {
     */
     pub async fn assistant_create(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserAssistantsList,
     ) -> Result<crate::types::AddRoleMembersResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/assistants",
             crate::progenitor_support::encode_path(user_id),
@@ -405,7 +397,8 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn assistants_delete(&self, user_id: &str) -> Result<()> {
+    pub async fn assistants_delete(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/assistants",
             crate::progenitor_support::encode_path(user_id),
@@ -434,7 +427,13 @@ impl Users {
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     * * `assistant_id: &str` -- User's first name.
     */
-    pub async fn assistant_delete(&self, user_id: &str, assistant_id: &str) -> Result<()> {
+    pub async fn assistant_delete(
+        &self,
+        user_id: impl AsRef<str>,
+        assistant_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let assistant_id = assistant_id.as_ref();
         let url = format!(
             "/users/{}/assistants/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -462,7 +461,11 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn scheduler(&self, user_id: &str) -> Result<crate::types::UserSchedulersList> {
+    pub async fn scheduler(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<crate::types::UserSchedulersList> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/schedulers",
             crate::progenitor_support::encode_path(user_id),
@@ -489,7 +492,8 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn schedulers_delete(&self, user_id: &str) -> Result<()> {
+    pub async fn schedulers_delete(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/schedulers",
             crate::progenitor_support::encode_path(user_id),
@@ -517,7 +521,13 @@ impl Users {
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     * * `scheduler_id: &str` -- User's first name.
     */
-    pub async fn scheduler_delete(&self, user_id: &str, scheduler_id: &str) -> Result<()> {
+    pub async fn scheduler_delete(
+        &self,
+        user_id: impl AsRef<str>,
+        scheduler_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let scheduler_id = scheduler_id.as_ref();
         let url = format!(
             "/users/{}/schedulers/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -542,7 +552,8 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn picture(&self, user_id: &str) -> Result<()> {
+    pub async fn picture(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/picture",
             crate::progenitor_support::encode_path(user_id),
@@ -585,29 +596,28 @@ impl Users {
     */
     pub async fn settings_domains(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         login_type: crate::types::LoginType,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+            query_args.push(("login_type", login_type.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -647,29 +657,28 @@ impl Users {
     */
     pub async fn settings_user(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         login_type: crate::types::LoginType,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::UserSettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+            query_args.push(("login_type", login_type.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -709,29 +718,28 @@ impl Users {
     */
     pub async fn settings_meeting_security(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         login_type: crate::types::LoginType,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+            query_args.push(("login_type", login_type.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -771,29 +779,28 @@ impl Users {
     */
     pub async fn setting(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         login_type: crate::types::LoginType,
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> Result<crate::types::UserSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+            query_args.push(("login_type", login_type.to_string()));
         }
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -816,18 +823,20 @@ impl Users {
     pub async fn settings_update(
         &self,
         option: crate::types::UserSettingsUpdateOption,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserSettingsUpdateRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -852,9 +861,10 @@ impl Users {
     */
     pub async fn status(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserStatusRequest,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/status",
             crate::progenitor_support::encode_path(user_id),
@@ -885,9 +895,10 @@ impl Users {
     */
     pub async fn password(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UserPasswordRequest,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/password",
             crate::progenitor_support::encode_path(user_id),
@@ -913,7 +924,11 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn permission(&self, user_id: &str) -> Result<crate::types::UserPermissions> {
+    pub async fn permission(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<crate::types::UserPermissions> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/permissions",
             crate::progenitor_support::encode_path(user_id),
@@ -941,22 +956,24 @@ impl Users {
     */
     pub async fn token(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         type_: crate::types::UserTokenType,
         ttl: i64,
     ) -> Result<crate::types::UserZakResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ttl > 0 {
-            query_args.push(("ttl".to_string(), ttl.to_string()));
+            query_args.push(("ttl", ttl.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/token?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/token",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -977,7 +994,8 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn sso_token_delete(&self, user_id: &str) -> Result<()> {
+    pub async fn sso_token_delete(&self, user_id: impl AsRef<str>) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/token",
             crate::progenitor_support::encode_path(user_id),
@@ -1005,12 +1023,11 @@ impl Users {
     * * `email: &str` -- The email address to be verified.
     */
     pub async fn email(&self, email: &str) -> Result<crate::types::UserEmailResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/email?{}", query_);
+        let url = crate::progenitor_support::build_url(format_args!("/users/email"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1035,7 +1052,12 @@ impl Users {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn email_update(&self, user_id: &str, body: &crate::types::Members) -> Result<()> {
+    pub async fn email_update(
+        &self,
+        user_id: impl AsRef<str>,
+        body: &crate::types::Members,
+    ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/email",
             crate::progenitor_support::encode_path(user_id),
@@ -1065,12 +1087,12 @@ impl Users {
         &self,
         vanity_name: &str,
     ) -> Result<crate::types::UserVanityNameResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !vanity_name.is_empty() {
-            query_args.push(("vanity_name".to_string(), vanity_name.to_string()));
+            query_args.push(("vanity_name", vanity_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/vanity_name?{}", query_);
+        let url =
+            crate::progenitor_support::build_url(format_args!("/users/vanity_name"), &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1101,10 +1123,12 @@ impl Users {
     */
     pub async fn switch_account(
         &self,
-        account_id: &str,
-        user_id: &str,
+        account_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
         body: &crate::types::SwitchUserAccountRequest,
     ) -> Result<()> {
+        let account_id = account_id.as_ref();
+        let user_id = user_id.as_ref();
         let url = format!(
             "/accounts/{}/users/{}/account",
             crate::progenitor_support::encode_path(account_id),
@@ -1140,9 +1164,10 @@ impl Users {
     */
     pub async fn update_presence_status(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UpdatePresenceStatusRequestData,
     ) -> Result<()> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/presence_status",
             crate::progenitor_support::encode_path(user_id),
@@ -1174,9 +1199,10 @@ impl Users {
     */
     pub async fn upload_v_buser(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         body: &crate::types::UploadVbRequest,
     ) -> Result<crate::types::Files> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/settings/virtual_backgrounds",
             crate::progenitor_support::encode_path(user_id),
@@ -1204,16 +1230,18 @@ impl Users {
     * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
     * * `user_id: &str` -- Unique identifier of the user. Retrieve the value of this field by calling the [List users](https://marketplace.zoom.us/docs/api-reference/zoom-api/users/users) API. .
     */
-    pub async fn del_vb(&self, user_id: &str, file_ids: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    pub async fn del_vb(&self, user_id: impl AsRef<str>, file_ids: &str) -> Result<()> {
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -0,0 +1,199 @@
+//! Schedule webinars, and manage the registrants of the ones that require
+//! registration.
+//!
+//! ```no_run
+//! # async fn run(
+//! #     zoom: zoom_api::Client,
+//! #     webinar: zoom_api::types::WebinarInfo,
+//! #     registrant: zoom_api::types::Registrant,
+//! # ) -> anyhow::Result<()> {
+//! use zoom_api::types::MeetingRegistrantsStatus;
+//!
+//! let webinars = zoom.webinars();
+//! let created = webinars.webinar_create("me", &webinar).await?;
+//! let webinar_id = created.webinar_create_response.id;
+//!
+//! webinars.registrant_add(webinar_id, &registrant, &[]).await?;
+//! let pending = webinars
+//!     .registrants_list_all(webinar_id, MeetingRegistrantsStatus::Pending)
+//!     .await?;
+//! let ids: Vec<&str> = pending.iter().map(|r| r.id.as_str()).collect();
+//! webinars.registrants_approve(webinar_id, &ids).await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::{
+    types::{
+        Assistants, MeetingRegistrantsStatus, Registrant, RegistrantStatus, RegistrantStatusAction,
+        WebinarCreateResponseAllOf, WebinarInfo, WebinarRegistrantCreateResponse,
+    },
+    webinars::Webinars,
+};
+
+/// A registrant of a webinar, as listed by `Webinars::registrants_list_all`. They
+/// have the same fields as the registrants of meetings.
+pub type WebinarRegistrant = crate::registrants::MeetingRegistrant;
+
+#[derive(Deserialize)]
+struct RegistrantsPage {
+    #[serde(default)]
+    registrants: Vec<WebinarRegistrant>,
+    #[serde(default)]
+    next_page_token: String,
+}
+
+impl Webinars {
+    /// Schedule a webinar hosted by a user, or by the user of the token with `me`.
+    ///
+    /// The fields that are only ever returned, like `join_url`, are left out when
+    /// empty.
+    pub async fn webinar_create(
+        &self,
+        user_id: &str,
+        webinar: &WebinarInfo,
+    ) -> Result<WebinarCreateResponseAllOf> {
+        let url = format!(
+            "/users/{}/webinars",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        self.client
+            .post(
+                &url,
+                Some(reqwest::Body::from(serde_json::to_vec(webinar)?)),
+            )
+            .await
+    }
+
+    /// Update a webinar, or only one occurrence of a recurring webinar when
+    /// `occurrence_id` is not empty. The fields left empty are not changed.
+    pub async fn webinar_update(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        webinar: &WebinarInfo,
+    ) -> Result<()> {
+        let mut url = format!(
+            "/webinars/{}",
+            crate::progenitor_support::encode_path(&webinar_id),
+        );
+        if !occurrence_id.is_empty() {
+            url =
+                crate::progenitor_support::append_query(&url, &[("occurrence_id", occurrence_id)]);
+        }
+
+        self.client
+            .patch(
+                &url,
+                Some(reqwest::Body::from(serde_json::to_vec(webinar)?)),
+            )
+            .await
+    }
+
+    /// Register someone for a webinar, for the given occurrences of a recurring
+    /// webinar or for all of them when there are none.
+    pub async fn registrant_add(
+        &self,
+        webinar_id: i64,
+        registrant: &Registrant,
+        occurrence_ids: &[&str],
+    ) -> Result<WebinarRegistrantCreateResponse> {
+        let mut url = format!(
+            "/webinars/{}/registrants",
+            crate::progenitor_support::encode_path(&webinar_id),
+        );
+        if !occurrence_ids.is_empty() {
+            url = crate::progenitor_support::append_query(
+                &url,
+                &[("occurrence_ids", &occurrence_ids.join(","))],
+            );
+        }
+
+        self.client
+            .post(
+                &url,
+                Some(reqwest::Body::from(serde_json::to_vec(registrant)?)),
+            )
+            .await
+    }
+
+    /// List all the registrants of a webinar with the given status, requesting
+    /// every page.
+    pub async fn registrants_list_all(
+        &self,
+        webinar_id: i64,
+        status: MeetingRegistrantsStatus,
+    ) -> Result<Vec<WebinarRegistrant>> {
+        let url = crate::progenitor_support::append_query(
+            &format!(
+                "/webinars/{}/registrants",
+                crate::progenitor_support::encode_path(&webinar_id),
+            ),
+            &[("status", &status.to_string()), ("page_size", "300")],
+        );
+
+        let mut resp: RegistrantsPage = self.client.get(&url, None).await?;
+        let mut registrants = resp.registrants;
+        while !resp.next_page_token.is_empty() {
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(
+                        &url,
+                        &[("next_page_token", &resp.next_page_token)],
+                    ),
+                    None,
+                )
+                .await?;
+            registrants.append(&mut resp.registrants);
+        }
+
+        Ok(registrants)
+    }
+
+    /// Approve the registrants with the given ids, who are then sent the link to
+    /// join the webinar.
+    pub async fn registrants_approve(
+        &self,
+        webinar_id: i64,
+        registrant_ids: &[&str],
+    ) -> Result<()> {
+        self.registrants_set_status(webinar_id, RegistrantStatusAction::Approve, registrant_ids)
+            .await
+    }
+
+    /// Deny the registrants with the given ids.
+    pub async fn registrants_deny(&self, webinar_id: i64, registrant_ids: &[&str]) -> Result<()> {
+        self.registrants_set_status(webinar_id, RegistrantStatusAction::Deny, registrant_ids)
+            .await
+    }
+
+    /// Cancel the registration of approved registrants.
+    pub async fn registrants_cancel(&self, webinar_id: i64, registrant_ids: &[&str]) -> Result<()> {
+        self.registrants_set_status(webinar_id, RegistrantStatusAction::Cancel, registrant_ids)
+            .await
+    }
+
+    async fn registrants_set_status(
+        &self,
+        webinar_id: i64,
+        action: RegistrantStatusAction,
+        registrant_ids: &[&str],
+    ) -> Result<()> {
+        let body = RegistrantStatus {
+            action,
+            registrants: registrant_ids
+                .iter()
+                .map(|id| Assistants {
+                    email: String::new(),
+                    id: id.to_string(),
+                })
+                .collect(),
+        };
+
+        self.registrant_status(webinar_id, "", &body).await
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+pub use crate::webinar_management::WebinarRegistrant;
 use crate::Client;
 
 pub struct Webinars {
@@ -37,22 +38,24 @@ impl Webinars {
     */
     pub async fn get(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
         page_size: i64,
         page_number: i64,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let user_id = user_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/webinars?{}",
-            crate::progenitor_support::encode_path(user_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/users/{}/webinars",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -76,7 +79,11 @@ impl Webinars {
     *
     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
     */
-    pub async fn create(&self, user_id: &str) -> Result<crate::types::WebinarCreateResponseAllOf> {
+    pub async fn create(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<crate::types::WebinarCreateResponseAllOf> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/webinars",
             crate::progenitor_support::encode_path(user_id),
@@ -107,21 +114,22 @@ impl Webinars {
         occurrence_id: &str,
         show_previous_occurrences: bool,
     ) -> Result<crate::types::WebinarResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if show_previous_occurrences {
             query_args.push((
-                "show_previous_occurrences".to_string(),
+                "show_previous_occurrences",
                 show_previous_occurrences.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}",
+                crate::progenitor_support::encode_path(&webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -155,21 +163,22 @@ impl Webinars {
         occurrence_id: &str,
         cancel_webinar_reminder: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cancel_webinar_reminder.is_empty() {
             query_args.push((
-                "cancel_webinar_reminder".to_string(),
+                "cancel_webinar_reminder",
                 cancel_webinar_reminder.to_string(),
             ));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}",
+                crate::progenitor_support::encode_path(&webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -194,15 +203,16 @@ impl Webinars {
     * * `occurrence_id: &str` -- Webinar occurrence id. Support change of agenda, start_time, duration, settings: {host_video, panelist_video, hd_video, watermark, auto_recording}.
     */
     pub async fn update(&self, webinar_id: i64, occurrence_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}",
+                crate::progenitor_support::encode_path(&webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.patch(&url, None).await
@@ -233,22 +243,24 @@ impl Webinars {
     */
     pub async fn list_participants(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Participants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/past_webinars/{}/participants?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/past_webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListWebinarParticipantsResponse =
@@ -279,8 +291,9 @@ impl Webinars {
     */
     pub async fn list_all_participants(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<Vec<crate::types::Participants>> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/past_webinars/{}/participants",
             crate::progenitor_support::encode_path(webinar_id),
@@ -294,18 +307,13 @@ impl Webinars {
 
         // Paginate if we should.
         while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
+            resp = self
+                .client
+                .get(
+                    &crate::progenitor_support::append_query(&url, &[("next_page_token", &page)]),
+                    None,
+                )
+                .await?;
 
             participants.append(&mut resp.participants);
 
@@ -343,7 +351,7 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/status",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client
@@ -372,7 +380,7 @@ impl Webinars {
     pub async fn panelist(&self, webinar_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/webinars/{}/panelists",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client.get(&url, None).await
@@ -402,7 +410,7 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/panelists",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client
@@ -429,7 +437,7 @@ impl Webinars {
     pub async fn panelists_delete(&self, webinar_id: i64) -> Result<()> {
         let url = format!(
             "/webinars/{}/panelists",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client.delete(&url, None).await
@@ -457,8 +465,8 @@ impl Webinars {
     pub async fn panelist_delete(&self, webinar_id: i64, panelist_id: i64) -> Result<()> {
         let url = format!(
             "/webinars/{}/panelists/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&panelist_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
+            crate::progenitor_support::encode_path(&panelist_id),
         );
 
         self.client.delete(&url, None).await
@@ -493,7 +501,7 @@ impl Webinars {
     */
     pub async fn registrant(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         occurrence_id: &str,
         status: crate::types::MeetingRegistrantsStatus,
         tracking_source_id: &str,
@@ -501,33 +509,32 @@ impl Webinars {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !tracking_source_id.is_empty() {
-            query_args.push((
-                "tracking_source_id".to_string(),
-                tracking_source_id.to_string(),
-            ));
+            query_args.push(("tracking_source_id", tracking_source_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}/registrants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -552,18 +559,20 @@ impl Webinars {
     */
     pub async fn registrant_create(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         occurrence_ids: &str,
     ) -> Result<crate::types::WebinarRegistrantCreateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_ids.is_empty() {
-            query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
+            query_args.push(("occurrence_ids", occurrence_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}/registrants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -596,9 +605,10 @@ impl Webinars {
     */
     pub async fn add_batch_registrants(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         body: &crate::types::AddBatchRegistrantsRequest,
     ) -> Result<crate::types::AddBatchRegistrantsResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/webinars/{}/batch_registrants",
             crate::progenitor_support::encode_path(webinar_id),
@@ -632,15 +642,16 @@ impl Webinars {
         occurrence_id: &str,
         body: &crate::types::RegistrantStatus,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants/status?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}/registrants/status",
+                crate::progenitor_support::encode_path(&webinar_id),
+            ),
+            &query_args,
         );
 
         self.client
@@ -667,7 +678,7 @@ impl Webinars {
     pub async fn past(&self, webinar_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/past_webinars/{}/instances",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client.get(&url, None).await
@@ -692,7 +703,7 @@ impl Webinars {
     pub async fn poll(&self, webinar_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/webinars/{}/polls",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client.get(&url, None).await
@@ -721,7 +732,7 @@ impl Webinars {
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
         let url = format!(
             "/webinars/{}/polls",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client
@@ -749,11 +760,12 @@ impl Webinars {
     pub async fn poll_get(
         &self,
         webinar_id: i64,
-        poll_id: &str,
+        poll_id: impl AsRef<str>,
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
+        let poll_id = poll_id.as_ref();
         let url = format!(
             "/webinars/{}/polls/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
             crate::progenitor_support::encode_path(poll_id),
         );
 
@@ -780,12 +792,13 @@ impl Webinars {
     pub async fn poll_update(
         &self,
         webinar_id: i64,
-        poll_id: &str,
+        poll_id: impl AsRef<str>,
         body: &crate::types::Poll,
     ) -> Result<()> {
+        let poll_id = poll_id.as_ref();
         let url = format!(
             "/webinars/{}/polls/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
             crate::progenitor_support::encode_path(poll_id),
         );
 
@@ -811,10 +824,11 @@ impl Webinars {
     * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
     * * `poll_id: &str` -- User's first name.
     */
-    pub async fn poll_delete(&self, webinar_id: i64, poll_id: &str) -> Result<()> {
+    pub async fn poll_delete(&self, webinar_id: i64, poll_id: impl AsRef<str>) -> Result<()> {
+        let poll_id = poll_id.as_ref();
         let url = format!(
             "/webinars/{}/polls/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
             crate::progenitor_support::encode_path(poll_id),
         );
 
@@ -845,7 +859,7 @@ impl Webinars {
     ) -> Result<crate::types::WebinarRegistrantQuestions> {
         let url = format!(
             "/webinars/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client.get(&url, None).await
@@ -877,7 +891,7 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client
@@ -905,20 +919,23 @@ impl Webinars {
     */
     pub async fn registrant_get(
         &self,
-        webinar_id: &str,
-        registrant_id: &str,
+        webinar_id: impl AsRef<str>,
+        registrant_id: impl AsRef<str>,
         occurrence_id: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let registrant_id = registrant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants/{}?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            crate::progenitor_support::encode_path(registrant_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}/registrants/{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(registrant_id),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -942,20 +959,23 @@ impl Webinars {
     */
     pub async fn delete_registrant(
         &self,
-        webinar_id: &str,
-        registrant_id: &str,
+        webinar_id: impl AsRef<str>,
+        registrant_id: impl AsRef<str>,
         occurrence_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_id = webinar_id.as_ref();
+        let registrant_id = registrant_id.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants/{}?{}",
-            crate::progenitor_support::encode_path(webinar_id),
-            crate::progenitor_support::encode_path(registrant_id),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/webinars/{}/registrants/{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(registrant_id),
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
@@ -982,23 +1002,25 @@ impl Webinars {
         occurrence_id: &str,
         page_size: i64,
         next_page_token: &str,
-        webinar_uuid: &str,
+        webinar_uuid: impl AsRef<str>,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let webinar_uuid = webinar_uuid.as_ref();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/past_webinars/{}/absentees?{}",
-            crate::progenitor_support::encode_path(webinar_uuid),
-            query_
+        let url = crate::progenitor_support::build_url(
+            format_args!(
+                "/past_webinars/{}/absentees",
+                crate::progenitor_support::encode_path(webinar_uuid),
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1024,8 +1046,9 @@ impl Webinars {
     */
     pub async fn get_tracking_sources(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::GetTrackingSourcesResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/webinars/{}/tracking_sources",
             crate::progenitor_support::encode_path(webinar_id),
@@ -1056,8 +1079,9 @@ impl Webinars {
     */
     pub async fn list_past_poll_results(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/past_webinars/{}/polls",
             crate::progenitor_support::encode_path(webinar_id),
@@ -1090,8 +1114,9 @@ impl Webinars {
     */
     pub async fn list_past_qa(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::ReportWebinarQaResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/past_webinars/{}/qa",
             crate::progenitor_support::encode_path(webinar_id),
@@ -1114,8 +1139,9 @@ impl Webinars {
     */
     pub async fn list_templates(
         &self,
-        user_id: &str,
+        user_id: impl AsRef<str>,
     ) -> Result<crate::types::ListWebinarTemplatesResponse> {
+        let user_id = user_id.as_ref();
         let url = format!(
             "/users/{}/webinar_templates",
             crate::progenitor_support::encode_path(user_id),
@@ -1146,8 +1172,9 @@ impl Webinars {
     */
     pub async fn get_live_stream_details(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
     ) -> Result<crate::types::GetLiveStreamDetailsResponse> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/webinars/{}/livestream",
             crate::progenitor_support::encode_path(webinar_id),
@@ -1177,9 +1204,10 @@ impl Webinars {
     */
     pub async fn live_stream_update(
         &self,
-        webinar_id: &str,
+        webinar_id: impl AsRef<str>,
         body: &crate::types::MeetingLiveStream,
     ) -> Result<()> {
+        let webinar_id = webinar_id.as_ref();
         let url = format!(
             "/webinars/{}/livestream",
             crate::progenitor_support::encode_path(webinar_id),
@@ -1215,7 +1243,7 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/livestream/status",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client
@@ -1247,7 +1275,7 @@ impl Webinars {
     ) -> Result<crate::types::InviteLinks> {
         let url = format!(
             "/webinars/{}/invite_links",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            crate::progenitor_support::encode_path(&webinar_id),
         );
 
         self.client